@@ -1,5 +1,10 @@
 fn main() {
     // Tree-sitter grammar crates handle their own compilation via build scripts.
-    // This build.rs is reserved for any future build-time logic (e.g., embedding
-    // git commit hash for version display).
+    // The GitHub schema is registered for cynic, which checks the GraphQL
+    // queries in src/github/graphql.rs against it.
+    cynic_codegen::register_schema("github")
+        .from_sdl_file("src/github/schema.docs.graphql")
+        .expect("GitHub GraphQL schema is present")
+        .as_default()
+        .expect("GitHub GraphQL schema is valid");
 }
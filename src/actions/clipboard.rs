@@ -1,4 +1,9 @@
+use std::collections::VecDeque;
 use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+
+use crate::config::keybindings::{TemplateVars, expand_template};
+use crate::config::types::{BrowserConfig, CopyConfig, SshBrowserMode};
 
 // ---------------------------------------------------------------------------
 // Clipboard operations (T091 — FR-143)
@@ -48,18 +53,197 @@ pub(crate) fn copy_to_clipboard(text: &str) -> Result<(), String> {
     };
 
     match result {
-        Ok(status) if status.success() => Ok(()),
+        Ok(status) if status.success() => {
+            if let Ok(mut history) = HISTORY.lock() {
+                remember(&mut history, text, history_size());
+            }
+            Ok(())
+        }
         Ok(status) => Err(format!("Clipboard command exited with {status}")),
         Err(e) => Err(format!("Failed to run clipboard command: {e}")),
     }
 }
 
+// ---------------------------------------------------------------------------
+// Copy templates and history
+// ---------------------------------------------------------------------------
+
+static COPY: OnceLock<CopyConfig> = OnceLock::new();
+
+/// Past copies, newest first.
+static HISTORY: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Set the `[copy]` settings. Only the first call takes effect.
+pub fn set_copy_config(config: CopyConfig) {
+    let _ = COPY.set(config);
+}
+
+fn history_size() -> usize {
+    COPY.get().and_then(|c| c.history).unwrap_or(10)
+}
+
+/// `(label, template)` of the copy menu entries.
+pub(crate) fn copy_templates() -> Vec<(String, String)> {
+    match COPY.get().map(|c| &c.templates) {
+        Some(templates) if !templates.is_empty() => templates
+            .iter()
+            .map(|(label, template)| (label.clone(), template.clone()))
+            .collect(),
+        _ => vec![
+            (
+                "Markdown link".to_owned(),
+                "[#{{.Number}}]({{.Url}}) {{.Title}}".to_owned(),
+            ),
+            (
+                "Reference".to_owned(),
+                "{{.RepoName}}#{{.Number}}".to_owned(),
+            ),
+        ],
+    }
+}
+
+/// Text copied this session, newest first.
+pub(crate) fn copy_history() -> Vec<String> {
+    HISTORY
+        .lock()
+        .map(|history| history.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Put `text` at the front of `history`, once, keeping `size` entries.
+fn remember(history: &mut VecDeque<String>, text: &str, size: usize) {
+    if text.trim().is_empty() {
+        return;
+    }
+    history.retain(|past| past != text);
+    history.push_front(text.to_owned());
+    history.truncate(size);
+}
+
 // ---------------------------------------------------------------------------
 // Open in browser (T092 — FR-144)
 // ---------------------------------------------------------------------------
 
-/// Open a URL in the default browser.
-pub(crate) fn open_in_browser(url: &str) -> Result<(), String> {
+static BROWSER: OnceLock<BrowserConfig> = OnceLock::new();
+
+/// Set the `[browser]` settings. Only the first call takes effect.
+pub fn set_browser_config(config: BrowserConfig) {
+    let _ = BROWSER.set(config);
+}
+
+/// How a URL was opened, for the footer feedback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BrowserOpen {
+    /// Opened by the OS opener.
+    Opened,
+    /// Handed to `browser.ssh_command`.
+    Forwarded,
+    /// Copied to the SSH client's clipboard (OSC 52).
+    Copied,
+}
+
+impl BrowserOpen {
+    /// Footer message for opening `subject` (e.g. `#42`).
+    pub(crate) fn message(self, subject: &str) -> String {
+        match self {
+            Self::Opened => format!("Opened {subject}"),
+            Self::Forwarded => format!("Opened {subject} on the SSH client"),
+            Self::Copied => format!("{subject}: link copied to your clipboard (SSH session)"),
+        }
+    }
+}
+
+/// Whether gh-board runs inside an SSH session.
+fn in_ssh_session() -> bool {
+    ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+/// Open a URL with the `[browser]` command for its host, else the default
+/// browser.
+///
+/// Inside an SSH session the OS opener would start a browser on the remote
+/// machine (or fail silently), so the URL goes to the client instead: see
+/// [`SshBrowserMode`].
+pub(crate) fn open_in_browser(url: &str) -> Result<BrowserOpen, String> {
+    if !in_ssh_session() {
+        return open_locally(url).map(|()| BrowserOpen::Opened);
+    }
+    let config = BROWSER.get().cloned().unwrap_or_default();
+    match (
+        config.ssh.unwrap_or(SshBrowserMode::Osc52),
+        config.ssh_command,
+    ) {
+        (SshBrowserMode::Local, _) => open_locally(url).map(|()| BrowserOpen::Opened),
+        (SshBrowserMode::Command, Some(command)) => {
+            spawn_browser(&command, url, "browser.ssh_command").map(|()| BrowserOpen::Forwarded)
+        }
+        // `command` without a command falls back to the clipboard.
+        (SshBrowserMode::Command | SshBrowserMode::Osc52, _) => {
+            copy_osc52(url).map(|()| BrowserOpen::Copied)
+        }
+    }
+}
+
+/// Host of `url` (`github.com` for `https://github.com/o/r`).
+fn url_host(url: &str) -> Option<&str> {
+    let (_, rest) = url.split_once("://")?;
+    rest.split(['/', ':', '?', '#'])
+        .next()
+        .filter(|h| !h.is_empty())
+}
+
+/// Browser command for `url`: its host's `browser.hosts` entry, else
+/// `browser.command`.
+fn browser_command<'a>(config: &'a BrowserConfig, url: &str) -> Option<&'a str> {
+    url_host(url)
+        .and_then(|host| {
+            config
+                .hosts
+                .iter()
+                .find(|(h, _)| h.eq_ignore_ascii_case(host))
+        })
+        .map(|(_, command)| command)
+        .or(config.command.as_ref())
+        .map(String::as_str)
+}
+
+/// The shell running `command` for `url`. The URL is passed as `$1`, so
+/// shell characters in it are not interpreted.
+fn browser_process(command: &str, url: &str) -> Command {
+    let vars = TemplateVars {
+        url: "\"$1\"".to_owned(),
+        ..TemplateVars::default()
+    };
+    let mut process = Command::new("sh");
+    process
+        .arg("-c")
+        .arg(expand_template(command, &vars))
+        .arg("sh")
+        .arg(url);
+    process
+}
+
+/// Start `command` (the `setting` named in errors) for `url` without
+/// waiting for it: a browser that was not running yet stays in the
+/// foreground until it is closed.
+fn spawn_browser(command: &str, url: &str, setting: &str) -> Result<(), String> {
+    use std::process::Stdio;
+    let mut child = browser_process(command, url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("{setting}: {e}"))?;
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+fn open_locally(url: &str) -> Result<(), String> {
+    if let Some(command) = BROWSER.get().and_then(|c| browser_command(c, url)) {
+        return spawn_browser(command, url, "browser.command");
+    }
     let result = if cfg!(target_os = "macos") {
         Command::new("open").arg(url).status()
     } else if cfg!(target_os = "linux") {
@@ -75,6 +259,49 @@ pub(crate) fn open_in_browser(url: &str) -> Result<(), String> {
     }
 }
 
+/// Copy `text` to the terminal's clipboard with an OSC 52 escape, which the
+/// terminal emulator on the SSH client handles.
+fn copy_osc52(text: &str) -> Result<(), String> {
+    use std::io::Write;
+    let sequence = osc52_sequence(text, std::env::var_os("TMUX").is_some());
+    let mut stdout = std::io::stdout();
+    stdout
+        .write_all(sequence.as_bytes())
+        .and_then(|()| stdout.flush())
+        .map_err(|e| format!("Failed to write to the terminal: {e}"))
+}
+
+/// The OSC 52 "set clipboard" escape for `text`, wrapped in a DCS
+/// passthrough when running under tmux.
+fn osc52_sequence(text: &str, tmux: bool) -> String {
+    let osc = format!("\x1b]52;c;{}\x07", base64(text.as_bytes()));
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b"))
+    } else {
+        osc
+    }
+}
+
+/// Standard base64 with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(char::from(ALPHABET[(n >> (18 - 6 * i)) as usize & 63]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,4 +313,70 @@ mod tests {
         // We just check it doesn't panic.
         let _ = result;
     }
+
+    #[test]
+    fn base64_pads_partial_chunks() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(
+            base64(b"https://github.com/o/r/pull/1"),
+            "aHR0cHM6Ly9naXRodWIuY29tL28vci9wdWxsLzE="
+        );
+    }
+
+    #[test]
+    fn remember_moves_repeats_to_front_and_caps_size() {
+        let mut history = VecDeque::new();
+        for text in ["a", "b", "", "c", "a"] {
+            remember(&mut history, text, 3);
+        }
+        assert_eq!(history, ["a", "c", "b"]);
+        remember(&mut history, "d", 3);
+        assert_eq!(history, ["d", "a", "c"]);
+        remember(&mut history, "e", 0);
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn browser_command_prefers_the_url_host() {
+        let mut config = BrowserConfig {
+            command: Some("firefox -P work {{.Url}}".to_owned()),
+            ..BrowserConfig::default()
+        };
+        config.hosts.insert(
+            "GitHub.com".to_owned(),
+            "firefox -P personal {{.Url}}".to_owned(),
+        );
+        assert_eq!(
+            browser_command(&config, "https://github.com/o/r/pull/1"),
+            Some("firefox -P personal {{.Url}}")
+        );
+        assert_eq!(
+            browser_command(&config, "https://ghe.corp.com:8443/o/r/issues/2"),
+            Some("firefox -P work {{.Url}}")
+        );
+        assert_eq!(
+            browser_command(&BrowserConfig::default(), "https://github.com"),
+            None
+        );
+    }
+
+    #[test]
+    fn browser_url_is_not_seen_by_the_shell() {
+        let url = "https://acme.dev/$(touch pwned);x";
+        let process = browser_process("ssh laptop open {{.Url}}", url);
+        let args: Vec<_> = process.get_args().collect();
+        assert_eq!(args, ["-c", "ssh laptop open \"$1\"", "sh", url]);
+    }
+
+    #[test]
+    fn osc52_wraps_for_tmux() {
+        assert_eq!(osc52_sequence("hi", false), "\x1b]52;c;aGk=\x07");
+        assert_eq!(
+            osc52_sequence("hi", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\"
+        );
+    }
 }
@@ -89,3 +89,41 @@ pub(crate) async fn set_assignees(
         .context("setting assignees on issue")?;
     Ok(())
 }
+
+#[derive(serde::Deserialize)]
+struct MilestoneRef {
+    number: u64,
+    title: String,
+}
+
+/// Move an issue/PR to the milestone titled `title` (open or closed), or
+/// clear its milestone when `title` is `None`.
+pub(crate) async fn set_milestone(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    title: Option<&str>,
+) -> Result<()> {
+    let milestone = match title {
+        Some(title) => {
+            let route = format!("/repos/{owner}/{repo}/milestones?state=all&per_page=100");
+            let milestones: Vec<MilestoneRef> = octocrab
+                .get(route, None::<&()>)
+                .await
+                .context("listing milestones")?;
+            let Some(m) = milestones.into_iter().find(|m| m.title == title) else {
+                anyhow::bail!("no milestone named {title:?} in {owner}/{repo}");
+            };
+            Some(m.number)
+        }
+        None => None,
+    };
+    let route = format!("/repos/{owner}/{repo}/issues/{number}");
+    let payload = serde_json::json!({ "milestone": milestone });
+    let _: serde_json::Value = octocrab
+        .patch(route, Some(&payload))
+        .await
+        .context("setting milestone on issue")?;
+    Ok(())
+}
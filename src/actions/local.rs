@@ -1,6 +1,5 @@
 use indexmap::IndexMap;
 use std::path::{Path, PathBuf};
-use std::sync::mpsc::Sender;
 
 use anyhow::{Context, Result};
 
@@ -288,7 +287,7 @@ pub fn spawn_checkout(
     repo_paths: IndexMap<String, PathBuf>,
     host: String,
     fork: Option<ForkSource>,
-    reply_tx: Sender<String>,
+    reply_tx: async_channel::Sender<String>,
 ) {
     std::thread::spawn(move || {
         let msg = match checkout_branch(
@@ -301,7 +300,7 @@ pub fn spawn_checkout(
             Ok(m) => m,
             Err(e) => format!("Checkout error: {e:#}"),
         };
-        let _ = reply_tx.send(msg);
+        let _ = reply_tx.send_blocking(msg);
     });
 }
 
@@ -315,7 +314,7 @@ pub fn spawn_worktree(
     repo_paths: IndexMap<String, PathBuf>,
     host: String,
     fork: Option<ForkSource>,
-    reply_tx: Sender<String>,
+    reply_tx: async_channel::Sender<String>,
 ) {
     std::thread::spawn(move || {
         let msg = match create_or_open_worktree(
@@ -331,7 +330,7 @@ pub fn spawn_worktree(
             },
             Err(e) => format!("Worktree error: {e:#}"),
         };
-        let _ = reply_tx.send(msg);
+        let _ = reply_tx.send_blocking(msg);
     });
 }
 
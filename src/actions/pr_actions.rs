@@ -3,6 +3,10 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use octocrab::Octocrab;
 
+use crate::config::types::MergeMethod;
+use crate::github::client::HostClient;
+use crate::types::{ReviewEvent, ReviewLineComment};
+
 // ---------------------------------------------------------------------------
 // PR action API calls (T057)
 // ---------------------------------------------------------------------------
@@ -27,6 +31,43 @@ pub async fn approve(
     Ok(())
 }
 
+/// Submit a review with `event`, `body` and comments on changed lines.
+pub async fn submit_review(
+    octocrab: &Arc<Octocrab>,
+    (owner, repo, number): (&str, &str, u64),
+    event: ReviewEvent,
+    body: &str,
+    comments: &[ReviewLineComment],
+) -> Result<()> {
+    let route = format!("/repos/{owner}/{repo}/pulls/{number}/reviews");
+    let comments: Vec<serde_json::Value> = comments
+        .iter()
+        .map(|c| {
+            let mut comment = serde_json::json!({
+                "path": c.path,
+                "line": c.line,
+                "side": "RIGHT",
+                "body": c.body,
+            });
+            if let Some(start) = c.start_line {
+                comment["start_line"] = start.into();
+                comment["start_side"] = "RIGHT".into();
+            }
+            comment
+        })
+        .collect();
+    let payload = serde_json::json!({
+        "event": event.as_api_str(),
+        "body": body,
+        "comments": comments,
+    });
+    let _: serde_json::Value = octocrab
+        .post(route, Some(&payload))
+        .await
+        .context("submitting review")?;
+    Ok(())
+}
+
 /// Add a comment to a PR (via issues endpoint).
 pub async fn add_comment(
     octocrab: &Arc<Octocrab>,
@@ -44,11 +85,17 @@ pub async fn add_comment(
     Ok(())
 }
 
-/// Merge a pull request.
-pub async fn merge(octocrab: &Arc<Octocrab>, owner: &str, repo: &str, number: u64) -> Result<()> {
+/// Merge a pull request with `method`.
+pub async fn merge(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    method: MergeMethod,
+) -> Result<()> {
     let route = format!("/repos/{owner}/{repo}/pulls/{number}/merge");
     let payload = serde_json::json!({
-        "merge_method": "merge",
+        "merge_method": method.as_str(),
     });
     let _: serde_json::Value = octocrab
         .put(route, Some(&payload))
@@ -96,7 +143,7 @@ pub async fn update_branch(
 
 /// Mark a draft PR as ready for review.
 pub async fn ready_for_review(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
     owner: &str,
     repo: &str,
     number: u64,
@@ -122,8 +169,88 @@ pub async fn ready_for_review(
         "variables": variables,
     });
     let _: serde_json::Value = octocrab
+        .graphql_client()
         .post("/graphql", Some(&payload))
         .await
         .context("marking PR as ready for review")?;
     Ok(())
 }
+
+/// Mark `paths` of a pull request viewed (or unviewed) on GitHub.
+pub async fn set_files_viewed(
+    octocrab: &HostClient,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    paths: &[String],
+    viewed: bool,
+) -> Result<()> {
+    let query = if viewed {
+        r"mutation($id: ID!, $path: String!) {
+            markFileAsViewed(input: { pullRequestId: $id, path: $path }) {
+                clientMutationId
+            }
+        }"
+    } else {
+        r"mutation($id: ID!, $path: String!) {
+            unmarkFileAsViewed(input: { pullRequestId: $id, path: $path }) {
+                clientMutationId
+            }
+        }"
+    };
+
+    let route = format!("/repos/{owner}/{repo}/pulls/{number}");
+    let pr: serde_json::Value = octocrab
+        .get(route, None::<&()>)
+        .await
+        .context("fetching PR for node_id")?;
+    let node_id = pr["node_id"].as_str().context("PR missing node_id")?;
+
+    for path in paths {
+        let payload = serde_json::json!({
+            "query": query,
+            "variables": { "id": node_id, "path": path },
+        });
+        let resp: serde_json::Value = octocrab
+            .graphql_client()
+            .post("/graphql", Some(&payload))
+            .await
+            .context("marking file as viewed")?;
+        if let Some(message) = resp["errors"][0]["message"].as_str() {
+            anyhow::bail!("{path}: {message}");
+        }
+    }
+    Ok(())
+}
+
+/// Add a pull request to its base branch's merge queue.
+pub async fn enqueue(octocrab: &HostClient, owner: &str, repo: &str, number: u64) -> Result<()> {
+    let query = r"mutation($id: ID!) {
+        enqueuePullRequest(input: { pullRequestId: $id }) {
+            mergeQueueEntry { position }
+        }
+    }";
+
+    let route = format!("/repos/{owner}/{repo}/pulls/{number}");
+    let pr: serde_json::Value = octocrab
+        .get(route, None::<&()>)
+        .await
+        .context("fetching PR for node_id")?;
+    let node_id = pr["node_id"].as_str().context("PR missing node_id")?;
+
+    let payload = serde_json::json!({
+        "query": query,
+        "variables": { "id": node_id },
+    });
+    let resp: serde_json::Value = octocrab
+        .graphql_client()
+        .post("/graphql", Some(&payload))
+        .await
+        .context("adding PR to the merge queue")?;
+    // Refusals (queue disabled, PR not mergeable) come back as GraphQL
+    // errors with a 200 status.
+    if let Some(message) = resp["errors"][0]["message"].as_str() {
+        anyhow::bail!("{message}");
+    }
+    Ok(())
+}
@@ -1,14 +1,15 @@
 // Root application component and view management.
 //
 // This module provides the main `App` TUI component that orchestrates the
-// five views (PRs, Issues, Actions, Notifications, Repo) and handles global
-// key events.
+// views (PRs, Issues, Actions, Alerts, Notifications, Repo, Org Events) and
+// handles global key events.
 //
 // ## View Kind Enum
 //
 // `ViewKind` is the public-facing enum for view identification, used by
 // the status bar and other components to determine which view is active.
 use std::path::Path;
+use std::sync::Arc;
 
 use iocraft::prelude::*;
 
@@ -18,17 +19,23 @@ use crate::components::selection_overlay::{
 };
 use crate::components::text_input::filter_suggestions;
 use crate::config::keybindings::MergedBindings;
-use crate::config::types::{AppConfig, Scope};
-use crate::engine::EngineHandle;
+use crate::config::loader::ConfigWatcher;
+use crate::config::types::{AppConfig, DEFAULT_WIP_PATTERNS, Scope};
+use crate::engine::{EngineHandle, Event, Request};
 use crate::icons::ResolvedIcons;
-use crate::theme::ResolvedTheme;
-use crate::types::{RateLimitInfo, RepoRef};
+use crate::script::Message as ScriptMessage;
+use crate::theme::{Background, ResolvedTheme};
+use crate::types::{RateLimits, RepoRef, SavedReply, ScopedFeature, scope_hint};
+use crate::url::{ParsedGitHubUrl, parse_github_url};
 use crate::views::actions::ActionsView;
 use crate::views::alerts::AlertsView;
+use crate::views::events::EventsView;
 use crate::views::issues::IssuesView;
+use crate::views::log_viewer::{self, LogViewer};
 use crate::views::notifications::NotificationsView;
 use crate::views::prs::PrsView;
 use crate::views::repo::RepoView;
+use crate::views::script_prompt::{self, OpenPrompt};
 
 // ---------------------------------------------------------------------------
 // Navigation target (cross-view deep-link context)
@@ -57,6 +64,58 @@ pub enum NavigationTarget {
     },
 }
 
+impl NavigationTarget {
+    /// The target of a GitHub PR, issue or actions run URL.
+    pub fn from_url(url: &str) -> anyhow::Result<Self> {
+        let parsed = parse_github_url(url)
+            .ok_or_else(|| anyhow::anyhow!("unrecognised GitHub URL: {url}"))?;
+        Ok(match parsed {
+            ParsedGitHubUrl::PullRequest {
+                host,
+                owner,
+                repo,
+                number,
+            } => Self::PullRequest {
+                owner,
+                repo,
+                number,
+                host,
+            },
+            ParsedGitHubUrl::Issue {
+                host,
+                owner,
+                repo,
+                number,
+            } => Self::Issue {
+                owner,
+                repo,
+                number,
+                host,
+            },
+            ParsedGitHubUrl::ActionsRun {
+                host,
+                owner,
+                repo,
+                run_id,
+            } => Self::ActionsRun {
+                owner,
+                repo,
+                run_id,
+                host,
+            },
+        })
+    }
+
+    /// `owner/repo` of the target.
+    fn repo_full_name(&self) -> String {
+        match self {
+            Self::ActionsRun { owner, repo, .. }
+            | Self::PullRequest { owner, repo, .. }
+            | Self::Issue { owner, repo, .. } => format!("{owner}/{repo}"),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // View kind enum (public for status bar)
 // ---------------------------------------------------------------------------
@@ -69,16 +128,18 @@ pub enum ViewKind {
     Alerts,
     Notifications,
     Repo,
+    Events,
 }
 
 impl ViewKind {
-    pub const ALL: [ViewKind; 6] = [
+    pub const ALL: [ViewKind; 7] = [
         ViewKind::Prs,
         ViewKind::Issues,
         ViewKind::Actions,
         ViewKind::Alerts,
         ViewKind::Notifications,
         ViewKind::Repo,
+        ViewKind::Events,
     ];
 
     pub fn label(self) -> &'static str {
@@ -89,6 +150,7 @@ impl ViewKind {
             Self::Alerts => "Alerts",
             Self::Notifications => "Notifs",
             Self::Repo => "Repo",
+            Self::Events => "Events",
         }
     }
 
@@ -100,23 +162,105 @@ impl ViewKind {
             Self::Alerts => format!("{} {}", icons.view_alerts, self.label()),
             Self::Notifications => format!("{} {}", icons.view_notifications, self.label()),
             Self::Repo => format!("{} {}", icons.view_repo, self.label()),
+            Self::Events => format!("{} {}", icons.view_events, self.label()),
         }
     }
 }
 
+/// Changes to watched items not seen yet, per view. Shown next to the view
+/// in every footer and cleared when the view is shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WatchBadges([u32; ViewKind::ALL.len()]);
+
+impl WatchBadges {
+    pub fn get(self, view: ViewKind) -> u32 {
+        self.0[view as usize]
+    }
+
+    pub fn bump(&mut self, view: ViewKind) {
+        self.0[view as usize] = self.0[view as usize].saturating_add(1);
+    }
+
+    pub fn clear(&mut self, view: ViewKind) {
+        self.0[view as usize] = 0;
+    }
+}
+
+/// Counts shown next to each view in every footer: the unread notifications
+/// and the items of the tabs marked `badge`, kept current by the background
+/// refresh. `None` until the view has one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ViewCounts([Option<u32>; ViewKind::ALL.len()]);
+
+impl ViewCounts {
+    pub fn get(self, view: ViewKind) -> Option<u32> {
+        self.0[view as usize]
+    }
+
+    pub fn set(&mut self, view: ViewKind, count: Option<u32>) {
+        self.0[view as usize] = count;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Config hot-reload
+// ---------------------------------------------------------------------------
+
+/// Reloads the config when one of its files changes.
+///
+/// Filters, theme and keybindings apply at once. Settings read once at
+/// startup, by the engine (`[github]`, `hooks`, `bell`, …) or process-wide
+/// (`defaults.timezone`, `browser`, `spell`, …), keep their values until a
+/// restart.
+#[derive(Clone)]
+pub struct ConfigReload {
+    pub watcher: ConfigWatcher,
+    /// `--ascii`, applied over each reloaded config.
+    pub ascii: bool,
+    /// Terminal background the theme is resolved for.
+    pub background: Background,
+}
+
+/// A reloaded config, with the theme and keybindings resolved from it.
+#[derive(Clone)]
+struct Reloaded {
+    config: Arc<AppConfig>,
+    theme: Arc<ResolvedTheme>,
+    keybindings: Arc<MergedBindings>,
+}
+
+impl ConfigReload {
+    /// Load the config again. Blocks on the file system.
+    fn load(&self) -> anyhow::Result<Reloaded> {
+        let mut config = self.watcher.reload()?;
+        if self.ascii {
+            config.theme.ui.ascii = Some(true);
+        }
+        Ok(Reloaded {
+            theme: Arc::new(ResolvedTheme::resolve(&config.theme, self.background)),
+            keybindings: Arc::new(MergedBindings::from_config(&config.keybindings)),
+            config: Arc::new(config),
+        })
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Root App component
 // ---------------------------------------------------------------------------
 
 #[derive(Default, Props)]
 pub struct AppProps<'a> {
-    pub config: Option<&'a AppConfig>,
+    pub config: Option<Arc<AppConfig>>,
+    /// Reloads `config` when it is edited; it stays as started without.
+    pub config_reload: Option<&'a ConfigReload>,
     pub engine: Option<&'a EngineHandle>,
-    pub theme: Option<&'a ResolvedTheme>,
-    pub keybindings: Option<&'a MergedBindings>,
+    pub theme: Option<Arc<ResolvedTheme>>,
+    pub keybindings: Option<Arc<MergedBindings>>,
     pub color_depth: ColorDepth,
     pub repo_path: Option<&'a Path>,
     pub detected_repo: Option<&'a RepoRef>,
+    /// Host of the detected repo, `None` for github.com.
+    pub detected_host: Option<&'a str>,
     pub initial_nav_target: Option<NavigationTarget>,
 }
 
@@ -126,9 +270,51 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
     let mut system = hooks.use_context_mut::<SystemContext>();
     let should_exit = hooks.use_state(|| false);
 
-    let config = props.config;
-    let theme = props.theme;
-    let keybindings = props.keybindings;
+    // The last config reloaded, replacing the props' from then on.
+    let mut reloaded: State<Option<Reloaded>> = hooks.use_state(|| None);
+    let mut reload_tick = hooks.use_state(|| 0u64);
+    let config_reload = props.config_reload.cloned();
+    hooks.use_future(async move {
+        let Some(config_reload) = config_reload else {
+            return;
+        };
+        loop {
+            config_reload.watcher.changed().await;
+            let loader = config_reload.clone();
+            match smol::unblock(move || loader.load()).await {
+                Ok(next) => {
+                    tracing::info!("config reloaded");
+                    crate::config::loader::set_reload_error(None);
+                    reloaded.set(Some(next));
+                }
+                Err(e) => {
+                    tracing::warn!("config reload failed: {e:#}");
+                    // TOML errors go on to quote the offending lines.
+                    let error = format!("{e:#}");
+                    let first_line = error.lines().next().unwrap_or_default();
+                    crate::config::loader::set_reload_error(Some(format!(
+                        "Config not reloaded: {first_line}"
+                    )));
+                }
+            }
+            reload_tick.set(reload_tick.get() + 1);
+        }
+    });
+    let current = reloaded.read().clone();
+    let config_arc = current
+        .as_ref()
+        .map(|r| r.config.clone())
+        .or_else(|| props.config.clone());
+    let theme_arc = current
+        .as_ref()
+        .map(|r| r.theme.clone())
+        .or_else(|| props.theme.clone());
+    let keybindings_arc = current
+        .map(|r| r.keybindings)
+        .or_else(|| props.keybindings.clone());
+    let config = config_arc.as_deref();
+    let theme = theme_arc.as_deref();
+    let keybindings = keybindings_arc.as_deref();
     let depth = props.color_depth;
 
     // View switching state.
@@ -140,6 +326,7 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
             crate::config::types::View::Notifications => ViewKind::Notifications,
             crate::config::types::View::Alerts => ViewKind::Alerts,
             crate::config::types::View::Repo => ViewKind::Repo,
+            crate::config::types::View::Events => ViewKind::Events,
         }
     });
     let mut active_view = hooks.use_state(move || initial_view);
@@ -184,7 +371,8 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
             ViewKind::Actions => ViewKind::Alerts,
             ViewKind::Alerts => ViewKind::Notifications,
             ViewKind::Notifications => ViewKind::Repo,
-            ViewKind::Repo => ViewKind::Prs,
+            ViewKind::Repo => ViewKind::Events,
+            ViewKind::Events => ViewKind::Prs,
         };
         active_view.set(next);
     }
@@ -194,12 +382,13 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
     if switch_back_signal.get() {
         switch_back_signal.set(false);
         let prev = match active_view.get() {
-            ViewKind::Prs => ViewKind::Repo,
+            ViewKind::Prs => ViewKind::Events,
             ViewKind::Issues => ViewKind::Prs,
             ViewKind::Actions => ViewKind::Issues,
             ViewKind::Alerts => ViewKind::Actions,
             ViewKind::Notifications => ViewKind::Alerts,
             ViewKind::Repo => ViewKind::Notifications,
+            ViewKind::Events => ViewKind::Repo,
         };
         active_view.set(prev);
     }
@@ -216,6 +405,53 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
         }
     }
 
+    // Token scope probe: once the granted scopes are known, views and actions
+    // the token cannot use are disabled with a hint instead of 403ing. The
+    // viewer's teams are fetched alongside to recognise team review requests,
+    // and their saved replies for the comment composer's template picker.
+    let mut granted_scopes = hooks.use_state(|| Option::<Vec<String>>::None);
+    let mut viewer_teams = hooks.use_state(Vec::<String>::new);
+    let mut saved_replies = hooks.use_state(Vec::<SavedReply>::new);
+    let scope_channel = hooks.use_state(crate::views::common::new_event_channel);
+    let (scope_tx, scope_rx) = scope_channel.read().clone();
+    let mut scopes_requested = hooks.use_state(|| false);
+    if !scopes_requested.get()
+        && let Some(engine) = props.engine
+    {
+        scopes_requested.set(true);
+        engine.send(Request::FetchTokenScopes {
+            host: None,
+            reply_tx: scope_tx.clone(),
+        });
+        engine.send(Request::FetchViewerTeams {
+            host: None,
+            reply_tx: scope_tx.clone(),
+        });
+        engine.send(Request::FetchSavedReplies {
+            host: None,
+            reply_tx: scope_tx,
+        });
+    }
+    hooks.use_future(async move {
+        while let Ok(event) = scope_rx.recv().await {
+            match event {
+                Event::TokenScopesFetched { scopes } => granted_scopes.set(scopes),
+                Event::ViewerTeamsFetched { teams } => viewer_teams.set(teams),
+                Event::SavedRepliesFetched { replies } => saved_replies.set(replies),
+                _ => {}
+            }
+        }
+    });
+    let (notifications_hint, alerts_hint, workflow_hint) = {
+        let granted = granted_scopes.read();
+        let hint = |feature| scope_hint(granted.as_deref(), feature);
+        (
+            hint(ScopedFeature::Notifications),
+            hint(ScopedFeature::SecurityAlerts),
+            hint(ScopedFeature::WorkflowRuns),
+        )
+    };
+
     // Scope state: repo-scoped vs global.
     // When deep-linking to an external repo (different from the detected local
     // repo), start in global scope so config tabs aren't hidden by scope.
@@ -223,14 +459,10 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
     let scope_config = config.map_or(Scope::Auto, |c| c.github.scope.unwrap_or_default());
     let nav_targets_external = {
         let detected_full = detected_repo.map(RepoRef::full_name);
-        nav_target.read().as_ref().is_some_and(|t| {
-            let target_repo = match t {
-                NavigationTarget::ActionsRun { owner, repo, .. }
-                | NavigationTarget::PullRequest { owner, repo, .. }
-                | NavigationTarget::Issue { owner, repo, .. } => format!("{owner}/{repo}"),
-            };
-            detected_full.as_ref().is_none_or(|d| *d != target_repo)
-        })
+        nav_target
+            .read()
+            .as_ref()
+            .is_some_and(|t| detected_full.is_none_or(|d| d != t.repo_full_name()))
     };
     let initial_scoped = if nav_targets_external {
         false
@@ -261,9 +493,101 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
         scope_toggle_signal.set(false);
         if effective_repo_name.is_some() {
             repo_scoped.set(!repo_scoped.get());
+            crate::smart_scope::clear_offer();
+        }
+    }
+
+    // URLs handed over by `gh-board open` while this instance runs: deep-link
+    // like the CLI does, leaving repo scope when the target is elsewhere.
+    let detected_full = detected_repo.map(RepoRef::full_name);
+    hooks.use_future(async move {
+        while let Some(target) = crate::ipc::next_open().await {
+            let scoped_to = selected_repo
+                .read()
+                .clone()
+                .or_else(|| detected_full.clone());
+            if scoped_to.is_none_or(|repo| repo != target.repo_full_name()) {
+                repo_scoped.set(false);
+            }
+            nav_target.set(Some(target));
+        }
+    });
+
+    // Date toggle signal: flips every timestamp between relative and absolute.
+    let mut date_toggle_signal = hooks.use_state(|| false);
+    if date_toggle_signal.get() {
+        date_toggle_signal.set(false);
+        crate::util::toggle_dates();
+    }
+
+    // Re-render relative dates every minute, without refetching.
+    let mut date_tick = hooks.use_state(|| 0u64);
+    hooks.use_future(async move {
+        loop {
+            smol::Timer::after(crate::util::DATE_TICK_INTERVAL).await;
+            crate::util::tick_dates();
+            date_tick.set(date_tick.get() + 1);
+        }
+    });
+
+    // Rescan signal (`rescan_repo`): take the SmartScope offer, else scope to
+    // the repo worked in right now.
+    let mut rescan_signal = hooks.use_state(|| false);
+    if rescan_signal.get() {
+        rescan_signal.set(false);
+        let target = crate::smart_scope::take_offer()
+            .or_else(|| crate::smart_scope::detect(props.repo_path).map(|r| r.full_name()));
+        if let Some(repo) = target {
+            let is_detected = detected_repo.is_some_and(|d| d.full_name() == repo);
+            selected_repo.set((!is_detected).then_some(repo));
+            repo_scoped.set(true);
         }
     }
 
+    // SmartScope: poll the directory worked in and offer a newly detected
+    // repo, withdrawing the offer once that repo is the scope again.
+    let smart_scope = config.is_some_and(|c| c.github.smart_scope.unwrap_or(false));
+    let rescan_key = keybindings
+        .and_then(|kb| {
+            kb.universal
+                .iter()
+                .find(|b| b.builtin.as_deref() == Some("rescan_repo"))
+        })
+        .map_or_else(|| "ctrl+g".to_owned(), |b| b.key.clone());
+    let mut offer_tick = hooks.use_state(|| 0u64);
+    let detected_name = detected_repo.map(RepoRef::full_name);
+    hooks.use_future(async move {
+        if !smart_scope {
+            return;
+        }
+        let mut last_seen = detected_name.clone();
+        loop {
+            smol::Timer::after(crate::smart_scope::POLL_INTERVAL).await;
+            let found = smol::unblock(|| crate::smart_scope::detect(None))
+                .await
+                .map(|r| r.full_name());
+            if found.is_none() || found == last_seen {
+                continue;
+            }
+            last_seen.clone_from(&found);
+            let scoped = if repo_scoped.get() {
+                selected_repo
+                    .read()
+                    .clone()
+                    .or_else(|| detected_name.clone())
+            } else {
+                None
+            };
+            match found {
+                Some(repo) if scoped.as_ref() != Some(&repo) => {
+                    crate::smart_scope::offer(repo, rescan_key.clone());
+                }
+                _ => crate::smart_scope::clear_offer(),
+            }
+            offer_tick.set(offer_tick.get() + 1);
+        }
+    });
+
     // Effective scope repo string to pass to views.
     let scope_repo: Option<String> = if repo_scoped.get() {
         effective_repo_name.clone()
@@ -344,6 +668,7 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                             }
                             // Activate scope so the selection takes effect immediately.
                             repo_scoped.set(true);
+                            crate::smart_scope::clear_offer();
                         }
                         picker_filter.set(String::new());
                         picker_visible.set(false);
@@ -377,33 +702,109 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
         }
     });
 
+    // Script prompts (`confirm`, `pick`, `input`), asked over every view, and
+    // footer redraws when a script's status changes.
+    let mut script_prompt = hooks.use_state(|| Option::<OpenPrompt>::None);
+    let mut script_redraw = hooks.use_state(|| 0u64);
+    hooks.use_future(async move {
+        while let Some(message) = crate::script::next_message().await {
+            match message {
+                ScriptMessage::Prompt(prompt) => script_prompt.set(Some(OpenPrompt::new(prompt))),
+                ScriptMessage::Redraw => script_redraw.set(script_redraw.get() + 1),
+            }
+        }
+    });
+    hooks.use_terminal_events(move |event| {
+        if let TerminalEvent::Key(KeyEvent { code, kind, .. }) = event
+            && kind != KeyEventKind::Release
+            && script_prompt.read().is_some()
+        {
+            script_prompt::handle_key(&mut script_prompt, code);
+        }
+    });
+
+    // Log viewer: child views set the signal on `show_logs`.
+    let mut log_viewer = hooks.use_state(|| Option::<LogViewer>::None);
+    let mut log_viewer_signal = hooks.use_state(|| false);
+    if log_viewer_signal.get() {
+        log_viewer_signal.set(false);
+        log_viewer.set(Some(LogViewer::new()));
+    }
+    hooks.use_terminal_events(move |event| {
+        if let TerminalEvent::Key(KeyEvent { code, kind, .. }) = event
+            && kind != KeyEventKind::Release
+            && log_viewer.read().is_some()
+            && script_prompt.read().is_none()
+        {
+            log_viewer::handle_key(&mut log_viewer, code);
+        }
+    });
+    let overlay_open =
+        picker_visible.get() || script_prompt.read().is_some() || log_viewer.read().is_some();
+
     // Exit handling.
     if should_exit.get() {
         system.exit();
     }
 
     // Per-pool rate-limit state — GraphQL and REST have separate GitHub quotas.
-    let graphql_rate_limit: State<Option<RateLimitInfo>> = hooks.use_state(|| None);
-    let rest_rate_limit: State<Option<RateLimitInfo>> = hooks.use_state(|| None);
+    let graphql_rate_limit: State<RateLimits> = hooks.use_state(RateLimits::default);
+    let rest_rate_limit: State<RateLimits> = hooks.use_state(RateLimits::default);
+
+    // Re-render periodically so the tab bars' refresh countdowns advance.
+    let mut countdown_tick = hooks.use_state(|| 0u64);
+    hooks.use_future(async move {
+        loop {
+            smol::Timer::after(std::time::Duration::from_secs(30)).await;
+            countdown_tick.set(countdown_tick.get() + 1);
+        }
+    });
+
+    // Watched-item changes, bumped by the views that own the watches.
+    let mut watch_badges = hooks.use_state(WatchBadges::default);
+    // Footer counts, set by the views with badge tabs.
+    let view_counts = hooks.use_state(ViewCounts::default);
 
     let show_count = config.is_none_or(|c| c.theme.ui.filters_show_count.unwrap_or(true));
     let show_separator = config.is_none_or(|c| c.theme.ui.table.show_separator.unwrap_or(true));
     let default_preview_pct = config.map_or(0.45, |c| c.defaults.preview.width.unwrap_or(0.45));
     let preview_width_pct: State<f64> = hooks.use_state(move || default_preview_pct);
-    let repo_paths = config.map(|c| &c.repo_paths);
-    let date_format = config.map(|c| c.defaults.date_format.as_deref().unwrap_or("relative"));
 
     // All filters/paths needed simultaneously (views are always in the tree).
     let active = active_view.get();
+    if watch_badges.get().get(active) > 0 {
+        let mut badges = watch_badges.get();
+        badges.clear(active);
+        watch_badges.set(badges);
+    }
     let refetch_minutes = config.map_or(10, |c| c.github.refetch_interval_minutes.unwrap_or(10));
     let prefetch_pr_details = config.map_or(0, |c| c.github.prefetch_pr_details.unwrap_or(0));
     let auto_clone = config.is_some_and(|c| c.github.auto_clone.unwrap_or(false));
+    let sync_viewed_files = config.is_some_and(|c| c.github.sync_viewed_files.unwrap_or(false));
+    let merge_method = config
+        .and_then(|c| c.github.merge_method)
+        .unwrap_or_default();
+    let detail_cache_size =
+        config.map_or(100, |c| c.defaults.detail_cache_size.unwrap_or(100)) as usize;
+    let wip_patterns: Vec<String> = config
+        .and_then(|c| c.defaults.wip_patterns.clone())
+        .unwrap_or_else(|| DEFAULT_WIP_PATTERNS.iter().map(|&p| p.to_owned()).collect());
+    // Local templates first, then the GitHub saved replies.
+    let reply_templates: Vec<SavedReply> = config
+        .map(|c| c.reply_templates.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .map(|t| SavedReply {
+            title: t.name.clone(),
+            body: t.body.clone(),
+        })
+        .chain(saved_replies.read().iter().cloned())
+        .collect();
     let group_matrix_jobs = config.is_some_and(|c| c.actions.group_matrix_jobs.unwrap_or(false));
-    let filters_pr = config.map(|c| c.pr_filters.as_slice());
-    let filters_issue = config.map(|c| c.issues_filters.as_slice());
-    let filters_actions = config.map(|c| c.actions_filters.as_slice());
-    let filters_notif = config.map(|c| c.notifications_filters.as_slice());
-    let filters_alerts = config.map(|c| c.alerts_filters.as_slice());
+    let failed_step_log_lines =
+        config.map_or(30, |c| c.actions.failed_step_log_lines.unwrap_or(30));
+    let cleanup_older_than_days =
+        config.map_or(90, |c| c.actions.cleanup_older_than_days.unwrap_or(90));
     let repo_path = props.repo_path;
 
     // Build repo picker overlay when visible.
@@ -432,6 +833,7 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                 cursor: picker_cursor.get(),
                 show_filter: true,
                 filter_text: filter_buf,
+                hint: None,
                 depth,
                 title_color: Some(theme_ref.text_primary),
                 item_color: Some(theme_ref.text_secondary),
@@ -442,11 +844,15 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                 filter_prompt_color: Some(theme_ref.text_faint),
                 filter_text_color: Some(theme_ref.text_primary),
                 cursor_marker: theme_ref.icons.select_cursor.clone(),
+                chrome: theme_ref.chrome,
             },
         ))
     } else {
         None
     };
+    let rendered_script_prompt =
+        theme.and_then(|theme| script_prompt::render(&script_prompt, theme, depth));
+    let rendered_log_viewer = theme.and_then(|theme| log_viewer::render(&log_viewer, theme, depth));
 
     element! {
         View(width: u32::from(width), height: u32::from(height), flex_direction: FlexDirection::Column) {
@@ -455,10 +861,10 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                 flex_grow: 1.0_f32,
             ) {
                 PrsView(
-                    filters: filters_pr,
+                    config: config_arc.clone(),
                     engine: props.engine,
-                    theme,
-                    keybindings,
+                    theme: theme_arc.clone(),
+                    keybindings: keybindings_arc.clone(),
                     color_depth: depth,
                     width,
                     height,
@@ -471,17 +877,26 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                     switch_view_back: switch_back_signal,
                     goto_view: goto_view_signal,
                     scope_toggle: scope_toggle_signal,
+                    date_toggle: date_toggle_signal,
+                    show_logs: log_viewer_signal,
                     repo_picker: picker_signal,
+                    rescan_repo: rescan_signal,
                     scope_repo: scope_repo.clone(),
-                    repo_paths,
-                    date_format,
-                    is_active: active == ViewKind::Prs && !picker_visible.get(),
+                    is_active: active == ViewKind::Prs && !overlay_open,
                     refetch_interval_minutes: refetch_minutes,
                     prefetch_pr_details,
                     auto_clone,
+                    detail_cache_size,
                     nav_target,
                     go_back: go_back_signal,
                     rate_limit: graphql_rate_limit,
+                    watch_badges,
+                    view_counts,
+                    viewer_teams: viewer_teams.read().clone(),
+                    wip_patterns: wip_patterns.clone(),
+                    reply_templates: reply_templates.clone(),
+                    sync_viewed_files,
+                    merge_method,
                 )
             }
             View(
@@ -489,10 +904,10 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                 flex_grow: 1.0_f32,
             ) {
                 IssuesView(
-                    filters: filters_issue,
+                    config: config_arc.clone(),
                     engine: props.engine,
-                    theme,
-                    keybindings,
+                    theme: theme_arc.clone(),
+                    keybindings: keybindings_arc.clone(),
                     color_depth: depth,
                     width,
                     height,
@@ -505,14 +920,20 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                     switch_view_back: switch_back_signal,
                     goto_view: goto_view_signal,
                     scope_toggle: scope_toggle_signal,
+                    date_toggle: date_toggle_signal,
+                    show_logs: log_viewer_signal,
                     repo_picker: picker_signal,
+                    rescan_repo: rescan_signal,
                     scope_repo: scope_repo.clone(),
-                    date_format,
-                    is_active: active == ViewKind::Issues && !picker_visible.get(),
+                    is_active: active == ViewKind::Issues && !overlay_open,
                     refetch_interval_minutes: refetch_minutes,
+                    detail_cache_size,
                     nav_target,
                     go_back: go_back_signal,
                     rate_limit: graphql_rate_limit,
+                    watch_badges,
+                    view_counts,
+                    reply_templates,
                 )
             }
             View(
@@ -520,10 +941,11 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                 flex_grow: 1.0_f32,
             ) {
                 ActionsView(
-                    filters: filters_actions,
+                    config: config_arc.clone(),
                     engine: props.engine,
-                    theme,
-                    keybindings,
+                    scope_hint: workflow_hint.clone(),
+                    theme: theme_arc.clone(),
+                    keybindings: keybindings_arc.clone(),
                     color_depth: depth,
                     width,
                     height,
@@ -538,13 +960,21 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                     switch_view_back: switch_back_signal,
                     goto_view: goto_view_signal,
                     scope_toggle: scope_toggle_signal,
+                    date_toggle: date_toggle_signal,
+                    show_logs: log_viewer_signal,
                     repo_picker: picker_signal,
-                    is_active: active == ViewKind::Actions && !picker_visible.get(),
+                    rescan_repo: rescan_signal,
+                    is_active: active == ViewKind::Actions && !overlay_open,
                     refetch_interval_minutes: refetch_minutes,
                     nav_target,
                     go_back: go_back_signal,
                     rate_limit: rest_rate_limit,
+                    watch_badges,
+                    view_counts,
                     group_matrix_jobs,
+                    failed_step_log_lines,
+                    cleanup_older_than_days,
+                    detail_cache_size,
                 )
             }
             View(
@@ -552,10 +982,11 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                 flex_grow: 1.0_f32,
             ) {
                 AlertsView(
-                    filters: filters_alerts,
+                    config: config_arc.clone(),
                     engine: props.engine,
-                    theme,
-                    keybindings,
+                    scope_hint: alerts_hint.clone(),
+                    theme: theme_arc.clone(),
+                    keybindings: keybindings_arc.clone(),
                     color_depth: depth,
                     width,
                     height,
@@ -570,11 +1001,15 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                     switch_view_back: switch_back_signal,
                     goto_view: goto_view_signal,
                     scope_toggle: scope_toggle_signal,
+                    date_toggle: date_toggle_signal,
+                    show_logs: log_viewer_signal,
                     repo_picker: picker_signal,
-                    is_active: active == ViewKind::Alerts && !picker_visible.get(),
+                    rescan_repo: rescan_signal,
+                    is_active: active == ViewKind::Alerts && !overlay_open,
                     refetch_interval_minutes: refetch_minutes,
-                    date_format,
                     rate_limit: rest_rate_limit,
+                    watch_badges,
+                    view_counts,
                 )
             }
             View(
@@ -582,10 +1017,11 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                 flex_grow: 1.0_f32,
             ) {
                 NotificationsView(
-                    filters: filters_notif,
+                    config: config_arc.clone(),
                     engine: props.engine,
-                    theme,
-                    keybindings,
+                    scope_hint: notifications_hint.clone(),
+                    theme: theme_arc.clone(),
+                    keybindings: keybindings_arc.clone(),
                     color_depth: depth,
                     width,
                     height,
@@ -596,12 +1032,16 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                     switch_view_back: switch_back_signal,
                     goto_view: goto_view_signal,
                     scope_toggle: scope_toggle_signal,
+                    date_toggle: date_toggle_signal,
+                    show_logs: log_viewer_signal,
                     repo_picker: picker_signal,
+                    rescan_repo: rescan_signal,
                     scope_repo: scope_repo.clone(),
-                    date_format,
-                    is_active: active == ViewKind::Notifications && !picker_visible.get(),
+                    is_active: active == ViewKind::Notifications && !overlay_open,
                     refetch_interval_minutes: refetch_minutes,
                     rate_limit: rest_rate_limit,
+                    watch_badges,
+                    view_counts,
                 )
             }
             View(
@@ -609,8 +1049,9 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                 flex_grow: 1.0_f32,
             ) {
                 RepoView(
-                    theme,
-                    keybindings,
+                    config: config_arc.clone(),
+                    theme: theme_arc.clone(),
+                    keybindings: keybindings_arc.clone(),
                     color_depth: depth,
                     width,
                     height,
@@ -622,20 +1063,54 @@ pub fn App<'a>(props: &AppProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'
                     switch_view_back: switch_back_signal,
                     goto_view: goto_view_signal,
                     scope_toggle: scope_toggle_signal,
+                    date_toggle: date_toggle_signal,
+                    show_logs: log_viewer_signal,
                     repo_picker: picker_signal,
+                    rescan_repo: rescan_signal,
                     scope_repo: scope_repo.clone(),
                     repo_path,
                     detected_repo,
-                    repo_paths,
+                    detected_host: props.detected_host,
                     engine: props.engine,
                     nav_target,
-                    date_format,
-                    is_active: active == ViewKind::Repo && !picker_visible.get(),
+                    is_active: active == ViewKind::Repo && !overlay_open,
                     refetch_interval_minutes: refetch_minutes,
                     rate_limit: graphql_rate_limit,
+                    watch_badges,
+                    view_counts,
+                )
+            }
+            View(
+                display: if active == ViewKind::Events { Display::Flex } else { Display::None },
+                flex_grow: 1.0_f32,
+            ) {
+                EventsView(
+                    config: config_arc.clone(),
+                    engine: props.engine,
+                    theme: theme_arc.clone(),
+                    keybindings: keybindings_arc.clone(),
+                    color_depth: depth,
+                    width,
+                    height,
+                    show_filter_count: show_count,
+                    show_separator,
+                    should_exit,
+                    switch_view: switch_signal,
+                    switch_view_back: switch_back_signal,
+                    goto_view: goto_view_signal,
+                    date_toggle: date_toggle_signal,
+                    show_logs: log_viewer_signal,
+                    is_active: active == ViewKind::Events && !overlay_open,
+                    refetch_interval_minutes: refetch_minutes,
+                    rate_limit: rest_rate_limit,
+                    watch_badges,
+                    view_counts,
+                    nav_target,
                 )
             }
             SelectionOverlay(overlay: rendered_repo_picker, width, height)
+            SelectionOverlay(overlay: rendered_log_viewer, width, height)
+            SelectionOverlay(overlay: rendered_script_prompt, width, height)
         }
     }
 }
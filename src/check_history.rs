@@ -0,0 +1,242 @@
+//! Check-run outcomes remembered across sessions, to spot flaky checks.
+//!
+//! Every PR list or refresh records the completed checks of each PR's head
+//! commit. A check that both passed and failed on one commit (a re-run
+//! changed its mind), or keeps flipping between the two on one branch, is
+//! flagged as likely flaky in the Checks tab. The history lives in
+//! `$XDG_STATE_HOME/gh-board/check-history.json`.
+
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{CheckConclusion, CheckRun, CheckStatus, PullRequest};
+
+/// Outcomes kept per check and branch.
+const OUTCOMES_KEPT: usize = 20;
+/// Histories kept overall; the least recently updated go first.
+const HISTORIES_KEPT: usize = 2000;
+/// Pass/fail flips within [`OUTCOMES_KEPT`] that make a branch's check flaky.
+const FLIPS_FLAKY: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Outcome {
+    sha: String,
+    passed: bool,
+    at: DateTime<Utc>,
+}
+
+/// Outcomes of one check on one branch, oldest first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct History {
+    /// `owner/repo`.
+    repo: String,
+    branch: String,
+    check: String,
+    outcomes: Vec<Outcome>,
+}
+
+/// A check of the selected PR that looks flaky.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FlakyCheck {
+    pub name: String,
+    /// Whether the check currently fails on the head commit.
+    pub failing: bool,
+    /// Actions job to re-run; `None` for external checks.
+    pub job_id: Option<u64>,
+    /// Why it looks flaky, e.g. "passed and failed on 1a2b3c4".
+    pub reason: String,
+}
+
+fn history_path() -> Option<PathBuf> {
+    crate::util::state_path("check-history.json")
+}
+
+static HISTORY: LazyLock<Mutex<Vec<History>>> = LazyLock::new(|| {
+    let history = history_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|text| match serde_json::from_str(&text) {
+            Ok(history) => Some(history),
+            Err(e) => {
+                tracing::warn!("ignoring unreadable check history: {e}");
+                None
+            }
+        })
+        .unwrap_or_default();
+    Mutex::new(history)
+});
+
+fn with_history<T>(f: impl FnOnce(&mut Vec<History>) -> T) -> T {
+    f(&mut HISTORY.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+fn persist(history: &[History]) {
+    let Some(path) = history_path() else {
+        return;
+    };
+    let result = serde_json::to_string(history)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| crate::util::write_atomic(&path, &json));
+    if let Err(e) = result {
+        tracing::warn!("saving check history to {}: {e}", path.display());
+    }
+}
+
+/// Pass or fail of a completed check; `None` while running or when the
+/// conclusion says nothing about the code (skipped, cancelled, …).
+fn passed(check: &CheckRun) -> Option<bool> {
+    if check.status != Some(CheckStatus::Completed) {
+        return None;
+    }
+    match check.conclusion? {
+        CheckConclusion::Success => Some(true),
+        CheckConclusion::Failure | CheckConclusion::TimedOut => Some(false),
+        _ => None,
+    }
+}
+
+/// Record the head-commit checks of `prs`, saving when anything is new.
+pub(crate) fn record(prs: &[PullRequest]) {
+    let now = Utc::now();
+    with_history(|history| {
+        let mut changed = false;
+        for pr in prs {
+            changed |= record_pr(history, pr, now);
+        }
+        if changed {
+            history.sort_by_key(|h| std::cmp::Reverse(h.outcomes.last().map(|o| o.at)));
+            history.truncate(HISTORIES_KEPT);
+            persist(history);
+        }
+    });
+}
+
+fn record_pr(history: &mut Vec<History>, pr: &PullRequest, now: DateTime<Utc>) -> bool {
+    let Some(repo) = pr.repo.as_ref().map(crate::types::RepoRef::full_name) else {
+        return false;
+    };
+    if pr.head_sha.is_empty() {
+        return false;
+    }
+    let mut changed = false;
+    for check in &pr.check_runs {
+        let Some(passed) = passed(check) else {
+            continue;
+        };
+        let entry = history
+            .iter()
+            .position(|h| h.repo == repo && h.branch == pr.head_ref && h.check == check.name);
+        let entry = entry.unwrap_or_else(|| {
+            history.push(History {
+                repo: repo.clone(),
+                branch: pr.head_ref.clone(),
+                check: check.name.clone(),
+                outcomes: Vec::new(),
+            });
+            history.len() - 1
+        });
+        let outcomes = &mut history[entry].outcomes;
+        if outcomes
+            .last()
+            .is_some_and(|o| o.sha == pr.head_sha && o.passed == passed)
+        {
+            continue;
+        }
+        outcomes.push(Outcome {
+            sha: pr.head_sha.clone(),
+            passed,
+            at: now,
+        });
+        if outcomes.len() > OUTCOMES_KEPT {
+            outcomes.remove(0);
+        }
+        changed = true;
+    }
+    changed
+}
+
+/// Why `outcomes` look flaky, if they do.
+fn flakiness(outcomes: &[Outcome], branch: &str) -> Option<String> {
+    let mixed = outcomes.iter().find(|o| {
+        !o.passed
+            && outcomes
+                .iter()
+                .any(|other| other.sha == o.sha && other.passed)
+    });
+    if let Some(o) = mixed {
+        let short: String = o.sha.chars().take(7).collect();
+        return Some(format!("passed and failed on {short}"));
+    }
+    let flips = outcomes
+        .windows(2)
+        .filter(|w| w[0].passed != w[1].passed)
+        .count();
+    (flips >= FLIPS_FLAKY).then(|| format!("{flips} pass/fail flips on {branch}"))
+}
+
+/// Checks of `pr` whose history looks flaky, in check order.
+pub(crate) fn flaky_checks(pr: &PullRequest) -> Vec<FlakyCheck> {
+    let Some(repo) = pr.repo.as_ref().map(crate::types::RepoRef::full_name) else {
+        return Vec::new();
+    };
+    with_history(|history| {
+        pr.check_runs
+            .iter()
+            .filter_map(|check| {
+                let h = history
+                    .iter()
+                    .find(|h| h.repo == repo && h.branch == pr.head_ref && h.check == check.name)?;
+                Some(FlakyCheck {
+                    name: check.name.clone(),
+                    failing: passed(check) == Some(false),
+                    job_id: check.job_id,
+                    reason: flakiness(&h.outcomes, &pr.head_ref)?,
+                })
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(sha: &str, passed: bool) -> Outcome {
+        Outcome {
+            sha: sha.into(),
+            passed,
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn flaky_when_one_commit_both_passed_and_failed() {
+        let outcomes = [
+            outcome("aaaaaaaaa", false),
+            outcome("bbbbbbbbb", false),
+            outcome("bbbbbbbbb", true),
+        ];
+        assert_eq!(
+            flakiness(&outcomes, "main").as_deref(),
+            Some("passed and failed on bbbbbbb")
+        );
+    }
+
+    #[test]
+    fn flaky_when_branch_keeps_flipping() {
+        let fixed = [outcome("a", false), outcome("b", true), outcome("c", true)];
+        assert_eq!(flakiness(&fixed, "main"), None);
+        let flipping = [
+            outcome("a", true),
+            outcome("b", false),
+            outcome("c", true),
+            outcome("d", false),
+        ];
+        assert_eq!(
+            flakiness(&flipping, "main").as_deref(),
+            Some("3 pass/fail flips on main")
+        );
+    }
+}
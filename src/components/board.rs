@@ -0,0 +1,142 @@
+use iocraft::prelude::*;
+
+use crate::color::{Color as AppColor, ColorDepth};
+use crate::theme::Chrome;
+
+// ---------------------------------------------------------------------------
+// Kanban board
+// ---------------------------------------------------------------------------
+
+/// One board column: a header and its cards.
+pub struct BoardColumnInput {
+    pub title: String,
+    pub cards: Vec<String>,
+}
+
+pub struct RenderedBoardColumn {
+    pub title: String,
+    /// The cards in view.
+    pub cards: Vec<String>,
+    /// Index into `cards` of the selected card.
+    pub selected: Option<usize>,
+    pub focused: bool,
+}
+
+/// Pre-rendered board data (owned, 'static-safe).
+pub struct RenderedBoard {
+    pub columns: Vec<RenderedBoardColumn>,
+    pub title_fg: Color,
+    pub card_fg: Color,
+    pub selected_bg: Color,
+    pub border_fg: Color,
+    pub focused_border_fg: Color,
+    pub chrome: Chrome,
+}
+
+/// Configuration for building a board.
+pub struct BoardBuildConfig {
+    pub columns: Vec<BoardColumnInput>,
+    /// Selected `(column, card)`, if any.
+    pub selected: Option<(usize, usize)>,
+    /// Cards that fit in a column.
+    pub visible_cards: usize,
+    pub depth: ColorDepth,
+    pub title_color: Option<AppColor>,
+    pub card_color: Option<AppColor>,
+    pub selected_bg: Option<AppColor>,
+    pub border_color: Option<AppColor>,
+    pub focused_border_color: Option<AppColor>,
+    pub chrome: Chrome,
+}
+
+impl RenderedBoard {
+    pub fn build(cfg: BoardBuildConfig) -> Self {
+        let color =
+            |c: Option<AppColor>, fallback| c.map_or(fallback, |c| c.to_crossterm_color(cfg.depth));
+        let visible = cfg.visible_cards.max(1);
+        let columns = cfg
+            .columns
+            .into_iter()
+            .enumerate()
+            .map(|(ci, col)| {
+                let selected = cfg.selected.filter(|&(c, _)| c == ci).map(|(_, r)| r);
+                // Scroll just enough to keep the selected card in view.
+                let offset = selected.map_or(0, |r| (r + 1).saturating_sub(visible));
+                RenderedBoardColumn {
+                    title: format!("{} ({})", col.title, col.cards.len()),
+                    cards: col.cards.into_iter().skip(offset).take(visible).collect(),
+                    selected: selected.map(|r| r - offset),
+                    focused: cfg.selected.is_some_and(|(c, _)| c == ci),
+                }
+            })
+            .collect();
+        Self {
+            columns,
+            title_fg: color(cfg.title_color, Color::White),
+            card_fg: color(cfg.card_color, Color::Grey),
+            selected_bg: color(cfg.selected_bg, Color::DarkBlue),
+            border_fg: color(cfg.border_color, Color::DarkGrey),
+            focused_border_fg: color(cfg.focused_border_color, Color::White),
+            chrome: cfg.chrome,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Board component
+// ---------------------------------------------------------------------------
+
+#[derive(Default, Props)]
+pub struct BoardProps {
+    pub board: Option<RenderedBoard>,
+}
+
+#[component]
+pub fn Board(props: &mut BoardProps) -> impl Into<AnyElement<'static>> {
+    let Some(board) = props.board.take() else {
+        return element! { View }.into_any();
+    };
+    let border = super::border(board.chrome, BorderStyle::Round);
+
+    element! {
+        View(flex_grow: 1.0_f32, flex_direction: FlexDirection::Row, overflow: Overflow::Hidden) {
+            #(board.columns.into_iter().enumerate().map(|(ci, col)| {
+                let border_color = if col.focused {
+                    board.focused_border_fg
+                } else {
+                    board.border_fg
+                };
+                element! {
+                    View(
+                        key: ci,
+                        flex_grow: 1.0_f32,
+                        flex_basis: FlexBasis::Length(0),
+                        flex_direction: FlexDirection::Column,
+                        border_style: border,
+                        border_color,
+                        padding_left: 1,
+                        padding_right: 1,
+                        overflow: Overflow::Hidden,
+                    ) {
+                        Text(
+                            content: col.title,
+                            color: board.title_fg,
+                            weight: Weight::Bold,
+                            wrap: TextWrap::NoWrap,
+                        )
+                        #(col.cards.into_iter().enumerate().map(|(ri, card)| {
+                            let background_color = (col.selected == Some(ri))
+                                .then_some(board.selected_bg);
+                            element! {
+                                View(key: ri, background_color) {
+                                    Text(content: card, color: board.card_fg, wrap: TextWrap::NoWrap)
+                                }
+                            }
+                        }))
+                    }
+                }
+            }))
+        }
+    }
+    .into_any()
+}
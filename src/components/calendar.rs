@@ -0,0 +1,356 @@
+use std::ops::Range;
+
+use chrono::{Datelike, NaiveDate};
+use iocraft::prelude::*;
+
+use crate::color::{Color as AppColor, ColorDepth};
+use crate::theme::Chrome;
+use crate::types::ContributionCalendar;
+
+// ---------------------------------------------------------------------------
+// Contribution calendar heatmap overlay
+// ---------------------------------------------------------------------------
+
+/// Heatmap glyphs per contribution level, lightest first.
+const UNICODE_LEVELS: [char; 5] = ['\u{b7}', '\u{2591}', '\u{2592}', '\u{2593}', '\u{2588}']; // ·░▒▓█
+const ASCII_LEVELS: [char; 5] = ['.', '-', '+', '*', '#'];
+
+/// Columns taken by one week: the glyph and a gap.
+const WEEK_WIDTH: usize = 2;
+/// Columns taken by the weekday labels.
+const LABEL_WIDTH: usize = 4;
+/// Rows of the overlay: borders, title, months, 7 days, marker, detail, hint.
+const OVERLAY_HEIGHT: u32 = 16;
+
+/// A run of same-colored heatmap cells.
+pub struct CalendarSpan {
+    pub text: String,
+    pub fg: Color,
+}
+
+/// Pre-rendered calendar overlay data (owned, 'static-safe).
+pub struct RenderedCalendar {
+    pub title: String,
+    /// Right side of the title row: the yearly total, or loading / error.
+    pub summary: String,
+    pub months: String,
+    /// One line of spans per weekday, Sunday first, labels included.
+    pub days: Vec<Vec<CalendarSpan>>,
+    /// Marker line pointing at the selected week.
+    pub marker: String,
+    /// Per-day counts of the selected week.
+    pub detail: String,
+    pub hint: String,
+    pub title_fg: Color,
+    pub text_fg: Color,
+    pub faint_fg: Color,
+    pub border_fg: Color,
+    pub chrome: Chrome,
+}
+
+/// Configuration for building a calendar overlay.
+pub struct CalendarBuildConfig<'a> {
+    /// `None` while loading or after an error.
+    pub calendar: Option<&'a ContributionCalendar>,
+    /// Shown until the calendar names the user; `None` is the viewer.
+    pub login: Option<&'a str>,
+    pub error: Option<&'a str>,
+    /// Selected week index into `calendar.weeks`.
+    pub week: usize,
+    /// Terminal width, which bounds how many weeks fit.
+    pub width: u16,
+    pub depth: ColorDepth,
+    pub title_color: Option<AppColor>,
+    pub text_color: Option<AppColor>,
+    pub faint_color: Option<AppColor>,
+    /// Color of the days with contributions.
+    pub active_color: Option<AppColor>,
+    pub border_color: Option<AppColor>,
+    pub chrome: Chrome,
+}
+
+impl RenderedCalendar {
+    /// Build the overlay for the calendar (or its loading state).
+    pub fn build(cfg: &CalendarBuildConfig<'_>) -> Self {
+        let color =
+            |c: Option<AppColor>, fallback| c.map_or(fallback, |c| c.to_crossterm_color(cfg.depth));
+        let title_fg = color(cfg.title_color, Color::White);
+        let text_fg = color(cfg.text_color, Color::Grey);
+        let faint_fg = color(cfg.faint_color, Color::DarkGrey);
+        let active_fg = color(cfg.active_color, Color::Green);
+        let border_fg = color(cfg.border_color, Color::DarkGrey);
+
+        let login = cfg
+            .calendar
+            .map(|c| c.login.as_str())
+            .or(cfg.login)
+            .filter(|l| !l.is_empty());
+        let title = login.map_or_else(|| "Your contributions".to_owned(), |l| format!("@{l}"));
+        let hint = "h/l week  g/G first/last  m yours  Esc close".to_owned();
+
+        let Some(calendar) = cfg.calendar else {
+            let summary = cfg.error.map_or_else(
+                || format!("Loading{}", super::ellipsis(cfg.chrome)),
+                |e| format!("Failed to load: {e}"),
+            );
+            return Self {
+                title,
+                summary,
+                months: String::new(),
+                days: Vec::new(),
+                marker: String::new(),
+                detail: String::new(),
+                hint,
+                title_fg,
+                text_fg,
+                faint_fg,
+                border_fg,
+                chrome: cfg.chrome,
+            };
+        };
+
+        let glyphs = if cfg.chrome.ascii_only() {
+            ASCII_LEVELS
+        } else {
+            UNICODE_LEVELS
+        };
+        let inner = (overlay_width(u32::from(cfg.width)) as usize).saturating_sub(4);
+        let visible = (inner.saturating_sub(LABEL_WIDTH) / WEEK_WIDTH).max(1);
+        let window = week_window(calendar.weeks.len(), visible, cfg.week);
+        let weeks = &calendar.weeks[window.clone()];
+
+        let days = (0..7)
+            .map(|weekday| {
+                let label = match weekday {
+                    1 => "Mon",
+                    3 => "Wed",
+                    5 => "Fri",
+                    _ => "",
+                };
+                let mut spans = vec![CalendarSpan {
+                    text: format!("{label:<LABEL_WIDTH$}"),
+                    fg: faint_fg,
+                }];
+                for week in weeks {
+                    let day = week
+                        .iter()
+                        .find(|d| d.date.weekday().num_days_from_sunday() == weekday);
+                    let (cell, fg) = match day {
+                        Some(d) => (
+                            format!("{} ", glyphs[usize::from(d.level.min(4))]),
+                            if d.level == 0 { faint_fg } else { active_fg },
+                        ),
+                        None => ("  ".to_owned(), faint_fg),
+                    };
+                    match spans.last_mut() {
+                        Some(last) if last.fg == fg => last.text.push_str(&cell),
+                        _ => spans.push(CalendarSpan { text: cell, fg }),
+                    }
+                }
+                spans
+            })
+            .collect();
+
+        let selected = cfg.week.min(calendar.weeks.len().saturating_sub(1));
+        let marker = format!(
+            "{}^",
+            " ".repeat(LABEL_WIDTH + (selected - window.start) * WEEK_WIDTH)
+        );
+
+        Self {
+            title,
+            summary: format!("{} contributions in the last year", calendar.total),
+            months: format!(
+                "{}{}",
+                " ".repeat(LABEL_WIDTH),
+                month_labels(weeks.iter().filter_map(|w| w.first().map(|d| d.date)))
+            ),
+            days,
+            marker,
+            detail: week_detail(calendar, selected),
+            hint,
+            title_fg,
+            text_fg,
+            faint_fg,
+            border_fg,
+            chrome: cfg.chrome,
+        }
+    }
+}
+
+/// Overlay width for a terminal `width` columns wide.
+fn overlay_width(width: u32) -> u32 {
+    (width * 4 / 5).max(40).min(width.saturating_sub(4))
+}
+
+/// The `visible` weeks shown out of `total`, scrolled so `selected` is in
+/// view and the newest weeks are shown by default.
+fn week_window(total: usize, visible: usize, selected: usize) -> Range<usize> {
+    if total == 0 {
+        return 0..0;
+    }
+    let visible = visible.min(total);
+    let selected = selected.min(total - 1);
+    let start = (selected + 1).saturating_sub(visible);
+    start..start + visible
+}
+
+/// Month names above the first week of each month, one week per
+/// [`WEEK_WIDTH`] columns. A label that would overlap the previous one is
+/// dropped.
+fn month_labels(week_starts: impl Iterator<Item = NaiveDate>) -> String {
+    let mut line = String::new();
+    let mut prev_month = None;
+    for (i, date) in week_starts.enumerate() {
+        let col = i * WEEK_WIDTH;
+        if prev_month != Some(date.month()) {
+            prev_month = Some(date.month());
+            // Keep a space between labels.
+            if col >= line.len() + usize::from(!line.is_empty()) {
+                line.push_str(&" ".repeat(col - line.len()));
+                line.push_str(&date.format("%b").to_string());
+            }
+        }
+    }
+    line
+}
+
+/// "Week of <date>: <n> contributions (Sun 0, Mon 3, ...)".
+fn week_detail(calendar: &ContributionCalendar, week: usize) -> String {
+    let Some(days) = calendar.weeks.get(week).filter(|d| !d.is_empty()) else {
+        return String::new();
+    };
+    let per_day: Vec<String> = days
+        .iter()
+        .map(|d| format!("{} {}", d.date.format("%a"), d.count))
+        .collect();
+    format!(
+        "Week of {}: {} contributions ({})",
+        days[0].date,
+        calendar.week_total(week),
+        per_day.join(", ")
+    )
+}
+
+// ---------------------------------------------------------------------------
+// CalendarOverlay component
+// ---------------------------------------------------------------------------
+
+#[derive(Default, Props)]
+pub struct CalendarOverlayProps {
+    pub overlay: Option<RenderedCalendar>,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[component]
+pub fn CalendarOverlay(props: &mut CalendarOverlayProps) -> impl Into<AnyElement<'static>> {
+    let Some(overlay) = props.overlay.take() else {
+        return element! { View }.into_any();
+    };
+
+    let width = u32::from(props.width);
+    let height = u32::from(props.height);
+    let overlay_width = overlay_width(width);
+    let overlay_height = OVERLAY_HEIGHT.min(height.saturating_sub(2));
+    let pad_left = (width.saturating_sub(overlay_width)) / 2;
+    let pad_top = (height.saturating_sub(overlay_height)) / 2;
+    let outer_border = super::border(overlay.chrome, BorderStyle::Round);
+    let title_border = super::border(overlay.chrome, BorderStyle::Single);
+    let text_fg = overlay.text_fg;
+    let faint_fg = overlay.faint_fg;
+
+    element! {
+        View(
+            width,
+            height,
+            position: Position::Absolute,
+        ) {
+            View(
+                margin_left: pad_left,
+                margin_top: pad_top,
+                width: overlay_width,
+                height: overlay_height,
+                flex_direction: FlexDirection::Column,
+                border_style: outer_border,
+                border_color: overlay.border_fg,
+                background_color: Color::Reset,
+                overflow: Overflow::Hidden,
+            ) {
+                View(
+                    border_style: title_border,
+                    border_edges: Edges::Bottom,
+                    border_color: overlay.border_fg,
+                    padding_left: 1,
+                    padding_right: 1,
+                ) {
+                    Text(
+                        content: overlay.title,
+                        color: overlay.title_fg,
+                        weight: Weight::Bold,
+                        wrap: TextWrap::NoWrap,
+                    )
+                    View(flex_grow: 1.0_f32)
+                    Text(
+                        content: overlay.summary,
+                        color: text_fg,
+                        wrap: TextWrap::NoWrap,
+                    )
+                }
+
+                View(
+                    flex_grow: 1.0_f32,
+                    flex_direction: FlexDirection::Column,
+                    padding_left: 1,
+                    padding_right: 1,
+                    overflow: Overflow::Hidden,
+                ) {
+                    Text(content: overlay.months, color: faint_fg, wrap: TextWrap::NoWrap)
+                    #(overlay.days.into_iter().enumerate().map(|(i, spans)| {
+                        element! {
+                            View(key: i) {
+                                #(spans.into_iter().enumerate().map(|(j, span)| {
+                                    element! {
+                                        Text(key: j, content: span.text, color: span.fg, wrap: TextWrap::NoWrap)
+                                    }
+                                }))
+                            }
+                        }
+                    }))
+                    Text(content: overlay.marker, color: text_fg, wrap: TextWrap::NoWrap)
+                    Text(content: overlay.detail, color: text_fg, wrap: TextWrap::NoWrap)
+                    View(flex_grow: 1.0_f32)
+                    Text(content: overlay.hint, color: faint_fg, wrap: TextWrap::NoWrap)
+                }
+            }
+        }
+    }
+    .into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn week_window_keeps_selection_in_view() {
+        assert_eq!(week_window(53, 20, 52), 33..53);
+        assert_eq!(week_window(53, 20, 5), 0..20);
+        assert_eq!(week_window(53, 20, 25), 6..26);
+        assert_eq!(week_window(10, 20, 3), 0..10);
+        assert_eq!(week_window(0, 20, 0), 0..0);
+    }
+
+    #[test]
+    fn month_labels_mark_month_changes() {
+        let weeks = ["2024-04-21", "2024-04-28", "2024-05-05", "2024-05-12"].map(date);
+        assert_eq!(month_labels(weeks.into_iter()), "Apr May");
+        // A new month one week after the previous label has no room.
+        let weeks = ["2024-04-28", "2024-05-05", "2024-05-12"].map(date);
+        assert_eq!(month_labels(weeks.into_iter()), "Apr");
+    }
+}
@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use iocraft::prelude::*;
+
+// ---------------------------------------------------------------------------
+// ErrorScreen component
+// ---------------------------------------------------------------------------
+
+/// Full-screen notice shown after the UI panicked, offering a restart.
+///
+/// Deliberately theme-free: the theme or config may be what broke.
+#[derive(Default, Props)]
+pub struct ErrorScreenProps {
+    /// Panic message and location.
+    pub summary: String,
+    /// Where the full panic was written, if anywhere.
+    pub panic_log: Option<PathBuf>,
+    /// Set to `true` when the user asks to restart the UI.
+    pub restart: Arc<AtomicBool>,
+}
+
+#[component]
+pub fn ErrorScreen(props: &ErrorScreenProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut should_exit = hooks.use_state(|| false);
+    let restart = props.restart.clone();
+
+    hooks.use_terminal_events(move |event| {
+        if let TerminalEvent::Key(KeyEvent { code, kind, .. }) = event
+            && kind != KeyEventKind::Release
+        {
+            match code {
+                KeyCode::Char('r') => {
+                    restart.store(true, Ordering::Relaxed);
+                    should_exit.set(true);
+                }
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => should_exit.set(true),
+                _ => {}
+            }
+        }
+    });
+    if should_exit.get() {
+        system.exit();
+    }
+
+    element! {
+        View(
+            flex_direction: FlexDirection::Column,
+            padding: 1,
+            border_style: BorderStyle::Round,
+            border_color: Color::Red,
+        ) {
+            Text(content: "gh-board hit an internal error", color: Color::Red, weight: Weight::Bold)
+            Text(content: "")
+            Text(content: props.summary.clone())
+            Text(content: "")
+            #(props.panic_log.as_ref().map(|path| element! {
+                Text(
+                    content: format!("Details were written to {}.", path.display()),
+                    color: Color::DarkGrey,
+                )
+            }))
+            Text(content: "[r] restart   [q] quit", color: Color::DarkGrey)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shows_the_panic_and_where_its_details_are() {
+        let screen = element! {
+            ErrorScreen(
+                summary: "panicked at src/views/prs.rs:42:9: index out of bounds".to_owned(),
+                panic_log: Some(PathBuf::from("/state/panic.log")),
+                restart: Arc::new(AtomicBool::new(false)),
+            )
+        }
+        .to_string();
+        assert!(screen.contains("src/views/prs.rs:42:9: index out of bounds"));
+        assert!(screen.contains("Details were written to /state/panic.log."));
+        assert!(screen.contains("[r] restart"));
+    }
+}
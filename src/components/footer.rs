@@ -1,20 +1,25 @@
 use std::time::Instant;
 
+use chrono::Utc;
+use crossterm::event::MouseButton;
 use iocraft::prelude::*;
+use unicode_width::UnicodeWidthStr;
 
-use crate::app::ViewKind;
+use crate::app::{ViewCounts, ViewKind, WatchBadges};
 use crate::color::{Color as AppColor, ColorDepth};
+use crate::github::auth_warnings;
 use crate::icons::ResolvedIcons;
-use crate::theme::ResolvedTheme;
+use crate::theme::{Chrome, ResolvedTheme};
 use crate::types::RateLimitInfo;
 
 /// Groups color parameters for `RenderedFooter::build`.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct FooterColors {
-    pub view_colors: [Option<AppColor>; 6],
+    pub view_colors: [Option<AppColor>; 7],
     pub inactive: Option<AppColor>,
     pub text: Option<AppColor>,
     pub border: Option<AppColor>,
+    pub chrome: Chrome,
 }
 
 // ---------------------------------------------------------------------------
@@ -66,6 +71,8 @@ pub struct FooterContent {
     pub context_text: String,
     pub updated_text: String,
     pub rate_limit_text: String,
+    pub watch_badges: WatchBadges,
+    pub view_counts: ViewCounts,
 }
 
 pub struct RenderedFooter {
@@ -81,6 +88,11 @@ pub struct RenderedFooter {
     pub text_fg: Color,
     pub border_fg: Color,
     pub separator_fg: Color,
+    pub border_style: BorderStyle,
+    /// Separator between context fields.
+    pub field_sep: &'static str,
+    /// Separator between footer sections.
+    pub section_sep: &'static str,
 }
 
 impl RenderedFooter {
@@ -104,7 +116,20 @@ impl RenderedFooter {
             .map_or(Color::DarkGrey, |c| c.to_crossterm_color(depth));
         let separator_fg = text_fg;
 
-        let (status_text, status_fg) = match status {
+        // A running script's messages, then token expiry and SSO warnings
+        // until resolved, then a failed config reload, then a pending
+        // SmartScope offer, all yielding only to the transient action
+        // feedback.
+        let notice = status
+            .is_none()
+            .then(|| {
+                crate::script::footer_status()
+                    .or_else(|| auth_warnings::footer_text(Utc::now()).map(ActionFeedback::Warning))
+                    .or_else(|| crate::config::loader::reload_error().map(ActionFeedback::Error))
+                    .or_else(|| crate::smart_scope::footer_text().map(ActionFeedback::Info))
+            })
+            .flatten();
+        let (status_text, status_fg) = match status.or(notice.as_ref()) {
             Some(fb) => (Some(fb.render(icons)), fb.color(theme, depth)),
             None => (None, Color::Reset),
         };
@@ -113,7 +138,21 @@ impl RenderedFooter {
             .iter()
             .zip(colors.view_colors.iter())
             .map(|(v, color)| FooterView {
-                label: v.icon_label(icons),
+                label: {
+                    let label = if colors.chrome == Chrome::Plain && *v == active_view {
+                        format!(">{}", v.icon_label(icons).trim_start())
+                    } else {
+                        v.icon_label(icons)
+                    };
+                    let label = match content.view_counts.get(*v) {
+                        Some(n) if n > 0 => format!("{label} {n}"),
+                        _ => label,
+                    };
+                    match content.watch_badges.get(*v) {
+                        0 => label,
+                        n => format!("{label} {}{n}", icons.action_watched),
+                    }
+                },
                 is_active: *v == active_view,
                 color: color.map_or(Color::White, |c| c.to_crossterm_color(depth)),
             })
@@ -132,15 +171,35 @@ impl RenderedFooter {
             text_fg,
             border_fg,
             separator_fg,
+            border_style: super::border(colors.chrome, BorderStyle::Single),
+            field_sep: match colors.chrome {
+                Chrome::Unicode => "  \u{2022}  ",
+                Chrome::Ascii => "  *  ",
+                Chrome::Plain => "; ",
+            },
+            section_sep: match colors.chrome {
+                Chrome::Unicode => " \u{2502} ",
+                Chrome::Ascii => " | ",
+                Chrome::Plain => "; ",
+            },
         }
     }
 }
 
-/// Format rate limit info as "API remaining/limit".
-pub fn format_rate_limit(info: Option<&RateLimitInfo>) -> String {
-    match info {
-        Some(rl) => format!("API {}/{}", rl.remaining, rl.limit),
-        None => String::new(),
+/// Format rate limit info as "API remaining/limit", followed by what one
+/// refresh of the active tab costs when known. The quota of an Enterprise
+/// host is prefixed with its name.
+pub fn format_rate_limit(info: Option<&RateLimitInfo>, refresh_cost: Option<u32>) -> String {
+    let Some(rl) = info else {
+        return String::new();
+    };
+    let host = rl
+        .host
+        .as_ref()
+        .map_or_else(String::new, |h| format!("{h} "));
+    match refresh_cost {
+        Some(cost) => format!("{host}API {}/{} ({cost}/refresh)", rl.remaining, rl.limit),
+        None => format!("{host}API {}/{}", rl.remaining, rl.limit),
     }
 }
 
@@ -162,10 +221,38 @@ pub fn format_updated_ago(last_fetch: Option<Instant>) -> String {
 #[derive(Default, Props)]
 pub struct FooterProps {
     pub footer: Option<RenderedFooter>,
+    /// Set to the view whose indicator is clicked; `None` ignores clicks.
+    pub on_click: Option<State<Option<ViewKind>>>,
 }
 
 #[component]
-pub fn Footer(props: &mut FooterProps) -> impl Into<AnyElement<'static>> {
+pub fn Footer(props: &mut FooterProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    // Indicators are ` label `, in `ViewKind::ALL` order.
+    let widths: Vec<usize> = props.footer.as_ref().map_or_else(Vec::new, |f| {
+        f.views.iter().map(|v| v.label.width() + 2).collect()
+    });
+    // Below the top border, which plain chrome leaves out.
+    let indicator_row = props
+        .footer
+        .as_ref()
+        .map_or(0, |f| u16::from(f.border_style != BorderStyle::None));
+    let on_click = props.on_click;
+    hooks.use_local_terminal_events(move |event| {
+        if let TerminalEvent::FullscreenMouse(FullscreenMouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            ..
+        }) = event
+            && row == indicator_row
+            && let Some(mut on_click) = on_click
+            && let Some(i) = super::item_at(&widths, column)
+            && let Some(&view) = ViewKind::ALL.get(i)
+        {
+            on_click.set(Some(view));
+        }
+    });
+
     let Some(f) = props.footer.take() else {
         return element! { View }.into_any();
     };
@@ -182,19 +269,19 @@ pub fn Footer(props: &mut FooterProps) -> impl Into<AnyElement<'static>> {
         context_contents.push(MixedTextContent::new(&f.scope_label).color(f.text_fg));
     }
     if has_scope && (has_context || has_updated || has_rate_limit) {
-        context_contents.push(MixedTextContent::new("  \u{2022}  ").color(f.separator_fg));
+        context_contents.push(MixedTextContent::new(f.field_sep).color(f.separator_fg));
     }
     if has_context {
         context_contents.push(MixedTextContent::new(&f.context_text).color(f.text_fg));
     }
     if has_context && has_updated {
-        context_contents.push(MixedTextContent::new("  \u{2022}  ").color(f.separator_fg));
+        context_contents.push(MixedTextContent::new(f.field_sep).color(f.separator_fg));
     }
     if has_updated {
         context_contents.push(MixedTextContent::new(&f.updated_text).color(f.text_fg));
     }
     if (has_context || has_updated) && has_rate_limit {
-        context_contents.push(MixedTextContent::new("  \u{2022}  ").color(f.separator_fg));
+        context_contents.push(MixedTextContent::new(f.field_sep).color(f.separator_fg));
     }
     if has_rate_limit {
         context_contents.push(MixedTextContent::new(&f.rate_limit_text).color(f.text_fg));
@@ -208,7 +295,7 @@ pub fn Footer(props: &mut FooterProps) -> impl Into<AnyElement<'static>> {
 
     element! {
         View(
-            border_style: BorderStyle::Single,
+            border_style: f.border_style,
             border_edges: Edges::Top,
             border_color: f.border_fg,
             padding_left: 1,
@@ -228,7 +315,7 @@ pub fn Footer(props: &mut FooterProps) -> impl Into<AnyElement<'static>> {
                 }
             }))
             // Pipe separator
-            Text(content: " \u{2502} ", color: f.separator_fg, wrap: TextWrap::NoWrap)
+            Text(content: f.section_sep, color: f.separator_fg, wrap: TextWrap::NoWrap)
             // Middle: context + updated (flex_grow to fill space)
             View(flex_grow: 1.0_f32) {
                 MixedText(contents: context_contents, wrap: TextWrap::NoWrap)
@@ -237,7 +324,7 @@ pub fn Footer(props: &mut FooterProps) -> impl Into<AnyElement<'static>> {
             #(if has_status {
                 Some(element! {
                     View {
-                        Text(content: " \u{2502} ", color: f.separator_fg, wrap: TextWrap::NoWrap)
+                        Text(content: f.section_sep, color: f.separator_fg, wrap: TextWrap::NoWrap)
                         MixedText(contents: status_contents, wrap: TextWrap::NoWrap)
                     }
                 })
@@ -245,7 +332,7 @@ pub fn Footer(props: &mut FooterProps) -> impl Into<AnyElement<'static>> {
                 None
             })
             // Pipe separator
-            Text(content: " \u{2502} ", color: f.separator_fg, wrap: TextWrap::NoWrap)
+            Text(content: f.section_sep, color: f.separator_fg, wrap: TextWrap::NoWrap)
             // Right: help hint
             Text(content: f.help_hint.clone(), color: f.text_fg, wrap: TextWrap::NoWrap)
         }
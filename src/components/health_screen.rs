@@ -0,0 +1,114 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use iocraft::prelude::*;
+
+use crate::types::HostHealth;
+
+// ---------------------------------------------------------------------------
+// HealthScreen component
+// ---------------------------------------------------------------------------
+
+/// Full-screen report of the startup check, shown instead of the views when
+/// a host fails it.
+///
+/// Theme-free like [`super::error_screen::ErrorScreen`]: it runs before the
+/// views and has nothing of theirs to show.
+#[derive(Default, Props)]
+pub struct HealthScreenProps {
+    /// Every checked host, failing or not.
+    pub hosts: Vec<HostHealth>,
+    /// Set to `true` when the user asks to check again.
+    pub retry: Arc<AtomicBool>,
+    /// Set to `true` when the user starts despite the problems.
+    pub proceed: Arc<AtomicBool>,
+}
+
+#[component]
+pub fn HealthScreen(props: &HealthScreenProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut should_exit = hooks.use_state(|| false);
+    let retry = props.retry.clone();
+    let proceed = props.proceed.clone();
+
+    hooks.use_terminal_events(move |event| {
+        if let TerminalEvent::Key(KeyEvent { code, kind, .. }) = event
+            && kind != KeyEventKind::Release
+        {
+            match code {
+                KeyCode::Char('r') => {
+                    retry.store(true, Ordering::Relaxed);
+                    should_exit.set(true);
+                }
+                KeyCode::Char('c') | KeyCode::Enter => {
+                    proceed.store(true, Ordering::Relaxed);
+                    should_exit.set(true);
+                }
+                KeyCode::Char('q') | KeyCode::Esc => should_exit.set(true),
+                _ => {}
+            }
+        }
+    });
+    if should_exit.get() {
+        system.exit();
+    }
+
+    let mut lines: Vec<AnyElement<'static>> = Vec::new();
+    for host in &props.hosts {
+        if host.problems.is_empty() {
+            let who = host
+                .login
+                .as_ref()
+                .map_or_else(String::new, |login| format!(" (signed in as {login})"));
+            lines.push(
+                element! {
+                    Text(content: format!("{}: ok{who}", host.host), color: Color::Green)
+                }
+                .into_any(),
+            );
+            continue;
+        }
+        lines.push(
+            element! {
+                Text(content: format!("{}:", host.host), color: Color::Red, weight: Weight::Bold)
+            }
+            .into_any(),
+        );
+        for problem in &host.problems {
+            lines.push(
+                element! {
+                    Text(content: format!("  {}: {}", problem.check.label(), problem.message))
+                }
+                .into_any(),
+            );
+            lines.push(
+                element! {
+                    Text(content: format!("    {}", problem.hint), color: Color::DarkGrey)
+                }
+                .into_any(),
+            );
+        }
+    }
+
+    element! {
+        View(
+            flex_direction: FlexDirection::Column,
+            padding: 1,
+            border_style: BorderStyle::Round,
+            border_color: Color::Red,
+        ) {
+            Text(
+                content: "gh-board cannot use GitHub as configured",
+                color: Color::Red,
+                weight: Weight::Bold,
+            )
+            Text(content: "")
+            #(lines)
+            Text(content: "")
+            Text(
+                content: "[r] check again   [c] start anyway   [q] quit",
+                color: Color::DarkGrey,
+            )
+        }
+    }
+}
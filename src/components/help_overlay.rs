@@ -2,6 +2,7 @@ use iocraft::prelude::*;
 
 use crate::color::{Color as AppColor, ColorDepth};
 use crate::config::keybindings::{Keybinding, MergedBindings, ViewContext};
+use crate::theme::Chrome;
 
 // ---------------------------------------------------------------------------
 // Help overlay (T066 — FR-104)
@@ -26,6 +27,7 @@ pub struct RenderedHelpOverlay {
     pub key_fg: Color,
     pub desc_fg: Color,
     pub border_fg: Color,
+    pub chrome: Chrome,
 }
 
 pub struct RenderedHelpGroup {
@@ -49,6 +51,7 @@ pub struct HelpOverlayBuildConfig<'a> {
     pub key_color: Option<AppColor>,
     pub desc_color: Option<AppColor>,
     pub border_color: Option<AppColor>,
+    pub chrome: Chrome,
 }
 
 impl RenderedHelpOverlay {
@@ -102,6 +105,7 @@ impl RenderedHelpOverlay {
             key_fg,
             desc_fg,
             border_fg,
+            chrome: cfg.chrome,
         }
     }
 }
@@ -208,6 +212,8 @@ pub fn HelpOverlay(props: &mut HelpOverlayProps) -> impl Into<AnyElement<'static
     let overlay_height = (height * 4 / 5).max(10).min(height.saturating_sub(2));
     let pad_left = (width.saturating_sub(overlay_width)) / 2;
     let pad_top = (height.saturating_sub(overlay_height)) / 2;
+    let outer_border = super::border(overlay.chrome, BorderStyle::Round);
+    let title_border = super::border(overlay.chrome, BorderStyle::Single);
 
     element! {
         View(
@@ -221,14 +227,14 @@ pub fn HelpOverlay(props: &mut HelpOverlayProps) -> impl Into<AnyElement<'static
                 width: overlay_width,
                 height: overlay_height,
                 flex_direction: FlexDirection::Column,
-                border_style: BorderStyle::Round,
+                border_style: outer_border,
                 border_color: overlay.border_fg,
                 background_color: Color::Reset,
                 overflow: Overflow::Hidden,
             ) {
                 // Title row (like sidebar title: bold, padded, with a bottom separator)
                 View(
-                    border_style: BorderStyle::Single,
+                    border_style: title_border,
                     border_edges: Edges::Bottom,
                     border_color: overlay.border_fg,
                     padding_left: 1,
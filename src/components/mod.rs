@@ -1,4 +1,8 @@
+pub mod board;
+pub mod calendar;
+pub mod error_screen;
 pub mod footer;
+pub mod health_screen;
 pub mod help_overlay;
 pub mod markdown_view;
 pub mod scrollbar;
@@ -8,3 +12,108 @@ pub mod sidebar_tabs;
 pub mod tab_bar;
 pub mod table;
 pub mod text_input;
+pub mod warning_banner;
+
+use iocraft::prelude::{BorderCharacters, BorderStyle};
+
+use crate::theme::Chrome;
+
+/// ASCII stand-in for every box-drawing border style.
+const ASCII_BORDER: BorderStyle = BorderStyle::Custom(BorderCharacters {
+    top: '-',
+    bottom: '-',
+    left: '|',
+    right: '|',
+    top_left: '+',
+    top_right: '+',
+    bottom_left: '+',
+    bottom_right: '+',
+});
+
+/// Resolve the border style a component would normally draw against the
+/// active chrome.
+pub(crate) fn border(chrome: Chrome, style: BorderStyle) -> BorderStyle {
+    match (chrome, style) {
+        (Chrome::Unicode, _) | (Chrome::Ascii, BorderStyle::None) => style,
+        (Chrome::Ascii, _) => ASCII_BORDER,
+        (Chrome::Plain, _) => BorderStyle::None,
+    }
+}
+
+/// The item at `column` of a bar whose items, `widths` columns wide, follow
+/// one column of padding side by side (the tab bar, the footer's views).
+pub(crate) fn item_at(widths: &[usize], column: u16) -> Option<usize> {
+    let column = usize::from(column);
+    let mut left = 1;
+    for (i, &width) in widths.iter().enumerate() {
+        if (left..left + width).contains(&column) {
+            return Some(i);
+        }
+        left += width;
+    }
+    None
+}
+
+/// Block cursor appended to text being typed.
+pub(crate) fn input_cursor(chrome: Chrome) -> &'static str {
+    if chrome.ascii_only() {
+        "_"
+    } else {
+        "\u{2588}" // █
+    }
+}
+
+/// Marker appended to truncated text and pending-work messages.
+pub(crate) fn ellipsis(chrome: Chrome) -> &'static str {
+    if chrome.ascii_only() {
+        "..."
+    } else {
+        "\u{2026}" // …
+    }
+}
+
+/// Separator between the inline fields of a label (`title · branch`).
+pub(crate) fn dot_separator(chrome: Chrome) -> &'static str {
+    if chrome.ascii_only() {
+        " - "
+    } else {
+        " \u{b7} " // ·
+    }
+}
+
+/// Connector drawn before a tree node; `last` closes its branch. Plain
+/// chrome only indents.
+pub(crate) fn tree_branch(chrome: Chrome, last: bool) -> &'static str {
+    match (chrome, last) {
+        (Chrome::Unicode, true) => "\u{2514}\u{2500} ",  // └─
+        (Chrome::Unicode, false) => "\u{251c}\u{2500} ", // ├─
+        (Chrome::Ascii, true) => "`- ",
+        (Chrome::Ascii, false) => "|- ",
+        (Chrome::Plain, _) => "   ",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bar_items_are_found_by_column() {
+        let widths = [4, 2, 6];
+        assert_eq!(item_at(&widths, 0), None);
+        assert_eq!(item_at(&widths, 1), Some(0));
+        assert_eq!(item_at(&widths, 4), Some(0));
+        assert_eq!(item_at(&widths, 5), Some(1));
+        assert_eq!(item_at(&widths, 7), Some(2));
+        assert_eq!(item_at(&widths, 12), Some(2));
+        assert_eq!(item_at(&widths, 13), None);
+    }
+
+    #[test]
+    fn tree_connectors_follow_chrome() {
+        assert_eq!(tree_branch(Chrome::Unicode, false), "\u{251c}\u{2500} ");
+        assert_eq!(tree_branch(Chrome::Ascii, true), "`- ");
+        assert_eq!(tree_branch(Chrome::Ascii, false), "|- ");
+        assert!(tree_branch(Chrome::Plain, true).trim().is_empty());
+    }
+}
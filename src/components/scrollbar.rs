@@ -58,6 +58,8 @@ impl ScrollInfo {
 // const TRACK_CHAR: &str = "│"; // U+2502 box-drawing light vertical
 const TRACK_CHAR: &str = "░"; // U+2591 light shade
 const THUMB_CHAR: &str = "█"; // U+2588 full block
+const ASCII_TRACK_CHAR: &str = "|";
+const ASCII_THUMB_CHAR: &str = "#";
 
 #[derive(Default, Props)]
 pub struct ScrollbarProps {
@@ -65,6 +67,8 @@ pub struct ScrollbarProps {
     pub track_height: u32,
     pub track_color: Option<Color>,
     pub thumb_color: Option<Color>,
+    /// Use ASCII track/thumb characters.
+    pub ascii: bool,
 }
 
 #[component]
@@ -80,15 +84,20 @@ pub fn Scrollbar(props: &mut ScrollbarProps) -> impl Into<AnyElement<'static>> {
     }
 
     let info = info.unwrap();
+    let (track_char, thumb_char) = if props.ascii {
+        (ASCII_TRACK_CHAR, ASCII_THUMB_CHAR)
+    } else {
+        (TRACK_CHAR, THUMB_CHAR)
+    };
     let (thumb_start, thumb_size) = info.thumb_geometry(track_height);
 
     let cells: Vec<(usize, &str, Color)> = (0..track_height)
         .map(|row| {
             let in_thumb = row >= thumb_start && row < thumb_start + thumb_size;
             let (ch, color) = if in_thumb {
-                (THUMB_CHAR, thumb_color)
+                (thumb_char, thumb_color)
             } else {
-                (TRACK_CHAR, track_color)
+                (track_char, track_color)
             };
             (row as usize, ch, color)
         })
@@ -1,6 +1,7 @@
 use iocraft::prelude::*;
 
 use crate::color::{Color as AppColor, ColorDepth};
+use crate::theme::Chrome;
 
 // ---------------------------------------------------------------------------
 // Selection overlay (T011 — deep-link disambiguation)
@@ -19,6 +20,7 @@ pub struct RenderedSelectionOverlay {
     pub cursor_marker: String,
     pub show_filter: bool,
     pub filter_text: String,
+    pub hint: Option<String>,
     pub title_fg: Color,
     pub item_fg: Color,
     pub cursor_fg: Color,
@@ -27,6 +29,9 @@ pub struct RenderedSelectionOverlay {
     pub hint_fg: Color,
     pub filter_prompt_fg: Color,
     pub filter_text_fg: Color,
+    pub chrome: Chrome,
+    /// Take most of the screen width instead of ~40%, for long lines.
+    pub wide: bool,
 }
 
 /// Configuration for building a selection overlay.
@@ -38,6 +43,8 @@ pub struct SelectionOverlayBuildConfig {
     /// the overlay is more compact (useful for small pickers).
     pub show_filter: bool,
     pub filter_text: String,
+    /// Replaces the default key hint in the title row.
+    pub hint: Option<String>,
     pub depth: ColorDepth,
     pub title_color: Option<AppColor>,
     pub item_color: Option<AppColor>,
@@ -48,6 +55,7 @@ pub struct SelectionOverlayBuildConfig {
     pub filter_prompt_color: Option<AppColor>,
     pub filter_text_color: Option<AppColor>,
     pub cursor_marker: String,
+    pub chrome: Chrome,
 }
 
 impl RenderedSelectionOverlay {
@@ -61,6 +69,7 @@ impl RenderedSelectionOverlay {
             cursor_marker: cfg.cursor_marker,
             show_filter: cfg.show_filter,
             filter_text: cfg.filter_text,
+            hint: cfg.hint,
             title_fg: cfg
                 .title_color
                 .map_or(Color::White, |c| c.to_crossterm_color(depth)),
@@ -85,6 +94,8 @@ impl RenderedSelectionOverlay {
             filter_text_fg: cfg
                 .filter_text_color
                 .map_or(Color::White, |c| c.to_crossterm_color(depth)),
+            chrome: cfg.chrome,
+            wide: false,
         }
     }
 }
@@ -115,20 +126,34 @@ pub fn SelectionOverlay(props: &mut SelectionOverlayProps) -> impl Into<AnyEleme
     let filter_overhead: u32 = if overlay.show_filter { 2 } else { 0 };
     #[expect(clippy::cast_possible_truncation)]
     let content_height = (overlay.items.len() as u32) + 4 + filter_overhead;
-    let overlay_width = (width * 2 / 5).max(30).min(width.saturating_sub(4));
+    let overlay_width = if overlay.wide {
+        width * 9 / 10
+    } else {
+        width * 2 / 5
+    }
+    .max(30)
+    .min(width.saturating_sub(4));
     let overlay_height = content_height.min(height.saturating_sub(2));
     let pad_left = (width.saturating_sub(overlay_width)) / 2;
     let pad_top = (height.saturating_sub(overlay_height)) / 2;
 
     let cursor_marker_str = format!("{} ", overlay.cursor_marker);
     let show_filter = overlay.show_filter;
-    let filter_display = format!("{}\u{2588}", overlay.filter_text); // append block cursor █
+    let filter_display = format!(
+        "{}{}",
+        overlay.filter_text,
+        super::input_cursor(overlay.chrome)
+    );
     let has_filter_text = !overlay.filter_text.is_empty();
-    let hint = if has_filter_text {
-        "Type to filter"
+    let hint = if let Some(hint) = overlay.hint {
+        hint
+    } else if has_filter_text {
+        "Type to filter".to_owned()
     } else {
-        "j/k Enter Esc"
+        "j/k Enter Esc".to_owned()
     };
+    let outer_border = super::border(overlay.chrome, BorderStyle::Round);
+    let rule_border = super::border(overlay.chrome, BorderStyle::Single);
 
     element! {
         View(
@@ -142,14 +167,14 @@ pub fn SelectionOverlay(props: &mut SelectionOverlayProps) -> impl Into<AnyEleme
                 width: overlay_width,
                 height: overlay_height,
                 flex_direction: FlexDirection::Column,
-                border_style: BorderStyle::Round,
+                border_style: outer_border,
                 border_color: overlay.border_fg,
                 background_color: Color::Reset,
                 overflow: Overflow::Hidden,
             ) {
                 // Title row (sidebar-style: bold title + spacer + hint)
                 View(
-                    border_style: BorderStyle::Single,
+                    border_style: rule_border,
                     border_edges: Edges::Bottom,
                     border_color: overlay.border_fg,
                     padding_left: 1,
@@ -198,7 +223,7 @@ pub fn SelectionOverlay(props: &mut SelectionOverlayProps) -> impl Into<AnyEleme
                 #(if show_filter {
                     Some(element! {
                         View(
-                            border_style: BorderStyle::Single,
+                            border_style: rule_border,
                             border_edges: Edges::Top,
                             border_color: overlay.border_fg,
                             padding_left: 1,
@@ -7,6 +7,7 @@ use crate::components::markdown_view::{MarkdownView, RenderedMarkdown};
 use crate::components::scrollbar::{ScrollInfo, Scrollbar};
 use crate::icons::ResolvedIcons;
 use crate::markdown::renderer::StyledLine;
+use crate::theme::Chrome;
 
 // ---------------------------------------------------------------------------
 // Sidebar color config
@@ -21,6 +22,7 @@ pub struct SidebarColors {
     pub indicator: Option<AppColor>,
     pub thumb: Option<AppColor>,
     pub depth: ColorDepth,
+    pub chrome: Chrome,
 }
 
 // ---------------------------------------------------------------------------
@@ -48,6 +50,9 @@ pub enum SidebarTab {
     Commits,
     Checks,
     Files,
+    /// Issue parent, sub-issues and dependencies; issues only, so not in
+    /// [`ALL`](Self::ALL).
+    Links,
 }
 
 impl SidebarTab {
@@ -66,6 +71,7 @@ impl SidebarTab {
             Self::Commits => "Commits",
             Self::Checks => "Checks",
             Self::Files => "Files",
+            Self::Links => "Links",
         }
     }
 
@@ -77,6 +83,7 @@ impl SidebarTab {
             Self::Commits => &icons.tab_commits,
             Self::Checks => &icons.tab_checks,
             Self::Files => &icons.tab_files,
+            Self::Links => &icons.tab_links,
         };
         format!("{icon} {}", self.label())
     }
@@ -144,6 +151,10 @@ pub struct SidebarMeta {
     pub lines_added: Option<String>,
     pub lines_deleted: Option<String>,
     pub reactions_text: Option<String>,
+    /// Latest deployment per environment as `(text, color)`, PRs only.
+    pub deployments: Vec<(String, Color)>,
+    /// Further `(label, value)` rows, e.g. a run's commit and triggering PR.
+    pub context_lines: Vec<(String, String)>,
     pub date_fg: Color,
     pub date_age_fg: Color,
     pub additions_fg: Color,
@@ -159,7 +170,8 @@ impl SidebarMeta {
     ///
     /// Base: pill(1) + author(1) = 2, plus optional participants(1).
     /// Plus overview metadata: created(1) + updated(1) + separator(1) = 3,
-    /// plus optional labels(1), assignees(1), lines(1), reactions(1).
+    /// plus optional labels(1), assignees(1), lines(1), reactions(1),
+    /// deployments(1), and one per context line.
     /// We also account for `margin_top: 1` on each sub-group.
     pub fn line_count(&self) -> u32 {
         // outer margin_top(1) + pill(1) + author margin_top(1) + author(1) = 4
@@ -181,7 +193,10 @@ impl SidebarMeta {
         if self.reactions_text.is_some() {
             count += 1;
         }
-        count
+        if !self.deployments.is_empty() {
+            count += 1;
+        }
+        count + u32::try_from(self.context_lines.len()).unwrap_or(u32::MAX)
     }
 }
 
@@ -215,6 +230,14 @@ pub struct RenderedSidebar {
     pub scrollbar_thumb_fg: Color,
     /// Scroll offset after clamping (views should store this back).
     pub clamped_scroll: usize,
+    /// Border drawn left of the pane and under the tab labels.
+    pub border_style: BorderStyle,
+    /// Horizontal rule below the overview metadata.
+    pub rule: String,
+    /// Prefix the active tab label with a marker (plain chrome).
+    pub mark_active_tab: bool,
+    /// Draw the scrollbar with ASCII characters.
+    pub ascii_scrollbar: bool,
 }
 
 impl RenderedSidebar {
@@ -298,7 +321,7 @@ impl RenderedSidebar {
             visible_count: visible_lines,
             total_count: layout.visual_total,
         };
-        let scroll_info = if scroll_info.needs_scrollbar() {
+        let scroll_info = if scroll_info.needs_scrollbar() && colors.chrome != Chrome::Plain {
             Some(scroll_info)
         } else {
             None
@@ -307,6 +330,13 @@ impl RenderedSidebar {
         #[expect(clippy::cast_possible_truncation)]
         let track_height = visible_lines as u32;
 
+        let border_style = super::border(colors.chrome, BorderStyle::Single);
+        let rule = match colors.chrome {
+            Chrome::Unicode => "\u{2500}".repeat(20),
+            Chrome::Ascii => "-".repeat(20),
+            Chrome::Plain => String::new(),
+        };
+
         Self {
             title: crate::util::expand_emoji(title).into_owned(),
             scroll_indicator,
@@ -324,6 +354,10 @@ impl RenderedSidebar {
             scrollbar_track_fg: border_fg,
             scrollbar_thumb_fg: thumb_fg,
             clamped_scroll: layout.clamped_scroll,
+            border_style,
+            rule,
+            mark_active_tab: colors.chrome == Chrome::Plain,
+            ascii_scrollbar: colors.chrome.ascii_only(),
         }
     }
 }
@@ -447,6 +481,10 @@ pub fn Sidebar(props: &mut SidebarProps) -> impl Into<AnyElement<'static>> {
     let track_height = sb.track_height;
     let track_color = sb.scrollbar_track_fg;
     let thumb_color = sb.scrollbar_thumb_fg;
+    let border_style = sb.border_style;
+    let rule = sb.rule;
+    let mark_active = sb.mark_active_tab;
+    let ascii_scrollbar = sb.ascii_scrollbar;
 
     // Pre-build tab label contents for MixedText.
     let tab_contents: Vec<MixedTextContent> = sb
@@ -459,7 +497,8 @@ pub fn Sidebar(props: &mut SidebarProps) -> impl Into<AnyElement<'static>> {
                 sb.tab_inactive_fg
             };
             let weight = if active { Weight::Bold } else { Weight::Normal };
-            MixedTextContent::new(format!(" {label} "))
+            let marker = if mark_active && active { ">" } else { " " };
+            MixedTextContent::new(format!("{marker}{} ", label.trim_start()))
                 .color(color)
                 .weight(weight)
         })
@@ -469,7 +508,7 @@ pub fn Sidebar(props: &mut SidebarProps) -> impl Into<AnyElement<'static>> {
         View(
             flex_direction: FlexDirection::Column,
             width: sb.width,
-            border_style: BorderStyle::Single,
+            border_style,
             border_edges: Edges::Left,
             border_color: sb.border_fg,
             padding_left: 1,
@@ -491,7 +530,7 @@ pub fn Sidebar(props: &mut SidebarProps) -> impl Into<AnyElement<'static>> {
             #(if has_tabs {
                 Some(element! {
                     View(
-                        border_style: BorderStyle::Single,
+                        border_style,
                         border_edges: Edges::Bottom,
                         border_color: sb.border_fg,
                     ) {
@@ -541,6 +580,19 @@ pub fn Sidebar(props: &mut SidebarProps) -> impl Into<AnyElement<'static>> {
                 let lines_deleted = m.lines_deleted.unwrap_or_default();
                 let has_reactions = m.reactions_text.is_some();
                 let reactions_text = m.reactions_text.unwrap_or_default();
+                let has_deployments = !m.deployments.is_empty();
+                let mut deployment_contents = vec![
+                    MixedTextContent::new("Deploy: ")
+                        .color(m.label_fg)
+                        .weight(Weight::Bold),
+                ];
+                for (i, (text, color)) in m.deployments.into_iter().enumerate() {
+                    if i > 0 {
+                        deployment_contents.push(MixedTextContent::new(", ").color(m.date_fg));
+                    }
+                    deployment_contents.push(MixedTextContent::new(text).color(color));
+                }
+                let context_lines = m.context_lines;
                 let date_fg = m.date_fg;
                 let date_age_fg = m.date_age_fg;
                 let additions_fg = m.additions_fg;
@@ -549,7 +601,7 @@ pub fn Sidebar(props: &mut SidebarProps) -> impl Into<AnyElement<'static>> {
                 let primary_fg = m.primary_fg;
                 let actor_fg = m.actor_fg;
                 let reactions_fg = m.reactions_fg;
-                let separator = "\u{2500}".repeat(20);
+                let separator = rule.clone();
 
                 element! {
                     View(margin_top: 1, flex_direction: FlexDirection::Column) {
@@ -745,6 +797,36 @@ pub fn Sidebar(props: &mut SidebarProps) -> impl Into<AnyElement<'static>> {
                         } else {
                             None
                         })
+                        // Deployments (optional, PRs only)
+                        #(if has_deployments {
+                            Some(element! {
+                                View {
+                                    MixedText(
+                                        contents: deployment_contents,
+                                        wrap: TextWrap::NoWrap,
+                                    )
+                                }
+                            })
+                        } else {
+                            None
+                        })
+                        // Context lines (optional, runs only)
+                        #(context_lines.into_iter().enumerate().map(|(i, (label, value))| {
+                            element! {
+                                View(key: i) {
+                                    MixedText(
+                                        contents: vec![
+                                            MixedTextContent::new(label)
+                                                .color(label_fg)
+                                                .weight(Weight::Bold),
+                                            MixedTextContent::new(value)
+                                                .color(primary_fg),
+                                        ],
+                                        wrap: TextWrap::NoWrap,
+                                    )
+                                }
+                            }
+                        }))
                         // Separator
                         View {
                             Text(content: separator, color: separator_fg, wrap: TextWrap::NoWrap)
@@ -760,6 +842,7 @@ pub fn Sidebar(props: &mut SidebarProps) -> impl Into<AnyElement<'static>> {
                     MarkdownView(markdown: sb.markdown)
                 }
                 Scrollbar(
+                    ascii: ascii_scrollbar,
                     scroll_info: scroll_info,
                     track_height: track_height,
                     track_color: track_color,
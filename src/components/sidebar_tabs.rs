@@ -1,9 +1,11 @@
+use crate::check_history::FlakyCheck;
 use crate::color::{Color as AppColor, ColorDepth};
 use crate::markdown::renderer::{StyledLine, StyledSpan};
-use crate::theme::ResolvedTheme;
+use crate::theme::{Chrome, ResolvedTheme};
 use crate::types::{
-    CheckConclusion, CheckRun, CheckStatus, CommitCheckState, FileChangeType, IssueDetail,
-    PrDetail, PullRequest, ReviewState, TimelineEvent,
+    CheckConclusion, CheckRun, CheckStatus, Commit, CommitCheckState, CommitSignature,
+    FileChangeType, FileViewedState, Issue, IssueDetail, IssueLink, IssueLinks, IssueState,
+    OwnerApproval, PageCursor, PrDetail, PrState, PullRequest, ReviewState, TimelineEvent,
 };
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
@@ -12,23 +14,78 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 // ---------------------------------------------------------------------------
 
 /// Render the Activity tab: chronological timeline events.
+///
+/// The header of the `selected` event (an index into `timeline_events`) is
+/// marked with the selection cursor. Bot and minimized comments are replaced
+/// by a count unless `show_noise` is set.
 pub fn render_activity(
     detail: &PrDetail,
     theme: &ResolvedTheme,
     depth: ColorDepth,
+    selected: Option<usize>,
+    show_noise: bool,
 ) -> Vec<StyledLine> {
+    activity_lines(detail, theme, depth, selected, show_noise).0
+}
+
+/// Line at which timeline event `idx` starts in [`render_activity`].
+pub fn activity_event_line(
+    detail: &PrDetail,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+    idx: usize,
+    show_noise: bool,
+) -> usize {
+    activity_lines(detail, theme, depth, Some(idx), show_noise)
+        .1
+        .unwrap_or(0)
+}
+
+/// The Activity tab lines and the line of the `selected` event's header.
+fn activity_lines(
+    detail: &PrDetail,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+    selected: Option<usize>,
+    show_noise: bool,
+) -> (Vec<StyledLine>, Option<usize>) {
     if detail.timeline_events.is_empty() {
-        return vec![StyledLine::from_span(StyledSpan::text(
-            "(no timeline events)",
-            theme.text_faint,
-        ))];
+        let placeholder =
+            StyledLine::from_span(StyledSpan::text("(no timeline events)", theme.text_faint));
+        return (vec![placeholder], None);
     }
 
     let mut lines = Vec::new();
-    for event in &detail.timeline_events {
+    let hidden = detail
+        .timeline_events
+        .iter()
+        .filter(|e| e.is_noise())
+        .count();
+    if hidden > 0 && !show_noise {
+        let noun = if hidden == 1 { "comment" } else { "comments" };
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            format!("({hidden} bot or minimized {noun} hidden)"),
+            theme.text_faint,
+        )));
+        lines.push(StyledLine::blank());
+    }
+
+    let mut selected_line = None;
+    for (i, event) in detail.timeline_events.iter().enumerate() {
+        if event.is_noise() && !show_noise {
+            continue;
+        }
+        let header = lines.len();
         render_timeline_event(event, theme, depth, &mut lines);
+        if selected == Some(i) {
+            let marker = format!("{} ", theme.icons.select_cursor);
+            lines[header]
+                .spans
+                .insert(0, StyledSpan::bold(marker, theme.text_primary));
+            selected_line = Some(header);
+        }
     }
-    lines
+    (lines, selected_line)
 }
 
 fn render_timeline_event(
@@ -42,6 +99,7 @@ fn render_timeline_event(
             author,
             body,
             created_at,
+            ..
         } => {
             push_event_header(
                 lines,
@@ -58,6 +116,7 @@ fn render_timeline_event(
             state,
             body,
             submitted_at,
+            ..
         } => {
             let action = match state {
                 ReviewState::Approved => "approved",
@@ -179,6 +238,15 @@ pub fn render_commits(detail: &PrDetail, theme: &ResolvedTheme) -> Vec<StyledLin
         return lines;
     }
 
+    let required = detail.merge_requirements.requires_signatures;
+    if let Some(summary) = unverified_summary(&detail.commits, required) {
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            summary,
+            theme.text_warning,
+        )));
+        lines.push(StyledLine::blank());
+    }
+
     for commit in &detail.commits {
         let short_sha = if commit.sha.len() >= 7 {
             &commit.sha[..7]
@@ -192,10 +260,11 @@ pub fn render_commits(detail: &PrDetail, theme: &ResolvedTheme) -> Vec<StyledLin
             .map(|d| crate::util::format_date(d, "relative"))
             .unwrap_or_default();
 
-        let mut spans = vec![StyledSpan::text(
-            format!("{short_sha} "),
-            theme.text_warning,
-        )];
+        let (sig_icon, sig_color) = commit_signature_icon(&commit.signature, theme);
+        let mut spans = vec![
+            StyledSpan::text(format!("{short_sha} "), theme.text_warning),
+            StyledSpan::text(format!("{sig_icon} "), sig_color),
+        ];
         if let Some(state) = commit.check_state {
             let (icon, color) = commit_check_state_icon(state, theme);
             spans.push(StyledSpan::text(format!("{icon} "), color));
@@ -206,26 +275,92 @@ pub fn render_commits(detail: &PrDetail, theme: &ResolvedTheme) -> Vec<StyledLin
         ));
         lines.push(StyledLine::from_spans(spans));
         if !author.is_empty() || !date.is_empty() {
-            lines.push(StyledLine::from_spans(vec![
+            let mut meta = vec![
                 StyledSpan::text(format!("        {author}"), theme.text_actor),
                 StyledSpan::text(format!("  {date}"), theme.text_faint),
-            ]));
+            ];
+            if let CommitSignature::Unverified { reason } = &commit.signature {
+                meta.push(StyledSpan::text(
+                    format!("  signature: {}", reason.replace('_', " ")),
+                    theme.text_warning,
+                ));
+            }
+            lines.push(StyledLine::from_spans(meta));
         }
     }
+    lines.extend(page_footer(
+        detail.commits.len(),
+        &detail.commits_page,
+        "commits",
+        theme,
+    ));
 
     lines
 }
 
+/// "2 of 5 commits unsigned" style note. Unsigned commits only count when
+/// the base branch `required` signatures; a signature that fails to verify
+/// is worth flagging either way.
+fn unverified_summary(commits: &[Commit], required: bool) -> Option<String> {
+    let unsigned = if required {
+        commits
+            .iter()
+            .filter(|c| c.signature == CommitSignature::Unsigned)
+            .count()
+    } else {
+        0
+    };
+    let unverified = commits
+        .iter()
+        .filter(|c| matches!(c.signature, CommitSignature::Unverified { .. }))
+        .count();
+    let total = commits.len();
+    let parts: Vec<String> = [(unsigned, "unsigned"), (unverified, "not verified")]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, what)| format!("{n} of {total} commits {what}"))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Trailing note for a paged list: progress while further pages stream in,
+/// or the shortfall when loading stopped short of GitHub's reported total.
+fn page_footer(
+    loaded: usize,
+    page: &PageCursor,
+    noun: &str,
+    theme: &ResolvedTheme,
+) -> Option<StyledLine> {
+    let total = page.total;
+    let text = if page.next.is_some() {
+        let ellipsis = crate::components::ellipsis(theme.chrome);
+        format!("loading more{ellipsis} ({loaded} of {total} {noun})")
+    } else if total > loaded {
+        format!("showing {loaded} of {total} {noun}")
+    } else {
+        return None;
+    };
+    Some(StyledLine::from_span(StyledSpan::text(
+        text,
+        theme.text_faint,
+    )))
+}
+
 // ---------------------------------------------------------------------------
 // T076: Checks tab
 // ---------------------------------------------------------------------------
 
 /// Render the Checks tab: check runs grouped by workflow, with duration column.
+/// Required checks come first and are marked with `*`.
+///
+/// With a `selected` check (an index into `check_runs`, see [`check_order`]),
+/// returns the line marked with the selection cursor too.
 pub fn render_checks(
     pr: &PullRequest,
     theme: &ResolvedTheme,
     sidebar_width: u16,
-) -> Vec<StyledLine> {
+    selected: Option<usize>,
+) -> (Vec<StyledLine>, Option<usize>) {
     let mut lines = Vec::new();
 
     if pr.check_runs.is_empty() {
@@ -233,11 +368,14 @@ pub fn render_checks(
             "(no checks)",
             theme.text_faint,
         )));
-        return lines;
+        return (lines, None);
     }
 
-    // Content width = sidebar minus left border (1) + padding (2) + scrollbar (1).
-    let content_width = usize::from(sidebar_width).saturating_sub(4).max(1);
+    // Content width = sidebar minus left border (1) + padding (2) + scrollbar (1),
+    // and the selection cursor.
+    let content_width = usize::from(sidebar_width)
+        .saturating_sub(4 + selection_width(selected, theme))
+        .max(1);
 
     // Group checks by workflow_name, preserving insertion order via IndexMap-like Vec.
     let groups = group_checks_by_workflow(&pr.check_runs);
@@ -248,7 +386,7 @@ pub fn render_checks(
         .map(|(_, checks)| {
             checks
                 .iter()
-                .map(|c| crate::util::expand_emoji(&c.name))
+                .map(|(_, c)| crate::util::expand_emoji(&c.name))
                 .collect()
         })
         .collect();
@@ -259,7 +397,7 @@ pub fn render_checks(
         .map(|(_, checks)| {
             checks
                 .iter()
-                .map(|c| crate::util::format_duration(c.started_at, c.completed_at))
+                .map(|(_, c)| crate::util::format_duration(c.started_at, c.completed_at))
                 .collect()
         })
         .collect();
@@ -284,6 +422,7 @@ pub fn render_checks(
         .unwrap_or(0);
     let name_col_width = natural_max.min(name_budget);
 
+    let mut selected_line = None;
     for (i, ((wf_name, checks), (names, durs))) in groups
         .iter()
         .zip(expanded_names.iter().zip(&durations))
@@ -300,16 +439,24 @@ pub fn render_checks(
             theme.text_faint,
         )));
 
-        for ((check, expanded_name), dur) in checks.iter().zip(names).zip(durs) {
+        for (((idx, check), expanded_name), dur) in checks.iter().zip(names).zip(durs) {
+            if selected == Some(*idx) {
+                selected_line = Some(lines.len());
+            }
             let (icon, icon_color) = check_status_icon(check.status, check.conclusion, theme);
             let name_w = UnicodeWidthStr::width(expanded_name.as_ref());
             let (display_name, display_w) = if name_w > name_col_width {
-                truncate_with_ellipsis(expanded_name.as_ref(), name_col_width)
+                truncate_with_ellipsis(expanded_name.as_ref(), name_col_width, theme.chrome)
             } else {
                 (expanded_name.to_string(), name_w)
             };
+            let marker = if check.is_required {
+                StyledSpan::text("* ", theme.text_warning)
+            } else {
+                StyledSpan::text("  ", theme.text_primary)
+            };
             let mut spans = vec![
-                StyledSpan::text("  ", theme.text_primary),
+                marker,
                 StyledSpan::text(format!("{icon} "), icon_color),
                 StyledSpan::text(display_name, theme.text_primary),
             ];
@@ -324,19 +471,145 @@ pub fn render_checks(
         }
     }
 
+    if selected.is_some() {
+        mark_selection(&mut lines, selected_line, theme);
+    }
+    (lines, selected_line)
+}
+
+/// Indices of `checks` in the order the Checks tab lists them.
+pub fn check_order(checks: &[CheckRun]) -> Vec<usize> {
+    group_checks_by_workflow(checks)
+        .into_iter()
+        .flat_map(|(_, group)| group.into_iter().map(|(i, _)| i))
+        .collect()
+}
+
+/// Workflow runs GitHub holds until a maintainer approves them, first in the
+/// Checks tab: until then the PR's checks never start (`approve_workflows`).
+pub fn render_awaiting_approval(
+    detail: Option<&PrDetail>,
+    theme: &ResolvedTheme,
+) -> Vec<StyledLine> {
+    let Some(detail) = detail.filter(|d| !d.awaiting_approval.is_empty()) else {
+        return Vec::new();
+    };
+    let mut lines = vec![StyledLine::from_spans(vec![
+        StyledSpan::bold(
+            format!("{} Awaiting approval", theme.icons.check_action_required),
+            theme.text_warning,
+        ),
+        StyledSpan::text("  (alt+a: approve)", theme.text_warning),
+    ])];
+    for run in &detail.awaiting_approval {
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            format!("  {}", run.workflow),
+            theme.text_primary,
+        )));
+    }
+    lines.push(StyledLine::from_span(StyledSpan::text(
+        "  A first-time contributor's runs wait for a maintainer",
+        theme.text_faint,
+    )));
+    lines.push(StyledLine::blank());
+    lines
+}
+
+/// Checks whose recorded history looks flaky, above the check list. Failing
+/// Actions checks can have just their job re-run (`rerun_flaky`).
+pub(crate) fn render_flaky_checks(flaky: &[FlakyCheck], theme: &ResolvedTheme) -> Vec<StyledLine> {
+    if flaky.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![StyledLine::from_span(StyledSpan::bold(
+        "Likely flaky",
+        theme.text_warning,
+    ))];
+    for check in flaky {
+        let mut spans = vec![
+            StyledSpan::text(format!("  {}", check.name), theme.text_primary),
+            StyledSpan::text(format!("  {}", check.reason), theme.text_faint),
+        ];
+        if check.failing && check.job_id.is_some() {
+            spans.push(StyledSpan::text("  (e: re-run job)", theme.text_warning));
+        }
+        lines.push(StyledLine::from_spans(spans));
+    }
+    lines.push(StyledLine::blank());
+    lines
+}
+
+/// Merge readiness shown above the checks: the PR's merge queue entry and
+/// what still blocks merging under the base branch rules. Empty until the
+/// detail is loaded, and for closed or merged PRs.
+pub fn render_merge_status(
+    pr: &PullRequest,
+    detail: Option<&PrDetail>,
+    theme: &ResolvedTheme,
+) -> Vec<StyledLine> {
+    let Some(detail) = detail else {
+        return Vec::new();
+    };
+    if pr.state != PrState::Open {
+        return Vec::new();
+    }
+    let mut lines = vec![StyledLine::from_span(StyledSpan::bold(
+        "Merge status",
+        theme.text_primary,
+    ))];
+    let req = &detail.merge_requirements;
+    if let Some(entry) = &req.queue_entry {
+        let eta = entry
+            .estimated_secs
+            .map(|secs| format!(" · ~{}m", secs.div_ceil(60)))
+            .unwrap_or_default();
+        let text = format!(
+            "In merge queue: position {} · {}{eta}",
+            entry.position,
+            entry.state.label()
+        );
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            text,
+            theme.text_warning,
+        )));
+    }
+    let blockers = detail.merge_blockers(pr);
+    if blockers.is_empty() {
+        let text = if req.merge_queue_enabled && req.queue_entry.is_none() {
+            "Ready to merge (via the merge queue)"
+        } else {
+            "Ready to merge"
+        };
+        lines.push(StyledLine::from_spans(vec![
+            StyledSpan::text(
+                format!("{} ", theme.icons.check_success),
+                theme.text_success,
+            ),
+            StyledSpan::text(text, theme.text_success),
+        ]));
+    }
+    for blocker in blockers {
+        lines.push(StyledLine::from_spans(vec![
+            StyledSpan::text(format!("{} ", theme.icons.check_failure), theme.text_error),
+            StyledSpan::text(blocker, theme.text_primary),
+        ]));
+    }
+    lines.push(StyledLine::blank());
     lines
 }
 
 /// Group check runs by `workflow_name`, keeping insertion order.
-/// The `None`-keyed group (non-Actions checks) is placed last.
-fn group_checks_by_workflow(checks: &[CheckRun]) -> Vec<(Option<String>, Vec<&CheckRun>)> {
-    let mut groups: Vec<(Option<String>, Vec<&CheckRun>)> = Vec::new();
-    for check in checks {
+/// The `None`-keyed group (non-Actions checks) is placed after the named
+/// ones; groups holding required checks, and required checks within each
+/// group, come first.
+fn group_checks_by_workflow(checks: &[CheckRun]) -> Vec<CheckGroup<'_>> {
+    let mut groups: Vec<CheckGroup<'_>> = Vec::new();
+    for (i, check) in checks.iter().enumerate() {
         let key = &check.workflow_name;
         if let Some(pos) = groups.iter().position(|(k, _)| k == key) {
-            groups[pos].1.push(check);
+            groups[pos].1.push((i, check));
         } else {
-            groups.push((key.clone(), vec![check]));
+            groups.push((key.clone(), vec![(i, check)]));
         }
     }
     // Sort named groups alphabetically (None < Some, so None goes first).
@@ -347,9 +620,30 @@ fn group_checks_by_workflow(checks: &[CheckRun]) -> Vec<(Option<String>, Vec<&Ch
         let none_group = groups.remove(pos);
         groups.push(none_group);
     }
+
+    for (_, checks) in &mut groups {
+        checks.sort_by_key(|(_, c)| !c.is_required);
+    }
+    groups.sort_by_key(|(_, checks)| !checks.iter().any(|(_, c)| c.is_required));
     groups
 }
 
+/// A workflow's name and its checks, each with its index.
+type CheckGroup<'a> = (Option<String>, Vec<(usize, &'a CheckRun)>);
+
+/// Badge of a commit's signature verification.
+pub(crate) fn commit_signature_icon(
+    signature: &CommitSignature,
+    theme: &ResolvedTheme,
+) -> (String, AppColor) {
+    let icons = &theme.icons;
+    match signature {
+        CommitSignature::Verified => (icons.commit_verified.clone(), theme.text_success),
+        CommitSignature::Unverified { .. } => (icons.commit_unverified.clone(), theme.text_warning),
+        CommitSignature::Unsigned => (icons.commit_unsigned.clone(), theme.text_faint),
+    }
+}
+
 fn commit_check_state_icon(state: CommitCheckState, theme: &ResolvedTheme) -> (String, AppColor) {
     let icons = &theme.icons;
     match state {
@@ -397,14 +691,23 @@ fn check_status_icon(
 
 /// Render the Files Changed tab: list of files with stats.
 ///
+/// `viewed` holds the review state of each of `detail.files` (files past
+/// its end use their GitHub state); files still to review come first, under
+/// a viewed count.
+///
 /// `sidebar_width` is the total sidebar width in columns (including border,
 /// padding, and scrollbar). When provided, paths that would push the stats
 /// columns beyond the sidebar edge are truncated with `…`.
+///
+/// With a `selected` file (an index into `detail.files`), returns the line
+/// marked with the selection cursor too.
 pub fn render_files(
     detail: &PrDetail,
+    viewed: &[FileViewedState],
     theme: &ResolvedTheme,
     sidebar_width: u16,
-) -> Vec<StyledLine> {
+    selected: Option<usize>,
+) -> (Vec<StyledLine>, Option<usize>) {
     let mut lines = Vec::new();
 
     if detail.files.is_empty() {
@@ -412,11 +715,30 @@ pub fn render_files(
             "(no files changed)",
             theme.text_faint,
         )));
-        return lines;
+        return (lines, None);
     }
 
-    // Content width = sidebar minus left border (1) + padding (2) + scrollbar (1).
-    let content_width = usize::from(sidebar_width).saturating_sub(4).max(1);
+    let owners = detail.code_owner_approvals();
+    lines.extend(code_owners_summary(&owners, theme));
+
+    let states: Vec<FileViewedState> = detail
+        .files
+        .iter()
+        .enumerate()
+        .map(|(i, f)| viewed.get(i).copied().unwrap_or(f.viewer_state))
+        .collect();
+    lines.push(viewed_count(&states, theme));
+    let marker_width = [FileViewedState::Viewed, FileViewedState::Dismissed]
+        .into_iter()
+        .map(|s| UnicodeWidthStr::width(viewed_marker(s, theme).0))
+        .max()
+        .unwrap_or(0);
+
+    // Content width = sidebar minus left border (1) + padding (2) + scrollbar (1),
+    // and the selection cursor.
+    let content_width = usize::from(sidebar_width)
+        .saturating_sub(4 + selection_width(selected, theme))
+        .max(1);
 
     // Pre-compute column widths so both +N and -N are right-aligned.
     let max_add_width = detail
@@ -432,9 +754,9 @@ pub fn render_files(
         .max()
         .unwrap_or(2);
 
-    // Fixed overhead: status letter + space (2), min gap (1), space between
-    // stats (1), plus the two stat columns.
-    let fixed_cols = 2 + 1 + max_add_width + 1 + max_del_width;
+    // Fixed overhead: viewed marker + space, status letter + space (2), min
+    // gap (1), space between stats (1), plus the two stat columns.
+    let fixed_cols = marker_width + 1 + 2 + 1 + max_add_width + 1 + max_del_width;
     let path_budget = content_width.saturating_sub(fixed_cols);
 
     // Natural alignment: use the longest path width, but cap to the budget so
@@ -447,63 +769,213 @@ pub fn render_files(
         .unwrap_or(0);
     let path_col_width = natural_max.min(path_budget);
 
-    for file in &detail.files {
-        let change = match file.status {
-            Some(FileChangeType::Added) => "A",
-            Some(FileChangeType::Deleted) => "D",
-            Some(FileChangeType::Modified) => "M",
-            Some(FileChangeType::Renamed) => "R",
-            Some(FileChangeType::Copied) => "C",
-            _ => "?",
-        };
-        let change_color = match file.status {
-            Some(FileChangeType::Added) => theme.text_success,
-            Some(FileChangeType::Deleted) => theme.text_error,
-            _ => theme.text_warning,
-        };
+    let mut selected_line = None;
+    for i in crate::viewed_files::review_order(&states) {
+        let file = &detail.files[i];
+        if selected == Some(i) {
+            selected_line = Some(lines.len());
+        }
+        lines.push(file_row(
+            file,
+            states[i],
+            &FileColumns {
+                marker: marker_width,
+                path: path_col_width,
+                additions: max_add_width,
+                deletions: max_del_width,
+            },
+            theme,
+        ));
+        if !owners.is_empty() {
+            lines.push(file_owners_line(
+                detail.code_owners.owners_for(&file.path),
+                &owners,
+                theme,
+            ));
+        }
+    }
+    lines.extend(page_footer(
+        detail.files.len(),
+        &detail.files_page,
+        "files",
+        theme,
+    ));
 
-        let path_w = UnicodeWidthStr::width(file.path.as_str());
-        let (display_path, display_w) = if path_w > path_col_width {
-            truncate_with_ellipsis(&file.path, path_col_width)
-        } else {
-            (file.path.clone(), path_w)
-        };
-        let pad = path_col_width.saturating_sub(display_w) + 1; // +1 = min gap
+    if selected.is_some() {
+        mark_selection(&mut lines, selected_line, theme);
+    }
+    (lines, selected_line)
+}
 
-        lines.push(StyledLine::from_spans(vec![
-            StyledSpan::text(format!("{change} "), change_color),
-            StyledSpan::text(display_path, theme.text_primary),
-            StyledSpan::text(
-                format!(
-                    "{:pad$}{:>width$}",
-                    "",
-                    format!("+{}", file.additions),
-                    pad = pad,
-                    width = max_add_width
-                ),
-                theme.text_success,
+/// Column widths shared by the rows of the Files tab.
+struct FileColumns {
+    marker: usize,
+    path: usize,
+    additions: usize,
+    deletions: usize,
+}
+
+/// One changed file: viewed marker, change letter, path and `+N -N` stats.
+fn file_row(
+    file: &crate::types::File,
+    state: FileViewedState,
+    cols: &FileColumns,
+    theme: &ResolvedTheme,
+) -> StyledLine {
+    let (mark, mark_color) = viewed_marker(state, theme);
+    let mark_pad = cols.marker.saturating_sub(UnicodeWidthStr::width(mark)) + 1;
+    let (change, change_color) = change_marker(file.status, theme);
+
+    let path_w = UnicodeWidthStr::width(file.path.as_str());
+    let (display_path, display_w) = if path_w > cols.path {
+        truncate_with_ellipsis(&file.path, cols.path, theme.chrome)
+    } else {
+        (file.path.clone(), path_w)
+    };
+    let pad = cols.path.saturating_sub(display_w) + 1; // +1 = min gap
+
+    let path_color = if state == FileViewedState::Viewed {
+        theme.text_faint
+    } else {
+        theme.text_primary
+    };
+
+    StyledLine::from_spans(vec![
+        StyledSpan::text(format!("{mark}{:mark_pad$}", ""), mark_color),
+        StyledSpan::text(format!("{change} "), change_color),
+        StyledSpan::text(display_path, path_color),
+        StyledSpan::text(
+            format!(
+                "{:pad$}{:>width$}",
+                "",
+                format!("+{}", file.additions),
+                pad = pad,
+                width = cols.additions
             ),
-            StyledSpan::text(
-                format!(
-                    " {:>width$}",
-                    format!("-{}", file.deletions),
-                    width = max_del_width
-                ),
-                theme.text_error,
+            theme.text_success,
+        ),
+        StyledSpan::text(
+            format!(
+                " {:>width$}",
+                format!("-{}", file.deletions),
+                width = cols.deletions
             ),
-        ]));
+            theme.text_error,
+        ),
+    ])
+}
+
+/// Status letter of a changed file and its color.
+fn change_marker(
+    status: Option<FileChangeType>,
+    theme: &ResolvedTheme,
+) -> (&'static str, AppColor) {
+    let letter = match status {
+        Some(FileChangeType::Added) => "A",
+        Some(FileChangeType::Deleted) => "D",
+        Some(FileChangeType::Modified) => "M",
+        Some(FileChangeType::Renamed) => "R",
+        Some(FileChangeType::Copied) => "C",
+        _ => "?",
+    };
+    let color = match status {
+        Some(FileChangeType::Added) => theme.text_success,
+        Some(FileChangeType::Deleted) => theme.text_error,
+        _ => theme.text_warning,
+    };
+    (letter, color)
+}
+
+/// "Viewed N/M" heading of the file list.
+fn viewed_count(states: &[FileViewedState], theme: &ResolvedTheme) -> StyledLine {
+    let done = states
+        .iter()
+        .filter(|s| **s == FileViewedState::Viewed)
+        .count();
+    let color = if done == states.len() {
+        theme.text_success
+    } else {
+        theme.text_primary
+    };
+    StyledLine::from_span(StyledSpan::bold(
+        format!("Viewed {done}/{}", states.len()),
+        color,
+    ))
+}
+
+/// Marker of a file row: a check once viewed, pending once changed since.
+fn viewed_marker(state: FileViewedState, theme: &ResolvedTheme) -> (&str, AppColor) {
+    match state {
+        FileViewedState::Viewed => (theme.icons.check_success.as_str(), theme.text_success),
+        FileViewedState::Dismissed => (theme.icons.check_pending.as_str(), theme.text_warning),
+        FileViewedState::Unviewed => ("", theme.text_faint),
     }
+}
 
+/// "Code owners" block: one line per owner of the changed files with its
+/// approval state and file count. Empty without a `CODEOWNERS` match.
+fn code_owners_summary(owners: &[OwnerApproval], theme: &ResolvedTheme) -> Vec<StyledLine> {
+    if owners.is_empty() {
+        return Vec::new();
+    }
+    let pending = owners.iter().filter(|o| !o.approved).count();
+    let heading = if pending == 0 {
+        "Code owners (all approved)".to_owned()
+    } else {
+        format!("Code owners ({pending} pending)")
+    };
+    let mut lines = vec![StyledLine::from_span(StyledSpan::bold(
+        heading,
+        theme.text_primary,
+    ))];
+    for owner in owners {
+        let (icon, color) = owner_approval_icon(owner.approved, theme);
+        let noun = if owner.files == 1 { "file" } else { "files" };
+        lines.push(StyledLine::from_spans(vec![
+            StyledSpan::text(format!("{icon} "), color),
+            StyledSpan::text(owner.owner.clone(), theme.text_actor),
+            StyledSpan::text(format!("  {} {noun}", owner.files), theme.text_faint),
+        ]));
+    }
+    lines.push(StyledLine::blank());
     lines
 }
 
+/// Owners of one file under its stat line, each tinted by approval.
+fn file_owners_line(
+    file_owners: &[String],
+    approvals: &[OwnerApproval],
+    theme: &ResolvedTheme,
+) -> StyledLine {
+    if file_owners.is_empty() {
+        return StyledLine::from_span(StyledSpan::text("  (no owner)", theme.text_faint));
+    }
+    let mut spans = vec![StyledSpan::text(" ", theme.text_faint)];
+    for owner in file_owners {
+        let approved = approvals.iter().any(|a| a.owner == *owner && a.approved);
+        let (_, color) = owner_approval_icon(approved, theme);
+        spans.push(StyledSpan::text(format!(" {owner}"), color));
+    }
+    StyledLine::from_spans(spans)
+}
+
+fn owner_approval_icon(approved: bool, theme: &ResolvedTheme) -> (String, AppColor) {
+    if approved {
+        (theme.icons.review_approved.clone(), theme.text_success)
+    } else {
+        (theme.icons.review_required.clone(), theme.text_warning)
+    }
+}
+
 /// Truncate a string to fit within `max_width` display columns, appending `…`
-/// if truncation occurs. Returns `(truncated_string, display_width)`.
-fn truncate_with_ellipsis(s: &str, max_width: usize) -> (String, usize) {
+/// (`...` in ASCII chrome) if truncation occurs. Returns
+/// `(truncated_string, display_width)`.
+fn truncate_with_ellipsis(s: &str, max_width: usize, chrome: Chrome) -> (String, usize) {
     if max_width == 0 {
         return (String::new(), 0);
     }
-    let ellipsis_w = 1; // `…` is 1 column wide
+    let ellipsis = crate::components::ellipsis(chrome);
+    let ellipsis_w = UnicodeWidthStr::width(ellipsis);
     let target = max_width.saturating_sub(ellipsis_w);
     let mut buf = String::new();
     let mut w = 0;
@@ -515,7 +987,7 @@ fn truncate_with_ellipsis(s: &str, max_width: usize) -> (String, usize) {
         buf.push(ch);
         w += cw;
     }
-    buf.push('…');
+    buf.push_str(ellipsis);
     w += ellipsis_w;
     (buf, w)
 }
@@ -543,3 +1015,178 @@ pub fn render_issue_activity(
     }
     lines
 }
+
+// ---------------------------------------------------------------------------
+// Links tab (issues)
+// ---------------------------------------------------------------------------
+
+/// Render the Links tab: the parent, the issue and its sub-issues as a tree,
+/// then the issues blocking it and those it blocks.
+///
+/// Returns the lines and the line of the `selected` node, an index into
+/// [`IssueLinks::nodes`], which is marked with the selection cursor.
+pub fn render_issue_links(
+    issue: &Issue,
+    links: &IssueLinks,
+    selected: Option<usize>,
+    theme: &ResolvedTheme,
+) -> (Vec<StyledLine>, Option<usize>) {
+    let mut out = LinkLines {
+        lines: Vec::new(),
+        selected,
+        selected_line: None,
+        next_node: 0,
+        home: issue.repo.as_ref(),
+        theme,
+    };
+    if let Some(parent) = &links.parent {
+        out.push_node("", parent);
+    }
+    let (self_prefix, indent) = if links.parent.is_some() {
+        (super::tree_branch(theme.chrome, true), "   ")
+    } else {
+        ("", "")
+    };
+    out.push_self(self_prefix, issue);
+    for (i, child) in links.children.iter().enumerate() {
+        let branch = super::tree_branch(theme.chrome, i + 1 == links.children.len());
+        out.push_node(&format!("{indent}{branch}"), child);
+    }
+    if let Some(summary) = closed_summary(&links.children) {
+        out.lines.push(StyledLine::from_span(StyledSpan::text(
+            format!("{indent}{summary}"),
+            theme.text_faint,
+        )));
+    }
+    for (title, group) in [("Blocked by", &links.blocked_by), ("Blocks", &links.blocks)] {
+        if group.is_empty() {
+            continue;
+        }
+        out.lines.push(StyledLine::blank());
+        out.lines.push(StyledLine::from_span(StyledSpan::bold(
+            title,
+            theme.text_secondary,
+        )));
+        for link in group {
+            out.push_node("  ", link);
+        }
+    }
+    if links.is_empty() {
+        out.lines.push(StyledLine::blank());
+        out.lines.push(StyledLine::from_span(StyledSpan::text(
+            "(no parent, sub-issues or dependencies)",
+            theme.text_faint,
+        )));
+    }
+    (out.lines, out.selected_line)
+}
+
+struct LinkLines<'a> {
+    lines: Vec<StyledLine>,
+    selected: Option<usize>,
+    selected_line: Option<usize>,
+    next_node: usize,
+    home: Option<&'a crate::types::RepoRef>,
+    theme: &'a ResolvedTheme,
+}
+
+impl LinkLines<'_> {
+    fn push_node(&mut self, prefix: &str, link: &IssueLink) {
+        let is_selected = self.selected == Some(self.next_node);
+        self.next_node += 1;
+        if is_selected {
+            self.selected_line = Some(self.lines.len());
+        }
+        let theme = self.theme;
+        let mut spans = vec![self.cursor(is_selected)];
+        spans.push(StyledSpan::text(prefix, theme.text_faint));
+        spans.push(issue_state_icon(link.state, theme));
+        spans.push(StyledSpan::bold(
+            link.short_ref(self.home),
+            theme.text_secondary,
+        ));
+        if !link.title.is_empty() {
+            spans.push(StyledSpan::text(
+                format!(" {}", link.title),
+                theme.text_primary,
+            ));
+        }
+        if link.sub_issue_count > 0 {
+            let noun = if link.sub_issue_count == 1 {
+                "sub-issue"
+            } else {
+                "sub-issues"
+            };
+            spans.push(StyledSpan::text(
+                format!(" \u{b7} {} {noun}", link.sub_issue_count),
+                theme.text_faint,
+            ));
+        }
+        self.lines.push(StyledLine::from_spans(spans));
+    }
+
+    /// The issue whose links these are, which is not a selectable node.
+    fn push_self(&mut self, prefix: &str, issue: &Issue) {
+        let theme = self.theme;
+        self.lines.push(StyledLine::from_spans(vec![
+            self.cursor(false),
+            StyledSpan::text(prefix, theme.text_faint),
+            issue_state_icon(Some(issue.state), theme),
+            StyledSpan::bold(
+                format!("#{} {}", issue.number, issue.title),
+                theme.text_primary,
+            ),
+        ]));
+    }
+
+    fn cursor(&self, selected: bool) -> StyledSpan {
+        selection_cursor(selected, self.theme)
+    }
+}
+
+/// Selection cursor, or as many blanks as it is wide.
+pub(crate) fn selection_cursor(selected: bool, theme: &ResolvedTheme) -> StyledSpan {
+    let marker = format!("{} ", theme.icons.select_cursor);
+    if selected {
+        StyledSpan::bold(marker, theme.text_primary)
+    } else {
+        StyledSpan::text(" ".repeat(marker.width()), theme.text_faint)
+    }
+}
+
+/// Columns taken by the selection cursor while there is a `selected` item.
+fn selection_width(selected: Option<usize>, theme: &ResolvedTheme) -> usize {
+    selected.map_or(0, |_| selection_cursor(false, theme).text.width())
+}
+
+/// Prefix every line with the selection cursor on the `selected` one, or
+/// with blanks, so the rows of a focused sidebar stay aligned.
+pub(crate) fn mark_selection(
+    lines: &mut [StyledLine],
+    selected: Option<usize>,
+    theme: &ResolvedTheme,
+) {
+    for (i, line) in lines.iter_mut().enumerate() {
+        line.spans
+            .insert(0, selection_cursor(selected == Some(i), theme));
+    }
+}
+
+fn issue_state_icon(state: Option<IssueState>, theme: &ResolvedTheme) -> StyledSpan {
+    let (icon, color) = match state {
+        Some(IssueState::Open) => (&theme.icons.issue_open, theme.text_success),
+        Some(IssueState::Closed) => (&theme.icons.issue_closed, theme.text_faint),
+        Some(IssueState::Unknown) | None => (&theme.icons.check_pending, theme.text_faint),
+    };
+    StyledSpan::text(format!("{icon} "), color)
+}
+
+/// "2 of 3 closed" over the children whose state is known.
+fn closed_summary(children: &[IssueLink]) -> Option<String> {
+    let known: Vec<IssueState> = children.iter().filter_map(|c| c.state).collect();
+    if known.is_empty() {
+        return None;
+    }
+    let closed = known.iter().filter(|&&s| s == IssueState::Closed).count();
+    Some(format!("{closed} of {} closed", known.len()))
+}
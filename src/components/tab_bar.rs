@@ -1,6 +1,9 @@
+use crossterm::event::MouseButton;
 use iocraft::prelude::*;
+use unicode_width::UnicodeWidthStr;
 
 use crate::color::{Color as AppColor, ColorDepth};
+use crate::theme::Chrome;
 
 // ---------------------------------------------------------------------------
 // TabBar component
@@ -12,6 +15,7 @@ pub struct TabBarColors {
     pub active: Option<AppColor>,
     pub inactive: Option<AppColor>,
     pub border: Option<AppColor>,
+    pub chrome: Chrome,
 }
 
 /// A single tab definition.
@@ -19,6 +23,8 @@ pub struct TabBarColors {
 pub struct Tab {
     pub title: String,
     pub count: Option<usize>,
+    /// Items loaded but hidden by a client-side toggle (0 = none).
+    pub hidden: usize,
     pub is_ephemeral: bool,
 }
 
@@ -28,6 +34,11 @@ pub struct RenderedTabBar {
     pub active_fg: Color,
     pub inactive_fg: Color,
     pub border_fg: Color,
+    pub border_style: BorderStyle,
+    /// Trailing note after the tabs, e.g. an active quick filter.
+    pub badge: Option<String>,
+    /// Right-aligned countdown to the active tab's next auto-refresh.
+    pub countdown: Option<String>,
 }
 
 pub struct RenderedTab {
@@ -76,14 +87,19 @@ impl RenderedTabBar {
                 } else {
                     icon_prefix.as_str()
                 };
-                let label = if show_count {
-                    if let Some(count) = tab.count {
-                        format!(" {prefix}{} ({}) ", tab.title, count)
-                    } else {
-                        format!(" {prefix}{} ", tab.title)
-                    }
+                // Plain chrome marks the active tab in text, not just color.
+                let marker = if colors.chrome == Chrome::Plain && i == active {
+                    ">"
                 } else {
-                    format!(" {prefix}{} ", tab.title)
+                    " "
+                };
+                let label = match (show_count.then_some(tab.count).flatten(), tab.hidden) {
+                    (Some(count), 0) => format!("{marker}{prefix}{} ({count}) ", tab.title),
+                    (Some(count), hidden) => {
+                        format!("{marker}{prefix}{} ({count}, {hidden} hidden) ", tab.title)
+                    }
+                    (None, 0) => format!("{marker}{prefix}{} ", tab.title),
+                    (None, hidden) => format!("{marker}{prefix}{} ({hidden} hidden) ", tab.title),
                 };
                 RenderedTab {
                     label,
@@ -97,17 +113,55 @@ impl RenderedTabBar {
             active_fg,
             inactive_fg,
             border_fg,
+            border_style: super::border(colors.chrome, BorderStyle::Single),
+            badge: None,
+            countdown: None,
         }
     }
+
+    /// Show `badge` after the tabs.
+    #[must_use]
+    pub fn with_badge(mut self, badge: Option<String>) -> Self {
+        self.badge = badge;
+        self
+    }
+
+    /// Show `countdown` at the right end of the bar.
+    #[must_use]
+    pub fn with_countdown(mut self, countdown: Option<String>) -> Self {
+        self.countdown = countdown;
+        self
+    }
 }
 
 #[derive(Default, Props)]
 pub struct TabBarProps {
     pub tab_bar: Option<RenderedTabBar>,
+    /// Set to the index of a clicked tab; `None` ignores clicks.
+    pub on_click: Option<State<Option<usize>>>,
 }
 
 #[component]
-pub fn TabBar(props: &mut TabBarProps) -> impl Into<AnyElement<'static>> {
+pub fn TabBar(props: &mut TabBarProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    // Each label has a column of padding after it.
+    let widths: Vec<usize> = props.tab_bar.as_ref().map_or_else(Vec::new, |tb| {
+        tb.tabs.iter().map(|tab| tab.label.width() + 1).collect()
+    });
+    let on_click = props.on_click;
+    hooks.use_local_terminal_events(move |event| {
+        if let TerminalEvent::FullscreenMouse(FullscreenMouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 0,
+            ..
+        }) = event
+            && let Some(mut on_click) = on_click
+            && let Some(tab) = super::item_at(&widths, column)
+        {
+            on_click.set(Some(tab));
+        }
+    });
+
     let Some(tb) = props.tab_bar.take() else {
         return element! { View }.into_any();
     };
@@ -117,7 +171,7 @@ pub fn TabBar(props: &mut TabBarProps) -> impl Into<AnyElement<'static>> {
 
     element! {
         View(
-            border_style: BorderStyle::Single,
+            border_style: tb.border_style,
             border_edges: Edges::Bottom,
             border_color: tb.border_fg,
             padding_left: 1,
@@ -135,6 +189,16 @@ pub fn TabBar(props: &mut TabBarProps) -> impl Into<AnyElement<'static>> {
                     }
                 }
             }))
+            #(tb.badge.map(|badge| element! {
+                View(padding_left: 1) {
+                    Text(content: format!("[{badge}]"), color: active_fg, wrap: TextWrap::NoWrap)
+                }
+            }))
+            #(tb.countdown.map(|countdown| element! {
+                View(flex_grow: 1.0_f32, justify_content: JustifyContent::End, padding_right: 1) {
+                    Text(content: countdown, color: inactive_fg, wrap: TextWrap::NoWrap)
+                }
+            }))
         }
     }
     .into_any()
@@ -1,10 +1,13 @@
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
+use crossterm::event::MouseButton;
 use iocraft::prelude::*;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::color::{Color as AppColor, ColorDepth};
 use crate::components::scrollbar::{ScrollInfo, Scrollbar};
+use crate::theme::Chrome;
 
 /// Dashed horizontal line for row separators (┈ U+2504).
 const DASHED_ROW_SEP: BorderStyle = BorderStyle::Custom(BorderCharacters {
@@ -38,6 +41,9 @@ pub struct Column {
     /// instead of scaling proportionally. Remaining space goes to flexible
     /// columns.
     pub fixed_width: Option<u16>,
+    /// Direction a sort by this column starts in; `None` for columns that
+    /// can't be sorted by.
+    pub sort: Option<SortDirection>,
 }
 
 /// A single styled fragment within a cell.
@@ -52,6 +58,8 @@ pub struct Span {
 #[derive(Debug, Clone)]
 pub struct Cell {
     pub spans: Vec<Span>,
+    /// Background behind the cell (row tint in the [`TINT_CELL`] slot).
+    pub bg: Option<AppColor>,
 }
 
 impl Cell {
@@ -62,6 +70,7 @@ impl Cell {
                 color: None,
                 bold: false,
             }],
+            bg: None,
         }
     }
 
@@ -72,6 +81,7 @@ impl Cell {
                 color: Some(color),
                 bold: false,
             }],
+            bg: None,
         }
     }
 
@@ -82,11 +92,18 @@ impl Cell {
                 color: None,
                 bold: true,
             }],
+            bg: None,
         }
     }
 
     pub fn from_spans(spans: Vec<Span>) -> Self {
-        Self { spans }
+        Self { spans, bg: None }
+    }
+
+    #[must_use]
+    pub fn with_bg(mut self, color: AppColor) -> Self {
+        self.bg = Some(color);
+        self
     }
 
     /// Concatenate all span texts into a single string (for filtering).
@@ -102,6 +119,130 @@ impl Cell {
 /// A complete row of cells indexed by column id.
 pub type Row = HashMap<String, Cell>;
 
+/// Row slot whose cell background tints the whole row when it is not
+/// selected; never rendered as a column.
+pub const TINT_CELL: &str = "tint";
+
+// ---------------------------------------------------------------------------
+// Sorting
+// ---------------------------------------------------------------------------
+
+/// Direction of a column sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    #[must_use]
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    fn indicator(self, chrome: Chrome) -> &'static str {
+        match (self, chrome.ascii_only()) {
+            (Self::Ascending, false) => "\u{25b2}",  // ▲
+            (Self::Descending, false) => "\u{25bc}", // ▼
+            (Self::Ascending, true) => "^",
+            (Self::Descending, true) => "v",
+        }
+    }
+}
+
+/// The column a table is sorted by. Tables without one keep the order the
+/// API returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableSort {
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+impl TableSort {
+    /// The sort after `current` when cycling through the sortable `columns`:
+    /// the next one in its own direction, and API order after the last.
+    pub fn next(current: Option<&Self>, columns: &[&Column]) -> Option<Self> {
+        let sortable = columns.iter().filter_map(|c| Some((&c.id, c.sort?)));
+        let mut rest = sortable.skip_while(|(id, _)| current.is_some_and(|s| &s.column != *id));
+        if current.is_some() {
+            rest.next();
+        }
+        rest.next().map(|(id, direction)| Self {
+            column: id.clone(),
+            direction,
+        })
+    }
+
+    /// `current` in the other direction, or the first sortable column of
+    /// `columns` in its own when there is no sort.
+    pub fn reversed(current: Option<&Self>, columns: &[&Column]) -> Option<Self> {
+        match current {
+            Some(sort) => Some(Self {
+                column: sort.column.clone(),
+                direction: sort.direction.reversed(),
+            }),
+            None => Self::next(None, columns),
+        }
+    }
+}
+
+/// `column`'s header, marked with the direction when the rows are sorted by
+/// it.
+fn header_text(column: &Column, sort: Option<&TableSort>, chrome: Chrome) -> String {
+    match sort {
+        Some(sort) if sort.column == column.id => {
+            format!("{} {}", column.header, sort.direction.indicator(chrome))
+        }
+        _ => column.header.clone(),
+    }
+}
+
+/// A value rows are sorted by.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SortValue {
+    Number(i64),
+    Text(String),
+}
+
+/// Stably reorder `indices` (into `rows`) by `sort`.
+///
+/// `value` gives the value of row `i` for a column when its cell text does
+/// not sort (counts, dates, status icons); the lowercased cell text is used
+/// otherwise. Rows with no value, or an empty cell, stay last either way.
+pub fn sort_indices(
+    indices: &mut [usize],
+    rows: &[Row],
+    sort: Option<&TableSort>,
+    value: impl Fn(usize, &str) -> Option<SortValue>,
+) {
+    let Some(sort) = sort else {
+        return;
+    };
+    let key = |i: usize| {
+        value(i, &sort.column).or_else(|| {
+            let text = rows.get(i)?.get(&sort.column)?.text();
+            let text = text.trim();
+            (!text.is_empty()).then(|| SortValue::Text(text.to_lowercase()))
+        })
+    };
+    let mut keyed: Vec<(Option<SortValue>, usize)> = indices.iter().map(|&i| (key(i), i)).collect();
+    keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+        (Some(a), Some(b)) => match sort.direction {
+            SortDirection::Ascending => a.cmp(b),
+            SortDirection::Descending => b.cmp(a),
+        },
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    for (slot, (_, i)) in indices.iter_mut().zip(keyed) {
+        *slot = i;
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Pre-rendered table data (all owned)
 // ---------------------------------------------------------------------------
@@ -128,6 +269,8 @@ pub struct RenderedTable {
     pub track_height: u32,
     /// Scrollbar thumb color (`header_fg`).
     pub scrollbar_thumb_fg: Color,
+    /// Chrome the table was built for (borders, wrapping, scrollbar glyphs).
+    pub chrome: Chrome,
 }
 
 pub struct HeaderCell {
@@ -154,6 +297,7 @@ pub struct RenderedCell {
     pub spans: Vec<RenderedSpan>,
     pub width: u32,
     pub align: TextAlign,
+    pub bg: Option<Color>,
 }
 
 /// Configuration for building a `RenderedTable`.
@@ -179,6 +323,10 @@ pub struct TableBuildConfig<'a> {
     pub row_separator: bool,
     /// Optional per-view accent color for the scrollbar thumb.
     pub scrollbar_thumb_color: Option<AppColor>,
+    /// `Chrome::Plain` linearizes every row into one labelled text line.
+    pub chrome: Chrome,
+    /// Column the rows are sorted by, marked in its header.
+    pub sort: Option<&'a TableSort>,
 }
 
 impl RenderedTable {
@@ -214,7 +362,7 @@ impl RenderedTable {
             .iter()
             .zip(col_widths.iter())
             .map(|(col, &w)| HeaderCell {
-                text: col.header.clone(),
+                text: header_text(col, cfg.sort, cfg.chrome),
                 width: u32::from(w),
                 align: col.align,
             })
@@ -231,6 +379,10 @@ impl RenderedTable {
             0
         };
 
+        if cfg.chrome == Chrome::Plain {
+            return Self::build_linear(cfg, &visible_columns, header_fg, border_fg);
+        }
+
         let body_rows = build_body_rows(
             rows,
             cfg.scroll_offset,
@@ -244,6 +396,7 @@ impl RenderedTable {
                 subtitle_padding,
                 subtitle_column,
                 depth,
+                ellipsis: crate::components::ellipsis(cfg.chrome),
             },
         );
 
@@ -299,6 +452,66 @@ impl RenderedTable {
             scrollbar_thumb_fg: cfg
                 .scrollbar_thumb_color
                 .map_or(header_fg, |c| c.to_crossterm_color(depth)),
+            chrome: cfg.chrome,
+        }
+    }
+
+    /// Plain-chrome variant: no header, separators or scrollbar; each visible
+    /// row becomes a single wrapping line of `Label: value` fields, with a
+    /// textual cursor marker so selection does not rely on the background.
+    fn build_linear(
+        cfg: &TableBuildConfig<'_>,
+        visible_columns: &[&Column],
+        header_fg: Color,
+        border_fg: Color,
+    ) -> Self {
+        let labels = linear_labels(visible_columns);
+        let end = (cfg.scroll_offset + cfg.visible_rows).min(cfg.rows.len());
+        let start = cfg.scroll_offset.min(end);
+        let body_rows = cfg.rows[start..end]
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let key = start + i;
+                RenderedRow {
+                    key,
+                    bg: None,
+                    cells: vec![RenderedCell {
+                        spans: linearize_row(
+                            row,
+                            visible_columns,
+                            &labels,
+                            cfg.subtitle_column,
+                            key == cfg.cursor,
+                            cfg.depth,
+                        ),
+                        width: u32::from(cfg.total_width.saturating_sub(1)),
+                        bg: None,
+                        align: TextAlign::Left,
+                    }],
+                    subtitle: None,
+                }
+            })
+            .collect();
+
+        Self {
+            header_cells: Vec::new(),
+            body_rows,
+            total_width: u32::from(cfg.total_width),
+            show_separator: false,
+            row_separator: false,
+            header_fg,
+            border_fg,
+            empty_message: if cfg.rows.is_empty() {
+                cfg.empty_message.map(String::from)
+            } else {
+                None
+            },
+            subtitle_padding: 0,
+            scroll_info: None,
+            track_height: 0,
+            scrollbar_thumb_fg: header_fg,
+            chrome: Chrome::Plain,
         }
     }
 }
@@ -307,14 +520,90 @@ impl RenderedTable {
 // ScrollableTable component
 // ---------------------------------------------------------------------------
 
+/// Longest gap between the clicks of a double-click.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// A left click on a body row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableClick {
+    /// Index of the row among all rows, like the cursor.
+    pub row: usize,
+    /// Second click on the same row in a short while.
+    pub double: bool,
+}
+
 #[derive(Default, Props)]
 pub struct ScrollableTableProps {
     /// Pre-rendered table data.
     pub table: Option<RenderedTable>,
+    /// Set to the clicked row; `None` ignores clicks.
+    pub on_click: Option<State<Option<TableClick>>>,
+}
+
+/// The row shown at `line` of the table, counted from its top, given the
+/// header's height and each visible row's key and height.
+fn row_at(header_lines: u16, rows: &[(usize, u16)], line: u16) -> Option<usize> {
+    let mut top = header_lines;
+    for &(key, height) in rows {
+        if line < top {
+            return None;
+        }
+        if line < top + height {
+            return Some(key);
+        }
+        top += height;
+    }
+    None
 }
 
 #[component]
-pub fn ScrollableTable(props: &mut ScrollableTableProps) -> impl Into<AnyElement<'static>> {
+pub fn ScrollableTable(
+    props: &mut ScrollableTableProps,
+    mut hooks: Hooks,
+) -> impl Into<AnyElement<'static>> {
+    let mut last_click = hooks.use_state(|| None::<(usize, Instant)>);
+    // Plain chrome wraps rows, so their lines are unknown.
+    let hits = props
+        .table
+        .as_ref()
+        .filter(|table| table.chrome != Chrome::Plain)
+        .map(|table| {
+            let count = table.body_rows.len();
+            let rows: Vec<(usize, u16)> = table
+                .body_rows
+                .iter()
+                .enumerate()
+                .map(|(ri, row)| {
+                    let separator = table.row_separator && ri + 1 < count;
+                    (
+                        row.key,
+                        1 + u16::from(row.subtitle.is_some()) + u16::from(separator),
+                    )
+                })
+                .collect();
+            (1 + u16::from(table.show_separator), rows)
+        });
+    let on_click = props.on_click;
+    hooks.use_local_terminal_events(move |event| {
+        if let TerminalEvent::FullscreenMouse(FullscreenMouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            row: line,
+            ..
+        }) = event
+            && let Some(mut on_click) = on_click
+            && let Some((header_lines, rows)) = &hits
+            && let Some(row) = row_at(*header_lines, rows, line)
+        {
+            let now = Instant::now();
+            let double = last_click
+                .get()
+                .is_some_and(|(last, at)| last == row && now - at < DOUBLE_CLICK);
+            // A third click starts over.
+            last_click.set((!double).then_some((row, now)));
+            on_click.set(Some(TableClick { row, double }));
+        }
+    });
+
     let Some(table) = props.table.take() else {
         return element! { View }.into_any();
     };
@@ -323,12 +612,27 @@ pub fn ScrollableTable(props: &mut ScrollableTableProps) -> impl Into<AnyElement
     let track_height = table.track_height;
     let track_color = table.border_fg;
     let thumb_color = table.scrollbar_thumb_fg;
+    let header_border = if table.show_separator {
+        super::border(table.chrome, BorderStyle::Single)
+    } else {
+        BorderStyle::None
+    };
+    let row_border = match table.chrome {
+        Chrome::Unicode => DASHED_ROW_SEP,
+        Chrome::Ascii | Chrome::Plain => super::border(table.chrome, BorderStyle::Single),
+    };
+    let ascii_scrollbar = table.chrome.ascii_only();
+    let row_wrap = if table.chrome == Chrome::Plain {
+        TextWrap::Wrap
+    } else {
+        TextWrap::NoWrap
+    };
 
     element! {
         View(flex_direction: FlexDirection::Column, width: table.total_width, padding_left: 1u32) {
             // Header row
             View(
-                border_style: if table.show_separator { BorderStyle::Single } else { BorderStyle::None },
+                border_style: header_border,
                 border_edges: Edges::Bottom,
                 border_color: table.border_fg,
             ) {
@@ -379,7 +683,7 @@ pub fn ScrollableTable(props: &mut ScrollableTableProps) -> impl Into<AnyElement
                             View(
                                 key: row.key,
                                 flex_direction: FlexDirection::Column,
-                                border_style: if row_sep && !is_last { DASHED_ROW_SEP } else { BorderStyle::None },
+                                border_style: if row_sep && !is_last { row_border } else { BorderStyle::None },
                                 border_edges: Edges::Bottom,
                                 border_color: sep_color,
                             ) {
@@ -394,10 +698,10 @@ pub fn ScrollableTable(props: &mut ScrollableTableProps) -> impl Into<AnyElement
                                                 .weight(s.weight)
                                         }).collect();
                                         element! {
-                                            View(key: ci, width: cell.width) {
+                                            View(key: ci, width: cell.width, background_color: cell.bg) {
                                                 MixedText(
                                                     contents,
-                                                    wrap: TextWrap::NoWrap,
+                                                    wrap: row_wrap,
                                                     align: cell.align,
                                                 )
                                             }
@@ -424,6 +728,7 @@ pub fn ScrollableTable(props: &mut ScrollableTableProps) -> impl Into<AnyElement
 
                 // Scrollbar (1 char wide, only when content overflows)
                 Scrollbar(
+                    ascii: ascii_scrollbar,
                     scroll_info: scroll_info,
                     track_height: track_height,
                     track_color: track_color,
@@ -447,6 +752,8 @@ struct BodyRowLayout<'a> {
     subtitle_padding: u32,
     subtitle_column: Option<&'a str>,
     depth: ColorDepth,
+    /// Marker appended to truncated cells.
+    ellipsis: &'a str,
 }
 
 /// Build the visible body rows from the full row slice, applying scroll offset,
@@ -472,7 +779,13 @@ fn build_body_rows(
         .map(|(i, row)| {
             let absolute_idx = scroll_offset + i;
             let is_selected = absolute_idx == cursor;
-            let bg = if is_selected { selected_bg_color } else { None };
+            let bg = if is_selected {
+                selected_bg_color
+            } else {
+                row.get(TINT_CELL)
+                    .and_then(|c| c.bg)
+                    .map(|c| c.to_crossterm_color(layout.depth))
+            };
 
             let cells: Vec<RenderedCell> = layout
                 .visible_columns
@@ -491,9 +804,12 @@ fn build_body_rows(
                         |c| render_spans(&c.spans, layout.depth),
                     );
                     RenderedCell {
-                        spans: truncate_spans(spans, usize::from(w)),
+                        spans: truncate_spans(spans, usize::from(w), layout.ellipsis),
                         width: u32::from(w),
                         align: col.align,
+                        bg: cell
+                            .and_then(|c| c.bg)
+                            .map(|c| c.to_crossterm_color(layout.depth)),
                     }
                 })
                 .collect();
@@ -503,12 +819,16 @@ fn build_body_rows(
                 .saturating_sub(layout.subtitle_padding as usize);
             let subtitle = layout.subtitle_column.and_then(|col_id| {
                 row.get(col_id).map(|cell| {
-                    let spans =
-                        truncate_spans(render_spans(&cell.spans, layout.depth), subtitle_available);
+                    let spans = truncate_spans(
+                        render_spans(&cell.spans, layout.depth),
+                        subtitle_available,
+                        layout.ellipsis,
+                    );
                     RenderedCell {
                         spans,
                         width: u32::from(layout.col_total_width),
                         align: TextAlign::Left,
+                        bg: None,
                     }
                 })
             });
@@ -523,6 +843,82 @@ fn build_body_rows(
         .collect()
 }
 
+// ---------------------------------------------------------------------------
+// Linearized rows (plain chrome)
+// ---------------------------------------------------------------------------
+
+/// Field labels for linearized rows. Glyph-only headers fall back to the
+/// column id, and headers shared by several columns are qualified with it.
+fn linear_labels(columns: &[&Column]) -> Vec<String> {
+    columns
+        .iter()
+        .map(|col| {
+            let header = col.header.trim();
+            if !header.chars().any(char::is_alphanumeric) {
+                col.id.replace('_', " ")
+            } else if columns.iter().filter(|c| c.header.trim() == header).count() > 1 {
+                format!("{header} ({})", col.id.replace('_', " "))
+            } else {
+                header.to_owned()
+            }
+        })
+        .collect()
+}
+
+/// Flatten one row into `Label: value; Label: value` spans. Empty cells are
+/// skipped; the subtitle (when configured) follows the first field unlabelled.
+fn linearize_row(
+    row: &Row,
+    columns: &[&Column],
+    labels: &[String],
+    subtitle_column: Option<&str>,
+    is_selected: bool,
+    depth: ColorDepth,
+) -> Vec<RenderedSpan> {
+    let plain = |text: String| RenderedSpan {
+        text,
+        fg: Color::Reset,
+        weight: Weight::Normal,
+    };
+    let mut fields = Vec::new();
+    for (idx, (col, label)) in columns.iter().zip(labels).enumerate() {
+        if let Some(cell) = row.get(&col.id)
+            && !cell.text().trim().is_empty()
+        {
+            fields.push((Some(label.as_str()), render_spans(&cell.spans, depth)));
+        }
+        if idx == 0
+            && let Some(sub) = subtitle_column.and_then(|id| row.get(id))
+            && !sub.text().trim().is_empty()
+        {
+            fields.push((None, render_spans(&sub.spans, depth)));
+        }
+    }
+    let mut spans = vec![plain(if is_selected { "> " } else { "  " }.to_owned())];
+    spans.extend(linearize_fields(fields, plain));
+    spans
+}
+
+/// Join `(label, value)` fields into `Label: value; Label: value` spans;
+/// unlabelled fields are emitted bare. `plain` builds the label and
+/// separator spans, so any span type can be linearized.
+pub(crate) fn linearize_fields<'a, S>(
+    fields: impl IntoIterator<Item = (Option<&'a str>, Vec<S>)>,
+    plain: impl Fn(String) -> S,
+) -> Vec<S> {
+    let mut spans = Vec::new();
+    for (idx, (label, value)) in fields.into_iter().enumerate() {
+        if idx > 0 {
+            spans.push(plain("; ".to_owned()));
+        }
+        if let Some(label) = label {
+            spans.push(plain(format!("{label}: ")));
+        }
+        spans.extend(value);
+    }
+    spans
+}
+
 // ---------------------------------------------------------------------------
 // Column width computation
 // ---------------------------------------------------------------------------
@@ -555,14 +951,14 @@ fn truncate_str_to_cols(s: &str, max_cols: usize) -> &str {
 }
 
 /// Truncate a list of rendered spans so that their total display width fits
-/// within `max_cols` columns. Appends `…` (U+2026) when truncation occurs.
-fn truncate_spans(spans: Vec<RenderedSpan>, max_cols: usize) -> Vec<RenderedSpan> {
+/// within `max_cols` columns. Appends `ellipsis` when truncation occurs.
+fn truncate_spans(spans: Vec<RenderedSpan>, max_cols: usize, ellipsis: &str) -> Vec<RenderedSpan> {
     let total: usize = spans.iter().map(|s| s.text.width()).sum();
     if total <= max_cols {
         return spans;
     }
-    // Reserve 3 columns: 1 for the ellipsis + 2 gap before the next column.
-    let budget = max_cols.saturating_sub(3);
+    // Reserve room for the ellipsis + 2 gap before the next column.
+    let budget = max_cols.saturating_sub(ellipsis.width() + 2);
     let mut used = 0usize;
     let mut result = Vec::new();
     for span in spans {
@@ -574,7 +970,7 @@ fn truncate_spans(spans: Vec<RenderedSpan>, max_cols: usize) -> Vec<RenderedSpan
             let remaining = budget.saturating_sub(used);
             let cut = truncate_str_to_cols(&span.text, remaining);
             result.push(RenderedSpan {
-                text: format!("{cut}\u{2026}"),
+                text: format!("{cut}{ellipsis}"),
                 fg: span.fg,
                 weight: span.weight,
             });
@@ -648,6 +1044,19 @@ fn compute_column_widths(
 mod tests {
     use super::*;
 
+    #[test]
+    fn clicks_map_to_the_row_under_them() {
+        // Header and its border, then rows of 3, 3 and 2 lines.
+        let rows = [(10, 3), (11, 3), (12, 2)];
+        assert_eq!(row_at(2, &rows, 0), None);
+        assert_eq!(row_at(2, &rows, 1), None);
+        assert_eq!(row_at(2, &rows, 2), Some(10));
+        assert_eq!(row_at(2, &rows, 4), Some(10));
+        assert_eq!(row_at(2, &rows, 5), Some(11));
+        assert_eq!(row_at(2, &rows, 9), Some(12));
+        assert_eq!(row_at(2, &rows, 10), None);
+    }
+
     fn make_columns() -> Vec<Column> {
         vec![
             Column {
@@ -656,6 +1065,7 @@ mod tests {
                 default_width_pct: 0.08,
                 align: TextAlign::Left,
                 fixed_width: None,
+                sort: None,
             },
             Column {
                 id: "title".to_owned(),
@@ -663,6 +1073,7 @@ mod tests {
                 default_width_pct: 0.50,
                 align: TextAlign::Left,
                 fixed_width: None,
+                sort: None,
             },
             Column {
                 id: "author".to_owned(),
@@ -670,6 +1081,7 @@ mod tests {
                 default_width_pct: 0.15,
                 align: TextAlign::Left,
                 fixed_width: None,
+                sort: None,
             },
             Column {
                 id: "updated".to_owned(),
@@ -677,6 +1089,7 @@ mod tests {
                 default_width_pct: 0.12,
                 align: TextAlign::Right,
                 fixed_width: None,
+                sort: None,
             },
         ]
     }
@@ -733,6 +1146,45 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn sort_cycles_columns_and_keeps_blanks_last() {
+        let mut cols = make_columns();
+        cols[1].sort = Some(SortDirection::Ascending);
+        cols[3].sort = Some(SortDirection::Descending);
+        let refs: Vec<&Column> = cols.iter().collect();
+        let title = TableSort::next(None, &refs).unwrap();
+        assert_eq!(title.column, "title");
+        let updated = TableSort::next(Some(&title), &refs).unwrap();
+        assert_eq!(updated.direction, SortDirection::Descending);
+        assert_eq!(TableSort::next(Some(&updated), &refs), None);
+        let reversed = TableSort::reversed(Some(&updated), &refs).unwrap();
+        assert_eq!(reversed.direction, SortDirection::Ascending);
+
+        let rows = vec![
+            make_row(&[("title", "beta")]),
+            make_row(&[("title", "")]),
+            make_row(&[("title", "Alpha")]),
+            make_row(&[("title", "gamma")]),
+        ];
+        let mut indices: Vec<usize> = (0..rows.len()).collect();
+        sort_indices(&mut indices, &rows, Some(&title), |_, _| None);
+        assert_eq!(indices, [2, 0, 3, 1]);
+        let descending = TableSort::reversed(Some(&title), &refs).unwrap();
+        sort_indices(&mut indices, &rows, Some(&descending), |_, _| None);
+        assert_eq!(indices, [3, 0, 2, 1]);
+
+        // Values given for a column win over its text; ties keep API order.
+        let ages = [Some(5), Some(9), None, Some(5)];
+        let mut indices: Vec<usize> = (0..rows.len()).collect();
+        sort_indices(&mut indices, &rows, Some(&updated), |i, _| {
+            ages[i].map(SortValue::Number)
+        });
+        assert_eq!(indices, [1, 0, 3, 2]);
+
+        let header = header_text(&cols[3], Some(&updated), Chrome::Ascii);
+        assert_eq!(header, "Updated v");
+    }
+
     #[test]
     fn build_body_rows_empty() {
         let cols = make_columns();
@@ -746,6 +1198,7 @@ mod tests {
             subtitle_padding: 0,
             subtitle_column: None,
             depth: ColorDepth::default(),
+            ellipsis: "\u{2026}",
         };
         let result = build_body_rows(&rows, 0, 10, 0, None, &layout);
         assert!(result.is_empty());
@@ -769,6 +1222,7 @@ mod tests {
             subtitle_padding: 0,
             subtitle_column: None,
             depth: ColorDepth::default(),
+            ellipsis: "\u{2026}",
         };
         let result = build_body_rows(&rows, 0, 10, 0, None, &layout);
         assert_eq!(result.len(), 1);
@@ -810,6 +1264,7 @@ mod tests {
             subtitle_padding: 0,
             subtitle_column: None,
             depth: ColorDepth::default(),
+            ellipsis: "\u{2026}",
         };
         let result = build_body_rows(&rows, 0, 10, 1, selected_bg, &layout);
         assert_eq!(result.len(), 3);
@@ -821,6 +1276,93 @@ mod tests {
         assert!(result[2].bg.is_none());
     }
 
+    #[test]
+    fn build_body_rows_tint_unless_selected() {
+        let cols = make_columns();
+        let col_refs: Vec<&Column> = cols.iter().collect();
+        let widths = compute_column_widths(&col_refs, None, 100);
+        let tint = AppColor::Ansi256(52);
+        let mut tinted = make_row(&[("state", "open"), ("title", "Urgent")]);
+        tinted.insert(TINT_CELL.to_owned(), Cell::plain("").with_bg(tint));
+        let mut state_only = make_row(&[("title", "Easy")]);
+        state_only.insert("state".to_owned(), Cell::plain("open").with_bg(tint));
+        let rows = vec![tinted.clone(), tinted, state_only];
+        let layout = BodyRowLayout {
+            visible_columns: &col_refs,
+            col_widths: &widths,
+            col_total_width: 100,
+            subtitle_padding: 0,
+            subtitle_column: None,
+            depth: ColorDepth::default(),
+            ellipsis: "\u{2026}",
+        };
+        let selected_bg = Some(Color::Grey);
+        let result = build_body_rows(&rows, 0, 10, 1, selected_bg, &layout);
+        let tint_bg = Some(tint.to_crossterm_color(ColorDepth::default()));
+        assert_eq!(result[0].bg, tint_bg);
+        assert_eq!(result[1].bg, selected_bg);
+        assert_eq!(result[2].bg, None);
+        assert_eq!(result[2].cells[0].bg, tint_bg);
+        // The tint slot is not a column.
+        assert_eq!(result[0].cells.len(), 4);
+    }
+
+    fn linear_text(spans: &[RenderedSpan]) -> String {
+        spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn linear_labels_fall_back_and_disambiguate() {
+        let mut cols = make_columns();
+        cols[0].header = "\u{1f500}".to_owned();
+        cols[3].header = "Time".to_owned();
+        cols.push(Column {
+            id: "created".to_owned(),
+            header: "Time".to_owned(),
+            default_width_pct: 0.1,
+            align: TextAlign::Right,
+            fixed_width: None,
+            sort: None,
+        });
+        let col_refs: Vec<&Column> = cols.iter().collect();
+        let labels = linear_labels(&col_refs);
+        assert_eq!(
+            labels,
+            [
+                "state",
+                "Title",
+                "Author",
+                "Time (updated)",
+                "Time (created)"
+            ]
+        );
+    }
+
+    #[test]
+    fn linearize_row_labels_fields_and_marks_cursor() {
+        let cols = make_columns();
+        let col_refs: Vec<&Column> = cols.iter().collect();
+        let labels = linear_labels(&col_refs);
+        let mut row = make_row(&[("state", "open"), ("title", "Fix bug"), ("updated", "2d")]);
+        row.insert("subtitle".to_owned(), Cell::plain("Sub"));
+
+        let selected = linearize_row(
+            &row,
+            &col_refs,
+            &labels,
+            Some("subtitle"),
+            true,
+            ColorDepth::default(),
+        );
+        assert_eq!(
+            linear_text(&selected),
+            "> State: open; Sub; Title: Fix bug; Updated: 2d"
+        );
+
+        let other = linearize_row(&row, &col_refs, &labels, None, false, ColorDepth::default());
+        assert!(linear_text(&other).starts_with("  State: open;"));
+    }
+
     #[test]
     fn build_body_rows_subtitle_extraction() {
         let cols = [
@@ -830,6 +1372,7 @@ mod tests {
                 default_width_pct: 0.1,
                 align: TextAlign::Left,
                 fixed_width: Some(6),
+                sort: None,
             },
             Column {
                 id: "title".to_owned(),
@@ -837,6 +1380,7 @@ mod tests {
                 default_width_pct: 0.9,
                 align: TextAlign::Left,
                 fixed_width: None,
+                sort: None,
             },
         ];
         let col_refs: Vec<&Column> = cols.iter().collect();
@@ -853,6 +1397,7 @@ mod tests {
             subtitle_padding,
             subtitle_column: Some("subtitle"),
             depth: ColorDepth::default(),
+            ellipsis: "\u{2026}",
         };
         let result = build_body_rows(&rows, 0, 10, 0, None, &layout);
         assert_eq!(result.len(), 1);
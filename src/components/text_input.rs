@@ -1,6 +1,9 @@
+use std::ops::Range;
+
 use iocraft::prelude::*;
 
 use crate::color::{Color as AppColor, ColorDepth};
+use crate::theme::Chrome;
 
 // ---------------------------------------------------------------------------
 // Pre-rendered text input (T056)
@@ -15,6 +18,7 @@ pub struct TextInputColors {
     pub highlight: Option<AppColor>,
     pub highlight_bg: Option<AppColor>,
     pub suggestion: Option<AppColor>,
+    pub chrome: Chrome,
 }
 
 pub struct RenderedTextInput {
@@ -23,8 +27,12 @@ pub struct RenderedTextInput {
     pub text_fg: Color,
     pub prompt_fg: Color,
     pub border_fg: Color,
+    pub border_style: BorderStyle,
     pub suggestions: Vec<RenderedSuggestion>,
     pub selected_index: Option<usize>,
+    /// Byte ranges of `text` underlined as misspelled.
+    pub misspelled: Vec<Range<usize>>,
+    pub misspelled_fg: Color,
 }
 
 pub struct RenderedSuggestion {
@@ -79,15 +87,26 @@ impl RenderedTextInput {
 
         Self {
             prompt: prompt.to_owned(),
-            text: format!("{text}\u{2588}"), // append full block cursor █
+            text: format!("{text}{}", super::input_cursor(colors.chrome)),
             text_fg,
             prompt_fg,
             border_fg,
+            border_style: super::border(colors.chrome, BorderStyle::Single),
             suggestions: rendered_suggestions,
             selected_index,
+            misspelled: Vec::new(),
+            misspelled_fg: text_fg,
         }
     }
 
+    /// Underline the misspelled byte `ranges` of the text in `color`.
+    #[must_use]
+    pub fn with_misspellings(mut self, ranges: Vec<Range<usize>>, color: Color) -> Self {
+        self.misspelled = ranges;
+        self.misspelled_fg = color;
+        self
+    }
+
     pub fn build_with_multiselect_suggestions(
         prompt: &str,
         text: &str,
@@ -99,11 +118,16 @@ impl RenderedTextInput {
     ) -> Self {
         use std::collections::HashSet;
         let checked: HashSet<&str> = selected_labels.iter().map(String::as_str).collect();
+        let check = if colors.chrome.ascii_only() {
+            "x"
+        } else {
+            "✓"
+        };
         let display: Vec<String> = suggestions
             .iter()
             .map(|s| {
                 if checked.contains(s.as_str()) {
-                    format!("[✓] {s}")
+                    format!("[{check}] {s}")
                 } else {
                     format!("[ ] {s}")
                 }
@@ -130,6 +154,51 @@ pub(crate) fn filter_suggestions(candidates: &[String], query: &str) -> Vec<Stri
         .collect()
 }
 
+// ---------------------------------------------------------------------------
+// Comment composer autocomplete
+// ---------------------------------------------------------------------------
+
+/// Most `@`/`#` suggestions shown under the comment composer.
+const MAX_COMPLETIONS: usize = 8;
+
+/// Byte offset where the last whitespace-delimited word of `buf` starts.
+fn last_word_start(buf: &str) -> usize {
+    buf.char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map_or(0, |(i, c)| i + c.len_utf8())
+}
+
+/// The `@mention` or `#reference` being typed at the end of `buf`, as
+/// `(sigil, query)`.
+pub(crate) fn completion_token(buf: &str) -> Option<(char, &str)> {
+    let word = &buf[last_word_start(buf)..];
+    let sigil = word.chars().next().filter(|c| matches!(c, '@' | '#'))?;
+    Some((sigil, &word[1..]))
+}
+
+/// Suggestions for the token at the end of `buf`: `@login` entries from
+/// `logins`, or `#number title` entries from `refs`.
+pub(crate) fn completion_suggestions(buf: &str, logins: &[String], refs: &[String]) -> Vec<String> {
+    let mut suggestions: Vec<String> = match completion_token(buf) {
+        Some(('@', query)) => filter_suggestions(logins, query)
+            .into_iter()
+            .map(|login| format!("@{login}"))
+            .collect(),
+        Some((_, query)) => filter_suggestions(refs, query),
+        None => return Vec::new(),
+    };
+    suggestions.truncate(MAX_COMPLETIONS);
+    suggestions
+}
+
+/// Replace the token at the end of `buf` with the first word of
+/// `suggestion` (`@login` or `#number`), followed by a space.
+pub(crate) fn accept_completion(buf: &str, suggestion: &str) -> String {
+    let word = suggestion.split_whitespace().next().unwrap_or(suggestion);
+    format!("{}{word} ", &buf[..last_word_start(buf)])
+}
+
 // ---------------------------------------------------------------------------
 // TextInput component
 // ---------------------------------------------------------------------------
@@ -162,10 +231,26 @@ pub fn TextInput(props: &mut TextInputProps) -> impl Into<AnyElement<'static>> {
 
     let has_suggestions = !suggestion_elements.is_empty();
 
+    let mut contents = vec![
+        MixedTextContent::new(&input.prompt).color(input.prompt_fg),
+        MixedTextContent::new(" ").color(input.text_fg),
+    ];
+    let mut shown = 0;
+    for range in &input.misspelled {
+        contents.push(MixedTextContent::new(&input.text[shown..range.start]).color(input.text_fg));
+        contents.push(
+            MixedTextContent::new(&input.text[range.clone()])
+                .color(input.misspelled_fg)
+                .decoration(TextDecoration::Underline),
+        );
+        shown = range.end;
+    }
+    contents.push(MixedTextContent::new(&input.text[shown..]).color(input.text_fg));
+
     element! {
         View(
             flex_direction: FlexDirection::Column,
-            border_style: BorderStyle::Single,
+            border_style: input.border_style,
             border_edges: Edges::Top,
             border_color: input.border_fg,
             padding_left: 1,
@@ -185,14 +270,7 @@ pub fn TextInput(props: &mut TextInputProps) -> impl Into<AnyElement<'static>> {
             } else {
                 None
             })
-            MixedText(
-                contents: vec![
-                    MixedTextContent::new(&input.prompt).color(input.prompt_fg),
-                    MixedTextContent::new(" ").color(input.text_fg),
-                    MixedTextContent::new(&input.text).color(input.text_fg),
-                ],
-                wrap: TextWrap::NoWrap,
-            )
+            MixedText(contents, wrap: TextWrap::NoWrap)
         }
     }
     .into_any()
@@ -239,4 +317,37 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0], "enhancement");
     }
+
+    #[test]
+    fn completion_token_reads_the_last_word() {
+        assert_eq!(completion_token("thanks @ali"), Some(('@', "ali")));
+        assert_eq!(completion_token("see\n#12"), Some(('#', "12")));
+        assert_eq!(completion_token("@"), Some(('@', "")));
+        assert_eq!(completion_token("@alice "), None);
+        assert_eq!(completion_token("mail a@b"), None);
+    }
+
+    #[test]
+    fn completion_suggestions_by_sigil() {
+        let logins = vec!["alice".to_owned(), "bob".to_owned()];
+        let refs = vec!["#12 Fix login".to_owned(), "#30 Add docs".to_owned()];
+        assert_eq!(
+            completion_suggestions("cc @AL", &logins, &refs),
+            vec!["@alice"]
+        );
+        assert_eq!(
+            completion_suggestions("dup of #log", &logins, &refs),
+            vec!["#12 Fix login"]
+        );
+        assert!(completion_suggestions("plain", &logins, &refs).is_empty());
+    }
+
+    #[test]
+    fn accept_completion_replaces_the_token() {
+        assert_eq!(accept_completion("cc @al", "@alice"), "cc @alice ");
+        assert_eq!(
+            accept_completion("dup of #1", "#12 Fix login"),
+            "dup of #12 "
+        );
+    }
 }
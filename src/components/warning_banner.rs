@@ -0,0 +1,75 @@
+use iocraft::prelude::*;
+
+use crate::color::{Color as AppColor, ColorDepth};
+
+// ---------------------------------------------------------------------------
+// WarningBanner component
+// ---------------------------------------------------------------------------
+
+/// Pre-rendered one-line banner naming what a partial fetch skipped.
+pub struct RenderedWarningBanner {
+    pub text: String,
+    pub fg: Color,
+}
+
+impl RenderedWarningBanner {
+    /// Build the banner for `warnings`, or `None` when there is nothing to
+    /// report. Only the first warning is spelled out; the rest are counted.
+    pub fn build(
+        warnings: &[String],
+        icon: &str,
+        depth: ColorDepth,
+        color: Option<AppColor>,
+    ) -> Option<Self> {
+        let (first, rest) = warnings.split_first()?;
+        let more = if rest.is_empty() {
+            String::new()
+        } else {
+            format!(" (+{} more)", rest.len())
+        };
+        Some(Self {
+            text: format!("{icon} Partial results: {first}{more}"),
+            fg: color.map_or(Color::Yellow, |c| c.to_crossterm_color(depth)),
+        })
+    }
+}
+
+#[derive(Default, Props)]
+pub struct WarningBannerProps {
+    pub banner: Option<RenderedWarningBanner>,
+}
+
+#[component]
+pub fn WarningBanner(props: &mut WarningBannerProps) -> impl Into<AnyElement<'static>> {
+    let Some(banner) = props.banner.take() else {
+        return element! { View }.into_any();
+    };
+
+    element! {
+        View(padding_left: 1) {
+            Text(content: banner.text, color: banner.fg, wrap: TextWrap::NoWrap)
+        }
+    }
+    .into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_warnings_means_no_banner() {
+        assert!(RenderedWarningBanner::build(&[], "!", ColorDepth::Color256, None).is_none());
+    }
+
+    #[test]
+    fn banner_spells_out_first_warning_and_counts_the_rest() {
+        let warnings = vec!["repo-a: forbidden".to_owned(), "repo-b: gone".to_owned()];
+        let banner =
+            RenderedWarningBanner::build(&warnings, "!", ColorDepth::Color256, None).unwrap();
+        assert_eq!(
+            banner.text,
+            "! Partial results: repo-a: forbidden (+1 more)"
+        );
+    }
+}
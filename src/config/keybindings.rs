@@ -8,9 +8,11 @@
 // ## Binding Resolution
 //
 // When a key is pressed, resolution follows this priority:
-// 1. Context-specific binding (e.g., `prs`, `issues`)
-// 2. Universal binding
-// 3. No action (key ignored)
+// 1. Sidebar binding, while the sidebar of a PR, issue or Actions view has
+//    focus
+// 2. Context-specific binding (e.g., `prs`, `issues`)
+// 3. Universal binding
+// 4. No action (key ignored)
 //
 // Context bindings can shadow universal ones without affecting other views.
 use anyhow::{Context as _, Result};
@@ -20,13 +22,15 @@ use serde::Deserialize;
 // Config types (T063)
 // ---------------------------------------------------------------------------
 
-/// A single key binding: maps a key chord to either a built-in action or a
-/// shell command template.
+/// A single key binding: maps a key chord to a built-in action, a shell
+/// command template or a Rhai script.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Keybinding {
     pub key: String,
     pub builtin: Option<String>,
     pub command: Option<String>,
+    /// Path of a Rhai script to run, `~` expanded.
+    pub script: Option<String>,
     pub name: Option<String>,
 }
 
@@ -40,6 +44,7 @@ pub struct KeybindingsConfig {
     pub actions: Vec<Keybinding>,
     pub alerts: Vec<Keybinding>,
     pub branches: Vec<Keybinding>,
+    pub sidebar: Vec<Keybinding>,
 }
 
 /// View-independent action identifier used for dispatch.
@@ -64,10 +69,13 @@ pub enum BuiltinAction {
     Search,
     CopyNumber,
     CopyUrl,
+    CopyAs,
+    Share,
     ToggleHelp,
     Quit,
     // PR
     Approve,
+    Review,
     Assign,
     Unassign,
     CommentAction,
@@ -78,18 +86,40 @@ pub enum BuiltinAction {
     Reopen,
     MarkReady,
     Merge,
+    Enqueue,
+    WatchPr,
+    MergeWhenGreen,
     UpdateFromBase,
+    SliceFailingCi,
+    SliceApprovedGreen,
+    SliceChangesRequested,
+    CycleSize,
+    ToggleDrafts,
+    OpenDeployment,
+    RerunFlaky,
+    ApproveWorkflows,
+    ViewedFiles,
+    CopyCommentUrl,
+    OpenComment,
+    ToggleBotComments,
     // Issues
     LabelAction,
+    BoardPrevColumn,
+    BoardNextColumn,
+    BoardMoveLeft,
+    BoardMoveRight,
     // Notifications
     MarkRead,
     MarkAllRead,
+    MarkDone,
     Unsubscribe,
     // Branches
     DeleteBranch,
     NewBranch,
     CreatePrFromBranch,
     ViewPrsForBranch,
+    ReleaseNotes,
+    Releases,
     // View switching
     SwitchView,
     SwitchViewBack,
@@ -99,30 +129,64 @@ pub enum BuiltinAction {
     GoToAlerts,
     GoToNotifications,
     GoToRepo,
+    GoToEvents,
     // Sidebar resize
     SidebarWider,
     SidebarNarrower,
     SidebarResetWidth,
     // Scope
     ToggleScope,
+    // Dates
+    ToggleDates,
+    // Sorting
+    SortColumn,
+    ReverseSort,
+    // Sidebar focus
+    ToggleFocus,
+    OpenItem,
+    CopyItem,
+    ToggleItem,
+    // Logs
+    ShowLogs,
+    // Authentication
+    AuthorizeSso,
+    // Background refresh
+    PostponeRefresh,
+    // Profile
+    ShowContributions,
+    // Comment drafts
+    ShowDrafts,
     // Actions view
     ToggleWorkflowNav,
     RerunFailed,
     RerunAll,
     CancelRun,
+    CleanupRuns,
+    CompareLastSuccess,
+    JobLog,
+    ToggleLogFolds,
+    QuickRun,
+    ShowSchedules,
+    RunWorkflow,
+    PickRef,
     // Cross-view navigation
     JumpToRun,
     JumpToPr,
+    JumpToLinkedIssue,
     GoBack,
     CloseTab,
+    KeepTab,
+    EditNote,
     // Watch workflow run
     WatchRun,
     // Repo picker
     SelectRepo,
+    RescanRepo,
 }
 
 impl BuiltinAction {
     /// Parse a builtin action name from the config string.
+    #[expect(clippy::too_many_lines)]
     pub fn from_name(name: &str) -> Option<Self> {
         Some(match name {
             "move_down" => Self::MoveDown,
@@ -143,9 +207,12 @@ impl BuiltinAction {
             "search" => Self::Search,
             "copy_number" => Self::CopyNumber,
             "copy_url" => Self::CopyUrl,
+            "copy_as" => Self::CopyAs,
+            "share" => Self::Share,
             "toggle_help" => Self::ToggleHelp,
             "quit" => Self::Quit,
             "approve" => Self::Approve,
+            "review" => Self::Review,
             "assign" => Self::Assign,
             "unassign" => Self::Unassign,
             "comment" => Self::CommentAction,
@@ -155,15 +222,37 @@ impl BuiltinAction {
             "close" => Self::Close,
             "reopen" => Self::Reopen,
             "mark_ready" => Self::MarkReady,
+            "enqueue" => Self::Enqueue,
+            "watch_pr" => Self::WatchPr,
+            "merge_when_green" => Self::MergeWhenGreen,
             "merge" => Self::Merge,
             "update_from_base" => Self::UpdateFromBase,
+            "slice_failing_ci" => Self::SliceFailingCi,
+            "slice_approved_green" => Self::SliceApprovedGreen,
+            "slice_changes_requested" => Self::SliceChangesRequested,
+            "cycle_size" => Self::CycleSize,
+            "toggle_drafts" => Self::ToggleDrafts,
+            "open_deployment" => Self::OpenDeployment,
+            "rerun_flaky" => Self::RerunFlaky,
+            "approve_workflows" => Self::ApproveWorkflows,
+            "viewed_files" => Self::ViewedFiles,
+            "copy_comment_url" => Self::CopyCommentUrl,
+            "open_comment" => Self::OpenComment,
+            "toggle_bot_comments" => Self::ToggleBotComments,
             "label" => Self::LabelAction,
+            "board_prev_column" => Self::BoardPrevColumn,
+            "board_next_column" => Self::BoardNextColumn,
+            "board_move_left" => Self::BoardMoveLeft,
+            "board_move_right" => Self::BoardMoveRight,
             "mark_read" => Self::MarkRead,
             "mark_all_read" => Self::MarkAllRead,
+            "mark_done" => Self::MarkDone,
             "unsubscribe" => Self::Unsubscribe,
             "delete_branch" => Self::DeleteBranch,
             "new_branch" => Self::NewBranch,
             "create_pr_from_branch" => Self::CreatePrFromBranch,
+            "release_notes" => Self::ReleaseNotes,
+            "releases" => Self::Releases,
             "view_prs_for_branch" => Self::ViewPrsForBranch,
             "switch_view" => Self::SwitchView,
             "switch_view_back" => Self::SwitchViewBack,
@@ -173,25 +262,51 @@ impl BuiltinAction {
             "go_to_alerts" => Self::GoToAlerts,
             "go_to_notifications" => Self::GoToNotifications,
             "go_to_repo" => Self::GoToRepo,
+            "go_to_events" => Self::GoToEvents,
             "sidebar_wider" => Self::SidebarWider,
             "sidebar_narrower" => Self::SidebarNarrower,
             "sidebar_reset_width" => Self::SidebarResetWidth,
             "toggle_scope" => Self::ToggleScope,
+            "toggle_dates" => Self::ToggleDates,
+            "sort_column" => Self::SortColumn,
+            "reverse_sort" => Self::ReverseSort,
+            "toggle_focus" => Self::ToggleFocus,
+            "open_item" => Self::OpenItem,
+            "copy_item" => Self::CopyItem,
+            "toggle_item" => Self::ToggleItem,
+            "show_logs" => Self::ShowLogs,
+            "authorize_sso" => Self::AuthorizeSso,
+            "postpone_refresh" => Self::PostponeRefresh,
+            "show_contributions" => Self::ShowContributions,
+            "show_drafts" => Self::ShowDrafts,
             "toggle_workflow_nav" => Self::ToggleWorkflowNav,
             "rerun_failed" => Self::RerunFailed,
             "rerun_all" => Self::RerunAll,
             "cancel_run" => Self::CancelRun,
+            "cleanup_runs" => Self::CleanupRuns,
+            "compare_last_success" => Self::CompareLastSuccess,
+            "job_log" => Self::JobLog,
+            "toggle_log_folds" => Self::ToggleLogFolds,
+            "quick_run" => Self::QuickRun,
+            "show_schedules" => Self::ShowSchedules,
+            "run_workflow" => Self::RunWorkflow,
+            "pick_ref" => Self::PickRef,
             "jump_to_run" => Self::JumpToRun,
+            "jump_to_linked_issue" => Self::JumpToLinkedIssue,
             "jump_to_pr" => Self::JumpToPr,
             "go_back" => Self::GoBack,
             "close_tab" => Self::CloseTab,
+            "keep_tab" => Self::KeepTab,
+            "edit_note" => Self::EditNote,
             "watch_run" => Self::WatchRun,
             "select_repo" => Self::SelectRepo,
+            "rescan_repo" => Self::RescanRepo,
             _ => return None,
         })
     }
 
     /// Human-readable description of this action (for help overlay).
+    #[expect(clippy::too_many_lines)]
     pub fn description(self) -> &'static str {
         match self {
             Self::MoveDown => "Move cursor down",
@@ -212,9 +327,12 @@ impl BuiltinAction {
             Self::Search => "Search / filter",
             Self::CopyNumber => "Copy number to clipboard",
             Self::CopyUrl => "Copy URL to clipboard",
+            Self::CopyAs => "Copy with a template or from the copy history",
+            Self::Share => "Post to a share target (Slack, Discord, webhook)",
             Self::ToggleHelp => "Toggle help overlay",
             Self::Quit => "Quit",
             Self::Approve => "Approve",
+            Self::Review => "Write a review with line comments",
             Self::Assign | Self::Unassign => "Assign/Unassign (multiselect)",
             Self::CommentAction => "Comment",
             Self::ViewDiff => "View diff in pager",
@@ -223,15 +341,37 @@ impl BuiltinAction {
             Self::Close => "Close",
             Self::Reopen => "Reopen",
             Self::MarkReady => "Mark as ready for review",
+            Self::Enqueue => "Add to merge queue",
+            Self::WatchPr => "Watch/unwatch PR",
+            Self::MergeWhenGreen => "Merge the PR once it is green, or cancel",
             Self::Merge => "Merge",
             Self::UpdateFromBase => "Update from base branch",
+            Self::SliceFailingCi => "Show only failing CI (toggle)",
+            Self::SliceApprovedGreen => "Show only approved and green (toggle)",
+            Self::SliceChangesRequested => "Show only changes requested (toggle)",
+            Self::CycleSize => "Show only one size class (XS to XL, then all)",
+            Self::ToggleDrafts => "Hide/show drafts and WIP PRs",
+            Self::OpenDeployment => "Open latest deployment",
+            Self::RerunFlaky => "Re-run the jobs of failing flaky checks",
+            Self::ApproveWorkflows => "Approve the workflow runs awaiting approval",
+            Self::ViewedFiles => "Mark the PR's files viewed",
+            Self::CopyCommentUrl => "Copy selected comment link",
+            Self::OpenComment => "Open selected comment",
+            Self::ToggleBotComments => "Show/hide bot and minimized comments",
             Self::LabelAction => "Label (autocomplete)",
+            Self::BoardPrevColumn => "Board: previous column",
+            Self::BoardNextColumn => "Board: next column",
+            Self::BoardMoveLeft => "Board: move item to the left column",
+            Self::BoardMoveRight => "Board: move item to the right column",
             Self::MarkRead => "Mark as read",
             Self::MarkAllRead => "Mark all as read",
+            Self::MarkDone => "Mark as done",
             Self::Unsubscribe => "Unsubscribe",
             Self::DeleteBranch => "Delete branch",
             Self::NewBranch => "Create new branch",
             Self::CreatePrFromBranch => "Create PR from branch",
+            Self::ReleaseNotes => "Draft release notes",
+            Self::Releases => "Releases and tags",
             Self::ViewPrsForBranch => "View PRs for branch",
             Self::SwitchView => "Switch view",
             Self::SwitchViewBack => "Switch view back",
@@ -241,20 +381,45 @@ impl BuiltinAction {
             Self::GoToAlerts => "Go to Alerts",
             Self::GoToNotifications => "Go to Notifications",
             Self::GoToRepo => "Go to Repo",
+            Self::GoToEvents => "Go to Org Events",
             Self::SidebarWider => "Widen sidebar",
             Self::SidebarNarrower => "Narrow sidebar",
             Self::SidebarResetWidth => "Reset sidebar width",
             Self::ToggleScope => "Toggle repo scope",
+            Self::ToggleDates => "Toggle relative/absolute dates",
+            Self::SortColumn => "Sort by the next column",
+            Self::ReverseSort => "Reverse the sort order",
+            Self::ToggleFocus => "Focus table / sidebar",
+            Self::OpenItem => "Open the selected sidebar item",
+            Self::CopyItem => "Copy the selected sidebar item's link or path",
+            Self::ToggleItem => "Mark the selected file viewed, or fold a step log",
+            Self::ShowLogs => "Show this session's log",
+            Self::AuthorizeSso => "Open SSO authorization / token settings",
+            Self::PostponeRefresh => "Postpone auto-refresh of this tab",
+            Self::ShowContributions => "Author's contribution calendar",
+            Self::ShowDrafts => "Comment drafts",
             Self::ToggleWorkflowNav => "Toggle workflow navigator",
             Self::RerunFailed => "Re-run failed jobs",
             Self::RerunAll => "Re-run all jobs",
             Self::CancelRun => "Cancel run",
+            Self::CleanupRuns => "Clean up old workflow runs",
+            Self::CompareLastSuccess => "Compare with last successful run",
+            Self::JobLog => "Show a job's log",
+            Self::ToggleLogFolds => "Fold/unfold log groups",
+            Self::QuickRun => "Run a dispatch preset",
+            Self::ShowSchedules => "Upcoming scheduled runs",
+            Self::RunWorkflow => "Run a workflow manually",
+            Self::PickRef => "Scope runs to a branch or tag",
             Self::JumpToRun => "Jump to Actions run",
+            Self::JumpToLinkedIssue => "Jump to selected linked issue",
             Self::JumpToPr => "Jump to PR for branch",
             Self::GoBack => "Go back to previous view",
             Self::CloseTab => "Close ephemeral tab",
+            Self::KeepTab => "Keep ephemeral tab in the config",
+            Self::EditNote => "Edit local note",
             Self::WatchRun => "Watch/unwatch run",
             Self::SelectRepo => "Select active repo",
+            Self::RescanRepo => "Scope to the repo worked in",
         }
     }
 }
@@ -264,6 +429,8 @@ impl BuiltinAction {
 pub enum ResolvedBinding {
     Builtin(BuiltinAction),
     ShellCommand(String),
+    /// Path of the script to run.
+    Script(String),
 }
 
 // ---------------------------------------------------------------------------
@@ -343,6 +510,7 @@ fn kb(key: &str, builtin: &str, name: &str) -> Keybinding {
         key: key.to_owned(),
         builtin: Some(builtin.to_owned()),
         command: None,
+        script: None,
         name: Some(name.to_owned()),
     }
 }
@@ -386,10 +554,21 @@ pub fn default_universal() -> Vec<Keybinding> {
         kb("4", "go_to_alerts", "Go to Alerts"),
         kb("5", "go_to_notifications", "Go to Notifications"),
         kb("6", "go_to_repo", "Go to Repo"),
+        kb("7", "go_to_events", "Go to Org Events"),
         kb("+", "sidebar_wider", "Widen sidebar"),
         kb("-", "sidebar_narrower", "Narrow sidebar"),
         kb("=", "sidebar_reset_width", "Reset sidebar width"),
+        kb("tab", "toggle_focus", "Focus table / sidebar"),
         kb("s", "select_repo", "Select active repo"),
+        kb("ctrl+g", "rescan_repo", "Scope to the repo worked in"),
+        kb("t", "toggle_dates", "Toggle relative/absolute dates"),
+        kb("ctrl+l", "show_logs", "Show this session's log"),
+        kb(
+            "!",
+            "authorize_sso",
+            "Open SSO authorization / token settings",
+        ),
+        kb("Z", "postpone_refresh", "Postpone auto-refresh of this tab"),
     ]
 }
 
@@ -397,6 +576,7 @@ pub fn default_universal() -> Vec<Keybinding> {
 pub fn default_prs() -> Vec<Keybinding> {
     vec![
         kb("v", "approve", "Approve"),
+        kb("alt+v", "review", "Write a review with line comments"),
         kb("L", "label", "Label (autocomplete)"),
         kb("a", "assign", "Assign/Unassign (multiselect)"),
         kb("C", "comment", "Comment"),
@@ -407,12 +587,43 @@ pub fn default_prs() -> Vec<Keybinding> {
         kb("X", "reopen", "Reopen PR"),
         kb("W", "mark_ready", "Mark ready for review"),
         kb("m", "merge", "Merge PR"),
+        kb("Q", "enqueue", "Add to merge queue"),
+        kb("ctrl+w", "watch_pr", "Watch/unwatch PR"),
+        kb("alt+m", "merge_when_green", "Merge when green / cancel"),
         kb("u", "update_from_base", "Update from base"),
+        kb("F", "slice_failing_ci", "Only failing CI"),
+        kb("A", "slice_approved_green", "Only approved and green"),
+        kb("D", "slice_changes_requested", "Only changes requested"),
+        kb("z", "cycle_size", "Cycle size class"),
+        kb("H", "toggle_drafts", "Hide/show drafts and WIP"),
+        kb("O", "open_deployment", "Open latest deployment"),
+        kb("e", "rerun_flaky", "Re-run failing flaky checks"),
+        kb(
+            "alt+a",
+            "approve_workflows",
+            "Approve waiting workflow runs",
+        ),
+        kb("E", "edit_note", "Edit local note"),
+        kb("V", "viewed_files", "Mark files viewed"),
+        kb("ctrl+y", "copy_comment_url", "Copy selected comment link"),
+        kb("ctrl+o", "open_comment", "Open selected comment"),
+        kb(
+            "b",
+            "toggle_bot_comments",
+            "Show/hide bot and minimized comments",
+        ),
         kb("ctrl+]", "jump_to_run", "Jump to Actions run"),
+        kb("alt+o", "sort_column", "Sort by the next column"),
+        kb("alt+r", "reverse_sort", "Reverse the sort order"),
         kb("ctrl+t", "go_back", "Go back"),
+        kb("K", "keep_tab", "Keep ephemeral tab in the config"),
         kb("n", "switch_view", "Switch view"),
         kb("N", "switch_view_back", "Switch view back"),
         kb("S", "toggle_scope", "Toggle repo scope"),
+        kb("@", "show_contributions", "Author's contribution calendar"),
+        kb("ctrl+e", "show_drafts", "Comment drafts"),
+        kb("alt+y", "copy_as", "Copy with a template / from history"),
+        kb("alt+s", "share", "Share to a webhook"),
     ]
 }
 
@@ -424,9 +635,26 @@ pub(crate) fn default_issues() -> Vec<Keybinding> {
         kb("c", "comment", "Comment"),
         kb("x", "close", "Close issue"),
         kb("X", "reopen", "Reopen issue"),
+        kb("K", "keep_tab", "Keep ephemeral tab in the config"),
+        kb("E", "edit_note", "Edit local note"),
         kb("n", "switch_view", "Switch view"),
         kb("N", "switch_view_back", "Switch view back"),
         kb("S", "toggle_scope", "Toggle repo scope"),
+        kb("@", "show_contributions", "Author's contribution calendar"),
+        kb("ctrl+e", "show_drafts", "Comment drafts"),
+        kb("alt+y", "copy_as", "Copy with a template / from history"),
+        kb("alt+s", "share", "Share to a webhook"),
+        kb("[", "board_prev_column", "Board: previous column"),
+        kb("]", "board_next_column", "Board: next column"),
+        kb("<", "board_move_left", "Board: move item left"),
+        kb(">", "board_move_right", "Board: move item right"),
+        kb(
+            "ctrl+]",
+            "jump_to_linked_issue",
+            "Jump to selected linked issue",
+        ),
+        kb("alt+o", "sort_column", "Sort by the next column"),
+        kb("alt+r", "reverse_sort", "Reverse the sort order"),
     ]
 }
 
@@ -436,13 +664,29 @@ pub(crate) fn default_actions() -> Vec<Keybinding> {
         kb("w", "toggle_workflow_nav", "Toggle workflow navigator"),
         kb("ctrl+t", "go_back", "Go back to previous view"),
         kb("d", "close_tab", "Close ephemeral tab"),
+        kb("K", "keep_tab", "Keep ephemeral tab in the config"),
         kb("n", "switch_view", "Switch view"),
         kb("N", "switch_view_back", "Switch view back"),
         kb("e", "rerun_failed", "Re-run failed jobs"),
         kb("E", "rerun_all", "Re-run all jobs"),
         kb("ctrl+x", "cancel_run", "Cancel run"),
+        kb("X", "cleanup_runs", "Clean up old workflow runs"),
+        kb(
+            "c",
+            "compare_last_success",
+            "Compare with last successful run",
+        ),
+        kb("L", "job_log", "Show a job's log"),
+        kb("z", "toggle_log_folds", "Fold/unfold log groups"),
         kb("W", "watch_run", "Watch/unwatch run"),
         kb("S", "toggle_scope", "Toggle repo scope"),
+        kb("D", "quick_run", "Run a dispatch preset"),
+        kb("C", "show_schedules", "Upcoming scheduled runs"),
+        kb("T", "run_workflow", "Run a workflow manually"),
+        kb("b", "pick_ref", "Scope runs to a branch or tag"),
+        kb("ctrl+]", "jump_to_pr", "Jump to triggering PR"),
+        kb("alt+o", "sort_column", "Sort by the next column"),
+        kb("alt+r", "reverse_sort", "Reverse the sort order"),
     ]
 }
 
@@ -461,10 +705,22 @@ pub(crate) fn default_notifications() -> Vec<Keybinding> {
     vec![
         kb("m", "mark_read", "Mark as read"),
         kb("M", "mark_all_read", "Mark all as read"),
+        kb("d", "mark_done", "Mark as done"),
         kb("u", "unsubscribe", "Unsubscribe"),
         kb("n", "switch_view", "Switch view"),
         kb("N", "switch_view_back", "Switch view back"),
         kb("S", "toggle_scope", "Toggle repo scope"),
+        kb("alt+o", "sort_column", "Sort by the next column"),
+        kb("alt+r", "reverse_sort", "Reverse the sort order"),
+    ]
+}
+
+/// Default Org Events view keybindings.
+pub(crate) fn default_events() -> Vec<Keybinding> {
+    vec![
+        kb("n", "switch_view", "Switch view"),
+        kb("N", "switch_view_back", "Switch view back"),
+        kb("ctrl+]", "jump_to_pr", "Jump to the event's PR or issue"),
     ]
 }
 
@@ -479,12 +735,29 @@ pub(crate) fn default_branches() -> Vec<Keybinding> {
         kb("v", "view_prs_for_branch", "View PRs for branch"),
         kb("ctrl+]", "jump_to_pr", "Jump to PR"),
         kb("w", "worktree", "Create/open worktree"),
+        kb("T", "release_notes", "Draft release notes"),
+        kb("L", "releases", "Releases and tags"),
         kb("n", "switch_view", "Switch view"),
         kb("N", "switch_view_back", "Switch view back"),
         kb("S", "toggle_scope", "Toggle repo scope"),
     ]
 }
 
+/// Default bindings of a focused sidebar (PR, Issue and Actions views).
+pub(crate) fn default_sidebar() -> Vec<Keybinding> {
+    vec![
+        kb("enter", "open_item", "Open the selected item"),
+        kb("o", "open_item", "Open the selected item"),
+        kb("y", "copy_item", "Copy the selected item's link or path"),
+        kb(
+            "space",
+            "toggle_item",
+            "Mark the selected file viewed, or fold a step log",
+        ),
+        kb("esc", "toggle_focus", "Focus the table"),
+    ]
+}
+
 // ---------------------------------------------------------------------------
 // Merged keybinding set (T063)
 // ---------------------------------------------------------------------------
@@ -500,7 +773,9 @@ pub struct MergedBindings {
     pub actions: Vec<Keybinding>,
     pub alerts: Vec<Keybinding>,
     pub notifications: Vec<Keybinding>,
+    pub events: Vec<Keybinding>,
     pub branches: Vec<Keybinding>,
+    pub sidebar: Vec<Keybinding>,
 }
 
 impl MergedBindings {
@@ -516,7 +791,9 @@ impl MergedBindings {
             actions: merge_lists(&default_actions(), &config.actions),
             alerts: merge_lists(&default_alerts(), &config.alerts),
             notifications: merge_lists(&default_notifications(), &[]),
+            events: merge_lists(&default_events(), &[]),
             branches: merge_lists(&default_branches(), &config.branches),
+            sidebar: merge_lists(&default_sidebar(), &config.sidebar),
         }
     }
 
@@ -529,6 +806,7 @@ impl MergedBindings {
             ViewContext::Actions => &self.actions,
             ViewContext::Alerts => &self.alerts,
             ViewContext::Notifications => &self.notifications,
+            ViewContext::Events => &self.events,
             ViewContext::Branches => &self.branches,
         };
 
@@ -538,8 +816,23 @@ impl MergedBindings {
         find_binding(&self.universal, key)
     }
 
+    /// Like [`resolve`](Self::resolve), with the sidebar bindings first
+    /// while the view's `sidebar_focused`.
+    pub fn resolve_focused(
+        &self,
+        key: &str,
+        context: ViewContext,
+        sidebar_focused: bool,
+    ) -> Option<ResolvedBinding> {
+        if sidebar_focused && let Some(binding) = find_binding(&self.sidebar, key) {
+            return Some(binding);
+        }
+        self.resolve(key, context)
+    }
+
     /// Return all bindings for a given context, grouped as
-    /// `(context_label, bindings)` pairs. Universal bindings come first.
+    /// `(context_label, bindings)` pairs. Universal bindings come first, the
+    /// focused sidebar's last in the views that have one.
     pub fn all_for_context(&self, context: ViewContext) -> Vec<(&'static str, &[Keybinding])> {
         let context_bindings = match context {
             ViewContext::Prs => ("PR", self.prs.as_slice()),
@@ -547,10 +840,18 @@ impl MergedBindings {
             ViewContext::Actions => ("Actions", self.actions.as_slice()),
             ViewContext::Alerts => ("Alerts", self.alerts.as_slice()),
             ViewContext::Notifications => ("Notification", self.notifications.as_slice()),
+            ViewContext::Events => ("Org Events", self.events.as_slice()),
             ViewContext::Branches => ("Branch", self.branches.as_slice()),
         };
 
-        vec![("Universal", self.universal.as_slice()), context_bindings]
+        let mut groups = vec![("Universal", self.universal.as_slice()), context_bindings];
+        if matches!(
+            context,
+            ViewContext::Prs | ViewContext::Issues | ViewContext::Actions
+        ) {
+            groups.push(("Sidebar (focused)", self.sidebar.as_slice()));
+        }
+        groups
     }
 }
 
@@ -562,6 +863,7 @@ pub enum ViewContext {
     Actions,
     Alerts,
     Notifications,
+    Events,
     Branches,
 }
 
@@ -623,6 +925,10 @@ impl KeybindingsConfig {
                 &default_branches(),
                 &merge_binding_lists(&global.branches, &local.branches),
             ),
+            sidebar: merge_lists(
+                &default_sidebar(),
+                &merge_binding_lists(&global.sidebar, &local.sidebar),
+            ),
         }
     }
 
@@ -650,6 +956,9 @@ fn find_binding(bindings: &[Keybinding], key: &str) -> Option<ResolvedBinding> {
             if let Some(ref cmd) = b.command {
                 return Some(ResolvedBinding::ShellCommand(cmd.clone()));
             }
+            if let Some(ref script) = b.script {
+                return Some(ResolvedBinding::Script(script.clone()));
+            }
         }
     }
     None
@@ -664,7 +973,9 @@ fn find_binding(bindings: &[Keybinding], key: &str) -> Option<ResolvedBinding> {
 pub struct TemplateVars {
     pub url: String,
     pub number: String,
+    pub title: String,
     pub repo_name: String,
+    pub state: String,
     pub head_branch: String,
     pub base_branch: String,
     pub run_id: String,
@@ -686,7 +997,10 @@ pub fn expand_template(template: &str, vars: &TemplateVars) -> String {
         .replace("{{.RunId}}", &vars.run_id)
         .replace("{{.RunName}}", &vars.run_name)
         .replace("{{.RunNumber}}", &vars.run_number)
+        .replace("{{.State}}", &vars.state)
         .replace("{{.Url}}", &vars.url)
+        // Last: a title may itself contain `{{.…}}`.
+        .replace("{{.Title}}", &vars.title)
 }
 
 // ---------------------------------------------------------------------------
@@ -884,6 +1198,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn focused_sidebar_bindings_come_first() {
+        let merged = MergedBindings::from_config(&KeybindingsConfig::default());
+        assert!(matches!(
+            merged.resolve_focused("y", ViewContext::Prs, true),
+            Some(ResolvedBinding::Builtin(BuiltinAction::CopyItem))
+        ));
+        assert!(matches!(
+            merged.resolve_focused("y", ViewContext::Prs, false),
+            Some(ResolvedBinding::Builtin(BuiltinAction::CopyNumber))
+        ));
+        // Keys the sidebar leaves alone reach the view's bindings.
+        assert!(matches!(
+            merged.resolve_focused("n", ViewContext::Prs, true),
+            Some(ResolvedBinding::Builtin(BuiltinAction::SwitchView))
+        ));
+    }
+
     #[test]
     fn resolve_falls_through_to_universal() {
         let config = KeybindingsConfig::default();
@@ -903,6 +1235,7 @@ mod tests {
                 key: "z".to_owned(),
                 builtin: None,
                 command: Some("echo {{.Number}}".to_owned()),
+                script: None,
                 name: Some("Custom".to_owned()),
             }],
             ..Default::default()
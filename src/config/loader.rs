@@ -1,11 +1,17 @@
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
 
 use anyhow::{Context, Result};
+use notify::{RecursiveMode, Watcher};
 use serde::Deserialize;
 
 use crate::config::builtin_themes;
 use crate::config::keybindings::KeybindingsConfig;
-use crate::config::types::{AppConfig, Defaults, GitHubConfig, PreviewDefaults, Theme};
+use crate::config::types::{
+    AppConfig, BellConfig, BrowserConfig, CopyConfig, Defaults, GitHubConfig, LogConfig,
+    PrSizeDefaults, PreviewDefaults, ReleaseNotesConfig, SpellConfig, StaleDefaults, Theme,
+};
 
 /// Wrapper used to parse a theme-only TOML file (contains only `[theme.*]`).
 #[derive(Deserialize, Default)]
@@ -95,6 +101,157 @@ fn apply_theme_file(config: &mut AppConfig) -> Result<()> {
     Ok(())
 }
 
+/// How long the config files must stay untouched before they are reloaded:
+/// editors write a save in several steps.
+const RELOAD_QUIET: Duration = Duration::from_millis(200);
+
+/// Why the last reload failed, shown in the footer until one succeeds.
+static RELOAD_ERROR: Mutex<Option<String>> = Mutex::new(None);
+
+/// The files [`load_config`] reads with `explicit_path`, including the theme
+/// file `config` names. Without a global config, the path one is created at,
+/// so that writing it takes effect.
+fn config_files(explicit_path: Option<&Path>, config: &AppConfig) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = match explicit_path {
+        Some(path) => vec![path.to_path_buf()],
+        None => find_global_config()
+            .or_else(default_global_config_path)
+            .into_iter()
+            .chain(find_local_config_chain())
+            .collect(),
+    };
+    if let Some(theme_file) = config.theme_file.as_deref()
+        && !theme_file.starts_with("builtin:")
+    {
+        files.push(expand_tilde(theme_file));
+    }
+    files
+        .into_iter()
+        .filter_map(|file| std::path::absolute(file).ok())
+        .flat_map(|file| {
+            // A symlinked file changes where it points to.
+            let target = std::fs::canonicalize(&file).ok().filter(|t| *t != file);
+            std::iter::once(file).chain(target)
+        })
+        .collect()
+}
+
+/// The watcher and the directories it watches.
+struct Watching {
+    watcher: notify::RecommendedWatcher,
+    dirs: Vec<PathBuf>,
+}
+
+/// Watches the files a config was loaded from, to reload it when they change.
+///
+/// Their directories are watched rather than the files themselves: editors
+/// often save by renaming a new file over the old one. Configs created where
+/// none was read (e.g. a new `gh-board.toml` in the repo) take a restart.
+#[derive(Clone)]
+pub struct ConfigWatcher {
+    explicit_path: Option<PathBuf>,
+    watching: Arc<Mutex<Watching>>,
+    /// The files whose changes count, shared with the watcher's callback.
+    files: Arc<Mutex<Vec<PathBuf>>>,
+    changes: async_channel::Receiver<()>,
+}
+
+impl ConfigWatcher {
+    /// Watch the files `config` was loaded from by [`load_config`] with
+    /// `explicit_path`.
+    pub fn new(explicit_path: Option<&Path>, config: &AppConfig) -> Result<Self> {
+        let files: Arc<Mutex<Vec<PathBuf>>> = Arc::default();
+        let (tx, changes) = async_channel::unbounded();
+        let seen = Arc::clone(&files);
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else {
+                return;
+            };
+            let files = seen.lock().unwrap_or_else(PoisonError::into_inner);
+            if !event.kind.is_access() && event.paths.iter().any(|p| files.contains(p)) {
+                let _ = tx.try_send(());
+            }
+        })
+        .context("watching the config files")?;
+        let this = Self {
+            explicit_path: explicit_path.map(Path::to_path_buf),
+            watching: Arc::new(Mutex::new(Watching {
+                watcher,
+                dirs: Vec::new(),
+            })),
+            files,
+            changes,
+        };
+        this.watch(config_files(explicit_path, config));
+        Ok(this)
+    }
+
+    /// Watch `files`, and no longer the others.
+    fn watch(&self, files: Vec<PathBuf>) {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        for dir in files.iter().filter_map(|file| file.parent()) {
+            if !dirs.iter().any(|d| d == dir) {
+                dirs.push(dir.to_path_buf());
+            }
+        }
+        let mut watching = self.watching.lock().unwrap_or_else(PoisonError::into_inner);
+        let Watching {
+            watcher,
+            dirs: watched,
+        } = &mut *watching;
+        for dir in watched.iter().filter(|d| !dirs.contains(d)) {
+            let _ = watcher.unwatch(dir);
+        }
+        // A directory that does not exist yet is skipped.
+        watched.retain(|d| dirs.contains(d));
+        for dir in dirs {
+            if !watched.contains(&dir) && watcher.watch(&dir, RecursiveMode::NonRecursive).is_ok() {
+                watched.push(dir);
+            }
+        }
+        *self.files.lock().unwrap_or_else(PoisonError::into_inner) = files;
+    }
+
+    /// Wait until a watched file changed, then went untouched for
+    /// [`RELOAD_QUIET`].
+    pub async fn changed(&self) {
+        if self.changes.recv().await.is_err() {
+            return std::future::pending().await;
+        }
+        loop {
+            let more = smol::future::or(async { self.changes.recv().await.is_ok() }, async {
+                smol::Timer::after(RELOAD_QUIET).await;
+                false
+            })
+            .await;
+            if !more {
+                return;
+            }
+        }
+    }
+
+    /// Load the config again, then watch the files it now reads. Blocks on
+    /// the file system.
+    pub fn reload(&self) -> Result<AppConfig> {
+        let config = load_config(self.explicit_path.as_deref())?;
+        self.watch(config_files(self.explicit_path.as_deref(), &config));
+        Ok(config)
+    }
+}
+
+/// Record why the last reload failed, or that it succeeded (`None`).
+pub(crate) fn set_reload_error(error: Option<String>) {
+    *RELOAD_ERROR.lock().unwrap_or_else(PoisonError::into_inner) = error;
+}
+
+/// Footer line of a failed reload.
+pub(crate) fn reload_error() -> Option<String> {
+    RELOAD_ERROR
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
 /// Merge repo-local config on top of global config.
 ///
 /// Filter lists (`pr_filters`, `issues_filters`, `notifications_filters`) from
@@ -107,6 +264,9 @@ fn apply_theme_file(config: &mut AppConfig) -> Result<()> {
 /// - `keybindings` (merged by context: universal, prs, issues, actions, branches)
 ///
 /// Repo paths are merged (local entries override matching global keys).
+/// `hooks`, `bell.command` and the `browser` commands run commands unasked,
+/// so a local config never sets them; nor `github.hosts`, which says where
+/// tokens are sent, or `share_targets`, which say where items are posted.
 fn merge_configs(global: AppConfig, local: AppConfig) -> AppConfig {
     AppConfig {
         pr_filters: if local.pr_filters.is_empty() {
@@ -134,6 +294,22 @@ fn merge_configs(global: AppConfig, local: AppConfig) -> AppConfig {
         } else {
             local.alerts_filters
         },
+        events_filters: if local.events_filters.is_empty() {
+            global.events_filters
+        } else {
+            local.events_filters
+        },
+        notification_rules: if local.notification_rules.is_empty() {
+            global.notification_rules
+        } else {
+            local.notification_rules
+        },
+        reply_templates: if local.reply_templates.is_empty() {
+            global.reply_templates
+        } else {
+            local.reply_templates
+        },
+        share_targets: global.share_targets,
         github: merge_github_config(&global.github, &local.github),
         defaults: merge_defaults(&global.defaults, &local.defaults),
         theme: Theme::merge(global.theme, local.theme),
@@ -145,6 +321,59 @@ fn merge_configs(global: AppConfig, local: AppConfig) -> AppConfig {
         },
         theme_file: local.theme_file.or(global.theme_file),
         actions: merge_actions_config(&global.actions, &local.actions),
+        browser: merge_browser_config(global.browser, &local.browser),
+        spell: SpellConfig {
+            enabled: local.spell.enabled.or(global.spell.enabled),
+            language: local.spell.language.or(global.spell.language),
+            dictionary: local.spell.dictionary.or(global.spell.dictionary),
+            words: {
+                let mut words = global.spell.words;
+                words.extend(local.spell.words);
+                words
+            },
+        },
+        release_notes: ReleaseNotesConfig {
+            sections: if local.release_notes.sections.is_empty() {
+                global.release_notes.sections
+            } else {
+                local.release_notes.sections
+            },
+            other_section: local
+                .release_notes
+                .other_section
+                .or(global.release_notes.other_section),
+            exclude_labels: local
+                .release_notes
+                .exclude_labels
+                .or(global.release_notes.exclude_labels),
+        },
+        copy: CopyConfig {
+            templates: if local.copy.templates.is_empty() {
+                global.copy.templates
+            } else {
+                local.copy.templates
+            },
+            history: local.copy.history.or(global.copy.history),
+        },
+        hooks: global.hooks,
+        bell: merge_bell_config(global.bell, local.bell),
+        log: LogConfig {
+            format: local.log.format.or(global.log.format),
+            level: local.log.level.or(global.log.level),
+        },
+    }
+}
+
+/// Merge two bell configs: local events override global ones, the command
+/// is global only.
+fn merge_bell_config(global: BellConfig, local: BellConfig) -> BellConfig {
+    BellConfig {
+        on: if local.on.is_empty() {
+            global.on
+        } else {
+            local.on
+        },
+        command: global.command,
     }
 }
 
@@ -163,6 +392,26 @@ fn merge_actions_config(
             .clone()
             .or_else(|| global.watch_complete_command.clone()),
         group_matrix_jobs: local.group_matrix_jobs.or(global.group_matrix_jobs),
+        failed_step_log_lines: local.failed_step_log_lines.or(global.failed_step_log_lines),
+        cleanup_older_than_days: local
+            .cleanup_older_than_days
+            .or(global.cleanup_older_than_days),
+        dispatch_presets: if local.dispatch_presets.is_empty() {
+            global.dispatch_presets.clone()
+        } else {
+            local.dispatch_presets.clone()
+        },
+    }
+}
+
+/// Merge two browser configs: the SSH mode may be set locally, the commands
+/// are global only.
+fn merge_browser_config(global: BrowserConfig, local: &BrowserConfig) -> BrowserConfig {
+    BrowserConfig {
+        ssh: local.ssh.or(global.ssh),
+        ssh_command: global.ssh_command,
+        command: global.command,
+        hosts: global.hosts,
     }
 }
 
@@ -175,6 +424,16 @@ fn merge_github_config(global: &GitHubConfig, local: &GitHubConfig) -> GitHubCon
             .or(global.refetch_interval_minutes),
         prefetch_pr_details: local.prefetch_pr_details.or(global.prefetch_pr_details),
         auto_clone: local.auto_clone.or(global.auto_clone),
+        quiet_hours: local.quiet_hours.or(global.quiet_hours),
+        request_timeout_seconds: local
+            .request_timeout_seconds
+            .or(global.request_timeout_seconds),
+        sync_viewed_files: local.sync_viewed_files.or(global.sync_viewed_files),
+        smart_scope: local.smart_scope.or(global.smart_scope),
+        disk_cache: local.disk_cache.or(global.disk_cache),
+        startup_check: local.startup_check.or(global.startup_check),
+        merge_method: local.merge_method.or(global.merge_method),
+        hosts: global.hosts.clone(),
     }
 }
 
@@ -189,6 +448,22 @@ fn merge_defaults(global: &Defaults, local: &Defaults) -> Defaults {
             .date_format
             .clone()
             .or_else(|| global.date_format.clone()),
+        timezone: local.timezone.clone().or_else(|| global.timezone.clone()),
+        detail_cache_size: local.detail_cache_size.or(global.detail_cache_size),
+        stale: StaleDefaults {
+            warn_days: local.stale.warn_days.or(global.stale.warn_days),
+            alert_days: local.stale.alert_days.or(global.stale.alert_days),
+        },
+        pr_size: PrSizeDefaults {
+            xs: local.pr_size.xs.or(global.pr_size.xs),
+            s: local.pr_size.s.or(global.pr_size.s),
+            m: local.pr_size.m.or(global.pr_size.m),
+            l: local.pr_size.l.or(global.pr_size.l),
+        },
+        wip_patterns: local
+            .wip_patterns
+            .clone()
+            .or_else(|| global.wip_patterns.clone()),
     }
 }
 
@@ -290,6 +565,50 @@ fn dirs_fallback() -> Option<PathBuf> {
     std::env::var("HOME").ok().map(PathBuf::from)
 }
 
+/// Where a global config file is created when none exists yet.
+fn default_global_config_path() -> Option<PathBuf> {
+    std::env::var("XDG_CONFIG_HOME")
+        .ok()
+        .map(PathBuf::from)
+        .or_else(|| dirs_fallback().map(|home| home.join(".config")))
+        .map(|dir| dir.join("gh-board/config.toml"))
+}
+
+/// `contents` with `table` appended as a new `[[section]]` entry.
+fn append_table(contents: &str, section: &str, table: &toml::Table) -> Result<String> {
+    let mut out = contents.to_owned();
+    if !out.is_empty() {
+        if !out.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    let entry = toml::to_string(table).context("serializing the new entry")?;
+    Ok(format!("{out}[[{section}]]\n{entry}"))
+}
+
+/// Append `table` to the global config file as a new `[[section]]` entry,
+/// creating the file when there is none. The result must still parse, so a
+/// file the entry cannot be added to (e.g. one listing `section` inline) is
+/// left untouched. A symlinked file is written through. Returns the path.
+pub(crate) fn append_to_global_config(section: &str, table: &toml::Table) -> Result<PathBuf> {
+    let path = find_global_config()
+        .or_else(default_global_config_path)
+        .context("no config directory: neither $XDG_CONFIG_HOME nor $HOME is set")?;
+    let path = std::fs::canonicalize(&path).unwrap_or(path);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+    };
+    let updated = append_table(&contents, section, table)?;
+    toml::from_str::<AppConfig>(&updated)
+        .with_context(|| format!("{} would no longer parse", path.display()))?;
+    crate::util::write_atomic(&path, &updated)
+        .with_context(|| format!("writing {}", path.display()))?;
+    Ok(path)
+}
+
 pub(crate) fn expand_tilde(path: &str) -> PathBuf {
     if let Some(rest) = path.strip_prefix("~/")
         && let Some(home) = dirs_fallback()
@@ -321,6 +640,41 @@ mod tests {
     use crate::config::keybindings::Keybinding;
     use crate::config::types::IconConfig;
 
+    #[test]
+    fn append_table_adds_an_entry_after_the_existing_ones() {
+        let mut table = toml::Table::new();
+        table.insert("title".to_owned(), "Kept".into());
+        table.insert("filters".to_owned(), "repo:o/r is:open".into());
+        let existing = "[[pr_filters]]\ntitle = \"Mine\"\nfilters = \"author:@me\"";
+        let updated = append_table(existing, "pr_filters", &table).unwrap();
+        let config: AppConfig = toml::from_str(&updated).unwrap();
+        let titles: Vec<&str> = config.pr_filters.iter().map(|f| f.title.as_str()).collect();
+        assert_eq!(titles, ["Mine", "Kept"]);
+        assert_eq!(config.pr_filters[1].filters, "repo:o/r is:open");
+        assert!(
+            append_table("", "pr_filters", &table)
+                .unwrap()
+                .starts_with("[[pr_filters]]\n")
+        );
+    }
+
+    #[test]
+    fn config_files_include_the_theme_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = std::fs::canonicalize(temp_dir.path()).unwrap();
+        let path = dir.join("config.toml");
+        let mut config = AppConfig {
+            theme_file: Some(dir.join("theme.toml").display().to_string()),
+            ..AppConfig::default()
+        };
+        assert_eq!(
+            config_files(Some(&path), &config),
+            [path.clone(), dir.join("theme.toml")]
+        );
+        config.theme_file = Some("builtin:dracula".to_owned());
+        assert_eq!(config_files(Some(&path), &config), [path]);
+    }
+
     #[test]
     fn merge_configs_preserves_global_theme_with_empty_local() {
         let mut global = AppConfig::default();
@@ -367,6 +721,7 @@ mod tests {
             key: "test".to_string(),
             builtin: Some("quit".to_string()),
             command: None,
+            script: None,
             name: Some("Test".to_string()),
         });
 
@@ -385,6 +740,7 @@ mod tests {
             key: "j".to_string(),
             builtin: Some("move_down".to_string()),
             command: None,
+            script: None,
             name: Some("Move Down".to_string()),
         });
 
@@ -393,6 +749,7 @@ mod tests {
             key: "j".to_string(),
             builtin: Some("first".to_string()),
             command: None,
+            script: None,
             name: Some("First".to_string()),
         });
 
@@ -506,6 +863,11 @@ mod tests {
             limit: Some(50),
             host: None,
             layout: None,
+            hide_drafts: None,
+            preview: None,
+            preview_tab: None,
+            refetch_interval_minutes: None,
+            badge: None,
         });
 
         let mut local = AppConfig::default();
@@ -515,6 +877,11 @@ mod tests {
             limit: Some(30),
             host: None,
             layout: None,
+            hide_drafts: None,
+            preview: None,
+            preview_tab: None,
+            refetch_interval_minutes: None,
+            badge: None,
         });
 
         let merged = merge_configs(global, local);
@@ -531,6 +898,11 @@ mod tests {
             limit: Some(50),
             host: None,
             layout: None,
+            hide_drafts: None,
+            preview: None,
+            preview_tab: None,
+            refetch_interval_minutes: None,
+            badge: None,
         });
 
         let local = AppConfig::default(); // Empty filters
@@ -540,6 +912,125 @@ mod tests {
         assert_eq!(merged.pr_filters[0].title, "Global Filter");
     }
 
+    #[test]
+    fn merge_configs_keeps_global_hooks_only() {
+        let global = AppConfig {
+            hooks: crate::config::types::HooksConfig {
+                on_mutation: Some("notify-send done".to_owned()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let local = AppConfig {
+            hooks: crate::config::types::HooksConfig {
+                on_mutation: Some("curl evil.example".to_owned()),
+                on_fetch_error: Some("curl evil.example".to_owned()),
+                ..Default::default()
+            },
+            bell: BellConfig {
+                on: vec![crate::config::types::BellEvent::ReviewRequest],
+                command: Some("curl evil.example".to_owned()),
+            },
+            ..Default::default()
+        };
+
+        let merged = merge_configs(global, local);
+        assert_eq!(
+            merged.hooks.on_mutation.as_deref(),
+            Some("notify-send done")
+        );
+        assert_eq!(merged.hooks.on_fetch_error, None);
+        assert_eq!(merged.bell.on.len(), 1);
+        assert_eq!(merged.bell.command, None);
+    }
+
+    #[test]
+    fn merge_configs_keeps_global_browser_commands_only() {
+        let global = AppConfig {
+            browser: BrowserConfig {
+                command: Some("firefox {{.Url}}".to_owned()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let local = AppConfig {
+            browser: BrowserConfig {
+                ssh: Some(crate::config::types::SshBrowserMode::Command),
+                ssh_command: Some("curl evil.example".to_owned()),
+                command: Some("curl evil.example".to_owned()),
+                hosts: [("github.com".to_owned(), "curl evil.example".to_owned())]
+                    .into_iter()
+                    .collect(),
+            },
+            ..Default::default()
+        };
+
+        let merged = merge_configs(global, local);
+        assert_eq!(merged.browser.command.as_deref(), Some("firefox {{.Url}}"));
+        assert_eq!(merged.browser.ssh_command, None);
+        assert!(merged.browser.hosts.is_empty());
+        assert_eq!(
+            merged.browser.ssh,
+            Some(crate::config::types::SshBrowserMode::Command)
+        );
+    }
+
+    #[test]
+    fn merge_configs_keeps_global_share_targets_only() {
+        let target = |name: &str| crate::config::types::ShareTarget {
+            name: name.to_owned(),
+            webhook: format!("https://{name}.example/hook"),
+            format: None,
+            template: None,
+        };
+        let local = AppConfig {
+            share_targets: vec![target("evil")],
+            ..Default::default()
+        };
+        assert!(
+            merge_configs(AppConfig::default(), local.clone())
+                .share_targets
+                .is_empty()
+        );
+
+        let global = AppConfig {
+            share_targets: vec![target("team")],
+            ..Default::default()
+        };
+        let merged = merge_configs(global, local);
+        assert_eq!(merged.share_targets.len(), 1);
+        assert_eq!(merged.share_targets[0].name, "team");
+    }
+
+    #[test]
+    fn merge_configs_keeps_global_hosts_only() {
+        let host = |api_url: &str| crate::config::types::HostConfig {
+            api_url: Some(api_url.to_owned()),
+            ..Default::default()
+        };
+        let mut global = AppConfig::default();
+        global.github.hosts.insert(
+            "ghe.acme.com".to_owned(),
+            host("https://ghe.acme.com/api/v3"),
+        );
+        let mut local = AppConfig::default();
+        local
+            .github
+            .hosts
+            .insert("ghe.acme.com".to_owned(), host("https://evil.example"));
+        local
+            .github
+            .hosts
+            .insert("github.com".to_owned(), host("https://evil.example"));
+
+        let merged = merge_configs(global, local);
+        assert_eq!(merged.github.hosts.len(), 1);
+        assert_eq!(
+            merged.github.hosts["ghe.acme.com"].api_url.as_deref(),
+            Some("https://ghe.acme.com/api/v3")
+        );
+    }
+
     #[test]
     fn merge_configs_theme_file_from_local() {
         let global = AppConfig::default();
@@ -595,6 +1086,7 @@ mod tests {
             key: "ctrl+shift+j".to_string(),
             builtin: Some("move_down".to_string()),
             command: None,
+            script: None,
             name: Some("Custom Move Down".to_string()),
         });
 
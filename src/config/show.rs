@@ -151,6 +151,9 @@ const FILTER_ARRAY_KEYS: &[&str] = &[
     "actions_filters",
     "notifications_filters",
     "alerts_filters",
+    "events_filters",
+    "notification_rules",
+    "reply_templates",
 ];
 
 /// Merge `overlay` on top of `base`, tracking which source provides each key.
@@ -26,6 +26,7 @@
 // This enables partial configs where only desired overrides need be specified.
 use std::collections::HashMap;
 
+use chrono::NaiveTime;
 use indexmap::IndexMap;
 use std::path::PathBuf;
 
@@ -40,6 +41,7 @@ use crate::config::keybindings::KeybindingsConfig;
 
 /// Deserialize an `Option<Color>` from a TOML string value.
 pub(crate) mod color_de {
+    use indexmap::IndexMap;
     use serde::{self, Deserialize, Deserializer};
 
     use crate::color::Color;
@@ -56,6 +58,20 @@ pub(crate) mod color_de {
                 .map_err(serde::de::Error::custom),
         }
     }
+
+    /// A table of name → color strings, keeping the order of the file.
+    pub fn deserialize_map<'de, D>(deserializer: D) -> Result<IndexMap<String, Color>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: IndexMap<String, String> = IndexMap::deserialize(deserializer)?;
+        raw.into_iter()
+            .map(|(name, s)| {
+                let color = Color::parse(&s, &name).map_err(serde::de::Error::custom)?;
+                Ok((name, color))
+            })
+            .collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -75,6 +91,17 @@ pub struct AppConfig {
     pub notifications_filters: Vec<NotificationFilter>,
     #[serde(default, rename = "alerts_filters")]
     pub alerts_filters: Vec<AlertsFilter>,
+    #[serde(default, rename = "events_filters")]
+    pub events_filters: Vec<EventsFilter>,
+    /// Mute and priority rules applied to every fetched notification.
+    #[serde(default, rename = "notification_rules")]
+    pub notification_rules: Vec<NotificationRule>,
+    /// Snippets offered by the comment composer's template picker.
+    #[serde(default, rename = "reply_templates")]
+    pub reply_templates: Vec<ReplyTemplate>,
+    /// Webhooks the `share` action posts the selected item to.
+    #[serde(default, rename = "share_targets")]
+    pub share_targets: Vec<ShareTarget>,
     pub github: GitHubConfig,
     pub defaults: Defaults,
     pub theme: Theme,
@@ -87,6 +114,178 @@ pub struct AppConfig {
     pub theme_file: Option<String>,
     #[serde(default)]
     pub actions: ActionsConfig,
+    pub browser: BrowserConfig,
+    pub spell: SpellConfig,
+    pub release_notes: ReleaseNotesConfig,
+    pub copy: CopyConfig,
+    pub hooks: HooksConfig,
+    pub bell: BellConfig,
+    pub log: LogConfig,
+}
+
+// ---------------------------------------------------------------------------
+// Browser settings
+// ---------------------------------------------------------------------------
+
+/// How URLs are opened (`[browser]`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BrowserConfig {
+    /// What opening a URL does inside an SSH session (default: `osc52`).
+    pub ssh: Option<SshBrowserMode>,
+    /// Command forwarding the open to the SSH client when `ssh = "command"`;
+    /// `{{.Url}}` is replaced by the URL.
+    pub ssh_command: Option<String>,
+    /// Command opening URLs instead of the OS opener (e.g.
+    /// `firefox -P work {{.Url}}`).
+    pub command: Option<String>,
+    /// Host → command opening the URLs of that host, overriding `command`.
+    pub hosts: IndexMap<String, String>,
+}
+
+/// Browser opening inside an SSH session, where the local opener would
+/// start a browser on the remote machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SshBrowserMode {
+    /// Copy the URL to the client's clipboard with an OSC 52 escape.
+    Osc52,
+    /// Run `browser.ssh_command`.
+    Command,
+    /// Open on the remote machine anyway (X forwarding, a remote desktop).
+    Local,
+}
+
+// ---------------------------------------------------------------------------
+// Event hooks
+// ---------------------------------------------------------------------------
+
+/// Shell commands run when something happens (`[hooks]`), with the event's
+/// fields in `GH_BOARD_*` environment variables. Only read from the global
+/// config, so a repository's `.gh-board.toml` cannot run commands.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// A mutation (approve, merge, comment, rerun, …) succeeded.
+    pub on_mutation: Option<String>,
+    /// The CI status of a watched PR changed.
+    pub on_ci_change: Option<String>,
+    /// A fetch or a background refresh failed.
+    pub on_fetch_error: Option<String>,
+}
+
+/// The terminal bell, rung when a PR tab shows something new for the user
+/// (`[bell]`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BellConfig {
+    /// Events that ring; none by default.
+    pub on: Vec<BellEvent>,
+    /// Shell command run for each event instead of ringing, with the event
+    /// in `GH_BOARD_EVENT` and the PR in other `GH_BOARD_*` variables. Only
+    /// read from the global config.
+    pub command: Option<String>,
+}
+
+/// Something that rings the bell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BellEvent {
+    /// CI started failing on one of the user's PRs.
+    CiFailure,
+    /// The user's review (or one of their teams') was requested.
+    ReviewRequest,
+}
+
+impl BellEvent {
+    /// The name in config and in `GH_BOARD_EVENT`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::CiFailure => "ci_failure",
+            Self::ReviewRequest => "review_request",
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Logging
+// ---------------------------------------------------------------------------
+
+/// The session log file (`[log]`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LogConfig {
+    /// Line format (default: `compact`).
+    pub format: Option<LogFormat>,
+    /// Lowest level written, or an `EnvFilter` directive such as
+    /// `warn,gh_board::engine=debug` (default: `warn`).
+    pub level: Option<String>,
+}
+
+/// How log lines are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// One human-readable line per event.
+    #[default]
+    Compact,
+    /// One JSON object per line.
+    Json,
+}
+
+// ---------------------------------------------------------------------------
+// Copy menu
+// ---------------------------------------------------------------------------
+
+/// Entries of the copy menu of the PR and Issue views (`[copy]`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CopyConfig {
+    /// Menu label → template, in menu order; `{{.Number}}`, `{{.Title}}`,
+    /// `{{.Url}}` and `{{.RepoName}}` are replaced (default: a markdown link
+    /// and an `owner/repo#123` reference).
+    pub templates: IndexMap<String, String>,
+    /// Past copies listed below the templates (default: 10; 0 turns the
+    /// history off).
+    pub history: Option<usize>,
+}
+
+// ---------------------------------------------------------------------------
+// Spell checking
+// ---------------------------------------------------------------------------
+
+/// Spell checking of the comment composer (`[spell]`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SpellConfig {
+    /// Underline misspelled words (default: true when a dictionary is found).
+    pub enabled: Option<bool>,
+    /// Hunspell dictionary name, looked up in the usual dictionary
+    /// directories (default: `en_US`).
+    pub language: Option<String>,
+    /// Path to a `.dic` file, instead of looking up `language`; its `.aff`
+    /// file is read from the same directory.
+    pub dictionary: Option<String>,
+    /// Extra accepted words (project names, jargon).
+    pub words: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Release notes
+// ---------------------------------------------------------------------------
+
+/// Release notes drafted from the Branches view (`[release_notes]`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ReleaseNotesConfig {
+    /// Section title → labels, in display order. A PR goes under the first
+    /// section one of its labels matches (default: Features, Fixes, Chores).
+    pub sections: IndexMap<String, Vec<String>>,
+    /// Section for PRs matching none of `sections` (default: "Other changes").
+    pub other_section: Option<String>,
+    /// PRs carrying any of these labels are left out (default:
+    /// `["skip-changelog"]`).
+    pub exclude_labels: Option<Vec<String>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -111,6 +310,31 @@ pub struct ActionsConfig {
     /// effective when the workflow uses GitHub's auto-format job names
     /// (no `name:` template). Default: `false`.
     pub group_matrix_jobs: Option<bool>,
+    /// Log lines shown under the failed step of a failed job in the
+    /// run-detail sidebar; `0` turns the excerpts off (default: 30).
+    pub failed_step_log_lines: Option<usize>,
+    /// Runs created more than this many days ago are offered for deletion by
+    /// the run cleanup action (default: 90).
+    pub cleanup_older_than_days: Option<u32>,
+    /// Named `workflow_dispatch` runs offered by the quick-run overlay.
+    pub dispatch_presets: Vec<DispatchPreset>,
+}
+
+/// A saved manual run of a workflow (`[[actions.dispatch_presets]]`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct DispatchPreset {
+    pub name: String,
+    /// `"owner/repo"` or `"@current"`; defaults to the active tab's repo.
+    pub repo: Option<String>,
+    /// Workflow file name (`deploy.yml`) or numeric id.
+    pub workflow: String,
+    /// Branch or tag to run on; defaults to the repository's default branch.
+    #[serde(rename = "ref")]
+    pub git_ref: Option<String>,
+    /// `workflow_dispatch` inputs.
+    #[serde(default)]
+    pub inputs: IndexMap<String, String>,
+    pub host: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -128,38 +352,178 @@ pub struct GitHubConfig {
     /// When `true`, automatically clone a repo via `gh repo clone` if the
     /// configured `repo_paths` target doesn't exist yet (checkout / worktree).
     pub auto_clone: Option<bool>,
+    /// Daily window during which background refresh is suspended.
+    pub quiet_hours: Option<QuietHours>,
+    /// Seconds a single engine request may run before it is cancelled
+    /// (default: 15). The background refresh gets twice as long.
+    pub request_timeout_seconds: Option<u32>,
+    /// When `true`, files marked viewed in gh-board are also marked viewed
+    /// on GitHub (default: `false`, kept locally only).
+    pub sync_viewed_files: Option<bool>,
+    /// When `true`, watch the directory worked in outside gh-board and offer
+    /// to scope to its repo when it changes (default: `false`).
+    pub smart_scope: Option<bool>,
+    /// When `false`, lists are not kept on disk between sessions (default:
+    /// `true`: a new session shows the last results while refreshing).
+    pub disk_cache: Option<bool>,
+    /// When `false`, skip checking each host's token and connectivity
+    /// before the views load (default: `true`).
+    pub startup_check: Option<bool>,
+    /// How `merge` and merge when green merge PRs (default: `merge`).
+    pub merge_method: Option<MergeMethod>,
+    /// `[github.hosts."<host>"]`: how to reach each Enterprise host filters
+    /// name. Only the global config sets them.
+    pub hosts: IndexMap<String, HostConfig>,
+}
+
+/// API endpoints and token of one host. Unset endpoints follow the host:
+/// `https://<host>/api/v3` and `https://<host>/api/graphql` for Enterprise
+/// Server, `https://api.<host>` and its `/graphql` for GHE.com.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct HostConfig {
+    /// Root of the REST API.
+    pub api_url: Option<String>,
+    /// The GraphQL endpoint, ending in `/graphql`.
+    pub graphql_url: Option<String>,
+    /// Environment variable holding the host's token, read before
+    /// `gh auth token`.
+    pub token_env: Option<String>,
+}
+
+/// `[github.quiet_hours]`: a daily `start`–`end` window in the configured
+/// timezone. A window whose end precedes its start spans midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct QuietHours {
+    /// `"HH:MM"`, inclusive.
+    pub start: NaiveTime,
+    /// `"HH:MM"`, exclusive.
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Whether `time` of day falls inside the window.
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Filters
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct PrFilter {
     pub title: String,
     pub filters: String,
     pub limit: Option<u32>,
     pub host: Option<String>,
     pub layout: Option<LayoutConfig>,
+    /// Start with draft and work-in-progress PRs hidden in this tab
+    /// (default: false). The `toggle_drafts` key flips it per tab.
+    pub hide_drafts: Option<bool>,
+    /// Open (`true`) or close (`false`) the preview pane when switching to
+    /// this tab (default: leave it as it is).
+    pub preview: Option<bool>,
+    /// Sidebar tab the preview shows when switching to this tab.
+    pub preview_tab: Option<PreviewTab>,
+    /// Minutes between background refreshes of this tab, overriding
+    /// `github.refetch_interval_minutes`.
+    pub refetch_interval_minutes: Option<u32>,
+    /// Count this tab's PRs next to the PRs view in the footer, fetching it
+    /// in the background (default: false).
+    pub badge: Option<bool>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct IssueFilter {
     pub title: String,
     pub filters: String,
     pub limit: Option<u32>,
     pub host: Option<String>,
     pub layout: Option<LayoutConfig>,
+    /// `type = "any"` lists pull requests alongside the issues the query
+    /// matches (default: issues only).
+    #[serde(default, rename = "type")]
+    pub kind: IssueFilterKind,
+    /// Open (`true`) or close (`false`) the preview pane when switching to
+    /// this tab (default: leave it as it is).
+    pub preview: Option<bool>,
+    /// Sidebar tab the preview shows when switching to this tab.
+    pub preview_tab: Option<PreviewTab>,
+    /// Minutes between background refreshes of this tab, overriding
+    /// `github.refetch_interval_minutes`.
+    pub refetch_interval_minutes: Option<u32>,
+    /// Count this tab's items next to the Issues view in the footer,
+    /// fetching it in the background (default: false).
+    pub badge: Option<bool>,
+    /// Show the tab as a kanban board instead of a table.
+    pub board: Option<BoardConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// Kanban layout of an issues filter.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BoardConfig {
+    /// What the columns group by.
+    pub by: BoardGrouping,
+    /// Column values, left to right: label names, milestone titles or
+    /// assignee logins. Items matching none of them go to a leading column.
+    pub columns: Vec<String>,
+}
+
+/// What a board's columns group by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardGrouping {
+    Label,
+    Milestone,
+    Assignee,
+}
+
+/// What an issues filter searches for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueFilterKind {
+    /// Issues only: `is:issue` is added to the query.
+    #[default]
+    Issue,
+    /// Issues and pull requests, in one mixed tab.
+    Any,
+}
+
+/// A preview sidebar tab, for a filter's `preview_tab`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewTab {
+    Overview,
+    Activity,
+    /// PRs only.
+    Commits,
+    /// PRs only.
+    Checks,
+    /// PRs only.
+    Files,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ActionsFilter {
     pub title: String,
     /// `"owner/repo"` — the repository to fetch workflow runs for.
     /// Use `"@current"` to resolve to the repository detected from the
     /// current working directory (requires running gh-board inside a git repo).
     /// The fetch is skipped when `@current` is used but no repo is in context.
+    /// May be omitted when `repos` is set.
+    #[serde(default)]
     pub repo: String,
+    /// Several repositories merged into one tab: `"owner/repo"` entries and
+    /// `"org:name"` for every repository of an organization. When set,
+    /// `repo` is ignored.
+    #[serde(default)]
+    pub repos: Vec<String>,
     pub host: Option<String>,
     pub limit: Option<u32>,
     /// GitHub API `status` query param: `"queued"`, `"in_progress"`, `"completed"`,
@@ -169,31 +533,140 @@ pub struct ActionsFilter {
     /// GitHub API `event` query param: `"push"`, `"pull_request"`, `"schedule"`,
     /// `"workflow_dispatch"`, …
     pub event: Option<String>,
+    /// GitHub API `branch` query param: only runs whose head is this branch
+    /// or tag.
+    pub branch: Option<String>,
+    /// Minutes between background refreshes of this tab, overriding
+    /// `github.refetch_interval_minutes`.
+    pub refetch_interval_minutes: Option<u32>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct AlertsFilter {
     pub title: String,
     /// `"owner/repo"` or `"@current"` for auto-detection.
     pub repo: String,
     pub host: Option<String>,
     pub limit: Option<u32>,
+    /// Minutes between background refreshes of this tab, overriding
+    /// `github.refetch_interval_minutes`.
+    pub refetch_interval_minutes: Option<u32>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// A tab of the Events view: recent changes in one organization, or an
+/// activity feed of `repos` or of what the viewer receives.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EventsFilter {
+    pub title: String,
+    /// Organization login.
+    #[serde(default)]
+    pub org: Option<String>,
+    /// Feed of these `owner/repo` repositories' events, merged.
+    #[serde(default)]
+    pub repos: Vec<String>,
+    /// Feed of the events GitHub shows on the viewer's dashboard: the
+    /// repositories they watch and the people they follow.
+    #[serde(default)]
+    pub received: bool,
+    /// Only events by this user.
+    pub actor: Option<String>,
+    /// Only actions starting with this, e.g. `"repo."` or `"org.add_member"`.
+    pub action: Option<String>,
+    pub host: Option<String>,
+    pub limit: Option<u32>,
+    /// Minutes between background refreshes of this tab, overriding
+    /// `github.refetch_interval_minutes`.
+    pub refetch_interval_minutes: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct NotificationFilter {
     pub title: String,
     pub filters: String,
     pub limit: Option<u32>,
     pub host: Option<String>,
+    /// Minutes between background refreshes of this tab, overriding
+    /// `github.refetch_interval_minutes`.
+    pub refetch_interval_minutes: Option<u32>,
+    /// Count this tab's unread notifications next to the Notifications view
+    /// in the footer, fetching it in the background (default: true for the
+    /// first tab only).
+    pub badge: Option<bool>,
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+/// A rule matching notifications by repository, reason and subject author.
+///
+/// Every criterion that is set must match; `repo` and `author` accept `*`
+/// wildcards and match case-insensitively.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationRule {
+    /// `owner/name` pattern, e.g. `"acme/*"`.
+    pub repo: Option<String>,
+    /// Reason as used by `reason:` filters, e.g. `"ci_activity"`.
+    pub reason: Option<String>,
+    /// Login pattern of the PR or issue author, e.g. `"dependabot*"`.
+    pub author: Option<String>,
+    pub action: NotificationRuleAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationRuleAction {
+    /// Mark the thread done on GitHub and hide it.
+    Done,
+    /// Keep the notification but list it after all the others.
+    Demote,
+}
+
+/// A local comment snippet, listed next to the GitHub saved replies.
+///
+/// The body may use `{{author}}`, `{{number}}`, `{{title}}` and `{{repo}}`,
+/// expanded for the PR or issue being commented on.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReplyTemplate {
+    pub name: String,
+    pub body: String,
+}
+
+/// A webhook the `share` action posts to (`[[share_targets]]`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShareTarget {
+    pub name: String,
+    pub webhook: String,
+    /// Payload shape (default: `slack`).
+    #[serde(default)]
+    pub format: Option<ShareFormat>,
+    /// Message; `{{.Title}}`, `{{.Url}}`, `{{.Number}}`, `{{.RepoName}}` and
+    /// `{{.State}}` are replaced.
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+/// JSON payload posted to a share target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShareFormat {
+    /// `{"text": …}` (Slack incoming webhooks, Mattermost, Google Chat).
+    Slack,
+    /// `{"content": …}`.
+    Discord,
+    /// The message plus the item's fields.
+    Generic,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
 #[serde(default)]
 pub struct LayoutConfig {
     // Per-column width/hidden overrides. Specific fields TBD.
     pub hidden: Vec<String>,
+    /// Optional columns to show, e.g. `reactions` in PR tabs.
+    pub shown: Vec<String>,
     pub widths: HashMap<String, u16>,
+    /// PR tabs: template of a row's first line, e.g. `{{title}}` (default:
+    /// `{{repo}} {{number}} by {{author}}`).
+    pub info: Option<String>,
+    /// PR tabs: template of a row's second line (default: `{{title}}`).
+    pub subtitle: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -209,6 +682,27 @@ pub enum Scope {
     Global,
 }
 
+/// How a PR's commits land on its base branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeMethod {
+    #[default]
+    Merge,
+    Squash,
+    Rebase,
+}
+
+impl MergeMethod {
+    /// The `merge_method` the REST API takes.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Merge => "merge",
+            Self::Squash => "squash",
+            Self::Rebase => "rebase",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[derive(Default)]
@@ -220,6 +714,7 @@ pub enum View {
     Alerts,
     Notifications,
     Repo,
+    Events,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -228,14 +723,49 @@ pub struct Defaults {
     pub view: Option<View>,
     pub preview: PreviewDefaults,
     pub date_format: Option<String>,
+    /// Timezone for absolute dates: `"local"` (default), `"utc"`, an offset
+    /// like `"+05:30"`, or an IANA name like `"Europe/Paris"`.
+    pub timezone: Option<String>,
+    /// Maximum number of PR/issue details and run job lists each view keeps
+    /// in memory; the least recently viewed entries are evicted first
+    /// (default: 100).
+    pub detail_cache_size: Option<u32>,
+    pub stale: StaleDefaults,
+    pub pr_size: PrSizeDefaults,
+    /// Title prefixes marking a PR as work in progress, matched
+    /// case-insensitively (default: [`DEFAULT_WIP_PATTERNS`]).
+    pub wip_patterns: Option<Vec<String>>,
 }
 
+/// WIP title prefixes used when `defaults.wip_patterns` is unset.
+pub const DEFAULT_WIP_PATTERNS: &[&str] = &["WIP", "[WIP]", "Draft:", "[Draft]"];
+
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct PreviewDefaults {
     pub width: Option<f64>,
 }
 
+/// Tint the "updated" date of open PRs and issues that have not moved for
+/// this many days.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct StaleDefaults {
+    pub warn_days: Option<u32>,
+    pub alert_days: Option<u32>,
+}
+
+/// Lines changed (additions + deletions) below which a PR falls in each size
+/// class; larger PRs are XL.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PrSizeDefaults {
+    pub xs: Option<u32>,
+    pub s: Option<u32>,
+    pub m: Option<u32>,
+    pub l: Option<u32>,
+}
+
 // ---------------------------------------------------------------------------
 // Theme
 // ---------------------------------------------------------------------------
@@ -257,6 +787,8 @@ impl Theme {
         Theme {
             ui: UiTheme {
                 filters_show_count: overlay.ui.filters_show_count.or(base.ui.filters_show_count),
+                screen_reader: overlay.ui.screen_reader.or(base.ui.screen_reader),
+                ascii: overlay.ui.ascii.or(base.ui.ascii),
                 table: TableTheme {
                     show_separator: overlay
                         .ui
@@ -264,6 +796,7 @@ impl Theme {
                         .show_separator
                         .or(base.ui.table.show_separator),
                     compact: overlay.ui.table.compact.or(base.ui.table.compact),
+                    label_tint: overlay.ui.table.label_tint.or(base.ui.table.label_tint),
                 },
             },
             colors: merge_colors(&base.colors, &overlay.colors),
@@ -313,6 +846,11 @@ fn merge_colors(base: &ColorsTheme, overlay: &ColorsTheme) -> ColorsTheme {
             separator: overlay.pill.separator.or(base.pill.separator),
         },
         markdown: merge_markdown(&base.markdown, &overlay.markdown),
+        labels: if overlay.labels.is_empty() {
+            base.labels.clone()
+        } else {
+            overlay.labels.clone()
+        },
         footer: FooterColors {
             prs: overlay.footer.prs.or(base.footer.prs),
             issues: overlay.footer.issues.or(base.footer.issues),
@@ -320,6 +858,7 @@ fn merge_colors(base: &ColorsTheme, overlay: &ColorsTheme) -> ColorsTheme {
             repo: overlay.footer.repo.or(base.footer.repo),
             actions: overlay.footer.actions.or(base.footer.actions),
             alerts: overlay.footer.alerts.or(base.footer.alerts),
+            events: overlay.footer.events.or(base.footer.events),
         },
     }
 }
@@ -428,12 +967,16 @@ fn merge_icons(base: IconConfig, overlay: IconConfig) -> IconConfig {
         check_skipped: overlay.check_skipped.or(base.check_skipped),
         check_cancelled: overlay.check_cancelled.or(base.check_cancelled),
         check_action_required: overlay.check_action_required.or(base.check_action_required),
+        commit_verified: overlay.commit_verified.or(base.commit_verified),
+        commit_unverified: overlay.commit_unverified.or(base.commit_unverified),
+        commit_unsigned: overlay.commit_unsigned.or(base.commit_unsigned),
         branch_arrow: overlay.branch_arrow.or(base.branch_arrow),
         tab_overview: overlay.tab_overview.or(base.tab_overview),
         tab_activity: overlay.tab_activity.or(base.tab_activity),
         tab_commits: overlay.tab_commits.or(base.tab_commits),
         tab_checks: overlay.tab_checks.or(base.tab_checks),
         tab_files: overlay.tab_files.or(base.tab_files),
+        tab_links: overlay.tab_links.or(base.tab_links),
         role_newcontributor: overlay.role_newcontributor.or(base.role_newcontributor),
         role_contributor: overlay.role_contributor.or(base.role_contributor),
         role_collaborator: overlay.role_collaborator.or(base.role_collaborator),
@@ -446,6 +989,7 @@ fn merge_icons(base: IconConfig, overlay: IconConfig) -> IconConfig {
         view_repo: overlay.view_repo.or(base.view_repo),
         view_actions: overlay.view_actions.or(base.view_actions),
         view_alerts: overlay.view_alerts.or(base.view_alerts),
+        view_events: overlay.view_events.or(base.view_events),
         tab_filter: overlay.tab_filter.or(base.tab_filter),
         pill_left: overlay.pill_left.or(base.pill_left),
         pill_right: overlay.pill_right.or(base.pill_right),
@@ -458,6 +1002,7 @@ fn merge_icons(base: IconConfig, overlay: IconConfig) -> IconConfig {
         feedback_warning: overlay.feedback_warning.or(base.feedback_warning),
         feedback_info: overlay.feedback_info.or(base.feedback_info),
         tab_ephemeral: overlay.tab_ephemeral.or(base.tab_ephemeral),
+        tab_refresh: overlay.tab_refresh.or(base.tab_refresh),
         select_cursor: overlay.select_cursor.or(base.select_cursor),
         action_success: overlay.action_success.or(base.action_success),
         action_failure: overlay.action_failure.or(base.action_failure),
@@ -467,6 +1012,7 @@ fn merge_icons(base: IconConfig, overlay: IconConfig) -> IconConfig {
         action_queued: overlay.action_queued.or(base.action_queued),
         action_watched: overlay.action_watched.or(base.action_watched),
         repo_anchor: overlay.repo_anchor.or(base.repo_anchor),
+        item_note: overlay.item_note.or(base.item_note),
     }
 }
 
@@ -474,6 +1020,11 @@ fn merge_icons(base: IconConfig, overlay: IconConfig) -> IconConfig {
 #[serde(default)]
 pub struct UiTheme {
     pub filters_show_count: Option<bool>,
+    /// Render for terminal screen readers: no box-drawing or icon glyphs,
+    /// one labelled text line per row.
+    pub screen_reader: Option<bool>,
+    /// Draw icons, borders and separators with ASCII characters only.
+    pub ascii: Option<bool>,
     pub table: TableTheme,
 }
 
@@ -482,6 +1033,17 @@ pub struct UiTheme {
 pub struct TableTheme {
     pub show_separator: Option<bool>,
     pub compact: Option<bool>,
+    /// What `[theme.colors.labels]` tints: the whole row (default) or only
+    /// the state cell.
+    pub label_tint: Option<LabelTint>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelTint {
+    #[default]
+    Row,
+    State,
 }
 
 // ---------------------------------------------------------------------------
@@ -498,6 +1060,10 @@ pub struct ColorsTheme {
     pub pill: PillColors,
     pub markdown: MarkdownColors,
     pub footer: FooterColors,
+    /// Label name → background of the PR and issue rows carrying it. Names
+    /// match case-insensitively; the first listed label an item has wins.
+    #[serde(deserialize_with = "color_de::deserialize_map")]
+    pub labels: IndexMap<String, Color>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -515,6 +1081,8 @@ pub struct FooterColors {
     pub actions: Option<Color>,
     #[serde(default, deserialize_with = "color_de::deserialize")]
     pub alerts: Option<Color>,
+    #[serde(default, deserialize_with = "color_de::deserialize")]
+    pub events: Option<Color>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -751,6 +1319,10 @@ pub struct IconConfig {
     pub check_skipped: Option<String>,
     pub check_cancelled: Option<String>,
     pub check_action_required: Option<String>,
+    // Commit signatures
+    pub commit_verified: Option<String>,
+    pub commit_unverified: Option<String>,
+    pub commit_unsigned: Option<String>,
     // Sidebar decorative
     pub branch_arrow: Option<String>,
     // Sidebar tabs
@@ -759,6 +1331,7 @@ pub struct IconConfig {
     pub tab_commits: Option<String>,
     pub tab_checks: Option<String>,
     pub tab_files: Option<String>,
+    pub tab_links: Option<String>,
     // Author roles
     pub role_newcontributor: Option<String>,
     pub role_contributor: Option<String>,
@@ -773,6 +1346,7 @@ pub struct IconConfig {
     pub view_repo: Option<String>,
     pub view_actions: Option<String>,
     pub view_alerts: Option<String>,
+    pub view_events: Option<String>,
     // Tab filter marker
     pub tab_filter: Option<String>,
     // Pill caps (rounded edges)
@@ -790,6 +1364,7 @@ pub struct IconConfig {
     pub feedback_info: Option<String>,
     // UI chrome
     pub tab_ephemeral: Option<String>,
+    pub tab_refresh: Option<String>,
     pub select_cursor: Option<String>,
     // Actions run status
     pub action_success: Option<String>,
@@ -801,4 +1376,6 @@ pub struct IconConfig {
     pub action_watched: Option<String>,
     // Repo picker
     pub repo_anchor: Option<String>,
+    // Local notes
+    pub item_note: Option<String>,
 }
@@ -0,0 +1,244 @@
+//! Cron schedules of workflow files.
+//!
+//! GitHub reads `on.schedule[].cron` as a five-field POSIX cron expression
+//! evaluated in UTC: minute, hour, day of month, month and day of week, with
+//! `*`, lists, ranges, steps and `JAN`–`DEC` / `SUN`–`SAT` names. As in
+//! Vixie cron, a day matches either of the two day fields when both are
+//! restricted.
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAYS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Days searched for a next fire time: enough for `0 0 29 2 *`.
+const SEARCH_DAYS: u32 = 8 * 366;
+
+/// A parsed cron expression; each field is a bit set of the values it allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cron {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// The day-of-month field is `*`-based.
+    any_day: bool,
+    /// The day-of-week field is `*`-based.
+    any_weekday: bool,
+}
+
+impl Cron {
+    pub(crate) fn parse(expr: &str) -> Result<Self> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            bail!("expected 5 fields, found {}", fields.len());
+        };
+        let mut weekdays = field(weekday, 0, 7, &WEEKDAYS).context("day of week")?;
+        // 7 is Sunday too.
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            minutes: field(minute, 0, 59, &[]).context("minute")?,
+            hours: field(hour, 0, 23, &[]).context("hour")?,
+            days: field(day, 1, 31, &[]).context("day of month")?,
+            months: field(month, 1, 12, &MONTHS).context("month")?,
+            weekdays,
+            any_day: day.starts_with('*'),
+            any_weekday: weekday.starts_with('*'),
+        })
+    }
+
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        let either = match (self.any_day, self.any_weekday) {
+            (true, true) => true,
+            (true, false) => weekday,
+            (false, true) => day,
+            (false, false) => day || weekday,
+        };
+        either && self.months & (1 << date.month()) != 0
+    }
+
+    /// First fire time strictly after `after`; `None` when the expression
+    /// never fires (`0 0 31 2 *`).
+    pub(crate) fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = (after + Duration::minutes(1))
+            .with_second(0)?
+            .with_nanosecond(0)?;
+        let mut date = start.date_naive();
+        for _ in 0..SEARCH_DAYS {
+            if self.day_matches(date) {
+                let (first_hour, first_minute) = if date == start.date_naive() {
+                    (start.hour(), start.minute())
+                } else {
+                    (0, 0)
+                };
+                for hour in (first_hour..24).filter(|h| self.hours & (1 << h) != 0) {
+                    let from = if hour == first_hour { first_minute } else { 0 };
+                    if let Some(minute) = (from..60).find(|m| self.minutes & (1 << m) != 0) {
+                        return Some(date.and_hms_opt(hour, minute, 0)?.and_utc());
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+}
+
+/// Bit set of the values a comma-separated cron field allows.
+fn field(text: &str, min: u32, max: u32, names: &[&str]) -> Result<u64> {
+    let value = |s: &str| -> Result<u32> {
+        let upper = s.to_ascii_uppercase();
+        if let Some(i) = names.iter().position(|&n| n == upper) {
+            // Names count from the field's first value: JAN is 1, SUN is 0.
+            return Ok(u32::try_from(i)? + min);
+        }
+        let n: u32 = s.parse().with_context(|| format!("invalid value {s:?}"))?;
+        if !(min..=max).contains(&n) {
+            bail!("{n} is out of range {min}-{max}");
+        }
+        Ok(n)
+    };
+    let mut bits = 0u64;
+    for part in text.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|&s| s > 0)
+                    .with_context(|| format!("invalid step {step:?}"))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (first, last) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (value(a)?, value(b)?)
+        } else if step > 1 {
+            // `5/15`: from 5 to the end of the range.
+            (value(range)?, max)
+        } else {
+            let n = value(range)?;
+            (n, n)
+        };
+        if first > last {
+            bail!("empty range {range:?}");
+        }
+        for n in (first..=last).step_by(step as usize) {
+            bits |= 1 << n;
+        }
+    }
+    Ok(bits)
+}
+
+/// Top-level `name` and the `cron` expressions of a workflow file.
+///
+/// A line scan rather than a YAML parser: `cron` keys only appear under
+/// `on.schedule`, so every `cron:` entry is a schedule.
+pub(crate) fn workflow_crons(text: &str) -> (Option<String>, Vec<String>) {
+    let unquote = |value: &str| {
+        let value = match value.find(" #") {
+            Some(at) => &value[..at],
+            None => value,
+        };
+        value.trim().trim_matches(['\'', '"']).trim().to_owned()
+    };
+    let mut name = None;
+    let mut crons = Vec::new();
+    for line in text.lines() {
+        if let Some(value) = line.strip_prefix("name:") {
+            name = Some(unquote(value)).filter(|n| !n.is_empty());
+            continue;
+        }
+        let item = line.trim_start();
+        let item = item.strip_prefix('-').map_or(item, str::trim_start);
+        if let Some(value) = item.strip_prefix("cron:") {
+            let cron = unquote(value);
+            if !cron.is_empty() {
+                crons.push(cron);
+            }
+        }
+    }
+    (name, crons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn next(expr: &str, after: &str) -> Option<String> {
+        let next = Cron::parse(expr).unwrap().next_after(at(after))?;
+        Some(next.format("%Y-%m-%d %H:%M %a").to_string())
+    }
+
+    #[test]
+    fn next_after_steps_ranges_and_names() {
+        let after = "2024-05-15T10:07:30Z"; // a Wednesday
+        assert_eq!(next("*/15 * * * *", after).unwrap(), "2024-05-15 10:15 Wed");
+        assert_eq!(next("30 2 * * *", after).unwrap(), "2024-05-16 02:30 Thu");
+        assert_eq!(
+            next("0 9-17/4 * * MON-FRI", after).unwrap(),
+            "2024-05-15 13:00 Wed"
+        );
+        assert_eq!(next("0 0 * * sun", after).unwrap(), "2024-05-19 00:00 Sun");
+        assert_eq!(next("0 0 * * 7", after).unwrap(), "2024-05-19 00:00 Sun");
+        assert_eq!(
+            next("0 0 1 jan,jul *", after).unwrap(),
+            "2024-07-01 00:00 Mon"
+        );
+        assert_eq!(next("0 0 29 2 *", after).unwrap(), "2028-02-29 00:00 Tue");
+        assert_eq!(next("0 0 31 2 *", after), None);
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either() {
+        // The 20th, or any Friday.
+        let after = "2024-05-15T10:07:00Z";
+        assert_eq!(next("0 0 20 * 5", after).unwrap(), "2024-05-17 00:00 Fri");
+        assert_eq!(next("0 0 20 * *", after).unwrap(), "2024-05-20 00:00 Mon");
+    }
+
+    #[test]
+    fn parse_rejects_malformed_expressions() {
+        for expr in [
+            "* * * *",
+            "60 * * * *",
+            "* * 0 * *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+        ] {
+            assert!(Cron::parse(expr).is_err(), "{expr}");
+        }
+    }
+
+    #[test]
+    fn workflow_crons_reads_schedule_entries_and_name() {
+        let text = "name: \"Nightly build\"\n\
+                    on:\n  \
+                      schedule:\n    \
+                        - cron: '30 2 * * *'\n    \
+                        - cron: \"0 12 * * MON\" # weekly\n  \
+                      workflow_dispatch:\n\
+                    jobs:\n  \
+                      build:\n    \
+                        name: Build\n";
+        let (name, crons) = workflow_crons(text);
+        assert_eq!(name.as_deref(), Some("Nightly build"));
+        assert_eq!(crons, ["30 2 * * *", "0 12 * * MON"]);
+    }
+}
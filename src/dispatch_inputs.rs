@@ -0,0 +1,214 @@
+//! `workflow_dispatch` triggers of workflow files.
+//!
+//! Like [`crate::cron::workflow_crons`], an indentation scan rather than a
+//! YAML parser: it understands block mappings and sequences, flow lists
+//! (`[push, workflow_dispatch]`), quoted scalars and `|`/`>` descriptions,
+//! which covers how dispatch inputs are written in practice.
+
+use crate::types::{WorkflowInput, WorkflowInputKind};
+
+/// A meaningful line: indentation and content, comments stripped.
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+    indent: usize,
+    text: &'a str,
+}
+
+fn lines(text: &str) -> Vec<Line<'_>> {
+    text.lines()
+        .filter_map(|raw| {
+            let trimmed = raw.trim_start();
+            let text = match trimmed.find(" #") {
+                Some(at) => &trimmed[..at],
+                None => trimmed,
+            }
+            .trim_end();
+            (!text.is_empty() && !text.starts_with('#')).then_some(Line {
+                indent: raw.len() - trimmed.len(),
+                text,
+            })
+        })
+        .collect()
+}
+
+/// The lines nested under `lines[at]`: deeper ones, and sequence items at
+/// its own indentation.
+fn block<'a>(lines: &'a [Line<'a>], at: usize) -> &'a [Line<'a>] {
+    let base = lines[at].indent;
+    let rest = &lines[at + 1..];
+    let len = rest
+        .iter()
+        .position(|l| l.indent < base || (l.indent == base && !l.text.starts_with('-')))
+        .unwrap_or(rest.len());
+    &rest[..len]
+}
+
+/// `(index, key, value)` of the mapping entries at the first indentation
+/// of `lines`.
+fn entries<'a>(lines: &'a [Line<'a>]) -> impl Iterator<Item = (usize, &'a str, &'a str)> {
+    let indent = lines.first().map_or(0, |l| l.indent);
+    lines.iter().enumerate().filter_map(move |(i, line)| {
+        if line.indent != indent {
+            return None;
+        }
+        let (key, value) = line.text.split_once(':').unwrap_or((line.text, ""));
+        Some((i, unquote(key), value.trim()))
+    })
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches(['\'', '"']).trim()
+}
+
+/// Items of a flow list (`[a, 'b']`), or of the block sequence `lines`.
+fn sequence<'a>(value: &'a str, lines: &[Line<'a>]) -> Vec<&'a str> {
+    if let Some(flow) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        return flow
+            .split(',')
+            .map(unquote)
+            .filter(|s| !s.is_empty())
+            .collect();
+    }
+    lines
+        .iter()
+        .filter_map(|l| l.text.strip_prefix('-'))
+        .map(unquote)
+        .collect()
+}
+
+/// The inputs of the `workflow_dispatch` trigger of a workflow file, or
+/// `None` when it cannot be run manually.
+pub(crate) fn workflow_dispatch(text: &str) -> Option<Vec<WorkflowInput>> {
+    let lines = lines(text);
+    let (on_at, _, on_value) = entries(&lines).find(|(_, key, _)| *key == "on")?;
+    if !on_value.is_empty() {
+        let triggers = sequence(on_value, &[]);
+        let manual = on_value == "workflow_dispatch" || triggers.contains(&"workflow_dispatch");
+        return manual.then(Vec::new);
+    }
+    let triggers = block(&lines, on_at);
+    if sequence("", triggers).contains(&"workflow_dispatch") {
+        return Some(Vec::new());
+    }
+    let (dispatch_at, _, _) = entries(triggers).find(|(_, key, _)| *key == "workflow_dispatch")?;
+    let dispatch = block(triggers, dispatch_at);
+    let Some((inputs_at, _, _)) = entries(dispatch).find(|(_, key, _)| *key == "inputs") else {
+        return Some(Vec::new());
+    };
+    let inputs = block(dispatch, inputs_at);
+    Some(
+        entries(inputs)
+            .map(|(at, name, _)| input(name, block(inputs, at)))
+            .collect(),
+    )
+}
+
+/// Read one input from its property lines.
+fn input(name: &str, props: &[Line<'_>]) -> WorkflowInput {
+    let mut input = WorkflowInput {
+        name: name.to_owned(),
+        description: None,
+        required: false,
+        default: None,
+        kind: WorkflowInputKind::String,
+    };
+    let mut options = Vec::new();
+    for (at, key, value) in entries(props) {
+        match key {
+            "description" if value.starts_with(['|', '>']) => {
+                let text: Vec<&str> = block(props, at).iter().map(|l| l.text).collect();
+                input.description = Some(text.join(" ")).filter(|d| !d.is_empty());
+            }
+            "description" => {
+                input.description = Some(unquote(value).to_owned()).filter(|d| !d.is_empty());
+            }
+            "required" => input.required = unquote(value) == "true",
+            "default" => input.default = Some(unquote(value).to_owned()),
+            "type" => {
+                input.kind = match unquote(value) {
+                    "boolean" => WorkflowInputKind::Boolean,
+                    "number" => WorkflowInputKind::Number,
+                    "environment" => WorkflowInputKind::Environment,
+                    "choice" => WorkflowInputKind::Choice(Vec::new()),
+                    _ => WorkflowInputKind::String,
+                };
+            }
+            "options" => {
+                options = sequence(value, block(props, at))
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+    if let WorkflowInputKind::Choice(choices) = &mut input.kind {
+        *choices = options;
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_inputs_of_the_dispatch_trigger() {
+        let text = "name: Deploy\n\
+                    on:\n  \
+                      push:\n    \
+                        branches: [main]\n  \
+                      workflow_dispatch:\n    \
+                        inputs:\n      \
+                          environment:\n        \
+                            description: 'Where to deploy' # required\n        \
+                            required: true\n        \
+                            type: choice\n        \
+                            options:\n        \
+                            - staging\n        \
+                            - \"production\"\n      \
+                          dry_run:\n        \
+                            type: boolean\n        \
+                            default: true\n      \
+                          notes:\n        \
+                            description: >\n          \
+                              Shown in the\n          \
+                              run summary\n\
+                    jobs:\n  \
+                      deploy:\n    \
+                        inputs: ignored\n";
+        let inputs = workflow_dispatch(text).unwrap();
+        let names: Vec<&str> = inputs.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["environment", "dry_run", "notes"]);
+        assert_eq!(inputs[0].description.as_deref(), Some("Where to deploy"));
+        assert!(inputs[0].required);
+        assert_eq!(
+            inputs[0].kind,
+            WorkflowInputKind::Choice(vec!["staging".to_owned(), "production".to_owned()])
+        );
+        assert_eq!(inputs[1].kind, WorkflowInputKind::Boolean);
+        assert_eq!(inputs[1].default.as_deref(), Some("true"));
+        assert_eq!(
+            inputs[2].description.as_deref(),
+            Some("Shown in the run summary")
+        );
+
+        assert_eq!(
+            workflow_dispatch("on: [push, workflow_dispatch]\n"),
+            Some(vec![])
+        );
+        assert_eq!(
+            workflow_dispatch("'on':\n  - workflow_dispatch\n"),
+            Some(vec![])
+        );
+        assert_eq!(
+            workflow_dispatch("on:\n  workflow_dispatch:\n  push:\n"),
+            Some(vec![])
+        );
+        assert_eq!(
+            workflow_dispatch("on:\n  push:\n    branches: [main]\n"),
+            None
+        );
+        assert_eq!(workflow_dispatch("on: push\n"), None);
+    }
+}
@@ -0,0 +1,304 @@
+//! `gh-board doctor`: what a bug report needs, gathered in one place.
+//!
+//! Without flags it prints the version, platform, terminal capabilities and
+//! where the config and logs live. `--bundle` also writes a `.tar` archive to
+//! attach to an issue: that report, the effective config with secrets
+//! redacted, the newest session logs and the last `panic.log` from the
+//! state directory.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Local;
+
+use crate::color::ColorDepth;
+use crate::config::{loader, show};
+use crate::terminal::panic_log_path;
+use crate::theme::Background;
+
+/// Session logs included in a bundle, newest first.
+const BUNDLED_SESSIONS: usize = 3;
+
+/// Top-level directory of the archive.
+const BUNDLE_DIR: &str = "gh-board-doctor";
+
+/// Placeholder written instead of a secret.
+const REDACTED: &str = "<redacted>";
+
+/// Key fragments whose values are never bundled.
+const SECRET_KEYS: &[&str] = &["token", "secret", "password", "webhook", "auth", "api_key"];
+
+/// Prefixes of GitHub credentials, redacted wherever they appear.
+const TOKEN_PREFIXES: &[&str] = &["github_pat_", "ghp_", "gho_", "ghu_", "ghs_", "ghr_"];
+
+/// Environment variables describing the terminal.
+const TERMINAL_VARS: &[&str] = &[
+    "TERM",
+    "COLORTERM",
+    "TERM_PROGRAM",
+    "TERM_PROGRAM_VERSION",
+    "COLORFGBG",
+    "LANG",
+];
+
+/// Print the report, and with `bundle` write the archive to `output`
+/// (default: `gh-board-doctor-<time>.tar` in the current directory).
+pub fn run(explicit_config: Option<&Path>, bundle: bool, output: Option<&Path>) -> Result<()> {
+    let report = report(explicit_config);
+    if !bundle {
+        print!("{report}");
+        return Ok(());
+    }
+    let path = output.map_or_else(
+        || {
+            PathBuf::from(format!(
+                "gh-board-doctor-{}.tar",
+                Local::now().format("%Y%m%dT%H%M%S")
+            ))
+        },
+        Path::to_path_buf,
+    );
+    let mut archive = Tar::default();
+    archive.add("report.txt", report.as_bytes());
+    archive.add("config.txt", config_dump(explicit_config).as_bytes());
+    if let Some(dir) = crate::logging::log_dir() {
+        for file in crate::logging::session_files(&dir)
+            .iter()
+            .rev()
+            .take(BUNDLED_SESSIONS)
+        {
+            if let (Some(name), Ok(log)) = (file.file_name(), std::fs::read_to_string(file)) {
+                let name = format!("logs/{}", name.to_string_lossy());
+                archive.add(&name, redact_tokens(&log).as_bytes());
+            }
+        }
+    }
+    if let Some(Ok(panic)) = panic_log_path().map(std::fs::read_to_string) {
+        archive.add("panic.log", redact_tokens(&panic).as_bytes());
+    }
+    std::fs::write(&path, archive.finish())
+        .with_context(|| format!("writing {}", path.display()))?;
+    println!(
+        "Wrote {}; look it over before attaching it to an issue.",
+        path.display()
+    );
+    Ok(())
+}
+
+/// Version, platform, terminal and file locations, one fact per line.
+fn report(explicit_config: Option<&Path>) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "gh-board {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        out,
+        "platform: {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+
+    let _ = writeln!(out, "\nterminal:");
+    for var in TERMINAL_VARS {
+        let value = std::env::var(var).unwrap_or_else(|_| "(unset)".to_owned());
+        let _ = writeln!(out, "  {var}: {value}");
+    }
+    let tmux = if std::env::var_os("TMUX").is_some() {
+        "yes"
+    } else {
+        "no"
+    };
+    let _ = writeln!(out, "  tmux: {tmux}");
+    if let Ok((cols, rows)) = crossterm::terminal::size() {
+        let _ = writeln!(out, "  size: {cols}x{rows}");
+    }
+    let _ = writeln!(out, "  color depth: {:?}", ColorDepth::detect());
+    let _ = writeln!(out, "  background: {:?}", Background::detect());
+
+    let _ = writeln!(out, "\nconfig:");
+    let files: Vec<PathBuf> = match explicit_config {
+        Some(path) => vec![path.to_path_buf()],
+        None => loader::find_global_config()
+            .into_iter()
+            .chain(loader::find_local_config_chain())
+            .collect(),
+    };
+    if files.is_empty() {
+        let _ = writeln!(out, "  no config file (built-in defaults)");
+    }
+    for file in &files {
+        let _ = writeln!(out, "  {}", file.display());
+    }
+    match loader::load_config(explicit_config) {
+        Ok(_) => {
+            let _ = writeln!(out, "  loads: ok");
+        }
+        Err(e) => {
+            let _ = writeln!(out, "  loads: {}", redact_tokens(&format!("{e:#}")));
+        }
+    }
+
+    let _ = writeln!(out, "\nlogs:");
+    match crate::logging::log_dir() {
+        Some(dir) => {
+            let sessions = crate::logging::session_files(&dir).len();
+            let _ = writeln!(out, "  {} ({sessions} sessions)", dir.display());
+        }
+        None => {
+            let _ = writeln!(out, "  no state directory");
+        }
+    }
+    match panic_log_path() {
+        Some(path) if path.exists() => {
+            let _ = writeln!(out, "  last panic: {}", path.display());
+        }
+        _ => {
+            let _ = writeln!(out, "  last panic: none");
+        }
+    }
+    out
+}
+
+/// The effective config as `gh-board config --show-origin` prints it, with
+/// secrets redacted.
+fn config_dump(explicit_config: Option<&Path>) -> String {
+    let entries = match show::load_config_entries(explicit_config) {
+        Ok(entries) => entries,
+        Err(e) => return format!("error: {}\n", redact_tokens(&format!("{e:#}"))),
+    };
+    let mut out = String::new();
+    for entry in &entries {
+        let _ = writeln!(
+            out,
+            "file:{}\t{} = {}",
+            entry.origin,
+            entry.key,
+            redact_entry(&entry.key, &entry.value)
+        );
+    }
+    out
+}
+
+/// `value`, or [`REDACTED`] when `key` names a secret.
+fn redact_entry(key: &str, value: &str) -> String {
+    let leaf = key.rsplit('.').next().unwrap_or(key).to_lowercase();
+    if SECRET_KEYS.iter().any(|secret| leaf.contains(secret)) {
+        format!("\"{REDACTED}\"")
+    } else {
+        redact_tokens(value)
+    }
+}
+
+/// `text` with every GitHub token replaced by [`REDACTED`].
+fn redact_tokens(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some((at, prefix)) = TOKEN_PREFIXES
+        .iter()
+        .filter_map(|prefix| rest.find(prefix).map(|at| (at, *prefix)))
+        .min_by_key(|(at, _)| *at)
+    {
+        let tail = &rest[at + prefix.len()..];
+        let len = tail
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(tail.len());
+        out.push_str(&rest[..at]);
+        if len == 0 {
+            // Just the prefix, e.g. in prose: nothing to hide.
+            out.push_str(prefix);
+        } else {
+            out.push_str(REDACTED);
+        }
+        rest = &tail[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// An uncompressed ustar archive, built in memory.
+#[derive(Default)]
+struct Tar {
+    bytes: Vec<u8>,
+}
+
+impl Tar {
+    const BLOCK: usize = 512;
+
+    /// Append a regular file at `BUNDLE_DIR/name`.
+    fn add(&mut self, name: &str, contents: &[u8]) {
+        let path = format!("{BUNDLE_DIR}/{name}");
+        let mut header = [0u8; Self::BLOCK];
+        let name_len = path.len().min(100);
+        header[..name_len].copy_from_slice(&path.as_bytes()[..name_len]);
+        header[100..108].copy_from_slice(b"0000644\0");
+        header[108..116].copy_from_slice(b"0000000\0");
+        header[116..124].copy_from_slice(b"0000000\0");
+        header[124..136].copy_from_slice(format!("{:011o}\0", contents.len()).as_bytes());
+        let mtime = Local::now().timestamp().max(0);
+        header[136..148].copy_from_slice(format!("{mtime:011o}\0").as_bytes());
+        header[156] = b'0';
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+        // The checksum is computed with its own field set to spaces.
+        header[148..156].copy_from_slice(b"        ");
+        let checksum: u32 = header.iter().map(|&b| u32::from(b)).sum();
+        header[148..156].copy_from_slice(format!("{checksum:06o}\0 ").as_bytes());
+
+        self.bytes.extend_from_slice(&header);
+        self.bytes.extend_from_slice(contents);
+        let padding = (Self::BLOCK - contents.len() % Self::BLOCK) % Self::BLOCK;
+        self.bytes.resize(self.bytes.len() + padding, 0);
+    }
+
+    /// The archive, closed by two empty blocks.
+    fn finish(mut self) -> Vec<u8> {
+        self.bytes.resize(self.bytes.len() + 2 * Self::BLOCK, 0);
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secrets_are_redacted_by_key_and_by_shape() {
+        assert_eq!(
+            redact_entry("share_targets.0.webhook", "\"https://hooks.slack.com/x\""),
+            "\"<redacted>\""
+        );
+        assert_eq!(
+            redact_entry("keybindings.prs.0.command", "\"curl -H 'ghp_abc123' x\""),
+            "\"curl -H '<redacted>' x\""
+        );
+        assert_eq!(redact_entry("defaults.view", "\"prs\""), "\"prs\"");
+        assert_eq!(
+            redact_tokens("token github_pat_11AB_cd, then gho_x."),
+            "token <redacted>, then <redacted>."
+        );
+        assert_eq!(
+            redact_tokens("tokens start with ghp_"),
+            "tokens start with ghp_"
+        );
+    }
+
+    #[test]
+    fn archive_entries_have_valid_headers() {
+        let mut tar = Tar::default();
+        tar.add("report.txt", b"gh-board 1.0\n");
+        tar.add("logs/empty.log", b"");
+        let bytes = tar.finish();
+        assert_eq!(bytes.len(), 512 * 5);
+
+        let header = &bytes[..512];
+        assert!(header.starts_with(b"gh-board-doctor/report.txt\0"));
+        assert_eq!(&header[124..136], b"00000000015\0");
+        let stored =
+            u32::from_str_radix(std::str::from_utf8(&header[148..154]).unwrap(), 8).unwrap();
+        let mut blank = header.to_vec();
+        blank[148..156].copy_from_slice(b"        ");
+        assert_eq!(stored, blank.iter().map(|&b| u32::from(b)).sum::<u32>());
+        assert_eq!(&bytes[512..525], b"gh-board 1.0\n");
+        assert!(bytes[1024..].starts_with(b"gh-board-doctor/logs/empty.log\0"));
+        assert!(bytes[1536..].iter().all(|&b| b == 0));
+    }
+}
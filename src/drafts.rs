@@ -0,0 +1,172 @@
+//! Comment drafts kept on disk until they are posted.
+//!
+//! The composers of the PR and Issue views save their text here as it is
+//! typed, keyed by item, so neither `Esc` nor a crash loses it: entering the
+//! composer on the same item again restores the draft. Drafts live in
+//! `$XDG_STATE_HOME/gh-board/drafts.json` (`~/.local/state` by default).
+
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The item a draft comments on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DraftTarget {
+    /// `owner/repo#number`.
+    pub key: String,
+    pub title: String,
+    pub url: String,
+}
+
+impl DraftTarget {
+    pub fn new(repo: &str, number: u64, title: &str, url: &str) -> Self {
+        Self {
+            key: format!("{repo}#{number}"),
+            title: title.to_owned(),
+            url: url.to_owned(),
+        }
+    }
+}
+
+/// An unposted comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Draft {
+    pub key: String,
+    pub title: String,
+    pub url: String,
+    pub body: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+fn drafts_path() -> Option<PathBuf> {
+    crate::util::state_path("drafts.json")
+}
+
+/// Drafts on disk, read on first use. Unreadable files start empty (and are
+/// replaced by the next save).
+static DRAFTS: LazyLock<Mutex<Vec<Draft>>> = LazyLock::new(|| {
+    let drafts = drafts_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|text| match serde_json::from_str(&text) {
+            Ok(drafts) => Some(drafts),
+            Err(e) => {
+                tracing::warn!("ignoring unreadable drafts file: {e}");
+                None
+            }
+        })
+        .unwrap_or_default();
+    Mutex::new(drafts)
+});
+
+fn with_drafts<T>(f: impl FnOnce(&mut Vec<Draft>) -> T) -> T {
+    f(&mut DRAFTS.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Write `drafts` out, replacing the file atomically.
+fn persist(drafts: &[Draft]) {
+    let Some(path) = drafts_path() else {
+        return;
+    };
+    let result = serde_json::to_string_pretty(drafts)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| crate::util::write_atomic(&path, &json));
+    if let Err(e) = result {
+        tracing::warn!("saving drafts to {}: {e}", path.display());
+    }
+}
+
+/// Record `body` as the draft for `target`; an empty body drops it. Returns
+/// whether anything changed.
+fn upsert(drafts: &mut Vec<Draft>, target: &DraftTarget, body: &str, now: DateTime<Utc>) -> bool {
+    let existing = drafts.iter().position(|d| d.key == target.key);
+    match (existing, body.trim().is_empty()) {
+        (None, true) => false,
+        (Some(i), true) => {
+            drafts.remove(i);
+            true
+        }
+        (Some(i), false) if drafts[i].body == body => false,
+        (Some(i), false) => {
+            let draft = &mut drafts[i];
+            body.clone_into(&mut draft.body);
+            draft.title.clone_from(&target.title);
+            draft.updated_at = now;
+            true
+        }
+        (None, false) => {
+            drafts.push(Draft {
+                key: target.key.clone(),
+                title: target.title.clone(),
+                url: target.url.clone(),
+                body: body.to_owned(),
+                updated_at: now,
+            });
+            true
+        }
+    }
+}
+
+/// Save the composer text for `target`, writing the file only on change.
+pub(crate) fn save(target: &DraftTarget, body: &str) {
+    with_drafts(|drafts| {
+        if upsert(drafts, target, body, Utc::now()) {
+            persist(drafts);
+        }
+    });
+}
+
+/// The saved draft for `key`, if any.
+pub(crate) fn get(key: &str) -> Option<String> {
+    with_drafts(|drafts| drafts.iter().find(|d| d.key == key).map(|d| d.body.clone()))
+}
+
+/// Drop the draft for `key` (posted or discarded).
+pub(crate) fn discard(key: &str) {
+    with_drafts(|drafts| {
+        let before = drafts.len();
+        drafts.retain(|d| d.key != key);
+        if drafts.len() != before {
+            persist(drafts);
+        }
+    });
+}
+
+/// Every saved draft, most recently edited first.
+pub(crate) fn list() -> Vec<Draft> {
+    let mut drafts = with_drafts(|drafts| drafts.clone());
+    drafts.sort_by_key(|d| std::cmp::Reverse(d.updated_at));
+    drafts
+}
+
+/// Composer text when opening it on `target`: its draft, followed by the
+/// `quote` being replied to, if any.
+pub(crate) fn restore(target: &DraftTarget, quote: Option<String>) -> (String, bool) {
+    match (get(&target.key), quote) {
+        (Some(draft), Some(quote)) => (format!("{}\n\n{quote}", draft.trim_end()), true),
+        (Some(draft), None) => (draft, true),
+        (None, quote) => (quote.unwrap_or_default(), false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upsert_adds_updates_and_drops_drafts() {
+        let target = DraftTarget::new("acme/widgets", 42, "Fix the parser", "https://x");
+        let now = Utc::now();
+        let mut drafts = Vec::new();
+        assert!(!upsert(&mut drafts, &target, "  ", now));
+        assert!(upsert(&mut drafts, &target, "LGTM", now));
+        assert_eq!(drafts[0].key, "acme/widgets#42");
+        assert!(!upsert(&mut drafts, &target, "LGTM", now));
+        assert!(upsert(&mut drafts, &target, "LGTM, thanks", now));
+        assert_eq!(drafts.len(), 1);
+        assert_eq!(drafts[0].body, "LGTM, thanks");
+        assert!(upsert(&mut drafts, &target, "", now));
+        assert!(drafts.is_empty());
+    }
+}
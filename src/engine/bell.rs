@@ -0,0 +1,252 @@
+//! `[bell]`: the terminal bell, rung when a PR tab shows something new for
+//! the user, for gh-board left open in a background pane.
+//!
+//! Each complete PR fetch is compared with the PRs seen before: CI that
+//! started failing on one of the user's PRs, or a review newly requested from
+//! them (or one of their teams), rings once per fetch, or runs `bell.command`
+//! once per event. The first fetch of a tab only takes note of what it shows.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use crate::config::types::{BellConfig, BellEvent, PrFilter};
+use crate::types::{CiState, PullRequest};
+
+static BELL: OnceLock<Bell> = OnceLock::new();
+
+struct Bell {
+    config: BellConfig,
+    state: Mutex<Seen>,
+}
+
+/// The signed-in user of a host.
+#[derive(Debug, Clone, Default)]
+struct Viewer {
+    login: String,
+    teams: Vec<String>,
+}
+
+/// What the fetches of this session showed.
+#[derive(Default)]
+struct Seen {
+    viewers: HashMap<String, Viewer>,
+    /// Tabs fetched at least once, by host and query.
+    tabs: HashSet<String>,
+    /// `(failing, requested)` of each PR, by URL.
+    prs: HashMap<String, (bool, bool)>,
+}
+
+impl Seen {
+    /// The events `prs`, just fetched for `tab` of `host`, ring.
+    fn observe<'a>(
+        &mut self,
+        host: &str,
+        tab: String,
+        prs: &'a [PullRequest],
+        on: &[BellEvent],
+    ) -> Vec<(BellEvent, &'a PullRequest)> {
+        let Some(viewer) = self.viewers.get(host) else {
+            return Vec::new();
+        };
+        // PRs new to a tab fetched before count as changed; a first fetch
+        // only sets what later ones are compared with.
+        let primed = !self.tabs.insert(tab);
+        let mut events = Vec::new();
+        for pr in prs {
+            let mine = pr.author.as_ref().is_some_and(|a| a.login == viewer.login);
+            let failing = mine && pr.ci_state() == CiState::Failing;
+            let requested = pr.review_requests.iter().any(|a| a.login == viewer.login)
+                || pr.requests_team_review(&viewer.teams);
+            let before = self.prs.insert(pr.url.clone(), (failing, requested));
+            let (was_failing, was_requested) = before.unwrap_or((!primed, !primed));
+            if failing && !was_failing && on.contains(&BellEvent::CiFailure) {
+                events.push((BellEvent::CiFailure, pr));
+            }
+            if requested && !was_requested && on.contains(&BellEvent::ReviewRequest) {
+                events.push((BellEvent::ReviewRequest, pr));
+            }
+        }
+        events
+    }
+}
+
+/// Ring on `config.on`, if any. Only the first call takes effect.
+pub(super) fn install(config: BellConfig) {
+    if config.on.is_empty() {
+        return;
+    }
+    let _ = BELL.set(Bell {
+        config,
+        state: Mutex::default(),
+    });
+}
+
+/// Whether the bell needs the signed-in user of `host` to be set.
+pub(super) fn wants_viewer(host: &str) -> bool {
+    BELL.get().is_some_and(|bell| {
+        !bell
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .viewers
+            .contains_key(host)
+    })
+}
+
+/// Set the signed-in user of `host`, with their teams as `org/team-slug`.
+pub(super) fn set_viewer(host: &str, login: String, teams: Vec<String>) {
+    if let Some(bell) = BELL.get() {
+        bell.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .viewers
+            .insert(host.to_owned(), Viewer { login, teams });
+    }
+}
+
+/// Ring for what the complete results `prs` of `filter` show anew.
+pub(super) fn observe_prs(filter: &PrFilter, prs: &[PullRequest]) {
+    let Some(bell) = BELL.get() else {
+        return;
+    };
+    let host = filter.host.as_deref().unwrap_or("github.com");
+    let tab = format!("{host}\n{}", filter.filters);
+    let events = bell
+        .state
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .observe(host, tab, prs, &bell.config.on);
+    if events.is_empty() {
+        return;
+    }
+    let Some(command) = &bell.config.command else {
+        tracing::debug!("bell: ringing for {} event(s)", events.len());
+        let mut out = std::io::stdout();
+        let _ = out.write_all(b"\x07").and_then(|()| out.flush());
+        return;
+    };
+    for (event, pr) in events {
+        let mut vars = vec![("GH_BOARD_EVENT", event.name().to_owned())];
+        if let Some(repo) = &pr.repo {
+            super::hooks::item_vars(&mut vars, repo.full_name(), pr.number);
+        }
+        vars.extend([
+            ("GH_BOARD_TITLE", pr.title.clone()),
+            ("GH_BOARD_URL", pr.url.clone()),
+        ]);
+        super::hooks::run(command, vars);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    use crate::types::{
+        Actor, CheckConclusion, CheckRun, CheckStatus, PrState, ReactionGroups, ReviewTimes,
+    };
+
+    fn actor(login: &str) -> Actor {
+        Actor {
+            login: login.to_owned(),
+            avatar_url: String::new(),
+        }
+    }
+
+    fn pr(number: u64, author: &str) -> PullRequest {
+        PullRequest {
+            number,
+            title: String::new(),
+            body: String::new(),
+            author: Some(actor(author)),
+            state: PrState::Open,
+            is_draft: false,
+            mergeable: None,
+            review_decision: None,
+            additions: 0,
+            deletions: 0,
+            head_ref: "feat/x".into(),
+            head_sha: String::new(),
+            base_ref: "main".into(),
+            labels: vec![],
+            assignees: vec![],
+            commits: vec![],
+            comments: vec![],
+            review_threads: vec![],
+            review_requests: vec![],
+            team_review_requests: vec![],
+            reviews: vec![],
+            review_times: ReviewTimes::default(),
+            timeline_events: vec![],
+            files: vec![],
+            check_runs: vec![],
+            updated_at: Utc::now(),
+            created_at: Utc::now(),
+            url: format!("https://github.com/o/r/pull/{number}"),
+            repo: None,
+            comment_count: 0,
+            reactions: ReactionGroups::default(),
+            author_association: None,
+            participants: vec![],
+            merge_state_status: None,
+            head_repo_owner: None,
+            head_repo_name: None,
+        }
+    }
+
+    fn failing(mut pr: PullRequest) -> PullRequest {
+        pr.check_runs = vec![CheckRun {
+            name: "ci".to_owned(),
+            status: Some(CheckStatus::Completed),
+            conclusion: Some(CheckConclusion::Failure),
+            url: None,
+            workflow_run_id: None,
+            workflow_name: None,
+            job_id: None,
+            started_at: None,
+            completed_at: None,
+            is_required: false,
+        }];
+        pr
+    }
+
+    #[test]
+    fn only_changes_since_a_tabs_first_fetch_ring() {
+        let on = [BellEvent::CiFailure, BellEvent::ReviewRequest];
+        let mut seen = Seen::default();
+        seen.viewers.insert(
+            "github.com".to_owned(),
+            Viewer {
+                login: "me".to_owned(),
+                teams: Vec::new(),
+            },
+        );
+        let tab = || "github.com\nauthor:@me".to_owned();
+        // Already failing at startup: nothing new.
+        let first = [failing(pr(1, "me")), pr(2, "me")];
+        assert!(seen.observe("github.com", tab(), &first, &on).is_empty());
+
+        let mut requested = pr(3, "alice");
+        requested.review_requests = vec![actor("me")];
+        let next = [
+            failing(pr(1, "me")),
+            failing(pr(2, "me")),
+            requested,
+            failing(pr(4, "bob")),
+        ];
+        let events: Vec<(BellEvent, u64)> = seen
+            .observe("github.com", tab(), &next, &on)
+            .into_iter()
+            .map(|(event, pr)| (event, pr.number))
+            .collect();
+        assert_eq!(
+            events,
+            [(BellEvent::CiFailure, 2), (BellEvent::ReviewRequest, 3)]
+        );
+        assert!(seen.observe("github.com", tab(), &next, &on).is_empty());
+        // Unknown user: nothing to compare with.
+        assert!(seen.observe("ghe.acme.com", tab(), &next, &on).is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+//! Lists fetched by earlier sessions, kept on disk so that a new session
+//! shows every PR, issue and Actions tab at once while its first fetch runs.
+//!
+//! The last complete results of each tab are one JSON file under
+//! `$XDG_CACHE_HOME/gh-board/lists/`, named after a hash of the tab's query:
+//! editing a filter starts a new entry. An entry is served only until the tab
+//! is fetched fresh in this session, and never once older than [`MAX_AGE`];
+//! such files are removed at startup.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::interface::{Event, Request};
+use crate::config::types::{ActionsFilter, IssueFilter, PrFilter};
+use crate::types::{Issue, PullRequest, WorkflowRun};
+
+/// Age past which an entry is too old to be worth showing.
+const MAX_AGE: TimeDelta = TimeDelta::days(7);
+
+static CACHE: OnceLock<DiskCache> = OnceLock::new();
+
+struct DiskCache {
+    dir: PathBuf,
+    /// Keys stored this session, whose entries are no longer served.
+    fresh: Mutex<HashSet<String>>,
+}
+
+/// The file of one entry.
+#[derive(Serialize, Deserialize)]
+struct Entry<I> {
+    saved_at: DateTime<Utc>,
+    items: I,
+}
+
+impl DiskCache {
+    fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            fresh: Mutex::default(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        // FNV-1a: stable across runs and builds, unlike `DefaultHasher`.
+        let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |h, b| {
+            (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.dir.join(format!("{hash:016x}.json"))
+    }
+
+    /// The entry of `key` and when it was saved, unless it is missing, too
+    /// old, or `key` was fetched fresh since.
+    fn load<T: DeserializeOwned>(&self, key: &str) -> Option<(Vec<T>, DateTime<Utc>)> {
+        if self
+            .fresh
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(key)
+        {
+            return None;
+        }
+        let text = std::fs::read_to_string(self.path(key)).ok()?;
+        let entry: Entry<Vec<T>> = match serde_json::from_str(&text) {
+            Ok(entry) => entry,
+            Err(e) => {
+                tracing::debug!("disk cache: unreadable entry for {key:?}: {e}");
+                return None;
+            }
+        };
+        (Utc::now() - entry.saved_at < MAX_AGE).then_some((entry.items, entry.saved_at))
+    }
+
+    /// Replace the entry of `key` with `items`, fetched just now.
+    fn store<T: Serialize>(&self, key: &str, items: &[T]) {
+        self.fresh
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.to_owned());
+        let entry = Entry {
+            saved_at: Utc::now(),
+            items,
+        };
+        let written = serde_json::to_string(&entry)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| crate::util::write_atomic(&self.path(key), &json));
+        if let Err(e) = written {
+            tracing::debug!("disk cache: writing entry for {key:?}: {e}");
+        }
+    }
+
+    /// Remove the entries too old to be served.
+    fn prune(&self) {
+        let Ok(files) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+        let max_age = MAX_AGE.to_std().unwrap_or_default();
+        for file in files.flatten() {
+            let expired = file
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age > max_age);
+            if expired {
+                let _ = std::fs::remove_file(file.path());
+            }
+        }
+    }
+}
+
+/// Keep lists on disk, unless `enabled` is `false` (`github.disk_cache`).
+/// Only the first call takes effect.
+pub(super) fn install(enabled: bool) {
+    if !enabled || CACHE.get().is_some() {
+        return;
+    }
+    let Some(dir) = crate::util::cache_path("lists") else {
+        return;
+    };
+    let cache = DiskCache::new(dir);
+    cache.prune();
+    let _ = CACHE.set(cache);
+}
+
+fn prs_key(filter: &PrFilter) -> String {
+    format!(
+        "prs\n{}\n{}\n{}",
+        filter.host.as_deref().unwrap_or("github.com"),
+        filter.filters,
+        filter.limit.unwrap_or(100)
+    )
+}
+
+fn issues_key(filter: &IssueFilter) -> String {
+    format!(
+        "issues\n{}\n{}\n{:?}\n{}",
+        filter.host.as_deref().unwrap_or("github.com"),
+        filter.filters,
+        filter.kind,
+        filter.limit.unwrap_or(100)
+    )
+}
+
+fn actions_key(filter: &ActionsFilter) -> String {
+    format!(
+        "actions\n{}\n{}\n{}\n{:?}\n{:?}\n{:?}\n{:?}",
+        filter.host.as_deref().unwrap_or("github.com"),
+        filter.repo,
+        filter.repos.join(","),
+        filter.status,
+        filter.event,
+        filter.branch,
+        filter.limit
+    )
+}
+
+/// Reply to a list fetch with the tab's entry, ahead of the fetch itself.
+/// Forced fetches get none: the user asked for current data.
+pub(super) fn serve(req: &Request) {
+    let Some(cache) = CACHE.get() else {
+        return;
+    };
+    match req {
+        Request::FetchPrs {
+            filter_idx,
+            filter,
+            force: false,
+            reply_tx,
+        } => {
+            if let Some((prs, saved_at)) = cache.load(&prs_key(filter)) {
+                let _ = reply_tx.send(Event::PrsCached {
+                    filter_idx: *filter_idx,
+                    prs,
+                    saved_at,
+                });
+            }
+        }
+        Request::FetchIssues {
+            filter_idx,
+            filter,
+            force: false,
+            reply_tx,
+        } => {
+            if let Some((issues, saved_at)) = cache.load(&issues_key(filter)) {
+                let _ = reply_tx.send(Event::IssuesCached {
+                    filter_idx: *filter_idx,
+                    issues,
+                    saved_at,
+                });
+            }
+        }
+        Request::FetchActions {
+            filter_idx,
+            filter,
+            reply_tx,
+        } => {
+            if let Some((runs, saved_at)) = cache.load(&actions_key(filter)) {
+                let _ = reply_tx.send(Event::ActionsCached {
+                    filter_idx: *filter_idx,
+                    runs,
+                    saved_at,
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Save the complete results of a PR tab.
+pub(super) fn store_prs(filter: &PrFilter, prs: &[PullRequest]) {
+    if let Some(cache) = CACHE.get() {
+        cache.store(&prs_key(filter), prs);
+    }
+}
+
+/// Save the complete results of an issues tab.
+pub(super) fn store_issues(filter: &IssueFilter, issues: &[Issue]) {
+    if let Some(cache) = CACHE.get() {
+        cache.store(&issues_key(filter), issues);
+    }
+}
+
+/// Save the results of an Actions tab.
+pub(super) fn store_actions(filter: &ActionsFilter, runs: &[WorkflowRun]) {
+    if let Some(cache) = CACHE.get() {
+        cache.store(&actions_key(filter), runs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_are_served_until_fetched_fresh() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = "prs\ngithub.com\nis:open\n100";
+        let earlier = DiskCache::new(dir.path().to_path_buf());
+        assert!(earlier.load::<u32>(key).is_none());
+        earlier.store(key, &[1_u32, 2]);
+        // Stored this session: the caller already has the data.
+        assert!(earlier.load::<u32>(key).is_none());
+
+        let next = DiskCache::new(dir.path().to_path_buf());
+        let (items, saved_at) = next.load::<u32>(key).unwrap();
+        assert_eq!(items, [1, 2]);
+        assert!(Utc::now() - saved_at < TimeDelta::minutes(1));
+        assert!(
+            next.load::<u32>("prs\ngithub.com\nis:closed\n100")
+                .is_none()
+        );
+        next.store(key, &[3_u32]);
+        assert!(next.load::<u32>(key).is_none());
+
+        let old = Entry {
+            saved_at: Utc::now() - MAX_AGE - TimeDelta::hours(1),
+            items: [4_u32],
+        };
+        std::fs::write(next.path(key), serde_json::to_string(&old).unwrap()).unwrap();
+        assert!(
+            DiskCache::new(dir.path().to_path_buf())
+                .load::<u32>(key)
+                .is_none()
+        );
+    }
+}
@@ -1,23 +1,35 @@
-use std::sync::mpsc::Sender;
+use std::collections::VecDeque;
+use std::sync::Arc;
 use std::time::Duration;
 
+use indexmap::IndexMap;
+use moka::future::Cache;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use tokio::sync::mpsc::UnboundedReceiver;
 
 use crate::actions::{issue_actions, pr_actions};
 use crate::config::keybindings::{TemplateVars, execute_shell_command, expand_template};
-use crate::config::types::AppConfig;
+use crate::config::types::{AppConfig, IssueFilterKind, MergeMethod, NotificationRule};
 use crate::github::{
-    actions as gh_actions,
-    client::GitHubClient,
-    graphql, notifications as notif,
+    actions as gh_actions, auth_warnings,
+    client::{GitHubClient, HostClient},
+    events as gh_events,
+    features::HostFeatures,
+    graphql, health, notifications as notif,
     rate_limit::{format_rate_limit_message, is_rate_limited},
-    security as gh_security,
+    releases, security as gh_security,
+    transient::is_transient,
+    viewer as gh_viewer,
+};
+use crate::types::{
+    Issue, OrgEventSource, PrDetail, PrDetailConnection, PrState, PullRequest, RateLimitInfo,
+    ReviewEvent, ReviewLineComment, RunStatus, WorkflowRun,
 };
-use crate::types::{RunStatus, WorkflowRun};
 
-use super::interface::{Engine, EngineHandle, Event, PrRef, Request};
+use super::interface::{Engine, EngineHandle, Event, EventSender, FetchKey, PrRef, Request};
 use super::refresh::{DueEntry, FilterConfig, RefreshScheduler, ViewKind};
-use super::watch::WatchScheduler;
+use super::watch::{DuePrWatch, WatchScheduler};
 
 /// The real GitHub backend engine.
 pub struct GitHubEngine {
@@ -44,16 +56,27 @@ impl Engine for GitHubEngine {
     }
 }
 
-/// Maximum time a single request handler may run before being cancelled.
-const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+/// Default for `github.request_timeout_seconds`.
+const DEFAULT_REQUEST_TIMEOUT_SECS: u32 = 15;
+
+/// Pause before fetching a tab again after a transient failure.
+const RETRY_DELAY: Duration = Duration::from_secs(2);
 
-/// Maximum time the periodic background refresh may run before being cancelled.
-const TICK_REFRESH_TIMEOUT: Duration = Duration::from_secs(30);
+/// How a request handed to [`handle_request`] ended.
+enum Outcome {
+    Finished,
+    TimedOut,
+    /// A `CancelFetches` for its view arrived first.
+    Cancelled,
+    /// A newer fetch of the same tab arrived first.
+    Superseded,
+}
 
 impl GitHubEngine {
+    #[expect(clippy::too_many_lines)]
     async fn run_loop(self, mut rx: UnboundedReceiver<Request>) {
         let refetch_mins = self.config.github.refetch_interval_minutes.unwrap_or(10);
-        let mut client = GitHubClient::new(refetch_mins);
+        let mut client = GitHubClient::new(refetch_mins, self.config.github.hosts.clone());
         let mut scheduler = RefreshScheduler::new();
 
         let watch_poll_secs = u64::from(
@@ -65,6 +88,21 @@ impl GitHubEngine {
         let mut watch_scheduler = WatchScheduler::new(Duration::from_secs(watch_poll_secs));
         let watch_fetch_jobs = self.config.actions.watch_fetch_jobs.unwrap_or(false);
         let complete_command = self.config.actions.watch_complete_command.clone();
+        let notification_rules = self.config.notification_rules.clone();
+        super::hooks::install(self.config.hooks.clone());
+        super::bell::install(self.config.bell.clone());
+        super::disk_cache::install(self.config.github.disk_cache.unwrap_or(true));
+        let quiet_hours = self.config.github.quiet_hours;
+
+        let request_timeout = Duration::from_secs(u64::from(
+            self.config
+                .github
+                .request_timeout_seconds
+                .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS)
+                .max(1),
+        ));
+        // The background refresh fetches several filters in one go.
+        let tick_refresh_timeout = request_timeout * 2;
 
         let refresh_interval = Duration::from_mins(u64::from(refetch_mins).max(1));
         let poll_dur = Duration::from_secs(30);
@@ -76,70 +114,171 @@ impl GitHubEngine {
         let mut watch_tick = tokio::time::interval(Duration::from_secs(watch_tick_secs));
         watch_tick.tick().await;
 
+        // Requests that arrived while another one was running.
+        let mut pending: VecDeque<Request> = VecDeque::new();
+        // Whether the front of `pending` is a list fetch sent again after it
+        // timed out; it is not retried twice.
+        let mut retrying = false;
+
         loop {
-            tokio::select! {
-                biased;
-                maybe_req = rx.recv() => {
-                    match maybe_req {
-                        None | Some(Request::Shutdown) => {
-                            tracing::debug!("engine: shutting down");
-                            break;
+            let next = if let Some(req) = pending.pop_front() {
+                Some(req)
+            } else {
+                tokio::select! {
+                    biased;
+                    maybe_req = rx.recv() => {
+                        if let Some(req) = &maybe_req {
+                            super::disk_cache::serve(req);
+                        }
+                        maybe_req
+                    }
+                    _ = refresh_tick.tick() => {
+                        if quiet_hours.is_some_and(|q| q.contains(crate::util::time_of_day_now())) {
+                            tracing::trace!("engine: quiet hours, skipping background refresh");
+                            continue;
+                        }
+                        if tokio::time::timeout(
+                            tick_refresh_timeout,
+                            tick_refresh(&mut client, &mut scheduler, &mut watch_scheduler, complete_command.as_ref(), &notification_rules, refresh_interval),
+                        )
+                        .await
+                        .is_err()
+                        {
+                            tracing::warn!(
+                                "engine: tick_refresh timed out after {tick_refresh_timeout:?}"
+                            );
+                        }
+                        continue;
+                    }
+                    _ = watch_tick.tick(), if !watch_scheduler.is_empty() => {
+                        tick_watches(&mut client, &mut watch_scheduler, watch_fetch_jobs, complete_command.as_ref()).await;
+                        tick_pr_watches(&mut client, &mut watch_scheduler).await;
+                        continue;
+                    }
+                }
+            };
+            let req = match next {
+                None | Some(Request::Shutdown) => {
+                    tracing::debug!("engine: shutting down");
+                    break;
+                }
+                // Nothing is queued or running, so there is nothing to cancel.
+                Some(Request::CancelFetches { .. }) => continue,
+                Some(mut req) => {
+                    req.tag_item();
+                    req
+                }
+            };
+
+            let is_retry = std::mem::take(&mut retrying);
+            let label = req.label();
+            let reply_tx = req.reply_tx();
+            let key = req.fetch_key();
+            let again = if is_retry { None } else { req.retry() };
+            let work = tokio::time::timeout(
+                request_timeout,
+                handle_request(
+                    req,
+                    &mut client,
+                    &mut scheduler,
+                    &mut watch_scheduler,
+                    complete_command.as_ref(),
+                    &notification_rules,
+                    refresh_interval,
+                ),
+            );
+            tokio::pin!(work);
+            // Keep reading requests while this one runs, so a cancellation or
+            // a newer fetch of the same tab can cut it short.
+            let outcome = loop {
+                tokio::select! {
+                    biased;
+                    done = &mut work => {
+                        break if done.is_ok() { Outcome::Finished } else { Outcome::TimedOut };
+                    }
+                    incoming = rx.recv() => match incoming {
+                        Some(Request::CancelFetches { reply_tx }) => {
+                            cancel_queued(&mut pending, &reply_tx);
+                            if key.as_ref().is_some_and(|k| k.replies_to(&reply_tx)) {
+                                break Outcome::Cancelled;
+                            }
                         }
-                        Some(req) => {
-                            let label = req.label();
-                            let reply_tx = req.reply_tx();
-                            if tokio::time::timeout(
-                                REQUEST_TIMEOUT,
-                                handle_request(
-                                    req,
-                                    &mut client,
-                                    &mut scheduler,
-                                    &mut watch_scheduler,
-                                    complete_command.as_ref(),
-                                    refresh_interval,
-                                ),
-                            )
-                            .await
-                            .is_err()
-                            {
-                                tracing::warn!(
-                                    "engine: {label} timed out after {REQUEST_TIMEOUT:?}, \
-                                     cancelling to unblock engine"
-                                );
-                                if let Some(tx) = reply_tx {
-                                    let _ = tx.send(Event::FetchError {
-                                        context: label.to_owned(),
-                                        message: format!(
-                                            "Request timed out after {}s",
-                                            REQUEST_TIMEOUT.as_secs()
-                                        ),
-                                    });
-                                }
+                        Some(newer) => {
+                            super::disk_cache::serve(&newer);
+                            let newer_key = newer.fetch_key();
+                            let supersedes = |k: &FetchKey| {
+                                newer_key.as_ref().is_some_and(|nk| nk.same_tab(k))
+                            };
+                            pending.retain(|q| !q.fetch_key().is_some_and(|k| supersedes(&k)));
+                            pending.push_back(newer);
+                            if key.as_ref().is_some_and(supersedes) {
+                                break Outcome::Superseded;
                             }
                         }
+                        None => {
+                            pending.push_back(Request::Shutdown);
+                            break Outcome::Cancelled;
+                        }
                     }
                 }
-                _ = refresh_tick.tick() => {
-                    if tokio::time::timeout(
-                        TICK_REFRESH_TIMEOUT,
-                        tick_refresh(&mut client, &mut scheduler, &mut watch_scheduler, complete_command.as_ref(), refresh_interval),
-                    )
-                    .await
-                    .is_err()
-                    {
-                        tracing::warn!(
-                            "engine: tick_refresh timed out after {TICK_REFRESH_TIMEOUT:?}"
-                        );
+            };
+
+            match outcome {
+                Outcome::Finished => {}
+                Outcome::TimedOut if let Some(again) = again => {
+                    tracing::warn!(
+                        "engine: {label} timed out after {request_timeout:?}, retrying once"
+                    );
+                    pending.push_front(again);
+                    retrying = true;
+                }
+                Outcome::TimedOut => {
+                    tracing::warn!(
+                        "engine: {label} timed out after {request_timeout:?}, \
+                         cancelling to unblock engine"
+                    );
+                    let secs = request_timeout.as_secs();
+                    if let Some(key) = &key {
+                        key.reply(Event::FetchTimedOut {
+                            filter_idx: key.filter_idx,
+                            secs,
+                        });
+                    } else if let Some(tx) = reply_tx {
+                        let _ = tx.send(Event::FetchError {
+                            context: label.to_owned(),
+                            message: format!("Request timed out after {secs}s"),
+                        });
+                    }
+                }
+                Outcome::Cancelled => {
+                    tracing::debug!("engine: {label} cancelled");
+                    if let Some(key) = &key {
+                        key.reply(Event::FetchCancelled {
+                            filter_idx: key.filter_idx,
+                        });
                     }
                 }
-                _ = watch_tick.tick(), if !watch_scheduler.is_empty() => {
-                    tick_watches(&mut client, &mut watch_scheduler, watch_fetch_jobs, complete_command.as_ref()).await;
+                Outcome::Superseded => {
+                    tracing::debug!("engine: {label} superseded by a newer fetch");
                 }
             }
         }
     }
 }
 
+/// Drop the queued list fetches replying to `reply_tx`, telling their view.
+fn cancel_queued(pending: &mut VecDeque<Request>, reply_tx: &EventSender) {
+    pending.retain(|req| match req.fetch_key() {
+        Some(key) if key.replies_to(reply_tx) => {
+            key.reply(Event::FetchCancelled {
+                filter_idx: key.filter_idx,
+            });
+            false
+        }
+        _ => true,
+    });
+}
+
 // ---------------------------------------------------------------------------
 // Request dispatch
 // ---------------------------------------------------------------------------
@@ -151,6 +290,7 @@ async fn handle_request(
     scheduler: &mut RefreshScheduler,
     watch_scheduler: &mut WatchScheduler,
     complete_command: Option<&String>,
+    notification_rules: &[NotificationRule],
     refresh_interval: Duration,
 ) {
     let label = req.label();
@@ -168,6 +308,18 @@ async fn handle_request(
             force,
             reply_tx,
         } => handle_fetch_issues(client, scheduler, filter_idx, filter, force, reply_tx).await,
+        Request::SearchPrs {
+            query,
+            limit,
+            host,
+            reply_tx,
+        } => handle_search_prs(client, &query, limit, host.as_deref(), reply_tx).await,
+        Request::SearchIssues {
+            query,
+            limit,
+            host,
+            reply_tx,
+        } => handle_search_issues(client, &query, limit, host.as_deref(), reply_tx).await,
         Request::FetchActions {
             filter_idx,
             filter,
@@ -178,12 +330,28 @@ async fn handle_request(
             filter,
             reply_tx,
         } => handle_fetch_alerts(client, scheduler, filter_idx, filter, reply_tx).await,
+        Request::FetchOrgEvents {
+            filter_idx,
+            filter,
+            reply_tx,
+        } => handle_fetch_org_events(client, scheduler, filter_idx, filter, reply_tx).await,
         Request::FetchSecretLocations {
             owner,
             repo,
             alert_number,
+            host,
             reply_tx,
-        } => handle_fetch_secret_locations(client, owner, repo, alert_number, reply_tx).await,
+        } => {
+            handle_fetch_secret_locations(
+                client,
+                host.as_deref(),
+                owner,
+                repo,
+                alert_number,
+                reply_tx,
+            )
+            .await;
+        }
         Request::FetchRunJobs {
             owner,
             repo,
@@ -191,194 +359,636 @@ async fn handle_request(
             host,
             reply_tx,
         } => handle_fetch_run_jobs(client, owner, repo, run_id, host, reply_tx).await,
+        Request::FetchJobLogs {
+            owner,
+            repo,
+            job_id,
+            host,
+            reply_tx,
+        } => handle_fetch_job_logs(client, owner, repo, job_id, host, reply_tx).await,
         Request::FetchNotifications {
             filter_idx,
             filter,
             reply_tx,
-        } => handle_fetch_notifications(client, scheduler, filter_idx, filter, reply_tx).await,
+        } => {
+            handle_fetch_notifications(
+                client,
+                scheduler,
+                filter_idx,
+                filter,
+                notification_rules,
+                reply_tx,
+            )
+            .await;
+        }
         Request::FetchPrDetail {
             pr_ref,
             force,
+            host,
+            reply_tx,
+        } => handle_fetch_pr_detail(client, host.as_deref(), pr_ref, force, reply_tx).await,
+        Request::FetchPrDetailPage {
+            owner,
+            repo,
+            number,
+            connection,
+            after,
+            host,
             reply_tx,
-        } => handle_fetch_pr_detail(client, pr_ref, force, reply_tx).await,
+        } => {
+            handle_fetch_pr_detail_page(
+                client,
+                host.as_deref(),
+                (owner, repo, number),
+                connection,
+                after,
+                reply_tx,
+            )
+            .await;
+        }
         Request::FetchIssueDetail {
             owner,
             repo,
             number,
+            host,
+            reply_tx,
+        } => {
+            handle_fetch_issue_detail(client, host.as_deref(), owner, repo, number, reply_tx).await;
+        }
+        Request::PrefetchPrDetails {
+            prs,
+            host,
             reply_tx,
-        } => handle_fetch_issue_detail(client, owner, repo, number, reply_tx).await,
-        Request::PrefetchPrDetails { prs, reply_tx } => {
-            handle_prefetch_pr_details(client, prs, reply_tx).await;
+        } => {
+            handle_prefetch_pr_details(client, host.as_deref(), prs, reply_tx).await;
         }
         Request::RegisterRefresh { configs, notify_tx } => {
+            let Some(kind) = configs.first().map(FilterConfig::view_kind) else {
+                return;
+            };
             scheduler.register(configs, refresh_interval, &notify_tx);
+            for c in scheduler.filter_costs(kind) {
+                tracing::debug!(
+                    "engine: refresh budget {kind:?}[{}] {:?}: {}{} every {}m",
+                    c.filter_idx,
+                    c.title,
+                    if c.estimated { "~" } else { "" },
+                    c.cost,
+                    c.interval.as_secs() / 60
+                );
+            }
+            if let Some(message) = scheduler.budget_warning(kind.rate_pool()) {
+                tracing::warn!("engine: {message}");
+                let _ = notify_tx.send(Event::RefreshBudgetExceeded { message });
+            }
+        }
+        Request::PostponeRefresh {
+            view,
+            filter_idx,
+            until,
+        } => {
+            scheduler.postpone(filter_idx, view, until);
+            tracing::debug!("engine: postponed refresh of {view:?}[{filter_idx}]");
         }
         Request::ApprovePr {
             owner,
             repo,
             number,
             body,
+            host,
+            reply_tx,
+        } => handle_approve_pr(client, host.as_deref(), owner, repo, number, body, reply_tx).await,
+        Request::SubmitPrReview {
+            owner,
+            repo,
+            number,
+            event,
+            body,
+            comments,
+            host,
             reply_tx,
-        } => handle_approve_pr(client, owner, repo, number, body, reply_tx).await,
+        } => {
+            handle_submit_pr_review(
+                client,
+                host.as_deref(),
+                (owner, repo, number),
+                event,
+                &body,
+                &comments,
+                reply_tx,
+            )
+            .await;
+        }
         Request::MergePr {
             owner,
             repo,
             number,
+            method,
+            host,
             reply_tx,
-        } => handle_merge_pr(client, owner, repo, number, reply_tx).await,
+        } => {
+            handle_merge_pr(
+                client,
+                host.as_deref(),
+                (owner, repo, number, method),
+                reply_tx,
+            )
+            .await;
+        }
         Request::ClosePr {
             owner,
             repo,
             number,
+            host,
             reply_tx,
-        } => handle_close_pr(client, owner, repo, number, reply_tx).await,
+        } => handle_close_pr(client, host.as_deref(), owner, repo, number, reply_tx).await,
         Request::ReopenPr {
             owner,
             repo,
             number,
+            host,
             reply_tx,
-        } => handle_reopen_pr(client, owner, repo, number, reply_tx).await,
+        } => handle_reopen_pr(client, host.as_deref(), owner, repo, number, reply_tx).await,
         Request::AddPrComment {
             owner,
             repo,
             number,
             body,
+            host,
             reply_tx,
-        } => handle_add_pr_comment(client, owner, repo, number, body, reply_tx).await,
+        } => {
+            handle_add_pr_comment(client, host.as_deref(), owner, repo, number, body, reply_tx)
+                .await;
+        }
         Request::UpdateBranch {
             owner,
             repo,
             number,
+            host,
             reply_tx,
-        } => handle_update_branch(client, owner, repo, number, reply_tx).await,
+        } => handle_update_branch(client, host.as_deref(), owner, repo, number, reply_tx).await,
         Request::ReadyForReview {
             owner,
             repo,
             number,
+            host,
+            reply_tx,
+        } => handle_ready_for_review(client, host.as_deref(), owner, repo, number, reply_tx).await,
+        Request::SetFilesViewed {
+            owner,
+            repo,
+            number,
+            paths,
+            viewed,
+            host,
+            reply_tx,
+        } => {
+            handle_set_files_viewed(
+                client,
+                host.as_deref(),
+                (owner, repo, number),
+                paths,
+                viewed,
+                reply_tx,
+            )
+            .await;
+        }
+        Request::EnqueuePr {
+            owner,
+            repo,
+            number,
+            host,
             reply_tx,
-        } => handle_ready_for_review(client, owner, repo, number, reply_tx).await,
+        } => handle_enqueue_pr(client, host.as_deref(), owner, repo, number, reply_tx).await,
         Request::SetPrAssignees {
             owner,
             repo,
             number,
             logins,
+            host,
             reply_tx,
-        } => handle_set_pr_assignees(client, owner, repo, number, logins, reply_tx).await,
+        } => {
+            handle_set_pr_assignees(
+                client,
+                host.as_deref(),
+                owner,
+                repo,
+                number,
+                logins,
+                reply_tx,
+            )
+            .await;
+        }
         Request::SetPrLabels {
             owner,
             repo,
             number,
             labels,
+            host,
             reply_tx,
-        } => handle_set_pr_labels(client, owner, repo, number, labels, reply_tx).await,
+        } => {
+            handle_set_pr_labels(
+                client,
+                host.as_deref(),
+                owner,
+                repo,
+                number,
+                labels,
+                reply_tx,
+            )
+            .await;
+        }
         Request::CloseIssue {
             owner,
             repo,
             number,
+            host,
             reply_tx,
-        } => handle_close_issue(client, owner, repo, number, reply_tx).await,
+        } => handle_close_issue(client, host.as_deref(), owner, repo, number, reply_tx).await,
         Request::ReopenIssue {
             owner,
             repo,
             number,
+            host,
             reply_tx,
-        } => handle_reopen_issue(client, owner, repo, number, reply_tx).await,
+        } => handle_reopen_issue(client, host.as_deref(), owner, repo, number, reply_tx).await,
         Request::AddIssueComment {
             owner,
             repo,
             number,
             body,
+            host,
             reply_tx,
-        } => handle_add_issue_comment(client, owner, repo, number, body, reply_tx).await,
+        } => {
+            handle_add_issue_comment(client, host.as_deref(), owner, repo, number, body, reply_tx)
+                .await;
+        }
         Request::SetIssueLabels {
             owner,
             repo,
             number,
             labels,
+            host,
             reply_tx,
-        } => handle_set_issue_labels(client, owner, repo, number, labels, reply_tx).await,
+        } => {
+            handle_set_issue_labels(
+                client,
+                host.as_deref(),
+                owner,
+                repo,
+                number,
+                labels,
+                reply_tx,
+            )
+            .await;
+        }
         Request::SetIssueAssignees {
             owner,
             repo,
             number,
             logins,
+            host,
+            reply_tx,
+        } => {
+            handle_set_issue_assignees(
+                client,
+                host.as_deref(),
+                owner,
+                repo,
+                number,
+                logins,
+                reply_tx,
+            )
+            .await;
+        }
+        Request::SetIssueMilestone {
+            owner,
+            repo,
+            number,
+            milestone,
+            host,
             reply_tx,
-        } => handle_set_issue_assignees(client, owner, repo, number, logins, reply_tx).await,
+        } => {
+            handle_set_issue_milestone(
+                client,
+                host.as_deref(),
+                owner,
+                repo,
+                number,
+                milestone,
+                reply_tx,
+            )
+            .await;
+        }
         Request::RerunWorkflowRun {
             owner,
             repo,
             run_id,
             failed_only,
+            host,
             reply_tx,
-        } => handle_rerun_workflow_run(client, owner, repo, run_id, failed_only, reply_tx).await,
+        } => {
+            handle_rerun_workflow_run(
+                client,
+                host.as_deref(),
+                owner,
+                repo,
+                run_id,
+                failed_only,
+                reply_tx,
+            )
+            .await;
+        }
         Request::CancelWorkflowRun {
             owner,
             repo,
             run_id,
+            host,
             reply_tx,
-        } => handle_cancel_workflow_run(client, owner, repo, run_id, reply_tx).await,
-        Request::MarkNotificationRead { id, reply_tx } => {
-            handle_mark_notification_read(client, id, reply_tx).await;
+        } => {
+            handle_cancel_workflow_run(client, host.as_deref(), owner, repo, run_id, reply_tx)
+                .await;
         }
-
-        Request::MarkAllNotificationsRead { reply_tx } => {
-            handle_mark_all_notifications_read(client, reply_tx).await;
+        Request::CreateDraftRelease {
+            owner,
+            repo,
+            tag,
+            name,
+            body,
+            host,
+            reply_tx,
+        } => {
+            handle_create_draft_release(
+                client,
+                host.as_deref(),
+                &owner,
+                &repo,
+                (&tag, &name, &body),
+                reply_tx,
+            )
+            .await;
         }
-
-        Request::UnsubscribeNotification { id, reply_tx } => {
-            handle_unsubscribe_notification(client, id, reply_tx).await;
+        Request::PublishRelease {
+            owner,
+            repo,
+            id,
+            tag,
+            host,
+            reply_tx,
+        } => {
+            handle_publish_release(client, host.as_deref(), &owner, &repo, (id, &tag), reply_tx)
+                .await;
         }
-
-        Request::FetchRepoLabels {
+        Request::DeleteRelease {
             owner,
             repo,
+            id,
+            tag,
+            host,
             reply_tx,
-        } => handle_fetch_repo_labels(client, owner, repo, reply_tx).await,
-        Request::FetchRepoCollaborators {
+        } => {
+            handle_delete_release(client, host.as_deref(), &owner, &repo, (id, &tag), reply_tx)
+                .await;
+        }
+        Request::RerunJobs {
+            host,
             owner,
             repo,
+            job_ids,
             reply_tx,
-        } => handle_fetch_repo_collaborators(client, owner, repo, reply_tx).await,
-        Request::RefreshPr {
+        } => handle_rerun_jobs(client, host.as_deref(), &owner, &repo, &job_ids, reply_tx).await,
+        Request::ApproveWorkflowRuns {
+            host,
             owner,
             repo,
             number,
-            base_ref,
-            head_repo_owner,
-            head_ref,
+            run_ids,
             reply_tx,
         } => {
-            let pr_ref = PrRef {
-                owner,
-                repo,
-                number,
-                base_ref,
-                head_repo_owner,
-                head_ref,
-            };
-            handle_refresh_pr(client, pr_ref, reply_tx).await;
+            handle_approve_workflow_runs(
+                client,
+                host.as_deref(),
+                (&owner, &repo, number),
+                &run_ids,
+                reply_tx,
+            )
+            .await;
         }
-        Request::RefreshIssue {
+        Request::DispatchWorkflow {
+            host,
             owner,
             repo,
-            number,
+            workflow,
+            git_ref,
+            inputs,
             reply_tx,
-        } => handle_refresh_issue(client, owner, repo, number, reply_tx).await,
-        Request::FetchRunById {
+        } => {
+            handle_dispatch_workflow(
+                client,
+                host.as_deref(),
+                &format!("{owner}/{repo}"),
+                &workflow,
+                git_ref.as_deref(),
+                &inputs,
+                reply_tx,
+            )
+            .await;
+        }
+        Request::PlanRunCleanup {
             owner,
             repo,
-            run_id,
+            older_than_days,
             host,
             reply_tx,
-        } => handle_fetch_run_by_id(client, owner, repo, run_id, host, reply_tx).await,
-        Request::WatchRun {
+        } => {
+            handle_plan_run_cleanup(
+                client,
+                host.as_deref(),
+                owner,
+                repo,
+                older_than_days,
+                reply_tx,
+            )
+            .await;
+        }
+        Request::DeleteWorkflowRuns {
             owner,
             repo,
-            run_id,
+            run_ids,
+            progress,
             host,
             reply_tx,
         } => {
-            handle_watch_run(
+            handle_delete_workflow_runs(
+                client,
+                host.as_deref(),
+                (owner, repo),
+                run_ids,
+                progress,
+                reply_tx,
+            )
+            .await;
+        }
+        Request::CompareWithLastSuccess {
+            owner,
+            repo,
+            run,
+            host,
+            reply_tx,
+        } => {
+            handle_compare_with_last_success(client, host.as_deref(), owner, repo, *run, reply_tx)
+                .await;
+        }
+        Request::ShareItem {
+            target,
+            webhook,
+            payload,
+            reply_tx,
+        } => {
+            let result = crate::share::post(&webhook, &payload).await;
+            send_mutation_result(
+                client,
+                &reply_tx,
+                result,
+                format!("Shared to {target}"),
+                format!("Share to {target}"),
+                None,
+            )
+            .await;
+        }
+
+        Request::MarkNotificationRead { id, host, reply_tx } => {
+            handle_mark_notification_read(client, host.as_deref(), id, reply_tx).await;
+        }
+
+        Request::MarkAllNotificationsRead { host, reply_tx } => {
+            handle_mark_all_notifications_read(client, host.as_deref(), reply_tx).await;
+        }
+
+        Request::MarkNotificationDone { id, host, reply_tx } => {
+            handle_mark_notification_done(client, host.as_deref(), id, reply_tx).await;
+        }
+
+        Request::UnsubscribeNotification { id, host, reply_tx } => {
+            handle_unsubscribe_notification(client, host.as_deref(), id, reply_tx).await;
+        }
+
+        Request::FetchRepoLabels {
+            owner,
+            repo,
+            host,
+            reply_tx,
+        } => handle_fetch_repo_labels(client, host.as_deref(), owner, repo, reply_tx).await,
+        Request::FetchRepoCollaborators {
+            owner,
+            repo,
+            host,
+            reply_tx,
+        } => handle_fetch_repo_collaborators(client, host.as_deref(), owner, repo, reply_tx).await,
+        Request::FetchOpenIssueRefs {
+            owner,
+            repo,
+            host,
+            reply_tx,
+        } => handle_fetch_open_issue_refs(client, host.as_deref(), owner, repo, reply_tx).await,
+        Request::FetchMergeQueue {
+            owner,
+            repo,
+            host,
+            reply_tx,
+        } => handle_fetch_merge_queue(client, host.as_deref(), owner, repo, reply_tx).await,
+        Request::CheckHealth { hosts, reply_tx } => {
+            handle_check_health(client, hosts, reply_tx).await;
+        }
+        Request::FetchViewerSetup { host, reply_tx } => {
+            handle_fetch_viewer_setup(client, host.as_deref(), reply_tx).await;
+        }
+        Request::FetchTokenScopes { host, reply_tx } => {
+            handle_fetch_token_scopes(client, host.as_deref(), reply_tx).await;
+        }
+        Request::FetchViewerTeams { host, reply_tx } => {
+            handle_fetch_viewer_teams(client, host.as_deref(), reply_tx).await;
+        }
+        Request::FetchSavedReplies { host, reply_tx } => {
+            handle_fetch_saved_replies(client, host.as_deref(), reply_tx).await;
+        }
+        Request::FetchContributions {
+            host,
+            login,
+            reply_tx,
+        } => {
+            handle_fetch_contributions(client, host.as_deref(), login, reply_tx).await;
+        }
+        Request::FetchWorkflowSchedules {
+            host,
+            repos,
+            reply_tx,
+        } => {
+            handle_fetch_workflow_schedules(client, host.as_deref(), &repos, reply_tx).await;
+        }
+        Request::FetchDispatchableWorkflows {
+            host,
+            repos,
+            reply_tx,
+        } => {
+            handle_fetch_dispatchable_workflows(client, host.as_deref(), &repos, reply_tx).await;
+        }
+        Request::FetchRepoRefs {
+            host,
+            owner,
+            repo,
+            reply_tx,
+        } => {
+            handle_fetch_repo_refs(client, host.as_deref(), &owner, &repo, reply_tx).await;
+        }
+        Request::FetchReleases {
+            owner,
+            repo,
+            host,
+            reply_tx,
+        } => handle_fetch_releases(client, host.as_deref(), &owner, &repo, reply_tx).await,
+        Request::RefreshPr {
+            owner,
+            repo,
+            number,
+            base_ref,
+            head_repo_owner,
+            head_ref,
+            host,
+            reply_tx,
+        } => {
+            let pr_ref = PrRef {
+                owner,
+                repo,
+                number,
+                base_ref,
+                head_repo_owner,
+                head_ref,
+            };
+            handle_refresh_pr(client, host.as_deref(), pr_ref, reply_tx).await;
+        }
+        Request::RefreshIssue {
+            owner,
+            repo,
+            number,
+            host,
+            reply_tx,
+        } => handle_refresh_issue(client, host.as_deref(), owner, repo, number, reply_tx).await,
+        Request::FetchRunById {
+            owner,
+            repo,
+            run_id,
+            host,
+            reply_tx,
+        } => handle_fetch_run_by_id(client, owner, repo, run_id, host, reply_tx).await,
+        Request::WatchRun {
+            owner,
+            repo,
+            run_id,
+            host,
+            reply_tx,
+        } => {
+            handle_watch_run(
                 client,
                 watch_scheduler,
                 complete_command,
@@ -396,7 +1006,70 @@ async fn handle_request(
             watch_scheduler.remove(run_id);
             tracing::debug!("engine: unwatched run_id={run_id}");
         }
-        Request::Shutdown => unreachable!("handled at run_loop level"),
+        Request::WatchPr {
+            owner,
+            repo,
+            number,
+            base_ref,
+            head_repo_owner,
+            head_ref,
+            host,
+            reply_tx,
+        } => {
+            let pr_ref = PrRef {
+                owner,
+                repo,
+                number,
+                base_ref,
+                head_repo_owner,
+                head_ref,
+            };
+            watch_scheduler.prs.add(pr_ref, host, reply_tx);
+            // Take the baseline right away instead of on the next tick.
+            tick_pr_watches(client, watch_scheduler).await;
+        }
+        Request::UnwatchPr {
+            owner,
+            repo,
+            number,
+        } => {
+            watch_scheduler.prs.remove(&owner, &repo, number);
+            tracing::debug!("engine: unwatched PR {owner}/{repo}#{number}");
+        }
+        Request::MergeWhenGreen {
+            owner,
+            repo,
+            number,
+            base_ref,
+            head_repo_owner,
+            head_ref,
+            method,
+            host,
+            reply_tx,
+        } => {
+            let pr_ref = PrRef {
+                owner,
+                repo,
+                number,
+                base_ref,
+                head_repo_owner,
+                head_ref,
+            };
+            watch_scheduler.prs.arm(pr_ref, host, reply_tx, method);
+            // A PR already green merges right away.
+            tick_pr_watches(client, watch_scheduler).await;
+        }
+        Request::CancelMergeWhenGreen {
+            owner,
+            repo,
+            number,
+        } => {
+            watch_scheduler.prs.disarm(&owner, &repo, number);
+            tracing::debug!("engine: cancelled merge when green of {owner}/{repo}#{number}");
+        }
+        Request::CancelFetches { .. } | Request::Shutdown => {
+            unreachable!("handled at run_loop level")
+        }
     }
 }
 
@@ -404,10 +1077,28 @@ async fn handle_request(
 // Per-request handler functions
 // ---------------------------------------------------------------------------
 
+/// Run the list fetch `fetch`, and once more after [`RETRY_DELAY`] when it
+/// fails in a way that may go away on its own.
+async fn retry_transient<T>(
+    label: &str,
+    mut fetch: impl AsyncFnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    match fetch().await {
+        Err(e) if is_transient(&e) => {
+            tracing::info!("engine: {label} failed ({e}), retrying once");
+            tokio::time::sleep(RETRY_DELAY).await;
+            fetch().await
+        }
+        result => result,
+    }
+}
+
 /// Format an error for reply, using rate-limit info when available.
 fn format_fetch_error(e: &anyhow::Error) -> String {
     if is_rate_limited(e) {
         format_rate_limit_message(e)
+    } else if auth_warnings::is_sso_error(&format!("{e:#}")) {
+        "SAML SSO has not authorized the token for this organization".to_owned()
     } else {
         e.to_string()
     }
@@ -419,26 +1110,69 @@ async fn handle_fetch_prs(
     filter_idx: usize,
     filter: crate::config::types::PrFilter,
     force: bool,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
     let host = filter.host.as_deref().unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
     let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchPrs") else {
         return;
     };
+    let features = client.features_for(host, &octocrab).await;
     let cache = client.cache();
     let limit = filter.limit.unwrap_or(100);
     let cache_opt = if force { None } else { Some(&cache) };
-    match graphql::search_pull_requests_all(&octocrab, &filter.filters, limit, cache_opt).await {
-        Ok((prs, rate_limit)) => {
-            scheduler.mark_fetched(filter_idx, ViewKind::Prs);
+    // Large result sets are delivered page by page as they arrive.
+    let on_page = |prs: &[PullRequest]| {
+        let _ = reply_tx.send(Event::PrsFetched {
+            filter_idx,
+            prs: prs.to_vec(),
+            rate_limit: None,
+            warnings: Vec::new(),
+            complete: false,
+        });
+    };
+    let fetch = async || {
+        graphql::search_pull_requests_all(
+            &octocrab,
+            features,
+            &filter.filters,
+            limit,
+            cache_opt,
+            &on_page,
+        )
+        .await
+    };
+    match retry_transient("FetchPrs", fetch).await {
+        Ok((prs, rate_limit, warnings)) => {
+            scheduler.mark_fetched(filter_idx, ViewKind::Prs, rate_limit.as_ref());
             tracing::debug!(
                 "engine: sending PrsFetched[{filter_idx}] count={}",
                 prs.len()
             );
+            if warnings.is_empty() {
+                super::disk_cache::store_prs(&filter, &prs);
+                if super::bell::wants_viewer(host) {
+                    match gh_viewer::fetch_viewer_login(&octocrab).await {
+                        Ok(login) => {
+                            // Without `read:org`, team requests just don't ring.
+                            let teams = gh_viewer::fetch_viewer_teams(&octocrab)
+                                .await
+                                .unwrap_or_default();
+                            super::bell::set_viewer(host, login, teams);
+                        }
+                        Err(e) => tracing::debug!("engine: bell: fetching the viewer: {e}"),
+                    }
+                }
+                super::bell::observe_prs(&filter, &prs);
+            } else {
+                tracing::warn!("engine: FetchPrs[{filter_idx}] partial: {warnings:?}");
+            }
             let _ = reply_tx.send(Event::PrsFetched {
                 filter_idx,
                 prs,
                 rate_limit,
+                warnings,
+                complete: true,
             });
         }
         Err(e) => {
@@ -457,26 +1191,54 @@ async fn handle_fetch_issues(
     filter_idx: usize,
     filter: crate::config::types::IssueFilter,
     force: bool,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
     let host = filter.host.as_deref().unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
     let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchIssues") else {
         return;
     };
     let cache = client.cache();
     let limit = filter.limit.unwrap_or(100);
     let cache_opt = if force { None } else { Some(&cache) };
-    match graphql::search_issues_all(&octocrab, &filter.filters, limit, cache_opt).await {
-        Ok((issues, rate_limit)) => {
-            scheduler.mark_fetched(filter_idx, ViewKind::Issues);
+    let on_page = |issues: &[Issue]| {
+        let _ = reply_tx.send(Event::IssuesFetched {
+            filter_idx,
+            issues: issues.to_vec(),
+            rate_limit: None,
+            warnings: Vec::new(),
+            complete: false,
+        });
+    };
+    let fetch = async || {
+        graphql::search_issues_all(
+            &octocrab,
+            &filter.filters,
+            filter.kind,
+            limit,
+            cache_opt,
+            &on_page,
+        )
+        .await
+    };
+    match retry_transient("FetchIssues", fetch).await {
+        Ok((issues, rate_limit, warnings)) => {
+            scheduler.mark_fetched(filter_idx, ViewKind::Issues, rate_limit.as_ref());
             tracing::debug!(
                 "engine: sending IssuesFetched[{filter_idx}] count={}",
                 issues.len()
             );
+            if warnings.is_empty() {
+                super::disk_cache::store_issues(&filter, &issues);
+            } else {
+                tracing::warn!("engine: FetchIssues[{filter_idx}] partial: {warnings:?}");
+            }
             let _ = reply_tx.send(Event::IssuesFetched {
                 filter_idx,
                 issues,
                 rate_limit,
+                warnings,
+                complete: true,
             });
         }
         Err(e) => {
@@ -489,24 +1251,112 @@ async fn handle_fetch_issues(
     }
 }
 
+/// A script's PR search: the results go straight back, without a tab
+/// fetch's scheduling, disk cache or bell bookkeeping.
+async fn handle_search_prs(
+    client: &mut GitHubClient,
+    query: &str,
+    limit: u32,
+    host: Option<&str>,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SearchPrs") else {
+        return;
+    };
+    let features = client.features_for(host, &octocrab).await;
+    let cache = client.cache();
+    let fetch = async || {
+        graphql::search_pull_requests_all(&octocrab, features, query, limit, Some(&cache), |_| {})
+            .await
+    };
+    match retry_transient("SearchPrs", fetch).await {
+        Ok((prs, rate_limit, warnings)) => {
+            let _ = reply_tx.send(Event::PrsFetched {
+                filter_idx: 0,
+                prs,
+                rate_limit,
+                warnings,
+                complete: true,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: SearchPrs error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "SearchPrs".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+/// A script's issue search; see [`handle_search_prs`].
+async fn handle_search_issues(
+    client: &mut GitHubClient,
+    query: &str,
+    limit: u32,
+    host: Option<&str>,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SearchIssues") else {
+        return;
+    };
+    let cache = client.cache();
+    let fetch = async || {
+        graphql::search_issues_all(
+            &octocrab,
+            query,
+            IssueFilterKind::default(),
+            limit,
+            Some(&cache),
+            |_| {},
+        )
+        .await
+    };
+    match retry_transient("SearchIssues", fetch).await {
+        Ok((issues, rate_limit, warnings)) => {
+            let _ = reply_tx.send(Event::IssuesFetched {
+                filter_idx: 0,
+                issues,
+                rate_limit,
+                warnings,
+                complete: true,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: SearchIssues error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "SearchIssues".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
 async fn handle_fetch_actions(
     client: &mut GitHubClient,
     scheduler: &mut RefreshScheduler,
     filter_idx: usize,
     filter: crate::config::types::ActionsFilter,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
     let host = filter.host.as_deref().unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
     let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchActions") else {
         return;
     };
-    match gh_actions::fetch_workflow_runs(&octocrab, &filter).await {
+    let fetch = async || gh_actions::fetch_workflow_runs(&octocrab, &filter).await;
+    match retry_transient("FetchActions", fetch).await {
         Ok((runs, rate_limit)) => {
-            scheduler.mark_fetched(filter_idx, ViewKind::Actions);
+            scheduler.mark_fetched(filter_idx, ViewKind::Actions, rate_limit.as_ref());
             tracing::debug!(
                 "engine: sending ActionsFetched[{filter_idx}] count={}",
                 runs.len()
             );
+            super::disk_cache::store_actions(&filter, &runs);
             let _ = reply_tx.send(Event::ActionsFetched {
                 filter_idx,
                 runs,
@@ -528,9 +1378,10 @@ async fn handle_fetch_alerts(
     scheduler: &mut RefreshScheduler,
     filter_idx: usize,
     filter: crate::config::types::AlertsFilter,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
     let host = filter.host.as_deref().unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
     let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchAlerts") else {
         return;
     };
@@ -546,37 +1397,37 @@ async fn handle_fetch_alerts(
     let mut all_alerts = Vec::new();
     let mut last_rl = None;
 
-    match gh_security::fetch_dependabot_alerts(&octocrab, owner, repo, limit).await {
+    // Each kind is retried on its own; one failing leaves the others listed.
+    let fetch = async || gh_security::fetch_dependabot_alerts(&octocrab, owner, repo, limit).await;
+    match retry_transient("FetchAlerts dependabot", fetch).await {
         Ok((alerts, rl)) => {
             all_alerts.extend(alerts);
-            if rl.is_some() {
-                last_rl = rl;
-            }
+            graphql::accumulate_rate_limit(&mut last_rl, rl);
         }
         Err(e) => tracing::warn!("engine: FetchAlerts[{filter_idx}] dependabot: {e}"),
     }
-    match gh_security::fetch_code_scanning_alerts(&octocrab, owner, repo, limit).await {
+    let fetch =
+        async || gh_security::fetch_code_scanning_alerts(&octocrab, owner, repo, limit).await;
+    match retry_transient("FetchAlerts code-scanning", fetch).await {
         Ok((alerts, rl)) => {
             all_alerts.extend(alerts);
-            if rl.is_some() {
-                last_rl = rl;
-            }
+            graphql::accumulate_rate_limit(&mut last_rl, rl);
         }
         Err(e) => tracing::warn!("engine: FetchAlerts[{filter_idx}] code-scanning: {e}"),
     }
-    match gh_security::fetch_secret_scanning_alerts(&octocrab, owner, repo, limit).await {
+    let fetch =
+        async || gh_security::fetch_secret_scanning_alerts(&octocrab, owner, repo, limit).await;
+    match retry_transient("FetchAlerts secret-scanning", fetch).await {
         Ok((alerts, rl)) => {
             all_alerts.extend(alerts);
-            if rl.is_some() {
-                last_rl = rl;
-            }
+            graphql::accumulate_rate_limit(&mut last_rl, rl);
         }
         Err(e) => tracing::warn!("engine: FetchAlerts[{filter_idx}] secret-scanning: {e}"),
     }
 
     all_alerts.sort_by_key(|a| std::cmp::Reverse(a.created_at));
 
-    scheduler.mark_fetched(filter_idx, ViewKind::Alerts);
+    scheduler.mark_fetched(filter_idx, ViewKind::Alerts, last_rl.as_ref());
     tracing::debug!(
         "engine: sending AlertsFetched[{filter_idx}] count={}",
         all_alerts.len()
@@ -588,15 +1439,78 @@ async fn handle_fetch_alerts(
     });
 }
 
+async fn handle_fetch_org_events(
+    client: &mut GitHubClient,
+    scheduler: &mut RefreshScheduler,
+    filter_idx: usize,
+    filter: crate::config::types::EventsFilter,
+    reply_tx: EventSender,
+) {
+    let host = filter.host.as_deref().unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchOrgEvents") else {
+        return;
+    };
+    let limit = filter.limit.unwrap_or(50);
+    let fetch = async || match &filter.org {
+        Some(org) => {
+            gh_events::fetch_org_events(
+                &octocrab,
+                host,
+                org,
+                filter.actor.as_deref(),
+                filter.action.as_deref(),
+                limit,
+            )
+            .await
+        }
+        None => gh_events::fetch_feed(
+            &octocrab,
+            host,
+            &filter.repos,
+            filter.received,
+            filter.actor.as_deref(),
+            filter.action.as_deref(),
+            limit,
+        )
+        .await
+        .map(|(events, rate_limit)| (events, OrgEventSource::Feed, rate_limit)),
+    };
+    match retry_transient("FetchOrgEvents", fetch).await {
+        Ok((events, source, rate_limit)) => {
+            scheduler.mark_fetched(filter_idx, ViewKind::Events, rate_limit.as_ref());
+            tracing::debug!(
+                "engine: sending OrgEventsFetched[{filter_idx}] count={}",
+                events.len()
+            );
+            let _ = reply_tx.send(Event::OrgEventsFetched {
+                filter_idx,
+                events,
+                source,
+                rate_limit,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchOrgEvents[{filter_idx}] error: {e:#}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: format!("FetchOrgEvents[{filter_idx}]"),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
 async fn handle_fetch_secret_locations(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     alert_number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "FetchSecretLocations")
-    else {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchSecretLocations") else {
         return;
     };
     match gh_security::fetch_secret_alert_locations(&octocrab, &owner, &repo, alert_number).await {
@@ -623,9 +1537,10 @@ async fn handle_fetch_run_jobs(
     repo: String,
     run_id: u64,
     host: Option<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
     let host = host.as_deref().unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
     let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchRunJobs") else {
         return;
     };
@@ -651,22 +1566,66 @@ async fn handle_fetch_run_jobs(
     }
 }
 
-async fn handle_fetch_notifications(
+async fn handle_fetch_job_logs(
     client: &mut GitHubClient,
-    scheduler: &mut RefreshScheduler,
-    filter_idx: usize,
-    filter: crate::config::types::NotificationFilter,
-    reply_tx: Sender<Event>,
+    owner: String,
+    repo: String,
+    job_id: u64,
+    host: Option<String>,
+    reply_tx: EventSender,
 ) {
-    let host = filter.host.as_deref().unwrap_or("github.com");
+    let host = host.as_deref().unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchJobLogs") else {
+        return;
+    };
+    match gh_actions::fetch_job_log(&octocrab, &owner, &repo, job_id).await {
+        Ok((status, log)) => {
+            let _ = reply_tx.send(Event::JobLogsFetched {
+                job_id,
+                status,
+                log,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchJobLogs job_id={job_id} error: {e:#}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "FetchJobLogs".to_owned(),
+                message: format!("{e:#}"),
+            });
+        }
+    }
+}
+
+async fn handle_fetch_notifications(
+    client: &mut GitHubClient,
+    scheduler: &mut RefreshScheduler,
+    filter_idx: usize,
+    filter: crate::config::types::NotificationFilter,
+    rules: &[NotificationRule],
+    reply_tx: EventSender,
+) {
+    let host = filter.host.as_deref().unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
     let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchNotifications") else {
         return;
     };
     let limit = filter.limit.unwrap_or(50);
     let params = notif::parse_filters(&filter.filters, limit);
-    match notif::fetch_notifications(&octocrab, &params).await {
-        Ok((notifications, rate_limit)) => {
-            scheduler.mark_fetched(filter_idx, ViewKind::Notifications);
+    let fetch = async || notif::fetch_notifications(&octocrab, &params).await;
+    match retry_transient("FetchNotifications", fetch).await {
+        Ok((mut notifications, rate_limit)) => {
+            scheduler.mark_fetched(filter_idx, ViewKind::Notifications, rate_limit.as_ref());
+            // Enrichment is best-effort: the bare list is still useful.
+            if let Err(e) = notif::resolve_subjects(&octocrab, &mut notifications).await {
+                tracing::warn!("engine: FetchNotifications[{filter_idx}] subjects: {e}");
+            }
+            let outcome = notif::apply_rules(notifications, rules);
+            for id in &outcome.to_mark_done {
+                if let Err(e) = notif::mark_as_done(&octocrab, id).await {
+                    tracing::warn!("engine: FetchNotifications[{filter_idx}] mute {id}: {e}");
+                }
+            }
+            let notifications = outcome.kept;
             tracing::debug!(
                 "engine: sending NotificationsFetched[{filter_idx}] count={}",
                 notifications.len()
@@ -674,6 +1633,7 @@ async fn handle_fetch_notifications(
             let _ = reply_tx.send(Event::NotificationsFetched {
                 filter_idx,
                 notifications,
+                muted: outcome.muted,
                 rate_limit,
             });
         }
@@ -689,10 +1649,13 @@ async fn handle_fetch_notifications(
 
 async fn handle_fetch_pr_detail(
     client: &mut GitHubClient,
+    host: Option<&str>,
     pr_ref: PrRef,
     force: bool,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
     let PrRef {
         owner,
         repo,
@@ -701,15 +1664,16 @@ async fn handle_fetch_pr_detail(
         head_repo_owner,
         head_ref,
     } = pr_ref;
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "FetchPrDetail") else {
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchPrDetail") else {
         return;
     };
+    let features = client.features_for(host, &octocrab).await;
     let cache = client.cache();
     if force {
         let cache_key = format!("pr:{owner}/{repo}#{number}");
         cache.remove(&cache_key).await;
     }
-    match graphql::fetch_pr_detail(&octocrab, &owner, &repo, number, Some(&cache)).await {
+    match graphql::fetch_pr_detail(&octocrab, features, &owner, &repo, number, Some(&cache)).await {
         Ok((mut detail, rate_limit)) => {
             if detail.behind_by.is_none()
                 && let Some(ref head_owner) = head_repo_owner
@@ -740,14 +1704,50 @@ async fn handle_fetch_pr_detail(
     }
 }
 
+async fn handle_fetch_pr_detail_page(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    (owner, repo, number): (String, String, u64),
+    connection: PrDetailConnection,
+    after: String,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchPrDetailPage") else {
+        return;
+    };
+    match graphql::fetch_pr_detail_page(&octocrab, &owner, &repo, number, connection, &after).await
+    {
+        Ok((page, rate_limit)) => {
+            tracing::debug!("engine: sending PrDetailPageFetched #{number} ({connection:?})");
+            let _ = reply_tx.send(Event::PrDetailPageFetched {
+                number,
+                page,
+                rate_limit,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchPrDetailPage #{number} error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: format!("FetchPrDetailPage #{number}"),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
 async fn handle_fetch_issue_detail(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "FetchIssueDetail") else {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchIssueDetail") else {
         return;
     };
     let cache = client.cache();
@@ -772,16 +1772,29 @@ async fn handle_fetch_issue_detail(
 
 async fn handle_prefetch_pr_details(
     client: &mut GitHubClient,
+    host: Option<&str>,
     prs: Vec<PrRef>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "PrefetchPrDetails") else {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "PrefetchPrDetails") else {
         return;
     };
+    let features = client.features_for(host, &octocrab).await;
     let cache = client.cache();
     for pr in prs {
         let number = pr.number;
-        match graphql::fetch_pr_detail(&octocrab, &pr.owner, &pr.repo, number, Some(&cache)).await {
+        match graphql::fetch_pr_detail(
+            &octocrab,
+            features,
+            &pr.owner,
+            &pr.repo,
+            number,
+            Some(&cache),
+        )
+        .await
+        {
             Ok((mut detail, rate_limit)) => {
                 if detail.behind_by.is_none()
                     && let Some(ref head_owner) = pr.head_repo_owner
@@ -818,13 +1831,15 @@ async fn handle_prefetch_pr_details(
 
 async fn handle_approve_pr(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
     body: Option<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "ApprovePr") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "ApprovePr") else {
         return;
     };
     let result = pr_actions::approve(&octocrab, &owner, &repo, number, body.as_deref()).await;
@@ -839,21 +1854,61 @@ async fn handle_approve_pr(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
+    }
+}
+
+async fn handle_submit_pr_review(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    (owner, repo, number): (String, String, u64),
+    event: ReviewEvent,
+    body: &str,
+    comments: &[ReviewLineComment],
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SubmitPrReview") else {
+        return;
+    };
+    let result =
+        pr_actions::submit_review(&octocrab, (&owner, &repo, number), event, body, comments).await;
+    let verdict = match event {
+        ReviewEvent::Approve => format!("Approved PR #{number}"),
+        ReviewEvent::RequestChanges => format!("Requested changes on PR #{number}"),
+        ReviewEvent::Comment => format!("Reviewed PR #{number}"),
+    };
+    let ok_desc = match comments.len() {
+        0 => verdict,
+        1 => format!("{verdict} with 1 line comment"),
+        n => format!("{verdict} with {n} line comments"),
+    };
+    let ck = Some(format!("pr:{owner}/{repo}#{number}"));
+    let ok = send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        ok_desc,
+        format!("Review PR #{number}"),
+        ck,
+    )
+    .await;
+    if ok {
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_merge_pr(
     client: &mut GitHubClient,
-    owner: String,
-    repo: String,
-    number: u64,
-    reply_tx: Sender<Event>,
+    host: Option<&str>,
+    (owner, repo, number, method): (String, String, u64, MergeMethod),
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "MergePr") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "MergePr") else {
         return;
     };
-    let result = pr_actions::merge(&octocrab, &owner, &repo, number).await;
+    let result = pr_actions::merge(&octocrab, &owner, &repo, number, method).await;
     let ck = Some(format!("pr:{owner}/{repo}#{number}"));
     let ok = send_mutation_result(
         client,
@@ -865,18 +1920,20 @@ async fn handle_merge_pr(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_close_pr(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "ClosePr") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "ClosePr") else {
         return;
     };
     let result = pr_actions::close(&octocrab, &owner, &repo, number).await;
@@ -891,18 +1948,20 @@ async fn handle_close_pr(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_reopen_pr(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "ReopenPr") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "ReopenPr") else {
         return;
     };
     let result = pr_actions::reopen(&octocrab, &owner, &repo, number).await;
@@ -917,19 +1976,21 @@ async fn handle_reopen_pr(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_add_pr_comment(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
     body: String,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "AddPrComment") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "AddPrComment") else {
         return;
     };
     let result = pr_actions::add_comment(&octocrab, &owner, &repo, number, &body).await;
@@ -944,18 +2005,20 @@ async fn handle_add_pr_comment(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_update_branch(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "UpdateBranch") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "UpdateBranch") else {
         return;
     };
     let result = pr_actions::update_branch(&octocrab, &owner, &repo, number).await;
@@ -970,18 +2033,48 @@ async fn handle_update_branch(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
+async fn handle_set_files_viewed(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    (owner, repo, number): (String, String, u64),
+    paths: Vec<String>,
+    viewed: bool,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SetFilesViewed") else {
+        return;
+    };
+    let result =
+        pr_actions::set_files_viewed(&octocrab, &owner, &repo, number, &paths, viewed).await;
+    let noun = if paths.len() == 1 { "file" } else { "files" };
+    let how = if viewed { "viewed" } else { "unviewed" };
+    // The marks are already applied locally: nothing to refetch.
+    send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Marked {} {noun} {how} on GitHub", paths.len()),
+        format!("Mark {} {noun} {how} on GitHub", paths.len()),
+        None,
+    )
+    .await;
+}
+
 async fn handle_ready_for_review(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "ReadyForReview") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "ReadyForReview") else {
         return;
     };
     let result = pr_actions::ready_for_review(&octocrab, &owner, &repo, number).await;
@@ -996,19 +2089,49 @@ async fn handle_ready_for_review(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
+    }
+}
+
+async fn handle_enqueue_pr(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    number: u64,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "EnqueuePr") else {
+        return;
+    };
+    let result = pr_actions::enqueue(&octocrab, &owner, &repo, number).await;
+    let ck = Some(format!("pr:{owner}/{repo}#{number}"));
+    let ok = send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Added PR #{number} to the merge queue"),
+        format!("Add PR #{number} to the merge queue"),
+        ck,
+    )
+    .await;
+    if ok {
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_set_pr_assignees(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
     logins: Vec<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "SetPrAssignees") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SetPrAssignees") else {
         return;
     };
     let result = issue_actions::set_assignees(&octocrab, &owner, &repo, number, &logins).await;
@@ -1023,19 +2146,21 @@ async fn handle_set_pr_assignees(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_set_pr_labels(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
     labels: Vec<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "SetPrLabels") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SetPrLabels") else {
         return;
     };
     let result = issue_actions::set_labels(&octocrab, &owner, &repo, number, &labels).await;
@@ -1050,18 +2175,20 @@ async fn handle_set_pr_labels(
     )
     .await;
     if ok {
-        post_mutation_refresh_pr(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_pr(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_close_issue(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "CloseIssue") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "CloseIssue") else {
         return;
     };
     let result = issue_actions::close(&octocrab, &owner, &repo, number).await;
@@ -1075,18 +2202,20 @@ async fn handle_close_issue(
     )
     .await;
     if ok {
-        post_mutation_refresh_issue(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_issue(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_reopen_issue(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "ReopenIssue") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "ReopenIssue") else {
         return;
     };
     let result = issue_actions::reopen(&octocrab, &owner, &repo, number).await;
@@ -1100,19 +2229,21 @@ async fn handle_reopen_issue(
     )
     .await;
     if ok {
-        post_mutation_refresh_issue(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_issue(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_add_issue_comment(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
     body: String,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "AddIssueComment") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "AddIssueComment") else {
         return;
     };
     let result = issue_actions::add_comment(&octocrab, &owner, &repo, number, &body).await;
@@ -1126,19 +2257,21 @@ async fn handle_add_issue_comment(
     )
     .await;
     if ok {
-        post_mutation_refresh_issue(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_issue(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_set_issue_labels(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
     labels: Vec<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "SetIssueLabels") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SetIssueLabels") else {
         return;
     };
     let result = issue_actions::set_labels(&octocrab, &owner, &repo, number, &labels).await;
@@ -1152,19 +2285,21 @@ async fn handle_set_issue_labels(
     )
     .await;
     if ok {
-        post_mutation_refresh_issue(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_issue(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_set_issue_assignees(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
     logins: Vec<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "SetIssueAssignees") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SetIssueAssignees") else {
         return;
     };
     let result = issue_actions::set_assignees(&octocrab, &owner, &repo, number, &logins).await;
@@ -1178,19 +2313,54 @@ async fn handle_set_issue_assignees(
     )
     .await;
     if ok {
-        post_mutation_refresh_issue(client, &owner, &repo, number, &reply_tx).await;
+        post_mutation_refresh_issue(client, host, &owner, &repo, number, &reply_tx).await;
+    }
+}
+
+async fn handle_set_issue_milestone(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    number: u64,
+    milestone: Option<String>,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "SetIssueMilestone") else {
+        return;
+    };
+    let result =
+        issue_actions::set_milestone(&octocrab, &owner, &repo, number, milestone.as_deref()).await;
+    let description = match &milestone {
+        Some(title) => format!("Moved #{number} to milestone {title}"),
+        None => format!("Cleared the milestone of #{number}"),
+    };
+    let ok = send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        description,
+        format!("Set milestone on #{number}"),
+        None,
+    )
+    .await;
+    if ok {
+        post_mutation_refresh_issue(client, host, &owner, &repo, number, &reply_tx).await;
     }
 }
 
 async fn handle_rerun_workflow_run(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     run_id: u64,
     failed_only: bool,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "RerunWorkflowRun") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "RerunWorkflowRun") else {
         return;
     };
     let result =
@@ -1200,159 +2370,860 @@ async fn handle_rerun_workflow_run(
     } else {
         "all jobs"
     };
-    send_mutation_result(
-        client,
-        &reply_tx,
-        result,
-        format!("Re-run {label} queued for run #{run_id}"),
-        format!("Re-run workflow run #{run_id}"),
-        None,
-    )
-    .await;
+    let ok = send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Re-run {label} queued for run #{run_id}"),
+        format!("Re-run workflow run #{run_id}"),
+        None,
+    )
+    .await;
+    if ok {
+        post_mutation_refresh_run(client, host, &owner, &repo, run_id, &reply_tx).await;
+    }
+}
+
+async fn handle_dispatch_workflow(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    full_repo: &str,
+    workflow: &str,
+    git_ref: Option<&str>,
+    inputs: &IndexMap<String, String>,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "DispatchWorkflow") else {
+        return;
+    };
+    let (owner, repo) = full_repo.split_once('/').unwrap_or((full_repo, ""));
+    let result =
+        gh_actions::dispatch_workflow(&octocrab, owner, repo, workflow, git_ref, inputs).await;
+    let ok_desc = match &result {
+        Ok(used_ref) => format!("Dispatched {workflow} on {used_ref} in {full_repo}"),
+        Err(_) => String::new(),
+    };
+    send_mutation_result(
+        client,
+        &reply_tx,
+        result.map(|_| ()),
+        ok_desc,
+        format!("Dispatch {workflow}"),
+        None,
+    )
+    .await;
+}
+
+async fn handle_cancel_workflow_run(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    run_id: u64,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "CancelWorkflowRun") else {
+        return;
+    };
+    let result = gh_actions::cancel_workflow_run(&octocrab, &owner, &repo, run_id).await;
+    let ok = send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Cancelled run #{run_id}"),
+        format!("Cancel workflow run #{run_id}"),
+        None,
+    )
+    .await;
+    if ok {
+        post_mutation_refresh_run(client, host, &owner, &repo, run_id, &reply_tx).await;
+    }
+}
+
+async fn handle_create_draft_release(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: &str,
+    repo: &str,
+    (tag, name, body): (&str, &str, &str),
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "CreateDraftRelease") else {
+        return;
+    };
+    let event = match releases::create_draft_release(&octocrab, owner, repo, tag, name, body).await
+    {
+        Ok(url) => Event::MutationOk {
+            description: format!("Drafted release {tag}: {url}"),
+        },
+        Err(e) => Event::MutationError {
+            description: format!("Draft release {tag}"),
+            message: format!("{e:#}"),
+        },
+    };
+    let _ = reply_tx.send(event);
+}
+
+async fn handle_publish_release(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: &str,
+    repo: &str,
+    (id, tag): (u64, &str),
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "PublishRelease") else {
+        return;
+    };
+    let event = match releases::publish_release(&octocrab, owner, repo, id).await {
+        Ok(url) => Event::MutationOk {
+            description: format!("Published release {tag}: {url}"),
+        },
+        Err(e) => Event::MutationError {
+            description: format!("Publish release {tag}"),
+            message: format!("{e:#}"),
+        },
+    };
+    let _ = reply_tx.send(event);
+}
+
+async fn handle_delete_release(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: &str,
+    repo: &str,
+    (id, tag): (u64, &str),
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "DeleteRelease") else {
+        return;
+    };
+    let result = releases::delete_release(&octocrab, owner, repo, id).await;
+    send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Deleted release {tag}"),
+        format!("Delete release {tag}"),
+        None,
+    )
+    .await;
+}
+
+async fn handle_rerun_jobs(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: &str,
+    repo: &str,
+    job_ids: &[u64],
+    reply_tx: EventSender,
+) {
+    let Some(octocrab) = get_octocrab(client, host.unwrap_or("github.com"), &reply_tx, "RerunJobs")
+    else {
+        return;
+    };
+    let mut result = Ok(());
+    for &job_id in job_ids {
+        result = gh_actions::rerun_job(&octocrab, owner, repo, job_id).await;
+        if result.is_err() {
+            break;
+        }
+    }
+    let noun = if job_ids.len() == 1 { "job" } else { "jobs" };
+    send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!(
+            "Re-run queued for {} {noun} in {owner}/{repo}",
+            job_ids.len()
+        ),
+        format!("Re-run {noun} in {owner}/{repo}"),
+        None,
+    )
+    .await;
+}
+
+async fn handle_approve_workflow_runs(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    (owner, repo, number): (&str, &str, u64),
+    run_ids: &[u64],
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "ApproveWorkflowRuns") else {
+        return;
+    };
+    let mut result = Ok(());
+    for &run_id in run_ids {
+        result = gh_actions::approve_workflow_run(&octocrab, owner, repo, run_id).await;
+        if result.is_err() {
+            break;
+        }
+    }
+    let noun = if run_ids.len() == 1 { "run" } else { "runs" };
+    let ok = send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Approved {} workflow {noun} of PR #{number}", run_ids.len()),
+        format!("Approve the workflow {noun} of PR #{number}"),
+        Some(format!("pr:{owner}/{repo}#{number}")),
+    )
+    .await;
+    if ok {
+        post_mutation_refresh_pr(client, host, owner, repo, number, &reply_tx).await;
+    }
+}
+
+async fn handle_plan_run_cleanup(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    older_than_days: u32,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "PlanRunCleanup") else {
+        return;
+    };
+    match gh_actions::plan_run_cleanup(&octocrab, &owner, &repo, older_than_days).await {
+        Ok((candidates, rate_limit)) => {
+            let _ = reply_tx.send(Event::RunCleanupPlanned {
+                owner,
+                repo,
+                candidates,
+                rate_limit,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: PlanRunCleanup error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "PlanRunCleanup".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+/// Delete one chunk of a cleanup's runs. Each run is reported as soon as it
+/// is gone, so a chunk cut short by the request timeout still leaves the
+/// list accurate.
+async fn handle_delete_workflow_runs(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    (owner, repo): (String, String),
+    run_ids: Vec<u64>,
+    (done, total): (usize, usize),
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "DeleteWorkflowRuns") else {
+        return;
+    };
+    let mut deleted = done;
+    let mut result = Ok(());
+    for run_id in run_ids {
+        if let Err(e) = gh_actions::delete_workflow_run(&octocrab, &owner, &repo, run_id).await {
+            result = Err(e.context(format!("after deleting {deleted} of {total}")));
+            break;
+        }
+        deleted += 1;
+        let _ = reply_tx.send(Event::RunsDeleted {
+            run_ids: vec![run_id],
+        });
+    }
+    send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Deleted {deleted} of {total} workflow runs from {owner}/{repo}"),
+        format!("Delete workflow runs in {owner}/{repo}"),
+        None,
+    )
+    .await;
+}
+
+async fn handle_compare_with_last_success(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    run: WorkflowRun,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "CompareWithLastSuccess") else {
+        return;
+    };
+    match gh_actions::compare_with_last_success(&octocrab, &owner, &repo, &run).await {
+        Ok((comparison, rate_limit)) => {
+            let _ = reply_tx.send(Event::RunComparisonFetched {
+                run_id: run.id,
+                comparison: comparison.map(Box::new),
+                rate_limit,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: CompareWithLastSuccess error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "CompareWithLastSuccess".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+async fn handle_mark_notification_read(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    id: String,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "MarkNotificationRead") else {
+        return;
+    };
+    let result = notif::mark_as_read(&octocrab, &id).await;
+    let ok = send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Marked notification {id} as read"),
+        format!("Mark notification {id} as read"),
+        None,
+    )
+    .await;
+    if ok {
+        let _ = reply_tx.send(Event::NotificationsRead { id: Some(id) });
+    }
+}
+
+async fn handle_mark_all_notifications_read(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "MarkAllNotificationsRead") else {
+        return;
+    };
+    let result = notif::mark_all_as_read(&octocrab).await;
+    let ok = send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        "Marked all notifications as read".to_owned(),
+        "Mark all notifications as read".to_owned(),
+        None,
+    )
+    .await;
+    if ok {
+        let _ = reply_tx.send(Event::NotificationsRead { id: None });
+    }
+}
+
+async fn handle_mark_notification_done(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    id: String,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "MarkNotificationDone") else {
+        return;
+    };
+    let result = notif::mark_as_done(&octocrab, &id).await;
+    send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Marked notification {id} as done"),
+        format!("Mark notification {id} as done"),
+        None,
+    )
+    .await;
+}
+
+async fn handle_unsubscribe_notification(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    id: String,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "UnsubscribeNotification") else {
+        return;
+    };
+    let result = notif::unsubscribe(&octocrab, &id).await;
+    send_mutation_result(
+        client,
+        &reply_tx,
+        result,
+        format!("Unsubscribed from notification {id}"),
+        format!("Unsubscribe from notification {id}"),
+        None,
+    )
+    .await;
+}
+
+async fn handle_fetch_repo_labels(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchRepoLabels") else {
+        return;
+    };
+    let cache = client.cache();
+    match graphql::fetch_repo_labels(&octocrab, &owner, &repo, Some(&cache)).await {
+        Ok((labels, rate_limit)) => {
+            tracing::debug!(
+                "engine: sending RepoLabelsFetched {owner}/{repo} count={}",
+                labels.len()
+            );
+            let _ = reply_tx.send(Event::RepoLabelsFetched {
+                labels: labels.into_iter().map(|l| l.name).collect(),
+                rate_limit,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchRepoLabels {owner}/{repo} error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: format!("FetchRepoLabels {owner}/{repo}"),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+async fn handle_fetch_repo_collaborators(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchRepoCollaborators") else {
+        return;
+    };
+    let cache = client.cache();
+    match graphql::fetch_repo_collaborators(&octocrab, &owner, &repo, Some(&cache)).await {
+        Ok((logins, rate_limit)) => {
+            tracing::debug!(
+                "engine: sending RepoCollaboratorsFetched {owner}/{repo} count={}",
+                logins.len()
+            );
+            let _ = reply_tx.send(Event::RepoCollaboratorsFetched { logins, rate_limit });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchRepoCollaborators {owner}/{repo} error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: format!("FetchRepoCollaborators {owner}/{repo}"),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+async fn handle_fetch_open_issue_refs(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchOpenIssueRefs") else {
+        return;
+    };
+    let cache = client.cache();
+    match graphql::fetch_open_issue_refs(&octocrab, &owner, &repo, Some(&cache)).await {
+        Ok((refs, rate_limit)) => {
+            tracing::debug!(
+                "engine: sending OpenIssueRefsFetched {owner}/{repo} count={}",
+                refs.len()
+            );
+            let _ = reply_tx.send(Event::OpenIssueRefsFetched { refs, rate_limit });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchOpenIssueRefs {owner}/{repo} error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: format!("FetchOpenIssueRefs {owner}/{repo}"),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+async fn handle_fetch_merge_queue(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    owner: String,
+    repo: String,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchMergeQueue") else {
+        return;
+    };
+    let features = client.features_for(host, &octocrab).await;
+    match graphql::fetch_merge_queue(&octocrab, features, &owner, &repo).await {
+        Ok((queue, rate_limit)) => {
+            let _ = reply_tx.send(Event::MergeQueueFetched {
+                owner,
+                repo,
+                queue,
+                rate_limit,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchMergeQueue {owner}/{repo} error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "FetchMergeQueue".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+async fn handle_check_health(client: &mut GitHubClient, hosts: Vec<String>, reply_tx: EventSender) {
+    // Hosts are checked side by side, so one unreachable host costs a single
+    // timeout.
+    let mut checks = tokio::task::JoinSet::new();
+    let mut results = Vec::with_capacity(hosts.len());
+    for (i, host) in hosts.into_iter().enumerate() {
+        match client.octocrab_for(&host) {
+            Ok(octocrab) => {
+                checks.spawn(async move { (i, health::check_host(&host, &octocrab).await) });
+            }
+            Err(e) => results.push((i, health::missing_token(&host, &e))),
+        }
+    }
+    while let Some(done) = checks.join_next().await {
+        match done {
+            Ok(result) => results.push(result),
+            Err(e) => tracing::warn!("engine: CheckHealth task failed: {e}"),
+        }
+    }
+    results.sort_by_key(|(i, _)| *i);
+    for (_, host) in &results {
+        tracing::info!(
+            "engine: {} health: {}",
+            host.host,
+            if host.problems.is_empty() {
+                "ok".to_owned()
+            } else {
+                format!("{:?}", host.problems)
+            }
+        );
+    }
+    let _ = reply_tx.send(Event::HealthChecked {
+        hosts: results.into_iter().map(|(_, host)| host).collect(),
+    });
+}
+
+async fn handle_fetch_viewer_setup(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchViewerSetup") else {
+        return;
+    };
+    match gh_viewer::fetch_viewer_setup(&octocrab).await {
+        Ok((setup, rate_limit)) => {
+            tracing::debug!(
+                "engine: sending ViewerSetupFetched login={} orgs={} repos={}",
+                setup.login,
+                setup.orgs.len(),
+                setup.repos.len()
+            );
+            let _ = reply_tx.send(Event::ViewerSetupFetched { setup, rate_limit });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchViewerSetup error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "FetchViewerSetup".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+async fn handle_fetch_token_scopes(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchTokenScopes") else {
+        return;
+    };
+    match gh_viewer::fetch_token_scopes(&octocrab).await {
+        Ok(scopes) => {
+            tracing::debug!("engine: sending TokenScopesFetched scopes={scopes:?}");
+            let _ = reply_tx.send(Event::TokenScopesFetched { scopes });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchTokenScopes error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "FetchTokenScopes".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
+}
+
+async fn handle_fetch_viewer_teams(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchViewerTeams") else {
+        return;
+    };
+    match gh_viewer::fetch_viewer_teams(&octocrab).await {
+        Ok(teams) => {
+            tracing::debug!("engine: sending ViewerTeamsFetched count={}", teams.len());
+            let _ = reply_tx.send(Event::ViewerTeamsFetched { teams });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchViewerTeams error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "FetchViewerTeams".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
 }
 
-async fn handle_cancel_workflow_run(
+async fn handle_fetch_saved_replies(
     client: &mut GitHubClient,
-    owner: String,
-    repo: String,
-    run_id: u64,
-    reply_tx: Sender<Event>,
+    host: Option<&str>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "CancelWorkflowRun") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchSavedReplies") else {
         return;
     };
-    let result = gh_actions::cancel_workflow_run(&octocrab, &owner, &repo, run_id).await;
-    send_mutation_result(
-        client,
-        &reply_tx,
-        result,
-        format!("Cancelled run #{run_id}"),
-        format!("Cancel workflow run #{run_id}"),
-        None,
-    )
-    .await;
+    match graphql::fetch_saved_replies(&octocrab).await {
+        Ok(replies) => {
+            tracing::debug!(
+                "engine: sending SavedRepliesFetched count={}",
+                replies.len()
+            );
+            let _ = reply_tx.send(Event::SavedRepliesFetched { replies });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchSavedReplies error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "FetchSavedReplies".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
 }
 
-async fn handle_mark_notification_read(
+async fn handle_fetch_contributions(
     client: &mut GitHubClient,
-    id: String,
-    reply_tx: Sender<Event>,
+    host: Option<&str>,
+    login: Option<String>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "MarkNotificationRead")
-    else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchContributions") else {
         return;
     };
-    let result = notif::mark_as_read(&octocrab, &id).await;
-    send_mutation_result(
-        client,
-        &reply_tx,
-        result,
-        format!("Marked notification {id} as read"),
-        format!("Mark notification {id} as read"),
-        None,
-    )
-    .await;
+    match graphql::fetch_contributions(&octocrab, login.as_deref()).await {
+        Ok(calendar) => {
+            tracing::debug!(
+                "engine: sending ContributionsFetched login={} total={}",
+                calendar.login,
+                calendar.total
+            );
+            let _ = reply_tx.send(Event::ContributionsFetched { login, calendar });
+        }
+        Err(e) => {
+            tracing::warn!("engine: FetchContributions error: {e}");
+            let _ = reply_tx.send(Event::FetchError {
+                context: "FetchContributions".to_owned(),
+                message: format_fetch_error(&e),
+            });
+        }
+    }
 }
 
-async fn handle_mark_all_notifications_read(client: &mut GitHubClient, reply_tx: Sender<Event>) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "MarkAllNotificationsRead")
-    else {
+async fn handle_fetch_workflow_schedules(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    repos: &[String],
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchWorkflowSchedules") else {
         return;
     };
-    let result = notif::mark_all_as_read(&octocrab).await;
-    send_mutation_result(
-        client,
-        &reply_tx,
-        result,
-        "Marked all notifications as read".to_owned(),
-        "Mark all notifications as read".to_owned(),
-        None,
-    )
-    .await;
+    let mut schedules = Vec::new();
+    let mut rate_limit = None;
+    for full_name in repos {
+        let Some((owner, repo)) = full_name.split_once('/') else {
+            continue;
+        };
+        match graphql::fetch_workflow_schedules(&octocrab, owner, repo).await {
+            Ok((found, rl)) => {
+                schedules.extend(found);
+                graphql::accumulate_rate_limit(&mut rate_limit, rl);
+            }
+            Err(e) => {
+                tracing::warn!("engine: FetchWorkflowSchedules error for {full_name}: {e}");
+                let _ = reply_tx.send(Event::FetchError {
+                    context: "FetchWorkflowSchedules".to_owned(),
+                    message: format_fetch_error(&e),
+                });
+                return;
+            }
+        }
+    }
+    tracing::debug!(
+        "engine: sending WorkflowSchedulesFetched count={}",
+        schedules.len()
+    );
+    let _ = reply_tx.send(Event::WorkflowSchedulesFetched {
+        schedules,
+        rate_limit,
+    });
 }
 
-async fn handle_unsubscribe_notification(
+async fn handle_fetch_dispatchable_workflows(
     client: &mut GitHubClient,
-    id: String,
-    reply_tx: Sender<Event>,
+    host: Option<&str>,
+    repos: &[String],
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "UnsubscribeNotification")
-    else {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchDispatchableWorkflows") else {
         return;
     };
-    let result = notif::unsubscribe(&octocrab, &id).await;
-    send_mutation_result(
-        client,
-        &reply_tx,
-        result,
-        format!("Unsubscribed from notification {id}"),
-        format!("Unsubscribe from notification {id}"),
-        None,
-    )
-    .await;
+    let mut workflows = Vec::new();
+    let mut rate_limit = None;
+    for full_name in repos {
+        let Some((owner, repo)) = full_name.split_once('/') else {
+            continue;
+        };
+        match graphql::fetch_dispatchable_workflows(&octocrab, owner, repo).await {
+            Ok((found, rl)) => {
+                workflows.extend(found);
+                graphql::accumulate_rate_limit(&mut rate_limit, rl);
+            }
+            Err(e) => {
+                tracing::warn!("engine: FetchDispatchableWorkflows error for {full_name}: {e}");
+                let _ = reply_tx.send(Event::FetchError {
+                    context: "FetchDispatchableWorkflows".to_owned(),
+                    message: format_fetch_error(&e),
+                });
+                return;
+            }
+        }
+    }
+    tracing::debug!(
+        "engine: sending DispatchableWorkflowsFetched count={}",
+        workflows.len()
+    );
+    let _ = reply_tx.send(Event::DispatchableWorkflowsFetched {
+        workflows,
+        rate_limit,
+    });
 }
 
-async fn handle_fetch_repo_labels(
+async fn handle_fetch_repo_refs(
     client: &mut GitHubClient,
-    owner: String,
-    repo: String,
-    reply_tx: Sender<Event>,
+    host: Option<&str>,
+    owner: &str,
+    repo: &str,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "FetchRepoLabels") else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchRepoRefs") else {
         return;
     };
-    let cache = client.cache();
-    match graphql::fetch_repo_labels(&octocrab, &owner, &repo, Some(&cache)).await {
-        Ok((labels, rate_limit)) => {
-            tracing::debug!(
-                "engine: sending RepoLabelsFetched {owner}/{repo} count={}",
-                labels.len()
-            );
-            let _ = reply_tx.send(Event::RepoLabelsFetched {
-                labels: labels.into_iter().map(|l| l.name).collect(),
-                rate_limit,
+    match gh_actions::fetch_repo_refs(&octocrab, owner, repo).await {
+        Ok((branches, tags)) => {
+            let _ = reply_tx.send(Event::RepoRefsFetched {
+                repo: format!("{owner}/{repo}"),
+                branches,
+                tags,
             });
         }
         Err(e) => {
-            tracing::warn!("engine: FetchRepoLabels {owner}/{repo} error: {e}");
+            tracing::warn!("engine: FetchRepoRefs error for {owner}/{repo}: {e}");
             let _ = reply_tx.send(Event::FetchError {
-                context: format!("FetchRepoLabels {owner}/{repo}"),
+                context: "FetchRepoRefs".to_owned(),
                 message: format_fetch_error(&e),
             });
         }
     }
 }
 
-async fn handle_fetch_repo_collaborators(
+async fn handle_fetch_releases(
     client: &mut GitHubClient,
-    owner: String,
-    repo: String,
-    reply_tx: Sender<Event>,
+    host: Option<&str>,
+    owner: &str,
+    repo: &str,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "FetchRepoCollaborators")
-    else {
+    let host = host.unwrap_or("github.com");
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchReleases") else {
         return;
     };
-    let cache = client.cache();
-    match graphql::fetch_repo_collaborators(&octocrab, &owner, &repo, Some(&cache)).await {
-        Ok((logins, rate_limit)) => {
-            tracing::debug!(
-                "engine: sending RepoCollaboratorsFetched {owner}/{repo} count={}",
-                logins.len()
-            );
-            let _ = reply_tx.send(Event::RepoCollaboratorsFetched { logins, rate_limit });
+    match releases::fetch_releases(&octocrab, owner, repo).await {
+        Ok((releases, tags)) => {
+            let _ = reply_tx.send(Event::ReleasesFetched {
+                repo: format!("{owner}/{repo}"),
+                releases,
+                tags,
+            });
         }
         Err(e) => {
-            tracing::warn!("engine: FetchRepoCollaborators {owner}/{repo} error: {e}");
+            tracing::warn!("engine: FetchReleases error for {owner}/{repo}: {e}");
             let _ = reply_tx.send(Event::FetchError {
-                context: format!("FetchRepoCollaborators {owner}/{repo}"),
+                context: "FetchReleases".to_owned(),
                 message: format_fetch_error(&e),
             });
         }
     }
 }
 
-async fn handle_refresh_pr(client: &mut GitHubClient, pr_ref: PrRef, reply_tx: Sender<Event>) {
+/// Fetch a PR and its detail past the cache, filling `behind_by` from the
+/// compare API when the detail query left it unset.
+async fn fetch_fresh_pr(
+    octocrab: &HostClient,
+    features: HostFeatures,
+    cache: &Cache<String, String>,
+    pr_ref: &PrRef,
+) -> anyhow::Result<(PullRequest, PrDetail, Option<RateLimitInfo>)> {
     let PrRef {
         owner,
         repo,
@@ -1361,28 +3232,39 @@ async fn handle_refresh_pr(client: &mut GitHubClient, pr_ref: PrRef, reply_tx: S
         head_repo_owner,
         head_ref,
     } = pr_ref;
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "RefreshPr") else {
+    cache
+        .remove(&format!("full_pr:{owner}/{repo}#{number}"))
+        .await;
+    cache.remove(&format!("pr:{owner}/{repo}#{number}")).await;
+    let (pr, mut detail, rate_limit) =
+        graphql::fetch_single_pr(octocrab, features, owner, repo, *number, Some(cache)).await?;
+    if detail.behind_by.is_none()
+        && let Some(head_owner) = head_repo_owner
+    {
+        match graphql::fetch_compare(octocrab, owner, repo, base_ref, head_owner, head_ref).await {
+            Ok(n) => detail.behind_by = n,
+            Err(e) => tracing::warn!("engine: compare API failed for #{number}: {e:#}"),
+        }
+    }
+    Ok((pr, detail, rate_limit))
+}
+
+async fn handle_refresh_pr(
+    client: &mut GitHubClient,
+    host: Option<&str>,
+    pr_ref: PrRef,
+    reply_tx: EventSender,
+) {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let number = pr_ref.number;
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "RefreshPr") else {
         return;
     };
+    let features = client.features_for(host, &octocrab).await;
     let cache = client.cache();
-    let full_key = format!("full_pr:{owner}/{repo}#{number}");
-    let detail_key = format!("pr:{owner}/{repo}#{number}");
-    cache.remove(&full_key).await;
-    cache.remove(&detail_key).await;
-    match graphql::fetch_single_pr(&octocrab, &owner, &repo, number, Some(&cache)).await {
-        Ok((pr, mut detail, rate_limit)) => {
-            if detail.behind_by.is_none()
-                && let Some(ref head_owner) = head_repo_owner
-            {
-                match graphql::fetch_compare(
-                    &octocrab, &owner, &repo, &base_ref, head_owner, &head_ref,
-                )
-                .await
-                {
-                    Ok(n) => detail.behind_by = n,
-                    Err(e) => tracing::warn!("engine: compare API failed for #{number}: {e:#}"),
-                }
-            }
+    match fetch_fresh_pr(&octocrab, features, &cache, &pr_ref).await {
+        Ok((pr, detail, rate_limit)) => {
             tracing::debug!("engine: sending PrRefreshed #{number}");
             let _ = reply_tx.send(Event::PrRefreshed {
                 number,
@@ -1403,12 +3285,15 @@ async fn handle_refresh_pr(client: &mut GitHubClient, pr_ref: PrRef, reply_tx: S
 
 async fn handle_refresh_issue(
     client: &mut GitHubClient,
+    host: Option<&str>,
     owner: String,
     repo: String,
     number: u64,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", &reply_tx, "RefreshIssue") else {
+    let host = host.unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, &reply_tx, "RefreshIssue") else {
         return;
     };
     let cache = client.cache();
@@ -1442,9 +3327,10 @@ async fn handle_fetch_run_by_id(
     repo: String,
     run_id: u64,
     host: Option<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 ) {
     let host = host.as_deref().unwrap_or("github.com");
+    let reply_tx = reply_tx.for_host(host);
     let Some(octocrab) = get_octocrab(client, host, &reply_tx, "FetchRunById") else {
         return;
     };
@@ -1473,7 +3359,7 @@ struct WatchRunParams {
     repo: String,
     run_id: u64,
     host: Option<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
 }
 
 async fn handle_watch_run(
@@ -1496,13 +3382,16 @@ async fn handle_watch_run(
     match gh_actions::fetch_run_by_id(&octocrab, &p.owner, &p.repo, p.run_id).await {
         Ok((run, rate_limit)) => {
             let completed = run.status == RunStatus::Completed;
-            let _ = p.reply_tx.send(Event::WatchedRunUpdated {
-                run_id: p.run_id,
-                run: run.clone(),
-                completed,
-                jobs: None,
-                rate_limit,
-            });
+            let _ = p
+                .reply_tx
+                .for_host(api_host)
+                .send(Event::WatchedRunUpdated {
+                    run_id: p.run_id,
+                    run: run.clone(),
+                    completed,
+                    jobs: None,
+                    rate_limit,
+                });
             if completed {
                 fire_watch_hook(complete_command, &run, &p.owner, &p.repo, &p.reply_tx);
                 watch_scheduler.complete(p.run_id);
@@ -1528,6 +3417,7 @@ async fn tick_refresh(
     scheduler: &mut RefreshScheduler,
     watch_scheduler: &mut WatchScheduler,
     complete_command: Option<&String>,
+    notification_rules: &[NotificationRule],
     refresh_interval: Duration,
 ) {
     for DueEntry {
@@ -1564,6 +3454,11 @@ async fn tick_refresh(
                 filter: f,
                 reply_tx: notify_tx,
             },
+            FilterConfig::Event(f) => Request::FetchOrgEvents {
+                filter_idx,
+                filter: f,
+                reply_tx: notify_tx,
+            },
         };
         handle_request(
             req,
@@ -1571,6 +3466,7 @@ async fn tick_refresh(
             scheduler,
             watch_scheduler,
             complete_command,
+            notification_rules,
             refresh_interval,
         )
         .await;
@@ -1632,6 +3528,7 @@ async fn tick_watches(
 
                 let send_ok = entry
                     .reply_tx
+                    .for_host(host)
                     .send(Event::WatchedRunUpdated {
                         run_id: entry.run_id,
                         run: run.clone(),
@@ -1669,12 +3566,82 @@ async fn tick_watches(
     }
 }
 
+async fn tick_pr_watches(client: &mut GitHubClient, watch_scheduler: &mut WatchScheduler) {
+    let cache = client.cache();
+    for entry in watch_scheduler.prs.due_entries() {
+        let PrRef {
+            ref owner,
+            ref repo,
+            number,
+            ..
+        } = entry.pr_ref;
+        let host = entry.host.as_deref().unwrap_or("github.com");
+        let octocrab = match client.octocrab_for(host) {
+            Ok(o) => o,
+            Err(e) => {
+                tracing::warn!(
+                    "engine: watch poll for {owner}/{repo}#{number} — octocrab_for({host}) failed: {e}"
+                );
+                continue;
+            }
+        };
+        let features = client.features_for(host, &octocrab).await;
+        match fetch_fresh_pr(&octocrab, features, &cache, &entry.pr_ref).await {
+            Ok((pr, detail, rate_limit)) => {
+                let snapshot = pr.watch_snapshot();
+                if let Some(before) = watch_scheduler.prs.last_snapshot(owner, repo, number)
+                    && before.ci != snapshot.ci
+                {
+                    super::hooks::ci_changed(&pr, before.ci.label(), snapshot.ci.label());
+                }
+                let changes = watch_scheduler.prs.record(owner, repo, number, snapshot);
+                // Decided before the PR moves into the event.
+                let merge = entry
+                    .merge_method
+                    .map(|method| (method, pr.state, detail.merge_wait(&pr, chrono::Utc::now())));
+                let send_ok = entry
+                    .reply_tx
+                    .for_host(host)
+                    .send(Event::PrRefreshed {
+                        number,
+                        pr: Box::new(pr),
+                        detail,
+                        rate_limit,
+                    })
+                    .is_ok();
+                if !send_ok {
+                    tracing::debug!(
+                        "engine: watch poll channel closed for {owner}/{repo}#{number}, evicting"
+                    );
+                    watch_scheduler.prs.evict(owner, repo, number);
+                    continue;
+                }
+                if !changes.is_empty() {
+                    let _ = entry.reply_tx.send(Event::WatchedPrChanged {
+                        owner: owner.clone(),
+                        repo: repo.clone(),
+                        number,
+                        changes,
+                    });
+                }
+                if let Some(merge) = merge {
+                    merge_when_green(client, watch_scheduler, &entry, &octocrab, merge).await;
+                }
+            }
+            Err(e) => {
+                tracing::warn!("engine: watch poll for {owner}/{repo}#{number} error: {e}");
+                watch_scheduler.prs.mark_polled(owner, repo, number);
+            }
+        }
+    }
+}
+
 fn fire_watch_hook(
     complete_command: Option<&String>,
     run: &WorkflowRun,
     owner: &str,
     repo: &str,
-    reply_tx: &Sender<Event>,
+    reply_tx: &EventSender,
 ) {
     let Some(cmd_template) = complete_command else {
         return;
@@ -1724,9 +3691,9 @@ fn fire_watch_hook(
 fn get_octocrab(
     client: &mut GitHubClient,
     host: &str,
-    reply_tx: &Sender<Event>,
+    reply_tx: &EventSender,
     context: &str,
-) -> Option<std::sync::Arc<octocrab::Octocrab>> {
+) -> Option<HostClient> {
     match client.octocrab_for(host) {
         Ok(o) => Some(o),
         Err(e) => {
@@ -1747,7 +3714,7 @@ fn get_octocrab(
 /// post-mutation per-item refresh).
 async fn send_mutation_result(
     client: &GitHubClient,
-    reply_tx: &Sender<Event>,
+    reply_tx: &EventSender,
     result: Result<(), anyhow::Error>,
     ok_desc: String,
     err_desc: String,
@@ -1773,26 +3740,77 @@ async fn send_mutation_result(
     }
 }
 
+/// Merge a PR to merge when green once nothing keeps it waiting (`wait` is
+/// `None`), and stop waiting for it once it is closed or merged by others.
+async fn merge_when_green(
+    client: &mut GitHubClient,
+    watch_scheduler: &mut WatchScheduler,
+    entry: &DuePrWatch,
+    octocrab: &HostClient,
+    (method, state, wait): (MergeMethod, PrState, Option<String>),
+) {
+    let PrRef {
+        ref owner,
+        ref repo,
+        number,
+        ..
+    } = entry.pr_ref;
+    let (merged, message) = match (state, wait) {
+        (PrState::Merged, _) => (true, format!("PR #{number} was merged")),
+        (PrState::Closed, _) => (
+            false,
+            format!("PR #{number} was closed: no longer merging it when green"),
+        ),
+        (PrState::Open, Some(wait)) => {
+            tracing::debug!("engine: {owner}/{repo}#{number} not green yet: {wait}");
+            return;
+        }
+        (PrState::Open, None) => {
+            match pr_actions::merge(octocrab, owner, repo, number, method).await {
+                Ok(()) => (true, format!("Merged PR #{number}: it is green")),
+                Err(e) => (
+                    false,
+                    format!("Merge when green of PR #{number} failed: {e:#}"),
+                ),
+            }
+        }
+    };
+    watch_scheduler.prs.disarm(owner, repo, number);
+    let _ = entry.reply_tx.send(Event::MergeWhenGreenEnded {
+        owner: owner.clone(),
+        repo: repo.clone(),
+        number,
+        merged,
+        message,
+    });
+    if merged && state == PrState::Open {
+        let host = entry.host.as_deref().unwrap_or("github.com");
+        post_mutation_refresh_pr(client, host, owner, repo, number, &entry.reply_tx).await;
+    }
+}
+
 /// After a successful PR mutation, fetch the updated PR data and send a
 /// `PrRefreshed` event so the view can update the row in place instead of
 /// doing a full table refresh.
 async fn post_mutation_refresh_pr(
     client: &mut GitHubClient,
+    host: &str,
     owner: &str,
     repo: &str,
     number: u64,
-    reply_tx: &Sender<Event>,
+    reply_tx: &EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", reply_tx, "PostMutationRefreshPr")
-    else {
+    let reply_tx = &reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, reply_tx, "PostMutationRefreshPr") else {
         return;
     };
+    let features = client.features_for(host, &octocrab).await;
     let cache = client.cache();
     let full_key = format!("full_pr:{owner}/{repo}#{number}");
     let detail_key = format!("pr:{owner}/{repo}#{number}");
     cache.remove(&full_key).await;
     cache.remove(&detail_key).await;
-    match graphql::fetch_single_pr(&octocrab, owner, repo, number, Some(&cache)).await {
+    match graphql::fetch_single_pr(&octocrab, features, owner, repo, number, Some(&cache)).await {
         Ok((pr, mut detail, rate_limit)) => {
             if detail.behind_by.is_none()
                 && let Some(ref head_owner) = pr.head_repo_owner
@@ -1813,6 +3831,10 @@ async fn post_mutation_refresh_pr(
                     }
                 }
             }
+            patch_cached_lists(&cache, "prs:", &pr, |p: &PullRequest| {
+                p.number == number && p.repo == pr.repo
+            })
+            .await;
             let _ = reply_tx.send(Event::PrRefreshed {
                 number,
                 pr: Box::new(pr),
@@ -1830,13 +3852,14 @@ async fn post_mutation_refresh_pr(
 /// `IssueRefreshed` event so the view can update the row in place.
 async fn post_mutation_refresh_issue(
     client: &mut GitHubClient,
+    host: &str,
     owner: &str,
     repo: &str,
     number: u64,
-    reply_tx: &Sender<Event>,
+    reply_tx: &EventSender,
 ) {
-    let Some(octocrab) = get_octocrab(client, "github.com", reply_tx, "PostMutationRefreshIssue")
-    else {
+    let reply_tx = &reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, reply_tx, "PostMutationRefreshIssue") else {
         return;
     };
     let cache = client.cache();
@@ -1846,6 +3869,10 @@ async fn post_mutation_refresh_issue(
     cache.remove(&detail_key).await;
     match graphql::fetch_single_issue(&octocrab, owner, repo, number, Some(&cache)).await {
         Ok((issue, detail, rate_limit)) => {
+            patch_cached_lists(&cache, "issues:", &issue, |i: &Issue| {
+                i.number == number && i.repo == issue.repo
+            })
+            .await;
             let _ = reply_tx.send(Event::IssueRefreshed {
                 number,
                 issue: Box::new(issue),
@@ -1858,3 +3885,64 @@ async fn post_mutation_refresh_issue(
         }
     }
 }
+
+/// After a successful re-run or cancel, re-read the run and send a
+/// `RunRefreshed` event so the view can update its row in place.
+async fn post_mutation_refresh_run(
+    client: &mut GitHubClient,
+    host: &str,
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+    reply_tx: &EventSender,
+) {
+    let reply_tx = &reply_tx.for_host(host);
+    let Some(octocrab) = get_octocrab(client, host, reply_tx, "PostMutationRefreshRun") else {
+        return;
+    };
+    match gh_actions::fetch_run_by_id(&octocrab, owner, repo, run_id).await {
+        Ok((run, rate_limit)) => {
+            let _ = reply_tx.send(Event::RunRefreshed {
+                run: Box::new(run),
+                rate_limit,
+            });
+        }
+        Err(e) => {
+            tracing::warn!("engine: post-mutation refresh for run {run_id}: {e}");
+        }
+    }
+}
+
+/// Replace the entries `same` matches in the cached search lists under
+/// `prefix` with `fresh`, so that a list fetch within the cache TTL does not
+/// bring back the item as it was before the mutation. Other entries, and
+/// lists without the item, are left alone.
+async fn patch_cached_lists<T: Serialize + DeserializeOwned + Clone>(
+    cache: &Cache<String, String>,
+    prefix: &str,
+    fresh: &T,
+    same: impl Fn(&T) -> bool,
+) {
+    let keys: Vec<Arc<String>> = cache
+        .iter()
+        .filter(|(key, _)| key.starts_with(prefix))
+        .map(|(key, _)| key)
+        .collect();
+    for key in keys {
+        let Some(json) = cache.get(key.as_str()).await else {
+            continue;
+        };
+        let Ok(mut items) = serde_json::from_str::<Vec<T>>(&json) else {
+            continue;
+        };
+        let mut patched = false;
+        for item in items.iter_mut().filter(|item| same(item)) {
+            item.clone_from(fresh);
+            patched = true;
+        }
+        if patched && let Ok(json) = serde_json::to_string(&items) {
+            tracing::debug!("engine: patched cached list {key}");
+            cache.insert((*key).clone(), json).await;
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! `[hooks]`: shell commands run on engine events.
+//!
+//! Each command runs with `sh -c` in the background, its output discarded,
+//! with the event in `GH_BOARD_EVENT` (`mutation`, `ci_change` or
+//! `fetch_error`) and its fields in other `GH_BOARD_*` variables.
+
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+
+use super::interface::Event;
+use crate::config::types::HooksConfig;
+use crate::types::PullRequest;
+
+static HOOKS: OnceLock<HooksConfig> = OnceLock::new();
+
+/// Set the hooks to run. Only the first call takes effect.
+pub(super) fn install(config: HooksConfig) {
+    let _ = HOOKS.set(config);
+}
+
+pub(super) type Vars = Vec<(&'static str, String)>;
+
+pub(super) fn item_vars(vars: &mut Vars, repo: String, number: u64) {
+    vars.push(("GH_BOARD_REPO", repo));
+    vars.push(("GH_BOARD_NUMBER", number.to_string()));
+}
+
+/// The command and variables `event` runs, if it has a hook.
+fn event_hook<'a>(
+    hooks: &'a HooksConfig,
+    event: &Event,
+    item: Option<(&str, u64)>,
+) -> Option<(&'a str, Vars)> {
+    let (command, mut vars) = match event {
+        Event::MutationOk { description } => (
+            hooks.on_mutation.as_deref()?,
+            vec![
+                ("GH_BOARD_EVENT", "mutation".to_owned()),
+                ("GH_BOARD_DESCRIPTION", description.clone()),
+            ],
+        ),
+        Event::FetchError { context, message } => (
+            hooks.on_fetch_error.as_deref()?,
+            vec![
+                ("GH_BOARD_EVENT", "fetch_error".to_owned()),
+                ("GH_BOARD_CONTEXT", context.clone()),
+                ("GH_BOARD_MESSAGE", message.clone()),
+            ],
+        ),
+        _ => return None,
+    };
+    if let (Event::MutationOk { .. }, Some((repo, number))) = (event, item) {
+        item_vars(&mut vars, repo.to_owned(), number);
+    }
+    Some((command, vars))
+}
+
+/// Run the hook of `event`, if any; `item` is the PR or issue of the
+/// mutation it answers.
+pub(super) fn observe(event: &Event, item: Option<(&str, u64)>) {
+    let Some(hooks) = HOOKS.get() else {
+        return;
+    };
+    if let Some((command, vars)) = event_hook(hooks, event, item) {
+        run(command, vars);
+    }
+}
+
+/// Run `on_ci_change` for the watched `pr`, whose CI went from `before` to
+/// `after` (`passing`, `failing`, `pending` or `no checks`).
+pub(super) fn ci_changed(pr: &PullRequest, before: &str, after: &str) {
+    let Some(command) = HOOKS.get().and_then(|h| h.on_ci_change.as_deref()) else {
+        return;
+    };
+    let mut vars = vec![("GH_BOARD_EVENT", "ci_change".to_owned())];
+    if let Some(repo) = &pr.repo {
+        item_vars(&mut vars, repo.full_name(), pr.number);
+    }
+    vars.extend([
+        ("GH_BOARD_TITLE", pr.title.clone()),
+        ("GH_BOARD_URL", pr.url.clone()),
+        ("GH_BOARD_BRANCH", pr.head_ref.clone()),
+        ("GH_BOARD_CI_BEFORE", before.to_owned()),
+        ("GH_BOARD_CI", after.to_owned()),
+    ]);
+    run(command, vars);
+}
+
+/// Run `command` with `sh -c` in the background, `vars` in its environment.
+pub(super) fn run(command: &str, vars: Vars) {
+    let command = command.to_owned();
+    std::thread::spawn(move || {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(&command)
+            .envs(vars)
+            .stdin(Stdio::null())
+            .output();
+        match output {
+            Ok(output) if !output.status.success() => tracing::warn!(
+                "hooks: `{command}` failed ({}): {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("hooks: could not run `{command}`: {e}"),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutation_hook_gets_the_item_of_the_request() {
+        let hooks = HooksConfig {
+            on_mutation: Some("notify".to_owned()),
+            ..Default::default()
+        };
+        let event = Event::MutationOk {
+            description: "Merged PR #7".to_owned(),
+        };
+        let (command, vars) = event_hook(&hooks, &event, Some(("o/r", 7))).unwrap();
+        assert_eq!(command, "notify");
+        assert_eq!(
+            vars,
+            [
+                ("GH_BOARD_EVENT", "mutation".to_owned()),
+                ("GH_BOARD_DESCRIPTION", "Merged PR #7".to_owned()),
+                ("GH_BOARD_REPO", "o/r".to_owned()),
+                ("GH_BOARD_NUMBER", "7".to_owned()),
+            ]
+        );
+        let error = Event::FetchError {
+            context: "FetchPrs".to_owned(),
+            message: "timeout".to_owned(),
+        };
+        assert!(event_hook(&hooks, &error, None).is_none());
+    }
+}
@@ -1,13 +1,79 @@
-use std::sync::mpsc::Sender;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
 
 use crate::config::types::{
-    ActionsFilter, AlertsFilter, IssueFilter, NotificationFilter, PrFilter,
+    ActionsFilter, AlertsFilter, EventsFilter, IssueFilter, MergeMethod, NotificationFilter,
+    PrFilter,
 };
 use crate::types::{
-    Issue, IssueDetail, Notification, PrDetail, PullRequest, RateLimitInfo, SecretLocation,
-    SecurityAlert, WorkflowJob, WorkflowRun,
+    ContributionCalendar, DispatchableWorkflow, GitTag, HostHealth, Issue, IssueDetail, MergeQueue,
+    Notification, OrgEvent, OrgEventSource, PrDetail, PrDetailConnection, PrDetailPage,
+    PullRequest, RateLimitInfo, Release, ReviewEvent, ReviewLineComment, RunCleanupCandidate,
+    RunComparison, RunStatus, SavedReply, SecretLocation, SecurityAlert, ViewerSetup, WorkflowJob,
+    WorkflowRun, WorkflowSchedule,
 };
 
+/// Sending half of a view's event channel, cloned into each [`Request`].
+///
+/// The channel is unbounded, so [`send`](Self::send) never blocks and can be
+/// called from the engine thread or any worker thread. The receiving view
+/// awaits the matching [`async_channel::Receiver`] and only wakes when an
+/// event arrives.
+#[derive(Clone)]
+pub struct EventSender {
+    tx: async_channel::Sender<Event>,
+    /// Stamped on the rate limits of the events sent; `None` for github.com.
+    host: Option<Arc<str>>,
+    /// The PR or issue, as `owner/repo` and number, of the mutation whose
+    /// events this carries (see [`Request::tag_item`]).
+    item: Option<(Arc<str>, u64)>,
+}
+
+impl EventSender {
+    /// Queue `event` for the view, running its `[hooks]` command if it has
+    /// one. Fails only once the view has been dropped.
+    pub fn send(&self, mut event: Event) -> Result<(), Disconnected> {
+        if let Some(rate_limit) = event.rate_limit_mut() {
+            rate_limit.host = self.host.as_deref().map(str::to_owned);
+        }
+        super::hooks::observe(&event, self.item.as_ref().map(|(repo, n)| (&**repo, *n)));
+        self.tx.try_send(event).map_err(|_| Disconnected)
+    }
+
+    /// The same channel, for replies about `host`: their rate limits count
+    /// against its quota.
+    pub(crate) fn for_host(&self, host: &str) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            host: crate::url::normalize_host(host).map(Arc::from),
+            item: self.item.clone(),
+        }
+    }
+
+    /// Whether both senders feed the same view.
+    pub(crate) fn same_channel(&self, other: &Self) -> bool {
+        self.tx.same_channel(&other.tx)
+    }
+}
+
+/// The view owning an [`EventSender`]'s channel has been dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Disconnected;
+
+/// Create an unbounded event channel for a view.
+pub fn event_channel() -> (EventSender, async_channel::Receiver<Event>) {
+    let (tx, rx) = async_channel::unbounded();
+    (
+        EventSender {
+            tx,
+            host: None,
+            item: None,
+        },
+        rx,
+    )
+}
+
 /// Handle to the backend engine held by the UI layer.
 ///
 /// Cheaply cloneable. When the last handle is dropped the sender channel
@@ -35,6 +101,7 @@ pub trait Engine: Send + 'static {
 }
 
 /// All fields needed by the engine to fetch a single PR detail including the compare call.
+#[derive(Clone)]
 pub struct PrRef {
     pub owner: String,
     pub repo: String,
@@ -54,64 +121,184 @@ pub enum Request {
         filter: PrFilter,
         /// Skip the moka cache and fetch fresh data from the GitHub API.
         force: bool,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
     },
     FetchIssues {
         filter_idx: usize,
         filter: IssueFilter,
         /// Skip the moka cache and fetch fresh data from the GitHub API.
         force: bool,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
+    },
+    /// One-off PR search for a script. Replies `PrsFetched` like a tab
+    /// fetch, but no tab owns it: nothing is scheduled, cached to disk or
+    /// rung.
+    SearchPrs {
+        query: String,
+        limit: u32,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// One-off issue search for a script; see [`Request::SearchPrs`].
+    SearchIssues {
+        query: String,
+        limit: u32,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     FetchActions {
         filter_idx: usize,
         filter: ActionsFilter,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
     },
     FetchAlerts {
         filter_idx: usize,
         filter: AlertsFilter,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
+    },
+    /// Recent member, repository and settings changes of an organization.
+    FetchOrgEvents {
+        filter_idx: usize,
+        filter: EventsFilter,
+        reply_tx: EventSender,
     },
     FetchSecretLocations {
         owner: String,
         repo: String,
         alert_number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     FetchRunJobs {
         owner: String,
         repo: String,
         run_id: u64,
         host: Option<String>,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
+    },
+    /// Download the log of one Actions job.
+    FetchJobLogs {
+        owner: String,
+        repo: String,
+        job_id: u64,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     FetchNotifications {
         filter_idx: usize,
         filter: NotificationFilter,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
     },
     FetchPrDetail {
         pr_ref: PrRef,
         /// Skip the moka cache and fetch fresh data from the GitHub API.
         force: bool,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Load the next page of a PR's commits or files beyond the first one
+    /// returned with its detail.
+    FetchPrDetailPage {
+        owner: String,
+        repo: String,
+        number: u64,
+        connection: PrDetailConnection,
+        /// Cursor from the detail's [`PageCursor`](crate::types::PageCursor).
+        after: String,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     FetchIssueDetail {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     FetchRepoLabels {
         owner: String,
         repo: String,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     FetchRepoCollaborators {
         owner: String,
         repo: String,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Fetch open issue and PR numbers with titles (for `#` autocomplete).
+    FetchOpenIssueRefs {
+        owner: String,
+        repo: String,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Fetch the merge queue of a repository's default branch.
+    FetchMergeQueue {
+        owner: String,
+        repo: String,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Check that each of `hosts` can be used at all, before the views load.
+    CheckHealth {
+        hosts: Vec<String>,
+        reply_tx: EventSender,
+    },
+    /// Fetch the authenticated user's login, token scopes, orgs and repos.
+    FetchViewerSetup {
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Probe the token's OAuth scopes so views can disable what it cannot do.
+    FetchTokenScopes {
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Fetch the teams the viewer belongs to (for team review requests).
+    FetchViewerTeams {
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Fetch the authenticated user's GitHub saved replies.
+    FetchSavedReplies {
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Fetch the contribution calendar of `login`, or of the viewer.
+    FetchContributions {
+        host: Option<String>,
+        login: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Read the `schedule` triggers of the workflow files of `repos`
+    /// (`owner/repo`).
+    FetchWorkflowSchedules {
+        host: Option<String>,
+        repos: Vec<String>,
+        reply_tx: EventSender,
+    },
+    /// List the workflows of `repos` (`owner/repo`) with a
+    /// `workflow_dispatch` trigger, and their inputs.
+    FetchDispatchableWorkflows {
+        host: Option<String>,
+        repos: Vec<String>,
+        reply_tx: EventSender,
+    },
+    /// List the branches and tags of `owner/repo`.
+    FetchRepoRefs {
+        host: Option<String>,
+        owner: String,
+        repo: String,
+        reply_tx: EventSender,
+    },
+    /// List the latest releases and tags of a repo.
+    FetchReleases {
+        owner: String,
+        repo: String,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     /// Refresh a single PR (table row + sidebar detail in one combined query).
     RefreshPr {
@@ -121,20 +308,23 @@ pub enum Request {
         base_ref: String,
         head_repo_owner: Option<String>,
         head_ref: String,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     /// Refresh a single Issue (table row + sidebar detail in one combined query).
     RefreshIssue {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
 
     /// Prefetch PR details for a list of PRs (includes branch refs for the compare call).
     PrefetchPrDetails {
         prs: Vec<PrRef>,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
 
     // -----------------------------------------------------------------------
@@ -142,7 +332,13 @@ pub enum Request {
     // -----------------------------------------------------------------------
     RegisterRefresh {
         configs: Vec<crate::engine::refresh::FilterConfig>,
-        notify_tx: Sender<Event>,
+        notify_tx: EventSender,
+    },
+    /// Hold off the background refresh of one registered filter.
+    PostponeRefresh {
+        view: crate::engine::refresh::ViewKind,
+        filter_idx: usize,
+        until: std::time::SystemTime,
     },
 
     // -----------------------------------------------------------------------
@@ -153,44 +349,81 @@ pub enum Request {
         repo: String,
         number: u64,
         body: Option<String>,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Submit a review with a verdict, a body and line comments.
+    SubmitPrReview {
+        owner: String,
+        repo: String,
+        number: u64,
+        event: ReviewEvent,
+        body: String,
+        comments: Vec<ReviewLineComment>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     MergePr {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        method: MergeMethod,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     ClosePr {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     ReopenPr {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     AddPrComment {
         owner: String,
         repo: String,
         number: u64,
         body: String,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     UpdateBranch {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     ReadyForReview {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Mark files of a PR viewed (or unviewed) on GitHub.
+    SetFilesViewed {
+        owner: String,
+        repo: String,
+        number: u64,
+        paths: Vec<String>,
+        viewed: bool,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Add a PR to its base branch's merge queue.
+    EnqueuePr {
+        owner: String,
+        repo: String,
+        number: u64,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     /// Replace the full assignee set on a PR. An empty `logins` vec unassigns everyone.
     SetPrAssignees {
@@ -198,7 +431,8 @@ pub enum Request {
         repo: String,
         number: u64,
         logins: Vec<String>,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     /// Replace the full label set on a PR. An empty `labels` vec clears all labels.
     SetPrLabels {
@@ -206,7 +440,8 @@ pub enum Request {
         repo: String,
         number: u64,
         labels: Vec<String>,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
 
     // -----------------------------------------------------------------------
@@ -216,20 +451,23 @@ pub enum Request {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     ReopenIssue {
         owner: String,
         repo: String,
         number: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     AddIssueComment {
         owner: String,
         repo: String,
         number: u64,
         body: String,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     /// Replace the full label set on an issue. An empty `labels` vec clears all labels.
     SetIssueLabels {
@@ -237,7 +475,8 @@ pub enum Request {
         repo: String,
         number: u64,
         labels: Vec<String>,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     /// Replace the full assignee set on an issue. An empty `logins` vec unassigns everyone.
     SetIssueAssignees {
@@ -245,7 +484,18 @@ pub enum Request {
         repo: String,
         number: u64,
         logins: Vec<String>,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Move an issue (or PR) to the milestone titled `milestone`; `None`
+    /// clears it.
+    SetIssueMilestone {
+        owner: String,
+        repo: String,
+        number: u64,
+        milestone: Option<String>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
 
     // -----------------------------------------------------------------------
@@ -257,13 +507,114 @@ pub enum Request {
         run_id: u64,
         /// true = rerun-failed-jobs, false = rerun all
         failed_only: bool,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     CancelWorkflowRun {
         owner: String,
         repo: String,
         run_id: u64,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Re-run single Actions jobs, leaving the rest of their runs alone.
+    RerunJobs {
+        host: Option<String>,
+        owner: String,
+        repo: String,
+        job_ids: Vec<u64>,
+        reply_tx: EventSender,
+    },
+    /// Approve the workflow runs GitHub holds on PR `number`, from a
+    /// first-time contributor's fork.
+    ApproveWorkflowRuns {
+        host: Option<String>,
+        owner: String,
+        repo: String,
+        number: u64,
+        run_ids: Vec<u64>,
+        reply_tx: EventSender,
+    },
+    /// Trigger a `workflow_dispatch` run; `git_ref: None` is the default
+    /// branch.
+    DispatchWorkflow {
+        host: Option<String>,
+        owner: String,
+        repo: String,
+        workflow: String,
+        git_ref: Option<String>,
+        inputs: IndexMap<String, String>,
+        reply_tx: EventSender,
+    },
+    /// Dry-run of the retention cleanup; answered with `RunCleanupPlanned`.
+    PlanRunCleanup {
+        owner: String,
+        repo: String,
+        older_than_days: u32,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Delete one chunk of a cleanup's runs. `progress` is the runs the
+    /// cleanup's earlier chunks deleted, and the runs it deletes in all.
+    DeleteWorkflowRuns {
+        owner: String,
+        repo: String,
+        run_ids: Vec<u64>,
+        progress: (usize, usize),
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Compare a run with the last successful run of its workflow and branch.
+    CompareWithLastSuccess {
+        owner: String,
+        repo: String,
+        run: Box<WorkflowRun>,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+
+    // -----------------------------------------------------------------------
+    // Mutation operations — Releases
+    // -----------------------------------------------------------------------
+    /// Answered with `MutationOk`, whose description carries the release URL.
+    CreateDraftRelease {
+        owner: String,
+        repo: String,
+        tag: String,
+        name: String,
+        body: String,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Publish the draft release `id` (tagged `tag`).
+    PublishRelease {
+        owner: String,
+        repo: String,
+        id: u64,
+        tag: String,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Delete the release `id` (tagged `tag`), keeping the tag.
+    DeleteRelease {
+        owner: String,
+        repo: String,
+        id: u64,
+        tag: String,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+
+    // -----------------------------------------------------------------------
+    // Sharing
+    // -----------------------------------------------------------------------
+    /// POST `payload` to the webhook of the share target `target`; answered
+    /// with `MutationOk` / `MutationError`.
+    ShareItem {
+        target: String,
+        webhook: String,
+        payload: serde_json::Value,
+        reply_tx: EventSender,
     },
 
     // -----------------------------------------------------------------------
@@ -271,14 +622,23 @@ pub enum Request {
     // -----------------------------------------------------------------------
     MarkNotificationRead {
         id: String,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     MarkAllNotificationsRead {
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    /// Mark a thread done, taking it out of the inbox until new activity.
+    MarkNotificationDone {
+        id: String,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
     UnsubscribeNotification {
         id: String,
-        reply_tx: Sender<Event>,
+        host: Option<String>,
+        reply_tx: EventSender,
     },
 
     // -----------------------------------------------------------------------
@@ -289,7 +649,7 @@ pub enum Request {
         repo: String,
         run_id: u64,
         host: Option<String>,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
     },
 
     // -----------------------------------------------------------------------
@@ -300,42 +660,386 @@ pub enum Request {
         repo: String,
         run_id: u64,
         host: Option<String>,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
     },
     UnwatchRun {
         run_id: u64,
     },
 
+    // -----------------------------------------------------------------------
+    // Watch pull request
+    // -----------------------------------------------------------------------
+    /// Poll a PR on the watch interval and report CI, review and
+    /// mergeability changes with `WatchedPrChanged`.
+    WatchPr {
+        owner: String,
+        repo: String,
+        number: u64,
+        base_ref: String,
+        head_repo_owner: Option<String>,
+        head_ref: String,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    UnwatchPr {
+        owner: String,
+        repo: String,
+        number: u64,
+    },
+    /// Poll a PR on the watch interval and merge it with `method` once
+    /// nothing blocks it and every check passed; `MergeWhenGreenEnded`
+    /// reports how it ended.
+    MergeWhenGreen {
+        owner: String,
+        repo: String,
+        number: u64,
+        base_ref: String,
+        head_repo_owner: Option<String>,
+        head_ref: String,
+        method: MergeMethod,
+        host: Option<String>,
+        reply_tx: EventSender,
+    },
+    CancelMergeWhenGreen {
+        owner: String,
+        repo: String,
+        number: u64,
+    },
+
     // -----------------------------------------------------------------------
     // Control
     // -----------------------------------------------------------------------
+    /// Drop the queued and running list fetches replying to `reply_tx`,
+    /// answering each with `FetchCancelled`. Views send it when they stop
+    /// being the active view.
+    CancelFetches {
+        reply_tx: EventSender,
+    },
     Shutdown,
 }
 
+/// Which tab of which view a list fetch loads.
+pub(crate) struct FetchKey {
+    kind: &'static str,
+    pub filter_idx: usize,
+    /// Filter title, telling apart fetches a view sends for one slot.
+    title: String,
+    reply_tx: EventSender,
+}
+
+impl FetchKey {
+    /// Whether both fetches load the same tab, so only the newer one matters.
+    pub(crate) fn same_tab(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.filter_idx == other.filter_idx
+            && self.title == other.title
+            && self.reply_tx.same_channel(&other.reply_tx)
+    }
+
+    pub(crate) fn replies_to(&self, reply_tx: &EventSender) -> bool {
+        self.reply_tx.same_channel(reply_tx)
+    }
+
+    pub(crate) fn reply(&self, event: Event) {
+        let _ = self.reply_tx.send(event);
+    }
+}
+
 impl Request {
+    /// The tab a list fetch loads, `None` for any other request.
+    pub(crate) fn fetch_key(&self) -> Option<FetchKey> {
+        let (filter_idx, title, reply_tx) = match self {
+            Self::FetchPrs {
+                filter_idx,
+                filter: PrFilter { title, .. },
+                reply_tx,
+                ..
+            }
+            | Self::FetchIssues {
+                filter_idx,
+                filter: IssueFilter { title, .. },
+                reply_tx,
+                ..
+            }
+            | Self::FetchActions {
+                filter_idx,
+                filter: ActionsFilter { title, .. },
+                reply_tx,
+                ..
+            }
+            | Self::FetchAlerts {
+                filter_idx,
+                filter: AlertsFilter { title, .. },
+                reply_tx,
+                ..
+            }
+            | Self::FetchOrgEvents {
+                filter_idx,
+                filter: EventsFilter { title, .. },
+                reply_tx,
+                ..
+            }
+            | Self::FetchNotifications {
+                filter_idx,
+                filter: NotificationFilter { title, .. },
+                reply_tx,
+                ..
+            } => (*filter_idx, title, reply_tx),
+            _ => return None,
+        };
+        Some(FetchKey {
+            kind: self.label(),
+            filter_idx,
+            title: title.clone(),
+            reply_tx: reply_tx.clone(),
+        })
+    }
+
+    /// A copy of a list fetch, to send again once it timed out; `None` for
+    /// any other request.
+    pub(crate) fn retry(&self) -> Option<Self> {
+        Some(match self {
+            Self::FetchPrs {
+                filter_idx,
+                filter,
+                force,
+                reply_tx,
+            } => Self::FetchPrs {
+                filter_idx: *filter_idx,
+                filter: filter.clone(),
+                force: *force,
+                reply_tx: reply_tx.clone(),
+            },
+            Self::FetchIssues {
+                filter_idx,
+                filter,
+                force,
+                reply_tx,
+            } => Self::FetchIssues {
+                filter_idx: *filter_idx,
+                filter: filter.clone(),
+                force: *force,
+                reply_tx: reply_tx.clone(),
+            },
+            Self::FetchActions {
+                filter_idx,
+                filter,
+                reply_tx,
+            } => Self::FetchActions {
+                filter_idx: *filter_idx,
+                filter: filter.clone(),
+                reply_tx: reply_tx.clone(),
+            },
+            Self::FetchAlerts {
+                filter_idx,
+                filter,
+                reply_tx,
+            } => Self::FetchAlerts {
+                filter_idx: *filter_idx,
+                filter: filter.clone(),
+                reply_tx: reply_tx.clone(),
+            },
+            Self::FetchOrgEvents {
+                filter_idx,
+                filter,
+                reply_tx,
+            } => Self::FetchOrgEvents {
+                filter_idx: *filter_idx,
+                filter: filter.clone(),
+                reply_tx: reply_tx.clone(),
+            },
+            Self::FetchNotifications {
+                filter_idx,
+                filter,
+                reply_tx,
+            } => Self::FetchNotifications {
+                filter_idx: *filter_idx,
+                filter: filter.clone(),
+                reply_tx: reply_tx.clone(),
+            },
+            _ => return None,
+        })
+    }
+
+    /// Tag the reply channel of a mutation with the PR or issue it acts on,
+    /// for the `on_mutation` hook its success runs.
+    #[expect(clippy::too_many_lines)]
+    pub(crate) fn tag_item(&mut self) {
+        let (owner, repo, number, reply_tx) = match self {
+            Self::ApprovePr {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::SubmitPrReview {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::MergePr {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::ClosePr {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::ReopenPr {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::AddPrComment {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::UpdateBranch {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::ReadyForReview {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::SetFilesViewed {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::EnqueuePr {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::SetPrAssignees {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::SetPrLabels {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::CloseIssue {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::ReopenIssue {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::AddIssueComment {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::SetIssueLabels {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::SetIssueAssignees {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            }
+            | Self::SetIssueMilestone {
+                owner,
+                repo,
+                number,
+                reply_tx,
+                ..
+            } => (owner, repo, *number, reply_tx),
+            _ => return,
+        };
+        reply_tx.item = Some((Arc::from(format!("{owner}/{repo}")), number));
+    }
+
     /// Clone the reply channel (if any) so we can send a timeout error after
     /// the request future is cancelled.
-    pub(crate) fn reply_tx(&self) -> Option<Sender<Event>> {
+    pub(crate) fn reply_tx(&self) -> Option<EventSender> {
         match self {
             Self::FetchPrs { reply_tx, .. }
             | Self::FetchIssues { reply_tx, .. }
+            | Self::SearchPrs { reply_tx, .. }
+            | Self::SearchIssues { reply_tx, .. }
             | Self::FetchActions { reply_tx, .. }
             | Self::FetchAlerts { reply_tx, .. }
+            | Self::FetchOrgEvents { reply_tx, .. }
             | Self::FetchSecretLocations { reply_tx, .. }
             | Self::FetchRunJobs { reply_tx, .. }
+            | Self::FetchJobLogs { reply_tx, .. }
             | Self::FetchNotifications { reply_tx, .. }
             | Self::FetchPrDetail { reply_tx, .. }
+            | Self::FetchPrDetailPage { reply_tx, .. }
             | Self::FetchIssueDetail { reply_tx, .. }
             | Self::FetchRepoLabels { reply_tx, .. }
             | Self::FetchRepoCollaborators { reply_tx, .. }
+            | Self::FetchOpenIssueRefs { reply_tx, .. }
+            | Self::FetchMergeQueue { reply_tx, .. }
+            | Self::CheckHealth { reply_tx, .. }
+            | Self::FetchViewerSetup { reply_tx, .. }
+            | Self::FetchTokenScopes { reply_tx, .. }
+            | Self::FetchViewerTeams { reply_tx, .. }
+            | Self::FetchSavedReplies { reply_tx, .. }
+            | Self::FetchContributions { reply_tx, .. }
+            | Self::FetchWorkflowSchedules { reply_tx, .. }
+            | Self::FetchDispatchableWorkflows { reply_tx, .. }
+            | Self::FetchRepoRefs { reply_tx, .. }
+            | Self::FetchReleases { reply_tx, .. }
             | Self::PrefetchPrDetails { reply_tx, .. }
             | Self::ApprovePr { reply_tx, .. }
+            | Self::SubmitPrReview { reply_tx, .. }
             | Self::MergePr { reply_tx, .. }
             | Self::ClosePr { reply_tx, .. }
             | Self::ReopenPr { reply_tx, .. }
             | Self::AddPrComment { reply_tx, .. }
             | Self::UpdateBranch { reply_tx, .. }
             | Self::ReadyForReview { reply_tx, .. }
+            | Self::SetFilesViewed { reply_tx, .. }
+            | Self::EnqueuePr { reply_tx, .. }
             | Self::SetPrAssignees { reply_tx, .. }
             | Self::SetPrLabels { reply_tx, .. }
             | Self::CloseIssue { reply_tx, .. }
@@ -343,16 +1047,36 @@ impl Request {
             | Self::AddIssueComment { reply_tx, .. }
             | Self::SetIssueLabels { reply_tx, .. }
             | Self::SetIssueAssignees { reply_tx, .. }
+            | Self::SetIssueMilestone { reply_tx, .. }
             | Self::RerunWorkflowRun { reply_tx, .. }
             | Self::CancelWorkflowRun { reply_tx, .. }
+            | Self::CreateDraftRelease { reply_tx, .. }
+            | Self::PublishRelease { reply_tx, .. }
+            | Self::DeleteRelease { reply_tx, .. }
+            | Self::ShareItem { reply_tx, .. }
+            | Self::RerunJobs { reply_tx, .. }
+            | Self::ApproveWorkflowRuns { reply_tx, .. }
+            | Self::DispatchWorkflow { reply_tx, .. }
+            | Self::PlanRunCleanup { reply_tx, .. }
+            | Self::DeleteWorkflowRuns { reply_tx, .. }
+            | Self::CompareWithLastSuccess { reply_tx, .. }
             | Self::MarkNotificationRead { reply_tx, .. }
             | Self::MarkAllNotificationsRead { reply_tx, .. }
+            | Self::MarkNotificationDone { reply_tx, .. }
             | Self::UnsubscribeNotification { reply_tx, .. }
             | Self::FetchRunById { reply_tx, .. }
             | Self::RefreshPr { reply_tx, .. }
             | Self::RefreshIssue { reply_tx, .. }
-            | Self::WatchRun { reply_tx, .. } => Some(reply_tx.clone()),
-            Self::RegisterRefresh { .. } | Self::UnwatchRun { .. } | Self::Shutdown => None,
+            | Self::WatchRun { reply_tx, .. }
+            | Self::WatchPr { reply_tx, .. }
+            | Self::MergeWhenGreen { reply_tx, .. }
+            | Self::CancelFetches { reply_tx } => Some(reply_tx.clone()),
+            Self::RegisterRefresh { .. }
+            | Self::PostponeRefresh { .. }
+            | Self::UnwatchRun { .. }
+            | Self::UnwatchPr { .. }
+            | Self::CancelMergeWhenGreen { .. }
+            | Self::Shutdown => None,
         }
     }
 
@@ -361,23 +1085,43 @@ impl Request {
         match self {
             Self::FetchPrs { .. } => "FetchPrs",
             Self::FetchIssues { .. } => "FetchIssues",
+            Self::SearchPrs { .. } => "SearchPrs",
+            Self::SearchIssues { .. } => "SearchIssues",
             Self::FetchActions { .. } => "FetchActions",
             Self::FetchAlerts { .. } => "FetchAlerts",
+            Self::FetchOrgEvents { .. } => "FetchOrgEvents",
             Self::FetchSecretLocations { .. } => "FetchSecretLocations",
             Self::FetchRunJobs { .. } => "FetchRunJobs",
+            Self::FetchJobLogs { .. } => "FetchJobLogs",
             Self::FetchNotifications { .. } => "FetchNotifications",
             Self::FetchPrDetail { .. } => "FetchPrDetail",
+            Self::FetchPrDetailPage { .. } => "FetchPrDetailPage",
             Self::FetchIssueDetail { .. } => "FetchIssueDetail",
             Self::FetchRepoLabels { .. } => "FetchRepoLabels",
             Self::FetchRepoCollaborators { .. } => "FetchRepoCollaborators",
+            Self::FetchOpenIssueRefs { .. } => "FetchOpenIssueRefs",
+            Self::FetchMergeQueue { .. } => "FetchMergeQueue",
+            Self::CheckHealth { .. } => "CheckHealth",
+            Self::FetchViewerSetup { .. } => "FetchViewerSetup",
+            Self::FetchTokenScopes { .. } => "FetchTokenScopes",
+            Self::FetchViewerTeams { .. } => "FetchViewerTeams",
+            Self::FetchSavedReplies { .. } => "FetchSavedReplies",
+            Self::FetchContributions { .. } => "FetchContributions",
+            Self::FetchWorkflowSchedules { .. } => "FetchWorkflowSchedules",
+            Self::FetchDispatchableWorkflows { .. } => "FetchDispatchableWorkflows",
+            Self::FetchRepoRefs { .. } => "FetchRepoRefs",
+            Self::FetchReleases { .. } => "FetchReleases",
             Self::PrefetchPrDetails { .. } => "PrefetchPrDetails",
             Self::ApprovePr { .. } => "ApprovePr",
+            Self::SubmitPrReview { .. } => "SubmitPrReview",
             Self::MergePr { .. } => "MergePr",
             Self::ClosePr { .. } => "ClosePr",
             Self::ReopenPr { .. } => "ReopenPr",
             Self::AddPrComment { .. } => "AddPrComment",
             Self::UpdateBranch { .. } => "UpdateBranch",
             Self::ReadyForReview { .. } => "ReadyForReview",
+            Self::SetFilesViewed { .. } => "SetFilesViewed",
+            Self::EnqueuePr { .. } => "EnqueuePr",
             Self::SetPrAssignees { .. } => "SetPrAssignees",
             Self::SetPrLabels { .. } => "SetPrLabels",
             Self::CloseIssue { .. } => "CloseIssue",
@@ -385,17 +1129,35 @@ impl Request {
             Self::AddIssueComment { .. } => "AddIssueComment",
             Self::SetIssueLabels { .. } => "SetIssueLabels",
             Self::SetIssueAssignees { .. } => "SetIssueAssignees",
+            Self::SetIssueMilestone { .. } => "SetIssueMilestone",
             Self::RerunWorkflowRun { .. } => "RerunWorkflowRun",
             Self::CancelWorkflowRun { .. } => "CancelWorkflowRun",
+            Self::CreateDraftRelease { .. } => "CreateDraftRelease",
+            Self::PublishRelease { .. } => "PublishRelease",
+            Self::DeleteRelease { .. } => "DeleteRelease",
+            Self::ShareItem { .. } => "ShareItem",
+            Self::RerunJobs { .. } => "RerunJobs",
+            Self::ApproveWorkflowRuns { .. } => "ApproveWorkflowRuns",
+            Self::DispatchWorkflow { .. } => "DispatchWorkflow",
+            Self::PlanRunCleanup { .. } => "PlanRunCleanup",
+            Self::DeleteWorkflowRuns { .. } => "DeleteWorkflowRuns",
+            Self::CompareWithLastSuccess { .. } => "CompareWithLastSuccess",
             Self::MarkNotificationRead { .. } => "MarkNotificationRead",
             Self::MarkAllNotificationsRead { .. } => "MarkAllNotificationsRead",
+            Self::MarkNotificationDone { .. } => "MarkNotificationDone",
             Self::UnsubscribeNotification { .. } => "UnsubscribeNotification",
             Self::FetchRunById { .. } => "FetchRunById",
             Self::RefreshPr { .. } => "RefreshPr",
             Self::RefreshIssue { .. } => "RefreshIssue",
             Self::WatchRun { .. } => "WatchRun",
             Self::UnwatchRun { .. } => "UnwatchRun",
+            Self::WatchPr { .. } => "WatchPr",
+            Self::UnwatchPr { .. } => "UnwatchPr",
+            Self::MergeWhenGreen { .. } => "MergeWhenGreen",
+            Self::CancelMergeWhenGreen { .. } => "CancelMergeWhenGreen",
             Self::RegisterRefresh { .. } => "RegisterRefresh",
+            Self::PostponeRefresh { .. } => "PostponeRefresh",
+            Self::CancelFetches { .. } => "CancelFetches",
             Self::Shutdown => "Shutdown",
         }
     }
@@ -410,15 +1172,39 @@ pub enum Event {
         filter_idx: usize,
         prs: Vec<PullRequest>,
         rate_limit: Option<RateLimitInfo>,
+        /// Errors GitHub reported next to the results; `prs` is then partial.
+        warnings: Vec<String>,
+        /// `false` while more pages are being fetched: `prs` holds the
+        /// results so far and a complete event follows.
+        complete: bool,
     },
     IssuesFetched {
         filter_idx: usize,
         issues: Vec<Issue>,
         rate_limit: Option<RateLimitInfo>,
+        /// Errors GitHub reported next to the results; `issues` is then partial.
+        warnings: Vec<String>,
+        /// `false` while more pages are being fetched, as for `PrsFetched`.
+        complete: bool,
+    },
+    /// Results of an earlier session's fetch of the tab, sent ahead of a
+    /// `PrsFetched` that replaces them.
+    PrsCached {
+        filter_idx: usize,
+        prs: Vec<PullRequest>,
+        saved_at: chrono::DateTime<chrono::Utc>,
+    },
+    /// As `PrsCached`, ahead of an `IssuesFetched`.
+    IssuesCached {
+        filter_idx: usize,
+        issues: Vec<Issue>,
+        saved_at: chrono::DateTime<chrono::Utc>,
     },
     NotificationsFetched {
         filter_idx: usize,
         notifications: Vec<Notification>,
+        /// Notifications hidden by `done` rules.
+        muted: usize,
         rate_limit: Option<RateLimitInfo>,
     },
     ActionsFetched {
@@ -426,11 +1212,23 @@ pub enum Event {
         runs: Vec<WorkflowRun>,
         rate_limit: Option<RateLimitInfo>,
     },
+    /// As `PrsCached`, ahead of an `ActionsFetched`.
+    ActionsCached {
+        filter_idx: usize,
+        runs: Vec<WorkflowRun>,
+        saved_at: chrono::DateTime<chrono::Utc>,
+    },
     AlertsFetched {
         filter_idx: usize,
         alerts: Vec<SecurityAlert>,
         rate_limit: Option<RateLimitInfo>,
     },
+    OrgEventsFetched {
+        filter_idx: usize,
+        events: Vec<OrgEvent>,
+        source: OrgEventSource,
+        rate_limit: Option<RateLimitInfo>,
+    },
     SecretLocationsFetched {
         alert_number: u64,
         locations: Vec<SecretLocation>,
@@ -441,11 +1239,23 @@ pub enum Event {
         jobs: Vec<WorkflowJob>,
         rate_limit: Option<RateLimitInfo>,
     },
+    JobLogsFetched {
+        job_id: u64,
+        /// Status of the job when its log was read.
+        status: RunStatus,
+        /// `None` until GitHub publishes the log of a running job.
+        log: Option<String>,
+    },
     PrDetailFetched {
         number: u64,
         detail: PrDetail,
         rate_limit: Option<RateLimitInfo>,
     },
+    PrDetailPageFetched {
+        number: u64,
+        page: PrDetailPage,
+        rate_limit: Option<RateLimitInfo>,
+    },
     IssueDetailFetched {
         number: u64,
         detail: IssueDetail,
@@ -459,6 +1269,62 @@ pub enum Event {
         logins: Vec<String>,
         rate_limit: Option<RateLimitInfo>,
     },
+    /// Open issues and PRs as `(number, title)`, most recently updated first.
+    OpenIssueRefsFetched {
+        refs: Vec<(u64, String)>,
+        rate_limit: Option<RateLimitInfo>,
+    },
+    /// `queue` is `None` when the default branch has no merge queue.
+    MergeQueueFetched {
+        owner: String,
+        repo: String,
+        queue: Option<MergeQueue>,
+        rate_limit: Option<RateLimitInfo>,
+    },
+    /// One entry per host asked about, in the same order.
+    HealthChecked {
+        hosts: Vec<HostHealth>,
+    },
+    ViewerSetupFetched {
+        setup: ViewerSetup,
+        rate_limit: Option<RateLimitInfo>,
+    },
+    /// `None` when the token type does not report scopes.
+    TokenScopesFetched {
+        scopes: Option<Vec<String>>,
+    },
+    /// Teams as `org/team-slug`.
+    ViewerTeamsFetched {
+        teams: Vec<String>,
+    },
+    SavedRepliesFetched {
+        replies: Vec<SavedReply>,
+    },
+    /// `login` is the one requested, `None` for the viewer.
+    ContributionsFetched {
+        login: Option<String>,
+        calendar: ContributionCalendar,
+    },
+    WorkflowSchedulesFetched {
+        schedules: Vec<WorkflowSchedule>,
+        rate_limit: Option<RateLimitInfo>,
+    },
+    DispatchableWorkflowsFetched {
+        workflows: Vec<DispatchableWorkflow>,
+        rate_limit: Option<RateLimitInfo>,
+    },
+    /// `repo` is `owner/repo`; both lists are sorted.
+    RepoRefsFetched {
+        repo: String,
+        branches: Vec<String>,
+        tags: Vec<String>,
+    },
+    /// `repo` is `owner/repo`; releases and tags come newest first.
+    ReleasesFetched {
+        repo: String,
+        releases: Vec<Release>,
+        tags: Vec<GitTag>,
+    },
     SingleRunFetched {
         run_id: u64,
         run: Option<WorkflowRun>,
@@ -476,6 +1342,32 @@ pub enum Event {
         detail: IssueDetail,
         rate_limit: Option<RateLimitInfo>,
     },
+    /// A run re-read right after a re-run or cancel, to patch its row.
+    RunRefreshed {
+        run: Box<WorkflowRun>,
+        rate_limit: Option<RateLimitInfo>,
+    },
+    /// Runs removed by `DeleteWorkflowRuns`, sent as each is deleted.
+    RunsDeleted {
+        run_ids: Vec<u64>,
+    },
+    /// Notification `id` was marked read; `None` is every notification.
+    NotificationsRead {
+        id: Option<String>,
+    },
+    /// Runs the retention cleanup would delete, oldest first.
+    RunCleanupPlanned {
+        owner: String,
+        repo: String,
+        candidates: Vec<RunCleanupCandidate>,
+        rate_limit: Option<RateLimitInfo>,
+    },
+    /// `None` when the workflow has no earlier successful run on that branch.
+    RunComparisonFetched {
+        run_id: u64,
+        comparison: Option<Box<RunComparison>>,
+        rate_limit: Option<RateLimitInfo>,
+    },
 
     /// Periodic update for a watched run (or final completed state).
     WatchedRunUpdated {
@@ -486,6 +1378,24 @@ pub enum Event {
         jobs: Option<Vec<WorkflowJob>>,
         rate_limit: Option<RateLimitInfo>,
     },
+    /// A watched PR's CI, review or mergeability changed since the last poll.
+    /// The refreshed PR itself arrives as `PrRefreshed`.
+    WatchedPrChanged {
+        owner: String,
+        repo: String,
+        number: u64,
+        /// One `what: before → after` line per change.
+        changes: Vec<String>,
+    },
+    /// A PR to merge when green was merged, failed to merge, or was closed
+    /// first. Nothing waits to merge it any more.
+    MergeWhenGreenEnded {
+        owner: String,
+        repo: String,
+        number: u64,
+        merged: bool,
+        message: String,
+    },
     /// Result of the watch completion hook execution.
     WatchHookResult {
         run_id: u64,
@@ -493,11 +1403,25 @@ pub enum Event {
         message: String,
     },
 
+    /// Sent on `RegisterRefresh` when the registered filters' background
+    /// refresh would spend more than the hourly rate limit.
+    RefreshBudgetExceeded {
+        message: String,
+    },
     /// Unified error event for all fetch or mutation failures.
     FetchError {
         context: String,
         message: String,
     },
+    /// The list fetch of `filter_idx` ran past the request timeout.
+    FetchTimedOut {
+        filter_idx: usize,
+        secs: u64,
+    },
+    /// The list fetch of `filter_idx` was dropped by `CancelFetches`.
+    FetchCancelled {
+        filter_idx: usize,
+    },
 
     // -----------------------------------------------------------------------
     // Mutation results
@@ -510,3 +1434,128 @@ pub enum Event {
         message: String,
     },
 }
+
+impl Event {
+    /// The rate limit the reply was fetched under, if it carries one.
+    fn rate_limit_mut(&mut self) -> Option<&mut RateLimitInfo> {
+        match self {
+            Self::PrsFetched { rate_limit, .. }
+            | Self::IssuesFetched { rate_limit, .. }
+            | Self::NotificationsFetched { rate_limit, .. }
+            | Self::ActionsFetched { rate_limit, .. }
+            | Self::AlertsFetched { rate_limit, .. }
+            | Self::OrgEventsFetched { rate_limit, .. }
+            | Self::SecretLocationsFetched { rate_limit, .. }
+            | Self::RunJobsFetched { rate_limit, .. }
+            | Self::PrDetailFetched { rate_limit, .. }
+            | Self::PrDetailPageFetched { rate_limit, .. }
+            | Self::IssueDetailFetched { rate_limit, .. }
+            | Self::RepoLabelsFetched { rate_limit, .. }
+            | Self::RepoCollaboratorsFetched { rate_limit, .. }
+            | Self::OpenIssueRefsFetched { rate_limit, .. }
+            | Self::MergeQueueFetched { rate_limit, .. }
+            | Self::ViewerSetupFetched { rate_limit, .. }
+            | Self::WorkflowSchedulesFetched { rate_limit, .. }
+            | Self::DispatchableWorkflowsFetched { rate_limit, .. }
+            | Self::SingleRunFetched { rate_limit, .. }
+            | Self::PrRefreshed { rate_limit, .. }
+            | Self::IssueRefreshed { rate_limit, .. }
+            | Self::RunRefreshed { rate_limit, .. }
+            | Self::RunCleanupPlanned { rate_limit, .. }
+            | Self::RunComparisonFetched { rate_limit, .. }
+            | Self::WatchedRunUpdated { rate_limit, .. } => rate_limit.as_mut(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fetch_prs(filter_idx: usize, title: &str, reply_tx: &EventSender) -> Request {
+        Request::FetchPrs {
+            filter_idx,
+            filter: PrFilter {
+                title: title.into(),
+                filters: String::new(),
+                limit: None,
+                host: None,
+                layout: None,
+                hide_drafts: None,
+                preview: None,
+                preview_tab: None,
+                refetch_interval_minutes: None,
+                badge: None,
+            },
+            force: false,
+            reply_tx: reply_tx.clone(),
+        }
+    }
+
+    #[test]
+    fn fetch_key_matches_same_tab_of_same_view() {
+        let (tx, _rx) = event_channel();
+        let (other_tx, _other_rx) = event_channel();
+        let key = |req: Request| req.fetch_key().unwrap();
+        let mine = key(fetch_prs(0, "Mine", &tx));
+        assert!(mine.same_tab(&key(fetch_prs(0, "Mine", &tx))));
+        assert!(!mine.same_tab(&key(fetch_prs(1, "Mine", &tx))));
+        assert!(!mine.same_tab(&key(fetch_prs(0, "acme/widget", &tx))));
+        assert!(!mine.same_tab(&key(fetch_prs(0, "Mine", &other_tx))));
+        assert!(mine.replies_to(&tx));
+        assert!(!mine.replies_to(&other_tx));
+        let shutdown = Request::Shutdown;
+        assert!(shutdown.fetch_key().is_none());
+        // A timed-out list fetch is sent again as it was.
+        let again = fetch_prs(0, "Mine", &tx).retry().unwrap();
+        assert!(mine.same_tab(&key(again)));
+        assert!(shutdown.retry().is_none());
+    }
+
+    #[test]
+    fn mutations_tag_their_replies_with_their_item() {
+        let (tx, _rx) = event_channel();
+        let mut close = Request::ClosePr {
+            owner: "acme".into(),
+            repo: "widget".into(),
+            number: 7,
+            host: None,
+            reply_tx: tx.clone(),
+        };
+        close.tag_item();
+        let reply_tx = close.reply_tx().unwrap();
+        assert_eq!(reply_tx.item, Some((Arc::from("acme/widget"), 7)));
+        // The host of the reply keeps the item.
+        assert_eq!(
+            reply_tx.for_host("ghe.acme.com").item,
+            Some((Arc::from("acme/widget"), 7))
+        );
+
+        let mut fetch = fetch_prs(0, "Mine", &tx);
+        fetch.tag_item();
+        assert_eq!(fetch.reply_tx().unwrap().item, None);
+    }
+
+    #[test]
+    fn replies_carry_the_host_of_their_rate_limit() {
+        let (tx, rx) = event_channel();
+        let fetched = || Event::RepoLabelsFetched {
+            labels: Vec::new(),
+            rate_limit: Some(RateLimitInfo {
+                limit: 5000,
+                remaining: 4999,
+                cost: 1,
+                host: None,
+            }),
+        };
+        let host_of = |event| match event {
+            Ok(Event::RepoLabelsFetched { rate_limit, .. }) => rate_limit.unwrap().host,
+            _ => panic!("expected RepoLabelsFetched"),
+        };
+        tx.for_host("ghe.acme.com").send(fetched()).unwrap();
+        assert_eq!(host_of(rx.try_recv()).as_deref(), Some("ghe.acme.com"));
+        tx.for_host("github.com").send(fetched()).unwrap();
+        assert_eq!(host_of(rx.try_recv()), None);
+    }
+}
@@ -1,12 +1,15 @@
 // engine module — GitHub backend engine
 
+mod bell;
+mod disk_cache;
 pub mod github;
+mod hooks;
 mod interface;
 mod refresh;
 pub mod stub;
 pub(crate) mod watch;
 
 pub use github::GitHubEngine;
-pub use interface::{Engine, EngineHandle, Event, PrRef, Request};
-pub use refresh::{FilterConfig, RefreshScheduler};
+pub use interface::{Engine, EngineHandle, Event, EventSender, PrRef, Request, event_channel};
+pub use refresh::{FilterConfig, RefreshScheduler, ViewKind as RefreshView};
 pub use stub::StubEngine;
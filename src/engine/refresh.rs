@@ -1,11 +1,11 @@
-use std::sync::mpsc::Sender;
 use std::time::{Duration, SystemTime};
 
 use crate::config::types::{
-    ActionsFilter, AlertsFilter, IssueFilter, NotificationFilter, PrFilter,
+    ActionsFilter, AlertsFilter, EventsFilter, IssueFilter, NotificationFilter, PrFilter,
 };
+use crate::types::RateLimitInfo;
 
-use super::interface::Event;
+use super::interface::EventSender;
 
 /// Identifies which view type a refresh entry belongs to.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
@@ -15,8 +15,38 @@ pub enum ViewKind {
     Actions,
     Notifications,
     Alerts,
+    Events,
 }
 
+impl ViewKind {
+    /// The rate-limit quota this view's list fetches draw from.
+    pub(crate) fn rate_pool(self) -> RatePool {
+        match self {
+            Self::Prs | Self::Issues => RatePool::GraphQl,
+            Self::Actions | Self::Notifications | Self::Alerts | Self::Events => RatePool::Rest,
+        }
+    }
+}
+
+/// GitHub meters GraphQL points and REST requests separately.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RatePool {
+    GraphQl,
+    Rest,
+}
+
+impl RatePool {
+    fn label(self) -> &'static str {
+        match self {
+            Self::GraphQl => "GraphQL points",
+            Self::Rest => "REST requests",
+        }
+    }
+}
+
+/// Hourly quota assumed before GitHub has reported the actual one.
+const DEFAULT_HOURLY_LIMIT: u32 = 5000;
+
 /// A filter configuration for any view kind.
 #[derive(Clone)]
 pub enum FilterConfig {
@@ -25,6 +55,7 @@ pub enum FilterConfig {
     Action(ActionsFilter),
     Notification(NotificationFilter),
     Alert(AlertsFilter),
+    Event(EventsFilter),
 }
 
 impl FilterConfig {
@@ -35,6 +66,44 @@ impl FilterConfig {
             Self::Action(_) => ViewKind::Actions,
             Self::Notification(_) => ViewKind::Notifications,
             Self::Alert(_) => ViewKind::Alerts,
+            Self::Event(_) => ViewKind::Events,
+        }
+    }
+
+    fn title(&self) -> &str {
+        match self {
+            Self::Pr(f) => &f.title,
+            Self::Issue(f) => &f.title,
+            Self::Action(f) => &f.title,
+            Self::Notification(f) => &f.title,
+            Self::Alert(f) => &f.title,
+            Self::Event(f) => &f.title,
+        }
+    }
+
+    /// Rough cost of one refresh before GitHub has reported the real one: a
+    /// point per search page of 100, a request per REST listing (one for
+    /// each of the three alert categories, two when org events fall back
+    /// from the audit log).
+    fn estimated_cost(&self) -> u32 {
+        match self {
+            Self::Pr(f) => f.limit.unwrap_or(100).div_ceil(100).max(1),
+            Self::Issue(f) => f.limit.unwrap_or(100).div_ceil(100).max(1),
+            Self::Action(_) | Self::Notification(_) => 1,
+            Self::Event(_) => 2,
+            Self::Alert(_) => 3,
+        }
+    }
+
+    /// The filter's own `refetch_interval_minutes`, if set.
+    fn refetch_interval_minutes(&self) -> Option<u32> {
+        match self {
+            Self::Pr(f) => f.refetch_interval_minutes,
+            Self::Issue(f) => f.refetch_interval_minutes,
+            Self::Action(f) => f.refetch_interval_minutes,
+            Self::Notification(f) => f.refetch_interval_minutes,
+            Self::Alert(f) => f.refetch_interval_minutes,
+            Self::Event(f) => f.refetch_interval_minutes,
         }
     }
 }
@@ -43,15 +112,21 @@ struct RefreshEntry {
     filter_idx: usize,
     filter: FilterConfig,
     interval: Duration,
-    notify_tx: Sender<Event>,
+    notify_tx: EventSender,
+    /// Rate-limit cost of the last uncached fetch.
+    cost: Option<u32>,
     // SystemTime (wall clock) intentionally — Instant uses CLOCK_MONOTONIC,
     // which freezes during laptop sleep, causing missed refreshes after wake.
     last_fetch: Option<SystemTime>,
+    /// No background refresh before this, whatever the interval says.
+    postponed_until: Option<SystemTime>,
 }
 
 /// Tracks per-filter background refresh state for the engine.
 pub struct RefreshScheduler {
     entries: Vec<RefreshEntry>,
+    /// Hourly quotas last reported by GitHub, GraphQL then REST.
+    limits: [Option<u32>; 2],
 }
 
 impl Default for RefreshScheduler {
@@ -64,46 +139,131 @@ impl RefreshScheduler {
     pub fn new() -> Self {
         Self {
             entries: Vec::new(),
+            limits: [None; 2],
         }
     }
 
     /// Register filters for background refresh, replacing any existing entries
     /// for the same view kind.
+    ///
+    /// `interval` applies to filters without their own
+    /// `refetch_interval_minutes`.
     pub fn register(
         &mut self,
         configs: Vec<FilterConfig>,
         interval: Duration,
-        notify_tx: &Sender<Event>,
+        notify_tx: &EventSender,
     ) {
         let Some(kind) = configs.first().map(FilterConfig::view_kind) else {
             return;
         };
-        self.entries.retain(|e| e.filter.view_kind() != kind);
+        let previous: Vec<RefreshEntry> = self
+            .entries
+            .extract_if(.., |e| e.filter.view_kind() == kind)
+            .collect();
         for (filter_idx, filter) in configs.into_iter().enumerate() {
+            // Re-registration (e.g. a scope toggle) keeps the measured cost
+            // and any postponement.
+            let same = previous
+                .iter()
+                .find(|e| e.filter_idx == filter_idx && e.filter.title() == filter.title());
+            let cost = same.and_then(|e| e.cost);
+            let postponed_until = same.and_then(|e| e.postponed_until);
+            let interval = filter
+                .refetch_interval_minutes()
+                .map_or(interval, |mins| Duration::from_mins(u64::from(mins.max(1))));
             self.entries.push(RefreshEntry {
                 filter_idx,
                 filter,
                 interval,
                 notify_tx: notify_tx.clone(),
+                cost,
                 last_fetch: None,
+                postponed_until,
             });
         }
     }
 
     /// Mark the given filter index + view kind as having just been fetched.
-    pub fn mark_fetched(&mut self, filter_idx: usize, view_kind: ViewKind) {
+    ///
+    /// `rate_limit` is `None` for cache hits, which leave the recorded cost
+    /// alone.
+    pub fn mark_fetched(
+        &mut self,
+        filter_idx: usize,
+        view_kind: ViewKind,
+        rate_limit: Option<&RateLimitInfo>,
+    ) {
         let now = SystemTime::now();
         for entry in &mut self.entries {
             if entry.filter.view_kind() == view_kind && entry.filter_idx == filter_idx {
                 entry.last_fetch = Some(now);
+                if let Some(rl) = rate_limit {
+                    entry.cost = Some(rl.cost);
+                }
             }
         }
+        if let Some(rl) = rate_limit {
+            self.limits[view_kind.rate_pool() as usize] = Some(rl.limit);
+        }
+    }
+
+    /// Hold off the background refresh of one filter until `until`.
+    pub fn postpone(&mut self, filter_idx: usize, view_kind: ViewKind, until: SystemTime) {
+        for entry in &mut self.entries {
+            if entry.filter.view_kind() == view_kind && entry.filter_idx == filter_idx {
+                entry.postponed_until = Some(until);
+            }
+        }
+    }
+
+    /// Per-filter refresh costs of `view_kind`, in filter order.
+    pub fn filter_costs(&self, view_kind: ViewKind) -> Vec<FilterCost> {
+        self.entries
+            .iter()
+            .filter(|e| e.filter.view_kind() == view_kind)
+            .map(|e| FilterCost {
+                filter_idx: e.filter_idx,
+                title: e.filter.title().to_owned(),
+                cost: e.cost.unwrap_or_else(|| e.filter.estimated_cost()),
+                estimated: e.cost.is_none(),
+                interval: e.interval,
+            })
+            .collect()
+    }
+
+    /// Points (or requests) per hour the registered filters of `pool` spend
+    /// on background refresh.
+    pub fn hourly_cost(&self, pool: RatePool) -> u64 {
+        self.entries
+            .iter()
+            .filter(|e| e.filter.view_kind().rate_pool() == pool)
+            .map(|e| {
+                let cost = u64::from(e.cost.unwrap_or_else(|| e.filter.estimated_cost()));
+                cost * 3600 / e.interval.as_secs().max(1)
+            })
+            .sum()
+    }
+
+    /// A warning when background refresh alone would exhaust the hourly
+    /// quota of `pool`.
+    pub fn budget_warning(&self, pool: RatePool) -> Option<String> {
+        let limit = self.limits[pool as usize].unwrap_or(DEFAULT_HOURLY_LIMIT);
+        let needed = self.hourly_cost(pool);
+        (needed > u64::from(limit)).then(|| {
+            format!(
+                "Background refresh needs ~{needed} {}/hour, over the {limit}/hour rate limit: \
+                 raise refetch_interval_minutes",
+                pool.label()
+            )
+        })
     }
 
     /// Return all entries whose refresh interval has elapsed since last fetch.
     ///
     /// Entries that have never been fetched are skipped — the initial load is
     /// done on-demand by the view; background refresh fires only afterwards.
+    /// Postponed entries wait for their postponement to pass as well.
     pub fn due_entries(&self) -> Vec<DueEntry> {
         let now = SystemTime::now();
         self.entries
@@ -111,6 +271,7 @@ impl RefreshScheduler {
             .filter(|e| {
                 e.last_fetch
                     .is_some_and(|t| now.duration_since(t).unwrap_or(Duration::ZERO) >= e.interval)
+                    && e.postponed_until.is_none_or(|until| now >= until)
             })
             .map(|e| DueEntry {
                 filter_idx: e.filter_idx,
@@ -121,9 +282,110 @@ impl RefreshScheduler {
     }
 }
 
+/// What one registered filter spends per background refresh.
+#[derive(Debug)]
+pub struct FilterCost {
+    pub filter_idx: usize,
+    pub title: String,
+    pub cost: u32,
+    /// `true` until a fetch has reported the actual cost.
+    pub estimated: bool,
+    pub interval: Duration,
+}
+
 /// An entry that is due for background refresh.
 pub struct DueEntry {
     pub filter_idx: usize,
     pub filter: FilterConfig,
-    pub notify_tx: Sender<Event>,
+    pub notify_tx: EventSender,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::event_channel;
+
+    fn pr_filter(title: &str, limit: u32, minutes: Option<u32>) -> FilterConfig {
+        FilterConfig::Pr(PrFilter {
+            title: title.into(),
+            filters: "is:open".into(),
+            limit: Some(limit),
+            host: None,
+            layout: None,
+            hide_drafts: None,
+            preview: None,
+            preview_tab: None,
+            refetch_interval_minutes: minutes,
+            badge: None,
+        })
+    }
+
+    #[test]
+    fn budget_warning_uses_measured_costs_and_per_filter_intervals() {
+        let (tx, _rx) = event_channel();
+        let mut sched = RefreshScheduler::new();
+        sched.register(
+            vec![
+                pr_filter("Review", 300, Some(2)),
+                pr_filter("Team", 50, None),
+            ],
+            Duration::from_mins(30),
+            &tx,
+        );
+        // Estimated: 3 pages every 2 minutes plus 1 page every 30 minutes.
+        assert_eq!(sched.hourly_cost(RatePool::GraphQl), 92);
+        assert!(sched.budget_warning(RatePool::GraphQl).is_none());
+
+        let rl = RateLimitInfo {
+            limit: 5000,
+            remaining: 4000,
+            cost: 200,
+            host: None,
+        };
+        sched.mark_fetched(0, ViewKind::Prs, Some(&rl));
+        assert_eq!(sched.hourly_cost(RatePool::GraphQl), 6002);
+        let warning = sched.budget_warning(RatePool::GraphQl).unwrap();
+        assert!(warning.contains("6002 GraphQL points/hour"), "{warning}");
+        assert!(sched.budget_warning(RatePool::Rest).is_none());
+
+        // The measured cost survives re-registering the same filters.
+        sched.register(
+            vec![
+                pr_filter("Review", 300, Some(2)),
+                pr_filter("Team", 50, None),
+            ],
+            Duration::from_mins(30),
+            &tx,
+        );
+        let costs = sched.filter_costs(ViewKind::Prs);
+        assert_eq!((costs[0].cost, costs[0].estimated), (200, false));
+        assert!(costs[1].estimated);
+    }
+
+    #[test]
+    fn postponed_entries_wait_past_their_interval() {
+        let (tx, _rx) = event_channel();
+        let mut sched = RefreshScheduler::new();
+        sched.register(
+            vec![pr_filter("Review", 50, None), pr_filter("Team", 50, None)],
+            Duration::from_mins(5),
+            &tx,
+        );
+        let an_hour_ago = SystemTime::now() - Duration::from_hours(1);
+        for entry in &mut sched.entries {
+            entry.last_fetch = Some(an_hour_ago);
+        }
+        assert_eq!(sched.due_entries().len(), 2);
+
+        sched.postpone(
+            1,
+            ViewKind::Prs,
+            SystemTime::now() + Duration::from_mins(10),
+        );
+        let due: Vec<usize> = sched.due_entries().iter().map(|e| e.filter_idx).collect();
+        assert_eq!(due, [0]);
+
+        sched.postpone(1, ViewKind::Prs, an_hour_ago);
+        assert_eq!(sched.due_entries().len(), 2);
+    }
 }
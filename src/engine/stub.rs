@@ -53,6 +53,8 @@ impl StubEngine {
                         filter_idx,
                         prs: self.prs.clone(),
                         rate_limit: None,
+                        warnings: Vec::new(),
+                        complete: true,
                     });
                 }
                 Request::FetchIssues {
@@ -64,6 +66,27 @@ impl StubEngine {
                         filter_idx,
                         issues: self.issues.clone(),
                         rate_limit: None,
+                        warnings: Vec::new(),
+                        complete: true,
+                    });
+                }
+
+                Request::SearchPrs { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::PrsFetched {
+                        filter_idx: 0,
+                        prs: self.prs.clone(),
+                        rate_limit: None,
+                        warnings: Vec::new(),
+                        complete: true,
+                    });
+                }
+                Request::SearchIssues { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::IssuesFetched {
+                        filter_idx: 0,
+                        issues: self.issues.clone(),
+                        rate_limit: None,
+                        warnings: Vec::new(),
+                        complete: true,
                     });
                 }
 
@@ -93,6 +116,20 @@ impl StubEngine {
                     });
                 }
 
+                // Org events — return empty list
+                Request::FetchOrgEvents {
+                    filter_idx,
+                    reply_tx,
+                    ..
+                } => {
+                    let _ = reply_tx.send(Event::OrgEventsFetched {
+                        filter_idx,
+                        events: vec![],
+                        source: crate::types::OrgEventSource::Events,
+                        rate_limit: None,
+                    });
+                }
+
                 // Secret locations — return empty list
                 Request::FetchSecretLocations {
                     alert_number,
@@ -117,6 +154,17 @@ impl StubEngine {
                     });
                 }
 
+                // Job log — not published yet
+                Request::FetchJobLogs {
+                    job_id, reply_tx, ..
+                } => {
+                    let _ = reply_tx.send(Event::JobLogsFetched {
+                        job_id,
+                        status: crate::types::RunStatus::Completed,
+                        log: None,
+                    });
+                }
+
                 // Single run by ID — return None (not found)
                 Request::FetchRunById {
                     run_id, reply_tx, ..
@@ -136,10 +184,12 @@ impl StubEngine {
                     let _ = reply_tx.send(Event::NotificationsFetched {
                         filter_idx,
                         notifications: self.notifications.clone(),
+                        muted: 0,
                         rate_limit: None,
                     });
                 }
                 Request::FetchPrDetail { reply_tx, .. }
+                | Request::FetchPrDetailPage { reply_tx, .. }
                 | Request::PrefetchPrDetails { reply_tx, .. } => {
                     let _ = reply_tx.send(Event::FetchError {
                         context: "stub".into(),
@@ -166,18 +216,136 @@ impl StubEngine {
                         rate_limit: None,
                     });
                 }
+                Request::FetchOpenIssueRefs { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::OpenIssueRefsFetched {
+                        refs: vec![],
+                        rate_limit: None,
+                    });
+                }
+                Request::FetchMergeQueue {
+                    owner,
+                    repo,
+                    reply_tx,
+                    ..
+                } => {
+                    let _ = reply_tx.send(Event::MergeQueueFetched {
+                        owner,
+                        repo,
+                        queue: None,
+                        rate_limit: None,
+                    });
+                }
+                Request::PlanRunCleanup {
+                    owner,
+                    repo,
+                    reply_tx,
+                    ..
+                } => {
+                    let _ = reply_tx.send(Event::RunCleanupPlanned {
+                        owner,
+                        repo,
+                        candidates: vec![],
+                        rate_limit: None,
+                    });
+                }
+                Request::CompareWithLastSuccess { run, reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::RunComparisonFetched {
+                        run_id: run.id,
+                        comparison: None,
+                        rate_limit: None,
+                    });
+                }
+                Request::CheckHealth { hosts, reply_tx } => {
+                    let hosts = hosts
+                        .into_iter()
+                        .map(|host| crate::types::HostHealth {
+                            host,
+                            ..Default::default()
+                        })
+                        .collect();
+                    let _ = reply_tx.send(Event::HealthChecked { hosts });
+                }
+                Request::FetchViewerSetup { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::ViewerSetupFetched {
+                        setup: crate::types::ViewerSetup::default(),
+                        rate_limit: None,
+                    });
+                }
+                Request::FetchTokenScopes { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::TokenScopesFetched { scopes: None });
+                }
+                Request::FetchViewerTeams { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::ViewerTeamsFetched { teams: Vec::new() });
+                }
+                Request::FetchSavedReplies { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::SavedRepliesFetched {
+                        replies: Vec::new(),
+                    });
+                }
+                Request::FetchWorkflowSchedules { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::WorkflowSchedulesFetched {
+                        schedules: Vec::new(),
+                        rate_limit: None,
+                    });
+                }
+                Request::FetchDispatchableWorkflows { reply_tx, .. } => {
+                    let _ = reply_tx.send(Event::DispatchableWorkflowsFetched {
+                        workflows: Vec::new(),
+                        rate_limit: None,
+                    });
+                }
+                Request::FetchRepoRefs {
+                    owner,
+                    repo,
+                    reply_tx,
+                    ..
+                } => {
+                    let _ = reply_tx.send(Event::RepoRefsFetched {
+                        repo: format!("{owner}/{repo}"),
+                        branches: Vec::new(),
+                        tags: Vec::new(),
+                    });
+                }
+                Request::FetchReleases {
+                    owner,
+                    repo,
+                    reply_tx,
+                    ..
+                } => {
+                    let _ = reply_tx.send(Event::ReleasesFetched {
+                        repo: format!("{owner}/{repo}"),
+                        releases: Vec::new(),
+                        tags: Vec::new(),
+                    });
+                }
+                Request::FetchContributions {
+                    login, reply_tx, ..
+                } => {
+                    let _ = reply_tx.send(Event::ContributionsFetched {
+                        calendar: crate::types::ContributionCalendar {
+                            login: login.clone().unwrap_or_default(),
+                            ..Default::default()
+                        },
+                        login,
+                    });
+                }
 
                 // Refresh registration — ignored by stub
-                Request::RegisterRefresh { .. } => {}
+                Request::RegisterRefresh { .. }
+                | Request::PostponeRefresh { .. }
+                | Request::CancelFetches { .. } => {}
 
                 // All mutations succeed instantly
                 Request::ApprovePr { reply_tx, .. }
+                | Request::SubmitPrReview { reply_tx, .. }
                 | Request::MergePr { reply_tx, .. }
                 | Request::ClosePr { reply_tx, .. }
                 | Request::ReopenPr { reply_tx, .. }
                 | Request::AddPrComment { reply_tx, .. }
                 | Request::UpdateBranch { reply_tx, .. }
                 | Request::ReadyForReview { reply_tx, .. }
+                | Request::SetFilesViewed { reply_tx, .. }
+                | Request::EnqueuePr { reply_tx, .. }
                 | Request::SetPrAssignees { reply_tx, .. }
                 | Request::SetPrLabels { reply_tx, .. }
                 | Request::CloseIssue { reply_tx, .. }
@@ -185,10 +353,20 @@ impl StubEngine {
                 | Request::AddIssueComment { reply_tx, .. }
                 | Request::SetIssueLabels { reply_tx, .. }
                 | Request::SetIssueAssignees { reply_tx, .. }
+                | Request::SetIssueMilestone { reply_tx, .. }
                 | Request::RerunWorkflowRun { reply_tx, .. }
                 | Request::CancelWorkflowRun { reply_tx, .. }
+                | Request::CreateDraftRelease { reply_tx, .. }
+                | Request::PublishRelease { reply_tx, .. }
+                | Request::DeleteRelease { reply_tx, .. }
+                | Request::ShareItem { reply_tx, .. }
+                | Request::RerunJobs { reply_tx, .. }
+                | Request::ApproveWorkflowRuns { reply_tx, .. }
+                | Request::DispatchWorkflow { reply_tx, .. }
+                | Request::DeleteWorkflowRuns { reply_tx, .. }
                 | Request::MarkNotificationRead { reply_tx, .. }
-                | Request::MarkAllNotificationsRead { reply_tx }
+                | Request::MarkAllNotificationsRead { reply_tx, .. }
+                | Request::MarkNotificationDone { reply_tx, .. }
                 | Request::UnsubscribeNotification { reply_tx, .. } => {
                     let _ = reply_tx.send(Event::MutationOk {
                         description: "stub ok".into(),
@@ -203,13 +381,20 @@ impl StubEngine {
                         run_id,
                         run: crate::types::WorkflowRun {
                             id: run_id,
+                            workflow_id: 0,
                             name: "stub-workflow".into(),
                             display_title: "stub run".into(),
                             status: crate::types::RunStatus::Completed,
                             conclusion: Some(crate::types::RunConclusion::Success),
                             event: "push".into(),
                             head_branch: None,
+                            head_sha: String::new(),
+                            head_repository: None,
+                            repository: None,
                             actor: None,
+                            triggering_actor: None,
+                            head_commit_message: None,
+                            pull_requests: Vec::new(),
                             run_number: 1,
                             html_url: String::new(),
                             created_at: chrono::Utc::now(),
@@ -221,7 +406,11 @@ impl StubEngine {
                         rate_limit: None,
                     });
                 }
-                Request::UnwatchRun { .. } => {}
+                Request::UnwatchRun { .. }
+                | Request::WatchPr { .. }
+                | Request::UnwatchPr { .. }
+                | Request::MergeWhenGreen { .. }
+                | Request::CancelMergeWhenGreen { .. } => {}
 
                 Request::Shutdown => break,
             }
@@ -1,14 +1,15 @@
-use std::sync::mpsc::Sender;
 use std::time::{Duration, SystemTime};
 
-use super::interface::Event;
+use super::interface::{EventSender, PrRef};
+use crate::config::types::MergeMethod;
+use crate::types::PrWatchSnapshot;
 
 struct WatchEntry {
     owner: String,
     repo: String,
     run_id: u64,
     host: Option<String>,
-    reply_tx: Sender<Event>,
+    reply_tx: EventSender,
     last_poll: Option<SystemTime>,
 }
 
@@ -16,10 +17,11 @@ struct WatchEntry {
 ///
 /// Sibling of `RefreshScheduler` — lives in the engine thread and polls
 /// individual runs at a short interval via the existing `fetch_run_by_id`
-/// REST endpoint.
+/// REST endpoint. Watched PRs share the interval through `prs`.
 pub(super) struct WatchScheduler {
     entries: Vec<WatchEntry>,
     interval: Duration,
+    pub(super) prs: PrWatchScheduler,
 }
 
 impl WatchScheduler {
@@ -27,6 +29,7 @@ impl WatchScheduler {
         Self {
             entries: Vec::new(),
             interval,
+            prs: PrWatchScheduler::new(interval),
         }
     }
 
@@ -37,7 +40,7 @@ impl WatchScheduler {
         repo: String,
         run_id: u64,
         host: Option<String>,
-        reply_tx: Sender<Event>,
+        reply_tx: EventSender,
     ) {
         if self.entries.iter().any(|e| e.run_id == run_id) {
             return;
@@ -100,8 +103,9 @@ impl WatchScheduler {
         self.remove(run_id);
     }
 
+    /// Whether no run and no PR is watched.
     pub(super) fn is_empty(&self) -> bool {
-        self.entries.is_empty()
+        self.entries.is_empty() && self.prs.is_empty()
     }
 }
 
@@ -111,16 +115,266 @@ pub(super) struct DueWatchEntry {
     pub(super) repo: String,
     pub(super) run_id: u64,
     pub(super) host: Option<String>,
-    pub(super) reply_tx: Sender<Event>,
+    pub(super) reply_tx: EventSender,
+}
+
+struct PrWatchEntry {
+    pr_ref: PrRef,
+    host: Option<String>,
+    reply_tx: EventSender,
+    /// Watched with `watch_pr`, not only polled to merge when green.
+    watched: bool,
+    /// Set while the PR is to be merged once green.
+    merge_method: Option<MergeMethod>,
+    last_poll: Option<SystemTime>,
+    last_snapshot: Option<PrWatchSnapshot>,
+}
+
+/// Tracks PRs watched for CI, review and mergeability changes.
+///
+/// Polled on the watch tick, so watched PRs refresh far more often than
+/// their filters. Unlike runs, PRs stay watched until the user unwatches
+/// them. A PR to merge when green is polled the same way until it is merged
+/// or the user cancels.
+pub(super) struct PrWatchScheduler {
+    entries: Vec<PrWatchEntry>,
+    interval: Duration,
+}
+
+impl PrWatchScheduler {
+    pub(super) fn new(interval: Duration) -> Self {
+        Self {
+            entries: Vec::new(),
+            interval,
+        }
+    }
+
+    fn position(&self, owner: &str, repo: &str, number: u64) -> Option<usize> {
+        self.entries.iter().position(|e| {
+            e.pr_ref.number == number && e.pr_ref.owner == owner && e.pr_ref.repo == repo
+        })
+    }
+
+    /// The entry of `pr_ref`, added if new. De-duplicates by
+    /// `owner/repo#number`.
+    fn entry(
+        &mut self,
+        pr_ref: PrRef,
+        host: Option<String>,
+        reply_tx: EventSender,
+    ) -> &mut PrWatchEntry {
+        if let Some(idx) = self.position(&pr_ref.owner, &pr_ref.repo, pr_ref.number) {
+            return &mut self.entries[idx];
+        }
+        self.entries.push(PrWatchEntry {
+            pr_ref,
+            host,
+            reply_tx,
+            watched: false,
+            merge_method: None,
+            last_poll: None,
+            last_snapshot: None,
+        });
+        let last = self.entries.len() - 1;
+        &mut self.entries[last]
+    }
+
+    /// Add a PR to watch.
+    pub(super) fn add(&mut self, pr_ref: PrRef, host: Option<String>, reply_tx: EventSender) {
+        self.entry(pr_ref, host, reply_tx).watched = true;
+    }
+
+    /// Stop watching a PR; it is still polled while it is to merge when green.
+    pub(super) fn remove(&mut self, owner: &str, repo: &str, number: u64) {
+        if let Some(idx) = self.position(owner, repo, number) {
+            self.entries[idx].watched = false;
+            self.drop_if_unused(idx);
+        }
+    }
+
+    /// Merge a PR with `method` once it is green.
+    pub(super) fn arm(
+        &mut self,
+        pr_ref: PrRef,
+        host: Option<String>,
+        reply_tx: EventSender,
+        method: MergeMethod,
+    ) {
+        self.entry(pr_ref, host, reply_tx).merge_method = Some(method);
+    }
+
+    /// Stop waiting to merge a PR; it is still polled while watched.
+    pub(super) fn disarm(&mut self, owner: &str, repo: &str, number: u64) {
+        if let Some(idx) = self.position(owner, repo, number) {
+            self.entries[idx].merge_method = None;
+            self.drop_if_unused(idx);
+        }
+    }
+
+    fn drop_if_unused(&mut self, idx: usize) {
+        let entry = &self.entries[idx];
+        if !entry.watched && entry.merge_method.is_none() {
+            self.entries.remove(idx);
+        }
+    }
+
+    /// Drop a PR whose view went away, watched or not.
+    pub(super) fn evict(&mut self, owner: &str, repo: &str, number: u64) {
+        if let Some(idx) = self.position(owner, repo, number) {
+            self.entries.remove(idx);
+        }
+    }
+
+    /// Return entries whose poll interval has elapsed (or never been polled),
+    /// with the same tolerance as [`WatchScheduler::due_entries`].
+    pub(super) fn due_entries(&self) -> Vec<DuePrWatch> {
+        let now = SystemTime::now();
+        let threshold = self.interval.saturating_sub(Duration::from_secs(1));
+        self.entries
+            .iter()
+            .filter(|e| {
+                e.last_poll
+                    .is_none_or(|t| now.duration_since(t).unwrap_or(Duration::ZERO) >= threshold)
+            })
+            .map(|e| DuePrWatch {
+                pr_ref: e.pr_ref.clone(),
+                host: e.host.clone(),
+                reply_tx: e.reply_tx.clone(),
+                merge_method: e.merge_method,
+            })
+            .collect()
+    }
+
+    /// Record a poll result; returns what changed since the previous poll
+    /// (nothing on the first one).
+    pub(super) fn record(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        snapshot: PrWatchSnapshot,
+    ) -> Vec<String> {
+        let Some(idx) = self.position(owner, repo, number) else {
+            return Vec::new();
+        };
+        let entry = &mut self.entries[idx];
+        entry.last_poll = Some(SystemTime::now());
+        let changes = entry
+            .last_snapshot
+            .map(|before| snapshot.changes_since(&before))
+            .unwrap_or_default();
+        entry.last_snapshot = Some(snapshot);
+        changes
+    }
+
+    /// The state recorded at the last poll of a watched PR.
+    pub(super) fn last_snapshot(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Option<PrWatchSnapshot> {
+        self.position(owner, repo, number)
+            .and_then(|idx| self.entries[idx].last_snapshot)
+    }
+
+    /// Record a failed poll so the entry waits a full interval before retrying.
+    pub(super) fn mark_polled(&mut self, owner: &str, repo: &str, number: u64) {
+        if let Some(idx) = self.position(owner, repo, number) {
+            self.entries[idx].last_poll = Some(SystemTime::now());
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// A PR watch that is due for polling.
+pub(super) struct DuePrWatch {
+    pub(super) pr_ref: PrRef,
+    pub(super) host: Option<String>,
+    pub(super) reply_tx: EventSender,
+    /// Set when the PR is to merge once green.
+    pub(super) merge_method: Option<MergeMethod>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::mpsc;
+    use crate::engine::event_channel;
+    use crate::types::CiState;
+
+    fn pr_ref(number: u64) -> PrRef {
+        PrRef {
+            owner: "acme".into(),
+            repo: "widget".into(),
+            number,
+            base_ref: "main".into(),
+            head_repo_owner: None,
+            head_ref: "feat".into(),
+        }
+    }
+
+    fn snapshot(ci: CiState) -> PrWatchSnapshot {
+        PrWatchSnapshot {
+            state: "open",
+            ci,
+            review: "no review",
+            mergeable: None,
+        }
+    }
+
+    #[test]
+    fn pr_watch_reports_changes_after_the_first_poll() {
+        let (tx, _rx) = event_channel();
+        let mut sched = PrWatchScheduler::new(Duration::from_mins(1));
+        sched.add(pr_ref(7), None, tx.clone());
+        sched.add(pr_ref(7), None, tx);
+        assert_eq!(sched.due_entries().len(), 1);
+
+        assert!(
+            sched
+                .record("acme", "widget", 7, snapshot(CiState::Pending))
+                .is_empty()
+        );
+        assert!(sched.due_entries().is_empty());
+        assert_eq!(
+            sched.record("acme", "widget", 7, snapshot(CiState::Passing)),
+            vec!["CI: pending \u{2192} passing"]
+        );
+
+        sched.remove("acme", "widget", 7);
+        assert!(sched.is_empty());
+    }
+
+    #[test]
+    fn merge_when_green_keeps_polling_until_disarmed() {
+        let (tx, _rx) = event_channel();
+        let mut sched = PrWatchScheduler::new(Duration::from_mins(1));
+        sched.add(pr_ref(7), None, tx.clone());
+        sched.arm(pr_ref(7), None, tx.clone(), MergeMethod::Squash);
+        assert_eq!(sched.due_entries().len(), 1);
+        assert_eq!(
+            sched.due_entries()[0].merge_method,
+            Some(MergeMethod::Squash)
+        );
+
+        // Unwatching keeps polling the armed PR, cancelling the merge too.
+        sched.remove("acme", "widget", 7);
+        assert_eq!(sched.due_entries().len(), 1);
+        sched.disarm("acme", "widget", 7);
+        assert!(sched.is_empty());
+
+        // Cancelling the merge of a watched PR keeps it watched.
+        sched.arm(pr_ref(8), None, tx.clone(), MergeMethod::Merge);
+        sched.add(pr_ref(8), None, tx);
+        sched.disarm("acme", "widget", 8);
+        assert_eq!(sched.due_entries()[0].merge_method, None);
+    }
 
-    fn make_scheduler(interval: Duration) -> (WatchScheduler, Sender<Event>) {
-        let (tx, _rx) = mpsc::channel::<Event>();
+    fn make_scheduler(interval: Duration) -> (WatchScheduler, EventSender) {
+        let (tx, _rx) = event_channel();
         (WatchScheduler::new(interval), tx)
     }
 
@@ -1,5 +1,9 @@
+use std::cmp::Ordering;
+
 use crate::components::table::Row;
 use crate::github::types::Notification;
+use crate::types::OrgEvent;
+use crate::util::PrSize;
 
 // ---------------------------------------------------------------------------
 // Scope injection helper
@@ -17,6 +21,29 @@ pub(crate) fn apply_scope(filters: &str, scope: Option<&str>) -> String {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Synthetic qualifiers
+// ---------------------------------------------------------------------------
+
+/// PR filter qualifier GitHub search does not know: keep only PRs whose review
+/// was requested from one of the viewer's teams.
+pub(crate) const TEAM_REVIEW_QUALIFIER: &str = "is:team-review";
+
+/// Remove [`TEAM_REVIEW_QUALIFIER`] from `filters` before they are sent to
+/// GitHub. Returns the remaining filters and whether the qualifier was present.
+pub(crate) fn strip_team_review(filters: &str) -> (String, bool) {
+    let mut found = false;
+    let kept: Vec<&str> = filters
+        .split_whitespace()
+        .filter(|t| {
+            let is_qualifier = t.eq_ignore_ascii_case(TEAM_REVIEW_QUALIFIER);
+            found |= is_qualifier;
+            !is_qualifier
+        })
+        .collect();
+    (kept.join(" "), found)
+}
+
 // ---------------------------------------------------------------------------
 // Generic row filter (T088)
 // ---------------------------------------------------------------------------
@@ -172,6 +199,103 @@ pub(crate) fn filter_notifications(
         .collect()
 }
 
+// ---------------------------------------------------------------------------
+// Structured org event filter
+// ---------------------------------------------------------------------------
+
+/// Filter org events by `actor:<login>` and `action:<prefix>` tokens plus
+/// free text matched against actor, action and target.
+/// Returns indices of matching events.
+pub(crate) fn filter_org_events(events: &[OrgEvent], query: &str) -> Vec<usize> {
+    let mut actor = None;
+    let mut action = None;
+    let mut text_parts = Vec::new();
+    for token in query.split_whitespace() {
+        if let Some(val) = token.strip_prefix("actor:") {
+            actor = Some(val.to_lowercase());
+        } else if let Some(val) = token.strip_prefix("action:") {
+            action = Some(val.to_lowercase());
+        } else {
+            text_parts.push(token.to_lowercase());
+        }
+    }
+    let text = text_parts.join(" ");
+
+    events
+        .iter()
+        .enumerate()
+        .filter(|(_, event)| {
+            let event_actor = event.actor.as_deref().unwrap_or_default().to_lowercase();
+            let event_action = event.action.to_lowercase();
+            if actor.as_ref().is_some_and(|a| *a != event_actor)
+                || action
+                    .as_ref()
+                    .is_some_and(|a| !event_action.starts_with(a))
+            {
+                return false;
+            }
+            text.is_empty()
+                || event_actor.contains(&text)
+                || event_action.contains(&text)
+                || event
+                    .target
+                    .as_deref()
+                    .is_some_and(|t| t.to_lowercase().contains(&text))
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// PR size qualifier
+// ---------------------------------------------------------------------------
+
+/// Size classes a `size:` token admits: `size:m`, `size:xs,s`, `size:>=l`,
+/// `size:<m`. `None` for any other token.
+fn parse_size_token(token: &str) -> Option<Vec<PrSize>> {
+    let value = token.strip_prefix("size:")?;
+    // Comparison operators, longest first, with the orderings they admit.
+    let operators: [(&str, &[Ordering]); 4] = [
+        (">=", &[Ordering::Greater, Ordering::Equal]),
+        ("<=", &[Ordering::Less, Ordering::Equal]),
+        (">", &[Ordering::Greater]),
+        ("<", &[Ordering::Less]),
+    ];
+    if let Some((bound, admitted)) = operators
+        .iter()
+        .find_map(|(op, admitted)| Some((value.strip_prefix(op)?, *admitted)))
+    {
+        let bound = PrSize::parse(bound)?;
+        return Some(
+            PrSize::ALL
+                .into_iter()
+                .filter(|size| admitted.contains(&size.cmp(&bound)))
+                .collect(),
+        );
+    }
+    value.split(',').map(PrSize::parse).collect()
+}
+
+/// Split the `size:` tokens off a PR search query. Returns the remaining
+/// free text and the size classes every token admits (`None` without any).
+/// A token naming no size class stays in the free text.
+pub(crate) fn split_size_query(query: &str) -> (String, Option<Vec<PrSize>>) {
+    let mut sizes: Option<Vec<PrSize>> = None;
+    let mut text_parts = Vec::new();
+    for token in query.split_whitespace() {
+        match parse_size_token(token) {
+            Some(admitted) => {
+                sizes = Some(match sizes {
+                    Some(prev) => prev.into_iter().filter(|s| admitted.contains(s)).collect(),
+                    None => admitted,
+                });
+            }
+            None => text_parts.push(token),
+        }
+    }
+    (text_parts.join(" "), sizes)
+}
+
 // ---------------------------------------------------------------------------
 // Tests (T090)
 // ---------------------------------------------------------------------------
@@ -213,6 +337,7 @@ mod tests {
             },
             url: String::new(),
             updated_at: Utc::now(),
+            subject_status: None,
         }
     }
 
@@ -256,6 +381,18 @@ mod tests {
 
     // --- filter_rows tests ---
 
+    #[test]
+    fn strip_team_review_removes_qualifier() {
+        assert_eq!(
+            strip_team_review("is:open is:team-review repo:a/b"),
+            ("is:open repo:a/b".to_owned(), true)
+        );
+        assert_eq!(
+            strip_team_review("is:open review-requested:@me"),
+            ("is:open review-requested:@me".to_owned(), false)
+        );
+    }
+
     #[test]
     fn filter_rows_empty_query_returns_all() {
         let rows = vec![
@@ -484,4 +621,46 @@ mod tests {
         let result = filter_notifications(&notifs, &rows, "is:unread feature");
         assert_eq!(result, vec![1]);
     }
+
+    #[test]
+    fn filter_org_events_by_actor_action_and_text() {
+        let event = |actor: &str, action: &str, target: &str| OrgEvent {
+            action: action.to_owned(),
+            actor: Some(actor.to_owned()),
+            target: Some(target.to_owned()),
+            created_at: Utc::now(),
+            url: String::new(),
+            item: None,
+        };
+        let events = vec![
+            event("alice", "repo.create", "acme/api"),
+            event("bob", "org.add_member", "carol"),
+            event("alice", "org.update_member", "dave"),
+        ];
+        assert_eq!(filter_org_events(&events, ""), vec![0, 1, 2]);
+        assert_eq!(filter_org_events(&events, "actor:Alice"), vec![0, 2]);
+        assert_eq!(filter_org_events(&events, "action:org."), vec![1, 2]);
+        assert_eq!(
+            filter_org_events(&events, "actor:alice action:org"),
+            vec![2]
+        );
+        assert_eq!(filter_org_events(&events, "api"), vec![0]);
+    }
+
+    #[test]
+    fn split_size_query_parses_lists_and_comparisons() {
+        use PrSize::{L, M, S, Xl, Xs};
+        assert_eq!(split_size_query("fix"), ("fix".to_owned(), None));
+        assert_eq!(
+            split_size_query("size:xs,S parser"),
+            ("parser".to_owned(), Some(vec![Xs, S]))
+        );
+        assert_eq!(split_size_query("size:>=l").1, Some(vec![L, Xl]));
+        assert_eq!(split_size_query("size:<m").1, Some(vec![Xs, S]));
+        assert_eq!(split_size_query("size:>s size:<=l").1, Some(vec![M, L]));
+        assert_eq!(
+            split_size_query("size:huge"),
+            ("size:huge".to_owned(), None)
+        );
+    }
 }
@@ -9,6 +9,12 @@ use crate::github::types::RepoRef;
 /// Parses both SSH (`git@github.com:owner/repo.git`) and HTTPS
 /// (`https://github.com/owner/repo.git`) URL formats.
 pub fn detect_repo(path: &Path) -> Option<RepoRef> {
+    detect_remote(path).map(|(repo, _)| repo)
+}
+
+/// Like [`detect_repo`], with the remote's host: `None` for github.com,
+/// as filters and the engine name it.
+pub fn detect_remote(path: &Path) -> Option<(RepoRef, Option<String>)> {
     let url = remote_url(path, "origin").or_else(|| {
         let first = first_remote_name(path)?;
         remote_url(path, &first)
@@ -48,22 +54,22 @@ fn first_remote_name(path: &Path) -> Option<String> {
     if name.is_empty() { None } else { Some(name) }
 }
 
-/// Parse `owner/repo` from an SSH or HTTPS remote URL.
-fn parse_remote_url(url: &str) -> Option<RepoRef> {
-    let slug = if let Some(rest) = url.strip_prefix("git@") {
+/// Parse `owner/repo` and the host from an SSH or HTTPS remote URL.
+fn parse_remote_url(url: &str) -> Option<(RepoRef, Option<String>)> {
+    let (host, slug) = if let Some(rest) = url.strip_prefix("git@") {
         // SSH: git@github.com:owner/repo.git
-        rest.split_once(':')?.1
+        rest.split_once(':')?
     } else if url.starts_with("https://") || url.starts_with("http://") {
         // HTTPS: https://github.com/owner/repo.git
-        // Skip scheme + host: find the third '/'
         let after_scheme = url.split_once("://")?.1;
-        after_scheme.split_once('/')?.1
+        after_scheme.split_once('/')?
     } else {
         return None;
     };
 
     let slug = slug.strip_suffix(".git").unwrap_or(slug);
-    RepoRef::from_full_name(slug)
+    let repo = RepoRef::from_full_name(slug)?;
+    Some((repo, crate::url::normalize_host(host)))
 }
 
 #[cfg(test)]
@@ -72,28 +78,39 @@ mod tests {
 
     #[test]
     fn parse_ssh_url() {
-        let r = parse_remote_url("git@github.com:graelo/gh-board.git").unwrap();
+        let (r, host) = parse_remote_url("git@github.com:graelo/gh-board.git").unwrap();
         assert_eq!(r.owner, "graelo");
         assert_eq!(r.name, "gh-board");
+        assert_eq!(host, None);
+    }
+
+    #[test]
+    fn parse_enterprise_urls_keep_the_host() {
+        let (r, host) = parse_remote_url("git@GHE.acme.com:team/app.git").unwrap();
+        assert_eq!(r.full_name(), "team/app");
+        assert_eq!(host.as_deref(), Some("ghe.acme.com"));
+        let (r, host) = parse_remote_url("https://bot@acme.ghe.com/team/app").unwrap();
+        assert_eq!(r.full_name(), "team/app");
+        assert_eq!(host.as_deref(), Some("acme.ghe.com"));
     }
 
     #[test]
     fn parse_ssh_url_no_git_suffix() {
-        let r = parse_remote_url("git@github.com:graelo/gh-board").unwrap();
+        let (r, _) = parse_remote_url("git@github.com:graelo/gh-board").unwrap();
         assert_eq!(r.owner, "graelo");
         assert_eq!(r.name, "gh-board");
     }
 
     #[test]
     fn parse_https_url() {
-        let r = parse_remote_url("https://github.com/graelo/gh-board.git").unwrap();
+        let (r, _) = parse_remote_url("https://github.com/graelo/gh-board.git").unwrap();
         assert_eq!(r.owner, "graelo");
         assert_eq!(r.name, "gh-board");
     }
 
     #[test]
     fn parse_https_url_no_git_suffix() {
-        let r = parse_remote_url("https://github.com/graelo/gh-board").unwrap();
+        let (r, _) = parse_remote_url("https://github.com/graelo/gh-board").unwrap();
         assert_eq!(r.owner, "graelo");
         assert_eq!(r.name, "gh-board");
     }
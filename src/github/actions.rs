@@ -1,7 +1,10 @@
+use std::collections::HashSet;
 use std::fmt::Write as _;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
 use octocrab::Octocrab;
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
@@ -9,7 +12,8 @@ use serde_json::Value as JsonValue;
 use crate::config::types::ActionsFilter;
 use crate::github::client::extract_rest_rate_limit;
 use crate::types::{
-    Actor, JobStep, RateLimitInfo, RunConclusion, RunStatus, WorkflowJob, WorkflowRun,
+    Actor, CleanupReason, CommitSignature, ComparedCommit, JobStatusChange, JobStep, RateLimitInfo,
+    RunCleanupCandidate, RunComparison, RunConclusion, RunStatus, WorkflowJob, WorkflowRun,
 };
 
 // ---------------------------------------------------------------------------
@@ -25,6 +29,8 @@ struct RawWorkflowRunsResponse {
 struct RawWorkflowRun {
     id: u64,
     #[serde(default)]
+    workflow_id: u64,
+    #[serde(default)]
     name: Option<String>,
     #[serde(default)]
     display_title: String,
@@ -37,7 +43,19 @@ struct RawWorkflowRun {
     #[serde(default)]
     head_branch: Option<String>,
     #[serde(default)]
+    head_sha: String,
+    #[serde(default)]
+    head_repository: Option<RawRepoRef>,
+    #[serde(default)]
+    repository: Option<RawRepoRef>,
+    #[serde(default)]
     actor: Option<RawActor>,
+    #[serde(default)]
+    triggering_actor: Option<RawActor>,
+    #[serde(default)]
+    head_commit: Option<RawHeadCommit>,
+    #[serde(default)]
+    pull_requests: Vec<RawRunPullRequest>,
     run_number: u64,
     #[serde(default)]
     html_url: String,
@@ -47,6 +65,22 @@ struct RawWorkflowRun {
     run_started_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+#[derive(Deserialize)]
+struct RawRepoRef {
+    full_name: String,
+}
+
+#[derive(Deserialize)]
+struct RawHeadCommit {
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct RawRunPullRequest {
+    number: u64,
+}
+
 #[derive(Deserialize)]
 struct RawActor {
     login: String,
@@ -84,6 +118,7 @@ fn parse_conclusion(s: &str) -> RunConclusion {
 fn into_domain(raw: RawWorkflowRun) -> WorkflowRun {
     WorkflowRun {
         id: raw.id,
+        workflow_id: raw.workflow_id,
         name: raw.name.unwrap_or_default(),
         display_title: raw.display_title,
         status: raw
@@ -93,10 +128,19 @@ fn into_domain(raw: RawWorkflowRun) -> WorkflowRun {
         conclusion: raw.conclusion.as_deref().map(parse_conclusion),
         event: raw.event,
         head_branch: raw.head_branch,
+        head_sha: raw.head_sha,
+        head_repository: raw.head_repository.map(|r| r.full_name),
+        repository: raw.repository.map(|r| r.full_name),
         actor: raw.actor.map(|a| Actor {
             login: a.login,
             avatar_url: a.avatar_url,
         }),
+        triggering_actor: raw.triggering_actor.map(|a| Actor {
+            login: a.login,
+            avatar_url: a.avatar_url,
+        }),
+        head_commit_message: raw.head_commit.map(|c| c.message),
+        pull_requests: raw.pull_requests.into_iter().map(|pr| pr.number).collect(),
         run_number: raw.run_number,
         html_url: raw.html_url,
         created_at: raw.created_at,
@@ -154,17 +198,122 @@ struct RawStep {
 // Fetch workflow runs
 // ---------------------------------------------------------------------------
 
-/// Fetch workflow runs for the repository described by `filter`.
+/// Most repositories a multi-repo tab fetches runs for.
+const MAX_MULTI_REPOS: usize = 50;
+
+/// Fetch workflow runs for the repository (or repositories) described by
+/// `filter`.
 pub async fn fetch_workflow_runs(
     octocrab: &Arc<Octocrab>,
     filter: &ActionsFilter,
 ) -> Result<(Vec<WorkflowRun>, Option<RateLimitInfo>)> {
-    let (owner, repo) = filter.repo.split_once('/').with_context(|| {
-        format!(
-            "invalid repo format {:?} — expected owner/repo",
-            filter.repo
-        )
-    })?;
+    if filter.repos.is_empty() {
+        fetch_repo_runs(octocrab, &filter.repo, filter).await
+    } else {
+        fetch_multi_repo_runs(octocrab, filter).await
+    }
+}
+
+/// Fetch the runs of every repository in `filter.repos`, expanding `org:`
+/// entries, and merge them newest first.
+///
+/// A repository that fails (Actions disabled, no access) is skipped; the
+/// fetch only fails when every repository does.
+async fn fetch_multi_repo_runs(
+    octocrab: &Arc<Octocrab>,
+    filter: &ActionsFilter,
+) -> Result<(Vec<WorkflowRun>, Option<RateLimitInfo>)> {
+    let mut repos: Vec<String> = Vec::new();
+    for entry in &filter.repos {
+        if let Some(org) = entry.strip_prefix("org:") {
+            for repo in list_org_repos(octocrab, org.trim()).await? {
+                if !repos.contains(&repo) {
+                    repos.push(repo);
+                }
+            }
+        } else if !repos.contains(entry) {
+            repos.push(entry.clone());
+        }
+    }
+    if repos.len() > MAX_MULTI_REPOS {
+        tracing::warn!(
+            "actions: {:?} covers {} repos, fetching the first {MAX_MULTI_REPOS}",
+            filter.title,
+            repos.len()
+        );
+        repos.truncate(MAX_MULTI_REPOS);
+    }
+
+    let mut per_repo = Vec::with_capacity(repos.len());
+    let mut rate_limit = None;
+    let mut first_error = None;
+    for repo in &repos {
+        match fetch_repo_runs(octocrab, repo, filter).await {
+            Ok((runs, rl)) => {
+                per_repo.push(runs);
+                rate_limit = rl.or(rate_limit);
+            }
+            Err(e) => {
+                tracing::warn!("actions: skipping {repo}: {e:#}");
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    if per_repo.is_empty()
+        && let Some(e) = first_error
+    {
+        return Err(e);
+    }
+    let limit = filter.limit.unwrap_or(30).min(100) as usize;
+    Ok((merge_runs(per_repo, limit), rate_limit))
+}
+
+/// Merge per-repository run lists newest first, keeping `limit` runs.
+fn merge_runs(per_repo: Vec<Vec<WorkflowRun>>, limit: usize) -> Vec<WorkflowRun> {
+    let mut runs: Vec<WorkflowRun> = per_repo.into_iter().flatten().collect();
+    runs.sort_by_key(|r| std::cmp::Reverse(r.created_at));
+    runs.truncate(limit);
+    runs
+}
+
+#[derive(Deserialize)]
+struct RawOrgRepo {
+    full_name: String,
+    #[serde(default)]
+    archived: bool,
+}
+
+/// `owner/repo` names of an organization's unarchived repositories, most
+/// recently pushed first.
+async fn list_org_repos(octocrab: &Arc<Octocrab>, org: &str) -> Result<Vec<String>> {
+    let url = format!("/orgs/{org}/repos?sort=pushed&per_page={MAX_MULTI_REPOS}");
+    let response = octocrab
+        ._get(url)
+        .await
+        .with_context(|| format!("listing repositories of {org}"))?;
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading organization repositories body")?;
+    let repos: Vec<RawOrgRepo> =
+        serde_json::from_str(&body).context("deserializing organization repositories")?;
+    Ok(repos
+        .into_iter()
+        .filter(|r| !r.archived)
+        .map(|r| r.full_name)
+        .collect())
+}
+
+/// Fetch the workflow runs of one `owner/repo`, with `filter`'s query
+/// parameters.
+async fn fetch_repo_runs(
+    octocrab: &Arc<Octocrab>,
+    full_repo: &str,
+    filter: &ActionsFilter,
+) -> Result<(Vec<WorkflowRun>, Option<RateLimitInfo>)> {
+    let (owner, repo) = full_repo
+        .split_once('/')
+        .with_context(|| format!("invalid repo format {full_repo:?} — expected owner/repo"))?;
 
     let per_page = filter.limit.unwrap_or(30).min(100);
 
@@ -175,6 +324,10 @@ pub async fn fetch_workflow_runs(
     if let Some(ref event) = filter.event {
         write!(qs, "&event={event}").expect("write to String is infallible");
     }
+    if let Some(ref branch) = filter.branch {
+        write!(qs, "&branch={}", encode_query_value(branch))
+            .expect("write to String is infallible");
+    }
 
     let url = format!("/repos/{owner}/{repo}/actions/runs?{qs}");
     let response = octocrab._get(url).await.context("fetching workflow runs")?;
@@ -236,6 +389,45 @@ pub async fn fetch_run_jobs(
     Ok((jobs, rate_limit))
 }
 
+/// Fetch a job's status and plain-text log.
+///
+/// The log is `None` while GitHub has not published it yet, which happens
+/// until the job has been running for a while.
+pub async fn fetch_job_log(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    job_id: u64,
+) -> Result<(RunStatus, Option<String>)> {
+    let job: RawJob = octocrab
+        .get(
+            format!("/repos/{owner}/{repo}/actions/jobs/{job_id}"),
+            None::<&()>,
+        )
+        .await
+        .context("fetching job")?;
+    let status = job
+        .status
+        .as_deref()
+        .map_or(RunStatus::Unknown, parse_status);
+    // Redirects to a short-lived download URL.
+    let response = octocrab
+        ._get(format!("/repos/{owner}/{repo}/actions/jobs/{job_id}/logs"))
+        .await
+        .context("fetching job log")?;
+    if response.status() == http::StatusCode::NOT_FOUND && status != RunStatus::Completed {
+        return Ok((status, None));
+    }
+    let response = octocrab::map_github_error(response)
+        .await
+        .context("fetching job log")?;
+    let log = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading job log")?;
+    Ok((status, Some(log)))
+}
+
 /// Fetch a single workflow run by ID.
 pub async fn fetch_run_by_id(
     octocrab: &Arc<Octocrab>,
@@ -281,6 +473,42 @@ pub async fn rerun_workflow_run(
     Ok(())
 }
 
+/// Re-run one job of a workflow run.
+pub async fn rerun_job(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    job_id: u64,
+) -> Result<()> {
+    let route = format!("/repos/{owner}/{repo}/actions/jobs/{job_id}/rerun");
+    let _: JsonValue = octocrab
+        .post(route, None::<&()>)
+        .await
+        .context("rerunning job")?;
+    Ok(())
+}
+
+/// Approve a workflow run held for a maintainer's approval, as GitHub holds
+/// those of first-time contributors' forks.
+pub async fn approve_workflow_run(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+) -> Result<()> {
+    let response = octocrab
+        ._post(
+            format!("/repos/{owner}/{repo}/actions/runs/{run_id}/approve"),
+            None::<&()>,
+        )
+        .await
+        .context("approving workflow run")?;
+    octocrab::map_github_error(response)
+        .await
+        .context("approving workflow run")?;
+    Ok(())
+}
+
 /// Cancel a workflow run.
 pub async fn cancel_workflow_run(
     octocrab: &Arc<Octocrab>,
@@ -295,3 +523,572 @@ pub async fn cancel_workflow_run(
         .context("cancelling workflow run")?;
     Ok(())
 }
+
+#[derive(Deserialize)]
+struct RawRepoInfo {
+    default_branch: String,
+}
+
+/// Trigger a `workflow_dispatch` run of `workflow` (file name or id) on
+/// `git_ref`, or on the repository's default branch. Returns the ref used.
+pub async fn dispatch_workflow(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    workflow: &str,
+    git_ref: Option<&str>,
+    inputs: &IndexMap<String, String>,
+) -> Result<String> {
+    let git_ref = if let Some(r) = git_ref {
+        r.to_owned()
+    } else {
+        let info: RawRepoInfo = octocrab
+            .get(format!("/repos/{owner}/{repo}"), None::<&()>)
+            .await
+            .context("fetching default branch")?;
+        info.default_branch
+    };
+    let payload = serde_json::json!({ "ref": git_ref, "inputs": inputs });
+    let response = octocrab
+        ._post(
+            format!("/repos/{owner}/{repo}/actions/workflows/{workflow}/dispatches"),
+            Some(&payload),
+        )
+        .await
+        .context("dispatching workflow")?;
+    octocrab::map_github_error(response)
+        .await
+        .context("dispatching workflow")?;
+    Ok(git_ref)
+}
+
+// ---------------------------------------------------------------------------
+// Run retention cleanup
+// ---------------------------------------------------------------------------
+
+/// Pages of 100 fetched per listing; bounds the API cost of one dry-run.
+const CLEANUP_MAX_PAGES: u32 = 3;
+
+/// Runs deleted per confirmation.
+pub const CLEANUP_BATCH: usize = 50;
+
+/// Runs deleted per engine request. Deletes are sequential, so this keeps
+/// each request well inside the engine's request timeout.
+pub const CLEANUP_CHUNK: usize = 10;
+
+#[derive(Deserialize)]
+struct RawRefName {
+    name: String,
+}
+
+/// Fetch completed runs, newest first, optionally restricted to runs created
+/// before `created_before`.
+async fn fetch_completed_runs(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    created_before: Option<DateTime<Utc>>,
+    max_pages: u32,
+) -> Result<(Vec<WorkflowRun>, Option<RateLimitInfo>)> {
+    let mut runs = Vec::new();
+    let mut rate_limit = None;
+    for page in 1..=max_pages {
+        let mut url =
+            format!("/repos/{owner}/{repo}/actions/runs?status=completed&per_page=100&page={page}");
+        if let Some(before) = created_before {
+            write!(url, "&created=<{}", before.format("%Y-%m-%d"))
+                .expect("write to String is infallible");
+        }
+        let response = octocrab
+            ._get(url)
+            .await
+            .context("fetching workflow runs for cleanup")?;
+        rate_limit = extract_rest_rate_limit(response.headers()).or(rate_limit);
+        let body = octocrab
+            .body_to_string(response)
+            .await
+            .context("reading workflow runs body")?;
+        let parsed: RawWorkflowRunsResponse =
+            serde_json::from_str(&body).context("deserializing workflow runs")?;
+        let last_page = parsed.workflow_runs.len() < 100;
+        runs.extend(parsed.workflow_runs.into_iter().map(into_domain));
+        if last_page {
+            break;
+        }
+    }
+    Ok((runs, rate_limit))
+}
+
+/// Fetch the names of the branches (`kind = "branches"`) or tags
+/// (`kind = "tags"`) of a repository, up to `CLEANUP_MAX_PAGES` pages, and
+/// whether that was all of them.
+async fn fetch_ref_names(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    kind: &str,
+) -> Result<(HashSet<String>, bool)> {
+    let mut names = HashSet::new();
+    for page in 1..=CLEANUP_MAX_PAGES {
+        let url = format!("/repos/{owner}/{repo}/{kind}?per_page=100&page={page}");
+        let response = octocrab
+            ._get(url)
+            .await
+            .with_context(|| format!("fetching {kind}"))?;
+        let body = octocrab
+            .body_to_string(response)
+            .await
+            .with_context(|| format!("reading {kind} body"))?;
+        let parsed: Vec<RawRefName> =
+            serde_json::from_str(&body).with_context(|| format!("deserializing {kind}"))?;
+        let last_page = parsed.len() < 100;
+        names.extend(parsed.into_iter().map(|r| r.name));
+        if last_page {
+            return Ok((names, true));
+        }
+    }
+    Ok((names, false))
+}
+
+/// Fetch the branch and tag names of a repository, each list sorted, for
+/// scoping runs to one of them. Large repos are listed up to
+/// `CLEANUP_MAX_PAGES` pages of each.
+pub async fn fetch_repo_refs(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+) -> Result<(Vec<String>, Vec<String>)> {
+    let sorted = |names: HashSet<String>| {
+        let mut names: Vec<String> = names.into_iter().collect();
+        names.sort_unstable();
+        names
+    };
+    let (branches, _) = fetch_ref_names(octocrab, owner, repo, "branches").await?;
+    let (tags, _) = fetch_ref_names(octocrab, owner, repo, "tags").await?;
+    Ok((sorted(branches), sorted(tags)))
+}
+
+/// Pick the runs to delete: those created before `cutoff`, and those whose
+/// head branch is no longer among `refs`.
+///
+/// Runs from forks are only removed by age, since their branches live
+/// elsewhere, and so are all runs when `refs` is `None`: a partial listing
+/// cannot tell a deleted branch from one past its end. Candidates are
+/// returned oldest first, without duplicates.
+pub fn select_cleanup_candidates(
+    runs: Vec<WorkflowRun>,
+    cutoff: DateTime<Utc>,
+    refs: Option<&HashSet<String>>,
+    repo_slug: &str,
+) -> Vec<RunCleanupCandidate> {
+    let mut seen = HashSet::new();
+    let mut candidates: Vec<RunCleanupCandidate> = runs
+        .into_iter()
+        .filter(|run| seen.insert(run.id))
+        .filter_map(|run| {
+            let same_repo = run
+                .head_repository
+                .as_deref()
+                .is_some_and(|r| r.eq_ignore_ascii_case(repo_slug));
+            let reason = if run.created_at < cutoff {
+                CleanupReason::Expired
+            } else if same_repo
+                && let Some(refs) = refs
+                && run
+                    .head_branch
+                    .as_deref()
+                    .is_some_and(|b| !refs.contains(b))
+            {
+                CleanupReason::BranchDeleted
+            } else {
+                return None;
+            };
+            Some(RunCleanupCandidate { run, reason })
+        })
+        .collect();
+    candidates.sort_by_key(|c| c.run.created_at);
+    candidates
+}
+
+/// Dry-run of the retention cleanup: list the completed runs of
+/// `owner/repo` that are older than `older_than_days` or belong to a deleted
+/// branch. Nothing is deleted.
+pub async fn plan_run_cleanup(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    older_than_days: u32,
+) -> Result<(Vec<RunCleanupCandidate>, Option<RateLimitInfo>)> {
+    let cutoff = Utc::now() - chrono::Duration::days(i64::from(older_than_days));
+    let (mut runs, _) =
+        fetch_completed_runs(octocrab, owner, repo, Some(cutoff), CLEANUP_MAX_PAGES).await?;
+    let (recent, rate_limit) = fetch_completed_runs(octocrab, owner, repo, None, 1).await?;
+    runs.extend(recent);
+
+    let (mut refs, all_branches) = fetch_ref_names(octocrab, owner, repo, "branches").await?;
+    let (tags, all_tags) = fetch_ref_names(octocrab, owner, repo, "tags").await?;
+    refs.extend(tags);
+    let refs = (all_branches && all_tags).then_some(&refs);
+
+    let candidates = select_cleanup_candidates(runs, cutoff, refs, &format!("{owner}/{repo}"));
+    Ok((candidates, rate_limit))
+}
+
+/// Delete a workflow run and its logs.
+pub async fn delete_workflow_run(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+) -> Result<()> {
+    let response = octocrab
+        ._delete(
+            format!("/repos/{owner}/{repo}/actions/runs/{run_id}"),
+            None::<&()>,
+        )
+        .await
+        .context("deleting workflow run")?;
+    octocrab::map_github_error(response)
+        .await
+        .context("deleting workflow run")?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Compare with the last successful run
+// ---------------------------------------------------------------------------
+
+/// Commits listed in a comparison; the compare API returns up to 250.
+const COMPARE_MAX_COMMITS: usize = 20;
+
+#[derive(Deserialize)]
+struct RawCompareResponse {
+    #[serde(default)]
+    total_commits: usize,
+    #[serde(default)]
+    commits: Vec<RawCompareCommit>,
+}
+
+#[derive(Deserialize)]
+struct RawCompareCommit {
+    sha: String,
+    commit: RawCommitDetail,
+    #[serde(default)]
+    author: Option<RawActor>,
+}
+
+#[derive(Deserialize)]
+struct RawCommitDetail {
+    message: String,
+    #[serde(default)]
+    verification: Option<RawVerification>,
+}
+
+#[derive(Deserialize)]
+struct RawVerification {
+    reason: String,
+}
+
+/// Percent-encode a query-string value (branch names may contain `#`, `&`, …).
+fn encode_query_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~' | b'/') {
+            out.push(char::from(byte));
+        } else {
+            write!(out, "%{byte:02X}").expect("write to String is infallible");
+        }
+    }
+    out
+}
+
+/// Jobs whose conclusion differs between `baseline` and `current`, in the
+/// current run's job order, followed by jobs that only ran in the baseline.
+pub fn diff_job_conclusions(
+    baseline: &[WorkflowJob],
+    current: &[WorkflowJob],
+) -> Vec<JobStatusChange> {
+    let mut changes: Vec<JobStatusChange> = current
+        .iter()
+        .filter_map(|job| {
+            let before = baseline
+                .iter()
+                .find(|b| b.name == job.name)
+                .map(|b| b.conclusion);
+            match before {
+                Some(before) if before == job.conclusion => None,
+                Some(before) => Some(JobStatusChange {
+                    name: job.name.clone(),
+                    before,
+                    after: job.conclusion,
+                }),
+                None => Some(JobStatusChange {
+                    name: job.name.clone(),
+                    before: None,
+                    after: job.conclusion,
+                }),
+            }
+        })
+        .collect();
+    changes.extend(
+        baseline
+            .iter()
+            .filter(|b| !current.iter().any(|j| j.name == b.name))
+            .map(|b| JobStatusChange {
+                name: b.name.clone(),
+                before: b.conclusion,
+                after: None,
+            }),
+    );
+    changes
+}
+
+/// Find the most recent successful run of `run`'s workflow on the same
+/// branch that started before it.
+async fn fetch_last_success(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    run: &WorkflowRun,
+) -> Result<Option<WorkflowRun>> {
+    let mut url = format!(
+        "/repos/{owner}/{repo}/actions/workflows/{}/runs?status=success&per_page=30",
+        run.workflow_id
+    );
+    if let Some(ref branch) = run.head_branch {
+        write!(url, "&branch={}", encode_query_value(branch))
+            .expect("write to String is infallible");
+    }
+    let response = octocrab
+        ._get(url)
+        .await
+        .context("fetching successful runs")?;
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading successful runs body")?;
+    let parsed: RawWorkflowRunsResponse =
+        serde_json::from_str(&body).context("deserializing successful runs")?;
+    Ok(parsed
+        .workflow_runs
+        .into_iter()
+        .map(into_domain)
+        .find(|r| r.id != run.id && r.created_at < run.created_at))
+}
+
+/// Commits reachable from `head` but not from `base`, newest first.
+async fn fetch_commits_between(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    base: &str,
+    head: &str,
+) -> Result<(Vec<ComparedCommit>, usize)> {
+    let url = format!("/repos/{owner}/{repo}/compare/{base}...{head}");
+    let response = octocrab._get(url).await.context("comparing commits")?;
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading compare body")?;
+    let parsed: RawCompareResponse =
+        serde_json::from_str(&body).context("deserializing compare")?;
+    let commits = parsed
+        .commits
+        .into_iter()
+        .rev()
+        .take(COMPARE_MAX_COMMITS)
+        .map(|c| ComparedCommit {
+            sha: c.sha,
+            title: c
+                .commit
+                .message
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_owned(),
+            author: c.author.map(|a| a.login),
+            signature: c
+                .commit
+                .verification
+                .map_or(CommitSignature::Unsigned, |v| {
+                    CommitSignature::from_state(&v.reason)
+                }),
+        })
+        .collect();
+    Ok((commits, parsed.total_commits))
+}
+
+/// Compare `run` with the last successful run of the same workflow and
+/// branch. `None` when no earlier successful run exists.
+pub async fn compare_with_last_success(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    run: &WorkflowRun,
+) -> Result<(Option<RunComparison>, Option<RateLimitInfo>)> {
+    let Some(baseline) = fetch_last_success(octocrab, owner, repo, run).await? else {
+        return Ok((None, None));
+    };
+    let (baseline_jobs, _) = fetch_run_jobs(octocrab, owner, repo, baseline.id).await?;
+    let (jobs, rate_limit) = fetch_run_jobs(octocrab, owner, repo, run.id).await?;
+    let (commits, total_commits) = if baseline.head_sha == run.head_sha {
+        (Vec::new(), 0)
+    } else {
+        fetch_commits_between(octocrab, owner, repo, &baseline.head_sha, &run.head_sha).await?
+    };
+    let duration_delta_secs = run
+        .duration_secs()
+        .zip(baseline.duration_secs())
+        .map(|(cur, base)| cur - base);
+    let comparison = RunComparison {
+        job_changes: diff_job_conclusions(&baseline_jobs, &jobs),
+        baseline,
+        commits,
+        total_commits,
+        duration_delta_secs,
+    };
+    Ok((Some(comparison), rate_limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(id: u64, days_ago: i64, branch: &str, head_repo: &str) -> WorkflowRun {
+        let created_at = Utc::now() - chrono::Duration::days(days_ago);
+        WorkflowRun {
+            id,
+            workflow_id: 1,
+            name: "CI".to_owned(),
+            display_title: String::new(),
+            status: RunStatus::Completed,
+            conclusion: Some(RunConclusion::Success),
+            event: "push".to_owned(),
+            head_branch: Some(branch.to_owned()),
+            head_sha: String::new(),
+            head_repository: Some(head_repo.to_owned()),
+            repository: None,
+            actor: None,
+            triggering_actor: None,
+            head_commit_message: None,
+            pull_requests: Vec::new(),
+            run_number: id,
+            html_url: String::new(),
+            created_at,
+            updated_at: created_at,
+            run_started_at: None,
+        }
+    }
+
+    fn job(name: &str, conclusion: RunConclusion) -> WorkflowJob {
+        WorkflowJob {
+            id: 0,
+            name: name.to_owned(),
+            status: RunStatus::Completed,
+            conclusion: Some(conclusion),
+            started_at: None,
+            completed_at: None,
+            html_url: String::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn job_diff_reports_changed_added_and_removed_jobs() {
+        let baseline = vec![
+            job("build", RunConclusion::Success),
+            job("test", RunConclusion::Success),
+            job("lint", RunConclusion::Success),
+        ];
+        let current = vec![
+            job("build", RunConclusion::Success),
+            job("test", RunConclusion::Failure),
+            job("docs", RunConclusion::Failure),
+        ];
+        let changes = diff_job_conclusions(&baseline, &current);
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|c| (c.name.as_str(), c.before, c.after))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "test",
+                    Some(RunConclusion::Success),
+                    Some(RunConclusion::Failure)
+                ),
+                ("docs", None, Some(RunConclusion::Failure)),
+                ("lint", Some(RunConclusion::Success), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn run_keeps_head_commit_prs_and_triggering_actor() {
+        let raw: RawWorkflowRun = serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "run_number": 3,
+            "event": "pull_request",
+            "actor": {"login": "alice"},
+            "triggering_actor": {"login": "bob"},
+            "head_commit": {"id": "abc", "message": "Fix the parser\n\nDetails"},
+            "pull_requests": [{"number": 42, "head": {"ref": "fix"}}],
+            "created_at": "2024-05-01T10:00:00Z",
+            "updated_at": "2024-05-01T10:05:00Z",
+        }))
+        .unwrap();
+        let run = into_domain(raw);
+        assert_eq!(
+            run.head_commit_message.as_deref(),
+            Some("Fix the parser\n\nDetails")
+        );
+        assert_eq!(run.pull_requests, [42]);
+        assert_eq!(
+            run.triggering_actor.map(|a| a.login).as_deref(),
+            Some("bob")
+        );
+    }
+
+    #[test]
+    fn merge_runs_orders_newest_first_across_repos() {
+        let per_repo = vec![
+            vec![run(1, 1, "main", "a/x"), run(2, 5, "main", "a/x")],
+            vec![run(3, 0, "main", "a/y"), run(4, 3, "main", "a/y")],
+        ];
+        let ids: Vec<u64> = merge_runs(per_repo, 3).iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![3, 1, 4]);
+    }
+
+    #[test]
+    fn query_values_are_percent_encoded() {
+        assert_eq!(encode_query_value("feat/a#1 b"), "feat/a%231%20b");
+    }
+
+    #[test]
+    fn cleanup_selects_expired_and_deleted_branch_runs() {
+        let refs: HashSet<String> = ["main".to_owned()].into();
+        let runs = vec![
+            run(1, 2, "main", "o/r"),
+            run(2, 100, "main", "o/r"),
+            run(3, 3, "gone", "o/r"),
+            run(4, 3, "gone", "fork/r"),
+            run(2, 100, "main", "o/r"),
+        ];
+        let cutoff = Utc::now() - chrono::Duration::days(90);
+        let picked = select_cleanup_candidates(runs.clone(), cutoff, Some(&refs), "O/R");
+        let summary: Vec<_> = picked.iter().map(|c| (c.run.id, c.reason)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (2, CleanupReason::Expired),
+                (3, CleanupReason::BranchDeleted)
+            ]
+        );
+
+        // Past the listed pages, `gone` may be a live branch: age only.
+        let picked = select_cleanup_candidates(runs, cutoff, None, "O/R");
+        let summary: Vec<_> = picked.iter().map(|c| (c.run.id, c.reason)).collect();
+        assert_eq!(summary, vec![(2, CleanupReason::Expired)]);
+    }
+}
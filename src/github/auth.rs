@@ -5,33 +5,47 @@ use anyhow::{Context, Result, bail};
 /// Resolve a GitHub auth token for the given host.
 ///
 /// Priority:
-/// 1. `gh auth token --hostname {host}` (gh CLI)
-/// 2. `GH_TOKEN` environment variable
-/// 3. `GITHUB_TOKEN` environment variable
-pub fn resolve_token(host: &str) -> Result<String> {
-    // Try gh CLI first.
-    if let Ok(token) = token_from_gh_cli(host) {
+/// 1. the variable named by the host's `token_env`, when configured
+/// 2. `gh auth token --hostname {host}` (gh CLI)
+/// 3. the variables of [`fallback_vars`]
+pub fn resolve_token(host: &str, token_env: Option<&str>) -> Result<String> {
+    let var = |name: &str| std::env::var(name).ok().filter(|token| !token.is_empty());
+    if let Some(token) = token_env.and_then(var) {
         return Ok(token);
     }
 
-    // Fall back to environment variables.
-    if let Ok(token) = std::env::var("GH_TOKEN")
-        && !token.is_empty()
-    {
+    // Then gh CLI.
+    if let Ok(token) = token_from_gh_cli(host) {
         return Ok(token);
     }
-    if let Ok(token) = std::env::var("GITHUB_TOKEN")
-        && !token.is_empty()
-    {
+
+    // Fall back to environment variables.
+    if let Some(token) = fallback_vars(host).iter().find_map(|name| var(name)) {
         return Ok(token);
     }
 
+    let vars = fallback_vars(host).join(" / ");
     bail!(
         "no GitHub token found for host \"{host}\". \
-         Run `gh auth login` or set GH_TOKEN / GITHUB_TOKEN."
+         Run `gh auth login --hostname {host}` or set {vars}."
     )
 }
 
+/// Environment variables read when `gh` has no token, in order. Enterprise
+/// hosts try gh's `GH_ENTERPRISE_TOKEN` first.
+pub(crate) fn fallback_vars(host: &str) -> &'static [&'static str] {
+    if host == "github.com" {
+        &["GH_TOKEN", "GITHUB_TOKEN"]
+    } else {
+        &[
+            "GH_ENTERPRISE_TOKEN",
+            "GITHUB_ENTERPRISE_TOKEN",
+            "GH_TOKEN",
+            "GITHUB_TOKEN",
+        ]
+    }
+}
+
 fn token_from_gh_cli(host: &str) -> Result<String> {
     let output = Command::new("gh")
         .args(["auth", "token", "--hostname", host])
@@ -53,3 +67,15 @@ fn token_from_gh_cli(host: &str) -> Result<String> {
 
     Ok(token)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enterprise_hosts_read_enterprise_tokens_first() {
+        assert_eq!(fallback_vars("github.com"), ["GH_TOKEN", "GITHUB_TOKEN"]);
+        assert_eq!(fallback_vars("ghe.acme.com")[0], "GH_ENTERPRISE_TOKEN");
+        assert_eq!(fallback_vars("acme.ghe.com").last(), Some(&"GITHUB_TOKEN"));
+    }
+}
@@ -0,0 +1,285 @@
+//! Authentication problems noticed in API responses: a token about to
+//! expire, and organizations whose SAML SSO has not authorized it.
+//!
+//! Response headers are inspected as they pass ([`inspect`]). The footer of
+//! every view shows the first pending warning ([`footer_text`]) until the
+//! `authorize_sso` key opens the page resolving it ([`take_authorization`]).
+
+use std::sync::{Mutex, PoisonError};
+
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use http::header::HeaderMap;
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+/// Token expiry closer than this is reported.
+const EXPIRY_NOTICE: TimeDelta = TimeDelta::days(7);
+
+/// Where fine-grained and classic tokens are regenerated.
+const TOKEN_SETTINGS_URL: &str = "https://github.com/settings/tokens";
+
+/// An organization whose SAML SSO has not authorized the token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SsoChallenge {
+    pub org: String,
+    /// Page granting the token access to `org`.
+    pub url: String,
+}
+
+/// A parsed `X-GitHub-SSO` header.
+#[derive(Debug, PartialEq, Eq)]
+enum SsoHeader {
+    /// `required; url=…`: the request failed, the URL authorizes the token.
+    Required(SsoChallenge),
+    /// `partial-results; organizations=1,2`: results of these organizations
+    /// (by ID) were left out.
+    Partial(Vec<u64>),
+}
+
+#[derive(Debug)]
+struct AuthState {
+    token_expires_at: Option<DateTime<Utc>>,
+    sso: Vec<SsoChallenge>,
+    /// Organization IDs from `partial-results` headers, awaiting their login.
+    unresolved: Vec<u64>,
+    /// Logins of the organization IDs resolved so far.
+    logins: Vec<(u64, String)>,
+}
+
+static STATE: Mutex<AuthState> = Mutex::new(AuthState {
+    token_expires_at: None,
+    sso: Vec::new(),
+    unresolved: Vec::new(),
+    logins: Vec::new(),
+});
+
+fn with_state<T>(f: impl FnOnce(&mut AuthState) -> T) -> T {
+    f(&mut STATE.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+impl AuthState {
+    fn add_challenge(&mut self, challenge: SsoChallenge) {
+        if !self.sso.iter().any(|c| c.org == challenge.org) {
+            tracing::warn!(
+                "SAML SSO has not authorized the token for {}",
+                challenge.org
+            );
+            self.sso.push(challenge);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Header parsing
+// ---------------------------------------------------------------------------
+
+/// `GitHub-Authentication-Token-Expiration`, sent for tokens that expire:
+/// `2024-06-01 12:00:00 UTC` (or with a `+0000` offset).
+fn parse_expiration(value: &str) -> Option<DateTime<Utc>> {
+    if let Some(naive) = value.strip_suffix(" UTC") {
+        return NaiveDateTime::parse_from_str(naive, "%Y-%m-%d %H:%M:%S")
+            .ok()
+            .map(|dt| dt.and_utc());
+    }
+    DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S %z")
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Organization login in an SSO URL (`https://github.com/orgs/acme/sso?…`).
+fn org_of_url(url: &str) -> Option<&str> {
+    let (_, rest) = url.split_once("/orgs/")?;
+    rest.split('/').next().filter(|org| !org.is_empty())
+}
+
+fn parse_sso_header(value: &str) -> Option<SsoHeader> {
+    let (kind, params) = value.split_once(';')?;
+    let params = params.trim();
+    match kind.trim() {
+        "required" => {
+            let url = params.strip_prefix("url=")?;
+            Some(SsoHeader::Required(SsoChallenge {
+                org: org_of_url(url)?.to_owned(),
+                url: url.to_owned(),
+            }))
+        }
+        "partial-results" => {
+            let ids = params.strip_prefix("organizations=")?;
+            Some(SsoHeader::Partial(
+                ids.split(',')
+                    .filter_map(|id| id.trim().parse().ok())
+                    .collect(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Note the token expiry and SSO challenges `headers` carry.
+pub(crate) fn inspect(headers: &HeaderMap) {
+    let expiration = headers
+        .get("github-authentication-token-expiration")
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_expiration);
+    let sso = headers
+        .get("x-github-sso")
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_sso_header);
+    if expiration.is_none() && sso.is_none() {
+        return;
+    }
+    with_state(|state| {
+        if expiration.is_some() {
+            state.token_expires_at = expiration;
+        }
+        match sso {
+            Some(SsoHeader::Required(challenge)) => state.add_challenge(challenge),
+            Some(SsoHeader::Partial(ids)) => {
+                for id in ids {
+                    let login = state
+                        .logins
+                        .iter()
+                        .find(|(known, _)| *known == id)
+                        .map(|(_, login)| login.clone());
+                    match login {
+                        Some(org) => state.add_challenge(SsoChallenge {
+                            url: format!("https://github.com/orgs/{org}/sso"),
+                            org,
+                        }),
+                        None if !state.unresolved.contains(&id) => state.unresolved.push(id),
+                        None => {}
+                    }
+                }
+            }
+            None => {}
+        }
+    });
+}
+
+#[derive(Deserialize)]
+struct RawOrg {
+    login: String,
+}
+
+/// Look up the logins of organizations only known by ID so far, turning
+/// them into challenges. Lookups that fail are dropped silently.
+pub(crate) async fn resolve_orgs(octocrab: &Octocrab) {
+    let ids = with_state(|state| std::mem::take(&mut state.unresolved));
+    for id in ids {
+        let org = match octocrab
+            .get::<RawOrg, _, ()>(format!("/organizations/{id}"), None)
+            .await
+        {
+            Ok(org) => org.login,
+            Err(e) => {
+                tracing::debug!("resolving organization {id}: {e}");
+                continue;
+            }
+        };
+        with_state(|state| {
+            state.logins.push((id, org.clone()));
+            state.add_challenge(SsoChallenge {
+                url: format!("https://github.com/orgs/{org}/sso"),
+                org,
+            });
+        });
+    }
+}
+
+/// Whether an API error message is GitHub's SAML enforcement refusal.
+pub(crate) fn is_sso_error(message: &str) -> bool {
+    message.contains("organization SAML enforcement")
+}
+
+/// The warning every footer shows, if any: SSO first, then token expiry.
+pub(crate) fn footer_text(now: DateTime<Utc>) -> Option<String> {
+    with_state(|state| {
+        if let Some((first, rest)) = state.sso.split_first() {
+            let more = match rest.len() {
+                0 => String::new(),
+                n => format!(" (+{n} more)"),
+            };
+            return Some(format!("SSO: authorize the token for {}{more}", first.org));
+        }
+        let expires_at = state.token_expires_at?;
+        let left = expires_at - now;
+        if left > EXPIRY_NOTICE {
+            return None;
+        }
+        let date = expires_at.format("%Y-%m-%d");
+        Some(if left <= TimeDelta::zero() {
+            format!("Token expired on {date}")
+        } else if left < TimeDelta::days(1) {
+            format!("Token expires in {}h ({date})", left.num_hours().max(1))
+        } else {
+            format!("Token expires in {}d ({date})", left.num_days())
+        })
+    })
+}
+
+/// The page resolving the first warning, with what it is about: the first
+/// pending SSO challenge (which is dropped, to come back on the next refusal
+/// if still unauthorized), else the token settings when it expires soon.
+pub(crate) fn take_authorization(now: DateTime<Utc>) -> Option<(String, String)> {
+    with_state(|state| {
+        if !state.sso.is_empty() {
+            let challenge = state.sso.remove(0);
+            return Some((
+                challenge.url,
+                format!("SSO authorization for {}", challenge.org),
+            ));
+        }
+        state
+            .token_expires_at
+            .filter(|at| *at - now <= EXPIRY_NOTICE)
+            .map(|_| (TOKEN_SETTINGS_URL.to_owned(), "token settings".to_owned()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_token_expiration_formats() {
+        let expected = "2024-06-01T12:00:00+00:00";
+        for value in ["2024-06-01 12:00:00 UTC", "2024-06-01 14:00:00 +0200"] {
+            assert_eq!(
+                parse_expiration(value).map(|dt| dt.to_rfc3339()).as_deref(),
+                Some(expected)
+            );
+        }
+        assert!(parse_expiration("soon").is_none());
+    }
+
+    #[test]
+    fn parses_sso_headers() {
+        assert_eq!(
+            parse_sso_header(
+                "required; url=https://github.com/orgs/acme/sso?authorization_request=ABC"
+            ),
+            Some(SsoHeader::Required(SsoChallenge {
+                org: "acme".to_owned(),
+                url: "https://github.com/orgs/acme/sso?authorization_request=ABC".to_owned(),
+            }))
+        );
+        assert_eq!(
+            parse_sso_header("partial-results; organizations=21955855,20582480"),
+            Some(SsoHeader::Partial(vec![21_955_855, 20_582_480]))
+        );
+        assert_eq!(parse_sso_header("required; url=https://github.com/"), None);
+    }
+
+    #[test]
+    fn recognises_saml_refusals() {
+        assert!(is_sso_error(
+            "Resource protected by organization SAML enforcement. You must grant your \
+             Personal Access token access to this organization."
+        ));
+        assert!(!is_sso_error("Bad credentials"));
+    }
+}
@@ -1,14 +1,85 @@
 use std::collections::HashMap;
+use std::ops::Deref;
 use std::sync::Arc;
 use std::time::Duration;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
+use indexmap::IndexMap;
 use moka::future::Cache;
 use octocrab::Octocrab;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 
-use crate::github::auth;
+use crate::config::types::HostConfig;
+use crate::github::features::{self, HostFeatures};
+use crate::github::{auth, auth_warnings};
 use crate::types::RateLimitInfo;
 
+/// The API clients of one host.
+///
+/// Derefs to the REST client. GraphQL goes through [`Self::graphql`]:
+/// Enterprise Server serves it at `/api/graphql`, outside the `/api/v3`
+/// root octocrab puts in front of every path.
+#[derive(Clone)]
+pub struct HostClient {
+    rest: Arc<Octocrab>,
+    graphql: Arc<Octocrab>,
+}
+
+impl HostClient {
+    /// The client GraphQL requests are sent with.
+    pub fn graphql_client(&self) -> &Octocrab {
+        &self.graphql
+    }
+
+    /// Run a GraphQL query (see [`Octocrab::graphql`]).
+    pub async fn graphql<R: DeserializeOwned>(
+        &self,
+        payload: &(impl Serialize + ?Sized),
+    ) -> octocrab::Result<R> {
+        self.graphql.graphql(payload).await
+    }
+}
+
+impl Deref for HostClient {
+    type Target = Arc<Octocrab>;
+
+    fn deref(&self) -> &Arc<Octocrab> {
+        &self.rest
+    }
+}
+
+/// One client for both APIs, as github.com serves them.
+impl From<Octocrab> for HostClient {
+    fn from(octocrab: Octocrab) -> Self {
+        let rest = Arc::new(octocrab);
+        Self {
+            graphql: Arc::clone(&rest),
+            rest,
+        }
+    }
+}
+
+/// Base URIs of the REST and GraphQL clients of `host`.
+fn base_uris(host: &str, config: &HostConfig) -> Result<(String, String)> {
+    let api_url = match &config.api_url {
+        Some(url) => url.trim_end_matches('/').to_owned(),
+        None if host == "github.com" => "https://api.github.com".to_owned(),
+        None if host.ends_with(".ghe.com") => format!("https://api.{host}"),
+        None => format!("https://{host}/api/v3"),
+    };
+    let graphql = match &config.graphql_url {
+        Some(url) => match url.trim_end_matches('/').strip_suffix("/graphql") {
+            Some(base) => base.to_owned(),
+            None => bail!("github.hosts.\"{host}\".graphql_url must end in /graphql, not {url}"),
+        },
+        None => api_url
+            .strip_suffix("/api/v3")
+            .map_or_else(|| api_url.clone(), |root| format!("{root}/api")),
+    };
+    Ok((api_url, graphql))
+}
+
 /// A GitHub API client that manages per-host Octocrab instances and an LRU
 /// cache for responses.
 ///
@@ -16,13 +87,17 @@ use crate::types::RateLimitInfo;
 /// `HashMap`. If concurrent async access is needed in the future, replace
 /// `instances` with `DashMap` or wrap the client in `RwLock`.
 pub struct GitHubClient {
-    instances: HashMap<String, Arc<Octocrab>>,
+    instances: HashMap<String, HostClient>,
+    features: HashMap<String, HostFeatures>,
     cache: Cache<String, String>,
+    /// `github.hosts`: endpoints and token of the hosts that set them.
+    hosts: IndexMap<String, HostConfig>,
 }
 
 impl GitHubClient {
-    /// Create a new client with the given cache TTL.
-    pub fn new(cache_ttl_minutes: u32) -> Self {
+    /// Create a new client with the given cache TTL, reaching `hosts` as
+    /// configured.
+    pub fn new(cache_ttl_minutes: u32, hosts: IndexMap<String, HostConfig>) -> Self {
         let cache = Cache::builder()
             .max_capacity(500)
             .time_to_live(Duration::from_mins(u64::from(cache_ttl_minutes)))
@@ -30,33 +105,77 @@ impl GitHubClient {
 
         Self {
             instances: HashMap::new(),
+            features: HashMap::new(),
             cache,
+            hosts,
         }
     }
 
-    /// Get or create an Octocrab instance for the given host.
-    pub fn octocrab_for(&mut self, host: &str) -> Result<Arc<Octocrab>> {
+    /// Get or create the clients of the given host.
+    pub fn octocrab_for(&mut self, host: &str) -> Result<HostClient> {
         if let Some(instance) = self.instances.get(host) {
-            return Ok(Arc::clone(instance));
+            return Ok(instance.clone());
         }
 
-        let token = auth::resolve_token(host)?;
-
-        let builder = if host == "github.com" {
-            Octocrab::builder().personal_token(token)
-        } else {
+        let config = self.hosts.get(host).cloned().unwrap_or_default();
+        let token = auth::resolve_token(host, config.token_env.as_deref())?;
+        let (api_url, graphql_url) = base_uris(host, &config)?;
+        let build = |base: &str| -> Result<Octocrab> {
             Octocrab::builder()
-                .personal_token(token)
-                .base_uri(format!("https://{host}/api/v3"))
-                .context("setting GHE base URI")?
+                .personal_token(token.clone())
+                .base_uri(base)
+                .with_context(|| format!("setting the API URL of {host} to {base}"))?
+                .build()
+                .context("building octocrab instance")
         };
 
-        let instance = Arc::new(builder.build().context("building octocrab instance")?);
-        self.instances
-            .insert(host.to_owned(), Arc::clone(&instance));
+        let rest = Arc::new(build(&api_url)?);
+        let graphql = if graphql_url == api_url {
+            Arc::clone(&rest)
+        } else {
+            Arc::new(build(&graphql_url)?)
+        };
+        let instance = HostClient { rest, graphql };
+        self.instances.insert(host.to_owned(), instance.clone());
         Ok(instance)
     }
 
+    /// Schema features of `host`, probed on first use.
+    ///
+    /// github.com has them all. An Enterprise Server host is introspected
+    /// once; when the probe fails the host is assumed current and probed
+    /// again next time.
+    pub async fn features_for(&mut self, host: &str, octocrab: &HostClient) -> HostFeatures {
+        if host == "github.com" {
+            return HostFeatures::ALL;
+        }
+        if let Some(known) = self.features.get(host) {
+            return *known;
+        }
+        match features::detect(octocrab).await {
+            Ok(detected) => {
+                let version = features::server_version(octocrab)
+                    .await
+                    .unwrap_or_else(|| "unknown version".to_owned());
+                let missing = detected.missing();
+                if missing.is_empty() {
+                    tracing::info!("{host} (GHES {version}): full schema");
+                } else {
+                    tracing::info!(
+                        "{host} (GHES {version}): degrading queries without {}",
+                        missing.join(", ")
+                    );
+                }
+                self.features.insert(host.to_owned(), detected);
+                detected
+            }
+            Err(e) => {
+                tracing::warn!("{host}: schema probe failed: {e:#}");
+                HostFeatures::ALL
+            }
+        }
+    }
+
     /// Return a clone of the internal cache (Arc-backed, cheap to clone).
     pub fn cache(&self) -> Cache<String, String> {
         self.cache.clone()
@@ -67,7 +186,10 @@ impl GitHubClient {
 ///
 /// Reads `x-ratelimit-remaining` and `x-ratelimit-limit`. Returns `None` if
 /// the headers are absent or cannot be parsed (e.g. non-REST responses).
+/// Token expiry and SSO headers are noted on the way (see
+/// [`auth_warnings::inspect`]).
 pub(crate) fn extract_rest_rate_limit(headers: &http::header::HeaderMap) -> Option<RateLimitInfo> {
+    auth_warnings::inspect(headers);
     let remaining = headers
         .get("x-ratelimit-remaining")?
         .to_str()
@@ -83,5 +205,56 @@ pub(crate) fn extract_rest_rate_limit(headers: &http::header::HeaderMap) -> Opti
         remaining,
         limit,
         cost: 1,
+        host: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoints_follow_the_host() {
+        let none = HostConfig::default();
+        let uris = |host: &str, config: &HostConfig| base_uris(host, config).unwrap();
+        assert_eq!(
+            uris("github.com", &none),
+            (
+                "https://api.github.com".to_owned(),
+                "https://api.github.com".to_owned()
+            )
+        );
+        assert_eq!(
+            uris("ghe.acme.com", &none),
+            (
+                "https://ghe.acme.com/api/v3".to_owned(),
+                "https://ghe.acme.com/api".to_owned()
+            )
+        );
+        assert_eq!(
+            uris("acme.ghe.com", &none),
+            (
+                "https://api.acme.ghe.com".to_owned(),
+                "https://api.acme.ghe.com".to_owned()
+            )
+        );
+
+        let proxied = HostConfig {
+            api_url: Some("https://gw.acme.com/github/".to_owned()),
+            graphql_url: Some("https://gw.acme.com/github-gql/graphql".to_owned()),
+            token_env: None,
+        };
+        assert_eq!(
+            uris("ghe.acme.com", &proxied),
+            (
+                "https://gw.acme.com/github".to_owned(),
+                "https://gw.acme.com/github-gql".to_owned()
+            )
+        );
+        let wrong = HostConfig {
+            graphql_url: Some("https://ghe.acme.com/api".to_owned()),
+            ..HostConfig::default()
+        };
+        assert!(base_uris("ghe.acme.com", &wrong).is_err());
+    }
+}
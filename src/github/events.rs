@@ -0,0 +1,468 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::github::auth_warnings;
+use crate::github::client::extract_rest_rate_limit;
+use crate::types::{EventItem, OrgEvent, OrgEventSource, RateLimitInfo};
+
+// ── Raw API response types: audit log ────────────────────────────────
+
+#[derive(Deserialize)]
+struct RawAuditEntry {
+    #[serde(default)]
+    action: String,
+    #[serde(default)]
+    actor: Option<String>,
+    /// Milliseconds since the epoch.
+    #[serde(rename = "@timestamp")]
+    timestamp: i64,
+    #[serde(default)]
+    repo: Option<String>,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    team: Option<String>,
+}
+
+// ── Raw API response types: events feed ──────────────────────────────
+
+#[derive(Deserialize)]
+struct RawEvent {
+    #[serde(rename = "type", default)]
+    kind: String,
+    #[serde(default)]
+    actor: Option<RawLogin>,
+    #[serde(default)]
+    repo: Option<RawRepoName>,
+    #[serde(default)]
+    payload: RawPayload,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct RawLogin {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct RawRepoName {
+    name: String,
+}
+
+#[derive(Deserialize, Default)]
+struct RawPayload {
+    #[serde(default)]
+    action: Option<String>,
+    #[serde(default)]
+    ref_type: Option<String>,
+    #[serde(default)]
+    member: Option<RawLogin>,
+    /// Pushed or created ref.
+    #[serde(default, rename = "ref")]
+    git_ref: Option<String>,
+    #[serde(default)]
+    before: Option<String>,
+    #[serde(default)]
+    head: Option<String>,
+    #[serde(default)]
+    issue: Option<RawItem>,
+    #[serde(default)]
+    pull_request: Option<RawItem>,
+    #[serde(default)]
+    comment: Option<RawLink>,
+    #[serde(default)]
+    release: Option<RawRelease>,
+    #[serde(default)]
+    forkee: Option<RawForkee>,
+}
+
+#[derive(Deserialize)]
+struct RawItem {
+    number: u64,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    html_url: Option<String>,
+    /// Set on the `issue` of a comment made on a pull request.
+    #[serde(default)]
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct RawLink {
+    #[serde(default)]
+    html_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawRelease {
+    tag_name: String,
+    #[serde(default)]
+    html_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawForkee {
+    full_name: String,
+    #[serde(default)]
+    html_url: Option<String>,
+}
+
+// ── Conversion ───────────────────────────────────────────────────────
+
+fn audit_into_domain(raw: RawAuditEntry, host: &str, org: &str) -> OrgEvent {
+    let created_at = DateTime::from_timestamp_millis(raw.timestamp).unwrap_or_default();
+    let url = format!(
+        "https://{host}/organizations/{org}/settings/audit-log?q=action%3A{}",
+        raw.action
+    );
+    OrgEvent {
+        target: raw.repo.or(raw.user).or(raw.team),
+        action: raw.action,
+        actor: raw.actor,
+        created_at,
+        url,
+        item: None,
+    }
+}
+
+/// `MemberEvent` + `added` → `member.added`; `CreateEvent` names what was
+/// created (`create.repository`) since it has no action.
+fn event_action(raw: &RawEvent) -> String {
+    let name = raw.kind.strip_suffix("Event").unwrap_or(&raw.kind);
+    let mut action = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            action.push('_');
+        }
+        action.push(c.to_ascii_lowercase());
+    }
+    if let Some(detail) = raw
+        .payload
+        .action
+        .as_ref()
+        .or(raw.payload.ref_type.as_ref())
+    {
+        action.push('.');
+        action.push_str(detail);
+    }
+    action
+}
+
+fn event_into_domain(raw: RawEvent, host: &str) -> OrgEvent {
+    let action = event_action(&raw);
+    let repo = raw.repo.map(|r| r.name).unwrap_or_default();
+    let repo_url = if repo.is_empty() {
+        String::new()
+    } else {
+        format!("https://{host}/{repo}")
+    };
+    let payload = raw.payload;
+    let mut item = None;
+    let (target, url) = if let Some(member) = payload.member {
+        (member.login, repo_url)
+    } else if let Some(raw_item) = payload.pull_request.as_ref().or(payload.issue.as_ref()) {
+        let is_pr = payload.pull_request.is_some() || raw_item.pull_request.is_some();
+        let mut target = format!("{repo}#{}", raw_item.number);
+        if let Some(title) = &raw_item.title {
+            target.push(' ');
+            target.push_str(title);
+        }
+        // A comment links to itself, anything else to its issue or PR.
+        let url = payload
+            .comment
+            .and_then(|c| c.html_url)
+            .or_else(|| raw_item.html_url.clone())
+            .unwrap_or_else(|| {
+                let kind = if is_pr { "pull" } else { "issues" };
+                format!("{repo_url}/{kind}/{}", raw_item.number)
+            });
+        item = Some(EventItem {
+            repo: repo.clone(),
+            number: raw_item.number,
+            is_pr,
+        });
+        (target, url)
+    } else if let Some(release) = payload.release {
+        let url = release
+            .html_url
+            .unwrap_or_else(|| format!("{repo_url}/releases/tag/{}", release.tag_name));
+        (format!("{repo} {}", release.tag_name), url)
+    } else if let Some(forkee) = payload.forkee {
+        let url = forkee
+            .html_url
+            .unwrap_or_else(|| format!("https://{host}/{}", forkee.full_name));
+        (format!("{repo} \u{2192} {}", forkee.full_name), url)
+    } else if raw.kind == "PushEvent"
+        && let Some(git_ref) = payload.git_ref
+    {
+        let branch = git_ref.strip_prefix("refs/heads/").unwrap_or(&git_ref);
+        let url = match (payload.before, payload.head) {
+            (Some(before), Some(head)) => format!("{repo_url}/compare/{before}...{head}"),
+            _ => format!("{repo_url}/commits/{branch}"),
+        };
+        (format!("{repo} {branch}"), url)
+    } else {
+        (repo.clone(), repo_url)
+    };
+    OrgEvent {
+        target: Some(target).filter(|t| !t.is_empty()),
+        url,
+        action,
+        actor: raw.actor.map(|a| a.login),
+        created_at: raw.created_at,
+        item,
+    }
+}
+
+/// Whether `event` passes a filter's `actor` and `action` settings.
+fn matches(event: &OrgEvent, actor: Option<&str>, action: Option<&str>) -> bool {
+    actor.is_none_or(|want| {
+        event
+            .actor
+            .as_deref()
+            .is_some_and(|a| a.eq_ignore_ascii_case(want))
+    }) && action.is_none_or(|prefix| event.action.starts_with(prefix))
+}
+
+/// One page of an events feed at `path`; `what` names it in errors.
+async fn get_events(
+    octocrab: &Arc<Octocrab>,
+    host: &str,
+    path: &str,
+    what: &str,
+) -> Result<(Vec<OrgEvent>, Option<RateLimitInfo>)> {
+    let response = octocrab
+        ._get(path)
+        .await
+        .with_context(|| format!("fetching events of {what}"))?;
+    let response = octocrab::map_github_error(response)
+        .await
+        .with_context(|| format!("fetching events of {what}"))?;
+    let rate_limit = extract_rest_rate_limit(response.headers());
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading events body")?;
+    let raw: Vec<RawEvent> = serde_json::from_str(&body).context("deserializing events")?;
+    let events = raw
+        .into_iter()
+        .map(|r| event_into_domain(r, host))
+        .collect();
+    Ok((events, rate_limit))
+}
+
+// ── Public API ───────────────────────────────────────────────────────
+
+/// Fetch recent events of an organization, newest first.
+///
+/// Reads the audit log when the token may (organization owners on
+/// Enterprise Cloud); otherwise falls back to the public events feed, which
+/// holds fewer kinds of changes.
+pub async fn fetch_org_events(
+    octocrab: &Arc<Octocrab>,
+    host: &str,
+    org: &str,
+    actor: Option<&str>,
+    action: Option<&str>,
+    limit: u32,
+) -> Result<(Vec<OrgEvent>, OrgEventSource, Option<RateLimitInfo>)> {
+    let per_page = limit.min(100);
+    let phrase = actor.map_or_else(String::new, |a| format!("&phrase=actor%3A{a}"));
+    let response = octocrab
+        ._get(format!(
+            "/orgs/{org}/audit-log?per_page={per_page}&order=desc{phrase}"
+        ))
+        .await
+        .context("fetching audit log")?;
+    auth_warnings::inspect(response.headers());
+
+    let (mut events, source, rate_limit) = if response.status().is_success() {
+        let rate_limit = extract_rest_rate_limit(response.headers());
+        let body = octocrab
+            .body_to_string(response)
+            .await
+            .context("reading audit log body")?;
+        let raw: Vec<RawAuditEntry> =
+            serde_json::from_str(&body).context("deserializing audit log")?;
+        let events: Vec<OrgEvent> = raw
+            .into_iter()
+            .map(|r| audit_into_domain(r, host, org))
+            .collect();
+        (events, OrgEventSource::AuditLog, rate_limit)
+    } else {
+        tracing::debug!(
+            "audit log of {org} unavailable ({}); reading the events feed",
+            response.status()
+        );
+        let path = format!("/orgs/{org}/events?per_page={per_page}");
+        let (events, rate_limit) = get_events(octocrab, host, &path, org).await?;
+        (events, OrgEventSource::Events, rate_limit)
+    };
+
+    events.retain(|e| matches(e, actor, action));
+    tracing::debug!("fetched {} events for {org}", events.len());
+    Ok((events, source, rate_limit))
+}
+
+/// Fetch an activity feed, newest first: the events of `repos`, and with
+/// `received` those of the repositories the viewer watches and the people
+/// they follow.
+pub async fn fetch_feed(
+    octocrab: &Arc<Octocrab>,
+    host: &str,
+    repos: &[String],
+    received: bool,
+    actor: Option<&str>,
+    action: Option<&str>,
+    limit: u32,
+) -> Result<(Vec<OrgEvent>, Option<RateLimitInfo>)> {
+    if repos.is_empty() && !received {
+        bail!("the tab needs `org`, `repos` or `received = true`");
+    }
+    let per_page = limit.min(100);
+    let mut events = Vec::new();
+    let mut rate_limit = None;
+    if received {
+        let user: RawLogin = octocrab
+            .get("/user", None::<&()>)
+            .await
+            .context("fetching authenticated user")?;
+        let path = format!("/users/{}/received_events?per_page={per_page}", user.login);
+        let (received, rl) = get_events(octocrab, host, &path, &user.login).await?;
+        events.extend(received);
+        rate_limit = rl.or(rate_limit);
+    }
+    for repo in repos {
+        let path = format!("/repos/{repo}/events?per_page={per_page}");
+        let (repo_events, rl) = get_events(octocrab, host, &path, repo).await?;
+        events.extend(repo_events);
+        rate_limit = rl.or(rate_limit);
+    }
+    events.sort_by_key(|e| std::cmp::Reverse(e.created_at));
+    // Received events include those of watched repos also listed in `repos`.
+    events.dedup_by(|a, b| a == b);
+    events.retain(|e| matches(e, actor, action));
+    events.truncate(limit as usize);
+    tracing::debug!("fetched {} feed events", events.len());
+    Ok((events, rate_limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_event(json: serde_json::Value) -> RawEvent {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn events_feed_maps_to_audit_style_actions() {
+        let member = raw_event(serde_json::json!({
+            "type": "MemberEvent",
+            "actor": {"login": "alice"},
+            "repo": {"name": "acme/api"},
+            "payload": {"action": "added", "member": {"login": "bob"}},
+            "created_at": "2024-05-02T10:00:00Z",
+        }));
+        let event = event_into_domain(member, "github.com");
+        assert_eq!(event.action, "member.added");
+        assert_eq!(event.target.as_deref(), Some("bob"));
+        assert_eq!(event.url, "https://github.com/acme/api");
+
+        let create = raw_event(serde_json::json!({
+            "type": "CreateEvent",
+            "repo": {"name": "acme/web"},
+            "payload": {"ref_type": "repository"},
+            "created_at": "2024-05-02T10:00:00Z",
+        }));
+        assert_eq!(event_action(&create), "create.repository");
+
+        let review = raw_event(serde_json::json!({
+            "type": "PullRequestReviewEvent",
+            "created_at": "2024-05-02T10:00:00Z",
+        }));
+        assert_eq!(event_action(&review), "pull_request_review");
+    }
+
+    #[test]
+    fn feed_events_link_to_their_item_release_or_push() {
+        let comment = event_into_domain(
+            raw_event(serde_json::json!({
+                "type": "IssueCommentEvent",
+                "actor": {"login": "alice"},
+                "repo": {"name": "acme/api"},
+                "payload": {
+                    "action": "created",
+                    "issue": {"number": 7, "title": "Flaky test", "pull_request": {}},
+                    "comment": {"html_url": "https://github.com/acme/api/pull/7#issuecomment-1"},
+                },
+                "created_at": "2024-05-02T10:00:00Z",
+            })),
+            "github.com",
+        );
+        assert_eq!(comment.target.as_deref(), Some("acme/api#7 Flaky test"));
+        assert_eq!(
+            comment.url,
+            "https://github.com/acme/api/pull/7#issuecomment-1"
+        );
+        assert_eq!(
+            comment.item,
+            Some(EventItem {
+                repo: "acme/api".to_owned(),
+                number: 7,
+                is_pr: true,
+            })
+        );
+
+        let release = event_into_domain(
+            raw_event(serde_json::json!({
+                "type": "ReleaseEvent",
+                "repo": {"name": "acme/api"},
+                "payload": {"action": "published", "release": {"tag_name": "v1.2.0"}},
+                "created_at": "2024-05-02T10:00:00Z",
+            })),
+            "github.com",
+        );
+        assert_eq!(release.target.as_deref(), Some("acme/api v1.2.0"));
+        assert_eq!(
+            release.url,
+            "https://github.com/acme/api/releases/tag/v1.2.0"
+        );
+
+        let push = event_into_domain(
+            raw_event(serde_json::json!({
+                "type": "PushEvent",
+                "repo": {"name": "acme/api"},
+                "payload": {"ref": "refs/heads/main", "before": "abc", "head": "def"},
+                "created_at": "2024-05-02T10:00:00Z",
+            })),
+            "github.com",
+        );
+        assert_eq!(push.action, "push");
+        assert_eq!(push.target.as_deref(), Some("acme/api main"));
+        assert_eq!(push.url, "https://github.com/acme/api/compare/abc...def");
+        assert_eq!(push.item, None);
+    }
+
+    #[test]
+    fn audit_entries_take_the_first_target() {
+        let raw: RawAuditEntry = serde_json::from_value(serde_json::json!({
+            "action": "org.add_member",
+            "actor": "alice",
+            "@timestamp": 1_714_644_000_000_i64,
+            "user": "bob",
+        }))
+        .unwrap();
+        let event = audit_into_domain(raw, "github.com", "acme");
+        assert_eq!(event.target.as_deref(), Some("bob"));
+        assert_eq!(event.created_at.to_rfc3339(), "2024-05-02T10:00:00+00:00");
+        assert!(matches(&event, Some("Alice"), Some("org.")));
+        assert!(!matches(&event, None, Some("repo.")));
+        assert!(!matches(&event, Some("carol"), None));
+    }
+}
@@ -0,0 +1,161 @@
+//! Per-host GraphQL schema features.
+//!
+//! GitHub Enterprise Server lags github.com's schema, and a query naming a
+//! field the server does not know fails validation as a whole. Each host's
+//! schema is probed once (see [`detect`]); queries then leave out the fields
+//! the host lacks instead of failing the whole filter.
+
+use anyhow::{Context, Result};
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use super::client::HostClient;
+
+/// Optional schema features the queries use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostFeatures {
+    /// `PullRequest.mergeStateStatus`.
+    pub merge_state_status: bool,
+    /// `isMergeQueueEnabled`, `mergeQueueEntry` and `Repository.mergeQueue`.
+    pub merge_queue: bool,
+    /// `Ref.rules` (repository rulesets).
+    pub repository_rules: bool,
+}
+
+impl HostFeatures {
+    /// Everything available, as on github.com.
+    pub const ALL: Self = Self {
+        merge_state_status: true,
+        merge_queue: true,
+        repository_rules: true,
+    };
+
+    /// The query features (`#[cynic(feature = ...)]`) this host has.
+    pub fn enabled(self) -> impl Iterator<Item = &'static str> {
+        [
+            (self.merge_state_status, "merge_state_status"),
+            (self.merge_queue, "merge_queue"),
+            (self.repository_rules, "repository_rules"),
+        ]
+        .into_iter()
+        .filter(|(present, _)| *present)
+        .map(|(_, name)| name)
+    }
+
+    /// Features present in the introspected field names of each type.
+    fn from_schema(pull_request: &[String], git_ref: &[String], repository: &[String]) -> Self {
+        let has = |fields: &[String], name: &str| fields.iter().any(|f| f == name);
+        Self {
+            merge_state_status: has(pull_request, "mergeStateStatus"),
+            merge_queue: has(pull_request, "mergeQueueEntry") && has(repository, "mergeQueue"),
+            repository_rules: has(git_ref, "rules"),
+        }
+    }
+
+    /// Names of the missing features, for logs.
+    pub fn missing(self) -> Vec<&'static str> {
+        [
+            (self.merge_state_status, "mergeStateStatus"),
+            (self.merge_queue, "merge queue"),
+            (self.repository_rules, "repository rules"),
+        ]
+        .into_iter()
+        .filter(|(present, _)| !present)
+        .map(|(_, name)| name)
+        .collect()
+    }
+}
+
+const SCHEMA_QUERY: &str = r#"
+query HostFeatures {
+  pullRequest: __type(name: "PullRequest") { fields { name } }
+  gitRef: __type(name: "Ref") { fields { name } }
+  repository: __type(name: "Repository") { fields { name } }
+}
+"#;
+
+#[derive(Deserialize)]
+struct SchemaData {
+    #[serde(rename = "pullRequest")]
+    pull_request: Option<SchemaType>,
+    #[serde(rename = "gitRef")]
+    git_ref: Option<SchemaType>,
+    repository: Option<SchemaType>,
+}
+
+#[derive(Deserialize)]
+struct SchemaType {
+    #[serde(default)]
+    fields: Vec<SchemaField>,
+}
+
+#[derive(Deserialize)]
+struct SchemaField {
+    name: String,
+}
+
+fn field_names(ty: Option<SchemaType>) -> Vec<String> {
+    ty.map(|t| t.fields.into_iter().map(|f| f.name).collect())
+        .unwrap_or_default()
+}
+
+/// Probe the schema of an Enterprise Server host.
+pub async fn detect(octocrab: &HostClient) -> Result<HostFeatures> {
+    let payload = serde_json::json!({ "query": SCHEMA_QUERY });
+    let data: SchemaData = octocrab
+        .graphql(&payload)
+        .await
+        .context("GraphQL schema introspection failed")?;
+    Ok(HostFeatures::from_schema(
+        &field_names(data.pull_request),
+        &field_names(data.git_ref),
+        &field_names(data.repository),
+    ))
+}
+
+/// The server version reported by `/meta` (`installed_version`), if any.
+pub async fn server_version(octocrab: &Octocrab) -> Option<String> {
+    let meta: serde_json::Value = octocrab.get("/meta", None::<&()>).await.ok()?;
+    meta.get("installed_version")?.as_str().map(str::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enabled_lists_present_features() {
+        assert_eq!(
+            HostFeatures::ALL.enabled().collect::<Vec<_>>(),
+            vec!["merge_state_status", "merge_queue", "repository_rules"]
+        );
+        let features = HostFeatures {
+            merge_state_status: false,
+            merge_queue: true,
+            repository_rules: false,
+        };
+        assert_eq!(features.enabled().collect::<Vec<_>>(), vec!["merge_queue"]);
+    }
+
+    #[test]
+    fn from_schema_requires_each_field() {
+        let names = |list: &[&str]| list.iter().map(|s| (*s).to_owned()).collect::<Vec<_>>();
+        let features = HostFeatures::from_schema(
+            &names(&["mergeable", "mergeQueueEntry"]),
+            &names(&["name"]),
+            &names(&["mergeQueue"]),
+        );
+        assert_eq!(
+            features,
+            HostFeatures {
+                merge_state_status: false,
+                merge_queue: true,
+                repository_rules: false,
+            }
+        );
+        assert_eq!(
+            features.missing(),
+            vec!["mergeStateStatus", "repository rules"]
+        );
+    }
+}
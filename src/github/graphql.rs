@@ -1,201 +1,111 @@
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
-use chrono::{DateTime, Utc};
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, NaiveDate, Utc};
+use cynic::{Operation, OperationBuilder, QueryBuilder, QueryFragment, QueryVariables};
 use moka::future::Cache;
 use octocrab::Octocrab;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::github::auth_warnings;
+use crate::github::client::HostClient;
+use crate::github::features::HostFeatures;
 use crate::github::types::{
-    Actor, AuthorAssociation, CheckConclusion, CheckRun, CheckStatus, Commit, CommitCheckState,
-    File, FileChangeType, Issue, IssueState, Label, MergeStateStatus, MergeableState, PrState,
-    PullRequest, ReactionGroups, RepoRef, Review, ReviewDecision, ReviewState, ReviewThread,
-    TimelineEvent,
+    Actor, AuthorAssociation, AwaitingRun, CheckConclusion, CheckRun, CheckStatus, CodeOwners,
+    Commit, CommitCheckState, CommitSignature, ContributionCalendar, ContributionDay, Deployment,
+    DeploymentState, DispatchableWorkflow, File, FileChangeType, FileViewedState, Issue, IssueLink,
+    IssueLinks, IssueState, Label, MergeQueue, MergeQueueEntry, MergeQueueEntryState,
+    MergeQueueItem, MergeRequirements, MergeStateStatus, MergeableState, PageCursor,
+    PrDetailConnection, PrDetailPage, PrState, PullRequest, ReactionGroups, RepoRef, Review,
+    ReviewDecision, ReviewState, ReviewThread, ReviewTimes, SavedReply, SubjectState,
+    SubjectStatus, TimelineEvent, WorkflowSchedule,
 };
 
+use crate::config::types::IssueFilterKind;
+
 // Re-export types moved to crate::types so existing importers continue to work.
 pub use crate::types::{IssueDetail, PrDetail, RateLimitInfo};
 
 // ---------------------------------------------------------------------------
-// GraphQL query strings
+// Schema
 // ---------------------------------------------------------------------------
 
-const SEARCH_PULL_REQUESTS_QUERY: &str = r"
-query SearchPullRequests($query: String!, $first: Int!, $after: String) {
-  rateLimit { limit remaining cost }
-  search(query: $query, type: ISSUE, first: $first, after: $after) {
-    pageInfo {
-      hasNextPage
-      endCursor
-    }
-    nodes {
-      ... on PullRequest {
-        number
-        title
-        body
-        state
-        isDraft
-        mergeable
-        reviewDecision
-        additions
-        deletions
-        headRefName
-        baseRefName
-        mergeStateStatus
-        headRepository { owner { login } name }
-        url
-        updatedAt
-        createdAt
-        author { login avatarUrl }
-        authorAssociation
-        labels(first: 10) { nodes { name color } }
-        assignees(first: 10) { nodes { login } }
-        comments { totalCount }
-        latestReviews(first: 10) {
-          nodes {
-            state
-            author { login }
-          }
-        }
-        reviewRequests(first: 10) {
-          nodes {
-            requestedReviewer {
-              ... on User { login }
-            }
-          }
-        }
-        commits(last: 1) {
-          nodes {
-            commit {
-              statusCheckRollup {
-                contexts(first: 50) {
-                  nodes {
-                    ... on CheckRun {
-                      name status conclusion detailsUrl startedAt completedAt
-                      checkSuite {
-                        workflowRun {
-                          databaseId
-                          workflow { name }
-                        }
-                      }
-                    }
-                    ... on StatusContext { context state targetUrl }
-                  }
-                }
-              }
-            }
-          }
-        }
-        participants(first: 30) { nodes { login } }
-        repository { nameWithOwner }
-      }
-    }
-  }
-}
-";
-
-const PR_DETAIL_QUERY: &str = r"
-query PullRequestDetail($owner: String!, $repo: String!, $number: Int!) {
-  rateLimit { limit remaining cost }
-  repository(owner: $owner, name: $repo) {
-    pullRequest(number: $number) {
-      body
-      mergeable
-      reviews(last: 50) {
-        nodes { author { login } state body submittedAt }
-      }
-      reviewThreads(first: 50) {
-        nodes { isResolved comments(first: 10) { nodes { author { login } body createdAt } } }
-      }
-      timelineItems(last: 100) {
-        nodes {
-          __typename
-          ... on IssueComment { author { login } body createdAt }
-          ... on PullRequestReview { author { login } state body submittedAt }
-          ... on MergedEvent { actor { login } createdAt }
-          ... on ClosedEvent { actor { login } createdAt }
-          ... on ReopenedEvent { actor { login } createdAt }
-          ... on HeadRefForcePushedEvent { actor { login } createdAt }
-        }
-      }
-      commits(first: 100) {
-        nodes { commit { oid messageHeadline author { name } committedDate statusCheckRollup { state } } }
-      }
-      files(first: 100) {
-        nodes { path additions deletions changeType }
-      }
-    }
-  }
+// The queries are generated from the response types below, which are checked
+// at build time against the vendored schema (`schema.docs.graphql`).
+#[cynic::schema("github")]
+mod schema {}
+
+/// A GraphQL `Int` holding an issue or PR number, a database id or a
+/// duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Number(u64);
+
+/// A GraphQL `Int` holding a count or a position.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Count(u32);
+
+cynic::impl_scalar!(Number, schema::Int);
+cynic::impl_scalar!(Count, schema::Int);
+// Both are also passed to nullable `Int` arguments.
+impl cynic::coercions::CoercesTo<Option<i32>> for Number {}
+impl cynic::coercions::CoercesTo<Option<i32>> for Count {}
+
+cynic::impl_scalar!(DateTime<Utc>, schema::DateTime);
+cynic::impl_scalar!(NaiveDate, schema::Date);
+
+// URLs and object ids are read as plain strings.
+impl cynic::schema::IsScalar<schema::URI> for String {
+    type SchemaType = schema::URI;
 }
-";
 
-const ISSUE_DETAIL_QUERY: &str = r"
-query IssueDetail($owner: String!, $repo: String!, $number: Int!) {
-  rateLimit { limit remaining cost }
-  repository(owner: $owner, name: $repo) {
-    issue(number: $number) {
-      body
-      timelineItems(last: 100) {
-        nodes {
-          __typename
-          ... on IssueComment { author { login } body createdAt }
-          ... on ClosedEvent { actor { login } createdAt }
-          ... on ReopenedEvent { actor { login } createdAt }
-        }
-      }
-    }
-  }
+impl cynic::schema::IsScalar<schema::GitObjectID> for String {
+    type SchemaType = schema::GitObjectID;
 }
-";
 
-const REPOSITORY_LABELS_QUERY: &str = r"
-query RepositoryLabels($owner: String!, $repo: String!, $first: Int!) {
-  rateLimit { limit remaining cost }
-  repository(owner: $owner, name: $repo) {
-    labels(first: $first, orderBy: { field: NAME, direction: ASC }) {
-      nodes { name color description }
-    }
-  }
+/// Read the domain enums straight from the schema enums sharing their values.
+macro_rules! schema_enums {
+    ($($domain:ty => $schema:ident),* $(,)?) => {
+        $(
+            impl cynic::Enum for $domain {
+                type SchemaType = schema::$schema;
+            }
+        )*
+    };
 }
-";
 
-const REPOSITORY_COLLABORATORS_QUERY: &str = r"
-query RepositoryCollaborators($owner: String!, $repo: String!, $first: Int!) {
-  rateLimit { limit remaining cost }
-  repository(owner: $owner, name: $repo) {
-    collaborators(first: $first, affiliation: ALL) {
-      nodes { login }
-    }
-  }
+schema_enums! {
+    AuthorAssociation => CommentAuthorAssociation,
+    CheckConclusion => CheckConclusionState,
+    CheckStatus => CheckStatusState,
+    CommitCheckState => StatusState,
+    DeploymentState => DeploymentState,
+    FileChangeType => PatchStatus,
+    FileViewedState => FileViewedState,
+    IssueState => IssueState,
+    MergeQueueEntryState => MergeQueueEntryState,
+    MergeStateStatus => MergeStateStatus,
+    MergeableState => MergeableState,
+    PrState => PullRequestState,
+    ReviewDecision => PullRequestReviewDecision,
+    ReviewState => PullRequestReviewState,
 }
-";
 
-const SEARCH_ISSUES_QUERY: &str = r"
-query SearchIssues($query: String!, $first: Int!, $after: String) {
-  rateLimit { limit remaining cost }
-  search(query: $query, type: ISSUE, first: $first, after: $after) {
-    pageInfo { hasNextPage endCursor }
-    nodes {
-      ... on Issue {
-        number
-        title
-        body
-        state
-        url
-        updatedAt
-        createdAt
-        author { login avatarUrl }
-        assignees(first: 10) { nodes { login } }
-        labels(first: 10) { nodes { name color } }
-        comments { totalCount }
-        reactionGroups { content users { totalCount } }
-        participants(first: 30) { nodes { login } }
-        repository { nameWithOwner }
-      }
+/// The operation of query `Q`, selecting the optional fields the host has.
+fn operation<Q, V>(features: HostFeatures, variables: V) -> Operation<Q, V>
+where
+    Q: QueryFragment<VariablesFields = V::Fields>,
+    Q::SchemaType: cynic::schema::QueryRoot,
+    V: QueryVariables,
+{
+    let mut builder = OperationBuilder::query().with_variables(variables);
+    for feature in features.enabled() {
+        builder.enable_feature(feature);
     }
-  }
+    builder.build().expect("the variables are set")
 }
-";
 
 // ---------------------------------------------------------------------------
 // Query helpers
@@ -217,668 +127,982 @@ fn ensure_type_qualifier(query: &str, qualifier: &str) -> String {
 }
 
 // ---------------------------------------------------------------------------
-// Request payload
+// Variables
 // ---------------------------------------------------------------------------
 
-#[derive(Serialize)]
-struct GraphQLPayload<V: Serialize> {
-    query: &'static str,
-    variables: V,
-}
-
-#[derive(Serialize)]
+#[derive(cynic::QueryVariables, Debug)]
 struct SearchVariables {
     query: String,
-    first: u32,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    first: Count,
     after: Option<String>,
 }
 
-#[derive(Serialize)]
-struct PrDetailVariables {
+/// An issue or PR of a repository.
+#[derive(cynic::QueryVariables, Debug)]
+struct ItemVariables {
     owner: String,
     repo: String,
-    number: i64,
-}
-
-// ---------------------------------------------------------------------------
-// Response types (mirror the GraphQL response shape)
-// ---------------------------------------------------------------------------
-
-#[derive(Debug, Deserialize)]
-struct SearchData {
-    #[serde(rename = "rateLimit", default)]
-    rate_limit: Option<RateLimitInfo>,
-    search: SearchResult,
+    number: Number,
 }
 
-#[derive(Debug, Deserialize)]
-struct SearchResult {
-    #[serde(rename = "pageInfo")]
-    page_info: PageInfo,
-    #[serde(default)]
-    nodes: Vec<Option<RawPullRequest>>,
+impl ItemVariables {
+    fn new(owner: &str, repo: &str, number: u64) -> Self {
+        Self {
+            owner: owner.to_owned(),
+            repo: repo.to_owned(),
+            number: Number(number),
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct IssueSearchData {
-    #[serde(rename = "rateLimit", default)]
-    rate_limit: Option<RateLimitInfo>,
-    search: IssueSearchResult,
+#[derive(cynic::QueryVariables, Debug)]
+struct PrDetailPageVariables {
+    owner: String,
+    repo: String,
+    number: Number,
+    after: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct IssueSearchResult {
-    #[serde(rename = "pageInfo")]
-    page_info: PageInfo,
-    #[serde(default)]
-    nodes: Vec<Option<RawIssue>>,
+/// The first `first` items of a repository's list.
+#[derive(cynic::QueryVariables, Debug)]
+struct RepoListVariables {
+    owner: String,
+    repo: String,
+    first: Count,
 }
 
-/// Pagination info from GraphQL.
-#[derive(Debug, Clone, Deserialize)]
-pub(crate) struct PageInfo {
-    #[serde(rename = "hasNextPage")]
-    pub has_next_page: bool,
-    #[serde(rename = "endCursor")]
-    pub end_cursor: Option<String>,
+impl RepoListVariables {
+    fn new(owner: &str, repo: &str) -> Self {
+        Self {
+            owner: owner.to_owned(),
+            repo: repo.to_owned(),
+            first: Count(100),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
-// PR detail response types (Q2)
+// Partial responses
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize)]
-struct PrDetailData {
-    #[serde(rename = "rateLimit", default)]
-    rate_limit: Option<RateLimitInfo>,
-    repository: Option<PrDetailRepo>,
-}
-
-#[derive(Debug, Deserialize)]
-struct PrDetailRepo {
-    #[serde(rename = "pullRequest")]
-    pull_request: Option<RawPrDetail>,
-}
-
-#[derive(Debug, Deserialize)]
-struct RawPrDetail {
+/// Response envelope that keeps `data` alongside any `errors`.
+///
+/// GitHub answers a search touching an inaccessible repository with both the
+/// readable results and an `errors` entry for the rest; octocrab's
+/// `graphql()` turns that into a hard error and drops the data.
+#[derive(Deserialize)]
+struct PartialResponse {
     #[serde(default)]
-    body: String,
-    mergeable: Option<MergeableState>,
-    reviews: Option<Connection<RawReview>>,
-    #[serde(rename = "reviewThreads")]
-    review_threads: Option<Connection<RawReviewThread>>,
-    #[serde(rename = "timelineItems")]
-    timeline_items: Option<Connection<RawTimelineItem>>,
-    commits: Option<Connection<RawDetailCommitNode>>,
-    files: Option<Connection<RawFile>>,
+    data: Option<serde_json::Value>,
+    #[serde(default)]
+    errors: Vec<ResponseError>,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawReview {
-    author: Option<RawActor>,
-    state: ReviewState,
+#[derive(Deserialize)]
+struct ResponseError {
+    message: String,
     #[serde(default)]
-    body: String,
-    #[serde(rename = "submittedAt")]
-    submitted_at: Option<DateTime<Utc>>,
+    path: Vec<serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawReviewThread {
-    #[serde(rename = "isResolved", default)]
-    is_resolved: bool,
-    comments: Option<Connection<RawComment>>,
+impl ResponseError {
+    /// `"search.nodes.3: Resource not accessible"`, or just the message.
+    fn describe(&self) -> String {
+        if self.path.is_empty() {
+            return self.message.clone();
+        }
+        let path: Vec<String> = self
+            .path
+            .iter()
+            .map(|seg| match seg {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        format!("{}: {}", path.join("."), self.message)
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawComment {
-    author: Option<RawActor>,
-    #[serde(default)]
-    body: String,
-    #[serde(rename = "createdAt")]
-    created_at: DateTime<Utc>,
+/// Run a GraphQL query, keeping usable data when GitHub also reports errors.
+///
+/// Returns the decoded data together with one warning per reported error.
+/// Fails only when there is no data to decode.
+async fn graphql_partial<R: DeserializeOwned>(
+    octocrab: &HostClient,
+    payload: &impl Serialize,
+) -> Result<(R, Vec<String>)> {
+    let response = octocrab
+        .graphql_client()
+        ._post("/graphql", Some(payload))
+        .await
+        .context("sending GraphQL request")?;
+    // The `X-GitHub-SSO` header names organizations whose results SAML SSO
+    // withheld.
+    auth_warnings::inspect(response.headers());
+    auth_warnings::resolve_orgs(octocrab).await;
+    let response = octocrab::map_github_error(response).await?;
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading GraphQL response body")?;
+    let response: PartialResponse =
+        serde_json::from_str(&body).context("deserializing GraphQL response")?;
+    let warnings: Vec<String> = response
+        .errors
+        .iter()
+        .map(ResponseError::describe)
+        .collect();
+    match response
+        .data
+        .filter(|d| !d.is_null())
+        .map(serde_json::from_value::<R>)
+    {
+        Some(Ok(data)) => Ok((data, warnings)),
+        Some(Err(e)) if warnings.is_empty() => Err(e).context("malformed GraphQL response"),
+        _ if !warnings.is_empty() => bail!("{}", warnings.join("; ")),
+        _ => bail!("GraphQL response contained no data"),
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawTimelineItem {
-    #[serde(rename = "__typename")]
-    typename: String,
-    // IssueComment / PullRequestReview fields
-    author: Option<RawActor>,
-    #[serde(default)]
-    body: Option<String>,
-    #[serde(rename = "createdAt")]
-    created_at: Option<DateTime<Utc>>,
-    #[serde(rename = "submittedAt")]
-    submitted_at: Option<DateTime<Utc>>,
-    state: Option<ReviewState>,
-    // MergedEvent / ClosedEvent / ReopenedEvent / HeadRefForcePushedEvent
-    actor: Option<RawActor>,
-}
+/// A search node decoded on its own, so one malformed node (e.g. a field
+/// GitHub nulled out) is skipped with a warning instead of failing the page.
+#[derive(Debug)]
+struct Lenient<T>(Result<T, String>);
 
-#[derive(Debug, Deserialize)]
-struct RawDetailCommitNode {
-    commit: Option<RawDetailCommit>,
+impl<T: QueryFragment> QueryFragment for Lenient<T> {
+    type SchemaType = T::SchemaType;
+    type VariablesFields = T::VariablesFields;
+
+    const TYPE: Option<&'static str> = T::TYPE;
+
+    fn query(
+        builder: cynic::queries::SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>,
+    ) {
+        T::query(builder);
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawDetailCommit {
-    oid: String,
-    #[serde(rename = "messageHeadline", default)]
-    message_headline: String,
-    author: Option<RawCommitAuthor>,
-    #[serde(rename = "committedDate")]
-    committed_date: Option<DateTime<Utc>>,
-    #[serde(rename = "statusCheckRollup")]
-    status_check_rollup: Option<RawDetailStatusCheckRollup>,
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Lenient<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let node = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self(
+            serde_json::from_value(node).map_err(|e| e.to_string()),
+        ))
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawCommitAuthor {
-    name: Option<String>,
+/// The decoded search nodes, with a warning for each one skipped.
+fn lenient_nodes<T>(nodes: Option<Vec<Option<Lenient<T>>>>, warnings: &mut Vec<String>) -> Vec<T> {
+    nodes
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|node| match node.0 {
+            Ok(node) => Some(node),
+            Err(e) => {
+                warnings.push(format!("skipped a result: {e}"));
+                None
+            }
+        })
+        .collect()
 }
 
-#[derive(Debug, Deserialize)]
-struct RawDetailStatusCheckRollup {
-    state: Option<CommitCheckState>,
+/// Append `new` warnings to `all`, dropping duplicates across pages.
+fn merge_warnings(all: &mut Vec<String>, new: Vec<String>) {
+    for w in new {
+        if !all.contains(&w) {
+            all.push(w);
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawFile {
-    path: String,
-    #[serde(default)]
-    additions: u32,
-    #[serde(default)]
-    deletions: u32,
-    #[serde(rename = "changeType")]
-    change_type: Option<FileChangeType>,
+/// Fold a page's rate limit into `total`: the latest quota, with `cost`
+/// summed over every page fetched so far.
+pub(crate) fn accumulate_rate_limit(
+    total: &mut Option<RateLimitInfo>,
+    page: Option<RateLimitInfo>,
+) {
+    if let Some(page) = page {
+        let cost = total.as_ref().map_or(0, |t| t.cost) + page.cost;
+        *total = Some(RateLimitInfo { cost, ..page });
+    }
 }
 
 // ---------------------------------------------------------------------------
-// Raw search PR response types (Q1)
+// Shared response types
 // ---------------------------------------------------------------------------
 
-/// Raw PR as returned by the GraphQL API (camelCase, nested connections).
-#[derive(Debug, Deserialize)]
-struct RawPullRequest {
-    number: u64,
-    title: String,
-    #[serde(default)]
-    body: String,
-    state: PrState,
-    #[serde(rename = "isDraft", default)]
-    is_draft: bool,
-    mergeable: Option<MergeableState>,
-    #[serde(rename = "reviewDecision")]
-    review_decision: Option<ReviewDecision>,
-    #[serde(default)]
-    additions: u32,
-    #[serde(default)]
-    deletions: u32,
-    #[serde(rename = "headRefName", default)]
-    head_ref_name: String,
-    #[serde(rename = "baseRefName", default)]
-    base_ref_name: String,
-    #[serde(rename = "mergeStateStatus")]
-    merge_state_status: Option<MergeStateStatus>,
-    #[serde(rename = "headRepository")]
-    head_repository: Option<RawHeadRepository>,
-    url: String,
-    #[serde(rename = "updatedAt")]
-    updated_at: DateTime<Utc>,
-    #[serde(rename = "createdAt")]
-    created_at: DateTime<Utc>,
-    author: Option<RawActor>,
-    #[serde(rename = "authorAssociation")]
-    author_association: Option<AuthorAssociation>,
-    labels: Option<Connection<RawLabel>>,
-    assignees: Option<Connection<RawAssignee>>,
-    comments: Option<TotalCount>,
-    #[serde(rename = "latestReviews")]
-    latest_reviews: Option<Connection<RawLatestReview>>,
-    #[serde(rename = "reviewRequests")]
-    review_requests: Option<Connection<RawReviewRequest>>,
-    commits: Option<Connection<RawCommitNode>>,
-    participants: Option<Connection<RawAssignee>>,
-    repository: Option<RawRepository>,
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "RateLimit")]
+struct RawRateLimit {
+    limit: Count,
+    remaining: Count,
+    cost: Count,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawActor {
-    login: String,
-    #[serde(rename = "avatarUrl", default)]
-    avatar_url: String,
+impl From<RawRateLimit> for RateLimitInfo {
+    fn from(r: RawRateLimit) -> Self {
+        Self {
+            limit: r.limit.0,
+            remaining: r.remaining.0,
+            cost: r.cost.0,
+            host: None,
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawHeadRepository {
-    owner: RawActorLogin,
-    name: String,
+/// Pagination info from GraphQL.
+#[derive(cynic::QueryFragment, Debug, Clone)]
+pub(crate) struct PageInfo {
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
 }
 
-/// Minimal actor with only `login` (no `avatarUrl`), used for nested owner nodes.
-#[derive(Debug, Deserialize)]
-struct RawActorLogin {
-    login: String,
+fn page_cursor(total_count: Count, page_info: PageInfo) -> PageCursor {
+    PageCursor {
+        total: total_count.0 as usize,
+        next: page_info
+            .has_next_page
+            .then_some(page_info.end_cursor)
+            .flatten(),
+    }
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(bound(deserialize = "T: serde::de::DeserializeOwned"))]
-struct Connection<T> {
-    #[serde(default)]
-    nodes: Vec<Option<T>>,
+/// A connection of which only the `nodes` are read.
+macro_rules! connection {
+    ($name:ident, $graphql_type:literal, $node:ty $(, $variables:literal)?) => {
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = $graphql_type $(, variables = $variables)?)]
+        struct $name {
+            nodes: Option<Vec<Option<$node>>>,
+        }
+
+        impl $name {
+            fn into_nodes(self) -> impl Iterator<Item = $node> {
+                self.nodes.into_iter().flatten().flatten()
+            }
+        }
+    };
 }
 
-#[derive(Debug, Deserialize)]
-struct RawLabel {
-    name: String,
-    color: String,
+/// A connection of which only the `totalCount` is read.
+macro_rules! total_count {
+    ($name:ident, $graphql_type:literal) => {
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = $graphql_type)]
+        struct $name {
+            total_count: Count,
+        }
+    };
 }
 
-#[derive(Debug, Deserialize)]
-struct RawAssignee {
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Actor")]
+struct RawActor {
     login: String,
+    avatar_url: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct TotalCount {
-    #[serde(rename = "totalCount", default)]
-    total_count: u32,
+impl From<RawActor> for Actor {
+    fn from(a: RawActor) -> Self {
+        Self {
+            login: a.login,
+            avatar_url: a.avatar_url,
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawLatestReview {
-    state: Option<ReviewState>,
-    author: Option<RawActor>,
+/// Minimal actor with only `login` (no `avatarUrl`).
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Actor")]
+struct RawActorLogin {
+    login: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawReviewRequest {
-    #[serde(rename = "requestedReviewer")]
-    requested_reviewer: Option<RawReviewer>,
+impl RawActorLogin {
+    fn into_actor(self) -> Actor {
+        Actor {
+            login: self.login,
+            avatar_url: String::new(),
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawReviewer {
-    login: Option<String>,
+/// The author of a timeline comment or review, told apart from bots.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Actor")]
+struct RawTimelineActor {
+    /// `User`, `Bot`, …
+    #[cynic(rename = "__typename")]
+    typename: String,
+    login: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawCommitNode {
-    commit: Option<RawCommit>,
+impl RawTimelineActor {
+    fn is_bot(&self) -> bool {
+        self.typename == "Bot" || self.login.ends_with("[bot]")
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct RawCommit {
-    #[serde(rename = "statusCheckRollup")]
-    status_check_rollup: Option<RawStatusCheckRollup>,
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "User")]
+struct RawUser {
+    login: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawStatusCheckRollup {
-    contexts: Option<Connection<RawCheckContext>>,
-}
+connection!(RawUserConnection, "UserConnection", RawUser);
 
-/// A check context can be either a `CheckRun` or a `StatusContext`.
-/// We unify them into our `CheckRun` domain type.
-#[derive(Debug, Deserialize)]
-struct RawCheckContext {
-    // CheckRun fields
-    name: Option<String>,
-    status: Option<CheckStatus>,
-    conclusion: Option<CheckConclusion>,
-    #[serde(rename = "detailsUrl")]
-    details_url: Option<String>,
-    #[serde(rename = "startedAt")]
-    started_at: Option<DateTime<Utc>>,
-    #[serde(rename = "completedAt")]
-    completed_at: Option<DateTime<Utc>>,
-    #[serde(rename = "checkSuite")]
-    check_suite: Option<RawCheckSuite>,
-    // StatusContext fields
-    context: Option<String>,
-    state: Option<String>,
-    #[serde(rename = "targetUrl")]
-    target_url: Option<String>,
+impl RawUserConnection {
+    fn into_actors(self) -> Vec<Actor> {
+        self.into_nodes()
+            .map(|u| Actor {
+                login: u.login,
+                avatar_url: String::new(),
+            })
+            .collect()
+    }
+
+    fn into_logins(self) -> Vec<String> {
+        self.into_nodes().map(|u| u.login).collect()
+    }
 }
 
-/// Nested `checkSuite.workflowRun` from the GraphQL response.
-#[derive(Debug, Deserialize)]
-struct RawCheckSuite {
-    #[serde(rename = "workflowRun")]
-    workflow_run: Option<RawCheckSuiteWorkflowRun>,
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Team")]
+struct RawTeam {
+    /// `org/team-slug`.
+    combined_slug: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawCheckSuiteWorkflowRun {
-    #[serde(rename = "databaseId")]
-    database_id: Option<u64>,
-    workflow: Option<RawCheckSuiteWorkflow>,
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Label")]
+struct RawLabel {
+    name: String,
+    color: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawCheckSuiteWorkflow {
-    name: Option<String>,
+connection!(RawLabelConnection, "LabelConnection", RawLabel);
+
+fn extract_labels(labels: Option<RawLabelConnection>) -> Vec<Label> {
+    labels
+        .into_iter()
+        .flat_map(RawLabelConnection::into_nodes)
+        .map(|l| Label {
+            name: l.name,
+            color: l.color,
+        })
+        .collect()
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository")]
 struct RawRepository {
-    #[serde(rename = "nameWithOwner")]
     name_with_owner: String,
 }
 
-/// Raw Issue as returned by the GraphQL API.
-#[derive(Debug, Deserialize)]
-struct RawIssue {
-    number: u64,
+impl RawRepository {
+    fn repo_ref(&self) -> Option<RepoRef> {
+        RepoRef::from_full_name(&self.name_with_owner)
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Milestone")]
+struct RawMilestone {
     title: String,
-    #[serde(default)]
-    body: String,
-    state: IssueState,
-    url: String,
-    #[serde(rename = "updatedAt")]
-    updated_at: DateTime<Utc>,
-    #[serde(rename = "createdAt")]
-    created_at: DateTime<Utc>,
-    author: Option<RawActor>,
-    assignees: Option<Connection<RawAssignee>>,
-    labels: Option<Connection<RawLabel>>,
-    comments: Option<TotalCount>,
-    #[serde(rename = "reactionGroups", default)]
-    reaction_groups: Vec<RawReactionGroup>,
-    participants: Option<Connection<RawAssignee>>,
-    repository: Option<RawRepository>,
 }
 
-#[derive(Debug, Deserialize)]
+total_count!(RawCommentCount, "IssueCommentConnection");
+total_count!(RawReactorCount, "ReactingUserConnection");
+
+#[derive(cynic::Enum, Debug, Clone, Copy)]
+#[cynic(graphql_type = "ReactionContent")]
+enum RawReactionContent {
+    ThumbsUp,
+    ThumbsDown,
+    Laugh,
+    Hooray,
+    Confused,
+    Heart,
+    Rocket,
+    Eyes,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ReactionGroup")]
 struct RawReactionGroup {
-    content: String,
-    users: TotalCount,
+    content: RawReactionContent,
+    users: RawReactorCount,
+}
+
+fn parse_reaction_groups(groups: Option<Vec<RawReactionGroup>>) -> ReactionGroups {
+    let mut r = ReactionGroups::default();
+    for g in groups.into_iter().flatten() {
+        let count = g.users.total_count.0;
+        match g.content {
+            RawReactionContent::ThumbsUp => r.thumbs_up = count,
+            RawReactionContent::ThumbsDown => r.thumbs_down = count,
+            RawReactionContent::Laugh => r.laugh = count,
+            RawReactionContent::Hooray => r.hooray = count,
+            RawReactionContent::Confused => r.confused = count,
+            RawReactionContent::Heart => r.heart = count,
+            RawReactionContent::Rocket => r.rocket = count,
+            RawReactionContent::Eyes => r.eyes = count,
+        }
+    }
+    r
 }
 
 // ---------------------------------------------------------------------------
-// Conversion helpers: Raw → Domain
+// Timeline response types
 // ---------------------------------------------------------------------------
 
-fn extract_labels(labels: Option<Connection<RawLabel>>) -> Vec<Label> {
-    labels
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .map(|l| Label {
-                    name: l.name,
-                    color: l.color,
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "IssueComment")]
+struct RawTimelineComment {
+    author: Option<RawTimelineActor>,
+    body: String,
+    created_at: DateTime<Utc>,
+    url: String,
+    is_minimized: bool,
 }
 
-fn extract_assignees(assignees: Option<Connection<RawAssignee>>) -> Vec<Actor> {
-    assignees
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .map(|a| Actor {
-                    login: a.login,
-                    avatar_url: String::new(),
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+impl From<RawTimelineComment> for TimelineEvent {
+    fn from(c: RawTimelineComment) -> Self {
+        Self::Comment {
+            is_bot: c.author.as_ref().is_some_and(RawTimelineActor::is_bot),
+            author: c.author.map(|a| a.login),
+            body: c.body,
+            created_at: c.created_at,
+            url: Some(c.url),
+            is_minimized: c.is_minimized,
+        }
+    }
 }
 
-fn extract_review_requests(requests: Option<Connection<RawReviewRequest>>) -> Vec<Actor> {
-    requests
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .filter_map(|rr| {
-                    rr.requested_reviewer.and_then(|r| {
-                        r.login.map(|login| Actor {
-                            login,
-                            avatar_url: String::new(),
-                        })
-                    })
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestReview")]
+struct RawTimelineReview {
+    author: Option<RawTimelineActor>,
+    state: ReviewState,
+    body: String,
+    submitted_at: Option<DateTime<Utc>>,
+    url: String,
 }
 
-fn extract_latest_reviews(reviews: Option<Connection<RawLatestReview>>) -> Vec<Review> {
-    reviews
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .map(|r| Review {
-                    author: r.author.map(|a| Actor {
-                        login: a.login,
-                        avatar_url: a.avatar_url,
-                    }),
-                    state: r.state.unwrap_or(ReviewState::Unknown),
-                    body: String::new(),
-                    submitted_at: None,
-                })
-                .collect()
+impl RawTimelineReview {
+    /// `None` for a pending review, which has not been submitted.
+    fn into_event(self) -> Option<TimelineEvent> {
+        Some(TimelineEvent::Review {
+            is_bot: self.author.as_ref().is_some_and(RawTimelineActor::is_bot),
+            author: self.author.map(|a| a.login),
+            state: self.state,
+            body: self.body,
+            submitted_at: self.submitted_at?,
+            url: Some(self.url),
         })
-        .unwrap_or_default()
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "MergedEvent")]
+struct RawMergedEvent {
+    actor: Option<RawActorLogin>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ClosedEvent")]
+struct RawClosedEvent {
+    actor: Option<RawActorLogin>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ReopenedEvent")]
+struct RawReopenedEvent {
+    actor: Option<RawActorLogin>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "HeadRefForcePushedEvent")]
+struct RawForcePushedEvent {
+    actor: Option<RawActorLogin>,
+    created_at: DateTime<Utc>,
 }
 
-/// Convert a single `RawCheckContext` into a domain `CheckRun`.
-fn convert_check_context(ctx: RawCheckContext) -> CheckRun {
-    let name = ctx
-        .name
-        .or(ctx.context)
-        .unwrap_or_else(|| "<unknown>".to_owned());
-    let url = ctx.details_url.or(ctx.target_url);
-
-    // StatusContext uses "state" (string) instead of typed status/conclusion.
-    let (status, conclusion) = if ctx.status.is_some() {
-        (ctx.status, ctx.conclusion)
-    } else if let Some(ref state) = ctx.state {
-        match state.as_str() {
-            "success" => (Some(CheckStatus::Completed), Some(CheckConclusion::Success)),
-            "failure" | "error" => (Some(CheckStatus::Completed), Some(CheckConclusion::Failure)),
-            "pending" => (Some(CheckStatus::InProgress), None),
-            _ => (None, None),
+impl From<RawMergedEvent> for TimelineEvent {
+    fn from(e: RawMergedEvent) -> Self {
+        Self::Merged {
+            actor: e.actor.map(|a| a.login),
+            created_at: e.created_at,
         }
-    } else {
-        (None, None)
-    };
+    }
+}
 
-    let (workflow_run_id, workflow_name) = ctx
-        .check_suite
-        .and_then(|cs| cs.workflow_run)
-        .map_or((None, None), |wr| {
-            (wr.database_id, wr.workflow.and_then(|w| w.name))
-        });
+impl From<RawClosedEvent> for TimelineEvent {
+    fn from(e: RawClosedEvent) -> Self {
+        Self::Closed {
+            actor: e.actor.map(|a| a.login),
+            created_at: e.created_at,
+        }
+    }
+}
 
-    CheckRun {
-        name,
-        status,
-        conclusion,
-        url,
-        workflow_run_id,
-        workflow_name,
-        started_at: ctx.started_at,
-        completed_at: ctx.completed_at,
-    }
-}
-
-/// Extract check runs from the commits connection (last-commit rollup).
-fn extract_check_runs(commits: Option<Connection<RawCommitNode>>) -> Vec<CheckRun> {
-    commits
-        .and_then(|c| c.nodes.into_iter().flatten().next())
-        .and_then(|cn| cn.commit)
-        .and_then(|c| c.status_check_rollup)
-        .and_then(|sr| sr.contexts)
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .map(convert_check_context)
-                .collect()
-        })
-        .unwrap_or_default()
+impl From<RawReopenedEvent> for TimelineEvent {
+    fn from(e: RawReopenedEvent) -> Self {
+        Self::Reopened {
+            actor: e.actor.map(|a| a.login),
+            created_at: e.created_at,
+        }
+    }
 }
 
-fn extract_participants(participants: Option<Connection<RawAssignee>>) -> Vec<String> {
-    participants
-        .map(|c| c.nodes.into_iter().flatten().map(|a| a.login).collect())
-        .unwrap_or_default()
+impl From<RawForcePushedEvent> for TimelineEvent {
+    fn from(e: RawForcePushedEvent) -> Self {
+        Self::ForcePushed {
+            actor: e.actor.map(|a| a.login),
+            created_at: e.created_at,
+        }
+    }
 }
 
-fn extract_timeline_events(
-    timeline_items: Option<Connection<RawTimelineItem>>,
-) -> Vec<TimelineEvent> {
-    timeline_items
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .filter_map(convert_timeline_item)
-                .collect()
-        })
-        .unwrap_or_default()
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "PullRequestTimelineItems")]
+enum RawPrTimelineItem {
+    IssueComment(RawTimelineComment),
+    PullRequestReview(RawTimelineReview),
+    MergedEvent(RawMergedEvent),
+    ClosedEvent(RawClosedEvent),
+    ReopenedEvent(RawReopenedEvent),
+    HeadRefForcePushedEvent(RawForcePushedEvent),
+    #[cynic(fallback)]
+    Other,
 }
 
-fn extract_detail_commits(all_commits: Option<Connection<RawDetailCommitNode>>) -> Vec<Commit> {
-    all_commits
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .filter_map(|cn| {
-                    let c = cn.commit?;
-                    Some(Commit {
-                        sha: c.oid,
-                        message: c.message_headline,
-                        author: c.author.and_then(|a| a.name),
-                        committed_date: c.committed_date,
-                        check_state: c.status_check_rollup.and_then(|r| r.state),
-                    })
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+impl RawPrTimelineItem {
+    fn into_event(self) -> Option<TimelineEvent> {
+        match self {
+            Self::IssueComment(c) => Some(c.into()),
+            Self::PullRequestReview(r) => r.into_event(),
+            Self::MergedEvent(e) => Some(e.into()),
+            Self::ClosedEvent(e) => Some(e.into()),
+            Self::ReopenedEvent(e) => Some(e.into()),
+            Self::HeadRefForcePushedEvent(e) => Some(e.into()),
+            Self::Other => None,
+        }
+    }
 }
 
-fn extract_files(files: Option<Connection<RawFile>>) -> Vec<File> {
-    files
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .map(|f| File {
-                    path: f.path,
-                    additions: f.additions,
-                    deletions: f.deletions,
-                    status: f.change_type,
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+connection!(
+    RawPrTimelineConnection,
+    "PullRequestTimelineItemsConnection",
+    RawPrTimelineItem
+);
+
+impl RawPrTimelineConnection {
+    fn into_events(self) -> Vec<TimelineEvent> {
+        self.into_nodes()
+            .filter_map(RawPrTimelineItem::into_event)
+            .collect()
+    }
 }
 
-fn extract_review_threads(
-    review_threads: Option<Connection<RawReviewThread>>,
-) -> Vec<ReviewThread> {
-    review_threads
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .map(|rt| ReviewThread {
-                    is_resolved: rt.is_resolved,
-                    comments: rt
-                        .comments
-                        .map(|cc| {
-                            cc.nodes
-                                .into_iter()
-                                .flatten()
-                                .map(|rc| crate::github::types::Comment {
-                                    author: rc.author.map(raw_actor_to_actor),
-                                    body: rc.body,
-                                    created_at: rc.created_at,
-                                })
-                                .collect()
-                        })
-                        .unwrap_or_default(),
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+/// The timeline of a PR shown as an issue: comments and state changes, as
+/// reviews and pushes belong to the PR view.
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "PullRequestTimelineItems")]
+enum RawPrConversationItem {
+    IssueComment(RawTimelineComment),
+    MergedEvent(RawMergedEvent),
+    ClosedEvent(RawClosedEvent),
+    ReopenedEvent(RawReopenedEvent),
+    #[cynic(fallback)]
+    Other,
 }
 
-fn extract_detail_reviews(reviews: Option<Connection<RawReview>>) -> Vec<Review> {
-    reviews
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .map(|r| Review {
-                    author: r.author.map(raw_actor_to_actor),
-                    state: r.state,
-                    body: r.body,
-                    submitted_at: r.submitted_at,
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+impl RawPrConversationItem {
+    fn into_event(self) -> Option<TimelineEvent> {
+        match self {
+            Self::IssueComment(c) => Some(c.into()),
+            Self::MergedEvent(e) => Some(e.into()),
+            Self::ClosedEvent(e) => Some(e.into()),
+            Self::ReopenedEvent(e) => Some(e.into()),
+            Self::Other => None,
+        }
+    }
+}
+
+connection!(
+    RawPrConversationConnection,
+    "PullRequestTimelineItemsConnection",
+    RawPrConversationItem
+);
+
+impl RawPrConversationConnection {
+    fn into_events(self) -> Vec<TimelineEvent> {
+        self.into_nodes()
+            .filter_map(RawPrConversationItem::into_event)
+            .collect()
+    }
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "IssueTimelineItems")]
+enum RawIssueTimelineItem {
+    IssueComment(RawTimelineComment),
+    ClosedEvent(RawClosedEvent),
+    ReopenedEvent(RawReopenedEvent),
+    #[cynic(fallback)]
+    Other,
+}
+
+impl RawIssueTimelineItem {
+    fn into_event(self) -> Option<TimelineEvent> {
+        match self {
+            Self::IssueComment(c) => Some(c.into()),
+            Self::ClosedEvent(e) => Some(e.into()),
+            Self::ReopenedEvent(e) => Some(e.into()),
+            Self::Other => None,
+        }
+    }
+}
+
+connection!(
+    RawIssueTimelineConnection,
+    "IssueTimelineItemsConnection",
+    RawIssueTimelineItem
+);
+
+impl RawIssueTimelineConnection {
+    fn into_events(self) -> Vec<TimelineEvent> {
+        self.into_nodes()
+            .filter_map(RawIssueTimelineItem::into_event)
+            .collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
-// Conversion: Raw → Domain
+// Check response types
 // ---------------------------------------------------------------------------
 
-impl RawPullRequest {
-    fn into_domain(self) -> PullRequest {
-        let author = self.author.map(|a| Actor {
-            login: a.login,
-            avatar_url: a.avatar_url,
-        });
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Workflow")]
+struct RawWorkflow {
+    name: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "WorkflowRun")]
+struct RawWorkflowRun {
+    database_id: Option<Number>,
+    workflow: RawWorkflow,
+}
+
+/// Nested `checkSuite.workflowRun` of a check run.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "CheckSuite")]
+struct RawCheckSuite {
+    workflow_run: Option<RawWorkflowRun>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "CheckRun")]
+struct RawCheckRun {
+    database_id: Option<Number>,
+    name: String,
+    status: CheckStatus,
+    conclusion: Option<CheckConclusion>,
+    details_url: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    check_suite: RawCheckSuite,
+}
+
+impl RawCheckRun {
+    fn into_domain(self) -> CheckRun {
+        let (workflow_run_id, workflow_name) =
+            self.check_suite.workflow_run.map_or((None, None), |wr| {
+                (wr.database_id.map(|id| id.0), Some(wr.workflow.name))
+            });
+        // An Actions job's check run shares its id.
+        let job_id = workflow_run_id.and(self.database_id.map(|id| id.0));
+        CheckRun {
+            name: self.name,
+            status: Some(self.status),
+            conclusion: self.conclusion,
+            url: self.details_url,
+            workflow_run_id,
+            workflow_name,
+            job_id,
+            started_at: self.started_at,
+            completed_at: self.completed_at,
+            is_required: false,
+        }
+    }
+}
 
-        let repo = self
-            .repository
-            .and_then(|r| RepoRef::from_full_name(&r.name_with_owner));
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "StatusContext")]
+struct RawStatusContext {
+    context: String,
+    state: CommitCheckState,
+    target_url: Option<String>,
+}
+
+impl RawStatusContext {
+    fn into_domain(self) -> CheckRun {
+        let (status, conclusion) = match self.state {
+            CommitCheckState::Success => {
+                (Some(CheckStatus::Completed), Some(CheckConclusion::Success))
+            }
+            CommitCheckState::Failure | CommitCheckState::Error => {
+                (Some(CheckStatus::Completed), Some(CheckConclusion::Failure))
+            }
+            CommitCheckState::Pending => (Some(CheckStatus::InProgress), None),
+            CommitCheckState::Expected | CommitCheckState::Unknown => (None, None),
+        };
+        CheckRun {
+            name: self.context,
+            status,
+            conclusion,
+            url: self.target_url,
+            workflow_run_id: None,
+            workflow_name: None,
+            job_id: None,
+            started_at: None,
+            completed_at: None,
+            is_required: false,
+        }
+    }
+}
+
+/// A check context is either a `CheckRun` or a `StatusContext`; both become
+/// a domain `CheckRun`.
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "StatusCheckRollupContext")]
+enum RawCheckContext {
+    CheckRun(RawCheckRun),
+    StatusContext(RawStatusContext),
+    #[cynic(fallback)]
+    Other,
+}
+
+impl RawCheckContext {
+    fn into_domain(self) -> Option<CheckRun> {
+        match self {
+            Self::CheckRun(run) => Some(run.into_domain()),
+            Self::StatusContext(status) => Some(status.into_domain()),
+            Self::Other => None,
+        }
+    }
+}
+
+connection!(
+    RawCheckContextConnection,
+    "StatusCheckRollupContextConnection",
+    RawCheckContext
+);
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "StatusCheckRollup")]
+struct RawStatusCheckRollup {
+    #[arguments(first: 50)]
+    contexts: RawCheckContextConnection,
+}
+
+/// The combined CI state of a commit.
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "StatusCheckRollup")]
+struct RawRollupState {
+    state: CommitCheckState,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Commit")]
+struct RawRollupCommit {
+    status_check_rollup: Option<RawRollupState>,
+}
+
+/// A check run's name and whether the PR requires it.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "CheckRun", variables = "ItemVariables")]
+struct RawRequiredCheckRun {
+    name: String,
+    #[arguments(pullRequestNumber: $number)]
+    is_required: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "StatusContext", variables = "ItemVariables")]
+struct RawRequiredStatusContext {
+    context: String,
+    #[arguments(pullRequestNumber: $number)]
+    is_required: bool,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "StatusCheckRollupContext", variables = "ItemVariables")]
+enum RawRequiredCheckContext {
+    CheckRun(RawRequiredCheckRun),
+    StatusContext(RawRequiredStatusContext),
+    #[cynic(fallback)]
+    Other,
+}
+
+impl RawRequiredCheckContext {
+    /// The check's name, if the PR requires it.
+    fn required_name(self) -> Option<String> {
+        match self {
+            Self::CheckRun(c) => c.is_required.then_some(c.name),
+            Self::StatusContext(c) => c.is_required.then_some(c.context),
+            Self::Other => None,
+        }
+    }
+}
+
+connection!(
+    RawRequiredCheckContextConnection,
+    "StatusCheckRollupContextConnection",
+    RawRequiredCheckContext,
+    "ItemVariables"
+);
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "StatusCheckRollup", variables = "ItemVariables")]
+struct RawRequiredStatusCheckRollup {
+    #[arguments(first: 50)]
+    contexts: RawRequiredCheckContextConnection,
+}
+
+// ---------------------------------------------------------------------------
+// Raw search PR response types (Q1)
+// ---------------------------------------------------------------------------
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "SearchVariables")]
+struct SearchPullRequestsQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(query: $query, type: ISSUE, first: $first, after: $after)]
+    search: RawPrSearchConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "SearchResultItemConnection")]
+struct RawPrSearchConnection {
+    page_info: PageInfo,
+    /// Decoded one by one (see [`Lenient`]).
+    nodes: Option<Vec<Option<Lenient<RawPrSearchItem>>>>,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "SearchResultItem")]
+enum RawPrSearchItem {
+    PullRequest(Box<RawPullRequest>),
+    #[cynic(fallback)]
+    Other,
+}
+
+/// A PR as listed by a search, with its last commit's checks.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest")]
+struct RawPullRequest {
+    number: Number,
+    title: String,
+    body: String,
+    state: PrState,
+    is_draft: bool,
+    mergeable: MergeableState,
+    review_decision: Option<ReviewDecision>,
+    additions: Count,
+    deletions: Count,
+    head_ref_name: String,
+    base_ref_name: String,
+    #[cynic(feature = "merge_state_status")]
+    merge_state_status: Option<MergeStateStatus>,
+    head_repository: Option<RawHeadRepository>,
+    url: String,
+    updated_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    author: Option<RawActor>,
+    author_association: AuthorAssociation,
+    #[arguments(first: 10)]
+    labels: Option<RawLabelConnection>,
+    #[arguments(first: 10)]
+    assignees: RawUserConnection,
+    comments: RawCommentCount,
+    reaction_groups: Option<Vec<RawReactionGroup>>,
+    #[arguments(first: 10)]
+    latest_reviews: Option<RawLatestReviewConnection>,
+    #[arguments(first: 10)]
+    review_requests: Option<RawReviewRequestConnection>,
+    /// Review request and first review times, for the review time columns.
+    #[cynic(alias, rename = "timelineItems")]
+    #[arguments(itemTypes: [REVIEW_REQUESTED_EVENT], first: 1)]
+    first_review_request: RawReviewRequestedConnection,
+    #[cynic(alias, rename = "timelineItems")]
+    #[arguments(itemTypes: [REVIEW_REQUESTED_EVENT], last: 1)]
+    last_review_request: RawReviewRequestedConnection,
+    #[cynic(alias, rename = "reviews")]
+    #[arguments(first: 5)]
+    earliest_reviews: Option<RawSubmittedReviewConnection>,
+    #[arguments(first: 30)]
+    participants: RawUserConnection,
+    repository: RawRepository,
+    base_ref: Option<RawBaseRef>,
+    #[arguments(last: 1)]
+    commits: RawHeadCommitConnection,
+}
 
+impl RawPullRequest {
+    fn into_domain(self) -> PullRequest {
+        let required = self
+            .base_ref
+            .map(RawBaseRef::required_contexts)
+            .unwrap_or_default();
+        let (head_sha, check_runs) = self.commits.into_checks(&required);
+        let (review_requests, team_review_requests) = extract_review_requests(self.review_requests);
+        let author = self.author.as_ref().map(|a| a.login.as_str());
+        let review_times = ReviewTimes {
+            first_requested_at: self.first_review_request.created_at(),
+            last_requested_at: self.last_review_request.created_at(),
+            first_reviewed_at: first_review_at(self.earliest_reviews, author),
+        };
         PullRequest {
-            number: self.number,
+            number: self.number.0,
             title: self.title,
             body: self.body,
-            author,
+            author: self.author.map(Actor::from),
             state: self.state,
             is_draft: self.is_draft,
-            mergeable: self.mergeable,
+            mergeable: Some(self.mergeable),
             review_decision: self.review_decision,
-            additions: self.additions,
-            deletions: self.deletions,
+            additions: self.additions.0,
+            deletions: self.deletions.0,
             head_ref: self.head_ref_name,
+            head_sha,
             base_ref: self.base_ref_name,
             labels: extract_labels(self.labels),
-            assignees: extract_assignees(self.assignees),
+            assignees: self.assignees.into_actors(),
             commits: Vec::new(),
             comments: Vec::new(),
             review_threads: Vec::new(),
-            review_requests: extract_review_requests(self.review_requests),
+            review_requests,
+            team_review_requests,
             reviews: extract_latest_reviews(self.latest_reviews),
+            review_times,
             timeline_events: Vec::new(),
             files: Vec::new(),
-            check_runs: extract_check_runs(self.commits),
+            check_runs,
             updated_at: self.updated_at,
             created_at: self.created_at,
             url: self.url,
-            repo,
-            comment_count: self.comments.map_or(0, |c| c.total_count),
-            author_association: self.author_association,
-            participants: extract_participants(self.participants),
+            repo: self.repository.repo_ref(),
+            comment_count: self.comments.total_count.0,
+            reactions: parse_reaction_groups(self.reaction_groups),
+            author_association: Some(self.author_association),
+            participants: self.participants.into_logins(),
             merge_state_status: self.merge_state_status,
             head_repo_owner: self.head_repository.as_ref().map(|r| r.owner.login.clone()),
             head_repo_name: self.head_repository.map(|r| r.name),
@@ -886,100 +1110,1142 @@ impl RawPullRequest {
     }
 }
 
-impl RawIssue {
-    fn into_domain(self) -> Issue {
-        let author = self.author.map(|a| Actor {
-            login: a.login,
-            avatar_url: a.avatar_url,
-        });
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository")]
+struct RawHeadRepository {
+    owner: RawRepositoryOwner,
+    name: String,
+}
 
-        let labels = self
-            .labels
-            .map(|c| {
-                c.nodes
-                    .into_iter()
-                    .flatten()
-                    .map(|l| Label {
-                        name: l.name,
-                        color: l.color,
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "RepositoryOwner")]
+struct RawRepositoryOwner {
+    login: String,
+}
 
-        let assignees = self
-            .assignees
-            .map(|c| {
-                c.nodes
-                    .into_iter()
-                    .flatten()
-                    .map(|a| Actor {
-                        login: a.login,
-                        avatar_url: String::new(),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestReview")]
+struct RawLatestReview {
+    state: ReviewState,
+    author: Option<RawActorLogin>,
+}
 
-        let comment_count = self.comments.map_or(0, |c| c.total_count);
+connection!(
+    RawLatestReviewConnection,
+    "PullRequestReviewConnection",
+    RawLatestReview
+);
 
-        let reactions = parse_reaction_groups(&self.reaction_groups);
+fn extract_latest_reviews(reviews: Option<RawLatestReviewConnection>) -> Vec<Review> {
+    reviews
+        .into_iter()
+        .flat_map(RawLatestReviewConnection::into_nodes)
+        .map(|r| Review {
+            author: r.author.map(RawActorLogin::into_actor),
+            state: r.state,
+            body: String::new(),
+            submitted_at: None,
+            on_behalf_of: Vec::new(),
+        })
+        .collect()
+}
 
-        let repo = self
-            .repository
-            .and_then(|r| RepoRef::from_full_name(&r.name_with_owner));
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ReviewRequestedEvent")]
+struct RawReviewRequested {
+    created_at: DateTime<Utc>,
+}
 
-        Issue {
-            number: self.number,
-            title: self.title,
-            body: self.body,
-            author,
-            state: self.state,
-            assignees,
-            comments: Vec::new(),
-            reactions,
-            labels,
-            updated_at: self.updated_at,
-            created_at: self.created_at,
-            url: self.url,
-            repo,
-            comment_count,
-            participants: self
-                .participants
-                .map(|c| c.nodes.into_iter().flatten().map(|a| a.login).collect())
-                .unwrap_or_default(),
-        }
-    }
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "PullRequestTimelineItems")]
+enum RawReviewRequestedItem {
+    ReviewRequestedEvent(RawReviewRequested),
+    #[cynic(fallback)]
+    Other,
 }
 
-fn parse_reaction_groups(groups: &[RawReactionGroup]) -> ReactionGroups {
-    let mut r = ReactionGroups::default();
-    for g in groups {
-        let count = g.users.total_count;
-        match g.content.as_str() {
-            "THUMBS_UP" => r.thumbs_up = count,
-            "THUMBS_DOWN" => r.thumbs_down = count,
-            "LAUGH" => r.laugh = count,
-            "HOORAY" => r.hooray = count,
-            "CONFUSED" => r.confused = count,
-            "HEART" => r.heart = count,
-            "ROCKET" => r.rocket = count,
-            "EYES" => r.eyes = count,
-            _ => {}
-        }
+connection!(
+    RawReviewRequestedConnection,
+    "PullRequestTimelineItemsConnection",
+    RawReviewRequestedItem
+);
+
+impl RawReviewRequestedConnection {
+    fn created_at(self) -> Option<DateTime<Utc>> {
+        self.into_nodes().find_map(|item| match item {
+            RawReviewRequestedItem::ReviewRequestedEvent(e) => Some(e.created_at),
+            RawReviewRequestedItem::Other => None,
+        })
     }
-    r
 }
 
-// ---------------------------------------------------------------------------
-// Public API
-// ---------------------------------------------------------------------------
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestReview")]
+struct RawSubmittedReview {
+    submitted_at: Option<DateTime<Utc>>,
+    author: Option<RawActorLogin>,
+}
 
-/// Result of a single page of PR search results.
-pub(crate) struct SearchPrPage {
-    pub pull_requests: Vec<PullRequest>,
-    pub page_info: PageInfo,
-    pub rate_limit: Option<RateLimitInfo>,
+connection!(
+    RawSubmittedReviewConnection,
+    "PullRequestReviewConnection",
+    RawSubmittedReview
+);
+
+/// The first review not by `author`, the PR's author.
+fn first_review_at(
+    reviews: Option<RawSubmittedReviewConnection>,
+    author: Option<&str>,
+) -> Option<DateTime<Utc>> {
+    reviews.and_then(|c| {
+        c.into_nodes()
+            .filter(|r| r.author.as_ref().map(|a| a.login.as_str()) != author)
+            .find_map(|r| r.submitted_at)
+    })
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "RequestedReviewer")]
+enum RawReviewer {
+    User(RawUser),
+    Team(RawTeam),
+    /// Mannequins, which cannot review.
+    #[cynic(fallback)]
+    Other,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ReviewRequest")]
+struct RawReviewRequest {
+    requested_reviewer: Option<RawReviewer>,
+}
+
+connection!(
+    RawReviewRequestConnection,
+    "ReviewRequestConnection",
+    RawReviewRequest
+);
+
+/// Split review requests into requested users and requested teams
+/// (`org/team-slug`).
+fn extract_review_requests(
+    requests: Option<RawReviewRequestConnection>,
+) -> (Vec<Actor>, Vec<String>) {
+    let mut users = Vec::new();
+    let mut teams = Vec::new();
+    let reviewers = requests
+        .into_iter()
+        .flat_map(RawReviewRequestConnection::into_nodes)
+        .filter_map(|rr| rr.requested_reviewer);
+    for reviewer in reviewers {
+        match reviewer {
+            RawReviewer::User(user) => users.push(Actor {
+                login: user.login,
+                avatar_url: String::new(),
+            }),
+            RawReviewer::Team(team) => teams.push(team.combined_slug),
+            RawReviewer::Other => {}
+        }
+    }
+    (users, teams)
+}
+
+/// `baseRef.branchProtectionRule`: `null` when the branch is unprotected or
+/// the viewer cannot read its protection settings.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Ref")]
+struct RawBaseRef {
+    branch_protection_rule: Option<RawBranchProtectionRule>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "BranchProtectionRule")]
+struct RawBranchProtectionRule {
+    required_status_check_contexts: Option<Vec<Option<String>>>,
+}
+
+impl RawBaseRef {
+    fn required_contexts(self) -> Vec<String> {
+        self.branch_protection_rule
+            .and_then(|r| r.required_status_check_contexts)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .collect()
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Commit")]
+struct RawHeadCommit {
+    oid: String,
+    status_check_rollup: Option<RawStatusCheckRollup>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestCommit")]
+struct RawHeadCommitNode {
+    commit: RawHeadCommit,
+}
+
+connection!(
+    RawHeadCommitConnection,
+    "PullRequestCommitConnection",
+    RawHeadCommitNode
+);
+
+impl RawHeadCommitConnection {
+    /// The head commit's oid and check runs.
+    ///
+    /// Checks named in `required` (the base branch protection's required
+    /// contexts) are marked required.
+    fn into_checks(self, required: &[String]) -> (String, Vec<CheckRun>) {
+        let Some(head) = self.into_nodes().next() else {
+            return (String::new(), Vec::new());
+        };
+        let check_runs = head
+            .commit
+            .status_check_rollup
+            .into_iter()
+            .flat_map(|r| r.contexts.into_nodes())
+            .filter_map(RawCheckContext::into_domain)
+            .map(|mut cr| {
+                cr.is_required |= required.contains(&cr.name);
+                cr
+            })
+            .collect();
+        (head.commit.oid, check_runs)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Raw search issue response types
+// ---------------------------------------------------------------------------
+
+/// Issue search; issue-only searches never return the PRs it also selects.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "SearchVariables")]
+struct SearchIssuesQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(query: $query, type: ISSUE, first: $first, after: $after)]
+    search: RawIssueSearchConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "SearchResultItemConnection")]
+struct RawIssueSearchConnection {
+    page_info: PageInfo,
+    /// Decoded one by one (see [`Lenient`]).
+    nodes: Option<Vec<Option<Lenient<RawIssueSearchItem>>>>,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "SearchResultItem")]
+enum RawIssueSearchItem {
+    Issue(RawIssue),
+    PullRequest(RawIssuePr),
+    #[cynic(fallback)]
+    Other,
+}
+
+impl RawIssueSearchItem {
+    fn into_domain(self) -> Option<Issue> {
+        match self {
+            Self::Issue(issue) => Some(issue.into_domain()),
+            Self::PullRequest(pr) => Some(pr.into_domain()),
+            Self::Other => None,
+        }
+    }
+}
+
+/// Raw Issue as returned by the GraphQL API.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Issue")]
+struct RawIssue {
+    number: Number,
+    title: String,
+    body: String,
+    state: IssueState,
+    url: String,
+    updated_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    author: Option<RawActor>,
+    #[arguments(first: 10)]
+    assignees: RawUserConnection,
+    #[arguments(first: 10)]
+    labels: Option<RawLabelConnection>,
+    milestone: Option<RawMilestone>,
+    comments: RawCommentCount,
+    reaction_groups: Option<Vec<RawReactionGroup>>,
+    #[arguments(first: 30)]
+    participants: RawUserConnection,
+    repository: RawRepository,
+}
+
+impl RawIssue {
+    fn into_domain(self) -> Issue {
+        Issue {
+            number: self.number.0,
+            title: self.title,
+            body: self.body,
+            author: self.author.map(Actor::from),
+            state: self.state,
+            assignees: self.assignees.into_actors(),
+            comments: Vec::new(),
+            reactions: parse_reaction_groups(self.reaction_groups),
+            labels: extract_labels(self.labels),
+            milestone: self.milestone.map(|m| m.title),
+            updated_at: self.updated_at,
+            created_at: self.created_at,
+            url: self.url,
+            repo: self.repository.repo_ref(),
+            comment_count: self.comments.total_count.0,
+            participants: self.participants.into_logins(),
+            pr_state: None,
+            is_draft: false,
+        }
+    }
+}
+
+/// A PR listed by mixed searches and lookups, with the issue row fields.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest")]
+struct RawIssuePr {
+    number: Number,
+    title: String,
+    body: String,
+    state: PrState,
+    is_draft: bool,
+    url: String,
+    updated_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    author: Option<RawActor>,
+    #[arguments(first: 10)]
+    assignees: RawUserConnection,
+    #[arguments(first: 10)]
+    labels: Option<RawLabelConnection>,
+    milestone: Option<RawMilestone>,
+    comments: RawCommentCount,
+    reaction_groups: Option<Vec<RawReactionGroup>>,
+    #[arguments(first: 30)]
+    participants: RawUserConnection,
+    repository: RawRepository,
+}
+
+impl RawIssuePr {
+    /// The PR as an issue, with its own state in `pr_state`.
+    fn into_domain(self) -> Issue {
+        let issue = RawIssue {
+            number: self.number,
+            title: self.title,
+            body: self.body,
+            state: match self.state {
+                PrState::Open => IssueState::Open,
+                PrState::Closed | PrState::Merged => IssueState::Closed,
+            },
+            url: self.url,
+            updated_at: self.updated_at,
+            created_at: self.created_at,
+            author: self.author,
+            assignees: self.assignees,
+            labels: self.labels,
+            milestone: self.milestone,
+            comments: self.comments,
+            reaction_groups: self.reaction_groups,
+            participants: self.participants,
+            repository: self.repository,
+        };
+        Issue {
+            pr_state: Some(self.state),
+            is_draft: self.is_draft,
+            ..issue.into_domain()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// PR detail response types (Q2)
+// ---------------------------------------------------------------------------
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "ItemVariables")]
+struct PullRequestDetailQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
+    repository: Option<PrDetailRepo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "ItemVariables")]
+struct PrDetailRepo {
+    #[arguments(number: $number)]
+    pull_request: Option<RawPrDetail>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest")]
+struct RawPrDetail {
+    body: String,
+    mergeable: MergeableState,
+    #[cynic(feature = "merge_queue")]
+    is_merge_queue_enabled: bool,
+    #[cynic(feature = "merge_queue")]
+    merge_queue_entry: Option<RawMergeQueueEntry>,
+    base_ref: Option<RawDetailBaseRef>,
+    #[arguments(last: 50)]
+    reviews: Option<RawReviewConnection>,
+    #[arguments(first: 50)]
+    review_threads: RawReviewThreadConnection,
+    #[arguments(last: 100)]
+    timeline_items: RawPrTimelineConnection,
+    #[arguments(first: 100)]
+    commits: RawPagedCommits,
+    #[arguments(first: 100)]
+    files: Option<RawPagedFiles>,
+    #[cynic(alias, rename = "commits")]
+    #[arguments(last: 1)]
+    last_commit: RawLastCommitConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "MergeQueueEntry")]
+struct RawMergeQueueEntry {
+    position: Count,
+    state: MergeQueueEntryState,
+    /// Seconds.
+    estimated_time_to_merge: Option<Number>,
+}
+
+impl From<RawMergeQueueEntry> for MergeQueueEntry {
+    fn from(e: RawMergeQueueEntry) -> Self {
+        Self {
+            position: e.position.0,
+            state: e.state,
+            estimated_secs: e.estimated_time_to_merge.map(|s| s.0),
+        }
+    }
+}
+
+/// Base branch rules as seen by the viewer: the classic protection rule is
+/// `null` without read access to it; rulesets are readable by everyone.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Ref")]
+struct RawDetailBaseRef {
+    branch_protection_rule: Option<RawProtectionDetail>,
+    #[cynic(feature = "repository_rules")]
+    #[arguments(first: 50)]
+    rules: Option<RawRepositoryRuleConnection>,
+    target: Option<RawCodeOwnersTarget>,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "GitObject")]
+enum RawCodeOwnersTarget {
+    Commit(RawCodeOwnersCommit),
+    #[cynic(fallback)]
+    Other,
+}
+
+/// `CODEOWNERS` candidates on the base branch head, in the order GitHub
+/// looks them up.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Commit")]
+struct RawCodeOwnersCommit {
+    #[cynic(alias, rename = "file")]
+    #[arguments(path: ".github/CODEOWNERS")]
+    github_dir: Option<RawTreeEntry>,
+    #[cynic(alias, rename = "file")]
+    #[arguments(path: "CODEOWNERS")]
+    root: Option<RawTreeEntry>,
+    #[cynic(alias, rename = "file")]
+    #[arguments(path: "docs/CODEOWNERS")]
+    docs_dir: Option<RawTreeEntry>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "TreeEntry")]
+struct RawTreeEntry {
+    object: Option<RawBlobObject>,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "GitObject")]
+enum RawBlobObject {
+    Blob(RawBlob),
+    #[cynic(fallback)]
+    Other,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Blob")]
+struct RawBlob {
+    /// `None` for binary blobs.
+    text: Option<String>,
+}
+
+impl RawTreeEntry {
+    fn text(&self) -> Option<&str> {
+        match self.object.as_ref()? {
+            RawBlobObject::Blob(blob) => blob.text.as_deref(),
+            RawBlobObject::Other => None,
+        }
+    }
+}
+
+fn extract_code_owners(base_ref: Option<&RawDetailBaseRef>) -> CodeOwners {
+    let Some(RawCodeOwnersTarget::Commit(commit)) = base_ref.and_then(|b| b.target.as_ref()) else {
+        return CodeOwners::default();
+    };
+    [&commit.github_dir, &commit.root, &commit.docs_dir]
+        .into_iter()
+        .find_map(|e| e.as_ref()?.text())
+        .map(CodeOwners::parse)
+        .unwrap_or_default()
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "BranchProtectionRule")]
+#[expect(clippy::struct_excessive_bools)]
+struct RawProtectionDetail {
+    requires_approving_reviews: bool,
+    required_approving_review_count: Option<Count>,
+    requires_code_owner_reviews: bool,
+    requires_conversation_resolution: bool,
+    required_status_check_contexts: Option<Vec<Option<String>>>,
+    requires_commit_signatures: bool,
+}
+
+#[derive(cynic::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+#[cynic(graphql_type = "RepositoryRuleType", non_exhaustive)]
+enum RawRuleType {
+    PullRequest,
+    RequiredSignatures,
+    RequiredStatusChecks,
+    #[cynic(fallback)]
+    Other,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "RepositoryRule")]
+struct RawRepositoryRule {
+    #[cynic(rename = "type")]
+    kind: RawRuleType,
+    parameters: Option<RawRuleParameters>,
+}
+
+connection!(
+    RawRepositoryRuleConnection,
+    "RepositoryRuleConnection",
+    RawRepositoryRule
+);
+
+/// The rule parameter shapes the rules read.
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "RuleParameters")]
+enum RawRuleParameters {
+    PullRequestParameters(RawPullRequestParameters),
+    RequiredStatusChecksParameters(RawStatusChecksParameters),
+    #[cynic(fallback)]
+    Other,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestParameters")]
+struct RawPullRequestParameters {
+    required_approving_review_count: Count,
+    require_code_owner_review: bool,
+    required_review_thread_resolution: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "RequiredStatusChecksParameters")]
+struct RawStatusChecksParameters {
+    required_status_checks: Vec<RawRequiredStatusCheck>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "StatusCheckConfiguration")]
+struct RawRequiredStatusCheck {
+    context: String,
+}
+
+/// Combine the classic protection rule with the rulesets: the strictest
+/// setting wins.
+fn merge_requirements(
+    base_ref: Option<RawDetailBaseRef>,
+    merge_queue_enabled: bool,
+    entry: Option<RawMergeQueueEntry>,
+) -> MergeRequirements {
+    let mut req = MergeRequirements {
+        merge_queue_enabled,
+        queue_entry: entry.map(MergeQueueEntry::from),
+        ..MergeRequirements::default()
+    };
+    let Some(base_ref) = base_ref else {
+        return req;
+    };
+    if let Some(rule) = base_ref.branch_protection_rule {
+        if rule.requires_approving_reviews {
+            req.required_approvals = rule.required_approving_review_count.map_or(1, |c| c.0);
+        }
+        req.requires_code_owner_review = rule.requires_code_owner_reviews;
+        req.requires_conversation_resolution = rule.requires_conversation_resolution;
+        req.required_checks = rule
+            .required_status_check_contexts
+            .into_iter()
+            .flatten()
+            .flatten()
+            .collect();
+        req.requires_signatures = rule.requires_commit_signatures;
+    }
+    for rule in base_ref
+        .rules
+        .into_iter()
+        .flat_map(RawRepositoryRuleConnection::into_nodes)
+    {
+        match (rule.kind, rule.parameters) {
+            (RawRuleType::PullRequest, Some(RawRuleParameters::PullRequestParameters(params))) => {
+                req.required_approvals = req
+                    .required_approvals
+                    .max(params.required_approving_review_count.0);
+                req.requires_code_owner_review |= params.require_code_owner_review;
+                req.requires_conversation_resolution |= params.required_review_thread_resolution;
+            }
+            (RawRuleType::RequiredSignatures, _) => req.requires_signatures = true,
+            (
+                RawRuleType::RequiredStatusChecks,
+                Some(RawRuleParameters::RequiredStatusChecksParameters(params)),
+            ) => {
+                for check in params.required_status_checks {
+                    if !req.required_checks.contains(&check.context) {
+                        req.required_checks.push(check.context);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    req
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestReview")]
+struct RawReview {
+    author: Option<RawActorLogin>,
+    state: ReviewState,
+    body: String,
+    submitted_at: Option<DateTime<Utc>>,
+    #[arguments(first: 10)]
+    on_behalf_of: RawTeamConnection,
+}
+
+connection!(
+    RawReviewConnection,
+    "PullRequestReviewConnection",
+    RawReview
+);
+connection!(RawTeamConnection, "TeamConnection", RawTeam);
+
+fn extract_detail_reviews(reviews: Option<RawReviewConnection>) -> Vec<Review> {
+    reviews
+        .into_iter()
+        .flat_map(RawReviewConnection::into_nodes)
+        .map(|r| Review {
+            author: r.author.map(RawActorLogin::into_actor),
+            state: r.state,
+            body: r.body,
+            submitted_at: r.submitted_at,
+            on_behalf_of: r
+                .on_behalf_of
+                .into_nodes()
+                .map(|t| t.combined_slug)
+                .collect(),
+        })
+        .collect()
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestReviewThread")]
+struct RawReviewThread {
+    is_resolved: bool,
+    #[arguments(first: 10)]
+    comments: RawReviewCommentConnection,
+}
+
+connection!(
+    RawReviewThreadConnection,
+    "PullRequestReviewThreadConnection",
+    RawReviewThread
+);
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestReviewComment")]
+struct RawComment {
+    author: Option<RawActorLogin>,
+    body: String,
+    created_at: DateTime<Utc>,
+}
+
+connection!(
+    RawReviewCommentConnection,
+    "PullRequestReviewCommentConnection",
+    RawComment
+);
+
+fn extract_review_threads(review_threads: RawReviewThreadConnection) -> Vec<ReviewThread> {
+    review_threads
+        .into_nodes()
+        .map(|rt| ReviewThread {
+            is_resolved: rt.is_resolved,
+            comments: rt
+                .comments
+                .into_nodes()
+                .map(|rc| crate::github::types::Comment {
+                    author: rc.author.map(RawActorLogin::into_actor),
+                    body: rc.body,
+                    created_at: rc.created_at,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// A page of a PR's commits.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestCommitConnection")]
+struct RawPagedCommits {
+    total_count: Count,
+    page_info: PageInfo,
+    nodes: Option<Vec<Option<RawDetailCommitNode>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestCommit")]
+struct RawDetailCommitNode {
+    commit: RawDetailCommit,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Commit")]
+struct RawDetailCommit {
+    oid: String,
+    message_headline: String,
+    author: Option<RawCommitAuthor>,
+    committed_date: DateTime<Utc>,
+    status_check_rollup: Option<RawRollupState>,
+    /// `null` for unsigned commits.
+    signature: Option<RawCommitSignature>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "GitActor")]
+struct RawCommitAuthor {
+    name: Option<String>,
+}
+
+#[derive(cynic::Enum, Debug, Clone, PartialEq, Eq)]
+#[cynic(graphql_type = "GitSignatureState", non_exhaustive)]
+enum RawSignatureState {
+    Valid,
+    Unsigned,
+    /// Why the signature could not be verified, e.g. `UNKNOWN_KEY`.
+    #[cynic(fallback)]
+    Other(String),
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "GitSignature")]
+struct RawCommitSignature {
+    state: RawSignatureState,
+}
+
+impl From<RawSignatureState> for CommitSignature {
+    fn from(state: RawSignatureState) -> Self {
+        match state {
+            RawSignatureState::Valid => Self::Verified,
+            RawSignatureState::Unsigned => Self::Unsigned,
+            RawSignatureState::Other(state) => Self::from_state(&state),
+        }
+    }
+}
+
+fn extract_detail_commits(commits: RawPagedCommits) -> (Vec<Commit>, PageCursor) {
+    let cursor = page_cursor(commits.total_count, commits.page_info);
+    let commits = commits
+        .nodes
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|cn| {
+            let c = cn.commit;
+            Commit {
+                sha: c.oid,
+                message: c.message_headline,
+                author: c.author.and_then(|a| a.name),
+                committed_date: Some(c.committed_date),
+                check_state: c.status_check_rollup.map(|r| r.state),
+                signature: c
+                    .signature
+                    .map_or(CommitSignature::Unsigned, |s| s.state.into()),
+            }
+        })
+        .collect();
+    (commits, cursor)
+}
+
+/// A page of a PR's changed files.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestChangedFileConnection")]
+struct RawPagedFiles {
+    total_count: Count,
+    page_info: PageInfo,
+    nodes: Option<Vec<Option<RawFile>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestChangedFile")]
+struct RawFile {
+    path: String,
+    additions: Count,
+    deletions: Count,
+    change_type: FileChangeType,
+    viewer_viewed_state: FileViewedState,
+}
+
+fn extract_files(files: Option<RawPagedFiles>) -> (Vec<File>, PageCursor) {
+    let Some(files) = files else {
+        return (Vec::new(), PageCursor::default());
+    };
+    let cursor = page_cursor(files.total_count, files.page_info);
+    let files = files
+        .nodes
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|f| File {
+            path: f.path,
+            additions: f.additions.0,
+            deletions: f.deletions.0,
+            status: Some(f.change_type),
+            viewer_state: f.viewer_viewed_state,
+        })
+        .collect();
+    (files, cursor)
+}
+
+/// The head commit's deployments and check suites.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Commit")]
+struct RawLastCommit {
+    #[arguments(first: 20, orderBy: { field: CREATED_AT, direction: DESC })]
+    deployments: Option<RawDeploymentConnection>,
+    #[arguments(last: 20)]
+    check_suites: Option<RawCommitCheckSuiteConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestCommit")]
+struct RawLastCommitNode {
+    commit: RawLastCommit,
+}
+
+connection!(
+    RawLastCommitConnection,
+    "PullRequestCommitConnection",
+    RawLastCommitNode
+);
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Deployment")]
+struct RawDeployment {
+    environment: Option<String>,
+    state: Option<DeploymentState>,
+    created_at: DateTime<Utc>,
+    latest_status: Option<RawDeploymentStatus>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "DeploymentStatus")]
+struct RawDeploymentStatus {
+    environment_url: Option<String>,
+}
+
+connection!(
+    RawDeploymentConnection,
+    "DeploymentConnection",
+    RawDeployment
+);
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "CheckSuite")]
+struct RawCommitCheckSuite {
+    conclusion: Option<CheckConclusion>,
+    workflow_run: Option<RawWorkflowRun>,
+}
+
+connection!(
+    RawCommitCheckSuiteConnection,
+    "CheckSuiteConnection",
+    RawCommitCheckSuite
+);
+
+impl RawLastCommitConnection {
+    /// The head commit's deployments and workflow runs awaiting approval.
+    fn into_domain(self) -> (Vec<Deployment>, Vec<AwaitingRun>) {
+        let Some(head) = self.into_nodes().next() else {
+            return (Vec::new(), Vec::new());
+        };
+        (
+            extract_deployments(head.commit.deployments),
+            extract_awaiting_approval(head.commit.check_suites),
+        )
+    }
+}
+
+/// The head commit's deployments, newest first.
+fn extract_deployments(deployments: Option<RawDeploymentConnection>) -> Vec<Deployment> {
+    deployments
+        .into_iter()
+        .flat_map(RawDeploymentConnection::into_nodes)
+        .map(|d| Deployment {
+            environment: d.environment.unwrap_or_else(|| "<unknown>".to_owned()),
+            state: d.state,
+            url: d
+                .latest_status
+                .and_then(|s| s.environment_url)
+                .filter(|u| !u.is_empty()),
+            created_at: d.created_at,
+        })
+        .collect()
+}
+
+/// The head commit's workflow runs awaiting approval: their check suite
+/// concluded `ACTION_REQUIRED` before any job ran.
+fn extract_awaiting_approval(
+    check_suites: Option<RawCommitCheckSuiteConnection>,
+) -> Vec<AwaitingRun> {
+    check_suites
+        .into_iter()
+        .flat_map(RawCommitCheckSuiteConnection::into_nodes)
+        .filter(|s| s.conclusion == Some(CheckConclusion::ActionRequired))
+        .filter_map(|s| {
+            let run = s.workflow_run?;
+            Some(AwaitingRun {
+                run_id: run.database_id?.0,
+                workflow: run.workflow.name,
+            })
+        })
+        .collect()
+}
+
+impl RawPrDetail {
+    fn into_domain(self) -> PrDetail {
+        let (commits, commits_page) = extract_detail_commits(self.commits);
+        let (files, files_page) = extract_files(self.files);
+        let code_owners = extract_code_owners(self.base_ref.as_ref());
+        let (deployments, awaiting_approval) = self.last_commit.into_domain();
+        PrDetail {
+            body: self.body,
+            reviews: extract_detail_reviews(self.reviews),
+            review_threads: extract_review_threads(self.review_threads),
+            timeline_events: self.timeline_items.into_events(),
+            commits,
+            files,
+            mergeable: Some(self.mergeable),
+            behind_by: None, // Populated by fetch_compare after the GraphQL call.
+            commits_page,
+            files_page,
+            deployments,
+            awaiting_approval,
+            merge_requirements: merge_requirements(
+                self.base_ref,
+                self.is_merge_queue_enabled,
+                self.merge_queue_entry,
+            ),
+            code_owners,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// PR detail page response types
+// ---------------------------------------------------------------------------
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "PrDetailPageVariables")]
+struct PullRequestCommitsPageQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
+    repository: Option<CommitsPageRepo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "PrDetailPageVariables")]
+struct CommitsPageRepo {
+    #[arguments(number: $number)]
+    pull_request: Option<CommitsPagePr>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest", variables = "PrDetailPageVariables")]
+struct CommitsPagePr {
+    #[arguments(first: 100, after: $after)]
+    commits: RawPagedCommits,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "PrDetailPageVariables")]
+struct PullRequestFilesPageQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
+    repository: Option<FilesPageRepo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "PrDetailPageVariables")]
+struct FilesPageRepo {
+    #[arguments(number: $number)]
+    pull_request: Option<FilesPagePr>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest", variables = "PrDetailPageVariables")]
+struct FilesPagePr {
+    #[arguments(first: 100, after: $after)]
+    files: Option<RawPagedFiles>,
+}
+
+// ---------------------------------------------------------------------------
+// Issue detail response types
+// ---------------------------------------------------------------------------
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "ItemVariables")]
+struct IssueDetailQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
+    repository: Option<IssueDetailRepo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "ItemVariables")]
+struct IssueDetailRepo {
+    #[arguments(number: $number)]
+    issue_or_pull_request: Option<RawIssueDetailItem>,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "IssueOrPullRequest")]
+enum RawIssueDetailItem {
+    Issue(RawIssueDetail),
+    PullRequest(RawPrConversation),
+    #[cynic(fallback)]
+    Other,
+}
+
+impl RawIssueDetailItem {
+    fn into_domain(self, repo: &RepoRef, number: u64) -> Option<IssueDetail> {
+        match self {
+            Self::Issue(issue) => Some(issue.into_domain(repo, number)),
+            Self::PullRequest(pr) => Some(pr.into_domain(repo, number)),
+            Self::Other => None,
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Issue")]
+struct RawIssueDetail {
+    body: String,
+    parent: Option<RawIssueLink>,
+    #[arguments(first: 50)]
+    sub_issues: RawIssueLinkConnection,
+    #[arguments(last: 100)]
+    timeline_items: RawIssueTimelineConnection,
+}
+
+/// The conversation of a PR shown as an issue.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest")]
+struct RawPrConversation {
+    body: String,
+    #[arguments(last: 100)]
+    timeline_items: RawPrConversationConnection,
+}
+
+total_count!(RawSubIssueCount, "IssueConnection");
+
+/// A parent or sub-issue.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Issue")]
+struct RawIssueLink {
+    number: Number,
+    title: String,
+    state: IssueState,
+    repository: RawRepository,
+    sub_issues: RawSubIssueCount,
+}
+
+connection!(RawIssueLinkConnection, "IssueConnection", RawIssueLink);
+
+impl RawIssueLink {
+    fn into_domain(self) -> Option<IssueLink> {
+        Some(IssueLink {
+            repo: self.repository.repo_ref()?,
+            number: self.number.0,
+            title: self.title,
+            state: Some(self.state),
+            sub_issue_count: self.sub_issues.total_count.0,
+        })
+    }
+}
+
+impl RawIssueDetail {
+    /// The detail of issue `number` in `repo`.
+    fn into_domain(self, repo: &RepoRef, number: u64) -> IssueDetail {
+        let sub_issues = self
+            .sub_issues
+            .into_nodes()
+            .filter_map(RawIssueLink::into_domain)
+            .collect();
+        let links = IssueLinks::new(
+            self.parent.and_then(RawIssueLink::into_domain),
+            sub_issues,
+            &self.body,
+            repo,
+            number,
+        );
+        IssueDetail {
+            body: self.body,
+            timeline_events: self.timeline_items.into_events(),
+            links,
+        }
+    }
+}
+
+impl RawPrConversation {
+    /// The detail of PR `number` in `repo`, linked only by its body.
+    fn into_domain(self, repo: &RepoRef, number: u64) -> IssueDetail {
+        let links = IssueLinks::new(None, Vec::new(), &self.body, repo, number);
+        IssueDetail {
+            body: self.body,
+            timeline_events: self.timeline_items.into_events(),
+            links,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Result of a single page of PR search results.
+pub(crate) struct SearchPrPage {
+    pub pull_requests: Vec<PullRequest>,
+    pub page_info: PageInfo,
+    pub rate_limit: Option<RateLimitInfo>,
+    /// Problems GitHub reported alongside the results (skipped items).
+    pub warnings: Vec<String>,
 }
 
 /// Execute the `SearchPullRequests` GraphQL query for a single page.
@@ -987,40 +2253,38 @@ pub(crate) struct SearchPrPage {
 /// Automatically prepends `is:pr` to the query if not already present, so that
 /// the search only returns pull requests (not issues).
 pub async fn search_pull_requests(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
+    features: HostFeatures,
     query: &str,
     limit: u32,
     after: Option<String>,
 ) -> Result<SearchPrPage> {
-    let effective_query = ensure_type_qualifier(query, "pr");
-    let payload = GraphQLPayload {
-        query: SEARCH_PULL_REQUESTS_QUERY,
-        variables: SearchVariables {
-            query: effective_query,
-            first: limit,
+    let operation = operation::<SearchPullRequestsQuery, _>(
+        features,
+        SearchVariables {
+            query: ensure_type_qualifier(query, "pr"),
+            first: Count(limit),
             after,
         },
-    };
+    );
 
-    let data: SearchData = octocrab
-        .graphql(&payload)
+    let (data, mut warnings): (SearchPullRequestsQuery, _) = graphql_partial(octocrab, &operation)
         .await
         .with_context(|| format!("GraphQL PR search failed for query: {query}"))?;
 
-    let rate_limit = data.rate_limit;
-
-    let pull_requests = data
-        .search
-        .nodes
+    let pull_requests = lenient_nodes(data.search.nodes, &mut warnings)
         .into_iter()
-        .flatten()
-        .map(RawPullRequest::into_domain)
+        .filter_map(|item| match item {
+            RawPrSearchItem::PullRequest(pr) => Some(pr.into_domain()),
+            RawPrSearchItem::Other => None,
+        })
         .collect();
 
     Ok(SearchPrPage {
         pull_requests,
         page_info: data.search.page_info,
-        rate_limit,
+        rate_limit: data.rate_limit.map(RateLimitInfo::from),
+        warnings,
     })
 }
 
@@ -1029,13 +2293,21 @@ pub async fn search_pull_requests(
 /// When a `cache` is provided, results are served from the moka LRU cache
 /// if a fresh entry exists (TTL is set at client creation time).
 ///
-/// Returns `(pull_requests, rate_limit)`. On cache hit, `rate_limit` is `None`.
+/// `on_page` is called with the results so far before each page after the
+/// first, so callers can show them while the rest loads.
+///
+/// Returns `(pull_requests, rate_limit, warnings)`, with `rate_limit.cost`
+/// totalled over all pages. On cache hit, `rate_limit` is `None`. Partial
+/// results (non-empty `warnings`) are not cached, so the next fetch retries
+/// what was skipped.
 pub async fn search_pull_requests_all(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
+    features: HostFeatures,
     query: &str,
     limit: u32,
     cache: Option<&Cache<String, String>>,
-) -> Result<(Vec<PullRequest>, Option<RateLimitInfo>)> {
+    mut on_page: impl FnMut(&[PullRequest]),
+) -> Result<(Vec<PullRequest>, Option<RateLimitInfo>, Vec<String>)> {
     let cache_key = format!("prs:{query}:{limit}");
 
     // Try cache first.
@@ -1044,13 +2316,14 @@ pub async fn search_pull_requests_all(
         && let Ok(prs) = serde_json::from_str::<Vec<PullRequest>>(&cached)
     {
         tracing::debug!("cache hit for {cache_key}");
-        return Ok((prs, None));
+        return Ok((prs, None, Vec::new()));
     }
 
     let page_size = limit.min(100); // GitHub caps at 100 per page
     let mut all_prs = Vec::new();
+    let mut warnings = Vec::new();
     let mut cursor: Option<String> = None;
-    let mut last_rate_limit: Option<RateLimitInfo> = None;
+    let mut rate_limit: Option<RateLimitInfo> = None;
 
     loop {
         let remaining = limit.saturating_sub(u32::try_from(all_prs.len()).unwrap_or(u32::MAX));
@@ -1058,12 +2331,14 @@ pub async fn search_pull_requests_all(
             break;
         }
         let fetch_count = remaining.min(page_size);
+        if cursor.is_some() {
+            on_page(&all_prs);
+        }
 
-        let page = search_pull_requests(octocrab, query, fetch_count, cursor).await?;
+        let page = search_pull_requests(octocrab, features, query, fetch_count, cursor).await?;
         all_prs.extend(page.pull_requests);
-        if page.rate_limit.is_some() {
-            last_rate_limit = page.rate_limit;
-        }
+        merge_warnings(&mut warnings, page.warnings);
+        accumulate_rate_limit(&mut rate_limit, page.rate_limit);
 
         if !page.page_info.has_next_page || page.page_info.end_cursor.is_none() {
             break;
@@ -1073,12 +2348,13 @@ pub async fn search_pull_requests_all(
 
     // Store in cache.
     if let Some(c) = cache
+        && warnings.is_empty()
         && let Ok(json) = serde_json::to_string(&all_prs)
     {
         c.insert(cache_key, json).await;
     }
 
-    Ok((all_prs, last_rate_limit))
+    Ok((all_prs, rate_limit, warnings))
 }
 
 // ---------------------------------------------------------------------------
@@ -1090,47 +2366,47 @@ pub(crate) struct SearchIssuePage {
     pub issues: Vec<Issue>,
     pub page_info: PageInfo,
     pub rate_limit: Option<RateLimitInfo>,
+    /// Problems GitHub reported alongside the results (skipped items).
+    pub warnings: Vec<String>,
 }
 
 /// Execute the `SearchIssues` GraphQL query for a single page.
 ///
-/// Automatically prepends `is:issue` to the query if not already present, so
-/// that the search only returns issues (not pull requests).
+/// For [`IssueFilterKind::Issue`], automatically prepends `is:issue` to the
+/// query if not already present, so that the search only returns issues (not
+/// pull requests). [`IssueFilterKind::Any`] runs the query as written and
+/// returns the matching PRs as issues with `pr_state` set.
 pub async fn search_issues(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
     query: &str,
+    kind: IssueFilterKind,
     limit: u32,
     after: Option<String>,
 ) -> Result<SearchIssuePage> {
-    let effective_query = ensure_type_qualifier(query, "issue");
-    let payload = GraphQLPayload {
-        query: SEARCH_ISSUES_QUERY,
-        variables: SearchVariables {
-            query: effective_query,
-            first: limit,
-            after,
-        },
+    let effective_query = match kind {
+        IssueFilterKind::Issue => ensure_type_qualifier(query, "issue"),
+        IssueFilterKind::Any => query.to_owned(),
     };
+    let operation = SearchIssuesQuery::build(SearchVariables {
+        query: effective_query,
+        first: Count(limit),
+        after,
+    });
 
-    let data: IssueSearchData = octocrab
-        .graphql(&payload)
+    let (data, mut warnings): (SearchIssuesQuery, _) = graphql_partial(octocrab, &operation)
         .await
         .context("GraphQL request failed")?;
 
-    let rate_limit = data.rate_limit;
-
-    let issues = data
-        .search
-        .nodes
+    let issues = lenient_nodes(data.search.nodes, &mut warnings)
         .into_iter()
-        .flatten()
-        .map(RawIssue::into_domain)
+        .filter_map(RawIssueSearchItem::into_domain)
         .collect();
 
     Ok(SearchIssuePage {
         issues,
         page_info: data.search.page_info,
-        rate_limit,
+        rate_limit: data.rate_limit.map(RateLimitInfo::from),
+        warnings,
     })
 }
 
@@ -1139,27 +2415,34 @@ pub async fn search_issues(
 /// When a `cache` is provided, results are served from the moka LRU cache
 /// if a fresh entry exists.
 ///
-/// Returns `(issues, rate_limit)`. On cache hit, `rate_limit` is `None`.
+/// `on_page` receives the results so far before each page after the first.
+///
+/// Returns `(issues, rate_limit, warnings)`, with `rate_limit.cost` totalled
+/// over all pages. On cache hit, `rate_limit` is `None`. Partial results are
+/// not cached.
 pub async fn search_issues_all(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
     query: &str,
+    kind: IssueFilterKind,
     limit: u32,
     cache: Option<&Cache<String, String>>,
-) -> Result<(Vec<Issue>, Option<RateLimitInfo>)> {
-    let cache_key = format!("issues:{query}:{limit}");
+    mut on_page: impl FnMut(&[Issue]),
+) -> Result<(Vec<Issue>, Option<RateLimitInfo>, Vec<String>)> {
+    let cache_key = format!("issues:{kind:?}:{query}:{limit}");
 
     if let Some(c) = cache
         && let Some(cached) = c.get(&cache_key).await
         && let Ok(issues) = serde_json::from_str::<Vec<Issue>>(&cached)
     {
         tracing::debug!("cache hit for {cache_key}");
-        return Ok((issues, None));
+        return Ok((issues, None, Vec::new()));
     }
 
     let page_size = limit.min(100);
     let mut all_issues = Vec::new();
+    let mut warnings = Vec::new();
     let mut cursor: Option<String> = None;
-    let mut last_rate_limit: Option<RateLimitInfo> = None;
+    let mut rate_limit: Option<RateLimitInfo> = None;
 
     loop {
         let remaining = limit.saturating_sub(u32::try_from(all_issues.len()).unwrap_or(u32::MAX));
@@ -1167,12 +2450,14 @@ pub async fn search_issues_all(
             break;
         }
         let fetch_count = remaining.min(page_size);
+        if cursor.is_some() {
+            on_page(&all_issues);
+        }
 
-        let page = search_issues(octocrab, query, fetch_count, cursor).await?;
+        let page = search_issues(octocrab, query, kind, fetch_count, cursor).await?;
         all_issues.extend(page.issues);
-        if page.rate_limit.is_some() {
-            last_rate_limit = page.rate_limit;
-        }
+        merge_warnings(&mut warnings, page.warnings);
+        accumulate_rate_limit(&mut rate_limit, page.rate_limit);
 
         if !page.page_info.has_next_page || page.page_info.end_cursor.is_none() {
             break;
@@ -1181,101 +2466,19 @@ pub async fn search_issues_all(
     }
 
     if let Some(c) = cache
+        && warnings.is_empty()
         && let Ok(json) = serde_json::to_string(&all_issues)
     {
         c.insert(cache_key, json).await;
     }
 
-    Ok((all_issues, last_rate_limit))
+    Ok((all_issues, rate_limit, warnings))
 }
 
 // ---------------------------------------------------------------------------
 // PR detail API (Q2 — sidebar tabs)
 // ---------------------------------------------------------------------------
 
-// ---------------------------------------------------------------------------
-// Issue detail response types
-// ---------------------------------------------------------------------------
-
-#[derive(Debug, Deserialize)]
-struct IssueDetailData {
-    #[serde(rename = "rateLimit", default)]
-    rate_limit: Option<RateLimitInfo>,
-    repository: Option<IssueDetailRepo>,
-}
-
-#[derive(Debug, Deserialize)]
-struct IssueDetailRepo {
-    issue: Option<RawIssueDetail>,
-}
-
-#[derive(Debug, Deserialize)]
-struct RawIssueDetail {
-    #[serde(default)]
-    body: String,
-    #[serde(rename = "timelineItems")]
-    timeline_items: Option<Connection<RawTimelineItem>>,
-}
-
-fn raw_actor_login(a: Option<RawActor>) -> Option<String> {
-    a.map(|a| a.login)
-}
-
-fn raw_actor_to_actor(a: RawActor) -> Actor {
-    Actor {
-        login: a.login,
-        avatar_url: String::new(),
-    }
-}
-
-fn convert_timeline_item(item: RawTimelineItem) -> Option<TimelineEvent> {
-    match item.typename.as_str() {
-        "IssueComment" => Some(TimelineEvent::Comment {
-            author: raw_actor_login(item.author),
-            body: item.body.unwrap_or_default(),
-            created_at: item.created_at?,
-        }),
-        "PullRequestReview" => Some(TimelineEvent::Review {
-            author: raw_actor_login(item.author),
-            state: item.state.unwrap_or(ReviewState::Unknown),
-            body: item.body.unwrap_or_default(),
-            submitted_at: item.submitted_at.or(item.created_at)?,
-        }),
-        "MergedEvent" => Some(TimelineEvent::Merged {
-            actor: raw_actor_login(item.actor),
-            created_at: item.created_at?,
-        }),
-        "ClosedEvent" => Some(TimelineEvent::Closed {
-            actor: raw_actor_login(item.actor),
-            created_at: item.created_at?,
-        }),
-        "ReopenedEvent" => Some(TimelineEvent::Reopened {
-            actor: raw_actor_login(item.actor),
-            created_at: item.created_at?,
-        }),
-        "HeadRefForcePushedEvent" => Some(TimelineEvent::ForcePushed {
-            actor: raw_actor_login(item.actor),
-            created_at: item.created_at?,
-        }),
-        _ => None,
-    }
-}
-
-impl RawPrDetail {
-    fn into_domain(self) -> PrDetail {
-        PrDetail {
-            body: self.body,
-            reviews: extract_detail_reviews(self.reviews),
-            review_threads: extract_review_threads(self.review_threads),
-            timeline_events: extract_timeline_events(self.timeline_items),
-            commits: extract_detail_commits(self.commits),
-            files: extract_files(self.files),
-            mergeable: self.mergeable,
-            behind_by: None, // Populated by fetch_compare after the GraphQL call.
-        }
-    }
-}
-
 /// Fetch detailed PR data for sidebar tabs.
 ///
 /// When a `cache` is provided, results are served from the moka LRU cache
@@ -1283,7 +2486,8 @@ impl RawPrDetail {
 ///
 /// Returns `(detail, rate_limit)`. On cache hit, `rate_limit` is `None`.
 pub async fn fetch_pr_detail(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
+    features: HostFeatures,
     owner: &str,
     repo: &str,
     number: u64,
@@ -1299,22 +2503,14 @@ pub async fn fetch_pr_detail(
         return Ok((detail, None));
     }
 
-    let payload = GraphQLPayload {
-        query: PR_DETAIL_QUERY,
-        variables: PrDetailVariables {
-            owner: owner.to_owned(),
-            repo: repo.to_owned(),
-            number: i64::try_from(number).context("PR number too large")?,
-        },
-    };
+    let operation =
+        operation::<PullRequestDetailQuery, _>(features, ItemVariables::new(owner, repo, number));
 
-    let data: PrDetailData = octocrab
-        .graphql(&payload)
+    let data: PullRequestDetailQuery = octocrab
+        .graphql(&operation)
         .await
         .context("GraphQL PR detail request failed")?;
 
-    let rate_limit = data.rate_limit;
-
     let raw = data
         .repository
         .and_then(|r| r.pull_request)
@@ -1328,7 +2524,60 @@ pub async fn fetch_pr_detail(
         c.insert(cache_key, json).await;
     }
 
-    Ok((detail, rate_limit))
+    Ok((detail, data.rate_limit.map(RateLimitInfo::from)))
+}
+
+/// Fetch the page of a PR's commits or files that follows `after`.
+///
+/// Pages are not cached: cursors go stale once the PR changes, and only the
+/// first page (cached with the rest of the detail) is re-read often.
+pub async fn fetch_pr_detail_page(
+    octocrab: &HostClient,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    connection: PrDetailConnection,
+    after: &str,
+) -> Result<(PrDetailPage, Option<RateLimitInfo>)> {
+    let variables = PrDetailPageVariables {
+        owner: owner.to_owned(),
+        repo: repo.to_owned(),
+        number: Number(number),
+        after: after.to_owned(),
+    };
+
+    match connection {
+        PrDetailConnection::Commits => {
+            let data: PullRequestCommitsPageQuery = octocrab
+                .graphql(&PullRequestCommitsPageQuery::build(variables))
+                .await
+                .context("GraphQL PR detail page request failed")?;
+            let raw = data
+                .repository
+                .and_then(|r| r.pull_request)
+                .context("PR not found")?;
+            let (commits, page) = extract_detail_commits(raw.commits);
+            Ok((
+                PrDetailPage::Commits { commits, page },
+                data.rate_limit.map(RateLimitInfo::from),
+            ))
+        }
+        PrDetailConnection::Files => {
+            let data: PullRequestFilesPageQuery = octocrab
+                .graphql(&PullRequestFilesPageQuery::build(variables))
+                .await
+                .context("GraphQL PR detail page request failed")?;
+            let raw = data
+                .repository
+                .and_then(|r| r.pull_request)
+                .context("PR not found")?;
+            let (files, page) = extract_files(raw.files);
+            Ok((
+                PrDetailPage::Files { files, page },
+                data.rate_limit.map(RateLimitInfo::from),
+            ))
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -1342,7 +2591,7 @@ pub async fn fetch_pr_detail(
 ///
 /// Returns `(detail, rate_limit)`. On cache hit, `rate_limit` is `None`.
 pub async fn fetch_issue_detail(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
     owner: &str,
     repo: &str,
     number: u64,
@@ -1358,50 +2607,30 @@ pub async fn fetch_issue_detail(
         return Ok((detail, None));
     }
 
-    let payload = GraphQLPayload {
-        query: ISSUE_DETAIL_QUERY,
-        variables: PrDetailVariables {
-            owner: owner.to_owned(),
-            repo: repo.to_owned(),
-            number: i64::try_from(number).context("Issue number too large")?,
-        },
-    };
-
-    let data: IssueDetailData = octocrab
-        .graphql(&payload)
+    let data: IssueDetailQuery = octocrab
+        .graphql(&IssueDetailQuery::build(ItemVariables::new(
+            owner, repo, number,
+        )))
         .await
         .context("GraphQL issue detail request failed")?;
 
-    let rate_limit = data.rate_limit;
-
-    let raw = data
+    let home = RepoRef {
+        owner: owner.to_owned(),
+        name: repo.to_owned(),
+    };
+    let detail = data
         .repository
-        .and_then(|r| r.issue)
+        .and_then(|r| r.issue_or_pull_request)
+        .and_then(|item| item.into_domain(&home, number))
         .context("Issue not found")?;
 
-    let timeline_events = raw
-        .timeline_items
-        .map(|c| {
-            c.nodes
-                .into_iter()
-                .flatten()
-                .filter_map(convert_timeline_item)
-                .collect()
-        })
-        .unwrap_or_default();
-
-    let detail = IssueDetail {
-        body: raw.body,
-        timeline_events,
-    };
-
     if let Some(c) = cache
         && let Ok(json) = serde_json::to_string(&detail)
     {
         c.insert(cache_key, json).await;
     }
 
-    Ok((detail, rate_limit))
+    Ok((detail, data.rate_limit.map(RateLimitInfo::from)))
 }
 
 // ---------------------------------------------------------------------------
@@ -1446,37 +2675,31 @@ pub async fn fetch_compare(
 // Q4: Repository Labels (T083)
 // ---------------------------------------------------------------------------
 
-#[derive(Serialize)]
-struct RepoLabelsVariables {
-    owner: String,
-    repo: String,
-    first: u32,
-}
-
-#[derive(Debug, Deserialize)]
-struct RepoLabelsData {
-    #[serde(rename = "rateLimit", default)]
-    rate_limit: Option<RateLimitInfo>,
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "RepoListVariables")]
+struct RepositoryLabelsQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
     repository: Option<RepoLabelsRepo>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "RepoListVariables")]
 struct RepoLabelsRepo {
-    labels: Option<RepoLabelsConnection>,
-}
-
-#[derive(Debug, Deserialize)]
-struct RepoLabelsConnection {
-    nodes: Option<Vec<RawRepoLabel>>,
+    #[arguments(first: $first, orderBy: { field: NAME, direction: ASC })]
+    labels: Option<RawRepoLabelConnection>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Label")]
 struct RawRepoLabel {
     name: String,
     color: String,
     description: Option<String>,
 }
 
+connection!(RawRepoLabelConnection, "LabelConnection", RawRepoLabel);
+
 /// A repository label with name, color, and optional description.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct RepoLabel {
@@ -1485,34 +2708,26 @@ pub(crate) struct RepoLabel {
     pub description: Option<String>,
 }
 
-#[derive(Serialize)]
-struct RepoCollaboratorsVariables {
-    owner: String,
-    repo: String,
-    first: u32,
-}
-
-#[derive(Debug, Deserialize)]
-struct RepoCollaboratorsData {
-    #[serde(rename = "rateLimit", default)]
-    rate_limit: Option<RateLimitInfo>,
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "RepoListVariables")]
+struct RepositoryCollaboratorsQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
     repository: Option<RepoCollaboratorsRepo>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "RepoListVariables")]
 struct RepoCollaboratorsRepo {
-    collaborators: Option<RepoCollaboratorsConnection>,
-}
-
-#[derive(Debug, Deserialize)]
-struct RepoCollaboratorsConnection {
-    nodes: Option<Vec<RawCollaborator>>,
+    #[arguments(first: $first, affiliation: ALL)]
+    collaborators: Option<RawCollaboratorConnection>,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawCollaborator {
-    login: String,
-}
+connection!(
+    RawCollaboratorConnection,
+    "RepositoryCollaboratorConnection",
+    RawUser
+);
 
 /// Fetch all labels for a repository (for autocomplete).
 ///
@@ -1521,7 +2736,7 @@ struct RawCollaborator {
 ///
 /// Returns `(labels, rate_limit)`. On cache hit, `rate_limit` is `None`.
 pub async fn fetch_repo_labels(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
     owner: &str,
     repo: &str,
     cache: Option<&Cache<String, String>>,
@@ -1536,28 +2751,18 @@ pub async fn fetch_repo_labels(
         return Ok((labels, None));
     }
 
-    let payload = GraphQLPayload {
-        query: REPOSITORY_LABELS_QUERY,
-        variables: RepoLabelsVariables {
-            owner: owner.to_owned(),
-            repo: repo.to_owned(),
-            first: 100,
-        },
-    };
-
-    let data: RepoLabelsData = octocrab
-        .graphql(&payload)
+    let data: RepositoryLabelsQuery = octocrab
+        .graphql(&RepositoryLabelsQuery::build(RepoListVariables::new(
+            owner, repo,
+        )))
         .await
         .context("GraphQL repo labels request failed")?;
 
-    let rate_limit = data.rate_limit;
-
     let labels: Vec<RepoLabel> = data
         .repository
         .and_then(|r| r.labels)
-        .and_then(|l| l.nodes)
-        .unwrap_or_default()
         .into_iter()
+        .flat_map(RawRepoLabelConnection::into_nodes)
         .map(|l| RepoLabel {
             name: l.name,
             color: l.color,
@@ -1571,7 +2776,7 @@ pub async fn fetch_repo_labels(
         c.insert(cache_key, json).await;
     }
 
-    Ok((labels, rate_limit))
+    Ok((labels, data.rate_limit.map(RateLimitInfo::from)))
 }
 
 /// Fetch all collaborators for a repository (for assignee autocomplete).
@@ -1581,7 +2786,7 @@ pub async fn fetch_repo_labels(
 ///
 /// Returns `(logins, rate_limit)`. On cache hit, `rate_limit` is `None`.
 pub async fn fetch_repo_collaborators(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
     owner: &str,
     repo: &str,
     cache: Option<&Cache<String, String>>,
@@ -1591,436 +2796,824 @@ pub async fn fetch_repo_collaborators(
     // Check cache first
     if let Some(c) = cache
         && let Some(cached) = c.get(&cache_key).await
-        && let Ok(logins) = serde_json::from_str::<Vec<String>>(&cached)
+        && let Ok(logins) = serde_json::from_str::<Vec<String>>(&cached)
+    {
+        tracing::debug!("cache hit for {cache_key}");
+        return Ok((logins, None));
+    }
+
+    // Execute GraphQL query
+    let data: RepositoryCollaboratorsQuery = octocrab
+        .graphql(&RepositoryCollaboratorsQuery::build(
+            RepoListVariables::new(owner, repo),
+        ))
+        .await
+        .context("GraphQL repo collaborators request failed")?;
+
+    // Parse collaborators
+    let logins: Vec<String> = data
+        .repository
+        .and_then(|r| r.collaborators)
+        .into_iter()
+        .flat_map(RawCollaboratorConnection::into_nodes)
+        .map(|collab| collab.login)
+        .collect();
+
+    // Cache the result
+    if let Some(c) = cache
+        && let Ok(json) = serde_json::to_string(&logins)
+    {
+        c.insert(cache_key, json).await;
+    }
+
+    Ok((logins, data.rate_limit.map(RateLimitInfo::from)))
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "RepoListVariables")]
+struct OpenIssueRefsQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
+    repository: Option<OpenIssueRefsRepo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "RepoListVariables")]
+struct OpenIssueRefsRepo {
+    #[arguments(
+        first: $first,
+        states: [OPEN],
+        orderBy: { field: UPDATED_AT, direction: DESC }
+    )]
+    issues: RawIssueRefConnection,
+    #[arguments(
+        first: $first,
+        states: [OPEN],
+        orderBy: { field: UPDATED_AT, direction: DESC }
+    )]
+    pull_requests: RawPrRefConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Issue")]
+struct RawIssueRef {
+    number: Number,
+    title: String,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest")]
+struct RawPrRef {
+    number: Number,
+    title: String,
+    updated_at: DateTime<Utc>,
+}
+
+connection!(RawIssueRefConnection, "IssueConnection", RawIssueRef);
+connection!(RawPrRefConnection, "PullRequestConnection", RawPrRef);
+
+/// Merge open issues and PRs into `(number, title)` pairs, most recently
+/// updated first.
+fn merge_issue_refs(repo: OpenIssueRefsRepo) -> Vec<(u64, String)> {
+    let mut refs: Vec<(DateTime<Utc>, u64, String)> = repo
+        .issues
+        .into_nodes()
+        .map(|r| (r.updated_at, r.number.0, r.title))
+        .chain(
+            repo.pull_requests
+                .into_nodes()
+                .map(|r| (r.updated_at, r.number.0, r.title)),
+        )
+        .collect();
+    refs.sort_by_key(|(updated_at, ..)| std::cmp::Reverse(*updated_at));
+    refs.into_iter()
+        .map(|(_, number, title)| (number, title))
+        .collect()
+}
+
+/// Fetch open issues and PRs of a repository (for `#` autocomplete).
+///
+/// When a `cache` is provided, results are served from the moka LRU cache
+/// if a fresh entry exists.
+///
+/// Returns `(refs, rate_limit)`. On cache hit, `rate_limit` is `None`.
+pub async fn fetch_open_issue_refs(
+    octocrab: &HostClient,
+    owner: &str,
+    repo: &str,
+    cache: Option<&Cache<String, String>>,
+) -> Result<(Vec<(u64, String)>, Option<RateLimitInfo>)> {
+    let cache_key = format!("issue_refs:{owner}/{repo}");
+
+    if let Some(c) = cache
+        && let Some(cached) = c.get(&cache_key).await
+        && let Ok(refs) = serde_json::from_str::<Vec<(u64, String)>>(&cached)
     {
         tracing::debug!("cache hit for {cache_key}");
-        return Ok((logins, None));
+        return Ok((refs, None));
     }
 
-    // Execute GraphQL query
-    let payload = GraphQLPayload {
-        query: REPOSITORY_COLLABORATORS_QUERY,
-        variables: RepoCollaboratorsVariables {
-            owner: owner.to_owned(),
-            repo: repo.to_owned(),
-            first: 100,
-        },
-    };
-
-    let data: RepoCollaboratorsData = octocrab
-        .graphql(&payload)
+    let data: OpenIssueRefsQuery = octocrab
+        .graphql(&OpenIssueRefsQuery::build(RepoListVariables::new(
+            owner, repo,
+        )))
         .await
-        .context("GraphQL repo collaborators request failed")?;
-
-    // Extract rate limit
-    let rate_limit = data.rate_limit;
+        .context("GraphQL open issue refs request failed")?;
 
-    // Parse collaborators
-    let logins: Vec<String> = data
-        .repository
-        .and_then(|r| r.collaborators)
-        .and_then(|c| c.nodes)
-        .unwrap_or_default()
-        .into_iter()
-        .map(|collab| collab.login)
-        .collect();
+    let refs = data.repository.map(merge_issue_refs).unwrap_or_default();
 
-    // Cache the result
     if let Some(c) = cache
-        && let Ok(json) = serde_json::to_string(&logins)
+        && let Ok(json) = serde_json::to_string(&refs)
     {
         c.insert(cache_key, json).await;
     }
 
-    Ok((logins, rate_limit))
+    Ok((refs, data.rate_limit.map(RateLimitInfo::from)))
 }
 
 // ---------------------------------------------------------------------------
-// Single-item combined queries (RefreshItem)
+// Merge queue
 // ---------------------------------------------------------------------------
 
-/// Combined query that returns all search-row fields AND detail fields for a
-/// single PR, so one API call can update both the table row and the sidebar.
-const SINGLE_PR_QUERY: &str = r"
-query SinglePullRequest($owner: String!, $repo: String!, $number: Int!) {
-  rateLimit { limit remaining cost }
-  repository(owner: $owner, name: $repo) {
-    pullRequest(number: $number) {
-      number
-      title
-      body
-      state
-      isDraft
-      mergeable
-      reviewDecision
-      additions
-      deletions
-      headRefName
-      baseRefName
-      mergeStateStatus
-      headRepository { owner { login } name }
-      url
-      updatedAt
-      createdAt
-      author { login avatarUrl }
-      authorAssociation
-      labels(first: 10) { nodes { name color } }
-      assignees(first: 10) { nodes { login } }
-      comments { totalCount }
-      latestReviews(first: 10) {
-        nodes {
-          state
-          author { login }
-        }
-      }
-      reviewRequests(first: 10) {
-        nodes {
-          requestedReviewer {
-            ... on User { login }
-          }
-        }
-      }
-      lastCommit: commits(last: 1) {
-        nodes {
-          commit {
-            statusCheckRollup {
-              contexts(first: 50) {
-                nodes {
-                  ... on CheckRun {
-                    name status conclusion detailsUrl startedAt completedAt
-                    checkSuite {
-                      workflowRun {
-                        databaseId
-                        workflow { name }
-                      }
-                    }
-                  }
-                  ... on StatusContext { context state targetUrl }
-                }
-              }
-            }
-          }
-        }
-      }
-      participants(first: 30) { nodes { login } }
-      repository { nameWithOwner }
-      reviews(last: 50) {
-        nodes { author { login } state body submittedAt }
-      }
-      reviewThreads(first: 50) {
-        nodes { isResolved comments(first: 10) { nodes { author { login } body createdAt } } }
-      }
-      timelineItems(last: 100) {
-        nodes {
-          __typename
-          ... on IssueComment { author { login } body createdAt }
-          ... on PullRequestReview { author { login } state body submittedAt }
-          ... on MergedEvent { actor { login } createdAt }
-          ... on ClosedEvent { actor { login } createdAt }
-          ... on ReopenedEvent { actor { login } createdAt }
-          ... on HeadRefForcePushedEvent { actor { login } createdAt }
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "RepoListVariables")]
+struct MergeQueueQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
+    repository: Option<MergeQueueRepo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "RepoListVariables")]
+struct MergeQueueRepo {
+    default_branch_ref: Option<RawRefName>,
+    merge_queue: Option<RawMergeQueue>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Ref")]
+struct RawRefName {
+    name: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "MergeQueue", variables = "RepoListVariables")]
+struct RawMergeQueue {
+    url: String,
+    #[arguments(first: $first)]
+    entries: Option<RawMergeQueueItemConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "MergeQueueEntry")]
+struct RawMergeQueueItem {
+    position: Count,
+    state: MergeQueueEntryState,
+    /// Seconds.
+    estimated_time_to_merge: Option<Number>,
+    enqueued_at: DateTime<Utc>,
+    head_commit: Option<RawRollupCommit>,
+    /// `null` when the PR is not visible to the viewer.
+    pull_request: Option<RawQueuedPullRequest>,
+}
+
+connection!(
+    RawMergeQueueItemConnection,
+    "MergeQueueEntryConnection",
+    RawMergeQueueItem
+);
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest")]
+struct RawQueuedPullRequest {
+    number: Number,
+    title: String,
+    url: String,
+    author: Option<RawActorLogin>,
+}
+
+impl RawMergeQueue {
+    fn into_domain(self, branch: String) -> MergeQueue {
+        let mut entries: Vec<MergeQueueItem> = self
+            .entries
+            .into_iter()
+            .flat_map(RawMergeQueueItemConnection::into_nodes)
+            .filter_map(|item| {
+                let pr = item.pull_request?;
+                Some(MergeQueueItem {
+                    entry: MergeQueueEntry {
+                        position: item.position.0,
+                        state: item.state,
+                        estimated_secs: item.estimated_time_to_merge.map(|s| s.0),
+                    },
+                    number: pr.number.0,
+                    title: pr.title,
+                    author: pr.author.map(|a| a.login),
+                    url: pr.url,
+                    enqueued_at: Some(item.enqueued_at),
+                    ci: item
+                        .head_commit
+                        .and_then(|c| c.status_check_rollup)
+                        .map(|r| r.state),
+                })
+            })
+            .collect();
+        entries.sort_by_key(|e| e.entry.position);
+        MergeQueue {
+            branch,
+            url: self.url,
+            entries,
         }
-      }
-      allCommits: commits(first: 100) {
-        nodes { commit { oid messageHeadline author { name } committedDate statusCheckRollup { state } } }
-      }
-      files(first: 100) {
-        nodes { path additions deletions changeType }
-      }
     }
-  }
 }
-";
 
-/// Combined query that returns all search-row fields AND detail fields for a
-/// single Issue.
-const SINGLE_ISSUE_QUERY: &str = r"
-query SingleIssue($owner: String!, $repo: String!, $number: Int!) {
-  rateLimit { limit remaining cost }
-  repository(owner: $owner, name: $repo) {
-    issue(number: $number) {
-      number
-      title
-      body
-      state
-      url
-      updatedAt
-      createdAt
-      author { login avatarUrl }
-      assignees(first: 10) { nodes { login } }
-      labels(first: 10) { nodes { name color } }
-      comments { totalCount }
-      reactionGroups { content users { totalCount } }
-      participants(first: 30) { nodes { login } }
-      repository { nameWithOwner }
-      timelineItems(last: 100) {
-        nodes {
-          __typename
-          ... on IssueComment { author { login } body createdAt }
-          ... on ClosedEvent { actor { login } createdAt }
-          ... on ReopenedEvent { actor { login } createdAt }
+/// Fetch the merge queue of a repository's default branch.
+///
+/// Returns `(queue, rate_limit)`; `queue` is `None` when the branch has no
+/// merge queue, or the host has no merge queues at all.
+pub async fn fetch_merge_queue(
+    octocrab: &HostClient,
+    features: HostFeatures,
+    owner: &str,
+    repo: &str,
+) -> Result<(Option<MergeQueue>, Option<RateLimitInfo>)> {
+    if !features.merge_queue {
+        return Ok((None, None));
+    }
+
+    let data: MergeQueueQuery = octocrab
+        .graphql(&MergeQueueQuery::build(RepoListVariables::new(owner, repo)))
+        .await
+        .context("GraphQL merge queue request failed")?;
+
+    let Some(repository) = data.repository else {
+        bail!("repository {owner}/{repo} not found");
+    };
+    let branch = repository
+        .default_branch_ref
+        .map(|r| r.name)
+        .unwrap_or_default();
+    let queue = repository.merge_queue.map(|q| q.into_domain(branch));
+    Ok((queue, data.rate_limit.map(RateLimitInfo::from)))
+}
+
+// ---------------------------------------------------------------------------
+// Saved replies
+// ---------------------------------------------------------------------------
+
+#[derive(cynic::QueryVariables, Debug)]
+struct SavedRepliesVariables {
+    first: Count,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "SavedRepliesVariables")]
+struct SavedRepliesQuery {
+    viewer: SavedRepliesViewer,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "User", variables = "SavedRepliesVariables")]
+struct SavedRepliesViewer {
+    #[arguments(first: $first)]
+    saved_replies: Option<RawSavedReplyConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "SavedReply")]
+struct RawSavedReply {
+    title: String,
+    body: String,
+}
+
+connection!(
+    RawSavedReplyConnection,
+    "SavedReplyConnection",
+    RawSavedReply
+);
+
+/// Fetch the authenticated user's saved replies (first 100).
+pub async fn fetch_saved_replies(octocrab: &HostClient) -> Result<Vec<SavedReply>> {
+    let data: SavedRepliesQuery = octocrab
+        .graphql(&SavedRepliesQuery::build(SavedRepliesVariables {
+            first: Count(100),
+        }))
+        .await
+        .context("GraphQL saved replies request failed")?;
+
+    Ok(data
+        .viewer
+        .saved_replies
+        .into_iter()
+        .flat_map(RawSavedReplyConnection::into_nodes)
+        .map(|r| SavedReply {
+            title: r.title,
+            body: r.body,
+        })
+        .collect())
+}
+
+// ---------------------------------------------------------------------------
+// Contribution calendar
+// ---------------------------------------------------------------------------
+
+#[derive(cynic::QueryVariables, Debug)]
+struct UserContributionsVariables {
+    login: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "UserContributionsVariables")]
+struct UserContributionsQuery {
+    #[arguments(login: $login)]
+    user: Option<RawContributionsUser>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query")]
+struct ViewerContributionsQuery {
+    viewer: RawContributionsUser,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "User")]
+struct RawContributionsUser {
+    login: String,
+    contributions_collection: RawContributionsCollection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ContributionsCollection")]
+struct RawContributionsCollection {
+    contribution_calendar: RawContributionCalendar,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ContributionCalendar")]
+struct RawContributionCalendar {
+    total_contributions: Count,
+    weeks: Vec<RawContributionWeek>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ContributionCalendarWeek")]
+struct RawContributionWeek {
+    contribution_days: Vec<RawContributionDay>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "ContributionCalendarDay")]
+struct RawContributionDay {
+    date: NaiveDate,
+    contribution_count: Count,
+    contribution_level: RawContributionLevel,
+}
+
+#[derive(cynic::Enum, Debug, Clone, Copy)]
+#[cynic(graphql_type = "ContributionLevel")]
+enum RawContributionLevel {
+    None,
+    FirstQuartile,
+    SecondQuartile,
+    ThirdQuartile,
+    FourthQuartile,
+}
+
+impl RawContributionLevel {
+    /// Quartile bucket, 0 for no contributions.
+    fn quartile(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::FirstQuartile => 1,
+            Self::SecondQuartile => 2,
+            Self::ThirdQuartile => 3,
+            Self::FourthQuartile => 4,
         }
-      }
     }
-  }
 }
-";
+
+/// Fetch the last year of contributions of `login`, or of the viewer.
+pub async fn fetch_contributions(
+    octocrab: &HostClient,
+    login: Option<&str>,
+) -> Result<ContributionCalendar> {
+    let user = if let Some(login) = login {
+        let data: UserContributionsQuery = octocrab
+            .graphql(&UserContributionsQuery::build(UserContributionsVariables {
+                login: login.to_owned(),
+            }))
+            .await
+            .context("GraphQL contributions request failed")?;
+        let Some(user) = data.user else {
+            bail!("user {login} not found");
+        };
+        user
+    } else {
+        let data: ViewerContributionsQuery = octocrab
+            .graphql(&ViewerContributionsQuery::build(()))
+            .await
+            .context("GraphQL contributions request failed")?;
+        data.viewer
+    };
+
+    let calendar = user.contributions_collection.contribution_calendar;
+    Ok(ContributionCalendar {
+        login: user.login,
+        total: calendar.total_contributions.0,
+        weeks: calendar
+            .weeks
+            .into_iter()
+            .map(|w| {
+                w.contribution_days
+                    .into_iter()
+                    .map(|d| ContributionDay {
+                        date: d.date,
+                        count: d.contribution_count.0,
+                        level: d.contribution_level.quartile(),
+                    })
+                    .collect()
+            })
+            .collect(),
+    })
+}
 
 // ---------------------------------------------------------------------------
-// Response types for single-item queries
+// Notification subjects
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize)]
-struct SinglePrData {
-    #[serde(rename = "rateLimit", default)]
-    rate_limit: Option<RateLimitInfo>,
-    repository: Option<SinglePrRepo>,
+/// Subjects resolved per request; keeps the query cost well under the limit.
+const SUBJECTS_PER_QUERY: usize = 50;
+
+// Written by hand: the query aliases one lookup per subject, which the
+// derived queries above cannot express.
+const NOTIFICATION_SUBJECT_FRAGMENT: &str = r"
+fragment SubjectFields on IssueOrPullRequest {
+  ... on Issue { state closedAt author { login } }
+  ... on PullRequest {
+    state
+    author { login }
+    isDraft
+    closedAt
+    commits(last: 1) { nodes { commit { statusCheckRollup { state } } } }
+  }
+}
+";
+
+/// One aliased `repository { issueOrPullRequest }` lookup per subject.
+fn notification_subjects_query(subjects: &[(String, String, u64)]) -> String {
+    use std::fmt::Write;
+
+    let mut query = String::from("query NotificationSubjects {\n");
+    for (i, (owner, repo, number)) in subjects.iter().enumerate() {
+        let _ = writeln!(
+            query,
+            "  s{i}: repository(owner: {owner:?}, name: {repo:?}) {{ \
+             issueOrPullRequest(number: {number}) {{ ...SubjectFields }} }}"
+        );
+    }
+    query.push_str("}\n");
+    query.push_str(NOTIFICATION_SUBJECT_FRAGMENT);
+    query
 }
 
 #[derive(Debug, Deserialize)]
-struct SinglePrRepo {
-    #[serde(rename = "pullRequest")]
-    pull_request: Option<RawFullPullRequest>,
+struct RawSubjectRepo {
+    #[serde(rename = "issueOrPullRequest")]
+    subject: Option<RawSubject>,
 }
 
-/// Full PR response containing both search-row and detail fields.
 #[derive(Debug, Deserialize)]
-struct RawFullPullRequest {
-    number: u64,
-    title: String,
-    #[serde(default)]
-    body: String,
+struct RawSubject {
     state: PrState,
     #[serde(rename = "isDraft", default)]
     is_draft: bool,
-    mergeable: Option<MergeableState>,
-    #[serde(rename = "reviewDecision")]
-    review_decision: Option<ReviewDecision>,
+    #[serde(rename = "closedAt")]
+    closed_at: Option<DateTime<Utc>>,
     #[serde(default)]
-    additions: u32,
+    author: Option<RawActorLogin>,
     #[serde(default)]
-    deletions: u32,
-    #[serde(rename = "headRefName", default)]
-    head_ref_name: String,
-    #[serde(rename = "baseRefName", default)]
-    base_ref_name: String,
-    #[serde(rename = "mergeStateStatus")]
-    merge_state_status: Option<MergeStateStatus>,
-    #[serde(rename = "headRepository")]
-    head_repository: Option<RawHeadRepository>,
-    url: String,
-    #[serde(rename = "updatedAt")]
-    updated_at: DateTime<Utc>,
-    #[serde(rename = "createdAt")]
-    created_at: DateTime<Utc>,
-    author: Option<RawActor>,
-    #[serde(rename = "authorAssociation")]
-    author_association: Option<AuthorAssociation>,
-    labels: Option<Connection<RawLabel>>,
-    assignees: Option<Connection<RawAssignee>>,
-    comments: Option<TotalCount>,
-    #[serde(rename = "latestReviews")]
-    latest_reviews: Option<Connection<RawLatestReview>>,
-    #[serde(rename = "reviewRequests")]
-    review_requests: Option<Connection<RawReviewRequest>>,
-    /// Aliased: `lastCommit: commits(last: 1)` — for `check_runs` on the search row.
-    #[serde(rename = "lastCommit")]
-    last_commit: Option<Connection<RawCommitNode>>,
-    participants: Option<Connection<RawAssignee>>,
-    repository: Option<RawRepository>,
-    // Detail fields
-    reviews: Option<Connection<RawReview>>,
-    #[serde(rename = "reviewThreads")]
-    review_threads: Option<Connection<RawReviewThread>>,
-    #[serde(rename = "timelineItems")]
-    timeline_items: Option<Connection<RawTimelineItem>>,
-    /// Aliased: `allCommits: commits(first: 100)` — for detail commits.
-    #[serde(rename = "allCommits")]
-    all_commits: Option<Connection<RawDetailCommitNode>>,
-    files: Option<Connection<RawFile>>,
-}
-
-impl RawFullPullRequest {
-    /// Split the combined response into a search-row `PullRequest` and a `PrDetail`.
-    fn into_domain(self) -> (PullRequest, PrDetail) {
-        let author = self.author.map(|a| Actor {
-            login: a.login,
-            avatar_url: a.avatar_url,
-        });
+    commits: Option<RawSubjectCommits>,
+}
 
-        let repo = self
-            .repository
-            .and_then(|r| RepoRef::from_full_name(&r.name_with_owner));
+#[derive(Debug, Deserialize)]
+struct RawSubjectCommits {
+    #[serde(default)]
+    nodes: Vec<Option<RawSubjectCommit>>,
+}
 
-        let pr = PullRequest {
-            number: self.number,
-            title: self.title,
-            body: self.body.clone(),
-            author,
-            state: self.state,
-            is_draft: self.is_draft,
-            mergeable: self.mergeable,
-            review_decision: self.review_decision,
-            additions: self.additions,
-            deletions: self.deletions,
-            head_ref: self.head_ref_name,
-            base_ref: self.base_ref_name,
-            labels: extract_labels(self.labels),
-            assignees: extract_assignees(self.assignees),
-            commits: Vec::new(),
-            comments: Vec::new(),
-            review_threads: Vec::new(),
-            review_requests: extract_review_requests(self.review_requests),
-            reviews: extract_latest_reviews(self.latest_reviews),
-            timeline_events: Vec::new(),
-            files: Vec::new(),
-            check_runs: extract_check_runs(self.last_commit),
-            updated_at: self.updated_at,
-            created_at: self.created_at,
-            url: self.url,
-            repo,
-            comment_count: self.comments.map_or(0, |c| c.total_count),
-            author_association: self.author_association,
-            participants: extract_participants(self.participants),
-            merge_state_status: self.merge_state_status,
-            head_repo_owner: self.head_repository.as_ref().map(|r| r.owner.login.clone()),
-            head_repo_name: self.head_repository.map(|r| r.name),
-        };
+#[derive(Debug, Deserialize)]
+struct RawSubjectCommit {
+    commit: RawRollupCommit,
+}
 
-        let detail = PrDetail {
-            body: self.body,
-            reviews: extract_detail_reviews(self.reviews),
-            review_threads: extract_review_threads(self.review_threads),
-            timeline_events: extract_timeline_events(self.timeline_items),
-            commits: extract_detail_commits(self.all_commits),
-            files: extract_files(self.files),
-            mergeable: self.mergeable,
-            behind_by: None,
+impl RawSubject {
+    fn into_status(self) -> SubjectStatus {
+        let state = match self.state {
+            PrState::Open if self.is_draft => SubjectState::Draft,
+            PrState::Open => SubjectState::Open,
+            PrState::Closed => SubjectState::Closed,
+            PrState::Merged => SubjectState::Merged,
         };
+        let ci = self
+            .commits
+            .and_then(|c| c.nodes.into_iter().flatten().next())
+            .and_then(|n| n.commit.status_check_rollup)
+            .map(|r| r.state);
+        SubjectStatus {
+            state,
+            ci,
+            closed_at: self.closed_at,
+            author: self.author.map(|a| a.login),
+        }
+    }
+}
 
-        (pr, detail)
+/// Resolve the current state and CI status of notification subjects given
+/// as `(owner, repo, number)`, in as few aliased queries as possible.
+///
+/// The result is aligned with `subjects`; subjects that could not be read
+/// (deleted, or in a repository the token cannot see) are `None`.
+pub async fn fetch_notification_subjects(
+    octocrab: &HostClient,
+    subjects: &[(String, String, u64)],
+) -> Result<Vec<Option<SubjectStatus>>> {
+    let mut statuses = Vec::with_capacity(subjects.len());
+    for chunk in subjects.chunks(SUBJECTS_PER_QUERY) {
+        let payload = serde_json::json!({ "query": notification_subjects_query(chunk) });
+        let (mut data, warnings): (std::collections::HashMap<String, Option<RawSubjectRepo>>, _) =
+            graphql_partial(octocrab, &payload)
+                .await
+                .context("GraphQL notification subjects request failed")?;
+        for w in warnings {
+            tracing::debug!("notification subjects: {w}");
+        }
+        statuses.extend((0..chunk.len()).map(|i| {
+            data.remove(&format!("s{i}"))
+                .flatten()
+                .and_then(|r| r.subject)
+                .map(RawSubject::into_status)
+        }));
     }
+    Ok(statuses)
 }
 
-// Response types for single issue query
+// ---------------------------------------------------------------------------
+// Workflow files: schedules and manual triggers
+// ---------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize)]
-struct SingleIssueData {
-    #[serde(rename = "rateLimit", default)]
-    rate_limit: Option<RateLimitInfo>,
-    repository: Option<SingleIssueRepo>,
+#[derive(cynic::QueryVariables, Debug)]
+struct WorkflowFilesVariables {
+    owner: String,
+    name: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct SingleIssueRepo {
-    issue: Option<RawFullIssue>,
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "WorkflowFilesVariables")]
+struct WorkflowFilesQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $name)]
+    repository: Option<RawWorkflowRepo>,
 }
 
-/// Full Issue response containing both search-row and detail fields.
-#[derive(Debug, Deserialize)]
-struct RawFullIssue {
-    number: u64,
-    title: String,
-    #[serde(default)]
-    body: String,
-    state: IssueState,
-    url: String,
-    #[serde(rename = "updatedAt")]
-    updated_at: DateTime<Utc>,
-    #[serde(rename = "createdAt")]
-    created_at: DateTime<Utc>,
-    author: Option<RawActor>,
-    assignees: Option<Connection<RawAssignee>>,
-    labels: Option<Connection<RawLabel>>,
-    comments: Option<TotalCount>,
-    #[serde(rename = "reactionGroups", default)]
-    reaction_groups: Vec<RawReactionGroup>,
-    participants: Option<Connection<RawAssignee>>,
-    repository: Option<RawRepository>,
-    #[serde(rename = "timelineItems")]
-    timeline_items: Option<Connection<RawTimelineItem>>,
-}
-
-impl RawFullIssue {
-    fn into_domain(self) -> (Issue, IssueDetail) {
-        let author = self.author.map(|a| Actor {
-            login: a.login,
-            avatar_url: a.avatar_url,
-        });
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository")]
+struct RawWorkflowRepo {
+    #[arguments(expression: "HEAD:.github/workflows")]
+    object: Option<RawWorkflowDir>,
+}
 
-        let labels = self
-            .labels
-            .map(|c| {
-                c.nodes
-                    .into_iter()
-                    .flatten()
-                    .map(|l| Label {
-                        name: l.name,
-                        color: l.color,
-                    })
-                    .collect()
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "GitObject")]
+enum RawWorkflowDir {
+    Tree(RawWorkflowTree),
+    #[cynic(fallback)]
+    Other,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Tree")]
+struct RawWorkflowTree {
+    entries: Option<Vec<RawWorkflowEntry>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "TreeEntry")]
+struct RawWorkflowEntry {
+    name: String,
+    path: Option<String>,
+    object: Option<RawBlobObject>,
+}
+
+/// The YAML files of `.github/workflows` on the default branch of
+/// `owner/repo`, as `(file name, path, text)`.
+async fn fetch_workflow_files(
+    octocrab: &HostClient,
+    owner: &str,
+    repo: &str,
+) -> Result<(Vec<(String, String, String)>, Option<RateLimitInfo>)> {
+    let data: WorkflowFilesQuery = octocrab
+        .graphql(&WorkflowFilesQuery::build(WorkflowFilesVariables {
+            owner: owner.to_owned(),
+            name: repo.to_owned(),
+        }))
+        .await
+        .context("GraphQL workflow files request failed")?;
+
+    let Some(repository) = data.repository else {
+        bail!("repository {owner}/{repo} not found");
+    };
+    let entries = match repository.object {
+        Some(RawWorkflowDir::Tree(tree)) => tree.entries.unwrap_or_default(),
+        Some(RawWorkflowDir::Other) | None => Vec::new(),
+    };
+    let files = entries
+        .into_iter()
+        .filter(|e| {
+            std::path::Path::new(&e.name)
+                .extension()
+                .is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("yml") || ext.eq_ignore_ascii_case("yaml")
+                })
+        })
+        .map(|entry| {
+            let text = match entry.object {
+                Some(RawBlobObject::Blob(blob)) => blob.text.unwrap_or_default(),
+                Some(RawBlobObject::Other) | None => String::new(),
+            };
+            (entry.name, entry.path.unwrap_or_default(), text)
+        })
+        .collect();
+    Ok((files, data.rate_limit.map(RateLimitInfo::from)))
+}
+
+/// The `schedule` triggers of the workflow files on the default branch of
+/// `owner/repo`; empty when it has no `.github/workflows` directory.
+pub async fn fetch_workflow_schedules(
+    octocrab: &HostClient,
+    owner: &str,
+    repo: &str,
+) -> Result<(Vec<WorkflowSchedule>, Option<RateLimitInfo>)> {
+    let (files, rate_limit) = fetch_workflow_files(octocrab, owner, repo).await?;
+    let schedules = files
+        .into_iter()
+        .flat_map(|(file, path, text)| {
+            let (name, crons) = crate::cron::workflow_crons(&text);
+            let workflow = name.unwrap_or(file);
+            crons.into_iter().map(move |cron| WorkflowSchedule {
+                repo: format!("{owner}/{repo}"),
+                workflow: workflow.clone(),
+                path: path.clone(),
+                cron,
             })
-            .unwrap_or_default();
+        })
+        .collect();
+    Ok((schedules, rate_limit))
+}
+
+/// The workflows on the default branch of `owner/repo` that can be run
+/// manually, with their declared inputs.
+pub async fn fetch_dispatchable_workflows(
+    octocrab: &HostClient,
+    owner: &str,
+    repo: &str,
+) -> Result<(Vec<DispatchableWorkflow>, Option<RateLimitInfo>)> {
+    let (files, rate_limit) = fetch_workflow_files(octocrab, owner, repo).await?;
+    let workflows = files
+        .into_iter()
+        .filter_map(|(file, _, text)| {
+            let inputs = crate::dispatch_inputs::workflow_dispatch(&text)?;
+            let (name, _) = crate::cron::workflow_crons(&text);
+            Some(DispatchableWorkflow {
+                repo: format!("{owner}/{repo}"),
+                name: name.unwrap_or_else(|| file.clone()),
+                file,
+                inputs,
+            })
+        })
+        .collect();
+    Ok((workflows, rate_limit))
+}
+
+// ---------------------------------------------------------------------------
+// Single-item combined queries (RefreshItem)
+// ---------------------------------------------------------------------------
+
+/// Combined query that returns all search-row fields AND detail fields for a
+/// single PR, so one API call can update both the table row and the sidebar.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "ItemVariables")]
+struct SinglePullRequestQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
+    repository: Option<SinglePrRepo>,
+}
+
+/// The PR is selected three times: as a search row, as a detail and for
+/// the checks it requires, which only a query naming the PR can ask about.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "ItemVariables")]
+struct SinglePrRepo {
+    #[arguments(number: $number)]
+    pull_request: Option<RawPullRequest>,
+    #[cynic(alias, rename = "pullRequest")]
+    #[arguments(number: $number)]
+    detail: Option<RawPrDetail>,
+    #[cynic(alias, rename = "pullRequest")]
+    #[arguments(number: $number)]
+    required_checks: Option<RawRequiredChecks>,
+}
 
-        let assignees = self
-            .assignees
-            .map(|c| {
-                c.nodes
-                    .into_iter()
-                    .flatten()
-                    .map(|a| Actor {
-                        login: a.login,
-                        avatar_url: String::new(),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequest", variables = "ItemVariables")]
+struct RawRequiredChecks {
+    #[arguments(last: 1)]
+    commits: RawRequiredHeadCommitConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Commit", variables = "ItemVariables")]
+struct RawRequiredHeadCommit {
+    status_check_rollup: Option<RawRequiredStatusCheckRollup>,
+}
 
-        let comment_count = self.comments.map_or(0, |c| c.total_count);
-        let reactions = parse_reaction_groups(&self.reaction_groups);
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "PullRequestCommit", variables = "ItemVariables")]
+struct RawRequiredHeadCommitNode {
+    commit: RawRequiredHeadCommit,
+}
 
-        let repo = self
-            .repository
-            .and_then(|r| RepoRef::from_full_name(&r.name_with_owner));
+connection!(
+    RawRequiredHeadCommitConnection,
+    "PullRequestCommitConnection",
+    RawRequiredHeadCommitNode,
+    "ItemVariables"
+);
+
+impl RawRequiredChecks {
+    /// The names of the head commit's checks the PR requires.
+    fn into_names(self) -> Vec<String> {
+        self.commits
+            .into_nodes()
+            .filter_map(|head| head.commit.status_check_rollup)
+            .flat_map(|r| r.contexts.into_nodes())
+            .filter_map(RawRequiredCheckContext::required_name)
+            .collect()
+    }
+}
 
-        let timeline_events = self
-            .timeline_items
-            .map(|c| {
-                c.nodes
-                    .into_iter()
-                    .flatten()
-                    .filter_map(convert_timeline_item)
-                    .collect()
-            })
+impl SinglePrRepo {
+    /// Split the combined response into a search-row `PullRequest` and a `PrDetail`.
+    fn into_domain(self) -> Option<(PullRequest, PrDetail)> {
+        let mut pr = self.pull_request?.into_domain();
+        let detail = self.detail?.into_domain();
+        let required = self
+            .required_checks
+            .map(RawRequiredChecks::into_names)
             .unwrap_or_default();
+        for cr in &mut pr.check_runs {
+            cr.is_required |= required.contains(&cr.name);
+        }
+        Some((pr, detail))
+    }
+}
 
-        let issue = Issue {
-            number: self.number,
-            title: self.title,
-            body: self.body.clone(),
-            author,
-            state: self.state,
-            assignees,
-            comments: Vec::new(),
-            reactions,
-            labels,
-            updated_at: self.updated_at,
-            created_at: self.created_at,
-            url: self.url,
-            repo,
-            comment_count,
-            participants: self
-                .participants
-                .map(|c| c.nodes.into_iter().flatten().map(|a| a.login).collect())
-                .unwrap_or_default(),
-        };
+/// Combined query that returns all search-row fields AND detail fields for a
+/// single Issue.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "ItemVariables")]
+struct SingleIssueQuery {
+    rate_limit: Option<RawRateLimit>,
+    #[arguments(owner: $owner, name: $repo)]
+    repository: Option<SingleIssueRepo>,
+}
 
-        let detail = IssueDetail {
-            body: self.body,
-            timeline_events,
-        };
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Repository", variables = "ItemVariables")]
+struct SingleIssueRepo {
+    #[arguments(number: $number)]
+    issue_or_pull_request: Option<RawIssueRowItem>,
+    #[cynic(alias, rename = "issueOrPullRequest")]
+    #[arguments(number: $number)]
+    detail: Option<RawIssueDetailItem>,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(graphql_type = "IssueOrPullRequest")]
+enum RawIssueRowItem {
+    Issue(RawIssue),
+    PullRequest(RawIssuePr),
+    #[cynic(fallback)]
+    Other,
+}
 
-        (issue, detail)
+impl RawIssueRowItem {
+    fn into_domain(self) -> Option<Issue> {
+        match self {
+            Self::Issue(issue) => Some(issue.into_domain()),
+            Self::PullRequest(pr) => Some(pr.into_domain()),
+            Self::Other => None,
+        }
     }
 }
 
@@ -2032,7 +3625,8 @@ impl RawFullIssue {
 ///
 /// Returns `(pull_request, pr_detail, rate_limit)`.
 pub async fn fetch_single_pr(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
+    features: HostFeatures,
     owner: &str,
     repo: &str,
     number: u64,
@@ -2048,43 +3642,33 @@ pub async fn fetch_single_pr(
         return Ok((pr, detail, None));
     }
 
-    let payload = GraphQLPayload {
-        query: SINGLE_PR_QUERY,
-        variables: PrDetailVariables {
-            owner: owner.to_owned(),
-            repo: repo.to_owned(),
-            number: i64::try_from(number).context("PR number too large")?,
-        },
-    };
+    let operation =
+        operation::<SinglePullRequestQuery, _>(features, ItemVariables::new(owner, repo, number));
 
-    let data: SinglePrData = octocrab
-        .graphql(&payload)
+    let data: SinglePullRequestQuery = octocrab
+        .graphql(&operation)
         .await
         .context("GraphQL single PR request failed")?;
 
-    let rate_limit = data.rate_limit;
-
-    let raw = data
+    let (pr, detail) = data
         .repository
-        .and_then(|r| r.pull_request)
+        .and_then(SinglePrRepo::into_domain)
         .context("PR not found")?;
 
-    let (pr, detail) = raw.into_domain();
-
     if let Some(c) = cache
         && let Ok(json) = serde_json::to_string(&(&pr, &detail))
     {
         c.insert(cache_key, json).await;
     }
 
-    Ok((pr, detail, rate_limit))
+    Ok((pr, detail, data.rate_limit.map(RateLimitInfo::from)))
 }
 
 /// Fetch a single Issue with combined search-row + detail fields in one query.
 ///
 /// Returns `(issue, issue_detail, rate_limit)`.
 pub async fn fetch_single_issue(
-    octocrab: &Arc<Octocrab>,
+    octocrab: &HostClient,
     owner: &str,
     repo: &str,
     number: u64,
@@ -2100,36 +3684,33 @@ pub async fn fetch_single_issue(
         return Ok((issue, detail, None));
     }
 
-    let payload = GraphQLPayload {
-        query: SINGLE_ISSUE_QUERY,
-        variables: PrDetailVariables {
-            owner: owner.to_owned(),
-            repo: repo.to_owned(),
-            number: i64::try_from(number).context("Issue number too large")?,
-        },
-    };
-
-    let data: SingleIssueData = octocrab
-        .graphql(&payload)
+    let data: SingleIssueQuery = octocrab
+        .graphql(&SingleIssueQuery::build(ItemVariables::new(
+            owner, repo, number,
+        )))
         .await
         .context("GraphQL single issue request failed")?;
 
-    let rate_limit = data.rate_limit;
-
-    let raw = data
+    let home = RepoRef {
+        owner: owner.to_owned(),
+        name: repo.to_owned(),
+    };
+    let (issue, detail) = data
         .repository
-        .and_then(|r| r.issue)
+        .and_then(|r| {
+            let issue = r.issue_or_pull_request?.into_domain()?;
+            let detail = r.detail?.into_domain(&home, number)?;
+            Some((issue, detail))
+        })
         .context("Issue not found")?;
 
-    let (issue, detail) = raw.into_domain();
-
     if let Some(c) = cache
         && let Ok(json) = serde_json::to_string(&(&issue, &detail))
     {
         c.insert(cache_key, json).await;
     }
 
-    Ok((issue, detail, rate_limit))
+    Ok((issue, detail, data.rate_limit.map(RateLimitInfo::from)))
 }
 
 // ---------------------------------------------------------------------------
@@ -2140,6 +3721,26 @@ pub async fn fetch_single_issue(
 mod tests {
     use super::*;
 
+    // --- accumulate_rate_limit ---
+
+    #[test]
+    fn accumulate_rate_limit_sums_page_costs() {
+        let page = |remaining, cost| {
+            Some(RateLimitInfo {
+                limit: 5000,
+                remaining,
+                cost,
+                host: None,
+            })
+        };
+        let mut total = None;
+        accumulate_rate_limit(&mut total, page(4990, 2));
+        accumulate_rate_limit(&mut total, None);
+        accumulate_rate_limit(&mut total, page(4987, 3));
+        let total = total.unwrap();
+        assert_eq!((total.remaining, total.cost), (4987, 5));
+    }
+
     // --- extract_labels ---
 
     #[test]
@@ -2150,19 +3751,14 @@ mod tests {
 
     #[test]
     fn extract_labels_some_with_labels() {
-        let conn = Connection {
-            nodes: vec![
-                Some(RawLabel {
-                    name: "bug".to_owned(),
-                    color: "d73a4a".to_owned(),
-                }),
-                None, // null nodes are filtered out
-                Some(RawLabel {
-                    name: "enhancement".to_owned(),
-                    color: "a2eeef".to_owned(),
-                }),
-            ],
-        };
+        let conn: RawLabelConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "name": "bug", "color": "d73a4a" },
+                null, // null nodes are filtered out
+                { "name": "enhancement", "color": "a2eeef" }
+            ]
+        }))
+        .unwrap();
         let result = extract_labels(Some(conn));
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].name, "bug");
@@ -2171,28 +3767,22 @@ mod tests {
         assert_eq!(result[1].color, "a2eeef");
     }
 
-    // --- extract_assignees ---
+    // --- assignees ---
 
     #[test]
-    fn extract_assignees_none_returns_empty() {
-        let result = extract_assignees(None);
-        assert!(result.is_empty());
+    fn user_connection_null_nodes_returns_empty() {
+        let conn: RawUserConnection =
+            serde_json::from_value(serde_json::json!({ "nodes": null })).unwrap();
+        assert!(conn.into_actors().is_empty());
     }
 
     #[test]
-    fn extract_assignees_some_with_actors() {
-        let conn = Connection {
-            nodes: vec![
-                Some(RawAssignee {
-                    login: "alice".to_owned(),
-                }),
-                None,
-                Some(RawAssignee {
-                    login: "bob".to_owned(),
-                }),
-            ],
-        };
-        let result = extract_assignees(Some(conn));
+    fn user_connection_into_actors() {
+        let conn: RawUserConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [{ "login": "alice" }, null, { "login": "bob" }]
+        }))
+        .unwrap();
+        let result = conn.into_actors();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].login, "alice");
         assert!(
@@ -2202,226 +3792,419 @@ mod tests {
         assert_eq!(result[1].login, "bob");
     }
 
-    // --- extract_check_runs ---
-
-    #[test]
-    fn extract_check_runs_none_returns_empty() {
-        let result = extract_check_runs(None);
-        assert!(result.is_empty());
+    // --- head commit checks ---
+
+    fn check_run(name: &str, conclusion: &str) -> serde_json::Value {
+        serde_json::json!({
+            "__typename": "CheckRun",
+            "databaseId": null,
+            "name": name,
+            "status": "COMPLETED",
+            "conclusion": conclusion,
+            "detailsUrl": format!("https://example.com/{name}"),
+            "startedAt": null,
+            "completedAt": null,
+            "checkSuite": { "workflowRun": null }
+        })
     }
 
     #[test]
-    fn extract_check_runs_empty_commits_returns_empty() {
-        let conn = Connection { nodes: vec![] };
-        let result = extract_check_runs(Some(conn));
-        assert!(result.is_empty());
+    fn head_commit_checks_empty_commits_returns_empty() {
+        let conn: RawHeadCommitConnection =
+            serde_json::from_value(serde_json::json!({ "nodes": [] })).unwrap();
+        let (head_sha, check_runs) = conn.into_checks(&[]);
+        assert!(head_sha.is_empty());
+        assert!(check_runs.is_empty());
     }
 
     #[test]
-    fn extract_check_runs_with_check_run_contexts() {
-        let ctx_success = RawCheckContext {
-            name: Some("CI".to_owned()),
-            status: Some(CheckStatus::Completed),
-            conclusion: Some(CheckConclusion::Success),
-            details_url: Some("https://example.com/ci".to_owned()),
-            started_at: None,
-            completed_at: None,
-            check_suite: None,
-            context: None,
-            state: None,
-            target_url: None,
-        };
-        let ctx_failure = RawCheckContext {
-            name: Some("Lint".to_owned()),
-            status: Some(CheckStatus::Completed),
-            conclusion: Some(CheckConclusion::Failure),
-            details_url: None,
-            started_at: None,
-            completed_at: None,
-            check_suite: None,
-            context: None,
-            state: None,
-            target_url: None,
-        };
-        let rollup = RawStatusCheckRollup {
-            contexts: Some(Connection {
-                nodes: vec![Some(ctx_success), Some(ctx_failure)],
-            }),
-        };
-        let commit = RawCommit {
-            status_check_rollup: Some(rollup),
-        };
-        let commit_node = RawCommitNode {
-            commit: Some(commit),
-        };
-        let conn = Connection {
-            nodes: vec![Some(commit_node)],
-        };
-        let result = extract_check_runs(Some(conn));
+    fn head_commit_checks_mark_required_contexts() {
+        let conn: RawHeadCommitConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [{ "commit": {
+                "oid": "abc123",
+                "statusCheckRollup": { "contexts": { "nodes": [
+                    check_run("CI", "SUCCESS"),
+                    check_run("Lint", "FAILURE")
+                ] } }
+            } }]
+        }))
+        .unwrap();
+        let (head_sha, result) = conn.into_checks(&["Lint".to_owned()]);
+        assert_eq!(head_sha, "abc123");
         assert_eq!(result.len(), 2);
+        assert!(!result[0].is_required);
+        assert!(result[1].is_required);
         assert_eq!(result[0].name, "CI");
         assert_eq!(result[0].status, Some(CheckStatus::Completed));
         assert_eq!(result[0].conclusion, Some(CheckConclusion::Success));
-        assert_eq!(result[0].url.as_deref(), Some("https://example.com/ci"));
+        assert_eq!(result[0].url.as_deref(), Some("https://example.com/CI"));
         assert_eq!(result[1].name, "Lint");
         assert_eq!(result[1].conclusion, Some(CheckConclusion::Failure));
     }
 
-    // --- convert_check_context ---
+    #[test]
+    fn required_checks_list_required_names() {
+        let checks: RawRequiredChecks = serde_json::from_value(serde_json::json!({
+            "commits": { "nodes": [{ "commit": {
+                "statusCheckRollup": { "contexts": { "nodes": [
+                    { "__typename": "CheckRun", "name": "build", "isRequired": true },
+                    { "__typename": "CheckRun", "name": "lint", "isRequired": false },
+                    { "__typename": "StatusContext", "context": "ci/circleci", "isRequired": true }
+                ] } }
+            } }] }
+        }))
+        .unwrap();
+        assert_eq!(checks.into_names(), vec!["build", "ci/circleci"]);
+    }
+
+    // --- deployments and awaiting runs ---
 
     #[test]
-    fn convert_check_context_check_run() {
-        let ctx = RawCheckContext {
-            name: Some("build".to_owned()),
-            status: Some(CheckStatus::InProgress),
-            conclusion: None,
-            details_url: Some("https://ci.example.com".to_owned()),
-            started_at: None,
-            completed_at: None,
-            check_suite: Some(RawCheckSuite {
-                workflow_run: Some(RawCheckSuiteWorkflowRun {
-                    database_id: Some(42),
-                    workflow: Some(RawCheckSuiteWorkflow {
-                        name: Some("CI".to_owned()),
-                    }),
-                }),
-            }),
-            context: None,
-            state: None,
-            target_url: None,
-        };
-        let cr = convert_check_context(ctx);
+    fn extract_deployments_reads_environment_urls() {
+        let conn: RawDeploymentConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                {
+                    "environment": "preview",
+                    "state": "ACTIVE",
+                    "createdAt": "2026-01-02T00:00:00Z",
+                    "latestStatus": { "environmentUrl": "https://pr-1.example.com" }
+                },
+                {
+                    "environment": null,
+                    "state": "NOT_YET_KNOWN",
+                    "createdAt": "2026-01-01T00:00:00Z",
+                    "latestStatus": { "environmentUrl": "" }
+                }
+            ]
+        }))
+        .unwrap();
+        let deployments = extract_deployments(Some(conn));
+        assert_eq!(deployments.len(), 2);
+        assert_eq!(deployments[0].environment, "preview");
+        assert_eq!(deployments[0].state, Some(DeploymentState::Active));
+        assert_eq!(
+            deployments[0].url.as_deref(),
+            Some("https://pr-1.example.com")
+        );
+        assert_eq!(deployments[1].environment, "<unknown>");
+        assert_eq!(deployments[1].state, Some(DeploymentState::Unknown));
+        assert!(deployments[1].url.is_none());
+    }
+
+    #[test]
+    fn extract_awaiting_approval_keeps_held_runs() {
+        let conn: RawCommitCheckSuiteConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                {
+                    "conclusion": "ACTION_REQUIRED",
+                    "workflowRun": { "databaseId": 7, "workflow": { "name": "CI" } }
+                },
+                {
+                    "conclusion": "SUCCESS",
+                    "workflowRun": { "databaseId": 8, "workflow": { "name": "Lint" } }
+                },
+                { "conclusion": "ACTION_REQUIRED", "workflowRun": null }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            extract_awaiting_approval(Some(conn)),
+            [AwaitingRun {
+                run_id: 7,
+                workflow: "CI".to_owned()
+            }]
+        );
+    }
+
+    // --- merge_requirements ---
+
+    #[test]
+    fn merge_requirements_takes_strictest_of_protection_and_rulesets() {
+        let base_ref: RawDetailBaseRef = serde_json::from_value(serde_json::json!({
+            "branchProtectionRule": {
+                "requiresApprovingReviews": true,
+                "requiredApprovingReviewCount": 1,
+                "requiresCodeOwnerReviews": false,
+                "requiresConversationResolution": false,
+                "requiredStatusCheckContexts": ["build"],
+                "requiresCommitSignatures": false
+            },
+            "rules": { "nodes": [
+                { "type": "PULL_REQUEST", "parameters": {
+                    "__typename": "PullRequestParameters",
+                    "requiredApprovingReviewCount": 2,
+                    "requireCodeOwnerReview": true,
+                    "requiredReviewThreadResolution": false
+                } },
+                { "type": "REQUIRED_STATUS_CHECKS", "parameters": {
+                    "__typename": "RequiredStatusChecksParameters",
+                    "requiredStatusChecks": [{ "context": "build" }, { "context": "lint" }]
+                } },
+                { "type": "DELETION", "parameters": null },
+                { "type": "REQUIRED_SIGNATURES", "parameters": null }
+            ] },
+            "target": null
+        }))
+        .unwrap();
+        let entry: RawMergeQueueEntry = serde_json::from_value(serde_json::json!({
+            "position": 3, "state": "AWAITING_CHECKS", "estimatedTimeToMerge": 600
+        }))
+        .unwrap();
+        let req = merge_requirements(Some(base_ref), true, Some(entry));
+        assert_eq!(req.required_approvals, 2);
+        assert!(req.requires_code_owner_review);
+        assert!(!req.requires_conversation_resolution);
+        assert_eq!(req.required_checks, vec!["build", "lint"]);
+        assert!(req.requires_signatures);
+        assert!(req.merge_queue_enabled);
+        let entry = req.queue_entry.unwrap();
+        assert_eq!(entry.position, 3);
+        assert_eq!(entry.state, MergeQueueEntryState::AwaitingChecks);
+        assert_eq!(entry.estimated_secs, Some(600));
+
+        let unreadable = merge_requirements(None, false, None);
+        assert_eq!(unreadable.required_approvals, 0);
+        assert!(unreadable.required_checks.is_empty());
+        assert!(unreadable.queue_entry.is_none());
+    }
+
+    #[test]
+    fn code_owners_read_from_first_candidate_found() {
+        let base_ref: RawDetailBaseRef = serde_json::from_value(serde_json::json!({
+            "branchProtectionRule": null,
+            "target": {
+                "__typename": "Commit",
+                "github_dir": null,
+                "root": { "object": { "__typename": "Blob", "text": "* @octo/core\n" } },
+                "docs_dir": { "object": { "__typename": "Blob", "text": "* @octo/docs\n" } }
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            extract_code_owners(Some(&base_ref)),
+            CodeOwners::parse("* @octo/core\n")
+        );
+    }
+
+    // --- merge queue ---
+
+    #[test]
+    fn merge_queue_orders_entries_and_skips_hidden_prs() {
+        let repo: MergeQueueRepo = serde_json::from_value(serde_json::json!({
+            "defaultBranchRef": { "name": "main" },
+            "mergeQueue": {
+                "url": "https://github.com/o/r/queue/main",
+                "entries": { "nodes": [
+                    {
+                        "position": 2, "state": "QUEUED", "estimatedTimeToMerge": null,
+                        "enqueuedAt": "2026-01-02T00:00:00Z", "headCommit": null,
+                        "pullRequest": { "number": 8, "title": "Second", "url": "u8",
+                                         "author": { "login": "bob" } }
+                    },
+                    {
+                        "position": 1, "state": "AWAITING_CHECKS", "estimatedTimeToMerge": 300,
+                        "enqueuedAt": "2026-01-01T00:00:00Z",
+                        "headCommit": { "statusCheckRollup": { "state": "PENDING" } },
+                        "pullRequest": { "number": 7, "title": "First", "url": "u7",
+                                         "author": null }
+                    },
+                    {
+                        "position": 3, "state": "QUEUED", "estimatedTimeToMerge": null,
+                        "enqueuedAt": "2026-01-03T00:00:00Z", "headCommit": null,
+                        "pullRequest": null
+                    }
+                ] }
+            }
+        }))
+        .unwrap();
+        let queue = repo.merge_queue.unwrap().into_domain("main".to_owned());
+        let numbers: Vec<u64> = queue.entries.iter().map(|e| e.number).collect();
+        assert_eq!(numbers, vec![7, 8]);
+        assert_eq!(queue.entries[0].ci, Some(CommitCheckState::Pending));
+        assert_eq!(queue.entries[0].entry.estimated_secs, Some(300));
+        assert_eq!(queue.entries[1].author.as_deref(), Some("bob"));
+        assert!(queue.entries[1].ci.is_none());
+    }
+
+    // --- notification subjects ---
+
+    #[test]
+    fn notification_subjects_query_aliases_each_subject() {
+        let query = notification_subjects_query(&[
+            ("octo".to_owned(), "app".to_owned(), 7),
+            ("octo".to_owned(), "lib".to_owned(), 12),
+        ]);
+        assert!(query.contains(
+            r#"s0: repository(owner: "octo", name: "app") { issueOrPullRequest(number: 7)"#
+        ));
+        assert!(query.contains(r#"s1: repository(owner: "octo", name: "lib")"#));
+        assert!(query.contains("fragment SubjectFields on IssueOrPullRequest"));
+    }
+
+    #[test]
+    fn raw_subject_maps_draft_and_rollup() {
+        let pr: RawSubject = serde_json::from_value(serde_json::json!({
+            "state": "OPEN",
+            "isDraft": true,
+            "closedAt": null,
+            "commits": { "nodes": [
+                { "commit": { "statusCheckRollup": { "state": "FAILURE" } } }
+            ] }
+        }))
+        .unwrap();
+        let status = pr.into_status();
+        assert_eq!(status.state, SubjectState::Draft);
+        assert_eq!(status.ci, Some(CommitCheckState::Failure));
+
+        let issue: RawSubject = serde_json::from_value(serde_json::json!({
+            "state": "CLOSED",
+            "closedAt": "2026-01-02T00:00:00Z",
+            "author": { "login": "alice" }
+        }))
+        .unwrap();
+        let status = issue.into_status();
+        assert_eq!(status.state, SubjectState::Closed);
+        assert!(status.ci.is_none());
+        assert!(status.closed_at.is_some());
+        assert_eq!(status.author.as_deref(), Some("alice"));
+    }
+
+    // --- merge_issue_refs ---
+
+    #[test]
+    fn merge_issue_refs_interleaves_by_update_time() {
+        let repo: OpenIssueRefsRepo = serde_json::from_value(serde_json::json!({
+            "issues": { "nodes": [
+                { "number": 3, "title": "Old issue", "updatedAt": "2026-01-01T00:00:00Z" }
+            ] },
+            "pullRequests": { "nodes": [
+                { "number": 7, "title": "Fresh PR", "updatedAt": "2026-01-03T00:00:00Z" },
+                null
+            ] }
+        }))
+        .unwrap();
+        assert_eq!(
+            merge_issue_refs(repo),
+            vec![(7, "Fresh PR".to_owned()), (3, "Old issue".to_owned())]
+        );
+    }
+
+    fn check_context(node: serde_json::Value) -> Option<CheckRun> {
+        serde_json::from_value::<RawCheckContext>(node)
+            .unwrap()
+            .into_domain()
+    }
+
+    #[test]
+    fn check_context_check_run() {
+        let cr = check_context(serde_json::json!({
+            "__typename": "CheckRun",
+            "databaseId": 7,
+            "name": "build",
+            "status": "IN_PROGRESS",
+            "conclusion": null,
+            "detailsUrl": "https://ci.example.com",
+            "startedAt": null,
+            "completedAt": null,
+            "checkSuite": { "workflowRun": {
+                "databaseId": 42,
+                "workflow": { "name": "CI" }
+            } }
+        }))
+        .unwrap();
         assert_eq!(cr.name, "build");
         assert_eq!(cr.status, Some(CheckStatus::InProgress));
         assert!(cr.conclusion.is_none());
         assert_eq!(cr.url.as_deref(), Some("https://ci.example.com"));
         assert_eq!(cr.workflow_run_id, Some(42));
         assert_eq!(cr.workflow_name.as_deref(), Some("CI"));
+        assert_eq!(cr.job_id, Some(7));
+    }
+
+    fn status_context(context: &str, state: &str) -> CheckRun {
+        check_context(serde_json::json!({
+            "__typename": "StatusContext",
+            "context": context,
+            "state": state,
+            "targetUrl": format!("https://ci.example.com/{context}")
+        }))
+        .unwrap()
     }
 
     #[test]
-    fn convert_check_context_status_context_success() {
-        let ctx = RawCheckContext {
-            name: None,
-            status: None,
-            conclusion: None,
-            details_url: None,
-            started_at: None,
-            completed_at: None,
-            check_suite: None,
-            context: Some("ci/circleci".to_owned()),
-            state: Some("success".to_owned()),
-            target_url: Some("https://circleci.com/build/123".to_owned()),
-        };
-        let cr = convert_check_context(ctx);
+    fn check_context_status_context_success() {
+        let cr = status_context("ci/circleci", "SUCCESS");
         assert_eq!(cr.name, "ci/circleci");
         assert_eq!(cr.status, Some(CheckStatus::Completed));
         assert_eq!(cr.conclusion, Some(CheckConclusion::Success));
-        assert_eq!(cr.url.as_deref(), Some("https://circleci.com/build/123"));
+        assert_eq!(
+            cr.url.as_deref(),
+            Some("https://ci.example.com/ci/circleci")
+        );
     }
 
     #[test]
-    fn convert_check_context_status_context_failure() {
-        let ctx = RawCheckContext {
-            name: None,
-            status: None,
-            conclusion: None,
-            details_url: None,
-            started_at: None,
-            completed_at: None,
-            check_suite: None,
-            context: Some("deploy".to_owned()),
-            state: Some("failure".to_owned()),
-            target_url: None,
-        };
-        let cr = convert_check_context(ctx);
-        assert_eq!(cr.name, "deploy");
-        assert_eq!(cr.status, Some(CheckStatus::Completed));
-        assert_eq!(cr.conclusion, Some(CheckConclusion::Failure));
+    fn check_context_status_context_failure() {
+        for state in ["FAILURE", "ERROR"] {
+            let cr = status_context("deploy", state);
+            assert_eq!(cr.name, "deploy");
+            assert_eq!(cr.status, Some(CheckStatus::Completed));
+            assert_eq!(cr.conclusion, Some(CheckConclusion::Failure));
+        }
     }
 
     #[test]
-    fn convert_check_context_status_context_pending() {
-        let ctx = RawCheckContext {
-            name: None,
-            status: None,
-            conclusion: None,
-            details_url: None,
-            started_at: None,
-            completed_at: None,
-            check_suite: None,
-            context: Some("pending-job".to_owned()),
-            state: Some("pending".to_owned()),
-            target_url: None,
-        };
-        let cr = convert_check_context(ctx);
+    fn check_context_status_context_pending() {
+        let cr = status_context("pending-job", "PENDING");
         assert_eq!(cr.name, "pending-job");
         assert_eq!(cr.status, Some(CheckStatus::InProgress));
         assert!(cr.conclusion.is_none());
     }
 
     #[test]
-    fn convert_check_context_no_name_or_context_uses_unknown() {
-        let ctx = RawCheckContext {
-            name: None,
-            status: None,
-            conclusion: None,
-            details_url: None,
-            started_at: None,
-            completed_at: None,
-            check_suite: None,
-            context: None,
-            state: None,
-            target_url: None,
-        };
-        let cr = convert_check_context(ctx);
-        assert_eq!(cr.name, "<unknown>");
+    fn check_context_of_unknown_type_is_skipped() {
+        assert!(check_context(serde_json::json!({ "__typename": "FutureContext" })).is_none());
     }
 
     // --- extract_review_requests ---
 
     #[test]
     fn extract_review_requests_none_returns_empty() {
-        let result = extract_review_requests(None);
-        assert!(result.is_empty());
+        let (users, teams) = extract_review_requests(None);
+        assert!(users.is_empty());
+        assert!(teams.is_empty());
     }
 
     #[test]
     fn extract_review_requests_with_requests() {
-        let conn = Connection {
-            nodes: vec![
-                Some(RawReviewRequest {
-                    requested_reviewer: Some(RawReviewer {
-                        login: Some("reviewer1".to_owned()),
-                    }),
-                }),
-                // Reviewer without login (e.g. team) is filtered out
-                Some(RawReviewRequest {
-                    requested_reviewer: Some(RawReviewer { login: None }),
-                }),
-                None,
-                Some(RawReviewRequest {
-                    requested_reviewer: Some(RawReviewer {
-                        login: Some("reviewer2".to_owned()),
-                    }),
-                }),
-            ],
-        };
-        let result = extract_review_requests(Some(conn));
-        assert_eq!(result.len(), 2);
-        assert_eq!(result[0].login, "reviewer1");
-        assert_eq!(result[1].login, "reviewer2");
+        let conn: RawReviewRequestConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "requestedReviewer": { "__typename": "User", "login": "reviewer1" } },
+                // Team reviewers carry a combined slug instead of a login.
+                { "requestedReviewer": { "__typename": "Team", "combinedSlug": "acme/platform" } },
+                // Mannequins cannot review and are dropped.
+                { "requestedReviewer": { "__typename": "Mannequin" } },
+                null,
+                { "requestedReviewer": { "__typename": "User", "login": "reviewer2" } }
+            ]
+        }))
+        .unwrap();
+        let (users, teams) = extract_review_requests(Some(conn));
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].login, "reviewer1");
+        assert_eq!(users[1].login, "reviewer2");
+        assert_eq!(teams, vec!["acme/platform"]);
     }
 
     #[test]
     fn extract_review_requests_no_reviewer_field() {
-        let conn = Connection {
-            nodes: vec![Some(RawReviewRequest {
-                requested_reviewer: None,
-            })],
-        };
-        let result = extract_review_requests(Some(conn));
-        assert!(result.is_empty());
+        let conn: RawReviewRequestConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [{ "requestedReviewer": null }]
+        }))
+        .unwrap();
+        let (users, teams) = extract_review_requests(Some(conn));
+        assert!(users.is_empty());
+        assert!(teams.is_empty());
     }
 
     // --- extract_latest_reviews ---
@@ -2434,34 +4217,72 @@ mod tests {
 
     #[test]
     fn extract_latest_reviews_with_reviews() {
-        let conn = Connection {
-            nodes: vec![
-                Some(RawLatestReview {
-                    state: Some(ReviewState::Approved),
-                    author: Some(RawActor {
-                        login: "alice".to_owned(),
-                        avatar_url: "https://avatar.example.com/alice".to_owned(),
-                    }),
-                }),
-                Some(RawLatestReview {
-                    state: None,
-                    author: None,
-                }),
-            ],
-        };
+        let conn: RawLatestReviewConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "state": "APPROVED", "author": { "login": "alice" } },
+                { "state": "FUTURE_STATE", "author": null }
+            ]
+        }))
+        .unwrap();
         let result = extract_latest_reviews(Some(conn));
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].state, ReviewState::Approved);
         assert_eq!(result[0].author.as_ref().unwrap().login, "alice");
-        assert_eq!(
-            result[0].author.as_ref().unwrap().avatar_url,
-            "https://avatar.example.com/alice"
-        );
-        // None state defaults to Unknown
+        // Unknown states default to Unknown
         assert_eq!(result[1].state, ReviewState::Unknown);
         assert!(result[1].author.is_none());
     }
 
+    // --- typed enums ---
+
+    #[test]
+    fn signature_states_map_to_commit_signatures() {
+        let state = |s: &str| -> CommitSignature {
+            serde_json::from_value::<RawSignatureState>(s.into())
+                .unwrap()
+                .into()
+        };
+        assert_eq!(state("VALID"), CommitSignature::Verified);
+        assert_eq!(state("UNSIGNED"), CommitSignature::Unsigned);
+        assert_eq!(
+            state("UNKNOWN_KEY"),
+            CommitSignature::from_state("UNKNOWN_KEY")
+        );
+    }
+
+    #[test]
+    fn reaction_groups_count_each_content() {
+        let groups: Vec<RawReactionGroup> = serde_json::from_value(serde_json::json!([
+            { "content": "THUMBS_UP", "users": { "totalCount": 3 } },
+            { "content": "EYES", "users": { "totalCount": 1 } }
+        ]))
+        .unwrap();
+        let reactions = parse_reaction_groups(Some(groups));
+        assert_eq!(reactions.thumbs_up, 3);
+        assert_eq!(reactions.eyes, 1);
+        assert_eq!(reactions.heart, 0);
+    }
+
+    // --- queries ---
+
+    #[test]
+    fn operation_leaves_out_missing_features() {
+        let variables = || ItemVariables::new("o", "r", 1);
+        let full = operation::<PullRequestDetailQuery, _>(HostFeatures::ALL, variables());
+        assert!(full.query.contains("mergeQueueEntry"));
+        assert!(full.query.contains("rules(first: 50)"));
+        let lagging = HostFeatures {
+            merge_state_status: true,
+            merge_queue: false,
+            repository_rules: false,
+        };
+        let adapted = operation::<PullRequestDetailQuery, _>(lagging, variables());
+        assert!(!adapted.query.contains("mergeQueueEntry"));
+        assert!(!adapted.query.contains("isMergeQueueEnabled"));
+        assert!(!adapted.query.contains("rules("));
+        assert!(adapted.query.contains("branchProtectionRule"));
+    }
+
     // --- ensure_type_qualifier ---
 
     #[test]
@@ -2497,41 +4318,48 @@ mod tests {
 /// that class of regression for every query.
 #[cfg(test)]
 mod graphql_endpoint_tests {
-    use std::sync::{Arc, Once};
+    use std::sync::Once;
 
     use serde_json::{Value, json};
-    use wiremock::{Mock, MockServer, ResponseTemplate, matchers::path};
+    use wiremock::matchers::{body_partial_json, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use super::*;
 
-    /// Build an `Octocrab` wired to a mock server that responds to any POST
+    /// Build a client wired to a mock server that responds to any POST
     /// `/graphql` with `body`. The returned `MockServer` must be held alive
     /// for the duration of the call.
-    async fn graphql_returns(body: Value) -> (MockServer, Arc<Octocrab>) {
+    async fn graphql_returns(body: Value) -> (MockServer, HostClient) {
+        let server = MockServer::start().await;
+        Mock::given(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+        let client = client_of(&server);
+        (server, client)
+    }
+
+    /// A client sending its requests to `server`.
+    fn client_of(server: &MockServer) -> HostClient {
         static INSTALL_CRYPTO: Once = Once::new();
         INSTALL_CRYPTO.call_once(|| {
             rustls::crypto::aws_lc_rs::default_provider()
                 .install_default()
                 .expect("install rustls CryptoProvider");
         });
-
-        let server = MockServer::start().await;
-        Mock::given(path("/graphql"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(body))
-            .mount(&server)
-            .await;
-        let octocrab = Arc::new(
-            Octocrab::builder()
-                .base_uri(server.uri())
-                .unwrap()
-                .build()
-                .unwrap(),
-        );
-        (server, octocrab)
+        let octocrab = Octocrab::builder()
+            .base_uri(server.uri())
+            .unwrap()
+            .build()
+            .unwrap();
+        HostClient::from(octocrab)
     }
 
-    /// Wrap a GraphQL `data` payload in the standard envelope GitHub returns.
+    /// Wrap a GraphQL `data` payload in the standard envelope GitHub returns,
+    /// with the `rateLimit` most queries select.
     fn envelope(data: &Value) -> Value {
+        let mut data = data.clone();
+        data["rateLimit"] = Value::Null;
         json!({ "data": data })
     }
 
@@ -2539,31 +4367,183 @@ mod graphql_endpoint_tests {
         json!({"pageInfo": {"hasNextPage": false, "endCursor": null}, "nodes": []})
     }
 
+    /// An issue search result; PRs also read `isDraft`.
+    fn issue_node(typename: &str, number: u64, state: &str) -> Value {
+        json!({
+            "__typename": typename,
+            "number": number,
+            "title": "t",
+            "body": "",
+            "state": state,
+            "isDraft": false,
+            "url": "https://github.com/o/r/1",
+            "updatedAt": "2024-01-01T00:00:00Z",
+            "createdAt": "2024-01-01T00:00:00Z",
+            "author": null,
+            "assignees": {"nodes": []},
+            "labels": null,
+            "milestone": null,
+            "comments": {"totalCount": 0},
+            "reactionGroups": null,
+            "participants": {"nodes": []},
+            "repository": {"nameWithOwner": "o/r"},
+        })
+    }
+
     #[tokio::test]
     async fn search_pull_requests_returns_empty_page() {
         let (_s, oc) = graphql_returns(envelope(&json!({"search": empty_page()}))).await;
-        let page = search_pull_requests(&oc, "is:pr", 10, None).await.unwrap();
+        let page = search_pull_requests(&oc, HostFeatures::ALL, "is:pr", 10, None)
+            .await
+            .unwrap();
         assert!(page.pull_requests.is_empty());
         assert!(!page.page_info.has_next_page);
     }
 
+    #[tokio::test]
+    async fn search_keeps_data_when_errors_are_reported() {
+        let body = json!({
+            "data": {"rateLimit": null, "search": {
+                "pageInfo": {"hasNextPage": false, "endCursor": null},
+                "nodes": [null, {"number": "not a number"}]
+            }},
+            "errors": [{
+                "type": "FORBIDDEN",
+                "path": ["search", "nodes", 0],
+                "message": "Resource not accessible by integration"
+            }]
+        });
+        let (_s, oc) = graphql_returns(body).await;
+        let page = search_issues(&oc, "is:issue", IssueFilterKind::Issue, 10, None)
+            .await
+            .unwrap();
+        assert!(page.issues.is_empty());
+        assert_eq!(page.warnings.len(), 2);
+        assert_eq!(
+            page.warnings[0],
+            "search.nodes.0: Resource not accessible by integration"
+        );
+        assert!(page.warnings[1].starts_with("skipped a result:"));
+    }
+
+    #[tokio::test]
+    async fn search_fails_when_errors_come_without_data() {
+        let body = json!({
+            "data": null,
+            "errors": [{"message": "API rate limit exceeded"}]
+        });
+        let (_s, oc) = graphql_returns(body).await;
+        let err = search_pull_requests(&oc, HostFeatures::ALL, "is:pr", 10, None)
+            .await
+            .err()
+            .unwrap();
+        assert!(format!("{err:#}").contains("API rate limit exceeded"));
+    }
+
     #[tokio::test]
     async fn search_issues_returns_empty_page() {
         let (_s, oc) = graphql_returns(envelope(&json!({"search": empty_page()}))).await;
-        let page = search_issues(&oc, "is:issue", 10, None).await.unwrap();
+        let page = search_issues(&oc, "is:issue", IssueFilterKind::Issue, 10, None)
+            .await
+            .unwrap();
         assert!(page.issues.is_empty());
         assert!(!page.page_info.has_next_page);
     }
 
+    #[tokio::test]
+    async fn search_issues_any_keeps_pull_requests() {
+        let search = json!({
+            "pageInfo": {"hasNextPage": false, "endCursor": null},
+            "nodes": [issue_node("Issue", 1, "OPEN"), issue_node("PullRequest", 2, "MERGED")],
+        });
+        let (_s, oc) = graphql_returns(envelope(&json!({"search": search}))).await;
+        let page = search_issues(&oc, "repo:o/r", IssueFilterKind::Any, 10, None)
+            .await
+            .unwrap();
+        let [issue, pr] = page.issues.as_slice() else {
+            panic!("expected two items, got {}", page.issues.len());
+        };
+        assert!(!issue.is_pull_request());
+        assert_eq!(issue.state, IssueState::Open);
+        assert_eq!(pr.pr_state, Some(PrState::Merged));
+        assert_eq!(pr.state, IssueState::Closed);
+    }
+
+    #[tokio::test]
+    async fn search_issues_all_reports_each_page_so_far() {
+        let server = MockServer::start().await;
+        let page = |number: u64, next: Option<&str>| {
+            envelope(&json!({"search": {
+                "pageInfo": {"hasNextPage": next.is_some(), "endCursor": next},
+                "nodes": [issue_node("Issue", number, "OPEN")],
+            }}))
+        };
+        // The first page answers the request no later-page mock matches.
+        for (after, number, next) in [("c1", 2, Some("c2")), ("c2", 3, None)] {
+            Mock::given(path("/graphql"))
+                .and(body_partial_json(json!({"variables": {"after": after}})))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page(number, next)))
+                .with_priority(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(1, Some("c1"))))
+            .mount(&server)
+            .await;
+
+        let mut so_far = Vec::new();
+        let (issues, _, warnings) = search_issues_all(
+            &client_of(&server),
+            "is:issue",
+            IssueFilterKind::Issue,
+            10,
+            None,
+            |issues| so_far.push(issues.len()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(so_far, [1, 2]);
+        let numbers: Vec<u64> = issues.iter().map(|i| i.number).collect();
+        assert_eq!(numbers, [1, 2, 3]);
+        assert!(warnings.is_empty());
+    }
+
     #[tokio::test]
     async fn fetch_pr_detail_handles_missing_repo() {
         let (_s, oc) = graphql_returns(envelope(&json!({"repository": null}))).await;
-        let Err(err) = fetch_pr_detail(&oc, "x", "y", 1, None).await else {
+        let Err(err) = fetch_pr_detail(&oc, HostFeatures::ALL, "x", "y", 1, None).await else {
             panic!("expected PR-not-found error");
         };
         assert!(err.to_string().contains("PR not found"));
     }
 
+    #[tokio::test]
+    async fn fetch_pr_detail_page_reads_files_and_cursor() {
+        let files = json!({
+            "totalCount": 150,
+            "pageInfo": {"hasNextPage": true, "endCursor": "c2"},
+            "nodes": [{
+                "path": "a.rs",
+                "additions": 1,
+                "deletions": 2,
+                "changeType": "MODIFIED",
+                "viewerViewedState": "UNVIEWED",
+            }],
+        });
+        let body = envelope(&json!({"repository": {"pullRequest": {"files": files}}}));
+        let (_s, oc) = graphql_returns(body).await;
+        let (page, _) = fetch_pr_detail_page(&oc, "x", "y", 1, PrDetailConnection::Files, "c1")
+            .await
+            .unwrap();
+        let PrDetailPage::Files { files, page } = page else {
+            panic!("expected a files page");
+        };
+        assert_eq!(files.len(), 1);
+        assert_eq!(page.total, 150);
+        assert_eq!(page.next.as_deref(), Some("c2"));
+    }
+
     #[tokio::test]
     async fn fetch_issue_detail_handles_missing_repo() {
         let (_s, oc) = graphql_returns(envelope(&json!({"repository": null}))).await;
@@ -2573,6 +4553,43 @@ mod graphql_endpoint_tests {
         assert!(err.to_string().contains("Issue not found"));
     }
 
+    #[tokio::test]
+    async fn fetch_issue_detail_links_parent_sub_issues_and_body_refs() {
+        let link = |number: u64, state: &str, subs: u32| {
+            json!({
+                "number": number,
+                "title": format!("Issue {number}"),
+                "state": state,
+                "repository": {"nameWithOwner": "acme/web"},
+                "subIssues": {"totalCount": subs},
+            })
+        };
+        let (_s, oc) = graphql_returns(envelope(&json!({"repository": {"issueOrPullRequest": {
+            "__typename": "Issue",
+            "body": "- [ ] #3 Docs\nBlocked by acme/api#9",
+            "parent": link(1, "OPEN", 4),
+            "subIssues": {"nodes": [link(2, "CLOSED", 0)]},
+            "timelineItems": {"nodes": []},
+        }}})))
+        .await;
+        let (detail, _) = fetch_issue_detail(&oc, "acme", "web", 5, None)
+            .await
+            .unwrap();
+        let links = detail.links;
+        let parent = links.parent.unwrap();
+        assert_eq!((parent.number, parent.sub_issue_count), (1, 4));
+        let children: Vec<_> = links
+            .children
+            .iter()
+            .map(|c| (c.number, c.state, c.title.as_str()))
+            .collect();
+        assert_eq!(
+            children,
+            [(2, Some(IssueState::Closed), "Issue 2"), (3, None, "Docs")]
+        );
+        assert_eq!(links.blocked_by[0].repo.full_name(), "acme/api");
+    }
+
     #[tokio::test]
     async fn fetch_repo_labels_returns_empty_list() {
         let body = envelope(&json!({"repository": {"labels": {"nodes": []}}}));
@@ -2592,7 +4609,7 @@ mod graphql_endpoint_tests {
     #[tokio::test]
     async fn fetch_single_pr_handles_missing_repo() {
         let (_s, oc) = graphql_returns(envelope(&json!({"repository": null}))).await;
-        let Err(err) = fetch_single_pr(&oc, "x", "y", 1, None).await else {
+        let Err(err) = fetch_single_pr(&oc, HostFeatures::ALL, "x", "y", 1, None).await else {
             panic!("expected PR-not-found error");
         };
         assert!(err.to_string().contains("PR not found"));
@@ -2606,4 +4623,91 @@ mod graphql_endpoint_tests {
         };
         assert!(err.to_string().contains("Issue not found"));
     }
+
+    #[tokio::test]
+    async fn fetch_contributions_maps_levels() {
+        let body = envelope(&json!({"user": {
+            "login": "octocat",
+            "contributionsCollection": {"contributionCalendar": {
+                "totalContributions": 5,
+                "weeks": [{"contributionDays": [
+                    {"date": "2024-05-05", "contributionCount": 0, "contributionLevel": "NONE"},
+                    {"date": "2024-05-06", "contributionCount": 5, "contributionLevel": "FOURTH_QUARTILE"},
+                ]}],
+            }},
+        }}));
+        let (_s, oc) = graphql_returns(body).await;
+        let calendar = fetch_contributions(&oc, Some("octocat")).await.unwrap();
+        assert_eq!(calendar.login, "octocat");
+        assert_eq!(calendar.total, 5);
+        let levels: Vec<u8> = calendar.weeks[0].iter().map(|d| d.level).collect();
+        assert_eq!(levels, vec![0, 4]);
+        assert_eq!(calendar.week_total(0), 5);
+    }
+
+    #[tokio::test]
+    async fn fetch_contributions_reports_unknown_user() {
+        let (_s, oc) = graphql_returns(envelope(&json!({"user": null}))).await;
+        let Err(err) = fetch_contributions(&oc, Some("ghost")).await else {
+            panic!("expected user-not-found error");
+        };
+        assert!(err.to_string().contains("user ghost not found"));
+    }
+
+    #[tokio::test]
+    async fn fetch_workflow_schedules_reads_cron_entries() {
+        let body = envelope(
+            &json!({"repository": {"object": {"__typename": "Tree", "entries": [
+                {"name": "nightly.yml", "path": ".github/workflows/nightly.yml",
+                 "object": {"__typename": "Blob", "text": "name: Nightly\non:\n  schedule:\n    - cron: '0 3 * * *'\n"}},
+                {"name": "ci.yml", "path": ".github/workflows/ci.yml",
+                 "object": {"__typename": "Blob", "text": "on: [push]\n"}},
+                {"name": "stale.yaml", "path": ".github/workflows/stale.yaml",
+                 "object": {"__typename": "Blob", "text": "on:\n  schedule:\n    - cron: '30 1 * * MON'\n"}},
+                {"name": "README.md", "path": ".github/workflows/README.md",
+                 "object": {"__typename": "Blob", "text": "cron: '* * * * *'"}},
+            ]}}}),
+        );
+        let (_s, oc) = graphql_returns(body).await;
+        let (schedules, _) = fetch_workflow_schedules(&oc, "acme", "app").await.unwrap();
+        let found: Vec<(&str, &str)> = schedules
+            .iter()
+            .map(|s| (s.workflow.as_str(), s.cron.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [("Nightly", "0 3 * * *"), ("stale.yaml", "30 1 * * MON")]
+        );
+        assert_eq!(schedules[0].repo, "acme/app");
+    }
+
+    #[tokio::test]
+    async fn fetch_dispatchable_workflows_keeps_manual_triggers() {
+        let body = envelope(
+            &json!({"repository": {"object": {"__typename": "Tree", "entries": [
+                {"name": "deploy.yml", "path": ".github/workflows/deploy.yml",
+                 "object": {"__typename": "Blob", "text": "name: Deploy\non:\n  workflow_dispatch:\n    inputs:\n      env:\n        required: true\n"}},
+                {"name": "ci.yml", "path": ".github/workflows/ci.yml",
+                 "object": {"__typename": "Blob", "text": "on: [push, pull_request]\n"}},
+                {"name": "release.yaml", "path": ".github/workflows/release.yaml",
+                 "object": {"__typename": "Blob", "text": "on: workflow_dispatch\n"}},
+            ]}}}),
+        );
+        let (_s, oc) = graphql_returns(body).await;
+        let (workflows, _) = fetch_dispatchable_workflows(&oc, "acme", "app")
+            .await
+            .unwrap();
+        let found: Vec<(&str, &str, usize)> = workflows
+            .iter()
+            .map(|w| (w.name.as_str(), w.file.as_str(), w.inputs.len()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("Deploy", "deploy.yml", 1),
+                ("release.yaml", "release.yaml", 0)
+            ]
+        );
+        assert!(workflows[0].inputs[0].required);
+    }
 }
@@ -0,0 +1,188 @@
+//! Startup health check of a host: token, connection, TLS, clock and GraphQL.
+
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Deserialize;
+
+use crate::github::client::HostClient;
+use crate::types::{HealthCheck, HealthProblem, HostHealth};
+
+/// Longest wait for a host before it counts as unreachable.
+const HOST_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// Clock difference past which relative times and TLS validity suffer.
+const MAX_CLOCK_SKEW: TimeDelta = TimeDelta::minutes(5);
+
+/// Words of transport errors caused by the TLS layer.
+const TLS_ERRORS: &[&str] = &["certificate", "tls", "ssl", "handshake"];
+
+#[derive(Deserialize)]
+struct RawUser {
+    login: String,
+}
+
+/// The health of a host whose token could not be found.
+pub fn missing_token(host: &str, error: &anyhow::Error) -> HostHealth {
+    HostHealth {
+        host: host.to_owned(),
+        login: None,
+        problems: vec![HealthProblem {
+            check: HealthCheck::Token,
+            message: format!("{error:#}"),
+            hint: format!(
+                "Run `gh auth login --hostname {host}`, or set {}.",
+                super::auth::fallback_vars(host)[0]
+            ),
+        }],
+    }
+}
+
+/// Check that `host` answers over TLS, accepts the token on REST and
+/// GraphQL, and agrees with the local clock.
+pub async fn check_host(host: &str, octocrab: &HostClient) -> HostHealth {
+    let mut health = HostHealth {
+        host: host.to_owned(),
+        ..HostHealth::default()
+    };
+    let query = serde_json::json!({ "query": "{ viewer { login } }" });
+    let checks = async {
+        tokio::join!(
+            octocrab._get("/user"),
+            octocrab.graphql::<serde_json::Value>(&query)
+        )
+    };
+    let Ok((rest, graphql)) = tokio::time::timeout(HOST_TIMEOUT, checks).await else {
+        health.problems.push(HealthProblem {
+            check: HealthCheck::Connection,
+            message: format!("{host} did not answer within {}s", HOST_TIMEOUT.as_secs()),
+            hint: "Check the network connection, VPN or HTTPS_PROXY.".to_owned(),
+        });
+        return health;
+    };
+
+    let response = match rest {
+        Ok(response) => response,
+        Err(e) => {
+            let detail = format!("{:#}", anyhow::Error::from(e));
+            health.problems.push(transport_problem(host, &detail));
+            return health;
+        }
+    };
+    if let Some(skew) = clock_skew(response.headers(), Utc::now()) {
+        let direction = if skew > TimeDelta::zero() {
+            "ahead of"
+        } else {
+            "behind"
+        };
+        health.problems.push(HealthProblem {
+            check: HealthCheck::Clock,
+            message: format!(
+                "the system clock is {} {direction} {host}",
+                crate::util::format_span(skew.abs())
+            ),
+            hint: "Sync the clock (e.g. enable NTP): times shown and TLS rely on it.".to_owned(),
+        });
+    }
+    let status = response.status();
+    if status == http::StatusCode::UNAUTHORIZED {
+        health.problems.push(HealthProblem {
+            check: HealthCheck::Token,
+            message: "GitHub rejected the token: it is expired, revoked or mistyped".to_owned(),
+            hint: format!(
+                "Run `gh auth refresh --hostname {host}`, or replace GH_TOKEN / GITHUB_TOKEN."
+            ),
+        });
+        return health;
+    }
+    if !status.is_success() {
+        health.problems.push(HealthProblem {
+            check: HealthCheck::Token,
+            message: format!("fetching the signed-in user answered {status}"),
+            hint: format!("Run `gh auth status --hostname {host}` for details."),
+        });
+        return health;
+    }
+    health.login = octocrab
+        .body_to_string(response)
+        .await
+        .ok()
+        .and_then(|body| serde_json::from_str::<RawUser>(&body).ok())
+        .map(|user| user.login);
+
+    if let Err(e) = graphql {
+        health.problems.push(HealthProblem {
+            check: HealthCheck::GraphQl,
+            message: format!("{:#}", anyhow::Error::from(e)),
+            hint: format!(
+                "The REST API works but GraphQL does not: check that a proxy lets \
+                 POST requests to {host} through."
+            ),
+        });
+    }
+    health
+}
+
+/// Classify a failed request that got no HTTP answer.
+fn transport_problem(host: &str, detail: &str) -> HealthProblem {
+    let lower = detail.to_lowercase();
+    if TLS_ERRORS.iter().any(|word| lower.contains(word)) {
+        HealthProblem {
+            check: HealthCheck::Tls,
+            message: format!("TLS with {host} failed: {detail}"),
+            hint: "Check the system clock and CA certificates; behind an intercepting \
+                   proxy, its certificate must be trusted."
+                .to_owned(),
+        }
+    } else {
+        HealthProblem {
+            check: HealthCheck::Connection,
+            message: format!("cannot reach {host}: {detail}"),
+            hint: "Check the network connection, VPN or HTTPS_PROXY.".to_owned(),
+        }
+    }
+}
+
+/// How far the local clock (`now`) runs ahead of the response's `Date`, if
+/// past [`MAX_CLOCK_SKEW`].
+fn clock_skew(headers: &http::HeaderMap, now: DateTime<Utc>) -> Option<TimeDelta> {
+    let date = headers.get(http::header::DATE)?.to_str().ok()?;
+    let server = DateTime::parse_from_rfc2822(date).ok()?.with_timezone(&Utc);
+    let skew = now - server;
+    (skew.abs() > MAX_CLOCK_SKEW).then_some(skew)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_are_classified() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::DATE,
+            "Wed, 14 Oct 2026 10:00:00 GMT".parse().unwrap(),
+        );
+        let server = DateTime::parse_from_rfc3339("2026-10-14T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(clock_skew(&headers, server + TimeDelta::minutes(2)), None);
+        assert_eq!(
+            clock_skew(&headers, server - TimeDelta::hours(1)),
+            Some(-TimeDelta::hours(1))
+        );
+        assert_eq!(clock_skew(&http::HeaderMap::new(), server), None);
+
+        let tls = transport_problem(
+            "github.com",
+            "Service Error: invalid peer certificate: UnknownIssuer",
+        );
+        assert_eq!(tls.check, HealthCheck::Tls);
+        let offline = transport_problem(
+            "ghe.acme.com",
+            "Service Error: dns error: failed to lookup address",
+        );
+        assert_eq!(offline.check, HealthCheck::Connection);
+        assert!(offline.message.starts_with("cannot reach ghe.acme.com"));
+    }
+}
@@ -1,8 +1,15 @@
 pub(crate) mod actions;
 pub(crate) mod auth;
+pub(crate) mod auth_warnings;
 pub(crate) mod client;
+pub(crate) mod events;
+pub(crate) mod features;
 pub(crate) mod graphql;
+pub(crate) mod health;
 pub(crate) mod notifications;
 pub(crate) mod rate_limit;
+pub(crate) mod releases;
 pub(crate) mod security;
+pub(crate) mod transient;
 pub(crate) mod types;
+pub(crate) mod viewer;
@@ -4,7 +4,9 @@ use anyhow::{Context, Result};
 use octocrab::Octocrab;
 use octocrab::models::NotificationId;
 
+use crate::config::types::{NotificationRule, NotificationRuleAction};
 use crate::github::client::extract_rest_rate_limit;
+use crate::github::graphql;
 use crate::github::types::{
     Notification, NotificationReason, NotificationStatus, RepoRef, SubjectType,
 };
@@ -113,15 +115,29 @@ fn api_url_to_html_url(api_url: &str, subject_type: &str, owner: &str, repo_name
     if api_url.is_empty() {
         return String::new();
     }
+    let (web_root, path) = split_api_url(api_url);
     match subject_type {
-        "Release" => format!("https://github.com/{owner}/{repo_name}/releases"),
-        "PullRequest" => api_url
-            .replace("https://api.github.com/repos/", "https://github.com/")
-            .replace("/pulls/", "/pull/"),
-        _ => api_url.replace("https://api.github.com/repos/", "https://github.com/"),
+        "Release" => format!("{web_root}/{owner}/{repo_name}/releases"),
+        "PullRequest" => format!("{web_root}/{}", path.replace("/pulls/", "/pull/")),
+        _ => format!("{web_root}/{path}"),
     }
 }
 
+/// The web root of the host `api_url` belongs to, and what follows its
+/// `/repos/`: `https://api.github.com`, `https://<host>/api/v3` (GHES) and
+/// `https://api.<host>` (GHE.com) map to `https://github.com` and
+/// `https://<host>`.
+fn split_api_url(api_url: &str) -> (String, &str) {
+    let Some((api_root, path)) = api_url.split_once("/repos/") else {
+        return ("https://github.com".to_owned(), api_url);
+    };
+    let web_root = match api_root.strip_suffix("/api/v3") {
+        Some(root) => root.to_owned(),
+        None => api_root.replacen("://api.", "://", 1),
+    };
+    (web_root, path)
+}
+
 fn parse_subject_type(s: &str) -> SubjectType {
     match s {
         "PullRequest" => SubjectType::PullRequest,
@@ -157,7 +173,83 @@ fn into_domain(n: octocrab::models::activity::Notification) -> Notification {
         repository: Some(repo),
         url,
         updated_at: n.updated_at,
+        subject_status: None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Mute and priority rules
+// ---------------------------------------------------------------------------
+
+/// Case-insensitive match where `*` stands for any run of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let text = text.to_lowercase();
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = text.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        let Some(i) = rest.find(part) else {
+            return false;
+        };
+        rest = &rest[i + part.len()..];
+    }
+    rest.ends_with(last)
+}
+
+fn rule_matches(rule: &NotificationRule, n: &Notification) -> bool {
+    let author = n.subject_status.as_ref().and_then(|s| s.author.as_deref());
+    rule.repo.as_deref().is_none_or(|p| {
+        n.repository
+            .as_ref()
+            .is_some_and(|r| glob_match(p, &r.full_name()))
+    }) && rule
+        .reason
+        .as_deref()
+        .is_none_or(|r| parse_reason(r) == n.reason)
+        && rule
+            .author
+            .as_deref()
+            .is_none_or(|p| author.is_some_and(|a| glob_match(p, a)))
+}
+
+/// Notifications left after applying [`NotificationRule`]s.
+#[derive(Debug, Default)]
+pub struct RuledNotifications {
+    /// Notifications to show, demoted ones last.
+    pub kept: Vec<Notification>,
+    /// Unread threads matched by a `done` rule, to mark done on GitHub.
+    pub to_mark_done: Vec<String>,
+    /// Number of notifications hidden by `done` rules.
+    pub muted: usize,
+}
+
+/// Apply `rules` to fetched notifications; the first matching rule wins.
+pub fn apply_rules(
+    notifications: Vec<Notification>,
+    rules: &[NotificationRule],
+) -> RuledNotifications {
+    let mut outcome = RuledNotifications::default();
+    let mut demoted = Vec::new();
+    for n in notifications {
+        match rules.iter().find(|r| rule_matches(r, &n)).map(|r| r.action) {
+            Some(NotificationRuleAction::Done) => {
+                if n.unread {
+                    outcome.to_mark_done.push(n.id);
+                }
+                outcome.muted += 1;
+            }
+            Some(NotificationRuleAction::Demote) => demoted.push(n),
+            None => outcome.kept.push(n),
+        }
     }
+    outcome.kept.extend(demoted);
+    outcome
 }
 
 // ---------------------------------------------------------------------------
@@ -210,6 +302,31 @@ pub async fn fetch_notifications(
     Ok((notifications, rate_limit))
 }
 
+/// Fill in `subject_status` for PR and issue notifications with one batched
+/// GraphQL lookup.
+pub async fn resolve_subjects(
+    octocrab: &crate::github::client::HostClient,
+    notifications: &mut [Notification],
+) -> Result<()> {
+    let (indices, subjects): (Vec<usize>, Vec<(String, String, u64)>) = notifications
+        .iter()
+        .enumerate()
+        .filter_map(|(i, n)| {
+            let repo = n.repository.as_ref()?;
+            let number = n.subject_number()?;
+            Some((i, (repo.owner.clone(), repo.name.clone(), number)))
+        })
+        .unzip();
+    if subjects.is_empty() {
+        return Ok(());
+    }
+    let statuses = graphql::fetch_notification_subjects(octocrab, &subjects).await?;
+    for (i, status) in indices.into_iter().zip(statuses) {
+        notifications[i].subject_status = status;
+    }
+    Ok(())
+}
+
 /// Mark a single notification as read.
 pub async fn mark_as_read(octocrab: &Arc<Octocrab>, thread_id: &str) -> Result<()> {
     let id: u64 = thread_id.parse().context("invalid notification id")?;
@@ -222,6 +339,19 @@ pub async fn mark_as_read(octocrab: &Arc<Octocrab>, thread_id: &str) -> Result<(
     Ok(())
 }
 
+/// Mark a notification thread as done, removing it from the inbox.
+pub async fn mark_as_done(octocrab: &Arc<Octocrab>, thread_id: &str) -> Result<()> {
+    let id: u64 = thread_id.parse().context("invalid notification id")?;
+    let response = octocrab
+        ._delete(format!("/notifications/threads/{id}"), None::<&()>)
+        .await
+        .context("marking notification as done")?;
+    octocrab::map_github_error(response)
+        .await
+        .context("marking notification as done")?;
+    Ok(())
+}
+
 /// Mark all notifications as read.
 pub async fn mark_all_as_read(octocrab: &Arc<Octocrab>) -> Result<()> {
     octocrab
@@ -430,11 +560,131 @@ mod tests {
         );
     }
 
+    #[test]
+    fn api_url_enterprise_conversion() {
+        assert_eq!(
+            api_url_to_html_url(
+                "https://ghe.acme.com/api/v3/repos/owner/repo/pulls/42",
+                "PullRequest",
+                "owner",
+                "repo",
+            ),
+            "https://ghe.acme.com/owner/repo/pull/42"
+        );
+        assert_eq!(
+            api_url_to_html_url(
+                "https://api.acme.ghe.com/repos/owner/repo/issues/5",
+                "Issue",
+                "owner",
+                "repo",
+            ),
+            "https://acme.ghe.com/owner/repo/issues/5"
+        );
+        assert_eq!(
+            api_url_to_html_url(
+                "https://ghe.acme.com/api/v3/repos/owner/repo/releases/1",
+                "Release",
+                "owner",
+                "repo",
+            ),
+            "https://ghe.acme.com/owner/repo/releases"
+        );
+    }
+
     #[test]
     fn api_url_empty_passthrough() {
         assert_eq!(api_url_to_html_url("", "Issue", "owner", "repo"), "");
     }
 
+    fn sample_notification() -> Notification {
+        Notification {
+            id: "1".to_owned(),
+            subject_type: Some(SubjectType::PullRequest),
+            subject_title: "Fix".to_owned(),
+            reason: NotificationReason::Mention,
+            unread: true,
+            repository: Some(RepoRef {
+                owner: "acme".to_owned(),
+                name: "app".to_owned(),
+            }),
+            url: "https://github.com/acme/app/pull/42".to_owned(),
+            updated_at: chrono::Utc::now(),
+            subject_status: None,
+        }
+    }
+
+    #[test]
+    fn subject_number_from_url() {
+        let mut n = sample_notification();
+        assert_eq!(n.subject_number(), Some(42));
+        n.subject_type = Some(SubjectType::Release);
+        n.url = "https://github.com/acme/app/releases".to_owned();
+        assert_eq!(n.subject_number(), None);
+    }
+
+    #[test]
+    fn glob_match_wildcards() {
+        assert!(glob_match("acme/*", "acme/app"));
+        assert!(glob_match("ACME/*-bot", "acme/release-bot"));
+        assert!(glob_match("*bot*", "dependabot[bot]"));
+        assert!(glob_match("acme/app", "acme/app"));
+        assert!(!glob_match("acme/app", "acme/app2"));
+        assert!(!glob_match("acme/*-bot", "acme/bot"));
+    }
+
+    #[test]
+    fn apply_rules_mutes_and_demotes() {
+        let rule = |repo: Option<&str>, reason: Option<&str>, action| NotificationRule {
+            repo: repo.map(str::to_owned),
+            reason: reason.map(str::to_owned),
+            author: None,
+            action,
+        };
+        let rules = vec![
+            rule(
+                Some("acme/*"),
+                Some("ci_activity"),
+                NotificationRuleAction::Done,
+            ),
+            rule(None, Some("subscribed"), NotificationRuleAction::Demote),
+        ];
+
+        let mut ci = sample_notification();
+        ci.id = "ci".to_owned();
+        ci.reason = NotificationReason::CiActivity;
+        let mut subscribed = sample_notification();
+        subscribed.id = "sub".to_owned();
+        subscribed.reason = NotificationReason::Subscribed;
+        let mention = sample_notification();
+
+        let outcome = apply_rules(vec![ci, subscribed, mention], &rules);
+        let ids: Vec<&str> = outcome.kept.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "sub"]);
+        assert_eq!(outcome.to_mark_done, vec!["ci".to_owned()]);
+        assert_eq!(outcome.muted, 1);
+    }
+
+    #[test]
+    fn author_rules_need_a_resolved_subject() {
+        let rules = vec![NotificationRule {
+            repo: None,
+            reason: None,
+            author: Some("dependabot*".to_owned()),
+            action: NotificationRuleAction::Done,
+        }];
+        let unresolved = sample_notification();
+        let mut resolved = sample_notification();
+        resolved.subject_status = Some(crate::types::SubjectStatus {
+            state: crate::types::SubjectState::Open,
+            ci: None,
+            closed_at: None,
+            author: Some("dependabot[bot]".to_owned()),
+        });
+        let outcome = apply_rules(vec![unresolved, resolved], &rules);
+        assert_eq!(outcome.kept.len(), 1);
+        assert_eq!(outcome.muted, 1);
+    }
+
     #[test]
     fn parse_subject_type_values() {
         assert_eq!(parse_subject_type("PullRequest"), SubjectType::PullRequest);
@@ -443,4 +693,39 @@ mod tests {
         assert_eq!(parse_subject_type("Discussion"), SubjectType::Discussion);
         assert_eq!(parse_subject_type("SomethingElse"), SubjectType::Other);
     }
+
+    #[tokio::test]
+    async fn mark_as_done_deletes_the_thread() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/notifications/threads/42"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/notifications/threads/7"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest"
+            })))
+            .mount(&server)
+            .await;
+        let octocrab = Arc::new(
+            Octocrab::builder()
+                .base_uri(server.uri())
+                .unwrap()
+                .build()
+                .unwrap(),
+        );
+
+        mark_as_done(&octocrab, "42").await.unwrap();
+        assert!(mark_as_done(&octocrab, "7").await.is_err());
+        let err = mark_as_done(&octocrab, "not-an-id").await.unwrap_err();
+        assert_eq!(err.to_string(), "invalid notification id");
+    }
 }
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::types::{GitTag, Release};
+
+/// Releases listed, newest first.
+const RELEASES_PER_PAGE: u32 = 50;
+
+/// Tags listed, newest first.
+const TAGS_PER_PAGE: u32 = 100;
+
+#[derive(Deserialize)]
+struct RawRelease {
+    html_url: String,
+}
+
+#[derive(Deserialize)]
+struct RawListedRelease {
+    id: u64,
+    tag_name: String,
+    name: Option<String>,
+    html_url: String,
+    tarball_url: Option<String>,
+    draft: bool,
+    prerelease: bool,
+    published_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+struct RawTag {
+    name: String,
+    tarball_url: String,
+}
+
+/// The latest releases (drafts included, for a token that can push) and
+/// tags of `owner/repo`.
+pub async fn fetch_releases(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+) -> Result<(Vec<Release>, Vec<GitTag>)> {
+    let releases: Vec<RawListedRelease> = octocrab
+        .get(
+            format!("/repos/{owner}/{repo}/releases?per_page={RELEASES_PER_PAGE}"),
+            None::<&()>,
+        )
+        .await
+        .context("fetching releases")?;
+    let tags: Vec<RawTag> = octocrab
+        .get(
+            format!("/repos/{owner}/{repo}/tags?per_page={TAGS_PER_PAGE}"),
+            None::<&()>,
+        )
+        .await
+        .context("fetching tags")?;
+    let releases = releases
+        .into_iter()
+        .map(|r| Release {
+            id: r.id,
+            tag: r.tag_name,
+            name: r.name.unwrap_or_default(),
+            url: r.html_url,
+            tarball_url: r.tarball_url,
+            draft: r.draft,
+            prerelease: r.prerelease,
+            published_at: r.published_at,
+        })
+        .collect();
+    let tags = tags
+        .into_iter()
+        .map(|t| GitTag {
+            name: t.name,
+            tarball_url: t.tarball_url,
+        })
+        .collect();
+    Ok((releases, tags))
+}
+
+/// Publish the draft release `id`, returning its URL.
+pub async fn publish_release(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    id: u64,
+) -> Result<String> {
+    let release: RawRelease = octocrab
+        .patch(
+            format!("/repos/{owner}/{repo}/releases/{id}"),
+            Some(&serde_json::json!({ "draft": false })),
+        )
+        .await
+        .context("publishing release")?;
+    Ok(release.html_url)
+}
+
+/// Delete the release `id`, leaving its tag.
+pub async fn delete_release(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    id: u64,
+) -> Result<()> {
+    let response = octocrab
+        ._delete(format!("/repos/{owner}/{repo}/releases/{id}"), None::<&()>)
+        .await
+        .context("deleting release")?;
+    octocrab::map_github_error(response)
+        .await
+        .context("deleting release")?;
+    Ok(())
+}
+
+/// Create a draft release named `name` for `tag`, returning its URL.
+///
+/// The tag need not exist: GitHub creates it from the default branch when
+/// the draft is published.
+pub async fn create_draft_release(
+    octocrab: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    tag: &str,
+    name: &str,
+    body: &str,
+) -> Result<String> {
+    let payload = serde_json::json!({
+        "tag_name": tag,
+        "name": name,
+        "body": body,
+        "draft": true,
+    });
+    let release: RawRelease = octocrab
+        .post(format!("/repos/{owner}/{repo}/releases"), Some(&payload))
+        .await
+        .context("creating draft release")?;
+    Ok(release.html_url)
+}
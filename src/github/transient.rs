@@ -0,0 +1,56 @@
+//! Detection of fetch failures worth trying again.
+//!
+//! A failure is transient when the request got no answer (connection reset,
+//! timeout) or GitHub answered with a server error: a 5xx status, an HTML
+//! error page where JSON was expected, or GraphQL's "Something went wrong
+//! while executing your query". Rate limits are not: retrying only burns
+//! more of the quota.
+
+use super::rate_limit::is_rate_limited;
+
+/// Check whether `error` is likely to go away on its own.
+pub(crate) fn is_transient(error: &anyhow::Error) -> bool {
+    if is_rate_limited(error) {
+        return false;
+    }
+    if let Some(e) = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<octocrab::Error>())
+    {
+        return match e {
+            octocrab::Error::GitHub { source, .. } => source.status_code.is_server_error(),
+            octocrab::Error::Hyper { .. } | octocrab::Error::Service { .. } => true,
+            octocrab::Error::Serde { source, .. } => source.is_syntax() || source.is_eof(),
+            _ => false,
+        };
+    }
+    let msg = format!("{error:#}").to_lowercase();
+    msg.contains("something went wrong while executing your query") || msg.contains("timed out")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    #[test]
+    fn server_errors_are_transient() {
+        let html = serde_json::from_str::<serde_json::Value>("<html>Unicorn!</html>").unwrap_err();
+        let page = octocrab::Error::Serde {
+            source: html,
+            backtrace: std::backtrace::Backtrace::disabled(),
+        };
+        assert!(is_transient(
+            &anyhow::Error::from(page).context("searching PRs")
+        ));
+        assert!(is_transient(&anyhow!(
+            "Something went wrong while executing your query. This may be the result of a timeout"
+        )));
+        assert!(is_transient(&anyhow!("operation timed out")));
+
+        assert!(!is_transient(&anyhow!("API rate limit exceeded for user")));
+        assert!(!is_transient(&anyhow!(
+            "Could not resolve to a Repository with the name 'acme/gone'"
+        )));
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::github::auth_warnings;
+use crate::github::client::extract_rest_rate_limit;
+use crate::types::{RateLimitInfo, ViewerSetup};
+
+// ---------------------------------------------------------------------------
+// Raw API response types
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+struct RawUser {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct RawOrg {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct RawTeam {
+    slug: String,
+    organization: RawOrg,
+}
+
+#[derive(Deserialize)]
+struct RawRepo {
+    full_name: String,
+    #[serde(default)]
+    archived: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Conversion helpers
+// ---------------------------------------------------------------------------
+
+/// Split an `X-OAuth-Scopes` header value (`"repo, read:org"`) into scopes.
+fn parse_scopes(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Fetch just the token's OAuth scopes (one `/user` request).
+///
+/// Returns `None` when GitHub does not report scopes for this token type.
+pub async fn fetch_token_scopes(octocrab: &Arc<Octocrab>) -> Result<Option<Vec<String>>> {
+    let response = octocrab
+        ._get("/user")
+        .await
+        .context("fetching authenticated user")?;
+    auth_warnings::inspect(response.headers());
+    Ok(response
+        .headers()
+        .get("x-oauth-scopes")
+        .and_then(|v| v.to_str().ok())
+        .map(parse_scopes))
+}
+
+/// Fetch just the authenticated user's login.
+pub async fn fetch_viewer_login(octocrab: &Arc<Octocrab>) -> Result<String> {
+    let response = octocrab
+        ._get("/user")
+        .await
+        .context("fetching authenticated user")?;
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading authenticated user body")?;
+    let user: RawUser = serde_json::from_str(&body).context("deserializing authenticated user")?;
+    Ok(user.login)
+}
+
+/// Fetch the teams the authenticated user belongs to, as `org/team-slug`
+/// (first page only). Needs the `read:org` scope.
+pub async fn fetch_viewer_teams(octocrab: &Arc<Octocrab>) -> Result<Vec<String>> {
+    let response = octocrab
+        ._get("/user/teams?per_page=100")
+        .await
+        .context("fetching user teams")?;
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading user teams body")?;
+    let teams: Vec<RawTeam> = serde_json::from_str(&body).context("deserializing user teams")?;
+    Ok(teams
+        .into_iter()
+        .map(|t| format!("{}/{}", t.organization.login, t.slug))
+        .collect())
+}
+
+/// Fetch the authenticated user's login, token scopes, organizations and
+/// most recently pushed repositories (first page only).
+pub async fn fetch_viewer_setup(
+    octocrab: &Arc<Octocrab>,
+) -> Result<(ViewerSetup, Option<RateLimitInfo>)> {
+    let response = octocrab
+        ._get("/user")
+        .await
+        .context("fetching authenticated user")?;
+    let scopes = response
+        .headers()
+        .get("x-oauth-scopes")
+        .and_then(|v| v.to_str().ok())
+        .map(parse_scopes);
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading authenticated user body")?;
+    let user: RawUser = serde_json::from_str(&body).context("deserializing authenticated user")?;
+
+    let response = octocrab
+        ._get("/user/orgs?per_page=100")
+        .await
+        .context("fetching user organizations")?;
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading user organizations body")?;
+    let orgs: Vec<RawOrg> =
+        serde_json::from_str(&body).context("deserializing user organizations")?;
+
+    let response = octocrab
+        ._get("/user/repos?per_page=100&sort=pushed")
+        .await
+        .context("fetching user repositories")?;
+    let rate_limit = extract_rest_rate_limit(response.headers());
+    let body = octocrab
+        .body_to_string(response)
+        .await
+        .context("reading user repositories body")?;
+    let repos: Vec<RawRepo> =
+        serde_json::from_str(&body).context("deserializing user repositories")?;
+
+    let setup = ViewerSetup {
+        login: user.login,
+        scopes,
+        orgs: orgs.into_iter().map(|o| o.login).collect(),
+        repos: repos
+            .into_iter()
+            .filter(|r| !r.archived)
+            .map(|r| r.full_name)
+            .collect(),
+    };
+    Ok((setup, rate_limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_scopes_splits_and_trims() {
+        assert_eq!(
+            parse_scopes("repo, read:org,notifications"),
+            vec!["repo", "read:org", "notifications"]
+        );
+    }
+
+    #[test]
+    fn parse_scopes_empty_header() {
+        assert!(parse_scopes("").is_empty());
+        assert!(parse_scopes(" , ").is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+//! Startup health check.
+//!
+//! Before the views load, every host the config uses is asked whether it
+//! answers over TLS, accepts the token on REST and GraphQL, and agrees with
+//! the local clock. Failures are reported once, on the health screen, instead
+//! of by every tab that would run into them.
+
+use std::time::Duration;
+
+use crate::config::types::AppConfig;
+use crate::engine::{EngineHandle, Event, Request, event_channel};
+use crate::types::{HealthCheck, HealthProblem, HostHealth};
+
+/// How long to wait for the engine's answer; longer than it waits for a host.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(12);
+
+/// The hosts `config`'s filters query, `github.com` for those naming none.
+pub fn configured_hosts(config: &AppConfig) -> Vec<String> {
+    let hosts = config
+        .pr_filters
+        .iter()
+        .map(|f| f.host.as_deref())
+        .chain(config.issues_filters.iter().map(|f| f.host.as_deref()))
+        .chain(config.actions_filters.iter().map(|f| f.host.as_deref()))
+        .chain(
+            config
+                .notifications_filters
+                .iter()
+                .map(|f| f.host.as_deref()),
+        )
+        .chain(config.alerts_filters.iter().map(|f| f.host.as_deref()))
+        .chain(config.events_filters.iter().map(|f| f.host.as_deref()));
+    let mut out: Vec<String> = Vec::new();
+    for host in hosts {
+        let host = host.unwrap_or("github.com");
+        if !out.iter().any(|h| h == host) {
+            out.push(host.to_owned());
+        }
+    }
+    if out.is_empty() {
+        out.push("github.com".to_owned());
+    }
+    out
+}
+
+/// Check `hosts` through `engine`. A host the engine did not answer for in
+/// time counts as unreachable.
+pub fn check(engine: &EngineHandle, hosts: &[String]) -> Vec<HostHealth> {
+    let (reply_tx, reply_rx) = event_channel();
+    engine.send(Request::CheckHealth {
+        hosts: hosts.to_vec(),
+        reply_tx,
+    });
+    let reply = smol::block_on(smol::future::or(
+        async {
+            loop {
+                match reply_rx.recv().await {
+                    Ok(Event::HealthChecked { hosts }) => return Some(hosts),
+                    Ok(_) => {}
+                    Err(_) => return None,
+                }
+            }
+        },
+        async {
+            smol::Timer::after(CHECK_TIMEOUT).await;
+            None
+        },
+    ));
+    reply.unwrap_or_else(|| {
+        hosts
+            .iter()
+            .map(|host| HostHealth {
+                host: host.clone(),
+                login: None,
+                problems: vec![HealthProblem {
+                    check: HealthCheck::Connection,
+                    message: format!("no answer from {host} within {}s", CHECK_TIMEOUT.as_secs()),
+                    hint: "Check the network connection, VPN or HTTPS_PROXY.".to_owned(),
+                }],
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::PrFilter;
+
+    fn pr_filter(host: Option<&str>) -> PrFilter {
+        PrFilter {
+            title: "Mine".to_owned(),
+            filters: "is:open author:@me".to_owned(),
+            limit: None,
+            host: host.map(str::to_owned),
+            layout: None,
+            hide_drafts: None,
+            preview: None,
+            preview_tab: None,
+            refetch_interval_minutes: None,
+            badge: None,
+        }
+    }
+
+    #[test]
+    fn each_host_is_checked_once() {
+        assert_eq!(configured_hosts(&AppConfig::default()), ["github.com"]);
+        let config = AppConfig {
+            pr_filters: vec![
+                pr_filter(Some("ghe.acme.com")),
+                pr_filter(None),
+                pr_filter(Some("ghe.acme.com")),
+            ],
+            ..AppConfig::default()
+        };
+        assert_eq!(configured_hosts(&config), ["ghe.acme.com", "github.com"]);
+    }
+}
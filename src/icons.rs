@@ -2,7 +2,7 @@ use crate::config::types::IconConfig;
 
 /// Fully resolved icon set: every slot has a concrete `String` value
 /// (either from a preset or from per-icon user overrides).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash)]
 pub struct ResolvedIcons {
     // PR state (4)
     pub pr_open: String,
@@ -51,14 +51,19 @@ pub struct ResolvedIcons {
     pub check_skipped: String,
     pub check_cancelled: String,
     pub check_action_required: String,
+    // Commit signatures (3)
+    pub commit_verified: String,
+    pub commit_unverified: String,
+    pub commit_unsigned: String,
     // Sidebar decorative (1)
     pub branch_arrow: String,
-    // Sidebar tabs (5)
+    // Sidebar tabs (6)
     pub tab_overview: String,
     pub tab_activity: String,
     pub tab_commits: String,
     pub tab_checks: String,
     pub tab_files: String,
+    pub tab_links: String,
     // Author roles (6)
     pub role_newcontributor: String,
     pub role_contributor: String,
@@ -71,6 +76,7 @@ pub struct ResolvedIcons {
     pub view_issues: String,
     pub view_actions: String,
     pub view_alerts: String,
+    pub view_events: String,
     pub view_notifications: String,
     pub view_repo: String,
     // Tab filter marker (1)
@@ -88,8 +94,9 @@ pub struct ResolvedIcons {
     pub feedback_error: String,
     pub feedback_warning: String,
     pub feedback_info: String,
-    // UI chrome (2)
+    // UI chrome (3)
     pub tab_ephemeral: String,
+    pub tab_refresh: String,
     pub select_cursor: String,
     // Actions run status (6)
     pub action_success: String,
@@ -101,6 +108,8 @@ pub struct ResolvedIcons {
     pub action_watched: String,
     // Repo picker (1)
     pub repo_anchor: String,
+    // Local notes (1)
+    pub item_note: String,
 }
 
 impl ResolvedIcons {
@@ -154,6 +163,10 @@ impl ResolvedIcons {
             check_skipped: "\u{23ed}".to_owned(),         // ⏭
             check_cancelled: "\u{2298}".to_owned(),       // ⊘
             check_action_required: "\u{26a0}".to_owned(), // ⚠
+            // Commit signatures
+            commit_verified: "\u{2713}".to_owned(),   // ✓
+            commit_unverified: "\u{26a0}".to_owned(), // ⚠
+            commit_unsigned: "\u{b7}".to_owned(),     // ·
             // Sidebar decorative
             branch_arrow: "\u{2192}".to_owned(), // →
             // Sidebar tabs
@@ -162,6 +175,7 @@ impl ResolvedIcons {
             tab_commits: "\u{25cb}".to_owned(),  // ○
             tab_checks: "\u{2611}".to_owned(),   // ☑
             tab_files: "\u{25a4}".to_owned(),    // ▤
+            tab_links: "\u{2442}".to_owned(),    // ⑂
             // Author roles
             role_newcontributor: "\u{2728}".to_owned(), // ✨
             role_contributor: "\u{2713}".to_owned(),    // ✓
@@ -176,6 +190,7 @@ impl ResolvedIcons {
             view_alerts: "\u{26a0}".to_owned(),         // ⚠
             view_notifications: "\u{1f514}".to_owned(), // 🔔
             view_repo: "\u{2387}".to_owned(),           // ⎇
+            view_events: "\u{2261}".to_owned(),         // ≡
             // Tab filter marker
             tab_filter: "\u{25c6}".to_owned(), // ◆
             // Pill caps (no rounded edges in unicode preset)
@@ -193,6 +208,7 @@ impl ResolvedIcons {
             feedback_info: "\u{2139}".to_owned(),    // ℹ
             // UI chrome
             tab_ephemeral: "\u{25cc}".to_owned(), // ◌
+            tab_refresh: "\u{21bb}".to_owned(),   // ↻
             select_cursor: "\u{25b6}".to_owned(), // ▶
             // Actions run status
             action_success: "\u{2714}".to_owned(),   // ✔
@@ -204,6 +220,8 @@ impl ResolvedIcons {
             action_watched: "\u{1f441}".to_owned(), // 👁 (U+1F441 EYE)
             // Repo picker
             repo_anchor: "\u{2693}".to_owned(), // ⚓
+            // Local notes
+            item_note: "\u{270e}".to_owned(), // ✎
         }
     }
 
@@ -258,6 +276,10 @@ impl ResolvedIcons {
             check_skipped: "\u{eab8}".to_owned(), //  nf-cod-debug_step_over
             check_cancelled: "\u{eabd}".to_owned(), //  nf-cod-circle_slash
             check_action_required: "\u{ea6c}".to_owned(), //  nf-cod-bell_dot (needs attention)
+            // Commit signatures
+            commit_verified: "\u{f4a1}".to_owned(), //  nf-oct-verified
+            commit_unverified: "\u{f4a3}".to_owned(), //  nf-oct-unverified
+            commit_unsigned: "\u{b7}".to_owned(),
             // Sidebar decorative
             branch_arrow: "\u{e344}".to_owned(), //  nf-pl-left_hard_divider
             // Sidebar tabs (Codicons + Octicons)
@@ -266,6 +288,7 @@ impl ResolvedIcons {
             tab_commits: "\u{f4b6}".to_owned(),  //  nf-oct-git_commit
             tab_checks: "\u{f52e}".to_owned(),   //  nf-oct-checklist
             tab_files: "\u{f4d2}".to_owned(),    //  nf-oct-diff
+            tab_links: "\u{f44c}".to_owned(),    //  nf-oct-link
             // Author roles (matching gh-dash)
             role_newcontributor: "\u{f0394}".to_owned(), // 󰎔 nf-md-new_box
             role_contributor: "\u{edc6}".to_owned(),     //  nf-fa-user_check
@@ -280,6 +303,7 @@ impl ResolvedIcons {
             view_repo: "\u{e727}".to_owned(), //  nf-dev-git_branch
             view_actions: "\u{ebc8}".to_owned(), //  nf-cod-run_all
             view_alerts: "\u{eb53}".to_owned(), //  nf-cod-shield
+            view_events: "\u{f0c0}".to_owned(), //  nf-fa-users
             // Tab filter marker
             tab_filter: "\u{f02b}".to_owned(), //  nf-fa-tag
             // Pill caps (Powerline half-circles for rounded edges)
@@ -297,6 +321,7 @@ impl ResolvedIcons {
             feedback_info: "\u{f449}".to_owned(),  //  nf-oct-info
             // UI chrome
             tab_ephemeral: "\u{f4c3}".to_owned(), //  nf-oct-dot
+            tab_refresh: "\u{f021}".to_owned(),   //  nf-fa-refresh
             select_cursor: "\u{ea9c}".to_owned(), //  nf-cod-triangle_right
             // Actions run status
             action_success: "\u{f058}".to_owned(), //  nf-fa-check_circle
@@ -308,6 +333,8 @@ impl ResolvedIcons {
             action_watched: "\u{e68f}".to_owned(), //  nf-seti-search
             // Repo picker
             repo_anchor: "\u{f13d}".to_owned(), //  nf-fa-anchor
+            // Local notes
+            item_note: "\u{f040}".to_owned(), //  nf-fa-pencil
         }
     }
 
@@ -361,6 +388,10 @@ impl ResolvedIcons {
             check_skipped: "-".to_owned(),
             check_cancelled: "/".to_owned(),
             check_action_required: "!".to_owned(),
+            // Commit signatures
+            commit_verified: "+".to_owned(),
+            commit_unverified: "?".to_owned(),
+            commit_unsigned: "-".to_owned(),
             // Sidebar decorative
             branch_arrow: "->".to_owned(),
             // Sidebar tabs
@@ -369,6 +400,7 @@ impl ResolvedIcons {
             tab_commits: "o".to_owned(),
             tab_checks: "+".to_owned(),
             tab_files: "#".to_owned(),
+            tab_links: "&".to_owned(),
             // Author roles
             role_newcontributor: "*".to_owned(),
             role_contributor: "+".to_owned(),
@@ -383,6 +415,7 @@ impl ResolvedIcons {
             view_repo: "R".to_owned(),
             view_actions: "A".to_owned(),
             view_alerts: "S".to_owned(),
+            view_events: "E".to_owned(),
             // Tab filter marker
             tab_filter: "#".to_owned(),
             // Pill caps (no rounded edges in ascii preset)
@@ -400,6 +433,7 @@ impl ResolvedIcons {
             feedback_info: "INFO".to_owned(),
             // UI chrome
             tab_ephemeral: "*".to_owned(),
+            tab_refresh: "~".to_owned(),
             select_cursor: ">".to_owned(),
             // Actions run status
             action_success: "v".to_owned(),
@@ -411,6 +445,118 @@ impl ResolvedIcons {
             // Repo picker
             repo_anchor: "@".to_owned(),
             action_watched: "O".to_owned(),
+            // Local notes
+            item_note: "n".to_owned(),
+        }
+    }
+
+    /// Words instead of glyphs, for screen readers. Purely decorative slots
+    /// are left empty; everything else falls back to the ASCII preset.
+    fn text() -> Self {
+        Self {
+            // PR state
+            pr_open: "open".to_owned(),
+            pr_closed: "closed".to_owned(),
+            pr_merged: "merged".to_owned(),
+            pr_draft: "draft".to_owned(),
+            // PR column headers
+            header_state: "State".to_owned(),
+            header_comments: "Comments".to_owned(),
+            header_review: "Review".to_owned(),
+            header_ci: "CI".to_owned(),
+            header_lines: "Lines".to_owned(),
+            header_time: "Time".to_owned(),
+            // Review decision
+            review_approved: "approved".to_owned(),
+            review_changes: "changes requested".to_owned(),
+            review_required: "review required".to_owned(),
+            review_none: "none".to_owned(),
+            review_commented: "commented".to_owned(),
+            // CI status
+            ci_success: "passed".to_owned(),
+            ci_failure: "failed".to_owned(),
+            ci_pending: "pending".to_owned(),
+            ci_running: "running".to_owned(),
+            ci_skipped: "skipped".to_owned(),
+            ci_cancelled: "cancelled".to_owned(),
+            ci_action_required: "action required".to_owned(),
+            ci_none: "none".to_owned(),
+            // Issue state
+            issue_open: "open".to_owned(),
+            issue_closed: "closed".to_owned(),
+            // Notifications
+            notif_unread: "unread".to_owned(),
+            notif_type_pr: "pull request".to_owned(),
+            notif_type_issue: "issue".to_owned(),
+            notif_type_release: "release".to_owned(),
+            notif_type_discussion: "discussion".to_owned(),
+            // Branch
+            branch_ahead: "ahead ".to_owned(),
+            branch_behind: "behind ".to_owned(),
+            // Sidebar checks
+            check_success: "passed".to_owned(),
+            check_failure: "failed".to_owned(),
+            check_pending: "pending".to_owned(),
+            check_running: "running".to_owned(),
+            check_skipped: "skipped".to_owned(),
+            check_cancelled: "cancelled".to_owned(),
+            check_action_required: "action required".to_owned(),
+            // Commit signatures
+            commit_verified: "verified".to_owned(),
+            commit_unverified: "unverified".to_owned(),
+            commit_unsigned: "unsigned".to_owned(),
+            // Sidebar decorative
+            branch_arrow: "from".to_owned(),
+            // Sidebar tabs (labels carry the meaning)
+            tab_overview: String::new(),
+            tab_activity: String::new(),
+            tab_commits: String::new(),
+            tab_checks: String::new(),
+            tab_files: String::new(),
+            tab_links: String::new(),
+            // Author roles
+            role_newcontributor: "new contributor".to_owned(),
+            role_contributor: "contributor".to_owned(),
+            role_collaborator: "collaborator".to_owned(),
+            role_member: "member".to_owned(),
+            role_owner: "owner".to_owned(),
+            role_unknown: String::new(),
+            // Status bar views (labels carry the meaning)
+            view_prs: String::new(),
+            view_issues: String::new(),
+            view_actions: String::new(),
+            view_alerts: String::new(),
+            view_notifications: String::new(),
+            view_repo: String::new(),
+            view_events: String::new(),
+            // Tab filter marker
+            tab_filter: String::new(),
+            // Branch update status
+            header_update: "Update".to_owned(),
+            update_needed: "behind base".to_owned(),
+            update_conflict: "conflicts".to_owned(),
+            update_ok: "up to date".to_owned(),
+            // Feedback indicators
+            feedback_ok: "Done:".to_owned(),
+            feedback_error: "Error:".to_owned(),
+            feedback_warning: "Warning:".to_owned(),
+            feedback_info: "Note:".to_owned(),
+            // UI chrome
+            tab_ephemeral: "temporary".to_owned(),
+            tab_refresh: "next refresh in".to_owned(),
+            // Actions run status
+            action_success: "succeeded".to_owned(),
+            action_failure: "failed".to_owned(),
+            action_cancelled: "cancelled".to_owned(),
+            action_skipped: "skipped".to_owned(),
+            action_running: "running".to_owned(),
+            action_queued: "queued".to_owned(),
+            action_watched: "watched".to_owned(),
+            // Repo picker
+            repo_anchor: "current".to_owned(),
+            // Local notes
+            item_note: "noted".to_owned(),
+            ..Self::ascii()
         }
     }
 
@@ -421,6 +567,7 @@ impl ResolvedIcons {
         let base = match config.preset.as_deref() {
             Some("nerdfont") => Self::nerdfont(),
             Some("ascii") => Self::ascii(),
+            Some("text") => Self::text(),
             _ => Self::unicode(),
         };
 
@@ -494,12 +641,25 @@ impl ResolvedIcons {
                 .check_action_required
                 .clone()
                 .unwrap_or(base.check_action_required),
+            commit_verified: config
+                .commit_verified
+                .clone()
+                .unwrap_or(base.commit_verified),
+            commit_unverified: config
+                .commit_unverified
+                .clone()
+                .unwrap_or(base.commit_unverified),
+            commit_unsigned: config
+                .commit_unsigned
+                .clone()
+                .unwrap_or(base.commit_unsigned),
             branch_arrow: config.branch_arrow.clone().unwrap_or(base.branch_arrow),
             tab_overview: config.tab_overview.clone().unwrap_or(base.tab_overview),
             tab_activity: config.tab_activity.clone().unwrap_or(base.tab_activity),
             tab_commits: config.tab_commits.clone().unwrap_or(base.tab_commits),
             tab_checks: config.tab_checks.clone().unwrap_or(base.tab_checks),
             tab_files: config.tab_files.clone().unwrap_or(base.tab_files),
+            tab_links: config.tab_links.clone().unwrap_or(base.tab_links),
             role_newcontributor: config
                 .role_newcontributor
                 .clone()
@@ -524,6 +684,7 @@ impl ResolvedIcons {
             view_repo: config.view_repo.clone().unwrap_or(base.view_repo),
             view_actions: config.view_actions.clone().unwrap_or(base.view_actions),
             view_alerts: config.view_alerts.clone().unwrap_or(base.view_alerts),
+            view_events: config.view_events.clone().unwrap_or(base.view_events),
             tab_filter: config.tab_filter.clone().unwrap_or(base.tab_filter),
             pill_left: config.pill_left.clone().unwrap_or(base.pill_left),
             pill_right: config.pill_right.clone().unwrap_or(base.pill_right),
@@ -542,6 +703,7 @@ impl ResolvedIcons {
                 .unwrap_or(base.feedback_warning),
             feedback_info: config.feedback_info.clone().unwrap_or(base.feedback_info),
             tab_ephemeral: config.tab_ephemeral.clone().unwrap_or(base.tab_ephemeral),
+            tab_refresh: config.tab_refresh.clone().unwrap_or(base.tab_refresh),
             select_cursor: config.select_cursor.clone().unwrap_or(base.select_cursor),
             action_success: config.action_success.clone().unwrap_or(base.action_success),
             action_failure: config.action_failure.clone().unwrap_or(base.action_failure),
@@ -554,6 +716,7 @@ impl ResolvedIcons {
             action_queued: config.action_queued.clone().unwrap_or(base.action_queued),
             action_watched: config.action_watched.clone().unwrap_or(base.action_watched),
             repo_anchor: config.repo_anchor.clone().unwrap_or(base.repo_anchor),
+            item_note: config.item_note.clone().unwrap_or(base.item_note),
         }
     }
 }
@@ -591,6 +754,21 @@ mod tests {
         assert_eq!(icons.ci_success, "v");
     }
 
+    #[test]
+    fn text_preset_uses_words() {
+        let config = IconConfig {
+            preset: Some("text".to_owned()),
+            ..Default::default()
+        };
+        let icons = ResolvedIcons::resolve(&config);
+        assert_eq!(icons.pr_open, "open");
+        assert_eq!(icons.ci_failure, "failed");
+        assert_eq!(icons.header_comments, "Comments");
+        assert!(icons.tab_overview.is_empty());
+        // Slots without a word fall back to ASCII.
+        assert_eq!(icons.select_cursor, ">");
+    }
+
     #[test]
     fn per_icon_override() {
         let config = IconConfig {
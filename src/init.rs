@@ -1,25 +1,61 @@
+use std::fmt::Write as _;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 
 use crate::config::builtin_themes;
+use crate::config::types::AppConfig;
+use crate::engine::{Engine, Event, GitHubEngine, Request, event_channel};
+use crate::types::{ViewerSetup, missing_scopes};
+
+/// How long to wait for the GitHub API before continuing offline.
+const VIEWER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How many of the most recently pushed repositories to offer for Actions
+/// filters.
+const MAX_ACTION_REPOS: usize = 20;
+
+/// How deep below each scan root to look for git clones. Three levels covers
+/// layouts like `~/src/github.com/owner/repo`.
+const SCAN_DEPTH: usize = 3;
+
+/// Conventional locations for local clones, relative to `$HOME`.
+const DEFAULT_SCAN_DIRS: &[&str] = &["code", "src", "projects", "dev", "git", "repos", "work"];
+
+/// Directories never descended into while scanning for clones.
+const SCAN_SKIP_DIRS: &[&str] = &["node_modules", "target", "vendor", "Library"];
+
+/// Everything the wizard collected, rendered by [`generate_config_content`].
+struct InitChoices {
+    theme_name: &'static str,
+    preset_name: &'static str,
+    /// Organizations that get their own PR and issue filter tabs.
+    orgs: Vec<String>,
+    /// `owner/repo` slugs that get an Actions filter tab.
+    action_repos: Vec<String>,
+    /// `owner/repo` → local clone, for `[repo_paths]`.
+    repo_paths: Vec<(String, PathBuf)>,
+}
 
 /// Run the interactive `gh-board init` wizard.
 ///
-/// Prompts for a theme and icon preset, then writes a starter config to
-/// `~/.config/gh-board/config.toml`.
+/// Checks the token's scopes, offers filters for the user's organizations and
+/// repositories, detects local clones for `[repo_paths]`, then previews the
+/// resulting config before writing it to `~/.config/gh-board/config.toml`.
 pub fn run() -> Result<()> {
     let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_owned());
-    let config_path = std::path::PathBuf::from(&home).join(".config/gh-board/config.toml");
+    let home = PathBuf::from(home);
+    let config_path = home.join(".config/gh-board/config.toml");
 
     // Warn if the file already exists.
     if config_path.exists() {
         let display = config_path.display();
-        eprint!("Config file already exists at {display}. Overwrite? [y/N] ");
-        io::stderr().flush()?;
-        let mut answer = String::new();
-        io::stdin().read_line(&mut answer)?;
-        if !matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") {
+        if !confirm(
+            &format!("Config file already exists at {display}. Overwrite?"),
+            false,
+        )? {
             println!("Aborted.");
             return Ok(());
         }
@@ -27,7 +63,40 @@ pub fn run() -> Result<()> {
 
     let theme_name = prompt_for_theme()?;
     let preset_name = prompt_for_icon_preset()?;
-    let content = generate_config_content(theme_name, preset_name);
+    let mut choices = InitChoices {
+        theme_name,
+        preset_name,
+        orgs: Vec::new(),
+        action_repos: Vec::new(),
+        repo_paths: Vec::new(),
+    };
+
+    println!("\nContacting GitHub\u{2026}");
+    match fetch_viewer_setup() {
+        Ok(setup) => {
+            report_scopes(&setup);
+            choices.orgs = prompt_for_selection(
+                "\nOrganizations (a PR and an issue tab is created for each):",
+                &setup.orgs,
+            )?;
+            choices.action_repos = prompt_for_selection(
+                "\nRepositories to monitor in the Actions view:",
+                &setup.repos[..setup.repos.len().min(MAX_ACTION_REPOS)],
+            )?;
+        }
+        Err(e) => {
+            eprintln!("warning: could not query GitHub ({e:#}); continuing with default filters.");
+        }
+    }
+
+    choices.repo_paths = prompt_for_repo_paths(&home)?;
+
+    let content = generate_config_content(&choices);
+    println!("\n--- {} ---\n{content}---", config_path.display());
+    if !confirm("Write this config?", true)? {
+        println!("Aborted.");
+        return Ok(());
+    }
 
     // --- Write file ---
     if let Some(parent) = config_path.parent() {
@@ -40,6 +109,211 @@ pub fn run() -> Result<()> {
     Ok(())
 }
 
+/// Ask a yes/no question; an empty answer picks `default`.
+fn confirm(question: &str, default: bool) -> Result<bool> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    eprint!("{question} {hint} ");
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(match answer.trim().to_lowercase().as_str() {
+        "" => default,
+        "y" | "yes" => true,
+        _ => false,
+    })
+}
+
+/// Query the authenticated user's scopes, orgs and repos through a
+/// short-lived engine.
+fn fetch_viewer_setup() -> Result<ViewerSetup> {
+    let engine = GitHubEngine::new(AppConfig::default()).start();
+    let (reply_tx, reply_rx) = event_channel();
+    engine.send(Request::FetchViewerSetup {
+        host: None,
+        reply_tx,
+    });
+    let event = smol::block_on(smol::future::or(
+        async { reply_rx.recv().await.ok() },
+        async {
+            smol::Timer::after(VIEWER_TIMEOUT).await;
+            None
+        },
+    ));
+    engine.send(Request::Shutdown);
+    match event {
+        Some(Event::ViewerSetupFetched { setup, .. }) => Ok(setup),
+        Some(Event::FetchError { message, .. }) => bail!(message),
+        Some(_) => bail!("unexpected reply from engine"),
+        None => bail!("timed out after {}s", VIEWER_TIMEOUT.as_secs()),
+    }
+}
+
+/// Print who we are authenticated as and which features lack a scope.
+fn report_scopes(setup: &ViewerSetup) {
+    println!("Authenticated as {}.", setup.login);
+    let Some(ref scopes) = setup.scopes else {
+        println!(
+            "Token scopes are not reported for this token type; \
+             make sure it can read repositories, organizations and notifications."
+        );
+        return;
+    };
+    let missing = missing_scopes(scopes);
+    if missing.is_empty() {
+        println!("\u{2713} Token scopes cover all features.");
+        return;
+    }
+    println!("Token is missing scopes for some features:");
+    for req in missing {
+        println!("  - {} (needs `{}`)", req.description, req.any_of[0]);
+    }
+    println!("Run `gh auth refresh -s <scope>` to grant them.");
+}
+
+/// List `items` and let the user pick any number of them.
+fn prompt_for_selection(title: &str, items: &[String]) -> Result<Vec<String>> {
+    if items.is_empty() {
+        return Ok(Vec::new());
+    }
+    println!("{title}");
+    for (i, item) in items.iter().enumerate() {
+        println!("  {:>2}. {item}", i + 1);
+    }
+    print!("Choose (e.g. `1 3`, `all`, empty for none): ");
+    io::stdout().flush()?;
+
+    loop {
+        let mut line = String::new();
+        io::stdin().read_line(&mut line)?;
+        if let Some(indices) = parse_selection(&line, items.len()) {
+            return Ok(indices.into_iter().map(|i| items[i].clone()).collect());
+        }
+        eprint!("Please enter numbers between 1 and {}: ", items.len());
+        io::stderr().flush()?;
+    }
+}
+
+/// Parse a selection such as `"1 3"`, `"2,4"` or `"all"` into zero-based
+/// indices. Returns `None` when any entry is out of range or not a number.
+fn parse_selection(input: &str, len: usize) -> Option<Vec<usize>> {
+    let input = input.trim();
+    if input.eq_ignore_ascii_case("all") {
+        return Some((0..len).collect());
+    }
+    let mut indices = Vec::new();
+    for token in input.split([',', ' ']).filter(|t| !t.is_empty()) {
+        let n: usize = token.parse().ok()?;
+        if n == 0 || n > len {
+            return None;
+        }
+        if !indices.contains(&(n - 1)) {
+            indices.push(n - 1);
+        }
+    }
+    Some(indices)
+}
+
+/// Offer to scan directories for local clones and return the ones the user
+/// keeps.
+fn prompt_for_repo_paths(home: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let defaults: Vec<PathBuf> = DEFAULT_SCAN_DIRS
+        .iter()
+        .map(|d| home.join(d))
+        .filter(|p| p.is_dir())
+        .collect();
+    let shown = defaults
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    println!("\nScan for local clones to fill [repo_paths] (used by checkout and worktrees).");
+    print!("Directories to scan [{shown}] (`-` to skip): ");
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    let roots: Vec<PathBuf> = match line.trim() {
+        "-" => return Ok(Vec::new()),
+        "" => defaults,
+        other => other
+            .split_whitespace()
+            .map(|p| expand_home(p, home))
+            .collect(),
+    };
+
+    let mut found = Vec::new();
+    for root in &roots {
+        scan_for_repos(root, SCAN_DEPTH, &mut found);
+    }
+    if found.is_empty() {
+        println!("No clones found.");
+        return Ok(found);
+    }
+    let labels: Vec<String> = found
+        .iter()
+        .map(|(slug, path)| format!("{slug}  {}", path.display()))
+        .collect();
+    print!("\nFound {} clones; add them all? [Y/n/pick] ", found.len());
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    match answer.trim().to_lowercase().as_str() {
+        "" | "y" | "yes" => Ok(found),
+        "p" | "pick" => {
+            let picked = prompt_for_selection("Local clones:", &labels)?;
+            Ok(found
+                .into_iter()
+                .zip(labels)
+                .filter(|(_, label)| picked.contains(label))
+                .map(|(entry, _)| entry)
+                .collect())
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+/// Expand a leading `~` to `home`.
+fn expand_home(path: &str, home: &Path) -> PathBuf {
+    match path.strip_prefix('~') {
+        Some(rest) => home.join(rest.trim_start_matches('/')),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Collect `(owner/repo, path)` for every git clone under `dir`, descending at
+/// most `depth` levels. Clones are not descended into, and the first path seen
+/// for a given slug wins.
+fn scan_for_repos(dir: &Path, depth: usize, found: &mut Vec<(String, PathBuf)>) {
+    if dir.join(".git").exists() {
+        if let Some(repo) = crate::git::detect_repo(dir) {
+            let slug = repo.full_name();
+            if !found.iter().any(|(s, _)| *s == slug) {
+                found.push((slug, dir.to_path_buf()));
+            }
+        }
+        return;
+    }
+    if depth == 0 {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut subdirs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .filter(|e| {
+            let name = e.file_name();
+            let name = name.to_string_lossy();
+            !name.starts_with('.') && !SCAN_SKIP_DIRS.contains(&name.as_ref())
+        })
+        .map(|e| e.path())
+        .collect();
+    subdirs.sort();
+    for sub in subdirs {
+        scan_for_repos(&sub, depth - 1, found);
+    }
+}
+
 /// Prompt the user to select a builtin theme by number.
 fn prompt_for_theme() -> Result<&'static str> {
     let themes = builtin_themes::list();
@@ -90,14 +364,65 @@ fn prompt_for_icon_preset() -> Result<&'static str> {
     }
 }
 
-/// Generate the starter configuration TOML content from selected options.
-fn generate_config_content(theme_name: &str, preset_name: &str) -> String {
-    let icons_line = if preset_name == "unicode" {
+/// Quote `s` as a TOML basic string.
+fn toml_str(s: &str) -> String {
+    toml::Value::String(s.to_owned()).to_string()
+}
+
+/// Generate the starter configuration TOML content from the wizard choices.
+fn generate_config_content(choices: &InitChoices) -> String {
+    let InitChoices {
+        theme_name,
+        preset_name,
+        ..
+    } = choices;
+    let icons_line = if *preset_name == "unicode" {
         String::new()
     } else {
         format!("\n[theme.icons]\npreset = \"{preset_name}\"\n")
     };
 
+    let mut org_prs = String::new();
+    let mut org_issues = String::new();
+    for org in &choices.orgs {
+        let _ = write!(
+            org_prs,
+            "\n[[pr_filters]]\ntitle = {}\nfilters = {}\nlimit = 50\n",
+            toml_str(org),
+            toml_str(&format!("is:open org:{org}")),
+        );
+        let _ = write!(
+            org_issues,
+            "\n[[issues_filters]]\ntitle = {}\nfilters = {}\n",
+            toml_str(org),
+            toml_str(&format!("is:open org:{org}")),
+        );
+    }
+
+    let mut actions = String::new();
+    for repo in &choices.action_repos {
+        let title = repo.rsplit('/').next().unwrap_or(repo);
+        let _ = write!(
+            actions,
+            "\n[[actions_filters]]\ntitle = {}\nrepo = {}\nlimit = 30\n",
+            toml_str(title),
+            toml_str(repo),
+        );
+    }
+
+    let mut repo_paths = String::new();
+    if !choices.repo_paths.is_empty() {
+        repo_paths.push_str("\n[repo_paths]\n");
+        for (slug, path) in &choices.repo_paths {
+            let _ = writeln!(
+                repo_paths,
+                "{} = {}",
+                toml_str(slug),
+                toml_str(&path.display().to_string()),
+            );
+        }
+    }
+
     format!(
         r#"# gh-board configuration – generated by `gh-board init`
 theme_file = "builtin:{theme_name}"
@@ -127,7 +452,7 @@ limit = 50
 title = "Recently Merged"
 filters = "is:merged author:@me sort:updated-desc"
 limit = 20
-
+{org_prs}
 [[issues_filters]]
 title = "Assigned to Me"
 filters = "is:open assignee:@me"
@@ -135,7 +460,7 @@ filters = "is:open assignee:@me"
 [[issues_filters]]
 title = "Created by Me"
 filters = "is:open author:@me"
-
+{org_issues}
 [[notifications_filters]]
 title = "Unread"
 filters = "is:unread"
@@ -143,7 +468,7 @@ filters = "is:unread"
 [[notifications_filters]]
 title = "Review Requests"
 filters = "is:unread reason:review_requested"
-"#
+{actions}{repo_paths}"#
     )
 }
 
@@ -151,9 +476,19 @@ filters = "is:unread reason:review_requested"
 mod tests {
     use super::*;
 
+    fn choices(theme_name: &'static str, preset_name: &'static str) -> InitChoices {
+        InitChoices {
+            theme_name,
+            preset_name,
+            orgs: Vec::new(),
+            action_repos: Vec::new(),
+            repo_paths: Vec::new(),
+        }
+    }
+
     #[test]
     fn config_contains_theme_name() {
-        let content = generate_config_content("tokyo-night", "unicode");
+        let content = generate_config_content(&choices("tokyo-night", "unicode"));
         assert!(
             content.contains("tokyo-night"),
             "config should contain the theme name"
@@ -162,31 +497,31 @@ mod tests {
 
     #[test]
     fn config_contains_github_section() {
-        let content = generate_config_content("nord", "unicode");
+        let content = generate_config_content(&choices("nord", "unicode"));
         assert!(content.contains("[github]"));
     }
 
     #[test]
     fn config_contains_pr_filters() {
-        let content = generate_config_content("nord", "unicode");
+        let content = generate_config_content(&choices("nord", "unicode"));
         assert!(content.contains("[[pr_filters]]"));
     }
 
     #[test]
     fn config_contains_issues_filters() {
-        let content = generate_config_content("nord", "unicode");
+        let content = generate_config_content(&choices("nord", "unicode"));
         assert!(content.contains("[[issues_filters]]"));
     }
 
     #[test]
     fn config_contains_notifications_filters() {
-        let content = generate_config_content("nord", "unicode");
+        let content = generate_config_content(&choices("nord", "unicode"));
         assert!(content.contains("[[notifications_filters]]"));
     }
 
     #[test]
     fn config_unicode_preset_no_icons_section() {
-        let content = generate_config_content("nord", "unicode");
+        let content = generate_config_content(&choices("nord", "unicode"));
         assert!(
             !content.contains("[theme.icons]"),
             "unicode preset should not emit [theme.icons] section"
@@ -195,7 +530,7 @@ mod tests {
 
     #[test]
     fn config_nerdfont_preset_has_icons_section() {
-        let content = generate_config_content("nord", "nerdfont");
+        let content = generate_config_content(&choices("nord", "nerdfont"));
         assert!(
             content.contains("[theme.icons]"),
             "nerdfont preset should emit [theme.icons] section"
@@ -205,7 +540,7 @@ mod tests {
 
     #[test]
     fn config_is_valid_toml() {
-        let content = generate_config_content("catppuccin-mocha", "ascii");
+        let content = generate_config_content(&choices("catppuccin-mocha", "ascii"));
         // Parse as generic TOML to verify syntactic correctness.
         let result: Result<toml::Value, _> = toml::from_str(&content);
         assert!(
@@ -214,4 +549,79 @@ mod tests {
             result.err()
         );
     }
+
+    #[test]
+    fn config_with_wizard_choices_parses_as_app_config() {
+        let content = generate_config_content(&InitChoices {
+            orgs: vec!["acme".into()],
+            action_repos: vec!["acme/api".into()],
+            repo_paths: vec![("acme/api".into(), PathBuf::from("/home/me/src/\"api\""))],
+            ..choices("nord", "unicode")
+        });
+        let config: AppConfig = toml::from_str(&content).expect("valid AppConfig");
+        assert_eq!(config.pr_filters.len(), 4);
+        assert_eq!(config.pr_filters[3].filters, "is:open org:acme");
+        assert_eq!(config.issues_filters.len(), 3);
+        assert_eq!(config.actions_filters.len(), 1);
+        assert_eq!(config.actions_filters[0].title, "api");
+        assert_eq!(config.actions_filters[0].repo, "acme/api");
+        assert_eq!(
+            config.repo_paths["acme/api"],
+            PathBuf::from("/home/me/src/\"api\"")
+        );
+    }
+
+    #[test]
+    fn parse_selection_accepts_lists_and_all() {
+        assert_eq!(parse_selection("1 3", 3), Some(vec![0, 2]));
+        assert_eq!(parse_selection("2,1,2\n", 3), Some(vec![1, 0]));
+        assert_eq!(parse_selection("ALL", 2), Some(vec![0, 1]));
+        assert_eq!(parse_selection("", 2), Some(vec![]));
+    }
+
+    #[test]
+    fn parse_selection_rejects_out_of_range() {
+        assert_eq!(parse_selection("0", 3), None);
+        assert_eq!(parse_selection("4", 3), None);
+        assert_eq!(parse_selection("x", 3), None);
+    }
+
+    #[test]
+    fn expand_home_replaces_tilde() {
+        let home = Path::new("/home/me");
+        assert_eq!(expand_home("~/src", home), PathBuf::from("/home/me/src"));
+        assert_eq!(expand_home("~", home), PathBuf::from("/home/me"));
+        assert_eq!(expand_home("/opt/src", home), PathBuf::from("/opt/src"));
+    }
+
+    #[test]
+    fn scan_finds_clones_and_skips_hidden_dirs() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let init = |dir: &Path, remote: &str| {
+            std::fs::create_dir_all(dir).unwrap();
+            let git = |args: &[&str]| {
+                std::process::Command::new("git")
+                    .args(args)
+                    .current_dir(dir)
+                    .output()
+                    .unwrap();
+            };
+            git(&["init", "-q"]);
+            git(&["remote", "add", "origin", remote]);
+        };
+        init(
+            &root.path().join("github.com/acme/api"),
+            "git@github.com:acme/api.git",
+        );
+        init(
+            &root.path().join(".cache/web"),
+            "https://github.com/acme/web.git",
+        );
+
+        let mut found = Vec::new();
+        scan_for_repos(root.path(), SCAN_DEPTH, &mut found);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, "acme/api");
+        assert_eq!(found[0].1, root.path().join("github.com/acme/api"));
+    }
 }
@@ -0,0 +1,238 @@
+//! Single-instance mode: `gh-board open <url>` hands the URL to a running
+//! gh-board instead of starting a second TUI and a second engine.
+//!
+//! Each TUI listens on a Unix socket, `$XDG_RUNTIME_DIR/gh-board.sock` (in
+//! the state directory when unset). A client writes `open <url>` on one line;
+//! the instance answers `ok`, followed by its tmux pane when it runs in one so
+//! that a client inside tmux can switch to it, or `error <reason>`. The URL
+//! then reaches the app through [`next_open`], like a CLI deep-link.
+
+use std::path::PathBuf;
+
+use anyhow::{Result, bail};
+
+use crate::app::NavigationTarget;
+
+/// Path of the socket instances listen on.
+fn socket_path() -> Option<PathBuf> {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .map(|dir| dir.join("gh-board.sock"))
+        .or_else(|| crate::util::state_path("gh-board.sock"))
+}
+
+static OPENS: std::sync::LazyLock<(
+    async_channel::Sender<NavigationTarget>,
+    async_channel::Receiver<NavigationTarget>,
+)> = std::sync::LazyLock::new(async_channel::unbounded);
+
+/// The next target handed over by `gh-board open`, `None` once none can come.
+pub(crate) async fn next_open() -> Option<NavigationTarget> {
+    OPENS.1.recv().await.ok()
+}
+
+/// A running instance that took a URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Handoff {
+    /// tmux pane the instance runs in, e.g. `%3`.
+    pub pane: Option<String>,
+}
+
+impl Handoff {
+    /// Bring the instance's pane forward when both sides run inside tmux.
+    /// Returns whether it did.
+    pub fn focus(&self) -> bool {
+        let Some(pane) = &self.pane else {
+            return false;
+        };
+        if std::env::var_os("TMUX").is_none() {
+            return false;
+        }
+        let tmux = |args: &[&str]| {
+            std::process::Command::new("tmux")
+                .args(args)
+                .output()
+                .is_ok_and(|out| out.status.success())
+        };
+        tmux(&["switch-client", "-t", pane])
+            && tmux(&["select-window", "-t", pane])
+            && tmux(&["select-pane", "-t", pane])
+    }
+}
+
+/// Parse an instance's answer to `open`.
+fn parse_reply(line: &str) -> Result<Handoff> {
+    let line = line.trim_end();
+    if let Some(reason) = line.strip_prefix("error ") {
+        bail!("the running gh-board refused the URL: {reason}");
+    }
+    match line.split_once(' ') {
+        None if line == "ok" => Ok(Handoff { pane: None }),
+        Some(("ok", pane)) => Ok(Handoff {
+            pane: Some(pane.to_owned()),
+        }),
+        _ => bail!("unexpected answer from the running gh-board: {line:?}"),
+    }
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::{Path, PathBuf};
+    use std::time::Duration;
+
+    use anyhow::{Context, Result};
+
+    use super::{Handoff, OPENS, parse_reply};
+    use crate::app::NavigationTarget;
+
+    /// How long either side waits on the other.
+    const TIMEOUT: Duration = Duration::from_secs(2);
+
+    pub(super) fn send_open(path: &Path, url: &str) -> Result<Option<Handoff>> {
+        let Ok(mut stream) = UnixStream::connect(path) else {
+            return Ok(None);
+        };
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        writeln!(stream, "open {url}").context("writing to the running gh-board")?;
+        let mut reply = String::new();
+        BufReader::new(stream)
+            .read_line(&mut reply)
+            .context("reading from the running gh-board")?;
+        parse_reply(&reply).map(Some)
+    }
+
+    /// Removes the socket when the instance exits.
+    #[derive(Debug)]
+    pub struct Instance(PathBuf);
+
+    impl Drop for Instance {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    pub(super) fn listen(path: &Path) -> Option<Instance> {
+        if UnixStream::connect(path).is_ok() {
+            // Another instance listens already; it keeps the socket.
+            return None;
+        }
+        // Left over by an instance that did not exit cleanly.
+        let _ = std::fs::remove_file(path);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).ok()?;
+        }
+        let listener = match UnixListener::bind(path) {
+            Ok(listener) => listener,
+            Err(e) => {
+                tracing::warn!("ipc: cannot listen on {}: {e}", path.display());
+                return None;
+            }
+        };
+        let pane = std::env::var("TMUX_PANE").ok();
+        std::thread::Builder::new()
+            .name("ipc".to_owned())
+            .spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    if let Err(e) = serve(&stream, pane.as_deref()) {
+                        tracing::warn!("ipc: {e:#}");
+                    }
+                }
+            })
+            .ok()?;
+        Some(Instance(path.to_owned()))
+    }
+
+    /// Answer one client.
+    fn serve(stream: &UnixStream, pane: Option<&str>) -> Result<()> {
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line)?;
+        let reply = match line.trim_end().strip_prefix("open ") {
+            Some(url) => match NavigationTarget::from_url(url) {
+                Ok(target) => {
+                    tracing::info!("ipc: opening {url}");
+                    let _ = OPENS.0.try_send(target);
+                    pane.map_or_else(|| "ok".to_owned(), |pane| format!("ok {pane}"))
+                }
+                Err(e) => format!("error {e}"),
+            },
+            None => "error unknown request".to_owned(),
+        };
+        writeln!(&*stream, "{reply}")?;
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+pub use unix::Instance;
+
+/// Placeholder: there is no socket to remove off Unix.
+#[cfg(not(unix))]
+#[derive(Debug)]
+pub struct Instance;
+
+/// Hand `url` to a running instance. `Ok(None)` when none is listening.
+pub fn send_open(url: &str) -> Result<Option<Handoff>> {
+    #[cfg(unix)]
+    if let Some(path) = socket_path() {
+        return unix::send_open(&path, url);
+    }
+    let _ = url;
+    Ok(None)
+}
+
+/// Accept URLs from later `gh-board open` runs until the returned guard is
+/// dropped. `None` when another instance listens already, or off Unix.
+pub fn listen() -> Option<Instance> {
+    #[cfg(unix)]
+    return unix::listen(&socket_path()?);
+    #[cfg(not(unix))]
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replies_name_the_pane_or_the_refusal() {
+        assert_eq!(parse_reply("ok\n").unwrap(), Handoff { pane: None });
+        assert_eq!(parse_reply("ok %3\n").unwrap().pane.as_deref(), Some("%3"));
+        let err = parse_reply("error unrecognised GitHub URL: x\n").unwrap_err();
+        assert!(err.to_string().contains("unrecognised GitHub URL"));
+        assert!(parse_reply("").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn a_listening_instance_receives_the_target() {
+        let path = std::env::temp_dir().join(format!("gh-board-ipc-{}.sock", std::process::id()));
+        let instance = unix::listen(&path).expect("listening");
+        assert!(
+            unix::listen(&path).is_none(),
+            "second instance must not take over"
+        );
+
+        let handoff = unix::send_open(&path, "https://github.com/acme/widgets/pull/7")
+            .unwrap()
+            .expect("instance answers");
+        assert_eq!(handoff.pane, std::env::var("TMUX_PANE").ok());
+        let target = smol::block_on(next_open()).unwrap();
+        assert!(matches!(
+            target,
+            NavigationTarget::PullRequest { number: 7, .. }
+        ));
+        assert!(unix::send_open(&path, "not a url").is_err());
+
+        drop(instance);
+        assert!(!path.exists());
+        assert!(
+            unix::send_open(&path, "https://github.com/a/b/pull/1")
+                .unwrap()
+                .is_none()
+        );
+    }
+}
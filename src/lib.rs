@@ -4,18 +4,35 @@
 
 pub mod actions;
 pub mod app;
+pub(crate) mod check_history;
 pub mod color;
 pub mod components;
 pub mod config;
+pub(crate) mod cron;
+pub(crate) mod dispatch_inputs;
+pub mod doctor;
+pub(crate) mod drafts;
 pub mod engine;
 pub mod filter;
 pub mod git;
 pub(crate) mod github;
+pub mod health;
 pub mod icons;
 pub mod init;
+pub mod ipc;
+pub mod logging;
 pub mod markdown;
+pub(crate) mod notes;
+pub(crate) mod release_notes;
+pub mod report;
+pub(crate) mod script;
+pub mod share;
+pub(crate) mod smart_scope;
+pub mod spell;
+pub mod terminal;
 pub mod theme;
 pub mod types;
 pub mod url;
 pub mod util;
+pub(crate) mod viewed_files;
 pub mod views;
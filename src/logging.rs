@@ -0,0 +1,236 @@
+//! Tracing setup, and the buffer behind the in-app log viewer.
+//!
+//! Each session logs to its own file,
+//! `$XDG_STATE_HOME/gh-board/logs/<start>-<pid>.log`, as compact text or
+//! JSON lines (`[log] format`); the newest [`KEEP_SESSIONS`] files are kept.
+//! The same events, from `info` up (`debug` with `--debug`), are also kept in
+//! memory for the log viewer (`show_logs`), so fetch problems can be read
+//! without leaving the TUI.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::layer::{Context as LayerContext, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{Layer, fmt};
+
+use crate::config::types::{LogConfig, LogFormat};
+
+/// Session files kept in the log directory, the current one included.
+pub const KEEP_SESSIONS: usize = 10;
+
+/// Events kept in memory for the log viewer.
+const BUFFER_LEN: usize = 2000;
+
+/// One event, as the log viewer lists it.
+#[derive(Debug, Clone)]
+pub(crate) struct Record {
+    pub(crate) at: DateTime<Local>,
+    pub(crate) level: Level,
+    pub(crate) target: String,
+    /// The message, followed by the other fields as `key=value`.
+    pub(crate) message: String,
+}
+
+static BUFFER: Mutex<VecDeque<Record>> = Mutex::new(VecDeque::new());
+
+static SESSION_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Buffered events, oldest first.
+pub(crate) fn recent() -> Vec<Record> {
+    BUFFER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .cloned()
+        .collect()
+}
+
+/// This session's log file, once [`init`] ran.
+pub fn session_file() -> Option<&'static Path> {
+    SESSION_FILE.get().map(PathBuf::as_path)
+}
+
+/// Collects an event's fields into [`Record::message`].
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
+
+/// Layer appending events to [`BUFFER`].
+struct BufferLayer;
+
+impl<S: Subscriber> Layer<S> for BufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: LayerContext<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        let record = Record {
+            at: Local::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_owned(),
+            message: visitor.message + visitor.fields.as_str(),
+        };
+        let mut buffer = BUFFER.lock().unwrap_or_else(PoisonError::into_inner);
+        if buffer.len() == BUFFER_LEN {
+            buffer.pop_front();
+        }
+        buffer.push_back(record);
+    }
+}
+
+/// Directory the session files are written to.
+pub(crate) fn log_dir() -> Option<PathBuf> {
+    crate::util::state_path("logs")
+}
+
+/// Session files in `dir`, oldest first.
+pub(crate) fn session_files(dir: &Path) -> Vec<PathBuf> {
+    let mut sessions: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
+        .collect();
+    // Names start with the session's start time, so they sort oldest first.
+    sessions.sort();
+    sessions
+}
+
+/// Delete the oldest session files in `dir` so that `keep` remain, counting
+/// the one about to be created.
+fn prune(dir: &Path, keep: usize) {
+    let sessions = session_files(dir);
+    let excess = (sessions.len() + 1).saturating_sub(keep);
+    for path in sessions.into_iter().take(excess) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Set up tracing for this session and return its log file.
+///
+/// The file logs `warn` and up unless `[log] level` says otherwise; `--debug`
+/// lowers it to `debug`, and `RUST_LOG` overrides both.
+pub fn init(config: &LogConfig, debug: bool) -> Result<PathBuf> {
+    let dir = log_dir().context("no state directory to log to")?;
+    std::fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+    prune(&dir, KEEP_SESSIONS);
+    let path = dir.join(format!(
+        "{}-{}.log",
+        Local::now().format("%Y%m%dT%H%M%S"),
+        std::process::id()
+    ));
+    let file =
+        std::fs::File::create(&path).with_context(|| format!("creating {}", path.display()))?;
+
+    let level = if debug {
+        "debug"
+    } else {
+        config.level.as_deref().unwrap_or("warn")
+    };
+    let file_filter = match EnvFilter::try_from_env("RUST_LOG") {
+        Ok(filter) => filter,
+        Err(_) => EnvFilter::try_new(level).context("invalid `log.level`")?,
+    };
+    let (json, compact) = match config.format.unwrap_or_default() {
+        LogFormat::Json => (
+            Some(
+                fmt::layer()
+                    .json()
+                    .with_writer(file)
+                    .with_filter(file_filter),
+            ),
+            None,
+        ),
+        LogFormat::Compact => (
+            None,
+            Some(
+                fmt::layer()
+                    .compact()
+                    .with_ansi(false)
+                    .with_writer(file)
+                    .with_filter(file_filter),
+            ),
+        ),
+    };
+    let buffered = BufferLayer.with_filter(if debug {
+        LevelFilter::DEBUG
+    } else {
+        LevelFilter::INFO
+    });
+    tracing_subscriber::registry()
+        .with(json)
+        .with(compact)
+        .with(buffered)
+        .init();
+    let _ = SESSION_FILE.set(path.clone());
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prune_keeps_the_newest_sessions() {
+        let dir = std::env::temp_dir().join(format!("gh-board-logs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "20260101T090000-1.log",
+            "20260102T090000-2.log",
+            "20260103T090000-3.log",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+        prune(&dir, 2);
+        let mut left: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        left.sort();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(left, ["20260103T090000-3.log", "notes.txt"]);
+    }
+
+    #[test]
+    fn buffered_records_carry_message_then_fields() {
+        let subscriber = tracing_subscriber::registry().with(BufferLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(status = 502, "fetch failed: {}", "Bad Gateway");
+        });
+        let record = recent()
+            .into_iter()
+            .rfind(|record| record.message.starts_with("fetch failed"))
+            .unwrap();
+        assert_eq!(record.level, Level::WARN);
+        assert_eq!(record.message, "fetch failed: Bad Gateway status=502");
+    }
+}
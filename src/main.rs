@@ -1,17 +1,26 @@
+use std::panic::AssertUnwindSafe;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 use iocraft::prelude::*;
 
-use gh_board::app::{App, NavigationTarget};
+use gh_board::app::{App, ConfigReload, NavigationTarget};
 use gh_board::color::ColorDepth;
+use gh_board::components::error_screen::ErrorScreen;
+use gh_board::components::health_screen::HealthScreen;
 use gh_board::config::builtin_themes;
 use gh_board::config::keybindings::MergedBindings;
 use gh_board::config::loader;
-use gh_board::engine::{Engine, GitHubEngine};
+use gh_board::config::types::Defaults;
+use gh_board::engine::{Engine, EngineHandle, GitHubEngine};
+use gh_board::report::ReportPeriod;
+use gh_board::terminal;
 use gh_board::theme::{Background, ResolvedTheme};
-use gh_board::url::{ParsedGitHubUrl, parse_github_url};
+use gh_board::util::{SizeThresholds, StaleThresholds, Timezone};
 
 #[derive(Parser)]
 #[command(name = "gh-board", version, about = "GitHub TUI Dashboard")]
@@ -20,10 +29,15 @@ struct Cli {
     #[arg(short, long)]
     config: Option<PathBuf>,
 
-    /// Enable verbose debug logging to ./debug.log (default: warn+ to ~/.cache/gh-board/).
+    /// Log at debug level (default: warn+) to this session's file under
+    /// ~/.local/state/gh-board/logs/.
     #[arg(long)]
     debug: bool,
 
+    /// Draw icons, borders and separators with ASCII characters only.
+    #[arg(long)]
+    ascii: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 
@@ -44,67 +58,94 @@ enum Commands {
         #[arg(long)]
         show_origin: bool,
     },
+    /// Summarize your merged PRs, reviews, closed issues and comments as markdown.
+    Report {
+        /// Number of days to cover, ending with `--until`.
+        #[arg(long, default_value_t = 7)]
+        days: u32,
+        /// First day to cover (YYYY-MM-DD), instead of counting `--days`.
+        #[arg(long)]
+        since: Option<NaiveDate>,
+        /// Last day to cover (YYYY-MM-DD, default: today).
+        #[arg(long)]
+        until: Option<NaiveDate>,
+        /// Write the report to this file instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Print version, terminal and config details for a bug report.
+    Doctor {
+        /// Also write them, the redacted config, recent logs and the last
+        /// panic log to a .tar archive to attach to the issue.
+        #[arg(long)]
+        bundle: bool,
+        /// Where to write the bundle (default: gh-board-doctor-<time>.tar).
+        #[arg(short, long, requires = "bundle")]
+        output: Option<PathBuf>,
+    },
     /// Open a GitHub URL directly in the appropriate view.
+    ///
+    /// Hands the URL to a gh-board already running, if any.
     Open {
         /// GitHub PR, issue, or actions run URL.
         url: String,
+        /// Start a new instance even when one is running.
+        #[arg(long)]
+        new_instance: bool,
     },
 }
 
-/// Convert a parsed GitHub URL into a navigation target.
-fn nav_target_from_url(url: &str) -> Result<NavigationTarget> {
-    let parsed =
-        parse_github_url(url).ok_or_else(|| anyhow::anyhow!("unrecognised GitHub URL: {url}"))?;
-    Ok(match parsed {
-        ParsedGitHubUrl::PullRequest {
-            host,
-            owner,
-            repo,
-            number,
-        } => NavigationTarget::PullRequest {
-            owner,
-            repo,
-            number,
-            host,
-        },
-        ParsedGitHubUrl::Issue {
-            host,
-            owner,
-            repo,
-            number,
-        } => NavigationTarget::Issue {
-            owner,
-            repo,
-            number,
-            host,
-        },
-        ParsedGitHubUrl::ActionsRun {
-            host,
-            owner,
-            repo,
-            run_id,
-        } => NavigationTarget::ActionsRun {
-            owner,
-            repo,
-            run_id,
-            host,
-        },
-    })
+/// Install the configured timezone for absolute dates.
+///
+/// Must run before any thread is spawned: named zones are applied by
+/// exporting `TZ`.
+fn apply_timezone(timezone: Option<&str>) -> Result<()> {
+    let Some(tz) = timezone else {
+        return Ok(());
+    };
+    let tz = Timezone::parse(tz).context("invalid `defaults.timezone`")?;
+    if let Timezone::Named(name) = &tz {
+        // SAFETY: called from `main` before the engine and UI threads start,
+        // so nothing can read the environment concurrently.
+        unsafe { std::env::set_var("TZ", name) };
+    }
+    gh_board::util::set_timezone(tz);
+    Ok(())
+}
+
+/// Install the staleness and PR size thresholds rows are classified by.
+fn apply_row_thresholds(defaults: &Defaults) {
+    gh_board::util::set_stale_thresholds(StaleThresholds {
+        warn_days: defaults.stale.warn_days,
+        alert_days: defaults.stale.alert_days,
+    });
+    let sizes = &defaults.pr_size;
+    let fallback = SizeThresholds::default();
+    gh_board::util::set_size_thresholds(SizeThresholds {
+        xs: sizes.xs.unwrap_or(fallback.xs),
+        s: sizes.s.unwrap_or(fallback.s),
+        m: sizes.m.unwrap_or(fallback.m),
+        l: sizes.l.unwrap_or(fallback.l),
+    });
 }
 
+#[expect(clippy::too_many_lines)]
 fn main() -> Result<()> {
-    // Install a panic hook that writes to a file, since the fullscreen TUI
-    // swallows stderr.
-    std::panic::set_hook(Box::new(|info| {
-        let backtrace = std::backtrace::Backtrace::force_capture();
-        let msg = format!("{info}\n\n{backtrace}");
-        let _ = std::fs::write("panic.log", &msg);
-        eprintln!("{msg}");
-    }));
+    // The fullscreen TUI swallows stderr: record panics and report them once
+    // the terminal is restored.
+    terminal::install_panic_hook();
 
     let cli = Cli::parse();
 
+    // Install the rustls CryptoProvider before any TLS client is constructed
+    // (`init` queries the API too). reqwest 0.13 / rustls 0.23 no longer
+    // auto-installs a provider.
+    rustls::crypto::aws_lc_rs::default_provider()
+        .install_default()
+        .expect("failed to install default CryptoProvider");
+
     // Handle subcommands that don't need the TUI.
+    let mut hand_off = false;
     let open_url: Option<String> = match cli.command {
         Some(Commands::Themes) => {
             for name in builtin_themes::list() {
@@ -126,7 +167,24 @@ fn main() -> Result<()> {
             }
             return Ok(());
         }
-        Some(Commands::Open { url }) => Some(url),
+        Some(Commands::Report {
+            days,
+            since,
+            until,
+            output,
+        }) => {
+            let config = loader::load_config(cli.config.as_deref())?;
+            apply_timezone(config.defaults.timezone.as_deref())?;
+            let period = ReportPeriod::new(days, since, until)?;
+            return gh_board::report::run(config, period, output.as_deref());
+        }
+        Some(Commands::Doctor { bundle, output }) => {
+            return gh_board::doctor::run(cli.config.as_deref(), bundle, output.as_deref());
+        }
+        Some(Commands::Open { url, new_instance }) => {
+            hand_off = !new_instance;
+            Some(url)
+        }
         None => {
             if let Some(ref url) = cli.url {
                 eprintln!("hint: use \"gh-board open <URL>\" for clarity");
@@ -138,55 +196,49 @@ fn main() -> Result<()> {
     };
 
     // Parse the URL into a navigation target (if provided).
-    let initial_nav_target: Option<NavigationTarget> =
-        open_url.as_deref().map(nav_target_from_url).transpose()?;
-
-    // Set up tracing.
-    //
-    // Always log warn+ to a well-known file so users can troubleshoot without
-    // needing to reproduce with `--debug`. The `--debug` flag lowers the filter
-    // to `debug` and writes to `./debug.log` instead (same behavior as before).
+    let initial_nav_target: Option<NavigationTarget> = open_url
+        .as_deref()
+        .map(NavigationTarget::from_url)
+        .transpose()?;
+
+    // Before tracing starts: it would truncate the running instance's log.
+    if hand_off
+        && let Some(url) = &open_url
+        && hand_off_url(url)?
     {
-        let (log_file, default_level) = if cli.debug {
-            (std::fs::File::create("debug.log")?, "debug")
-        } else {
-            let cache_dir = std::env::var("XDG_CACHE_HOME")
-                .map_or_else(
-                    |_| {
-                        PathBuf::from(std::env::var("HOME").unwrap_or_else(|_| "/tmp".into()))
-                            .join(".cache")
-                    },
-                    PathBuf::from,
-                )
-                .join("gh-board");
-            std::fs::create_dir_all(&cache_dir)?;
-            let path = cache_dir.join("gh-board.log");
-            (std::fs::File::create(path)?, "warn")
-        };
-        tracing_subscriber::fmt()
-            .with_writer(log_file)
-            .with_ansi(false)
-            .with_env_filter(
-                tracing_subscriber::EnvFilter::try_from_env("RUST_LOG")
-                    .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(default_level)),
-            )
-            .init();
+        return Ok(());
     }
 
     // Load config.
-    let config = loader::load_config(cli.config.as_deref())?;
+    let mut config = loader::load_config(cli.config.as_deref())?;
+    gh_board::logging::init(&config.log, cli.debug)?;
+    let _instance = gh_board::ipc::listen();
+    if cli.ascii {
+        config.theme.ui.ascii = Some(true);
+    }
+    apply_timezone(config.defaults.timezone.as_deref())?;
+    apply_row_thresholds(&config.defaults);
+    gh_board::actions::clipboard::set_browser_config(config.browser.clone());
+    gh_board::actions::clipboard::set_copy_config(config.copy.clone());
+    gh_board::share::set_share_targets(config.share_targets.clone());
+    gh_board::spell::configure(&config.spell);
 
     // Detect terminal capabilities.
     let color_depth = ColorDepth::detect();
     let background = Background::detect();
-    let theme = ResolvedTheme::resolve(&config.theme, background);
-    let keybindings = MergedBindings::from_config(&config.keybindings);
-
-    // Install the rustls CryptoProvider before any TLS client is constructed.
-    // reqwest 0.13 / rustls 0.23 no longer auto-installs a provider.
-    rustls::crypto::aws_lc_rs::default_provider()
-        .install_default()
-        .expect("failed to install default CryptoProvider");
+    let theme = Arc::new(ResolvedTheme::resolve(&config.theme, background));
+    let keybindings = Arc::new(MergedBindings::from_config(&config.keybindings));
+    let config_reload = match loader::ConfigWatcher::new(cli.config.as_deref(), &config) {
+        Ok(watcher) => Some(ConfigReload {
+            watcher,
+            ascii: cli.ascii,
+            background,
+        }),
+        Err(e) => {
+            tracing::warn!("config edits apply after a restart: {e:#}");
+            None
+        }
+    };
 
     // Start the GitHub backend engine in a dedicated OS thread (owns its own
     // Tokio runtime). Dropping `engine_handle` at the end of `main` closes the
@@ -195,25 +247,112 @@ fn main() -> Result<()> {
 
     tracing::info!("gh-board starting");
 
+    // A broken token or network is reported once, before any tab runs into it.
+    if config.github.startup_check.unwrap_or(true) && !startup_check(&engine_handle, &config)? {
+        return Ok(());
+    }
+
     let cwd = std::env::current_dir().ok();
-    let detected_repo = cwd.as_deref().and_then(gh_board::git::detect_repo);
+    let (detected_repo, detected_host) = cwd
+        .as_deref()
+        .and_then(gh_board::git::detect_remote)
+        .unzip();
+    let detected_host = detected_host.flatten();
+    let config = Arc::new(config);
+
+    // Enter fullscreen TUI (iocraft uses smol internally). A panic unwinds
+    // out of the render loop; show it and offer a fresh start instead of
+    // exiting.
+    let mut initial_nav_target = initial_nav_target;
+    loop {
+        let run = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            smol::block_on(
+                element! {
+                    App(
+                        config: config.clone(),
+                        config_reload: config_reload.as_ref(),
+                        engine: &engine_handle,
+                        theme: theme.clone(),
+                        keybindings: keybindings.clone(),
+                        color_depth,
+                        repo_path: cwd.as_deref(),
+                        detected_repo: detected_repo.as_ref(),
+                        detected_host: detected_host.as_deref(),
+                        initial_nav_target: initial_nav_target.take(),
+                    )
+                }
+                .fullscreen(),
+            )
+        }));
+        if let Ok(result) = run {
+            return Ok(result?);
+        }
+        terminal::restore();
+        let summary = terminal::take_panic().unwrap_or_else(|| "unknown panic".to_owned());
+        if !offer_restart(&summary)? {
+            match terminal::panic_log_path() {
+                Some(path) => bail!("gh-board crashed: {summary} (see {})", path.display()),
+                None => bail!("gh-board crashed: {summary}"),
+            }
+        }
+    }
+}
 
-    // Enter fullscreen TUI (iocraft uses smol internally).
+/// Give `url` to a running instance; returns whether one took it.
+fn hand_off_url(url: &str) -> Result<bool> {
+    let Some(handoff) = gh_board::ipc::send_open(url)? else {
+        return Ok(false);
+    };
+    if handoff.focus() {
+        println!("Opened in the running gh-board");
+    } else {
+        println!("Opened in the running gh-board; switch to its terminal");
+    }
+    Ok(true)
+}
+
+/// Check the configured hosts until they pass or the user starts anyway;
+/// returns whether to start.
+fn startup_check(
+    engine: &EngineHandle,
+    config: &gh_board::config::types::AppConfig,
+) -> Result<bool> {
+    let hosts = gh_board::health::configured_hosts(config);
+    loop {
+        let report = gh_board::health::check(engine, &hosts);
+        if report.iter().all(|host| host.problems.is_empty()) {
+            return Ok(true);
+        }
+        let retry = Arc::new(AtomicBool::new(false));
+        let proceed = Arc::new(AtomicBool::new(false));
+        smol::block_on(
+            element! {
+                HealthScreen(hosts: report, retry: retry.clone(), proceed: proceed.clone())
+            }
+            .fullscreen(),
+        )?;
+        if proceed.load(Ordering::Relaxed) {
+            return Ok(true);
+        }
+        if !retry.load(Ordering::Relaxed) {
+            return Ok(false);
+        }
+        eprintln!("Checking {} again...", hosts.join(", "));
+    }
+}
+
+/// Show the crash screen; returns whether the user chose to restart.
+fn offer_restart(summary: &str) -> Result<bool> {
+    let restart = Arc::new(AtomicBool::new(false));
     smol::block_on(
         element! {
-            App(
-                config: &config,
-                engine: &engine_handle,
-                theme: &theme,
-                keybindings: &keybindings,
-                color_depth,
-                repo_path: cwd.as_deref(),
-                detected_repo: detected_repo.as_ref(),
-                initial_nav_target,
+            ErrorScreen(
+                summary: summary.to_owned(),
+                panic_log: terminal::panic_log_path(),
+                restart: restart.clone(),
             )
         }
         .fullscreen(),
     )?;
-
-    Ok(())
+    Ok(restart.load(Ordering::Relaxed))
 }
@@ -0,0 +1,152 @@
+//! Memoized markdown rendering for sidebar previews.
+//!
+//! Rendering a large PR or issue body (with syntax-highlighted code blocks)
+//! is far too slow to repeat on every frame. [`MarkdownCache`] keeps the
+//! rendered lines keyed by content, theme and color depth, and renders large
+//! documents on the blocking thread pool so the UI thread never stalls.
+//!
+//! Width is not part of the key: the renderer emits unwrapped lines and the
+//! sidebar wraps them at draw time.
+
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use iocraft::prelude::State;
+
+use crate::color::ColorDepth;
+use crate::markdown::renderer::{StyledLine, StyledSpan, render_markdown};
+use crate::theme::ResolvedTheme;
+use crate::util::LruCache;
+
+/// Documents up to this size are rendered inline: they are fast enough that a
+/// placeholder would only flicker.
+const INLINE_RENDER_MAX_BYTES: usize = 4 * 1024;
+
+/// Rendered documents kept per cache; the least recently shown is evicted
+/// first.
+const MAX_ENTRIES: usize = 64;
+
+/// Rendered markdown shared across frames of one view.
+///
+/// Cheap to clone; all clones share the same entries.
+#[derive(Clone, Default)]
+pub struct MarkdownCache {
+    inner: Arc<Mutex<Inner>>,
+}
+
+struct Inner {
+    ready: LruCache<u64, Arc<Vec<StyledLine>>>,
+    pending: HashSet<u64>,
+}
+
+impl Default for Inner {
+    fn default() -> Self {
+        Self {
+            ready: LruCache::new(MAX_ENTRIES),
+            pending: HashSet::new(),
+        }
+    }
+}
+
+impl Inner {
+    fn insert(&mut self, key: u64, lines: Arc<Vec<StyledLine>>) {
+        self.pending.remove(&key);
+        self.ready.insert(key, lines);
+    }
+}
+
+impl MarkdownCache {
+    /// Return `markdown` rendered with `theme` and `depth`.
+    ///
+    /// On a miss, small documents are rendered immediately. Larger ones are
+    /// rendered in the background and `None` is returned until they are
+    /// ready; `ready_gen` is bumped when a background render lands so the
+    /// owning component re-renders.
+    pub fn get_or_render(
+        &self,
+        markdown: &str,
+        theme: &ResolvedTheme,
+        depth: ColorDepth,
+        mut ready_gen: State<u64>,
+    ) -> Option<Arc<Vec<StyledLine>>> {
+        let key = cache_key(markdown, theme, depth);
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(lines) = inner.ready.get(&key) {
+            return Some(Arc::clone(lines));
+        }
+        if markdown.len() <= INLINE_RENDER_MAX_BYTES {
+            let lines = Arc::new(render_markdown(markdown, theme, depth));
+            inner.insert(key, Arc::clone(&lines));
+            return Some(lines);
+        }
+        if inner.pending.insert(key) {
+            let cache = Arc::clone(&self.inner);
+            let markdown = markdown.to_owned();
+            let theme = theme.clone();
+            smol::unblock(move || {
+                let lines = Arc::new(render_markdown(&markdown, &theme, depth));
+                cache
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(key, lines);
+                let next = ready_gen.try_get().unwrap_or_default().wrapping_add(1);
+                ready_gen.set(next);
+            })
+            .detach();
+        }
+        None
+    }
+}
+
+/// Placeholder line shown while a document is rendering.
+pub fn pending_line(theme: &ResolvedTheme) -> StyledLine {
+    let text = if theme.chrome.ascii_only() {
+        "Rendering..."
+    } else {
+        "Rendering\u{2026}"
+    };
+    StyledLine::from_span(StyledSpan::text(text, theme.text_faint))
+}
+
+fn cache_key(markdown: &str, theme: &ResolvedTheme, depth: ColorDepth) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    markdown.hash(&mut hasher);
+    theme.hash(&mut hasher);
+    depth.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::Theme;
+    use crate::theme::Background;
+
+    fn theme() -> ResolvedTheme {
+        ResolvedTheme::resolve(&Theme::default(), Background::Dark)
+    }
+
+    #[test]
+    fn cache_key_depends_on_content_and_depth() {
+        let t = theme();
+        let a = cache_key("# a", &t, ColorDepth::TrueColor);
+        assert_eq!(a, cache_key("# a", &t, ColorDepth::TrueColor));
+        assert_ne!(a, cache_key("# b", &t, ColorDepth::TrueColor));
+        assert_ne!(a, cache_key("# a", &t, ColorDepth::Color16));
+    }
+
+    #[test]
+    fn insert_evicts_least_recently_shown_beyond_capacity() {
+        let mut inner = Inner::default();
+        for key in 0..MAX_ENTRIES as u64 {
+            inner.insert(key, Arc::new(Vec::new()));
+        }
+        assert!(inner.ready.get(&0).is_some());
+        inner.insert(MAX_ENTRIES as u64, Arc::new(Vec::new()));
+        assert!(inner.ready.contains_key(&0));
+        assert!(!inner.ready.contains_key(&1));
+        assert!(inner.ready.contains_key(&(MAX_ENTRIES as u64)));
+    }
+}
@@ -4,8 +4,9 @@ use pulldown_cmark::{
 use unicode_width::UnicodeWidthStr;
 
 use crate::color::{Color as AppColor, ColorDepth};
+use crate::components::table::linearize_fields;
 use crate::markdown::syntax;
-use crate::theme::ResolvedTheme;
+use crate::theme::{Chrome, ResolvedTheme};
 
 // ---------------------------------------------------------------------------
 // Styled output types
@@ -256,6 +257,16 @@ impl<'t> RenderContext<'t> {
         }
     }
 
+    /// Pick the Unicode glyph, or its ASCII stand-in when the chrome asks
+    /// for ASCII-only output.
+    fn glyph(&self, unicode: &'static str, ascii: &'static str) -> &'static str {
+        if self.theme.chrome.ascii_only() {
+            ascii
+        } else {
+            unicode
+        }
+    }
+
     fn push_span(&mut self, span: StyledSpan) {
         if self.in_table {
             self.table_current_cell.push(span);
@@ -275,7 +286,8 @@ impl<'t> RenderContext<'t> {
                     self.push_text(&text[pos..url_start]);
                 }
                 // Emit the link icon instead of the URL.
-                self.push_span(StyledSpan::plain("\u{2197}", self.theme.md_link)); // ↗
+                let icon = self.glyph("\u{2197}", "->"); // ↗
+                self.push_span(StyledSpan::plain(icon, self.theme.md_link));
                 pos = url_end;
             } else {
                 // No more URLs; emit the rest.
@@ -287,10 +299,12 @@ impl<'t> RenderContext<'t> {
 
     fn push_prefix(&mut self) {
         if self.in_blockquote {
-            let span = StyledSpan::plain(
-                "\u{2502} ", // │
-                self.theme.md_blockquote,
-            );
+            let prefix = if self.theme.chrome == Chrome::Plain {
+                "> "
+            } else {
+                self.glyph("\u{2502} ", "| ") // │
+            };
+            let span = StyledSpan::plain(prefix, self.theme.md_blockquote);
             self.current_line.push(span);
         }
         if self.list_depth > 0 {
@@ -325,6 +339,10 @@ impl<'t> RenderContext<'t> {
         if rows.is_empty() {
             return;
         }
+        if self.theme.chrome == Chrome::Plain {
+            self.flush_linear_table(rows);
+            return;
+        }
 
         let num_cols = alignments.len();
 
@@ -339,6 +357,9 @@ impl<'t> RenderContext<'t> {
         }
 
         let border_color = self.theme.border_faint;
+        let cell_sep = self.glyph(" \u{2502} ", " | "); // │
+        let rule = self.glyph("\u{2500}", "-"); // ─
+        let cross = self.glyph("\u{2500}\u{253C}\u{2500}", "-+-"); // ─┼─
 
         // Pass 2: emit rows.
         for (r, row) in rows.iter().enumerate() {
@@ -346,7 +367,7 @@ impl<'t> RenderContext<'t> {
             for (c, cell) in row.iter().enumerate() {
                 if c < num_cols {
                     if c > 0 {
-                        line.push(StyledSpan::plain(" \u{2502} ", border_color)); // │
+                        line.push(StyledSpan::plain(cell_sep, border_color));
                     } else {
                         line.push(StyledSpan::plain(" ", self.theme.md_text));
                     }
@@ -395,14 +416,11 @@ impl<'t> RenderContext<'t> {
                 let mut sep = StyledLine::new();
                 for (c, &w) in col_widths.iter().enumerate() {
                     if c > 0 {
-                        sep.push(StyledSpan::plain(
-                            "\u{2500}\u{253C}\u{2500}", // ─┼─
-                            border_color,
-                        ));
+                        sep.push(StyledSpan::plain(cross, border_color));
                     } else {
-                        sep.push(StyledSpan::plain("\u{2500}", border_color)); // ─
+                        sep.push(StyledSpan::plain(rule, border_color));
                     }
-                    sep.push(StyledSpan::plain("\u{2500}".repeat(w), border_color));
+                    sep.push(StyledSpan::plain(rule.repeat(w), border_color));
                 }
                 self.lines.push(sep);
             }
@@ -412,6 +430,49 @@ impl<'t> RenderContext<'t> {
         self.in_table = false;
     }
 
+    /// Screen-reader tables: one `Header: cell; Header: cell` line per body
+    /// row, in the same form as the linearized list tables.
+    fn flush_linear_table(&mut self, rows: Vec<Vec<Vec<StyledSpan>>>) {
+        let text = |cell: &[StyledSpan]| -> String {
+            cell.iter()
+                .map(|s| s.text.as_str())
+                .collect::<String>()
+                .trim()
+                .to_owned()
+        };
+        let color = self.theme.md_text;
+        let plain = |text: String| StyledSpan::plain(text, color);
+        let mut rows = rows.into_iter();
+        let header = rows.next().unwrap_or_default();
+        let labels: Vec<String> = header
+            .iter()
+            .enumerate()
+            .map(|(c, cell)| match text(cell) {
+                label if label.is_empty() => format!("Column {}", c + 1),
+                label => label,
+            })
+            .collect();
+
+        let mut body = rows.peekable();
+        if body.peek().is_none() {
+            let fields = header.into_iter().map(|cell| (None, cell));
+            self.lines
+                .push(StyledLine::from_spans(linearize_fields(fields, plain)));
+        }
+        for row in body {
+            let fields = labels
+                .iter()
+                .zip(row)
+                .filter(|(_, cell)| !text(cell).is_empty())
+                .map(|(label, cell)| (Some(label.as_str()), cell));
+            self.lines
+                .push(StyledLine::from_spans(linearize_fields(fields, plain)));
+        }
+
+        self.lines.push(StyledLine::new());
+        self.in_table = false;
+    }
+
     #[expect(clippy::too_many_lines)]
     fn process<'a>(&mut self, parser: impl Iterator<Item = Event<'a>>) {
         for event in parser {
@@ -473,7 +534,7 @@ impl<'t> RenderContext<'t> {
                         *n += 1;
                         m
                     } else {
-                        "\u{2022} ".to_owned() // •
+                        self.glyph("\u{2022} ", "* ").to_owned() // •
                     };
                     self.current_line
                         .push(StyledSpan::plain(marker, self.theme.md_text));
@@ -491,7 +552,8 @@ impl<'t> RenderContext<'t> {
                     self.in_link = true;
                     self.link_url = dest_url.to_string();
                     // Emit link icon before the link text.
-                    self.push_span(StyledSpan::plain("\u{2197} ", self.theme.md_link)); // ↗
+                    let icon = self.glyph("\u{2197} ", "-> "); // ↗
+                    self.push_span(StyledSpan::plain(icon, self.theme.md_link));
                 }
                 Event::Start(Tag::Image { dest_url, .. }) => {
                     self.push_span(StyledSpan::plain(
@@ -648,14 +710,18 @@ impl<'t> RenderContext<'t> {
                 Event::Rule => {
                     self.ensure_blank_line();
                     self.current_line.push(StyledSpan::plain(
-                        "\u{2500}".repeat(40), // ─ repeated
+                        self.glyph("\u{2500}", "-").repeat(40), // ─ repeated
                         self.theme.md_horizontal_rule,
                     ));
                     self.flush_line();
                     self.lines.push(StyledLine::new());
                 }
                 Event::TaskListMarker(checked) => {
-                    let marker = if checked { "[\u{2714}] " } else { "[ ] " };
+                    let marker = if checked {
+                        self.glyph("[\u{2714}] ", "[x] ")
+                    } else {
+                        "[ ] "
+                    };
                     self.current_line
                         .push(StyledSpan::plain(marker, self.theme.md_text));
                 }
@@ -1018,4 +1084,41 @@ mod tests {
             "alert prefix should be bold"
         );
     }
+
+    #[test]
+    fn ascii_chrome_renders_only_ascii() {
+        let mut theme = test_theme();
+        theme.chrome = Chrome::Ascii;
+        let md = "| A | B |\n|---|---|\n| 1 | 2 |\n\n> quoted\n\n- item\n- [x] done\n\n\
+                  [link](https://example.com) and https://example.com\n\n---\n";
+        let lines = render_markdown(md, &theme, ColorDepth::TrueColor);
+        let all_text: String = lines.iter().map(line_text).collect::<Vec<_>>().join("\n");
+
+        assert!(all_text.is_ascii(), "non-ASCII glyph in: {all_text}");
+        for expected in [
+            " | ", "-+-", "| quoted", "* item", "[x] ", "-> link", "----",
+        ] {
+            assert!(
+                all_text.contains(expected),
+                "missing {expected:?}: {all_text}"
+            );
+        }
+    }
+
+    #[test]
+    fn plain_chrome_linearizes_tables_and_marks_quotes() {
+        let mut theme = test_theme();
+        theme.chrome = Chrome::Plain;
+        let md = "| Name | Status |\n|---|---|\n| api | ok |\n| web | |\n\n> quoted\n";
+        let lines = render_markdown(md, &theme, ColorDepth::TrueColor);
+        let texts: Vec<String> = lines.iter().map(line_text).collect();
+
+        assert!(
+            texts.contains(&"Name: api; Status: ok".to_owned()),
+            "{texts:?}"
+        );
+        assert!(texts.contains(&"Name: web".to_owned()), "{texts:?}");
+        assert!(texts.contains(&"> quoted".to_owned()), "{texts:?}");
+        assert!(texts.iter().all(|t| t.is_ascii()), "{texts:?}");
+    }
 }
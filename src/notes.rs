@@ -0,0 +1,128 @@
+//! Personal notes on PRs and issues, kept on disk and never sent to GitHub.
+//!
+//! `edit_note` in the PR and Issue views opens the selected item's note in
+//! `$VISUAL` / `$EDITOR`; saving it blank deletes it. Each note is a markdown
+//! file, `$XDG_STATE_HOME/gh-board/notes/<owner>/<repo>/<number>.md`
+//! (`~/.local/state` by default), so notes can be read and searched outside
+//! gh-board as well. Rows with a note are marked, and the sidebar shows the
+//! note above the item.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use anyhow::{Context, Result};
+
+fn notes_dir() -> Option<PathBuf> {
+    crate::util::state_path("notes")
+}
+
+/// `owner/repo#number`, the key notes are indexed by.
+fn key(repo: &str, number: u64) -> String {
+    format!("{repo}#{number}")
+}
+
+/// Every note under `dir`, keyed by item.
+fn load(dir: &Path) -> HashMap<String, String> {
+    let subdirs = |path: &Path| {
+        std::fs::read_dir(path)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .collect::<Vec<_>>()
+    };
+    let mut notes = HashMap::new();
+    for owner in subdirs(dir) {
+        for repo in subdirs(&owner.path()) {
+            let full_name = format!(
+                "{}/{}",
+                owner.file_name().to_string_lossy(),
+                repo.file_name().to_string_lossy()
+            );
+            for file in std::fs::read_dir(repo.path())
+                .into_iter()
+                .flatten()
+                .flatten()
+            {
+                let path = file.path();
+                let number = path
+                    .extension()
+                    .filter(|ext| *ext == "md")
+                    .and_then(|_| path.file_stem()?.to_str()?.parse::<u64>().ok());
+                if let Some(number) = number
+                    && let Ok(body) = std::fs::read_to_string(&path)
+                    && !body.trim().is_empty()
+                {
+                    notes.insert(key(&full_name, number), body);
+                }
+            }
+        }
+    }
+    notes
+}
+
+/// Notes on disk, read on first use.
+static NOTES: LazyLock<Mutex<HashMap<String, String>>> =
+    LazyLock::new(|| Mutex::new(notes_dir().map(|dir| load(&dir)).unwrap_or_default()));
+
+fn with_notes<T>(f: impl FnOnce(&mut HashMap<String, String>) -> T) -> T {
+    f(&mut NOTES.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// The note on `repo#number`, if any.
+pub(crate) fn get(repo: &str, number: u64) -> Option<String> {
+    with_notes(|notes| notes.get(&key(repo, number)).cloned())
+}
+
+/// Whether `repo#number` has a note.
+pub(crate) fn has(repo: &str, number: u64) -> bool {
+    with_notes(|notes| notes.contains_key(&key(repo, number)))
+}
+
+/// Open the note on `repo#number` in the editor, then keep what was saved:
+/// `Some` with the note, `None` when it was left blank (and then deleted).
+pub(crate) fn edit(repo: &str, number: u64) -> Result<Option<String>> {
+    let (owner, name) = repo
+        .split_once('/')
+        .with_context(|| format!("not an owner/repo name: {repo}"))?;
+    let dir = notes_dir()
+        .context("no state directory to keep notes in")?
+        .join(owner)
+        .join(name);
+    std::fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+    let path = dir.join(format!("{number}.md"));
+    crate::terminal::edit_file(&path)?;
+    let body = match std::fs::read_to_string(&path) {
+        Ok(body) => body,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+    };
+    let key = key(repo, number);
+    if body.trim().is_empty() {
+        let _ = std::fs::remove_file(&path);
+        with_notes(|notes| notes.remove(&key));
+        return Ok(None);
+    }
+    with_notes(|notes| notes.insert(key, body.clone()));
+    Ok(Some(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_indexes_numbered_markdown_files_per_repo() {
+        let dir = std::env::temp_dir().join(format!("gh-board-notes-{}", std::process::id()));
+        let repo = dir.join("acme").join("widgets");
+        std::fs::create_dir_all(&repo).unwrap();
+        std::fs::write(repo.join("42.md"), "- [ ] check the migration\n").unwrap();
+        std::fs::write(repo.join("43.md"), "  \n").unwrap();
+        std::fs::write(repo.join("notes.md"), "not an item\n").unwrap();
+        let notes = load(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes["acme/widgets#42"], "- [ ] check the migration\n");
+    }
+}
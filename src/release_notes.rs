@@ -0,0 +1,232 @@
+//! Release notes drafted from the pull requests merged since the last tag.
+//!
+//! `release_notes` in the Branches view finds the repo's latest tag with
+//! git, lists the PRs merged after its commit, and writes them grouped by
+//! label (`[release_notes]`) into a markdown draft opened in `$VISUAL` /
+//! `$EDITOR`. The draft's `# <tag>` heading names the tag of the draft
+//! release it can then create; the rest of the file is the release body.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+
+use crate::config::types::ReleaseNotesConfig;
+use crate::types::PullRequest;
+
+/// Most recent tag reachable from `HEAD`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LastTag {
+    pub name: String,
+    /// Commit date of the tagged commit.
+    pub date: DateTime<Utc>,
+}
+
+/// The latest tag of the repo at `repo_path`; `None` when it has none.
+pub(crate) fn last_tag(repo_path: &Path) -> Result<Option<LastTag>> {
+    let output = Command::new("git")
+        .args(["describe", "--tags", "--abbrev=0"])
+        .current_dir(repo_path)
+        .output()
+        .context("running git describe")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("No names found") || stderr.contains("No tags can describe") {
+            return Ok(None);
+        }
+        bail!("git describe: {}", stderr.trim());
+    }
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    let output = Command::new("git")
+        .args(["log", "-1", "--format=%cI", &name, "--"])
+        .current_dir(repo_path)
+        .output()
+        .context("running git log")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let date = DateTime::parse_from_rfc3339(stdout.trim())
+        .with_context(|| format!("reading the commit date of {name}"))?
+        .with_timezone(&Utc);
+    Ok(Some(LastTag { name, date }))
+}
+
+/// Search qualifiers for the PRs of `repo` merged after `since`.
+pub(crate) fn merged_since_query(repo: &str, since: Option<&LastTag>) -> String {
+    let mut query = format!("repo:{repo} is:pr is:merged");
+    if let Some(tag) = since {
+        let _ = write!(query, " merged:>{}", tag.date.format("%Y-%m-%dT%H:%M:%SZ"));
+    }
+    query
+}
+
+/// Configured sections, or Features / Fixes / Chores.
+fn sections(config: &ReleaseNotesConfig) -> Vec<(String, Vec<String>)> {
+    if !config.sections.is_empty() {
+        return config.sections.clone().into_iter().collect();
+    }
+    [
+        ("Features", &["feature", "enhancement"][..]),
+        ("Fixes", &["bug", "fix"]),
+        ("Chores", &["chore", "dependencies", "ci"]),
+    ]
+    .into_iter()
+    .map(|(title, labels)| {
+        let labels = labels.iter().map(|&l| l.to_owned()).collect();
+        (title.to_owned(), labels)
+    })
+    .collect()
+}
+
+/// Tag following `last`: a minor bump when the first section (features,
+/// by default) lists anything, a patch bump otherwise.
+fn next_tag(last: Option<&str>, minor: bool) -> String {
+    let Some(last) = last else {
+        return "v0.1.0".to_owned();
+    };
+    let digits = last
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(last.len());
+    let (prefix, version) = last.split_at(digits);
+    let parts: Vec<u64> = version.split('.').map_while(|p| p.parse().ok()).collect();
+    match parts[..] {
+        [major, minor_v, _] if minor => format!("{prefix}{major}.{}.0", minor_v + 1),
+        [major, minor_v, patch] => format!("{prefix}{major}.{minor_v}.{}", patch + 1),
+        // Not `x.y.z`: leave the name to the user.
+        _ => format!("{last}-next"),
+    }
+}
+
+/// Markdown draft for the release following `last` of `repo` (`owner/repo`).
+pub(crate) fn draft(
+    repo: &str,
+    last: Option<&str>,
+    prs: &[PullRequest],
+    config: &ReleaseNotesConfig,
+) -> String {
+    let has_label = |pr: &PullRequest, labels: &[String]| {
+        pr.labels
+            .iter()
+            .any(|l| labels.iter().any(|name| name.eq_ignore_ascii_case(&l.name)))
+    };
+    let excluded = config
+        .exclude_labels
+        .clone()
+        .unwrap_or_else(|| vec!["skip-changelog".to_owned()]);
+    let mut prs: Vec<&PullRequest> = prs.iter().filter(|pr| !has_label(pr, &excluded)).collect();
+    prs.sort_by_key(|pr| pr.number);
+
+    let sections = sections(config);
+    let mut grouped: Vec<Vec<&PullRequest>> = vec![Vec::new(); sections.len() + 1];
+    for pr in prs {
+        let section = sections
+            .iter()
+            .position(|(_, labels)| has_label(pr, labels))
+            .unwrap_or(sections.len());
+        grouped[section].push(pr);
+    }
+
+    let tag = next_tag(last, grouped.len() > 1 && !grouped[0].is_empty());
+    let other = config.other_section.as_deref().unwrap_or("Other changes");
+    let titles = sections.iter().map(|(t, _)| t.as_str()).chain([other]);
+    let mut out = format!("# {tag}\n");
+    for (title, prs) in titles.zip(&grouped) {
+        if prs.is_empty() {
+            continue;
+        }
+        let _ = write!(out, "\n## {title}\n\n");
+        for pr in prs {
+            let _ = write!(out, "- {} (#{})", pr.title.trim(), pr.number);
+            if let Some(author) = &pr.author {
+                let _ = write!(out, " @{}", author.login);
+            }
+            out.push('\n');
+        }
+    }
+    if let Some(last) = last {
+        let _ = write!(
+            out,
+            "\n**Full changelog**: https://github.com/{repo}/compare/{last}...{tag}\n"
+        );
+    }
+    out
+}
+
+/// Tag and body of a saved draft, whose first line is the `# <tag>`
+/// heading; `None` when the heading is missing.
+pub(crate) fn parse_draft(text: &str) -> Option<(String, String)> {
+    let text = text.trim_start();
+    let (first, body) = text.split_once('\n').unwrap_or((text, ""));
+    let tag = first.strip_prefix("# ")?.trim();
+    (!tag.is_empty() && !tag.contains(char::is_whitespace))
+        .then(|| (tag.to_owned(), body.trim().to_owned()))
+}
+
+/// Where the draft for `repo` (`owner/repo`) is written.
+pub(crate) fn draft_path(repo: &str) -> Option<PathBuf> {
+    crate::util::state_path(&format!("release-notes/{}.md", repo.replace('/', "-")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(number: u64, title: &str, labels: &[&str]) -> PullRequest {
+        serde_json::from_value(serde_json::json!({
+            "number": number,
+            "title": title,
+            "author": {"login": "octo", "avatar_url": ""},
+            "state": "MERGED",
+            "labels": labels.iter().map(|n| serde_json::json!({"name": n, "color": "fff"})).collect::<Vec<_>>(),
+            "updated_at": "2024-05-02T10:00:00Z",
+            "created_at": "2024-04-20T10:00:00Z",
+            "url": format!("https://github.com/acme/app/pull/{number}"),
+            "repo": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn draft_groups_prs_by_first_matching_section() {
+        let prs = [
+            pr(12, "Fix crash on empty list", &["bug"]),
+            pr(10, "Add dark mode", &["Enhancement", "bug"]),
+            pr(11, "Bump serde", &["dependencies"]),
+            pr(13, "Tidy README", &[]),
+            pr(14, "Internal refactor", &["skip-changelog", "feature"]),
+        ];
+        let text = draft(
+            "acme/app",
+            Some("v1.2.3"),
+            &prs,
+            &ReleaseNotesConfig::default(),
+        );
+        assert_eq!(
+            text,
+            "# v1.3.0\n\
+             \n## Features\n\n- Add dark mode (#10) @octo\n\
+             \n## Fixes\n\n- Fix crash on empty list (#12) @octo\n\
+             \n## Chores\n\n- Bump serde (#11) @octo\n\
+             \n## Other changes\n\n- Tidy README (#13) @octo\n\
+             \n**Full changelog**: https://github.com/acme/app/compare/v1.2.3...v1.3.0\n"
+        );
+    }
+
+    #[test]
+    fn next_tag_bumps_minor_for_features_and_patch_otherwise() {
+        assert_eq!(next_tag(Some("v1.2.3"), false), "v1.2.4");
+        assert_eq!(next_tag(Some("1.2.3"), true), "1.3.0");
+        assert_eq!(next_tag(Some("v2.0.0-rc.1"), false), "v2.0.0-rc.1-next");
+        assert_eq!(next_tag(None, true), "v0.1.0");
+    }
+
+    #[test]
+    fn parse_draft_splits_heading_tag_from_body() {
+        assert_eq!(
+            parse_draft("\n# v1.3.0\n\n## Fixes\n- x\n"),
+            Some(("v1.3.0".to_owned(), "## Fixes\n- x".to_owned()))
+        );
+        assert_eq!(parse_draft("## Fixes\n- x\n"), None);
+        assert_eq!(parse_draft("# version one\n"), None);
+    }
+}
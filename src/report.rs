@@ -0,0 +1,272 @@
+//! `gh-board report`: a markdown summary of your activity over a period.
+//!
+//! The report is built from the same searches the PR and Issues views run,
+//! sent through the engine once each and rendered as a standup-ready list.
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+
+use crate::config::types::{AppConfig, IssueFilter, IssueFilterKind, PrFilter};
+use crate::engine::{Engine, EngineHandle, Event, GitHubEngine, Request, event_channel};
+use crate::types::{Issue, PullRequest, RepoRef};
+
+/// How long to wait for all searches before giving up.
+const REPORT_TIMEOUT: Duration = Duration::from_mins(2);
+
+/// Items fetched per section.
+const SECTION_LIMIT: u32 = 100;
+
+/// Everything the report lists, one entry per section.
+#[derive(Debug, Default)]
+pub struct ReportData {
+    pub merged: Vec<PullRequest>,
+    /// PRs by others you reviewed that saw activity in the period.
+    pub reviewed: Vec<PullRequest>,
+    /// Closed issues assigned to you.
+    pub closed: Vec<Issue>,
+    /// Issues and PRs you commented on that saw activity in the period.
+    pub commented: Vec<Issue>,
+}
+
+/// The days a report covers, both ends included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportPeriod {
+    pub since: NaiveDate,
+    pub until: NaiveDate,
+}
+
+impl ReportPeriod {
+    /// `since` to `until` (default: today), or the `days` days up to `until`.
+    pub fn new(days: u32, since: Option<NaiveDate>, until: Option<NaiveDate>) -> Result<Self> {
+        let until = until.unwrap_or_else(crate::util::today);
+        let since =
+            since.unwrap_or_else(|| until - chrono::Days::new(u64::from(days.saturating_sub(1))));
+        if since > until {
+            bail!("report period starts ({since}) after it ends ({until})");
+        }
+        Ok(Self { since, until })
+    }
+
+    /// Search qualifier value matching the period, e.g. `2024-05-01..2024-05-07`.
+    fn range(self) -> String {
+        format!("{}..{}", self.since, self.until)
+    }
+}
+
+/// Search queries for each section, restricted to `period`.
+fn section_queries(period: ReportPeriod) -> [String; 4] {
+    let range = period.range();
+    [
+        format!("is:pr author:@me is:merged merged:{range}"),
+        format!("is:pr reviewed-by:@me -author:@me updated:{range}"),
+        format!("is:issue is:closed assignee:@me closed:{range}"),
+        format!("commenter:@me updated:{range}"),
+    ]
+}
+
+/// Fetch the report data and write the markdown to `output`, or stdout.
+pub fn run(config: AppConfig, period: ReportPeriod, output: Option<&Path>) -> Result<()> {
+    let engine = GitHubEngine::new(config).start();
+    let result = fetch(&engine, period);
+    engine.send(Request::Shutdown);
+    let markdown = render(period, &result?);
+    match output {
+        Some(path) => {
+            std::fs::write(path, &markdown)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            println!("Report written to {}", path.display());
+        }
+        None => print!("{markdown}"),
+    }
+    Ok(())
+}
+
+fn fetch(engine: &EngineHandle, period: ReportPeriod) -> Result<ReportData> {
+    let (reply_tx, reply_rx) = event_channel();
+    let [merged, reviewed, closed, commented] = section_queries(period);
+    let pr_filter = |filters| PrFilter {
+        title: String::new(),
+        filters,
+        limit: Some(SECTION_LIMIT),
+        host: None,
+        layout: None,
+        hide_drafts: None,
+        preview: None,
+        preview_tab: None,
+        refetch_interval_minutes: None,
+        badge: None,
+    };
+    let issue_filter = |filters, kind| IssueFilter {
+        title: String::new(),
+        filters,
+        limit: Some(SECTION_LIMIT),
+        host: None,
+        layout: None,
+        kind,
+        preview: None,
+        preview_tab: None,
+        refetch_interval_minutes: None,
+        badge: None,
+        board: None,
+    };
+    for (filter_idx, filters) in [merged, reviewed].into_iter().enumerate() {
+        engine.send(Request::FetchPrs {
+            filter_idx,
+            filter: pr_filter(filters),
+            force: true,
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    for (filter_idx, filter) in [
+        (2, issue_filter(closed, IssueFilterKind::Issue)),
+        (3, issue_filter(commented, IssueFilterKind::Any)),
+    ] {
+        engine.send(Request::FetchIssues {
+            filter_idx,
+            filter,
+            force: true,
+            reply_tx: reply_tx.clone(),
+        });
+    }
+
+    let mut data = ReportData::default();
+    let mut pending = 4;
+    let deadline = smol::Timer::after(REPORT_TIMEOUT);
+    smol::block_on(async {
+        let recv_all = async {
+            while pending > 0 {
+                let Ok(event) = reply_rx.recv().await else {
+                    bail!("engine stopped");
+                };
+                match event {
+                    Event::PrsFetched {
+                        filter_idx,
+                        prs,
+                        complete: true,
+                        ..
+                    } => {
+                        pending -= 1;
+                        if filter_idx == 0 {
+                            data.merged = prs;
+                        } else {
+                            data.reviewed = prs;
+                        }
+                    }
+                    Event::IssuesFetched {
+                        filter_idx,
+                        issues,
+                        complete: true,
+                        ..
+                    } => {
+                        pending -= 1;
+                        if filter_idx == 2 {
+                            data.closed = issues;
+                        } else {
+                            data.commented = issues;
+                        }
+                    }
+                    Event::FetchError { message, .. } => bail!(message),
+                    _ => {}
+                }
+            }
+            Ok(())
+        };
+        smol::future::or(recv_all, async {
+            deadline.await;
+            bail!("timed out after {}s", REPORT_TIMEOUT.as_secs())
+        })
+        .await
+    })?;
+    Ok(data)
+}
+
+/// Render the report as markdown.
+pub fn render(period: ReportPeriod, data: &ReportData) -> String {
+    let mut out = format!("# Activity report: {} to {}\n", period.since, period.until);
+    let pr_line = |pr: &PullRequest| item_line(pr.repo.as_ref(), pr.number, &pr.title, &pr.url);
+    let issue_line = |i: &Issue| item_line(i.repo.as_ref(), i.number, &i.title, &i.url);
+    section(
+        &mut out,
+        "Merged pull requests",
+        data.merged.iter().map(pr_line),
+    );
+    section(&mut out, "Reviews given", data.reviewed.iter().map(pr_line));
+    section(
+        &mut out,
+        "Issues closed",
+        data.closed.iter().map(issue_line),
+    );
+    section(
+        &mut out,
+        "Commented on",
+        data.commented.iter().map(issue_line),
+    );
+    out
+}
+
+fn section(out: &mut String, title: &str, lines: impl ExactSizeIterator<Item = String>) {
+    let _ = write!(out, "\n## {title} ({})\n\n", lines.len());
+    if lines.len() == 0 {
+        out.push_str("_None_\n");
+    }
+    for line in lines {
+        let _ = writeln!(out, "- {line}");
+    }
+}
+
+fn item_line(repo: Option<&RepoRef>, number: u64, title: &str, url: &str) -> String {
+    let repo = repo.map(RepoRef::full_name).unwrap_or_default();
+    format!("[{repo}#{number}]({url}) {title}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn period_covers_days_up_to_until() {
+        let period = ReportPeriod::new(7, None, Some(date("2024-05-07"))).unwrap();
+        assert_eq!(period.since, date("2024-05-01"));
+        assert_eq!(
+            section_queries(period)[0],
+            "is:pr author:@me is:merged merged:2024-05-01..2024-05-07"
+        );
+        assert!(ReportPeriod::new(7, Some(date("2024-05-08")), Some(date("2024-05-07"))).is_err());
+    }
+
+    #[test]
+    fn render_lists_each_section() {
+        let period = ReportPeriod {
+            since: date("2024-05-01"),
+            until: date("2024-05-07"),
+        };
+        let issue: Issue = serde_json::from_value(serde_json::json!({
+            "number": 12,
+            "title": "Fix the widget",
+            "state": "CLOSED",
+            "updated_at": "2024-05-02T10:00:00Z",
+            "created_at": "2024-04-20T10:00:00Z",
+            "url": "https://github.com/o/r/issues/12",
+            "repo": {"owner": "o", "name": "r"},
+        }))
+        .unwrap();
+        let data = ReportData {
+            closed: vec![issue],
+            ..ReportData::default()
+        };
+        let md = render(period, &data);
+        assert!(md.starts_with("# Activity report: 2024-05-01 to 2024-05-07\n"));
+        assert!(md.contains(
+            "## Issues closed (1)\n\n- [o/r#12](https://github.com/o/r/issues/12) Fix the widget\n"
+        ));
+        assert!(md.contains("## Merged pull requests (0)\n\n_None_\n"));
+    }
+}
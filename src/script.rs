@@ -0,0 +1,631 @@
+//! Scripted key bindings.
+//!
+//! A `[[keybindings.*]]` entry with `script = "~/.config/gh-board/triage.rhai"`
+//! runs that [Rhai](https://rhai.rs) script on its own thread when the key is
+//! pressed. The script sees the selected row as the `item` map and the view
+//! as `view`, and gets a small API:
+//!
+//! - `comment(body)`, `set_labels(labels)`, `set_assignees(logins)`,
+//!   `close()`, `reopen()`, and on PRs `approve()` and `merge()`, each acting
+//!   on `item` or on an item map passed first (`close(found[0])`);
+//! - `search_prs(query)` and `search_issues(query)`, returning item maps;
+//! - `confirm(message)`, `pick(title, options)` and `input(prompt)`, asking
+//!   in an overlay (`pick` and `input` return `""` when cancelled);
+//! - `notify(message)` (and `print`) for the footer, and `shell(command)`.
+//!
+//! A failed call stops the script with its error in the footer, unless the
+//! script catches it. One script runs at a time.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use rhai::{Array, Dynamic, EvalAltResult, Map, Scope};
+
+use crate::components::footer::ActionFeedback;
+use crate::config::keybindings::TemplateVars;
+use crate::config::types::MergeMethod;
+use crate::engine::{EngineHandle, Event, Request, event_channel};
+use crate::theme::Chrome;
+
+/// How long the last script message stays in the footer.
+const STATUS_TTL: Duration = Duration::from_secs(10);
+
+/// Items a search returns.
+const SEARCH_LIMIT: u32 = 50;
+
+type CallResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// What a script asks the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Ask {
+    /// Yes or no; answered `Some` for yes.
+    Confirm(String),
+    /// One of `options`, filtered as typed.
+    Pick { title: String, options: Vec<String> },
+    /// Free text.
+    Input(String),
+}
+
+/// A question from a running script, answered from the app's overlay.
+#[derive(Clone)]
+pub(crate) struct Prompt {
+    pub(crate) ask: Ask,
+    reply: async_channel::Sender<Option<String>>,
+}
+
+impl Prompt {
+    /// A prompt asking `ask`, with the receiver of its answer.
+    pub(crate) fn new(ask: Ask) -> (Self, async_channel::Receiver<Option<String>>) {
+        let (reply, answer) = async_channel::bounded(1);
+        (Self { ask, reply }, answer)
+    }
+
+    /// Answer the script; `None` cancels.
+    pub(crate) fn answer(&self, answer: Option<String>) {
+        let _ = self.reply.try_send(answer);
+    }
+}
+
+/// What the app hears from scripts.
+pub(crate) enum Message {
+    Prompt(Prompt),
+    /// The footer status changed.
+    Redraw,
+}
+
+static MESSAGES: LazyLock<(
+    async_channel::Sender<Message>,
+    async_channel::Receiver<Message>,
+)> = LazyLock::new(async_channel::unbounded);
+
+/// The next message from a script, `None` once none can come.
+pub(crate) async fn next_message() -> Option<Message> {
+    MESSAGES.1.recv().await.ok()
+}
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Footer status, with the instant it expires (`None` while the script runs).
+static STATUS: Mutex<Option<(ActionFeedback, Option<Instant>)>> = Mutex::new(None);
+
+fn set_status(status: Option<(ActionFeedback, Option<Instant>)>) {
+    *STATUS.lock().unwrap_or_else(PoisonError::into_inner) = status;
+    let _ = MESSAGES.0.try_send(Message::Redraw);
+}
+
+/// Footer line of the running or last script.
+pub(crate) fn footer_status() -> Option<ActionFeedback> {
+    STATUS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .filter(|(_, expiry)| expiry.is_none_or(|at| Instant::now() < at))
+        .map(|(status, _)| status.clone())
+}
+
+fn notify(message: &str) {
+    set_status(Some((
+        ActionFeedback::Info(message.to_owned()),
+        Some(Instant::now() + STATUS_TTL),
+    )));
+}
+
+fn ask(ask: Ask) -> Option<String> {
+    let (prompt, answer) = Prompt::new(ask);
+    MESSAGES.0.try_send(Message::Prompt(prompt)).ok()?;
+    answer.recv_blocking().ok().flatten()
+}
+
+/// Run the script at `path` for the row in `vars` of the view bound as
+/// `view` (`prs`, `issues`, …). Returns at once; the script reports in the
+/// footer.
+pub(crate) fn run(
+    path: &str,
+    view: &'static str,
+    vars: &TemplateVars,
+    engine: Option<EngineHandle>,
+    chrome: Chrome,
+) -> Option<ActionFeedback> {
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return Some(ActionFeedback::Info(
+            "A script is already running".to_owned(),
+        ));
+    }
+    let path = crate::config::loader::expand_tilde(path);
+    let name = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    let vars = vars.clone();
+    set_status(Some((
+        ActionFeedback::Info(format!(
+            "Running {name}{}",
+            crate::components::ellipsis(chrome)
+        )),
+        None,
+    )));
+    std::thread::spawn(move || {
+        let result = run_file(&path, view, item_map(view, &vars), engine);
+        match result {
+            Ok(()) => {
+                let running = matches!(
+                    *STATUS.lock().unwrap_or_else(PoisonError::into_inner),
+                    Some((_, None))
+                );
+                if running {
+                    set_status(None);
+                }
+            }
+            Err(e) => set_status(Some((
+                ActionFeedback::Error(format!("{name}: {e}")),
+                Some(Instant::now() + STATUS_TTL),
+            ))),
+        }
+        RUNNING.store(false, Ordering::SeqCst);
+    });
+    None
+}
+
+fn run_file(path: &Path, view: &str, item: Map, engine: Option<EngineHandle>) -> CallResult<()> {
+    let rhai = script_engine(engine, &item);
+    let mut scope = scope(view, item);
+    rhai.run_file_with_scope(&mut scope, path.to_path_buf())
+}
+
+fn scope(view: &str, item: Map) -> Scope<'static> {
+    let mut scope = Scope::new();
+    scope.push_constant("view", view.to_owned());
+    scope.push_constant("item", item);
+    scope
+}
+
+/// `item` of a row: its non-empty template variables, `number` as an
+/// integer, and `kind` (`pr` or `issue`) in the PR and Issue views.
+fn item_map(view: &str, vars: &TemplateVars) -> Map {
+    let mut map = Map::new();
+    let kind = match view {
+        "prs" => "pr",
+        "issues" => "issue",
+        _ => "",
+    };
+    let fields = [
+        ("kind", kind),
+        ("url", vars.url.as_str()),
+        ("title", &vars.title),
+        ("repo", &vars.repo_name),
+        ("state", &vars.state),
+        ("head_branch", &vars.head_branch),
+        ("base_branch", &vars.base_branch),
+        ("run_id", &vars.run_id),
+        ("run_name", &vars.run_name),
+        ("run_number", &vars.run_number),
+        ("conclusion", &vars.conclusion),
+    ];
+    for (key, value) in fields {
+        if !value.is_empty() {
+            map.insert(key.into(), value.to_owned().into());
+        }
+    }
+    if let Ok(number) = vars.number.parse::<i64>() {
+        map.insert("number".into(), number.into());
+    }
+    map
+}
+
+/// A PR or issue to act on, read from an item map.
+struct Target {
+    pr: bool,
+    owner: String,
+    repo: String,
+    number: u64,
+    /// From the item's URL; `None` for github.com.
+    host: Option<String>,
+}
+
+fn target(item: &Map) -> CallResult<Target> {
+    let field = |key: &str| item.get(key).map(Dynamic::to_string).unwrap_or_default();
+    let pr = match field("kind").as_str() {
+        "pr" => true,
+        "issue" => false,
+        _ => return Err("this needs a PR or an issue".into()),
+    };
+    let repo = field("repo");
+    let (owner, name) = repo
+        .split_once('/')
+        .ok_or_else(|| format!("not an owner/repo name: {repo:?}"))?;
+    let number = item
+        .get("number")
+        .and_then(|n| n.as_int().ok())
+        .and_then(|n| u64::try_from(n).ok())
+        .ok_or("the item has no number")?;
+    Ok(Target {
+        pr,
+        owner: owner.to_owned(),
+        repo: name.to_owned(),
+        number,
+        host: crate::url::host_of(&field("url")),
+    })
+}
+
+fn strings(values: &Array) -> Vec<String> {
+    values.iter().map(Dynamic::to_string).collect()
+}
+
+/// Blocking access to the engine for one script.
+#[derive(Clone)]
+struct Api {
+    engine: Option<EngineHandle>,
+}
+
+impl Api {
+    fn engine(&self) -> CallResult<&EngineHandle> {
+        self.engine
+            .as_ref()
+            .ok_or_else(|| "not connected to GitHub".into())
+    }
+
+    /// Send the mutation `make` builds and wait until it is done.
+    fn mutate(&self, make: impl FnOnce(crate::engine::EventSender) -> Request) -> CallResult<()> {
+        let engine = self.engine()?;
+        let (reply_tx, events) = event_channel();
+        engine.send(make(reply_tx));
+        while let Ok(event) = events.recv_blocking() {
+            match event {
+                Event::MutationOk { .. } => return Ok(()),
+                Event::MutationError {
+                    description,
+                    message,
+                } => return Err(format!("{description}: {message}").into()),
+                _ => {}
+            }
+        }
+        Err("gh-board is shutting down".into())
+    }
+
+    fn comment(&self, item: &Map, body: String) -> CallResult<()> {
+        let t = target(item)?;
+        self.mutate(|reply_tx| {
+            if t.pr {
+                Request::AddPrComment {
+                    owner: t.owner,
+                    repo: t.repo,
+                    number: t.number,
+                    host: t.host,
+                    body,
+                    reply_tx,
+                }
+            } else {
+                Request::AddIssueComment {
+                    owner: t.owner,
+                    repo: t.repo,
+                    number: t.number,
+                    host: t.host,
+                    body,
+                    reply_tx,
+                }
+            }
+        })
+    }
+
+    fn set_labels(&self, item: &Map, labels: &Array) -> CallResult<()> {
+        let t = target(item)?;
+        let labels = strings(labels);
+        self.mutate(|reply_tx| {
+            if t.pr {
+                Request::SetPrLabels {
+                    owner: t.owner,
+                    repo: t.repo,
+                    number: t.number,
+                    host: t.host,
+                    labels,
+                    reply_tx,
+                }
+            } else {
+                Request::SetIssueLabels {
+                    owner: t.owner,
+                    repo: t.repo,
+                    number: t.number,
+                    host: t.host,
+                    labels,
+                    reply_tx,
+                }
+            }
+        })
+    }
+
+    fn set_assignees(&self, item: &Map, logins: &Array) -> CallResult<()> {
+        let t = target(item)?;
+        let logins = strings(logins);
+        self.mutate(|reply_tx| {
+            if t.pr {
+                Request::SetPrAssignees {
+                    owner: t.owner,
+                    repo: t.repo,
+                    number: t.number,
+                    host: t.host,
+                    logins,
+                    reply_tx,
+                }
+            } else {
+                Request::SetIssueAssignees {
+                    owner: t.owner,
+                    repo: t.repo,
+                    number: t.number,
+                    host: t.host,
+                    logins,
+                    reply_tx,
+                }
+            }
+        })
+    }
+
+    fn set_open(&self, item: &Map, open: bool) -> CallResult<()> {
+        let t = target(item)?;
+        let (owner, repo, number, host) = (t.owner, t.repo, t.number, t.host);
+        self.mutate(|reply_tx| match (t.pr, open) {
+            (true, true) => Request::ReopenPr {
+                owner,
+                repo,
+                number,
+                host,
+                reply_tx,
+            },
+            (true, false) => Request::ClosePr {
+                owner,
+                repo,
+                number,
+                host,
+                reply_tx,
+            },
+            (false, true) => Request::ReopenIssue {
+                owner,
+                repo,
+                number,
+                host,
+                reply_tx,
+            },
+            (false, false) => Request::CloseIssue {
+                owner,
+                repo,
+                number,
+                host,
+                reply_tx,
+            },
+        })
+    }
+
+    fn pr_target(item: &Map) -> CallResult<Target> {
+        let t = target(item)?;
+        if t.pr {
+            Ok(t)
+        } else {
+            Err("this needs a PR".into())
+        }
+    }
+
+    fn approve(&self, item: &Map) -> CallResult<()> {
+        let t = Self::pr_target(item)?;
+        self.mutate(|reply_tx| Request::ApprovePr {
+            owner: t.owner,
+            repo: t.repo,
+            number: t.number,
+            host: t.host,
+            body: None,
+            reply_tx,
+        })
+    }
+
+    fn merge(&self, item: &Map) -> CallResult<()> {
+        let t = Self::pr_target(item)?;
+        self.mutate(|reply_tx| Request::MergePr {
+            owner: t.owner,
+            repo: t.repo,
+            number: t.number,
+            method: MergeMethod::default(),
+            host: t.host,
+            reply_tx,
+        })
+    }
+
+    fn search_prs(&self, query: String) -> CallResult<Array> {
+        let engine = self.engine()?;
+        let (reply_tx, events) = event_channel();
+        engine.send(Request::SearchPrs {
+            query,
+            limit: SEARCH_LIMIT,
+            host: None,
+            reply_tx,
+        });
+        loop {
+            if let Event::PrsFetched {
+                prs,
+                complete: true,
+                ..
+            } = wait_for_fetch(&events)?
+            {
+                return Ok(prs
+                    .iter()
+                    .map(|pr| {
+                        let mut map = found(
+                            "pr",
+                            pr.repo
+                                .as_ref()
+                                .map(crate::github::types::RepoRef::full_name),
+                            (pr.number, &pr.title, &pr.url),
+                        );
+                        map.insert("state".into(), crate::share::pr_state(pr).into());
+                        map.insert(
+                            "author".into(),
+                            pr.author.as_ref().map_or("", |a| &a.login).into(),
+                        );
+                        map.insert("head_branch".into(), pr.head_ref.clone().into());
+                        map.insert("base_branch".into(), pr.base_ref.clone().into());
+                        map.insert("labels".into(), label_names(&pr.labels));
+                        map.into()
+                    })
+                    .collect());
+            }
+        }
+    }
+
+    fn search_issues(&self, query: String) -> CallResult<Array> {
+        let engine = self.engine()?;
+        let (reply_tx, events) = event_channel();
+        engine.send(Request::SearchIssues {
+            query,
+            limit: SEARCH_LIMIT,
+            host: None,
+            reply_tx,
+        });
+        loop {
+            if let Event::IssuesFetched {
+                issues,
+                complete: true,
+                ..
+            } = wait_for_fetch(&events)?
+            {
+                return Ok(issues
+                    .iter()
+                    .map(|issue| {
+                        let mut map = found(
+                            "issue",
+                            issue
+                                .repo
+                                .as_ref()
+                                .map(crate::github::types::RepoRef::full_name),
+                            (issue.number, &issue.title, &issue.url),
+                        );
+                        map.insert("state".into(), crate::share::issue_state(issue).into());
+                        map.insert(
+                            "author".into(),
+                            issue.author.as_ref().map_or("", |a| &a.login).into(),
+                        );
+                        map.insert("labels".into(), label_names(&issue.labels));
+                        map.into()
+                    })
+                    .collect());
+            }
+        }
+    }
+}
+
+/// The next event of a list fetch, failing on its errors.
+fn wait_for_fetch(events: &async_channel::Receiver<Event>) -> CallResult<Event> {
+    match events.recv_blocking() {
+        Ok(Event::FetchError { message, .. }) => Err(message.into()),
+        Ok(Event::FetchTimedOut { secs, .. }) => {
+            Err(format!("search timed out after {secs}s").into())
+        }
+        Ok(Event::FetchCancelled { .. }) | Err(_) => Err("search cancelled".into()),
+        Ok(event) => Ok(event),
+    }
+}
+
+/// Item map of a search result.
+fn found(kind: &str, repo: Option<String>, (number, title, url): (u64, &str, &str)) -> Map {
+    let mut map = Map::new();
+    map.insert("kind".into(), kind.into());
+    map.insert("repo".into(), repo.unwrap_or_default().into());
+    map.insert(
+        "number".into(),
+        i64::try_from(number).unwrap_or_default().into(),
+    );
+    map.insert("title".into(), title.into());
+    map.insert("url".into(), url.into());
+    map
+}
+
+fn label_names(labels: &[crate::types::Label]) -> Dynamic {
+    labels
+        .iter()
+        .map(|l| Dynamic::from(l.name.clone()))
+        .collect::<Array>()
+        .into()
+}
+
+/// The engine running scripts for the row `item`.
+fn script_engine(engine: Option<EngineHandle>, item: &Map) -> rhai::Engine {
+    let mut rhai = rhai::Engine::new();
+    let api = Api { engine };
+    rhai.on_print(notify);
+    rhai.on_debug(|text, source, pos| tracing::debug!(?source, %pos, "script: {text}"));
+    rhai.register_fn("notify", notify);
+    rhai.register_fn("confirm", |message: &str| {
+        ask(Ask::Confirm(message.to_owned())).is_some()
+    });
+    rhai.register_fn("pick", |title: &str, options: Array| {
+        ask(Ask::Pick {
+            title: title.to_owned(),
+            options: strings(&options),
+        })
+        .unwrap_or_default()
+    });
+    rhai.register_fn("input", |prompt: &str| {
+        ask(Ask::Input(prompt.to_owned())).unwrap_or_default()
+    });
+    rhai.register_fn("shell", |command: &str| -> CallResult<String> {
+        crate::config::keybindings::execute_shell_command(command)
+            .map_err(|e| format!("{e:#}").into())
+    });
+
+    macro_rules! on_item {
+        ($name:literal, |$api:ident, $item:ident $(, $arg:ident: $ty:ty)*| $body:expr) => {{
+            let a = api.clone();
+            rhai.register_fn($name, move |$item: Map $(, $arg: $ty)*| -> CallResult<()> {
+                let $api = &a;
+                let $item = &$item;
+                $body
+            });
+            let (a, current) = (api.clone(), item.clone());
+            rhai.register_fn($name, move |$($arg: $ty),*| -> CallResult<()> {
+                let $api = &a;
+                let $item = &current;
+                $body
+            });
+        }};
+    }
+    on_item!("comment", |api, item, body: String| api.comment(item, body));
+    on_item!("set_labels", |api, item, labels: Array| api
+        .set_labels(item, &labels));
+    on_item!("set_assignees", |api, item, logins: Array| api
+        .set_assignees(item, &logins));
+    on_item!("close", |api, item| api.set_open(item, false));
+    on_item!("reopen", |api, item| api.set_open(item, true));
+    on_item!("approve", |api, item| api.approve(item));
+    on_item!("merge", |api, item| api.merge(item));
+
+    let a = api.clone();
+    rhai.register_fn("search_prs", move |query: String| a.search_prs(query));
+    rhai.register_fn("search_issues", move |query: String| {
+        api.search_issues(query)
+    });
+    rhai
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_map_exposes_the_row_to_scripts() {
+        let vars = TemplateVars {
+            number: "42".to_owned(),
+            title: "Fix the flux".to_owned(),
+            repo_name: "acme/widgets".to_owned(),
+            ..Default::default()
+        };
+        let mut scope = scope("prs", item_map("prs", &vars));
+        let text: String = rhai::Engine::new()
+            .eval_with_scope(
+                &mut scope,
+                r#"`${view}: ${item.kind} ${item.repo}#${item.number + 1} ${"url" in item}`"#,
+            )
+            .unwrap();
+        assert_eq!(text, "prs: pr acme/widgets#43 false");
+        let t = target(&item_map("prs", &vars)).ok().unwrap();
+        assert_eq!((t.pr, t.owner.as_str(), t.number), (true, "acme", 42));
+        assert!(target(&item_map("actions", &vars)).is_err());
+    }
+}
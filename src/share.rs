@@ -0,0 +1,132 @@
+//! Posting the selected PR or issue to a chat webhook (`share`).
+//!
+//! Each `[[share_targets]]` entry names an incoming webhook and the message
+//! to post, expanded like the keybinding commands. Slack-compatible hooks
+//! (Slack, Mattermost, Google Chat) read `text`, Discord reads `content`;
+//! `generic` targets also get the item's fields to build their own message.
+
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result, bail};
+use serde_json::{Value, json};
+
+use crate::config::keybindings::{TemplateVars, expand_template};
+use crate::config::types::{ShareFormat, ShareTarget};
+use crate::types::{Issue, IssueState, PrState, PullRequest};
+
+/// Message of targets without a `template`.
+const DEFAULT_TEMPLATE: &str = "{{.RepoName}}#{{.Number}} {{.Title}} ({{.State}})\n{{.Url}}";
+
+static TARGETS: OnceLock<Vec<ShareTarget>> = OnceLock::new();
+
+/// Set the `[[share_targets]]`. Only the first call takes effect.
+pub fn set_share_targets(targets: Vec<ShareTarget>) {
+    let _ = TARGETS.set(targets);
+}
+
+/// The configured share targets, in file order.
+pub(crate) fn targets() -> &'static [ShareTarget] {
+    TARGETS.get().map_or(&[], Vec::as_slice)
+}
+
+/// Message posted to `target` for the item described by `vars`.
+pub(crate) fn message(target: &ShareTarget, vars: &TemplateVars) -> String {
+    let template = target.template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+    expand_template(template, vars)
+}
+
+/// `{{.State}}` of a PR.
+pub(crate) fn pr_state(pr: &PullRequest) -> &'static str {
+    match pr.state {
+        PrState::Open if pr.is_draft => "draft",
+        PrState::Open => "open",
+        PrState::Closed => "closed",
+        PrState::Merged => "merged",
+    }
+}
+
+/// `{{.State}}` of an issue.
+pub(crate) fn issue_state(issue: &Issue) -> &'static str {
+    match issue.state {
+        IssueState::Open => "open",
+        IssueState::Closed => "closed",
+        IssueState::Unknown => "unknown",
+    }
+}
+
+/// JSON body posted to `target`.
+pub(crate) fn payload(target: &ShareTarget, vars: &TemplateVars) -> Value {
+    let text = message(target, vars);
+    match target.format.unwrap_or(ShareFormat::Slack) {
+        ShareFormat::Slack => json!({ "text": text }),
+        ShareFormat::Discord => json!({ "content": text }),
+        ShareFormat::Generic => json!({
+            "text": text,
+            "title": vars.title,
+            "url": vars.url,
+            "number": vars.number,
+            "repo": vars.repo_name,
+            "state": vars.state,
+        }),
+    }
+}
+
+/// POST `payload` to `webhook`.
+///
+/// Through a client of its own rather than the engine's GitHub clients, so
+/// that no token can reach the hook's host.
+pub(crate) async fn post(webhook: &str, payload: &Value) -> Result<()> {
+    let client = octocrab::Octocrab::builder()
+        .build()
+        .context("building the webhook client")?;
+    let response = client
+        ._post(webhook, Some(payload))
+        .await
+        .context("posting to the webhook")?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!("webhook answered {status}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(format: Option<ShareFormat>, template: Option<&str>) -> ShareTarget {
+        ShareTarget {
+            name: "team".to_owned(),
+            webhook: "https://hooks.example.com/T0".to_owned(),
+            format,
+            template: template.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn payload_follows_the_target_format() {
+        let vars = TemplateVars {
+            url: "https://github.com/acme/app/pull/7".to_owned(),
+            number: "7".to_owned(),
+            title: "Fix layout".to_owned(),
+            repo_name: "acme/app".to_owned(),
+            state: "open".to_owned(),
+            ..TemplateVars::default()
+        };
+        assert_eq!(
+            payload(&target(None, None), &vars),
+            json!({ "text": "acme/app#7 Fix layout (open)\nhttps://github.com/acme/app/pull/7" })
+        );
+        assert_eq!(
+            payload(
+                &target(Some(ShareFormat::Discord), Some("Please review {{.Url}}")),
+                &vars
+            ),
+            json!({ "content": "Please review https://github.com/acme/app/pull/7" })
+        );
+        let generic = payload(&target(Some(ShareFormat::Generic), Some("x")), &vars);
+        assert_eq!(generic["text"], "x");
+        assert_eq!(generic["repo"], "acme/app");
+        assert_eq!(generic["state"], "open");
+    }
+}
@@ -0,0 +1,116 @@
+//! Smart scope: follow the directory worked in outside gh-board.
+//!
+//! gh-board's own directory is fixed once started, so the one you `cd` to
+//! elsewhere is read from the `cwd` state file a shell hook keeps current
+//! (`$XDG_STATE_HOME/gh-board/cwd`), else, inside tmux, from the previously
+//! active pane. With `github.smart_scope`, a repo detected there that is not
+//! the scoped one is offered in every footer ([`footer_text`]) until the
+//! `rescan_repo` key switches to it.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::github::types::RepoRef;
+
+/// How often the working directory is checked.
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A repo offered as the new scope, with the key taking it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Offer {
+    repo: String,
+    key: String,
+}
+
+static OFFER: Mutex<Option<Offer>> = Mutex::new(None);
+
+fn with_offer<T>(f: impl FnOnce(&mut Option<Offer>) -> T) -> T {
+    f(&mut OFFER.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// The existing directory named by `text`, trailing newline ignored.
+fn parse_dir(text: &str) -> Option<PathBuf> {
+    let path = Path::new(text.trim_end_matches(['\n', '\r']));
+    (!path.as_os_str().is_empty() && path.is_dir()).then(|| path.to_path_buf())
+}
+
+fn cwd_file_dir() -> Option<PathBuf> {
+    let text = std::fs::read_to_string(crate::util::state_path("cwd")?).ok()?;
+    parse_dir(&text)
+}
+
+/// Directory of the pane active before gh-board's, inside tmux.
+fn tmux_last_pane_dir() -> Option<PathBuf> {
+    std::env::var_os("TMUX")?;
+    let output = Command::new("tmux")
+        .args([
+            "display-message",
+            "-p",
+            "-t",
+            "{last}",
+            "#{pane_current_path}",
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_dir(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// The directory worked in outside gh-board, when a source knows it.
+pub(crate) fn working_dir() -> Option<PathBuf> {
+    cwd_file_dir().or_else(tmux_last_pane_dir)
+}
+
+/// Repo of the working directory, else of `fallback` when no source knows
+/// one. Runs `git`, so keep it off the render path when polling.
+pub(crate) fn detect(fallback: Option<&Path>) -> Option<RepoRef> {
+    match working_dir() {
+        Some(dir) => crate::git::detect_repo(&dir),
+        None => fallback.and_then(crate::git::detect_repo),
+    }
+}
+
+/// Offer `repo` (`owner/name`) as the new scope, taken with `key`.
+pub(crate) fn offer(repo: String, key: String) {
+    with_offer(|offer| *offer = Some(Offer { repo, key }));
+}
+
+/// The offered repo, withdrawing the offer.
+pub(crate) fn take_offer() -> Option<String> {
+    with_offer(Option::take).map(|o| o.repo)
+}
+
+/// Withdraw the offer, once the scope was chosen another way.
+pub(crate) fn clear_offer() {
+    with_offer(|offer| *offer = None);
+}
+
+/// Footer line of a pending offer.
+pub(crate) fn footer_text() -> Option<String> {
+    with_offer(|offer| {
+        offer
+            .as_ref()
+            .map(|o| format!("Now in {}: {} scopes to it", o.repo, o.key))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dir_keeps_existing_directories_only() {
+        let dir = std::env::temp_dir();
+        let text = format!("{}\n", dir.display());
+        assert_eq!(parse_dir(&text), Some(dir.clone()));
+        assert_eq!(parse_dir(""), None);
+        assert_eq!(
+            parse_dir(&dir.join("no-such-dir").display().to_string()),
+            None
+        );
+    }
+}
@@ -0,0 +1,651 @@
+//! Spell checking for the comment composer.
+//!
+//! A small checker reading Hunspell dictionaries: the `.dic` word list and
+//! the prefix/suffix rules of its `.aff` file (one level of affixes plus
+//! their cross products; compounding and the other Hunspell options are not
+//! supported). Every word form is expanded once, when the dictionary loads
+//! in the background, so checking a word is a set lookup.
+//!
+//! Code, quoted lines, links, paths, `@mentions`, `#refs` and identifiers
+//! are left alone, and so is the word still being typed.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+
+use crate::config::loader::expand_tilde;
+use crate::config::types::SpellConfig;
+
+const DEFAULT_LANGUAGE: &str = "en_US";
+
+/// Most suggestions offered for a misspelled word.
+const MAX_SUGGESTIONS: usize = 6;
+
+/// Largest edit distance of a suggestion.
+const MAX_DISTANCE: usize = 2;
+
+static DICTIONARY: OnceLock<Dictionary> = OnceLock::new();
+
+/// Load the dictionary selected by `[spell]` in a background thread; until
+/// it is ready (or when none is found), nothing is flagged.
+pub fn configure(config: &SpellConfig) {
+    if config.enabled == Some(false) {
+        return;
+    }
+    let config = config.clone();
+    std::thread::spawn(move || match load(&config) {
+        Ok(dictionary) => {
+            let _ = DICTIONARY.set(dictionary);
+        }
+        Err(e) => tracing::warn!("spell checking disabled: {e:#}"),
+    });
+}
+
+/// Directories searched for `<language>.dic`.
+fn search_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    if let Some(data) = std::env::var_os("XDG_DATA_HOME").map(PathBuf::from) {
+        dirs.push(data.join("hunspell"));
+    } else if let Some(home) = &home {
+        dirs.push(home.join(".local/share/hunspell"));
+    }
+    if let Some(home) = &home {
+        dirs.push(home.join("Library/Spelling"));
+    }
+    for dir in [
+        "/usr/share/hunspell",
+        "/usr/share/myspell",
+        "/usr/share/myspell/dicts",
+        "/usr/local/share/hunspell",
+        "/opt/homebrew/share/hunspell",
+        "/Library/Spelling",
+    ] {
+        dirs.push(PathBuf::from(dir));
+    }
+    dirs
+}
+
+fn load(config: &SpellConfig) -> Result<Dictionary> {
+    let dic_path = if let Some(path) = &config.dictionary {
+        expand_tilde(path)
+    } else {
+        let language = config.language.as_deref().unwrap_or(DEFAULT_LANGUAGE);
+        search_dirs()
+            .into_iter()
+            .map(|dir| dir.join(format!("{language}.dic")))
+            .find(|path| path.is_file())
+            .with_context(|| format!("no {language}.dic dictionary found"))?
+    };
+    let dic =
+        std::fs::read(&dic_path).with_context(|| format!("reading {}", dic_path.display()))?;
+    let aff_path = dic_path.with_extension("aff");
+    let aff = match std::fs::read(&aff_path) {
+        Ok(aff) => aff,
+        Err(e) => {
+            // Dictionaries without rules are plain word lists.
+            tracing::warn!(
+                "spell: no affix rules read from {}: {e}; only listed words are known",
+                aff_path.display()
+            );
+            Vec::new()
+        }
+    };
+    let encoding = aff_encoding(&aff);
+    let aff = decode(&aff, encoding.as_deref())
+        .with_context(|| format!("decoding {}", aff_path.display()))?;
+    let dic = decode(&dic, encoding.as_deref())
+        .with_context(|| format!("decoding {}", dic_path.display()))?;
+    let mut dictionary = Dictionary::parse(&aff, &dic);
+    dictionary.words.extend(config.words.iter().cloned());
+    Ok(dictionary)
+}
+
+/// The encoding the `SET` line of an `.aff` file names.
+fn aff_encoding(aff: &[u8]) -> Option<String> {
+    aff.split(|&b| b == b'\n').find_map(|line| {
+        let line = line.strip_prefix(b"SET")?;
+        let name = String::from_utf8_lossy(line).trim().to_owned();
+        (line.first().is_some_and(u8::is_ascii_whitespace) && !name.is_empty()).then_some(name)
+    })
+}
+
+/// Decode a dictionary file written in `encoding`, the `SET` of its `.aff`.
+/// Without one, Hunspell reads ISO-8859-1; files that are valid UTF-8 are
+/// taken as such, as most dictionaries shipped without `SET` are.
+fn decode(bytes: &[u8], encoding: Option<&str>) -> Result<String> {
+    let latin1 = |bytes: &[u8]| bytes.iter().map(|&b| char::from(b)).collect::<String>();
+    let Some(encoding) = encoding else {
+        return Ok(std::str::from_utf8(bytes).map_or_else(|_| latin1(bytes), str::to_owned));
+    };
+    match encoding.to_ascii_uppercase().replace('-', "").as_str() {
+        "UTF8" => Ok(String::from_utf8_lossy(bytes).into_owned()),
+        "ISO88591" => Ok(latin1(bytes)),
+        // ISO-8859-15 is ISO-8859-1 with eight letters replaced.
+        "ISO885915" => Ok(latin1(bytes)
+            .chars()
+            .map(|c| match c {
+                '\u{a4}' => '\u{20ac}',
+                '\u{a6}' => '\u{160}',
+                '\u{a8}' => '\u{161}',
+                '\u{b4}' => '\u{17d}',
+                '\u{b8}' => '\u{17e}',
+                '\u{bc}' => '\u{152}',
+                '\u{bd}' => '\u{153}',
+                '\u{be}' => '\u{178}',
+                c => c,
+            })
+            .collect()),
+        _ => anyhow::bail!("unsupported dictionary encoding {encoding}"),
+    }
+}
+
+/// Byte ranges of the misspelled words of `text`.
+pub(crate) fn misspellings(text: &str) -> Vec<Range<usize>> {
+    DICTIONARY
+        .get()
+        .map(|dictionary| dictionary.misspellings(text))
+        .unwrap_or_default()
+}
+
+/// Replacements for the misspelled `word`, closest first.
+pub(crate) fn suggestions(word: &str) -> Vec<String> {
+    DICTIONARY
+        .get()
+        .map(|dictionary| dictionary.suggest(word))
+        .unwrap_or_default()
+}
+
+// ---------------------------------------------------------------------------
+// Dictionary
+// ---------------------------------------------------------------------------
+
+/// How the `.aff` file writes affix flags (`FLAG`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlagFormat {
+    /// One character per flag (the default, and `UTF-8`).
+    Char,
+    /// Two characters per flag.
+    Long,
+    /// Comma-separated numbers.
+    Num,
+}
+
+impl FlagFormat {
+    fn split(self, flags: &str) -> Vec<String> {
+        match self {
+            Self::Char => flags.chars().map(String::from).collect(),
+            Self::Long => {
+                let chars: Vec<char> = flags.chars().collect();
+                chars.chunks(2).map(|pair| pair.iter().collect()).collect()
+            }
+            Self::Num => flags.split(',').map(|f| f.trim().to_owned()).collect(),
+        }
+    }
+}
+
+/// One element of an affix condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CondChar {
+    Any,
+    Char(char),
+    /// `[abc]`, or `[^abc]` when negated.
+    Set {
+        negated: bool,
+        chars: Vec<char>,
+    },
+}
+
+impl CondChar {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Char(expected) => *expected == c,
+            Self::Set { negated, chars } => chars.contains(&c) != *negated,
+        }
+    }
+}
+
+fn parse_condition(condition: &str) -> Vec<CondChar> {
+    let mut parsed = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(c) = chars.next() {
+        parsed.push(match c {
+            '.' => CondChar::Any,
+            '[' => {
+                let mut set: Vec<char> = chars.by_ref().take_while(|&c| c != ']').collect();
+                let negated = set.first() == Some(&'^');
+                if negated {
+                    set.remove(0);
+                }
+                CondChar::Set {
+                    negated,
+                    chars: set,
+                }
+            }
+            c => CondChar::Char(c),
+        });
+    }
+    parsed
+}
+
+/// A prefix or suffix rule.
+#[derive(Debug, Clone)]
+struct Affix {
+    strip: String,
+    add: String,
+    condition: Vec<CondChar>,
+    /// Combines with affixes of the other kind.
+    cross: bool,
+}
+
+impl Affix {
+    fn apply_suffix(&self, word: &str) -> Option<String> {
+        let tail: Vec<char> = word.chars().rev().take(self.condition.len()).collect();
+        let matches = tail.len() == self.condition.len()
+            && self
+                .condition
+                .iter()
+                .rev()
+                .zip(&tail)
+                .all(|(cond, &c)| cond.matches(c));
+        let stem = word.strip_suffix(self.strip.as_str())?;
+        matches.then(|| format!("{stem}{}", self.add))
+    }
+
+    fn apply_prefix(&self, word: &str) -> Option<String> {
+        let head: Vec<char> = word.chars().take(self.condition.len()).collect();
+        let matches = head.len() == self.condition.len()
+            && self
+                .condition
+                .iter()
+                .zip(&head)
+                .all(|(cond, &c)| cond.matches(c));
+        let stem = word.strip_prefix(self.strip.as_str())?;
+        matches.then(|| format!("{}{stem}", self.add))
+    }
+}
+
+#[derive(Debug, Default)]
+struct Affixes {
+    prefixes: HashMap<String, Vec<Affix>>,
+    suffixes: HashMap<String, Vec<Affix>>,
+}
+
+impl Affixes {
+    fn parse(aff: &str) -> (Self, FlagFormat) {
+        let mut affixes = Self::default();
+        let mut format = FlagFormat::Char;
+        let mut cross: HashMap<(bool, String), bool> = HashMap::new();
+        for line in aff.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["FLAG", "long", ..] => format = FlagFormat::Long,
+                ["FLAG", "num", ..] => format = FlagFormat::Num,
+                [kind @ ("PFX" | "SFX"), flag, yes_no @ ("Y" | "N"), count]
+                    if count.parse::<usize>().is_ok() =>
+                {
+                    cross.insert((*kind == "PFX", (*flag).to_owned()), *yes_no == "Y");
+                }
+                [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                    let is_prefix = *kind == "PFX";
+                    let empty_if_zero = |s: &str| {
+                        if s == "0" {
+                            String::new()
+                        } else {
+                            s.to_owned()
+                        }
+                    };
+                    // Continuation flags (`add/flags`) are not supported.
+                    let add = add.split('/').next().unwrap_or_default();
+                    let affix = Affix {
+                        strip: empty_if_zero(strip),
+                        add: empty_if_zero(add),
+                        condition: parse_condition(rest.first().copied().unwrap_or(".")),
+                        cross: cross
+                            .get(&(is_prefix, (*flag).to_owned()))
+                            .copied()
+                            .unwrap_or(false),
+                    };
+                    let map = if is_prefix {
+                        &mut affixes.prefixes
+                    } else {
+                        &mut affixes.suffixes
+                    };
+                    map.entry((*flag).to_owned()).or_default().push(affix);
+                }
+                _ => {}
+            }
+        }
+        (affixes, format)
+    }
+}
+
+/// Every accepted word form.
+#[derive(Debug, Default)]
+pub(crate) struct Dictionary {
+    words: HashSet<String>,
+}
+
+impl Dictionary {
+    /// Expand the `dic` word list with the rules of `aff`.
+    fn parse(aff: &str, dic: &str) -> Self {
+        let (affixes, format) = Affixes::parse(aff);
+        let mut words = HashSet::new();
+        let mut lines = dic.lines().peekable();
+        // The first line holds the (approximate) word count.
+        if lines
+            .peek()
+            .is_some_and(|l| l.trim().parse::<usize>().is_ok())
+        {
+            lines.next();
+        }
+        for line in lines {
+            // Morphological fields follow the word after whitespace.
+            let Some(entry) = line.split_whitespace().next() else {
+                continue;
+            };
+            let (word, flags) = entry.split_once('/').unwrap_or((entry, ""));
+            let flags = format.split(flags);
+            let of_kind = |map: &'_ HashMap<String, Vec<Affix>>| -> Vec<Affix> {
+                flags
+                    .iter()
+                    .filter_map(|f| map.get(f))
+                    .flatten()
+                    .cloned()
+                    .collect()
+            };
+            let prefixes = of_kind(&affixes.prefixes);
+            let suffixes = of_kind(&affixes.suffixes);
+            for suffix in &suffixes {
+                let Some(form) = suffix.apply_suffix(word) else {
+                    continue;
+                };
+                if suffix.cross {
+                    words.extend(
+                        prefixes
+                            .iter()
+                            .filter(|p| p.cross)
+                            .filter_map(|p| p.apply_prefix(&form)),
+                    );
+                }
+                words.insert(form);
+            }
+            words.extend(prefixes.iter().filter_map(|p| p.apply_prefix(word)));
+            words.insert(word.to_owned());
+        }
+        Self { words }
+    }
+
+    /// Whether `word` is spelled correctly. Capitalized and all-caps
+    /// spellings of a lowercase word are accepted too.
+    fn check(&self, word: &str) -> bool {
+        let word = word.replace('\u{2019}', "'");
+        if self.words.contains(&word) {
+            return true;
+        }
+        let lower = word.to_lowercase();
+        let capitalized = capitalize(&lower);
+        (word == capitalized || word == word.to_uppercase())
+            && (self.words.contains(&lower) || self.words.contains(&capitalized))
+    }
+
+    fn misspellings(&self, text: &str) -> Vec<Range<usize>> {
+        checked_words(text)
+            .into_iter()
+            .filter(|range| !self.check(&text[range.clone()]))
+            .collect()
+    }
+
+    fn suggest(&self, word: &str) -> Vec<String> {
+        let lower: Vec<char> = word.to_lowercase().chars().collect();
+        let first = lower.first().copied();
+        let mut scored: Vec<(usize, bool, &str)> = self
+            .words
+            .iter()
+            .filter(|w| w.chars().count().abs_diff(lower.len()) <= MAX_DISTANCE)
+            .filter_map(|w| {
+                let candidate: Vec<char> = w.to_lowercase().chars().collect();
+                let distance = edit_distance(&lower, &candidate);
+                (distance <= MAX_DISTANCE)
+                    .then(|| (distance, candidate.first().copied() != first, w.as_str()))
+            })
+            .collect();
+        scored.sort_unstable();
+        let capitalized = word.chars().next().is_some_and(char::is_uppercase);
+        let mut suggestions: Vec<String> = Vec::new();
+        for (_, _, w) in scored {
+            let w = if capitalized {
+                capitalize(w)
+            } else {
+                w.to_owned()
+            };
+            if !suggestions.contains(&w) {
+                suggestions.push(w);
+            }
+            if suggestions.len() == MAX_SUGGESTIONS {
+                break;
+            }
+        }
+        suggestions
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Edit distance counting an adjacent transposition as one edit.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut prev2: Vec<usize> = Vec::new();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (prev[j] + 1).min(row[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(prev2[j - 2] + 1);
+            }
+        }
+        prev2 = std::mem::replace(&mut prev, row);
+    }
+    prev[b.len()]
+}
+
+// ---------------------------------------------------------------------------
+// Tokenizing
+// ---------------------------------------------------------------------------
+
+/// Byte ranges of the words of `text` worth checking.
+fn checked_words(text: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut in_fence = false;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        // Fenced or indented code, and quoted text (not the user's).
+        if in_fence
+            || line.starts_with("    ")
+            || line.starts_with('\t')
+            || trimmed.starts_with('>')
+        {
+            continue;
+        }
+        line_words(line, start, &mut words);
+    }
+    // The word being typed is checked once it is finished.
+    if words.last().is_some_and(|w| w.end == text.len()) {
+        words.pop();
+    }
+    words
+}
+
+/// Push the checked words of `line` (starting at byte `start` of the text).
+fn line_words(line: &str, start: usize, words: &mut Vec<Range<usize>>) {
+    let mut in_code = false;
+    let mut token_start = None;
+    for (i, c) in line
+        .char_indices()
+        .chain(std::iter::once((line.len(), ' ')))
+    {
+        if c.is_whitespace() || c == '`' {
+            if let Some(from) = token_start.take()
+                && !in_code
+            {
+                token_words(&line[from..i], start + from, words);
+            }
+            if c == '`' {
+                in_code = !in_code;
+            }
+        } else if token_start.is_none() {
+            token_start = Some(i);
+        }
+    }
+}
+
+/// Push the words of a whitespace-delimited `token`, unless it is a link,
+/// path, reference or identifier.
+fn token_words(token: &str, start: usize, words: &mut Vec<Range<usize>>) {
+    let skipped = token.contains("://")
+        || token.starts_with("www.")
+        || token.starts_with(['@', '#', ':'])
+        || token.contains(['@', '/', '\\', '_', '=', '<', '>', '{', '}'])
+        || token.chars().any(|c| c.is_ascii_digit());
+    if skipped {
+        return;
+    }
+    let is_word_char = |c: char| c.is_alphabetic() || c == '\'' || c == '\u{2019}';
+    let mut run_start = None;
+    for (i, c) in token
+        .char_indices()
+        .chain(std::iter::once((token.len(), ' ')))
+    {
+        if is_word_char(c) {
+            run_start.get_or_insert(i);
+            continue;
+        }
+        let Some(from) = run_start.take() else {
+            continue;
+        };
+        let run = &token[from..i];
+        let trimmed = run.trim_start_matches(['\'', '\u{2019}']);
+        let from = from + (run.len() - trimmed.len());
+        let word = trimmed.trim_end_matches(['\'', '\u{2019}']);
+        // Not single letters, acronyms or CamelCase identifiers.
+        let checked = word.chars().count() > 1 && !word.chars().skip(1).any(char::is_uppercase);
+        if checked {
+            words.push(start + from..start + from + word.len());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "\
+SET UTF-8
+PFX U Y 1
+PFX U 0 un .
+SFX S Y 3
+SFX S y ies [^aeiou]y
+SFX S 0 es [sxz]
+SFX S 0 s [^sxzy]
+SFX D N 1
+SFX D 0 ed .
+";
+
+    const DIC: &str = "\
+4
+fix/DS
+review
+lock/USD
+copy/S
+London
+";
+
+    fn dictionary() -> Dictionary {
+        Dictionary::parse(AFF, DIC)
+    }
+
+    #[test]
+    fn files_decode_per_their_set_line() {
+        let aff = b"# comment\nSET ISO8859-1\nTRY esianrt\n";
+        assert_eq!(aff_encoding(aff).as_deref(), Some("ISO8859-1"));
+        assert_eq!(aff_encoding(b"SETTINGS x\n"), None);
+        // "café" in ISO-8859-1.
+        let dic = b"1\ncaf\xe9\n";
+        assert_eq!(decode(dic, Some("ISO8859-1")).unwrap(), "1\ncaf\u{e9}\n");
+        assert_eq!(decode(b"\xa4", Some("ISO-8859-15")).unwrap(), "\u{20ac}");
+        assert_eq!(decode("café".as_bytes(), Some("UTF-8")).unwrap(), "café");
+        // Without SET: UTF-8 when valid, else ISO-8859-1.
+        assert_eq!(decode("café".as_bytes(), None).unwrap(), "café");
+        assert_eq!(decode(dic, None).unwrap(), "1\ncaf\u{e9}\n");
+        assert!(decode(dic, Some("KOI8-R")).is_err());
+    }
+
+    #[test]
+    fn affix_rules_expand_word_forms() {
+        let dict = dictionary();
+        for word in [
+            "fix", "fixes", "fixed", "copies", "unlock", "unlocks", "locked",
+        ] {
+            assert!(dict.check(word), "{word}");
+        }
+        // `D` does not combine with prefixes; `copys` fails the condition.
+        assert!(!dict.check("unlocked"));
+        assert!(!dict.check("copys"));
+        assert!(!dict.check("reviews"));
+    }
+
+    #[test]
+    fn capitalized_forms_of_lowercase_words_are_accepted() {
+        let dict = dictionary();
+        assert!(dict.check("Review"));
+        assert!(dict.check("REVIEW"));
+        assert!(dict.check("London"));
+        assert!(!dict.check("london"));
+        assert!(!dict.check("rEview"));
+    }
+
+    #[test]
+    fn misspellings_skip_code_links_and_references() {
+        let dict = dictionary();
+        let text = "fixx the `lcok` at https://exmple.com for @alce #12\n\
+                    > quoted revew\n```\ncopyy\n```\nreviw ";
+        let flagged: Vec<&str> = dict
+            .misspellings(text)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect();
+        assert_eq!(flagged, ["fixx", "the", "at", "for", "reviw"]);
+    }
+
+    #[test]
+    fn the_word_being_typed_is_not_checked() {
+        let dict = dictionary();
+        assert!(dict.misspellings("fix revi").is_empty());
+        assert_eq!(dict.misspellings("fix revi ").len(), 1);
+    }
+
+    #[test]
+    fn suggestions_rank_by_edit_distance() {
+        let dict = dictionary();
+        assert_eq!(dict.suggest("fxi")[0], "fix");
+        assert_eq!(dict.suggest("Reveiw"), ["Review"]);
+        assert!(dict.suggest("zzzzzzzz").is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+//! Terminal restoration and crash reporting.
+//!
+//! The fullscreen TUI runs in raw mode on the alternate screen, where a panic
+//! message is invisible and a crash can leave the shell unusable. The panic
+//! hook installed here records the panic instead of printing it; once the UI
+//! has unwound, `main` restores the terminal and shows the summary.
+//! [`run_foreground`] hands the terminal to a child program (an editor) for
+//! a while.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+use anyhow::{Context, Result, bail};
+use std::sync::{Mutex, PoisonError};
+
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::terminal::{
+    EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+    is_raw_mode_enabled,
+};
+use crossterm::{cursor, execute};
+
+/// Where the full panic message and backtrace are written: `panic.log` in
+/// the state directory, next to the session logs.
+pub fn panic_log_path() -> Option<PathBuf> {
+    crate::util::state_path("panic.log")
+}
+
+/// Summary of the most recent panic, waiting to be shown.
+static LAST_PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+/// Leave raw mode and the alternate screen and show the cursor again.
+///
+/// Safe to call when the terminal was never switched, and more than once.
+pub fn restore() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        io::stdout(),
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        cursor::Show
+    );
+}
+
+/// Run `command` on the normal screen with the terminal in cooked mode, then
+/// switch back to the TUI's raw alternate screen.
+///
+/// The alternate screen comes back blank: the caller must have the UI redraw
+/// in full.
+pub fn run_foreground(command: &mut Command) -> io::Result<ExitStatus> {
+    release_input();
+    restore();
+    let status = command.status();
+    let _ = execute!(
+        io::stdout(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        cursor::Hide
+    );
+    let _ = enable_raw_mode();
+    status
+}
+
+/// Open `path` in `$VISUAL`, `$EDITOR` or `vi`, handing it the terminal.
+pub(crate) fn edit_file(path: &Path) -> Result<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok().filter(|e| !e.trim().is_empty()))
+        .unwrap_or_else(|| "vi".to_owned());
+    // Through the shell, so that `EDITOR="code --wait"` works.
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(format!("{editor} \"$1\""))
+        .arg("sh")
+        .arg(path);
+    let status = run_foreground(&mut command).with_context(|| format!("starting {editor}"))?;
+    if !status.success() {
+        bail!("{editor} exited with {status}");
+    }
+    Ok(())
+}
+
+/// Stop the UI's input reader before a child takes the terminal.
+///
+/// crossterm reads input on a background thread, which may be blocked
+/// waiting for a key and would swallow the child's first keystrokes. A
+/// SIGWINCH makes it return with a resize event; it then waits until the UI
+/// asks for the next event, which the UI cannot do while the child runs.
+#[cfg(unix)]
+fn release_input() {
+    // SAFETY: `raise` only signals this process. SIGWINCH is ignored by
+    // default, and crossterm's handler just wakes its reader.
+    unsafe { libc::raise(libc::SIGWINCH) };
+    // The reader holds its lock while blocked: taking it waits for the
+    // thread to have returned.
+    let _ = crossterm::event::poll(std::time::Duration::ZERO);
+}
+
+#[cfg(not(unix))]
+fn release_input() {}
+
+/// Install a panic hook that writes [`panic_log_path`] and records the summary for
+/// [`take_panic`] rather than printing into the alternate screen.
+///
+/// A panic on a background thread leaves the UI running, so it is only
+/// logged.
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let backtrace = std::backtrace::Backtrace::force_capture();
+        if let Some(path) = panic_log_path() {
+            if let Some(dir) = path.parent() {
+                let _ = std::fs::create_dir_all(dir);
+            }
+            let _ = std::fs::write(path, format!("{info}\n\n{backtrace}"));
+        }
+        let thread = std::thread::current();
+        if thread.name() == Some("main") {
+            if !is_raw_mode_enabled().unwrap_or(false) {
+                // Not in the TUI (e.g. during startup): stderr is visible.
+                eprintln!("{info}");
+            }
+            *LAST_PANIC.lock().unwrap_or_else(PoisonError::into_inner) = Some(info.to_string());
+        } else {
+            tracing::error!(
+                "panic on thread {}: {info}",
+                thread.name().unwrap_or("<unnamed>")
+            );
+        }
+    }));
+}
+
+/// Take the summary recorded by the most recent main-thread panic.
+pub fn take_panic() -> Option<String> {
+    LAST_PANIC
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+}
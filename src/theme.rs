@@ -1,5 +1,5 @@
 use crate::color::Color;
-use crate::config::types::Theme;
+use crate::config::types::{IconConfig, LabelTint, Theme};
 use crate::icons::ResolvedIcons;
 
 /// Detected terminal background.
@@ -27,9 +27,29 @@ impl Background {
     }
 }
 
+/// How UI chrome (borders, separators, scrollbars) is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Chrome {
+    /// Unicode box-drawing characters.
+    #[default]
+    Unicode,
+    /// ASCII-only borders and separators (`-`, `|`, `+`).
+    Ascii,
+    /// No decorative glyphs at all; tables are emitted as labelled text
+    /// lines (screen-reader mode).
+    Plain,
+}
+
+impl Chrome {
+    /// Whether text glyphs (cursors, ellipses, separators) must stay ASCII.
+    pub fn ascii_only(self) -> bool {
+        !matches!(self, Self::Unicode)
+    }
+}
+
 /// A fully resolved theme: every color slot has a concrete `Color` value
 /// (either from user config or from defaults for the detected background).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash)]
 pub struct ResolvedTheme {
     // Text
     pub text_primary: Color,
@@ -90,11 +110,27 @@ pub struct ResolvedTheme {
     pub footer_notifications: Color,
     pub footer_repo: Color,
     pub footer_alerts: Color,
+    pub footer_events: Color,
+    // Label tints
+    pub label_tints: Vec<(String, Color)>,
+    pub label_tint: LabelTint,
     // Icons
     pub icons: ResolvedIcons,
+    // Chrome
+    pub chrome: Chrome,
 }
 
 impl ResolvedTheme {
+    /// Tint of the first `[theme.colors.labels]` entry among `labels`.
+    pub fn label_color(&self, labels: &[&str]) -> Option<Color> {
+        self.label_tints.iter().find_map(|(name, color)| {
+            labels
+                .iter()
+                .any(|l| l.eq_ignore_ascii_case(name))
+                .then_some(*color)
+        })
+    }
+
     /// Build a resolved theme from user config and detected background.
     pub fn resolve(theme: &Theme, bg: Background) -> Self {
         let d = Defaults::for_background(bg);
@@ -110,6 +146,28 @@ impl ResolvedTheme {
         let text_error = theme.colors.text.error.unwrap_or(d.text_error);
         let text_actor = theme.colors.text.actor.unwrap_or(d.text_actor);
 
+        // Screen-reader and ASCII modes pin the icon preset; per-icon
+        // overrides still apply on top.
+        let chrome = if theme.ui.screen_reader.unwrap_or(false) {
+            Chrome::Plain
+        } else if theme.ui.ascii.unwrap_or(false) {
+            Chrome::Ascii
+        } else {
+            Chrome::Unicode
+        };
+        let forced_preset = match chrome {
+            Chrome::Unicode => None,
+            Chrome::Ascii => Some("ascii"),
+            Chrome::Plain => Some("text"),
+        };
+        let icons = match forced_preset {
+            Some(preset) => ResolvedIcons::resolve(&IconConfig {
+                preset: Some(preset.to_owned()),
+                ..theme.icons.clone()
+            }),
+            None => ResolvedIcons::resolve(&theme.icons),
+        };
+
         Self {
             text_primary,
             text_secondary,
@@ -171,7 +229,16 @@ impl ResolvedTheme {
                 .unwrap_or(d.footer_notifications),
             footer_repo: theme.colors.footer.repo.unwrap_or(d.footer_repo),
             footer_alerts: theme.colors.footer.alerts.unwrap_or(d.footer_alerts),
-            icons: ResolvedIcons::resolve(&theme.icons),
+            footer_events: theme.colors.footer.events.unwrap_or(d.footer_events),
+            label_tints: theme
+                .colors
+                .labels
+                .iter()
+                .map(|(name, &color)| (name.clone(), color))
+                .collect(),
+            label_tint: theme.ui.table.label_tint.unwrap_or_default(),
+            icons,
+            chrome,
         }
     }
 }
@@ -215,6 +282,7 @@ struct Defaults {
     footer_notifications: Color,
     footer_repo: Color,
     footer_alerts: Color,
+    footer_events: Color,
 }
 
 impl Defaults {
@@ -258,6 +326,7 @@ impl Defaults {
                 footer_notifications: Color::Ansi256(5), // magenta
                 footer_repo: Color::Ansi256(6),          // cyan
                 footer_alerts: Color::Ansi256(3),        // yellow
+                footer_events: Color::Ansi256(1),        // red
             },
             Background::Light => Self {
                 text_primary: Color::Ansi256(0),
@@ -297,6 +366,7 @@ impl Defaults {
                 footer_notifications: Color::Ansi256(5), // magenta
                 footer_repo: Color::Ansi256(6),          // cyan
                 footer_alerts: Color::Ansi256(3),        // yellow
+                footer_events: Color::Ansi256(1),        // red
             },
         }
     }
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+/// A parsed `CODEOWNERS` file.
+///
+/// Patterns follow GitHub's subset of gitignore syntax: the last matching
+/// rule wins, a rule without owners leaves its paths unowned, and `!`
+/// negations or `[...]` classes are not supported (GitHub ignores those rules
+/// too).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeOwners {
+    rules: Vec<CodeOwnersRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CodeOwnersRule {
+    pattern: String,
+    /// `@user`, `@org/team` or an email address.
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    pub fn parse(text: &str) -> Self {
+        let rules = text
+            .lines()
+            .filter_map(|line| {
+                let line = strip_comment(line);
+                let mut tokens = line.split_whitespace();
+                let pattern = tokens.next()?;
+                if pattern.starts_with('!') || pattern.contains('[') {
+                    return None;
+                }
+                Some(CodeOwnersRule {
+                    pattern: pattern.replace("\\#", "#"),
+                    owners: tokens.map(str::to_owned).collect(),
+                })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Owners of the file at `path` (repo-relative), empty when unowned.
+    pub fn owners_for(&self, path: &str) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|r| pattern_matches(&r.pattern, path))
+            .map_or(&[], |r| r.owners.as_slice())
+    }
+}
+
+/// `line` without its `#` comment; `\#` is a literal hash.
+fn strip_comment(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    let end = (0..bytes.len())
+        .find(|&i| bytes[i] == b'#' && (i == 0 || bytes[i - 1] != b'\\'))
+        .unwrap_or(bytes.len());
+    &line[..end]
+}
+
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    // A slash anywhere but at the end anchors the pattern to the repo root;
+    // otherwise it matches at any depth.
+    let anchored = trimmed.contains('/');
+    let trimmed = trimmed.trim_start_matches('/');
+    if trimmed.is_empty() {
+        return false;
+    }
+    let mut pat: Vec<&str> = Vec::new();
+    if !anchored {
+        pat.push("**");
+    }
+    pat.extend(trimmed.split('/'));
+
+    let segments: Vec<&str> = path.split('/').collect();
+    if !dir_only && match_segments(&pat, &segments) {
+        return true;
+    }
+    // A pattern naming a directory owns everything beneath it, except that
+    // `docs/*` stops at direct children.
+    let last = pat.last().copied().unwrap_or_default();
+    if last != "**" && last.contains(['*', '?']) {
+        return false;
+    }
+    (1..segments.len()).any(|n| match_segments(&pat, &segments[..n]))
+}
+
+fn match_segments(pat: &[&str], path: &[&str]) -> bool {
+    match pat.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((first, rest)) => path
+            .split_first()
+            .is_some_and(|(seg, tail)| match_segment(first, seg) && match_segments(rest, tail)),
+    }
+}
+
+/// `*` and `?` wildcards within a single path segment.
+fn match_segment(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((sp, st)) = star {
+            pi = sp + 1;
+            ti = st + 1;
+            star = Some((sp, st + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_matching_rule_wins() {
+        let owners = CodeOwners::parse(
+            "# Fallback\n\
+             *       @acme/core\n\
+             *.js    @js-owner # inline comment\n\
+             /docs/  docs@acme.dev\n\
+             docs/*  @acme/writers\n\
+             apps/   @acme/apps\n\
+             /vendor/generated/\n\
+             **/logs @acme/ops\n",
+        );
+        assert_eq!(owners.owners_for("src/main.rs"), ["@acme/core"]);
+        assert_eq!(owners.owners_for("web/app.js"), ["@js-owner"]);
+        assert_eq!(owners.owners_for("docs/guide.md"), ["@acme/writers"]);
+        assert_eq!(owners.owners_for("docs/build/faq.md"), ["docs@acme.dev"]);
+        assert_eq!(owners.owners_for("pkg/apps/x/y.rs"), ["@acme/apps"]);
+        assert!(owners.owners_for("vendor/generated/a.rs").is_empty());
+        assert_eq!(owners.owners_for("deploy/logs/app.log"), ["@acme/ops"]);
+    }
+
+    #[test]
+    fn segment_wildcards_stay_within_a_segment() {
+        assert!(match_segment("*.rs", "main.rs"));
+        assert!(match_segment("v?.toml", "v1.toml"));
+        assert!(!match_segment("*.rs", "main.rsx"));
+        assert!(!pattern_matches("/src/*.rs", "src/bin/main.rs"));
+        assert!(pattern_matches("/src/**/*.rs", "src/bin/main.rs"));
+    }
+}
@@ -75,7 +75,7 @@ pub struct Actor {
     pub avatar_url: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RepoRef {
     pub owner: String,
     pub name: String,
@@ -132,6 +132,32 @@ pub struct Comment {
     pub created_at: DateTime<Utc>,
 }
 
+/// Signature verification of a commit, as GitHub reports it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommitSignature {
+    /// Signed (GPG, SSH, S/MIME or Sigstore) with a signature GitHub verified.
+    Verified,
+    /// Signed, but the signature does not verify; `reason` is GitHub's
+    /// verification state, e.g. `unknown_key` or `unverified_email`.
+    Unverified { reason: String },
+    #[default]
+    Unsigned,
+}
+
+impl CommitSignature {
+    /// From a verification state: GraphQL's `VALID`/`UNKNOWN_KEY`/… or the
+    /// REST `verification.reason` (`valid`, `unsigned`, …).
+    pub fn from_state(state: &str) -> Self {
+        match state.to_ascii_lowercase().as_str() {
+            "valid" => Self::Verified,
+            "unsigned" => Self::Unsigned,
+            reason => Self::Unverified {
+                reason: reason.to_owned(),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Commit {
     pub sha: String,
@@ -139,6 +165,8 @@ pub struct Commit {
     pub author: Option<String>,
     pub committed_date: Option<DateTime<Utc>>,
     pub check_state: Option<CommitCheckState>,
+    #[serde(default)]
+    pub signature: CommitSignature,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -153,10 +181,29 @@ pub struct CheckRun {
     /// Workflow display name (e.g., "Essentials"); `None` for non-Actions checks.
     #[serde(default)]
     pub workflow_name: Option<String>,
+    /// Actions job behind the check (its check run id); `None` for
+    /// non-Actions checks.
+    #[serde(default)]
+    pub job_id: Option<u64>,
     #[serde(default)]
     pub started_at: Option<DateTime<Utc>>,
     #[serde(default)]
     pub completed_at: Option<DateTime<Utc>>,
+    /// Required by the base branch protection rules.
+    #[serde(default)]
+    pub is_required: bool,
+}
+
+/// Whether the viewer has reviewed a changed file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FileViewedState {
+    Viewed,
+    /// Marked viewed, then changed again.
+    Dismissed,
+    #[default]
+    #[serde(other)]
+    Unviewed,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -165,6 +212,9 @@ pub struct File {
     pub additions: u32,
     pub deletions: u32,
     pub status: Option<FileChangeType>,
+    /// The viewer's state on GitHub (`viewerViewedState`).
+    #[serde(default)]
+    pub viewer_state: FileViewedState,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -180,6 +230,9 @@ pub struct Review {
     pub state: ReviewState,
     pub body: String,
     pub submitted_at: Option<DateTime<Utc>>,
+    /// Teams (`org/team`) the review counts for as a code owner review.
+    #[serde(default)]
+    pub on_behalf_of: Vec<String>,
 }
 
 /// A timeline event on a pull request or issue.
@@ -189,12 +242,25 @@ pub enum TimelineEvent {
         author: Option<String>,
         body: String,
         created_at: DateTime<Utc>,
+        /// Permalink anchored to the comment (`#issuecomment-…`).
+        #[serde(default)]
+        url: Option<String>,
+        #[serde(default)]
+        is_bot: bool,
+        /// Hidden by a maintainer (outdated, resolved, off-topic, …).
+        #[serde(default)]
+        is_minimized: bool,
     },
     Review {
         author: Option<String>,
         state: ReviewState,
         body: String,
         submitted_at: DateTime<Utc>,
+        /// Permalink anchored to the review (`#pullrequestreview-…`).
+        #[serde(default)]
+        url: Option<String>,
+        #[serde(default)]
+        is_bot: bool,
     },
     Merged {
         actor: Option<String>,
@@ -214,6 +280,68 @@ pub enum TimelineEvent {
     },
 }
 
+impl TimelineEvent {
+    /// Permalink of a comment or review; `None` for other events.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Comment { url, .. } | Self::Review { url, .. } => url.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Login of whoever wrote or triggered the event.
+    pub fn actor(&self) -> Option<&str> {
+        match self {
+            Self::Comment { author, .. } | Self::Review { author, .. } => author.as_deref(),
+            Self::Merged { actor, .. }
+            | Self::Closed { actor, .. }
+            | Self::Reopened { actor, .. }
+            | Self::ForcePushed { actor, .. } => actor.as_deref(),
+        }
+    }
+
+    /// Bot-authored or minimized: collapsed in the Activity tab by default.
+    pub fn is_noise(&self) -> bool {
+        match self {
+            Self::Comment {
+                is_bot,
+                is_minimized,
+                ..
+            } => *is_bot || *is_minimized,
+            Self::Review { is_bot, .. } => *is_bot,
+            _ => false,
+        }
+    }
+
+    /// Reply text quoting a comment or review, GitHub-style: the body as a
+    /// `> ` block followed by an @mention of its author.
+    pub fn quote_reply(&self) -> Option<String> {
+        let (Self::Comment { author, body, .. } | Self::Review { author, body, .. }) = self else {
+            return None;
+        };
+        let mut reply: String = body
+            .trim_end()
+            .lines()
+            .map(|l| {
+                if l.is_empty() {
+                    ">\n".to_owned()
+                } else {
+                    format!("> {l}\n")
+                }
+            })
+            .collect();
+        if !reply.is_empty() {
+            reply.push('\n');
+        }
+        if let Some(author) = author {
+            reply.push('@');
+            reply.push_str(author);
+            reply.push(' ');
+        }
+        Some(reply)
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ReactionGroups {
     #[serde(default)]
@@ -246,6 +374,41 @@ impl ReactionGroups {
             + self.rocket
             + self.eyes
     }
+
+    /// Reaction counts as an emoji string (e.g. "👍 3  🎉 1").
+    ///
+    /// Returns `None` when no reactions are present.
+    pub fn summary(&self) -> Option<String> {
+        if self.total() == 0 {
+            return None;
+        }
+        let mut parts = Vec::new();
+        if self.thumbs_up > 0 {
+            parts.push(format!("\u{1f44d} {}", self.thumbs_up));
+        }
+        if self.thumbs_down > 0 {
+            parts.push(format!("\u{1f44e} {}", self.thumbs_down));
+        }
+        if self.laugh > 0 {
+            parts.push(format!("\u{1f604} {}", self.laugh));
+        }
+        if self.hooray > 0 {
+            parts.push(format!("\u{1f389} {}", self.hooray));
+        }
+        if self.confused > 0 {
+            parts.push(format!("\u{1f615} {}", self.confused));
+        }
+        if self.heart > 0 {
+            parts.push(format!("\u{2764}\u{fe0f} {}", self.heart));
+        }
+        if self.rocket > 0 {
+            parts.push(format!("\u{1f680} {}", self.rocket));
+        }
+        if self.eyes > 0 {
+            parts.push(format!("\u{1f440} {}", self.eyes));
+        }
+        Some(parts.join("  "))
+    }
 }
 
 /// Public rate limit info extracted from GraphQL responses.
@@ -254,4 +417,89 @@ pub struct RateLimitInfo {
     pub limit: u32,
     pub remaining: u32,
     pub cost: u32,
+    /// The host it was read from, `None` for github.com; the engine stamps
+    /// it on the way out.
+    #[serde(default)]
+    pub host: Option<String>,
+}
+
+/// The latest [`RateLimitInfo`] of each host: every host has its own quota.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimits(std::collections::HashMap<Option<String>, RateLimitInfo>);
+
+impl RateLimits {
+    /// Replace the rate limit of the host `info` was read from.
+    pub fn record(&mut self, info: RateLimitInfo) {
+        self.0.insert(info.host.clone(), info);
+    }
+
+    /// The latest rate limit of `host` (`None` for github.com).
+    pub fn of(&self, host: Option<&str>) -> Option<&RateLimitInfo> {
+        self.0.get(&host.and_then(crate::url::normalize_host))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limits_are_kept_per_host() {
+        let info = |remaining, host: Option<&str>| RateLimitInfo {
+            limit: 5000,
+            remaining,
+            cost: 1,
+            host: host.map(str::to_owned),
+        };
+        let mut limits = RateLimits::default();
+        limits.record(info(4990, None));
+        limits.record(info(120, Some("ghe.acme.com")));
+        limits.record(info(4980, None));
+        assert_eq!(limits.of(None).map(|rl| rl.remaining), Some(4980));
+        assert_eq!(
+            limits.of(Some("github.com")).map(|rl| rl.remaining),
+            Some(4980)
+        );
+        assert_eq!(
+            limits.of(Some("GHE.acme.com")).map(|rl| rl.remaining),
+            Some(120)
+        );
+        assert!(limits.of(Some("ghe.other.com")).is_none());
+    }
+
+    #[test]
+    fn quote_reply_quotes_body_and_mentions_author() {
+        let comment = TimelineEvent::Comment {
+            author: Some("alice".into()),
+            body: "First line\n\nSecond line\n".into(),
+            created_at: Utc::now(),
+            url: None,
+            is_bot: false,
+            is_minimized: false,
+        };
+        assert_eq!(
+            comment.quote_reply().as_deref(),
+            Some("> First line\n>\n> Second line\n\n@alice ")
+        );
+
+        let merged = TimelineEvent::Merged {
+            actor: None,
+            created_at: Utc::now(),
+        };
+        assert!(merged.quote_reply().is_none());
+    }
+
+    #[test]
+    fn reaction_summary_lists_non_zero_counts() {
+        assert!(ReactionGroups::default().summary().is_none());
+        let reactions = ReactionGroups {
+            thumbs_up: 12,
+            hooray: 3,
+            ..ReactionGroups::default()
+        };
+        assert_eq!(
+            reactions.summary().as_deref(),
+            Some("\u{1f44d} 12  \u{1f389} 3")
+        );
+    }
 }
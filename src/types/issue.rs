@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::common::{Actor, Comment, Label, ReactionGroups, RepoRef, TimelineEvent};
+use super::issue_links::IssueLinks;
+use super::pr::PrState;
 
 // ---------------------------------------------------------------------------
 // Issue-specific enums
@@ -46,6 +48,9 @@ pub struct Issue {
     pub reactions: ReactionGroups,
     #[serde(default)]
     pub labels: Vec<Label>,
+    /// Title of the milestone the item belongs to.
+    #[serde(default)]
+    pub milestone: Option<String>,
     pub updated_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub url: String,
@@ -55,6 +60,18 @@ pub struct Issue {
     /// Deduplicated participant logins (from GitHub's `participants` connection).
     #[serde(default)]
     pub participants: Vec<String>,
+    /// Set when the item is a pull request listed by a mixed (`type = "any"`)
+    /// filter. `state` then reports merged PRs as closed.
+    #[serde(default)]
+    pub pr_state: Option<PrState>,
+    #[serde(default)]
+    pub is_draft: bool,
+}
+
+impl Issue {
+    pub fn is_pull_request(&self) -> bool {
+        self.pr_state.is_some()
+    }
 }
 
 /// Detailed Issue data fetched for the sidebar tabs.
@@ -62,4 +79,7 @@ pub struct Issue {
 pub struct IssueDetail {
     pub body: String,
     pub timeline_events: Vec<TimelineEvent>,
+    /// Parent, sub-issues and dependencies, for the Links tab.
+    #[serde(default)]
+    pub links: IssueLinks,
 }
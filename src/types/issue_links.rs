@@ -0,0 +1,305 @@
+use serde::{Deserialize, Serialize};
+
+use super::common::RepoRef;
+use super::issue::IssueState;
+
+/// An issue linked to another one: a node of its dependency graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueLink {
+    pub repo: RepoRef,
+    pub number: u64,
+    /// Empty for a bare reference in the body.
+    #[serde(default)]
+    pub title: String,
+    /// `None` until known: the body only names the issue.
+    #[serde(default)]
+    pub state: Option<IssueState>,
+    /// Sub-issues of the linked issue itself.
+    #[serde(default)]
+    pub sub_issue_count: u32,
+}
+
+impl IssueLink {
+    fn reference(repo: RepoRef, number: u64, title: &str) -> Self {
+        Self {
+            repo,
+            number,
+            title: title.to_owned(),
+            state: None,
+            sub_issue_count: 0,
+        }
+    }
+
+    pub fn is(&self, repo: &RepoRef, number: u64) -> bool {
+        self.number == number && self.repo == *repo
+    }
+
+    /// `#12` within `home`, `owner/repo#12` elsewhere.
+    pub fn short_ref(&self, home: Option<&RepoRef>) -> String {
+        if home == Some(&self.repo) {
+            format!("#{}", self.number)
+        } else {
+            format!("{}#{}", self.repo.full_name(), self.number)
+        }
+    }
+}
+
+/// Parent, sub-issues and blocking relations of an issue.
+///
+/// Sub-issues and the parent come from GitHub; the body adds task-list
+/// items naming an issue as further children, and "blocked by" / "depends
+/// on" / "blocks" lines (or list sections under such a heading) as
+/// dependencies.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueLinks {
+    pub parent: Option<IssueLink>,
+    pub children: Vec<IssueLink>,
+    pub blocked_by: Vec<IssueLink>,
+    pub blocks: Vec<IssueLink>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    Child,
+    BlockedBy,
+    Blocks,
+}
+
+impl IssueLinks {
+    /// Links of issue `number` in `repo`: GitHub's `parent` and
+    /// `sub_issues`, plus the references in `body`.
+    pub fn new(
+        parent: Option<IssueLink>,
+        sub_issues: Vec<IssueLink>,
+        body: &str,
+        repo: &RepoRef,
+        number: u64,
+    ) -> Self {
+        let mut links = Self {
+            parent,
+            children: sub_issues,
+            ..Self::default()
+        };
+        for (relation, link) in body_references(body, repo) {
+            let known =
+                link.is(repo, number) || links.nodes().any(|n| n.is(&link.repo, link.number));
+            if known {
+                continue;
+            }
+            match relation {
+                Relation::Child => links.children.push(link),
+                Relation::BlockedBy => links.blocked_by.push(link),
+                Relation::Blocks => links.blocks.push(link),
+            }
+        }
+        links
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes().next().is_none()
+    }
+
+    /// Every linked issue in display order: parent, children, blocked by,
+    /// blocks.
+    pub fn nodes(&self) -> impl Iterator<Item = &IssueLink> {
+        self.parent
+            .iter()
+            .chain(&self.children)
+            .chain(&self.blocked_by)
+            .chain(&self.blocks)
+    }
+
+    /// Mutable counterpart of [`nodes`](Self::nodes).
+    pub fn nodes_mut(&mut self) -> impl Iterator<Item = &mut IssueLink> {
+        self.parent
+            .iter_mut()
+            .chain(&mut self.children)
+            .chain(&mut self.blocked_by)
+            .chain(&mut self.blocks)
+    }
+}
+
+/// Issues named in `body`, with the relation their line gives them.
+fn body_references(body: &str, repo: &RepoRef) -> Vec<(Relation, IssueLink)> {
+    let mut found = Vec::new();
+    // Relation of the list under a "Blocked by:"-style line or heading.
+    let mut section: Option<Relation> = None;
+    for line in body.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            section = None;
+            continue;
+        }
+        let item = list_item(line);
+        if let Some((relation, before, rest)) = relation_phrase(line) {
+            let refs = issue_refs(rest, repo);
+            // Only a line that is nothing but the phrase heads a list.
+            let is_markup = |s: &str| s.trim_matches(['#', '*', '_', ':', ' ']).is_empty();
+            if refs.is_empty() && is_markup(before) && is_markup(rest) {
+                section = Some(relation);
+            }
+            found.extend(
+                refs.into_iter()
+                    .map(|(r, n)| (relation, IssueLink::reference(r, n, ""))),
+            );
+            continue;
+        }
+        if line.trim_start_matches('#').starts_with(' ') {
+            section = None;
+            continue;
+        }
+        let Some((task, text)) = item else {
+            continue;
+        };
+        let relation = match section {
+            Some(relation) => relation,
+            None if task => Relation::Child,
+            None => continue,
+        };
+        let (first, title) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+        if let Some((r, n)) = issue_ref(first, repo) {
+            found.push((relation, IssueLink::reference(r, n, title.trim())));
+        } else if section.is_some() {
+            found.extend(
+                issue_refs(text, repo)
+                    .into_iter()
+                    .map(|(r, n)| (relation, IssueLink::reference(r, n, ""))),
+            );
+        }
+    }
+    found
+}
+
+/// `(is_task, text)` of a markdown list item: `- [ ] text` is a task,
+/// `- text` is not.
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    let rest = line
+        .strip_prefix(['-', '*', '+'])
+        .or_else(|| {
+            let digits = line
+                .find(|c: char| !c.is_ascii_digit())
+                .filter(|&n| n > 0)?;
+            line[digits..].strip_prefix(['.', ')'])
+        })?
+        .strip_prefix(' ')?
+        .trim_start();
+    for box_ in ["[ ]", "[x]", "[X]"] {
+        if let Some(text) = rest.strip_prefix(box_) {
+            return Some((true, text.trim_start()));
+        }
+    }
+    Some((false, rest))
+}
+
+/// The relation a "blocked by" / "depends on" / "blocks" phrase in `line`
+/// introduces, with the text before and after the phrase.
+fn relation_phrase(line: &str) -> Option<(Relation, &str, &str)> {
+    // ASCII lowercasing keeps byte offsets valid in `line`.
+    let lower = line.to_ascii_lowercase();
+    [
+        ("blocked by", Relation::BlockedBy),
+        ("depends on", Relation::BlockedBy),
+        ("blocking", Relation::Blocks),
+        ("blocks", Relation::Blocks),
+    ]
+    .into_iter()
+    .find_map(|(phrase, relation)| {
+        let at = find_word(&lower, phrase)?;
+        Some((relation, &line[..at], &line[at + phrase.len()..]))
+    })
+}
+
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    let is_word = |c: char| c.is_ascii_alphanumeric();
+    text.match_indices(word)
+        .map(|(i, _)| i)
+        .find(|&i| !text[..i].ends_with(is_word) && !text[i + word.len()..].starts_with(is_word))
+}
+
+fn issue_refs(text: &str, repo: &RepoRef) -> Vec<(RepoRef, u64)> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '(' | ')' | '[' | ']'))
+        .filter_map(|token| issue_ref(token, repo))
+        .collect()
+}
+
+/// `#12`, `owner/repo#12` or an issue URL, relative to `repo`.
+fn issue_ref(token: &str, repo: &RepoRef) -> Option<(RepoRef, u64)> {
+    let token = token.trim_end_matches(['.', ':', '!', '?', '*', '_']);
+    let token = token.trim_start_matches(['*', '_']);
+    if let Some(path) = token
+        .strip_prefix("https://")
+        .or_else(|| token.strip_prefix("http://"))
+    {
+        let mut parts = path.split('/').skip(1);
+        let (owner, name, kind, number) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+        if !matches!(kind, "issues" | "pull") {
+            return None;
+        }
+        let number = number.split(['#', '?']).next()?.parse().ok()?;
+        let linked = RepoRef::from_full_name(&format!("{owner}/{name}"))?;
+        return Some((linked, number));
+    }
+    let (prefix, number) = token.split_once('#')?;
+    let number = number.parse().ok()?;
+    if prefix.is_empty() {
+        return Some((repo.clone(), number));
+    }
+    let linked = RepoRef::from_full_name(prefix)?;
+    (!linked.owner.is_empty() && !linked.name.is_empty() && !linked.name.contains('/'))
+        .then_some((linked, number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(full: &str) -> RepoRef {
+        RepoRef::from_full_name(full).unwrap()
+    }
+
+    fn numbers(links: &[IssueLink]) -> Vec<u64> {
+        links.iter().map(|l| l.number).collect()
+    }
+
+    #[test]
+    fn body_tasks_and_dependency_lines_become_links() {
+        let body = "Epic for the new login flow.\n\
+                    \n\
+                    ## Tasks\n\
+                    - [x] #11 Design the form\n\
+                    - [ ] acme/api#4 Token endpoint\n\
+                    - [ ] Write the docs\n\
+                    - [ ] https://github.com/acme/web/issues/12\n\
+                    \n\
+                    Blocked by #7, acme/infra#3.\n\
+                    \n\
+                    **Blocks:**\n\
+                    - #20\n\
+                    - the release of #21\n";
+        let home = repo("acme/web");
+        let links = IssueLinks::new(None, Vec::new(), body, &home, 10);
+        assert_eq!(numbers(&links.children), [11, 4, 12]);
+        assert_eq!(links.children[0].title, "Design the form");
+        assert_eq!(links.children[1].short_ref(Some(&home)), "acme/api#4");
+        assert_eq!(numbers(&links.blocked_by), [7, 3]);
+        assert_eq!(links.blocked_by[1].repo, repo("acme/infra"));
+        assert_eq!(numbers(&links.blocks), [20, 21]);
+    }
+
+    #[test]
+    fn body_references_do_not_repeat_sub_issues_or_self() {
+        let home = repo("acme/web");
+        let sub = IssueLink {
+            title: "Design the form".into(),
+            state: Some(IssueState::Closed),
+            ..IssueLink::reference(home.clone(), 11, "")
+        };
+        let body = "- [x] #11\n- [ ] #10\nDepends on #11 and #12";
+        let links = IssueLinks::new(None, vec![sub], body, &home, 10);
+        assert_eq!(numbers(&links.children), [11]);
+        assert_eq!(links.children[0].state, Some(IssueState::Closed));
+        assert_eq!(numbers(&links.blocked_by), [12]);
+        assert_eq!(links.nodes().count(), 2);
+    }
+}
@@ -1,16 +1,28 @@
 // Shared domain types — used by both the engine layer and the UI layer.
 // Neither layer depends on the other; both import from this module.
 
+pub mod codeowners;
 pub mod common;
 pub mod issue;
+pub mod issue_links;
 pub mod notification;
+pub mod org_event;
 pub mod pr;
+pub mod profile;
+pub mod release;
 pub mod security_alert;
+pub mod viewer;
 pub mod workflow_run;
 
+pub use codeowners::*;
 pub use common::*;
 pub use issue::*;
+pub use issue_links::*;
 pub use notification::*;
+pub use org_event::*;
 pub use pr::*;
+pub use profile::*;
+pub use release::*;
 pub use security_alert::*;
+pub use viewer::*;
 pub use workflow_run::*;
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::common::RepoRef;
+use super::common::{CommitCheckState, RepoRef};
 use super::issue::SubjectType;
 
 // ---------------------------------------------------------------------------
@@ -50,6 +50,28 @@ pub enum NotificationStatus {
     Read,
 }
 
+/// Current state of the PR or issue a notification is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubjectState {
+    Open,
+    Draft,
+    Closed,
+    Merged,
+}
+
+/// Live status of a notification's subject, resolved after the fetch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubjectStatus {
+    pub state: SubjectState,
+    /// Status check rollup of the head commit (PRs only).
+    pub ci: Option<CommitCheckState>,
+    /// When the subject was closed or merged.
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Login of whoever opened the PR or issue.
+    #[serde(default)]
+    pub author: Option<String>,
+}
+
 // ---------------------------------------------------------------------------
 // Notification domain type
 // ---------------------------------------------------------------------------
@@ -66,4 +88,28 @@ pub struct Notification {
     #[serde(default)]
     pub url: String,
     pub updated_at: DateTime<Utc>,
+    /// `None` until resolved, and for subjects that are not PRs or issues.
+    #[serde(default)]
+    pub subject_status: Option<SubjectStatus>,
+}
+
+impl Notification {
+    /// Number of the PR or issue, parsed from its URL.
+    pub fn subject_number(&self) -> Option<u64> {
+        if !matches!(
+            self.subject_type,
+            Some(SubjectType::PullRequest | SubjectType::Issue)
+        ) {
+            return None;
+        }
+        self.url.rsplit('/').next()?.parse().ok()
+    }
+
+    /// Whether the subject has been closed or merged, so the notification
+    /// likely needs no action.
+    pub fn is_resolved(&self) -> bool {
+        self.subject_status
+            .as_ref()
+            .is_some_and(|s| matches!(s.state, SubjectState::Closed | SubjectState::Merged))
+    }
 }
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Where an organization's events were read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrgEventSource {
+    /// The audit log (Enterprise Cloud, organization owners).
+    AuditLog,
+    /// The public events feed, for everyone else.
+    Events,
+    /// Events of a tab's `repos`, or received by the viewer.
+    Feed,
+}
+
+/// The issue or pull request an event is about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventItem {
+    /// `owner/repo`.
+    pub repo: String,
+    pub number: u64,
+    pub is_pr: bool,
+}
+
+/// A member, repository or settings change in an organization, or an
+/// activity feed entry (push, release, new issue, star).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrgEvent {
+    /// Audit-log style action: `repo.create`, `org.add_member`, or for the
+    /// events feed the event type with its payload action (`member.added`).
+    pub action: String,
+    pub actor: Option<String>,
+    /// What the action applied to: a repository, user or team.
+    pub target: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Page showing the event, empty when there is none.
+    pub url: String,
+    /// Issue or PR the event is about, for jumping to it.
+    #[serde(default)]
+    pub item: Option<EventItem>,
+}
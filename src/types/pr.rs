@@ -1,10 +1,17 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::codeowners::CodeOwners;
 use super::common::{
-    Actor, CheckRun, Comment, Commit, File, Label, RepoRef, Review, ReviewThread, TimelineEvent,
+    Actor, CheckConclusion, CheckRun, CheckStatus, Comment, Commit, CommitCheckState,
+    CommitSignature, File, Label, ReactionGroups, RepoRef, Review, ReviewState, ReviewThread,
+    TimelineEvent,
 };
 
+/// How long after its last update a PR without checks waits for them to be
+/// reported before it is merged when green.
+pub const CHECKS_GRACE: TimeDelta = TimeDelta::minutes(2);
+
 // ---------------------------------------------------------------------------
 // PR-specific enums
 // ---------------------------------------------------------------------------
@@ -71,6 +78,23 @@ pub enum AuthorAssociation {
     Owner,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeploymentState {
+    Active,
+    Pending,
+    Queued,
+    InProgress,
+    Waiting,
+    Error,
+    Failure,
+    Inactive,
+    Destroyed,
+    Abandoned,
+    #[serde(other)]
+    Unknown,
+}
+
 // ---------------------------------------------------------------------------
 // PR domain types
 // ---------------------------------------------------------------------------
@@ -93,6 +117,9 @@ pub struct PullRequest {
     pub deletions: u32,
     #[serde(default)]
     pub head_ref: String,
+    /// Head commit, as checked by `check_runs`.
+    #[serde(default)]
+    pub head_sha: String,
     #[serde(default)]
     pub base_ref: String,
     #[serde(default)]
@@ -107,8 +134,13 @@ pub struct PullRequest {
     pub review_threads: Vec<ReviewThread>,
     #[serde(default)]
     pub review_requests: Vec<Actor>,
+    /// Teams asked to review, as `org/team-slug`.
+    #[serde(default)]
+    pub team_review_requests: Vec<String>,
     #[serde(default)]
     pub reviews: Vec<Review>,
+    #[serde(default)]
+    pub review_times: ReviewTimes,
     #[serde(skip)]
     pub timeline_events: Vec<TimelineEvent>,
     #[serde(default)]
@@ -122,6 +154,8 @@ pub struct PullRequest {
     /// Total comment count (from GraphQL `comments { totalCount }`).
     #[serde(default)]
     pub comment_count: u32,
+    #[serde(default)]
+    pub reactions: ReactionGroups,
     pub author_association: Option<AuthorAssociation>,
     /// Deduplicated participant logins (from GitHub's `participants` connection).
     #[serde(default)]
@@ -148,6 +182,235 @@ impl PullRequest {
             repo_name: self.head_repo_name.clone()?,
         })
     }
+
+    /// Whether the PR is a draft or its title starts with one of the
+    /// `wip_patterns` (case-insensitive).
+    pub fn is_draft_or_wip(&self, wip_patterns: &[String]) -> bool {
+        let title = self.title.trim_start().to_lowercase();
+        self.is_draft
+            || wip_patterns
+                .iter()
+                .filter(|p| !p.is_empty())
+                .any(|p| title.starts_with(&p.to_lowercase()))
+    }
+
+    /// Whether review was requested from one of `teams` (`org/team-slug`,
+    /// compared case-insensitively).
+    pub fn requests_team_review(&self, teams: &[String]) -> bool {
+        self.team_review_requests
+            .iter()
+            .any(|req| teams.iter().any(|t| t.eq_ignore_ascii_case(req)))
+    }
+
+    /// When the review still pending was last requested, while the PR is
+    /// open.
+    pub fn in_review_since(&self) -> Option<DateTime<Utc>> {
+        let pending = !self.review_requests.is_empty() || !self.team_review_requests.is_empty();
+        if self.state != PrState::Open || !pending {
+            return None;
+        }
+        self.review_times.last_requested_at
+    }
+
+    /// Time from the first review request (or opening, when reviewed
+    /// unasked) to the first review, or waited so far while none came.
+    pub fn first_review_wait(&self, now: DateTime<Utc>) -> Option<FirstReviewWait> {
+        let times = &self.review_times;
+        match (times.first_requested_at, times.first_reviewed_at) {
+            (requested, Some(reviewed)) => Some(FirstReviewWait::Reviewed(
+                reviewed - requested.unwrap_or(self.created_at).min(reviewed),
+            )),
+            (Some(requested), None) if self.state == PrState::Open => {
+                Some(FirstReviewWait::Waiting(now - requested.min(now)))
+            }
+            _ => None,
+        }
+    }
+
+    /// When the last check of a fully green head commit completed.
+    pub fn green_since(&self) -> Option<DateTime<Utc>> {
+        let all_green = !self.check_runs.is_empty()
+            && self.check_runs.iter().all(|c| {
+                matches!(
+                    c.conclusion,
+                    Some(
+                        CheckConclusion::Success
+                            | CheckConclusion::Neutral
+                            | CheckConclusion::Skipped
+                    )
+                )
+            });
+        if !all_green {
+            return None;
+        }
+        self.check_runs.iter().filter_map(|c| c.completed_at).max()
+    }
+
+    /// The combined state of the head commit's checks.
+    pub fn ci_state(&self) -> CiState {
+        if self.check_runs.is_empty() {
+            CiState::NoChecks
+        } else if self.check_runs.iter().any(|c| {
+            matches!(
+                c.conclusion,
+                Some(
+                    CheckConclusion::Failure
+                        | CheckConclusion::TimedOut
+                        | CheckConclusion::Cancelled
+                        | CheckConclusion::ActionRequired
+                )
+            )
+        }) {
+            CiState::Failing
+        } else if self
+            .check_runs
+            .iter()
+            .any(|c| c.status != Some(CheckStatus::Completed))
+        {
+            CiState::Pending
+        } else {
+            CiState::Passing
+        }
+    }
+
+    /// The state a watch compares between polls.
+    pub fn watch_snapshot(&self) -> PrWatchSnapshot {
+        PrWatchSnapshot {
+            state: match self.state {
+                PrState::Open => "open",
+                PrState::Closed => "closed",
+                PrState::Merged => "merged",
+            },
+            ci: self.ci_state(),
+            review: match self.review_decision {
+                Some(ReviewDecision::Approved) => "approved",
+                Some(ReviewDecision::ChangesRequested) => "changes requested",
+                Some(ReviewDecision::ReviewRequired) => "review required",
+                None => "no review",
+            },
+            mergeable: match self.mergeable {
+                Some(MergeableState::Mergeable) => Some("mergeable"),
+                Some(MergeableState::Conflicting) => Some("conflicting"),
+                Some(MergeableState::Unknown) | None => None,
+            },
+        }
+    }
+}
+
+/// The verdict of a submitted review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+impl ReviewEvent {
+    /// The `event` of the REST reviews endpoint.
+    pub fn as_api_str(self) -> &'static str {
+        match self {
+            Self::Approve => "APPROVE",
+            Self::RequestChanges => "REQUEST_CHANGES",
+            Self::Comment => "COMMENT",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Approve => "Approve",
+            Self::RequestChanges => "Request changes",
+            Self::Comment => "Comment",
+        }
+    }
+
+    /// The verdict after `self` when cycling through them.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Self::Approve => Self::RequestChanges,
+            Self::RequestChanges => Self::Comment,
+            Self::Comment => Self::Approve,
+        }
+    }
+}
+
+/// A comment on lines of a PR's changes, submitted with a review.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewLineComment {
+    pub path: String,
+    /// Line in the new version of the file.
+    pub line: u32,
+    /// First line of a multi-line comment, ending at `line`.
+    pub start_line: Option<u32>,
+    pub body: String,
+}
+
+/// Review request and first review times of a PR.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewTimes {
+    pub first_requested_at: Option<DateTime<Utc>>,
+    pub last_requested_at: Option<DateTime<Utc>>,
+    /// First review by someone other than the author.
+    pub first_reviewed_at: Option<DateTime<Utc>>,
+}
+
+/// Time to a PR's first review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirstReviewWait {
+    Reviewed(TimeDelta),
+    /// Not reviewed yet, though requested: the time waited so far.
+    Waiting(TimeDelta),
+}
+
+/// The combined state of a PR's checks, see [`PullRequest::ci_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiState {
+    NoChecks,
+    Pending,
+    Failing,
+    Passing,
+}
+
+impl CiState {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::NoChecks => "no checks",
+            Self::Pending => "pending",
+            Self::Failing => "failing",
+            Self::Passing => "passing",
+        }
+    }
+}
+
+/// CI, review and mergeability of a watched PR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrWatchSnapshot {
+    pub state: &'static str,
+    pub ci: CiState,
+    pub review: &'static str,
+    /// `None` while GitHub is still computing mergeability.
+    pub mergeable: Option<&'static str>,
+}
+
+impl PrWatchSnapshot {
+    /// One `what: before → after` line per field that changed since `earlier`.
+    ///
+    /// Mergeability going to or from "not computed yet" is not a change.
+    pub fn changes_since(&self, earlier: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+        let mut diff = |what: &str, before: &str, after: &str| {
+            if before != after {
+                changes.push(format!("{what}: {before} \u{2192} {after}"));
+            }
+        };
+        diff("state", earlier.state, self.state);
+        diff("CI", earlier.ci.label(), self.ci.label());
+        diff("review", earlier.review, self.review);
+        if let (Some(before), Some(after)) = (earlier.mergeable, self.mergeable) {
+            diff("merge", before, after);
+        }
+        changes
+    }
 }
 
 /// Detailed PR data fetched for the sidebar tabs.
@@ -163,6 +426,379 @@ pub struct PrDetail {
     pub mergeable: Option<MergeableState>,
     /// How many commits behind base this PR is (from REST compare API).
     pub behind_by: Option<u32>,
+    /// Paging state of `commits`.
+    #[serde(default)]
+    pub commits_page: PageCursor,
+    /// Paging state of `files`.
+    #[serde(default)]
+    pub files_page: PageCursor,
+    /// Deployments of the head commit, newest first.
+    #[serde(default)]
+    pub deployments: Vec<Deployment>,
+    /// Workflow runs of the head commit waiting for a maintainer's approval,
+    /// as GitHub holds those of first-time contributors' forks.
+    #[serde(default)]
+    pub awaiting_approval: Vec<AwaitingRun>,
+    /// Base-branch rules and merge queue state.
+    #[serde(default)]
+    pub merge_requirements: MergeRequirements,
+    /// `CODEOWNERS` of the base branch; empty when the repo has none.
+    #[serde(default)]
+    pub code_owners: CodeOwners,
+}
+
+/// A code owner of some of a PR's changed files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnerApproval {
+    /// `@user`, `@org/team` or an email, as written in `CODEOWNERS`.
+    pub owner: String,
+    /// Changed files the owner covers.
+    pub files: usize,
+    pub approved: bool,
+}
+
+/// Base-branch protection (classic rules and rulesets combined) and merge
+/// queue state for a PR.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[expect(clippy::struct_excessive_bools)]
+pub struct MergeRequirements {
+    /// Approving reviews required; `0` when reviews are not required or the
+    /// rules are not readable.
+    pub required_approvals: u32,
+    pub requires_code_owner_review: bool,
+    pub requires_conversation_resolution: bool,
+    /// Every commit needs a verified signature.
+    pub requires_signatures: bool,
+    /// Status check contexts the base branch requires.
+    pub required_checks: Vec<String>,
+    pub merge_queue_enabled: bool,
+    /// Set while the PR is in the merge queue.
+    pub queue_entry: Option<MergeQueueEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MergeQueueEntryState {
+    AwaitingChecks,
+    Locked,
+    Mergeable,
+    Queued,
+    Unmergeable,
+    #[serde(other)]
+    Unknown,
+}
+
+impl MergeQueueEntryState {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::AwaitingChecks => "awaiting checks",
+            Self::Locked => "locked",
+            Self::Mergeable => "mergeable",
+            Self::Queued => "queued",
+            Self::Unmergeable => "unmergeable",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// A PR's place in its base branch's merge queue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeQueueEntry {
+    /// 1-based position in the queue.
+    pub position: u32,
+    pub state: MergeQueueEntryState,
+    /// GitHub's estimate until merge, in seconds.
+    pub estimated_secs: Option<u64>,
+}
+
+/// A branch's merge queue, front of the queue first.
+#[derive(Debug, Clone)]
+pub struct MergeQueue {
+    pub branch: String,
+    pub url: String,
+    pub entries: Vec<MergeQueueItem>,
+}
+
+/// A PR waiting in a merge queue.
+#[derive(Debug, Clone)]
+pub struct MergeQueueItem {
+    pub entry: MergeQueueEntry,
+    pub number: u64,
+    pub title: String,
+    pub author: Option<String>,
+    pub url: String,
+    pub enqueued_at: Option<DateTime<Utc>>,
+    /// Check rollup of the merge group commit the queue is testing.
+    pub ci: Option<CommitCheckState>,
+}
+
+/// A workflow run held until a maintainer approves it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AwaitingRun {
+    pub run_id: u64,
+    pub workflow: String,
+}
+
+/// A deployment of the PR's head commit to an environment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Deployment {
+    pub environment: String,
+    pub state: Option<DeploymentState>,
+    /// Environment URL from the latest deployment status (e.g. a preview site).
+    pub url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PrDetail {
+    /// Cursor for the next page of `connection`, if more pages remain.
+    pub fn next_cursor(&self, connection: PrDetailConnection) -> Option<&str> {
+        match connection {
+            PrDetailConnection::Commits => self.commits_page.next.as_deref(),
+            PrDetailConnection::Files => self.files_page.next.as_deref(),
+        }
+    }
+
+    /// Append a page fetched for the cursor returned by [`Self::next_cursor`].
+    pub fn append_page(&mut self, page: PrDetailPage) {
+        match page {
+            PrDetailPage::Commits { commits, page } => {
+                self.commits.extend(commits);
+                self.commits_page = page;
+            }
+            PrDetailPage::Files { files, page } => {
+                self.files.extend(files);
+                self.files_page = page;
+            }
+        }
+    }
+
+    /// Owners of the changed files in order of first appearance, and whether
+    /// each approved: a user by their latest review, a team by an approving
+    /// review submitted on its behalf.
+    pub fn code_owner_approvals(&self) -> Vec<OwnerApproval> {
+        let mut latest: Vec<&Review> = Vec::new();
+        for review in &self.reviews {
+            let Some(login) = review.author.as_ref().map(|a| a.login.as_str()) else {
+                continue;
+            };
+            if review.state == ReviewState::Commented {
+                continue;
+            }
+            latest.retain(|r| r.author.as_ref().is_none_or(|a| a.login != login));
+            latest.push(review);
+        }
+        let approvals: Vec<&Review> = latest
+            .into_iter()
+            .filter(|r| r.state == ReviewState::Approved)
+            .collect();
+        let approved = |owner: &str| {
+            let Some(name) = owner.strip_prefix('@') else {
+                return false;
+            };
+            approvals.iter().any(|r| {
+                if name.contains('/') {
+                    r.on_behalf_of.iter().any(|t| t.eq_ignore_ascii_case(name))
+                } else {
+                    r.author
+                        .as_ref()
+                        .is_some_and(|a| a.login.eq_ignore_ascii_case(name))
+                }
+            })
+        };
+
+        let mut owners: Vec<OwnerApproval> = Vec::new();
+        for file in &self.files {
+            for owner in self.code_owners.owners_for(&file.path) {
+                if let Some(entry) = owners.iter_mut().find(|o| o.owner == *owner) {
+                    entry.files += 1;
+                } else {
+                    owners.push(OwnerApproval {
+                        owner: owner.clone(),
+                        files: 1,
+                        approved: approved(owner),
+                    });
+                }
+            }
+        }
+        owners
+    }
+
+    /// Why `pr` cannot be merged yet, most fundamental reason first. Empty
+    /// when nothing known blocks it.
+    pub fn merge_blockers(&self, pr: &PullRequest) -> Vec<String> {
+        let mut blockers = Vec::new();
+        if pr.state != PrState::Open {
+            return blockers;
+        }
+        let status = pr.merge_state_status;
+        if pr.is_draft {
+            blockers.push("Draft: mark it ready for review".to_owned());
+        }
+        if status == Some(MergeStateStatus::Dirty)
+            || self.mergeable.or(pr.mergeable) == Some(MergeableState::Conflicting)
+        {
+            blockers.push("Conflicts with the base branch".to_owned());
+        } else if status == Some(MergeStateStatus::Behind) {
+            blockers.push("Behind the base branch".to_owned());
+        }
+
+        let req = &self.merge_requirements;
+        let approvals = u32::try_from(
+            pr.reviews
+                .iter()
+                .filter(|r| r.state == ReviewState::Approved)
+                .count(),
+        )
+        .unwrap_or(u32::MAX);
+        match pr.review_decision {
+            Some(ReviewDecision::ChangesRequested) => {
+                blockers.push("Changes requested by a reviewer".to_owned());
+            }
+            Some(ReviewDecision::ReviewRequired) if approvals < req.required_approvals => {
+                let n = req.required_approvals;
+                let noun = if n == 1 { "review" } else { "reviews" };
+                blockers.push(format!("Needs {n} approving {noun} ({approvals} so far)"));
+            }
+            Some(ReviewDecision::ReviewRequired) if req.requires_code_owner_review => {
+                blockers.push("Needs a code owner review".to_owned());
+            }
+            Some(ReviewDecision::ReviewRequired) => {
+                blockers.push("Review required".to_owned());
+            }
+            _ => {}
+        }
+
+        let mut required: Vec<&str> = req.required_checks.iter().map(String::as_str).collect();
+        for check in pr.check_runs.iter().filter(|c| c.is_required) {
+            if !required.contains(&check.name.as_str()) {
+                required.push(&check.name);
+            }
+        }
+        for name in required {
+            let Some(check) = pr.check_runs.iter().find(|c| c.name == name) else {
+                blockers.push(format!("Required check `{name}` has not run"));
+                continue;
+            };
+            if check.status.is_some_and(|s| s != CheckStatus::Completed) {
+                blockers.push(format!("Required check `{name}` pending"));
+            } else if !matches!(
+                check.conclusion,
+                Some(
+                    CheckConclusion::Success | CheckConclusion::Neutral | CheckConclusion::Skipped
+                )
+            ) {
+                blockers.push(format!("Required check `{name}` failed"));
+            }
+        }
+
+        if req.requires_conversation_resolution {
+            let open = self
+                .review_threads
+                .iter()
+                .filter(|t| !t.is_resolved)
+                .count();
+            if open > 0 {
+                let noun = if open == 1 {
+                    "conversation"
+                } else {
+                    "conversations"
+                };
+                blockers.push(format!("{open} unresolved {noun}"));
+            }
+        }
+
+        if req.requires_signatures {
+            let unverified = self
+                .commits
+                .iter()
+                .filter(|c| c.signature != CommitSignature::Verified)
+                .count();
+            if unverified > 0 {
+                let noun = if unverified == 1 { "commit" } else { "commits" };
+                blockers.push(format!("{unverified} {noun} without a verified signature"));
+            }
+        }
+
+        if blockers.is_empty() && status == Some(MergeStateStatus::Blocked) {
+            blockers.push("Blocked by the base branch rules".to_owned());
+        }
+        blockers
+    }
+
+    /// What keeps an open `pr` from being merged when green at `now`, `None`
+    /// once it can be: nothing in [`Self::merge_blockers`], any checks
+    /// passed, and mergeability computed. A PR without checks waits for
+    /// [`CHECKS_GRACE`] after its last update, as right after a push they
+    /// have not registered yet; past that, the repo is taken to have no CI.
+    pub fn merge_wait(&self, pr: &PullRequest, now: DateTime<Utc>) -> Option<String> {
+        if let Some(blocker) = self.merge_blockers(pr).into_iter().next() {
+            return Some(blocker);
+        }
+        match pr.ci_state() {
+            CiState::NoChecks if now - pr.updated_at < CHECKS_GRACE => {
+                return Some("No checks reported yet".to_owned());
+            }
+            CiState::Pending => return Some("Checks pending".to_owned()),
+            CiState::Failing => return Some("A check failed".to_owned()),
+            CiState::NoChecks | CiState::Passing => {}
+        }
+        match self.mergeable.or(pr.mergeable) {
+            Some(MergeableState::Mergeable) => None,
+            Some(MergeableState::Conflicting) => Some("Conflicts with the base branch".to_owned()),
+            _ => Some("Mergeability not computed yet".to_owned()),
+        }
+    }
+
+    /// The most recent deployment of each environment, newest first.
+    pub fn latest_deployments(&self) -> Vec<&Deployment> {
+        let mut latest: Vec<&Deployment> = Vec::new();
+        for d in &self.deployments {
+            match latest.iter_mut().find(|l| l.environment == d.environment) {
+                Some(l) if l.created_at < d.created_at => *l = d,
+                Some(_) => {}
+                None => latest.push(d),
+            }
+        }
+        latest.sort_by_key(|d| std::cmp::Reverse(d.created_at));
+        latest
+    }
+
+    /// Give up on loading further pages of `connection`.
+    pub fn stop_paging(&mut self, connection: PrDetailConnection) {
+        match connection {
+            PrDetailConnection::Commits => self.commits_page.next = None,
+            PrDetailConnection::Files => self.files_page.next = None,
+        }
+    }
+}
+
+/// Paging state of a detail connection that is loaded page by page.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PageCursor {
+    /// Total item count reported by GitHub; may exceed the loaded count.
+    pub total: usize,
+    /// Cursor after the last loaded item, while more pages remain.
+    pub next: Option<String>,
+}
+
+/// A paged connection of [`PrDetail`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrDetailConnection {
+    Commits,
+    Files,
+}
+
+/// One further page of a [`PrDetail`] connection.
+#[derive(Debug, Clone)]
+pub enum PrDetailPage {
+    Commits {
+        commits: Vec<Commit>,
+        page: PageCursor,
+    },
+    Files {
+        files: Vec<File>,
+        page: PageCursor,
+    },
 }
 
 #[cfg(test)]
@@ -187,6 +823,7 @@ mod tests {
             additions: 0,
             deletions: 0,
             head_ref: "feat/x".into(),
+            head_sha: String::new(),
             base_ref: "main".into(),
             labels: vec![],
             assignees: vec![],
@@ -194,7 +831,9 @@ mod tests {
             comments: vec![],
             review_threads: vec![],
             review_requests: vec![],
+            team_review_requests: vec![],
             reviews: vec![],
+            review_times: ReviewTimes::default(),
             timeline_events: vec![],
             files: vec![],
             check_runs: vec![],
@@ -206,6 +845,7 @@ mod tests {
                 name: "repo".into(),
             }),
             comment_count: 0,
+            reactions: ReactionGroups::default(),
             author_association: None,
             participants: vec![],
             merge_state_status: None,
@@ -239,4 +879,344 @@ mod tests {
         let pr = pr_stub(Some("alice"), None, "graelo");
         assert!(pr.fork_source().is_none());
     }
+
+    #[test]
+    fn is_draft_or_wip_matches_title_prefix() {
+        let patterns = vec!["WIP".to_owned(), "[draft]".to_owned()];
+        let mut pr = pr_stub(None, None, "graelo");
+        pr.title = "wip: refactor parser".into();
+        assert!(pr.is_draft_or_wip(&patterns));
+        pr.title = "[Draft] new API".into();
+        assert!(pr.is_draft_or_wip(&patterns));
+        pr.title = "Fix WIP handling".into();
+        assert!(!pr.is_draft_or_wip(&patterns));
+        pr.is_draft = true;
+        assert!(pr.is_draft_or_wip(&[]));
+    }
+
+    #[test]
+    fn requests_team_review_matches_case_insensitively() {
+        let mut pr = pr_stub(None, None, "graelo");
+        pr.team_review_requests = vec!["acme/Platform".into()];
+        assert!(pr.requests_team_review(&["acme/platform".to_owned()]));
+        assert!(!pr.requests_team_review(&["acme/web".to_owned()]));
+        assert!(!pr.requests_team_review(&[]));
+    }
+
+    #[test]
+    fn first_review_wait_counts_from_the_first_request() {
+        let at = |h: i64| DateTime::UNIX_EPOCH + TimeDelta::hours(h);
+        let mut pr = pr_stub(None, None, "graelo");
+        pr.created_at = at(0);
+        assert_eq!(pr.first_review_wait(at(10)), None);
+        pr.review_times.first_requested_at = Some(at(2));
+        assert_eq!(
+            pr.first_review_wait(at(10)),
+            Some(FirstReviewWait::Waiting(TimeDelta::hours(8)))
+        );
+        pr.review_times.first_reviewed_at = Some(at(5));
+        assert_eq!(
+            pr.first_review_wait(at(10)),
+            Some(FirstReviewWait::Reviewed(TimeDelta::hours(3)))
+        );
+        pr.review_times.first_requested_at = None;
+        assert_eq!(
+            pr.first_review_wait(at(10)),
+            Some(FirstReviewWait::Reviewed(TimeDelta::hours(5)))
+        );
+    }
+
+    fn detail_stub() -> PrDetail {
+        PrDetail {
+            body: String::new(),
+            reviews: vec![],
+            review_threads: vec![],
+            timeline_events: vec![],
+            commits: vec![],
+            files: vec![],
+            mergeable: None,
+            behind_by: None,
+            commits_page: PageCursor::default(),
+            files_page: PageCursor::default(),
+            deployments: vec![],
+            awaiting_approval: vec![],
+            merge_requirements: MergeRequirements::default(),
+            code_owners: CodeOwners::default(),
+        }
+    }
+
+    fn check(name: &str, status: CheckStatus, conclusion: Option<CheckConclusion>) -> CheckRun {
+        CheckRun {
+            name: name.into(),
+            status: Some(status),
+            conclusion,
+            url: None,
+            workflow_run_id: None,
+            workflow_name: None,
+            job_id: None,
+            started_at: None,
+            completed_at: None,
+            is_required: false,
+        }
+    }
+
+    #[test]
+    fn merge_blockers_explain_reviews_and_required_checks() {
+        let mut pr = pr_stub(None, None, "graelo");
+        pr.review_decision = Some(ReviewDecision::ReviewRequired);
+        pr.merge_state_status = Some(MergeStateStatus::Blocked);
+        pr.check_runs = vec![
+            check("build", CheckStatus::InProgress, None),
+            check(
+                "lint",
+                CheckStatus::Completed,
+                Some(CheckConclusion::Failure),
+            ),
+            check(
+                "docs",
+                CheckStatus::Completed,
+                Some(CheckConclusion::Failure),
+            ),
+        ];
+        let mut detail = detail_stub();
+        detail.merge_requirements = MergeRequirements {
+            required_approvals: 2,
+            required_checks: vec!["build".into(), "lint".into(), "e2e".into()],
+            ..MergeRequirements::default()
+        };
+        assert_eq!(
+            detail.merge_blockers(&pr),
+            vec![
+                "Needs 2 approving reviews (0 so far)",
+                "Required check `build` pending",
+                "Required check `lint` failed",
+                "Required check `e2e` has not run",
+            ]
+        );
+
+        pr.review_decision = Some(ReviewDecision::Approved);
+        detail.merge_requirements.required_checks.clear();
+        assert_eq!(
+            detail.merge_blockers(&pr),
+            vec!["Blocked by the base branch rules"]
+        );
+        pr.merge_state_status = Some(MergeStateStatus::Clean);
+        assert!(detail.merge_blockers(&pr).is_empty());
+    }
+
+    #[test]
+    fn merge_wait_holds_until_every_check_passes() {
+        let mut pr = pr_stub(None, None, "graelo");
+        pr.merge_state_status = Some(MergeStateStatus::Unstable);
+        pr.mergeable = Some(MergeableState::Mergeable);
+        let detail = detail_stub();
+        let now = pr.updated_at;
+        assert_eq!(
+            detail.merge_wait(&pr, now).as_deref(),
+            Some("No checks reported yet")
+        );
+        // A repo without CI never reports any.
+        assert_eq!(detail.merge_wait(&pr, now + CHECKS_GRACE), None);
+
+        pr.check_runs = vec![check("build", CheckStatus::InProgress, None)];
+        assert_eq!(
+            detail.merge_wait(&pr, now).as_deref(),
+            Some("Checks pending")
+        );
+
+        pr.check_runs[0] = check(
+            "build",
+            CheckStatus::Completed,
+            Some(CheckConclusion::Failure),
+        );
+        assert_eq!(
+            detail.merge_wait(&pr, now).as_deref(),
+            Some("A check failed")
+        );
+
+        pr.check_runs[0].conclusion = Some(CheckConclusion::Success);
+        pr.mergeable = Some(MergeableState::Unknown);
+        assert_eq!(
+            detail.merge_wait(&pr, now).as_deref(),
+            Some("Mergeability not computed yet")
+        );
+        pr.mergeable = Some(MergeableState::Mergeable);
+        assert_eq!(detail.merge_wait(&pr, now), None);
+
+        pr.review_decision = Some(ReviewDecision::ChangesRequested);
+        assert_eq!(
+            detail.merge_wait(&pr, now).as_deref(),
+            Some("Changes requested by a reviewer")
+        );
+    }
+
+    #[test]
+    fn code_owner_approvals_follow_latest_review_and_team() {
+        let review = |login: &str, state, teams: &[&str]| Review {
+            author: Some(Actor {
+                login: login.into(),
+                avatar_url: String::new(),
+            }),
+            state,
+            body: String::new(),
+            submitted_at: None,
+            on_behalf_of: teams.iter().map(|&t| t.to_owned()).collect(),
+        };
+        let mut detail = detail_stub();
+        detail.files = vec![file("src/a.rs"), file("src/b.rs"), file("docs/x.md")];
+        detail.code_owners = CodeOwners::parse("*.rs @acme/core @Alice\n/docs/ @bob\n");
+        detail.reviews = vec![
+            review("alice", ReviewState::Approved, &[]),
+            review("bob", ReviewState::Approved, &[]),
+            review("carol", ReviewState::Approved, &["acme/core"]),
+            review("bob", ReviewState::Commented, &[]),
+            review("bob", ReviewState::ChangesRequested, &[]),
+        ];
+        let owners: Vec<_> = detail
+            .code_owner_approvals()
+            .into_iter()
+            .map(|o| (o.owner, o.files, o.approved))
+            .collect();
+        assert_eq!(
+            owners,
+            vec![
+                ("@acme/core".to_owned(), 2, true),
+                ("@Alice".to_owned(), 2, true),
+                ("@bob".to_owned(), 1, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_blockers_count_commits_without_verified_signature() {
+        let mut pr = pr_stub(None, None, "graelo");
+        pr.merge_state_status = Some(MergeStateStatus::Blocked);
+        let commit = |signature| Commit {
+            sha: "abc1234".into(),
+            message: "Fix".into(),
+            author: None,
+            committed_date: None,
+            check_state: None,
+            signature,
+        };
+        let mut detail = detail_stub();
+        detail.commits = vec![
+            commit(CommitSignature::Verified),
+            commit(CommitSignature::from_state("UNKNOWN_KEY")),
+            commit(CommitSignature::from_state("unsigned")),
+        ];
+        detail.merge_requirements.requires_signatures = true;
+        assert_eq!(
+            detail.merge_blockers(&pr),
+            vec!["2 commits without a verified signature"]
+        );
+        detail.merge_requirements.requires_signatures = false;
+        assert_eq!(
+            detail.merge_blockers(&pr),
+            vec!["Blocked by the base branch rules"]
+        );
+    }
+
+    #[test]
+    fn latest_deployments_keeps_newest_per_environment() {
+        let at = |secs| DateTime::from_timestamp(secs, 0).unwrap();
+        let deploy = |env: &str, secs| Deployment {
+            environment: env.into(),
+            state: Some(DeploymentState::Active),
+            url: None,
+            created_at: at(secs),
+        };
+        let mut detail = detail_stub();
+        detail.deployments = vec![
+            deploy("preview", 10),
+            deploy("staging", 30),
+            deploy("preview", 20),
+        ];
+        let latest: Vec<_> = detail
+            .latest_deployments()
+            .into_iter()
+            .map(|d| (d.environment.as_str(), d.created_at))
+            .collect();
+        assert_eq!(latest, vec![("staging", at(30)), ("preview", at(20))]);
+    }
+
+    fn file(path: &str) -> File {
+        File {
+            path: path.into(),
+            additions: 1,
+            deletions: 0,
+            status: None,
+            viewer_state: crate::types::FileViewedState::Unviewed,
+        }
+    }
+
+    #[test]
+    fn append_page_extends_files_and_advances_cursor() {
+        let mut detail = detail_stub();
+        detail.files = vec![file("a")];
+        detail.files_page = PageCursor {
+            total: 3,
+            next: Some("c1".into()),
+        };
+        assert_eq!(detail.next_cursor(PrDetailConnection::Files), Some("c1"));
+        assert_eq!(detail.next_cursor(PrDetailConnection::Commits), None);
+
+        detail.append_page(PrDetailPage::Files {
+            files: vec![file("b"), file("c")],
+            page: PageCursor {
+                total: 3,
+                next: None,
+            },
+        });
+        assert_eq!(detail.files.len(), 3);
+        assert_eq!(detail.next_cursor(PrDetailConnection::Files), None);
+    }
+
+    #[test]
+    fn watch_snapshot_reports_ci_and_review_changes() {
+        let mut pr = pr_stub(None, None, "graelo");
+        pr.check_runs = vec![CheckRun {
+            name: "ci".into(),
+            status: Some(CheckStatus::InProgress),
+            conclusion: None,
+            url: None,
+            workflow_run_id: None,
+            workflow_name: None,
+            job_id: None,
+            started_at: None,
+            completed_at: None,
+            is_required: true,
+        }];
+        pr.mergeable = Some(MergeableState::Mergeable);
+        let before = pr.watch_snapshot();
+        assert_eq!(before.ci, CiState::Pending);
+
+        pr.check_runs[0].status = Some(CheckStatus::Completed);
+        pr.check_runs[0].conclusion = Some(CheckConclusion::Failure);
+        pr.review_decision = Some(ReviewDecision::Approved);
+        pr.mergeable = Some(MergeableState::Unknown);
+        let after = pr.watch_snapshot();
+        assert_eq!(
+            after.changes_since(&before),
+            vec![
+                "CI: pending \u{2192} failing",
+                "review: no review \u{2192} approved"
+            ]
+        );
+        assert!(after.changes_since(&after).is_empty());
+    }
+
+    #[test]
+    fn stop_paging_keeps_loaded_items_and_total() {
+        let mut detail = detail_stub();
+        detail.files = vec![file("a")];
+        detail.files_page = PageCursor {
+            total: 3,
+            next: Some("c1".into()),
+        };
+        detail.stop_paging(PrDetailConnection::Files);
+        assert_eq!(detail.files.len(), 1);
+        assert_eq!(detail.files_page.total, 3);
+        assert_eq!(detail.next_cursor(PrDetailConnection::Files), None);
+    }
 }
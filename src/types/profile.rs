@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+
+/// One day of a contribution calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContributionDay {
+    pub date: NaiveDate,
+    pub count: u32,
+    /// GitHub's quartile bucket for the day, 0 (none) to 4 (busiest).
+    pub level: u8,
+}
+
+/// A user's contributions over the last year, as on their GitHub profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContributionCalendar {
+    pub login: String,
+    pub total: u32,
+    /// Weeks from oldest to newest, days Sunday first. The first and last
+    /// weeks may be partial.
+    pub weeks: Vec<Vec<ContributionDay>>,
+}
+
+impl ContributionCalendar {
+    /// Contributions made in week `idx`.
+    pub fn week_total(&self, idx: usize) -> u32 {
+        self.weeks
+            .get(idx)
+            .map_or(0, |days| days.iter().map(|d| d.count).sum())
+    }
+}
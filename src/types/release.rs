@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+
+/// A release of a repo, draft or published.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    pub id: u64,
+    pub tag: String,
+    /// Title; empty when the release has none.
+    pub name: String,
+    pub url: String,
+    /// API URL of the source tarball; `None` on drafts.
+    pub tarball_url: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    /// `None` on drafts.
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// A tag of a repo, whether or not a release was made from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitTag {
+    pub name: String,
+    /// API URL of the source tarball.
+    pub tarball_url: String,
+}
@@ -0,0 +1,170 @@
+/// What the authenticated user can see, gathered once by `gh-board init`.
+#[derive(Debug, Clone, Default)]
+pub struct ViewerSetup {
+    pub login: String,
+    /// OAuth scopes granted to the token (`X-OAuth-Scopes`). `None` when the
+    /// header is absent, e.g. for fine-grained tokens and GitHub App tokens.
+    pub scopes: Option<Vec<String>>,
+    /// Logins of the organizations the user belongs to.
+    pub orgs: Vec<String>,
+    /// `owner/repo` slugs the user can access, most recently pushed first.
+    pub repos: Vec<String>,
+}
+
+/// Outcome of the startup check of one host.
+#[derive(Debug, Clone, Default)]
+pub struct HostHealth {
+    pub host: String,
+    /// Who the token signs in as, when it works.
+    pub login: Option<String>,
+    /// Empty when the host is ready to use.
+    pub problems: Vec<HealthProblem>,
+}
+
+/// One failed startup check, with what to do about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthProblem {
+    pub check: HealthCheck,
+    pub message: String,
+    pub hint: String,
+}
+
+/// What a startup check verifies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthCheck {
+    /// A token is found and GitHub accepts it.
+    Token,
+    /// The host answers at all.
+    Connection,
+    /// The TLS handshake succeeds.
+    Tls,
+    /// The local clock agrees with the host's.
+    Clock,
+    /// The GraphQL API answers, not only REST.
+    GraphQl,
+}
+
+impl HealthCheck {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Token => "token",
+            Self::Connection => "connection",
+            Self::Tls => "TLS",
+            Self::Clock => "clock",
+            Self::GraphQl => "GraphQL",
+        }
+    }
+}
+
+/// A comment snippet offered by the composer's template picker: one of the
+/// viewer's GitHub saved replies or a `reply_templates` entry from config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedReply {
+    pub title: String,
+    pub body: String,
+}
+
+/// A gh-board feature that only works when the token has a given scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopedFeature {
+    PrivateRepos,
+    OrgFilters,
+    Notifications,
+    SecurityAlerts,
+    WorkflowRuns,
+}
+
+/// A token scope a gh-board feature depends on.
+pub struct ScopeRequirement {
+    pub feature: ScopedFeature,
+    /// Any of these scopes satisfies the requirement (broader scopes imply
+    /// narrower ones, e.g. `write:org` implies `read:org`). The first one is
+    /// the scope suggested to the user.
+    pub any_of: &'static [&'static str],
+    pub description: &'static str,
+}
+
+pub const SCOPE_REQUIREMENTS: &[ScopeRequirement] = &[
+    ScopeRequirement {
+        feature: ScopedFeature::PrivateRepos,
+        any_of: &["repo"],
+        description: "private repositories in the PRs, Issues and Actions views",
+    },
+    ScopeRequirement {
+        feature: ScopedFeature::OrgFilters,
+        any_of: &["read:org", "write:org", "admin:org"],
+        description: "organization filters and team review requests",
+    },
+    ScopeRequirement {
+        feature: ScopedFeature::Notifications,
+        any_of: &["notifications", "repo"],
+        description: "the Notifications view",
+    },
+    ScopeRequirement {
+        feature: ScopedFeature::SecurityAlerts,
+        any_of: &["security_events", "repo"],
+        description: "the Alerts view",
+    },
+    ScopeRequirement {
+        feature: ScopedFeature::WorkflowRuns,
+        any_of: &["repo", "public_repo"],
+        description: "re-running, cancelling and deleting workflow runs",
+    },
+];
+
+impl ScopeRequirement {
+    /// Footer message explaining why the feature is off and how to enable it.
+    pub fn hint(&self) -> String {
+        let scope = self.any_of[0];
+        format!(
+            "Disabled: {} needs the `{scope}` token scope (run `gh auth refresh -s {scope}`)",
+            self.description
+        )
+    }
+}
+
+/// The requirements not satisfied by any of the `granted` scopes.
+pub fn missing_scopes(granted: &[String]) -> Vec<&'static ScopeRequirement> {
+    SCOPE_REQUIREMENTS
+        .iter()
+        .filter(|req| !req.any_of.iter().any(|s| granted.iter().any(|g| g == s)))
+        .collect()
+}
+
+/// The hint for `feature` when the `granted` scopes cannot use it.
+///
+/// `None` scopes (fine-grained and GitHub App tokens report none) are given
+/// the benefit of the doubt.
+pub fn scope_hint(granted: Option<&[String]>, feature: ScopedFeature) -> Option<String> {
+    missing_scopes(granted?)
+        .into_iter()
+        .find(|req| req.feature == feature)
+        .map(ScopeRequirement::hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_scopes_honours_implied_scopes() {
+        let granted = vec!["repo".to_owned(), "admin:org".to_owned()];
+        assert!(missing_scopes(&granted).is_empty());
+
+        let missing = missing_scopes(&["notifications".to_owned()]);
+        let features: Vec<_> = missing.iter().map(|r| r.any_of[0]).collect();
+        assert_eq!(
+            features,
+            vec!["repo", "read:org", "security_events", "repo"]
+        );
+    }
+
+    #[test]
+    fn scope_hint_names_the_scope_to_add() {
+        let granted = vec!["read:org".to_owned()];
+        let hint = scope_hint(Some(&granted), ScopedFeature::Notifications).unwrap();
+        assert!(hint.contains("gh auth refresh -s notifications"));
+        assert!(scope_hint(None, ScopedFeature::Notifications).is_none());
+        assert!(scope_hint(Some(&granted), ScopedFeature::OrgFilters).is_none());
+    }
+}
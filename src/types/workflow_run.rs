@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::common::Actor;
+use super::common::{Actor, CommitSignature};
 
 // ---------------------------------------------------------------------------
 // WorkflowRun-specific enums
@@ -98,6 +98,9 @@ pub struct WorkflowJob {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowRun {
     pub id: u64,
+    /// ID of the workflow definition; shared by every run of that workflow.
+    #[serde(default)]
+    pub workflow_id: u64,
     /// Workflow file name / workflow display name.
     pub name: String,
     /// Commit message head / trigger title shown in the GitHub UI.
@@ -107,7 +110,26 @@ pub struct WorkflowRun {
     /// Trigger event: `"push"`, `"pull_request"`, `"schedule"`, …
     pub event: String,
     pub head_branch: Option<String>,
+    #[serde(default)]
+    pub head_sha: String,
+    /// `owner/repo` holding the head commit; differs from the run's own repo
+    /// for pull requests opened from forks.
+    #[serde(default)]
+    pub head_repository: Option<String>,
+    /// `owner/repo` the run belongs to.
+    #[serde(default)]
+    pub repository: Option<String>,
     pub actor: Option<Actor>,
+    /// Who started this attempt; differs from `actor` on a re-run.
+    #[serde(default)]
+    pub triggering_actor: Option<Actor>,
+    /// Full message of the head commit.
+    #[serde(default)]
+    pub head_commit_message: Option<String>,
+    /// Pull requests the run was triggered for. GitHub only lists PRs whose
+    /// head branch lives in the run's repo, so fork PRs never show up.
+    #[serde(default)]
+    pub pull_requests: Vec<u64>,
     pub run_number: u64,
     /// URL used for the `o` keybinding (open in browser).
     pub html_url: String,
@@ -116,3 +138,126 @@ pub struct WorkflowRun {
     #[serde(default)]
     pub run_started_at: Option<DateTime<Utc>>,
 }
+
+impl WorkflowRun {
+    /// Wall-clock duration of a completed run, in seconds.
+    pub fn duration_secs(&self) -> Option<i64> {
+        if self.status != RunStatus::Completed {
+            return None;
+        }
+        let start = self.run_started_at.unwrap_or(self.created_at);
+        Some((self.updated_at - start).num_seconds())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Run cleanup
+// ---------------------------------------------------------------------------
+
+/// Why the run cleanup action offers a run for deletion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupReason {
+    /// Created before the configured age threshold.
+    Expired,
+    /// Ran on a branch that no longer exists in the repository.
+    BranchDeleted,
+}
+
+/// A completed workflow run the cleanup dry-run would delete.
+#[derive(Debug, Clone)]
+pub struct RunCleanupCandidate {
+    pub run: WorkflowRun,
+    pub reason: CleanupReason,
+}
+
+// ---------------------------------------------------------------------------
+// Comparison against the last successful run
+// ---------------------------------------------------------------------------
+
+/// A commit between the last successful run and the compared run.
+#[derive(Debug, Clone)]
+pub struct ComparedCommit {
+    pub sha: String,
+    /// First line of the commit message.
+    pub title: String,
+    pub author: Option<String>,
+    pub signature: CommitSignature,
+}
+
+/// A job whose conclusion differs between the two runs. `None` on either side
+/// means the job only exists in the other run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobStatusChange {
+    pub name: String,
+    pub before: Option<RunConclusion>,
+    pub after: Option<RunConclusion>,
+}
+
+/// What changed between a run and the most recent successful run of the same
+/// workflow on the same branch.
+#[derive(Debug, Clone)]
+pub struct RunComparison {
+    pub baseline: WorkflowRun,
+    /// Newest first, possibly truncated; see `total_commits`.
+    pub commits: Vec<ComparedCommit>,
+    pub total_commits: usize,
+    pub job_changes: Vec<JobStatusChange>,
+    /// Compared run's duration minus the baseline's.
+    pub duration_delta_secs: Option<i64>,
+}
+
+// ---------------------------------------------------------------------------
+// Scheduled runs
+// ---------------------------------------------------------------------------
+
+/// A `schedule` trigger of a workflow file on the default branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowSchedule {
+    /// `owner/repo`.
+    pub repo: String,
+    /// The workflow's `name`, else its file name.
+    pub workflow: String,
+    /// Path of the workflow file, e.g. `.github/workflows/nightly.yml`.
+    pub path: String,
+    /// The cron expression, as written.
+    pub cron: String,
+}
+
+// ---------------------------------------------------------------------------
+// Manual runs
+// ---------------------------------------------------------------------------
+
+/// A workflow file on the default branch with a `workflow_dispatch` trigger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchableWorkflow {
+    /// `owner/repo`.
+    pub repo: String,
+    /// The workflow's `name`, else its file name.
+    pub name: String,
+    /// File name, e.g. `deploy.yml`, as the dispatch endpoint takes it.
+    pub file: String,
+    /// Declared `inputs`, in file order.
+    pub inputs: Vec<WorkflowInput>,
+}
+
+/// One `on.workflow_dispatch.inputs` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub required: bool,
+    pub default: Option<String>,
+    pub kind: WorkflowInputKind,
+}
+
+/// An input's `type`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum WorkflowInputKind {
+    #[default]
+    String,
+    Number,
+    Boolean,
+    Environment,
+    /// `choice`, with its `options`.
+    Choice(Vec<String>),
+}
@@ -36,6 +36,28 @@ pub(crate) fn owner_repo_from_url(url: &str) -> Option<(String, String)> {
     Some((owner.to_owned(), repo.to_owned()))
 }
 
+/// The host of a GitHub web URL, as filters and the engine name it: `None`
+/// for github.com (see [`parse_github_url`]).
+pub(crate) fn host_of(url: &str) -> Option<String> {
+    let after_scheme = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let host = after_scheme.split(['/', '?', '#']).next()?;
+    normalize_host(host)
+}
+
+/// `host` lowercased, without credentials or a default port; `None` when
+/// that is github.com (or `www.github.com`) or nothing.
+pub(crate) fn normalize_host(host: &str) -> Option<String> {
+    let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    let host = host
+        .strip_suffix(":443")
+        .or_else(|| host.strip_suffix(":80"))
+        .unwrap_or(host)
+        .to_ascii_lowercase();
+    (!host.is_empty() && host != "github.com" && host != "www.github.com").then_some(host)
+}
+
 /// Parse a GitHub URL into a structured deep-link target.
 ///
 /// Supported patterns:
@@ -48,6 +70,10 @@ pub(crate) fn owner_repo_from_url(url: &str) -> Option<(String, String)> {
 /// Query strings (`?tab=files`) and fragments (`#L42`) are stripped before
 /// parsing, so browser-copied URLs work as expected.
 ///
+/// Any host is accepted: an Enterprise Server domain (`ghe.acme.com`) or a
+/// GHE.com tenant (`acme.ghe.com`) comes back as `host`, lowercased and
+/// without a default port; github.com comes back as `None`.
+///
 /// Returns `None` for unrecognised or malformed URLs.
 ///
 /// # Examples
@@ -97,11 +123,7 @@ pub fn parse_github_url(url: &str) -> Option<ParsedGitHubUrl> {
         return None;
     }
 
-    let host = if host_str == "github.com" {
-        None
-    } else {
-        Some(host_str.to_owned())
-    };
+    let host = normalize_host(host_str);
 
     match segments[2] {
         "pull" => {
@@ -195,6 +217,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ghes_hosts_are_normalized() {
+        let url = "https://WWW.GHE.Acme.com:443/team/project/issues/3?q=1";
+        assert!(matches!(
+            parse_github_url(url),
+            Some(ParsedGitHubUrl::Issue { host: Some(host), .. }) if host == "www.ghe.acme.com"
+        ));
+        assert_eq!(
+            host_of("https://acme.ghe.com:8443/o/r/pull/1").as_deref(),
+            Some("acme.ghe.com:8443")
+        );
+        assert_eq!(host_of("https://www.github.com/o/r/pull/1"), None);
+        assert_eq!(host_of("https://bot@GitHub.com/o/r"), None);
+        assert_eq!(host_of("not a url"), None);
+    }
+
     #[test]
     fn parse_http_url() {
         let url = "http://github.com/owner/repo/issues/99";
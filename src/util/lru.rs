@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A size-bounded map that evicts the least recently used entry on insert.
+///
+/// Lookups through [`get`](Self::get) take `&self` so the cache can be read
+/// out of an iocraft `State` during render while still refreshing recency.
+/// Eviction scans all entries, which is fine for the small capacities used by
+/// per-view caches.
+#[derive(Debug)]
+pub(crate) struct LruCache<K, V> {
+    capacity: usize,
+    entries: HashMap<K, Entry<V>>,
+    clock: AtomicU64,
+}
+
+#[derive(Debug)]
+struct Entry<V> {
+    value: V,
+    last_used: AtomicU64,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    /// Create an empty cache holding at most `capacity` entries (minimum 1).
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Look up `key`, marking it as most recently used.
+    pub(crate) fn get(&self, key: &K) -> Option<&V> {
+        let entry = self.entries.get(key)?;
+        entry.last_used.store(self.tick(), Ordering::Relaxed);
+        Some(&entry.value)
+    }
+
+    /// Whether `key` is cached, without touching its recency.
+    pub(crate) fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Insert or replace `key`, evicting the least recently used entry when
+    /// the cache is full.
+    pub(crate) fn insert(&mut self, key: K, value: V) {
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_used.load(Ordering::Relaxed))
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        let last_used = AtomicU64::new(self.tick());
+        self.entries.insert(key, Entry { value, last_used });
+    }
+
+    /// Remove `key`, returning its value if it was cached.
+    pub(crate) fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.remove(key).map(|e| e.value)
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Clone for LruCache<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            capacity: self.capacity,
+            entries: self
+                .entries
+                .iter()
+                .map(|(k, e)| {
+                    let entry = Entry {
+                        value: e.value.clone(),
+                        last_used: AtomicU64::new(e.last_used.load(Ordering::Relaxed)),
+                    };
+                    (k.clone(), entry)
+                })
+                .collect(),
+            clock: AtomicU64::new(self.clock.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        assert_eq!(cache.get(&1), Some(&"a"));
+        cache.insert(3, "c");
+        assert_eq!(cache.entries.len(), 2);
+        assert!(cache.contains_key(&1));
+        assert!(!cache.contains_key(&2));
+        assert!(cache.contains_key(&3));
+    }
+
+    #[test]
+    fn replacing_a_key_does_not_evict() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        cache.insert(2, "b2");
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.get(&1), Some(&"a"));
+        assert_eq!(cache.get(&2), Some(&"b2"));
+    }
+
+    #[test]
+    fn clone_keeps_recency() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        let _ = cache.get(&1);
+        let mut copy = cache.clone();
+        copy.insert(3, "c");
+        assert!(copy.contains_key(&1));
+        assert!(!copy.contains_key(&2));
+    }
+
+    #[test]
+    fn zero_capacity_holds_one_entry() {
+        let mut cache = LruCache::new(0);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        assert_eq!(cache.entries.len(), 1);
+        assert!(cache.contains_key(&2));
+    }
+}
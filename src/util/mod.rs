@@ -0,0 +1,513 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{LazyLock, OnceLock};
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc};
+
+mod lru;
+
+pub(crate) use lru::LruCache;
+
+static EMOJI_REPLACER: LazyLock<gh_emoji::Replacer> = LazyLock::new(gh_emoji::Replacer::new);
+
+/// Expand GitHub emoji shortcodes (e.g. `:tada:` → 🎉) in the given text.
+///
+/// Returns `Cow::Borrowed` when no shortcodes are found, avoiding allocation.
+pub(crate) fn expand_emoji(text: &str) -> Cow<'_, str> {
+    EMOJI_REPLACER.replace_all(text)
+}
+
+/// Timezone used to render absolute timestamps (`defaults.timezone`).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Timezone {
+    /// The system timezone.
+    #[default]
+    Local,
+    Utc,
+    /// A fixed offset such as `+05:30`.
+    Offset(FixedOffset),
+    /// An IANA zone name such as `Europe/Paris`. It is applied by exporting
+    /// `TZ` at startup, after which it renders like [`Timezone::Local`].
+    Named(String),
+}
+
+impl Timezone {
+    /// Parse `"local"`, `"utc"`, a `±HH:MM` offset, or an IANA zone name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for a malformed offset or a zone name with no entry
+    /// in the system zoneinfo database.
+    pub fn parse(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("local") {
+            return Ok(Self::Local);
+        }
+        if s.eq_ignore_ascii_case("utc") || s == "Z" {
+            return Ok(Self::Utc);
+        }
+        if s.starts_with(['+', '-']) {
+            return match parse_offset(s) {
+                Some(offset) => Ok(Self::Offset(offset)),
+                None => bail!("invalid timezone offset {s:?} (expected e.g. \"+05:30\")"),
+            };
+        }
+        let zoneinfo = std::env::var_os("TZDIR").unwrap_or_else(|| "/usr/share/zoneinfo".into());
+        if s.is_empty() || s.contains("..") || !Path::new(&zoneinfo).join(s).is_file() {
+            bail!(
+                "unknown timezone {s:?} (expected \"local\", \"utc\", an offset, or an IANA name)"
+            );
+        }
+        Ok(Self::Named(s.to_owned()))
+    }
+}
+
+/// Parse `±HH:MM` or `±HHMM` into a fixed offset.
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+static TIMEZONE: OnceLock<Timezone> = OnceLock::new();
+static DATES_TOGGLED: AtomicBool = AtomicBool::new(false);
+static DATE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Strftime format used when absolute dates are toggled on while
+/// `date_format` is `"relative"`.
+const DEFAULT_ABSOLUTE_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Set the timezone for absolute timestamps. Only the first call takes effect.
+pub fn set_timezone(tz: Timezone) {
+    let _ = TIMEZONE.set(tz);
+}
+
+/// Flip every date between relative and absolute rendering.
+pub(crate) fn toggle_dates() {
+    DATES_TOGGLED.fetch_xor(true, Ordering::Relaxed);
+    DATE_GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// How often relative dates are re-rendered between refreshes.
+pub(crate) const DATE_TICK_INTERVAL: std::time::Duration = std::time::Duration::from_mins(1);
+
+/// Have relative dates re-rendered, so `"3m"` does not stay `"3m"` until
+/// the next refresh.
+pub(crate) fn tick_dates() {
+    DATE_GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counter bumped by every [`toggle_dates`] and [`tick_dates`] call.
+///
+/// Views that cache formatted rows compare it against the value they last
+/// rendered with to know when to rebuild their date cells.
+pub(crate) fn date_generation() -> u64 {
+    DATE_GENERATION.load(Ordering::Relaxed)
+}
+
+/// Age thresholds after which an open item counts as stale
+/// (`[defaults.stale]`). Unset thresholds never trigger.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StaleThresholds {
+    pub warn_days: Option<u32>,
+    pub alert_days: Option<u32>,
+}
+
+/// How long an item has gone without an update, against [`StaleThresholds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Staleness {
+    Fresh,
+    Warn,
+    Alert,
+}
+
+impl StaleThresholds {
+    /// Classify an item last updated at `updated`.
+    pub fn classify(&self, updated: &DateTime<Utc>, now: DateTime<Utc>) -> Staleness {
+        let age_days = now.signed_duration_since(*updated).num_days();
+        let reached = |days: Option<u32>| days.is_some_and(|d| age_days >= i64::from(d));
+        if reached(self.alert_days) {
+            Staleness::Alert
+        } else if reached(self.warn_days) {
+            Staleness::Warn
+        } else {
+            Staleness::Fresh
+        }
+    }
+}
+
+static STALE_THRESHOLDS: OnceLock<StaleThresholds> = OnceLock::new();
+
+/// Set the staleness thresholds. Only the first call takes effect.
+pub fn set_stale_thresholds(thresholds: StaleThresholds) {
+    let _ = STALE_THRESHOLDS.set(thresholds);
+}
+
+/// Staleness of an item last updated at `updated`, against the configured
+/// thresholds.
+pub(crate) fn staleness(updated: &DateTime<Utc>) -> Staleness {
+    STALE_THRESHOLDS
+        .get()
+        .copied()
+        .unwrap_or_default()
+        .classify(updated, Utc::now())
+}
+
+/// Size class of a PR by lines changed, against [`SizeThresholds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PrSize {
+    Xs,
+    S,
+    M,
+    L,
+    Xl,
+}
+
+impl PrSize {
+    /// Every class, smallest first.
+    pub const ALL: [Self; 5] = [Self::Xs, Self::S, Self::M, Self::L, Self::Xl];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Xs => "XS",
+            Self::S => "S",
+            Self::M => "M",
+            Self::L => "L",
+            Self::Xl => "XL",
+        }
+    }
+
+    /// Parse a label, case-insensitively.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|size| size.label().eq_ignore_ascii_case(s))
+    }
+}
+
+/// Lines changed below which a PR is XS, S, M and L (`[defaults.pr_size]`);
+/// anything larger is XL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeThresholds {
+    pub xs: u32,
+    pub s: u32,
+    pub m: u32,
+    pub l: u32,
+}
+
+impl Default for SizeThresholds {
+    fn default() -> Self {
+        Self {
+            xs: 10,
+            s: 100,
+            m: 500,
+            l: 1000,
+        }
+    }
+}
+
+impl SizeThresholds {
+    /// Classify a PR changing `lines` lines.
+    pub fn classify(&self, lines: u64) -> PrSize {
+        [self.xs, self.s, self.m, self.l]
+            .into_iter()
+            .zip(PrSize::ALL)
+            .find(|(limit, _)| lines < u64::from(*limit))
+            .map_or(PrSize::Xl, |(_, size)| size)
+    }
+}
+
+static SIZE_THRESHOLDS: OnceLock<SizeThresholds> = OnceLock::new();
+
+/// Set the size class thresholds. Only the first call takes effect.
+pub fn set_size_thresholds(thresholds: SizeThresholds) {
+    let _ = SIZE_THRESHOLDS.set(thresholds);
+}
+
+/// Size class of a PR with these changes, against the configured thresholds.
+pub(crate) fn pr_size(additions: u32, deletions: u32) -> PrSize {
+    SIZE_THRESHOLDS
+        .get()
+        .copied()
+        .unwrap_or_default()
+        .classify(u64::from(additions) + u64::from(deletions))
+}
+
+/// Format a datetime according to the configured date format.
+///
+/// If `date_format` is `"relative"` (or empty/default), displays relative
+/// times like `"2h"`, `"3d"`, `"1w"`. Otherwise, uses `strftime`-style
+/// formatting in the configured timezone. The runtime date toggle swaps the
+/// two, so every caller honours it.
+pub(crate) fn format_date(dt: &DateTime<Utc>, date_format: &str) -> String {
+    format_date_with(
+        dt,
+        date_format,
+        DATES_TOGGLED.load(Ordering::Relaxed),
+        TIMEZONE.get().unwrap_or(&Timezone::Local),
+    )
+}
+
+/// Format a datetime with `fmt` in the configured timezone, whatever the
+/// date toggle: relative times only count backwards.
+pub(crate) fn format_local(dt: &DateTime<Utc>, fmt: &str) -> String {
+    format_date_with(dt, fmt, false, TIMEZONE.get().unwrap_or(&Timezone::Local))
+}
+
+fn format_date_with(dt: &DateTime<Utc>, date_format: &str, toggled: bool, tz: &Timezone) -> String {
+    let relative = date_format.is_empty() || date_format == "relative";
+    if relative != toggled {
+        return format_relative_time(dt);
+    }
+    let fmt = if relative {
+        DEFAULT_ABSOLUTE_FORMAT
+    } else {
+        date_format
+    };
+    match tz {
+        Timezone::Utc => dt.format(fmt).to_string(),
+        Timezone::Offset(offset) => dt.with_timezone(offset).format(fmt).to_string(),
+        Timezone::Local | Timezone::Named(_) => dt.with_timezone(&Local).format(fmt).to_string(),
+    }
+}
+
+/// The current wall-clock date and time in the configured timezone.
+fn local_now() -> NaiveDateTime {
+    let now = Utc::now();
+    match TIMEZONE.get().unwrap_or(&Timezone::Local) {
+        Timezone::Utc => now.naive_utc(),
+        Timezone::Offset(offset) => now.with_timezone(offset).naive_local(),
+        Timezone::Local | Timezone::Named(_) => now.with_timezone(&Local).naive_local(),
+    }
+}
+
+/// The current wall-clock time of day in the configured timezone.
+pub(crate) fn time_of_day_now() -> NaiveTime {
+    local_now().time()
+}
+
+/// Today's date in the configured timezone.
+pub(crate) fn today() -> NaiveDate {
+    local_now().date()
+}
+
+/// `$XDG_STATE_HOME/gh-board/<file>` (`~/.local/state` by default), where
+/// state worth keeping across sessions lives.
+pub(crate) fn state_path(file: &str) -> Option<PathBuf> {
+    let state_dir = std::env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/state")))?;
+    Some(state_dir.join("gh-board").join(file))
+}
+
+/// `$XDG_CACHE_HOME/gh-board/<file>` (`~/.cache` by default), for data that
+/// can be fetched again if lost.
+pub(crate) fn cache_path(file: &str) -> Option<PathBuf> {
+    let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")))?;
+    Some(cache_dir.join("gh-board").join(file))
+}
+
+/// Replace `path` with `contents` through a temporary sibling, so readers
+/// never see a half-written file.
+pub(crate) fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Format the elapsed duration between two optional timestamps.
+///
+/// Returns e.g. `"12s"`, `"2m 05s"`, or an empty string when either timestamp
+/// is `None`.
+pub(crate) fn format_duration(
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+) -> String {
+    let (Some(start), Some(end)) = (started_at, completed_at) else {
+        return String::new();
+    };
+    let secs = (end - start).num_seconds().max(0).cast_unsigned();
+    if secs < 60 {
+        format!("{secs}s")
+    } else {
+        let m = secs / 60;
+        let s = secs % 60;
+        format!("{m}m{s:02}s")
+    }
+}
+
+/// Format a datetime as relative time (e.g., `"2h"`, `"3d"`, `"1w"`).
+pub(crate) fn format_relative_time(dt: &DateTime<Utc>) -> String {
+    let duration = Utc::now().signed_duration_since(dt);
+    if duration.num_minutes() < 1 {
+        return "now".to_owned();
+    }
+    format_span(duration)
+}
+
+/// Format a length of time in its largest unit (e.g., `"45m"`, `"3d"`).
+pub(crate) fn format_span(duration: TimeDelta) -> String {
+    let minutes = duration.num_minutes().max(0);
+    if minutes < 60 {
+        return format!("{minutes}m");
+    }
+
+    let hours = duration.num_hours();
+    if hours < 24 {
+        return format!("{hours}h");
+    }
+
+    let days = duration.num_days();
+    if days < 7 {
+        return format!("{days}d");
+    }
+    if days < 30 {
+        return format!("{}w", days / 7);
+    }
+    if days < 365 {
+        return format!("{}mo", days / 30);
+    }
+
+    format!("{}y", days / 365)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_tick_rebuilds_dates_without_toggling_them() {
+        let before = date_generation();
+        tick_dates();
+        assert!(date_generation() > before);
+        let dt = Utc::now() - chrono::TimeDelta::minutes(3);
+        assert_eq!(format_date(&dt, "relative"), "3m");
+    }
+
+    #[test]
+    fn known_shortcode_is_expanded() {
+        assert_eq!(expand_emoji(":tada:").as_ref(), "🎉");
+    }
+
+    #[test]
+    fn unknown_shortcode_is_left_as_is() {
+        assert_eq!(
+            expand_emoji(":not_a_real_emoji:").as_ref(),
+            ":not_a_real_emoji:"
+        );
+    }
+
+    #[test]
+    fn text_without_shortcodes_is_unchanged() {
+        let result = expand_emoji("Hello, world!");
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(result.as_ref(), "Hello, world!");
+    }
+
+    #[test]
+    fn mixed_text_expands_only_known() {
+        assert_eq!(
+            expand_emoji("Hello :tada: :unknown:").as_ref(),
+            "Hello 🎉 :unknown:"
+        );
+    }
+
+    fn sample_date() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-03-01T12:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn strftime_format_renders_in_timezone() {
+        let dt = sample_date();
+        let tz = Timezone::parse("+05:30").unwrap();
+        assert_eq!(
+            format_date_with(&dt, "%Y-%m-%d %H:%M", false, &tz),
+            "2024-03-01 18:00"
+        );
+        assert_eq!(
+            format_date_with(&dt, "%H:%M", false, &Timezone::Utc),
+            "12:30"
+        );
+    }
+
+    #[test]
+    fn toggle_swaps_relative_and_absolute() {
+        let dt = sample_date();
+        assert_eq!(
+            format_date_with(&dt, "relative", true, &Timezone::Utc),
+            "2024-03-01 12:30"
+        );
+        assert_eq!(
+            format_date_with(&dt, "%H:%M", true, &Timezone::Utc),
+            format_relative_time(&dt)
+        );
+    }
+
+    #[test]
+    fn timezone_parse_accepts_keywords_and_offsets() {
+        assert_eq!(Timezone::parse("local").unwrap(), Timezone::Local);
+        assert_eq!(Timezone::parse("UTC").unwrap(), Timezone::Utc);
+        assert_eq!(
+            Timezone::parse("-0800").unwrap(),
+            Timezone::Offset(FixedOffset::west_opt(8 * 3600).unwrap())
+        );
+        assert!(Timezone::parse("+25:00").is_err());
+        assert!(Timezone::parse("Not/AZone").is_err());
+        assert!(Timezone::parse("../etc/passwd").is_err());
+    }
+
+    #[test]
+    fn stale_thresholds_classify_by_age() {
+        let updated = sample_date();
+        let days = |n| updated + chrono::Duration::days(n);
+        let thresholds = StaleThresholds {
+            warn_days: Some(7),
+            alert_days: Some(30),
+        };
+        assert_eq!(thresholds.classify(&updated, days(6)), Staleness::Fresh);
+        assert_eq!(thresholds.classify(&updated, days(7)), Staleness::Warn);
+        assert_eq!(thresholds.classify(&updated, days(30)), Staleness::Alert);
+        assert_eq!(
+            StaleThresholds::default().classify(&updated, days(365)),
+            Staleness::Fresh
+        );
+    }
+
+    #[test]
+    fn size_thresholds_classify_by_lines() {
+        let thresholds = SizeThresholds::default();
+        assert_eq!(thresholds.classify(0), PrSize::Xs);
+        assert_eq!(thresholds.classify(10), PrSize::S);
+        assert_eq!(thresholds.classify(499), PrSize::M);
+        assert_eq!(thresholds.classify(999), PrSize::L);
+        assert_eq!(thresholds.classify(1000), PrSize::Xl);
+        assert_eq!(PrSize::parse("xl"), Some(PrSize::Xl));
+        assert_eq!(PrSize::parse("XXL"), None);
+    }
+}
@@ -0,0 +1,185 @@
+//! Files of a PR marked viewed, remembered across sessions.
+//!
+//! The Files tab lists unviewed files first and counts the viewed ones, so a
+//! large review can be resumed where it stopped. Marks are kept per PR with
+//! the line counts the file had when it was marked: when those move, a new
+//! push touched the file and it shows as changed since viewed, the way
+//! GitHub dismisses its own viewed flag. Without a local mark, GitHub's
+//! `viewerViewedState` applies. Marks live in
+//! `$XDG_STATE_HOME/gh-board/viewed-files.json`.
+
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{File, FileViewedState};
+
+/// PRs kept overall; the least recently marked go first.
+const PRS_KEPT: usize = 500;
+
+/// A file marked viewed or unviewed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Mark {
+    path: String,
+    viewed: bool,
+    /// Line counts when marked.
+    additions: u32,
+    deletions: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct PrMarks {
+    /// `owner/repo#number`.
+    key: String,
+    marks: Vec<Mark>,
+    updated_at: DateTime<Utc>,
+}
+
+fn marks_path() -> Option<PathBuf> {
+    crate::util::state_path("viewed-files.json")
+}
+
+static MARKS: LazyLock<Mutex<Vec<PrMarks>>> = LazyLock::new(|| {
+    let marks = marks_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|text| match serde_json::from_str(&text) {
+            Ok(marks) => Some(marks),
+            Err(e) => {
+                tracing::warn!("ignoring unreadable viewed files: {e}");
+                None
+            }
+        })
+        .unwrap_or_default();
+    Mutex::new(marks)
+});
+
+fn with_marks<T>(f: impl FnOnce(&mut Vec<PrMarks>) -> T) -> T {
+    f(&mut MARKS.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+fn persist(marks: &[PrMarks]) {
+    let Some(path) = marks_path() else {
+        return;
+    };
+    let result = serde_json::to_string(marks)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| crate::util::write_atomic(&path, &json));
+    if let Err(e) = result {
+        tracing::warn!("saving viewed files to {}: {e}", path.display());
+    }
+}
+
+/// Store key of a PR.
+pub(crate) fn key(repo: &str, number: u64) -> String {
+    format!("{repo}#{number}")
+}
+
+/// State of `file` given the PR's local `marks`.
+fn resolve(marks: &[Mark], file: &File) -> FileViewedState {
+    match marks.iter().find(|m| m.path == file.path) {
+        Some(mark) if !mark.viewed => FileViewedState::Unviewed,
+        Some(mark) if (mark.additions, mark.deletions) == (file.additions, file.deletions) => {
+            FileViewedState::Viewed
+        }
+        Some(_) => FileViewedState::Dismissed,
+        None => file.viewer_state,
+    }
+}
+
+/// State of each of `files` of the PR `key`, in order.
+pub(crate) fn states(key: &str, files: &[File]) -> Vec<FileViewedState> {
+    with_marks(|all| {
+        let marks = all
+            .iter()
+            .find(|pr| pr.key == key)
+            .map_or(&[][..], |pr| pr.marks.as_slice());
+        files.iter().map(|file| resolve(marks, file)).collect()
+    })
+}
+
+fn mark(all: &mut Vec<PrMarks>, key: &str, files: &[&File], viewed: bool, now: DateTime<Utc>) {
+    let idx = all.iter().position(|pr| pr.key == key).unwrap_or_else(|| {
+        all.push(PrMarks {
+            key: key.to_owned(),
+            marks: Vec::new(),
+            updated_at: now,
+        });
+        all.len() - 1
+    });
+    let pr = &mut all[idx];
+    pr.updated_at = now;
+    for file in files {
+        pr.marks.retain(|m| m.path != file.path);
+        pr.marks.push(Mark {
+            path: file.path.clone(),
+            viewed,
+            additions: file.additions,
+            deletions: file.deletions,
+        });
+    }
+}
+
+/// Mark `files` of the PR `key` viewed (or unviewed), saving the change.
+pub(crate) fn set_viewed(key: &str, files: &[&File], viewed: bool) {
+    with_marks(|all| {
+        mark(all, key, files, viewed, Utc::now());
+        all.sort_by_key(|pr| std::cmp::Reverse(pr.updated_at));
+        all.truncate(PRS_KEPT);
+        persist(all);
+    });
+}
+
+/// Indices of `states`, files still to review first, each group in its
+/// original order.
+pub(crate) fn review_order(states: &[FileViewedState]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..states.len()).collect();
+    order.sort_by_key(|&i| states[i] == FileViewedState::Viewed);
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, additions: u32, viewer_state: FileViewedState) -> File {
+        File {
+            path: path.to_owned(),
+            additions,
+            deletions: 0,
+            status: None,
+            viewer_state,
+        }
+    }
+
+    #[test]
+    fn local_marks_override_github_and_notice_new_changes() {
+        let now = Utc::now();
+        let a = file("a.rs", 3, FileViewedState::Unviewed);
+        let b = file("b.rs", 1, FileViewedState::Viewed);
+        let c = file("c.rs", 5, FileViewedState::Unviewed);
+        let mut all = Vec::new();
+        mark(&mut all, "acme/app#7", &[&a, &c], true, now);
+        mark(&mut all, "acme/app#7", &[&b], false, now);
+
+        let pushed = file("c.rs", 8, FileViewedState::Unviewed);
+        let marks = &all[0].marks;
+        assert_eq!(resolve(marks, &a), FileViewedState::Viewed);
+        assert_eq!(resolve(marks, &b), FileViewedState::Unviewed);
+        assert_eq!(resolve(marks, &pushed), FileViewedState::Dismissed);
+        assert_eq!(
+            resolve(&[], &file("d.rs", 1, FileViewedState::Viewed)),
+            FileViewedState::Viewed
+        );
+    }
+
+    #[test]
+    fn review_order_puts_viewed_files_last() {
+        use FileViewedState::{Dismissed, Unviewed, Viewed};
+        assert_eq!(
+            review_order(&[Viewed, Unviewed, Dismissed, Viewed, Unviewed]),
+            [1, 2, 4, 0, 3]
+        );
+    }
+}
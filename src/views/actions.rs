@@ -1,47 +1,68 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
 
 use iocraft::prelude::*;
 
 use crate::actions::clipboard;
-use crate::app::{NavigationTarget, ViewKind};
+use crate::app::{NavigationTarget, ViewCounts, ViewKind, WatchBadges};
 use crate::color::{Color as AppColor, ColorDepth};
 use crate::components::footer::{
     self, ActionFeedback, Footer, FooterColors, FooterContent, RenderedFooter,
 };
 use crate::components::help_overlay::{HelpOverlay, HelpOverlayBuildConfig, RenderedHelpOverlay};
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
 use crate::components::sidebar::{
     RenderedSidebar, Sidebar, SidebarColors, SidebarMeta, SidebarTabConfig,
 };
 use crate::components::tab_bar::{RenderedTabBar, Tab, TabBar, TabBarColors};
 use crate::components::table::{
-    Cell, Column, RenderedTable, Row, ScrollableTable, TableBuildConfig,
+    self as table, Cell, Column, RenderedTable, Row, ScrollableTable, SortDirection, SortValue,
+    TableBuildConfig, TableClick, TableSort,
 };
 use crate::components::text_input::{RenderedTextInput, TextInput, TextInputColors};
+use crate::components::{dot_separator, ellipsis, sidebar_tabs};
 use crate::config::keybindings::{
     BuiltinAction, MergedBindings, ResolvedBinding, TemplateVars, ViewContext,
     execute_shell_command, expand_template, key_event_to_string,
 };
-use crate::config::types::ActionsFilter;
-use crate::engine::{EngineHandle, Event, FilterConfig, Request};
+use crate::config::types::{ActionsFilter, AppConfig, DispatchPreset};
+use crate::engine::{EngineHandle, Event, EventSender, FilterConfig, RefreshView, Request};
+use crate::github::actions::{CLEANUP_BATCH, CLEANUP_CHUNK};
 use crate::markdown::renderer::{StyledLine, StyledSpan};
 use crate::theme::ResolvedTheme;
-use crate::types::{RateLimitInfo, RunConclusion, RunStatus, WorkflowJob, WorkflowRun};
+use crate::types::{
+    CleanupReason, RateLimits, RunCleanupCandidate, RunComparison, RunConclusion, RunStatus,
+    WorkflowJob, WorkflowRun,
+};
 use crate::url::owner_repo_from_url;
+use crate::util::LruCache;
 use crate::views::MAX_EPHEMERAL_TABS;
+use crate::views::dispatch::{self, QuickRunKey};
+use crate::views::job_log::{self, JobLog, JobPicker, PickerOutcome};
+use crate::views::keep_tab;
+use crate::views::ref_picker::{self, RefPick, RefPicker};
+use crate::views::run_workflow::{self, RunWorkflowOverlay};
+use crate::views::schedules::{self, SchedulesOverlay};
+use crate::views::sidebar_focus;
+use crate::views::step_excerpt::StepExcerpts;
 use unicode_width::UnicodeWidthStr;
 
 // ---------------------------------------------------------------------------
 // Column definitions
 // ---------------------------------------------------------------------------
 
-fn actions_columns() -> Vec<Column> {
-    vec![
+/// Table columns; multi-repo tabs get a repo column after the run number.
+fn actions_columns(multi_repo: bool) -> Vec<Column> {
+    let mut columns = vec![
         Column {
             id: "status".to_owned(),
             header: " ".to_owned(),
             default_width_pct: 0.04,
             align: TextAlign::Center,
             fixed_width: Some(3),
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "run".to_owned(),
@@ -49,6 +70,7 @@ fn actions_columns() -> Vec<Column> {
             default_width_pct: 0.07,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: Some(SortDirection::Descending),
         },
         Column {
             id: "workflow".to_owned(),
@@ -56,6 +78,7 @@ fn actions_columns() -> Vec<Column> {
             default_width_pct: 0.15,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "title".to_owned(),
@@ -63,6 +86,7 @@ fn actions_columns() -> Vec<Column> {
             default_width_pct: 0.30,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "event".to_owned(),
@@ -70,6 +94,7 @@ fn actions_columns() -> Vec<Column> {
             default_width_pct: 0.12,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "branch".to_owned(),
@@ -77,6 +102,7 @@ fn actions_columns() -> Vec<Column> {
             default_width_pct: 0.16,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "actor".to_owned(),
@@ -84,6 +110,7 @@ fn actions_columns() -> Vec<Column> {
             default_width_pct: 0.10,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "age".to_owned(),
@@ -91,6 +118,7 @@ fn actions_columns() -> Vec<Column> {
             default_width_pct: 0.06,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: Some(SortDirection::Descending),
         },
         Column {
             id: "duration".to_owned(),
@@ -98,8 +126,41 @@ fn actions_columns() -> Vec<Column> {
             default_width_pct: 0.06,
             align: TextAlign::Right,
             fixed_width: Some(7),
+            sort: Some(SortDirection::Descending),
         },
-    ]
+    ];
+    if multi_repo {
+        columns.insert(
+            2,
+            Column {
+                id: "repo".to_owned(),
+                header: "Repo".to_owned(),
+                default_width_pct: 0.14,
+                align: TextAlign::Left,
+                fixed_width: None,
+                sort: Some(SortDirection::Ascending),
+            },
+        );
+    }
+    columns
+}
+
+/// The value `run` is sorted by in `column`, for columns whose cells don't
+/// sort as text.
+fn run_sort_value(run: &WorkflowRun, column: &str) -> Option<SortValue> {
+    match column {
+        // Failures first, then runs still going.
+        "status" => Some(SortValue::Number(match (run.status, run.conclusion) {
+            (_, Some(RunConclusion::Failure | RunConclusion::TimedOut)) => 0,
+            (RunStatus::Completed, _) => 2,
+            _ => 1,
+        })),
+        "run" => i64::try_from(run.run_number).ok().map(SortValue::Number),
+        "title" => Some(SortValue::Text(run.display_title.to_lowercase())),
+        "age" => Some(SortValue::Number(run.created_at.timestamp())),
+        "duration" => run.duration_secs().map(SortValue::Number),
+        _ => None,
+    }
 }
 
 /// Map run status/conclusion to (icon, color).
@@ -127,6 +188,24 @@ fn run_status_icon_color(
     }
 }
 
+/// Failed runs of workflow `name` (every workflow when `None`) among the
+/// loaded `runs`, and when the latest of them was created.
+fn workflow_stats(
+    runs: &[WorkflowRun],
+    name: Option<&str>,
+) -> (usize, Option<chrono::DateTime<chrono::Utc>>) {
+    let runs = || runs.iter().filter(|r| name.is_none_or(|n| r.name == n));
+    let failures = runs()
+        .filter(|r| {
+            matches!(
+                r.conclusion,
+                Some(RunConclusion::Failure | RunConclusion::TimedOut)
+            )
+        })
+        .count();
+    (failures, runs().map(|r| r.created_at).max())
+}
+
 /// Convert a `WorkflowRun` into a table `Row`.
 fn run_to_row(run: &WorkflowRun, theme: &ResolvedTheme, watched_ids: &HashSet<u64>) -> Row {
     let mut row = HashMap::new();
@@ -144,6 +223,12 @@ fn run_to_row(run: &WorkflowRun, theme: &ResolvedTheme, watched_ids: &HashSet<u6
         "run".to_owned(),
         Cell::colored(format!("#{}", run.run_number), theme.text_faint),
     );
+    if let Some(repo) = &run.repository {
+        row.insert(
+            "repo".to_owned(),
+            Cell::colored(repo.clone(), theme.text_secondary),
+        );
+    }
     row.insert(
         "workflow".to_owned(),
         Cell::plain(crate::util::expand_emoji(&run.name)),
@@ -192,6 +277,11 @@ struct FilterData {
     run_count: usize,
     loading: bool,
     error: Option<String>,
+    /// Rate-limit cost of the last uncached fetch, for the footer.
+    refresh_cost: Option<u32>,
+    /// When the runs were fetched, if they come from an earlier session's
+    /// disk cache and the first fetch of this one hasn't completed.
+    stale: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl Default for FilterData {
@@ -202,13 +292,83 @@ impl Default for FilterData {
             run_count: 0,
             loading: true,
             error: None,
+            refresh_cost: None,
+            stale: None,
         }
     }
 }
 
 #[derive(Debug, Clone)]
 struct ActionsState {
-    filters: Vec<FilterData>,
+    filters: Vec<Arc<FilterData>>,
+}
+
+/// Replace `run` in every tab listing it, leaving the other rows untouched.
+fn patch_run(
+    state: &mut ActionsState,
+    run: &WorkflowRun,
+    theme: &ResolvedTheme,
+    watched_ids: &HashSet<u64>,
+) {
+    for fd in &mut state.filters {
+        if let Some(idx) = fd.runs.iter().position(|r| r.id == run.id) {
+            let fd = Arc::make_mut(fd);
+            fd.rows[idx] = run_to_row(run, theme, watched_ids);
+            fd.runs[idx] = run.clone();
+        }
+    }
+}
+
+/// Drop the runs in `run_ids` from every tab.
+fn remove_runs(state: &mut ActionsState, run_ids: &HashSet<u64>) {
+    for fd in &mut state.filters {
+        if !fd.runs.iter().any(|r| run_ids.contains(&r.id)) {
+            continue;
+        }
+        let fd = Arc::make_mut(fd);
+        let (runs, rows): (Vec<_>, Vec<_>) = std::mem::take(&mut fd.runs)
+            .into_iter()
+            .zip(std::mem::take(&mut fd.rows))
+            .filter(|(run, _)| !run_ids.contains(&run.id))
+            .unzip();
+        fd.run_count = runs.len();
+        fd.runs = runs;
+        fd.rows = rows;
+    }
+}
+
+/// Mark tab `fi` as failed with `message`. Cached runs stay listed under
+/// the error.
+fn fail_filter(
+    state: &mut State<ActionsState>,
+    fetch_times: &mut State<Vec<Option<std::time::Instant>>>,
+    in_flight: &mut State<Vec<bool>>,
+    fi: usize,
+    message: String,
+) {
+    let mut snapshot = state.read().clone();
+    if let Some(slot) = snapshot.filters.get_mut(fi) {
+        let failed = if slot.stale.is_some() {
+            FilterData {
+                error: Some(message),
+                ..(**slot).clone()
+            }
+        } else {
+            FilterData {
+                loading: false,
+                error: Some(message),
+                ..FilterData::default()
+            }
+        };
+        *slot = Arc::new(failed);
+    }
+    state.set(snapshot);
+    let mut times = fetch_times.read().clone();
+    if fi < times.len() {
+        times[fi] = Some(std::time::Instant::now());
+    }
+    fetch_times.set(times);
+    super::common::set_in_flight(in_flight, fi, false);
 }
 
 // ---------------------------------------------------------------------------
@@ -220,6 +380,8 @@ enum InputMode {
     Normal,
     Search,
     Confirm(BuiltinAction),
+    /// Typing the title an ephemeral tab is kept under.
+    KeepTab,
 }
 
 // ---------------------------------------------------------------------------
@@ -228,7 +390,7 @@ enum InputMode {
 
 const NAV_W: u16 = 28;
 
-/// Deferred request for a single workflow run, set by the polling future
+/// Deferred request for a single workflow run, set by the event future
 /// when an ephemeral tab's bulk fetch completes without the target run.
 #[derive(Clone)]
 struct PendingRunFetch {
@@ -238,6 +400,95 @@ struct PendingRunFetch {
     host: Option<String>,
 }
 
+/// Dry-run result of the run cleanup action, shown until confirmed or
+/// dismissed.
+#[derive(Clone)]
+struct CleanupPlan {
+    owner: String,
+    repo: String,
+    candidates: Vec<RunCleanupCandidate>,
+}
+
+/// A confirmed cleanup, deleted one chunk at a time: the next chunk is sent
+/// only once every run of the one in flight is reported deleted, so a failed
+/// chunk ends the cleanup.
+#[derive(Clone)]
+struct CleanupDeletion {
+    owner: String,
+    repo: String,
+    host: Option<String>,
+    /// Runs not sent yet.
+    queued: Vec<u64>,
+    /// Runs of the chunk in flight not reported deleted yet.
+    in_flight: HashSet<u64>,
+    deleted: usize,
+    total: usize,
+}
+
+impl CleanupDeletion {
+    fn new(owner: String, repo: String, host: Option<String>, run_ids: Vec<u64>) -> Self {
+        Self {
+            owner,
+            repo,
+            host,
+            total: run_ids.len(),
+            queued: run_ids,
+            in_flight: HashSet::new(),
+            deleted: 0,
+        }
+    }
+
+    /// Put the next chunk in flight, if the previous one is done.
+    fn next_request(&mut self, reply_tx: &EventSender) -> Option<Request> {
+        if !self.in_flight.is_empty() || self.queued.is_empty() {
+            return None;
+        }
+        let rest = self.queued.split_off(CLEANUP_CHUNK.min(self.queued.len()));
+        let chunk = std::mem::replace(&mut self.queued, rest);
+        self.in_flight = chunk.iter().copied().collect();
+        Some(Request::DeleteWorkflowRuns {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            run_ids: chunk,
+            progress: (self.deleted, self.total),
+            host: self.host.clone(),
+            reply_tx: reply_tx.clone(),
+        })
+    }
+
+    fn record_deleted(&mut self, run_ids: &[u64]) {
+        for id in run_ids {
+            if self.in_flight.remove(id) {
+                self.deleted += 1;
+            }
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.in_flight.is_empty() && self.queued.is_empty()
+    }
+}
+
+/// Preview lines shown in the cleanup overlay; the rest are summarised.
+const CLEANUP_PREVIEW_ROWS: usize = 15;
+
+/// Comparison with the last successful run, shown in the sidebar in place of
+/// the job list while the compared run is selected.
+#[derive(Clone)]
+enum ComparisonState {
+    Loading(u64),
+    NoBaseline(u64),
+    Ready(u64, Arc<RunComparison>),
+}
+
+impl ComparisonState {
+    fn run_id(&self) -> u64 {
+        match self {
+            Self::Loading(id) | Self::NoBaseline(id) | Self::Ready(id, _) => *id,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -256,19 +507,79 @@ fn resolve_filter_repo<'a>(
     }
 }
 
+/// `cfg` with `@current` resolved, as sent to the engine. `None` when the
+/// tab follows `@current` and no repo is in context. Multi-repo tabs resolve
+/// `@current` entries and drop them when there is no scope.
+fn resolve_filter(
+    cfg: &ActionsFilter,
+    scope_repo: Option<&str>,
+    detected_repo: Option<&str>,
+) -> Option<ActionsFilter> {
+    if !cfg.repos.is_empty() {
+        let repos = cfg
+            .repos
+            .iter()
+            .filter_map(|r| resolve_filter_repo(r, scope_repo, detected_repo))
+            .map(str::to_owned)
+            .collect();
+        return Some(ActionsFilter {
+            repos,
+            ..cfg.clone()
+        });
+    }
+    let repo = resolve_filter_repo(&cfg.repo, scope_repo, detected_repo)?;
+    Some(ActionsFilter {
+        repo: repo.to_owned(),
+        ..cfg.clone()
+    })
+}
+
+/// Whether the tab `cfg` fetches the runs of `full_repo`.
+fn filter_covers_repo(
+    cfg: &ActionsFilter,
+    full_repo: &str,
+    scope_repo: Option<&str>,
+    detected_repo: Option<&str>,
+) -> bool {
+    if cfg.repos.is_empty() {
+        return resolve_filter_repo(&cfg.repo, scope_repo, detected_repo)
+            .is_some_and(|r| r == full_repo);
+    }
+    let owner = full_repo.split_once('/').map_or(full_repo, |(o, _)| o);
+    cfg.repos
+        .iter()
+        .any(|entry| match entry.strip_prefix("org:") {
+            Some(org) => org.trim().eq_ignore_ascii_case(owner),
+            None => resolve_filter_repo(entry, scope_repo, detected_repo)
+                .is_some_and(|r| r.eq_ignore_ascii_case(full_repo)),
+        })
+}
+
 fn owner_repo_for_run(
     run: &WorkflowRun,
     filter: Option<&ActionsFilter>,
 ) -> Option<(String, String)> {
     owner_repo_from_url(&run.html_url).or_else(|| {
-        filter.and_then(|f| {
-            f.repo
-                .split_once('/')
-                .map(|(o, r)| (o.to_owned(), r.to_owned()))
-        })
+        run.repository
+            .as_deref()
+            .or(filter.map(|f| f.repo.as_str()))
+            .and_then(|r| r.split_once('/'))
+            .map(|(o, r)| (o.to_owned(), r.to_owned()))
     })
 }
 
+/// `cfg` with the branch picked for its tab, when one was: `Some(None)`
+/// drops the configured branch.
+fn with_ref_scope(cfg: &ActionsFilter, scope: Option<&Option<String>>) -> ActionsFilter {
+    match scope {
+        Some(branch) => ActionsFilter {
+            branch: branch.clone(),
+            ..cfg.clone()
+        },
+        None => cfg.clone(),
+    }
+}
+
 /// Build a merged list of (filter, `is_ephemeral`) from config + ephemeral filters.
 fn merged_filters<'a>(
     config: &'a [ActionsFilter],
@@ -279,8 +590,36 @@ fn merged_filters<'a>(
     out
 }
 
+/// Header rows on what triggered `run`: its head commit, the PRs it ran
+/// for (`jump_to_pr` opens the first) and, when someone else started this
+/// attempt, who did.
+fn run_context_lines(run: &WorkflowRun) -> Vec<(String, String)> {
+    let mut lines = Vec::new();
+    let subject = run
+        .head_commit_message
+        .as_deref()
+        .and_then(|m| m.lines().next())
+        .map(str::trim)
+        .filter(|s| !s.is_empty());
+    if let Some(subject) = subject {
+        let sha: String = run.head_sha.chars().take(7).collect();
+        lines.push(("Commit: ".to_owned(), format!("{sha} {subject}")));
+    }
+    if !run.pull_requests.is_empty() {
+        let prs: Vec<String> = run.pull_requests.iter().map(|n| format!("#{n}")).collect();
+        lines.push(("PR:     ".to_owned(), prs.join(", ")));
+    }
+    if let Some(by) = &run.triggering_actor
+        && run.actor.as_ref().is_none_or(|a| a.login != by.login)
+    {
+        lines.push(("Run by: ".to_owned(), format!("@{}", by.login)));
+    }
+    lines
+}
+
 /// Build a `SidebarMeta` header for a workflow run (pill + event/branch +
 /// actor + timestamps), mirroring the PR sidebar's layout.
+#[expect(clippy::too_many_lines)]
 fn build_run_sidebar_meta(
     run: &WorkflowRun,
     theme: &ResolvedTheme,
@@ -389,6 +728,8 @@ fn build_run_sidebar_meta(
         lines_added: None,
         lines_deleted: None,
         reactions_text: None,
+        deployments: Vec::new(),
+        context_lines: run_context_lines(run),
         date_fg: theme.text_faint.to_crossterm_color(depth),
         date_age_fg: theme.text_secondary.to_crossterm_color(depth),
         additions_fg: theme.text_success.to_crossterm_color(depth),
@@ -456,6 +797,7 @@ fn push_job_block(
     job: &WorkflowJob,
     label: &str,
     indent: usize,
+    excerpts: &StepExcerpts,
     theme: &ResolvedTheme,
 ) {
     let header_indent: String = " ".repeat(indent);
@@ -499,6 +841,7 @@ fn push_job_block(
             ));
         }
         lines.push(StyledLine::from_spans(spans));
+        lines.extend(excerpts.lines(job, step, indent + 6, theme));
     }
 }
 
@@ -524,40 +867,18 @@ fn push_group_header(
     ]));
 }
 
-fn build_jobs_lines(
+/// The jobs' matrix groups (prefix and variants, by name) and the jobs
+/// outside any, in the order the sidebar lists them.
+///
+/// Without `group_matrix`, every job stands alone.
+fn job_layout(
     jobs: &[WorkflowJob],
-    loading: bool,
     group_matrix: bool,
-    theme: &ResolvedTheme,
-) -> Vec<StyledLine> {
-    let mut lines = Vec::new();
-    if loading {
-        lines.push(StyledLine::from_span(StyledSpan::text(
-            "Loading jobs\u{2026}",
-            theme.text_faint,
-        )));
-        return lines;
-    }
-    if jobs.is_empty() {
-        lines.push(StyledLine::from_span(StyledSpan::text(
-            "No jobs found",
-            theme.text_faint,
-        )));
-        return lines;
-    }
-
-    // When grouping is disabled, fall back to the original flat alphabetical
-    // render — every job rendered as a top-level block.
+) -> (Vec<(&str, Vec<&WorkflowJob>)>, Vec<&WorkflowJob>) {
     if !group_matrix {
         let mut sorted_jobs: Vec<&WorkflowJob> = jobs.iter().collect();
         sorted_jobs.sort_by(|a, b| a.name.cmp(&b.name));
-        for (i, job) in sorted_jobs.iter().enumerate() {
-            if i > 0 {
-                lines.push(StyledLine::from_spans(vec![]));
-            }
-            push_job_block(&mut lines, job, &job.name, 0, theme);
-        }
-        return lines;
+        return (Vec::new(), sorted_jobs);
     }
 
     // Partition jobs by parsed `prefix (variant)`. Prefixes with only one
@@ -571,32 +892,77 @@ fn build_jobs_lines(
         }
     }
     let mut multi_groups: Vec<(&str, Vec<&WorkflowJob>)> = Vec::new();
-    for (prefix, members) in groups {
+    for (prefix, mut members) in groups {
         if members.len() >= 2 {
+            members.sort_by(|a, b| a.name.cmp(&b.name));
             multi_groups.push((prefix, members));
         } else {
             standalone.extend(members);
         }
     }
     standalone.sort_by(|a, b| a.name.cmp(&b.name));
+    (multi_groups, standalone)
+}
+
+/// Ids of the jobs in the order the sidebar lists them, which the focused
+/// sidebar steps through.
+fn job_order(jobs: &[WorkflowJob], group_matrix: bool) -> Vec<u64> {
+    let (groups, standalone) = job_layout(jobs, group_matrix);
+    groups
+        .into_iter()
+        .flat_map(|(_, members)| members)
+        .chain(standalone)
+        .map(|job| job.id)
+        .collect()
+}
+
+/// The jobs' lines, and the line of the `selected` job (by id), marked with
+/// the selection cursor.
+fn build_jobs_lines(
+    jobs: &[WorkflowJob],
+    loading: bool,
+    group_matrix: bool,
+    selected: Option<u64>,
+    excerpts: &StepExcerpts,
+    theme: &ResolvedTheme,
+) -> (Vec<StyledLine>, Option<usize>) {
+    let mut lines = Vec::new();
+    if loading {
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            format!("Loading jobs{}", ellipsis(theme.chrome)),
+            theme.text_faint,
+        )));
+        return (lines, None);
+    }
+    if jobs.is_empty() {
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            "No jobs found",
+            theme.text_faint,
+        )));
+        return (lines, None);
+    }
 
     // Groups first (alphabetical by prefix via BTreeMap iteration), then
     // standalone jobs alphabetically. Blank line between each top-level block
     // and between sibling variants inside a group.
+    let (multi_groups, standalone) = job_layout(jobs, group_matrix);
+    let mut selected_line = None;
     let mut first_block = true;
-    for (prefix, mut members) in multi_groups {
+    for (prefix, members) in multi_groups {
         if !first_block {
             lines.push(StyledLine::from_spans(vec![]));
         }
         first_block = false;
-        members.sort_by(|a, b| a.name.cmp(&b.name));
         push_group_header(&mut lines, prefix, &members, theme);
         for (vi, member) in members.iter().enumerate() {
             if vi > 0 {
                 lines.push(StyledLine::from_spans(vec![]));
             }
+            if selected == Some(member.id) {
+                selected_line = Some(lines.len());
+            }
             let variant = parse_matrix_name(&member.name).map_or(member.name.as_str(), |(_, v)| v);
-            push_job_block(&mut lines, member, variant, 3, theme);
+            push_job_block(&mut lines, member, variant, 3, excerpts, theme);
         }
     }
     for job in &standalone {
@@ -604,10 +970,16 @@ fn build_jobs_lines(
             lines.push(StyledLine::from_spans(vec![]));
         }
         first_block = false;
-        push_job_block(&mut lines, job, &job.name, 0, theme);
+        if selected == Some(job.id) {
+            selected_line = Some(lines.len());
+        }
+        push_job_block(&mut lines, job, &job.name, 0, excerpts, theme);
     }
 
-    lines
+    if selected.is_some() {
+        sidebar_tabs::mark_selection(&mut lines, selected_line, theme);
+    }
+    (lines, selected_line)
 }
 
 // ---------------------------------------------------------------------------
@@ -617,10 +989,11 @@ fn build_jobs_lines(
 #[derive(Default, Props)]
 #[expect(clippy::struct_excessive_bools)]
 pub struct ActionsViewProps<'a> {
-    pub filters: Option<&'a [ActionsFilter]>,
+    /// Config, replaced on each reload.
+    pub config: Option<Arc<AppConfig>>,
     pub engine: Option<&'a EngineHandle>,
-    pub theme: Option<&'a ResolvedTheme>,
-    pub keybindings: Option<&'a MergedBindings>,
+    pub theme: Option<Arc<ResolvedTheme>>,
+    pub keybindings: Option<Arc<MergedBindings>>,
     pub color_depth: ColorDepth,
     pub width: u16,
     pub height: u16,
@@ -636,7 +1009,15 @@ pub struct ActionsViewProps<'a> {
     /// Signal to jump directly to a specific view.
     pub goto_view: Option<State<Option<ViewKind>>>,
     pub scope_toggle: Option<State<bool>>,
+    /// Signal to toggle relative/absolute dates.
+    pub date_toggle: Option<State<bool>>,
+    /// Signal to open the log viewer.
+    pub show_logs: Option<State<bool>>,
+    /// Set when the token cannot re-run or cancel runs; those keys show it instead.
+    pub scope_hint: Option<String>,
     pub repo_picker: Option<State<bool>>,
+    /// Signal to scope to the repo worked in.
+    pub rescan_repo: Option<State<bool>>,
     pub is_active: bool,
     pub refetch_interval_minutes: u32,
     /// Navigation target state — set by `PrsView`, consumed here.
@@ -644,9 +1025,21 @@ pub struct ActionsViewProps<'a> {
     /// Go-back signal — set to true to return to previous view.
     pub go_back: Option<State<bool>>,
     /// Shared rate-limit state (owned by App).
-    pub rate_limit: Option<State<Option<RateLimitInfo>>>,
+    pub rate_limit: Option<State<RateLimits>>,
+    /// Shared watched-item badges (owned by App).
+    pub watch_badges: Option<State<WatchBadges>>,
+    /// Shared footer counts of the views (owned by App).
+    pub view_counts: Option<State<ViewCounts>>,
     /// Group matrix-job variants in the run-detail sidebar.
     pub group_matrix_jobs: bool,
+    /// Log lines shown under a job's failed step (from
+    /// `actions.failed_step_log_lines`).
+    pub failed_step_log_lines: usize,
+    /// Age threshold for the run cleanup action (from
+    /// `actions.cleanup_older_than_days`).
+    pub cleanup_older_than_days: u32,
+    /// Capacity of the per-view detail cache (from `defaults.detail_cache_size`).
+    pub detail_cache_size: usize,
 }
 
 #[component]
@@ -654,8 +1047,23 @@ pub fn ActionsView<'a>(
     props: &ActionsViewProps<'a>,
     mut hooks: Hooks,
 ) -> impl Into<AnyElement<'a>> {
-    let filters_cfg = props.filters.unwrap_or(&[]);
-    let theme = props.theme.cloned().unwrap_or_else(default_theme);
+    // Branch picked for each tab (`pick_ref`), overriding its `branch`.
+    let mut ref_scopes = hooks.use_state(HashMap::<usize, Option<String>>::new);
+    let scopes_snapshot = ref_scopes.read().clone();
+    let scoped_cfg: Vec<ActionsFilter> = props
+        .config
+        .as_deref()
+        .map_or(&[][..], |c| &c.actions_filters)
+        .iter()
+        .enumerate()
+        .map(|(i, f)| with_ref_scope(f, scopes_snapshot.get(&i)))
+        .collect();
+    let filters_cfg = scoped_cfg.as_slice();
+    let theme = props
+        .theme
+        .as_deref()
+        .cloned()
+        .unwrap_or_else(default_theme);
     let depth = props.color_depth;
     let should_exit = props.should_exit;
     let switch_view = props.switch_view;
@@ -670,15 +1078,26 @@ pub fn ActionsView<'a>(
     let scope_repo = props.scope_repo.clone();
     let detected_repo = props.detected_repo.clone();
     let scope_toggle = props.scope_toggle;
+    let date_toggle = props.date_toggle;
+    let show_logs = props.show_logs;
     let repo_picker = props.repo_picker;
+    let rescan_repo = props.rescan_repo;
 
     let mut active_filter = hooks.use_state(|| 0usize);
     let mut cursor = hooks.use_state(|| 0usize);
     let mut scroll_offset = hooks.use_state(|| 0usize);
+    // Identity of the item under the cursor, followed across refreshes.
+    let mut cursor_anchor = hooks.use_state(super::common::CursorAnchor::default);
     let mut input_mode = hooks.use_state(|| InputMode::Normal);
     let mut search_query = hooks.use_state(String::new);
+    // Column the table is sorted by; API order when `None`.
+    let mut table_sort = hooks.use_state(|| Option::<TableSort>::None);
+    let mut keep_title = hooks.use_state(String::new);
 
     let mut help_visible = hooks.use_state(|| false);
+    // Clicks reported by the tab bar and the table.
+    let tab_click = hooks.use_state(|| None::<usize>);
+    let row_click = hooks.use_state(|| None::<TableClick>);
 
     let mut nav_open = hooks.use_state(|| false);
     let mut nav_cursor = hooks.use_state(|| 0usize);
@@ -686,55 +1105,140 @@ pub fn ActionsView<'a>(
 
     let mut detail_open = hooks.use_state(|| false);
     let mut detail_scroll = hooks.use_state(|| 0usize);
-    let mut jobs_cache = hooks.use_state(HashMap::<u64, Vec<WorkflowJob>>::new);
+    // Whether the sidebar has keyboard focus; the job selected in it, as
+    // (run id, job id), and whether to scroll to it.
+    let mut sidebar_focused = hooks.use_state(|| false);
+    let mut job_sel = hooks.use_state(|| Option::<(u64, u64)>::None);
+    let mut job_jump = hooks.use_state(|| false);
+    let detail_cache_size = props.detail_cache_size;
+    let mut jobs_cache =
+        hooks.use_state(|| LruCache::<u64, Vec<WorkflowJob>>::new(detail_cache_size));
     let mut jobs_in_flight = hooks.use_state(HashSet::<u64>::new);
+    let failed_step_log_lines = props.failed_step_log_lines;
+    let mut step_excerpts =
+        hooks.use_state(|| StepExcerpts::new(failed_step_log_lines, detail_cache_size));
 
     // State: ephemeral tabs created by deep-linking to repos without config tabs.
     // Each entry is (filter, optional pending run_id to highlight after fetch).
     let mut ephemeral_filters = hooks.use_state(Vec::<(ActionsFilter, Option<u64>)>::new);
 
-    // Signal: the polling future sets this when an ephemeral tab's data arrives
+    // Signal: the event future sets this when an ephemeral tab's data arrives
     // but the pending run_id is not among the results — the synchronous render
     // path picks it up and sends FetchRunById.
     let mut pending_run_fetch = hooks.use_state(|| Option::<PendingRunFetch>::None);
+    let mut cleanup_plan = hooks.use_state(|| Option::<CleanupPlan>::None);
+    let mut cleanup_deletion = hooks.use_state(|| Option::<CleanupDeletion>::None);
+    // Quick-run overlay cursor, and the preset awaiting confirmation.
+    let mut quick_run = hooks.use_state(|| Option::<usize>::None);
+    let mut schedules_overlay = hooks.use_state(|| None::<SchedulesOverlay>);
+    let mut run_workflow_overlay = hooks.use_state(|| None::<RunWorkflowOverlay>);
+    let mut ref_picker_state = hooks.use_state(|| None::<RefPicker>);
+    // A dispatch adds a run of unknown id: its `MutationOk` refetches the tab.
+    let mut refetch_on_mutation = hooks.use_state(|| false);
+    let mut pending_preset = hooks.use_state(|| Option::<usize>::None);
+    let mut comparison = hooks.use_state(|| Option::<ComparisonState>::None);
+    // Job log shown in the sidebar, and the picker of its job.
+    let mut job_log = hooks.use_state(|| Option::<JobLog>::None);
+    let mut job_picker = hooks.use_state(|| Option::<JobPicker>::None);
 
     let mut watched_run_ids = hooks.use_state(HashSet::<u64>::new);
     let mut action_status = hooks.use_state(|| Option::<ActionFeedback>::None);
     let mut status_set_at = hooks.use_state(|| Option::<std::time::Instant>::None);
-    let fallback_rl = hooks.use_state(|| None);
+    let fallback_rl = hooks.use_state(RateLimits::default);
     let mut rate_limit_state = props.rate_limit.unwrap_or(fallback_rl);
+    let watch_badges = props.watch_badges;
 
     let mut refresh_registered = hooks.use_state(|| false);
     let mut filter_fetch_times =
         hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
+    // Per-filter auto-refresh postponement (`postpone_refresh`), for the countdown.
+    let mut refresh_postponed =
+        hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
     let mut filter_in_flight = hooks.use_state(move || vec![false; filter_count]);
     let mut refresh_all = hooks.use_state(|| false);
 
-    let initial_filters = vec![FilterData::default(); filter_count];
+    let initial_filters = super::common::empty_filters(filter_count);
     let mut actions_state = hooks.use_state(move || ActionsState {
         filters: initial_filters,
     });
 
     let event_channel = hooks.use_state(super::common::new_event_channel);
-    let (event_tx, event_rx_arc) = event_channel.read().clone();
+    let (event_tx, event_rx) = event_channel.read().clone();
     let engine: Option<crate::engine::EngineHandle> = props.engine.cloned();
+    let mut was_active = hooks.use_state(|| false);
+    super::common::cancel_fetches_on_leave(&mut was_active, is_active, engine.as_ref(), &event_tx);
+
+    // Track config reloads: keep to the tab shown and refetch every filter.
+    // Branches picked for the tabs no longer apply.
+    let configured = props
+        .config
+        .as_deref()
+        .map_or(&[][..], |c| &c.actions_filters);
+    let mut last_filters = hooks.use_state(|| configured.to_vec());
+    let filters_changed = super::common::follow_reload(
+        &mut last_filters,
+        configured,
+        &mut active_filter,
+        &mut refresh_postponed,
+    );
+    if filters_changed {
+        ref_scopes.set(HashMap::new());
+    }
 
     // Track scope changes: when scope_repo changes, invalidate all filters.
     let mut last_scope = hooks.use_state(|| scope_repo.clone());
-    if *last_scope.read() != scope_repo {
+    if filters_changed || *last_scope.read() != scope_repo {
         last_scope.set(scope_repo.clone());
         actions_state.set(ActionsState {
-            filters: vec![FilterData::default(); filter_count],
+            filters: super::common::empty_filters(filter_count),
         });
         filter_fetch_times.set(vec![None; filter_count]);
         filter_in_flight.set(vec![false; filter_count]);
         refresh_registered.set(false);
     }
 
-    let eph_snapshot = ephemeral_filters.read().clone();
+    // Rebuild the cached rows' date cells after the relative/absolute toggle.
+    let mut date_generation = hooks.use_state(crate::util::date_generation);
+    if date_generation.get() != crate::util::date_generation() {
+        date_generation.set(crate::util::date_generation());
+        let watched = watched_run_ids.read().clone();
+        let mut state = actions_state.read().clone();
+        for fd in &mut state.filters {
+            let fd = Arc::make_mut(fd);
+            fd.rows = fd
+                .runs
+                .iter()
+                .map(|r| run_to_row(r, &theme, &watched))
+                .collect();
+        }
+        actions_state.set(state);
+    }
+
+    let eph_snapshot: Vec<(ActionsFilter, Option<u64>)> = ephemeral_filters
+        .read()
+        .iter()
+        .enumerate()
+        .map(|(i, (f, run_id))| {
+            (
+                with_ref_scope(f, scopes_snapshot.get(&(filter_count + i))),
+                *run_id,
+            )
+        })
+        .collect();
     let ephemeral_count = eph_snapshot.len();
     let total_tab_count = filter_count + ephemeral_count;
+    if let Some(tab) =
+        super::common::take_tab_click(tab_click, total_tab_count, active_filter.get())
+    {
+        active_filter.set(tab);
+        cursor.set(0);
+        scroll_offset.set(0);
+    }
     let current_filter_idx = active_filter.get().min(total_tab_count.saturating_sub(1));
+    // Ephemeral tabs are not registered for auto-refresh.
+    let refresh_interval = filters_cfg.get(current_filter_idx).map(|f| {
+        super::common::refresh_interval(f.refetch_interval_minutes, props.refetch_interval_minutes)
+    });
     let all_filters = merged_filters(filters_cfg, &eph_snapshot);
 
     let active_needs_fetch = actions_state
@@ -756,16 +1260,8 @@ pub fn ActionsView<'a>(
         let resolved_for_refresh: Vec<ActionsFilter> = filters_cfg
             .iter()
             .map(|f| {
-                if let Some(repo) =
-                    resolve_filter_repo(&f.repo, scope_repo.as_deref(), detected_repo.as_deref())
-                    && repo != f.repo.as_str()
-                {
-                    return ActionsFilter {
-                        repo: repo.to_owned(),
-                        ..f.clone()
-                    };
-                }
-                f.clone()
+                resolve_filter(f, scope_repo.as_deref(), detected_repo.as_deref())
+                    .unwrap_or_else(|| f.clone())
             })
             .collect();
         eng.send(Request::RegisterRefresh {
@@ -784,8 +1280,7 @@ pub fn ActionsView<'a>(
     {
         refresh_all.set(false);
         for (filter_idx, (cfg, is_eph)) in all_filters.iter().enumerate() {
-            let Some(resolved_repo) =
-                resolve_filter_repo(&cfg.repo, scope_repo.as_deref(), detected_repo.as_deref())
+            let Some(filter) = resolve_filter(cfg, scope_repo.as_deref(), detected_repo.as_deref())
             else {
                 if !is_eph {
                     tracing::debug!(
@@ -794,14 +1289,6 @@ pub fn ActionsView<'a>(
                 }
                 continue;
             };
-            let filter = if resolved_repo == cfg.repo.as_str() {
-                (*cfg).clone()
-            } else {
-                ActionsFilter {
-                    repo: resolved_repo.to_owned(),
-                    ..(*cfg).clone()
-                }
-            };
             super::common::set_in_flight(&mut filter_in_flight, filter_idx, true);
             eng.send(Request::FetchActions {
                 filter_idx,
@@ -816,17 +1303,9 @@ pub fn ActionsView<'a>(
     {
         // Look up the active filter from the merged list (config + ephemeral).
         if let Some((cfg, _is_eph)) = all_filters.get(current_filter_idx) {
-            if let Some(resolved_repo) =
-                resolve_filter_repo(&cfg.repo, scope_repo.as_deref(), detected_repo.as_deref())
+            if let Some(filter) =
+                resolve_filter(cfg, scope_repo.as_deref(), detected_repo.as_deref())
             {
-                let filter = if resolved_repo == cfg.repo.as_str() {
-                    (*cfg).clone()
-                } else {
-                    ActionsFilter {
-                        repo: resolved_repo.to_owned(),
-                        ..(*cfg).clone()
-                    }
-                };
                 super::common::set_in_flight(&mut filter_in_flight, current_filter_idx, true);
                 eng.send(Request::FetchActions {
                     filter_idx: current_filter_idx,
@@ -840,37 +1319,25 @@ pub fn ActionsView<'a>(
                 );
                 let mut state = actions_state.read().clone();
                 if current_filter_idx < state.filters.len() {
-                    state.filters[current_filter_idx].loading = false;
+                    Arc::make_mut(&mut state.filters[current_filter_idx]).loading = false;
                 }
                 actions_state.set(state);
             }
         }
     }
 
-    // Poll engine events.
+    hooks.use_future(super::common::expire_action_status(
+        action_status,
+        status_set_at,
+    ));
+
+    // Handle engine events as they arrive.
     {
-        let rx_for_poll = event_rx_arc.clone();
+        let rx_for_poll = event_rx.clone();
         let theme_for_poll = theme.clone();
         let current_filter_for_poll = current_filter_idx;
         hooks.use_future(async move {
-            loop {
-                smol::Timer::after(std::time::Duration::from_millis(100)).await;
-                // Auto-clear status after 60 seconds.
-                if let Some(t) = status_set_at.get()
-                    && t.elapsed().as_secs() >= 60
-                {
-                    action_status.set(None);
-                    status_set_at.set(None);
-                }
-
-                let events: Vec<Event> = {
-                    let rx = rx_for_poll.lock().unwrap();
-                    let mut evts = Vec::new();
-                    while let Ok(evt) = rx.try_recv() {
-                        evts.push(evt);
-                    }
-                    evts
-                };
+            while let Some(events) = super::common::recv_batch(&rx_for_poll).await {
                 for evt in events {
                     match evt {
                         Event::ActionsFetched {
@@ -878,6 +1345,14 @@ pub fn ActionsView<'a>(
                             runs,
                             rate_limit,
                         } => {
+                            let refresh_cost =
+                                rate_limit.as_ref().map(|rl| rl.cost).or_else(|| {
+                                    actions_state
+                                        .read()
+                                        .filters
+                                        .get(filter_idx)
+                                        .and_then(|f| f.refresh_cost)
+                                });
                             let rows: Vec<Row> = runs
                                 .iter()
                                 .map(|r| run_to_row(r, &theme_for_poll, &watched_run_ids.read()))
@@ -889,10 +1364,12 @@ pub fn ActionsView<'a>(
                                 run_count,
                                 loading: false,
                                 error: None,
+                                refresh_cost,
+                                stale: None,
                             };
                             let mut state = actions_state.read().clone();
                             if filter_idx < state.filters.len() {
-                                state.filters[filter_idx] = filter_data;
+                                state.filters[filter_idx] = Arc::new(filter_data);
                             }
                             actions_state.set(state);
                             let mut times = filter_fetch_times.read().clone();
@@ -904,7 +1381,7 @@ pub fn ActionsView<'a>(
                             // Fresh run data arrived — evict job cache so the
                             // sidebar re-fetches updated job status rather than
                             // displaying stale results from the previous poll.
-                            jobs_cache.set(HashMap::new());
+                            jobs_cache.set(LruCache::new(detail_cache_size));
                             jobs_in_flight.set(HashSet::new());
                             super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
 
@@ -921,6 +1398,8 @@ pub fn ActionsView<'a>(
                                     });
                                     if let Some(pos) = found_pos {
                                         // Run is in the results — position cursor.
+                                        // The position is in API order.
+                                        table_sort.set(None);
                                         cursor.set(pos);
                                         scroll_offset.set(pos.saturating_sub(5));
                                         detail_open.set(true);
@@ -962,17 +1441,47 @@ pub fn ActionsView<'a>(
                         Event::MutationOk { description } => {
                             action_status.set(Some(ActionFeedback::Success(description)));
                             status_set_at.set(Some(std::time::Instant::now()));
-                            // Mark the current filter as needing a re-fetch
-                            // but keep existing rows visible until the fresh
-                            // response arrives (avoids blanking the table).
+                            // Re-runs and cancels are followed by RunRefreshed,
+                            // deletions by RunsDeleted, which patch the rows in
+                            // place. Only a dispatch re-fetches the tab, keeping
+                            // the rows visible until the response arrives.
+                            if refetch_on_mutation.get() {
+                                refetch_on_mutation.set(false);
+                                let mut state = actions_state.read().clone();
+                                if let Some(fd) = state.filters.get_mut(current_filter_for_poll) {
+                                    Arc::make_mut(fd).loading = true;
+                                }
+                                actions_state.set(state);
+                            }
+                        }
+                        Event::RunRefreshed { run, rate_limit } => {
+                            super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
                             let mut state = actions_state.read().clone();
-                            if let Some(fd) = state.filters.get_mut(current_filter_for_poll) {
-                                fd.loading = true;
+                            patch_run(&mut state, &run, &theme_for_poll, &watched_run_ids.read());
+                            actions_state.set(state);
+                            // Only this run's jobs changed.
+                            let mut cache = jobs_cache.read().clone();
+                            if cache.remove(&run.id).is_some() {
+                                jobs_cache.set(cache);
+                            }
+                            let mut ifl = jobs_in_flight.read().clone();
+                            if ifl.remove(&run.id) {
+                                jobs_in_flight.set(ifl);
+                            }
+                        }
+                        Event::RunsDeleted { run_ids } => {
+                            let deletion = cleanup_deletion.read().clone();
+                            if let Some(mut deletion) = deletion {
+                                deletion.record_deleted(&run_ids);
+                                cleanup_deletion.set(Some(deletion));
                             }
+                            let mut state = actions_state.read().clone();
+                            remove_runs(&mut state, &run_ids.into_iter().collect());
                             actions_state.set(state);
-                            // Clear jobs cache so the sidebar re-fetches after
-                            // the mutation.
-                            jobs_cache.set(HashMap::new());
+                        }
+                        Event::RefreshBudgetExceeded { message } => {
+                            action_status.set(Some(ActionFeedback::Warning(message)));
+                            status_set_at.set(Some(std::time::Instant::now()));
                         }
                         Event::MutationError {
                             description,
@@ -1001,6 +1510,7 @@ pub fn ActionsView<'a>(
                                     if let Some(existing_idx) =
                                         fd.runs.iter().position(|r| r.id == fetched.id)
                                     {
+                                        let fd = Arc::make_mut(fd);
                                         fd.rows[existing_idx] = run_to_row(
                                             &fetched,
                                             &theme_for_poll,
@@ -1014,6 +1524,7 @@ pub fn ActionsView<'a>(
                                     if let Some(fd) = state.filters.get_mut(tab_idx) {
                                         // Prepend if not already present (new run in ephemeral tab).
                                         if !fd.runs.iter().any(|r| r.id == fetched.id) {
+                                            let fd = Arc::make_mut(fd);
                                             fd.rows.insert(
                                                 0,
                                                 run_to_row(
@@ -1045,27 +1556,180 @@ pub fn ActionsView<'a>(
                             }
                             super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
                         }
+                        Event::RunCleanupPlanned {
+                            owner,
+                            repo,
+                            candidates,
+                            rate_limit,
+                        } => {
+                            super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                            if candidates.is_empty() {
+                                action_status.set(Some(ActionFeedback::Info(format!(
+                                    "No workflow runs to clean up in {owner}/{repo}"
+                                ))));
+                                status_set_at.set(Some(std::time::Instant::now()));
+                            } else {
+                                cleanup_plan.set(Some(CleanupPlan {
+                                    owner,
+                                    repo,
+                                    candidates,
+                                }));
+                                input_mode.set(InputMode::Confirm(BuiltinAction::CleanupRuns));
+                                action_status.set(None);
+                            }
+                        }
+                        Event::RunComparisonFetched {
+                            run_id,
+                            comparison: result,
+                            rate_limit,
+                        } => {
+                            super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                            let pending = comparison.read().as_ref().map(ComparisonState::run_id);
+                            if pending == Some(run_id) {
+                                comparison.set(Some(match result {
+                                    Some(c) => ComparisonState::Ready(run_id, Arc::from(c)),
+                                    None => ComparisonState::NoBaseline(run_id),
+                                }));
+                            }
+                        }
+                        Event::FetchError { context, message }
+                            if context == "CompareWithLastSuccess" =>
+                        {
+                            comparison.set(None);
+                            action_status.set(Some(ActionFeedback::Error(format!(
+                                "Compare with last success: {message}"
+                            ))));
+                            status_set_at.set(Some(std::time::Instant::now()));
+                        }
+                        Event::JobLogsFetched {
+                            job_id,
+                            status,
+                            log,
+                        } => {
+                            let mut excerpts = step_excerpts.read().clone();
+                            if excerpts.on_fetched(job_id, log.as_deref()) {
+                                step_excerpts.set(excerpts);
+                            }
+                            let mut shown = job_log.read().clone();
+                            if let Some(current) = shown.as_mut().filter(|l| l.job_id == job_id) {
+                                if current.on_fetched(status, log) {
+                                    // Failures are at the end: start there.
+                                    detail_scroll.set(usize::MAX);
+                                }
+                                job_log.set(shown);
+                            }
+                        }
+                        Event::FetchError { context, message }
+                            if context == job_log::FETCH_CONTEXT =>
+                        {
+                            let mut excerpts = step_excerpts.read().clone();
+                            if excerpts.on_error(&message) {
+                                step_excerpts.set(excerpts);
+                            }
+                            let mut shown = job_log.read().clone();
+                            if let Some(current) = shown.as_mut() {
+                                current.on_error(message);
+                                job_log.set(shown);
+                            }
+                        }
+                        Event::WorkflowSchedulesFetched {
+                            schedules: found,
+                            rate_limit,
+                        } => {
+                            super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                            schedules::on_fetched(&mut schedules_overlay, found);
+                        }
+                        Event::FetchError { context, message }
+                            if context == schedules::FETCH_CONTEXT =>
+                        {
+                            schedules::on_error(&mut schedules_overlay, message);
+                        }
+                        Event::DispatchableWorkflowsFetched {
+                            workflows,
+                            rate_limit,
+                        } => {
+                            super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                            run_workflow::on_fetched(&mut run_workflow_overlay, workflows);
+                        }
+                        Event::FetchError { context, message }
+                            if context == run_workflow::FETCH_CONTEXT =>
+                        {
+                            run_workflow::on_error(&mut run_workflow_overlay, message);
+                        }
+                        Event::RepoRefsFetched {
+                            repo,
+                            branches,
+                            tags,
+                        } => {
+                            ref_picker::on_fetched(&mut ref_picker_state, &repo, branches, tags);
+                        }
+                        Event::FetchError { context, message }
+                            if context == ref_picker::FETCH_CONTEXT =>
+                        {
+                            ref_picker::on_error(&mut ref_picker_state, message);
+                        }
+                        Event::FetchError { context, message } if context == "PlanRunCleanup" => {
+                            action_status.set(Some(ActionFeedback::Error(format!(
+                                "Run cleanup: {message}"
+                            ))));
+                            status_set_at.set(Some(std::time::Instant::now()));
+                        }
                         Event::FetchError { message, .. } => {
-                            let ifl = filter_in_flight.read().clone();
-                            let fi = ifl.iter().position(|&f| f);
+                            let fi = filter_in_flight.read().iter().position(|&f| f);
                             if let Some(fi) = fi {
-                                let mut state = actions_state.read().clone();
-                                if fi < state.filters.len() {
-                                    state.filters[fi] = FilterData {
-                                        loading: false,
-                                        error: Some(message.clone()),
-                                        ..FilterData::default()
-                                    };
-                                }
+                                fail_filter(
+                                    &mut actions_state,
+                                    &mut filter_fetch_times,
+                                    &mut filter_in_flight,
+                                    fi,
+                                    message,
+                                );
+                            }
+                        }
+                        Event::FetchTimedOut { filter_idx, secs } => fail_filter(
+                            &mut actions_state,
+                            &mut filter_fetch_times,
+                            &mut filter_in_flight,
+                            filter_idx,
+                            super::common::timed_out_message(secs),
+                        ),
+                        Event::ActionsCached {
+                            filter_idx,
+                            runs,
+                            saved_at,
+                        } => {
+                            let mut state = actions_state.read().clone();
+                            if let Some(slot) = state.filters.get_mut(filter_idx)
+                                && slot.loading
+                            {
+                                *slot = Arc::new(FilterData {
+                                    rows: runs
+                                        .iter()
+                                        .map(|r| {
+                                            run_to_row(r, &theme_for_poll, &watched_run_ids.read())
+                                        })
+                                        .collect(),
+                                    run_count: runs.len(),
+                                    runs,
+                                    loading: false,
+                                    stale: Some(saved_at),
+                                    ..FilterData::default()
+                                });
                                 actions_state.set(state);
-                                let mut times = filter_fetch_times.read().clone();
-                                if fi < times.len() {
-                                    times[fi] = Some(std::time::Instant::now());
-                                }
-                                filter_fetch_times.set(times);
-                                super::common::set_in_flight(&mut filter_in_flight, fi, false);
                             }
                         }
+                        Event::FetchCancelled { filter_idx } => {
+                            // Cached runs are fetched again, like a first load.
+                            let mut state = actions_state.read().clone();
+                            if let Some(slot) = state.filters.get_mut(filter_idx)
+                                && slot.stale.is_some()
+                                && slot.error.is_none()
+                            {
+                                *slot = Arc::new(FilterData::default());
+                                actions_state.set(state);
+                            }
+                            super::common::set_in_flight(&mut filter_in_flight, filter_idx, false);
+                        }
                         Event::WatchedRunUpdated {
                             run_id,
                             run,
@@ -1077,13 +1741,26 @@ pub fn ActionsView<'a>(
                             // clears the icon immediately on completion.
                             if completed {
                                 let mut ids = watched_run_ids.read().clone();
-                                ids.remove(&run_id);
+                                if ids.remove(&run_id) {
+                                    let conclusion = run.conclusion.map_or("done", |c| c.as_str());
+                                    action_status.set(Some(ActionFeedback::Info(format!(
+                                        "Watched run {} #{} finished: {conclusion}",
+                                        run.name, run.run_number
+                                    ))));
+                                    status_set_at.set(Some(std::time::Instant::now()));
+                                    if let Some(mut badges) = watch_badges {
+                                        let mut b = badges.get();
+                                        b.bump(ViewKind::Actions);
+                                        badges.set(b);
+                                    }
+                                }
                                 watched_run_ids.set(ids);
                             }
                             // Update the run in-place across ALL filters.
                             let mut state = actions_state.read().clone();
                             for fd in &mut state.filters {
                                 if let Some(idx) = fd.runs.iter().position(|r| r.id == run_id) {
+                                    let fd = Arc::make_mut(fd);
                                     fd.rows[idx] =
                                         run_to_row(&run, &theme_for_poll, &watched_run_ids.read());
                                     fd.runs[idx] = run.clone();
@@ -1123,7 +1800,7 @@ pub fn ActionsView<'a>(
     }
 
     // -----------------------------------------------------------------------
-    // Deferred FetchRunById: the polling future detected that an ephemeral
+    // Deferred FetchRunById: the event future detected that an ephemeral
     // tab's data arrived without the target run — fetch it individually.
     // -----------------------------------------------------------------------
     let pending_fetch = pending_run_fetch.read().clone();
@@ -1146,6 +1823,19 @@ pub fn ActionsView<'a>(
         }
     }
 
+    // Next chunk of a cleanup, once the one in flight is fully deleted.
+    let deletion = cleanup_deletion.read().clone();
+    if let Some(mut deletion) = deletion
+        && deletion.in_flight.is_empty()
+    {
+        if let Some(req) = deletion.next_request(&event_tx)
+            && let Some(ref eng) = engine
+        {
+            eng.send(req);
+        }
+        cleanup_deletion.set((!deletion.is_done()).then_some(deletion));
+    }
+
     // -----------------------------------------------------------------------
     // Process cross-view navigation target (deep-link from PR checks)
     // -----------------------------------------------------------------------
@@ -1173,12 +1863,12 @@ pub fn ActionsView<'a>(
                     }
                     // Only consider tabs whose configured repo matches the target.
                     let tab_matches = all_filters.get(fi).is_some_and(|(cfg, _)| {
-                        resolve_filter_repo(
-                            &cfg.repo,
+                        filter_covers_repo(
+                            cfg,
+                            &full_repo,
                             scope_repo.as_deref(),
                             detected_repo.as_deref(),
                         )
-                        .is_some_and(|r| r == full_repo)
                     });
                     if !tab_matches {
                         return None;
@@ -1193,6 +1883,7 @@ pub fn ActionsView<'a>(
             if let Some((filter_idx, run_pos)) = found {
                 // Run found in an existing tab — switch to it.
                 active_filter.set(filter_idx);
+                table_sort.set(None);
                 cursor.set(run_pos);
                 scroll_offset.set(run_pos.saturating_sub(5));
                 detail_open.set(true);
@@ -1209,12 +1900,12 @@ pub fn ActionsView<'a>(
                 //    those — don't block on unrelated filters.
                 let repo_matches = |fi: usize| {
                     all_filters.get(fi).is_some_and(|(cfg, _)| {
-                        resolve_filter_repo(
-                            &cfg.repo,
+                        filter_covers_repo(
+                            cfg,
+                            &full_repo,
                             scope_repo.as_deref(),
                             detected_repo.as_deref(),
                         )
-                        .is_some_and(|r| r == full_repo)
                     })
                 };
                 let (matching_still_pending, needs_fetch) = {
@@ -1241,20 +1932,12 @@ pub fn ActionsView<'a>(
                 if let Some(ref eng) = engine {
                     for fi in needs_fetch {
                         if let Some((cfg, _)) = all_filters.get(fi) {
-                            let resolved = resolve_filter_repo(
-                                &cfg.repo,
+                            let filter = resolve_filter(
+                                cfg,
                                 scope_repo.as_deref(),
                                 detected_repo.as_deref(),
                             )
                             .unwrap();
-                            let filter = if resolved == cfg.repo.as_str() {
-                                (*cfg).clone()
-                            } else {
-                                ActionsFilter {
-                                    repo: resolved.to_owned(),
-                                    ..(*cfg).clone()
-                                }
-                            };
                             super::common::set_in_flight(&mut filter_in_flight, fi, true);
                             eng.send(Request::FetchActions {
                                 filter_idx: fi,
@@ -1299,6 +1982,7 @@ pub fn ActionsView<'a>(
                                     .position(|r| r.id == run_id)
                                     .unwrap_or(0)
                             };
+                            table_sort.set(None);
                             cursor.set(pos);
                             scroll_offset.set(pos.saturating_sub(5));
                             detail_open.set(true);
@@ -1327,10 +2011,13 @@ pub fn ActionsView<'a>(
                         let new_filter = ActionsFilter {
                             title: full_repo.clone(),
                             repo: full_repo,
+                            repos: Vec::new(),
                             host: host.clone(),
                             limit: None,
                             status: None,
                             event: None,
+                            branch: None,
+                            refetch_interval_minutes: None,
                         };
                         let mut eph = ephemeral_filters.read().clone();
                         eph.push((new_filter, Some(run_id)));
@@ -1339,7 +2026,7 @@ pub fn ActionsView<'a>(
 
                         // Grow state vectors for the new tab.
                         let mut state = actions_state.read().clone();
-                        state.filters.push(FilterData::default());
+                        state.filters.push(Arc::new(FilterData::default()));
                         actions_state.set(state);
                         let mut in_flight = filter_in_flight.read().clone();
                         in_flight.push(false);
@@ -1421,7 +2108,7 @@ pub fn ActionsView<'a>(
     };
 
     // Apply workflow nav filter.
-    let filtered_run_indices: Vec<usize> = if nav_cur == 0 {
+    let mut filtered_run_indices: Vec<usize> = if nav_cur == 0 {
         after_search_idx
     } else {
         let nav_name = workflow_names.get(nav_cur).map_or("", String::as_str);
@@ -1435,6 +2122,24 @@ pub fn ActionsView<'a>(
             .collect()
     };
 
+    let multi_repo = all_filters
+        .get(current_filter_idx)
+        .is_some_and(|(f, _)| !f.repos.is_empty());
+    let columns = actions_columns(multi_repo);
+    let current_sort = table_sort.read().clone();
+    table::sort_indices(
+        &mut filtered_run_indices,
+        all_rows,
+        current_sort.as_ref(),
+        |i, column| run_sort_value(all_runs.get(i)?, column),
+    );
+    // Sortable columns shown, in order, cycled through by `sort_column`.
+    let sort_columns: Vec<Column> = columns
+        .iter()
+        .filter(|c| c.sort.is_some())
+        .cloned()
+        .collect();
+
     let filtered_rows: Vec<Row> = filtered_run_indices
         .iter()
         .filter_map(|&i| all_rows.get(i))
@@ -1443,6 +2148,35 @@ pub fn ActionsView<'a>(
 
     let total_rows = filtered_rows.len();
     let visible_rows = (props.height.saturating_sub(5) / 2).max(1) as usize;
+    super::common::take_table_click(
+        row_click,
+        total_rows,
+        super::common::ClickTargets {
+            cursor,
+            preview_scroll: detail_scroll,
+            preview_open: detail_open,
+            sidebar_focused,
+        },
+    );
+
+    // Keep the cursor on the same run when a refresh replaces the rows.
+    let fetched_at = filter_fetch_times
+        .read()
+        .get(current_filter_idx)
+        .copied()
+        .flatten();
+    super::common::anchor_cursor(
+        &mut cursor_anchor,
+        &mut cursor,
+        &mut scroll_offset,
+        current_filter_idx,
+        fetched_at,
+        &filtered_run_indices
+            .iter()
+            .filter_map(|&i| current_data?.runs.get(i))
+            .map(|run| run.id)
+            .collect::<Vec<_>>(),
+    );
 
     // Clone for keyboard handler capture.
     let filtered_run_indices_for_kb = filtered_run_indices.clone();
@@ -1482,17 +2216,105 @@ pub fn ActionsView<'a>(
             ifl.insert(run_id);
             jobs_in_flight.set(ifl);
         }
+        // Then the logs of its failed steps.
+        if let Some(jobs) = jobs_cache.read().get(&run_id)
+            && step_excerpts.read().wants(jobs)
+            && let Some(ref eng) = engine
+            && let Some((owner, repo)) =
+                owner_repo_for_run(cur_run, current_filter_cfg_for_kb.as_ref())
+        {
+            let host = current_filter_cfg_for_kb
+                .as_ref()
+                .and_then(|f| f.host.as_deref());
+            let mut excerpts = step_excerpts.read().clone();
+            excerpts.fetch(jobs, (&owner, &repo, host), eng, &event_tx);
+            step_excerpts.set(excerpts);
+        }
+    }
+
+    // Quick-run presets with their target repo: the tab's own repo, else the
+    // selected run's (multi-repo tabs).
+    let presets: Vec<DispatchPreset> = props
+        .config
+        .as_deref()
+        .map(|c| c.actions.dispatch_presets.clone())
+        .unwrap_or_default();
+    let dispatch_fallback_repo = current_filter_cfg_for_kb
+        .as_ref()
+        .filter(|f| f.repos.is_empty())
+        .and_then(|f| resolve_filter_repo(&f.repo, scope_repo.as_deref(), detected_repo.as_deref()))
+        .map(str::to_owned)
+        .or_else(|| {
+            cur_run_for_fetch
+                .as_ref()
+                .and_then(|r| r.repository.clone())
+        });
+    let preset_repos: Vec<Option<String>> = presets
+        .iter()
+        .map(|p| {
+            dispatch::preset_repo(
+                p,
+                dispatch_fallback_repo.as_deref(),
+                scope_repo.as_deref(),
+                detected_repo.as_deref(),
+            )
+        })
+        .collect();
+
+    // Fetch the shown log again while its job runs.
+    {
+        let engine_for_poll = engine.clone();
+        let tx_for_poll = event_tx.clone();
+        hooks.use_future(async move {
+            loop {
+                smol::Timer::after(job_log::POLL_INTERVAL).await;
+                if let Some(ref eng) = engine_for_poll
+                    && let Some(shown) = job_log.read().as_ref().filter(|l| l.live)
+                {
+                    eng.send(shown.request(&tx_for_poll));
+                }
+            }
+        });
     }
 
     // -----------------------------------------------------------------------
     // Keyboard handling
     // -----------------------------------------------------------------------
 
-    let keybindings = props.keybindings.cloned();
+    let keybindings = props.keybindings.as_deref().cloned();
     hooks.use_terminal_events({
         let engine_for_keys = engine.clone();
         let event_tx_for_keys = event_tx.clone();
         let theme_for_keys = theme.clone();
+        let scope_hint_for_keys = props.scope_hint.clone();
+        let cleanup_older_than_days = props.cleanup_older_than_days;
+        let group_matrix = props.group_matrix_jobs;
+        // The cleanup targets the scoped repo, else the active tab's repo.
+        let cleanup_repo_for_keys = scope_repo.clone().or_else(|| {
+            current_filter_cfg_for_kb
+                .as_ref()
+                .filter(|f| f.repos.is_empty())
+                .and_then(|f| resolve_filter_repo(&f.repo, None, detected_repo.as_deref()))
+                .map(str::to_owned)
+        });
+        // Schedules and manual runs are read for the scoped repo, else the
+        // tab's repos.
+        let schedule_repos_for_keys: Vec<String> = match (&scope_repo, &current_filter_cfg_for_kb) {
+            (Some(repo), _) => vec![repo.clone()],
+            (None, Some(f)) if !f.repos.is_empty() => f
+                .repos
+                .iter()
+                .filter(|r| !r.starts_with("org:"))
+                .cloned()
+                .collect(),
+            (None, Some(f)) => resolve_filter_repo(&f.repo, None, detected_repo.as_deref())
+                .map(str::to_owned)
+                .into_iter()
+                .collect(),
+            (None, None) => Vec::new(),
+        };
+        let presets_for_keys = presets.clone();
+        let preset_repos_for_keys = preset_repos.clone();
         move |event| match event {
             TerminalEvent::Key(KeyEvent {
                 code,
@@ -1512,8 +2334,117 @@ pub fn ActionsView<'a>(
                     return;
                 }
 
+                // Quick-run overlay intercepts all keys.
+                if quick_run.get().is_some() {
+                    if let QuickRunKey::Pick(idx) =
+                        dispatch::handle_key(&mut quick_run, code, presets_for_keys.len())
+                    {
+                        pending_preset.set(Some(idx));
+                        input_mode.set(InputMode::Confirm(BuiltinAction::QuickRun));
+                    }
+                    return;
+                }
+
+                // Scheduled-runs overlay intercepts all keys.
+                if schedules_overlay.read().is_some() {
+                    schedules::handle_key(&mut schedules_overlay, code);
+                    return;
+                }
+
+                // Manual-run overlay intercepts all keys; a submitted form
+                // triggers the run.
+                if run_workflow_overlay.read().is_some() {
+                    if let Some((run, host)) =
+                        run_workflow::handle_key(&mut run_workflow_overlay, code)
+                    {
+                        refetch_on_mutation.set(true);
+                        action_status.set(Some(ActionFeedback::Info(run_workflow::send(
+                            engine_for_keys.as_ref(),
+                            run,
+                            host,
+                            &event_tx_for_keys,
+                            theme_for_keys.chrome,
+                        ))));
+                        status_set_at.set(Some(std::time::Instant::now()));
+                    }
+                    return;
+                }
+
+                // Job picker intercepts all keys; a pick shows that job's log.
+                let picker = job_picker.read().clone();
+                if let Some(mut picker) = picker {
+                    match job_log::handle_picker_key(&mut picker, code) {
+                        PickerOutcome::Open => job_picker.set(Some(picker)),
+                        PickerOutcome::Closed => job_picker.set(None),
+                        PickerOutcome::Picked(job) => {
+                            if let Some(ref eng) = engine_for_keys {
+                                job_log.set(Some(picker.show(&job, eng, &event_tx_for_keys)));
+                                detail_scroll.set(0);
+                            }
+                            job_picker.set(None);
+                        }
+                    }
+                    return;
+                }
+
+                // Branch picker intercepts all keys; a pick refetches its tab.
+                if ref_picker_state.read().is_some() {
+                    if let Some(RefPick { tab, branch }) =
+                        ref_picker::handle_key(&mut ref_picker_state, code)
+                    {
+                        action_status.set(Some(ActionFeedback::Info(match &branch {
+                            Some(branch) => format!("Runs on {branch}"),
+                            None => "Runs on all branches".to_owned(),
+                        })));
+                        status_set_at.set(Some(std::time::Instant::now()));
+                        let mut scopes = ref_scopes.read().clone();
+                        scopes.insert(tab, branch);
+                        ref_scopes.set(scopes);
+                        let mut state = actions_state.read().clone();
+                        if let Some(fd) = state.filters.get_mut(tab) {
+                            *fd = Arc::new(FilterData::default());
+                        }
+                        actions_state.set(state);
+                        super::common::set_in_flight(&mut filter_in_flight, tab, false);
+                        refresh_registered.set(false);
+                        cursor.set(0);
+                        scroll_offset.set(0);
+                    }
+                    return;
+                }
+
                 let current_mode = input_mode.read().clone();
                 match current_mode {
+                    InputMode::KeepTab => {
+                        match keep_tab::handle_key(code, modifiers, &mut keep_title) {
+                            keep_tab::Prompt::Editing => {}
+                            keep_tab::Prompt::Cancelled => input_mode.set(InputMode::Normal),
+                            keep_tab::Prompt::Submitted(title) => {
+                                input_mode.set(InputMode::Normal);
+                                keep_title.set(String::new());
+                                let mut eph = ephemeral_filters.read().clone();
+                                if let Some((filter, _)) = current_filter_idx
+                                    .checked_sub(filter_count)
+                                    .and_then(|ei| eph.get_mut(ei))
+                                {
+                                    let scoped = with_ref_scope(
+                                        filter,
+                                        ref_scopes.read().get(&current_filter_idx),
+                                    );
+                                    let feedback = keep_tab::keep(
+                                        "actions_filters",
+                                        &keep_tab::actions_table(&scoped, &title),
+                                    );
+                                    if matches!(feedback, ActionFeedback::Success(_)) {
+                                        filter.title = title;
+                                        ephemeral_filters.set(eph);
+                                    }
+                                    action_status.set(Some(feedback));
+                                    status_set_at.set(Some(std::time::Instant::now()));
+                                }
+                            }
+                        }
+                    }
                     InputMode::Search => match code {
                         KeyCode::Esc => {
                             input_mode.set(InputMode::Normal);
@@ -1571,12 +2502,76 @@ pub fn ActionsView<'a>(
                                         &event_tx_for_keys,
                                     );
                                 }
+                                BuiltinAction::CleanupRuns => {
+                                    if let Some(plan) = cleanup_plan.read().as_ref()
+                                        && let Some(ref eng) = engine_for_keys
+                                    {
+                                        let mut deletion = CleanupDeletion::new(
+                                            plan.owner.clone(),
+                                            plan.repo.clone(),
+                                            current_filter_cfg_for_kb
+                                                .as_ref()
+                                                .and_then(|f| f.host.clone()),
+                                            plan.candidates
+                                                .iter()
+                                                .take(CLEANUP_BATCH)
+                                                .map(|c| c.run.id)
+                                                .collect(),
+                                        );
+                                        if let Some(req) = deletion.next_request(&event_tx_for_keys)
+                                        {
+                                            eng.send(req);
+                                        }
+                                        cleanup_deletion.set(Some(deletion));
+                                        action_status.set(Some(ActionFeedback::Info(format!(
+                                            "Deleting workflow runs in {}/{}{}",
+                                            plan.owner,
+                                            plan.repo,
+                                            ellipsis(theme_for_keys.chrome)
+                                        ))));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    cleanup_plan.set(None);
+                                }
+                                BuiltinAction::QuickRun => {
+                                    let idx = pending_preset.get();
+                                    if let Some(preset) = idx.and_then(|i| presets_for_keys.get(i))
+                                    {
+                                        let feedback = match idx
+                                            .and_then(|i| preset_repos_for_keys.get(i).cloned())
+                                            .flatten()
+                                        {
+                                            Some(repo) => {
+                                                refetch_on_mutation.set(true);
+                                                ActionFeedback::Info(dispatch::send(
+                                                    engine_for_keys.as_ref(),
+                                                    preset,
+                                                    &repo,
+                                                    current_filter_cfg_for_kb
+                                                        .as_ref()
+                                                        .and_then(|f| f.host.clone()),
+                                                    &event_tx_for_keys,
+                                                    theme_for_keys.chrome,
+                                                ))
+                                            }
+                                            None => ActionFeedback::Warning(format!(
+                                                "{}: no repository in context",
+                                                preset.name
+                                            )),
+                                        };
+                                        action_status.set(Some(feedback));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    pending_preset.set(None);
+                                }
                                 _ => {}
                             }
                             input_mode.set(InputMode::Normal);
                         }
                         KeyCode::Char('n' | 'N') | KeyCode::Esc => {
                             input_mode.set(InputMode::Normal);
+                            cleanup_plan.set(None);
+                            pending_preset.set(None);
                             action_status.set(Some(ActionFeedback::Info("Cancelled".to_owned())));
                             status_set_at.set(Some(std::time::Instant::now()));
                         }
@@ -1645,7 +2640,14 @@ pub fn ActionsView<'a>(
                             };
                             match keybindings
                                 .as_ref()
-                                .and_then(|kb| kb.resolve(&key_str, ViewContext::Actions))
+                                .and_then(|kb| {
+                                    kb.resolve_focused(
+                                        &key_str,
+                                        ViewContext::Actions,
+                                        sidebar_focused.get(),
+                                    )
+                                })
+                                .map(|binding| super::common::refresh_fallback(binding, total_rows))
                             {
                                 Some(ResolvedBinding::Builtin(action)) => match action {
                                     BuiltinAction::Quit => {
@@ -1673,11 +2675,182 @@ pub fn ActionsView<'a>(
                                             st.set(true);
                                         }
                                     }
+                                    BuiltinAction::ShowLogs => {
+                                        if let Some(mut sl) = show_logs {
+                                            sl.set(true);
+                                        }
+                                    }
+                                    BuiltinAction::ToggleDates => {
+                                        if let Some(mut dt) = date_toggle {
+                                            dt.set(true);
+                                        }
+                                    }
+                                    BuiltinAction::SortColumn | BuiltinAction::ReverseSort => {
+                                        let columns: Vec<&Column> = sort_columns.iter().collect();
+                                        let current = table_sort.read().clone();
+                                        table_sort.set(if action == BuiltinAction::SortColumn {
+                                            TableSort::next(current.as_ref(), &columns)
+                                        } else {
+                                            TableSort::reversed(current.as_ref(), &columns)
+                                        });
+                                        cursor.set(0);
+                                        scroll_offset.set(0);
+                                    }
+                                    BuiltinAction::ToggleFocus => {
+                                        let focused = !sidebar_focused.get();
+                                        sidebar_focused.set(focused);
+                                        if focused {
+                                            detail_open.set(true);
+                                            nav_focused.set(false);
+                                        }
+                                    }
+                                    BuiltinAction::MoveDown
+                                    | BuiltinAction::MoveUp
+                                    | BuiltinAction::First
+                                    | BuiltinAction::Last
+                                        if sidebar_focused.get() =>
+                                    {
+                                        // A job log or comparison in the sidebar scrolls.
+                                        let order = get_run_at_cursor(
+                                            &actions_state,
+                                            current_filter_idx,
+                                            cursor.get(),
+                                            &filtered_run_indices_for_kb,
+                                        )
+                                        .filter(|_| {
+                                            job_log.read().is_none() && comparison.read().is_none()
+                                        })
+                                        .and_then(|run| {
+                                            let cache = jobs_cache.read();
+                                            Some((
+                                                run.id,
+                                                job_order(cache.get(&run.id)?, group_matrix),
+                                            ))
+                                        });
+                                        match order {
+                                            Some((run_id, order)) if !order.is_empty() => {
+                                                let current = job_sel
+                                                    .get()
+                                                    .filter(|(id, _)| *id == run_id)
+                                                    .map(|(_, job)| job);
+                                                if let Some(job) =
+                                                    sidebar_focus::step(&order, current, action)
+                                                {
+                                                    job_sel.set(Some((run_id, job)));
+                                                    job_jump.set(true);
+                                                }
+                                            }
+                                            _ => detail_scroll.set(sidebar_focus::scroll(
+                                                detail_scroll.get(),
+                                                action,
+                                            )),
+                                        }
+                                    }
+                                    BuiltinAction::OpenItem
+                                    | BuiltinAction::CopyItem
+                                    | BuiltinAction::ToggleItem => {
+                                        let run = get_run_at_cursor(
+                                            &actions_state,
+                                            current_filter_idx,
+                                            cursor.get(),
+                                            &filtered_run_indices_for_kb,
+                                        );
+                                        let selected = run.as_ref().and_then(|run| {
+                                            let (_, job_id) =
+                                                job_sel.get().filter(|(id, _)| *id == run.id)?;
+                                            let cache = jobs_cache.read();
+                                            let jobs = cache.get(&run.id)?;
+                                            let job = jobs.iter().find(|job| job.id == job_id)?;
+                                            Some((job.clone(), jobs.clone()))
+                                        });
+                                        let feedback = match (action, run, selected) {
+                                            (_, Some(run), Some((job, jobs)))
+                                                if action == BuiltinAction::OpenItem =>
+                                            {
+                                                if let Some((owner, repo)) = owner_repo_for_run(
+                                                    &run,
+                                                    current_filter_cfg_for_kb.as_ref(),
+                                                ) && let Some(ref eng) = engine_for_keys
+                                                {
+                                                    let host = current_filter_cfg_for_kb
+                                                        .as_ref()
+                                                        .and_then(|f| f.host.clone());
+                                                    let picker = JobPicker::new(
+                                                        run.id,
+                                                        jobs,
+                                                        (owner, repo, host),
+                                                    );
+                                                    comparison.set(None);
+                                                    job_log.set(Some(picker.show(
+                                                        &job,
+                                                        eng,
+                                                        &event_tx_for_keys,
+                                                    )));
+                                                    detail_scroll.set(0);
+                                                }
+                                                None
+                                            }
+                                            (BuiltinAction::CopyItem, _, Some((job, _))) => Some(
+                                                match clipboard::copy_to_clipboard(&job.html_url) {
+                                                    Ok(()) => ActionFeedback::Success(
+                                                        "Copied job link".to_owned(),
+                                                    ),
+                                                    Err(e) => ActionFeedback::Error(format!(
+                                                        "Copy failed: {e}"
+                                                    )),
+                                                },
+                                            ),
+                                            (BuiltinAction::ToggleItem, _, Some((job, _))) => {
+                                                let mut excerpts = step_excerpts.read().clone();
+                                                if excerpts.has(&job) {
+                                                    excerpts.toggle(job.id);
+                                                    step_excerpts.set(excerpts);
+                                                    None
+                                                } else {
+                                                    Some(ActionFeedback::Info(
+                                                        "Only a failed step's log folds".to_owned(),
+                                                    ))
+                                                }
+                                            }
+                                            _ => Some(ActionFeedback::Info(
+                                                "Select a job first: tab, then j/k".to_owned(),
+                                            )),
+                                        };
+                                        if let Some(feedback) = feedback {
+                                            action_status.set(Some(feedback));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                    }
+                                    BuiltinAction::AuthorizeSso => {
+                                        action_status.set(Some(super::common::authorize_sso()));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::PostponeRefresh => {
+                                        let fetched = filter_fetch_times
+                                            .read()
+                                            .get(current_filter_idx)
+                                            .copied()
+                                            .flatten();
+                                        action_status.set(Some(super::common::postpone_refresh(
+                                            engine_for_keys.as_ref(),
+                                            RefreshView::Actions,
+                                            current_filter_idx,
+                                            fetched,
+                                            refresh_interval,
+                                            &mut refresh_postponed,
+                                        )));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::SelectRepo => {
                                         if let Some(mut rp) = repo_picker {
                                             rp.set(true);
                                         }
                                     }
+                                    BuiltinAction::RescanRepo => {
+                                        if let Some(mut rr) = rescan_repo {
+                                            rr.set(true);
+                                        }
+                                    }
                                     BuiltinAction::ToggleHelp => {
                                         help_visible.set(true);
                                     }
@@ -1685,7 +2858,19 @@ pub fn ActionsView<'a>(
                                         if let Some(run) = current_run
                                             && !run.html_url.is_empty()
                                         {
-                                            let _ = clipboard::open_in_browser(&run.html_url);
+                                            let feedback =
+                                                match clipboard::open_in_browser(&run.html_url) {
+                                                    Ok(how) => {
+                                                        ActionFeedback::Success(how.message(
+                                                            &format!("run #{}", run.run_number),
+                                                        ))
+                                                    }
+                                                    Err(e) => ActionFeedback::Error(format!(
+                                                        "Open failed: {e}"
+                                                    )),
+                                                };
+                                            action_status.set(Some(feedback));
+                                            status_set_at.set(Some(std::time::Instant::now()));
                                         }
                                     }
                                     BuiltinAction::CopyNumber => {
@@ -1764,7 +2949,7 @@ pub fn ActionsView<'a>(
                                         let idx = current_filter_idx;
                                         let mut state = actions_state.read().clone();
                                         if idx < state.filters.len() {
-                                            state.filters[idx] = FilterData::default();
+                                            state.filters[idx] = Arc::new(FilterData::default());
                                         }
                                         actions_state.set(state);
                                         let mut times = filter_fetch_times.read().clone();
@@ -1776,26 +2961,26 @@ pub fn ActionsView<'a>(
                                         // sidebar detail re-fetches along with the
                                         // table (otherwise the cache hit prevents
                                         // a new FetchRunJobs from being sent).
-                                        jobs_cache.set(HashMap::new());
+                                        jobs_cache.set(LruCache::new(detail_cache_size));
                                         jobs_in_flight.set(HashSet::new());
                                         // Reset the monotonic rate-limit guard so
                                         // the next response seeds the counter.
-                                        rate_limit_state.set(None);
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                     }
                                     BuiltinAction::RefreshAll => {
                                         let mut state = actions_state.read().clone();
                                         for filter in &mut state.filters {
-                                            *filter = FilterData::default();
+                                            *filter = Arc::new(FilterData::default());
                                         }
                                         actions_state.set(state);
                                         let mut times = filter_fetch_times.read().clone();
                                         times.fill(None);
                                         filter_fetch_times.set(times);
-                                        jobs_cache.set(HashMap::new());
+                                        jobs_cache.set(LruCache::new(detail_cache_size));
                                         jobs_in_flight.set(HashSet::new());
-                                        rate_limit_state.set(None);
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                         refresh_all.set(true);
@@ -1810,6 +2995,8 @@ pub fn ActionsView<'a>(
                                         detail_open.set(new_open);
                                         if new_open {
                                             detail_scroll.set(0);
+                                        } else {
+                                            sidebar_focused.set(false);
                                         }
                                     }
                                     BuiltinAction::SidebarWider => {
@@ -1827,6 +3014,16 @@ pub fn ActionsView<'a>(
                                             s.set(default_pct);
                                         }
                                     }
+                                    BuiltinAction::RerunFailed
+                                    | BuiltinAction::RerunAll
+                                    | BuiltinAction::CancelRun
+                                    | BuiltinAction::CleanupRuns
+                                        if let Some(hint) = &scope_hint_for_keys =>
+                                    {
+                                        action_status
+                                            .set(Some(ActionFeedback::Warning(hint.clone())));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::RerunFailed => {
                                         if let Some(run) = get_run_at_cursor(
                                             &actions_state,
@@ -1893,6 +3090,250 @@ pub fn ActionsView<'a>(
                                             }
                                         }
                                     }
+                                    BuiltinAction::CompareLastSuccess => {
+                                        let Some(run) = get_run_at_cursor(
+                                            &actions_state,
+                                            current_filter_idx,
+                                            cursor.get(),
+                                            &filtered_run_indices_for_kb,
+                                        ) else {
+                                            return;
+                                        };
+                                        let showing = comparison
+                                            .read()
+                                            .as_ref()
+                                            .is_some_and(|c| c.run_id() == run.id);
+                                        if showing {
+                                            comparison.set(None);
+                                        } else if run.status != RunStatus::Completed {
+                                            action_status.set(Some(ActionFeedback::Warning(
+                                                "Cannot compare: run is still in progress"
+                                                    .to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        } else if let Some((owner, repo)) = owner_repo_for_run(
+                                            &run,
+                                            current_filter_cfg_for_kb.as_ref(),
+                                        ) && let Some(ref eng) = engine_for_keys
+                                        {
+                                            comparison.set(Some(ComparisonState::Loading(run.id)));
+                                            job_log.set(None);
+                                            detail_open.set(true);
+                                            detail_scroll.set(0);
+                                            eng.send(Request::CompareWithLastSuccess {
+                                                owner,
+                                                repo,
+                                                run: Box::new(run),
+                                                host: current_filter_cfg_for_kb
+                                                    .as_ref()
+                                                    .and_then(|f| f.host.clone()),
+                                                reply_tx: event_tx_for_keys.clone(),
+                                            });
+                                        }
+                                    }
+                                    BuiltinAction::QuickRun => {
+                                        if presets_for_keys.is_empty() {
+                                            action_status.set(Some(ActionFeedback::Info(
+                                                "No dispatch presets configured \
+                                                 ([[actions.dispatch_presets]])"
+                                                    .to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        } else {
+                                            quick_run.set(Some(0));
+                                        }
+                                    }
+                                    BuiltinAction::JobLog => {
+                                        let Some(run) = get_run_at_cursor(
+                                            &actions_state,
+                                            current_filter_idx,
+                                            cursor.get(),
+                                            &filtered_run_indices_for_kb,
+                                        ) else {
+                                            return;
+                                        };
+                                        let jobs = jobs_cache
+                                            .read()
+                                            .get(&run.id)
+                                            .cloned()
+                                            .filter(|jobs| !jobs.is_empty());
+                                        if job_log
+                                            .read()
+                                            .as_ref()
+                                            .is_some_and(|l| l.run_id == run.id)
+                                        {
+                                            job_log.set(None);
+                                            detail_scroll.set(0);
+                                        } else if let Some(jobs) = jobs
+                                            && let Some((owner, repo)) = owner_repo_for_run(
+                                                &run,
+                                                current_filter_cfg_for_kb.as_ref(),
+                                            )
+                                            && let Some(ref eng) = engine_for_keys
+                                        {
+                                            let host = current_filter_cfg_for_kb
+                                                .as_ref()
+                                                .and_then(|f| f.host.clone());
+                                            let picker =
+                                                JobPicker::new(run.id, jobs, (owner, repo, host));
+                                            comparison.set(None);
+                                            detail_open.set(true);
+                                            if let Some(job) = picker.only_job() {
+                                                job_log.set(Some(picker.show(
+                                                    job,
+                                                    eng,
+                                                    &event_tx_for_keys,
+                                                )));
+                                                detail_scroll.set(0);
+                                            } else {
+                                                job_picker.set(Some(picker));
+                                            }
+                                        } else {
+                                            // The sidebar fetches the jobs.
+                                            detail_open.set(true);
+                                            action_status.set(Some(ActionFeedback::Info(
+                                                "Loading the run's jobs, press L again".to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                    }
+                                    BuiltinAction::ToggleLogFolds => {
+                                        let mut shown = job_log.read().clone();
+                                        if let Some(current) = shown.as_mut() {
+                                            current.toggle_folds();
+                                            job_log.set(shown);
+                                        } else if detail_open.get()
+                                            && let Some(run) = get_run_at_cursor(
+                                                &actions_state,
+                                                current_filter_idx,
+                                                cursor.get(),
+                                                &filtered_run_indices_for_kb,
+                                            )
+                                            && let Some(jobs) =
+                                                jobs_cache.read().get(&run.id).cloned()
+                                        {
+                                            // The job list: its step excerpts.
+                                            let mut excerpts = step_excerpts.read().clone();
+                                            if excerpts.toggle_all(&jobs) {
+                                                step_excerpts.set(excerpts);
+                                            }
+                                        }
+                                    }
+                                    BuiltinAction::ShowSchedules => {
+                                        if schedule_repos_for_keys.is_empty() {
+                                            action_status.set(Some(ActionFeedback::Warning(
+                                                "Scheduled runs need a repo in context".to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        } else {
+                                            schedules::open(
+                                                &mut schedules_overlay,
+                                                engine_for_keys.as_ref(),
+                                                current_filter_cfg_for_kb
+                                                    .as_ref()
+                                                    .and_then(|f| f.host.clone()),
+                                                schedule_repos_for_keys.clone(),
+                                                &event_tx_for_keys,
+                                            );
+                                        }
+                                    }
+                                    BuiltinAction::RunWorkflow => {
+                                        if schedule_repos_for_keys.is_empty() {
+                                            action_status.set(Some(ActionFeedback::Warning(
+                                                "Running a workflow needs a repo in context"
+                                                    .to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        } else {
+                                            run_workflow::open(
+                                                &mut run_workflow_overlay,
+                                                engine_for_keys.as_ref(),
+                                                current_filter_cfg_for_kb
+                                                    .as_ref()
+                                                    .and_then(|f| f.host.clone()),
+                                                schedule_repos_for_keys.clone(),
+                                                &event_tx_for_keys,
+                                            );
+                                        }
+                                    }
+                                    BuiltinAction::PickRef => {
+                                        if let Some(repo) = cleanup_repo_for_keys.as_deref() {
+                                            let cfg = current_filter_cfg_for_kb.as_ref();
+                                            ref_picker::open(
+                                                &mut ref_picker_state,
+                                                engine_for_keys.as_ref(),
+                                                cfg.and_then(|f| f.host.clone()),
+                                                (current_filter_idx, repo),
+                                                cfg.and_then(|f| f.branch.clone()),
+                                                &event_tx_for_keys,
+                                            );
+                                        } else {
+                                            action_status.set(Some(ActionFeedback::Warning(
+                                                "Picking a branch needs a single repo in context"
+                                                    .to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                    }
+                                    BuiltinAction::CleanupRuns => {
+                                        if let Some((owner, repo)) = cleanup_repo_for_keys
+                                            .as_deref()
+                                            .and_then(|r| r.split_once('/'))
+                                            && let Some(ref eng) = engine_for_keys
+                                        {
+                                            eng.send(Request::PlanRunCleanup {
+                                                owner: owner.to_owned(),
+                                                repo: repo.to_owned(),
+                                                older_than_days: cleanup_older_than_days,
+                                                host: current_filter_cfg_for_kb
+                                                    .as_ref()
+                                                    .and_then(|f| f.host.clone()),
+                                                reply_tx: event_tx_for_keys.clone(),
+                                            });
+                                            action_status.set(Some(ActionFeedback::Info(format!(
+                                                "Scanning workflow runs in {owner}/{repo}{}",
+                                                ellipsis(theme_for_keys.chrome)
+                                            ))));
+                                        } else {
+                                            action_status.set(Some(ActionFeedback::Warning(
+                                                "Run cleanup needs a scoped repo".to_owned(),
+                                            )));
+                                        }
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::JumpToPr => {
+                                        let run = get_run_at_cursor(
+                                            &actions_state,
+                                            current_filter_idx,
+                                            cursor.get(),
+                                            &filtered_run_indices_for_kb,
+                                        );
+                                        let target = run.as_ref().and_then(|run| {
+                                            let number = *run.pull_requests.first()?;
+                                            let (owner, repo) = owner_repo_for_run(
+                                                run,
+                                                current_filter_cfg_for_kb.as_ref(),
+                                            )?;
+                                            Some(NavigationTarget::PullRequest {
+                                                owner,
+                                                repo,
+                                                number,
+                                                host: current_filter_cfg_for_kb
+                                                    .as_ref()
+                                                    .and_then(|f| f.host.clone()),
+                                            })
+                                        });
+                                        match (target, nav_target_prop) {
+                                            (Some(target), Some(mut nt)) => nt.set(Some(target)),
+                                            (None, _) if run.is_some() => {
+                                                action_status.set(Some(ActionFeedback::Warning(
+                                                    "No pull request triggered this run".to_owned(),
+                                                )));
+                                                status_set_at.set(Some(std::time::Instant::now()));
+                                            }
+                                            _ => {}
+                                        }
+                                    }
                                     BuiltinAction::WatchRun => {
                                         if let Some(run) = get_run_at_cursor(
                                             &actions_state,
@@ -1934,11 +3375,12 @@ pub fn ActionsView<'a>(
                                                                 .copied()
                                                         && let Some(r) = fd.runs.get(orig_idx)
                                                     {
-                                                        fd.rows[orig_idx] = run_to_row(
+                                                        let row = run_to_row(
                                                             r,
                                                             &theme_for_keys,
                                                             &watched_run_ids.read(),
                                                         );
+                                                        Arc::make_mut(fd).rows[orig_idx] = row;
                                                     }
                                                     actions_state.set(state);
                                                 } else {
@@ -1981,11 +3423,12 @@ pub fn ActionsView<'a>(
                                                                     .copied()
                                                             && let Some(r) = fd.runs.get(orig_idx)
                                                         {
-                                                            fd.rows[orig_idx] = run_to_row(
+                                                            let row = run_to_row(
                                                                 r,
                                                                 &theme_for_keys,
                                                                 &watched_run_ids.read(),
                                                             );
+                                                            Arc::make_mut(fd).rows[orig_idx] = row;
                                                         }
                                                         actions_state.set(state);
                                                     }
@@ -1993,6 +3436,19 @@ pub fn ActionsView<'a>(
                                             }
                                         }
                                     }
+                                    BuiltinAction::KeepTab => {
+                                        let kept =
+                                            current_filter_idx.checked_sub(filter_count).and_then(
+                                                |ei| ephemeral_filters.read().get(ei).cloned(),
+                                            );
+                                        if let Some((filter, _)) = kept {
+                                            keep_title.set(filter.title);
+                                            input_mode.set(InputMode::KeepTab);
+                                        } else {
+                                            action_status.set(Some(keep_tab::not_ephemeral()));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                    }
                                     BuiltinAction::GoBack => {
                                         detail_open.set(false);
                                         if let Some(mut gb) = go_back_prop {
@@ -2115,6 +3571,20 @@ pub fn ActionsView<'a>(
                                                 times.remove(current_filter_idx);
                                             }
                                             filter_fetch_times.set(times);
+                                            let scopes = ref_scopes
+                                                .read()
+                                                .iter()
+                                                .filter(|&(&i, _)| i != current_filter_idx)
+                                                .map(|(&i, branch)| {
+                                                    let i = if i > current_filter_idx {
+                                                        i - 1
+                                                    } else {
+                                                        i
+                                                    };
+                                                    (i, branch.clone())
+                                                })
+                                                .collect();
+                                            ref_scopes.set(scopes);
 
                                             // Clamp active filter.
                                             if active_filter.get() >= new_total && new_total > 0 {
@@ -2135,6 +3605,17 @@ pub fn ActionsView<'a>(
                                     let expanded = expand_template(&cmd, &vars);
                                     let _ = execute_shell_command(&expanded);
                                 }
+                                Some(ResolvedBinding::Script(path)) => {
+                                    if let Some(feedback) = crate::script::run(
+                                        &path,
+                                        "actions",
+                                        &vars,
+                                        engine_for_keys.clone(),
+                                        theme_for_keys.chrome,
+                                    ) {
+                                        action_status.set(Some(feedback));
+                                    }
+                                }
                                 None => {
                                     // Esc: close nav → close detail
                                     if key_str == "esc" {
@@ -2159,7 +3640,14 @@ pub fn ActionsView<'a>(
                 }
             }
             TerminalEvent::FullscreenMouse(mouse_event) => {
-                if !is_active || help_visible.get() {
+                if !is_active
+                    || help_visible.get()
+                    || quick_run.get().is_some()
+                    || schedules_overlay.read().is_some()
+                    || run_workflow_overlay.read().is_some()
+                    || job_picker.read().is_some()
+                    || ref_picker_state.read().is_some()
+                {
                     return;
                 }
                 let delta = match mouse_event.kind {
@@ -2221,13 +3709,20 @@ pub fn ActionsView<'a>(
         .iter()
         .enumerate()
         .map(|(i, (f, is_eph))| Tab {
-            title: f.title.clone(),
+            title: match scopes_snapshot.get(&i) {
+                Some(Some(branch)) => format!("{}{}{branch}", f.title, dot_separator(theme.chrome)),
+                _ => f.title.clone(),
+            },
             count: state_ref.filters.get(i).map(|d| d.run_count),
+            hidden: 0,
             is_ephemeral: *is_eph,
         })
         .collect();
 
-    let columns = actions_columns();
+    // A failed fetch shows its error in the footer, and how to retry here.
+    let failed_message = current_data
+        .is_some_and(|d| d.error.is_some())
+        .then(|| super::common::failed_tab_message(props.keybindings.as_deref()));
     let rendered_table = RenderedTable::build(&TableBuildConfig {
         columns: &columns,
         rows: &filtered_rows,
@@ -2242,7 +3737,9 @@ pub fn ActionsView<'a>(
         header_color: Some(theme.text_secondary),
         border_color: Some(theme.border_faint),
         show_separator: props.show_separator,
-        empty_message: if search_q.is_empty() {
+        empty_message: if let Some(msg) = &failed_message {
+            Some(msg.as_str())
+        } else if search_q.is_empty() {
             Some("No workflow runs found")
         } else {
             Some("No runs match this filter")
@@ -2250,13 +3747,30 @@ pub fn ActionsView<'a>(
         subtitle_column: None,
         row_separator: true,
         scrollbar_thumb_color: Some(theme.border_primary),
+        chrome: theme.chrome,
+        sort: current_sort.as_ref(),
     });
 
     let tab_colors = TabBarColors {
         active: Some(theme.footer_actions),
         inactive: Some(theme.footer_actions),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
+    let countdown = super::common::refresh_countdown(
+        filter_fetch_times
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        refresh_interval,
+        refresh_postponed
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        &theme.icons.tab_refresh,
+    );
     let rendered_tab_bar = RenderedTabBar::build(
         &tabs,
         current_filter_idx,
@@ -2265,29 +3779,54 @@ pub fn ActionsView<'a>(
         &tab_colors,
         &theme.icons.tab_filter,
         &theme.icons.tab_ephemeral,
-    );
+    )
+    .with_countdown(countdown);
 
     let current_mode = input_mode.read().clone();
+    let plan_snapshot = cleanup_plan.read().clone();
     let rendered_text_input = match current_mode {
         InputMode::Confirm(ref action) => {
             let prompt = match action {
-                BuiltinAction::RerunFailed => "Re-run failed jobs? (y/n)",
-                BuiltinAction::RerunAll => "Re-run ALL jobs? (y/n)",
-                BuiltinAction::CancelRun => "Cancel this run? (y/n)",
-                _ => "(y/n)",
+                BuiltinAction::RerunFailed => "Re-run failed jobs? (y/n)".to_owned(),
+                BuiltinAction::RerunAll => "Re-run ALL jobs? (y/n)".to_owned(),
+                BuiltinAction::CancelRun => "Cancel this run? (y/n)".to_owned(),
+                BuiltinAction::CleanupRuns => plan_snapshot
+                    .as_ref()
+                    .map_or_else(|| "(y/n)".to_owned(), cleanup_prompt),
+                BuiltinAction::QuickRun => pending_preset
+                    .get()
+                    .and_then(|i| presets.get(i).map(|p| (i, p)))
+                    .map_or_else(
+                        || "(y/n)".to_owned(),
+                        |(i, p)| dispatch::confirm_prompt(p, preset_repos[i].as_deref()),
+                    ),
+                _ => "(y/n)".to_owned(),
             };
             Some(RenderedTextInput::build(
-                prompt,
+                &prompt,
                 "",
                 depth,
                 &TextInputColors {
                     text: Some(theme.text_primary),
                     prompt: Some(theme.text_warning),
                     border: Some(theme.border_faint),
+                    chrome: theme.chrome,
                     ..Default::default()
                 },
             ))
         }
+        InputMode::KeepTab => Some(RenderedTextInput::build(
+            "Keep tab as:",
+            &keep_title.read(),
+            depth,
+            &TextInputColors {
+                text: Some(theme.text_primary),
+                prompt: Some(theme.text_secondary),
+                border: Some(theme.border_faint),
+                chrome: theme.chrome,
+                ..Default::default()
+            },
+        )),
         InputMode::Search => Some(RenderedTextInput::build(
             "/",
             &search_query.read(),
@@ -2296,6 +3835,7 @@ pub fn ActionsView<'a>(
                 text: Some(theme.text_primary),
                 prompt: Some(theme.text_secondary),
                 border: Some(theme.border_faint),
+                chrome: theme.chrome,
                 ..Default::default()
             },
         )),
@@ -2304,13 +3844,22 @@ pub fn ActionsView<'a>(
 
     // Context text: action status > normal.
     let context_text = if current_data.is_some_and(|d| d.loading) {
-        "Fetching workflow runs\u{2026}".to_owned()
+        format!("Fetching workflow runs{}", ellipsis(theme.chrome))
     } else if let Some(err) = current_data.and_then(|d| d.error.as_ref()) {
-        format!("Error: {err}")
+        let cached = current_data
+            .and_then(|d| d.stale)
+            .map(|saved_at| super::common::cached_note(&saved_at, false));
+        format!("Error: {err}{}", cached.unwrap_or_default())
     } else {
         let total = current_data.map_or(0, |d| d.run_count);
         let cursor_pos = if total_rows > 0 { cursor.get() + 1 } else { 0 };
-        format!("Run {cursor_pos}/{total_rows} (of {total})")
+        let cached = current_data
+            .and_then(|d| d.stale)
+            .map(|saved_at| super::common::cached_note(&saved_at, true));
+        format!(
+            "Run {cursor_pos}/{total_rows} (of {total}){}",
+            cached.unwrap_or_default()
+        )
     };
 
     let active_fetch_time = filter_fetch_times
@@ -2319,11 +3868,19 @@ pub fn ActionsView<'a>(
         .copied()
         .flatten();
     let updated_text = footer::format_updated_ago(active_fetch_time);
-    let rate_limit_text = footer::format_rate_limit(rate_limit_state.read().as_ref());
+    let rate_limit_text = footer::format_rate_limit(
+        rate_limit_state.read().of(all_filters
+            .get(current_filter_idx)
+            .and_then(|(f, _)| f.host.as_deref())),
+        current_data.and_then(|d| d.refresh_cost),
+    );
     let scope_label = match &scope_repo {
         Some(_) => all_filters
             .get(current_filter_idx)
             .map_or_else(String::new, |(f, _)| {
+                if !f.repos.is_empty() {
+                    return format!("{} repos", f.repos.len());
+                }
                 resolve_filter_repo(&f.repo, scope_repo.as_deref(), detected_repo.as_deref())
                     .unwrap_or("all repos")
                     .to_owned()
@@ -2339,10 +3896,12 @@ pub fn ActionsView<'a>(
             Some(theme.footer_alerts),
             Some(theme.footer_notifications),
             Some(theme.footer_repo),
+            Some(theme.footer_events),
         ],
         inactive: Some(theme.text_faint),
         text: Some(theme.text_faint),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
     let rendered_footer = RenderedFooter::build(
         ViewKind::Actions,
@@ -2352,6 +3911,8 @@ pub fn ActionsView<'a>(
             context_text,
             updated_text,
             rate_limit_text,
+            watch_badges: props.watch_badges.map(|b| b.get()).unwrap_or_default(),
+            view_counts: props.view_counts.map(|c| c.get()).unwrap_or_default(),
         },
         action_status.read().as_ref(),
         &theme,
@@ -2360,7 +3921,7 @@ pub fn ActionsView<'a>(
     );
 
     let rendered_help = if help_visible.get() {
-        props.keybindings.map(|kb| {
+        props.keybindings.as_deref().map(|kb| {
             RenderedHelpOverlay::build(&HelpOverlayBuildConfig {
                 bindings: kb,
                 context: ViewContext::Actions,
@@ -2369,6 +3930,7 @@ pub fn ActionsView<'a>(
                 key_color: Some(theme.text_success),
                 desc_color: Some(theme.text_secondary),
                 border_color: Some(theme.border_primary),
+                chrome: theme.chrome,
             })
         })
     } else {
@@ -2384,21 +3946,63 @@ pub fn ActionsView<'a>(
     let sidebar_jobs = sidebar_run_id
         .and_then(|id| jobs_cache.read().get(&id).cloned())
         .unwrap_or_default();
+    let sidebar_comparison = comparison
+        .read()
+        .clone()
+        .filter(|c| Some(c.run_id()) == sidebar_run_id);
+    // The log follows the run it belongs to: moving away or hiding the
+    // sidebar closes it.
+    let sidebar_job_log = job_log
+        .read()
+        .clone()
+        .filter(|l| detail_open.get() && Some(l.run_id) == sidebar_run_id);
+    if sidebar_job_log.is_none() && job_log.read().is_some() {
+        job_log.set(None);
+    }
     let rendered_sidebar = if detail_open.get() && sidebar_w > 0 {
-        let jobs_lines = build_jobs_lines(
-            &sidebar_jobs,
-            sidebar_loading,
-            props.group_matrix_jobs,
-            &theme,
-        );
-        let sidebar_title = current_run_for_detail
-            .map_or_else(|| "Jobs".to_owned(), |r| format!("Run #{}", r.run_number));
+        let selected_job = job_sel
+            .get()
+            .filter(|(run_id, _)| sidebar_focused.get() && Some(*run_id) == sidebar_run_id)
+            .map(|(_, job)| job);
+        let mut selected_line = None;
+        let jobs_lines = if let Some(ref log) = sidebar_job_log {
+            log.lines(&theme)
+        } else if let Some(ref cmp) = sidebar_comparison {
+            build_comparison_lines(cmp, &theme)
+        } else {
+            let (lines, line) = build_jobs_lines(
+                &sidebar_jobs,
+                sidebar_loading,
+                props.group_matrix_jobs,
+                selected_job,
+                &step_excerpts.read(),
+                &theme,
+            );
+            selected_line = line;
+            lines
+        };
+        let sidebar_title = if let Some(ref log) = sidebar_job_log {
+            log.title()
+        } else {
+            match (&sidebar_comparison, current_run_for_detail) {
+                (Some(ComparisonState::Ready(_, cmp)), Some(r)) => {
+                    format!("Run #{} vs #{}", r.run_number, cmp.baseline.run_number)
+                }
+                (_, Some(r)) => format!("Run #{}", r.run_number),
+                (_, None) => "Jobs".to_owned(),
+            }
+        };
         let sidebar_colors = SidebarColors {
             title: Some(theme.text_primary),
-            border: Some(theme.border_faint),
+            border: Some(if sidebar_focused.get() {
+                theme.border_primary
+            } else {
+                theme.border_faint
+            }),
             indicator: Some(theme.text_faint),
             thumb: Some(theme.border_primary),
             depth,
+            chrome: theme.chrome,
         };
         let sidebar_meta = current_run_for_detail.map(|r| build_run_sidebar_meta(r, &theme, depth));
         let meta_lines = sidebar_meta.as_ref().map_or(0, SidebarMeta::line_count);
@@ -2407,6 +4011,16 @@ pub fn ActionsView<'a>(
             .height
             .saturating_sub(6)
             .saturating_sub(meta_lines as u16) as usize;
+        if job_jump.get()
+            && let Some(line) = selected_line
+        {
+            job_jump.set(false);
+            detail_scroll.set(sidebar_focus::follow(
+                detail_scroll.get(),
+                line,
+                sidebar_visible_lines,
+            ));
+        }
         let sidebar = RenderedSidebar::build_tabbed(
             &sidebar_title,
             &jobs_lines,
@@ -2430,6 +4044,21 @@ pub fn ActionsView<'a>(
         None
     };
 
+    let rendered_cleanup = plan_snapshot
+        .filter(|_| current_mode == InputMode::Confirm(BuiltinAction::CleanupRuns))
+        .map(|plan| build_cleanup_overlay(&plan, &theme, depth));
+
+    let rendered_quick_run = quick_run
+        .get()
+        .map(|c| dispatch::render(&presets, &preset_repos, c, &theme, depth));
+    let rendered_schedules = schedules::render(&schedules_overlay, &theme, depth);
+    let rendered_run_workflow = run_workflow::render(&run_workflow_overlay, &theme, depth);
+    let rendered_job_picker = job_picker
+        .read()
+        .as_ref()
+        .map(|picker| job_log::render_picker(picker, &theme, depth));
+    let rendered_ref_picker = ref_picker::render(&ref_picker_state, &theme, depth);
+
     let nav_is_open = nav_open.get();
     let nav_is_focused = nav_focused.get();
     let nav_border_color = if nav_is_focused {
@@ -2440,10 +4069,19 @@ pub fn ActionsView<'a>(
 
     let width = u32::from(props.width);
     let height = u32::from(props.height);
+    // Clicks go to the overlay or prompt shown, not what is under it.
+    let clickable = *input_mode.read() == InputMode::Normal
+        && !help_visible.get()
+        && cleanup_plan.read().is_none()
+        && quick_run.get().is_none()
+        && schedules_overlay.read().is_none()
+        && run_workflow_overlay.read().is_none()
+        && job_picker.read().is_none()
+        && ref_picker_state.read().is_none();
 
     element! {
         View(flex_direction: FlexDirection::Column, width, height) {
-            TabBar(tab_bar: rendered_tab_bar)
+            TabBar(tab_bar: rendered_tab_bar, on_click: clickable.then_some(tab_click))
 
             View(flex_grow: 1.0_f32, flex_direction: FlexDirection::Row, overflow: Overflow::Hidden) {
                 // Left workflow navigator (optional)
@@ -2455,13 +4093,13 @@ pub fn ActionsView<'a>(
                         View(
                             width: u32::from(NAV_W),
                             flex_direction: FlexDirection::Column,
-                            border_style: BorderStyle::Single,
+                            border_style: crate::components::border(theme.chrome, BorderStyle::Single),
                             border_edges: Edges::Right,
                             border_color: nav_border_color,
                             padding_left: 1u32,
                         ) {
                             View(
-                                border_style: BorderStyle::Single,
+                                border_style: crate::components::border(theme_nav.chrome, BorderStyle::Single),
                                 border_edges: Edges::Bottom,
                                 border_color: theme_nav.border_faint.to_crossterm_color(depth),
                             ) {
@@ -2475,7 +4113,12 @@ pub fn ActionsView<'a>(
                             #(names.into_iter().enumerate().map(|(i, name)| {
                                 let is_selected = i == cur;
                                 let (dot, dot_color) = if i == 0 {
-                                    ("\u{25a1}".to_owned(), theme_nav.text_faint)
+                                    let all = if theme_nav.chrome.ascii_only() {
+                                        "*"
+                                    } else {
+                                        "\u{25a1}" // □
+                                    };
+                                    (all.to_owned(), theme_nav.text_faint)
                                 } else {
                                     let most_recent = all_runs.iter().find(|r| r.name == name);
                                     most_recent.map_or_else(
@@ -2493,20 +4136,36 @@ pub fn ActionsView<'a>(
                                 } else {
                                     Color::Reset
                                 };
-                                let max_len = (NAV_W as usize).saturating_sub(4);
+                                let (failures, last) =
+                                    workflow_stats(all_runs, (i > 0).then_some(name.as_str()));
+                                let fail_text = if failures == 0 {
+                                    String::new()
+                                } else if theme_nav.chrome == crate::theme::Chrome::Plain {
+                                    format!(" {failures} {}", theme_nav.icons.action_failure)
+                                } else {
+                                    format!(" {failures}{}", theme_nav.icons.action_failure)
+                                };
+                                let age_text = last.map_or_else(String::new, |t| {
+                                    format!(" {}", crate::util::format_relative_time(&t))
+                                });
+                                let stats_w = fail_text.width() + age_text.width();
+                                let max_len = (NAV_W as usize).saturating_sub(4 + stats_w);
                                 let display = if name.chars().count() > max_len {
                                     let end =
                                         name.char_indices()
                                             .nth(max_len.saturating_sub(1))
                                             .map_or(name.len(), |(i, _)| i);
-                                    format!("{}\u{2026}", &name[..end])
+                                    format!("{}{}", &name[..end], ellipsis(theme_nav.chrome))
                                 } else {
                                     name.clone()
                                 };
                                 element! {
-                                    View(key: i, flex_direction: FlexDirection::Row, background_color: bg) {
+                                    View(key: i, flex_direction: FlexDirection::Row, background_color: bg, padding_right: 1u32) {
                                         Text(content: dot, color: dot_color.to_crossterm_color(depth), wrap: TextWrap::NoWrap)
                                         Text(content: format!(" {display}"), color: text_color.to_crossterm_color(depth), wrap: TextWrap::NoWrap)
+                                        View(flex_grow: 1.0_f32)
+                                        Text(content: fail_text, color: theme_nav.text_error.to_crossterm_color(depth), wrap: TextWrap::NoWrap)
+                                        Text(content: age_text, color: theme_nav.text_faint.to_crossterm_color(depth), wrap: TextWrap::NoWrap)
                                     }
                                 }.into_any()
                             }))
@@ -2516,7 +4175,7 @@ pub fn ActionsView<'a>(
 
                 // Main table
                 View(flex_grow: 1.0_f32, flex_direction: FlexDirection::Column) {
-                    ScrollableTable(table: rendered_table)
+                    ScrollableTable(table: rendered_table, on_click: clickable.then_some(row_click))
                 }
 
                 // Right sidebar
@@ -2524,13 +4183,240 @@ pub fn ActionsView<'a>(
             }
 
             TextInput(input: rendered_text_input)
-            Footer(footer: rendered_footer)
+            Footer(footer: rendered_footer, on_click: props.goto_view.filter(|_| clickable))
+            SelectionOverlay(overlay: rendered_cleanup, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_quick_run, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_schedules, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_run_workflow, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_job_picker, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_ref_picker, width: props.width, height: props.height)
             HelpOverlay(overlay: rendered_help, width: props.width, height: props.height)
         }
     }
     .into_any()
 }
 
+// ---------------------------------------------------------------------------
+// Comparison with the last successful run
+// ---------------------------------------------------------------------------
+
+/// Signed duration such as `+1m05s` or `-12s`.
+fn format_duration_delta(secs: i64) -> String {
+    let sign = if secs < 0 { '-' } else { '+' };
+    let abs = secs.unsigned_abs();
+    if abs < 60 {
+        format!("{sign}{abs}s")
+    } else {
+        format!("{sign}{}m{:02}s", abs / 60, abs % 60)
+    }
+}
+
+fn conclusion_icon(conclusion: Option<RunConclusion>, theme: &ResolvedTheme) -> StyledSpan {
+    if conclusion.is_none() {
+        let none = if theme.chrome.ascii_only() {
+            "-"
+        } else {
+            "\u{2013}" // –
+        };
+        return StyledSpan::text(none, theme.text_faint);
+    }
+    let (icon, color) = run_status_icon_color(RunStatus::Completed, conclusion, theme);
+    StyledSpan::text(icon, color)
+}
+
+/// The `before → after` arrow between two conclusion icons.
+fn transition_arrow(theme: &ResolvedTheme) -> StyledSpan {
+    let arrow = if theme.chrome.ascii_only() {
+        " -> "
+    } else {
+        " \u{2192} " // →
+    };
+    StyledSpan::text(arrow, theme.text_faint)
+}
+
+fn build_comparison_lines(state: &ComparisonState, theme: &ResolvedTheme) -> Vec<StyledLine> {
+    let cmp = match state {
+        ComparisonState::Loading(_) => {
+            return vec![StyledLine::from_span(StyledSpan::text(
+                format!(
+                    "Comparing with the last successful run{}",
+                    ellipsis(theme.chrome)
+                ),
+                theme.text_faint,
+            ))];
+        }
+        ComparisonState::NoBaseline(_) => {
+            return vec![StyledLine::from_span(StyledSpan::text(
+                "No earlier successful run of this workflow on this branch",
+                theme.text_faint,
+            ))];
+        }
+        ComparisonState::Ready(_, cmp) => cmp,
+    };
+    let base = &cmp.baseline;
+    let short_sha: String = base.head_sha.chars().take(7).collect();
+    let mut lines = vec![StyledLine::from_spans(vec![
+        StyledSpan::text("Last success: ", theme.text_secondary),
+        StyledSpan::text(format!("#{} ", base.run_number), theme.text_primary),
+        StyledSpan::text(
+            format!(
+                "{short_sha}, {} ago",
+                crate::util::format_date(&base.created_at, "relative")
+            ),
+            theme.text_faint,
+        ),
+    ])];
+    if let Some(delta) = cmp.duration_delta_secs {
+        let color = if delta > 0 {
+            theme.text_warning
+        } else {
+            theme.text_success
+        };
+        lines.push(StyledLine::from_spans(vec![
+            StyledSpan::text("Duration:     ", theme.text_secondary),
+            StyledSpan::text(format_duration_delta(delta), color),
+        ]));
+    }
+
+    lines.push(StyledLine::blank());
+    lines.push(StyledLine::from_span(StyledSpan::bold(
+        format!("Jobs changed ({})", cmp.job_changes.len()),
+        theme.text_primary,
+    )));
+    if cmp.job_changes.is_empty() {
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            "  No job changed status",
+            theme.text_faint,
+        )));
+    }
+    for change in &cmp.job_changes {
+        lines.push(StyledLine::from_spans(vec![
+            StyledSpan::text("  ", theme.text_faint),
+            conclusion_icon(change.before, theme),
+            transition_arrow(theme),
+            conclusion_icon(change.after, theme),
+            StyledSpan::text(format!("  {}", change.name), theme.text_primary),
+        ]));
+    }
+
+    lines.push(StyledLine::blank());
+    lines.push(StyledLine::from_span(StyledSpan::bold(
+        format!("Commits in between ({})", cmp.total_commits),
+        theme.text_primary,
+    )));
+    if cmp.total_commits == 0 {
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            "  Same commit as the last success \u{2014} possibly flaky",
+            theme.text_faint,
+        )));
+    }
+    for commit in &cmp.commits {
+        let sha: String = commit.sha.chars().take(7).collect();
+        let (sig_icon, sig_color) =
+            crate::components::sidebar_tabs::commit_signature_icon(&commit.signature, theme);
+        let mut spans = vec![
+            StyledSpan::text(format!("  {sha} "), theme.text_faint),
+            StyledSpan::text(format!("{sig_icon} "), sig_color),
+            StyledSpan::text(commit.title.clone(), theme.text_primary),
+        ];
+        if let Some(ref author) = commit.author {
+            spans.push(StyledSpan::text(format!("  @{author}"), theme.text_actor));
+        }
+        lines.push(StyledLine::from_spans(spans));
+    }
+    if cmp.total_commits > cmp.commits.len() {
+        lines.push(StyledLine::from_span(StyledSpan::text(
+            format!(
+                "  {} and {} older",
+                ellipsis(theme.chrome),
+                cmp.total_commits - cmp.commits.len()
+            ),
+            theme.text_faint,
+        )));
+    }
+    lines
+}
+
+// ---------------------------------------------------------------------------
+// Run cleanup preview
+// ---------------------------------------------------------------------------
+
+fn cleanup_prompt(plan: &CleanupPlan) -> String {
+    let total = plan.candidates.len();
+    let batch = total.min(CLEANUP_BATCH);
+    let repo = format!("{}/{}", plan.owner, plan.repo);
+    if batch < total {
+        format!("Delete the {batch} oldest of {total} workflow runs in {repo}? (y/n)")
+    } else {
+        format!("Delete {total} workflow runs in {repo}? (y/n)")
+    }
+}
+
+/// Dry-run listing of the runs the cleanup would delete, oldest first.
+fn build_cleanup_overlay(
+    plan: &CleanupPlan,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> RenderedSelectionOverlay {
+    let batch = &plan.candidates[..plan.candidates.len().min(CLEANUP_BATCH)];
+    let dot = dot_separator(theme.chrome);
+    let expired = batch
+        .iter()
+        .filter(|c| c.reason == CleanupReason::Expired)
+        .count();
+    let mut items: Vec<SelectionOverlayItem> = batch
+        .iter()
+        .take(CLEANUP_PREVIEW_ROWS)
+        .map(|c| {
+            let reason = match c.reason {
+                CleanupReason::Expired => "old",
+                CleanupReason::BranchDeleted => "branch deleted",
+            };
+            SelectionOverlayItem {
+                label: format!(
+                    "#{} {}{dot}{}{dot}{} ({reason})",
+                    c.run.run_number,
+                    c.run.name,
+                    c.run.head_branch.as_deref().unwrap_or("-"),
+                    crate::util::format_date(&c.run.created_at, "relative"),
+                ),
+            }
+        })
+        .collect();
+    if batch.len() > CLEANUP_PREVIEW_ROWS {
+        items.push(SelectionOverlayItem {
+            label: format!(
+                "{} and {} more",
+                ellipsis(theme.chrome),
+                batch.len() - CLEANUP_PREVIEW_ROWS
+            ),
+        });
+    }
+    RenderedSelectionOverlay::build(SelectionOverlayBuildConfig {
+        title: format!(
+            "Cleanup dry-run: {} old, {} on deleted branches",
+            expired,
+            batch.len() - expired
+        ),
+        items,
+        cursor: usize::MAX,
+        show_filter: false,
+        filter_text: String::new(),
+        hint: Some("y delete  n cancel".to_owned()),
+        depth,
+        title_color: Some(theme.text_primary),
+        item_color: Some(theme.text_secondary),
+        cursor_color: Some(theme.text_primary),
+        selected_bg: Some(theme.bg_selected),
+        border_color: Some(theme.text_warning),
+        hint_color: Some(theme.text_faint),
+        filter_prompt_color: None,
+        filter_text_color: None,
+        cursor_marker: theme.icons.select_cursor.clone(),
+        chrome: theme.chrome,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Key lookup helpers (used in keyboard handler)
 // ---------------------------------------------------------------------------
@@ -2559,7 +4445,7 @@ fn send_rerun(
     ctx: &RunContext<'_>,
     failed_only: bool,
     engine: Option<&EngineHandle>,
-    reply_tx: &std::sync::mpsc::Sender<Event>,
+    reply_tx: &EventSender,
 ) {
     let Some(run) = get_run_at_cursor(
         ctx.actions_state,
@@ -2578,15 +4464,12 @@ fn send_rerun(
         repo,
         run_id: run.id,
         failed_only,
+        host: ctx.filter.and_then(|f| f.host.clone()),
         reply_tx: reply_tx.clone(),
     });
 }
 
-fn send_cancel(
-    ctx: &RunContext<'_>,
-    engine: Option<&EngineHandle>,
-    reply_tx: &std::sync::mpsc::Sender<Event>,
-) {
+fn send_cancel(ctx: &RunContext<'_>, engine: Option<&EngineHandle>, reply_tx: &EventSender) {
     let Some(run) = get_run_at_cursor(
         ctx.actions_state,
         ctx.filter_idx,
@@ -2603,6 +4486,7 @@ fn send_cancel(
         owner,
         repo,
         run_id: run.id,
+        host: ctx.filter.and_then(|f| f.host.clone()),
         reply_tx: reply_tx.clone(),
     });
 }
@@ -2613,8 +4497,17 @@ fn default_theme() -> ResolvedTheme {
 
 #[cfg(test)]
 mod tests {
-    use super::{conclusion_severity, parse_matrix_name, rollup_group_status};
-    use crate::types::{RunConclusion, RunStatus, WorkflowJob};
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use super::{
+        ActionsState, CleanupDeletion, FilterData, conclusion_severity, default_theme,
+        format_duration_delta, parse_matrix_name, patch_run, remove_runs, rollup_group_status,
+        run_context_lines, run_to_row, workflow_stats,
+    };
+    use crate::engine::{Request, event_channel};
+    use crate::github::actions::CLEANUP_CHUNK;
+    use crate::types::{Actor, RunConclusion, RunStatus, WorkflowJob, WorkflowRun};
 
     fn job(status: RunStatus, conclusion: Option<RunConclusion>) -> WorkflowJob {
         WorkflowJob {
@@ -2629,6 +4522,121 @@ mod tests {
         }
     }
 
+    fn run(name: &str, conclusion: Option<RunConclusion>, created_at: &str) -> WorkflowRun {
+        WorkflowRun {
+            id: 0,
+            workflow_id: 0,
+            name: name.to_owned(),
+            display_title: String::new(),
+            status: RunStatus::Completed,
+            conclusion,
+            event: "push".to_owned(),
+            head_branch: None,
+            head_sha: String::new(),
+            head_repository: None,
+            repository: None,
+            actor: None,
+            triggering_actor: None,
+            head_commit_message: None,
+            pull_requests: Vec::new(),
+            run_number: 0,
+            html_url: String::new(),
+            created_at: created_at.parse().unwrap(),
+            updated_at: created_at.parse().unwrap(),
+            run_started_at: None,
+        }
+    }
+
+    #[test]
+    fn run_context_lines_show_commit_prs_and_rerun_actor() {
+        let actor = |login: &str| Actor {
+            login: login.to_owned(),
+            avatar_url: String::new(),
+        };
+        let mut r = run("CI", None, "2024-05-03T10:00:00Z");
+        assert!(run_context_lines(&r).is_empty());
+        r.head_sha = "0123456789abcdef".to_owned();
+        r.head_commit_message = Some("Fix the parser\n\nLong body".to_owned());
+        r.pull_requests = vec![42, 43];
+        r.actor = Some(actor("alice"));
+        r.triggering_actor = Some(actor("alice"));
+        assert_eq!(
+            run_context_lines(&r),
+            [
+                ("Commit: ".to_owned(), "0123456 Fix the parser".to_owned()),
+                ("PR:     ".to_owned(), "#42, #43".to_owned()),
+            ]
+        );
+        r.triggering_actor = Some(actor("bob"));
+        assert_eq!(
+            run_context_lines(&r).last(),
+            Some(&("Run by: ".to_owned(), "@bob".to_owned()))
+        );
+    }
+
+    #[test]
+    fn workflow_stats_count_failures_and_latest_run() {
+        let runs = [
+            run("CI", Some(RunConclusion::Success), "2024-05-03T10:00:00Z"),
+            run("CI", Some(RunConclusion::Failure), "2024-05-02T10:00:00Z"),
+            run("CI", Some(RunConclusion::TimedOut), "2024-05-01T10:00:00Z"),
+            run(
+                "Deploy",
+                Some(RunConclusion::Cancelled),
+                "2024-05-04T10:00:00Z",
+            ),
+        ];
+        let (failures, last) = workflow_stats(&runs, Some("CI"));
+        assert_eq!(failures, 2);
+        assert_eq!(last, Some("2024-05-03T10:00:00Z".parse().unwrap()));
+        let (failures, last) = workflow_stats(&runs, None);
+        assert_eq!(failures, 2);
+        assert_eq!(last, Some("2024-05-04T10:00:00Z".parse().unwrap()));
+        assert_eq!(workflow_stats(&runs, Some("Lint")), (0, None));
+    }
+
+    #[test]
+    fn patch_run_and_remove_runs_leave_other_rows_alone() {
+        let theme = default_theme();
+        let watched = HashSet::new();
+        let runs: Vec<WorkflowRun> = (1..=3)
+            .map(|id| WorkflowRun {
+                id,
+                ..run("CI", Some(RunConclusion::Failure), "2024-05-01T10:00:00Z")
+            })
+            .collect();
+        let tab = FilterData {
+            rows: runs
+                .iter()
+                .map(|r| run_to_row(r, &theme, &watched))
+                .collect(),
+            run_count: runs.len(),
+            runs,
+            loading: false,
+            ..FilterData::default()
+        };
+        let untouched = Arc::new(FilterData::default());
+        let mut state = ActionsState {
+            filters: vec![Arc::new(tab), Arc::clone(&untouched)],
+        };
+
+        let rerun = WorkflowRun {
+            id: 2,
+            status: RunStatus::Queued,
+            ..run("CI", None, "2024-05-01T10:00:00Z")
+        };
+        patch_run(&mut state, &rerun, &theme, &watched);
+        let tab = &state.filters[0];
+        assert_eq!(tab.runs[1].status, RunStatus::Queued);
+        assert_eq!(tab.runs[0].conclusion, Some(RunConclusion::Failure));
+        assert!(Arc::ptr_eq(&state.filters[1], &untouched));
+
+        remove_runs(&mut state, &HashSet::from([1, 3]));
+        let tab = &state.filters[0];
+        assert_eq!(tab.runs.iter().map(|r| r.id).collect::<Vec<_>>(), [2]);
+        assert_eq!((tab.rows.len(), tab.run_count), (1, 1));
+    }
+
     #[test]
     fn parse_matrix_name_auto_format() {
         assert_eq!(
@@ -2736,4 +4744,38 @@ mod tests {
             (RunStatus::Completed, Some(RunConclusion::Failure))
         );
     }
+
+    #[test]
+    fn duration_delta_is_signed() {
+        assert_eq!(format_duration_delta(65), "+1m05s");
+        assert_eq!(format_duration_delta(-12), "-12s");
+        assert_eq!(format_duration_delta(0), "+0s");
+    }
+
+    #[test]
+    fn cleanup_sends_next_chunk_only_after_previous_is_deleted() {
+        let (tx, _rx) = event_channel();
+        let ids: Vec<u64> = (0..25).collect();
+        let mut deletion = CleanupDeletion::new("o".into(), "r".into(), None, ids);
+
+        let Some(Request::DeleteWorkflowRuns {
+            run_ids, progress, ..
+        }) = deletion.next_request(&tx)
+        else {
+            panic!("expected a first chunk");
+        };
+        assert_eq!(run_ids.len(), CLEANUP_CHUNK);
+        assert_eq!(progress, (0, 25));
+
+        // A chunk cut short by a failure holds back the rest.
+        deletion.record_deleted(&run_ids[..3]);
+        assert!(deletion.next_request(&tx).is_none());
+
+        deletion.record_deleted(&run_ids[3..]);
+        let Some(Request::DeleteWorkflowRuns { progress, .. }) = deletion.next_request(&tx) else {
+            panic!("expected a second chunk");
+        };
+        assert_eq!(progress, (CLEANUP_CHUNK, 25));
+        assert!(!deletion.is_done());
+    }
 }
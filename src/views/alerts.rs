@@ -7,11 +7,12 @@
 // (tab bar, scrollable table, sidebar, footer, help overlay, text input).
 
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use iocraft::prelude::*;
 
 use crate::actions::clipboard;
-use crate::app::ViewKind;
+use crate::app::{ViewCounts, ViewKind, WatchBadges};
 use crate::color::{Color as AppColor, ColorDepth};
 use crate::components::footer::{
     self, ActionFeedback, Footer, FooterColors, FooterContent, RenderedFooter,
@@ -29,12 +30,12 @@ use crate::config::keybindings::{
     BuiltinAction, MergedBindings, ResolvedBinding, TemplateVars, ViewContext,
     execute_shell_command, expand_template, key_event_to_string,
 };
-use crate::config::types::AlertsFilter;
-use crate::engine::{EngineHandle, Event, FilterConfig, Request};
+use crate::config::types::{AlertsFilter, AppConfig};
+use crate::engine::{EngineHandle, Event, FilterConfig, RefreshView, Request};
 use crate::markdown::renderer::{StyledLine, StyledSpan};
 use crate::theme::ResolvedTheme;
 use crate::types::{
-    AlertCategory, AlertDetail, AlertSeverity, AlertState, CodeScanningInstance, RateLimitInfo,
+    AlertCategory, AlertDetail, AlertSeverity, AlertState, CodeScanningInstance, RateLimits,
     SecretLocation, SecurityAlert,
 };
 
@@ -50,6 +51,7 @@ fn alerts_columns() -> Vec<Column> {
             default_width_pct: 0.05,
             align: TextAlign::Right,
             fixed_width: Some(6),
+            sort: None,
         },
         Column {
             id: "severity".to_owned(),
@@ -57,6 +59,7 @@ fn alerts_columns() -> Vec<Column> {
             default_width_pct: 0.08,
             align: TextAlign::Left,
             fixed_width: Some(10),
+            sort: None,
         },
         Column {
             id: "category".to_owned(),
@@ -64,6 +67,7 @@ fn alerts_columns() -> Vec<Column> {
             default_width_pct: 0.10,
             align: TextAlign::Left,
             fixed_width: Some(14),
+            sort: None,
         },
         Column {
             id: "pkg_rule".to_owned(),
@@ -71,6 +75,7 @@ fn alerts_columns() -> Vec<Column> {
             default_width_pct: 0.30,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: None,
         },
         Column {
             id: "state".to_owned(),
@@ -78,6 +83,7 @@ fn alerts_columns() -> Vec<Column> {
             default_width_pct: 0.08,
             align: TextAlign::Left,
             fixed_width: Some(10),
+            sort: None,
         },
         Column {
             id: "repo".to_owned(),
@@ -85,6 +91,7 @@ fn alerts_columns() -> Vec<Column> {
             default_width_pct: 0.20,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: None,
         },
         Column {
             id: "age".to_owned(),
@@ -92,6 +99,7 @@ fn alerts_columns() -> Vec<Column> {
             default_width_pct: 0.08,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: None,
         },
     ]
 }
@@ -167,6 +175,8 @@ struct FilterData {
     alert_count: usize,
     loading: bool,
     error: Option<String>,
+    /// Rate-limit cost of the last uncached fetch, for the footer.
+    refresh_cost: Option<u32>,
 }
 
 impl Default for FilterData {
@@ -177,10 +187,36 @@ impl Default for FilterData {
             alert_count: 0,
             loading: true,
             error: None,
+            refresh_cost: None,
         }
     }
 }
 
+/// Mark tab `fi` as failed with `message`.
+fn fail_filter(
+    state: &mut State<Vec<Arc<FilterData>>>,
+    fetch_times: &mut State<Vec<Option<std::time::Instant>>>,
+    in_flight: &mut State<Vec<bool>>,
+    fi: usize,
+    message: String,
+) {
+    let mut snapshot = state.read().clone();
+    if let Some(slot) = snapshot.get_mut(fi) {
+        *slot = Arc::new(FilterData {
+            loading: false,
+            error: Some(message),
+            ..FilterData::default()
+        });
+    }
+    state.set(snapshot);
+    let mut times = fetch_times.read().clone();
+    if fi < times.len() {
+        times[fi] = Some(std::time::Instant::now());
+    }
+    fetch_times.set(times);
+    super::common::set_in_flight(in_flight, fi, false);
+}
+
 // ---------------------------------------------------------------------------
 // Input mode
 // ---------------------------------------------------------------------------
@@ -373,6 +409,8 @@ fn build_alert_sidebar_meta(
         lines_added: None,
         lines_deleted: None,
         reactions_text: None,
+        deployments: Vec::new(),
+        context_lines: Vec::new(),
         date_fg: theme.text_faint.to_crossterm_color(depth),
         date_age_fg: theme.text_secondary.to_crossterm_color(depth),
         additions_fg: theme.text_success.to_crossterm_color(depth),
@@ -621,10 +659,11 @@ fn resolve_filter_repo<'a>(
 
 #[derive(Default, Props)]
 pub struct AlertsViewProps<'a> {
-    pub filters: Option<&'a [AlertsFilter]>,
+    /// Config, replaced on each reload.
+    pub config: Option<Arc<AppConfig>>,
     pub engine: Option<&'a EngineHandle>,
-    pub theme: Option<&'a ResolvedTheme>,
-    pub keybindings: Option<&'a MergedBindings>,
+    pub theme: Option<Arc<ResolvedTheme>>,
+    pub keybindings: Option<Arc<MergedBindings>>,
     pub color_depth: ColorDepth,
     pub width: u16,
     pub height: u16,
@@ -640,18 +679,36 @@ pub struct AlertsViewProps<'a> {
     /// Signal to jump directly to a specific view.
     pub goto_view: Option<State<Option<ViewKind>>>,
     pub scope_toggle: Option<State<bool>>,
+    /// Signal to toggle relative/absolute dates.
+    pub date_toggle: Option<State<bool>>,
+    /// Signal to open the log viewer.
+    pub show_logs: Option<State<bool>>,
+    /// Set when the token cannot read security alerts; the view shows it instead of fetching.
+    pub scope_hint: Option<String>,
     pub repo_picker: Option<State<bool>>,
+    /// Signal to scope to the repo worked in.
+    pub rescan_repo: Option<State<bool>>,
     pub is_active: bool,
     pub refetch_interval_minutes: u32,
-    pub date_format: Option<&'a str>,
     /// Shared rate-limit state (owned by App).
-    pub rate_limit: Option<State<Option<RateLimitInfo>>>,
+    pub rate_limit: Option<State<RateLimits>>,
+    /// Shared watched-item badges (owned by App).
+    pub watch_badges: Option<State<WatchBadges>>,
+    /// Shared footer counts of the views (owned by App).
+    pub view_counts: Option<State<ViewCounts>>,
 }
 
 #[component]
 pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
-    let filters_cfg = props.filters.unwrap_or(&[]);
-    let theme = props.theme.cloned().unwrap_or_else(default_theme);
+    let filters_cfg = props
+        .config
+        .as_deref()
+        .map_or(&[][..], |c| &c.alerts_filters);
+    let theme = props
+        .theme
+        .as_deref()
+        .cloned()
+        .unwrap_or_else(default_theme);
     let depth = props.color_depth;
     let should_exit = props.should_exit;
     let switch_view = props.switch_view;
@@ -666,7 +723,11 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     let scope_repo = props.scope_repo.clone();
     let detected_repo = props.detected_repo.clone();
     let scope_toggle = props.scope_toggle;
+    let date_toggle = props.date_toggle;
+    let show_logs = props.show_logs;
+    let scope_hint = props.scope_hint.clone();
     let repo_picker = props.repo_picker;
+    let rescan_repo = props.rescan_repo;
 
     // -----------------------------------------------------------------------
     // State hooks
@@ -675,6 +736,8 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     let mut active_filter = hooks.use_state(|| 0usize);
     let mut cursor = hooks.use_state(|| 0usize);
     let mut scroll_offset = hooks.use_state(|| 0usize);
+    // Identity of the item under the cursor, followed across refreshes.
+    let mut cursor_anchor = hooks.use_state(super::common::CursorAnchor::default);
     let mut input_mode = hooks.use_state(|| InputMode::Normal);
     let mut search_query = hooks.use_state(String::new);
     let mut help_visible = hooks.use_state(|| false);
@@ -690,12 +753,15 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     let mut sidebar_tab = hooks.use_state(|| AlertSidebarTab::Overview);
 
     // Alerts data: one FilterData per filter tab
-    let initial_filters = vec![FilterData::default(); filter_count];
+    let initial_filters: Vec<Arc<FilterData>> = super::common::empty_filters(filter_count);
     let mut alerts_state = hooks.use_state(move || initial_filters);
 
     let mut filter_in_flight = hooks.use_state(move || vec![false; filter_count]);
     let mut filter_fetch_times =
         hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
+    // Per-filter auto-refresh postponement (`postpone_refresh`), for the countdown.
+    let mut refresh_postponed =
+        hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
 
     // Secret locations cache for sidebar Locations tab
     let mut locations_cache = hooks.use_state(HashMap::<u64, Vec<SecretLocation>>::new);
@@ -706,7 +772,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     let mut status_set_at = hooks.use_state(|| Option::<std::time::Instant>::None);
 
     // Rate limit
-    let fallback_rl = hooks.use_state(|| None);
+    let fallback_rl = hooks.use_state(RateLimits::default);
     let mut rate_limit_state = props.rate_limit.unwrap_or(fallback_rl);
 
     let mut refresh_registered = hooks.use_state(|| false);
@@ -714,20 +780,47 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
 
     // Event channel
     let event_channel = hooks.use_state(super::common::new_event_channel);
-    let (event_tx, event_rx_arc) = event_channel.read().clone();
+    let (event_tx, event_rx) = event_channel.read().clone();
     let engine: Option<EngineHandle> = props.engine.cloned();
+    let mut was_active = hooks.use_state(|| false);
+    super::common::cancel_fetches_on_leave(&mut was_active, is_active, engine.as_ref(), &event_tx);
+
+    // Track config reloads: keep to the tab shown and refetch every filter.
+    let mut last_filters = hooks.use_state(|| filters_cfg.to_vec());
+    let filters_changed = super::common::follow_reload(
+        &mut last_filters,
+        filters_cfg,
+        &mut active_filter,
+        &mut refresh_postponed,
+    );
 
     // Track scope changes: when scope_repo changes, invalidate all filters.
     let mut last_scope = hooks.use_state(|| scope_repo.clone());
-    if *last_scope.read() != scope_repo {
+    if filters_changed || *last_scope.read() != scope_repo {
         last_scope.set(scope_repo.clone());
-        alerts_state.set(vec![FilterData::default(); filter_count]);
+        alerts_state.set(super::common::empty_filters(filter_count));
         filter_fetch_times.set(vec![None; filter_count]);
         filter_in_flight.set(vec![false; filter_count]);
         refresh_registered.set(false);
     }
 
+    // Rebuild the cached rows' date cells after the relative/absolute toggle.
+    let mut date_generation = hooks.use_state(crate::util::date_generation);
+    if date_generation.get() != crate::util::date_generation() {
+        date_generation.set(crate::util::date_generation());
+        let mut filters = alerts_state.read().clone();
+        for fd in &mut filters {
+            let fd = Arc::make_mut(fd);
+            fd.rows = fd.alerts.iter().map(|a| alert_to_row(a, &theme)).collect();
+        }
+        alerts_state.set(filters);
+    }
+
     let current_filter_idx = active_filter.get().min(filter_count.saturating_sub(1));
+    // Ephemeral tabs are not registered for auto-refresh.
+    let refresh_interval = filters_cfg.get(current_filter_idx).map(|f| {
+        super::common::refresh_interval(f.refetch_interval_minutes, props.refetch_interval_minutes)
+    });
 
     let active_needs_fetch = alerts_state
         .read()
@@ -771,6 +864,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     // Refresh-all: re-fetch every filter tab.
     if refresh_all.get()
         && is_active
+        && scope_hint.is_none()
         && let Some(ref eng) = engine
     {
         refresh_all.set(false);
@@ -799,6 +893,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     } else if active_needs_fetch
         && !active_in_flight
         && is_active
+        && scope_hint.is_none()
         && let Some(ref eng) = engine
         && let Some(cfg) = filters_cfg.get(current_filter_idx)
     {
@@ -826,35 +921,23 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
             );
             let mut data = alerts_state.read().clone();
             if current_filter_idx < data.len() {
-                data[current_filter_idx].loading = false;
+                Arc::make_mut(&mut data[current_filter_idx]).loading = false;
             }
             alerts_state.set(data);
         }
     }
 
-    // Poll engine events.
+    hooks.use_future(super::common::expire_action_status(
+        action_status,
+        status_set_at,
+    ));
+
+    // Handle engine events as they arrive.
     {
-        let rx_for_poll = event_rx_arc.clone();
+        let rx_for_poll = event_rx.clone();
         let theme_for_poll = theme.clone();
         hooks.use_future(async move {
-            loop {
-                smol::Timer::after(std::time::Duration::from_millis(100)).await;
-                // Auto-clear status after 60 seconds.
-                if let Some(t) = status_set_at.get()
-                    && t.elapsed().as_secs() >= 60
-                {
-                    action_status.set(None);
-                    status_set_at.set(None);
-                }
-
-                let events: Vec<Event> = {
-                    let rx = rx_for_poll.lock().unwrap();
-                    let mut evts = Vec::new();
-                    while let Ok(evt) = rx.try_recv() {
-                        evts.push(evt);
-                    }
-                    evts
-                };
+            while let Some(events) = super::common::recv_batch(&rx_for_poll).await {
                 for evt in events {
                     match evt {
                         Event::AlertsFetched {
@@ -862,6 +945,13 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                             alerts,
                             rate_limit,
                         } => {
+                            let refresh_cost =
+                                rate_limit.as_ref().map(|rl| rl.cost).or_else(|| {
+                                    alerts_state
+                                        .read()
+                                        .get(filter_idx)
+                                        .and_then(|f| f.refresh_cost)
+                                });
                             let rows: Vec<Row> = alerts
                                 .iter()
                                 .map(|a| alert_to_row(a, &theme_for_poll))
@@ -873,10 +963,11 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                                 alert_count,
                                 loading: false,
                                 error: None,
+                                refresh_cost,
                             };
                             let mut data = alerts_state.read().clone();
                             if filter_idx < data.len() {
-                                data[filter_idx] = filter_data;
+                                data[filter_idx] = Arc::new(filter_data);
                             }
                             alerts_state.set(data);
                             let mut times = filter_fetch_times.read().clone();
@@ -900,12 +991,26 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                             inflight.remove(&alert_number);
                             locations_in_flight.set(inflight);
                         }
+                        Event::RefreshBudgetExceeded { message } => {
+                            action_status.set(Some(ActionFeedback::Warning(message)));
+                            status_set_at.set(Some(std::time::Instant::now()));
+                        }
                         Event::FetchError { message, .. } => {
                             action_status.set(Some(ActionFeedback::Error(format!(
                                 "Fetch error: {message}"
                             ))));
                             status_set_at.set(Some(std::time::Instant::now()));
                         }
+                        Event::FetchTimedOut { filter_idx, secs } => fail_filter(
+                            &mut alerts_state,
+                            &mut filter_fetch_times,
+                            &mut filter_in_flight,
+                            filter_idx,
+                            super::common::timed_out_message(secs),
+                        ),
+                        Event::FetchCancelled { filter_idx } => {
+                            super::common::set_in_flight(&mut filter_in_flight, filter_idx, false);
+                        }
                         _ => {}
                     }
                 }
@@ -928,8 +1033,10 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     // Keyboard handling (before render data to avoid borrow conflicts)
     // -----------------------------------------------------------------------
 
-    let keybindings = props.keybindings.cloned();
+    let keybindings = props.keybindings.as_deref().cloned();
+    let chrome = theme.chrome;
     hooks.use_terminal_events({
+        let engine_for_keys = engine.clone();
         move |event| match event {
             TerminalEvent::Key(KeyEvent {
                 code,
@@ -1034,6 +1141,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                             match keybindings
                                 .as_ref()
                                 .and_then(|kb| kb.resolve(&key_str, ViewContext::Alerts))
+                                .map(|binding| super::common::refresh_fallback(binding, total_rows))
                             {
                                 Some(ResolvedBinding::Builtin(action)) => match action {
                                     BuiltinAction::Quit => {
@@ -1061,11 +1169,46 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                                             st.set(true);
                                         }
                                     }
+                                    BuiltinAction::ShowLogs => {
+                                        if let Some(mut sl) = show_logs {
+                                            sl.set(true);
+                                        }
+                                    }
+                                    BuiltinAction::ToggleDates => {
+                                        if let Some(mut dt) = date_toggle {
+                                            dt.set(true);
+                                        }
+                                    }
+                                    BuiltinAction::AuthorizeSso => {
+                                        action_status.set(Some(super::common::authorize_sso()));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::PostponeRefresh => {
+                                        let fetched = filter_fetch_times
+                                            .read()
+                                            .get(current_filter_idx)
+                                            .copied()
+                                            .flatten();
+                                        action_status.set(Some(super::common::postpone_refresh(
+                                            engine_for_keys.as_ref(),
+                                            RefreshView::Alerts,
+                                            current_filter_idx,
+                                            fetched,
+                                            refresh_interval,
+                                            &mut refresh_postponed,
+                                        )));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::SelectRepo => {
                                         if let Some(mut rp) = repo_picker {
                                             rp.set(true);
                                         }
                                     }
+                                    BuiltinAction::RescanRepo => {
+                                        if let Some(mut rr) = rescan_repo {
+                                            rr.set(true);
+                                        }
+                                    }
                                     BuiltinAction::ToggleHelp => {
                                         help_visible.set(true);
                                     }
@@ -1073,7 +1216,19 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                                         if let Some(ref alert) = current_alert
                                             && !alert.html_url.is_empty()
                                         {
-                                            let _ = clipboard::open_in_browser(&alert.html_url);
+                                            let feedback =
+                                                match clipboard::open_in_browser(&alert.html_url) {
+                                                    Ok(how) => {
+                                                        ActionFeedback::Success(how.message(
+                                                            &format!("alert #{}", alert.number),
+                                                        ))
+                                                    }
+                                                    Err(e) => ActionFeedback::Error(format!(
+                                                        "Open failed: {e}"
+                                                    )),
+                                                };
+                                            action_status.set(Some(feedback));
+                                            status_set_at.set(Some(std::time::Instant::now()));
                                         }
                                     }
                                     BuiltinAction::CopyNumber => {
@@ -1111,7 +1266,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                                         let idx = current_filter_idx;
                                         let mut data = alerts_state.read().clone();
                                         if idx < data.len() {
-                                            data[idx] = FilterData::default();
+                                            data[idx] = Arc::new(FilterData::default());
                                         }
                                         alerts_state.set(data);
                                         let mut times = filter_fetch_times.read().clone();
@@ -1119,15 +1274,16 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                                             times[idx] = None;
                                         }
                                         filter_fetch_times.set(times);
-                                        rate_limit_state.set(None);
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                     }
                                     BuiltinAction::RefreshAll => {
-                                        alerts_state.set(vec![FilterData::default(); filter_count]);
+                                        alerts_state
+                                            .set(super::common::empty_filters(filter_count));
                                         filter_fetch_times.set(vec![None; filter_count]);
                                         filter_in_flight.set(vec![false; filter_count]);
-                                        rate_limit_state.set(None);
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                         refresh_all.set(true);
@@ -1251,6 +1407,17 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                                     let expanded = expand_template(&cmd, &vars);
                                     let _ = execute_shell_command(&expanded);
                                 }
+                                Some(ResolvedBinding::Script(path)) => {
+                                    if let Some(feedback) = crate::script::run(
+                                        &path,
+                                        "alerts",
+                                        &vars,
+                                        engine_for_keys.clone(),
+                                        chrome,
+                                    ) {
+                                        action_status.set(Some(feedback));
+                                    }
+                                }
                                 None => {
                                     // Esc: close nav -> close sidebar
                                     if key_str == "esc" {
@@ -1359,6 +1526,25 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
 
     let total_rows = filtered_rows.len();
 
+    // Keep the cursor on the same alert when a refresh replaces the rows.
+    let fetched_at = filter_fetch_times
+        .read()
+        .get(current_filter_idx)
+        .copied()
+        .flatten();
+    super::common::anchor_cursor(
+        &mut cursor_anchor,
+        &mut cursor,
+        &mut scroll_offset,
+        current_filter_idx,
+        fetched_at,
+        &filtered_indices
+            .iter()
+            .filter_map(|&i| all_alerts.get(i))
+            .map(|alert| alert.html_url.clone())
+            .collect::<Vec<_>>(),
+    );
+
     // Skip heavy rendering for inactive views.
     if !is_active {
         return element! {
@@ -1391,11 +1577,16 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
         .map(|(i, f)| Tab {
             title: f.title.clone(),
             count: state_ref.get(i).map(|d| d.alert_count),
+            hidden: 0,
             is_ephemeral: false,
         })
         .collect();
 
     let columns = alerts_columns();
+    // A failed fetch shows its error in the footer, and how to retry here.
+    let failed_message = current_data
+        .is_some_and(|d| d.error.is_some())
+        .then(|| super::common::failed_tab_message(props.keybindings.as_deref()));
     let rendered_table = RenderedTable::build(&TableBuildConfig {
         columns: &columns,
         rows: &filtered_rows,
@@ -1410,7 +1601,9 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
         header_color: Some(theme.text_secondary),
         border_color: Some(theme.border_faint),
         show_separator: props.show_separator,
-        empty_message: if search_q.is_empty() {
+        empty_message: if let Some(msg) = &failed_message {
+            Some(msg.as_str())
+        } else if search_q.is_empty() {
             Some("No security alerts found")
         } else {
             Some("No alerts match this filter")
@@ -1418,13 +1611,30 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
         subtitle_column: None,
         row_separator: true,
         scrollbar_thumb_color: Some(theme.border_primary),
+        chrome: theme.chrome,
+        sort: None,
     });
 
     let tab_colors = TabBarColors {
         active: Some(theme.footer_alerts),
         inactive: Some(theme.footer_alerts),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
+    let countdown = super::common::refresh_countdown(
+        filter_fetch_times
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        refresh_interval,
+        refresh_postponed
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        &theme.icons.tab_refresh,
+    );
     let rendered_tab_bar = RenderedTabBar::build(
         &tabs,
         current_filter_idx,
@@ -1433,7 +1643,8 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
         &tab_colors,
         &theme.icons.tab_filter,
         "", // no ephemeral icon for alerts
-    );
+    )
+    .with_countdown(countdown);
 
     let current_mode = input_mode.read().clone();
     let rendered_text_input = match current_mode {
@@ -1445,6 +1656,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                 text: Some(theme.text_primary),
                 prompt: Some(theme.text_secondary),
                 border: Some(theme.border_faint),
+                chrome: theme.chrome,
                 ..Default::default()
             },
         )),
@@ -1452,8 +1664,13 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     };
 
     // Context text.
-    let context_text = if current_data.is_some_and(|d| d.loading) {
-        "Fetching security alerts\u{2026}".to_owned()
+    let context_text = if let Some(hint) = &scope_hint {
+        hint.clone()
+    } else if current_data.is_some_and(|d| d.loading) {
+        format!(
+            "Fetching security alerts{}",
+            crate::components::ellipsis(theme.chrome)
+        )
     } else if let Some(err) = current_data.and_then(|d| d.error.as_ref()) {
         format!("Error: {err}")
     } else {
@@ -1468,7 +1685,12 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
         .copied()
         .flatten();
     let updated_text = footer::format_updated_ago(active_fetch_time);
-    let rate_limit_text = footer::format_rate_limit(rate_limit_state.read().as_ref());
+    let rate_limit_text = footer::format_rate_limit(
+        rate_limit_state.read().of(filters_cfg
+            .get(current_filter_idx)
+            .and_then(|f| f.host.as_deref())),
+        current_data.and_then(|d| d.refresh_cost),
+    );
     let scope_label = match &scope_repo {
         Some(_) => filters_cfg
             .get(current_filter_idx)
@@ -1488,10 +1710,12 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
             Some(theme.footer_alerts),
             Some(theme.footer_notifications),
             Some(theme.footer_repo),
+            Some(theme.footer_events),
         ],
         inactive: Some(theme.text_faint),
         text: Some(theme.text_faint),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
     let rendered_footer = RenderedFooter::build(
         ViewKind::Alerts,
@@ -1501,6 +1725,8 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
             context_text,
             updated_text,
             rate_limit_text,
+            watch_badges: props.watch_badges.map(|b| b.get()).unwrap_or_default(),
+            view_counts: props.view_counts.map(|c| c.get()).unwrap_or_default(),
         },
         action_status.read().as_ref(),
         &theme,
@@ -1509,7 +1735,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
     );
 
     let rendered_help = if help_visible.get() {
-        props.keybindings.map(|kb| {
+        props.keybindings.as_deref().map(|kb| {
             RenderedHelpOverlay::build(&HelpOverlayBuildConfig {
                 bindings: kb,
                 context: ViewContext::Alerts,
@@ -1518,6 +1744,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                 key_color: Some(theme.text_success),
                 desc_color: Some(theme.text_secondary),
                 border_color: Some(theme.border_primary),
+                chrome: theme.chrome,
             })
         })
     } else {
@@ -1544,6 +1771,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
             owner: owner.to_owned(),
             repo: repo.to_owned(),
             alert_number: selected_alert.number,
+            host: crate::url::host_of(&selected_alert.html_url),
             reply_tx: event_tx.clone(),
         });
         let mut inflight = locations_in_flight.read().clone();
@@ -1588,6 +1816,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
             indicator: Some(theme.text_faint),
             thumb: Some(theme.border_primary),
             depth,
+            chrome: theme.chrome,
         };
         let sidebar = RenderedSidebar::build_tabbed(
             &sidebar_title,
@@ -1637,13 +1866,13 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                         View(
                             width: u32::from(NAV_W),
                             flex_direction: FlexDirection::Column,
-                            border_style: BorderStyle::Single,
+                            border_style: crate::components::border(theme.chrome, BorderStyle::Single),
                             border_edges: Edges::Right,
                             border_color: nav_border_color,
                             padding_left: 1u32,
                         ) {
                             View(
-                                border_style: BorderStyle::Single,
+                                border_style: crate::components::border(theme_nav.chrome, BorderStyle::Single),
                                 border_edges: Edges::Bottom,
                                 border_color: theme_nav.border_faint.to_crossterm_color(depth),
                             ) {
@@ -1673,7 +1902,11 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
                                         .char_indices()
                                         .nth(max_len.saturating_sub(1))
                                         .map_or(label.len(), |(idx, _)| idx);
-                                    format!("{}\u{2026}", &label[..end])
+                                    format!(
+                                        "{}{}",
+                                        &label[..end],
+                                        crate::components::ellipsis(theme_nav.chrome)
+                                    )
                                 } else {
                                     label
                                 };
@@ -1709,7 +1942,7 @@ pub fn AlertsView<'a>(props: &AlertsViewProps<'a>, mut hooks: Hooks) -> impl Int
 // ---------------------------------------------------------------------------
 
 fn get_alert_at_cursor(
-    alerts_state: &State<Vec<FilterData>>,
+    alerts_state: &State<Vec<Arc<FilterData>>>,
     filter_idx: usize,
     cursor: usize,
 ) -> Option<SecurityAlert> {
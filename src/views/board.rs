@@ -0,0 +1,209 @@
+//! Kanban layout of an issues tab (a filter's `board` setting).
+//!
+//! The board is a different rendering of the tab's items: the view's cursor
+//! still indexes the selected item, so every other action applies to the
+//! selected card unchanged. Moving a card to another column is the label,
+//! milestone or assignee mutation that puts the item there.
+
+use crate::config::keybindings::BuiltinAction;
+use crate::config::types::{BoardConfig, BoardGrouping};
+use crate::types::Issue;
+
+/// A board column and the items (indices into the tab's items) in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BoardColumn {
+    pub title: String,
+    /// The configured value, `None` for the leading catch-all column.
+    pub value: Option<String>,
+    pub items: Vec<usize>,
+}
+
+/// The change that moves an item to another column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum BoardMove {
+    Labels(Vec<String>),
+    Assignees(Vec<String>),
+    Milestone(Option<String>),
+}
+
+/// Group `items` into the board's columns.
+pub(crate) fn columns(items: &[Issue], board: &BoardConfig) -> Vec<BoardColumn> {
+    let other = match board.by {
+        BoardGrouping::Label => "Other",
+        BoardGrouping::Milestone => "No milestone",
+        BoardGrouping::Assignee => "Unassigned",
+    };
+    let mut columns: Vec<BoardColumn> = std::iter::once(BoardColumn {
+        title: other.to_owned(),
+        value: None,
+        items: Vec::new(),
+    })
+    .chain(board.columns.iter().map(|value| BoardColumn {
+        title: value.clone(),
+        value: Some(value.clone()),
+        items: Vec::new(),
+    }))
+    .collect();
+    for (idx, item) in items.iter().enumerate() {
+        let col = board
+            .columns
+            .iter()
+            .position(|value| has_value(item, board.by, value))
+            .map_or(0, |c| c + 1);
+        columns[col].items.push(idx);
+    }
+    columns
+}
+
+fn has_value(item: &Issue, by: BoardGrouping, value: &str) -> bool {
+    match by {
+        BoardGrouping::Label => item
+            .labels
+            .iter()
+            .any(|l| l.name.eq_ignore_ascii_case(value)),
+        BoardGrouping::Milestone => item.milestone.as_deref() == Some(value),
+        BoardGrouping::Assignee => item
+            .assignees
+            .iter()
+            .any(|a| a.login.eq_ignore_ascii_case(value)),
+    }
+}
+
+/// `(column, row)` of `item` on the board.
+pub(crate) fn position(columns: &[BoardColumn], item: usize) -> Option<(usize, usize)> {
+    columns
+        .iter()
+        .enumerate()
+        .find_map(|(c, col)| col.items.iter().position(|&i| i == item).map(|r| (c, r)))
+}
+
+/// The item selected after a navigation `action` from `item`, or `None`
+/// when the action does not move on a board.
+///
+/// Column moves skip empty columns and keep the row where possible.
+pub(crate) fn step(columns: &[BoardColumn], item: usize, action: BuiltinAction) -> Option<usize> {
+    let Some((col, row)) = position(columns, item) else {
+        // Nothing selected yet: start at the first card.
+        return columns.iter().find_map(|c| c.items.first().copied());
+    };
+    let items = &columns[col].items;
+    let pick = |c: &BoardColumn| c.items.get(row.min(c.items.len() - 1)).copied();
+    match action {
+        BuiltinAction::MoveDown => items.get(row + 1).or(items.last()).copied(),
+        BuiltinAction::MoveUp => items.get(row.saturating_sub(1)).copied(),
+        BuiltinAction::First => items.first().copied(),
+        BuiltinAction::Last => items.last().copied(),
+        BuiltinAction::BoardPrevColumn => columns[..col]
+            .iter()
+            .rev()
+            .find(|c| !c.items.is_empty())
+            .and_then(pick)
+            .or(Some(item)),
+        BuiltinAction::BoardNextColumn => columns[col + 1..]
+            .iter()
+            .find(|c| !c.items.is_empty())
+            .and_then(pick)
+            .or(Some(item)),
+        _ => None,
+    }
+}
+
+/// The change moving `item` into column `to` of the board.
+pub(crate) fn move_to(item: &Issue, board: &BoardConfig, to: &BoardColumn) -> BoardMove {
+    let target = to.value.clone();
+    match board.by {
+        BoardGrouping::Label => {
+            // An item sits in one column: drop every board label first.
+            let mut labels: Vec<String> = item
+                .labels
+                .iter()
+                .map(|l| l.name.clone())
+                .filter(|name| !board.columns.iter().any(|c| c.eq_ignore_ascii_case(name)))
+                .collect();
+            labels.extend(target);
+            BoardMove::Labels(labels)
+        }
+        BoardGrouping::Milestone => BoardMove::Milestone(target),
+        BoardGrouping::Assignee => {
+            let mut logins: Vec<String> = item
+                .assignees
+                .iter()
+                .map(|a| a.login.clone())
+                .filter(|login| !board.columns.iter().any(|c| c.eq_ignore_ascii_case(login)))
+                .collect();
+            logins.extend(target);
+            BoardMove::Assignees(logins)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(labels: &[&str]) -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "number": 1,
+            "title": "t",
+            "state": "OPEN",
+            "updated_at": "2024-05-02T10:00:00Z",
+            "created_at": "2024-04-20T10:00:00Z",
+            "url": "https://github.com/o/r/issues/1",
+            "repo": null,
+            "labels": labels.iter().map(|n| serde_json::json!({"name": n, "color": "fff"})).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    fn label_board() -> BoardConfig {
+        BoardConfig {
+            by: BoardGrouping::Label,
+            columns: vec!["todo".to_owned(), "doing".to_owned(), "done".to_owned()],
+        }
+    }
+
+    #[test]
+    fn columns_group_by_first_matching_label() {
+        let items = [issue(&["bug"]), issue(&["Doing"]), issue(&["todo", "done"])];
+        let cols = columns(&items, &label_board());
+        let grouped: Vec<(&str, Vec<usize>)> = cols
+            .iter()
+            .map(|c| (c.title.as_str(), c.items.clone()))
+            .collect();
+        assert_eq!(
+            grouped,
+            vec![
+                ("Other", vec![0]),
+                ("todo", vec![2]),
+                ("doing", vec![1]),
+                ("done", vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn step_skips_empty_columns() {
+        let items = [issue(&["todo"]), issue(&["todo"]), issue(&["done"])];
+        let cols = columns(&items, &label_board());
+        assert_eq!(step(&cols, 1, BuiltinAction::BoardNextColumn), Some(2));
+        assert_eq!(step(&cols, 2, BuiltinAction::BoardPrevColumn), Some(0));
+        assert_eq!(step(&cols, 0, BuiltinAction::MoveDown), Some(1));
+        assert_eq!(step(&cols, 2, BuiltinAction::BoardNextColumn), Some(2));
+        assert_eq!(step(&cols, 0, BuiltinAction::Search), None);
+    }
+
+    #[test]
+    fn move_to_swaps_the_board_label() {
+        let board = label_board();
+        let item = issue(&["bug", "todo"]);
+        let cols = columns(std::slice::from_ref(&item), &board);
+        assert_eq!(
+            move_to(&item, &board, &cols[2]),
+            BoardMove::Labels(vec!["bug".to_owned(), "doing".to_owned()])
+        );
+        assert_eq!(
+            move_to(&item, &board, &cols[0]),
+            BoardMove::Labels(vec!["bug".to_owned()])
+        );
+    }
+}
@@ -1,19 +1,29 @@
-use std::sync::mpsc::Sender;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
 use iocraft::prelude::*;
 
-use crate::app::ViewKind;
+use crate::actions::clipboard;
+use crate::app::{ViewCounts, ViewKind};
+use crate::color::Color as AppColor;
+use crate::components::footer::ActionFeedback;
+use crate::components::table::{Cell, Row, Span, TINT_CELL, TableClick};
 use crate::components::text_input;
-use crate::config::keybindings::BuiltinAction;
-use crate::engine::Event;
-use crate::types::RateLimitInfo;
+use crate::config::keybindings::{BuiltinAction, MergedBindings, ResolvedBinding};
+use crate::config::types::{AppConfig, LabelTint};
+use crate::drafts::{self, DraftTarget};
+use crate::engine::{EngineHandle, Event, EventSender, RefreshView, Request};
+use crate::github::auth_warnings;
+use crate::theme::ResolvedTheme;
+use crate::types::{Actor, Label, RateLimitInfo, RateLimits, SavedReply};
+use crate::util::Staleness;
 
 /// Type alias for the event channel pair used by every view.
 ///
-/// The `Sender` is cloned into each `Request` so the engine can reply.
-/// The `Arc<Mutex<Receiver>>` is polled every 100ms in a `use_future` hook.
-pub type EventChannel = (Sender<Event>, Arc<Mutex<std::sync::mpsc::Receiver<Event>>>);
+/// The `EventSender` is cloned into each `Request` so the engine can reply.
+/// The `Arc<Receiver>` is awaited in a `use_future` hook, so an idle view
+/// does no work until the engine pushes an event.
+pub type EventChannel = (EventSender, Arc<async_channel::Receiver<Event>>);
 
 /// Create a per-view event channel and unpack it into its two halves.
 ///
@@ -21,11 +31,91 @@ pub type EventChannel = (Sender<Event>, Arc<Mutex<std::sync::mpsc::Receiver<Even
 ///
 /// ```text
 /// let event_channel = hooks.use_state(common::new_event_channel);
-/// let (event_tx, event_rx_arc) = event_channel.read().clone();
+/// let (event_tx, event_rx) = event_channel.read().clone();
 /// ```
 pub fn new_event_channel() -> EventChannel {
-    let (tx, rx) = std::sync::mpsc::channel::<Event>();
-    (tx, Arc::new(Mutex::new(rx)))
+    let (tx, rx) = crate::engine::event_channel();
+    (tx, Arc::new(rx))
+}
+
+/// Wait for the next event, then drain everything else already queued.
+///
+/// Handling a burst in one pass coalesces the resulting state updates into a
+/// single re-render. Returns `None` once every sender has been dropped.
+pub(crate) async fn recv_batch<T>(rx: &async_channel::Receiver<T>) -> Option<Vec<T>> {
+    let first = rx.recv().await.ok()?;
+    let mut batch = vec![first];
+    while let Ok(item) = rx.try_recv() {
+        batch.push(item);
+    }
+    Some(batch)
+}
+
+/// How long an action status message stays in the footer.
+const STATUS_TTL: Duration = Duration::from_mins(1);
+
+/// Clear the footer status once it has been shown for [`STATUS_TTL`].
+///
+/// Sleeps until the current message's deadline rather than ticking, so an
+/// idle view wakes at most once a minute.
+pub(crate) async fn expire_action_status(
+    mut action_status: State<Option<ActionFeedback>>,
+    mut status_set_at: State<Option<Instant>>,
+) {
+    loop {
+        let remaining = status_set_at
+            .get()
+            .map_or(STATUS_TTL, |t| STATUS_TTL.saturating_sub(t.elapsed()));
+        if remaining.is_zero() {
+            action_status.set(None);
+            status_set_at.set(None);
+        } else {
+            smol::Timer::after(remaining).await;
+        }
+    }
+}
+
+/// Build `count` empty per-filter slots.
+///
+/// Filter data sits behind an `Arc` so cloning a view's state to mutate it
+/// copies pointers only; `Arc::make_mut` then copies just the filter being
+/// changed.
+pub(crate) fn empty_filters<T: Default>(count: usize) -> Vec<Arc<T>> {
+    std::iter::repeat_with(Arc::default).take(count).collect()
+}
+
+/// The tab to show once a config reload changed the configured tabs from
+/// `old_count` to `new_count`, `tab` being shown: the same configured tab,
+/// or the last one if it is gone, or the same ephemeral tab after them.
+pub(crate) fn tab_after_reload(tab: usize, old_count: usize, new_count: usize) -> usize {
+    if tab >= old_count {
+        tab - old_count + new_count
+    } else {
+        tab.min(new_count.saturating_sub(1))
+    }
+}
+
+/// Follow a config reload that changed the configured tabs to `filters`:
+/// keep to the tab shown and drop the per-tab postponements. Returns whether
+/// they changed, for the view to refetch every filter as on a scope change.
+pub(crate) fn follow_reload<T: Clone + PartialEq + Send + Sync + 'static>(
+    last: &mut State<Vec<T>>,
+    filters: &[T],
+    active_filter: &mut State<usize>,
+    refresh_postponed: &mut State<Vec<Option<Instant>>>,
+) -> bool {
+    if *last.read() == filters {
+        return false;
+    }
+    let old_count = last.read().len();
+    active_filter.set(tab_after_reload(
+        active_filter.get(),
+        old_count,
+        filters.len(),
+    ));
+    last.set(filters.to_vec());
+    refresh_postponed.set(vec![None; filters.len()]);
+    true
 }
 
 /// Map a `GoTo*` keybinding action to its target `ViewKind`.
@@ -39,10 +129,174 @@ pub(crate) fn goto_target(action: BuiltinAction) -> Option<ViewKind> {
         BuiltinAction::GoToAlerts => Some(ViewKind::Alerts),
         BuiltinAction::GoToNotifications => Some(ViewKind::Notifications),
         BuiltinAction::GoToRepo => Some(ViewKind::Repo),
+        BuiltinAction::GoToEvents => Some(ViewKind::Events),
         _ => None,
     }
 }
 
+/// Open the page resolving the footer's SSO or token-expiry warning
+/// (`authorize_sso`), returning the feedback to show.
+pub(crate) fn authorize_sso() -> ActionFeedback {
+    let Some((url, subject)) = auth_warnings::take_authorization(chrono::Utc::now()) else {
+        return ActionFeedback::Info("No SSO or token warning to resolve".to_owned());
+    };
+    match clipboard::open_in_browser(&url) {
+        Ok(how) => ActionFeedback::Success(how.message(&subject)),
+        Err(e) => ActionFeedback::Error(format!("Open failed: {e}")),
+    }
+}
+
+/// When a filter fetched at `fetched` is next refreshed: one `interval`
+/// later, unless `postponed` past that.
+fn next_refresh(fetched: Instant, interval: Duration, postponed: Option<Instant>) -> Instant {
+    let next = fetched + interval;
+    postponed.map_or(next, |until| until.max(next))
+}
+
+/// Compact remaining time, rounded up to the minute: `4m`, `1h05m`.
+fn format_remaining(left: Duration) -> String {
+    let mins = left.as_secs().div_ceil(60);
+    if mins < 60 {
+        format!("{mins}m")
+    } else {
+        format!("{}h{:02}m", mins / 60, mins % 60)
+    }
+}
+
+/// Auto-refresh interval of a configured filter: its own
+/// `refetch_interval_minutes`, else the `[github]` default.
+pub(crate) fn refresh_interval(filter_minutes: Option<u32>, default_minutes: u32) -> Duration {
+    Duration::from_mins(u64::from(filter_minutes.unwrap_or(default_minutes).max(1)))
+}
+
+/// Tab-bar countdown to the active filter's next auto-refresh, mirroring
+/// the engine's schedule. `None` until the filter has been fetched once, and
+/// for ephemeral tabs (no `interval`), which are never auto-refreshed.
+pub(crate) fn refresh_countdown(
+    fetched: Option<Instant>,
+    interval: Option<Duration>,
+    postponed: Option<Instant>,
+    icon: &str,
+) -> Option<String> {
+    let next = next_refresh(fetched?, interval?, postponed);
+    let left = next.saturating_duration_since(Instant::now());
+    Some(if left.is_zero() {
+        format!("{icon} now")
+    } else {
+        format!("{icon} {}", format_remaining(left))
+    })
+}
+
+/// Push the active filter's next auto-refresh one `interval` further
+/// (`postpone_refresh`), returning the feedback to show.
+pub(crate) fn postpone_refresh(
+    engine: Option<&EngineHandle>,
+    view: RefreshView,
+    filter_idx: usize,
+    fetched: Option<Instant>,
+    interval: Option<Duration>,
+    postponed: &mut State<Vec<Option<Instant>>>,
+) -> ActionFeedback {
+    let (Some(engine), Some(fetched), Some(interval)) = (engine, fetched, interval) else {
+        return ActionFeedback::Info("No auto-refresh scheduled for this tab".to_owned());
+    };
+    let now = Instant::now();
+    let current = postponed.read().get(filter_idx).copied().flatten();
+    let until = next_refresh(fetched, interval, current).max(now) + interval;
+    let mut all = postponed.read().clone();
+    if all.len() <= filter_idx {
+        all.resize(filter_idx + 1, None);
+    }
+    all[filter_idx] = Some(until);
+    postponed.set(all);
+    engine.send(Request::PostponeRefresh {
+        view,
+        filter_idx,
+        until: SystemTime::now() + (until - now),
+    });
+    ActionFeedback::Info(format!(
+        "Auto-refresh postponed: next in {}",
+        format_remaining(until - now)
+    ))
+}
+
+/// The item under a view's cursor, remembered so that a refresh reordering
+/// or replacing the rows leaves the cursor on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CursorAnchor<K> {
+    filter_idx: usize,
+    /// When the rows the cursor was placed in were fetched.
+    fetched: Option<Instant>,
+    key: Option<K>,
+}
+
+impl<K> Default for CursorAnchor<K> {
+    fn default() -> Self {
+        Self {
+            filter_idx: 0,
+            fetched: None,
+            key: None,
+        }
+    }
+}
+
+/// Where the anchored item now is among `keys` (the visible rows' identities,
+/// in order): `None` while the cursor should stay put.
+///
+/// Only a new fetch of the same tab moves the cursor. An item that has gone
+/// keeps the cursor at its index, clamped to the last row.
+fn anchored_position<K: PartialEq>(
+    anchor: &CursorAnchor<K>,
+    cursor: usize,
+    filter_idx: usize,
+    fetched: Option<Instant>,
+    keys: &[K],
+) -> Option<usize> {
+    if anchor.filter_idx != filter_idx || anchor.fetched == fetched {
+        return None;
+    }
+    let key = anchor.key.as_ref()?;
+    let pos = keys
+        .iter()
+        .position(|k| k == key)
+        .unwrap_or_else(|| cursor.min(keys.len().saturating_sub(1)));
+    (pos != cursor).then_some(pos)
+}
+
+/// Keep the cursor on the same item when the active tab's rows are
+/// refetched, scrolling so that it stays at the same height on screen.
+///
+/// Call on every render with the identities of the visible rows; `fetched`
+/// is the tab's last fetch time. Tabs still loading (no `keys`) leave the
+/// anchor untouched, for the rows to come.
+pub(crate) fn anchor_cursor<K: PartialEq + Clone + Send + Sync + Unpin + 'static>(
+    anchor: &mut State<CursorAnchor<K>>,
+    cursor: &mut State<usize>,
+    scroll_offset: &mut State<usize>,
+    filter_idx: usize,
+    fetched: Option<Instant>,
+    keys: &[K],
+) {
+    if keys.is_empty() {
+        return;
+    }
+    let current = anchor.read().clone();
+    let mut pos = cursor.get();
+    if let Some(new_pos) = anchored_position(&current, pos, filter_idx, fetched, keys) {
+        scroll_offset.set((scroll_offset.get() + new_pos).saturating_sub(pos));
+        cursor.set(new_pos);
+        pos = new_pos;
+    }
+    let next = CursorAnchor {
+        filter_idx,
+        fetched,
+        key: keys.get(pos).cloned(),
+    };
+    if next != current {
+        anchor.set(next);
+    }
+}
+
 /// Mark a filter index as in-flight (or clear it).
 ///
 /// This is the canonical way to update `filter_in_flight` state — it avoids
@@ -56,10 +310,223 @@ pub fn set_in_flight(state: &mut State<Vec<bool>>, idx: usize, value: bool) {
     state.set(v);
 }
 
-/// Update the displayed rate-limit counter from the latest API response.
-pub fn update_rate_limit(state: &mut State<Option<RateLimitInfo>>, new: Option<RateLimitInfo>) {
+/// Error shown in a tab whose fetch timed out.
+pub(crate) fn timed_out_message(secs: u64) -> String {
+    format!("Request timed out after {secs}s — refresh to retry")
+}
+
+/// `defaults.date_format`, `relative` when unset.
+pub(crate) fn date_format(config: Option<&AppConfig>) -> &str {
+    config
+        .and_then(|c| c.defaults.date_format.as_deref())
+        .unwrap_or("relative")
+}
+
+/// Table message of a tab whose fetch failed with nothing to show; the
+/// error itself is in the footer.
+pub(crate) fn failed_tab_message(bindings: Option<&MergedBindings>) -> String {
+    let key = bindings
+        .and_then(|kb| {
+            kb.universal
+                .iter()
+                .find(|b| b.builtin.as_deref() == Some("refresh_item"))
+        })
+        .map_or("r", |b| b.key.as_str());
+    format!("Could not load this tab — press {key} to retry")
+}
+
+/// `refresh_item` with no row to refresh, as in a tab whose fetch failed,
+/// refreshes the tab instead.
+pub(crate) fn refresh_fallback(binding: ResolvedBinding, total_rows: usize) -> ResolvedBinding {
+    match binding {
+        ResolvedBinding::Builtin(BuiltinAction::RefreshItem) if total_rows == 0 => {
+            ResolvedBinding::Builtin(BuiltinAction::Refresh)
+        }
+        binding => binding,
+    }
+}
+
+/// Indices of the tabs whose `badge` is set; they load in the background
+/// for the footer count.
+pub(crate) fn badge_tabs(badges: impl IntoIterator<Item = bool>) -> Vec<usize> {
+    badges
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, badge)| badge.then_some(i))
+        .collect()
+}
+
+/// Footer count of a view: the distinct ids of those of its badge tabs that
+/// have loaded, `None` before any has.
+pub(crate) fn badge_count<'a, I>(loaded_tabs: impl IntoIterator<Item = I>) -> Option<u32>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut any = false;
+    let mut ids = std::collections::HashSet::new();
+    for tab in loaded_tabs {
+        any = true;
+        ids.extend(tab);
+    }
+    any.then(|| u32::try_from(ids.len()).unwrap_or(u32::MAX))
+}
+
+/// Show `count` next to `view` in the footer. Leaves the shared state alone
+/// when the count is unchanged, so that renders settle.
+pub(crate) fn publish_view_count(
+    counts: Option<State<ViewCounts>>,
+    view: ViewKind,
+    count: Option<u32>,
+) {
+    let Some(mut counts) = counts else {
+        return;
+    };
+    let mut current = counts.get();
+    if current.get(view) != count {
+        current.set(view, count);
+        counts.set(current);
+    }
+}
+
+/// Ask the engine to drop the view's list fetches once it stops being the
+/// active view.
+///
+/// Call on every render. A tab whose first load is cancelled still reads as
+/// loading, so it is fetched again when the view comes back.
+pub(crate) fn cancel_fetches_on_leave(
+    was_active: &mut State<bool>,
+    is_active: bool,
+    engine: Option<&EngineHandle>,
+    event_tx: &EventSender,
+) {
+    if was_active.get() == is_active {
+        return;
+    }
+    was_active.set(is_active);
+    if !is_active && let Some(engine) = engine {
+        engine.send(Request::CancelFetches {
+            reply_tx: event_tx.clone(),
+        });
+    }
+}
+
+/// Color of an "updated" date: faint, or tinted once an open item has gone
+/// stale (`[defaults.stale]`).
+pub(crate) fn updated_color(
+    updated: &chrono::DateTime<chrono::Utc>,
+    is_open: bool,
+    theme: &ResolvedTheme,
+) -> AppColor {
+    match is_open.then(|| crate::util::staleness(updated)) {
+        Some(Staleness::Alert) => theme.text_error,
+        Some(Staleness::Warn) => theme.text_warning,
+        Some(Staleness::Fresh) | None => theme.text_faint,
+    }
+}
+
+/// Tint `row` after its labels (`[theme.colors.labels]`): the whole row, or
+/// only the state cell with `theme.ui.table.label_tint = "state"`.
+pub(crate) fn tint_by_labels(row: &mut Row, labels: &[Label], theme: &ResolvedTheme) {
+    let names: Vec<&str> = labels.iter().map(|l| l.name.as_str()).collect();
+    let Some(color) = theme.label_color(&names) else {
+        return;
+    };
+    match theme.label_tint {
+        LabelTint::Row => {
+            row.insert(TINT_CELL.to_owned(), Cell::plain("").with_bg(color));
+        }
+        LabelTint::State => {
+            if let Some(state) = row.get_mut("state") {
+                state.bg = Some(color);
+            }
+        }
+    }
+}
+
+/// Badge colors: mid-tone ANSI 256 hues that read on dark and light
+/// backgrounds alike.
+const BADGE_COLORS: [u8; 12] = [167, 173, 179, 107, 72, 73, 74, 104, 140, 175, 139, 109];
+
+/// Two-letter badge for `login` (`jane-doe` → `JD`, `octocat` → `OC`), in a
+/// color that only depends on the login.
+pub(crate) fn login_badge(login: &str) -> Span {
+    let name = login.strip_suffix("[bot]").unwrap_or(login);
+    let mut parts = name.split(['-', '_', '.']).filter(|p| !p.is_empty());
+    let initials: String = match (parts.next(), parts.next()) {
+        (Some(first), Some(second)) => first
+            .chars()
+            .take(1)
+            .chain(second.chars().take(1))
+            .collect(),
+        (Some(first), None) => first.chars().take(2).collect(),
+        _ => "?".to_owned(),
+    };
+    // FNV-1a: stable across runs and builds, unlike `DefaultHasher`.
+    let hash = login.bytes().fold(0x811c_9dc5_u32, |h, b| {
+        (h ^ u32::from(b)).wrapping_mul(0x0100_0193)
+    });
+    Span {
+        text: initials.to_uppercase(),
+        color: Some(AppColor::Ansi256(
+            BADGE_COLORS[hash as usize % BADGE_COLORS.len()],
+        )),
+        bold: true,
+    }
+}
+
+/// The optional `people` column: the author's badge, then the assignees'.
+pub(crate) fn people_cell(
+    author: Option<&Actor>,
+    assignees: &[Actor],
+    theme: &ResolvedTheme,
+) -> Cell {
+    let mut spans: Vec<Span> = author.map(|a| login_badge(&a.login)).into_iter().collect();
+    if !assignees.is_empty() {
+        spans.push(Span {
+            text: if theme.chrome.ascii_only() {
+                " > "
+            } else {
+                " \u{203a} "
+            }
+            .to_owned(),
+            color: Some(theme.text_faint),
+            bold: false,
+        });
+    }
+    for (i, assignee) in assignees.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span {
+                text: " ".to_owned(),
+                color: None,
+                bold: false,
+            });
+        }
+        spans.push(login_badge(&assignee.login));
+    }
+    Cell::from_spans(spans)
+}
+
+/// Update the displayed rate-limit counter of the host of the latest API
+/// response.
+pub fn update_rate_limit(state: &mut State<RateLimits>, new: Option<RateLimitInfo>) {
     if let Some(rl) = new {
-        state.set(Some(rl));
+        let mut limits = state.read().clone();
+        limits.record(rl);
+        state.set(limits);
+    }
+}
+
+/// Footer note of a tab listing the results an earlier session saved at
+/// `saved_at`, while its fetch is `refreshing` or after it failed.
+pub(crate) fn cached_note(saved_at: &chrono::DateTime<chrono::Utc>, refreshing: bool) -> String {
+    let age = match crate::util::format_relative_time(saved_at).as_str() {
+        "now" => "just now".to_owned(),
+        span => format!("{span} ago"),
+    };
+    if refreshing {
+        format!(" (cached {age}, refreshing...)")
+    } else {
+        format!(" (showing results cached {age})")
     }
 }
 
@@ -191,10 +658,310 @@ pub(crate) fn handle_multiselect_input(
     }
 }
 
+/// Shared state for `@mention` / `#reference` autocomplete in a comment
+/// composer.
+pub(crate) struct CompletionState {
+    pub input_buffer: State<String>,
+    /// Logins offered after `@`.
+    pub logins: State<Vec<String>>,
+    /// `#number title` entries offered after `#`.
+    pub refs: State<Vec<String>>,
+    pub selection: State<usize>,
+}
+
+/// Keyboard handler for the autocomplete popup of a comment composer.
+///
+/// While suggestions are showing, Tab/Down and Up/BackTab move the selection
+/// and Enter inserts it. Returns `false` when the key was not consumed and
+/// should be handled as regular text input.
+pub(crate) fn handle_completion_input(code: KeyCode, cs: &mut CompletionState) -> bool {
+    let mut input_buffer = cs.input_buffer;
+    let mut selection = cs.selection;
+    let buf = input_buffer.read().clone();
+    let suggestions = text_input::completion_suggestions(&buf, &cs.logins.read(), &cs.refs.read());
+    if suggestions.is_empty() {
+        return false;
+    }
+    let sel = selection.get().min(suggestions.len() - 1);
+    match code {
+        KeyCode::Tab | KeyCode::Down => selection.set((sel + 1) % suggestions.len()),
+        KeyCode::Up | KeyCode::BackTab => {
+            selection.set(sel.checked_sub(1).unwrap_or(suggestions.len() - 1));
+        }
+        KeyCode::Enter => {
+            input_buffer.set(text_input::accept_completion(&buf, &suggestions[sel]));
+            selection.set(0);
+        }
+        _ => {
+            selection.set(0);
+            return false;
+        }
+    }
+    true
+}
+
+// ---------------------------------------------------------------------------
+// Spelling suggestions
+// ---------------------------------------------------------------------------
+
+/// Replacements offered for a misspelled word of a comment composer.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SpellPick {
+    /// Byte range of `word` in the comment; empty when nothing is misspelled.
+    pub range: std::ops::Range<usize>,
+    pub word: String,
+    pub suggestions: Vec<String>,
+    pub selection: usize,
+}
+
+impl SpellPick {
+    /// Suggestions for the last misspelled word of `comment`.
+    fn last_misspelling(comment: &str) -> Self {
+        let Some(range) = crate::spell::misspellings(comment).pop() else {
+            return Self::default();
+        };
+        let word = comment[range.clone()].to_owned();
+        Self {
+            suggestions: crate::spell::suggestions(&word),
+            range,
+            word,
+            selection: 0,
+        }
+    }
+
+    /// Prompt of the composer while the suggestions show.
+    pub fn prompt(&self) -> String {
+        match (self.word.as_str(), self.suggestions.is_empty()) {
+            ("", _) => "Spelling: no misspelled words (Esc to close):".to_owned(),
+            (word, true) => format!("Spelling: no suggestions for {word:?} (Esc to close):"),
+            (word, false) => format!("Spelling: replace {word:?} (Enter to pick, Esc to close):"),
+        }
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        (!self.suggestions.is_empty()).then_some(self.selection)
+    }
+}
+
+/// Keyboard handler for the spelling suggestions of a comment composer.
+///
+/// Ctrl+S lists replacements for the last misspelled word; while they show,
+/// Tab/Down and Up/BackTab move the selection, Enter replaces the word and
+/// Esc closes the list. Any other key closes it too and returns `false`: it
+/// should be handled as regular text input.
+pub(crate) fn handle_spell_input(
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    pick: &mut State<Option<SpellPick>>,
+    mut comment: State<String>,
+) -> bool {
+    let Some(mut current) = pick.read().clone() else {
+        if code == KeyCode::Char('s') && modifiers.contains(KeyModifiers::CONTROL) {
+            pick.set(Some(SpellPick::last_misspelling(&comment.read())));
+            return true;
+        }
+        return false;
+    };
+    let len = current.suggestions.len().max(1);
+    match code {
+        KeyCode::Tab | KeyCode::Down => current.selection = (current.selection + 1) % len,
+        KeyCode::Up | KeyCode::BackTab => {
+            current.selection = current.selection.checked_sub(1).unwrap_or(len - 1);
+        }
+        KeyCode::Enter => {
+            let text = comment.read().clone();
+            if let Some(replacement) = current.suggestions.get(current.selection)
+                && text.get(current.range.clone()) == Some(current.word.as_str())
+            {
+                let mut text = text;
+                text.replace_range(current.range.clone(), replacement);
+                comment.set(text);
+            }
+            pick.set(None);
+            return true;
+        }
+        KeyCode::Esc => {
+            pick.set(None);
+            return true;
+        }
+        _ => {
+            pick.set(None);
+            return false;
+        }
+    }
+    pick.set(Some(current));
+    true
+}
+
 // ---------------------------------------------------------------------------
-// Mouse scroll helpers
+// Reply template picker
 // ---------------------------------------------------------------------------
 
+/// Most templates listed under the picker; typing narrows the list.
+const MAX_REPLY_MATCHES: usize = 8;
+
+/// What a reply template's placeholders expand to for the item being
+/// commented on.
+#[derive(Debug, Default)]
+pub(crate) struct ReplyVars {
+    pub author: String,
+    pub number: u64,
+    pub title: String,
+    /// `owner/name`.
+    pub repo: String,
+}
+
+/// Replace `{{author}}`, `{{number}}`, `{{title}}` and `{{repo}}` in `body`.
+///
+/// Other `{{...}}` sequences are left as typed.
+pub(crate) fn expand_reply_template(body: &str, vars: &ReplyVars) -> String {
+    body.replace("{{author}}", &vars.author)
+        .replace("{{number}}", &vars.number.to_string())
+        .replace("{{title}}", &vars.title)
+        .replace("{{repo}}", &vars.repo)
+}
+
+/// The templates whose title contains `query` (case-insensitive).
+pub(crate) fn reply_matches<'r>(replies: &'r [SavedReply], query: &str) -> Vec<&'r SavedReply> {
+    let lower = query.to_lowercase();
+    replies
+        .iter()
+        .filter(|r| r.title.to_lowercase().contains(&lower))
+        .take(MAX_REPLY_MATCHES)
+        .collect()
+}
+
+/// Save the composer text as a draft for `target` when the composer is
+/// dismissed; returns the footer message telling the user it was kept.
+pub(crate) fn keep_draft(target: Option<&DraftTarget>, text: &str) -> Option<ActionFeedback> {
+    let target = target?;
+    drafts::save(target, text);
+    (!text.trim().is_empty()).then(|| {
+        ActionFeedback::Info(format!(
+            "Draft saved for {} (Ctrl+E lists drafts)",
+            target.key
+        ))
+    })
+}
+
+/// Shared state for the template picker opened from a comment composer.
+pub(crate) struct ReplyPickerState {
+    /// The comment being composed; the picked template is appended to it.
+    pub comment: State<String>,
+    pub query: State<String>,
+    pub selection: State<usize>,
+}
+
+/// Keyboard handler for the template picker.
+///
+/// Typing filters `replies` by title, Tab/Down and Up/BackTab move the
+/// selection, and Enter appends the expanded template to the comment. Enter
+/// and Esc both call `on_dismiss` to return to the composer.
+pub(crate) fn handle_reply_picker_input(
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    ps: &mut ReplyPickerState,
+    replies: &[SavedReply],
+    vars: impl FnOnce() -> ReplyVars,
+    on_dismiss: impl FnOnce(),
+) {
+    let mut comment = ps.comment;
+    let mut query = ps.query;
+    let mut selection = ps.selection;
+    let q = query.read().clone();
+    let matches = reply_matches(replies, &q);
+    let sel = selection.get().min(matches.len().saturating_sub(1));
+    match code {
+        KeyCode::Tab | KeyCode::Down if !matches.is_empty() => {
+            selection.set((sel + 1) % matches.len());
+        }
+        KeyCode::Up | KeyCode::BackTab if !matches.is_empty() => {
+            selection.set(sel.checked_sub(1).unwrap_or(matches.len() - 1));
+        }
+        KeyCode::Enter => {
+            if let Some(reply) = matches.get(sel) {
+                let text = expand_reply_template(&reply.body, &vars());
+                let mut buf = comment.read().clone();
+                if !buf.is_empty() && !buf.ends_with(char::is_whitespace) {
+                    buf.push('\n');
+                }
+                buf.push_str(&text);
+                comment.set(buf);
+            }
+            query.set(String::new());
+            selection.set(0);
+            on_dismiss();
+        }
+        KeyCode::Esc => {
+            query.set(String::new());
+            selection.set(0);
+            on_dismiss();
+        }
+        KeyCode::Backspace => {
+            let mut q = q;
+            q.pop();
+            query.set(q);
+            selection.set(0);
+        }
+        KeyCode::Char(ch) if !modifiers.contains(KeyModifiers::CONTROL) => {
+            let mut q = q;
+            q.push(ch);
+            query.set(q);
+            selection.set(0);
+        }
+        _ => {}
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Mouse helpers
+// ---------------------------------------------------------------------------
+
+/// State a click on the table acts on.
+pub(crate) struct ClickTargets {
+    pub cursor: State<usize>,
+    pub preview_scroll: State<usize>,
+    pub preview_open: State<bool>,
+    pub sidebar_focused: State<bool>,
+}
+
+/// Take a click on a table row, reported by `ScrollableTable`: the cursor
+/// moves to the row and the table gets the focus; a double-click also opens
+/// the preview.
+pub(crate) fn take_table_click(
+    mut click: State<Option<TableClick>>,
+    total_rows: usize,
+    mut targets: ClickTargets,
+) {
+    let Some(TableClick { row, double }) = click.get() else {
+        return;
+    };
+    click.set(None);
+    if row >= total_rows {
+        return;
+    }
+    if targets.cursor.get() != row {
+        targets.cursor.set(row);
+        targets.preview_scroll.set(0);
+    }
+    targets.sidebar_focused.set(false);
+    if double {
+        targets.preview_open.set(true);
+    }
+}
+
+/// Take a click on the tab bar, reported by `TabBar`: the tab to switch to,
+/// unless it is the one shown.
+pub(crate) fn take_tab_click(
+    mut click: State<Option<usize>>,
+    tab_count: usize,
+    active: usize,
+) -> Option<usize> {
+    let tab = click.get()?;
+    click.set(None);
+    (tab < tab_count && tab != active).then_some(tab)
+}
+
 /// Number of rows to scroll per mouse wheel tick.
 pub const MOUSE_SCROLL_LINES: isize = 3;
 
@@ -245,3 +1012,142 @@ pub fn mouse_scroll_sidebar(mut preview_scroll: State<usize>, delta: isize) {
     };
     preview_scroll.set(new_val);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn recv_batch_drains_a_burst_in_one_wake() {
+        let (tx, rx) = async_channel::unbounded();
+        for n in 1..=3 {
+            tx.try_send(n).unwrap();
+        }
+        assert_eq!(smol::block_on(recv_batch(&rx)), Some(vec![1, 2, 3]));
+        tx.try_send(4).unwrap();
+        drop(tx);
+        // What was queued before the senders went away still arrives.
+        assert_eq!(smol::block_on(recv_batch(&rx)), Some(vec![4]));
+        assert_eq!(smol::block_on(recv_batch(&rx)), None);
+    }
+
+    #[test]
+    fn tab_after_reload_keeps_the_tab_shown() {
+        assert_eq!(tab_after_reload(1, 3, 4), 1);
+        assert_eq!(tab_after_reload(2, 3, 2), 1);
+        assert_eq!(tab_after_reload(0, 3, 0), 0);
+        // Ephemeral tabs follow the configured ones.
+        assert_eq!(tab_after_reload(4, 3, 2), 3);
+    }
+
+    #[test]
+    fn badge_count_dedupes_across_loaded_tabs() {
+        assert_eq!(badge_count(Vec::<Vec<&str>>::new()), None);
+        assert_eq!(badge_count([Vec::new()]), Some(0));
+        assert_eq!(badge_count([vec!["a", "b"], vec!["b", "c"]]), Some(3));
+    }
+
+    #[test]
+    fn login_badge_initials_and_stable_color() {
+        assert_eq!(login_badge("jane-doe").text, "JD");
+        assert_eq!(login_badge("octocat").text, "OC");
+        assert_eq!(login_badge("dependabot[bot]").text, "DE");
+        assert_eq!(login_badge("x").text, "X");
+        assert_eq!(login_badge("octocat").color, login_badge("octocat").color);
+        let colors: HashSet<_> = ["alice", "bob", "carol", "dave", "erin"]
+            .into_iter()
+            .map(|l| login_badge(l).color)
+            .collect();
+        assert!(colors.len() > 1);
+    }
+
+    #[test]
+    fn expand_reply_template_fills_known_placeholders() {
+        let vars = ReplyVars {
+            author: "alice".into(),
+            number: 42,
+            title: "Fix the parser".into(),
+            repo: "acme/widgets".into(),
+        };
+        assert_eq!(
+            expand_reply_template(
+                "Thanks @{{author}} for #{{number}} in {{repo}} ({{title}}) {{other}}",
+                &vars
+            ),
+            "Thanks @alice for #42 in acme/widgets (Fix the parser) {{other}}"
+        );
+    }
+
+    #[test]
+    fn reply_matches_filters_by_title() {
+        let replies: Vec<SavedReply> = ["Thanks", "Needs rebase", "thanks, merged"]
+            .into_iter()
+            .map(|t| SavedReply {
+                title: t.into(),
+                body: String::new(),
+            })
+            .collect();
+        let titles: Vec<_> = reply_matches(&replies, "THANKS")
+            .iter()
+            .map(|r| r.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Thanks", "thanks, merged"]);
+        assert_eq!(reply_matches(&replies, "").len(), 3);
+    }
+
+    #[test]
+    fn refresh_countdown_follows_postponement() {
+        let interval = Duration::from_mins(5);
+        assert_eq!(format_remaining(Duration::from_secs(42)), "1m");
+        assert_eq!(format_remaining(Duration::from_secs(181)), "4m");
+        assert_eq!(format_remaining(Duration::from_mins(65)), "1h05m");
+        assert_eq!(refresh_countdown(None, Some(interval), None, "~"), None);
+        assert_eq!(
+            refresh_countdown(Some(Instant::now()), None, None, "~"),
+            None
+        );
+        let fetched = Instant::now();
+        let postponed = fetched + Duration::from_mins(20);
+        assert_eq!(next_refresh(fetched, interval, None), fetched + interval);
+        assert_eq!(next_refresh(fetched, interval, Some(postponed)), postponed);
+        assert_eq!(
+            next_refresh(fetched, interval, Some(fetched)),
+            fetched + interval
+        );
+        let stale = fetched.checked_sub(Duration::from_mins(10));
+        assert_eq!(
+            stale.and_then(|at| refresh_countdown(Some(at), Some(interval), None, "~")),
+            stale.map(|_| "~ now".to_owned())
+        );
+    }
+
+    #[test]
+    fn anchored_position_follows_the_item() {
+        let before = Instant::now();
+        let after = before + Duration::from_mins(1);
+        let anchor = CursorAnchor {
+            filter_idx: 0,
+            fetched: Some(before),
+            key: Some(7),
+        };
+        // Same fetch: the cursor is the user's to move.
+        assert_eq!(
+            anchored_position(&anchor, 1, 0, Some(before), &[3, 7]),
+            None
+        );
+        // Another tab.
+        assert_eq!(anchored_position(&anchor, 1, 1, Some(after), &[7, 3]), None);
+        // Refetched: follow the item, or clamp once it is gone.
+        assert_eq!(
+            anchored_position(&anchor, 1, 0, Some(after), &[7, 3]),
+            Some(0)
+        );
+        assert_eq!(anchored_position(&anchor, 0, 0, Some(after), &[7, 3]), None);
+        assert_eq!(
+            anchored_position(&anchor, 4, 0, Some(after), &[1, 2]),
+            Some(1)
+        );
+    }
+}
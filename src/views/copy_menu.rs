@@ -0,0 +1,228 @@
+//! Copy menu of the PR and Issue views.
+//!
+//! `alt+y` lists the `[copy]` templates expanded for the selected item,
+//! followed by what was copied earlier in the session; Enter or the entry's
+//! digit copies it again.
+
+use iocraft::prelude::*;
+
+use crate::actions::clipboard;
+use crate::color::ColorDepth;
+use crate::components::footer::ActionFeedback;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::config::keybindings::{TemplateVars, expand_template};
+use crate::theme::{Chrome, ResolvedTheme};
+
+/// Characters of an entry shown in the menu.
+const PREVIEW_CHARS: usize = 60;
+
+/// One line of the menu and the text it copies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CopyEntry {
+    /// Template label; `None` for a history entry.
+    pub label: Option<String>,
+    pub text: String,
+}
+
+/// An open copy menu.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CopyMenu {
+    pub entries: Vec<CopyEntry>,
+    pub cursor: usize,
+    /// Chrome the previews are drawn in.
+    pub chrome: Chrome,
+}
+
+/// Menu entries: `templates` expanded with `vars` (when an item is
+/// selected), then `history`. Duplicates keep their first entry.
+fn entries(
+    templates: &[(String, String)],
+    history: &[String],
+    vars: Option<&TemplateVars>,
+) -> Vec<CopyEntry> {
+    let mut entries: Vec<CopyEntry> = Vec::new();
+    let expanded = vars.into_iter().flat_map(|vars| {
+        templates.iter().map(|(label, template)| CopyEntry {
+            label: Some(label.clone()),
+            text: expand_template(template, vars),
+        })
+    });
+    let past = history.iter().map(|text| CopyEntry {
+        label: None,
+        text: text.clone(),
+    });
+    for entry in expanded.chain(past) {
+        if !entries.iter().any(|e| e.text == entry.text) {
+            entries.push(entry);
+        }
+    }
+    entries
+}
+
+/// The menu for the selected item (`vars: None` when nothing is selected);
+/// `None` when it would be empty.
+pub(crate) fn open(vars: Option<&TemplateVars>, chrome: Chrome) -> Option<CopyMenu> {
+    let entries = entries(
+        &clipboard::copy_templates(),
+        &clipboard::copy_history(),
+        vars,
+    );
+    (!entries.is_empty()).then_some(CopyMenu {
+        entries,
+        cursor: 0,
+        chrome,
+    })
+}
+
+/// Copy `entry`, closing the menu.
+fn copy(menu: &mut State<Option<CopyMenu>>, entry: &CopyEntry, chrome: Chrome) -> ActionFeedback {
+    menu.set(None);
+    match clipboard::copy_to_clipboard(&entry.text) {
+        Ok(()) => ActionFeedback::Success(match &entry.label {
+            Some(label) => format!("Copied {label}"),
+            None => format!("Copied {}", preview(&entry.text, chrome)),
+        }),
+        Err(e) => ActionFeedback::Error(format!("Copy failed: {e}")),
+    }
+}
+
+/// Handle a key while the menu is open. Every key is consumed; returns
+/// the feedback of the copy made, if any.
+pub(crate) fn handle_key(
+    menu: &mut State<Option<CopyMenu>>,
+    code: KeyCode,
+) -> Option<ActionFeedback> {
+    let mut current = menu.read().clone()?;
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => menu.set(None),
+        KeyCode::Enter => {
+            let entry = current.entries.get(current.cursor)?;
+            return Some(copy(menu, entry, current.chrome));
+        }
+        KeyCode::Char(c @ '1'..='9') => {
+            let entry = current.entries.get((c as usize) - ('1' as usize))?;
+            return Some(copy(menu, entry, current.chrome));
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            current.cursor = (current.cursor + 1).min(current.entries.len().saturating_sub(1));
+            menu.set(Some(current));
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            current.cursor = current.cursor.saturating_sub(1);
+            menu.set(Some(current));
+        }
+        _ => {}
+    }
+    None
+}
+
+/// First line of `text`, shortened to [`PREVIEW_CHARS`].
+fn preview(text: &str, chrome: Chrome) -> String {
+    let first = text.lines().next().unwrap_or_default();
+    let mut preview: String = first.chars().take(PREVIEW_CHARS).collect();
+    if preview.len() < first.len() || text.lines().nth(1).is_some() {
+        preview.push_str(crate::components::ellipsis(chrome));
+    }
+    preview
+}
+
+/// Render the menu, or `None` when it is closed.
+pub(crate) fn render(
+    menu: &State<Option<CopyMenu>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let menu = menu.read();
+    let menu = menu.as_ref()?;
+    let items = menu
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let key = if i < 9 {
+                format!("{} ", i + 1)
+            } else {
+                "  ".to_owned()
+            };
+            let text = preview(&entry.text, theme.chrome);
+            let label = match &entry.label {
+                Some(label) => format!("{key}{label}: {text}"),
+                None if theme.chrome.ascii_only() => format!("{key}~ {text}"),
+                None => format!("{key}\u{21ba} {text}"), // ↺
+            };
+            SelectionOverlayItem { label }
+        })
+        .collect();
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title: "Copy".to_owned(),
+            items,
+            cursor: menu.cursor,
+            show_filter: false,
+            filter_text: String::new(),
+            hint: Some("Enter copy  Esc close".to_owned()),
+            depth,
+            title_color: Some(theme.text_primary),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: None,
+            filter_text_color: None,
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_expand_templates_then_list_history_once() {
+        let templates = [
+            (
+                "Markdown".to_owned(),
+                "[#{{.Number}}]({{.Url}}) {{.Title}}".to_owned(),
+            ),
+            ("Ref".to_owned(), "{{.RepoName}}#{{.Number}}".to_owned()),
+        ];
+        let history = ["acme/app#7".to_owned(), "older".to_owned()];
+        let vars = TemplateVars {
+            number: "7".to_owned(),
+            url: "https://github.com/acme/app/pull/7".to_owned(),
+            title: "Fix {{.Url}} parsing".to_owned(),
+            repo_name: "acme/app".to_owned(),
+            ..TemplateVars::default()
+        };
+        let found = entries(&templates, &history, Some(&vars));
+        let texts: Vec<&str> = found.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "[#7](https://github.com/acme/app/pull/7) Fix {{.Url}} parsing",
+                "acme/app#7",
+                "older",
+            ]
+        );
+        assert_eq!(found[2].label, None);
+
+        let without_item = entries(&templates, &history, None);
+        assert_eq!(without_item.len(), 2);
+    }
+
+    #[test]
+    fn preview_keeps_the_first_line() {
+        assert_eq!(preview("one\ntwo", Chrome::Unicode), "one\u{2026}");
+        assert_eq!(preview("one\ntwo", Chrome::Ascii), "one...");
+        assert_eq!(preview("short", Chrome::Unicode), "short");
+        assert_eq!(
+            preview(&"x".repeat(70), Chrome::Unicode).chars().count(),
+            PREVIEW_CHARS + 1
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Quick-run overlay of the Actions view (`actions.dispatch_presets`).
+//!
+//! The overlay lists the configured presets; picking one asks for a y/n
+//! confirmation before the `workflow_dispatch` run is triggered. A preset
+//! without a `repo` runs against the active tab's repository, or the
+//! selected run's one on multi-repo tabs.
+
+use iocraft::prelude::*;
+
+use crate::color::ColorDepth;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::config::types::DispatchPreset;
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::theme::{Chrome, ResolvedTheme};
+
+/// Outcome of a key pressed while the overlay is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum QuickRunKey {
+    /// Keep the overlay open.
+    Handled,
+    Close,
+    /// Preset picked for confirmation.
+    Pick(usize),
+}
+
+/// Handle a key while the overlay is open. Every key is consumed.
+pub(crate) fn handle_key(
+    cursor: &mut State<Option<usize>>,
+    code: KeyCode,
+    len: usize,
+) -> QuickRunKey {
+    let Some(current) = cursor.get() else {
+        return QuickRunKey::Close;
+    };
+    match code {
+        KeyCode::Esc | KeyCode::Char('q' | 'D') => {
+            cursor.set(None);
+            return QuickRunKey::Close;
+        }
+        KeyCode::Enter => {
+            cursor.set(None);
+            return QuickRunKey::Pick(current);
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            cursor.set(Some((current + 1).min(len.saturating_sub(1))));
+        }
+        KeyCode::Char('k') | KeyCode::Up => cursor.set(Some(current.saturating_sub(1))),
+        // Digits pick directly: one keypress plus the confirmation.
+        KeyCode::Char(c @ '1'..='9') => {
+            let idx = (c as usize) - ('1' as usize);
+            if idx < len {
+                cursor.set(None);
+                return QuickRunKey::Pick(idx);
+            }
+        }
+        _ => {}
+    }
+    QuickRunKey::Handled
+}
+
+/// `owner/repo` a preset runs against: its own `repo` (with `@current`
+/// resolved), else `fallback`.
+pub(crate) fn preset_repo(
+    preset: &DispatchPreset,
+    fallback: Option<&str>,
+    scope_repo: Option<&str>,
+    detected_repo: Option<&str>,
+) -> Option<String> {
+    match preset.repo.as_deref() {
+        Some("@current") => scope_repo.or(detected_repo).map(str::to_owned),
+        Some(repo) => Some(repo.to_owned()),
+        None => fallback.map(str::to_owned),
+    }
+}
+
+/// "workflow on ref in repo" summary of a preset.
+fn describe(preset: &DispatchPreset, repo: Option<&str>) -> String {
+    let git_ref = preset.git_ref.as_deref().unwrap_or("default branch");
+    let mut text = format!("{} on {git_ref}", preset.workflow);
+    if let Some(repo) = repo {
+        text.push_str(" in ");
+        text.push_str(repo);
+    }
+    if !preset.inputs.is_empty() {
+        let inputs: Vec<String> = preset
+            .inputs
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect();
+        text.push_str(" (");
+        text.push_str(&inputs.join(", "));
+        text.push(')');
+    }
+    text
+}
+
+/// The y/n prompt confirming a preset run.
+pub(crate) fn confirm_prompt(preset: &DispatchPreset, repo: Option<&str>) -> String {
+    match repo {
+        Some(_) => format!("Run {}: {}? (y/n)", preset.name, describe(preset, repo)),
+        None => format!("Run {}: no repository in context (n)", preset.name),
+    }
+}
+
+/// Trigger the preset; returns the status message to show.
+pub(crate) fn send(
+    engine: Option<&EngineHandle>,
+    preset: &DispatchPreset,
+    repo: &str,
+    host: Option<String>,
+    reply_tx: &EventSender,
+    chrome: Chrome,
+) -> String {
+    let Some((owner, name)) = repo.split_once('/') else {
+        return format!("Invalid repo {repo:?} for {}", preset.name);
+    };
+    if let Some(engine) = engine {
+        engine.send(Request::DispatchWorkflow {
+            host: preset.host.clone().or(host),
+            owner: owner.to_owned(),
+            repo: name.to_owned(),
+            workflow: preset.workflow.clone(),
+            git_ref: preset.git_ref.clone(),
+            inputs: preset.inputs.clone(),
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    format!(
+        "Dispatching {}{}",
+        preset.name,
+        crate::components::ellipsis(chrome)
+    )
+}
+
+/// Render the overlay listing `presets`, each with its resolved repo.
+pub(crate) fn render(
+    presets: &[DispatchPreset],
+    repos: &[Option<String>],
+    cursor: usize,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> RenderedSelectionOverlay {
+    let items = presets
+        .iter()
+        .zip(repos)
+        .enumerate()
+        .map(|(i, (preset, repo))| {
+            let key = if i < 9 {
+                format!("{} ", i + 1)
+            } else {
+                "  ".to_owned()
+            };
+            SelectionOverlayItem {
+                label: format!(
+                    "{key}{}{}{}",
+                    preset.name,
+                    crate::components::dot_separator(theme.chrome),
+                    describe(preset, repo.as_deref())
+                ),
+            }
+        })
+        .collect();
+    RenderedSelectionOverlay::build(SelectionOverlayBuildConfig {
+        title: "Quick run".to_owned(),
+        items,
+        cursor,
+        show_filter: false,
+        filter_text: String::new(),
+        hint: Some("Enter/1-9 run  Esc close".to_owned()),
+        depth,
+        title_color: Some(theme.text_primary),
+        item_color: Some(theme.text_secondary),
+        cursor_color: Some(theme.text_primary),
+        selected_bg: Some(theme.bg_selected),
+        border_color: Some(theme.border_primary),
+        hint_color: Some(theme.text_faint),
+        filter_prompt_color: None,
+        filter_text_color: None,
+        cursor_marker: theme.icons.select_cursor.clone(),
+        chrome: theme.chrome,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(repo: Option<&str>) -> DispatchPreset {
+        DispatchPreset {
+            name: "Deploy staging".to_owned(),
+            repo: repo.map(str::to_owned),
+            workflow: "deploy.yml".to_owned(),
+            git_ref: None,
+            inputs: [("env".to_owned(), "staging".to_owned())]
+                .into_iter()
+                .collect(),
+            host: None,
+        }
+    }
+
+    #[test]
+    fn preset_repo_prefers_its_own_repo() {
+        let tab = Some("o/tab");
+        assert_eq!(
+            preset_repo(&preset(Some("o/app")), tab, None, None).as_deref(),
+            Some("o/app")
+        );
+        assert_eq!(
+            preset_repo(&preset(None), tab, None, None).as_deref(),
+            Some("o/tab")
+        );
+        assert_eq!(
+            preset_repo(&preset(Some("@current")), tab, None, Some("o/cwd")).as_deref(),
+            Some("o/cwd")
+        );
+        assert_eq!(
+            preset_repo(&preset(Some("@current")), tab, None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn confirm_prompt_lists_ref_and_inputs() {
+        assert_eq!(
+            confirm_prompt(&preset(None), Some("o/app")),
+            "Run Deploy staging: deploy.yml on default branch in o/app (env=staging)? (y/n)"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! Overlay listing the saved comment drafts (`show_drafts`).
+//!
+//! Drafts outlive the composer they were typed in: a draft left behind on
+//! an item that no longer shows in any tab can still be copied, opened or
+//! discarded from here.
+
+use iocraft::prelude::*;
+
+use crate::actions::clipboard;
+use crate::color::ColorDepth;
+use crate::components::footer::ActionFeedback;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::drafts::{self, Draft};
+use crate::theme::{Chrome, ResolvedTheme};
+use crate::util::format_date;
+
+/// Handle a key while the overlay is open. Every key is consumed; returns
+/// the feedback of the action taken, if any.
+pub(crate) fn handle_key(
+    cursor: &mut State<Option<usize>>,
+    code: KeyCode,
+) -> Option<ActionFeedback> {
+    let current = cursor.get()?;
+    let list = drafts::list();
+    let draft = list.get(current);
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            cursor.set(None);
+            None
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            cursor.set(Some((current + 1).min(list.len().saturating_sub(1))));
+            None
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            cursor.set(Some(current.saturating_sub(1)));
+            None
+        }
+        KeyCode::Enter | KeyCode::Char('y') => {
+            draft.map(|d| match clipboard::copy_to_clipboard(&d.body) {
+                Ok(()) => ActionFeedback::Success(format!("Copied draft for {}", d.key)),
+                Err(e) => ActionFeedback::Error(format!("Copy failed: {e}")),
+            })
+        }
+        KeyCode::Char('o') => draft.map(|d| match clipboard::open_in_browser(&d.url) {
+            Ok(how) => ActionFeedback::Success(how.message(&d.key)),
+            Err(e) => ActionFeedback::Error(format!("Open failed: {e}")),
+        }),
+        KeyCode::Char('d') => draft.map(|d| {
+            drafts::discard(&d.key);
+            cursor.set(Some(current.min(list.len().saturating_sub(2))));
+            ActionFeedback::Info(format!("Discarded draft for {}", d.key))
+        }),
+        _ => None,
+    }
+}
+
+/// One line of `draft`: key, title, age and the start of its text.
+fn describe(draft: &Draft, chrome: Chrome) -> String {
+    let first_line = draft
+        .body
+        .lines()
+        .find(|l| !l.trim().is_empty())
+        .unwrap_or("");
+    let dot = crate::components::dot_separator(chrome);
+    format!(
+        "{} {}{dot}{}{dot}{}",
+        draft.key,
+        draft.title,
+        format_date(&draft.updated_at, "relative"),
+        first_line.trim()
+    )
+}
+
+/// Render the overlay, or `None` when it is closed.
+pub(crate) fn render(
+    cursor: Option<usize>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let cursor = cursor?;
+    let list = drafts::list();
+    let items = if list.is_empty() {
+        vec![SelectionOverlayItem {
+            label: "No saved drafts".to_owned(),
+        }]
+    } else {
+        list.iter()
+            .map(|d| SelectionOverlayItem {
+                label: describe(d, theme.chrome),
+            })
+            .collect()
+    };
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title: "Comment drafts".to_owned(),
+            items,
+            cursor,
+            show_filter: false,
+            filter_text: String::new(),
+            hint: Some("Enter copy  o open  d discard  Esc close".to_owned()),
+            depth,
+            title_color: Some(theme.text_primary),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: None,
+            filter_text_color: None,
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
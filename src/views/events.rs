@@ -0,0 +1,1018 @@
+//! Events view: recent member, repository and settings changes of the
+//! organizations in `[[events_filters]]`, and activity feeds.
+//!
+//! An `org` tab reads its organization's audit log when the token may, and
+//! the public events feed otherwise; the footer says which one is shown. A
+//! feed tab merges the events of its `repos` and, with `received`, those of
+//! the repositories the viewer watches, like GitHub's dashboard feed.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use iocraft::prelude::*;
+
+use crate::actions::clipboard;
+use crate::app::{NavigationTarget, ViewCounts, ViewKind, WatchBadges};
+use crate::color::ColorDepth;
+use crate::components::footer::{
+    self, ActionFeedback, Footer, FooterColors, FooterContent, RenderedFooter,
+};
+use crate::components::help_overlay::{HelpOverlay, HelpOverlayBuildConfig, RenderedHelpOverlay};
+use crate::components::tab_bar::{RenderedTabBar, Tab, TabBar, TabBarColors};
+use crate::components::table::{
+    Cell, Column, RenderedTable, Row, ScrollableTable, TableBuildConfig,
+};
+use crate::components::text_input::{RenderedTextInput, TextInput, TextInputColors};
+use crate::config::keybindings::{
+    BuiltinAction, MergedBindings, ResolvedBinding, TemplateVars, ViewContext,
+    execute_shell_command, expand_template, key_event_to_string,
+};
+use crate::config::types::{AppConfig, EventsFilter};
+use crate::engine::{EngineHandle, Event, FilterConfig, RefreshView, Request};
+use crate::filter;
+use crate::theme::{Chrome, ResolvedTheme};
+use crate::types::{EventItem, OrgEvent, OrgEventSource, RateLimits};
+
+// ---------------------------------------------------------------------------
+// Column definitions
+// ---------------------------------------------------------------------------
+
+fn event_columns() -> Vec<Column> {
+    vec![
+        Column {
+            id: "when".to_owned(),
+            header: "When".to_owned(),
+            default_width_pct: 0.10,
+            align: TextAlign::Right,
+            fixed_width: Some(8),
+            sort: None,
+        },
+        Column {
+            id: "actor".to_owned(),
+            header: "Actor".to_owned(),
+            default_width_pct: 0.20,
+            align: TextAlign::Left,
+            fixed_width: None,
+            sort: None,
+        },
+        Column {
+            id: "action".to_owned(),
+            header: "Action".to_owned(),
+            default_width_pct: 0.35,
+            align: TextAlign::Left,
+            fixed_width: None,
+            sort: None,
+        },
+        Column {
+            id: "target".to_owned(),
+            header: "Target".to_owned(),
+            default_width_pct: 0.35,
+            align: TextAlign::Left,
+            fixed_width: None,
+            sort: None,
+        },
+    ]
+}
+
+/// Convert an `OrgEvent` into a table `Row`.
+fn event_to_row(event: &OrgEvent, theme: &ResolvedTheme, date_format: &str) -> Row {
+    let mut row = HashMap::new();
+    let when = crate::util::format_date(&event.created_at, date_format);
+    row.insert("when".to_owned(), Cell::colored(when, theme.text_faint));
+    row.insert(
+        "actor".to_owned(),
+        Cell::colored(event.actor.clone().unwrap_or_default(), theme.text_actor),
+    );
+    // The category (`repo`, `org`, `team`...) carries most of the meaning.
+    let color = match event.action.split('.').next().unwrap_or_default() {
+        "org" | "member" | "membership" => theme.text_warning,
+        "repo" | "repository" | "create" | "delete" | "public" | "push" | "release" => {
+            theme.text_success
+        }
+        "team" | "watch" | "fork" => theme.text_secondary,
+        _ => theme.text_primary,
+    };
+    row.insert("action".to_owned(), Cell::colored(&event.action, color));
+    row.insert(
+        "target".to_owned(),
+        Cell::colored(
+            event.target.clone().unwrap_or_default(),
+            theme.text_secondary,
+        ),
+    );
+    row
+}
+
+/// Input modes for the events view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InputMode {
+    Normal,
+    Search,
+}
+
+// ---------------------------------------------------------------------------
+// Filter state
+// ---------------------------------------------------------------------------
+
+/// State for a single filter.
+#[derive(Debug, Clone)]
+struct FilterData {
+    rows: Vec<Row>,
+    events: Vec<OrgEvent>,
+    source: Option<OrgEventSource>,
+    loading: bool,
+    error: Option<String>,
+    /// Rate-limit cost of the last uncached fetch, for the footer.
+    refresh_cost: Option<u32>,
+}
+
+impl Default for FilterData {
+    fn default() -> Self {
+        Self {
+            rows: Vec::new(),
+            events: Vec::new(),
+            source: None,
+            loading: true,
+            error: None,
+            refresh_cost: None,
+        }
+    }
+}
+
+/// Shared state across all event filters.
+#[derive(Debug, Clone)]
+struct EventsState {
+    filters: Vec<Arc<FilterData>>,
+}
+
+/// Where `jump_to_pr` goes for an event about `item`.
+fn jump_target(item: &EventItem, host: Option<String>) -> Option<NavigationTarget> {
+    let (owner, repo) = item.repo.split_once('/')?;
+    let (owner, repo, number) = (owner.to_owned(), repo.to_owned(), item.number);
+    Some(if item.is_pr {
+        NavigationTarget::PullRequest {
+            owner,
+            repo,
+            number,
+            host,
+        }
+    } else {
+        NavigationTarget::Issue {
+            owner,
+            repo,
+            number,
+            host,
+        }
+    })
+}
+
+/// Footer scope of a tab: its organization, or what its feed follows.
+fn scope_label(filter: &EventsFilter, chrome: Chrome) -> String {
+    if let Some(org) = &filter.org {
+        return org.clone();
+    }
+    let mut parts: Vec<String> = Vec::new();
+    if filter.received {
+        parts.push("received".to_owned());
+    }
+    match &filter.repos[..] {
+        [] => {}
+        [repo] => parts.push(repo.clone()),
+        [repo, rest @ ..] => parts.push(format!("{repo} +{}", rest.len())),
+    }
+    parts.join(crate::components::dot_separator(chrome))
+}
+
+/// Mark tab `fi` as failed with `message`.
+fn fail_filter(
+    state: &mut State<EventsState>,
+    fetch_times: &mut State<Vec<Option<std::time::Instant>>>,
+    in_flight: &mut State<Vec<bool>>,
+    fi: usize,
+    message: String,
+) {
+    let mut snapshot = state.read().clone();
+    if let Some(slot) = snapshot.filters.get_mut(fi) {
+        *slot = Arc::new(FilterData {
+            loading: false,
+            error: Some(message),
+            ..FilterData::default()
+        });
+    }
+    state.set(snapshot);
+    let mut times = fetch_times.read().clone();
+    if fi < times.len() {
+        times[fi] = Some(std::time::Instant::now());
+    }
+    fetch_times.set(times);
+    super::common::set_in_flight(in_flight, fi, false);
+}
+
+// ---------------------------------------------------------------------------
+// EventsView component
+// ---------------------------------------------------------------------------
+
+#[derive(Default, Props)]
+pub struct EventsViewProps<'a> {
+    /// Config, replaced on each reload.
+    pub config: Option<Arc<AppConfig>>,
+    pub engine: Option<&'a EngineHandle>,
+    pub theme: Option<Arc<ResolvedTheme>>,
+    /// Merged keybindings for help overlay.
+    pub keybindings: Option<Arc<MergedBindings>>,
+    pub color_depth: ColorDepth,
+    pub width: u16,
+    pub height: u16,
+    pub show_filter_count: bool,
+    pub show_separator: bool,
+    pub should_exit: Option<State<bool>>,
+    pub switch_view: Option<State<bool>>,
+    /// Signal to switch to the previous view.
+    pub switch_view_back: Option<State<bool>>,
+    /// Signal to jump directly to a specific view.
+    pub goto_view: Option<State<Option<ViewKind>>>,
+    /// Signal to toggle relative/absolute dates.
+    pub date_toggle: Option<State<bool>>,
+    /// Signal to open the log viewer.
+    pub show_logs: Option<State<bool>>,
+    /// Whether this view is the currently active (visible) one.
+    pub is_active: bool,
+    /// Default auto-refresh interval, for the tab-bar countdown.
+    pub refetch_interval_minutes: u32,
+    /// Shared rate-limit state (owned by App).
+    pub rate_limit: Option<State<RateLimits>>,
+    /// Shared watched-item badges (owned by App).
+    pub watch_badges: Option<State<WatchBadges>>,
+    /// Shared footer counts of the views (owned by App).
+    pub view_counts: Option<State<ViewCounts>>,
+    /// Cross-view navigation target (owned by App), set by `jump_to_pr`.
+    pub nav_target: Option<State<Option<NavigationTarget>>>,
+}
+
+#[component]
+pub fn EventsView<'a>(props: &EventsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let filters_cfg = props
+        .config
+        .as_deref()
+        .map_or(&[][..], |c| &c.events_filters);
+    let theme = props
+        .theme
+        .as_deref()
+        .cloned()
+        .unwrap_or_else(super::default_theme);
+    let depth = props.color_depth;
+    let should_exit = props.should_exit;
+    let switch_view = props.switch_view;
+    let switch_view_back = props.switch_view_back;
+    let goto_view = props.goto_view;
+    let date_toggle = props.date_toggle;
+    let show_logs = props.show_logs;
+    let nav_target = props.nav_target;
+    let filter_count = filters_cfg.len();
+    let is_active = props.is_active;
+
+    let mut active_filter = hooks.use_state(|| 0usize);
+    let mut cursor = hooks.use_state(|| 0usize);
+    let mut scroll_offset = hooks.use_state(|| 0usize);
+    // Identity of the item under the cursor, followed across refreshes.
+    let mut cursor_anchor = hooks.use_state(super::common::CursorAnchor::default);
+
+    let mut input_mode = hooks.use_state(|| InputMode::Normal);
+    let mut search_query = hooks.use_state(String::new);
+    let mut help_visible = hooks.use_state(|| false);
+    let mut action_status = hooks.use_state(|| Option::<ActionFeedback>::None);
+    let mut status_set_at = hooks.use_state(|| Option::<std::time::Instant>::None);
+    let fallback_rl = hooks.use_state(RateLimits::default);
+    let mut rate_limit_state = props.rate_limit.unwrap_or(fallback_rl);
+
+    let mut refresh_registered = hooks.use_state(|| false);
+    let mut filter_fetch_times =
+        hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
+    // Per-filter auto-refresh postponement (`postpone_refresh`), for the countdown.
+    let mut refresh_postponed =
+        hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
+    let mut filter_in_flight = hooks.use_state(move || vec![false; filter_count]);
+    let mut refresh_all = hooks.use_state(|| false);
+
+    let initial_filters = super::common::empty_filters(filter_count);
+    let mut events_state = hooks.use_state(move || EventsState {
+        filters: initial_filters,
+    });
+
+    let event_channel = hooks.use_state(super::common::new_event_channel);
+    let (event_tx, event_rx) = event_channel.read().clone();
+    let engine: Option<crate::engine::EngineHandle> = props.engine.cloned();
+    let mut was_active = hooks.use_state(|| false);
+    super::common::cancel_fetches_on_leave(&mut was_active, is_active, engine.as_ref(), &event_tx);
+
+    // Track config reloads: keep to the tab shown and refetch every filter.
+    let mut last_filters = hooks.use_state(|| filters_cfg.to_vec());
+    if super::common::follow_reload(
+        &mut last_filters,
+        filters_cfg,
+        &mut active_filter,
+        &mut refresh_postponed,
+    ) {
+        events_state.set(EventsState {
+            filters: super::common::empty_filters(filter_count),
+        });
+        filter_fetch_times.set(vec![None; filter_count]);
+        filter_in_flight.set(vec![false; filter_count]);
+        refresh_registered.set(false);
+    }
+
+    // Rebuild the cached rows' date cells after the relative/absolute toggle.
+    let mut date_generation = hooks.use_state(crate::util::date_generation);
+    if date_generation.get() != crate::util::date_generation() {
+        date_generation.set(crate::util::date_generation());
+        let date_format = super::common::date_format(props.config.as_deref());
+        let mut state = events_state.read().clone();
+        for fd in &mut state.filters {
+            let fd = Arc::make_mut(fd);
+            fd.rows = fd
+                .events
+                .iter()
+                .map(|e| event_to_row(e, &theme, date_format))
+                .collect();
+        }
+        events_state.set(state);
+    }
+
+    let current_filter_idx = active_filter.get().min(filter_count.saturating_sub(1));
+    // Ephemeral tabs are not registered for auto-refresh.
+    let refresh_interval = filters_cfg.get(current_filter_idx).map(|f| {
+        super::common::refresh_interval(f.refetch_interval_minutes, props.refetch_interval_minutes)
+    });
+    let active_needs_fetch = events_state
+        .read()
+        .filters
+        .get(current_filter_idx)
+        .is_some_and(|s| s.loading);
+    let active_in_flight = filter_in_flight
+        .read()
+        .get(current_filter_idx)
+        .copied()
+        .unwrap_or(false);
+
+    if !refresh_registered.get()
+        && let Some(ref eng) = engine
+    {
+        eng.send(Request::RegisterRefresh {
+            configs: filters_cfg
+                .iter()
+                .cloned()
+                .map(FilterConfig::Event)
+                .collect(),
+            notify_tx: event_tx.clone(),
+        });
+        refresh_registered.set(true);
+    }
+
+    if refresh_all.get()
+        && is_active
+        && let Some(ref eng) = engine
+    {
+        refresh_all.set(false);
+        for (filter_idx, cfg) in filters_cfg.iter().enumerate() {
+            super::common::set_in_flight(&mut filter_in_flight, filter_idx, true);
+            eng.send(Request::FetchOrgEvents {
+                filter_idx,
+                filter: cfg.clone(),
+                reply_tx: event_tx.clone(),
+            });
+        }
+    } else if active_needs_fetch
+        && !active_in_flight
+        && is_active
+        && let Some(cfg) = filters_cfg.get(current_filter_idx)
+        && let Some(ref eng) = engine
+    {
+        super::common::set_in_flight(&mut filter_in_flight, current_filter_idx, true);
+        eng.send(Request::FetchOrgEvents {
+            filter_idx: current_filter_idx,
+            filter: cfg.clone(),
+            reply_tx: event_tx.clone(),
+        });
+    }
+
+    hooks.use_future(super::common::expire_action_status(
+        action_status,
+        status_set_at,
+    ));
+
+    // Handle engine events as they arrive and update local state.
+    {
+        let rx_for_poll = event_rx.clone();
+        let theme_for_poll = theme.clone();
+        let date_format_for_poll = super::common::date_format(props.config.as_deref()).to_owned();
+        hooks.use_future(async move {
+            while let Some(batch) = super::common::recv_batch(&rx_for_poll).await {
+                for evt in batch {
+                    match evt {
+                        Event::OrgEventsFetched {
+                            filter_idx,
+                            events,
+                            source,
+                            rate_limit,
+                        } => {
+                            let refresh_cost =
+                                rate_limit.as_ref().map(|rl| rl.cost).or_else(|| {
+                                    events_state
+                                        .read()
+                                        .filters
+                                        .get(filter_idx)
+                                        .and_then(|f| f.refresh_cost)
+                                });
+                            let rows = events
+                                .iter()
+                                .map(|e| event_to_row(e, &theme_for_poll, &date_format_for_poll))
+                                .collect();
+                            let mut state = events_state.read().clone();
+                            if let Some(slot) = state.filters.get_mut(filter_idx) {
+                                *slot = Arc::new(FilterData {
+                                    rows,
+                                    events,
+                                    source: Some(source),
+                                    loading: false,
+                                    error: None,
+                                    refresh_cost,
+                                });
+                            }
+                            events_state.set(state);
+                            let mut times = filter_fetch_times.read().clone();
+                            if filter_idx < times.len() {
+                                times[filter_idx] = Some(std::time::Instant::now());
+                            }
+                            filter_fetch_times.set(times);
+                            super::common::set_in_flight(&mut filter_in_flight, filter_idx, false);
+                            super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                        }
+                        Event::FetchError { message, .. } => {
+                            let fi = filter_in_flight.read().iter().position(|&f| f);
+                            if let Some(fi) = fi {
+                                fail_filter(
+                                    &mut events_state,
+                                    &mut filter_fetch_times,
+                                    &mut filter_in_flight,
+                                    fi,
+                                    message,
+                                );
+                            }
+                        }
+                        Event::FetchTimedOut { filter_idx, secs } => fail_filter(
+                            &mut events_state,
+                            &mut filter_fetch_times,
+                            &mut filter_in_flight,
+                            filter_idx,
+                            super::common::timed_out_message(secs),
+                        ),
+                        Event::FetchCancelled { filter_idx } => {
+                            super::common::set_in_flight(&mut filter_in_flight, filter_idx, false);
+                        }
+                        Event::RefreshBudgetExceeded { message } => {
+                            action_status.set(Some(ActionFeedback::Warning(message)));
+                            status_set_at.set(Some(std::time::Instant::now()));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        });
+    }
+
+    let state_ref = events_state.read();
+    let current_data = state_ref.filters.get(current_filter_idx);
+    let all_events: &[OrgEvent] = current_data.map_or(&[], |d| d.events.as_slice());
+    let search_q = search_query.read().clone();
+    let filtered_indices = filter::filter_org_events(all_events, &search_q);
+    let total_rows = filtered_indices.len();
+
+    // Keep the cursor on the same event when a refresh replaces the rows.
+    let fetched_at = filter_fetch_times
+        .read()
+        .get(current_filter_idx)
+        .copied()
+        .flatten();
+    super::common::anchor_cursor(
+        &mut cursor_anchor,
+        &mut cursor,
+        &mut scroll_offset,
+        current_filter_idx,
+        fetched_at,
+        &filtered_indices
+            .iter()
+            .filter_map(|&i| all_events.get(i))
+            .map(|event| (event.created_at, event.action.clone(), event.target.clone()))
+            .collect::<Vec<_>>(),
+    );
+
+    // Reserve space for tab bar (2 lines), footer (2 lines), header (1 line).
+    let visible_rows = (props.height.saturating_sub(5) / 2).max(1) as usize;
+
+    let selected = filtered_indices
+        .get(cursor.get())
+        .and_then(|&i| all_events.get(i))
+        .cloned();
+
+    let keybindings = props.keybindings.as_deref().cloned();
+    let current_host = filters_cfg
+        .get(current_filter_idx)
+        .and_then(|f| f.host.clone());
+    let chrome = theme.chrome;
+    hooks.use_terminal_events({
+        let engine_for_keys = engine.clone();
+        move |event| match event {
+            TerminalEvent::Key(KeyEvent {
+                code,
+                kind,
+                modifiers,
+                ..
+            }) if kind != KeyEventKind::Release => {
+                if !is_active {
+                    return;
+                }
+                if help_visible.get() {
+                    if matches!(code, KeyCode::Char('?') | KeyCode::Esc) {
+                        help_visible.set(false);
+                    }
+                    return;
+                }
+                let current_mode = input_mode.read().clone();
+                match current_mode {
+                    InputMode::Search => match code {
+                        KeyCode::Esc => {
+                            input_mode.set(InputMode::Normal);
+                            search_query.set(String::new());
+                        }
+                        KeyCode::Enter => input_mode.set(InputMode::Normal),
+                        KeyCode::Backspace => {
+                            let mut q = search_query.read().clone();
+                            q.pop();
+                            search_query.set(q);
+                            cursor.set(0);
+                            scroll_offset.set(0);
+                        }
+                        KeyCode::Char(ch) if !modifiers.contains(KeyModifiers::CONTROL) => {
+                            let mut q = search_query.read().clone();
+                            q.push(ch);
+                            search_query.set(q);
+                            cursor.set(0);
+                            scroll_offset.set(0);
+                        }
+                        _ => {}
+                    },
+                    InputMode::Normal => {
+                        let Some(key_str) = key_event_to_string(code, modifiers, kind) else {
+                            return;
+                        };
+                        let vars = TemplateVars {
+                            url: selected
+                                .as_ref()
+                                .map_or_else(String::new, |e| e.url.clone()),
+                            ..Default::default()
+                        };
+                        match keybindings
+                            .as_ref()
+                            .and_then(|kb| kb.resolve(&key_str, ViewContext::Events))
+                        {
+                            Some(ResolvedBinding::Builtin(action)) => match action {
+                                BuiltinAction::Quit => {
+                                    if let Some(mut exit) = should_exit {
+                                        exit.set(true);
+                                    }
+                                }
+                                BuiltinAction::SwitchView => {
+                                    if let Some(mut sv) = switch_view {
+                                        sv.set(true);
+                                    }
+                                }
+                                BuiltinAction::SwitchViewBack => {
+                                    if let Some(mut sv) = switch_view_back {
+                                        sv.set(true);
+                                    }
+                                }
+                                action if super::common::goto_target(action).is_some() => {
+                                    if let Some(mut gv) = goto_view {
+                                        gv.set(super::common::goto_target(action));
+                                    }
+                                }
+                                BuiltinAction::ShowLogs => {
+                                    if let Some(mut sl) = show_logs {
+                                        sl.set(true);
+                                    }
+                                }
+                                BuiltinAction::ToggleDates => {
+                                    if let Some(mut dt) = date_toggle {
+                                        dt.set(true);
+                                    }
+                                }
+                                BuiltinAction::AuthorizeSso => {
+                                    action_status.set(Some(super::common::authorize_sso()));
+                                    status_set_at.set(Some(std::time::Instant::now()));
+                                }
+                                BuiltinAction::PostponeRefresh => {
+                                    let fetched = filter_fetch_times
+                                        .read()
+                                        .get(current_filter_idx)
+                                        .copied()
+                                        .flatten();
+                                    action_status.set(Some(super::common::postpone_refresh(
+                                        engine_for_keys.as_ref(),
+                                        RefreshView::Events,
+                                        current_filter_idx,
+                                        fetched,
+                                        refresh_interval,
+                                        &mut refresh_postponed,
+                                    )));
+                                    status_set_at.set(Some(std::time::Instant::now()));
+                                }
+                                BuiltinAction::CopyNumber => {
+                                    if let Some(e) = &selected {
+                                        let _ = clipboard::copy_to_clipboard(&e.action);
+                                    }
+                                }
+                                BuiltinAction::CopyUrl => {
+                                    if let Some(e) = &selected
+                                        && !e.url.is_empty()
+                                    {
+                                        let _ = clipboard::copy_to_clipboard(&e.url);
+                                    }
+                                }
+                                BuiltinAction::OpenBrowser => {
+                                    if let Some(e) = &selected
+                                        && !e.url.is_empty()
+                                    {
+                                        let feedback = match clipboard::open_in_browser(&e.url) {
+                                            Ok(how) => {
+                                                ActionFeedback::Success(how.message(&e.action))
+                                            }
+                                            Err(err) => {
+                                                ActionFeedback::Error(format!("Open failed: {err}"))
+                                            }
+                                        };
+                                        action_status.set(Some(feedback));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                }
+                                BuiltinAction::JumpToPr => {
+                                    let target = selected
+                                        .as_ref()
+                                        .and_then(|e| e.item.as_ref())
+                                        .and_then(|item| jump_target(item, current_host.clone()));
+                                    match (target, nav_target) {
+                                        (Some(target), Some(mut nt)) => nt.set(Some(target)),
+                                        (None, _) if selected.is_some() => {
+                                            action_status.set(Some(ActionFeedback::Warning(
+                                                "Event is not about an issue or PR".to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                                BuiltinAction::RefreshItem | BuiltinAction::Refresh => {
+                                    let idx = current_filter_idx;
+                                    let mut state = events_state.read().clone();
+                                    if let Some(slot) = state.filters.get_mut(idx) {
+                                        *slot = Arc::new(FilterData::default());
+                                    }
+                                    events_state.set(state);
+                                    rate_limit_state.set(RateLimits::default());
+                                    cursor.set(0);
+                                    scroll_offset.set(0);
+                                }
+                                BuiltinAction::RefreshAll => {
+                                    events_state.set(EventsState {
+                                        filters: super::common::empty_filters(filter_count),
+                                    });
+                                    rate_limit_state.set(RateLimits::default());
+                                    cursor.set(0);
+                                    scroll_offset.set(0);
+                                    refresh_all.set(true);
+                                }
+                                BuiltinAction::Search => {
+                                    input_mode.set(InputMode::Search);
+                                    search_query.set(String::new());
+                                }
+                                BuiltinAction::MoveDown if total_rows > 0 => {
+                                    let new_cursor =
+                                        (cursor.get() + 1).min(total_rows.saturating_sub(1));
+                                    cursor.set(new_cursor);
+                                    if new_cursor >= scroll_offset.get() + visible_rows {
+                                        scroll_offset
+                                            .set(new_cursor.saturating_sub(visible_rows) + 1);
+                                    }
+                                }
+                                BuiltinAction::MoveUp => {
+                                    let new_cursor = cursor.get().saturating_sub(1);
+                                    cursor.set(new_cursor);
+                                    if new_cursor < scroll_offset.get() {
+                                        scroll_offset.set(new_cursor);
+                                    }
+                                }
+                                BuiltinAction::First => {
+                                    cursor.set(0);
+                                    scroll_offset.set(0);
+                                }
+                                BuiltinAction::Last if total_rows > 0 => {
+                                    cursor.set(total_rows.saturating_sub(1));
+                                    scroll_offset.set(total_rows.saturating_sub(visible_rows));
+                                }
+                                BuiltinAction::PageDown | BuiltinAction::HalfPageDown
+                                    if total_rows > 0 =>
+                                {
+                                    let step = if action == BuiltinAction::PageDown {
+                                        visible_rows
+                                    } else {
+                                        visible_rows / 2
+                                    };
+                                    let new_cursor =
+                                        (cursor.get() + step).min(total_rows.saturating_sub(1));
+                                    cursor.set(new_cursor);
+                                    if new_cursor >= scroll_offset.get() + visible_rows {
+                                        scroll_offset
+                                            .set(new_cursor.saturating_sub(visible_rows) + 1);
+                                    }
+                                }
+                                BuiltinAction::PageUp | BuiltinAction::HalfPageUp => {
+                                    let step = if action == BuiltinAction::PageUp {
+                                        visible_rows
+                                    } else {
+                                        visible_rows / 2
+                                    };
+                                    let new_cursor = cursor.get().saturating_sub(step);
+                                    cursor.set(new_cursor);
+                                    if new_cursor < scroll_offset.get() {
+                                        scroll_offset.set(new_cursor);
+                                    }
+                                }
+                                BuiltinAction::PrevFilter if filter_count > 0 => {
+                                    let current = active_filter.get();
+                                    active_filter.set(if current == 0 {
+                                        filter_count - 1
+                                    } else {
+                                        current - 1
+                                    });
+                                    cursor.set(0);
+                                    scroll_offset.set(0);
+                                }
+                                BuiltinAction::NextFilter if filter_count > 0 => {
+                                    active_filter.set((active_filter.get() + 1) % filter_count);
+                                    cursor.set(0);
+                                    scroll_offset.set(0);
+                                }
+                                BuiltinAction::ToggleHelp => help_visible.set(true),
+                                _ => {}
+                            },
+                            Some(ResolvedBinding::ShellCommand(cmd)) => {
+                                let expanded = expand_template(&cmd, &vars);
+                                let _ = execute_shell_command(&expanded);
+                            }
+                            Some(ResolvedBinding::Script(path)) => {
+                                if let Some(feedback) = crate::script::run(
+                                    &path,
+                                    "events",
+                                    &vars,
+                                    engine_for_keys.clone(),
+                                    chrome,
+                                ) {
+                                    action_status.set(Some(feedback));
+                                }
+                            }
+                            None => {}
+                        }
+                    }
+                }
+            }
+            TerminalEvent::FullscreenMouse(mouse_event) => {
+                if !is_active || help_visible.get() {
+                    return;
+                }
+                let delta = match mouse_event.kind {
+                    MouseEventKind::ScrollDown => super::common::MOUSE_SCROLL_LINES,
+                    MouseEventKind::ScrollUp => -super::common::MOUSE_SCROLL_LINES,
+                    _ => return,
+                };
+                super::common::mouse_scroll_table(
+                    scroll_offset,
+                    cursor,
+                    total_rows,
+                    visible_rows,
+                    delta,
+                );
+            }
+            _ => {}
+        }
+    });
+
+    // Skip heavy rendering for inactive views (all hooks above are unconditional).
+    if !is_active {
+        return element! {
+            View(flex_direction: FlexDirection::Column)
+        }
+        .into_any();
+    }
+
+    let tabs: Vec<Tab> = filters_cfg
+        .iter()
+        .enumerate()
+        .map(|(i, f)| Tab {
+            title: f.title.clone(),
+            count: state_ref
+                .filters
+                .get(i)
+                .filter(|d| !d.loading)
+                .map(|d| d.events.len()),
+            hidden: 0,
+            is_ephemeral: false,
+        })
+        .collect();
+
+    let columns = event_columns();
+    let all_rows: &[Row] = current_data.map_or(&[], |d| d.rows.as_slice());
+    let filtered_rows: Vec<Row> = filtered_indices
+        .iter()
+        .filter_map(|&i| all_rows.get(i).cloned())
+        .collect();
+    // A failed fetch shows its error in the footer, and how to retry here.
+    let failed_message = current_data
+        .is_some_and(|d| d.error.is_some())
+        .then(|| super::common::failed_tab_message(props.keybindings.as_deref()));
+    let empty_message = if filter_count == 0 {
+        "No [[events_filters]] configured"
+    } else if let Some(msg) = &failed_message {
+        msg.as_str()
+    } else if search_q.is_empty() {
+        "No recent events"
+    } else {
+        "No events match this filter"
+    };
+
+    let rendered_table = RenderedTable::build(&TableBuildConfig {
+        columns: &columns,
+        rows: &filtered_rows,
+        cursor: cursor.get(),
+        scroll_offset: scroll_offset.get(),
+        visible_rows,
+        hidden_columns: None,
+        width_overrides: None,
+        total_width: props.width,
+        depth,
+        selected_bg: Some(theme.bg_selected),
+        header_color: Some(theme.text_secondary),
+        border_color: Some(theme.border_faint),
+        show_separator: props.show_separator,
+        empty_message: Some(empty_message),
+        subtitle_column: None,
+        row_separator: true,
+        scrollbar_thumb_color: Some(theme.border_primary),
+        chrome: theme.chrome,
+        sort: None,
+    });
+
+    let tab_colors = TabBarColors {
+        active: Some(theme.footer_events),
+        inactive: Some(theme.footer_events),
+        border: Some(theme.border_faint),
+        chrome: theme.chrome,
+    };
+    let countdown = super::common::refresh_countdown(
+        filter_fetch_times
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        refresh_interval,
+        refresh_postponed
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        &theme.icons.tab_refresh,
+    );
+    let rendered_tab_bar = RenderedTabBar::build(
+        &tabs,
+        current_filter_idx,
+        props.show_filter_count,
+        depth,
+        &tab_colors,
+        &theme.icons.tab_filter,
+        &theme.icons.tab_ephemeral,
+    )
+    .with_countdown(countdown);
+
+    let rendered_text_input = (*input_mode.read() == InputMode::Search).then(|| {
+        RenderedTextInput::build(
+            "/",
+            &search_query.read(),
+            depth,
+            &TextInputColors {
+                text: Some(theme.text_primary),
+                prompt: Some(theme.text_secondary),
+                border: Some(theme.border_faint),
+                chrome: theme.chrome,
+                ..Default::default()
+            },
+        )
+    });
+
+    let context_text = if current_data.is_some_and(|d| d.loading) && filter_count > 0 {
+        "Fetching events...".to_owned()
+    } else if let Some(err) = current_data.and_then(|d| d.error.as_ref()) {
+        format!("Error: {err}")
+    } else {
+        let total = all_events.len();
+        let cursor_pos = if total_rows > 0 { cursor.get() + 1 } else { 0 };
+        let text = if search_q.is_empty() {
+            format!("Event {cursor_pos}/{total}")
+        } else {
+            format!("Event {cursor_pos}/{total_rows} (filtered from {total})")
+        };
+        let dot = crate::components::dot_separator(theme.chrome);
+        match current_data.and_then(|d| d.source) {
+            Some(OrgEventSource::AuditLog) => format!("{text}{dot}audit log"),
+            Some(OrgEventSource::Events) => format!("{text}{dot}public events"),
+            Some(OrgEventSource::Feed) => format!("{text}{dot}activity feed"),
+            None => text,
+        }
+    };
+    let active_fetch_time = filter_fetch_times
+        .read()
+        .get(current_filter_idx)
+        .copied()
+        .flatten();
+    let rate_limit_text = footer::format_rate_limit(
+        rate_limit_state.read().of(filters_cfg
+            .get(current_filter_idx)
+            .and_then(|f| f.host.as_deref())),
+        current_data.and_then(|d| d.refresh_cost),
+    );
+    let footer_colors = FooterColors {
+        view_colors: [
+            Some(theme.footer_prs),
+            Some(theme.footer_issues),
+            Some(theme.footer_actions),
+            Some(theme.footer_alerts),
+            Some(theme.footer_notifications),
+            Some(theme.footer_repo),
+            Some(theme.footer_events),
+        ],
+        inactive: Some(theme.text_faint),
+        text: Some(theme.text_faint),
+        border: Some(theme.border_faint),
+        chrome: theme.chrome,
+    };
+    let rendered_footer = RenderedFooter::build(
+        ViewKind::Events,
+        &theme.icons,
+        FooterContent {
+            scope_label: filters_cfg
+                .get(current_filter_idx)
+                .map_or_else(String::new, |f| scope_label(f, theme.chrome)),
+            context_text,
+            updated_text: footer::format_updated_ago(active_fetch_time),
+            rate_limit_text,
+            watch_badges: props.watch_badges.map(|b| b.get()).unwrap_or_default(),
+            view_counts: props.view_counts.map(|c| c.get()).unwrap_or_default(),
+        },
+        action_status.read().as_ref(),
+        &theme,
+        depth,
+        &footer_colors,
+    );
+
+    let rendered_help = if help_visible.get() {
+        props.keybindings.as_deref().map(|kb| {
+            RenderedHelpOverlay::build(&HelpOverlayBuildConfig {
+                bindings: kb,
+                context: ViewContext::Events,
+                depth,
+                title_color: Some(theme.text_primary),
+                key_color: Some(theme.text_success),
+                desc_color: Some(theme.text_secondary),
+                border_color: Some(theme.border_primary),
+                chrome: theme.chrome,
+            })
+        })
+    } else {
+        None
+    };
+
+    let width = u32::from(props.width);
+    let height = u32::from(props.height);
+
+    element! {
+        View(flex_direction: FlexDirection::Column, width, height) {
+            TabBar(tab_bar: rendered_tab_bar)
+
+            View(flex_grow: 1.0_f32, flex_direction: FlexDirection::Column, overflow: Overflow::Hidden) {
+                ScrollableTable(table: rendered_table)
+            }
+
+            TextInput(input: rendered_text_input)
+            Footer(footer: rendered_footer)
+            HelpOverlay(overlay: rendered_help, width: props.width, height: props.height)
+        }
+    }
+    .into_any()
+}
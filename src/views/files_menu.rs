@@ -0,0 +1,239 @@
+//! Viewed files menu of the PR view.
+//!
+//! `V` lists the selected PR's changed files, those still to review first;
+//! Space marks the file under the cursor viewed or unviewed, `a` all of
+//! them. Marks are kept locally and, with `github.sync_viewed_files`, also
+//! sent to GitHub.
+
+use iocraft::prelude::*;
+use unicode_width::UnicodeWidthStr;
+
+use crate::color::ColorDepth;
+use crate::components::footer::ActionFeedback;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::theme::ResolvedTheme;
+use crate::types::{File, FileViewedState};
+use crate::viewed_files;
+
+/// Files listed at once; the list scrolls past that.
+const VISIBLE_ROWS: usize = 20;
+
+/// Where marks are synced to GitHub: the engine, its reply channel and the
+/// PR's host (`None` for github.com).
+pub(crate) type SyncTo<'a> = (&'a EngineHandle, &'a EventSender, Option<&'a str>);
+
+/// An open viewed files menu.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FilesMenu {
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+    pub files: Vec<File>,
+    /// Review state of each of `files`.
+    pub states: Vec<FileViewedState>,
+    /// Indices of `files` in display order, fixed while the menu is open so
+    /// marking a file does not move it.
+    pub order: Vec<usize>,
+    pub cursor: usize,
+    /// First row shown.
+    pub offset: usize,
+}
+
+impl FilesMenu {
+    fn key(&self) -> String {
+        viewed_files::key(&format!("{}/{}", self.owner, self.repo), self.number)
+    }
+
+    fn viewed(&self) -> usize {
+        self.states
+            .iter()
+            .filter(|s| **s == FileViewedState::Viewed)
+            .count()
+    }
+}
+
+/// The menu for PR `owner/repo#number` changing `files`.
+pub(crate) fn open(
+    owner: &str,
+    repo: &str,
+    number: u64,
+    files: Vec<File>,
+) -> Result<FilesMenu, ActionFeedback> {
+    if files.is_empty() {
+        return Err(ActionFeedback::Info("No files changed".to_owned()));
+    }
+    let key = viewed_files::key(&format!("{owner}/{repo}"), number);
+    let states = viewed_files::states(&key, &files);
+    Ok(FilesMenu {
+        owner: owner.to_owned(),
+        repo: repo.to_owned(),
+        number,
+        order: viewed_files::review_order(&states),
+        files,
+        states,
+        cursor: 0,
+        offset: 0,
+    })
+}
+
+/// Mark the files at `indices` viewed (or unviewed), locally and, when
+/// `sync` is set, on GitHub.
+fn mark(menu: &mut FilesMenu, indices: &[usize], viewed: bool, sync: Option<SyncTo>) {
+    let files: Vec<&File> = indices.iter().map(|&i| &menu.files[i]).collect();
+    let key = menu.key();
+    viewed_files::set_viewed(&key, &files, viewed);
+    if let Some((engine, reply_tx, host)) = sync {
+        engine.send(Request::SetFilesViewed {
+            owner: menu.owner.clone(),
+            repo: menu.repo.clone(),
+            number: menu.number,
+            paths: files.iter().map(|f| f.path.clone()).collect(),
+            viewed,
+            host: host.map(str::to_owned),
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    menu.states = viewed_files::states(&key, &menu.files);
+}
+
+/// Mark file `idx` of PR `owner/repo#number` viewed, or unviewed when it
+/// is, as Space does in the menu; used by the focused Files tab.
+pub(crate) fn toggle_file(
+    (owner, repo, number): (&str, &str, u64),
+    files: Vec<File>,
+    idx: usize,
+    sync: Option<SyncTo>,
+) -> Option<ActionFeedback> {
+    let mut menu = open(owner, repo, number, files).ok()?;
+    let viewed = *menu.states.get(idx)? != FileViewedState::Viewed;
+    mark(&mut menu, &[idx], viewed, sync);
+    let path = &menu.files[idx].path;
+    Some(ActionFeedback::Success(if viewed {
+        format!("Viewed {path}")
+    } else {
+        format!("Unviewed {path}")
+    }))
+}
+
+/// Handle a key while the menu is open. Every key is consumed; returns
+/// the feedback of marking every file, if any.
+pub(crate) fn handle_key(
+    menu: &mut State<Option<FilesMenu>>,
+    code: KeyCode,
+    sync: Option<SyncTo>,
+) -> Option<ActionFeedback> {
+    let mut current = menu.read().clone()?;
+    let mut feedback = None;
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            menu.set(None);
+            return None;
+        }
+        KeyCode::Char(' ' | 'x') | KeyCode::Enter => {
+            let idx = *current.order.get(current.cursor)?;
+            let viewed = current.states[idx] != FileViewedState::Viewed;
+            mark(&mut current, &[idx], viewed, sync);
+            current.cursor = (current.cursor + 1).min(current.order.len() - 1);
+        }
+        KeyCode::Char('a') => {
+            let viewed = current.viewed() < current.files.len();
+            let all: Vec<usize> = (0..current.files.len()).collect();
+            mark(&mut current, &all, viewed, sync);
+            feedback = Some(ActionFeedback::Success(if viewed {
+                format!("All files of #{} viewed", current.number)
+            } else {
+                format!("No files of #{} viewed", current.number)
+            }));
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            current.cursor = (current.cursor + 1).min(current.order.len() - 1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            current.cursor = current.cursor.saturating_sub(1);
+        }
+        KeyCode::Char('g') | KeyCode::Home => current.cursor = 0,
+        KeyCode::Char('G') | KeyCode::End => current.cursor = current.order.len() - 1,
+        _ => return None,
+    }
+    if current.cursor < current.offset {
+        current.offset = current.cursor;
+    } else if current.cursor >= current.offset + VISIBLE_ROWS {
+        current.offset = current.cursor + 1 - VISIBLE_ROWS;
+    }
+    menu.set(Some(current));
+    feedback
+}
+
+/// Render the menu, or `None` when it is closed.
+pub(crate) fn render(
+    menu: &State<Option<FilesMenu>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let menu = menu.read();
+    let menu = menu.as_ref()?;
+    let marker = |state: FileViewedState| match state {
+        FileViewedState::Viewed => theme.icons.check_success.as_str(),
+        FileViewedState::Dismissed => theme.icons.check_pending.as_str(),
+        FileViewedState::Unviewed => "",
+    };
+    let marker_width = UnicodeWidthStr::width(marker(FileViewedState::Viewed))
+        .max(UnicodeWidthStr::width(marker(FileViewedState::Dismissed)));
+    let items = menu
+        .order
+        .iter()
+        .skip(menu.offset)
+        .take(VISIBLE_ROWS)
+        .map(|&i| {
+            let mark = marker(menu.states[i]);
+            let pad = marker_width.saturating_sub(UnicodeWidthStr::width(mark));
+            SelectionOverlayItem {
+                label: format!("{mark}{:pad$} {}", "", menu.files[i].path),
+            }
+        })
+        .collect();
+    let more = if menu.order.len() > VISIBLE_ROWS {
+        format!(
+            " ({}{}{} of {})",
+            menu.offset + 1,
+            if theme.chrome.ascii_only() {
+                "-"
+            } else {
+                "\u{2013}" // –
+            },
+            (menu.offset + VISIBLE_ROWS).min(menu.order.len()),
+            menu.order.len()
+        )
+    } else {
+        String::new()
+    };
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title: format!(
+                "#{} viewed {}/{}{more}",
+                menu.number,
+                menu.viewed(),
+                menu.files.len()
+            ),
+            items,
+            cursor: menu.cursor - menu.offset,
+            show_filter: false,
+            filter_text: String::new(),
+            hint: Some("Space mark  a all  Esc close".to_owned()),
+            depth,
+            title_color: Some(theme.text_primary),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: None,
+            filter_text_color: None,
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
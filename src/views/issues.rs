@@ -1,42 +1,66 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use iocraft::prelude::*;
 
 use crate::actions::clipboard;
-use crate::app::{NavigationTarget, ViewKind};
+use crate::app::{NavigationTarget, ViewCounts, ViewKind, WatchBadges};
 use crate::color::ColorDepth;
+use crate::components::board::{Board, BoardBuildConfig, BoardColumnInput, RenderedBoard};
+use crate::components::calendar::CalendarOverlay;
 use crate::components::footer::{
     self, ActionFeedback, Footer, FooterColors, FooterContent, RenderedFooter,
 };
 use crate::components::help_overlay::{HelpOverlay, HelpOverlayBuildConfig, RenderedHelpOverlay};
+use crate::components::selection_overlay::SelectionOverlay;
 use crate::components::sidebar::{
     RenderedSidebar, Sidebar, SidebarColors, SidebarMeta, SidebarTab, SidebarTabConfig,
 };
-use crate::components::sidebar_tabs;
 use crate::components::tab_bar::{RenderedTabBar, Tab, TabBar, TabBarColors};
 use crate::components::table::{
-    Cell, Column, RenderedTable, Row, ScrollableTable, Span, TableBuildConfig,
+    self as table, Cell, Column, RenderedTable, Row, ScrollableTable, SortDirection, SortValue,
+    Span, TableBuildConfig, TableClick, TableSort,
 };
 use crate::components::text_input::{self, RenderedTextInput, TextInput, TextInputColors};
+use crate::components::warning_banner::{RenderedWarningBanner, WarningBanner};
+use crate::components::{ellipsis, sidebar_tabs};
 use crate::config::keybindings::{
     BuiltinAction, MergedBindings, ResolvedBinding, TemplateVars, ViewContext,
     execute_shell_command, expand_template, key_event_to_string,
 };
-use crate::config::types::IssueFilter;
-use crate::engine::{EngineHandle, Event, FilterConfig, Request};
-use crate::filter::{self, apply_scope};
+use crate::config::types::{AppConfig, IssueFilter, IssueFilterKind};
+use crate::drafts::{self, DraftTarget};
+use crate::engine::{EngineHandle, Event, EventSender, RefreshView, Request};
+use crate::filter;
 use crate::icons::ResolvedIcons;
-use crate::markdown::renderer::{self, StyledLine, StyledSpan};
+use crate::markdown::cache::{self as md_cache, MarkdownCache};
+use crate::markdown::renderer::{StyledLine, StyledSpan};
 use crate::theme::ResolvedTheme;
-use crate::types::RateLimitInfo;
-use crate::types::{Issue, IssueDetail};
+use crate::types::RateLimits;
+use crate::types::{
+    Issue, IssueDetail, IssueLinks, IssueState, PrState, RepoRef, SavedReply, TimelineEvent,
+};
+use crate::util::LruCache;
 use crate::views::MAX_EPHEMERAL_TABS;
 
-/// Issue sidebar only shows Overview and Activity tabs.
-const ISSUE_TABS: &[SidebarTab] = &[SidebarTab::Overview, SidebarTab::Activity];
+use super::board::{self, BoardMove};
+use super::copy_menu::{self, CopyMenu};
+use super::keep_tab;
+use super::list::{self, FetchTracking, ListState};
+use super::notes;
+use super::profile::{self, ProfileOverlay};
+use super::share_menu::{self, ShareMenu};
+use super::sidebar_focus;
+
+/// Issue sidebar only shows Overview, Activity and Links tabs.
+const ISSUE_TABS: &[SidebarTab] = &[
+    SidebarTab::Overview,
+    SidebarTab::Activity,
+    SidebarTab::Links,
+];
 
 /// Pending detail fetch request: (owner, repo, number).
-type DetailRequest = Option<(String, String, u64)>;
+type DetailRequest = Option<(String, String, u64, Option<String>)>;
 
 // ---------------------------------------------------------------------------
 // Issue-specific column definitions (FR-021)
@@ -50,6 +74,7 @@ fn issue_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.03,
             align: TextAlign::Center,
             fixed_width: Some(3),
+            sort: None,
         },
         Column {
             id: "info".to_owned(),
@@ -57,6 +82,7 @@ fn issue_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.35,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "comments".to_owned(),
@@ -64,6 +90,7 @@ fn issue_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.04,
             align: TextAlign::Right,
             fixed_width: Some(4),
+            sort: Some(SortDirection::Descending),
         },
         Column {
             id: "reactions".to_owned(),
@@ -71,6 +98,7 @@ fn issue_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.05,
             align: TextAlign::Right,
             fixed_width: Some(6),
+            sort: Some(SortDirection::Descending),
         },
         Column {
             id: "assignees".to_owned(),
@@ -78,6 +106,15 @@ fn issue_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.12,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
+        },
+        Column {
+            id: "people".to_owned(),
+            header: "Who".to_owned(),
+            default_width_pct: 0.08,
+            align: TextAlign::Left,
+            fixed_width: Some(12),
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "updated".to_owned(),
@@ -85,6 +122,7 @@ fn issue_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.06,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: Some(SortDirection::Descending),
         },
         Column {
             id: "created".to_owned(),
@@ -92,26 +130,49 @@ fn issue_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.06,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: Some(SortDirection::Descending),
         },
     ]
 }
 
+/// The value `issue` is sorted by in `column`, for columns whose cells don't
+/// sort as text.
+fn issue_sort_value(issue: &Issue, column: &str) -> Option<SortValue> {
+    match column {
+        "info" => Some(SortValue::Text(issue.title.to_lowercase())),
+        "comments" => Some(SortValue::Number(i64::from(issue.comment_count))),
+        "reactions" => Some(SortValue::Number(i64::from(issue.reactions.total()))),
+        "updated" => Some(SortValue::Number(issue.updated_at.timestamp())),
+        "created" => Some(SortValue::Number(issue.created_at.timestamp())),
+        _ => None,
+    }
+}
+
+/// Columns left out unless a filter's `layout.shown` lists them.
+const OPTIONAL_ISSUE_COLUMNS: &[&str] = &["people"];
+
+/// Build the state indicator cell for an issue row.
+fn build_state_cell(issue: &Issue, theme: &ResolvedTheme) -> Cell {
+    let icons = &theme.icons;
+    let (state_icon, state_color) = match issue.pr_state {
+        // Pull requests listed by a mixed tab keep their PR icons.
+        Some(_) if issue.is_draft => (&icons.pr_draft, theme.text_faint),
+        Some(PrState::Open) => (&icons.pr_open, theme.text_success),
+        Some(PrState::Closed) => (&icons.pr_closed, theme.text_error),
+        Some(PrState::Merged) => (&icons.pr_merged, theme.text_actor),
+        None => match issue.state {
+            IssueState::Open => (&icons.issue_open, theme.text_success),
+            IssueState::Closed | IssueState::Unknown => (&icons.issue_closed, theme.text_actor),
+        },
+    };
+    Cell::colored(state_icon.clone(), state_color)
+}
+
 /// Convert an `Issue` into a table `Row`.
 fn issue_to_row(issue: &Issue, theme: &ResolvedTheme, date_format: &str) -> Row {
     let mut row = HashMap::new();
 
-    // State indicator
-    let icons = &theme.icons;
-    let (state_icon, state_color) = match issue.state {
-        crate::github::types::IssueState::Open => (&icons.issue_open, theme.text_success),
-        crate::github::types::IssueState::Closed | crate::github::types::IssueState::Unknown => {
-            (&icons.issue_closed, theme.text_actor)
-        }
-    };
-    row.insert(
-        "state".to_owned(),
-        Cell::colored(state_icon.clone(), state_color),
-    );
+    row.insert("state".to_owned(), build_state_cell(issue, theme));
 
     // Info line: repo/name #N by @author
     let repo_name = issue
@@ -188,12 +249,20 @@ fn issue_to_row(issue: &Issue, theme: &ResolvedTheme, date_format: &str) -> Row
         "assignees".to_owned(),
         Cell::colored(assignees_text, theme.text_faint),
     );
+    row.insert(
+        "people".to_owned(),
+        super::common::people_cell(issue.author.as_ref(), &issue.assignees, theme),
+    );
 
     // Updated
     let updated = crate::util::format_date(&issue.updated_at, date_format);
+    let is_open = issue.state == IssueState::Open;
     row.insert(
         "updated".to_owned(),
-        Cell::colored(updated, theme.text_faint),
+        Cell::colored(
+            updated,
+            super::common::updated_color(&issue.updated_at, is_open, theme),
+        ),
     );
 
     // Created
@@ -202,6 +271,7 @@ fn issue_to_row(issue: &Issue, theme: &ResolvedTheme, date_format: &str) -> Row
         "created".to_owned(),
         Cell::colored(created, theme.text_faint),
     );
+    super::common::tint_by_labels(&mut row, &issue.labels, theme);
 
     row
 }
@@ -214,57 +284,22 @@ fn issue_to_row(issue: &Issue, theme: &ResolvedTheme, date_format: &str) -> Row
 enum InputMode {
     Normal,
     Comment,
+    /// Picking a reply template to insert into the comment.
+    ReplyPicker,
     Label,
     Assign,
     Confirm(BuiltinAction),
     Search,
+    /// Typing the title an ephemeral tab is kept under.
+    KeepTab,
 }
 
 // ---------------------------------------------------------------------------
 // Filter state (T047)
 // ---------------------------------------------------------------------------
 
-/// State for a single filter.
-#[derive(Debug, Clone)]
-struct FilterData {
-    rows: Vec<Row>,
-    bodies: Vec<String>,
-    titles: Vec<String>,
-    issues: Vec<Issue>,
-    issue_count: usize,
-    loading: bool,
-    error: Option<String>,
-}
-
-impl Default for FilterData {
-    fn default() -> Self {
-        Self {
-            rows: Vec::new(),
-            bodies: Vec::new(),
-            titles: Vec::new(),
-            issues: Vec::new(),
-            issue_count: 0,
-            loading: true,
-            error: None,
-        }
-    }
-}
-
-/// Shared state across all issue filters.
-#[derive(Debug, Clone)]
-struct IssuesState {
-    filters: Vec<FilterData>,
-}
-
-/// Build a merged list of (filter, `is_ephemeral`) from config + ephemeral filters.
-fn merged_issue_filters<'a>(
-    config: &'a [IssueFilter],
-    ephemeral: &'a [(IssueFilter, Option<u64>)],
-) -> Vec<(&'a IssueFilter, bool)> {
-    let mut out: Vec<_> = config.iter().map(|f| (f, false)).collect();
-    out.extend(ephemeral.iter().map(|(f, _)| (f, true)));
-    out
-}
+type FilterData = list::FilterData<Issue>;
+type IssuesState = ListState<Issue>;
 
 // ---------------------------------------------------------------------------
 // IssuesView component (T047-T048, T086)
@@ -272,12 +307,13 @@ fn merged_issue_filters<'a>(
 
 #[derive(Default, Props)]
 pub struct IssuesViewProps<'a> {
-    pub filters: Option<&'a [IssueFilter]>,
+    /// Config, replaced on each reload.
+    pub config: Option<Arc<AppConfig>>,
     /// Engine handle.
     pub engine: Option<&'a EngineHandle>,
-    pub theme: Option<&'a ResolvedTheme>,
+    pub theme: Option<Arc<ResolvedTheme>>,
     /// Merged keybindings for help overlay.
-    pub keybindings: Option<&'a MergedBindings>,
+    pub keybindings: Option<Arc<MergedBindings>>,
     pub color_depth: ColorDepth,
     pub width: u16,
     pub height: u16,
@@ -293,34 +329,57 @@ pub struct IssuesViewProps<'a> {
     pub goto_view: Option<State<Option<ViewKind>>>,
     /// Signal to toggle repo scope.
     pub scope_toggle: Option<State<bool>>,
+    /// Signal to toggle relative/absolute dates.
+    pub date_toggle: Option<State<bool>>,
+    /// Signal to open the log viewer.
+    pub show_logs: Option<State<bool>>,
     /// Signal to open the repo picker overlay.
     pub repo_picker: Option<State<bool>>,
+    /// Signal to scope to the repo worked in.
+    pub rescan_repo: Option<State<bool>>,
     /// Active scope repo (e.g. `"owner/repo"`), or `None` for global.
     pub scope_repo: Option<String>,
-    pub date_format: Option<&'a str>,
     /// Whether this view is the currently active (visible) one.
     pub is_active: bool,
     /// Auto-refetch interval in minutes (0 = disabled).
     pub refetch_interval_minutes: u32,
+    /// Capacity of the per-view detail cache (from `defaults.detail_cache_size`).
+    pub detail_cache_size: usize,
     /// Navigation target state — set by App for deep-linking.
     pub nav_target: Option<State<Option<NavigationTarget>>>,
     /// Go-back signal — set to true to return to previous view.
     pub go_back: Option<State<bool>>,
     /// Shared rate-limit state (owned by App).
-    pub rate_limit: Option<State<Option<RateLimitInfo>>>,
+    pub rate_limit: Option<State<RateLimits>>,
+    /// Shared watched-item badges (owned by App).
+    pub watch_badges: Option<State<WatchBadges>>,
+    /// Shared footer counts of the views (owned by App).
+    pub view_counts: Option<State<ViewCounts>>,
+    /// Reply templates and saved replies offered by the comment composer.
+    pub reply_templates: Vec<SavedReply>,
 }
 
 #[component]
 pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
-    let filters_cfg = props.filters.unwrap_or(&[]);
-    let theme = props.theme.cloned().unwrap_or_else(default_theme);
+    let filters_cfg = props
+        .config
+        .as_deref()
+        .map_or(&[][..], |c| &c.issues_filters);
+    let theme = props
+        .theme
+        .as_deref()
+        .cloned()
+        .unwrap_or_else(default_theme);
     let depth = props.color_depth;
     let should_exit = props.should_exit;
     let switch_view = props.switch_view;
     let switch_view_back = props.switch_view_back;
     let goto_view = props.goto_view;
     let scope_toggle = props.scope_toggle;
+    let date_toggle = props.date_toggle;
+    let show_logs = props.show_logs;
     let repo_picker = props.repo_picker;
+    let rescan_repo = props.rescan_repo;
     let scope_repo = &props.scope_repo;
     let filter_count = filters_cfg.len();
     let is_active = props.is_active;
@@ -332,14 +391,34 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
     let mut active_filter = hooks.use_state(|| 0usize);
     let mut cursor = hooks.use_state(|| 0usize);
     let mut scroll_offset = hooks.use_state(|| 0usize);
+    // Identity of the item under the cursor, followed across refreshes.
+    let mut cursor_anchor = hooks.use_state(super::common::CursorAnchor::default);
     let mut preview_open = hooks.use_state(|| false);
     let mut preview_scroll = hooks.use_state(|| 0usize);
+    // Rendered Overview bodies; `md_ready` ticks when a background render lands.
+    let md_cache: MarkdownCache = hooks.use_const_default();
+    let md_ready = hooks.use_state(|| 0u64);
+    // Printing nothing makes the next render redraw the whole screen, which
+    // comes back blank from the note editor.
+    let (stdout, _) = hooks.use_output();
 
     // State: sidebar tab.
     let mut sidebar_tab = hooks.use_state(|| SidebarTab::Overview);
+    // Selected node of the Links tab: (issue number, index into its links);
+    // `link_jump` scrolls it into view on the next render.
+    let mut link_sel = hooks.use_state(|| Option::<(u64, usize)>::None);
+    let mut link_jump = hooks.use_state(|| false);
+    // Whether the sidebar has keyboard focus.
+    let mut sidebar_focused = hooks.use_state(|| false);
+    // Clicks reported by the tab bar and the table.
+    let tab_click = hooks.use_state(|| None::<usize>);
+    let row_click = hooks.use_state(|| None::<TableClick>);
+    // Tab whose `preview` / `preview_tab` settings were last applied.
+    let mut preview_applied = hooks.use_state(|| Option::<usize>::None);
 
     // State: cached issue detail data for sidebar tabs (HashMap cache + debounce).
-    let mut detail_cache = hooks.use_state(HashMap::<u64, IssueDetail>::new);
+    let detail_cache_size = props.detail_cache_size;
+    let mut detail_cache = hooks.use_state(|| LruCache::<u64, IssueDetail>::new(detail_cache_size));
     let mut pending_detail = hooks.use_state(|| DetailRequest::None);
     let mut debounce_gen = hooks.use_state(|| 0u64);
 
@@ -354,6 +433,19 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
     let mut assignee_candidates = hooks.use_state(Vec::<String>::new);
     let mut assignee_selection = hooks.use_state(|| 0usize);
     let mut assignee_selected = hooks.use_state(Vec::<String>::new);
+    // `@`/`#` autocomplete in the comment composer; mentions reuse
+    // `assignee_candidates`.
+    let mut issue_ref_candidates = hooks.use_state(Vec::<String>::new);
+    let mut completion_selection = hooks.use_state(|| 0usize);
+    // Filter typed in the reply template picker; the selection reuses
+    // `completion_selection`.
+    let mut reply_query = hooks.use_state(String::new);
+    // Item the open composer saves its draft for, and the cursor of the
+    // drafts overlay while it is open.
+    let mut draft_target = hooks.use_state(|| Option::<DraftTarget>::None);
+    let mut drafts_cursor = hooks.use_state(|| Option::<usize>::None);
+    // Spelling suggestions showing under the composer (`Ctrl+S`).
+    let mut spell_pick = hooks.use_state(|| Option::<super::common::SpellPick>::None);
 
     // When true, the next lazy fetch bypasses the moka cache (set by `r` key and MutationOk).
     let mut force_refresh = hooks.use_state(|| false);
@@ -363,8 +455,13 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
 
     // State: search query (T087).
     let mut search_query = hooks.use_state(String::new);
+    // Column the table is sorted by; API order when `None`.
+    let mut table_sort = hooks.use_state(|| Option::<TableSort>::None);
 
     let mut help_visible = hooks.use_state(|| false);
+    let mut profile_overlay = hooks.use_state(|| None::<ProfileOverlay>);
+    let mut copy_menu_state = hooks.use_state(|| None::<CopyMenu>);
+    let mut share_menu_state = hooks.use_state(|| None::<ShareMenu>);
 
     let go_back_prop = props.go_back;
 
@@ -372,220 +469,203 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
     let mut ephemeral_filters = hooks.use_state(Vec::<(IssueFilter, Option<u64>)>::new);
 
     // State: rate limit from last GraphQL response.
-    let fallback_rl = hooks.use_state(|| None);
+    let fallback_rl = hooks.use_state(RateLimits::default);
     let mut rate_limit_state = props.rate_limit.unwrap_or(fallback_rl);
 
     // State: per-filter fetch tracking (lazy: only fetch the active filter).
     let mut filter_fetch_times =
         hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
+    // Per-filter auto-refresh postponement (`postpone_refresh`), for the countdown.
+    let mut refresh_postponed =
+        hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
     let mut filter_in_flight = hooks.use_state(move || vec![false; filter_count]);
     // Set by 'R' keypress; consumed by render body to fetch all filters eagerly.
     let mut refresh_all = hooks.use_state(|| false);
 
-    let initial_filters = vec![FilterData::default(); filter_count];
-    let mut issues_state = hooks.use_state(move || IssuesState {
-        filters: initial_filters,
-    });
+    let mut issues_state = hooks.use_state(move || IssuesState::new(filter_count));
+
+    // Track config reloads: keep to the tab shown and refetch every filter.
+    let mut last_filters = hooks.use_state(|| filters_cfg.to_vec());
+    let filters_changed = super::common::follow_reload(
+        &mut last_filters,
+        filters_cfg,
+        &mut active_filter,
+        &mut refresh_postponed,
+    );
 
     // Track scope changes: when scope_repo changes, invalidate all filters.
     let mut last_scope = hooks.use_state(|| scope_repo.clone());
-    if *last_scope.read() != *scope_repo {
+    if filters_changed || *last_scope.read() != *scope_repo {
         last_scope.set(scope_repo.clone());
-        issues_state.set(IssuesState {
-            filters: vec![FilterData::default(); filter_count],
-        });
+        issues_state.set(IssuesState::new(filter_count));
         filter_fetch_times.set(vec![None; filter_count]);
         filter_in_flight.set(vec![false; filter_count]);
         refresh_registered.set(false);
     }
 
+    // Rebuild the cached rows' date cells after the relative/absolute toggle.
+    let mut date_generation = hooks.use_state(crate::util::date_generation);
+    if date_generation.get() != crate::util::date_generation() {
+        date_generation.set(crate::util::date_generation());
+        let date_format = super::common::date_format(props.config.as_deref());
+        let mut state = issues_state.read().clone();
+        for fd in &mut state.filters {
+            let fd = Arc::make_mut(fd);
+            fd.rows = fd
+                .items
+                .iter()
+                .map(|issue| issue_to_row(issue, &theme, date_format))
+                .collect();
+        }
+        issues_state.set(state);
+    }
+
     // Event channel: engine pushes events back to UI.
     let event_channel = hooks.use_state(super::common::new_event_channel);
-    let (event_tx, event_rx_arc) = event_channel.read().clone();
+    let (event_tx, event_rx) = event_channel.read().clone();
     // Clone the EngineHandle so it can be captured in 'static use_future closures.
     let engine: Option<EngineHandle> = props.engine.cloned();
+    let mut was_active = hooks.use_state(|| false);
+    super::common::cancel_fetches_on_leave(&mut was_active, is_active, engine.as_ref(), &event_tx);
     // Pre-clone for each consumer: debounce future, fetch trigger, keyboard handler.
     let engine_for_keyboard = engine.clone();
 
     // Debounce future: waits for cursor to settle, then sends FetchIssueDetail via engine.
     let engine_for_debounce = engine.clone();
     let event_tx_for_debounce = event_tx.clone();
-    hooks.use_future(async move {
-        let mut last_gen = 0u64;
-        let mut spawned_gen = 0u64;
-        loop {
-            smol::Timer::after(std::time::Duration::from_millis(300)).await;
-            let current_gen = debounce_gen.get();
-            if current_gen != last_gen {
-                // Generation changed during this cycle — not stable yet.
-                last_gen = current_gen;
-            } else if current_gen > 0 && current_gen != spawned_gen {
-                // Stable for one full cycle and not yet spawned — fetch now.
-                let req = pending_detail.read().clone();
-                if let Some((owner, repo, issue_number)) = req {
-                    spawned_gen = current_gen;
-                    if let Some(ref eng) = engine_for_debounce {
-                        eng.send(Request::FetchIssueDetail {
-                            owner: owner.clone(),
-                            repo: repo.clone(),
-                            number: issue_number,
-                            reply_tx: event_tx_for_debounce.clone(),
-                        });
-                    }
-                }
+    hooks.use_future(list::debounce_detail(
+        debounce_gen,
+        pending_detail,
+        move |(owner, repo, number, host)| {
+            if let Some(ref eng) = engine_for_debounce {
+                eng.send(Request::FetchIssueDetail {
+                    owner,
+                    repo,
+                    number,
+                    host,
+                    reply_tx: event_tx_for_debounce.clone(),
+                });
             }
-        }
-    });
+        },
+    ));
 
     // Compute active filter index early (needed by fetch logic below).
     let eph_snapshot = ephemeral_filters.read().clone();
     let ephemeral_count = eph_snapshot.len();
     let total_tab_count = filter_count + ephemeral_count;
-    let current_filter_idx = active_filter.get().min(total_tab_count.saturating_sub(1));
-    let all_filters = merged_issue_filters(filters_cfg, &eph_snapshot);
-
-    // Lazy fetch: only fetch the active filter when it needs data.
-    let active_needs_fetch = issues_state
-        .read()
-        .filters
-        .get(current_filter_idx)
-        .is_some_and(|s| s.loading);
-    let active_in_flight = filter_in_flight
-        .read()
-        .get(current_filter_idx)
-        .copied()
-        .unwrap_or(false);
-
-    // Register all filters for background refresh once at mount (or after scope change).
-    if !refresh_registered.get()
-        && let Some(ref eng) = engine
+    if let Some(tab) =
+        super::common::take_tab_click(tab_click, total_tab_count, active_filter.get())
     {
-        let scoped_configs: Vec<_> = filters_cfg
-            .iter()
-            .map(|cfg| {
-                let mut modified = cfg.clone();
-                modified.filters = apply_scope(&cfg.filters, scope_repo.as_deref());
-                modified
-            })
-            .collect();
-        eng.send(Request::RegisterRefresh {
-            configs: scoped_configs
-                .into_iter()
-                .map(FilterConfig::Issue)
-                .collect(),
-            notify_tx: event_tx.clone(),
-        });
-        refresh_registered.set(true);
+        active_filter.set(tab);
+        cursor.set(0);
+        scroll_offset.set(0);
+        preview_scroll.set(0);
     }
+    let current_filter_idx = active_filter.get().min(total_tab_count.saturating_sub(1));
+    // Ephemeral tabs are not registered for auto-refresh.
+    let refresh_interval = filters_cfg.get(current_filter_idx).map(|f| {
+        super::common::refresh_interval(f.refetch_interval_minutes, props.refetch_interval_minutes)
+    });
+    let all_filters = list::merged_filters(filters_cfg, &eph_snapshot);
 
-    if refresh_all.get()
-        && is_active
-        && let Some(ref engine_ref) = engine
-    {
-        // 'R' was pressed: reset the flag and eagerly fetch every filter.
-        refresh_all.set(false);
-        for (filter_idx, (cfg, _is_eph)) in all_filters.iter().enumerate() {
-            super::common::set_in_flight(&mut filter_in_flight, filter_idx, true);
-            let mut modified_filter = (*cfg).clone();
-            modified_filter.filters = apply_scope(&cfg.filters, scope_repo.as_deref());
-            engine_ref.send(Request::FetchIssues {
-                filter_idx,
-                filter: modified_filter,
-                force: true,
-                reply_tx: event_tx.clone(),
-            });
-        }
-    } else if active_needs_fetch
-        && !active_in_flight
-        && is_active
-        && let Some(ref engine_ref) = engine
-        && let Some((cfg, _is_eph)) = all_filters.get(current_filter_idx)
-    {
-        super::common::set_in_flight(&mut filter_in_flight, current_filter_idx, true);
-
-        let filter_idx = current_filter_idx;
-        let mut modified_filter = (*cfg).clone();
-        modified_filter.filters = apply_scope(&cfg.filters, scope_repo.as_deref());
-
-        // Consume the force flag: bypass cache for `r`-key and post-mutation fetches.
-        let force = force_refresh.get();
-        if force {
-            force_refresh.set(false);
-        }
+    list::apply_preview(
+        &mut preview_applied,
+        current_filter_idx,
+        all_filters.get(current_filter_idx).map(|(f, _)| *f),
+        ISSUE_TABS,
+        &mut preview_open,
+        &mut sidebar_tab,
+    );
 
-        engine_ref.send(Request::FetchIssues {
-            filter_idx,
-            filter: modified_filter,
-            force,
-            reply_tx: event_tx.clone(),
-        });
+    let badge_tabs = list::Fetches {
+        state: issues_state,
+        in_flight: filter_in_flight,
+        registered: refresh_registered,
+        refresh_all,
+        force: force_refresh,
+        engine: engine.as_ref(),
+        event_tx: &event_tx,
+        scope_repo: scope_repo.as_deref(),
+        is_active,
     }
+    .run(filters_cfg, &all_filters, current_filter_idx);
+    super::common::publish_view_count(
+        props.view_counts,
+        ViewKind::Issues,
+        list::badge_count(&issues_state.read(), &badge_tabs),
+    );
 
-    // Event polling: drain events from engine reply channel.
+    hooks.use_future(super::common::expire_action_status(
+        action_status,
+        status_set_at,
+    ));
+    let status = list::Status {
+        feedback: action_status,
+        set_at: status_set_at,
+    };
+
+    // Event handling: process engine replies as they arrive.
     {
-        let rx_for_poll = event_rx_arc.clone();
+        let rx_for_poll = event_rx.clone();
         let theme_for_poll = theme.clone();
-        let date_format_for_poll = props.date_format.unwrap_or("relative").to_owned();
+        let date_format_for_poll = super::common::date_format(props.config.as_deref()).to_owned();
+        let mut tracking = FetchTracking {
+            fetch_times: filter_fetch_times,
+            in_flight: filter_in_flight,
+        };
+        let mut shared = list::ListEvents {
+            state: issues_state,
+            tracking,
+            status,
+            profile: profile_overlay,
+            labels: label_candidates,
+            assignees: assignee_candidates,
+            issue_refs: issue_ref_candidates,
+        };
         hooks.use_future(async move {
-            loop {
-                smol::Timer::after(std::time::Duration::from_millis(100)).await;
-                // Auto-clear status after 60 seconds.
-                if let Some(t) = status_set_at.get()
-                    && t.elapsed().as_secs() >= 60
-                {
-                    action_status.set(None);
-                    status_set_at.set(None);
-                }
-
-                let events: Vec<Event> = {
-                    let rx = rx_for_poll.lock().unwrap();
-                    let mut evts = Vec::new();
-                    while let Ok(evt) = rx.try_recv() {
-                        evts.push(evt);
-                    }
-                    evts
-                };
+            while let Some(events) = super::common::recv_batch(&rx_for_poll).await {
                 for evt in events {
                     match evt {
                         Event::IssuesFetched {
                             filter_idx,
                             issues,
                             rate_limit,
+                            warnings,
+                            complete,
                         } => {
+                            if list::skip_streamed_page(&issues_state, filter_idx, complete) {
+                                continue;
+                            }
+                            let cost = rate_limit.as_ref().map(|rl| rl.cost);
                             super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
-                            let detail_snap = detail_cache.read().clone();
                             let rows: Vec<Row> = issues
                                 .iter()
                                 .map(|issue| {
                                     issue_to_row(issue, &theme_for_poll, &date_format_for_poll)
                                 })
                                 .collect();
-                            let bodies: Vec<String> =
-                                issues.iter().map(|i| i.body.clone()).collect();
-                            let titles: Vec<String> =
-                                issues.iter().map(|i| i.title.clone()).collect();
-                            let issue_count = issues.len();
-                            let filter_data = FilterData {
-                                rows,
-                                bodies,
-                                titles,
-                                issue_count,
-                                issues,
-                                loading: false,
-                                error: None,
-                            };
-                            let _ = detail_snap; // suppress unused warning
-                            let mut state = issues_state.read().clone();
-                            if filter_idx < state.filters.len() {
-                                state.filters[filter_idx] = filter_data;
-                            }
-                            issues_state.set(state);
-                            let mut times = filter_fetch_times.read().clone();
-                            if filter_idx < times.len() {
-                                times[filter_idx] = Some(std::time::Instant::now());
-                            }
-                            filter_fetch_times.set(times);
-                            super::common::set_in_flight(&mut filter_in_flight, filter_idx, false);
+                            let data = FilterData::loaded(issues, rows, warnings, complete);
+                            list::store_fetched(
+                                &mut issues_state,
+                                &mut tracking,
+                                filter_idx,
+                                data,
+                                cost,
+                            );
+                        }
+                        Event::IssuesCached {
+                            filter_idx,
+                            issues,
+                            saved_at,
+                        } => {
+                            let rows: Vec<Row> = issues
+                                .iter()
+                                .map(|issue| {
+                                    issue_to_row(issue, &theme_for_poll, &date_format_for_poll)
+                                })
+                                .collect();
+                            let data = FilterData::cached(issues, rows, saved_at);
+                            list::store_cached(&mut issues_state, filter_idx, data);
                         }
                         Event::IssueDetailFetched {
                             number,
@@ -606,14 +686,15 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                             super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
                             let mut state = issues_state.read().clone();
                             for fd in &mut state.filters {
-                                if let Some(idx) = fd.issues.iter().position(|i| i.number == number)
+                                if let Some(idx) = fd.items.iter().position(|i| i.number == number)
                                 {
+                                    let fd = Arc::make_mut(fd);
                                     fd.rows[idx] = issue_to_row(
                                         &issue,
                                         &theme_for_poll,
                                         &date_format_for_poll,
                                     );
-                                    fd.issues[idx] = (*issue).clone();
+                                    fd.items[idx] = (*issue).clone();
                                 }
                             }
                             issues_state.set(state);
@@ -621,57 +702,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                             cache.insert(number, detail);
                             detail_cache.set(cache);
                         }
-                        Event::FetchError {
-                            context: _,
-                            message,
-                        } => {
-                            let in_flight_snap = filter_in_flight.read().clone();
-                            let error_fi = in_flight_snap.iter().position(|&f| f);
-                            if let Some(fi) = error_fi {
-                                let mut state = issues_state.read().clone();
-                                if fi < state.filters.len() {
-                                    state.filters[fi] = FilterData {
-                                        loading: false,
-                                        error: Some(message.clone()),
-                                        ..FilterData::default()
-                                    };
-                                }
-                                issues_state.set(state);
-                                let mut times = filter_fetch_times.read().clone();
-                                if fi < times.len() {
-                                    times[fi] = Some(std::time::Instant::now());
-                                }
-                                filter_fetch_times.set(times);
-                                super::common::set_in_flight(&mut filter_in_flight, fi, false);
-                            }
-                        }
-                        Event::MutationOk { description } => {
-                            action_status.set(Some(ActionFeedback::Success(description)));
-                            status_set_at.set(Some(std::time::Instant::now()));
-                            // The engine sends an IssueRefreshed event right
-                            // after MutationOk, which will update the row in
-                            // place — no full table refresh needed.
-                        }
-                        Event::MutationError {
-                            description,
-                            message,
-                        } => {
-                            action_status.set(Some(ActionFeedback::Error(format!(
-                                "{description}: {message}"
-                            ))));
-                            status_set_at.set(Some(std::time::Instant::now()));
-                        }
-                        Event::RepoLabelsFetched { labels, .. } => {
-                            label_candidates.set(labels);
-                        }
-                        Event::RepoCollaboratorsFetched { logins, .. } => {
-                            let mut combined = assignee_candidates.read().clone();
-                            combined.extend(logins);
-                            combined.sort();
-                            combined.dedup();
-                            assignee_candidates.set(combined);
-                        }
-                        _ => {}
+                        other => shared.handle(other),
                     }
                 }
             }
@@ -683,6 +714,19 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
     // -----------------------------------------------------------------------
 
     let nav_target_prop = props.nav_target;
+    let mut nav = list::ListNav {
+        state: issues_state,
+        tracking: FetchTracking {
+            fetch_times: filter_fetch_times,
+            in_flight: filter_in_flight,
+        },
+        ephemeral: ephemeral_filters,
+        active_filter,
+        cursor,
+        scroll_offset,
+        preview_open,
+        preview_scroll,
+    };
 
     if is_active && let Some(ref nt_state) = nav_target_prop {
         let target = nt_state.read().clone();
@@ -700,7 +744,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                     if fd.loading {
                         return None;
                     }
-                    fd.issues
+                    fd.items
                         .iter()
                         .position(|i| {
                             i.number == number
@@ -715,6 +759,8 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
             if let Some((filter_idx, issue_pos)) = found {
                 // Issue found in an existing tab — switch to it.
                 active_filter.set(filter_idx);
+                // The position is in API order.
+                table_sort.set(None);
                 cursor.set(issue_pos);
                 scroll_offset.set(issue_pos.saturating_sub(5));
                 preview_open.set(true);
@@ -751,10 +797,11 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                 if fd.loading {
                                     return None;
                                 }
-                                fd.issues.iter().position(|i| i.number == number)
+                                fd.items.iter().position(|i| i.number == number)
                             })
                         };
                         if let Some(pos) = issue_in_tab {
+                            table_sort.set(None);
                             cursor.set(pos);
                             scroll_offset.set(pos.saturating_sub(5));
                             preview_open.set(true);
@@ -784,27 +831,14 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                             host: host.clone(),
                             limit: None,
                             layout: None,
+                            kind: IssueFilterKind::Issue,
+                            preview: None,
+                            preview_tab: None,
+                            refetch_interval_minutes: None,
+                            badge: None,
+                            board: None,
                         };
-                        let mut eph = ephemeral_filters.read().clone();
-                        eph.push((new_filter, Some(number)));
-                        let new_tab_idx = filter_count + eph.len() - 1;
-                        ephemeral_filters.set(eph);
-
-                        // Grow state vectors for the new tab.
-                        let mut state = issues_state.read().clone();
-                        state.filters.push(FilterData::default());
-                        issues_state.set(state);
-                        let mut in_flight = filter_in_flight.read().clone();
-                        in_flight.push(false);
-                        filter_in_flight.set(in_flight);
-                        let mut times = filter_fetch_times.read().clone();
-                        times.push(None);
-                        filter_fetch_times.set(times);
-
-                        active_filter.set(new_tab_idx);
-                        cursor.set(0);
-                        scroll_offset.set(0);
-                        preview_scroll.set(0);
+                        nav.open_tab(filter_count, new_filter, Some(number));
                         search_query.set(String::new());
                     } else {
                         action_status.set(Some(ActionFeedback::Warning(
@@ -830,10 +864,12 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                 let state = issues_state.read();
                 if let Some(fd) = state.filters.get(tab_idx)
                     && !fd.loading
+                    && !fd.loading_more
                 {
-                    let pos = fd.issues.iter().position(|i| i.number == *target_number);
+                    let pos = fd.items.iter().position(|i| i.number == *target_number);
                     if let Some(pos) = pos {
                         if active_filter.get() == tab_idx {
+                            table_sort.set(None);
                             cursor.set(pos);
                             scroll_offset.set(pos.saturating_sub(5));
                             preview_open.set(true);
@@ -855,28 +891,110 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
     }
 
     let state_ref = issues_state.read();
-    let all_rows_count = state_ref
-        .filters
+
+    // Kanban layout of the active tab, when configured.
+    let board_cfg = all_filters
+        .get(current_filter_idx)
+        .and_then(|(f, _)| f.board.clone());
+    let board_columns = board_cfg.as_ref().map(|b| {
+        let items = state_ref
+            .filters
+            .get(current_filter_idx)
+            .map_or(&[][..], |fd| &fd.items[..]);
+        board::columns(items, b)
+    });
+    let columns = issue_columns(&theme.icons);
+
+    let layout = filters_cfg
         .get(current_filter_idx)
-        .map_or(0, |s| s.rows.len());
+        .and_then(|s| s.layout.as_ref());
+    let hidden_set: HashSet<String> = OPTIONAL_ISSUE_COLUMNS
+        .iter()
+        .filter(|id| layout.is_none_or(|l| !l.shown.iter().any(|s| s == *id)))
+        .map(|id| (*id).to_owned())
+        .chain(layout.into_iter().flat_map(|l| l.hidden.iter().cloned()))
+        .collect();
+    let width_map: HashMap<String, u16> = layout.map(|l| l.widths.clone()).unwrap_or_default();
     let search_q = search_query.read().clone();
-    let total_rows = if search_q.is_empty() {
-        all_rows_count
-    } else {
+    // Table rows map to issues through these indices: the cursor is a
+    // position in the visible list, not in `items` (except on a board).
+    let current_sort = table_sort.read().clone();
+    let visible_indices: Vec<usize> =
         state_ref
             .filters
             .get(current_filter_idx)
-            .map_or(0, |s| filter::filter_rows(&s.rows, &search_q).len())
-    };
+            .map_or_else(Vec::new, |s| {
+                let mut indices = filter::filter_rows(&s.rows, &search_q);
+                table::sort_indices(&mut indices, &s.rows, current_sort.as_ref(), |i, column| {
+                    issue_sort_value(s.items.get(i)?, column)
+                });
+                indices
+            });
+    let total_rows = visible_indices.len();
+    // Sortable columns shown, in order, cycled through by `sort_column`.
+    let sort_columns: Vec<Column> = columns
+        .iter()
+        .filter(|c| c.sort.is_some() && !hidden_set.contains(&c.id))
+        .cloned()
+        .collect();
 
-    let visible_rows = (props.height.saturating_sub(5) / 3).max(1) as usize;
+    let rendered_banner = state_ref.filters.get(current_filter_idx).and_then(|s| {
+        RenderedWarningBanner::build(
+            &s.warnings,
+            &theme.icons.feedback_warning,
+            depth,
+            Some(theme.text_warning),
+        )
+    });
+    let banner_lines = u16::from(rendered_banner.is_some());
+
+    let visible_rows = (props.height.saturating_sub(5 + banner_lines) / 3).max(1) as usize;
+    super::common::take_table_click(
+        row_click,
+        total_rows,
+        super::common::ClickTargets {
+            cursor,
+            preview_scroll,
+            preview_open,
+            sidebar_focused,
+        },
+    );
 
     // Engine and event_tx clones for the keyboard handler closure.
     let engine = engine_for_keyboard;
     let event_tx_kb = event_tx.clone();
+    let board_cfg_kb = board_cfg.clone();
+    let board_columns_kb = board_columns.clone();
+    let filter_host_kb = all_filters
+        .get(current_filter_idx)
+        .and_then(|(f, _)| f.host.clone());
+    let mut overlays = list::ListOverlays {
+        help: help_visible,
+        profile: profile_overlay,
+        drafts: drafts_cursor,
+        copy_menu: copy_menu_state,
+        share_menu: share_menu_state,
+        status,
+    };
+    let signals = list::AppSignals {
+        should_exit,
+        switch_view,
+        switch_view_back,
+        goto_view,
+        scope_toggle,
+        show_logs,
+        date_toggle,
+        repo_picker,
+        rescan_repo,
+        preview_pct: preview_pct_state,
+        default_pct,
+    };
 
     // Keyboard handling.
-    let keybindings = props.keybindings.cloned();
+    let keybindings = props.keybindings.as_deref().cloned();
+    let reply_templates = props.reply_templates.clone();
+    let visible_for_keys = visible_indices.clone();
+    let chrome = theme.chrome;
     hooks.use_terminal_events({
         move |event| match event {
             TerminalEvent::Key(KeyEvent {
@@ -889,11 +1007,15 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                 if !is_active {
                     return;
                 }
-                // Help overlay: intercept all keys when visible.
-                if help_visible.get() {
-                    if matches!(code, KeyCode::Char('?') | KeyCode::Esc) {
-                        help_visible.set(false);
-                    }
+                // Index into the active filter's `items` of the issue under
+                // the cursor.
+                let selected = if board_columns_kb.is_some() {
+                    Some(cursor.get())
+                } else {
+                    visible_for_keys.get(cursor.get()).copied()
+                };
+                if overlays.handle_key(code, engine.as_ref(), filter_host_kb.clone(), &event_tx_kb)
+                {
                     return;
                 }
 
@@ -901,28 +1023,80 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                 let input_ctx = InputContext {
                     input_mode,
                     input_buffer,
+                    draft_target,
                     issues_state: &issues_state,
                     filter_idx: current_filter_idx,
-                    cursor: cursor.get(),
+                    item: selected,
                     engine: engine.as_ref(),
                     event_tx: &event_tx_kb,
                 };
                 match current_mode {
-                    InputMode::Comment => {
-                        handle_text_input(
+                    InputMode::Comment
+                        if super::common::handle_spell_input(
+                            code,
+                            modifiers,
+                            &mut spell_pick,
+                            input_buffer,
+                        ) => {}
+                    InputMode::Comment
+                        if super::common::handle_completion_input(
+                            code,
+                            &mut super::common::CompletionState {
+                                input_buffer,
+                                logins: assignee_candidates,
+                                refs: issue_ref_candidates,
+                                selection: completion_selection,
+                            },
+                        ) => {}
+                    // Insert a reply template with Ctrl+T.
+                    InputMode::Comment
+                        if code == KeyCode::Char('t')
+                            && modifiers.contains(KeyModifiers::CONTROL) =>
+                    {
+                        if reply_templates.is_empty() {
+                            action_status.set(Some(ActionFeedback::Info(
+                                "No saved replies or reply templates".to_owned(),
+                            )));
+                            status_set_at.set(Some(std::time::Instant::now()));
+                        } else {
+                            reply_query.set(String::new());
+                            completion_selection.set(0);
+                            input_mode.set(InputMode::ReplyPicker);
+                        }
+                    }
+                    InputMode::ReplyPicker => {
+                        let mut im = input_mode;
+                        let (fi, item) = (current_filter_idx, selected);
+                        super::common::handle_reply_picker_input(
                             code,
                             modifiers,
-                            &current_mode,
-                            &input_ctx,
-                            action_status,
+                            &mut super::common::ReplyPickerState {
+                                comment: input_buffer,
+                                query: reply_query,
+                                selection: completion_selection,
+                            },
+                            &reply_templates,
+                            || {
+                                list::with_item(&issues_state, fi, item, list::reply_vars)
+                                    .unwrap_or_default()
+                            },
+                            move || im.set(InputMode::Comment),
                         );
                     }
+                    InputMode::Comment => {
+                        if let Some(feedback) =
+                            handle_text_input(code, modifiers, &current_mode, &input_ctx)
+                        {
+                            action_status.set(Some(feedback));
+                            status_set_at.set(Some(std::time::Instant::now()));
+                        }
+                    }
                     InputMode::Assign => {
                         let mut im = input_ctx.input_mode;
                         let eng = input_ctx.engine.cloned();
                         let tx = input_ctx.event_tx.clone();
                         let fi = input_ctx.filter_idx;
-                        let cur = input_ctx.cursor;
+                        let item = input_ctx.item;
                         let is = *input_ctx.issues_state;
                         super::common::handle_multiselect_input(
                             code,
@@ -934,17 +1108,15 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                 selected: assignee_selected,
                             },
                             |logins| {
-                                if let Some((owner, repo, number)) =
-                                    get_current_issue_info(&is, fi, cur)
+                                let is_pr = item_is_pr(&is, fi, item);
+                                let host = list::item_host(&is, fi, item);
+                                if let Some((owner, repo, number)) = list::item_info(&is, fi, item)
                                     && let Some(eng) = eng
                                 {
-                                    eng.send(Request::SetIssueAssignees {
-                                        owner,
-                                        repo,
-                                        number,
-                                        logins,
-                                        reply_tx: tx,
-                                    });
+                                    eng.send(
+                                        Mutation::Assignees(logins)
+                                            .request(is_pr, owner, repo, number, host, tx),
+                                    );
                                 }
                             },
                             move || im.set(InputMode::Normal),
@@ -955,7 +1127,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                         let eng = input_ctx.engine.cloned();
                         let tx = input_ctx.event_tx.clone();
                         let fi = input_ctx.filter_idx;
-                        let cur = input_ctx.cursor;
+                        let item = input_ctx.item;
                         let is = *input_ctx.issues_state;
                         super::common::handle_multiselect_input(
                             code,
@@ -967,17 +1139,15 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                 selected: label_selected,
                             },
                             |labels| {
-                                if let Some((owner, repo, number)) =
-                                    get_current_issue_info(&is, fi, cur)
+                                let is_pr = item_is_pr(&is, fi, item);
+                                let host = list::item_host(&is, fi, item);
+                                if let Some((owner, repo, number)) = list::item_info(&is, fi, item)
                                     && let Some(eng) = eng
                                 {
-                                    eng.send(Request::SetIssueLabels {
-                                        owner,
-                                        repo,
-                                        number,
-                                        labels,
-                                        reply_tx: tx,
-                                    });
+                                    eng.send(
+                                        Mutation::Labels(labels)
+                                            .request(is_pr, owner, repo, number, host, tx),
+                                    );
                                 }
                             },
                             move || im.set(InputMode::Normal),
@@ -985,28 +1155,26 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                     }
                     InputMode::Confirm(ref pending) => match code {
                         KeyCode::Char('y' | 'Y') => {
-                            let info = get_current_issue_info(
-                                &issues_state,
-                                current_filter_idx,
-                                cursor.get(),
-                            );
+                            let info = list::item_info(&issues_state, current_filter_idx, selected);
                             if let Some((owner, repo, number)) = info
                                 && let Some(eng) = engine.as_ref()
                             {
-                                match pending {
-                                    BuiltinAction::Close => eng.send(Request::CloseIssue {
-                                        owner,
-                                        repo,
-                                        number,
-                                        reply_tx: event_tx_kb.clone(),
-                                    }),
-                                    BuiltinAction::Reopen => eng.send(Request::ReopenIssue {
+                                let mutation = match pending {
+                                    BuiltinAction::Close => Some(Mutation::Close),
+                                    BuiltinAction::Reopen => Some(Mutation::Reopen),
+                                    _ => None,
+                                };
+                                if let Some(mutation) = mutation {
+                                    let is_pr =
+                                        item_is_pr(&issues_state, current_filter_idx, selected);
+                                    eng.send(mutation.request(
+                                        is_pr,
                                         owner,
                                         repo,
                                         number,
-                                        reply_tx: event_tx_kb.clone(),
-                                    }),
-                                    _ => {}
+                                        filter_host_kb.clone(),
+                                        event_tx_kb.clone(),
+                                    ));
                                 }
                             }
                             input_mode.set(InputMode::Normal);
@@ -1018,39 +1186,58 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                         }
                         _ => {}
                     },
-                    InputMode::Search => match code {
-                        KeyCode::Esc => {
-                            input_mode.set(InputMode::Normal);
-                            search_query.set(String::new());
+                    InputMode::KeepTab => {
+                        match keep_tab::handle_key(code, modifiers, &mut input_buffer) {
+                            keep_tab::Prompt::Editing => {}
+                            keep_tab::Prompt::Cancelled => input_mode.set(InputMode::Normal),
+                            keep_tab::Prompt::Submitted(title) => {
+                                input_mode.set(InputMode::Normal);
+                                input_buffer.set(String::new());
+                                let mut eph = ephemeral_filters.read().clone();
+                                if let Some((filter, _)) = current_filter_idx
+                                    .checked_sub(filter_count)
+                                    .and_then(|ei| eph.get_mut(ei))
+                                {
+                                    let feedback = keep_tab::keep(
+                                        "issues_filters",
+                                        &keep_tab::issue_table(filter, &title),
+                                    );
+                                    if matches!(feedback, ActionFeedback::Success(_)) {
+                                        filter.title = title;
+                                        ephemeral_filters.set(eph);
+                                    }
+                                    action_status.set(Some(feedback));
+                                    status_set_at.set(Some(std::time::Instant::now()));
+                                }
+                            }
                         }
-                        KeyCode::Enter => {
+                    }
+                    InputMode::Search => {
+                        if list::search_key(
+                            code,
+                            modifiers,
+                            &mut search_query,
+                            &mut cursor,
+                            &mut scroll_offset,
+                        ) {
                             input_mode.set(InputMode::Normal);
                         }
-                        KeyCode::Backspace => {
-                            let mut q = search_query.read().clone();
-                            q.pop();
-                            search_query.set(q);
-                            cursor.set(0);
-                            scroll_offset.set(0);
-                        }
-                        KeyCode::Char(ch) if !modifiers.contains(KeyModifiers::CONTROL) => {
-                            let mut q = search_query.read().clone();
-                            q.push(ch);
-                            search_query.set(q);
-                            cursor.set(0);
-                            scroll_offset.set(0);
-                        }
-                        _ => {}
-                    },
+                    }
                     InputMode::Normal => {
                         let engine = engine.as_ref();
                         let event_tx = &event_tx_kb;
                         if let Some(key_str) = key_event_to_string(code, modifiers, kind) {
-                            let info = get_current_issue_info(
+                            let info = list::item_info(&issues_state, current_filter_idx, selected);
+                            let (title, state) = list::with_item(
                                 &issues_state,
                                 current_filter_idx,
-                                cursor.get(),
-                            );
+                                selected,
+                                |issue| {
+                                    let state = crate::share::issue_state(issue).to_owned();
+                                    (issue.title.clone(), state)
+                                },
+                            )
+                            .unwrap_or_default();
                             let vars = TemplateVars {
                                 url: info.as_ref().map_or_else(String::new, |(o, r, n)| {
                                     format!("https://github.com/{o}/{r}/issues/{n}")
@@ -1061,76 +1248,276 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                 repo_name: info
                                     .as_ref()
                                     .map_or_else(String::new, |(o, r, _)| format!("{o}/{r}")),
+                                title,
+                                state,
                                 ..Default::default()
                             };
                             match keybindings
                                 .as_ref()
-                                .and_then(|kb| kb.resolve(&key_str, ViewContext::Issues))
+                                .and_then(|kb| {
+                                    kb.resolve_focused(
+                                        &key_str,
+                                        ViewContext::Issues,
+                                        sidebar_focused.get(),
+                                    )
+                                })
+                                .map(|binding| super::common::refresh_fallback(binding, total_rows))
                             {
                                 Some(ResolvedBinding::Builtin(action)) => match action {
-                                    BuiltinAction::Quit => {
-                                        if let Some(mut exit) = should_exit {
-                                            exit.set(true);
+                                    BuiltinAction::ToggleFocus => {
+                                        let focused = !sidebar_focused.get();
+                                        sidebar_focused.set(focused);
+                                        if focused {
+                                            preview_open.set(true);
                                         }
                                     }
-                                    BuiltinAction::SwitchView => {
-                                        if let Some(mut sv) = switch_view {
-                                            sv.set(true);
+                                    BuiltinAction::MoveDown
+                                    | BuiltinAction::MoveUp
+                                    | BuiltinAction::First
+                                    | BuiltinAction::Last
+                                        if sidebar_focused.get() =>
+                                    {
+                                        // Only the Links tab has items; the others scroll.
+                                        let num = list::with_item(
+                                            &issues_state,
+                                            current_filter_idx,
+                                            selected,
+                                            |issue| issue.number,
+                                        );
+                                        let count = num
+                                            .filter(|_| sidebar_tab.get() == SidebarTab::Links)
+                                            .and_then(|n| {
+                                                detail_cache
+                                                    .read()
+                                                    .get(&n)
+                                                    .map(|d| d.links.nodes().count())
+                                            })
+                                            .unwrap_or(0);
+                                        let order: Vec<usize> = (0..count).collect();
+                                        let current = link_sel
+                                            .get()
+                                            .filter(|(n, _)| Some(*n) == num)
+                                            .map(|(_, i)| i);
+                                        match num.zip(sidebar_focus::step(&order, current, action))
+                                        {
+                                            Some((num, idx)) => {
+                                                link_sel.set(Some((num, idx)));
+                                                link_jump.set(true);
+                                            }
+                                            None => preview_scroll.set(sidebar_focus::scroll(
+                                                preview_scroll.get(),
+                                                action,
+                                            )),
                                         }
                                     }
-                                    BuiltinAction::SwitchViewBack => {
-                                        if let Some(mut sv) = switch_view_back {
-                                            sv.set(true);
-                                        }
+                                    BuiltinAction::CopyItem | BuiltinAction::ToggleItem => {
+                                        let num = list::with_item(
+                                            &issues_state,
+                                            current_filter_idx,
+                                            selected,
+                                            |issue| issue.number,
+                                        );
+                                        let link = num.and_then(|num| {
+                                            let (n, idx) =
+                                                link_sel.get().filter(|(n, _)| *n == num)?;
+                                            let cache = detail_cache.read();
+                                            cache.get(&n)?.links.nodes().nth(idx).cloned()
+                                        });
+                                        let feedback = match link {
+                                            None => ActionFeedback::Info(
+                                                "Select a linked issue first: tab, then j/k"
+                                                    .to_owned(),
+                                            ),
+                                            Some(_) if action == BuiltinAction::ToggleItem => {
+                                                ActionFeedback::Info(
+                                                    "Only files can be marked viewed".to_owned(),
+                                                )
+                                            }
+                                            Some(link) => {
+                                                let url = format!(
+                                                    "https://{}/{}/issues/{}",
+                                                    filter_host_kb
+                                                        .as_deref()
+                                                        .unwrap_or("github.com"),
+                                                    link.repo.full_name(),
+                                                    link.number
+                                                );
+                                                match clipboard::copy_to_clipboard(&url) {
+                                                    Ok(()) => ActionFeedback::Success(
+                                                        "Copied issue link".to_owned(),
+                                                    ),
+                                                    Err(e) => ActionFeedback::Error(format!(
+                                                        "Copy failed: {e}"
+                                                    )),
+                                                }
+                                            }
+                                        };
+                                        action_status.set(Some(feedback));
+                                        status_set_at.set(Some(std::time::Instant::now()));
                                     }
-                                    action if super::common::goto_target(action).is_some() => {
-                                        if let Some(mut gv) = goto_view {
-                                            gv.set(super::common::goto_target(action));
+                                    BuiltinAction::MoveDown
+                                    | BuiltinAction::MoveUp
+                                    | BuiltinAction::First
+                                    | BuiltinAction::Last
+                                    | BuiltinAction::BoardPrevColumn
+                                    | BuiltinAction::BoardNextColumn
+                                        if board_columns_kb.is_some() =>
+                                    {
+                                        if let Some(cols) = &board_columns_kb
+                                            && let Some(next) =
+                                                board::step(cols, cursor.get(), action)
+                                        {
+                                            cursor.set(next);
+                                            preview_scroll.set(0);
                                         }
                                     }
-                                    BuiltinAction::ToggleScope => {
-                                        if let Some(mut st) = scope_toggle {
-                                            st.set(true);
+                                    BuiltinAction::BoardMoveLeft
+                                    | BuiltinAction::BoardMoveRight => {
+                                        let moved = board_columns_kb
+                                            .as_ref()
+                                            .zip(board_cfg_kb.as_ref())
+                                            .and_then(|(cols, cfg)| {
+                                                let (col, _) = board::position(cols, cursor.get())?;
+                                                let target =
+                                                    if action == BuiltinAction::BoardMoveLeft {
+                                                        col.checked_sub(1)?
+                                                    } else {
+                                                        col + 1
+                                                    };
+                                                let to = cols.get(target)?;
+                                                list::with_item(
+                                                    &issues_state,
+                                                    current_filter_idx,
+                                                    selected,
+                                                    |issue| {
+                                                        (
+                                                            board::move_to(issue, cfg, to),
+                                                            issue.is_pull_request(),
+                                                            to.title.clone(),
+                                                        )
+                                                    },
+                                                )
+                                            });
+                                        if let Some((change, is_pr, column)) = moved
+                                            && let Some((owner, repo, number)) = info.clone()
+                                            && let Some(engine) = engine
+                                        {
+                                            engine.send(Mutation::from(change).request(
+                                                is_pr,
+                                                owner,
+                                                repo,
+                                                number,
+                                                filter_host_kb.clone(),
+                                                event_tx.clone(),
+                                            ));
+                                            action_status.set(Some(ActionFeedback::Info(format!(
+                                                "Moving #{number} to {column}{}",
+                                                ellipsis(chrome)
+                                            ))));
+                                            status_set_at.set(Some(std::time::Instant::now()));
                                         }
                                     }
-                                    BuiltinAction::SelectRepo => {
-                                        if let Some(mut rp) = repo_picker {
-                                            rp.set(true);
-                                        }
+                                    action if signals.handle(action) => {}
+                                    // A board orders its cards by column.
+                                    BuiltinAction::SortColumn | BuiltinAction::ReverseSort
+                                        if board_columns_kb.is_none() =>
+                                    {
+                                        let columns: Vec<&Column> = sort_columns.iter().collect();
+                                        let current = table_sort.read().clone();
+                                        table_sort.set(if action == BuiltinAction::SortColumn {
+                                            TableSort::next(current.as_ref(), &columns)
+                                        } else {
+                                            TableSort::reversed(current.as_ref(), &columns)
+                                        });
+                                        cursor.set(0);
+                                        scroll_offset.set(0);
+                                    }
+                                    BuiltinAction::AuthorizeSso => {
+                                        action_status.set(Some(super::common::authorize_sso()));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::PostponeRefresh => {
+                                        let fetched = filter_fetch_times
+                                            .read()
+                                            .get(current_filter_idx)
+                                            .copied()
+                                            .flatten();
+                                        action_status.set(Some(super::common::postpone_refresh(
+                                            engine,
+                                            RefreshView::Issues,
+                                            current_filter_idx,
+                                            fetched,
+                                            refresh_interval,
+                                            &mut refresh_postponed,
+                                        )));
+                                        status_set_at.set(Some(std::time::Instant::now()));
                                     }
                                     BuiltinAction::TogglePreview => {
                                         preview_open.set(!preview_open.get());
                                         preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::SidebarWider => {
-                                        if let Some(mut s) = preview_pct_state {
-                                            s.set((s.get() + 0.05).min(0.80));
-                                        }
-                                    }
-                                    BuiltinAction::SidebarNarrower => {
-                                        if let Some(mut s) = preview_pct_state {
-                                            s.set((s.get() - 0.05).max(0.15));
-                                        }
-                                    }
-                                    BuiltinAction::SidebarResetWidth => {
-                                        if let Some(mut s) = preview_pct_state {
-                                            s.set(default_pct);
-                                        }
+                                        sidebar_focused.set(false);
                                     }
                                     BuiltinAction::CommentAction => {
+                                        let target = list::draft_target(
+                                            &issues_state,
+                                            current_filter_idx,
+                                            selected,
+                                        );
+                                        let (text, restored) = match &target {
+                                            Some(target) => drafts::restore(target, None),
+                                            None => (String::new(), false),
+                                        };
                                         input_mode.set(InputMode::Comment);
-                                        input_buffer.set(String::new());
-                                        action_status.set(None);
+                                        input_buffer.set(text);
+                                        draft_target.set(target);
+                                        action_status.set(restored.then(|| {
+                                            ActionFeedback::Info("Restored saved draft".to_owned())
+                                        }));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                        completion_selection.set(0);
+                                        issue_ref_candidates.set(Vec::new());
+                                        let mentions = {
+                                            let state = issues_state.read();
+                                            let cache = detail_cache.read();
+                                            state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| f.items.get(selected?))
+                                                .map(|issue| {
+                                                    build_issue_mention_candidates(
+                                                        issue,
+                                                        cache.get(&issue.number),
+                                                    )
+                                                })
+                                                .unwrap_or_default()
+                                        };
+                                        assignee_candidates.set(mentions);
+                                        if let Some(engine) = engine
+                                            && let Some((owner, repo, _)) = &info
+                                        {
+                                            engine.send(Request::FetchRepoCollaborators {
+                                                owner: owner.clone(),
+                                                repo: repo.clone(),
+                                                host: filter_host_kb.clone(),
+                                                reply_tx: event_tx.clone(),
+                                            });
+                                            engine.send(Request::FetchOpenIssueRefs {
+                                                owner: owner.clone(),
+                                                repo: repo.clone(),
+                                                host: filter_host_kb.clone(),
+                                                reply_tx: event_tx.clone(),
+                                            });
+                                        }
                                     }
                                     BuiltinAction::LabelAction => {
                                         input_mode.set(InputMode::Label);
                                         input_buffer.set(String::new());
                                         label_selection.set(0);
                                         label_candidates.set(Vec::new());
-                                        let current_labels = get_current_issue_labels(
+                                        let current_labels = list::item_labels(
                                             &issues_state,
                                             current_filter_idx,
-                                            cursor.get(),
+                                            selected,
                                         );
                                         label_selected.set(current_labels);
                                         action_status.set(None);
@@ -1140,6 +1527,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                             engine.send(Request::FetchRepoLabels {
                                                 owner: owner.clone(),
                                                 repo: repo.clone(),
+                                                host: filter_host_kb.clone(),
                                                 reply_tx: event_tx.clone(),
                                             });
                                         }
@@ -1148,10 +1536,10 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                         input_mode.set(InputMode::Assign);
                                         input_buffer.set(String::new());
                                         assignee_selection.set(0);
-                                        let current = get_current_issue_assignees(
+                                        let current = list::item_assignees(
                                             &issues_state,
                                             current_filter_idx,
-                                            cursor.get(),
+                                            selected,
                                         );
                                         assignee_selected.set(current);
                                         let initial = {
@@ -1159,7 +1547,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                             state
                                                 .filters
                                                 .get(current_filter_idx)
-                                                .and_then(|f| f.issues.get(cursor.get()))
+                                                .and_then(|f| f.items.get(selected?))
                                                 .map(build_issue_assignee_candidates)
                                                 .unwrap_or_default()
                                         };
@@ -1171,6 +1559,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                             engine.send(Request::FetchRepoCollaborators {
                                                 owner: owner.clone(),
                                                 repo: repo.clone(),
+                                                host: filter_host_kb.clone(),
                                                 reply_tx: event_tx.clone(),
                                             });
                                         }
@@ -1231,17 +1620,51 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                             }
                                         }
                                     }
+                                    BuiltinAction::CopyAs => {
+                                        let menu = copy_menu::open(
+                                            info.is_some().then_some(&vars),
+                                            chrome,
+                                        );
+                                        if menu.is_none() {
+                                            action_status.set(Some(ActionFeedback::Info(
+                                                "Nothing to copy".to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                        copy_menu_state.set(menu);
+                                    }
+                                    BuiltinAction::Share => {
+                                        match share_menu::open(
+                                            info.is_some().then_some(&vars),
+                                            chrome,
+                                        ) {
+                                            Ok(menu) => share_menu_state.set(Some(menu)),
+                                            Err(feedback) => {
+                                                action_status.set(Some(feedback));
+                                                status_set_at.set(Some(std::time::Instant::now()));
+                                            }
+                                        }
+                                    }
+                                    BuiltinAction::EditNote => {
+                                        if let Some((owner, repo, number)) = info {
+                                            let feedback =
+                                                notes::edit(&format!("{owner}/{repo}"), number);
+                                            stdout.print("");
+                                            action_status.set(Some(feedback));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                    }
                                     BuiltinAction::OpenBrowser => {
                                         if let Some((owner, repo, number)) = info {
                                             let url = format!(
                                                 "https://github.com/{owner}/{repo}/issues/{number}"
                                             );
                                             match clipboard::open_in_browser(&url) {
-                                                Ok(()) => {
+                                                Ok(how) => {
                                                     action_status.set(Some(
-                                                        ActionFeedback::Success(format!(
-                                                            "Opened #{number}"
-                                                        )),
+                                                        ActionFeedback::Success(
+                                                            how.message(&format!("#{number}")),
+                                                        ),
                                                     ));
                                                     status_set_at
                                                         .set(Some(std::time::Instant::now()));
@@ -1257,16 +1680,17 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                         }
                                     }
                                     BuiltinAction::RefreshItem => {
-                                        if let Some((owner, repo, number)) = get_current_issue_info(
+                                        if let Some((owner, repo, number)) = list::item_info(
                                             &issues_state,
                                             active_filter.get(),
-                                            cursor.get(),
+                                            selected,
                                         ) && let Some(eng) = engine.as_ref()
                                         {
                                             eng.send(Request::RefreshIssue {
                                                 owner,
                                                 repo,
                                                 number,
+                                                host: filter_host_kb.clone(),
                                                 reply_tx: event_tx.clone(),
                                             });
                                         }
@@ -1276,7 +1700,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                         let idx = active_filter.get();
                                         let mut state = issues_state.read().clone();
                                         if idx < state.filters.len() {
-                                            state.filters[idx] = FilterData::default();
+                                            state.filters[idx] = Arc::new(FilterData::default());
                                         }
                                         issues_state.set(state);
                                         let mut times = filter_fetch_times.read().clone();
@@ -1285,23 +1709,23 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                         }
                                         filter_fetch_times.set(times);
                                         pending_detail.set(None);
-                                        detail_cache.set(HashMap::new());
-                                        rate_limit_state.set(None);
+                                        detail_cache.set(LruCache::new(detail_cache_size));
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                     }
                                     BuiltinAction::RefreshAll => {
                                         let mut state = issues_state.read().clone();
                                         for filter in &mut state.filters {
-                                            *filter = FilterData::default();
+                                            *filter = Arc::new(FilterData::default());
                                         }
                                         issues_state.set(state);
                                         let mut times = filter_fetch_times.read().clone();
                                         times.fill(None);
                                         filter_fetch_times.set(times);
                                         pending_detail.set(None);
-                                        detail_cache.set(HashMap::new());
-                                        rate_limit_state.set(None);
+                                        detail_cache.set(LruCache::new(detail_cache_size));
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                         refresh_all.set(true);
@@ -1310,138 +1734,28 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                         input_mode.set(InputMode::Search);
                                         action_status.set(None);
                                     }
-                                    BuiltinAction::MoveDown if total_rows > 0 => {
-                                        let new_cursor =
-                                            (cursor.get() + 1).min(total_rows.saturating_sub(1));
-                                        cursor.set(new_cursor);
-                                        if new_cursor >= scroll_offset.get() + visible_rows {
-                                            scroll_offset
-                                                .set(new_cursor.saturating_sub(visible_rows) + 1);
-                                        }
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::MoveUp => {
-                                        let new_cursor = cursor.get().saturating_sub(1);
-                                        cursor.set(new_cursor);
-                                        if new_cursor < scroll_offset.get() {
-                                            scroll_offset.set(new_cursor);
-                                        }
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::First => {
-                                        cursor.set(0);
-                                        scroll_offset.set(0);
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::Last if total_rows > 0 => {
-                                        cursor.set(total_rows.saturating_sub(1));
-                                        scroll_offset.set(total_rows.saturating_sub(visible_rows));
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::PageDown if total_rows > 0 => {
-                                        let new_cursor = (cursor.get() + visible_rows)
-                                            .min(total_rows.saturating_sub(1));
-                                        cursor.set(new_cursor);
-                                        scroll_offset.set(
-                                            new_cursor
-                                                .saturating_sub(visible_rows.saturating_sub(1)),
-                                        );
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::PageUp => {
-                                        let new_cursor = cursor.get().saturating_sub(visible_rows);
-                                        cursor.set(new_cursor);
-                                        scroll_offset
-                                            .set(scroll_offset.get().saturating_sub(visible_rows));
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::HalfPageDown => {
-                                        let half = visible_rows / 2;
-                                        if preview_open.get() {
-                                            preview_scroll.set(preview_scroll.get() + half);
-                                        } else if total_rows > 0 {
-                                            let new_cursor = (cursor.get() + half)
-                                                .min(total_rows.saturating_sub(1));
-                                            cursor.set(new_cursor);
-                                            if new_cursor >= scroll_offset.get() + visible_rows {
-                                                scroll_offset.set(
-                                                    new_cursor.saturating_sub(visible_rows) + 1,
-                                                );
-                                            }
-                                            preview_scroll.set(0);
-                                        }
-                                    }
-                                    BuiltinAction::HalfPageUp => {
-                                        let half = visible_rows / 2;
-                                        if preview_open.get() {
-                                            preview_scroll
-                                                .set(preview_scroll.get().saturating_sub(half));
-                                        } else {
-                                            let new_cursor = cursor.get().saturating_sub(half);
-                                            cursor.set(new_cursor);
-                                            if new_cursor < scroll_offset.get() {
-                                                scroll_offset.set(new_cursor);
-                                            }
-                                            preview_scroll.set(0);
-                                        }
-                                    }
-                                    BuiltinAction::PrevFilter if total_tab_count > 0 => {
-                                        let current = active_filter.get();
-                                        active_filter.set(if current == 0 {
-                                            total_tab_count.saturating_sub(1)
-                                        } else {
-                                            current - 1
-                                        });
-                                        cursor.set(0);
-                                        scroll_offset.set(0);
-                                        preview_scroll.set(0);
+                                    action if nav.navigate(action, total_rows, visible_rows) => {}
+                                    action if nav.switch_tab(action, total_tab_count) => {
                                         pending_detail.set(None);
                                     }
-                                    BuiltinAction::NextFilter if total_tab_count > 0 => {
-                                        active_filter
-                                            .set((active_filter.get() + 1) % total_tab_count);
-                                        cursor.set(0);
-                                        scroll_offset.set(0);
-                                        preview_scroll.set(0);
-                                        pending_detail.set(None);
+                                    BuiltinAction::CloseTab
+                                        if !nav.close_tab(filter_count, current_filter_idx) =>
+                                    {
+                                        action_status.set(Some(ActionFeedback::Warning(
+                                            "Cannot close config tabs".to_owned(),
+                                        )));
+                                        status_set_at.set(Some(std::time::Instant::now()));
                                     }
-                                    BuiltinAction::CloseTab => {
-                                        if current_filter_idx >= filter_count {
-                                            let ei = current_filter_idx - filter_count;
-                                            let mut eph = ephemeral_filters.read().clone();
-                                            debug_assert!(
-                                                ei < eph.len(),
-                                                "ephemeral index out of range"
+                                    BuiltinAction::KeepTab => {
+                                        let kept =
+                                            current_filter_idx.checked_sub(filter_count).and_then(
+                                                |ei| ephemeral_filters.read().get(ei).cloned(),
                                             );
-                                            eph.remove(ei);
-                                            let new_total = filter_count + eph.len();
-                                            ephemeral_filters.set(eph);
-
-                                            let mut state = issues_state.read().clone();
-                                            if current_filter_idx < state.filters.len() {
-                                                state.filters.remove(current_filter_idx);
-                                            }
-                                            issues_state.set(state);
-                                            let mut in_flight = filter_in_flight.read().clone();
-                                            if current_filter_idx < in_flight.len() {
-                                                in_flight.remove(current_filter_idx);
-                                            }
-                                            filter_in_flight.set(in_flight);
-                                            let mut times = filter_fetch_times.read().clone();
-                                            if current_filter_idx < times.len() {
-                                                times.remove(current_filter_idx);
-                                            }
-                                            filter_fetch_times.set(times);
-
-                                            if active_filter.get() >= new_total && new_total > 0 {
-                                                active_filter.set(new_total - 1);
-                                            }
-                                            cursor.set(0);
-                                            scroll_offset.set(0);
+                                        if let Some((filter, _)) = kept {
+                                            input_buffer.set(filter.title);
+                                            input_mode.set(InputMode::KeepTab);
                                         } else {
-                                            action_status.set(Some(ActionFeedback::Warning(
-                                                "Cannot close config tabs".to_owned(),
-                                            )));
+                                            action_status.set(Some(keep_tab::not_ephemeral()));
                                             status_set_at.set(Some(std::time::Instant::now()));
                                         }
                                     }
@@ -1454,12 +1768,72 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                     BuiltinAction::ToggleHelp => {
                                         help_visible.set(true);
                                     }
+                                    BuiltinAction::ShowDrafts => {
+                                        drafts_cursor.set(Some(0));
+                                    }
+                                    BuiltinAction::JumpToLinkedIssue | BuiltinAction::OpenItem => {
+                                        let num = list::with_item(
+                                            &issues_state,
+                                            current_filter_idx,
+                                            selected,
+                                            |issue| issue.number,
+                                        );
+                                        let link = num.and_then(|num| {
+                                            let (n, idx) =
+                                                link_sel.get().filter(|(n, _)| *n == num)?;
+                                            let cache = detail_cache.read();
+                                            cache.get(&n)?.links.nodes().nth(idx).cloned()
+                                        });
+                                        if let Some(link) = link {
+                                            if let Some(mut nt) = nav_target_prop {
+                                                nt.set(Some(NavigationTarget::Issue {
+                                                    owner: link.repo.owner,
+                                                    repo: link.repo.name,
+                                                    number: link.number,
+                                                    host: filter_host_kb.clone(),
+                                                }));
+                                            }
+                                        } else {
+                                            action_status.set(Some(ActionFeedback::Info(
+                                                "Select a linked issue with } or {, or tab then j/k"
+                                                    .to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                    }
+                                    BuiltinAction::ShowContributions => {
+                                        let author = list::with_item(
+                                            &issues_state,
+                                            current_filter_idx,
+                                            selected,
+                                            |issue| issue.author.as_ref().map(|a| a.login.clone()),
+                                        )
+                                        .flatten();
+                                        profile::open(
+                                            &mut profile_overlay,
+                                            engine,
+                                            filter_host_kb.clone(),
+                                            author,
+                                            &event_tx_kb,
+                                        );
+                                    }
                                     _ => {}
                                 },
                                 Some(ResolvedBinding::ShellCommand(cmd)) => {
                                     let expanded = expand_template(&cmd, &vars);
                                     let _ = execute_shell_command(&expanded);
                                 }
+                                Some(ResolvedBinding::Script(path)) => {
+                                    if let Some(feedback) = crate::script::run(
+                                        &path,
+                                        "issues",
+                                        &vars,
+                                        engine.cloned(),
+                                        chrome,
+                                    ) {
+                                        action_status.set(Some(feedback));
+                                    }
+                                }
                                 None => {
                                     if key_str == "]" {
                                         let current = sidebar_tab.get();
@@ -1483,6 +1857,38 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                                             }],
                                         );
                                         preview_scroll.set(0);
+                                    } else if key_str == "}" || key_str == "{" {
+                                        // Select the next/previous issue in the Links tab.
+                                        let num = list::with_item(
+                                            &issues_state,
+                                            current_filter_idx,
+                                            selected,
+                                            |issue| issue.number,
+                                        );
+                                        let count = num.and_then(|n| {
+                                            detail_cache
+                                                .read()
+                                                .get(&n)
+                                                .map(|d| d.links.nodes().count())
+                                        });
+                                        if let (Some(num), Some(count)) = (num, count)
+                                            && count > 0
+                                        {
+                                            let current = link_sel
+                                                .get()
+                                                .filter(|(n, _)| *n == num)
+                                                .map(|(_, i)| i);
+                                            let idx = match (current, key_str == "}") {
+                                                (None, true) => 0,
+                                                (None, false) => count - 1,
+                                                (Some(i), true) => (i + 1).min(count - 1),
+                                                (Some(i), false) => i.saturating_sub(1),
+                                            };
+                                            link_sel.set(Some((num, idx)));
+                                            link_jump.set(true);
+                                            sidebar_tab.set(SidebarTab::Links);
+                                            preview_open.set(true);
+                                        }
                                     }
                                 }
                             }
@@ -1491,7 +1897,12 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                 }
             }
             TerminalEvent::FullscreenMouse(mouse_event) => {
-                if !is_active || help_visible.get() {
+                if !is_active
+                    || help_visible.get()
+                    || profile_overlay.read().is_some()
+                    || copy_menu_state.read().is_some()
+                    || share_menu_state.read().is_some()
+                {
                     return;
                 }
                 let delta = match mouse_event.kind {
@@ -1535,21 +1946,13 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
         .enumerate()
         .map(|(i, (f, is_eph))| Tab {
             title: f.title.clone(),
-            count: state_ref.filters.get(i).map(|d| d.issue_count),
+            count: state_ref.filters.get(i).map(|d| d.count),
+            hidden: 0,
             is_ephemeral: *is_eph,
         })
         .collect();
 
     let current_data = state_ref.filters.get(current_filter_idx);
-    let columns = issue_columns(&theme.icons);
-
-    let layout = filters_cfg
-        .get(current_filter_idx)
-        .and_then(|s| s.layout.as_ref());
-    let hidden_set: HashSet<String> = layout
-        .map(|l| l.hidden.iter().cloned().collect())
-        .unwrap_or_default();
-    let width_map: HashMap<String, u16> = layout.map(|l| l.widths.clone()).unwrap_or_default();
 
     let is_preview_open = preview_open.get();
     let (table_width, sidebar_width) = if is_preview_open {
@@ -1562,12 +1965,42 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
     };
 
     let all_rows: &[Row] = current_data.map_or(&[], |d| d.rows.as_slice());
-    let filtered_indices = filter::filter_rows(all_rows, &search_q);
-    let filtered_rows: Vec<Row> = filtered_indices
+
+    // Keep the cursor on the same issue when a refresh replaces the rows.
+    let fetched_at = filter_fetch_times
+        .read()
+        .get(current_filter_idx)
+        .copied()
+        .flatten();
+    super::common::anchor_cursor(
+        &mut cursor_anchor,
+        &mut cursor,
+        &mut scroll_offset,
+        current_filter_idx,
+        fetched_at,
+        &visible_indices
+            .iter()
+            .filter_map(|&i| current_data?.items.get(i))
+            .map(|issue| (issue.repo.as_ref().map(RepoRef::full_name), issue.number))
+            .collect::<Vec<_>>(),
+    );
+    let filtered_rows: Vec<Row> = visible_indices
         .iter()
-        .filter_map(|&i| all_rows.get(i).cloned())
+        .filter_map(|&i| {
+            let mut row = all_rows.get(i).cloned()?;
+            if let Some(issue) = current_data.and_then(|d| d.items.get(i))
+                && let Some(repo) = &issue.repo
+            {
+                notes::mark_row(&mut row, &repo.full_name(), issue.number, &theme);
+            }
+            Some(row)
+        })
         .collect();
 
+    // A failed fetch shows its error in the footer, and how to retry here.
+    let failed_message = current_data
+        .is_some_and(|d| d.error.is_some())
+        .then(|| super::common::failed_tab_message(props.keybindings.as_deref()));
     let rendered_table = RenderedTable::build(&TableBuildConfig {
         columns: &columns,
         rows: &filtered_rows,
@@ -1582,7 +2015,9 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
         header_color: Some(theme.text_secondary),
         border_color: Some(theme.border_faint),
         show_separator: props.show_separator,
-        empty_message: if search_q.is_empty() {
+        empty_message: if let Some(msg) = &failed_message {
+            Some(msg.as_str())
+        } else if search_q.is_empty() {
             Some("No issues found")
         } else {
             Some("No issues match this filter")
@@ -1590,19 +2025,25 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
         subtitle_column: Some("subtitle"),
         row_separator: true,
         scrollbar_thumb_color: Some(theme.border_primary),
+        chrome: theme.chrome,
+        sort: current_sort.as_ref(),
     });
 
     // Request issue detail when sidebar is open and current issue is not cached.
+    let selected_issue = if board_columns.is_some() {
+        Some(cursor.get())
+    } else {
+        visible_indices.get(cursor.get()).copied()
+    };
     if is_preview_open {
-        let cursor_idx = cursor.get();
-        let current_issue = current_data.and_then(|d| d.issues.get(cursor_idx));
+        let current_issue = current_data.and_then(|d| d.items.get(selected_issue?));
         if let Some(issue) = current_issue {
             let issue_number = issue.number;
             let already_cached = detail_cache.read().contains_key(&issue_number);
             let already_pending = {
                 let guard = pending_detail.read();
                 match *guard {
-                    Some((_, _, n)) => n == issue_number,
+                    Some((_, _, n, _)) => n == issue_number,
                     None => false,
                 }
             };
@@ -1615,6 +2056,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                     repo_ref.owner.clone(),
                     repo_ref.name.clone(),
                     issue_number,
+                    crate::url::host_of(&issue.url),
                 )));
                 debounce_gen.set(debounce_gen.get() + 1);
             }
@@ -1623,38 +2065,68 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
 
     // Pre-render sidebar (preview pane with tabs).
     let rendered_sidebar = if is_preview_open {
-        let cursor_idx = cursor.get();
         let title = current_data
-            .and_then(|d| d.titles.get(cursor_idx))
+            .and_then(|d| d.titles.get(selected_issue?))
             .map_or("Preview", String::as_str);
 
         let current_tab = sidebar_tab.get();
-        let current_issue = current_data.and_then(|d| d.issues.get(cursor_idx));
+        let current_issue = current_data.and_then(|d| d.items.get(selected_issue?));
         let cache_ref = detail_cache.read();
         let detail_for_issue = current_issue.and_then(|i| cache_ref.get(&i.number));
 
-        let md_lines: Vec<StyledLine> = match current_tab {
+        let mut link_line = None;
+        let md_lines: Arc<Vec<StyledLine>> = match current_tab {
             SidebarTab::Overview => {
                 let body = current_data
-                    .and_then(|d| d.bodies.get(cursor_idx))
+                    .and_then(|d| d.bodies.get(selected_issue?))
                     .map_or("", String::as_str);
-                if body.is_empty() {
-                    Vec::new()
+                let body_lines = if body.is_empty() {
+                    Arc::default()
                 } else {
-                    renderer::render_markdown(body, &theme, depth)
+                    md_cache
+                        .get_or_render(body, &theme, depth, md_ready)
+                        .unwrap_or_else(|| Arc::new(vec![md_cache::pending_line(&theme)]))
+                };
+                match current_issue
+                    .and_then(|item| Some((item.repo.as_ref()?.full_name(), item.number)))
+                {
+                    Some((repo, number)) => notes::overview(
+                        (&repo, number),
+                        body_lines,
+                        &md_cache,
+                        &theme,
+                        (depth, md_ready),
+                    ),
+                    None => body_lines,
                 }
             }
-            SidebarTab::Activity => {
-                if let Some(detail) = detail_for_issue {
-                    sidebar_tabs::render_issue_activity(detail, &theme, depth)
+            SidebarTab::Activity => Arc::new(if let Some(detail) = detail_for_issue {
+                sidebar_tabs::render_issue_activity(detail, &theme, depth)
+            } else {
+                vec![StyledLine::from_span(StyledSpan::text(
+                    "Loading...",
+                    theme.text_faint,
+                ))]
+            }),
+            SidebarTab::Links => Arc::new(
+                if let (Some(issue), Some(detail)) = (current_issue, detail_for_issue) {
+                    let selected = link_sel
+                        .get()
+                        .filter(|(n, _)| *n == issue.number)
+                        .map(|(_, i)| i);
+                    let links = with_loaded_states(&detail.links, &issues_state.read());
+                    let (lines, selected_line) =
+                        sidebar_tabs::render_issue_links(issue, &links, selected, &theme);
+                    link_line = selected_line;
+                    lines
                 } else {
                     vec![StyledLine::from_span(StyledSpan::text(
                         "Loading...",
                         theme.text_faint,
                     ))]
-                }
-            }
-            _ => Vec::new(),
+                },
+            ),
+            _ => Arc::default(),
         };
 
         // Build meta header for Overview tab.
@@ -1668,13 +2140,29 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
         #[expect(clippy::cast_possible_truncation)]
         let meta_lines = sidebar_meta.as_ref().map_or(0, SidebarMeta::line_count) as u16;
         let sidebar_visible_lines = props.height.saturating_sub(8 + meta_lines) as usize;
+        if link_jump.get()
+            && let Some(line) = link_line
+        {
+            link_jump.set(false);
+            // Focused, the selection moves a line at a time: keep it in view.
+            preview_scroll.set(if sidebar_focused.get() {
+                sidebar_focus::follow(preview_scroll.get(), line, sidebar_visible_lines)
+            } else {
+                line
+            });
+        }
 
         let sidebar_colors = SidebarColors {
             title: Some(theme.text_primary),
-            border: Some(theme.border_faint),
+            border: Some(if sidebar_focused.get() {
+                theme.border_primary
+            } else {
+                theme.border_faint
+            }),
             indicator: Some(theme.text_faint),
             thumb: Some(theme.border_primary),
             depth,
+            chrome: theme.chrome,
         };
         let sidebar = RenderedSidebar::build_tabbed(
             title,
@@ -1703,7 +2191,22 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
         active: Some(theme.footer_issues),
         inactive: Some(theme.footer_issues),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
+    let countdown = super::common::refresh_countdown(
+        filter_fetch_times
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        refresh_interval,
+        refresh_postponed
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        &theme.icons.tab_refresh,
+    );
     let rendered_tab_bar = RenderedTabBar::build(
         &tabs,
         current_filter_idx,
@@ -1712,22 +2215,80 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
         &tab_colors,
         &theme.icons.tab_filter,
         &theme.icons.tab_ephemeral,
-    );
+    )
+    .with_countdown(countdown);
 
     // Build text input widget.
     let current_mode = input_mode.read().clone();
+    if matches!(current_mode, InputMode::Comment | InputMode::ReplyPicker)
+        && let Some(target) = draft_target.read().as_ref()
+    {
+        drafts::save(target, &input_buffer.read());
+    }
     let rendered_text_input = match &current_mode {
-        InputMode::Comment => Some(RenderedTextInput::build(
-            "Comment:",
-            &input_buffer.read(),
-            depth,
-            &TextInputColors {
-                text: Some(theme.text_primary),
-                prompt: Some(theme.text_secondary),
-                border: Some(theme.border_faint),
-                ..Default::default()
-            },
-        )),
+        InputMode::Comment => {
+            let buf = input_buffer.read().clone();
+            let (prompt, suggestions, selected_idx) = if let Some(pick) = spell_pick.read().as_ref()
+            {
+                (pick.prompt(), pick.suggestions.clone(), pick.selected())
+            } else {
+                let suggestions = crate::components::text_input::completion_suggestions(
+                    &buf,
+                    &assignee_candidates.read(),
+                    &issue_ref_candidates.read(),
+                );
+                let selected_idx = (!suggestions.is_empty())
+                    .then(|| completion_selection.get().min(suggestions.len() - 1));
+                ("Comment:".to_owned(), suggestions, selected_idx)
+            };
+            Some(
+                RenderedTextInput::build_with_suggestions(
+                    &prompt,
+                    &buf,
+                    depth,
+                    &TextInputColors {
+                        text: Some(theme.text_primary),
+                        prompt: Some(theme.text_secondary),
+                        border: Some(theme.border_faint),
+                        highlight: Some(theme.text_primary),
+                        highlight_bg: Some(theme.bg_selected),
+                        suggestion: Some(theme.text_faint),
+                        chrome: theme.chrome,
+                    },
+                    &suggestions,
+                    selected_idx,
+                )
+                .with_misspellings(
+                    crate::spell::misspellings(&buf),
+                    theme.text_error.to_crossterm_color(depth),
+                ),
+            )
+        }
+        InputMode::ReplyPicker => {
+            let query = reply_query.read().clone();
+            let titles: Vec<String> = super::common::reply_matches(&props.reply_templates, &query)
+                .into_iter()
+                .map(|r| r.title.clone())
+                .collect();
+            let selected_idx =
+                (!titles.is_empty()).then(|| completion_selection.get().min(titles.len() - 1));
+            Some(RenderedTextInput::build_with_suggestions(
+                "Insert template (Enter to insert, Esc to go back):",
+                &query,
+                depth,
+                &TextInputColors {
+                    text: Some(theme.text_primary),
+                    prompt: Some(theme.text_secondary),
+                    border: Some(theme.border_faint),
+                    highlight: Some(theme.text_primary),
+                    highlight_bg: Some(theme.bg_selected),
+                    suggestion: Some(theme.text_faint),
+                    chrome: theme.chrome,
+                },
+                &titles,
+                selected_idx,
+            ))
+        }
         InputMode::Assign => {
             let buf = input_buffer.read().clone();
             let candidates = assignee_candidates.read();
@@ -1755,6 +2316,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                     highlight: Some(theme.text_primary),
                     highlight_bg: Some(theme.bg_selected),
                     suggestion: Some(theme.text_faint),
+                    chrome: theme.chrome,
                 },
                 &filtered,
                 selected_idx,
@@ -1788,6 +2350,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                     highlight: Some(theme.text_primary),
                     highlight_bg: Some(theme.bg_selected),
                     suggestion: Some(theme.text_faint),
+                    chrome: theme.chrome,
                 },
                 &filtered,
                 selected_idx,
@@ -1808,10 +2371,23 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                     text: Some(theme.text_primary),
                     prompt: Some(theme.text_warning),
                     border: Some(theme.border_faint),
+                    chrome: theme.chrome,
                     ..Default::default()
                 },
             ))
         }
+        InputMode::KeepTab => Some(RenderedTextInput::build(
+            "Keep tab as:",
+            &input_buffer.read(),
+            depth,
+            &TextInputColors {
+                text: Some(theme.text_primary),
+                prompt: Some(theme.text_secondary),
+                border: Some(theme.border_faint),
+                chrome: theme.chrome,
+                ..Default::default()
+            },
+        )),
         InputMode::Search => Some(RenderedTextInput::build(
             "/",
             &search_query.read(),
@@ -1820,6 +2396,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                 text: Some(theme.text_primary),
                 prompt: Some(theme.text_secondary),
                 border: Some(theme.border_faint),
+                chrome: theme.chrome,
                 ..Default::default()
             },
         )),
@@ -1829,14 +2406,24 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
     let context_text = if current_data.is_some_and(|d| d.loading) {
         "Fetching issues...".to_owned()
     } else if let Some(err) = current_data.and_then(|d| d.error.as_ref()) {
-        format!("Error: {err}")
+        let cached = current_data
+            .and_then(|d| d.stale)
+            .map(|saved_at| super::common::cached_note(&saved_at, false));
+        format!("Error: {err}{}", cached.unwrap_or_default())
     } else {
-        let total = current_data.map_or(0, |d| d.issue_count);
+        let total = current_data.map_or(0, |d| d.count);
         let cursor_pos = if total_rows > 0 { cursor.get() + 1 } else { 0 };
+        let more = if current_data.is_some_and(|d| d.loading_more) {
+            " (fetching more...)".to_owned()
+        } else if let Some(saved_at) = current_data.and_then(|d| d.stale) {
+            super::common::cached_note(&saved_at, true)
+        } else {
+            String::new()
+        };
         if search_q.is_empty() {
-            format!("Issue {cursor_pos}/{total}")
+            format!("Issue {cursor_pos}/{total}{more}")
         } else {
-            format!("Issue {cursor_pos}/{total_rows} (filtered from {total})")
+            format!("Issue {cursor_pos}/{total_rows} (filtered from {total}){more}")
         }
     };
     let active_fetch_time = filter_fetch_times
@@ -1846,7 +2433,12 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
         .flatten();
     let updated_text = footer::format_updated_ago(active_fetch_time);
 
-    let rate_limit_text = footer::format_rate_limit(rate_limit_state.read().as_ref());
+    let rate_limit_text = footer::format_rate_limit(
+        rate_limit_state.read().of(all_filters
+            .get(current_filter_idx)
+            .and_then(|(f, _)| f.host.as_deref())),
+        current_data.and_then(|d| d.refresh_cost),
+    );
 
     let scope_label = match scope_repo {
         Some(repo) => repo.clone(),
@@ -1860,10 +2452,12 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
             Some(theme.footer_alerts),
             Some(theme.footer_notifications),
             Some(theme.footer_repo),
+            Some(theme.footer_events),
         ],
         inactive: Some(theme.text_faint),
         text: Some(theme.text_faint),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
     let rendered_footer = RenderedFooter::build(
         ViewKind::Issues,
@@ -1873,6 +2467,8 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
             context_text,
             updated_text,
             rate_limit_text,
+            watch_badges: props.watch_badges.map(|b| b.get()).unwrap_or_default(),
+            view_counts: props.view_counts.map(|c| c.get()).unwrap_or_default(),
         },
         action_status.read().as_ref(),
         &theme,
@@ -1880,8 +2476,44 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
         &footer_colors,
     );
 
+    let rendered_board = board_columns.as_ref().map(|cols| {
+        let items = state_ref
+            .filters
+            .get(current_filter_idx)
+            .map_or(&[][..], |fd| &fd.items[..]);
+        RenderedBoard::build(BoardBuildConfig {
+            columns: cols
+                .iter()
+                .map(|c| BoardColumnInput {
+                    title: c.title.clone(),
+                    cards: c
+                        .items
+                        .iter()
+                        .filter_map(|&i| items.get(i))
+                        .map(|issue| format!("#{} {}", issue.number, issue.title))
+                        .collect(),
+                })
+                .collect(),
+            selected: board::position(cols, cursor.get()),
+            // Tab bar, footer and the column's borders and header.
+            visible_cards: usize::from(props.height.saturating_sub(7 + banner_lines)),
+            depth,
+            title_color: Some(theme.text_primary),
+            card_color: Some(theme.text_secondary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_faint),
+            focused_border_color: Some(theme.border_primary),
+            chrome: theme.chrome,
+        })
+    });
+
+    let rendered_calendar = profile::render(&profile_overlay, &theme, depth, props.width);
+    let rendered_drafts = super::drafts::render(drafts_cursor.get(), &theme, depth);
+    let rendered_copy_menu = copy_menu::render(&copy_menu_state, &theme, depth);
+    let rendered_share_menu = share_menu::render(&share_menu_state, &theme, depth);
+
     let rendered_help = if help_visible.get() {
-        props.keybindings.map(|kb| {
+        props.keybindings.as_deref().map(|kb| {
             RenderedHelpOverlay::build(&HelpOverlayBuildConfig {
                 bindings: kb,
                 context: ViewContext::Issues,
@@ -1890,6 +2522,7 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
                 key_color: Some(theme.text_success),
                 desc_color: Some(theme.text_secondary),
                 border_color: Some(theme.border_primary),
+                chrome: theme.chrome,
             })
         })
     } else {
@@ -1898,21 +2531,37 @@ pub fn IssuesView<'a>(props: &IssuesViewProps<'a>, mut hooks: Hooks) -> impl Int
 
     let width = u32::from(props.width);
     let height = u32::from(props.height);
+    // Clicks go to the overlay, prompt or composer shown, not what is under it.
+    let clickable = *input_mode.read() == InputMode::Normal
+        && !help_visible.get()
+        && drafts_cursor.get().is_none()
+        && copy_menu_state.read().is_none()
+        && share_menu_state.read().is_none()
+        && profile_overlay.read().is_none();
 
     element! {
         View(flex_direction: FlexDirection::Column, width, height) {
-            TabBar(tab_bar: rendered_tab_bar)
+            TabBar(tab_bar: rendered_tab_bar, on_click: clickable.then_some(tab_click))
 
             View(flex_grow: 1.0_f32, flex_direction: FlexDirection::Row, overflow: Overflow::Hidden) {
                 View(flex_grow: 1.0_f32, flex_direction: FlexDirection::Column) {
-                    ScrollableTable(table: rendered_table)
+                    WarningBanner(banner: rendered_banner)
+                    ScrollableTable(
+                        table: rendered_board.is_none().then_some(rendered_table),
+                        on_click: clickable.then_some(row_click),
+                    )
+                    Board(board: rendered_board)
                 }
                 Sidebar(sidebar: rendered_sidebar)
             }
 
             TextInput(input: rendered_text_input)
-            Footer(footer: rendered_footer)
+            Footer(footer: rendered_footer, on_click: props.goto_view.filter(|_| clickable))
             HelpOverlay(overlay: rendered_help, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_drafts, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_copy_menu, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_share_menu, width: props.width, height: props.height)
+            CalendarOverlay(overlay: rendered_calendar, width: props.width, height: props.height)
         }
     }
     .into_any()
@@ -1941,56 +2590,88 @@ fn build_issue_assignee_candidates(issue: &Issue) -> Vec<String> {
     pool
 }
 
+/// Logins offered after `@` in the comment composer: the assignee pool plus
+/// everyone who appears in the loaded timeline.
+fn build_issue_mention_candidates(issue: &Issue, detail: Option<&IssueDetail>) -> Vec<String> {
+    let mut pool = build_issue_assignee_candidates(issue);
+    if let Some(detail) = detail {
+        pool.extend(
+            detail
+                .timeline_events
+                .iter()
+                .filter_map(TimelineEvent::actor)
+                .map(str::to_owned),
+        );
+        pool.sort();
+        pool.dedup();
+    }
+    pool
+}
+
 /// Groups the common parameters shared by multiselect input handling
 /// and `handle_text_input` in the Issues view.
 struct InputContext<'a> {
     input_mode: State<InputMode>,
     input_buffer: State<String>,
+    draft_target: State<Option<DraftTarget>>,
     issues_state: &'a State<IssuesState>,
     filter_idx: usize,
-    cursor: usize,
+    /// Index into the tab's items of the issue under the cursor.
+    item: Option<usize>,
     engine: Option<&'a EngineHandle>,
-    event_tx: &'a std::sync::mpsc::Sender<Event>,
+    event_tx: &'a EventSender,
 }
 
+/// Returns the footer message to show, if any.
 fn handle_text_input(
     code: KeyCode,
     modifiers: KeyModifiers,
     current_mode: &InputMode,
     ctx: &InputContext<'_>,
-    _action_status: State<Option<ActionFeedback>>,
-) {
+) -> Option<ActionFeedback> {
     let mut input_mode = ctx.input_mode;
     let mut input_buffer = ctx.input_buffer;
+    let mut draft_target = ctx.draft_target;
     let issues_state = ctx.issues_state;
     let filter_idx = ctx.filter_idx;
-    let cursor = ctx.cursor;
+    let item = ctx.item;
     let engine = ctx.engine;
     let event_tx = ctx.event_tx;
     match code {
         KeyCode::Char('d') if modifiers.contains(KeyModifiers::CONTROL) => {
             let text = input_buffer.read().clone();
             if !text.is_empty() {
-                let info = get_current_issue_info(issues_state, filter_idx, cursor);
+                let info = list::item_info(issues_state, filter_idx, item);
                 if let Some((owner, repo, number)) = info
                     && let Some(engine) = engine
                     && *current_mode == InputMode::Comment
                 {
-                    engine.send(Request::AddIssueComment {
+                    let is_pr = item_is_pr(issues_state, filter_idx, item);
+                    engine.send(Mutation::Comment(text.clone()).request(
+                        is_pr,
                         owner,
                         repo,
                         number,
-                        body: text.clone(),
-                        reply_tx: event_tx.clone(),
-                    });
+                        list::item_host(issues_state, filter_idx, item),
+                        event_tx.clone(),
+                    ));
                 }
             }
+            if let Some(target) = draft_target.read().as_ref() {
+                drafts::discard(&target.key);
+            }
+            draft_target.set(None);
             input_mode.set(InputMode::Normal);
             input_buffer.set(String::new());
         }
+        // Cancel; the text stays saved as a draft.
         KeyCode::Esc => {
+            let feedback =
+                super::common::keep_draft(draft_target.read().as_ref(), &input_buffer.read());
+            draft_target.set(None);
             input_mode.set(InputMode::Normal);
             input_buffer.set(String::new());
+            return feedback;
         }
         KeyCode::Backspace => {
             let mut buf = input_buffer.read().clone();
@@ -2009,52 +2690,158 @@ fn handle_text_input(
         }
         _ => {}
     }
+    None
 }
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn get_current_issue_info(
-    issues_state: &State<IssuesState>,
-    filter_idx: usize,
-    cursor: usize,
-) -> Option<(String, String, u64)> {
-    let state = issues_state.read();
-    let filter = state.filters.get(filter_idx)?;
-    let issue = filter.issues.get(cursor)?;
-    let repo_ref = issue.repo.as_ref()?;
-    Some((repo_ref.owner.clone(), repo_ref.name.clone(), issue.number))
+/// A mutation of the item under the cursor.
+enum Mutation {
+    Close,
+    Reopen,
+    Comment(String),
+    Labels(Vec<String>),
+    Assignees(Vec<String>),
+    Milestone(Option<String>),
 }
 
-fn get_current_issue_labels(
-    issues_state: &State<IssuesState>,
-    filter_idx: usize,
-    cursor: usize,
-) -> Vec<String> {
-    let state = issues_state.read();
-    let Some(filter) = state.filters.get(filter_idx) else {
-        return vec![];
-    };
-    let Some(issue) = filter.issues.get(cursor) else {
-        return vec![];
-    };
-    issue.labels.iter().map(|l| l.name.clone()).collect()
+impl From<BoardMove> for Mutation {
+    fn from(change: BoardMove) -> Self {
+        match change {
+            BoardMove::Labels(labels) => Self::Labels(labels),
+            BoardMove::Assignees(logins) => Self::Assignees(logins),
+            BoardMove::Milestone(title) => Self::Milestone(title),
+        }
+    }
 }
 
-fn get_current_issue_assignees(
-    issues_state: &State<IssuesState>,
-    filter_idx: usize,
-    cursor: usize,
-) -> Vec<String> {
-    let state = issues_state.read();
-    let Some(filter) = state.filters.get(filter_idx) else {
-        return vec![];
-    };
-    let Some(issue) = filter.issues.get(cursor) else {
-        return vec![];
-    };
-    issue.assignees.iter().map(|a| a.login.clone()).collect()
+impl Mutation {
+    /// The engine request applying the mutation; pull requests listed by a
+    /// mixed tab go through the PR mutations.
+    fn request(
+        self,
+        is_pr: bool,
+        owner: String,
+        repo: String,
+        number: u64,
+        host: Option<String>,
+        reply_tx: EventSender,
+    ) -> Request {
+        match (self, is_pr) {
+            (Self::Close, false) => Request::CloseIssue {
+                owner,
+                repo,
+                number,
+                host,
+                reply_tx,
+            },
+            (Self::Close, true) => Request::ClosePr {
+                owner,
+                repo,
+                number,
+                host,
+                reply_tx,
+            },
+            (Self::Reopen, false) => Request::ReopenIssue {
+                owner,
+                repo,
+                number,
+                host,
+                reply_tx,
+            },
+            (Self::Reopen, true) => Request::ReopenPr {
+                owner,
+                repo,
+                number,
+                host,
+                reply_tx,
+            },
+            (Self::Comment(body), false) => Request::AddIssueComment {
+                owner,
+                repo,
+                number,
+                host,
+                body,
+                reply_tx,
+            },
+            (Self::Comment(body), true) => Request::AddPrComment {
+                owner,
+                repo,
+                number,
+                host,
+                body,
+                reply_tx,
+            },
+            (Self::Labels(labels), false) => Request::SetIssueLabels {
+                owner,
+                repo,
+                number,
+                host,
+                labels,
+                reply_tx,
+            },
+            (Self::Labels(labels), true) => Request::SetPrLabels {
+                owner,
+                repo,
+                number,
+                host,
+                labels,
+                reply_tx,
+            },
+            (Self::Assignees(logins), false) => Request::SetIssueAssignees {
+                owner,
+                repo,
+                number,
+                host,
+                logins,
+                reply_tx,
+            },
+            (Self::Assignees(logins), true) => Request::SetPrAssignees {
+                owner,
+                repo,
+                number,
+                host,
+                logins,
+                reply_tx,
+            },
+            // The issues endpoint sets milestones on PRs too.
+            (Self::Milestone(milestone), _) => Request::SetIssueMilestone {
+                owner,
+                repo,
+                number,
+                host,
+                milestone,
+                reply_tx,
+            },
+        }
+    }
+}
+
+/// Whether the item at `item` is a pull request (mixed tabs only).
+fn item_is_pr(state: &State<IssuesState>, filter_idx: usize, item: Option<usize>) -> bool {
+    list::with_item(state, filter_idx, item, Issue::is_pull_request).unwrap_or(false)
+}
+
+/// `links` with the state (and missing title) of issues only named in the
+/// body taken from those loaded in any tab.
+fn with_loaded_states(links: &IssueLinks, state: &IssuesState) -> IssueLinks {
+    let mut links = links.clone();
+    for link in links.nodes_mut().filter(|l| l.state.is_none()) {
+        let loaded = state
+            .filters
+            .iter()
+            .flat_map(|f| &f.items)
+            .find(|i| i.repo.as_ref().is_some_and(|repo| link.is(repo, i.number)));
+        if let Some(issue) = loaded {
+            link.state = Some(issue.state);
+            if link.title.is_empty() {
+                link.title.clone_from(&issue.title);
+            }
+        }
+    }
+    links
 }
 
 fn build_issue_sidebar_meta(
@@ -2064,19 +2851,20 @@ fn build_issue_sidebar_meta(
 ) -> SidebarMeta {
     let icons = &theme.icons;
 
-    // Pill: Open (green) / Closed (red)
-    let (pill_icon, pill_text, pill_bg_app) = match issue.state {
-        crate::github::types::IssueState::Open => (
-            icons.issue_open.clone(),
-            "Open".to_owned(),
-            theme.pill_open_bg,
-        ),
-        crate::github::types::IssueState::Closed | crate::github::types::IssueState::Unknown => (
-            icons.issue_closed.clone(),
-            "Closed".to_owned(),
-            theme.pill_closed_bg,
-        ),
+    // Pill: Open (green) / Closed (red), or the PR states in a mixed tab
+    let (pill_icon, pill_text, pill_bg_app) = match issue.pr_state {
+        Some(_) if issue.is_draft => (icons.pr_draft.clone(), "Draft", theme.pill_draft_bg),
+        Some(PrState::Open) => (icons.pr_open.clone(), "Open", theme.pill_open_bg),
+        Some(PrState::Closed) => (icons.pr_closed.clone(), "Closed", theme.pill_closed_bg),
+        Some(PrState::Merged) => (icons.pr_merged.clone(), "Merged", theme.pill_merged_bg),
+        None => match issue.state {
+            IssueState::Open => (icons.issue_open.clone(), "Open", theme.pill_open_bg),
+            IssueState::Closed | IssueState::Unknown => {
+                (icons.issue_closed.clone(), "Closed", theme.pill_closed_bg)
+            }
+        },
     };
+    let pill_text = pill_text.to_owned();
 
     // Author login (issues have no branch — use empty branch_text)
     let author_login = issue
@@ -2132,7 +2920,7 @@ fn build_issue_sidebar_meta(
         .to_string();
     let updated_age = crate::util::format_date(&issue.updated_at, "relative");
 
-    let reactions_text = format_reactions_text(&issue.reactions);
+    let reactions_text = issue.reactions.summary();
 
     SidebarMeta {
         pill_icon,
@@ -2162,6 +2950,8 @@ fn build_issue_sidebar_meta(
         lines_added: None,
         lines_deleted: None,
         reactions_text,
+        deployments: Vec::new(),
+        context_lines: Vec::new(),
         date_fg: theme.text_faint.to_crossterm_color(depth),
         date_age_fg: theme.text_secondary.to_crossterm_color(depth),
         additions_fg: theme.text_success.to_crossterm_color(depth),
@@ -2173,41 +2963,6 @@ fn build_issue_sidebar_meta(
     }
 }
 
-/// Format reaction counts into an emoji string (e.g. "👍 3  🎉 1").
-///
-/// Returns `None` when no reactions are present.
-fn format_reactions_text(r: &crate::types::ReactionGroups) -> Option<String> {
-    if r.total() == 0 {
-        return None;
-    }
-    let mut parts = Vec::new();
-    if r.thumbs_up > 0 {
-        parts.push(format!("\u{1f44d} {}", r.thumbs_up));
-    }
-    if r.thumbs_down > 0 {
-        parts.push(format!("\u{1f44e} {}", r.thumbs_down));
-    }
-    if r.laugh > 0 {
-        parts.push(format!("\u{1f604} {}", r.laugh));
-    }
-    if r.hooray > 0 {
-        parts.push(format!("\u{1f389} {}", r.hooray));
-    }
-    if r.confused > 0 {
-        parts.push(format!("\u{1f615} {}", r.confused));
-    }
-    if r.heart > 0 {
-        parts.push(format!("\u{2764}\u{fe0f} {}", r.heart));
-    }
-    if r.rocket > 0 {
-        parts.push(format!("\u{1f680} {}", r.rocket));
-    }
-    if r.eyes > 0 {
-        parts.push(format!("\u{1f440} {}", r.eyes));
-    }
-    Some(parts.join("  "))
-}
-
 fn default_theme() -> ResolvedTheme {
     super::default_theme()
 }
@@ -0,0 +1,529 @@
+//! Job log pane of the Actions sidebar.
+//!
+//! `L` picks a job of the selected run (the first failed one is preselected)
+//! and shows its log in place of the job list. ANSI colors are kept,
+//! `##[group]` sections are folded unless they report an error, and the log
+//! is fetched again every [`POLL_INTERVAL`] while the job runs.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use iocraft::prelude::*;
+
+use crate::color::{Color as AppColor, ColorDepth};
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::markdown::renderer::{StyledLine, StyledSpan};
+use crate::theme::ResolvedTheme;
+use crate::types::{RunConclusion, RunStatus, WorkflowJob};
+
+/// Engine request context of log fetches.
+pub(crate) const FETCH_CONTEXT: &str = "FetchJobLogs";
+
+/// Delay between fetches of a running job's log.
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Lines kept from the end of a log; earlier ones are counted only.
+const MAX_LINES: usize = 5000;
+
+/// A line of the log, or a folded `##[group]` section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Entry {
+    Line(String),
+    Group {
+        title: String,
+        lines: Vec<String>,
+        /// Holds an `##[error]` line, so it starts unfolded.
+        failed: bool,
+    },
+}
+
+/// Where a job's log is.
+#[derive(Debug, Clone)]
+enum Content {
+    Loading,
+    /// GitHub has not published the log of the running job yet.
+    Waiting,
+    Ready {
+        entries: Arc<[Entry]>,
+        /// Lines cut from the start.
+        dropped: usize,
+    },
+    Failed(String),
+}
+
+/// The log shown in the sidebar.
+#[derive(Debug, Clone)]
+pub(crate) struct JobLog {
+    pub(crate) run_id: u64,
+    pub(crate) job_id: u64,
+    job_name: String,
+    owner: String,
+    repo: String,
+    host: Option<String>,
+    /// The job still runs: keep fetching.
+    pub(crate) live: bool,
+    /// Every group unfolded.
+    unfolded: bool,
+    content: Content,
+}
+
+impl JobLog {
+    /// Fetch the log of `job`, a job of run `run_id` in `owner/repo`.
+    pub(crate) fn fetch(
+        run_id: u64,
+        job: &WorkflowJob,
+        (owner, repo, host): (String, String, Option<String>),
+        engine: &EngineHandle,
+        reply_tx: &EventSender,
+    ) -> Self {
+        let log = Self {
+            run_id,
+            job_id: job.id,
+            job_name: job.name.clone(),
+            owner,
+            repo,
+            host,
+            live: job.status != RunStatus::Completed,
+            unfolded: false,
+            content: Content::Loading,
+        };
+        engine.send(log.request(reply_tx));
+        log
+    }
+
+    /// Request fetching the log again.
+    pub(crate) fn request(&self, reply_tx: &EventSender) -> Request {
+        Request::FetchJobLogs {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            job_id: self.job_id,
+            host: self.host.clone(),
+            reply_tx: reply_tx.clone(),
+        }
+    }
+
+    /// Take a fetched log; returns whether it is the first one shown, to
+    /// scroll to its end.
+    pub(crate) fn on_fetched(&mut self, status: RunStatus, log: Option<String>) -> bool {
+        let first = matches!(self.content, Content::Loading | Content::Waiting);
+        self.live = status != RunStatus::Completed;
+        self.content = match log {
+            Some(log) => {
+                let (entries, dropped) = parse(&log);
+                Content::Ready {
+                    entries: entries.into(),
+                    dropped,
+                }
+            }
+            None if self.live => Content::Waiting,
+            None => Content::Failed("GitHub kept no log for this job".to_owned()),
+        };
+        first && matches!(self.content, Content::Ready { .. })
+    }
+
+    pub(crate) fn on_error(&mut self, message: String) {
+        self.live = false;
+        self.content = Content::Failed(message);
+    }
+
+    /// Fold or unfold every group.
+    pub(crate) fn toggle_folds(&mut self) {
+        self.unfolded = !self.unfolded;
+    }
+
+    pub(crate) fn title(&self) -> String {
+        if self.live {
+            format!("Log: {} (live)", self.job_name)
+        } else {
+            format!("Log: {}", self.job_name)
+        }
+    }
+
+    /// Lines of the sidebar.
+    pub(crate) fn lines(&self, theme: &ResolvedTheme) -> Vec<StyledLine> {
+        let note = |text: &str| StyledLine::from_span(StyledSpan::text(text, theme.text_faint));
+        let ellipsis = crate::components::ellipsis(theme.chrome);
+        let (entries, dropped) = match &self.content {
+            Content::Loading => return vec![note(&format!("Loading log{ellipsis}"))],
+            Content::Waiting => {
+                return vec![note(&format!(
+                    "Waiting for GitHub to publish the log{ellipsis}"
+                ))];
+            }
+            Content::Failed(message) => {
+                return vec![StyledLine::from_span(StyledSpan::text(
+                    message.as_str(),
+                    theme.text_error,
+                ))];
+            }
+            Content::Ready { entries, dropped } => (entries, *dropped),
+        };
+        let (open, closed) = if theme.chrome.ascii_only() {
+            ("v ", "> ")
+        } else {
+            ("\u{25be} ", "\u{25b8} ")
+        };
+        let mut lines = Vec::new();
+        if dropped > 0 {
+            lines.push(note(&format!("{ellipsis} {dropped} earlier lines")));
+        }
+        for entry in entries.iter() {
+            match entry {
+                Entry::Line(line) => lines.push(log_line(line, "", theme)),
+                Entry::Group {
+                    title,
+                    lines: body,
+                    failed,
+                } => {
+                    let unfolded = self.unfolded || *failed;
+                    let marker = if unfolded { open } else { closed };
+                    let mut header = vec![StyledSpan::bold(
+                        format!("{marker}{title}"),
+                        theme.text_primary,
+                    )];
+                    if !unfolded {
+                        header.push(StyledSpan::text(
+                            format!(" ({} lines)", body.len()),
+                            theme.text_faint,
+                        ));
+                    }
+                    lines.push(StyledLine::from_spans(header));
+                    if unfolded {
+                        lines.extend(body.iter().map(|line| log_line(line, "  ", theme)));
+                    }
+                }
+            }
+        }
+        lines
+    }
+}
+
+/// Split off the timestamp GitHub starts every line with.
+pub(super) fn split_timestamp(line: &str) -> (Option<&str>, &str) {
+    match line.split_once(' ') {
+        Some((stamp, rest))
+            if stamp.len() >= 20
+                && stamp.ends_with('Z')
+                && stamp.as_bytes().get(10) == Some(&b'T') =>
+        {
+            (Some(stamp), rest)
+        }
+        _ => (None, line),
+    }
+}
+
+/// Split a log into lines and groups, keeping the last [`MAX_LINES`] lines.
+fn parse(log: &str) -> (Vec<Entry>, usize) {
+    let all: Vec<&str> = log
+        .lines()
+        .map(|line| split_timestamp(line.strip_prefix('\u{feff}').unwrap_or(line)).1)
+        .collect();
+    let dropped = all.len().saturating_sub(MAX_LINES);
+    let mut entries = Vec::new();
+    let mut group: Option<(String, Vec<String>, bool)> = None;
+    for line in &all[dropped..] {
+        if let Some(title) = line.strip_prefix("##[group]") {
+            if let Some((title, lines, failed)) = group.take() {
+                entries.push(Entry::Group {
+                    title,
+                    lines,
+                    failed,
+                });
+            }
+            group = Some((title.to_owned(), Vec::new(), false));
+        } else if line.starts_with("##[endgroup]") {
+            if let Some((title, lines, failed)) = group.take() {
+                entries.push(Entry::Group {
+                    title,
+                    lines,
+                    failed,
+                });
+            }
+        } else if let Some((_, lines, failed)) = &mut group {
+            *failed |= line.starts_with("##[error]");
+            lines.push((*line).to_owned());
+        } else {
+            entries.push(Entry::Line((*line).to_owned()));
+        }
+    }
+    if let Some((title, lines, failed)) = group {
+        entries.push(Entry::Group {
+            title,
+            lines,
+            failed,
+        });
+    }
+    (entries, dropped)
+}
+
+/// One log line: workflow commands (`##[error]`, …) colored by kind, other
+/// text in its own ANSI colors.
+pub(super) fn log_line(line: &str, indent: &str, theme: &ResolvedTheme) -> StyledLine {
+    let commands = [
+        ("##[error]", theme.text_error, "Error: "),
+        ("##[warning]", theme.text_warning, "Warning: "),
+        ("##[notice]", theme.text_secondary, "Notice: "),
+        ("##[command]", theme.text_faint, ""),
+        ("##[debug]", theme.text_faint, ""),
+    ];
+    let mut spans = vec![StyledSpan::text(indent, theme.text_secondary)];
+    for (prefix, color, label) in commands {
+        if let Some(rest) = line.strip_prefix(prefix) {
+            spans.push(StyledSpan::text(
+                format!("{label}{}", strip_ansi(rest)),
+                color,
+            ));
+            return StyledLine::from_spans(spans);
+        }
+    }
+    spans.extend(ansi_spans(line, theme.text_secondary));
+    StyledLine::from_spans(spans)
+}
+
+/// `text` without its escape sequences.
+fn strip_ansi(text: &str) -> String {
+    ansi_spans(text, AppColor::Ansi256(7))
+        .into_iter()
+        .map(|span| span.text)
+        .collect()
+}
+
+/// Split `text` on SGR escapes into spans with their foreground color and
+/// weight; other escapes are dropped.
+fn ansi_spans(text: &str, default: AppColor) -> Vec<StyledSpan> {
+    let mut spans = Vec::new();
+    let (mut color, mut bold) = (default, false);
+    let mut push = |chunk: &str, color: AppColor, bold: bool| {
+        if !chunk.is_empty() {
+            let mut span = StyledSpan::text(chunk, color);
+            span.bold = bold;
+            spans.push(span);
+        }
+    };
+    let mut rest = text;
+    while let Some(start) = rest.find('\u{1b}') {
+        push(&rest[..start], color, bold);
+        let after = &rest[start + 1..];
+        let Some(params) = after.strip_prefix('[') else {
+            rest = after;
+            continue;
+        };
+        let Some(end) = params.find(|c: char| c.is_ascii_alphabetic()) else {
+            rest = "";
+            break;
+        };
+        if params.as_bytes()[end] == b'm' {
+            let codes: Vec<u16> = params[..end]
+                .split(';')
+                .map(|code| code.parse().unwrap_or(0))
+                .collect();
+            let mut codes = codes.iter().copied();
+            while let Some(code) = codes.next() {
+                match code {
+                    0 => (color, bold) = (default, false),
+                    1 => bold = true,
+                    22 => bold = false,
+                    39 => color = default,
+                    #[expect(clippy::cast_possible_truncation)]
+                    30..=37 => color = AppColor::Ansi256((code - 30) as u8),
+                    #[expect(clippy::cast_possible_truncation)]
+                    90..=97 => color = AppColor::Ansi256((code - 90 + 8) as u8),
+                    38 => match codes.next() {
+                        Some(5) => {
+                            if let Some(n) = codes.next().and_then(|n| u8::try_from(n).ok()) {
+                                color = AppColor::Ansi256(n);
+                            }
+                        }
+                        Some(2) => {
+                            let mut channel = || codes.next().and_then(|c| u8::try_from(c).ok());
+                            if let (Some(r), Some(g), Some(b)) = (channel(), channel(), channel()) {
+                                color = AppColor::Hex { r, g, b };
+                            }
+                        }
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+        }
+        rest = &params[end + 1..];
+    }
+    push(rest, color, bold);
+    spans
+}
+
+// ---------------------------------------------------------------------------
+// Job picker
+// ---------------------------------------------------------------------------
+
+/// Picker of the job whose log to show, open when a run has several jobs.
+#[derive(Debug, Clone)]
+pub(crate) struct JobPicker {
+    run_id: u64,
+    jobs: Vec<WorkflowJob>,
+    cursor: usize,
+    /// Owner, name and host of the run's repo.
+    repo: (String, String, Option<String>),
+}
+
+impl JobPicker {
+    /// Jobs sorted by name, the cursor on the first failed one, else the
+    /// first running one.
+    pub(crate) fn new(
+        run_id: u64,
+        mut jobs: Vec<WorkflowJob>,
+        repo: (String, String, Option<String>),
+    ) -> Self {
+        jobs.sort_by(|a, b| a.name.cmp(&b.name));
+        let cursor = jobs
+            .iter()
+            .position(|job| {
+                matches!(
+                    job.conclusion,
+                    Some(RunConclusion::Failure | RunConclusion::TimedOut)
+                )
+            })
+            .or_else(|| {
+                jobs.iter()
+                    .position(|job| job.status == RunStatus::InProgress)
+            })
+            .unwrap_or(0);
+        Self {
+            run_id,
+            jobs,
+            cursor,
+            repo,
+        }
+    }
+
+    /// The job of a run with a single one.
+    pub(crate) fn only_job(&self) -> Option<&WorkflowJob> {
+        (self.jobs.len() == 1).then(|| &self.jobs[0])
+    }
+
+    /// Start showing the log of `job`.
+    pub(crate) fn show(
+        &self,
+        job: &WorkflowJob,
+        engine: &EngineHandle,
+        reply_tx: &EventSender,
+    ) -> JobLog {
+        JobLog::fetch(self.run_id, job, self.repo.clone(), engine, reply_tx)
+    }
+}
+
+/// What a key did to the picker.
+pub(crate) enum PickerOutcome {
+    Open,
+    Closed,
+    Picked(WorkflowJob),
+}
+
+/// Apply a key to the open picker.
+pub(crate) fn handle_picker_key(picker: &mut JobPicker, code: KeyCode) -> PickerOutcome {
+    match code {
+        KeyCode::Esc | KeyCode::Char('q' | 'L') => return PickerOutcome::Closed,
+        KeyCode::Down | KeyCode::Char('j') => {
+            picker.cursor = (picker.cursor + 1).min(picker.jobs.len().saturating_sub(1));
+        }
+        KeyCode::Up | KeyCode::Char('k') => picker.cursor = picker.cursor.saturating_sub(1),
+        KeyCode::Enter => {
+            if let Some(job) = picker.jobs.get(picker.cursor) {
+                return PickerOutcome::Picked(job.clone());
+            }
+        }
+        _ => {}
+    }
+    PickerOutcome::Open
+}
+
+/// Render the open picker.
+pub(crate) fn render_picker(
+    picker: &JobPicker,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> RenderedSelectionOverlay {
+    let items = picker
+        .jobs
+        .iter()
+        .map(|job| {
+            let state = match (job.status, job.conclusion) {
+                (RunStatus::Completed, Some(conclusion)) => conclusion.as_str(),
+                (RunStatus::InProgress, _) => "in progress",
+                (RunStatus::Queued, _) => "queued",
+                _ => "unknown",
+            };
+            SelectionOverlayItem {
+                label: format!("{}  ({state})", job.name),
+            }
+        })
+        .collect();
+    RenderedSelectionOverlay::build(SelectionOverlayBuildConfig {
+        title: "Show the log of".to_owned(),
+        items,
+        cursor: picker.cursor,
+        show_filter: false,
+        filter_text: String::new(),
+        hint: Some("Enter show  Esc cancel".to_owned()),
+        depth,
+        title_color: Some(theme.text_primary),
+        item_color: Some(theme.text_secondary),
+        cursor_color: Some(theme.text_primary),
+        selected_bg: Some(theme.bg_selected),
+        border_color: Some(theme.border_primary),
+        hint_color: Some(theme.text_faint),
+        filter_prompt_color: Some(theme.text_faint),
+        filter_text_color: Some(theme.text_primary),
+        cursor_marker: theme.icons.select_cursor.clone(),
+        chrome: theme.chrome,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_folds_groups_and_keeps_ansi_colors() {
+        let log = "\u{feff}2026-03-01T10:00:00.1234567Z ##[group]Run actions/checkout@v4\n\
+                   2026-03-01T10:00:00.2Z with: fetch-depth 1\n\
+                   2026-03-01T10:00:01.0Z ##[endgroup]\n\
+                   2026-03-01T10:00:02.0Z \u{1b}[1;31mFAIL\u{1b}[0m tests/api.rs\n\
+                   2026-03-01T10:00:03.0Z ##[group]Run cargo test\n\
+                   2026-03-01T10:00:04.0Z ##[error]Process completed with exit code 101.\n";
+        let (entries, dropped) = parse(log);
+        assert_eq!(dropped, 0);
+        assert_eq!(
+            entries[0],
+            Entry::Group {
+                title: "Run actions/checkout@v4".to_owned(),
+                lines: vec!["with: fetch-depth 1".to_owned()],
+                failed: false,
+            }
+        );
+        assert_eq!(
+            entries[1],
+            Entry::Line("\u{1b}[1;31mFAIL\u{1b}[0m tests/api.rs".to_owned())
+        );
+        assert!(matches!(&entries[2], Entry::Group { failed: true, .. }));
+
+        let default = AppColor::Ansi256(7);
+        let spans = ansi_spans("\u{1b}[1;31mFAIL\u{1b}[0m tests/api.rs", default);
+        assert_eq!(spans.len(), 2);
+        assert_eq!(
+            (spans[0].text.as_str(), spans[0].color, spans[0].bold),
+            ("FAIL", AppColor::Ansi256(1), true)
+        );
+        assert_eq!(
+            (spans[1].text.as_str(), spans[1].color, spans[1].bold),
+            (" tests/api.rs", default, false)
+        );
+        assert_eq!(
+            ansi_spans("\u{1b}[38;2;1;2;3mx", default)[0].color,
+            AppColor::Hex { r: 1, g: 2, b: 3 }
+        );
+    }
+}
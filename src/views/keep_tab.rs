@@ -0,0 +1,137 @@
+//! Keeping an ephemeral tab.
+//!
+//! `K` on a tab a deep link opened prompts for a title, prefilled with the
+//! tab's, then appends the tab's filter to the global config file as a new
+//! `[[pr_filters]]`, `[[issues_filters]]` or `[[actions_filters]]` entry. The
+//! tab takes the title at once and is a configured one from the next start.
+
+use iocraft::prelude::*;
+
+use crate::components::footer::ActionFeedback;
+use crate::config::types::{ActionsFilter, IssueFilter, PrFilter};
+
+/// What a key did to the title prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Prompt {
+    Editing,
+    Cancelled,
+    Submitted(String),
+}
+
+/// Edit the title in `buffer` with `code`. Enter submits a non-blank title.
+pub(crate) fn handle_key(
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    buffer: &mut State<String>,
+) -> Prompt {
+    match code {
+        KeyCode::Esc => return Prompt::Cancelled,
+        KeyCode::Enter => {
+            let title = buffer.read().trim().to_owned();
+            if !title.is_empty() {
+                return Prompt::Submitted(title);
+            }
+        }
+        KeyCode::Backspace => {
+            let mut text = buffer.read().clone();
+            text.pop();
+            buffer.set(text);
+        }
+        KeyCode::Char(ch) if !modifiers.contains(KeyModifiers::CONTROL) => {
+            let mut text = buffer.read().clone();
+            text.push(ch);
+            buffer.set(text);
+        }
+        _ => {}
+    }
+    Prompt::Editing
+}
+
+fn insert_some(table: &mut toml::Table, key: &str, value: Option<impl Into<toml::Value>>) {
+    if let Some(value) = value {
+        table.insert(key.to_owned(), value.into());
+    }
+}
+
+fn titled(title: &str) -> toml::Table {
+    let mut table = toml::Table::new();
+    table.insert("title".to_owned(), title.into());
+    table
+}
+
+/// `[[pr_filters]]` entry of `filter`, titled `title`.
+pub(crate) fn pr_table(filter: &PrFilter, title: &str) -> toml::Table {
+    let mut table = titled(title);
+    table.insert("filters".to_owned(), filter.filters.as_str().into());
+    insert_some(&mut table, "host", filter.host.as_deref());
+    insert_some(&mut table, "limit", filter.limit.map(i64::from));
+    table
+}
+
+/// `[[issues_filters]]` entry of `filter`, titled `title`.
+pub(crate) fn issue_table(filter: &IssueFilter, title: &str) -> toml::Table {
+    let mut table = titled(title);
+    table.insert("filters".to_owned(), filter.filters.as_str().into());
+    insert_some(&mut table, "host", filter.host.as_deref());
+    insert_some(&mut table, "limit", filter.limit.map(i64::from));
+    table
+}
+
+/// `[[actions_filters]]` entry of `filter`, titled `title`, keeping the
+/// branch the tab is scoped to.
+pub(crate) fn actions_table(filter: &ActionsFilter, title: &str) -> toml::Table {
+    let mut table = titled(title);
+    if filter.repos.is_empty() {
+        table.insert("repo".to_owned(), filter.repo.as_str().into());
+    } else {
+        table.insert("repos".to_owned(), filter.repos.clone().into());
+    }
+    insert_some(&mut table, "host", filter.host.as_deref());
+    insert_some(&mut table, "limit", filter.limit.map(i64::from));
+    insert_some(&mut table, "status", filter.status.as_deref());
+    insert_some(&mut table, "event", filter.event.as_deref());
+    insert_some(&mut table, "branch", filter.branch.as_deref());
+    table
+}
+
+/// Append `table` to the `section` of the global config file.
+pub(crate) fn keep(section: &str, table: &toml::Table) -> ActionFeedback {
+    let title = table
+        .get("title")
+        .and_then(toml::Value::as_str)
+        .unwrap_or("");
+    match crate::config::loader::append_to_global_config(section, table) {
+        Ok(path) => ActionFeedback::Success(format!("Kept \"{title}\" in {}", path.display())),
+        Err(e) => ActionFeedback::Error(format!("Could not keep the tab: {e:#}")),
+    }
+}
+
+/// Message for a tab that is already configured.
+pub(crate) fn not_ephemeral() -> ActionFeedback {
+    ActionFeedback::Info("This tab is already in the config".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn actions_table_keeps_set_fields_only() {
+        let filter = ActionsFilter {
+            title: "o/r".to_owned(),
+            repo: "o/r".to_owned(),
+            repos: Vec::new(),
+            host: None,
+            limit: None,
+            status: None,
+            event: None,
+            branch: Some("release/2.x".to_owned()),
+            refetch_interval_minutes: None,
+        };
+        let table = actions_table(&filter, "Release runs");
+        assert_eq!(
+            toml::to_string(&table).unwrap(),
+            "title = \"Release runs\"\nrepo = \"o/r\"\nbranch = \"release/2.x\"\n"
+        );
+    }
+}
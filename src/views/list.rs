@@ -0,0 +1,1142 @@
+//! Shared core of the list views (PRs, Issues).
+//!
+//! Both views keep one [`FilterData`] per filter tab in a [`ListState`] and
+//! drive it through the same fetch lifecycle: lazy load, streamed pages,
+//! errors and per-tab fetch bookkeeping. They also share how fetches are
+//! scheduled ([`Fetches`]), the debounced detail fetch, the engine events
+//! neither view treats specially ([`ListEvents`]), and the keys of search,
+//! overlays, app signals, cursor and tabs. A view supplies the item type (via
+//! [`ListItem`]), its filter type (via [`ListFilter`]), the row builder and
+//! its actions; everything here is independent of what the rows show.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use iocraft::prelude::*;
+
+use crate::app::ViewKind;
+use crate::components::footer::ActionFeedback;
+use crate::components::sidebar::SidebarTab;
+use crate::components::table::Row;
+use crate::config::keybindings::BuiltinAction;
+use crate::config::types::{IssueFilter, PrFilter, PreviewTab};
+use crate::drafts::DraftTarget;
+use crate::engine::{EngineHandle, Event, EventSender, FilterConfig, Request};
+use crate::filter::{self, apply_scope};
+use crate::types::{Actor, Issue, Label, PullRequest, RepoRef};
+
+use super::copy_menu::{self, CopyMenu};
+use super::profile::{self, ProfileOverlay};
+use super::share_menu::{self, ShareMenu};
+
+/// An item listed by a filter tab.
+pub(crate) trait ListItem: Clone + Send + Sync + 'static {
+    fn number(&self) -> u64;
+    fn title(&self) -> &str;
+    fn body(&self) -> &str;
+    fn author(&self) -> Option<&Actor>;
+    fn repo(&self) -> Option<&RepoRef>;
+    fn labels(&self) -> &[Label];
+    fn assignees(&self) -> &[Actor];
+    fn url(&self) -> &str;
+}
+
+macro_rules! impl_list_item {
+    ($ty:ty) => {
+        impl ListItem for $ty {
+            fn number(&self) -> u64 {
+                self.number
+            }
+            fn title(&self) -> &str {
+                &self.title
+            }
+            fn body(&self) -> &str {
+                &self.body
+            }
+            fn author(&self) -> Option<&Actor> {
+                self.author.as_ref()
+            }
+            fn repo(&self) -> Option<&RepoRef> {
+                self.repo.as_ref()
+            }
+            fn labels(&self) -> &[Label] {
+                &self.labels
+            }
+            fn assignees(&self) -> &[Actor] {
+                &self.assignees
+            }
+            fn url(&self) -> &str {
+                &self.url
+            }
+        }
+    };
+}
+
+impl_list_item!(PullRequest);
+impl_list_item!(Issue);
+
+/// The filter of a tab, as a list view fetches it.
+pub(crate) trait ListFilter: Clone {
+    fn preview(&self) -> Option<bool>;
+    fn preview_tab(&self) -> Option<PreviewTab>;
+    /// Whether the tab counts towards the view's footer badge.
+    fn badge(&self) -> bool;
+    /// The filter as sent to the engine, narrowed to `scope_repo`.
+    fn scoped(&self, scope_repo: Option<&str>) -> Self;
+    /// The request fetching tab `filter_idx` with this filter.
+    fn fetch(self, filter_idx: usize, force: bool, reply_tx: EventSender) -> Request;
+    /// The filter, registered for background refresh.
+    fn refresh_config(self) -> FilterConfig;
+}
+
+impl ListFilter for PrFilter {
+    fn preview(&self) -> Option<bool> {
+        self.preview
+    }
+    fn preview_tab(&self) -> Option<PreviewTab> {
+        self.preview_tab
+    }
+    fn badge(&self) -> bool {
+        self.badge == Some(true)
+    }
+    /// Team-review qualifiers are stripped: the view applies them to the
+    /// fetched PRs itself.
+    fn scoped(&self, scope_repo: Option<&str>) -> Self {
+        Self {
+            filters: apply_scope(&filter::strip_team_review(&self.filters).0, scope_repo),
+            ..self.clone()
+        }
+    }
+    fn fetch(self, filter_idx: usize, force: bool, reply_tx: EventSender) -> Request {
+        Request::FetchPrs {
+            filter_idx,
+            filter: self,
+            force,
+            reply_tx,
+        }
+    }
+    fn refresh_config(self) -> FilterConfig {
+        FilterConfig::Pr(self)
+    }
+}
+
+impl ListFilter for IssueFilter {
+    fn preview(&self) -> Option<bool> {
+        self.preview
+    }
+    fn preview_tab(&self) -> Option<PreviewTab> {
+        self.preview_tab
+    }
+    fn badge(&self) -> bool {
+        self.badge == Some(true)
+    }
+    fn scoped(&self, scope_repo: Option<&str>) -> Self {
+        Self {
+            filters: apply_scope(&self.filters, scope_repo),
+            ..self.clone()
+        }
+    }
+    fn fetch(self, filter_idx: usize, force: bool, reply_tx: EventSender) -> Request {
+        Request::FetchIssues {
+            filter_idx,
+            filter: self,
+            force,
+            reply_tx,
+        }
+    }
+    fn refresh_config(self) -> FilterConfig {
+        FilterConfig::Issue(self)
+    }
+}
+
+/// State for a single filter tab.
+#[derive(Debug, Clone)]
+pub(crate) struct FilterData<T> {
+    pub rows: Vec<Row>,
+    /// Item bodies for the preview (indexed same as rows).
+    pub bodies: Vec<String>,
+    /// Item titles for the sidebar header.
+    pub titles: Vec<String>,
+    /// Full item data for actions.
+    pub items: Vec<T>,
+    pub count: usize,
+    pub loading: bool,
+    /// The rows are the first pages of a fetch that is still running.
+    pub loading_more: bool,
+    pub error: Option<String>,
+    /// Rate-limit cost of the last uncached fetch, for the footer.
+    pub refresh_cost: Option<u32>,
+    /// What GitHub reported skipping when the results are partial.
+    pub warnings: Vec<String>,
+    /// When the rows were fetched, if they come from an earlier session's
+    /// disk cache and the first fetch of this one hasn't completed.
+    pub stale: Option<DateTime<Utc>>,
+}
+
+impl<T> Default for FilterData<T> {
+    fn default() -> Self {
+        Self {
+            rows: Vec::new(),
+            bodies: Vec::new(),
+            titles: Vec::new(),
+            items: Vec::new(),
+            count: 0,
+            loading: true,
+            loading_more: false,
+            error: None,
+            refresh_cost: None,
+            warnings: Vec::new(),
+            stale: None,
+        }
+    }
+}
+
+impl<T: ListItem> FilterData<T> {
+    /// Fetched `items` with the `rows` the view built for them.
+    pub fn loaded(items: Vec<T>, rows: Vec<Row>, warnings: Vec<String>, complete: bool) -> Self {
+        Self {
+            rows,
+            bodies: items.iter().map(|i| i.body().to_owned()).collect(),
+            titles: items.iter().map(|i| i.title().to_owned()).collect(),
+            count: items.len(),
+            items,
+            loading: false,
+            loading_more: !complete,
+            error: None,
+            refresh_cost: None,
+            warnings,
+            stale: None,
+        }
+    }
+
+    /// `items` an earlier session fetched at `saved_at`, from the disk cache.
+    pub fn cached(items: Vec<T>, rows: Vec<Row>, saved_at: DateTime<Utc>) -> Self {
+        Self {
+            stale: Some(saved_at),
+            ..Self::loaded(items, rows, Vec::new(), true)
+        }
+    }
+}
+
+/// Loaded data of every filter tab, configured tabs first, then ephemeral
+/// ones.
+#[derive(Debug, Clone)]
+pub(crate) struct ListState<T> {
+    pub filters: Vec<Arc<FilterData<T>>>,
+}
+
+impl<T> ListState<T> {
+    /// `count` tabs, all waiting for their first load.
+    pub fn new(count: usize) -> Self {
+        Self {
+            filters: super::common::empty_filters(count),
+        }
+    }
+}
+
+/// Build a merged list of (filter, `is_ephemeral`) from config + ephemeral
+/// filters.
+pub(crate) fn merged_filters<'a, F>(
+    config: &'a [F],
+    ephemeral: &'a [(F, Option<u64>)],
+) -> Vec<(&'a F, bool)> {
+    let mut out: Vec<_> = config.iter().map(|f| (f, false)).collect();
+    out.extend(ephemeral.iter().map(|(f, _)| (f, true)));
+    out
+}
+
+/// The sidebar tab a filter's `preview_tab` names, if the view has it.
+pub(crate) fn preview_tab(tab: PreviewTab, available: &[SidebarTab]) -> Option<SidebarTab> {
+    let tab = match tab {
+        PreviewTab::Overview => SidebarTab::Overview,
+        PreviewTab::Activity => SidebarTab::Activity,
+        PreviewTab::Commits => SidebarTab::Commits,
+        PreviewTab::Checks => SidebarTab::Checks,
+        PreviewTab::Files => SidebarTab::Files,
+    };
+    available.contains(&tab).then_some(tab)
+}
+
+/// Apply the preview settings of `filter`, tab `idx`, on switching to it.
+/// `applied` is the last tab they were applied for.
+pub(crate) fn apply_preview<F: ListFilter>(
+    applied: &mut State<Option<usize>>,
+    idx: usize,
+    filter: Option<&F>,
+    available: &[SidebarTab],
+    preview_open: &mut State<bool>,
+    sidebar_tab: &mut State<SidebarTab>,
+) {
+    if applied.get() == Some(idx) {
+        return;
+    }
+    applied.set(Some(idx));
+    if let Some(f) = filter {
+        if let Some(open) = f.preview() {
+            preview_open.set(open);
+        }
+        if let Some(tab) = f.preview_tab().and_then(|t| preview_tab(t, available)) {
+            sidebar_tab.set(tab);
+        }
+    }
+}
+
+/// Fetch scheduling of a list view, run on each render.
+pub(crate) struct Fetches<'a, T: ListItem> {
+    pub state: State<ListState<T>>,
+    pub in_flight: State<Vec<bool>>,
+    /// The configured tabs are registered for background refresh.
+    pub registered: State<bool>,
+    /// Fetch every tab, bypassing the cache.
+    pub refresh_all: State<bool>,
+    /// The next lazy fetch bypasses the cache.
+    pub force: State<bool>,
+    pub engine: Option<&'a EngineHandle>,
+    pub event_tx: &'a EventSender,
+    pub scope_repo: Option<&'a str>,
+    pub is_active: bool,
+}
+
+impl<T: ListItem> Fetches<'_, T> {
+    /// Send the fetches the tabs need and return the badge tabs.
+    ///
+    /// `configured` are registered for background refresh once (again after
+    /// a scope change). While the view shows, a full refresh fetches `all`
+    /// tabs, otherwise the `active` tab loads lazily. Badge tabs load even
+    /// while another view shows, for the footer count.
+    pub fn run<F: ListFilter>(
+        mut self,
+        configured: &[F],
+        all: &[(&F, bool)],
+        active: usize,
+    ) -> Vec<usize> {
+        let badge_tabs = super::common::badge_tabs(configured.iter().map(ListFilter::badge));
+        let Some(engine) = self.engine else {
+            return badge_tabs;
+        };
+        if !self.registered.get() {
+            engine.send(Request::RegisterRefresh {
+                configs: configured
+                    .iter()
+                    .map(|f| f.scoped(self.scope_repo).refresh_config())
+                    .collect(),
+                notify_tx: self.event_tx.clone(),
+            });
+            self.registered.set(true);
+        }
+
+        let needs_fetch = self
+            .state
+            .read()
+            .filters
+            .get(active)
+            .is_some_and(|s| s.loading);
+        let in_flight = self.in_flight.read().get(active).copied().unwrap_or(false);
+        if self.refresh_all.get() && self.is_active {
+            tracing::debug!("list: refreshing all {} tabs", all.len());
+            self.refresh_all.set(false);
+            for (filter_idx, (f, _)) in all.iter().enumerate() {
+                self.send(*f, filter_idx, true);
+            }
+        } else if needs_fetch
+            && !in_flight
+            && self.is_active
+            && let Some((f, _)) = all.get(active)
+        {
+            // Consume the force flag: bypass cache for `r`-key and post-mutation fetches.
+            let force = self.force.get();
+            if force {
+                self.force.set(false);
+            }
+            tracing::debug!("list: lazy fetch of tab {active} force={force}");
+            self.send(*f, active, force);
+        }
+
+        let pending = badge_tabs_to_fetch(&self.state.read(), &self.in_flight.read(), &badge_tabs);
+        for filter_idx in pending {
+            self.send(&configured[filter_idx], filter_idx, false);
+        }
+        badge_tabs
+    }
+
+    fn send<F: ListFilter>(&mut self, filter: &F, filter_idx: usize, force: bool) {
+        super::common::set_in_flight(&mut self.in_flight, filter_idx, true);
+        if let Some(engine) = self.engine {
+            engine.send(filter.scoped(self.scope_repo).fetch(
+                filter_idx,
+                force,
+                self.event_tx.clone(),
+            ));
+        }
+    }
+}
+
+/// Debounced detail fetch: once `debounce_gen` held still for a full tick,
+/// `send` the `pending` request, once per generation.
+pub(crate) async fn debounce_detail<D: Clone + Send + Sync + 'static>(
+    debounce_gen: State<u64>,
+    pending: State<Option<D>>,
+    mut send: impl FnMut(D),
+) {
+    let mut last_gen = 0u64;
+    let mut spawned_gen = 0u64;
+    loop {
+        smol::Timer::after(Duration::from_millis(300)).await;
+        let current_gen = debounce_gen.get();
+        if current_gen != last_gen {
+            // Generation changed during this cycle — not stable yet.
+            last_gen = current_gen;
+        } else if current_gen > 0 && current_gen != spawned_gen {
+            // Stable for one full cycle and not yet spawned — fetch now.
+            let req = pending.read().clone();
+            if let Some(req) = req {
+                spawned_gen = current_gen;
+                send(req);
+            }
+        }
+    }
+}
+
+/// The footer's action feedback, with when it was shown.
+#[derive(Clone, Copy)]
+pub(crate) struct Status {
+    pub feedback: State<Option<ActionFeedback>>,
+    pub set_at: State<Option<Instant>>,
+}
+
+impl Status {
+    pub fn show(&mut self, feedback: ActionFeedback) {
+        self.feedback.set(Some(feedback));
+        self.set_at.set(Some(Instant::now()));
+    }
+}
+
+/// State the event loops of both list views update alike.
+pub(crate) struct ListEvents<T: ListItem> {
+    pub state: State<ListState<T>>,
+    pub tracking: FetchTracking,
+    pub status: Status,
+    pub profile: State<Option<ProfileOverlay>>,
+    pub labels: State<Vec<String>>,
+    pub assignees: State<Vec<String>>,
+    pub issue_refs: State<Vec<String>>,
+}
+
+impl<T: ListItem> ListEvents<T> {
+    /// Handle an engine event neither view treats specially; other events
+    /// are ignored.
+    pub fn handle(&mut self, event: Event) {
+        match event {
+            Event::FetchError { context, message } if context == profile::FETCH_CONTEXT => {
+                profile::on_error(&mut self.profile, message);
+            }
+            Event::FetchError {
+                context: _,
+                message,
+            } => fail_in_flight(&mut self.state, &mut self.tracking, &message),
+            Event::FetchTimedOut { filter_idx, secs } => fail_filter(
+                &mut self.state,
+                &mut self.tracking,
+                filter_idx,
+                &super::common::timed_out_message(secs),
+            ),
+            Event::FetchCancelled { filter_idx } => {
+                cancel_filter(&mut self.state, &mut self.tracking, filter_idx);
+            }
+            Event::ContributionsFetched { login, calendar } => {
+                profile::on_fetched(&mut self.profile, login.as_deref(), calendar);
+            }
+            // The engine refreshes the item right after, which updates its
+            // row in place — no full table refresh needed.
+            Event::MutationOk { description } => {
+                self.status.show(ActionFeedback::Success(description));
+            }
+            Event::RefreshBudgetExceeded { message } => {
+                self.status.show(ActionFeedback::Warning(message));
+            }
+            Event::MutationError {
+                description,
+                message,
+            } => {
+                self.status
+                    .show(ActionFeedback::Error(format!("{description}: {message}")));
+            }
+            Event::RepoLabelsFetched { labels, .. } => {
+                self.labels.set(labels);
+            }
+            Event::RepoCollaboratorsFetched { logins, .. } => {
+                let mut combined = self.assignees.read().clone();
+                combined.extend(logins);
+                combined.sort();
+                combined.dedup();
+                self.assignees.set(combined);
+            }
+            Event::OpenIssueRefsFetched { refs, .. } => {
+                self.issue_refs.set(
+                    refs.into_iter()
+                        .map(|(number, title)| format!("#{number} {title}"))
+                        .collect(),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Overlays both list views show over their table.
+#[derive(Clone, Copy)]
+pub(crate) struct ListOverlays {
+    pub help: State<bool>,
+    pub profile: State<Option<ProfileOverlay>>,
+    pub drafts: State<Option<usize>>,
+    pub copy_menu: State<Option<CopyMenu>>,
+    pub share_menu: State<Option<ShareMenu>>,
+    pub status: Status,
+}
+
+impl ListOverlays {
+    /// Give `code` to the open overlay, if any. Returns whether one was open.
+    pub fn handle_key(
+        &mut self,
+        code: KeyCode,
+        engine: Option<&EngineHandle>,
+        host: Option<String>,
+        event_tx: &EventSender,
+    ) -> bool {
+        let feedback = if self.help.get() {
+            if matches!(code, KeyCode::Char('?') | KeyCode::Esc) {
+                self.help.set(false);
+            }
+            None
+        } else if self.profile.read().is_some() {
+            profile::handle_key(&mut self.profile, code, engine, host, event_tx);
+            None
+        } else if self.drafts.get().is_some() {
+            super::drafts::handle_key(&mut self.drafts, code)
+        } else if self.copy_menu.read().is_some() {
+            copy_menu::handle_key(&mut self.copy_menu, code)
+        } else if self.share_menu.read().is_some() {
+            share_menu::handle_key(&mut self.share_menu, code, engine, event_tx)
+        } else {
+            return false;
+        };
+        if let Some(feedback) = feedback {
+            self.status.show(feedback);
+        }
+        true
+    }
+}
+
+/// Edit the search `query` with `code`. The list goes back to its top on
+/// each edit. Returns whether the search input is done: Enter keeps the
+/// query, Esc clears it.
+pub(crate) fn search_key(
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    query: &mut State<String>,
+    cursor: &mut State<usize>,
+    scroll_offset: &mut State<usize>,
+) -> bool {
+    let mut q = query.read().clone();
+    match code {
+        KeyCode::Esc => {
+            query.set(String::new());
+            return true;
+        }
+        KeyCode::Enter => return true,
+        KeyCode::Backspace => {
+            q.pop();
+        }
+        KeyCode::Char(ch) if !modifiers.contains(KeyModifiers::CONTROL) => q.push(ch),
+        _ => return false,
+    }
+    query.set(q);
+    cursor.set(0);
+    scroll_offset.set(0);
+    false
+}
+
+/// What the views signal the app to do.
+#[derive(Clone, Copy)]
+pub(crate) struct AppSignals {
+    pub should_exit: Option<State<bool>>,
+    pub switch_view: Option<State<bool>>,
+    pub switch_view_back: Option<State<bool>>,
+    pub goto_view: Option<State<Option<ViewKind>>>,
+    pub scope_toggle: Option<State<bool>>,
+    pub show_logs: Option<State<bool>>,
+    pub date_toggle: Option<State<bool>>,
+    pub repo_picker: Option<State<bool>>,
+    pub rescan_repo: Option<State<bool>>,
+    /// Width of the preview sidebar, as a fraction of the view.
+    pub preview_pct: Option<State<f64>>,
+    pub default_pct: f64,
+}
+
+impl AppSignals {
+    /// Signal the app for `action`. Returns whether it is an app action.
+    pub fn handle(&self, action: BuiltinAction) -> bool {
+        let signal = match action {
+            BuiltinAction::Quit => self.should_exit,
+            BuiltinAction::SwitchView => self.switch_view,
+            BuiltinAction::SwitchViewBack => self.switch_view_back,
+            BuiltinAction::ToggleScope => self.scope_toggle,
+            BuiltinAction::ShowLogs => self.show_logs,
+            BuiltinAction::ToggleDates => self.date_toggle,
+            BuiltinAction::SelectRepo => self.repo_picker,
+            BuiltinAction::RescanRepo => self.rescan_repo,
+            BuiltinAction::SidebarWider
+            | BuiltinAction::SidebarNarrower
+            | BuiltinAction::SidebarResetWidth => {
+                if let Some(mut pct) = self.preview_pct {
+                    pct.set(sidebar_width(action, pct.get(), self.default_pct));
+                }
+                return true;
+            }
+            action => {
+                let Some(target) = super::common::goto_target(action) else {
+                    return false;
+                };
+                if let Some(mut gv) = self.goto_view {
+                    gv.set(Some(target));
+                }
+                return true;
+            }
+        };
+        if let Some(mut s) = signal {
+            s.set(true);
+        }
+        true
+    }
+}
+
+/// The preview sidebar width after `action`, from `pct`.
+fn sidebar_width(action: BuiltinAction, pct: f64, default_pct: f64) -> f64 {
+    match action {
+        BuiltinAction::SidebarWider => (pct + 0.05).min(0.80),
+        BuiltinAction::SidebarNarrower => (pct - 0.05).max(0.15),
+        _ => default_pct,
+    }
+}
+
+/// Cursor `(position, scroll offset)` after `action` in a table of
+/// `total_rows` showing `visible_rows`, for the actions that move it.
+fn moved_cursor(
+    action: BuiltinAction,
+    (cursor, scroll): (usize, usize),
+    total_rows: usize,
+    visible_rows: usize,
+) -> Option<(usize, usize)> {
+    let last = total_rows.saturating_sub(1);
+    let down = |step: usize| {
+        let c = (cursor + step).min(last);
+        let s = if c >= scroll + visible_rows {
+            c.saturating_sub(visible_rows) + 1
+        } else {
+            scroll
+        };
+        (c, s)
+    };
+    let up = |step: usize| {
+        let c = cursor.saturating_sub(step);
+        (c, scroll.min(c))
+    };
+    match action {
+        BuiltinAction::MoveDown if total_rows > 0 => Some(down(1)),
+        BuiltinAction::HalfPageDown if total_rows > 0 => Some(down(visible_rows / 2)),
+        BuiltinAction::MoveUp => Some(up(1)),
+        BuiltinAction::HalfPageUp => Some(up(visible_rows / 2)),
+        BuiltinAction::First => Some((0, 0)),
+        BuiltinAction::Last if total_rows > 0 => {
+            Some((last, total_rows.saturating_sub(visible_rows)))
+        }
+        BuiltinAction::PageDown if total_rows > 0 => {
+            let c = (cursor + visible_rows).min(last);
+            Some((c, c.saturating_sub(visible_rows.saturating_sub(1))))
+        }
+        BuiltinAction::PageUp => Some((
+            cursor.saturating_sub(visible_rows),
+            scroll.saturating_sub(visible_rows),
+        )),
+        _ => None,
+    }
+}
+
+/// Cursor and tabs of a list view, with filters of type `F`.
+pub(crate) struct ListNav<T: ListItem, F: Send + Sync + 'static> {
+    pub state: State<ListState<T>>,
+    pub tracking: FetchTracking,
+    pub ephemeral: State<Vec<(F, Option<u64>)>>,
+    pub active_filter: State<usize>,
+    pub cursor: State<usize>,
+    pub scroll_offset: State<usize>,
+    pub preview_open: State<bool>,
+    pub preview_scroll: State<usize>,
+}
+
+impl<T: ListItem, F: Send + Sync + 'static> Clone for ListNav<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ListItem, F: Send + Sync + 'static> Copy for ListNav<T, F> {}
+
+impl<T: ListItem, F: Clone + Send + Sync + 'static> ListNav<T, F> {
+    /// Move the cursor for `action`. Half pages scroll the preview instead
+    /// while it is open. Returns whether `action` moves the cursor.
+    pub fn navigate(
+        &mut self,
+        action: BuiltinAction,
+        total_rows: usize,
+        visible_rows: usize,
+    ) -> bool {
+        let half = visible_rows / 2;
+        match action {
+            BuiltinAction::HalfPageDown if self.preview_open.get() => {
+                self.preview_scroll.set(self.preview_scroll.get() + half);
+            }
+            BuiltinAction::HalfPageUp if self.preview_open.get() => {
+                self.preview_scroll
+                    .set(self.preview_scroll.get().saturating_sub(half));
+            }
+            // Nothing to move, but the key is still taken.
+            BuiltinAction::HalfPageDown if total_rows == 0 => {}
+            _ => {
+                let position = (self.cursor.get(), self.scroll_offset.get());
+                let Some((cursor, scroll)) =
+                    moved_cursor(action, position, total_rows, visible_rows)
+                else {
+                    return false;
+                };
+                self.cursor.set(cursor);
+                self.scroll_offset.set(scroll);
+                self.preview_scroll.set(0);
+            }
+        }
+        true
+    }
+
+    /// Switch to the previous or next of `total_tabs` tabs, wrapping around.
+    /// Returns whether `action` switches tabs.
+    pub fn switch_tab(&mut self, action: BuiltinAction, total_tabs: usize) -> bool {
+        let current = self.active_filter.get();
+        let tab = match action {
+            _ if total_tabs == 0 => return false,
+            BuiltinAction::PrevFilter => current.checked_sub(1).unwrap_or(total_tabs - 1),
+            BuiltinAction::NextFilter => (current + 1) % total_tabs,
+            _ => return false,
+        };
+        self.select_tab(tab);
+        true
+    }
+
+    fn select_tab(&mut self, tab: usize) {
+        self.active_filter.set(tab);
+        self.cursor.set(0);
+        self.scroll_offset.set(0);
+        self.preview_scroll.set(0);
+    }
+
+    /// Open an ephemeral tab for `filter`, positioned on item `number` once
+    /// loaded, and switch to it. Ephemeral tabs follow the `configured` ones.
+    pub fn open_tab(&mut self, configured: usize, filter: F, number: Option<u64>) {
+        let mut eph = self.ephemeral.read().clone();
+        eph.push((filter, number));
+        let tab = configured + eph.len() - 1;
+        self.ephemeral.set(eph);
+
+        let mut state = self.state.read().clone();
+        state.filters.push(Arc::new(FilterData::default()));
+        self.state.set(state);
+        let mut in_flight = self.tracking.in_flight.read().clone();
+        in_flight.push(false);
+        self.tracking.in_flight.set(in_flight);
+        let mut times = self.tracking.fetch_times.read().clone();
+        times.push(None);
+        self.tracking.fetch_times.set(times);
+
+        self.select_tab(tab);
+    }
+
+    /// Close tab `tab` if it is ephemeral, i.e. after the `configured` ones.
+    /// Returns whether it was; config tabs stay.
+    pub fn close_tab(&mut self, configured: usize, tab: usize) -> bool {
+        let Some(ei) = tab.checked_sub(configured) else {
+            return false;
+        };
+        let mut eph = self.ephemeral.read().clone();
+        debug_assert!(ei < eph.len(), "ephemeral index out of range");
+        eph.remove(ei);
+        let total = configured + eph.len();
+        self.ephemeral.set(eph);
+
+        let mut state = self.state.read().clone();
+        if tab < state.filters.len() {
+            state.filters.remove(tab);
+        }
+        self.state.set(state);
+        let mut in_flight = self.tracking.in_flight.read().clone();
+        if tab < in_flight.len() {
+            in_flight.remove(tab);
+        }
+        self.tracking.in_flight.set(in_flight);
+        let mut times = self.tracking.fetch_times.read().clone();
+        if tab < times.len() {
+            times.remove(tab);
+        }
+        self.tracking.fetch_times.set(times);
+
+        if self.active_filter.get() >= total && total > 0 {
+            self.active_filter.set(total - 1);
+        }
+        self.cursor.set(0);
+        self.scroll_offset.set(0);
+        true
+    }
+}
+
+/// Per-tab fetch bookkeeping shared by the list views' event loops.
+#[derive(Clone, Copy)]
+pub(crate) struct FetchTracking {
+    pub fetch_times: State<Vec<Option<Instant>>>,
+    pub in_flight: State<Vec<bool>>,
+}
+
+impl FetchTracking {
+    fn finish(&mut self, filter_idx: usize) {
+        let mut times = self.fetch_times.read().clone();
+        if filter_idx < times.len() {
+            times[filter_idx] = Some(Instant::now());
+        }
+        self.fetch_times.set(times);
+        super::common::set_in_flight(&mut self.in_flight, filter_idx, false);
+    }
+}
+
+/// Whether a streamed page for `filter_idx` should be dropped.
+///
+/// Pages stream in only on a first load; a refresh keeps the old rows until
+/// the complete set arrives.
+pub(crate) fn skip_streamed_page<T: ListItem>(
+    state: &State<ListState<T>>,
+    filter_idx: usize,
+    complete: bool,
+) -> bool {
+    !complete
+        && state
+            .read()
+            .filters
+            .get(filter_idx)
+            .is_some_and(|f| !f.loading && !f.loading_more)
+}
+
+/// Store fetched results for `filter_idx`.
+///
+/// `cost` is the fetch's rate-limit cost, `None` for cache hits and streamed
+/// pages, which keep the tab's last known cost. The tab's fetch completes
+/// unless `data` is a streamed page.
+pub(crate) fn store_fetched<T: ListItem>(
+    state: &mut State<ListState<T>>,
+    tracking: &mut FetchTracking,
+    filter_idx: usize,
+    mut data: FilterData<T>,
+    cost: Option<u32>,
+) {
+    let complete = !data.loading_more;
+    let mut snapshot = state.read().clone();
+    if let Some(slot) = snapshot.filters.get_mut(filter_idx) {
+        data.refresh_cost = cost.or(slot.refresh_cost);
+        *slot = Arc::new(data);
+    }
+    state.set(snapshot);
+    if complete {
+        tracking.finish(filter_idx);
+    }
+}
+
+/// Show cached results for `filter_idx` while it loads for the first time.
+/// Its fetch stays in flight; anything already loaded is kept.
+pub(crate) fn store_cached<T: ListItem>(
+    state: &mut State<ListState<T>>,
+    filter_idx: usize,
+    data: FilterData<T>,
+) {
+    let mut snapshot = state.read().clone();
+    if let Some(slot) = snapshot.filters.get_mut(filter_idx)
+        && slot.loading
+    {
+        *slot = Arc::new(data);
+        state.set(snapshot);
+    }
+}
+
+/// Mark the in-flight tab as failed with `message`.
+///
+/// Fetch errors don't name their filter; the first in-flight tab is the one
+/// being loaded.
+pub(crate) fn fail_in_flight<T: ListItem>(
+    state: &mut State<ListState<T>>,
+    tracking: &mut FetchTracking,
+    message: &str,
+) {
+    let in_flight = tracking.in_flight.read().iter().position(|&f| f);
+    if let Some(fi) = in_flight {
+        fail_filter(state, tracking, fi, message);
+    }
+}
+
+/// Mark tab `fi` as failed with `message`. Cached rows stay listed under
+/// the error.
+pub(crate) fn fail_filter<T: ListItem>(
+    state: &mut State<ListState<T>>,
+    tracking: &mut FetchTracking,
+    fi: usize,
+    message: &str,
+) {
+    let mut snapshot = state.read().clone();
+    if let Some(slot) = snapshot.filters.get_mut(fi) {
+        let failed = if slot.stale.is_some() {
+            FilterData {
+                error: Some(message.to_owned()),
+                ..(**slot).clone()
+            }
+        } else {
+            FilterData {
+                loading: false,
+                error: Some(message.to_owned()),
+                ..FilterData::default()
+            }
+        };
+        *slot = Arc::new(failed);
+    }
+    state.set(snapshot);
+    tracking.finish(fi);
+}
+
+/// Forget the cancelled fetch of tab `fi`. A tab cut short while its first
+/// pages streamed in, or still showing cached rows, goes back to loading, so
+/// it is fetched again.
+pub(crate) fn cancel_filter<T: ListItem>(
+    state: &mut State<ListState<T>>,
+    tracking: &mut FetchTracking,
+    fi: usize,
+) {
+    if state
+        .read()
+        .filters
+        .get(fi)
+        .is_some_and(|f| f.loading_more || (f.stale.is_some() && f.error.is_none()))
+    {
+        let mut snapshot = state.read().clone();
+        snapshot.filters[fi] = Arc::new(FilterData::default());
+        state.set(snapshot);
+    }
+    super::common::set_in_flight(&mut tracking.in_flight, fi, false);
+}
+
+/// Those of `tabs` waiting for their first load with no fetch in flight.
+pub(crate) fn badge_tabs_to_fetch<T>(
+    state: &ListState<T>,
+    in_flight: &[bool],
+    tabs: &[usize],
+) -> Vec<usize> {
+    tabs.iter()
+        .copied()
+        .filter(|&i| state.filters.get(i).is_some_and(|d| d.loading))
+        .filter(|&i| !in_flight.get(i).copied().unwrap_or(false))
+        .collect()
+}
+
+/// Footer count of the view: the distinct items of its loaded badge tabs.
+pub(crate) fn badge_count<T: ListItem>(state: &ListState<T>, tabs: &[usize]) -> Option<u32> {
+    super::common::badge_count(
+        tabs.iter()
+            .filter_map(|&i| state.filters.get(i))
+            .filter(|d| !d.loading)
+            .map(|d| d.items.iter().map(ListItem::url)),
+    )
+}
+
+/// Apply `f` to item `idx` of tab `filter_idx`, if both exist.
+pub(crate) fn with_item<T: ListItem, R>(
+    state: &State<ListState<T>>,
+    filter_idx: usize,
+    idx: Option<usize>,
+    f: impl FnOnce(&T) -> R,
+) -> Option<R> {
+    let state = state.read();
+    state
+        .filters
+        .get(filter_idx)
+        .and_then(|fd| fd.items.get(idx?))
+        .map(f)
+}
+
+/// `(owner, repo, number)` of the item at `idx` in the tab.
+pub(crate) fn item_info<T: ListItem>(
+    state: &State<ListState<T>>,
+    filter_idx: usize,
+    idx: Option<usize>,
+) -> Option<(String, String, u64)> {
+    with_item(state, filter_idx, idx, |item| {
+        let repo = item.repo()?;
+        Some((repo.owner.clone(), repo.name.clone(), item.number()))
+    })
+    .flatten()
+}
+
+/// Host of the item at `idx` in the tab, read from its URL; `None` for
+/// github.com.
+pub(crate) fn item_host<T: ListItem>(
+    state: &State<ListState<T>>,
+    filter_idx: usize,
+    idx: Option<usize>,
+) -> Option<String> {
+    with_item(state, filter_idx, idx, |item| {
+        crate::url::host_of(item.url())
+    })
+    .flatten()
+}
+
+/// Where a comment on the item at `idx` in the tab is drafted.
+pub(crate) fn draft_target<T: ListItem>(
+    state: &State<ListState<T>>,
+    filter_idx: usize,
+    idx: Option<usize>,
+) -> Option<DraftTarget> {
+    with_item(state, filter_idx, idx, |item| {
+        let repo = item.repo()?;
+        Some(DraftTarget::new(
+            &repo.full_name(),
+            item.number(),
+            item.title(),
+            item.url(),
+        ))
+    })
+    .flatten()
+}
+
+/// Label names of the item at `idx` in the tab.
+pub(crate) fn item_labels<T: ListItem>(
+    state: &State<ListState<T>>,
+    filter_idx: usize,
+    idx: Option<usize>,
+) -> Vec<String> {
+    with_item(state, filter_idx, idx, |item| {
+        item.labels().iter().map(|l| l.name.clone()).collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Assignee logins of the item at `idx` in the tab.
+pub(crate) fn item_assignees<T: ListItem>(
+    state: &State<ListState<T>>,
+    filter_idx: usize,
+    idx: Option<usize>,
+) -> Vec<String> {
+    with_item(state, filter_idx, idx, |item| {
+        item.assignees().iter().map(|a| a.login.clone()).collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Template variables for the reply picker, taken from `item`.
+pub(crate) fn reply_vars<T: ListItem>(item: &T) -> super::common::ReplyVars {
+    super::common::ReplyVars {
+        author: item.author().map(|a| a.login.clone()).unwrap_or_default(),
+        number: item.number(),
+        title: item.title().to_owned(),
+        repo: item.repo().map(RepoRef::full_name).unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_moves_keep_it_in_view() {
+        // 10 rows, 4 visible, cursor on the last visible row.
+        let at = (3, 0);
+        assert_eq!(
+            moved_cursor(BuiltinAction::MoveDown, at, 10, 4),
+            Some((4, 1))
+        );
+        assert_eq!(
+            moved_cursor(BuiltinAction::MoveUp, (4, 3), 10, 4),
+            Some((3, 3))
+        );
+        assert_eq!(
+            moved_cursor(BuiltinAction::MoveUp, (3, 3), 10, 4),
+            Some((2, 2))
+        );
+        assert_eq!(moved_cursor(BuiltinAction::Last, at, 10, 4), Some((9, 6)));
+        assert_eq!(
+            moved_cursor(BuiltinAction::First, (9, 6), 10, 4),
+            Some((0, 0))
+        );
+        assert_eq!(
+            moved_cursor(BuiltinAction::PageDown, at, 10, 4),
+            Some((7, 4))
+        );
+        assert_eq!(
+            moved_cursor(BuiltinAction::PageDown, (8, 6), 10, 4),
+            Some((9, 6))
+        );
+        assert_eq!(
+            moved_cursor(BuiltinAction::PageUp, (7, 4), 10, 4),
+            Some((3, 0))
+        );
+        assert_eq!(
+            moved_cursor(BuiltinAction::HalfPageDown, at, 10, 4),
+            Some((5, 2))
+        );
+        assert_eq!(
+            moved_cursor(BuiltinAction::HalfPageUp, (5, 2), 10, 4),
+            Some((3, 2))
+        );
+    }
+
+    #[test]
+    fn cursor_moves_down_only_with_rows() {
+        for action in [
+            BuiltinAction::MoveDown,
+            BuiltinAction::HalfPageDown,
+            BuiltinAction::Last,
+            BuiltinAction::PageDown,
+        ] {
+            assert_eq!(moved_cursor(action, (0, 0), 0, 4), None, "{action:?}");
+        }
+        assert_eq!(moved_cursor(BuiltinAction::Refresh, (0, 0), 10, 4), None);
+    }
+
+    #[test]
+    fn sidebar_width_stays_in_bounds() {
+        assert!((sidebar_width(BuiltinAction::SidebarWider, 0.5, 0.4) - 0.55).abs() < 1e-9);
+        assert!((sidebar_width(BuiltinAction::SidebarWider, 0.78, 0.4) - 0.80).abs() < 1e-9);
+        assert!((sidebar_width(BuiltinAction::SidebarNarrower, 0.17, 0.4) - 0.15).abs() < 1e-9);
+        assert!((sidebar_width(BuiltinAction::SidebarResetWidth, 0.7, 0.4) - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn pr_filters_are_scoped_without_team_review() {
+        let filter = PrFilter {
+            title: "Reviews".to_owned(),
+            filters: "is:open is:team-review".to_owned(),
+            host: None,
+            limit: None,
+            layout: None,
+            hide_drafts: None,
+            preview: None,
+            preview_tab: None,
+            refetch_interval_minutes: None,
+            badge: Some(true),
+        };
+        let scoped = filter.scoped(Some("acme/app"));
+        assert_eq!(scoped.title, "Reviews");
+        assert_eq!(scoped.filters, "is:open repo:acme/app");
+        assert!(scoped.badge());
+    }
+}
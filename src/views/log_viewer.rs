@@ -0,0 +1,205 @@
+//! Overlay listing this session's log, from the in-memory buffer kept by
+//! [`crate::logging`].
+//!
+//! Opened by `show_logs` from any view. Typing filters the lines, `Tab`
+//! cycles the lowest level shown, and the list follows new events until the
+//! cursor moves up.
+
+use iocraft::prelude::*;
+use tracing::Level;
+
+use crate::color::ColorDepth;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::logging::{self, Record};
+use crate::theme::ResolvedTheme;
+
+/// Lines shown at once.
+const VISIBLE_ROWS: usize = 20;
+
+/// The open log viewer.
+#[derive(Debug, Clone)]
+pub(crate) struct LogViewer {
+    /// Lowest level shown.
+    level: Level,
+    filter: String,
+    /// Selected line; `None` follows the newest.
+    cursor: Option<usize>,
+}
+
+impl LogViewer {
+    pub(crate) fn new() -> Self {
+        Self {
+            level: Level::INFO,
+            filter: String::new(),
+            cursor: None,
+        }
+    }
+
+    /// Lines of `records` at or above the level and matching the filter.
+    fn lines(&self, records: &[Record]) -> Vec<String> {
+        let needle = self.filter.to_lowercase();
+        records
+            .iter()
+            .filter(|record| record.level <= self.level)
+            .map(|record| {
+                format!(
+                    "{} {:<5} {}: {}",
+                    record.at.format("%H:%M:%S"),
+                    record.level.as_str(),
+                    record.target,
+                    record.message
+                )
+            })
+            .filter(|line| needle.is_empty() || line.to_lowercase().contains(&needle))
+            .collect()
+    }
+
+    /// Apply `code` given `len` lines; returns whether the viewer stays open.
+    fn key(&mut self, code: KeyCode, len: usize) -> bool {
+        let last = len.saturating_sub(1);
+        let cursor = self.cursor.unwrap_or(last).min(last);
+        match code {
+            KeyCode::Esc => return false,
+            KeyCode::Tab => {
+                self.level = match self.level {
+                    Level::ERROR => Level::WARN,
+                    Level::WARN => Level::INFO,
+                    Level::INFO => Level::DEBUG,
+                    _ => Level::ERROR,
+                };
+                self.cursor = None;
+            }
+            KeyCode::Up => self.cursor = Some(cursor.saturating_sub(1)),
+            KeyCode::PageUp => self.cursor = Some(cursor.saturating_sub(VISIBLE_ROWS)),
+            KeyCode::Home => self.cursor = Some(0),
+            KeyCode::Down | KeyCode::PageDown => {
+                let step = if code == KeyCode::Down {
+                    1
+                } else {
+                    VISIBLE_ROWS
+                };
+                let next = cursor + step;
+                self.cursor = (next < last).then_some(next);
+            }
+            KeyCode::End => self.cursor = None,
+            KeyCode::Backspace => {
+                self.filter.pop();
+                self.cursor = None;
+            }
+            KeyCode::Char(ch) => {
+                self.filter.push(ch);
+                self.cursor = None;
+            }
+            _ => {}
+        }
+        true
+    }
+}
+
+/// Handle a key while the viewer is open, closing it on Esc.
+pub(crate) fn handle_key(viewer: &mut State<Option<LogViewer>>, code: KeyCode) {
+    let Some(mut current) = viewer.read().clone() else {
+        return;
+    };
+    let len = current.lines(&logging::recent()).len();
+    if current.key(code, len) {
+        viewer.set(Some(current));
+    } else {
+        viewer.set(None);
+    }
+}
+
+/// Render the viewer, if open.
+pub(crate) fn render(
+    viewer: &State<Option<LogViewer>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let viewer = viewer.read();
+    let current = viewer.as_ref()?;
+    let lines = current.lines(&logging::recent());
+    let last = lines.len().saturating_sub(1);
+    let cursor = current.cursor.unwrap_or(last).min(last);
+    let offset = if current.cursor.is_none() {
+        lines.len().saturating_sub(VISIBLE_ROWS)
+    } else {
+        cursor.saturating_sub(VISIBLE_ROWS / 2)
+    };
+    let items: Vec<SelectionOverlayItem> = if lines.is_empty() {
+        vec![SelectionOverlayItem {
+            label: "No events at this level yet".to_owned(),
+        }]
+    } else {
+        lines
+            .into_iter()
+            .skip(offset)
+            .take(VISIBLE_ROWS)
+            .map(|label| SelectionOverlayItem { label })
+            .collect()
+    };
+    let title = match logging::session_file() {
+        Some(path) => format!("Log ({}+) — {}", current.level, path.display()),
+        None => format!("Log ({}+)", current.level),
+    };
+    let mut rendered = RenderedSelectionOverlay::build(SelectionOverlayBuildConfig {
+        title,
+        items,
+        cursor: cursor - offset,
+        show_filter: true,
+        filter_text: current.filter.clone(),
+        hint: Some("Tab level  End follow  Esc close".to_owned()),
+        depth,
+        title_color: Some(theme.text_primary),
+        item_color: Some(theme.text_secondary),
+        cursor_color: Some(theme.text_primary),
+        selected_bg: Some(theme.bg_selected),
+        border_color: Some(theme.border_primary),
+        hint_color: Some(theme.text_faint),
+        filter_prompt_color: Some(theme.text_faint),
+        filter_text_color: Some(theme.text_primary),
+        cursor_marker: theme.icons.select_cursor.clone(),
+        chrome: theme.chrome,
+    });
+    rendered.wide = true;
+    Some(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(level: Level, message: &str) -> Record {
+        Record {
+            at: chrono::Local::now(),
+            level,
+            target: "gh_board::engine".to_owned(),
+            message: message.to_owned(),
+        }
+    }
+
+    #[test]
+    fn level_and_filter_narrow_the_lines() {
+        let records = [
+            record(Level::ERROR, "FetchPrs failed"),
+            record(Level::WARN, "rate limit low"),
+            record(Level::INFO, "refreshing FetchPrs"),
+        ];
+        let mut viewer = LogViewer::new();
+        assert_eq!(viewer.lines(&records).len(), 3);
+        viewer.key(KeyCode::Tab, 3);
+        viewer.key(KeyCode::Tab, 3);
+        assert_eq!(viewer.level, Level::ERROR);
+        assert_eq!(viewer.lines(&records).len(), 1);
+        viewer.key(KeyCode::Tab, 1);
+        viewer.key(KeyCode::Tab, 1);
+        for ch in "fetchprs".chars() {
+            viewer.key(KeyCode::Char(ch), 3);
+        }
+        let lines = viewer.lines(&records);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains("INFO  gh_board::engine: refreshing FetchPrs"));
+        assert!(!viewer.key(KeyCode::Esc, 2));
+    }
+}
@@ -1,10 +1,32 @@
 pub mod actions;
 pub mod alerts;
+pub(crate) mod board;
 pub(crate) mod common;
+pub(crate) mod copy_menu;
+pub(crate) mod dispatch;
+pub(crate) mod drafts;
+pub mod events;
+pub(crate) mod files_menu;
 pub mod issues;
+pub(crate) mod job_log;
+pub(crate) mod keep_tab;
+pub(crate) mod list;
+pub(crate) mod log_viewer;
+pub(crate) mod notes;
 pub mod notifications;
+pub(crate) mod profile;
 pub mod prs;
+pub(crate) mod ref_picker;
+pub(crate) mod releases;
 pub mod repo;
+pub(crate) mod review;
+pub(crate) mod row_layout;
+pub(crate) mod run_workflow;
+pub(crate) mod schedules;
+pub(crate) mod script_prompt;
+pub(crate) mod share_menu;
+pub(crate) mod sidebar_focus;
+pub(crate) mod step_excerpt;
 
 use crate::theme::ResolvedTheme;
 
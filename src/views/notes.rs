@@ -0,0 +1,63 @@
+//! Local notes in the PR and Issue views: the row marker, the note at the
+//! top of the Overview tab, and `edit_note`.
+
+use std::sync::Arc;
+
+use iocraft::prelude::State;
+
+use crate::color::ColorDepth;
+use crate::components::footer::ActionFeedback;
+use crate::components::table::{Row, Span};
+use crate::markdown::cache::{self as md_cache, MarkdownCache};
+use crate::markdown::renderer::{StyledLine, StyledSpan};
+use crate::notes;
+use crate::theme::ResolvedTheme;
+
+/// Mark `row` when `repo#number` has a note.
+pub(crate) fn mark_row(row: &mut Row, repo: &str, number: u64, theme: &ResolvedTheme) {
+    if notes::has(repo, number)
+        && let Some(info) = row.get_mut("info")
+    {
+        info.spans.push(Span {
+            text: format!(" {}", theme.icons.item_note),
+            color: Some(theme.text_warning),
+            bold: false,
+        });
+    }
+}
+
+/// The Overview lines of `repo#number`: its note under a heading, if it has
+/// one, then `body`.
+pub(crate) fn overview(
+    (repo, number): (&str, u64),
+    body: Arc<Vec<StyledLine>>,
+    cache: &MarkdownCache,
+    theme: &ResolvedTheme,
+    (depth, md_ready): (ColorDepth, State<u64>),
+) -> Arc<Vec<StyledLine>> {
+    let Some(note) = notes::get(repo, number) else {
+        return body;
+    };
+    let note_lines = cache
+        .get_or_render(&note, theme, depth, md_ready)
+        .unwrap_or_else(|| Arc::new(vec![md_cache::pending_line(theme)]));
+    let mut lines = vec![StyledLine::from_span(StyledSpan::bold(
+        format!("{} Note", theme.icons.item_note),
+        theme.text_warning,
+    ))];
+    lines.extend(note_lines.iter().cloned());
+    lines.push(StyledLine::blank());
+    lines.extend(body.iter().cloned());
+    Arc::new(lines)
+}
+
+/// Edit the note on `repo#number` in the editor.
+pub(crate) fn edit(repo: &str, number: u64) -> ActionFeedback {
+    let had_note = notes::has(repo, number);
+    match notes::edit(repo, number) {
+        Ok(Some(_)) => ActionFeedback::Success(format!("Saved note on {repo}#{number}")),
+        Ok(None) if had_note => ActionFeedback::Info(format!("Deleted note on {repo}#{number}")),
+        Ok(None) => ActionFeedback::Info("Empty note not saved".to_owned()),
+        Err(e) => ActionFeedback::Error(format!("Note: {e:#}")),
+    }
+}
@@ -1,28 +1,30 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use iocraft::prelude::*;
 
 use crate::actions::clipboard;
-use crate::app::ViewKind;
-use crate::color::ColorDepth;
+use crate::app::{ViewCounts, ViewKind, WatchBadges};
+use crate::color::{Color as AppColor, ColorDepth};
 use crate::components::footer::{
     self, ActionFeedback, Footer, FooterColors, FooterContent, RenderedFooter,
 };
 use crate::components::help_overlay::{HelpOverlay, HelpOverlayBuildConfig, RenderedHelpOverlay};
 use crate::components::tab_bar::{RenderedTabBar, Tab, TabBar, TabBarColors};
 use crate::components::table::{
-    Cell, Column, RenderedTable, Row, ScrollableTable, TableBuildConfig,
+    self as table, Cell, Column, RenderedTable, Row, ScrollableTable, SortDirection, SortValue,
+    TableBuildConfig, TableSort,
 };
 use crate::components::text_input::{RenderedTextInput, TextInput, TextInputColors};
 use crate::config::keybindings::{
     BuiltinAction, MergedBindings, ResolvedBinding, TemplateVars, ViewContext,
     execute_shell_command, expand_template, key_event_to_string,
 };
-use crate::config::types::NotificationFilter;
-use crate::engine::{EngineHandle, Event, FilterConfig, Request};
+use crate::config::types::AppConfig;
+use crate::engine::{EngineHandle, Event, FilterConfig, RefreshView, Request};
 use crate::filter::{self, apply_scope};
 use crate::theme::ResolvedTheme;
-use crate::types::{Notification, RateLimitInfo, SubjectType};
+use crate::types::{CommitCheckState, Notification, RateLimits, SubjectState, SubjectType};
 
 // ---------------------------------------------------------------------------
 // Notification-specific column definitions (FR-031)
@@ -36,6 +38,7 @@ fn notification_columns() -> Vec<Column> {
             default_width_pct: 0.03,
             align: TextAlign::Center,
             fixed_width: Some(3),
+            sort: Some(SortDirection::Descending),
         },
         Column {
             id: "type".to_owned(),
@@ -43,13 +46,31 @@ fn notification_columns() -> Vec<Column> {
             default_width_pct: 0.05,
             align: TextAlign::Center,
             fixed_width: Some(6),
+            sort: Some(SortDirection::Ascending),
+        },
+        Column {
+            id: "state".to_owned(),
+            header: "State".to_owned(),
+            default_width_pct: 0.05,
+            align: TextAlign::Center,
+            fixed_width: Some(6),
+            sort: None,
+        },
+        Column {
+            id: "ci".to_owned(),
+            header: "CI".to_owned(),
+            default_width_pct: 0.03,
+            align: TextAlign::Center,
+            fixed_width: Some(4),
+            sort: None,
         },
         Column {
             id: "title".to_owned(),
             header: "Title".to_owned(),
-            default_width_pct: 0.38,
+            default_width_pct: 0.30,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "repo".to_owned(),
@@ -57,6 +78,7 @@ fn notification_columns() -> Vec<Column> {
             default_width_pct: 0.20,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "reason".to_owned(),
@@ -64,6 +86,7 @@ fn notification_columns() -> Vec<Column> {
             default_width_pct: 0.14,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "updated".to_owned(),
@@ -71,10 +94,22 @@ fn notification_columns() -> Vec<Column> {
             default_width_pct: 0.12,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: Some(SortDirection::Descending),
         },
     ]
 }
 
+/// The value `notification` is sorted by in `column`, for columns whose
+/// cells don't sort as text.
+fn notification_sort_value(notification: &Notification, column: &str) -> Option<SortValue> {
+    match column {
+        "unread" => Some(SortValue::Number(i64::from(notification.unread))),
+        "title" => Some(SortValue::Text(notification.subject_title.to_lowercase())),
+        "updated" => Some(SortValue::Number(notification.updated_at.timestamp())),
+        _ => None,
+    }
+}
+
 /// Convert a `Notification` into a table `Row`.
 fn notification_to_row(
     notification: &Notification,
@@ -107,9 +142,22 @@ fn notification_to_row(
     };
     row.insert("type".to_owned(), Cell::colored(type_icon, type_color));
 
-    // Title.
+    // Subject state and CI, once resolved.
+    if let Some(status) = &notification.subject_status {
+        let is_pr = notification.subject_type == Some(SubjectType::PullRequest);
+        let (state_icon, state_color) = subject_state_icon(status.state, is_pr, theme);
+        row.insert("state".to_owned(), Cell::colored(state_icon, state_color));
+        if let Some(ci) = status.ci {
+            let (ci_icon, ci_color) = subject_ci_icon(ci, theme);
+            row.insert("ci".to_owned(), Cell::colored(ci_icon, ci_color));
+        }
+    }
+
+    // Title: dimmed once the subject resolved itself.
     let expanded_title = crate::util::expand_emoji(&notification.subject_title);
-    let title_cell = if notification.unread {
+    let title_cell = if notification.is_resolved() {
+        Cell::colored(expanded_title, theme.text_faint)
+    } else if notification.unread {
         Cell::bold(expanded_title)
     } else {
         Cell::plain(expanded_title)
@@ -143,6 +191,37 @@ fn notification_to_row(
     row
 }
 
+fn subject_state_icon(
+    state: SubjectState,
+    is_pr: bool,
+    theme: &ResolvedTheme,
+) -> (String, AppColor) {
+    let icons = &theme.icons;
+    let (icon, color) = match (state, is_pr) {
+        (SubjectState::Open, true) => (&icons.pr_open, theme.text_success),
+        (SubjectState::Draft, _) => (&icons.pr_draft, theme.text_faint),
+        (SubjectState::Closed, true) => (&icons.pr_closed, theme.text_error),
+        (SubjectState::Merged, _) => (&icons.pr_merged, theme.text_actor),
+        (SubjectState::Open, false) => (&icons.issue_open, theme.text_success),
+        (SubjectState::Closed, false) => (&icons.issue_closed, theme.text_actor),
+    };
+    (icon.clone(), color)
+}
+
+fn subject_ci_icon(state: CommitCheckState, theme: &ResolvedTheme) -> (String, AppColor) {
+    let icons = &theme.icons;
+    match state {
+        CommitCheckState::Success => (icons.ci_success.clone(), theme.text_success),
+        CommitCheckState::Failure | CommitCheckState::Error => {
+            (icons.ci_failure.clone(), theme.text_error)
+        }
+        CommitCheckState::Pending | CommitCheckState::Expected => {
+            (icons.ci_pending.clone(), theme.text_warning)
+        }
+        CommitCheckState::Unknown => (icons.ci_none.clone(), theme.text_faint),
+    }
+}
+
 /// Input modes for the notifications view.
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum InputMode {
@@ -165,8 +244,12 @@ struct FilterData {
     /// Original notification objects for structured filtering (T089).
     notifications: Vec<Notification>,
     notification_count: usize,
+    /// Hidden by `done` notification rules.
+    muted: usize,
     loading: bool,
     error: Option<String>,
+    /// Rate-limit cost of the last uncached fetch, for the footer.
+    refresh_cost: Option<u32>,
 }
 
 impl Default for FilterData {
@@ -176,8 +259,10 @@ impl Default for FilterData {
             ids: Vec::new(),
             notifications: Vec::new(),
             notification_count: 0,
+            muted: 0,
             loading: true,
             error: None,
+            refresh_cost: None,
         }
     }
 }
@@ -185,7 +270,57 @@ impl Default for FilterData {
 /// Shared state across all notification filters.
 #[derive(Debug, Clone)]
 struct NotificationsState {
-    filters: Vec<FilterData>,
+    filters: Vec<Arc<FilterData>>,
+}
+
+/// Show notification `id` (`None`: all of them) as read in every tab.
+fn mark_read(
+    state: &mut NotificationsState,
+    id: Option<&str>,
+    theme: &ResolvedTheme,
+    date_format: &str,
+) {
+    for fd in &mut state.filters {
+        let unread: Vec<usize> = (0..fd.notifications.len())
+            .filter(|&i| {
+                let n = &fd.notifications[i];
+                n.unread && id.is_none_or(|id| n.id == id)
+            })
+            .collect();
+        if unread.is_empty() {
+            continue;
+        }
+        let fd = Arc::make_mut(fd);
+        for i in unread {
+            fd.notifications[i].unread = false;
+            fd.rows[i] = notification_to_row(&fd.notifications[i], theme, date_format);
+        }
+    }
+}
+
+/// Mark tab `fi` as failed with `message`.
+fn fail_filter(
+    state: &mut State<NotificationsState>,
+    fetch_times: &mut State<Vec<Option<std::time::Instant>>>,
+    in_flight: &mut State<Vec<bool>>,
+    fi: usize,
+    message: String,
+) {
+    let mut snapshot = state.read().clone();
+    if let Some(slot) = snapshot.filters.get_mut(fi) {
+        *slot = Arc::new(FilterData {
+            loading: false,
+            error: Some(message),
+            ..FilterData::default()
+        });
+    }
+    state.set(snapshot);
+    let mut times = fetch_times.read().clone();
+    if fi < times.len() {
+        times[fi] = Some(std::time::Instant::now());
+    }
+    fetch_times.set(times);
+    super::common::set_in_flight(in_flight, fi, false);
 }
 
 // ---------------------------------------------------------------------------
@@ -194,12 +329,13 @@ struct NotificationsState {
 
 #[derive(Default, Props)]
 pub struct NotificationsViewProps<'a> {
-    pub filters: Option<&'a [NotificationFilter]>,
+    /// Config, replaced on each reload.
+    pub config: Option<Arc<AppConfig>>,
     /// Engine handle (replaces octocrab; used after T022-T023 refactor).
     pub engine: Option<&'a EngineHandle>,
-    pub theme: Option<&'a ResolvedTheme>,
+    pub theme: Option<Arc<ResolvedTheme>>,
     /// Merged keybindings for help overlay.
-    pub keybindings: Option<&'a MergedBindings>,
+    pub keybindings: Option<Arc<MergedBindings>>,
     pub color_depth: ColorDepth,
     pub width: u16,
     pub height: u16,
@@ -213,18 +349,28 @@ pub struct NotificationsViewProps<'a> {
     pub goto_view: Option<State<Option<ViewKind>>>,
     /// Signal to toggle repo scope.
     pub scope_toggle: Option<State<bool>>,
+    /// Signal to toggle relative/absolute dates.
+    pub date_toggle: Option<State<bool>>,
+    /// Signal to open the log viewer.
+    pub show_logs: Option<State<bool>>,
+    /// Set when the token cannot read notifications; the view shows it instead of fetching.
+    pub scope_hint: Option<String>,
     /// Signal to open the repo picker overlay.
     pub repo_picker: Option<State<bool>>,
+    /// Signal to scope to the repo worked in.
+    pub rescan_repo: Option<State<bool>>,
     /// Active scope repo (e.g. `"owner/repo"`), or `None` for global.
     pub scope_repo: Option<String>,
-    /// Date format string (from `config.defaults.date_format`).
-    pub date_format: Option<&'a str>,
     /// Whether this view is the currently active (visible) one.
     pub is_active: bool,
     /// Auto-refetch interval in minutes (0 = disabled).
     pub refetch_interval_minutes: u32,
     /// Shared rate-limit state (owned by App).
-    pub rate_limit: Option<State<Option<RateLimitInfo>>>,
+    pub rate_limit: Option<State<RateLimits>>,
+    /// Shared watched-item badges (owned by App).
+    pub watch_badges: Option<State<WatchBadges>>,
+    /// Shared footer counts of the views (owned by App).
+    pub view_counts: Option<State<ViewCounts>>,
 }
 
 #[component]
@@ -232,15 +378,26 @@ pub fn NotificationsView<'a>(
     props: &NotificationsViewProps<'a>,
     mut hooks: Hooks,
 ) -> impl Into<AnyElement<'a>> {
-    let filters_cfg = props.filters.unwrap_or(&[]);
-    let theme = props.theme.cloned().unwrap_or_else(default_theme);
+    let filters_cfg = props
+        .config
+        .as_deref()
+        .map_or(&[][..], |c| &c.notifications_filters);
+    let theme = props
+        .theme
+        .as_deref()
+        .cloned()
+        .unwrap_or_else(default_theme);
     let depth = props.color_depth;
     let should_exit = props.should_exit;
     let switch_view = props.switch_view;
     let switch_view_back = props.switch_view_back;
     let goto_view = props.goto_view;
     let scope_toggle = props.scope_toggle;
+    let date_toggle = props.date_toggle;
+    let show_logs = props.show_logs;
+    let scope_hint = props.scope_hint.clone();
     let repo_picker = props.repo_picker;
+    let rescan_repo = props.rescan_repo;
     let scope_repo = &props.scope_repo;
     let filter_count = filters_cfg.len();
     let is_active = props.is_active;
@@ -248,14 +405,18 @@ pub fn NotificationsView<'a>(
     let mut active_filter = hooks.use_state(|| 0usize);
     let mut cursor = hooks.use_state(|| 0usize);
     let mut scroll_offset = hooks.use_state(|| 0usize);
+    // Identity of the item under the cursor, followed across refreshes.
+    let mut cursor_anchor = hooks.use_state(super::common::CursorAnchor::default);
 
     // State: input mode and search (T087, T089).
     let mut input_mode = hooks.use_state(|| InputMode::Normal);
     let mut search_query = hooks.use_state(String::new);
+    // Column the table is sorted by; API order when `None`.
+    let mut table_sort = hooks.use_state(|| Option::<TableSort>::None);
     let mut help_visible = hooks.use_state(|| false);
     let mut action_status = hooks.use_state(|| Option::<ActionFeedback>::None);
     let mut status_set_at = hooks.use_state(|| Option::<std::time::Instant>::None);
-    let fallback_rl = hooks.use_state(|| None);
+    let fallback_rl = hooks.use_state(RateLimits::default);
     let mut rate_limit_state = props.rate_limit.unwrap_or(fallback_rl);
 
     // Whether RegisterNotificationsRefresh has been sent to the engine yet.
@@ -264,35 +425,70 @@ pub fn NotificationsView<'a>(
     // State: per-filter fetch tracking (lazy: only fetch the active filter).
     let mut filter_fetch_times =
         hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
+    // Per-filter auto-refresh postponement (`postpone_refresh`), for the countdown.
+    let mut refresh_postponed =
+        hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
     let mut filter_in_flight = hooks.use_state(move || vec![false; filter_count]);
     // Set by 'R' keypress; consumed by render body to fetch all filters eagerly.
     let mut refresh_all = hooks.use_state(|| false);
 
-    let initial_filters = vec![FilterData::default(); filter_count];
+    let initial_filters = super::common::empty_filters(filter_count);
     let mut notif_state = hooks.use_state(move || NotificationsState {
         filters: initial_filters,
     });
 
     // Event channel: engine sends events back to this view.
     let event_channel = hooks.use_state(super::common::new_event_channel);
-    let (event_tx, event_rx_arc) = event_channel.read().clone();
+    let (event_tx, event_rx) = event_channel.read().clone();
     // Clone so it can be captured in 'static futures.
     let engine: Option<crate::engine::EngineHandle> = props.engine.cloned();
+    let mut was_active = hooks.use_state(|| false);
+    super::common::cancel_fetches_on_leave(&mut was_active, is_active, engine.as_ref(), &event_tx);
+
+    // Track config reloads: keep to the tab shown and refetch every filter.
+    let mut last_filters = hooks.use_state(|| filters_cfg.to_vec());
+    let filters_changed = super::common::follow_reload(
+        &mut last_filters,
+        filters_cfg,
+        &mut active_filter,
+        &mut refresh_postponed,
+    );
 
     // Track scope changes: when scope_repo changes, invalidate all filters.
     let mut last_scope = hooks.use_state(|| scope_repo.clone());
-    if *last_scope.read() != *scope_repo {
+    if filters_changed || *last_scope.read() != *scope_repo {
         last_scope.set(scope_repo.clone());
         notif_state.set(NotificationsState {
-            filters: vec![FilterData::default(); filter_count],
+            filters: super::common::empty_filters(filter_count),
         });
         filter_fetch_times.set(vec![None; filter_count]);
         filter_in_flight.set(vec![false; filter_count]);
         refresh_registered.set(false);
     }
 
+    // Rebuild the cached rows' date cells after the relative/absolute toggle.
+    let mut date_generation = hooks.use_state(crate::util::date_generation);
+    if date_generation.get() != crate::util::date_generation() {
+        date_generation.set(crate::util::date_generation());
+        let date_format = super::common::date_format(props.config.as_deref());
+        let mut state = notif_state.read().clone();
+        for fd in &mut state.filters {
+            let fd = Arc::make_mut(fd);
+            fd.rows = fd
+                .notifications
+                .iter()
+                .map(|n| notification_to_row(n, &theme, date_format))
+                .collect();
+        }
+        notif_state.set(state);
+    }
+
     // Compute active filter index early (needed by fetch logic below).
     let current_filter_idx = active_filter.get().min(filter_count.saturating_sub(1));
+    // Ephemeral tabs are not registered for auto-refresh.
+    let refresh_interval = filters_cfg.get(current_filter_idx).map(|f| {
+        super::common::refresh_interval(f.refetch_interval_minutes, props.refetch_interval_minutes)
+    });
 
     // Lazy fetch: only fetch the active filter when it needs data.
     let active_needs_fetch = notif_state
@@ -330,6 +526,7 @@ pub fn NotificationsView<'a>(
 
     if refresh_all.get()
         && is_active
+        && scope_hint.is_none()
         && let Some(ref eng) = engine
     {
         // 'R' was pressed: reset the flag and eagerly fetch every filter.
@@ -347,6 +544,7 @@ pub fn NotificationsView<'a>(
     } else if active_needs_fetch
         && !active_in_flight
         && is_active
+        && scope_hint.is_none()
         && let Some(cfg) = filters_cfg.get(current_filter_idx)
         && let Some(ref eng) = engine
     {
@@ -363,37 +561,87 @@ pub fn NotificationsView<'a>(
         });
     }
 
-    // Poll engine events and update local state.
+    // Badge tabs load even while another view shows, for the footer count
+    // of their unread notifications.
+    let badge_tabs = super::common::badge_tabs(
+        filters_cfg
+            .iter()
+            .enumerate()
+            .map(|(i, f)| f.badge.unwrap_or(i == 0)),
+    );
+    if scope_hint.is_none()
+        && let Some(ref eng) = engine
+    {
+        for &filter_idx in &badge_tabs {
+            let loading = notif_state
+                .read()
+                .filters
+                .get(filter_idx)
+                .is_some_and(|s| s.loading);
+            let in_flight = filter_in_flight
+                .read()
+                .get(filter_idx)
+                .copied()
+                .unwrap_or(false);
+            if !loading || in_flight {
+                continue;
+            }
+            let cfg = &filters_cfg[filter_idx];
+            super::common::set_in_flight(&mut filter_in_flight, filter_idx, true);
+            let mut modified_filter = cfg.clone();
+            modified_filter.filters = apply_scope(&cfg.filters, scope_repo.as_deref());
+            eng.send(Request::FetchNotifications {
+                filter_idx,
+                filter: modified_filter,
+                reply_tx: event_tx.clone(),
+            });
+        }
+    }
+    let unread_count = {
+        let state = notif_state.read();
+        super::common::badge_count(
+            badge_tabs
+                .iter()
+                .filter_map(|&i| state.filters.get(i))
+                .filter(|d| !d.loading)
+                .map(|d| {
+                    d.notifications
+                        .iter()
+                        .filter(|n| n.unread)
+                        .map(|n| n.id.as_str())
+                }),
+        )
+    };
+    super::common::publish_view_count(props.view_counts, ViewKind::Notifications, unread_count);
+
+    hooks.use_future(super::common::expire_action_status(
+        action_status,
+        status_set_at,
+    ));
+
+    // Handle engine events as they arrive and update local state.
     {
-        let rx_for_poll = event_rx_arc.clone();
-        let current_filter_for_poll = current_filter_idx;
+        let rx_for_poll = event_rx.clone();
         let theme_for_poll = theme.clone();
-        let date_format_for_poll = props.date_format.unwrap_or("relative").to_owned();
+        let date_format_for_poll = super::common::date_format(props.config.as_deref()).to_owned();
         hooks.use_future(async move {
-            loop {
-                smol::Timer::after(std::time::Duration::from_millis(100)).await;
-                // Auto-clear status after 60 seconds.
-                if let Some(t) = status_set_at.get()
-                    && t.elapsed().as_secs() >= 60
-                {
-                    action_status.set(None);
-                    status_set_at.set(None);
-                }
-                let events: Vec<Event> = {
-                    let rx = rx_for_poll.lock().unwrap();
-                    let mut evts = Vec::new();
-                    while let Ok(evt) = rx.try_recv() {
-                        evts.push(evt);
-                    }
-                    evts
-                };
+            while let Some(events) = super::common::recv_batch(&rx_for_poll).await {
                 for evt in events {
                     match evt {
                         Event::NotificationsFetched {
                             filter_idx,
                             notifications,
+                            muted,
                             rate_limit,
                         } => {
+                            let refresh_cost =
+                                rate_limit.as_ref().map(|rl| rl.cost).or_else(|| {
+                                    notif_state
+                                        .read()
+                                        .filters
+                                        .get(filter_idx)
+                                        .and_then(|f| f.refresh_cost)
+                                });
                             let rows: Vec<Row> = notifications
                                 .iter()
                                 .map(|n| {
@@ -408,12 +656,14 @@ pub fn NotificationsView<'a>(
                                 ids,
                                 notifications,
                                 notification_count,
+                                muted,
                                 loading: false,
                                 error: None,
+                                refresh_cost,
                             };
                             let mut state = notif_state.read().clone();
                             if filter_idx < state.filters.len() {
-                                state.filters[filter_idx] = filter_data;
+                                state.filters[filter_idx] = Arc::new(filter_data);
                             }
                             notif_state.set(state);
                             let mut times = filter_fetch_times.read().clone();
@@ -424,44 +674,48 @@ pub fn NotificationsView<'a>(
                             super::common::set_in_flight(&mut filter_in_flight, filter_idx, false);
                             super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
                         }
-                        Event::FetchError {
-                            context: _,
-                            message,
-                        } => {
-                            let ifl = filter_in_flight.read().clone();
-                            let fi = ifl.iter().position(|&f| f);
+                        Event::FetchError { message, .. } => {
+                            let fi = filter_in_flight.read().iter().position(|&f| f);
                             if let Some(fi) = fi {
-                                let mut state = notif_state.read().clone();
-                                if fi < state.filters.len() {
-                                    state.filters[fi] = FilterData {
-                                        loading: false,
-                                        error: Some(message.clone()),
-                                        ..FilterData::default()
-                                    };
-                                }
-                                notif_state.set(state);
-                                let mut times = filter_fetch_times.read().clone();
-                                if fi < times.len() {
-                                    times[fi] = Some(std::time::Instant::now());
-                                }
-                                filter_fetch_times.set(times);
-                                super::common::set_in_flight(&mut filter_in_flight, fi, false);
+                                fail_filter(
+                                    &mut notif_state,
+                                    &mut filter_fetch_times,
+                                    &mut filter_in_flight,
+                                    fi,
+                                    message,
+                                );
                             }
                         }
+                        Event::FetchTimedOut { filter_idx, secs } => fail_filter(
+                            &mut notif_state,
+                            &mut filter_fetch_times,
+                            &mut filter_in_flight,
+                            filter_idx,
+                            super::common::timed_out_message(secs),
+                        ),
+                        Event::FetchCancelled { filter_idx } => {
+                            super::common::set_in_flight(&mut filter_in_flight, filter_idx, false);
+                        }
                         Event::MutationOk { description } => {
                             action_status.set(Some(ActionFeedback::Success(description)));
                             status_set_at.set(Some(std::time::Instant::now()));
-                            // Trigger refetch of current filter.
+                            // Reads arrive as NotificationsRead and patch the
+                            // rows in place; done and unsubscribed threads
+                            // were removed when confirmed.
+                        }
+                        Event::NotificationsRead { id } => {
                             let mut state = notif_state.read().clone();
-                            if current_filter_for_poll < state.filters.len() {
-                                state.filters[current_filter_for_poll] = FilterData::default();
-                            }
+                            mark_read(
+                                &mut state,
+                                id.as_deref(),
+                                &theme_for_poll,
+                                &date_format_for_poll,
+                            );
                             notif_state.set(state);
-                            let mut times = filter_fetch_times.read().clone();
-                            if current_filter_for_poll < times.len() {
-                                times[current_filter_for_poll] = None;
-                            }
-                            filter_fetch_times.set(times);
+                        }
+                        Event::RefreshBudgetExceeded { message } => {
+                            action_status.set(Some(ActionFeedback::Warning(message)));
+                            status_set_at.set(Some(std::time::Instant::now()));
                         }
                         Event::MutationError {
                             description,
@@ -480,24 +734,40 @@ pub fn NotificationsView<'a>(
     }
 
     let state_ref = notif_state.read();
-    let all_rows_count = state_ref
-        .filters
-        .get(current_filter_idx)
-        .map_or(0, |s| s.rows.len());
     let search_q = search_query.read().clone();
-    let total_rows = if search_q.is_empty() {
-        all_rows_count
-    } else {
-        state_ref.filters.get(current_filter_idx).map_or(0, |s| {
-            filter::filter_notifications(&s.notifications, &s.rows, &search_q).len()
-        })
-    };
+    let columns = notification_columns();
+    // Table rows map to notifications through these indices: the cursor is a
+    // position in the visible list, not in `notifications`.
+    let current_sort = table_sort.read().clone();
+    let visible_indices: Vec<usize> =
+        state_ref
+            .filters
+            .get(current_filter_idx)
+            .map_or_else(Vec::new, |s| {
+                let mut indices =
+                    filter::filter_notifications(&s.notifications, &s.rows, &search_q);
+                table::sort_indices(&mut indices, &s.rows, current_sort.as_ref(), |i, column| {
+                    notification_sort_value(s.notifications.get(i)?, column)
+                });
+                indices
+            });
+    let total_rows = visible_indices.len();
 
     // Reserve space for tab bar (2 lines), footer (2 lines), header (1 line).
     let visible_rows = (props.height.saturating_sub(5) / 2).max(1) as usize;
 
     // Keyboard handling.
-    let keybindings = props.keybindings.cloned();
+    let keybindings = props.keybindings.as_deref().cloned();
+    let visible_for_keys = visible_indices.clone();
+    let sort_columns: Vec<Column> = columns
+        .iter()
+        .filter(|c| c.sort.is_some())
+        .cloned()
+        .collect();
+    let filter_host_for_keys = filters_cfg
+        .get(current_filter_idx)
+        .and_then(|f| f.host.clone());
+    let chrome = theme.chrome;
     hooks.use_terminal_events({
         let engine_for_keys = engine.clone();
         move |event| match event {
@@ -511,6 +781,9 @@ pub fn NotificationsView<'a>(
                 if !is_active {
                     return;
                 }
+                // Index into the active filter's `notifications` of the one
+                // under the cursor.
+                let selected = visible_for_keys.get(cursor.get()).copied();
                 // Help overlay: intercept all keys when visible.
                 if help_visible.get() {
                     if matches!(code, KeyCode::Char('?') | KeyCode::Esc) {
@@ -530,18 +803,43 @@ pub fn NotificationsView<'a>(
                                         let notif = get_current_notification(
                                             &notif_state,
                                             current_filter_idx,
-                                            cursor.get(),
+                                            selected,
                                         );
                                         if let Some(n) = notif {
                                             let id = n.id.clone();
                                             eng.send(Request::UnsubscribeNotification {
                                                 id,
+                                                host: filter_host_for_keys.clone(),
+                                                reply_tx: event_tx.clone(),
+                                            });
+                                            remove_notification(
+                                                notif_state,
+                                                current_filter_idx,
+                                                selected,
+                                            );
+                                            clamp_cursor(
+                                                cursor,
+                                                scroll_offset,
+                                                total_rows.saturating_sub(1),
+                                            );
+                                        }
+                                    }
+                                    BuiltinAction::MarkDone => {
+                                        let notif = get_current_notification(
+                                            &notif_state,
+                                            current_filter_idx,
+                                            selected,
+                                        );
+                                        if let Some(n) = notif {
+                                            eng.send(Request::MarkNotificationDone {
+                                                id: n.id.clone(),
+                                                host: filter_host_for_keys.clone(),
                                                 reply_tx: event_tx.clone(),
                                             });
                                             remove_notification(
                                                 notif_state,
                                                 current_filter_idx,
-                                                cursor.get(),
+                                                selected,
                                             );
                                             clamp_cursor(
                                                 cursor,
@@ -552,6 +850,7 @@ pub fn NotificationsView<'a>(
                                     }
                                     BuiltinAction::MarkAllRead => {
                                         eng.send(Request::MarkAllNotificationsRead {
+                                            host: filter_host_for_keys.clone(),
                                             reply_tx: event_tx.clone(),
                                         });
                                         clear_filter(notif_state, current_filter_idx);
@@ -599,7 +898,7 @@ pub fn NotificationsView<'a>(
                             let notif = get_current_notification(
                                 &notif_state,
                                 current_filter_idx,
-                                cursor.get(),
+                                selected,
                             );
                             let vars = TemplateVars {
                                 url: notif.as_ref().map_or_else(String::new, |n| n.url.clone()),
@@ -643,11 +942,57 @@ pub fn NotificationsView<'a>(
                                             st.set(true);
                                         }
                                     }
+                                    BuiltinAction::ShowLogs => {
+                                        if let Some(mut sl) = show_logs {
+                                            sl.set(true);
+                                        }
+                                    }
+                                    BuiltinAction::ToggleDates => {
+                                        if let Some(mut dt) = date_toggle {
+                                            dt.set(true);
+                                        }
+                                    }
+                                    BuiltinAction::SortColumn | BuiltinAction::ReverseSort => {
+                                        let columns: Vec<&Column> = sort_columns.iter().collect();
+                                        let current = table_sort.read().clone();
+                                        table_sort.set(if action == BuiltinAction::SortColumn {
+                                            TableSort::next(current.as_ref(), &columns)
+                                        } else {
+                                            TableSort::reversed(current.as_ref(), &columns)
+                                        });
+                                        cursor.set(0);
+                                        scroll_offset.set(0);
+                                    }
+                                    BuiltinAction::AuthorizeSso => {
+                                        action_status.set(Some(super::common::authorize_sso()));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::PostponeRefresh => {
+                                        let fetched = filter_fetch_times
+                                            .read()
+                                            .get(current_filter_idx)
+                                            .copied()
+                                            .flatten();
+                                        action_status.set(Some(super::common::postpone_refresh(
+                                            engine_for_keys.as_ref(),
+                                            RefreshView::Notifications,
+                                            current_filter_idx,
+                                            fetched,
+                                            refresh_interval,
+                                            &mut refresh_postponed,
+                                        )));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::SelectRepo => {
                                         if let Some(mut rp) = repo_picker {
                                             rp.set(true);
                                         }
                                     }
+                                    BuiltinAction::RescanRepo => {
+                                        if let Some(mut rr) = rescan_repo {
+                                            rr.set(true);
+                                        }
+                                    }
                                     BuiltinAction::CopyNumber => {
                                         if let Some(n) = &notif {
                                             let _ = clipboard::copy_to_clipboard(&n.subject_title);
@@ -664,14 +1009,24 @@ pub fn NotificationsView<'a>(
                                         if let Some(n) = &notif
                                             && !n.url.is_empty()
                                         {
-                                            let _ = clipboard::open_in_browser(&n.url);
+                                            let feedback = match clipboard::open_in_browser(&n.url)
+                                            {
+                                                Ok(how) => ActionFeedback::Success(
+                                                    how.message(&n.subject_title),
+                                                ),
+                                                Err(e) => ActionFeedback::Error(format!(
+                                                    "Open failed: {e}"
+                                                )),
+                                            };
+                                            action_status.set(Some(feedback));
+                                            status_set_at.set(Some(std::time::Instant::now()));
                                         }
                                     }
                                     BuiltinAction::RefreshItem | BuiltinAction::Refresh => {
                                         let idx = active_filter.get();
                                         let mut state = notif_state.read().clone();
                                         if idx < state.filters.len() {
-                                            state.filters[idx] = FilterData::default();
+                                            state.filters[idx] = Arc::new(FilterData::default());
                                         }
                                         notif_state.set(state);
                                         let mut times = filter_fetch_times.read().clone();
@@ -679,20 +1034,20 @@ pub fn NotificationsView<'a>(
                                             times[idx] = None;
                                         }
                                         filter_fetch_times.set(times);
-                                        rate_limit_state.set(None);
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                     }
                                     BuiltinAction::RefreshAll => {
                                         let mut state = notif_state.read().clone();
                                         for filter in &mut state.filters {
-                                            *filter = FilterData::default();
+                                            *filter = Arc::new(FilterData::default());
                                         }
                                         notif_state.set(state);
                                         let mut times = filter_fetch_times.read().clone();
                                         times.fill(None);
                                         filter_fetch_times.set(times);
-                                        rate_limit_state.set(None);
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                         refresh_all.set(true);
@@ -708,12 +1063,13 @@ pub fn NotificationsView<'a>(
                                             let id = n.id.clone();
                                             eng.send(Request::MarkNotificationRead {
                                                 id,
+                                                host: filter_host_for_keys.clone(),
                                                 reply_tx: event_tx.clone(),
                                             });
                                             remove_notification(
                                                 notif_state,
                                                 current_filter_idx,
-                                                cursor.get(),
+                                                selected,
                                             );
                                             clamp_cursor(
                                                 cursor,
@@ -727,6 +1083,10 @@ pub fn NotificationsView<'a>(
                                             .set(InputMode::Confirm(BuiltinAction::MarkAllRead));
                                         action_status.set(None);
                                     }
+                                    BuiltinAction::MarkDone => {
+                                        input_mode.set(InputMode::Confirm(BuiltinAction::MarkDone));
+                                        action_status.set(None);
+                                    }
                                     BuiltinAction::Unsubscribe => {
                                         input_mode
                                             .set(InputMode::Confirm(BuiltinAction::Unsubscribe));
@@ -816,6 +1176,17 @@ pub fn NotificationsView<'a>(
                                     let expanded = expand_template(&cmd, &vars);
                                     let _ = execute_shell_command(&expanded);
                                 }
+                                Some(ResolvedBinding::Script(path)) => {
+                                    if let Some(feedback) = crate::script::run(
+                                        &path,
+                                        "notifications",
+                                        &vars,
+                                        engine_for_keys.clone(),
+                                        chrome,
+                                    ) {
+                                        action_status.set(Some(feedback));
+                                    }
+                                }
                                 None => {}
                             }
                         }
@@ -858,21 +1229,43 @@ pub fn NotificationsView<'a>(
         .map(|(i, s)| Tab {
             title: s.title.clone(),
             count: state_ref.filters.get(i).map(|d| d.notification_count),
+            hidden: 0,
             is_ephemeral: false,
         })
         .collect();
 
     let current_data = state_ref.filters.get(current_filter_idx);
-    let columns = notification_columns();
 
     let all_rows: &[Row] = current_data.map_or(&[], |d| d.rows.as_slice());
     let all_notifs: &[Notification] = current_data.map_or(&[], |d| d.notifications.as_slice());
-    let filtered_indices = filter::filter_notifications(all_notifs, all_rows, &search_q);
-    let filtered_rows: Vec<Row> = filtered_indices
+
+    // Keep the cursor on the same notification when a refresh replaces the rows.
+    let fetched_at = filter_fetch_times
+        .read()
+        .get(current_filter_idx)
+        .copied()
+        .flatten();
+    super::common::anchor_cursor(
+        &mut cursor_anchor,
+        &mut cursor,
+        &mut scroll_offset,
+        current_filter_idx,
+        fetched_at,
+        &visible_indices
+            .iter()
+            .filter_map(|&i| all_notifs.get(i))
+            .map(|notif| notif.id.clone())
+            .collect::<Vec<_>>(),
+    );
+    let filtered_rows: Vec<Row> = visible_indices
         .iter()
         .filter_map(|&i| all_rows.get(i).cloned())
         .collect();
 
+    // A failed fetch shows its error in the footer, and how to retry here.
+    let failed_message = current_data
+        .is_some_and(|d| d.error.is_some())
+        .then(|| super::common::failed_tab_message(props.keybindings.as_deref()));
     let rendered_table = RenderedTable::build(&TableBuildConfig {
         columns: &columns,
         rows: &filtered_rows,
@@ -887,7 +1280,9 @@ pub fn NotificationsView<'a>(
         header_color: Some(theme.text_secondary),
         border_color: Some(theme.border_faint),
         show_separator: props.show_separator,
-        empty_message: if search_q.is_empty() {
+        empty_message: if let Some(msg) = &failed_message {
+            Some(msg.as_str())
+        } else if search_q.is_empty() {
             Some("No notifications found")
         } else {
             Some("No notifications match this filter")
@@ -895,13 +1290,30 @@ pub fn NotificationsView<'a>(
         subtitle_column: None,
         row_separator: true,
         scrollbar_thumb_color: Some(theme.border_primary),
+        chrome: theme.chrome,
+        sort: current_sort.as_ref(),
     });
 
     let tab_colors = TabBarColors {
         active: Some(theme.footer_notifications),
         inactive: Some(theme.footer_notifications),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
+    let countdown = super::common::refresh_countdown(
+        filter_fetch_times
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        refresh_interval,
+        refresh_postponed
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        &theme.icons.tab_refresh,
+    );
     let rendered_tab_bar = RenderedTabBar::build(
         &tabs,
         current_filter_idx,
@@ -910,7 +1322,8 @@ pub fn NotificationsView<'a>(
         &tab_colors,
         &theme.icons.tab_filter,
         &theme.icons.tab_ephemeral,
-    );
+    )
+    .with_countdown(countdown);
 
     let current_mode = input_mode.read().clone();
 
@@ -918,6 +1331,9 @@ pub fn NotificationsView<'a>(
         InputMode::Confirm(action) => {
             let prompt = match action {
                 BuiltinAction::MarkAllRead => "Mark ALL notifications as read? (y/n)",
+                BuiltinAction::MarkDone => {
+                    "Mark this thread as done? It leaves the inbox until new activity. (y/n)"
+                }
                 BuiltinAction::Unsubscribe => {
                     "Unsubscribe from this thread? This is irreversible. (y/n)"
                 }
@@ -931,6 +1347,7 @@ pub fn NotificationsView<'a>(
                     text: Some(theme.text_primary),
                     prompt: Some(theme.text_warning),
                     border: Some(theme.border_faint),
+                    chrome: theme.chrome,
                     ..Default::default()
                 },
             ))
@@ -943,23 +1360,30 @@ pub fn NotificationsView<'a>(
                 text: Some(theme.text_primary),
                 prompt: Some(theme.text_secondary),
                 border: Some(theme.border_faint),
+                chrome: theme.chrome,
                 ..Default::default()
             },
         )),
         InputMode::Normal => None,
     };
 
-    let context_text = if current_data.is_some_and(|d| d.loading) {
+    let context_text = if let Some(hint) = &scope_hint {
+        hint.clone()
+    } else if current_data.is_some_and(|d| d.loading) {
         "Fetching notifications...".to_owned()
     } else if let Some(err) = current_data.and_then(|d| d.error.as_ref()) {
         format!("Error: {err}")
     } else {
         let total = current_data.map_or(0, |d| d.notification_count);
         let cursor_pos = if total_rows > 0 { cursor.get() + 1 } else { 0 };
-        if search_q.is_empty() {
+        let text = if search_q.is_empty() {
             format!("Notif {cursor_pos}/{total}")
         } else {
             format!("Notif {cursor_pos}/{total_rows} (filtered from {total})")
+        };
+        match current_data.map_or(0, |d| d.muted) {
+            0 => text,
+            muted => format!("{text} ({muted} muted)"),
         }
     };
     let active_fetch_time = filter_fetch_times
@@ -973,7 +1397,12 @@ pub fn NotificationsView<'a>(
         Some(repo) => repo.clone(),
         None => "all repos".to_owned(),
     };
-    let rate_limit_text = footer::format_rate_limit(rate_limit_state.read().as_ref());
+    let rate_limit_text = footer::format_rate_limit(
+        rate_limit_state.read().of(filters_cfg
+            .get(current_filter_idx)
+            .and_then(|f| f.host.as_deref())),
+        current_data.and_then(|d| d.refresh_cost),
+    );
     let footer_colors = FooterColors {
         view_colors: [
             Some(theme.footer_prs),
@@ -982,10 +1411,12 @@ pub fn NotificationsView<'a>(
             Some(theme.footer_alerts),
             Some(theme.footer_notifications),
             Some(theme.footer_repo),
+            Some(theme.footer_events),
         ],
         inactive: Some(theme.text_faint),
         text: Some(theme.text_faint),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
     let rendered_footer = RenderedFooter::build(
         ViewKind::Notifications,
@@ -995,6 +1426,8 @@ pub fn NotificationsView<'a>(
             context_text,
             updated_text,
             rate_limit_text,
+            watch_badges: props.watch_badges.map(|b| b.get()).unwrap_or_default(),
+            view_counts: props.view_counts.map(|c| c.get()).unwrap_or_default(),
         },
         action_status.read().as_ref(),
         &theme,
@@ -1003,7 +1436,7 @@ pub fn NotificationsView<'a>(
     );
 
     let rendered_help = if help_visible.get() {
-        props.keybindings.map(|kb| {
+        props.keybindings.as_deref().map(|kb| {
             RenderedHelpOverlay::build(&HelpOverlayBuildConfig {
                 bindings: kb,
                 context: ViewContext::Notifications,
@@ -1012,6 +1445,7 @@ pub fn NotificationsView<'a>(
                 key_color: Some(theme.text_success),
                 desc_color: Some(theme.text_secondary),
                 border_color: Some(theme.border_primary),
+                chrome: theme.chrome,
             })
         })
     } else {
@@ -1040,23 +1474,25 @@ pub fn NotificationsView<'a>(
 fn get_current_notification(
     notif_state: &State<NotificationsState>,
     filter_idx: usize,
-    cursor: usize,
+    index: Option<usize>,
 ) -> Option<Notification> {
     let state = notif_state.read();
     let filter = state.filters.get(filter_idx)?;
-    filter.notifications.get(cursor).cloned()
+    filter.notifications.get(index?).cloned()
 }
 
 /// Remove a notification at `index` from filter `filter_idx` in local state.
 fn remove_notification(
     mut notif_state: State<NotificationsState>,
     filter_idx: usize,
-    index: usize,
+    index: Option<usize>,
 ) {
     let mut state = notif_state.read().clone();
     if let Some(filter) = state.filters.get_mut(filter_idx)
+        && let Some(index) = index
         && index < filter.rows.len()
     {
+        let filter = Arc::make_mut(filter);
         filter.rows.remove(index);
         filter.ids.remove(index);
         filter.notifications.remove(index);
@@ -1069,6 +1505,7 @@ fn remove_notification(
 fn clear_filter(mut notif_state: State<NotificationsState>, filter_idx: usize) {
     let mut state = notif_state.read().clone();
     if let Some(filter) = state.filters.get_mut(filter_idx) {
+        let filter = Arc::make_mut(filter);
         filter.rows.clear();
         filter.ids.clear();
         filter.notifications.clear();
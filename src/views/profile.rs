@@ -0,0 +1,132 @@
+//! Contribution calendar overlay of the list views.
+//!
+//! `@` opens the calendar of the selected item's author; inside the overlay
+//! `m` switches to your own. The overlay is plain view state: the view
+//! forwards its keys and engine events here while it is open.
+
+use iocraft::prelude::*;
+
+use crate::color::ColorDepth;
+use crate::components::calendar::{CalendarBuildConfig, RenderedCalendar};
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::theme::ResolvedTheme;
+use crate::types::ContributionCalendar;
+
+/// Engine request context of contribution fetches.
+pub(crate) const FETCH_CONTEXT: &str = "FetchContributions";
+
+/// An open calendar overlay.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProfileOverlay {
+    /// Whose calendar; `None` is the viewer.
+    pub login: Option<String>,
+    pub calendar: Option<ContributionCalendar>,
+    pub error: Option<String>,
+    /// Selected week.
+    pub week: usize,
+}
+
+/// Open the overlay for `login` (`None`: the viewer) and fetch its calendar.
+pub(crate) fn open(
+    overlay: &mut State<Option<ProfileOverlay>>,
+    engine: Option<&EngineHandle>,
+    host: Option<String>,
+    login: Option<String>,
+    reply_tx: &EventSender,
+) {
+    if let Some(engine) = engine {
+        engine.send(Request::FetchContributions {
+            host,
+            login: login.clone(),
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    overlay.set(Some(ProfileOverlay {
+        login,
+        ..ProfileOverlay::default()
+    }));
+}
+
+/// Handle a key while the overlay is open. Every key is consumed.
+pub(crate) fn handle_key(
+    overlay: &mut State<Option<ProfileOverlay>>,
+    code: KeyCode,
+    engine: Option<&EngineHandle>,
+    host: Option<String>,
+    reply_tx: &EventSender,
+) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    let last = current
+        .calendar
+        .as_ref()
+        .map_or(0, |c| c.weeks.len().saturating_sub(1));
+    match code {
+        KeyCode::Esc | KeyCode::Char('q' | '@') => {
+            overlay.set(None);
+            return;
+        }
+        KeyCode::Char('m') if current.login.is_some() => {
+            open(overlay, engine, host, None, reply_tx);
+            return;
+        }
+        KeyCode::Char('h') | KeyCode::Left => current.week = current.week.saturating_sub(1),
+        KeyCode::Char('l') | KeyCode::Right => current.week = (current.week + 1).min(last),
+        KeyCode::Char('g') | KeyCode::Home => current.week = 0,
+        KeyCode::Char('G') | KeyCode::End => current.week = last,
+        _ => return,
+    }
+    overlay.set(Some(current));
+}
+
+/// Store a fetched calendar if it is the one the overlay is waiting for.
+pub(crate) fn on_fetched(
+    overlay: &mut State<Option<ProfileOverlay>>,
+    login: Option<&str>,
+    calendar: ContributionCalendar,
+) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    if current.login.as_deref() != login {
+        return;
+    }
+    current.week = calendar.weeks.len().saturating_sub(1);
+    current.calendar = Some(calendar);
+    overlay.set(Some(current));
+}
+
+/// Show a failed fetch in the open overlay.
+pub(crate) fn on_error(overlay: &mut State<Option<ProfileOverlay>>, message: String) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    current.error = Some(message);
+    overlay.set(Some(current));
+}
+
+/// Render the open overlay, if any.
+pub(crate) fn render(
+    overlay: &State<Option<ProfileOverlay>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+    width: u16,
+) -> Option<RenderedCalendar> {
+    let overlay = overlay.read();
+    let current = overlay.as_ref()?;
+    Some(RenderedCalendar::build(&CalendarBuildConfig {
+        calendar: current.calendar.as_ref(),
+        login: current.login.as_deref(),
+        error: current.error.as_deref(),
+        week: current.week,
+        width,
+        depth,
+        title_color: Some(theme.text_primary),
+        text_color: Some(theme.text_secondary),
+        faint_color: Some(theme.text_faint),
+        active_color: Some(theme.text_success),
+        border_color: Some(theme.border_primary),
+        chrome: theme.chrome,
+    }))
+}
@@ -1,10 +1,12 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use iocraft::prelude::*;
 
 use crate::actions::clipboard;
-use crate::app::{NavigationTarget, ViewKind};
+use crate::app::{NavigationTarget, ViewCounts, ViewKind, WatchBadges};
 use crate::color::{Color as AppColor, ColorDepth};
+use crate::components::calendar::CalendarOverlay;
 use crate::components::footer::{
     self, ActionFeedback, Footer, FooterColors, FooterContent, RenderedFooter,
 };
@@ -15,32 +17,50 @@ use crate::components::selection_overlay::{
 use crate::components::sidebar::{
     RenderedSidebar, Sidebar, SidebarColors, SidebarMeta, SidebarTab, SidebarTabConfig,
 };
-use crate::components::sidebar_tabs;
 use crate::components::tab_bar::{RenderedTabBar, Tab, TabBar, TabBarColors};
 use crate::components::table::{
-    Cell, Column, RenderedTable, Row, ScrollableTable, Span, TableBuildConfig,
+    self as table, Cell, Column, RenderedTable, Row, ScrollableTable, SortDirection, SortValue,
+    Span, TableBuildConfig, TableClick, TableSort,
 };
 use crate::components::text_input::{RenderedTextInput, TextInput, TextInputColors};
+use crate::components::warning_banner::{RenderedWarningBanner, WarningBanner};
+use crate::components::{ellipsis, sidebar_tabs};
 use crate::config::keybindings::{
     BuiltinAction, MergedBindings, ResolvedBinding, TemplateVars, ViewContext,
     execute_shell_command, expand_template, key_event_to_string,
 };
-use crate::config::types::PrFilter;
-use crate::engine::{EngineHandle, Event, FilterConfig, PrRef, Request};
-use crate::filter::{self, apply_scope};
+use crate::config::types::{AppConfig, MergeMethod, PrFilter};
+use crate::drafts::{self, DraftTarget};
+use crate::engine::{EngineHandle, Event, EventSender, PrRef, RefreshView, Request};
+use crate::filter;
 use crate::icons::ResolvedIcons;
-use crate::markdown::renderer::{self, StyledLine};
+use crate::markdown::cache::{self as md_cache, MarkdownCache};
+use crate::markdown::renderer::StyledLine;
 use crate::theme::ResolvedTheme;
 use crate::types::{
-    AuthorAssociation, BranchUpdateStatus, MergeStateStatus, MergeableState, PrDetail, PullRequest,
-    RateLimitInfo,
+    AuthorAssociation, BranchUpdateStatus, FileViewedState, FirstReviewWait, MergeStateStatus,
+    MergeableState, PrDetail, PrDetailConnection, PullRequest, RateLimits, RepoRef, ReviewEvent,
+    SavedReply, TimelineEvent,
 };
+use crate::util::{LruCache, PrSize};
 use crate::views::MAX_EPHEMERAL_TABS;
 
+use super::copy_menu::{self, CopyMenu};
+use super::files_menu::{self, FilesMenu};
+use super::keep_tab;
+use super::list::{self, FetchTracking, ListState};
+use super::notes;
+use super::profile::{self, ProfileOverlay};
+use super::review;
+use super::row_layout::RowLayout;
+use super::share_menu::{self, ShareMenu};
+use super::sidebar_focus;
+
 // ---------------------------------------------------------------------------
 // PR-specific column definitions (FR-011)
 // ---------------------------------------------------------------------------
 
+#[expect(clippy::too_many_lines)]
 fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
     vec![
         Column {
@@ -49,6 +69,7 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.03,
             align: TextAlign::Center,
             fixed_width: Some(3),
+            sort: None,
         },
         Column {
             id: "info".to_owned(),
@@ -56,6 +77,7 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.35,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "comments".to_owned(),
@@ -63,6 +85,15 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.04,
             align: TextAlign::Right,
             fixed_width: Some(4),
+            sort: Some(SortDirection::Descending),
+        },
+        Column {
+            id: "reactions".to_owned(),
+            header: "React".to_owned(),
+            default_width_pct: 0.05,
+            align: TextAlign::Right,
+            fixed_width: Some(6),
+            sort: Some(SortDirection::Descending),
         },
         Column {
             id: "review".to_owned(),
@@ -70,6 +101,7 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.04,
             align: TextAlign::Center,
             fixed_width: Some(4),
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "ci".to_owned(),
@@ -77,6 +109,7 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.04,
             align: TextAlign::Center,
             fixed_width: Some(4),
+            sort: Some(SortDirection::Ascending),
         },
         Column {
             id: "update".to_owned(),
@@ -84,6 +117,7 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.04,
             align: TextAlign::Center,
             fixed_width: Some(4),
+            sort: None,
         },
         Column {
             id: "lines".to_owned(),
@@ -91,6 +125,55 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.10,
             align: TextAlign::Right,
             fixed_width: None,
+            sort: Some(SortDirection::Descending),
+        },
+        Column {
+            id: "size".to_owned(),
+            header: "Size".to_owned(),
+            default_width_pct: 0.04,
+            align: TextAlign::Center,
+            fixed_width: Some(4),
+            sort: Some(SortDirection::Descending),
+        },
+        Column {
+            id: "diff".to_owned(),
+            header: "Diff".to_owned(),
+            default_width_pct: 0.05,
+            align: TextAlign::Left,
+            fixed_width: Some(DIFF_BAR_WIDTH),
+            sort: Some(SortDirection::Descending),
+        },
+        Column {
+            id: "people".to_owned(),
+            header: "Who".to_owned(),
+            default_width_pct: 0.08,
+            align: TextAlign::Left,
+            fixed_width: Some(12),
+            sort: Some(SortDirection::Ascending),
+        },
+        Column {
+            id: "in_review".to_owned(),
+            header: "Wait".to_owned(),
+            default_width_pct: 0.04,
+            align: TextAlign::Right,
+            fixed_width: Some(5),
+            sort: None,
+        },
+        Column {
+            id: "first_review".to_owned(),
+            header: "1st Rv".to_owned(),
+            default_width_pct: 0.05,
+            align: TextAlign::Right,
+            fixed_width: Some(6),
+            sort: None,
+        },
+        Column {
+            id: "green_for".to_owned(),
+            header: "Green".to_owned(),
+            default_width_pct: 0.04,
+            align: TextAlign::Right,
+            fixed_width: Some(5),
+            sort: None,
         },
         Column {
             id: "updated".to_owned(),
@@ -98,6 +181,7 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.06,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: Some(SortDirection::Descending),
         },
         Column {
             id: "created".to_owned(),
@@ -105,10 +189,22 @@ fn pr_columns(icons: &ResolvedIcons) -> Vec<Column> {
             default_width_pct: 0.06,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: Some(SortDirection::Descending),
         },
     ]
 }
 
+/// Columns left out unless a filter's `layout.shown` lists them.
+const OPTIONAL_PR_COLUMNS: &[&str] = &[
+    "reactions",
+    "size",
+    "diff",
+    "people",
+    "in_review",
+    "first_review",
+    "green_for",
+];
+
 /// Build the state indicator cell for a PR row.
 fn build_state_cell(pr: &PullRequest, theme: &ResolvedTheme) -> Cell {
     let icons = &theme.icons;
@@ -124,37 +220,6 @@ fn build_state_cell(pr: &PullRequest, theme: &ResolvedTheme) -> Cell {
     Cell::colored(state_icon.clone(), state_color)
 }
 
-/// Build the info cell: `repo/name #N by @author`.
-fn build_info_cell(pr: &PullRequest, theme: &ResolvedTheme) -> Cell {
-    let repo_name = pr
-        .repo
-        .as_ref()
-        .map_or_else(String::new, crate::github::types::RepoRef::full_name);
-    let author = pr.author.as_ref().map_or("unknown", |a| a.login.as_str());
-    Cell::from_spans(vec![
-        Span {
-            text: repo_name,
-            color: Some(theme.text_secondary),
-            bold: false,
-        },
-        Span {
-            text: format!(" #{}", pr.number),
-            color: Some(theme.text_primary),
-            bold: false,
-        },
-        Span {
-            text: " by ".to_owned(),
-            color: Some(theme.text_faint),
-            bold: false,
-        },
-        Span {
-            text: format!("@{author}"),
-            color: Some(theme.text_actor),
-            bold: false,
-        },
-    ])
-}
-
 /// Build the review status cell: prefer `reviewDecision`, fall back to `latestReviews`.
 fn build_review_cell(pr: &PullRequest, theme: &ResolvedTheme) -> Cell {
     let icons = &theme.icons;
@@ -209,24 +274,96 @@ fn build_lines_cell(pr: &PullRequest, theme: &ResolvedTheme) -> Cell {
     ])
 }
 
+/// Squares in the `diff` column's bar, as in GitHub's file list.
+const DIFF_BAR_WIDTH: u16 = 5;
+
+/// How many squares of the diff bar show additions and deletions.
+///
+/// One square is filled per order of magnitude of the lines changed (1-9,
+/// 10-99, …), shared out in proportion; a side with any change keeps at
+/// least one square while the other side has some left.
+fn diff_bar_split(additions: u32, deletions: u32) -> (usize, usize) {
+    let total = u64::from(additions) + u64::from(deletions);
+    if total == 0 {
+        return (0, 0);
+    }
+    let filled = (total.ilog10() as usize + 1).min(usize::from(DIFF_BAR_WIDTH));
+    let share = (u64::from(additions) * filled as u64 + total / 2) / total;
+    let mut plus = usize::try_from(share).unwrap_or(filled);
+    if additions > 0 && plus == 0 {
+        plus = 1;
+    } else if deletions > 0 && plus == filled && filled > 1 {
+        plus = filled - 1;
+    }
+    (plus, filled - plus)
+}
+
+/// Build the diff bar cell: green and red squares for additions and
+/// deletions, log-scaled, the rest faint.
+fn build_diff_bar_cell(pr: &PullRequest, theme: &ResolvedTheme) -> Cell {
+    let square = if theme.chrome.ascii_only() {
+        "#"
+    } else {
+        "\u{25a0}" // ■
+    };
+    let (plus, minus) = diff_bar_split(pr.additions, pr.deletions);
+    let spans = [
+        (plus, theme.text_success),
+        (minus, theme.text_error),
+        (usize::from(DIFF_BAR_WIDTH) - plus - minus, theme.text_faint),
+    ]
+    .into_iter()
+    .filter(|(n, _)| *n > 0)
+    .map(|(n, color)| Span {
+        text: square.repeat(n),
+        color: Some(color),
+        bold: false,
+    })
+    .collect();
+    Cell::from_spans(spans)
+}
+
+/// Build the size class cell: `XS` to `XL`, warmer as PRs grow.
+fn build_size_cell(pr: &PullRequest, theme: &ResolvedTheme) -> Cell {
+    let size = crate::util::pr_size(pr.additions, pr.deletions);
+    let color = match size {
+        PrSize::Xs | PrSize::S => theme.text_success,
+        PrSize::M => theme.text_secondary,
+        PrSize::L => theme.text_warning,
+        PrSize::Xl => theme.text_error,
+    };
+    Cell::colored(size.label(), color)
+}
+
 /// Convert a `PullRequest` into a table `Row`.
 ///
 /// When `detail` is provided the "update" cell is derived from the refined detail
 /// data; otherwise the coarse `merge_state_status` from the PR itself is used.
+/// PRs awaiting review from one of the viewer's `teams` get a `team-review`
+/// marker in the info cell. `layout` is the tab's row layout (standard when
+/// `None`).
 fn pr_to_row(
     pr: &PullRequest,
     theme: &ResolvedTheme,
     date_format: &str,
     detail: Option<&PrDetail>,
+    teams: &[String],
+    layout: Option<&RowLayout>,
 ) -> Row {
     let mut row = HashMap::new();
+    let layout = layout.unwrap_or_else(|| RowLayout::standard());
 
     row.insert("state".to_owned(), build_state_cell(pr, theme));
-    row.insert("info".to_owned(), build_info_cell(pr, theme));
-    row.insert(
-        "subtitle".to_owned(),
-        Cell::colored(crate::util::expand_emoji(&pr.title), theme.text_primary),
-    );
+    let mut info = layout.info.render(pr, theme);
+    if pr.requests_team_review(teams) {
+        info.spans.push(Span {
+            text: " team-review".to_owned(),
+            color: Some(theme.text_warning),
+            bold: false,
+        });
+    }
+    row.insert("info".to_owned(), info);
+    row.insert("subtitle".to_owned(), layout.subtitle.render(pr, theme));
 
     let comments = if pr.comment_count > 0 {
         pr.comment_count.to_string()
@@ -238,17 +375,39 @@ fn pr_to_row(
         Cell::colored(comments, theme.text_secondary),
     );
 
+    let total_reactions = pr.reactions.total();
+    let reactions = if total_reactions > 0 {
+        total_reactions.to_string()
+    } else {
+        String::new()
+    };
+    row.insert(
+        "reactions".to_owned(),
+        Cell::colored(reactions, theme.text_secondary),
+    );
+
     row.insert("review".to_owned(), build_review_cell(pr, theme));
 
     let (ci_text, ci_color) = aggregate_ci_status(&pr.check_runs, theme);
     row.insert("ci".to_owned(), Cell::colored(ci_text, ci_color));
 
     row.insert("lines".to_owned(), build_lines_cell(pr, theme));
+    row.insert("size".to_owned(), build_size_cell(pr, theme));
+    row.insert("diff".to_owned(), build_diff_bar_cell(pr, theme));
+    row.insert(
+        "people".to_owned(),
+        super::common::people_cell(pr.author.as_ref(), &pr.assignees, theme),
+    );
+    insert_review_time_cells(&mut row, pr, theme);
 
     let updated = crate::util::format_date(&pr.updated_at, date_format);
+    let is_open = pr.state == crate::github::types::PrState::Open;
     row.insert(
         "updated".to_owned(),
-        Cell::colored(updated, theme.text_faint),
+        Cell::colored(
+            updated,
+            super::common::updated_color(&pr.updated_at, is_open, theme),
+        ),
     );
     let created = crate::util::format_date(&pr.created_at, date_format);
     row.insert(
@@ -269,11 +428,46 @@ fn pr_to_row(
         update_cell(branch_update_status(pr), theme)
     };
     row.insert("update".to_owned(), update);
+    super::common::tint_by_labels(&mut row, &pr.labels, theme);
 
     row
 }
 
+/// The review SLA cells: time in review since the pending request, time to
+/// the first review (in the warning colour while still waiting), and how
+/// long CI has been green.
+fn insert_review_time_cells(row: &mut Row, pr: &PullRequest, theme: &ResolvedTheme) {
+    use crate::util::format_span;
+
+    let now = chrono::Utc::now();
+    let since = |at: Option<chrono::DateTime<chrono::Utc>>| {
+        at.map(|at| format_span(now - at)).unwrap_or_default()
+    };
+    row.insert(
+        "in_review".to_owned(),
+        Cell::colored(since(pr.in_review_since()), theme.text_secondary),
+    );
+    let first_review = match pr.first_review_wait(now) {
+        Some(FirstReviewWait::Reviewed(took)) => {
+            Cell::colored(format_span(took), theme.text_secondary)
+        }
+        Some(FirstReviewWait::Waiting(waited)) => {
+            Cell::colored(format_span(waited), theme.text_warning)
+        }
+        None => Cell::plain(String::new()),
+    };
+    row.insert("first_review".to_owned(), first_review);
+    row.insert(
+        "green_for".to_owned(),
+        Cell::colored(since(pr.green_since()), theme.text_success),
+    );
+}
+
 /// Aggregate CI check runs into a single status icon.
+///
+/// A failing optional check is shown in the warning colour rather than as a
+/// blocker. When no check is known to be required (unprotected branch, or
+/// protection settings the viewer cannot read), every check counts.
 fn aggregate_ci_status(
     checks: &[crate::github::types::CheckRun],
     theme: &ResolvedTheme,
@@ -286,15 +480,21 @@ fn aggregate_ci_status(
         return (icons.ci_none.clone(), theme.text_faint);
     }
 
-    let any_failing = checks.iter().any(|c| {
+    let failing = |c: &&crate::github::types::CheckRun| {
         matches!(
             c.conclusion,
             Some(CheckConclusion::Failure | CheckConclusion::TimedOut)
         )
-    });
-    if any_failing {
+    };
+    let any_required = checks.iter().any(|c| c.is_required);
+    if checks
+        .iter()
+        .filter(failing)
+        .any(|c| c.is_required || !any_required)
+    {
         return (icons.ci_failure.clone(), theme.text_error);
     }
+    let any_optional_failing = checks.iter().any(|c| failing(&c));
 
     let any_cancelled = checks
         .iter()
@@ -313,13 +513,17 @@ fn aggregate_ci_status(
             || (matches!(c.status, Some(CheckStatus::Completed)) && c.conclusion.is_none())
     });
 
-    // Priority: running > queued > action_required > cancelled > success
+    // Priority: running > queued > optional failure > action_required >
+    // cancelled > success
     if any_running {
         return (icons.ci_running.clone(), theme.text_warning);
     }
     if any_queued {
         return (icons.ci_pending.clone(), theme.text_secondary);
     }
+    if any_optional_failing {
+        return (icons.ci_failure.clone(), theme.text_warning);
+    }
     if any_action_required {
         return (icons.ci_action_required.clone(), theme.text_warning);
     }
@@ -403,6 +607,7 @@ struct DetailRequest {
     base_ref: String,
     head_repo_owner: Option<String>,
     head_ref: String,
+    host: Option<String>,
     force: bool,
 }
 
@@ -416,6 +621,8 @@ enum InputMode {
     Normal,
     /// Typing a comment; buffer accumulates chars.
     Comment,
+    /// Picking a reply template to insert into the comment (from `Comment`).
+    ReplyPicker,
     /// Confirmation prompt for a destructive action (y/n).
     Confirm(BuiltinAction),
     /// Search/filter mode (T087).
@@ -426,55 +633,137 @@ enum InputMode {
     Label,
     /// Prompt for which branch-update method to use (merge or rebase).
     UpdateBranchMethod,
+    /// Typing the title an ephemeral tab is kept under.
+    KeepTab,
 }
 
 // ---------------------------------------------------------------------------
 // Filter state
 // ---------------------------------------------------------------------------
 
-/// State for a single filter.
-#[derive(Debug, Clone)]
-struct FilterData {
-    rows: Vec<Row>,
-    /// PR bodies for preview (indexed same as rows).
-    bodies: Vec<String>,
-    /// PR titles for sidebar header.
-    titles: Vec<String>,
-    /// Full PR data for actions.
-    prs: Vec<PullRequest>,
-    pr_count: usize,
-    loading: bool,
-    error: Option<String>,
-}
+type FilterData = list::FilterData<PullRequest>;
+type PrsState = ListState<PullRequest>;
+
+/// The value `pr` is sorted by in `column`, for columns whose cells don't
+/// sort as text.
+fn pr_sort_value(pr: &PullRequest, column: &str) -> Option<SortValue> {
+    use crate::github::types::ReviewDecision;
+    use crate::types::{CheckConclusion, CheckStatus};
 
-impl Default for FilterData {
-    fn default() -> Self {
-        Self {
-            rows: Vec::new(),
-            bodies: Vec::new(),
-            titles: Vec::new(),
-            prs: Vec::new(),
-            pr_count: 0,
-            loading: true,
-            error: None,
+    let number = |n: u32| Some(SortValue::Number(i64::from(n)));
+    match column {
+        "info" => Some(SortValue::Text(pr.title.to_lowercase())),
+        "comments" => number(pr.comment_count),
+        "reactions" => number(pr.reactions.total()),
+        "lines" | "size" | "diff" => number(pr.additions + pr.deletions),
+        "updated" => Some(SortValue::Number(pr.updated_at.timestamp())),
+        "created" => Some(SortValue::Number(pr.created_at.timestamp())),
+        // Most in need of attention first.
+        "review" => match pr.review_decision? {
+            ReviewDecision::ChangesRequested => number(0),
+            ReviewDecision::ReviewRequired => number(1),
+            ReviewDecision::Approved => number(2),
+        },
+        "ci" if pr.check_runs.is_empty() => None,
+        "ci" => {
+            let checks = &pr.check_runs;
+            if checks.iter().any(|c| {
+                matches!(
+                    c.conclusion,
+                    Some(CheckConclusion::Failure | CheckConclusion::TimedOut)
+                )
+            }) {
+                number(0)
+            } else if checks
+                .iter()
+                .any(|c| c.status != Some(CheckStatus::Completed) || c.conclusion.is_none())
+            {
+                number(1)
+            } else {
+                number(2)
+            }
         }
+        _ => None,
     }
 }
 
-/// Shared state across all filters (stored in a single State handle).
-#[derive(Debug, Clone)]
-struct PrsState {
-    filters: Vec<FilterData>,
+/// Quick client-side slice of the loaded PRs, toggled per view and applied on
+/// top of the search query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CiSlice {
+    FailingCi,
+    ApprovedGreen,
+    ChangesRequested,
+}
+
+impl CiSlice {
+    /// Short label shown in the tab bar while the slice is active.
+    fn label(self) -> &'static str {
+        match self {
+            Self::FailingCi => "failing CI",
+            Self::ApprovedGreen => "approved + green",
+            Self::ChangesRequested => "changes requested",
+        }
+    }
+
+    fn matches(self, pr: &PullRequest) -> bool {
+        use crate::github::types::ReviewDecision;
+        use crate::types::{CheckConclusion, ReviewState};
+
+        match self {
+            Self::FailingCi => pr.check_runs.iter().any(|c| {
+                matches!(
+                    c.conclusion,
+                    Some(CheckConclusion::Failure | CheckConclusion::TimedOut)
+                )
+            }),
+            Self::ApprovedGreen => {
+                pr.review_decision == Some(ReviewDecision::Approved)
+                    && !pr.check_runs.is_empty()
+                    && pr.check_runs.iter().all(|c| {
+                        matches!(
+                            c.conclusion,
+                            Some(
+                                CheckConclusion::Success
+                                    | CheckConclusion::Neutral
+                                    | CheckConclusion::Skipped
+                            )
+                        )
+                    })
+            }
+            Self::ChangesRequested => match pr.review_decision {
+                Some(decision) => decision == ReviewDecision::ChangesRequested,
+                None => pr
+                    .reviews
+                    .iter()
+                    .any(|r| r.state == ReviewState::ChangesRequested),
+            },
+        }
+    }
 }
 
-/// Build a merged list of (filter, `is_ephemeral`) from config + ephemeral filters.
-fn merged_pr_filters<'a>(
-    config: &'a [PrFilter],
-    ephemeral: &'a [(PrFilter, Option<u64>)],
-) -> Vec<(&'a PrFilter, bool)> {
-    let mut out: Vec<_> = config.iter().map(|f| (f, false)).collect();
-    out.extend(ephemeral.iter().map(|(f, _)| (f, true)));
-    out
+/// Indices into `data.items` of the PRs left after the search query (with
+/// its `size:` tokens), the active CI slice and size class and, when
+/// `hide_wip` holds the WIP patterns, hiding drafts.
+fn visible_pr_indices(
+    data: &FilterData,
+    query: &str,
+    slice: Option<CiSlice>,
+    size: Option<PrSize>,
+    hide_wip: Option<&[String]>,
+) -> Vec<usize> {
+    let (text, sizes) = filter::split_size_query(query);
+    let mut indices = filter::filter_rows(&data.rows, &text);
+    indices.retain(|&i| {
+        data.items.get(i).is_some_and(|pr| {
+            let pr_size = crate::util::pr_size(pr.additions, pr.deletions);
+            slice.is_none_or(|s| s.matches(pr))
+                && size.is_none_or(|s| s == pr_size)
+                && sizes.as_ref().is_none_or(|sizes| sizes.contains(&pr_size))
+                && hide_wip.is_none_or(|patterns| !pr.is_draft_or_wip(patterns))
+        })
+    });
+    indices
 }
 
 // ---------------------------------------------------------------------------
@@ -484,14 +773,14 @@ fn merged_pr_filters<'a>(
 #[derive(Default, Props)]
 #[expect(clippy::struct_excessive_bools)]
 pub struct PrsViewProps<'a> {
-    /// PR filter configs.
-    pub filters: Option<&'a [PrFilter]>,
+    /// Config, replaced on each reload.
+    pub config: Option<Arc<AppConfig>>,
     /// Engine handle.
     pub engine: Option<&'a EngineHandle>,
     /// Resolved theme.
-    pub theme: Option<&'a ResolvedTheme>,
+    pub theme: Option<Arc<ResolvedTheme>>,
     /// Merged keybindings for help overlay.
-    pub keybindings: Option<&'a MergedBindings>,
+    pub keybindings: Option<Arc<MergedBindings>>,
     /// Color depth.
     pub color_depth: ColorDepth,
     /// Available width.
@@ -516,14 +805,16 @@ pub struct PrsViewProps<'a> {
     pub goto_view: Option<State<Option<ViewKind>>>,
     /// Signal to toggle repo scope.
     pub scope_toggle: Option<State<bool>>,
+    /// Signal to toggle relative/absolute dates.
+    pub date_toggle: Option<State<bool>>,
+    /// Signal to open the log viewer.
+    pub show_logs: Option<State<bool>>,
     /// Signal to open the repo picker overlay.
     pub repo_picker: Option<State<bool>>,
+    /// Signal to scope to the repo worked in.
+    pub rescan_repo: Option<State<bool>>,
     /// Active scope repo (e.g. `"owner/repo"`), or `None` for global.
     pub scope_repo: Option<String>,
-    /// Repo paths for checkout (from `config.repo_paths`).
-    pub repo_paths: Option<&'a indexmap::IndexMap<String, std::path::PathBuf>>,
-    /// Date format string (from `config.defaults.date_format`).
-    pub date_format: Option<&'a str>,
     /// Whether this view is the currently active (visible) one.
     pub is_active: bool,
     /// Auto-refetch interval in minutes (0 = disabled).
@@ -532,25 +823,52 @@ pub struct PrsViewProps<'a> {
     pub prefetch_pr_details: u32,
     /// Auto-clone repos that aren't cloned yet (for checkout / worktree).
     pub auto_clone: bool,
+    /// Capacity of the per-view detail cache (from `defaults.detail_cache_size`).
+    pub detail_cache_size: usize,
     /// Navigation target state — set by `JumpToRun` to trigger cross-view navigation.
     pub nav_target: Option<State<Option<NavigationTarget>>>,
     /// Go-back signal — set to true to return to previous view.
     pub go_back: Option<State<bool>>,
     /// Shared rate-limit state (owned by App).
-    pub rate_limit: Option<State<Option<RateLimitInfo>>>,
+    pub rate_limit: Option<State<RateLimits>>,
+    /// Shared watched-item badges (owned by App).
+    pub watch_badges: Option<State<WatchBadges>>,
+    /// Shared footer counts of the views (owned by App).
+    pub view_counts: Option<State<ViewCounts>>,
+    /// Teams the viewer belongs to, as `org/team-slug` (empty until fetched).
+    pub viewer_teams: Vec<String>,
+    /// Title prefixes marking a PR as work in progress.
+    pub wip_patterns: Vec<String>,
+    /// Reply templates and saved replies offered by the comment composer.
+    pub reply_templates: Vec<SavedReply>,
+    /// Also mark files viewed on GitHub (from `github.sync_viewed_files`).
+    pub sync_viewed_files: bool,
+    /// How PRs are merged (from `github.merge_method`).
+    pub merge_method: MergeMethod,
 }
 
 #[component]
 pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
-    let filters_cfg = props.filters.unwrap_or(&[]);
-    let theme = props.theme.cloned().unwrap_or_else(default_theme);
+    let filters_cfg = props.config.as_deref().map_or(&[][..], |c| &c.pr_filters);
+    let row_layouts: Vec<RowLayout> = filters_cfg
+        .iter()
+        .map(|f| RowLayout::new(f.layout.as_ref()))
+        .collect();
+    let theme = props
+        .theme
+        .as_deref()
+        .cloned()
+        .unwrap_or_else(default_theme);
     let depth = props.color_depth;
     let should_exit = props.should_exit;
     let switch_view = props.switch_view;
     let switch_view_back = props.switch_view_back;
     let goto_view = props.goto_view;
     let scope_toggle = props.scope_toggle;
+    let date_toggle = props.date_toggle;
+    let show_logs = props.show_logs;
     let repo_picker = props.repo_picker;
+    let rescan_repo = props.rescan_repo;
     let scope_repo = &props.scope_repo;
     let filter_count = filters_cfg.len();
     let is_active = props.is_active;
@@ -563,19 +881,47 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     let mut active_filter = hooks.use_state(|| 0usize);
     let mut cursor = hooks.use_state(|| 0usize);
     let mut scroll_offset = hooks.use_state(|| 0usize);
+    // Identity of the item under the cursor, followed across refreshes.
+    let mut cursor_anchor = hooks.use_state(super::common::CursorAnchor::default);
 
     // State: preview pane.
     let mut preview_open = hooks.use_state(|| false);
     let mut preview_scroll = hooks.use_state(|| 0usize);
+    // Rendered Overview bodies; `md_ready` ticks when a background render lands.
+    let md_cache: MarkdownCache = hooks.use_const_default();
+    let md_ready = hooks.use_state(|| 0u64);
+    // Printing nothing makes the next render redraw the whole screen, which
+    // comes back blank from the note editor.
+    let (stdout, _) = hooks.use_output();
 
     // State: sidebar tab.
     let mut sidebar_tab = hooks.use_state(|| SidebarTab::Overview);
+    // Tab whose `preview` / `preview_tab` settings were last applied.
+    let mut preview_applied = hooks.use_state(|| Option::<usize>::None);
+    // Comment selected in the Activity tab: (PR number, timeline event index),
+    // and whether the preview should scroll to it on the next render.
+    let mut comment_sel = hooks.use_state(|| Option::<(u64, usize)>::None);
+    let mut comment_jump = hooks.use_state(|| false);
+    // Whether the sidebar has keyboard focus; the file or check selected in
+    // it, as (PR number, tab, index), and whether to scroll to it.
+    let mut sidebar_focused = hooks.use_state(|| false);
+    let mut item_sel = hooks.use_state(|| Option::<(u64, SidebarTab, usize)>::None);
+    let mut item_jump = hooks.use_state(|| false);
+    // Show bot and minimized comments in the Activity tab.
+    let mut show_noise = hooks.use_state(|| false);
+    // Clicks reported by the tab bar and the table.
+    let tab_click = hooks.use_state(|| None::<usize>);
+    let row_click = hooks.use_state(|| None::<TableClick>);
 
     // State: cached PR detail data for sidebar tabs (HashMap cache + debounce).
-    let mut detail_cache = hooks.use_state(HashMap::<u64, PrDetail>::new);
+    let detail_cache_size = props.detail_cache_size;
+    let mut detail_cache = hooks.use_state(|| LruCache::<u64, PrDetail>::new(detail_cache_size));
     // Pending detail request: parameters for the next debounced fetch.
     let mut pending_detail = hooks.use_state(|| Option::<DetailRequest>::None);
     let mut debounce_gen = hooks.use_state(|| 0u64);
+    // Commits/files page in flight for the PR shown in the sidebar, with the
+    // cursor it was requested after.
+    let mut pending_page = hooks.use_state(|| Option::<(u64, PrDetailConnection, String)>::None);
 
     // State: input mode for actions.
     let mut input_mode = hooks.use_state(|| InputMode::Normal);
@@ -585,18 +931,53 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
 
     // State: search query.
     let mut search_query = hooks.use_state(String::new);
+    let mut ci_slice = hooks.use_state(|| Option::<CiSlice>::None);
+    // Size class slice, cycled XS to XL by `cycle_size`.
+    let mut size_slice = hooks.use_state(|| Option::<PrSize>::None);
+    // Column the table is sorted by; API order when `None`.
+    let mut table_sort = hooks.use_state(|| Option::<TableSort>::None);
+    // Tabs whose `hide_drafts` setting was flipped with `toggle_drafts`.
+    let mut drafts_flipped = hooks.use_state(HashSet::<usize>::new);
 
     // State: assignee autocomplete.
     let mut assignee_candidates = hooks.use_state(Vec::<String>::new);
     let mut assignee_selection = hooks.use_state(|| 0usize);
     let mut assignee_selected = hooks.use_state(Vec::<String>::new);
 
+    // State: `@`/`#` autocomplete in the comment composer. Mentions reuse
+    // `assignee_candidates`.
+    let mut issue_ref_candidates = hooks.use_state(Vec::<String>::new);
+    let mut completion_selection = hooks.use_state(|| 0usize);
+    // Filter typed in the reply template picker; the selection reuses
+    // `completion_selection`.
+    let mut reply_query = hooks.use_state(String::new);
+    // Item the open composer saves its draft for, and the cursor of the
+    // drafts overlay while it is open.
+    let mut draft_target = hooks.use_state(|| Option::<DraftTarget>::None);
+    // Set while the composer writes a review, to its verdict.
+    let mut review_event = hooks.use_state(|| Option::<ReviewEvent>::None);
+    let mut drafts_cursor = hooks.use_state(|| Option::<usize>::None);
+    // Spelling suggestions showing under the composer (`Ctrl+S`).
+    let mut spell_pick = hooks.use_state(|| Option::<super::common::SpellPick>::None);
+
+    // State: watched PRs (`owner/repo#number`), and those with a change the
+    // cursor has not visited yet.
+    let mut watched_prs = hooks.use_state(HashSet::<String>::new);
+    let mut watch_unseen = hooks.use_state(HashSet::<String>::new);
+    let watch_badges = props.watch_badges;
+    // State: PRs to merge when green (`owner/repo#number`).
+    let mut merge_when_green = hooks.use_state(HashSet::<String>::new);
+
     // State: label autocomplete.
     let mut label_candidates = hooks.use_state(Vec::<String>::new);
     let mut label_selection = hooks.use_state(|| 0usize);
     let mut label_selected = hooks.use_state(Vec::<String>::new);
 
     let mut help_visible = hooks.use_state(|| false);
+    let mut profile_overlay = hooks.use_state(|| None::<ProfileOverlay>);
+    let mut copy_menu_state = hooks.use_state(|| None::<CopyMenu>);
+    let mut share_menu_state = hooks.use_state(|| None::<ShareMenu>);
+    let mut files_menu_state = hooks.use_state(|| None::<FilesMenu>);
 
     // State: run selector overlay for JumpToRun disambiguation.
     let mut run_selector_items =
@@ -610,12 +991,15 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     let mut ephemeral_filters = hooks.use_state(Vec::<(PrFilter, Option<u64>)>::new);
 
     // State: rate limit from last GraphQL response.
-    let fallback_rl = hooks.use_state(|| None);
+    let fallback_rl = hooks.use_state(RateLimits::default);
     let mut rate_limit_state = props.rate_limit.unwrap_or(fallback_rl);
 
     // State: per-filter fetch tracking (lazy: only fetch the active filter).
     let mut filter_fetch_times =
         hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
+    // Per-filter auto-refresh postponement (`postpone_refresh`), for the countdown.
+    let mut refresh_postponed =
+        hooks.use_state(move || vec![Option::<std::time::Instant>::None; filter_count]);
     let mut filter_in_flight = hooks.use_state(move || vec![false; filter_count]);
     // Set by 'R' keypress; consumed by render body to fetch all filters eagerly.
     let mut refresh_all = hooks.use_state(|| false);
@@ -629,245 +1013,273 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     let mut refresh_registered = hooks.use_state(|| false);
 
     // State: loaded filter data (non-Copy, use .read()/.set()).
-    let initial_filters = vec![FilterData::default(); filter_count];
-    let mut prs_state = hooks.use_state(move || PrsState {
-        filters: initial_filters,
-    });
+    let mut prs_state = hooks.use_state(move || PrsState::new(filter_count));
+
+    // Track config reloads: keep to the tab shown and refetch every filter.
+    let mut last_filters = hooks.use_state(|| filters_cfg.to_vec());
+    let filters_changed = super::common::follow_reload(
+        &mut last_filters,
+        filters_cfg,
+        &mut active_filter,
+        &mut refresh_postponed,
+    );
+    if filters_changed {
+        drafts_flipped.set(HashSet::new());
+    }
 
     // Track scope changes: when scope_repo changes, invalidate all filters.
     let mut last_scope = hooks.use_state(|| scope_repo.clone());
-    if *last_scope.read() != *scope_repo {
+    if filters_changed || *last_scope.read() != *scope_repo {
         last_scope.set(scope_repo.clone());
-        // Reset all filters to trigger refetch with new scope.
-        prs_state.set(PrsState {
-            filters: vec![FilterData::default(); filter_count],
-        });
+        // Reset all filters to trigger refetch with the new scope or filters.
+        prs_state.set(PrsState::new(filter_count));
         filter_fetch_times.set(vec![None; filter_count]);
         filter_in_flight.set(vec![false; filter_count]);
         refresh_registered.set(false);
     }
 
+    // Rebuild the cached rows' date cells after the relative/absolute toggle.
+    let mut date_generation = hooks.use_state(crate::util::date_generation);
+    if date_generation.get() != crate::util::date_generation() {
+        date_generation.set(crate::util::date_generation());
+        let date_format = super::common::date_format(props.config.as_deref());
+        let details = detail_cache.read();
+        let mut state = prs_state.read().clone();
+        for (idx, fd) in state.filters.iter_mut().enumerate() {
+            let fd = Arc::make_mut(fd);
+            fd.rows = fd
+                .items
+                .iter()
+                .map(|pr| {
+                    pr_to_row(
+                        pr,
+                        &theme,
+                        date_format,
+                        details.get(&pr.number),
+                        &props.viewer_teams,
+                        row_layouts.get(idx),
+                    )
+                })
+                .collect();
+        }
+        drop(details);
+        prs_state.set(state);
+    }
+
+    // Team memberships arrive after the first fetches: mark team review
+    // requests in the cached rows, and refetch the `is:team-review` filters
+    // that were narrowed without them.
+    let team_review_only: Vec<bool> = filters_cfg
+        .iter()
+        .map(|cfg| filter::strip_team_review(&cfg.filters).1)
+        .collect();
+    let mut viewer_teams = hooks.use_state(Vec::<String>::new);
+    if *viewer_teams.read() != props.viewer_teams {
+        viewer_teams.set(props.viewer_teams.clone());
+        let date_format = super::common::date_format(props.config.as_deref());
+        let details = detail_cache.read();
+        let mut state = prs_state.read().clone();
+        for (idx, fd) in state.filters.iter_mut().enumerate() {
+            if team_review_only.get(idx).copied().unwrap_or(false) {
+                *fd = Arc::new(FilterData::default());
+                continue;
+            }
+            let fd = Arc::make_mut(fd);
+            fd.rows = fd
+                .items
+                .iter()
+                .map(|pr| {
+                    pr_to_row(
+                        pr,
+                        &theme,
+                        date_format,
+                        details.get(&pr.number),
+                        &props.viewer_teams,
+                        row_layouts.get(idx),
+                    )
+                })
+                .collect();
+        }
+        drop(details);
+        prs_state.set(state);
+    }
+
     // Channel for local (blocking) action results (clone, checkout, worktree).
-    // Spawned threads send the result string here; the polling future drains it.
+    // Spawned threads send the result string here; a future below awaits it.
     let local_action_channel = hooks.use_state(|| {
-        let (tx, rx) = std::sync::mpsc::channel::<String>();
-        (tx, std::sync::Arc::new(std::sync::Mutex::new(rx)))
+        let (tx, rx) = async_channel::unbounded::<String>();
+        (tx, std::sync::Arc::new(rx))
     });
-    let (local_action_tx, local_action_rx_arc) = local_action_channel.read().clone();
+    let (local_action_tx, local_action_rx) = local_action_channel.read().clone();
 
-    // Per-view event channel: engine sends results here, polling future processes them.
+    // Per-view event channel: engine sends results here, the event future processes them.
     let event_channel = hooks.use_state(super::common::new_event_channel);
-    let (event_tx, event_rx_arc) = event_channel.read().clone();
+    let (event_tx, event_rx) = event_channel.read().clone();
     // Clone the EngineHandle so it can be captured in 'static use_future closures.
     let engine: Option<EngineHandle> = props.engine.cloned();
-    // Pre-clone for each consumer: debounce future, polling future, fetch trigger, keyboard handler.
+    let mut was_active = hooks.use_state(|| false);
+    super::common::cancel_fetches_on_leave(&mut was_active, is_active, engine.as_ref(), &event_tx);
+    // Pre-clone for each consumer: debounce future, event future, fetch trigger, keyboard handler.
     let engine_for_poll = engine.clone();
     let engine_for_keyboard = engine.clone();
 
     // Debounce future: waits for cursor to settle, then sends FetchPrDetail to engine.
     let engine_for_debounce = engine.clone();
     let event_tx_for_debounce = event_tx.clone();
-    hooks.use_future(async move {
-        let mut last_gen = 0u64;
-        let mut spawned_gen = 0u64;
-        loop {
-            smol::Timer::after(std::time::Duration::from_millis(300)).await;
-            let current_gen = debounce_gen.get();
-            if current_gen != last_gen {
-                // Generation changed during this cycle — not stable yet.
-                last_gen = current_gen;
-            } else if current_gen > 0 && current_gen != spawned_gen {
-                // Stable for one full cycle and not yet spawned — fetch now.
-                let req = pending_detail.read().clone();
-                if let Some(DetailRequest {
-                    owner,
-                    repo,
-                    pr_number,
-                    base_ref,
-                    head_repo_owner,
-                    head_ref,
-                    force,
-                }) = req
-                {
-                    spawned_gen = current_gen;
-                    if let Some(ref eng) = engine_for_debounce {
-                        eng.send(Request::FetchPrDetail {
-                            pr_ref: PrRef {
-                                owner: owner.clone(),
-                                repo: repo.clone(),
-                                number: pr_number,
-                                base_ref,
-                                head_repo_owner,
-                                head_ref,
-                            },
-                            force,
-                            reply_tx: event_tx_for_debounce.clone(),
-                        });
-                    }
-                }
+    let event_tx_for_pages = event_tx.clone();
+    hooks.use_future(list::debounce_detail(
+        debounce_gen,
+        pending_detail,
+        move |req: DetailRequest| {
+            if let Some(ref eng) = engine_for_debounce {
+                eng.send(Request::FetchPrDetail {
+                    pr_ref: PrRef {
+                        owner: req.owner,
+                        repo: req.repo,
+                        number: req.pr_number,
+                        base_ref: req.base_ref,
+                        head_repo_owner: req.head_repo_owner,
+                        head_ref: req.head_ref,
+                    },
+                    force: req.force,
+                    host: req.host,
+                    reply_tx: event_tx_for_debounce.clone(),
+                });
             }
-        }
-    });
+        },
+    ));
 
     // Compute active filter index early (needed by fetch logic below).
     let eph_snapshot = ephemeral_filters.read().clone();
     let ephemeral_count = eph_snapshot.len();
     let total_tab_count = filter_count + ephemeral_count;
+    if let Some(tab) =
+        super::common::take_tab_click(tab_click, total_tab_count, active_filter.get())
+    {
+        active_filter.set(tab);
+        cursor.set(0);
+        scroll_offset.set(0);
+        preview_scroll.set(0);
+    }
     let current_filter_idx = active_filter.get().min(total_tab_count.saturating_sub(1));
-    let all_filters = merged_pr_filters(filters_cfg, &eph_snapshot);
-
-    // Lazy fetch: only fetch the active filter when it needs data.
-    let active_needs_fetch = prs_state
-        .read()
-        .filters
-        .get(current_filter_idx)
-        .is_some_and(|s| s.loading);
-    let active_in_flight = filter_in_flight
-        .read()
-        .get(current_filter_idx)
-        .copied()
-        .unwrap_or(false);
+    // Ephemeral tabs are not registered for auto-refresh.
+    let refresh_interval = filters_cfg.get(current_filter_idx).map(|f| {
+        super::common::refresh_interval(f.refetch_interval_minutes, props.refetch_interval_minutes)
+    });
+    let all_filters = list::merged_filters(filters_cfg, &eph_snapshot);
 
-    tracing::debug!(
-        "prs: lazy-fetch check: needs={active_needs_fetch} in_flight={active_in_flight} \
-         is_active={is_active} filter_idx={current_filter_idx} \
-         filter_count={filter_count} eph_count={ephemeral_count}"
+    list::apply_preview(
+        &mut preview_applied,
+        current_filter_idx,
+        all_filters.get(current_filter_idx).map(|(f, _)| *f),
+        SidebarTab::ALL,
+        &mut preview_open,
+        &mut sidebar_tab,
     );
 
-    // Register all filters for background refresh once at mount (or after scope change).
-    if !refresh_registered.get()
-        && let Some(ref eng) = engine
-    {
-        let scoped_configs: Vec<_> = filters_cfg
-            .iter()
-            .map(|cfg| {
-                let mut modified = cfg.clone();
-                modified.filters = apply_scope(&cfg.filters, scope_repo.as_deref());
-                modified
-            })
-            .collect();
-        eng.send(Request::RegisterRefresh {
-            configs: scoped_configs.into_iter().map(FilterConfig::Pr).collect(),
-            notify_tx: event_tx.clone(),
-        });
-        refresh_registered.set(true);
+    let badge_tabs = list::Fetches {
+        state: prs_state,
+        in_flight: filter_in_flight,
+        registered: refresh_registered,
+        refresh_all,
+        force: force_refresh,
+        engine: engine.as_ref(),
+        event_tx: &event_tx,
+        scope_repo: scope_repo.as_deref(),
+        is_active,
     }
+    .run(filters_cfg, &all_filters, current_filter_idx);
+    super::common::publish_view_count(
+        props.view_counts,
+        ViewKind::Prs,
+        list::badge_count(&prs_state.read(), &badge_tabs),
+    );
 
-    if refresh_all.get()
-        && is_active
-        && let Some(ref engine) = engine
-    {
-        // 'R' was pressed: reset the flag and eagerly fetch every filter.
-        tracing::debug!("prs: refresh_all FIRING for {} filters", all_filters.len());
-        refresh_all.set(false);
-        for (filter_idx, (cfg, _is_eph)) in all_filters.iter().enumerate() {
-            super::common::set_in_flight(&mut filter_in_flight, filter_idx, true);
-            let mut modified_filter = (*cfg).clone();
-            modified_filter.filters = apply_scope(&cfg.filters, scope_repo.as_deref());
-            engine.send(Request::FetchPrs {
-                filter_idx,
-                filter: modified_filter,
-                force: true,
-                reply_tx: event_tx.clone(),
-            });
+    // Local action results (clone/checkout/worktree background threads).
+    hooks.use_future(async move {
+        while let Ok(msg) = local_action_rx.recv().await {
+            let feedback = if msg.contains("error:") || msg.contains("failed:") {
+                ActionFeedback::Error(msg)
+            } else {
+                ActionFeedback::Success(msg)
+            };
+            action_status.set(Some(feedback));
+            status_set_at.set(Some(std::time::Instant::now()));
         }
-    } else if active_needs_fetch
-        && !active_in_flight
-        && is_active
-        && let Some(ref engine) = engine
-    {
-        // Look up the active filter from the merged list (config + ephemeral).
-        if let Some((cfg, _is_eph)) = all_filters.get(current_filter_idx) {
-            super::common::set_in_flight(&mut filter_in_flight, current_filter_idx, true);
-
-            let filter_idx = current_filter_idx;
-            let mut modified_filter = (*cfg).clone();
-            modified_filter.filters = apply_scope(&cfg.filters, scope_repo.as_deref());
-
-            // Consume the force flag: bypass cache for `r`-key and post-mutation fetches.
-            let force = force_refresh.get();
-            tracing::debug!("prs: lazy-fetch FIRING for filter_idx={filter_idx} force={force}");
-            if force {
-                force_refresh.set(false);
-            }
+    });
 
-            engine.send(Request::FetchPrs {
-                filter_idx,
-                filter: modified_filter,
-                force,
-                reply_tx: event_tx.clone(),
-            });
-        }
-    }
+    hooks.use_future(super::common::expire_action_status(
+        action_status,
+        status_set_at,
+    ));
+    let status = list::Status {
+        feedback: action_status,
+        set_at: status_set_at,
+    };
 
-    // Polling future: receive engine events every 100ms and update state.
+    // Event future: handle engine events as they arrive and update state.
     {
-        let rx_for_poll = event_rx_arc.clone();
-        let local_rx_for_poll = local_action_rx_arc.clone();
+        let rx_for_poll = event_rx.clone();
         let theme_for_poll = theme.clone();
-        let date_format_for_poll = props.date_format.unwrap_or("relative").to_owned();
+        let date_format_for_poll = super::common::date_format(props.config.as_deref()).to_owned();
         let prefetch_limit = props.prefetch_pr_details as usize;
+        let team_review_only = team_review_only.clone();
+        let row_layouts = row_layouts.clone();
         let engine = engine_for_poll;
         let event_tx = event_tx.clone();
+        let mut tracking = FetchTracking {
+            fetch_times: filter_fetch_times,
+            in_flight: filter_in_flight,
+        };
+        let mut shared = list::ListEvents {
+            state: prs_state,
+            tracking,
+            status,
+            profile: profile_overlay,
+            labels: label_candidates,
+            assignees: assignee_candidates,
+            issue_refs: issue_ref_candidates,
+        };
         hooks.use_future(async move {
-            loop {
-                smol::Timer::after(std::time::Duration::from_millis(100)).await;
-
-                // Drain local action results (clone/checkout/worktree background threads).
-                {
-                    let rx = local_rx_for_poll.lock().unwrap();
-                    while let Ok(msg) = rx.try_recv() {
-                        let feedback = if msg.contains("error:") || msg.contains("failed:") {
-                            ActionFeedback::Error(msg)
-                        } else {
-                            ActionFeedback::Success(msg)
-                        };
-                        action_status.set(Some(feedback));
-                        status_set_at.set(Some(std::time::Instant::now()));
-                    }
-                }
-
-                // Auto-clear status after 60 seconds.
-                if let Some(t) = status_set_at.get()
-                    && t.elapsed().as_secs() >= 60
-                {
-                    action_status.set(None);
-                    status_set_at.set(None);
-                }
-
-                let events: Vec<Event> = {
-                    let rx = rx_for_poll.lock().unwrap();
-                    let mut evts = Vec::new();
-                    while let Ok(evt) = rx.try_recv() {
-                        evts.push(evt);
-                    }
-                    evts
-                };
+            while let Some(events) = super::common::recv_batch(&rx_for_poll).await {
                 for evt in events {
                     match evt {
                         Event::PrsFetched {
                             filter_idx,
-                            prs,
+                            mut prs,
                             rate_limit,
+                            warnings,
+                            complete,
                         } => {
+                            if list::skip_streamed_page(&prs_state, filter_idx, complete) {
+                                continue;
+                            }
+                            let cost = rate_limit.as_ref().map(|rl| rl.cost);
                             tracing::debug!(
                                 "prs: PrsFetched received: filter_idx={filter_idx} count={}",
                                 prs.len()
                             );
                             super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                            crate::check_history::record(&prs);
+                            let teams = viewer_teams.read().clone();
+                            if team_review_only.get(filter_idx).copied().unwrap_or(false) {
+                                prs.retain(|pr| pr.requests_team_review(&teams));
+                            }
                             let detail_snap = detail_cache.read().clone();
                             let rows: Vec<Row> = prs
                                 .iter()
                                 .map(|pr| {
                                     let detail = detail_snap.get(&pr.number);
-                                    pr_to_row(pr, &theme_for_poll, &date_format_for_poll, detail)
+                                    pr_to_row(
+                                        pr,
+                                        &theme_for_poll,
+                                        &date_format_for_poll,
+                                        detail,
+                                        &teams,
+                                        row_layouts.get(filter_idx),
+                                    )
                                 })
                                 .collect();
-                            let bodies: Vec<String> =
-                                prs.iter().map(|pr| pr.body.clone()).collect();
-                            let titles: Vec<String> =
-                                prs.iter().map(|pr| pr.title.clone()).collect();
-                            let pr_count = prs.len();
                             let prs_for_prefetch: Vec<PrRef> = prs
                                 .iter()
                                 .take(prefetch_limit)
@@ -886,36 +1298,56 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                     })
                                 })
                                 .collect();
-                            let filter_data = FilterData {
-                                rows,
-                                bodies,
-                                titles,
-                                prs,
-                                pr_count,
-                                loading: false,
-                                error: None,
-                            };
-                            let mut state = prs_state.read().clone();
-                            if filter_idx < state.filters.len() {
-                                state.filters[filter_idx] = filter_data;
-                            }
-                            prs_state.set(state);
-                            let mut times = filter_fetch_times.read().clone();
-                            if filter_idx < times.len() {
-                                times[filter_idx] = Some(std::time::Instant::now());
+                            let prefetch_host =
+                                prs.first().and_then(|pr| crate::url::host_of(&pr.url));
+                            let data = FilterData::loaded(prs, rows, warnings, complete);
+                            list::store_fetched(
+                                &mut prs_state,
+                                &mut tracking,
+                                filter_idx,
+                                data,
+                                cost,
+                            );
+                            if !complete {
+                                continue;
                             }
-                            filter_fetch_times.set(times);
-                            super::common::set_in_flight(&mut filter_in_flight, filter_idx, false);
                             // Trigger prefetch via engine.
                             if !prs_for_prefetch.is_empty()
                                 && let Some(ref eng) = engine
                             {
                                 eng.send(Request::PrefetchPrDetails {
                                     prs: prs_for_prefetch,
+                                    host: prefetch_host,
                                     reply_tx: event_tx.clone(),
                                 });
                             }
                         }
+                        Event::PrsCached {
+                            filter_idx,
+                            mut prs,
+                            saved_at,
+                        } => {
+                            let teams = viewer_teams.read().clone();
+                            if team_review_only.get(filter_idx).copied().unwrap_or(false) {
+                                prs.retain(|pr| pr.requests_team_review(&teams));
+                            }
+                            let detail_snap = detail_cache.read().clone();
+                            let rows: Vec<Row> = prs
+                                .iter()
+                                .map(|pr| {
+                                    pr_to_row(
+                                        pr,
+                                        &theme_for_poll,
+                                        &date_format_for_poll,
+                                        detail_snap.get(&pr.number),
+                                        &teams,
+                                        row_layouts.get(filter_idx),
+                                    )
+                                })
+                                .collect();
+                            let data = FilterData::cached(prs, rows, saved_at);
+                            list::store_cached(&mut prs_state, filter_idx, data);
+                        }
                         Event::PrDetailFetched {
                             number,
                             detail,
@@ -926,9 +1358,11 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             // Skip for closed/merged PRs — branch status is irrelevant.
                             let mut state = prs_state.read().clone();
                             'update: for fd in &mut state.filters {
-                                if let Some(idx) = fd.prs.iter().position(|p| p.number == number) {
+                                if let Some(idx) = fd.items.iter().position(|p| p.number == number)
+                                {
+                                    let fd = Arc::make_mut(fd);
                                     let update = if matches!(
-                                        fd.prs[idx].state,
+                                        fd.items[idx].state,
                                         crate::github::types::PrState::Closed
                                             | crate::github::types::PrState::Merged
                                     ) {
@@ -945,6 +1379,25 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             cache.insert(number, detail);
                             detail_cache.set(cache);
                         }
+                        Event::PrDetailPageFetched {
+                            number,
+                            page,
+                            rate_limit,
+                        } => {
+                            super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                            // Drop the page if the detail was refetched meanwhile.
+                            let requested = pending_page.read().clone();
+                            let mut cache = detail_cache.read().clone();
+                            if let Some((_, connection, after)) = requested
+                                && let Some(mut detail) = cache.get(&number).cloned()
+                                && detail.next_cursor(connection) == Some(after.as_str())
+                            {
+                                detail.append_page(page);
+                                cache.insert(number, detail);
+                                detail_cache.set(cache);
+                            }
+                            pending_page.set(None);
+                        }
                         Event::PrRefreshed {
                             number,
                             pr,
@@ -952,17 +1405,22 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             rate_limit,
                         } => {
                             super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                            crate::check_history::record(std::slice::from_ref(&*pr));
                             // Update table row in ALL filters.
                             let mut state = prs_state.read().clone();
-                            for fd in &mut state.filters {
-                                if let Some(idx) = fd.prs.iter().position(|p| p.number == number) {
+                            for (filter_idx, fd) in state.filters.iter_mut().enumerate() {
+                                if let Some(idx) = fd.items.iter().position(|p| p.number == number)
+                                {
+                                    let fd = Arc::make_mut(fd);
                                     fd.rows[idx] = pr_to_row(
                                         &pr,
                                         &theme_for_poll,
                                         &date_format_for_poll,
                                         Some(&detail),
+                                        &viewer_teams.read(),
+                                        row_layouts.get(filter_idx),
                                     );
-                                    fd.prs[idx] = (*pr).clone();
+                                    fd.items[idx] = (*pr).clone();
                                 }
                             }
                             prs_state.set(state);
@@ -970,59 +1428,70 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             cache.insert(number, detail);
                             detail_cache.set(cache);
                         }
-                        Event::FetchError {
-                            context: _,
-                            message,
+                        Event::WatchedPrChanged {
+                            owner,
+                            repo,
+                            number,
+                            changes,
                         } => {
-                            // Find the in-flight filter and mark it as error.
-                            let in_flight = filter_in_flight.read().clone();
-                            let error_filter_idx = in_flight.iter().position(|&f| f);
-                            if let Some(fi) = error_filter_idx {
-                                let mut state = prs_state.read().clone();
-                                if fi < state.filters.len() {
-                                    state.filters[fi] = FilterData {
-                                        loading: false,
-                                        error: Some(message.clone()),
-                                        ..FilterData::default()
-                                    };
-                                }
-                                prs_state.set(state);
-                                let mut times = filter_fetch_times.read().clone();
-                                if fi < times.len() {
-                                    times[fi] = Some(std::time::Instant::now());
+                            let key = format!("{owner}/{repo}#{number}");
+                            if watched_prs.read().contains(&key) {
+                                let mut unseen = watch_unseen.read().clone();
+                                unseen.insert(key);
+                                watch_unseen.set(unseen);
+                                action_status.set(Some(ActionFeedback::Info(format!(
+                                    "PR #{number}: {}",
+                                    changes.join("; ")
+                                ))));
+                                status_set_at.set(Some(std::time::Instant::now()));
+                                if let Some(mut badges) = watch_badges {
+                                    let mut b = badges.get();
+                                    b.bump(ViewKind::Prs);
+                                    badges.set(b);
                                 }
-                                filter_fetch_times.set(times);
-                                super::common::set_in_flight(&mut filter_in_flight, fi, false);
                             }
                         }
-                        Event::MutationOk { description } => {
-                            action_status.set(Some(ActionFeedback::Success(description)));
-                            status_set_at.set(Some(std::time::Instant::now()));
-                            // The engine sends a PrRefreshed event right after
-                            // MutationOk, which will update the row in place
-                            // and refresh the detail cache — no full table
-                            // refresh needed.
-                        }
-                        Event::MutationError {
-                            description,
+                        Event::MergeWhenGreenEnded {
+                            owner,
+                            repo,
+                            number,
+                            merged,
                             message,
                         } => {
-                            action_status.set(Some(ActionFeedback::Error(format!(
-                                "{description}: {message}"
-                            ))));
-                            status_set_at.set(Some(std::time::Instant::now()));
-                        }
-                        Event::RepoLabelsFetched { labels, .. } => {
-                            label_candidates.set(labels);
+                            let mut armed = merge_when_green.read().clone();
+                            if armed.remove(&format!("{owner}/{repo}#{number}")) {
+                                merge_when_green.set(armed);
+                                action_status.set(Some(if merged {
+                                    ActionFeedback::Success(message)
+                                } else {
+                                    ActionFeedback::Error(message)
+                                }));
+                                status_set_at.set(Some(std::time::Instant::now()));
+                                if let Some(mut badges) = watch_badges {
+                                    let mut b = badges.get();
+                                    b.bump(ViewKind::Prs);
+                                    badges.set(b);
+                                }
+                            }
                         }
-                        Event::RepoCollaboratorsFetched { logins, .. } => {
-                            let mut combined = assignee_candidates.read().clone();
-                            combined.extend(logins);
-                            combined.sort();
-                            combined.dedup();
-                            assignee_candidates.set(combined);
+                        Event::FetchError { context, message }
+                            if context.starts_with("FetchPrDetailPage") =>
+                        {
+                            // Keep what loaded so far; the tab reports the gap.
+                            let requested = pending_page.read().clone();
+                            if let Some((number, connection, _)) = requested {
+                                let mut cache = detail_cache.read().clone();
+                                if let Some(mut detail) = cache.get(&number).cloned() {
+                                    detail.stop_paging(connection);
+                                    cache.insert(number, detail);
+                                    detail_cache.set(cache);
+                                }
+                                pending_page.set(None);
+                            }
+                            action_status.set(Some(ActionFeedback::Error(message)));
+                            status_set_at.set(Some(std::time::Instant::now()));
                         }
-                        _ => {}
+                        other => shared.handle(other),
                     }
                 }
             }
@@ -1034,6 +1503,19 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     // -----------------------------------------------------------------------
 
     let nav_target_prop = props.nav_target;
+    let mut nav = list::ListNav {
+        state: prs_state,
+        tracking: FetchTracking {
+            fetch_times: filter_fetch_times,
+            in_flight: filter_in_flight,
+        },
+        ephemeral: ephemeral_filters,
+        active_filter,
+        cursor,
+        scroll_offset,
+        preview_open,
+        preview_scroll,
+    };
 
     // When a deep-link is pending and some config tabs were never fetched,
     // trigger a full refresh so the search doesn't wait forever.
@@ -1071,7 +1553,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                     if fd.loading {
                         return None;
                     }
-                    fd.prs
+                    fd.items
                         .iter()
                         .position(|p| {
                             p.number == number
@@ -1086,6 +1568,8 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
             if let Some((filter_idx, pr_pos)) = found {
                 // PR found in an existing tab — switch to it.
                 active_filter.set(filter_idx);
+                // The position is in API order.
+                table_sort.set(None);
                 cursor.set(pr_pos);
                 scroll_offset.set(pr_pos.saturating_sub(5));
                 preview_open.set(true);
@@ -1133,10 +1617,11 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                 if fd.loading {
                                     return None;
                                 }
-                                fd.prs.iter().position(|p| p.number == number)
+                                fd.items.iter().position(|p| p.number == number)
                             })
                         };
                         if let Some(pos) = pr_in_tab {
+                            table_sort.set(None);
                             cursor.set(pos);
                             scroll_offset.set(pos.saturating_sub(5));
                             preview_open.set(true);
@@ -1168,28 +1653,14 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             host: host.clone(),
                             limit: None,
                             layout: None,
+                            hide_drafts: None,
+                            preview: None,
+                            preview_tab: None,
+                            refetch_interval_minutes: None,
+                            badge: None,
                         };
-                        let mut eph = ephemeral_filters.read().clone();
-                        eph.push((new_filter, Some(number)));
-                        let new_tab_idx = filter_count + eph.len() - 1;
-                        ephemeral_filters.set(eph);
-
-                        // Grow state vectors for the new tab.
-                        let mut state = prs_state.read().clone();
-                        state.filters.push(FilterData::default());
-                        prs_state.set(state);
-                        let mut in_flight = filter_in_flight.read().clone();
-                        in_flight.push(false);
-                        filter_in_flight.set(in_flight);
-                        let mut times = filter_fetch_times.read().clone();
-                        times.push(None);
-                        filter_fetch_times.set(times);
-
                         // Switch to the new tab and trigger fetch.
-                        active_filter.set(new_tab_idx);
-                        cursor.set(0);
-                        scroll_offset.set(0);
-                        preview_scroll.set(0);
+                        nav.open_tab(filter_count, new_filter, Some(number));
                         search_query.set(String::new());
 
                         // FetchPrs will be triggered by the active_needs_fetch
@@ -1220,10 +1691,12 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 let state = prs_state.read();
                 if let Some(fd) = state.filters.get(tab_idx)
                     && !fd.loading
+                    && !fd.loading_more
                 {
-                    let pos = fd.prs.iter().position(|p| p.number == *target_number);
+                    let pos = fd.items.iter().position(|p| p.number == *target_number);
                     if let Some(pos) = pos {
                         if active_filter.get() == tab_idx {
+                            table_sort.set(None);
                             cursor.set(pos);
                             scroll_offset.set(pos.saturating_sub(5));
                             preview_open.set(true);
@@ -1252,28 +1725,147 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
         .get(current_filter_idx)
         .map_or(0, |s| s.rows.len());
     let search_q = search_query.read().clone();
-    let total_rows = if search_q.is_empty() {
-        all_rows_count
+    let active_slice = ci_slice.get();
+    let active_size = size_slice.get();
+    let hides_drafts = |idx: usize| {
+        let configured = all_filters
+            .get(idx)
+            .and_then(|(cfg, _)| cfg.hide_drafts)
+            .unwrap_or(false);
+        configured != drafts_flipped.read().contains(&idx)
+    };
+    let wip_patterns = props.wip_patterns.as_slice();
+    let hide_wip = hides_drafts(current_filter_idx).then_some(wip_patterns);
+    let columns = pr_columns(&theme.icons);
+
+    // Layout config for hidden/width overrides.
+    let layout = filters_cfg
+        .get(current_filter_idx)
+        .and_then(|s| s.layout.as_ref());
+    let hidden_set: HashSet<String> = OPTIONAL_PR_COLUMNS
+        .iter()
+        .filter(|id| layout.is_none_or(|l| !l.shown.iter().any(|s| s == *id)))
+        .map(|id| (*id).to_owned())
+        .chain(layout.into_iter().flat_map(|l| l.hidden.iter().cloned()))
+        .collect();
+    let width_map: HashMap<String, u16> = layout.map(|l| l.widths.clone()).unwrap_or_default();
+
+    // Table rows map to PRs through these indices: the cursor is a position in
+    // the visible list, not in `prs`.
+    let mut visible_indices: Vec<usize> = if search_q.is_empty()
+        && active_slice.is_none()
+        && active_size.is_none()
+        && hide_wip.is_none()
+    {
+        (0..all_rows_count).collect()
     } else {
         state_ref
             .filters
             .get(current_filter_idx)
-            .map_or(0, |s| filter::filter_rows(&s.rows, &search_q).len())
+            .map_or_else(Vec::new, |s| {
+                visible_pr_indices(s, &search_q, active_slice, active_size, hide_wip)
+            })
     };
+    let current_sort = table_sort.read().clone();
+    if let Some(data) = state_ref.filters.get(current_filter_idx) {
+        table::sort_indices(
+            &mut visible_indices,
+            &data.rows,
+            current_sort.as_ref(),
+            |i, column| pr_sort_value(data.items.get(i)?, column),
+        );
+    }
+    // Sortable columns shown, in order, cycled through by `sort_column`.
+    let sort_columns: Vec<Column> = columns
+        .iter()
+        .filter(|c| c.sort.is_some() && !hidden_set.contains(&c.id))
+        .cloned()
+        .collect();
+    let total_rows = visible_indices.len();
+
+    // Keep the cursor on the same PR when a refresh replaces the rows.
+    let fetched_at = filter_fetch_times
+        .read()
+        .get(current_filter_idx)
+        .copied()
+        .flatten();
+    super::common::anchor_cursor(
+        &mut cursor_anchor,
+        &mut cursor,
+        &mut scroll_offset,
+        current_filter_idx,
+        fetched_at,
+        &visible_indices
+            .iter()
+            .filter_map(|&i| state_ref.filters.get(current_filter_idx)?.items.get(i))
+            .map(|pr| (pr.repo.as_ref().map(RepoRef::full_name), pr.number))
+            .collect::<Vec<_>>(),
+    );
+
+    let rendered_banner = state_ref.filters.get(current_filter_idx).and_then(|s| {
+        RenderedWarningBanner::build(
+            &s.warnings,
+            &theme.icons.feedback_warning,
+            depth,
+            Some(theme.text_warning),
+        )
+    });
+    let banner_lines = u16::from(rendered_banner.is_some());
 
-    // Reserve space for tab bar (2 lines), footer (2 lines), header (1 line).
+    // Reserve space for tab bar (2 lines), footer (2 lines), header (1 line)
+    // and the partial-results banner when shown.
     // Each PR row occupies 2 terminal lines (info + subtitle).
-    let visible_rows = (props.height.saturating_sub(5) / 3).max(1) as usize;
+    let visible_rows = (props.height.saturating_sub(5 + banner_lines) / 3).max(1) as usize;
+    super::common::take_table_click(
+        row_click,
+        total_rows,
+        super::common::ClickTargets {
+            cursor,
+            preview_scroll,
+            preview_open,
+            sidebar_focused,
+        },
+    );
 
-    let repo_paths = props.repo_paths.cloned().unwrap_or_default();
+    let repo_paths = props
+        .config
+        .as_deref()
+        .map(|c| c.repo_paths.clone())
+        .unwrap_or_default();
     let auto_clone = props.auto_clone;
+    let sync_viewed_files = props.sync_viewed_files;
+    let merge_method = props.merge_method;
     let filter_host_for_kb = all_filters
         .get(current_filter_idx)
         .and_then(|(f, _)| f.host.clone());
+    let mut overlays = list::ListOverlays {
+        help: help_visible,
+        profile: profile_overlay,
+        drafts: drafts_cursor,
+        copy_menu: copy_menu_state,
+        share_menu: share_menu_state,
+        status,
+    };
+    let signals = list::AppSignals {
+        should_exit,
+        switch_view,
+        switch_view_back,
+        goto_view,
+        scope_toggle,
+        show_logs,
+        date_toggle,
+        repo_picker,
+        rescan_repo,
+        preview_pct: preview_pct_state,
+        default_pct,
+    };
     // Engine handle for the keyboard handler closure.
     let engine = engine_for_keyboard;
 
-    let keybindings = props.keybindings.cloned();
+    let keybindings = props.keybindings.as_deref().cloned();
+    let visible_for_keys = visible_indices.clone();
+    let reply_templates = props.reply_templates.clone();
+    let chrome = theme.chrome;
     // Keyboard handling.
     hooks.use_terminal_events({
         move |event| match event {
@@ -1287,10 +1879,26 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 if !is_active {
                     return;
                 }
-                // Help overlay: intercept all keys when visible.
-                if help_visible.get() {
-                    if matches!(code, KeyCode::Char('?') | KeyCode::Esc) {
-                        help_visible.set(false);
+                // Index into the active filter's `prs` of the PR under the cursor.
+                let selected_pr = visible_for_keys.get(cursor.get()).copied();
+                if overlays.handle_key(
+                    code,
+                    engine.as_ref(),
+                    filter_host_for_kb.clone(),
+                    &event_tx,
+                ) {
+                    return;
+                }
+                if files_menu_state.read().is_some() {
+                    let sync = engine
+                        .as_ref()
+                        .filter(|_| sync_viewed_files)
+                        .map(|eng| (eng, &event_tx, filter_host_for_kb.as_deref()));
+                    if let Some(feedback) =
+                        files_menu::handle_key(&mut files_menu_state, code, sync)
+                    {
+                        action_status.set(Some(feedback));
+                        status_set_at.set(Some(std::time::Instant::now()));
                     }
                     return;
                 }
@@ -1338,7 +1946,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                     input_buffer,
                     prs_state: &prs_state,
                     filter_idx: current_filter_idx,
-                    cursor: cursor.get(),
+                    pr_idx: selected_pr,
                     engine: engine.as_ref(),
                     event_tx: &event_tx,
                 };
@@ -1348,7 +1956,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                         let eng = input_ctx.engine.cloned();
                         let tx = input_ctx.event_tx.clone();
                         let fi = input_ctx.filter_idx;
-                        let cur = input_ctx.cursor;
+                        let cur = input_ctx.pr_idx;
                         let ps = *input_ctx.prs_state;
                         super::common::handle_multiselect_input(
                             code,
@@ -1361,7 +1969,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             },
                             |logins| {
                                 if let Some((owner, repo, number)) =
-                                    get_current_pr_info(&ps, fi, cur)
+                                    list::item_info(&ps, fi, cur)
                                     && let Some(eng) = eng
                                 {
                                     eng.send(Request::SetPrAssignees {
@@ -1369,6 +1977,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         repo,
                                         number,
                                         logins,
+                                        host: filter_host_for_kb.clone(),
                                         reply_tx: tx,
                                     });
                                 }
@@ -1381,7 +1990,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                         let eng = input_ctx.engine.cloned();
                         let tx = input_ctx.event_tx.clone();
                         let fi = input_ctx.filter_idx;
-                        let cur = input_ctx.cursor;
+                        let cur = input_ctx.pr_idx;
                         let ps = *input_ctx.prs_state;
                         super::common::handle_multiselect_input(
                             code,
@@ -1394,7 +2003,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             },
                             |labels| {
                                 if let Some((owner, repo, number)) =
-                                    get_current_pr_info(&ps, fi, cur)
+                                    list::item_info(&ps, fi, cur)
                                     && let Some(eng) = eng
                                 {
                                     eng.send(Request::SetPrLabels {
@@ -1402,6 +2011,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         repo,
                                         number,
                                         labels,
+                                        host: filter_host_for_kb.clone(),
                                         reply_tx: tx,
                                     });
                                 }
@@ -1409,33 +2019,142 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             move || im.set(InputMode::Normal),
                         );
                     }
+                    InputMode::Comment
+                        if super::common::handle_spell_input(
+                            code,
+                            modifiers,
+                            &mut spell_pick,
+                            input_buffer,
+                        ) => {}
+                    InputMode::Comment
+                        if super::common::handle_completion_input(
+                            code,
+                            &mut super::common::CompletionState {
+                                input_buffer,
+                                logins: assignee_candidates,
+                                refs: issue_ref_candidates,
+                                selection: completion_selection,
+                            },
+                        ) => {}
+                    InputMode::ReplyPicker => {
+                        let mut im = input_mode;
+                        super::common::handle_reply_picker_input(
+                            code,
+                            modifiers,
+                            &mut super::common::ReplyPickerState {
+                                comment: input_buffer,
+                                query: reply_query,
+                                selection: completion_selection,
+                            },
+                            &reply_templates,
+                            || {
+                                let state = prs_state.read();
+                                state
+                                    .filters
+                                    .get(current_filter_idx)
+                                    .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                    .map(list::reply_vars)
+                                    .unwrap_or_default()
+                            },
+                            move || im.set(InputMode::Comment),
+                        );
+                    }
                     InputMode::Comment => match code {
-                        // Submit comment with Ctrl+D.
+                        // Insert a reply template with Ctrl+T.
+                        KeyCode::Char('t') if modifiers.contains(KeyModifiers::CONTROL) => {
+                            if reply_templates.is_empty() {
+                                action_status.set(Some(ActionFeedback::Info(
+                                    "No saved replies or reply templates".to_owned(),
+                                )));
+                                status_set_at.set(Some(std::time::Instant::now()));
+                            } else {
+                                reply_query.set(String::new());
+                                completion_selection.set(0);
+                                input_mode.set(InputMode::ReplyPicker);
+                            }
+                        }
+                        // Cycle the verdict of a review with Ctrl+R.
+                        KeyCode::Char('r')
+                            if modifiers.contains(KeyModifiers::CONTROL)
+                                && review_event.get().is_some() =>
+                        {
+                            review_event.set(review_event.get().map(ReviewEvent::next));
+                        }
+                        // Submit comment (or review) with Ctrl+D.
                         KeyCode::Char('d') if modifiers.contains(KeyModifiers::CONTROL) => {
                             let comment_text = input_buffer.read().clone();
-                            if !comment_text.is_empty() {
-                                let pr_info = get_current_pr_info(
-                                    &prs_state,
-                                    current_filter_idx,
-                                    cursor.get(),
-                                );
-                                if let Some((owner, repo, number)) = pr_info
+                            let pr_info = list::item_info(
+                                &prs_state,
+                                current_filter_idx,
+                                selected_pr,
+                            );
+                            let submitted = if let Some(event) = review_event.get() {
+                                let draft = review::parse(&comment_text).and_then(|draft| {
+                                    match review::missing(event, &draft) {
+                                        Some(missing) => Err(missing.to_owned()),
+                                        None => Ok(draft),
+                                    }
+                                });
+                                match draft {
+                                    Ok(draft) => {
+                                        if let Some((owner, repo, number)) = pr_info
+                                            && let Some(ref eng) = engine
+                                        {
+                                            eng.send(Request::SubmitPrReview {
+                                                owner,
+                                                repo,
+                                                number,
+                                                event,
+                                                body: draft.body,
+                                                comments: draft.comments,
+                                                host: filter_host_for_kb.clone(),
+                                                reply_tx: event_tx.clone(),
+                                            });
+                                        }
+                                        true
+                                    }
+                                    Err(message) => {
+                                        action_status.set(Some(ActionFeedback::Error(message)));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                        false
+                                    }
+                                }
+                            } else {
+                                if !comment_text.is_empty()
+                                    && let Some((owner, repo, number)) = pr_info
                                     && let Some(ref eng) = engine
                                 {
                                     eng.send(Request::AddPrComment {
-                                        owner: owner.clone(),
-                                        repo: repo.clone(),
+                                        owner,
+                                        repo,
                                         number,
                                         body: comment_text.clone(),
+                                        host: filter_host_for_kb.clone(),
                                         reply_tx: event_tx.clone(),
                                     });
                                 }
+                                true
+                            };
+                            if submitted {
+                                if let Some(target) = draft_target.read().as_ref() {
+                                    drafts::discard(&target.key);
+                                }
+                                draft_target.set(None);
+                                review_event.set(None);
+                                input_mode.set(InputMode::Normal);
+                                input_buffer.set(String::new());
                             }
-                            input_mode.set(InputMode::Normal);
-                            input_buffer.set(String::new());
                         }
-                        // Cancel with Esc.
+                        // Cancel with Esc; the text stays saved as a draft.
                         KeyCode::Esc => {
+                            if let Some(feedback) =
+                                super::common::keep_draft(draft_target.read().as_ref(), &input_buffer.read())
+                            {
+                                action_status.set(Some(feedback));
+                                status_set_at.set(Some(std::time::Instant::now()));
+                            }
+                            draft_target.set(None);
+                            review_event.set(None);
                             input_mode.set(InputMode::Normal);
                             input_buffer.set(String::new());
                         }
@@ -1468,7 +2187,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         .get(current_filter_idx)
                                         .cloned();
                                     if let Some(data) = current_data
-                                        && let Some(pr) = data.prs.get(cursor.get())
+                                        && let Some(pr) = selected_pr.and_then(|i| data.items.get(i))
                                     {
                                         let repo_name = pr
                                             .repo
@@ -1479,7 +2198,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                             .as_deref()
                                             .unwrap_or("github.com")
                                             .to_owned();
-                                        action_status.set(Some(ActionFeedback::Info(format!("Cloning {repo_name}…"))));
+                                        action_status.set(Some(ActionFeedback::Info(format!("Cloning {repo_name}{}", ellipsis(chrome)))));
                                         status_set_at.set(Some(std::time::Instant::now()));
                                         crate::actions::local::spawn_checkout(
                                             pr.head_ref.clone(),
@@ -1498,7 +2217,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         .get(current_filter_idx)
                                         .cloned();
                                     if let Some(data) = current_data
-                                        && let Some(pr) = data.prs.get(cursor.get())
+                                        && let Some(pr) = selected_pr.and_then(|i| data.items.get(i))
                                     {
                                         let repo_name = pr
                                             .repo
@@ -1511,7 +2230,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                 .as_deref()
                                                 .unwrap_or("github.com")
                                                 .to_owned();
-                                            action_status.set(Some(ActionFeedback::Info(format!("Cloning {repo_name}…"))));
+                                            action_status.set(Some(ActionFeedback::Info(format!("Cloning {repo_name}{}", ellipsis(chrome)))));
                                             status_set_at.set(Some(std::time::Instant::now()));
                                             crate::actions::local::spawn_worktree(
                                                 pr.head_ref.clone(),
@@ -1557,10 +2276,10 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                     }
                                 }
                                 _ => {
-                                    let pr_info = get_current_pr_info(
+                                    let pr_info = list::item_info(
                                         &prs_state,
                                         current_filter_idx,
-                                        cursor.get(),
+                                        selected_pr,
                                     );
                                     if let Some((owner, repo, number)) = pr_info
                                         && let Some(ref eng) = engine
@@ -1571,6 +2290,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                     owner: owner.clone(),
                                                     repo: repo.clone(),
                                                     number,
+                                                    host: filter_host_for_kb.clone(),
                                                     reply_tx: event_tx.clone(),
                                                 });
                                             }
@@ -1579,6 +2299,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                     owner: owner.clone(),
                                                     repo: repo.clone(),
                                                     number,
+                                                    host: filter_host_for_kb.clone(),
                                                     reply_tx: event_tx.clone(),
                                                 });
                                             }
@@ -1587,6 +2308,8 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                     owner: owner.clone(),
                                                     repo: repo.clone(),
                                                     number,
+                                                    method: merge_method,
+                                                    host: filter_host_for_kb.clone(),
                                                     reply_tx: event_tx.clone(),
                                                 });
                                             }
@@ -1596,6 +2319,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                     repo: repo.clone(),
                                                     number,
                                                     body: None,
+                                                    host: filter_host_for_kb.clone(),
                                                     reply_tx: event_tx.clone(),
                                                 });
                                             }
@@ -1604,6 +2328,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                     owner: owner.clone(),
                                                     repo: repo.clone(),
                                                     number,
+                                                    host: filter_host_for_kb.clone(),
                                                     reply_tx: event_tx.clone(),
                                                 });
                                             }
@@ -1612,9 +2337,80 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                     owner: owner.clone(),
                                                     repo: repo.clone(),
                                                     number,
+                                                    host: filter_host_for_kb.clone(),
                                                     reply_tx: event_tx.clone(),
                                                 });
                                             }
+                                            BuiltinAction::Enqueue => {
+                                                eng.send(Request::EnqueuePr {
+                                                    owner: owner.clone(),
+                                                    repo: repo.clone(),
+                                                    number,
+                                                    host: filter_host_for_kb.clone(),
+                                                    reply_tx: event_tx.clone(),
+                                                });
+                                            }
+                                            BuiltinAction::ApproveWorkflows => {
+                                                let run_ids: Vec<u64> = detail_cache
+                                                    .read()
+                                                    .get(&number)
+                                                    .map(|d| {
+                                                        d.awaiting_approval
+                                                            .iter()
+                                                            .map(|run| run.run_id)
+                                                            .collect()
+                                                    })
+                                                    .unwrap_or_default();
+                                                if !run_ids.is_empty() {
+                                                    eng.send(Request::ApproveWorkflowRuns {
+                                                        host: filter_host_for_kb.clone(),
+                                                        owner: owner.clone(),
+                                                        repo: repo.clone(),
+                                                        number,
+                                                        run_ids,
+                                                        reply_tx: event_tx.clone(),
+                                                    });
+                                                }
+                                            }
+                                            BuiltinAction::MergeWhenGreen => {
+                                                let branches = {
+                                                    let state = prs_state.read();
+                                                    state
+                                                        .filters
+                                                        .get(current_filter_idx)
+                                                        .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                        .map(|p| {
+                                                            (
+                                                                p.base_ref.clone(),
+                                                                p.head_repo_owner.clone(),
+                                                                p.head_ref.clone(),
+                                                            )
+                                                        })
+                                                };
+                                                if let Some((base_ref, head_repo_owner, head_ref)) =
+                                                    branches
+                                                {
+                                                    eng.send(Request::MergeWhenGreen {
+                                                        owner: owner.clone(),
+                                                        repo: repo.clone(),
+                                                        number,
+                                                        base_ref,
+                                                        head_repo_owner,
+                                                        head_ref,
+                                                        method: merge_method,
+                                                        host: filter_host_for_kb.clone(),
+                                                        reply_tx: event_tx.clone(),
+                                                    });
+                                                    let mut armed = merge_when_green.read().clone();
+                                                    armed.insert(format!("{owner}/{repo}#{number}"));
+                                                    merge_when_green.set(armed);
+                                                    action_status.set(Some(ActionFeedback::Success(format!(
+                                                        "PR #{number} will be merged ({}) once green",
+                                                        merge_method.as_str()
+                                                    ))));
+                                                    status_set_at.set(Some(std::time::Instant::now()));
+                                                }
+                                            }
                                             _ => {}
                                         }
                                     }
@@ -1622,6 +2418,19 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                             }
                             input_mode.set(InputMode::Normal);
                         }
+                        // Write the approval out as a review instead.
+                        KeyCode::Char('e') if matches!(pending, BuiltinAction::Approve) => {
+                            let target = list::draft_target(&prs_state, current_filter_idx, selected_pr);
+                            let text = target
+                                .as_ref()
+                                .map(|target| drafts::restore(target, None).0)
+                                .unwrap_or_default();
+                            review_event.set(Some(ReviewEvent::Approve));
+                            input_buffer.set(text);
+                            draft_target.set(target);
+                            completion_selection.set(0);
+                            input_mode.set(InputMode::Comment);
+                        }
                         KeyCode::Char('n' | 'N') | KeyCode::Esc => {
                             input_mode.set(InputMode::Normal);
                             action_status.set(Some(ActionFeedback::Info("Cancelled".to_owned())));
@@ -1629,35 +2438,42 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                         }
                         _ => {}
                     },
-                    InputMode::Search => match code {
-                        KeyCode::Esc => {
+                    InputMode::KeepTab => match keep_tab::handle_key(code, modifiers, &mut input_buffer) {
+                        keep_tab::Prompt::Editing => {}
+                        keep_tab::Prompt::Cancelled => input_mode.set(InputMode::Normal),
+                        keep_tab::Prompt::Submitted(title) => {
                             input_mode.set(InputMode::Normal);
-                            search_query.set(String::new());
+                            input_buffer.set(String::new());
+                            let mut eph = ephemeral_filters.read().clone();
+                            if let Some((filter, _)) = current_filter_idx
+                                .checked_sub(filter_count)
+                                .and_then(|ei| eph.get_mut(ei))
+                            {
+                                let feedback = keep_tab::keep("pr_filters", &keep_tab::pr_table(filter, &title));
+                                if matches!(feedback, ActionFeedback::Success(_)) {
+                                    filter.title = title;
+                                    ephemeral_filters.set(eph);
+                                }
+                                action_status.set(Some(feedback));
+                                status_set_at.set(Some(std::time::Instant::now()));
+                            }
                         }
-                        KeyCode::Enter => {
-                            // Confirm search: stay filtered but exit search input.
+                    },
+                    InputMode::Search => {
+                        if list::search_key(
+                            code,
+                            modifiers,
+                            &mut search_query,
+                            &mut cursor,
+                            &mut scroll_offset,
+                        ) {
                             input_mode.set(InputMode::Normal);
                         }
-                        KeyCode::Backspace => {
-                            let mut q = search_query.read().clone();
-                            q.pop();
-                            search_query.set(q);
-                            cursor.set(0);
-                            scroll_offset.set(0);
-                        }
-                        KeyCode::Char(ch) if !modifiers.contains(KeyModifiers::CONTROL) => {
-                            let mut q = search_query.read().clone();
-                            q.push(ch);
-                            search_query.set(q);
-                            cursor.set(0);
-                            scroll_offset.set(0);
-                        }
-                        _ => {}
-                    },
+                    }
                     InputMode::Normal => {
                         if let Some(key_str) = key_event_to_string(code, modifiers, kind) {
                             let pr_info =
-                                get_current_pr_info(&prs_state, current_filter_idx, cursor.get());
+                                list::item_info(&prs_state, current_filter_idx, selected_pr);
                             let (pr_owner, pr_repo, pr_number) =
                                 pr_info.unwrap_or_else(|| (String::new(), String::new(), 0));
                             let pr_url = if pr_number > 0 {
@@ -1670,7 +2486,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                 state
                                     .filters
                                     .get(current_filter_idx)
-                                    .and_then(|f| f.prs.get(cursor.get()))
+                                    .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
                                     .map_or_else(String::new, |p| p.head_ref.clone())
                             };
                             let base_branch = {
@@ -1678,9 +2494,16 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                 state
                                     .filters
                                     .get(current_filter_idx)
-                                    .and_then(|f| f.prs.get(cursor.get()))
+                                    .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
                                     .map_or_else(String::new, |p| p.base_ref.clone())
                             };
+                            let (title, state) = list::with_item(
+                                &prs_state,
+                                current_filter_idx,
+                                selected_pr,
+                                |pr| (pr.title.clone(), crate::share::pr_state(pr).to_owned()),
+                            )
+                            .unwrap_or_default();
                             let vars = TemplateVars {
                                 url: pr_url.clone(),
                                 number: if pr_number > 0 {
@@ -1691,70 +2514,364 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                 repo_name: format!("{pr_owner}/{pr_repo}"),
                                 head_branch,
                                 base_branch,
+                                title,
+                                state,
                                 ..Default::default()
                             };
                             match keybindings
                                 .as_ref()
-                                .and_then(|kb| kb.resolve(&key_str, ViewContext::Prs))
+                                .and_then(|kb| {
+                                    kb.resolve_focused(&key_str, ViewContext::Prs, sidebar_focused.get())
+                                })
+                                .map(|binding| super::common::refresh_fallback(binding, total_rows))
                             {
                                 Some(ResolvedBinding::Builtin(action)) => match action {
-                                    BuiltinAction::Quit => {
-                                        if let Some(mut exit) = should_exit {
-                                            exit.set(true);
-                                        }
+                                    action if signals.handle(action) => {}
+                                    BuiltinAction::SortColumn | BuiltinAction::ReverseSort => {
+                                        let columns: Vec<&Column> = sort_columns.iter().collect();
+                                        let current = table_sort.read().clone();
+                                        table_sort.set(if action == BuiltinAction::SortColumn {
+                                            TableSort::next(current.as_ref(), &columns)
+                                        } else {
+                                            TableSort::reversed(current.as_ref(), &columns)
+                                        });
+                                        cursor.set(0);
+                                        scroll_offset.set(0);
                                     }
-                                    BuiltinAction::SwitchView => {
-                                        if let Some(mut sv) = switch_view {
-                                            sv.set(true);
+                                    BuiltinAction::ToggleFocus => {
+                                        let focused = !sidebar_focused.get();
+                                        sidebar_focused.set(focused);
+                                        if focused {
+                                            preview_open.set(true);
                                         }
                                     }
-                                    BuiltinAction::SwitchViewBack => {
-                                        if let Some(mut sv) = switch_view_back {
-                                            sv.set(true);
+                                    BuiltinAction::MoveDown
+                                    | BuiltinAction::MoveUp
+                                    | BuiltinAction::First
+                                    | BuiltinAction::Last
+                                        if sidebar_focused.get() =>
+                                    {
+                                        let tab = sidebar_tab.get();
+                                        let items = {
+                                            let state = prs_state.read();
+                                            let cache = detail_cache.read();
+                                            state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                .map(|pr| {
+                                                    let detail = cache.get(&pr.number);
+                                                    (pr.number, sidebar_items(tab, pr, detail, show_noise.get()))
+                                                })
+                                        };
+                                        match items {
+                                            Some((num, items)) if !items.is_empty() => {
+                                                if tab == SidebarTab::Activity {
+                                                    let current = comment_sel
+                                                        .get()
+                                                        .filter(|(n, _)| *n == num)
+                                                        .map(|(_, i)| i);
+                                                    if let Some(idx) = sidebar_focus::step(&items, current, action) {
+                                                        comment_sel.set(Some((num, idx)));
+                                                        comment_jump.set(true);
+                                                    }
+                                                } else {
+                                                    let current = item_sel
+                                                        .get()
+                                                        .filter(|(n, t, _)| *n == num && *t == tab)
+                                                        .map(|(_, _, i)| i);
+                                                    if let Some(idx) = sidebar_focus::step(&items, current, action) {
+                                                        item_sel.set(Some((num, tab, idx)));
+                                                        item_jump.set(true);
+                                                    }
+                                                }
+                                            }
+                                            _ => preview_scroll.set(sidebar_focus::scroll(preview_scroll.get(), action)),
                                         }
                                     }
-                                    action if super::common::goto_target(action).is_some() => {
-                                        if let Some(mut gv) = goto_view {
-                                            gv.set(super::common::goto_target(action));
-                                        }
+                                    BuiltinAction::OpenItem
+                                    | BuiltinAction::CopyItem
+                                    | BuiltinAction::ToggleItem => {
+                                        let tab = sidebar_tab.get();
+                                        let item = {
+                                            let state = prs_state.read();
+                                            let cache = detail_cache.read();
+                                            state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                .and_then(|pr| {
+                                                    let detail = cache.get(&pr.number);
+                                                    let selected = if tab == SidebarTab::Activity {
+                                                        comment_sel.get().filter(|(n, _)| *n == pr.number).map(|(_, i)| i)
+                                                    } else {
+                                                        item_sel
+                                                            .get()
+                                                            .filter(|(n, t, _)| *n == pr.number && *t == tab)
+                                                            .map(|(_, _, i)| i)
+                                                    };
+                                                    SidebarItem::of(tab, pr, detail, selected?)
+                                                })
+                                        };
+                                        let feedback = match (action, item) {
+                                            (_, None) => ActionFeedback::Info(
+                                                "Select a comment, file or check first: tab, then j/k".to_owned(),
+                                            ),
+                                            (BuiltinAction::ToggleItem, Some(SidebarItem::File { idx, .. })) => {
+                                                let files = detail_cache
+                                                    .read()
+                                                    .get(&pr_number)
+                                                    .map(|d| d.files.clone())
+                                                    .unwrap_or_default();
+                                                let sync = engine
+                                                    .as_ref()
+                                                    .filter(|_| sync_viewed_files)
+                                                    .map(|eng| (eng, &event_tx, filter_host_for_kb.as_deref()));
+                                                files_menu::toggle_file((&pr_owner, &pr_repo, pr_number), files, idx, sync)
+                                                    .unwrap_or_else(|| ActionFeedback::Info(format!("Loading files{}", ellipsis(chrome))))
+                                            }
+                                            (BuiltinAction::ToggleItem, Some(_)) => {
+                                                ActionFeedback::Info("Only files can be marked viewed".to_owned())
+                                            }
+                                            (BuiltinAction::CopyItem, Some(item)) => match item.copy_text() {
+                                                Some(text) => match clipboard::copy_to_clipboard(text) {
+                                                    Ok(()) => ActionFeedback::Success(format!("Copied {}", item.copied())),
+                                                    Err(e) => ActionFeedback::Error(format!("Copy failed: {e}")),
+                                                },
+                                                None => ActionFeedback::Info("This check has no link".to_owned()),
+                                            },
+                                            (_, Some(item)) => match item.url() {
+                                                Some(url) => match clipboard::open_in_browser(url) {
+                                                    Ok(how) => ActionFeedback::Success(how.message(item.noun())),
+                                                    Err(e) => ActionFeedback::Error(format!("Open failed: {e}")),
+                                                },
+                                                None => ActionFeedback::Info("This check has no link".to_owned()),
+                                            },
+                                        };
+                                        action_status.set(Some(feedback));
+                                        status_set_at.set(Some(std::time::Instant::now()));
                                     }
-                                    BuiltinAction::ToggleScope => {
-                                        if let Some(mut st) = scope_toggle {
-                                            st.set(true);
-                                        }
+                                    BuiltinAction::AuthorizeSso => {
+                                        action_status.set(Some(super::common::authorize_sso()));
+                                        status_set_at.set(Some(std::time::Instant::now()));
                                     }
-                                    BuiltinAction::SelectRepo => {
-                                        if let Some(mut rp) = repo_picker {
-                                            rp.set(true);
-                                        }
+                                    BuiltinAction::PostponeRefresh => {
+                                        let fetched = filter_fetch_times.read().get(current_filter_idx).copied().flatten();
+                                        action_status.set(Some(super::common::postpone_refresh(
+                                            engine.as_ref(),
+                                            RefreshView::Prs,
+                                            current_filter_idx,
+                                            fetched,
+                                            refresh_interval,
+                                            &mut refresh_postponed,
+                                        )));
+                                        status_set_at.set(Some(std::time::Instant::now()));
                                     }
-                                    BuiltinAction::TogglePreview => {
-                                        preview_open.set(!preview_open.get());
+                                    BuiltinAction::SliceFailingCi
+                                    | BuiltinAction::SliceApprovedGreen
+                                    | BuiltinAction::SliceChangesRequested => {
+                                        let slice = match action {
+                                            BuiltinAction::SliceFailingCi => CiSlice::FailingCi,
+                                            BuiltinAction::SliceApprovedGreen => {
+                                                CiSlice::ApprovedGreen
+                                            }
+                                            _ => CiSlice::ChangesRequested,
+                                        };
+                                        // Pressing the active slice again clears it.
+                                        ci_slice.set((ci_slice.get() != Some(slice)).then_some(slice));
+                                        cursor.set(0);
+                                        scroll_offset.set(0);
                                         preview_scroll.set(0);
                                     }
-                                    BuiltinAction::SidebarWider => {
-                                        if let Some(mut s) = preview_pct_state {
-                                            s.set((s.get() + 0.05).min(0.80));
-                                        }
+                                    BuiltinAction::CycleSize => {
+                                        // XS, S, … XL, then every size again.
+                                        let next = match size_slice.get() {
+                                            None => PrSize::ALL.first().copied(),
+                                            Some(size) => PrSize::ALL
+                                                .into_iter()
+                                                .skip_while(|s| *s != size)
+                                                .nth(1),
+                                        };
+                                        size_slice.set(next);
+                                        cursor.set(0);
+                                        scroll_offset.set(0);
+                                        preview_scroll.set(0);
                                     }
-                                    BuiltinAction::SidebarNarrower => {
-                                        if let Some(mut s) = preview_pct_state {
-                                            s.set((s.get() - 0.05).max(0.15));
+                                    BuiltinAction::CopyCommentUrl | BuiltinAction::OpenComment => {
+                                        let url = {
+                                            let state = prs_state.read();
+                                            let pn = state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                .map(|p| p.number);
+                                            comment_sel
+                                                .get()
+                                                .filter(|(n, _)| Some(*n) == pn)
+                                                .and_then(|(n, i)| {
+                                                    let cache = detail_cache.read();
+                                                    cache
+                                                        .get(&n)?
+                                                        .timeline_events
+                                                        .get(i)?
+                                                        .url()
+                                                        .map(str::to_owned)
+                                                })
+                                        };
+                                        let feedback = match url {
+                                            None => ActionFeedback::Info(
+                                                "Select a comment with { or } first".into(),
+                                            ),
+                                            Some(url) if action == BuiltinAction::CopyCommentUrl => {
+                                                match clipboard::copy_to_clipboard(&url) {
+                                                    Ok(()) => ActionFeedback::Success("Copied comment link".into()),
+                                                    Err(e) => ActionFeedback::Error(format!("Copy failed: {e}")),
+                                                }
+                                            }
+                                            Some(url) => match clipboard::open_in_browser(&url) {
+                                                Ok(how) => ActionFeedback::Success(how.message("comment")),
+                                                Err(e) => ActionFeedback::Error(format!("Open failed: {e}")),
+                                            },
+                                        };
+                                        action_status.set(Some(feedback));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::ViewedFiles if pr_number > 0 => {
+                                        sidebar_tab.set(SidebarTab::Files);
+                                        preview_open.set(true);
+                                        let files = detail_cache
+                                            .read()
+                                            .get(&pr_number)
+                                            .map(|d| d.files.clone());
+                                        let opened = match files {
+                                            Some(files) => files_menu::open(&pr_owner, &pr_repo, pr_number, files),
+                                            None => Err(ActionFeedback::Info(format!("Loading files{}", ellipsis(chrome)))),
+                                        };
+                                        match opened {
+                                            Ok(menu) => files_menu_state.set(Some(menu)),
+                                            Err(feedback) => {
+                                                action_status.set(Some(feedback));
+                                                status_set_at.set(Some(std::time::Instant::now()));
+                                            }
                                         }
                                     }
-                                    BuiltinAction::SidebarResetWidth => {
-                                        if let Some(mut s) = preview_pct_state {
-                                            s.set(default_pct);
+                                    BuiltinAction::ToggleBotComments => {
+                                        show_noise.set(!show_noise.get());
+                                        sidebar_tab.set(SidebarTab::Activity);
+                                        preview_open.set(true);
+                                        // Keep the selected comment in view.
+                                        comment_jump.set(comment_sel.get().is_some());
+                                    }
+                                    BuiltinAction::OpenDeployment => {
+                                        let pn = {
+                                            let state = prs_state.read();
+                                            state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                .map(|p| p.number)
+                                        };
+                                        let target = pn.and_then(|num| {
+                                            detail_cache.read().get(&num).and_then(latest_deployment_url)
+                                        });
+                                        let feedback = match target {
+                                            Some((env, url)) => match clipboard::open_in_browser(&url) {
+                                                Ok(how) => ActionFeedback::Success(how.message(&format!("{env} deployment"))),
+                                                Err(e) => ActionFeedback::Error(format!("Open failed: {e}")),
+                                            },
+                                            None => ActionFeedback::Info("No deployment URL for this PR".into()),
+                                        };
+                                        action_status.set(Some(feedback));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::ToggleDrafts => {
+                                        let mut flipped = drafts_flipped.read().clone();
+                                        if !flipped.remove(&current_filter_idx) {
+                                            flipped.insert(current_filter_idx);
                                         }
+                                        drafts_flipped.set(flipped);
+                                        cursor.set(0);
+                                        scroll_offset.set(0);
+                                        preview_scroll.set(0);
+                                    }
+                                    BuiltinAction::TogglePreview => {
+                                        preview_open.set(!preview_open.get());
+                                        preview_scroll.set(0);
+                                        sidebar_focused.set(false);
                                     }
                                     BuiltinAction::Approve => {
                                         input_mode.set(InputMode::Confirm(BuiltinAction::Approve));
                                         action_status.set(None);
                                     }
-                                    BuiltinAction::CommentAction => {
+                                    BuiltinAction::CommentAction | BuiltinAction::Review => {
+                                        review_event.set(
+                                            matches!(action, BuiltinAction::Review)
+                                                .then_some(ReviewEvent::Comment),
+                                        );
+                                        // Replying from the Activity tab quotes the
+                                        // selected comment.
+                                        let quoted = if sidebar_tab.get() == SidebarTab::Activity {
+                                            let state = prs_state.read();
+                                            let pn = state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                .map(|p| p.number);
+                                            comment_sel
+                                                .get()
+                                                .filter(|(n, _)| Some(*n) == pn)
+                                                .and_then(|(n, i)| {
+                                                    let cache = detail_cache.read();
+                                                    cache.get(&n)?.timeline_events.get(i)?.quote_reply()
+                                                })
+                                        } else {
+                                            None
+                                        };
+                                        let target = list::draft_target(&prs_state, current_filter_idx, selected_pr);
+                                        let (text, restored) = match &target {
+                                            Some(target) => drafts::restore(target, quoted),
+                                            None => (quoted.unwrap_or_default(), false),
+                                        };
                                         input_mode.set(InputMode::Comment);
-                                        input_buffer.set(String::new());
-                                        action_status.set(None);
+                                        input_buffer.set(text);
+                                        draft_target.set(target);
+                                        action_status.set(
+                                            restored.then(|| ActionFeedback::Info("Restored saved draft".to_owned())),
+                                        );
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                        completion_selection.set(0);
+                                        issue_ref_candidates.set(Vec::new());
+                                        let mentions = {
+                                            let state = prs_state.read();
+                                            let cache = detail_cache.read();
+                                            state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                .map(|pr| build_pr_mention_candidates(pr, cache.get(&pr.number)))
+                                                .unwrap_or_default()
+                                        };
+                                        assignee_candidates.set(mentions);
+                                        if let Some(ref eng) = engine
+                                            && let Some((owner, repo, _)) = list::item_info(
+                                                &prs_state,
+                                                current_filter_idx,
+                                                selected_pr,
+                                            )
+                                        {
+                                            eng.send(Request::FetchRepoCollaborators {
+                                                owner: owner.clone(),
+                                                repo: repo.clone(),
+                                                host: filter_host_for_kb.clone(),
+                                                reply_tx: event_tx.clone(),
+                                            });
+                                            eng.send(Request::FetchOpenIssueRefs {
+                                                owner,
+                                                repo,
+                                                host: filter_host_for_kb.clone(),
+                                                reply_tx: event_tx.clone(),
+                                            });
+                                        }
                                     }
                                     BuiltinAction::Close => {
                                         input_mode.set(InputMode::Confirm(BuiltinAction::Close));
@@ -1774,7 +2891,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                             let pr = state
                                                 .filters
                                                 .get(current_filter_idx)
-                                                .and_then(|f| f.prs.get(cursor.get()));
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)));
                                             (pr.map(|p| p.number), pr.map(branch_update_status))
                                         };
                                         let effective = pn.and_then(|num| {
@@ -1825,6 +2942,167 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                             .set(InputMode::Confirm(BuiltinAction::MarkReady));
                                         action_status.set(None);
                                     }
+                                    BuiltinAction::Enqueue => {
+                                        let queue = detail_cache.read().get(&pr_number).map(|d| {
+                                            let req = &d.merge_requirements;
+                                            let position = req.queue_entry.as_ref().map(|e| e.position);
+                                            (req.merge_queue_enabled, position)
+                                        });
+                                        match queue {
+                                            Some((false, _)) => {
+                                                action_status.set(Some(ActionFeedback::Warning(
+                                                    "The base branch has no merge queue".into(),
+                                                )));
+                                                status_set_at.set(Some(std::time::Instant::now()));
+                                            }
+                                            Some((true, Some(position))) => {
+                                                action_status.set(Some(ActionFeedback::Info(format!(
+                                                    "Already in the merge queue (position {position})"
+                                                ))));
+                                                status_set_at.set(Some(std::time::Instant::now()));
+                                            }
+                                            _ => {
+                                                input_mode
+                                                    .set(InputMode::Confirm(BuiltinAction::Enqueue));
+                                                action_status.set(None);
+                                            }
+                                        }
+                                    }
+                                    BuiltinAction::WatchPr if pr_number > 0 => {
+                                        let key = format!("{pr_owner}/{pr_repo}#{pr_number}");
+                                        let mut watched = watched_prs.read().clone();
+                                        if watched.remove(&key) {
+                                            if let Some(ref eng) = engine {
+                                                eng.send(Request::UnwatchPr {
+                                                    owner: pr_owner.clone(),
+                                                    repo: pr_repo.clone(),
+                                                    number: pr_number,
+                                                });
+                                            }
+                                            let mut unseen = watch_unseen.read().clone();
+                                            unseen.remove(&key);
+                                            watch_unseen.set(unseen);
+                                            action_status.set(Some(ActionFeedback::Info(format!(
+                                                "Stopped watching PR #{pr_number}"
+                                            ))));
+                                        } else {
+                                            let branches = {
+                                                let state = prs_state.read();
+                                                state
+                                                    .filters
+                                                    .get(current_filter_idx)
+                                                    .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                    .map(|p| {
+                                                        (
+                                                            p.base_ref.clone(),
+                                                            p.head_repo_owner.clone(),
+                                                            p.head_ref.clone(),
+                                                        )
+                                                    })
+                                            };
+                                            if let Some(ref eng) = engine
+                                                && let Some((base_ref, head_repo_owner, head_ref)) =
+                                                    branches
+                                            {
+                                                eng.send(Request::WatchPr {
+                                                    owner: pr_owner.clone(),
+                                                    repo: pr_repo.clone(),
+                                                    number: pr_number,
+                                                    base_ref,
+                                                    head_repo_owner,
+                                                    head_ref,
+                                                    host: filter_host_for_kb.clone(),
+                                                    reply_tx: event_tx.clone(),
+                                                });
+                                            }
+                                            watched.insert(key);
+                                            action_status.set(Some(ActionFeedback::Success(format!(
+                                                "Watching PR #{pr_number} for CI, review and mergeability changes"
+                                            ))));
+                                        }
+                                        watched_prs.set(watched);
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::MergeWhenGreen if pr_number > 0 => {
+                                        let key = format!("{pr_owner}/{pr_repo}#{pr_number}");
+                                        let mut armed = merge_when_green.read().clone();
+                                        if armed.remove(&key) {
+                                            if let Some(ref eng) = engine {
+                                                eng.send(Request::CancelMergeWhenGreen {
+                                                    owner: pr_owner.clone(),
+                                                    repo: pr_repo.clone(),
+                                                    number: pr_number,
+                                                });
+                                            }
+                                            merge_when_green.set(armed);
+                                            action_status.set(Some(ActionFeedback::Info(format!(
+                                                "No longer merging PR #{pr_number} when green"
+                                            ))));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        } else {
+                                            input_mode
+                                                .set(InputMode::Confirm(BuiltinAction::MergeWhenGreen));
+                                            action_status.set(None);
+                                        }
+                                    }
+                                    BuiltinAction::ApproveWorkflows if pr_number > 0 => {
+                                        let awaiting = detail_cache
+                                            .read()
+                                            .get(&pr_number)
+                                            .map(|d| d.awaiting_approval.len());
+                                        match awaiting {
+                                            None => {
+                                                action_status.set(Some(ActionFeedback::Info(
+                                                    "The PR's checks are still loading".to_owned(),
+                                                )));
+                                                status_set_at.set(Some(std::time::Instant::now()));
+                                            }
+                                            Some(0) => {
+                                                action_status.set(Some(ActionFeedback::Info(
+                                                    "No workflow run of this PR awaits approval".to_owned(),
+                                                )));
+                                                status_set_at.set(Some(std::time::Instant::now()));
+                                            }
+                                            Some(_) => {
+                                                input_mode.set(InputMode::Confirm(
+                                                    BuiltinAction::ApproveWorkflows,
+                                                ));
+                                                action_status.set(None);
+                                            }
+                                        }
+                                    }
+                                    BuiltinAction::RerunFlaky if pr_number > 0 => {
+                                        let job_ids: Vec<u64> = {
+                                            let state = prs_state.read();
+                                            state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                .map(|p| {
+                                                    crate::check_history::flaky_checks(p)
+                                                        .into_iter()
+                                                        .filter(|c| c.failing)
+                                                        .filter_map(|c| c.job_id)
+                                                        .collect()
+                                                })
+                                                .unwrap_or_default()
+                                        };
+                                        if job_ids.is_empty() {
+                                            action_status.set(Some(ActionFeedback::Info(
+                                                "No failing flaky Actions check on this PR".to_owned(),
+                                            )));
+                                        } else if let Some(ref eng) = engine {
+                                            eng.send(Request::RerunJobs {
+                                                host: filter_host_for_kb.clone(),
+                                                owner: pr_owner.clone(),
+                                                repo: pr_repo.clone(),
+                                                job_ids,
+                                                reply_tx: event_tx.clone(),
+                                            });
+                                            action_status.set(None);
+                                        }
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::ViewDiff if pr_number > 0 => {
                                         match crate::actions::local::open_diff(
                                             &pr_owner, &pr_repo, pr_number,
@@ -1846,7 +3124,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                             .get(current_filter_idx)
                                             .cloned();
                                         if let Some(data) = current_data
-                                            && let Some(pr) = data.prs.get(cursor.get())
+                                            && let Some(pr) = selected_pr.and_then(|i| data.items.get(i))
                                         {
                                             let repo_name = pr
                                                 .repo
@@ -1863,7 +3141,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                     .as_deref()
                                                     .unwrap_or("github.com")
                                                     .to_owned();
-                                                action_status.set(Some(ActionFeedback::Info(format!("Cloning {repo_name}…"))));
+                                                action_status.set(Some(ActionFeedback::Info(format!("Cloning {repo_name}{}", ellipsis(chrome)))));
                                                 status_set_at.set(Some(std::time::Instant::now()));
                                                 crate::actions::local::spawn_checkout(
                                                     pr.head_ref.clone(),
@@ -1904,10 +3182,10 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         input_mode.set(InputMode::Assign);
                                         input_buffer.set(String::new());
                                         assignee_selection.set(0);
-                                        let current = get_current_pr_assignees(
+                                        let current = list::item_assignees(
                                             &prs_state,
                                             current_filter_idx,
-                                            cursor.get(),
+                                            selected_pr,
                                         );
                                         assignee_selected.set(current);
                                         let initial = {
@@ -1915,22 +3193,23 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                             state
                                                 .filters
                                                 .get(current_filter_idx)
-                                                .and_then(|f| f.prs.get(cursor.get()))
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
                                                 .map(build_pr_assignee_candidates)
                                                 .unwrap_or_default()
                                         };
                                         assignee_candidates.set(initial);
                                         action_status.set(None);
                                         if let Some(ref eng) = engine
-                                            && let Some((owner, repo, _)) = get_current_pr_info(
+                                            && let Some((owner, repo, _)) = list::item_info(
                                                 &prs_state,
                                                 current_filter_idx,
-                                                cursor.get(),
+                                                selected_pr,
                                             )
                                         {
                                             eng.send(Request::FetchRepoCollaborators {
                                                 owner,
                                                 repo,
+                                                host: filter_host_for_kb.clone(),
                                                 reply_tx: event_tx.clone(),
                                             });
                                         }
@@ -1940,27 +3219,34 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         input_buffer.set(String::new());
                                         label_selection.set(0);
                                         label_candidates.set(Vec::new());
-                                        let current_labels = get_current_pr_labels(
+                                        let current_labels = list::item_labels(
                                             &prs_state,
                                             current_filter_idx,
-                                            cursor.get(),
+                                            selected_pr,
                                         );
                                         label_selected.set(current_labels);
                                         action_status.set(None);
                                         if let Some(ref eng) = engine
-                                            && let Some((owner, repo, _)) = get_current_pr_info(
+                                            && let Some((owner, repo, _)) = list::item_info(
                                                 &prs_state,
                                                 current_filter_idx,
-                                                cursor.get(),
+                                                selected_pr,
                                             )
                                         {
                                             eng.send(Request::FetchRepoLabels {
                                                 owner,
                                                 repo,
+                                                host: filter_host_for_kb.clone(),
                                                 reply_tx: event_tx.clone(),
                                             });
                                         }
                                     }
+                                    BuiltinAction::EditNote if pr_number > 0 => {
+                                        let feedback = notes::edit(&format!("{pr_owner}/{pr_repo}"), pr_number);
+                                        stdout.print("");
+                                        action_status.set(Some(feedback));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::CopyNumber if pr_number > 0 => {
                                         let text = pr_number.to_string();
                                         match clipboard::copy_to_clipboard(&text) {
@@ -1986,10 +3272,27 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                             }
                                         }
                                     }
+                                    BuiltinAction::CopyAs => {
+                                        let menu = copy_menu::open((pr_number > 0).then_some(&vars), chrome);
+                                        if menu.is_none() {
+                                            action_status.set(Some(ActionFeedback::Info("Nothing to copy".to_owned())));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                        copy_menu_state.set(menu);
+                                    }
+                                    BuiltinAction::Share => {
+                                        match share_menu::open((pr_number > 0).then_some(&vars), chrome) {
+                                            Ok(menu) => share_menu_state.set(Some(menu)),
+                                            Err(feedback) => {
+                                                action_status.set(Some(feedback));
+                                                status_set_at.set(Some(std::time::Instant::now()));
+                                            }
+                                        }
+                                    }
                                     BuiltinAction::OpenBrowser if !pr_url.is_empty() => {
                                         match clipboard::open_in_browser(&pr_url) {
-                                            Ok(()) => {
-                                                action_status.set(Some(ActionFeedback::Success(format!("Opened #{pr_number}"))));
+                                            Ok(how) => {
+                                                action_status.set(Some(ActionFeedback::Success(how.message(&format!("#{pr_number}")))));
                                                 status_set_at.set(Some(std::time::Instant::now()));
                                             }
                                             Err(e) => {
@@ -2000,17 +3303,17 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                     }
                                     BuiltinAction::RefreshItem => {
                                         if let Some((owner, repo, number)) =
-                                            get_current_pr_info(
+                                            list::item_info(
                                                 &prs_state,
                                                 active_filter.get(),
-                                                cursor.get(),
+                                                selected_pr,
                                             )
                                         {
                                             let state = prs_state.read();
                                             let fd =
                                                 &state.filters[active_filter.get()];
                                             if let Some(pr) = fd
-                                                .prs
+                                                .items
                                                 .iter()
                                                 .find(|p| p.number == number)
                                             {
@@ -2025,6 +3328,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                                     head_ref: pr
                                                         .head_ref
                                                         .clone(),
+                                                    host: filter_host_for_kb.clone(),
                                                     reply_tx: event_tx.clone(),
                                                 };
                                                 if let Some(ref eng) = engine {
@@ -2038,7 +3342,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         let idx = active_filter.get();
                                         let mut state = prs_state.read().clone();
                                         if idx < state.filters.len() {
-                                            state.filters[idx] = FilterData::default();
+                                            state.filters[idx] = Arc::new(FilterData::default());
                                         }
                                         prs_state.set(state);
                                         tracing::debug!("prs: Refresh handler: idx={idx} force_refresh=true");
@@ -2048,9 +3352,9 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         }
                                         filter_fetch_times.set(times);
                                         pending_detail.set(None);
-                                        detail_cache.set(HashMap::new());
+                                        detail_cache.set(LruCache::new(detail_cache_size));
                                         force_detail.set(true);
-                                        rate_limit_state.set(None);
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                     }
@@ -2058,16 +3362,16 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         tracing::debug!("prs: RefreshAll handler: refresh_all=true");
                                         let mut state = prs_state.read().clone();
                                         for filter in &mut state.filters {
-                                            *filter = FilterData::default();
+                                            *filter = Arc::new(FilterData::default());
                                         }
                                         prs_state.set(state);
                                         let mut times = filter_fetch_times.read().clone();
                                         times.fill(None);
                                         filter_fetch_times.set(times);
                                         pending_detail.set(None);
-                                        detail_cache.set(HashMap::new());
+                                        detail_cache.set(LruCache::new(detail_cache_size));
                                         force_detail.set(true);
-                                        rate_limit_state.set(None);
+                                        rate_limit_state.set(RateLimits::default());
                                         cursor.set(0);
                                         scroll_offset.set(0);
                                         refresh_all.set(true);
@@ -2076,140 +3380,47 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                         input_mode.set(InputMode::Search);
                                         search_query.set(String::new());
                                     }
-                                    BuiltinAction::MoveDown if total_rows > 0 => {
-                                        let new_cursor =
-                                            (cursor.get() + 1).min(total_rows.saturating_sub(1));
-                                        cursor.set(new_cursor);
-                                        if new_cursor >= scroll_offset.get() + visible_rows {
-                                            scroll_offset
-                                                .set(new_cursor.saturating_sub(visible_rows) + 1);
-                                        }
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::MoveUp => {
-                                        let new_cursor = cursor.get().saturating_sub(1);
-                                        cursor.set(new_cursor);
-                                        if new_cursor < scroll_offset.get() {
-                                            scroll_offset.set(new_cursor);
-                                        }
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::First => {
-                                        cursor.set(0);
-                                        scroll_offset.set(0);
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::Last if total_rows > 0 => {
-                                        cursor.set(total_rows.saturating_sub(1));
-                                        scroll_offset.set(total_rows.saturating_sub(visible_rows));
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::PageDown if total_rows > 0 => {
-                                        let new_cursor = (cursor.get() + visible_rows)
-                                            .min(total_rows.saturating_sub(1));
-                                        cursor.set(new_cursor);
-                                        scroll_offset.set(
-                                            new_cursor
-                                                .saturating_sub(visible_rows.saturating_sub(1)),
-                                        );
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::PageUp => {
-                                        let new_cursor = cursor.get().saturating_sub(visible_rows);
-                                        cursor.set(new_cursor);
-                                        scroll_offset
-                                            .set(scroll_offset.get().saturating_sub(visible_rows));
-                                        preview_scroll.set(0);
-                                    }
-                                    BuiltinAction::HalfPageDown => {
-                                        let half = visible_rows / 2;
-                                        if preview_open.get() {
-                                            preview_scroll.set(preview_scroll.get() + half);
-                                        } else if total_rows > 0 {
-                                            let new_cursor = (cursor.get() + half)
-                                                .min(total_rows.saturating_sub(1));
-                                            cursor.set(new_cursor);
-                                            if new_cursor >= scroll_offset.get() + visible_rows {
-                                                scroll_offset.set(
-                                                    new_cursor.saturating_sub(visible_rows) + 1,
-                                                );
-                                            }
-                                            preview_scroll.set(0);
-                                        }
-                                    }
-                                    BuiltinAction::HalfPageUp => {
-                                        let half = visible_rows / 2;
-                                        if preview_open.get() {
-                                            preview_scroll
-                                                .set(preview_scroll.get().saturating_sub(half));
-                                        } else {
-                                            let new_cursor = cursor.get().saturating_sub(half);
-                                            cursor.set(new_cursor);
-                                            if new_cursor < scroll_offset.get() {
-                                                scroll_offset.set(new_cursor);
-                                            }
-                                            preview_scroll.set(0);
-                                        }
-                                    }
+                                    action if nav.navigate(action, total_rows, visible_rows) => {}
                                     BuiltinAction::ToggleHelp => {
                                         help_visible.set(true);
                                     }
-                                    BuiltinAction::PrevFilter if total_tab_count > 0 => {
-                                        let current = active_filter.get();
-                                        active_filter.set(if current == 0 {
-                                            total_tab_count.saturating_sub(1)
-                                        } else {
-                                            current - 1
-                                        });
-                                        cursor.set(0);
-                                        scroll_offset.set(0);
-                                        preview_scroll.set(0);
+                                    BuiltinAction::ShowDrafts => {
+                                        drafts_cursor.set(Some(0));
                                     }
-                                    BuiltinAction::NextFilter if total_tab_count > 0 => {
-                                        active_filter
-                                            .set((active_filter.get() + 1) % total_tab_count);
-                                        cursor.set(0);
-                                        scroll_offset.set(0);
-                                        preview_scroll.set(0);
+                                    BuiltinAction::ShowContributions => {
+                                        let author = list::with_item(
+                                            &prs_state,
+                                            current_filter_idx,
+                                            selected_pr,
+                                            |pr| pr.author.as_ref().map(|a| a.login.clone()),
+                                        )
+                                        .flatten();
+                                        profile::open(
+                                            &mut profile_overlay,
+                                            engine.as_ref(),
+                                            filter_host_for_kb.clone(),
+                                            author,
+                                            &event_tx,
+                                        );
                                     }
-                                    BuiltinAction::CloseTab => {
-                                        if current_filter_idx >= filter_count {
-                                            // Ephemeral tab — remove it.
-                                            let ei = current_filter_idx - filter_count;
-                                            let mut eph = ephemeral_filters.read().clone();
-                                            debug_assert!(
-                                                ei < eph.len(),
-                                                "ephemeral index out of range"
-                                            );
-                                            eph.remove(ei);
-                                            let new_total = filter_count + eph.len();
-                                            ephemeral_filters.set(eph);
-
-                                            // Remove from state vectors.
-                                            let mut state = prs_state.read().clone();
-                                            if current_filter_idx < state.filters.len() {
-                                                state.filters.remove(current_filter_idx);
-                                            }
-                                            prs_state.set(state);
-                                            let mut in_flight = filter_in_flight.read().clone();
-                                            if current_filter_idx < in_flight.len() {
-                                                in_flight.remove(current_filter_idx);
-                                            }
-                                            filter_in_flight.set(in_flight);
-                                            let mut times = filter_fetch_times.read().clone();
-                                            if current_filter_idx < times.len() {
-                                                times.remove(current_filter_idx);
-                                            }
-                                            filter_fetch_times.set(times);
-
-                                            // Clamp active filter.
-                                            if active_filter.get() >= new_total && new_total > 0 {
-                                                active_filter.set(new_total - 1);
-                                            }
-                                            cursor.set(0);
-                                            scroll_offset.set(0);
+                                    action if nav.switch_tab(action, total_tab_count) => {}
+                                    BuiltinAction::CloseTab
+                                        if !nav.close_tab(filter_count, current_filter_idx) =>
+                                    {
+                                        action_status.set(Some(ActionFeedback::Warning(
+                                            "Cannot close config tabs".to_owned(),
+                                        )));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
+                                    BuiltinAction::KeepTab => {
+                                        let kept = current_filter_idx
+                                            .checked_sub(filter_count)
+                                            .and_then(|ei| ephemeral_filters.read().get(ei).cloned());
+                                        if let Some((filter, _)) = kept {
+                                            input_buffer.set(filter.title);
+                                            input_mode.set(InputMode::KeepTab);
                                         } else {
-                                            action_status.set(Some(ActionFeedback::Warning("Cannot close config tabs".to_owned())));
+                                            action_status.set(Some(keep_tab::not_ephemeral()));
                                             status_set_at.set(Some(std::time::Instant::now()));
                                         }
                                     }
@@ -2226,7 +3437,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                             let pr = state
                                                 .filters
                                                 .get(current_filter_idx)
-                                                .and_then(|f| f.prs.get(cursor.get()));
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)));
                                             let repo_ref = pr.and_then(|p| p.repo.as_ref());
                                             if let Some(pr) = pr
                                                 && let Some(rr) = repo_ref
@@ -2285,6 +3496,13 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                     let expanded = expand_template(&cmd, &vars);
                                     let _ = execute_shell_command(&expanded);
                                 }
+                                Some(ResolvedBinding::Script(path)) => {
+                                    if let Some(feedback) =
+                                        crate::script::run(&path, "prs", &vars, engine.clone(), chrome)
+                                    {
+                                        action_status.set(Some(feedback));
+                                    }
+                                }
                                 None => {
                                     if key_str == "]" {
                                         sidebar_tab.set(sidebar_tab.get().next());
@@ -2292,6 +3510,37 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                     } else if key_str == "[" {
                                         sidebar_tab.set(sidebar_tab.get().prev());
                                         preview_scroll.set(0);
+                                    } else if key_str == "}" || key_str == "{" {
+                                        // Select the next/previous comment in the Activity tab.
+                                        let pn = {
+                                            let state = prs_state.read();
+                                            state
+                                                .filters
+                                                .get(current_filter_idx)
+                                                .and_then(|f| selected_pr.and_then(|i| f.items.get(i)))
+                                                .map(|p| p.number)
+                                        };
+                                        let next = pn.and_then(|num| {
+                                            let cache = detail_cache.read();
+                                            let current = comment_sel
+                                                .get()
+                                                .filter(|(n, _)| *n == num)
+                                                .map(|(_, i)| i);
+                                            cache.get(&num).and_then(|d| {
+                                                step_comment(
+                                                    &d.timeline_events,
+                                                    current,
+                                                    key_str == "}",
+                                                    show_noise.get(),
+                                                )
+                                            })
+                                        });
+                                        if let (Some(num), Some(idx)) = (pn, next) {
+                                            comment_sel.set(Some((num, idx)));
+                                            comment_jump.set(true);
+                                            sidebar_tab.set(SidebarTab::Activity);
+                                            preview_open.set(true);
+                                        }
                                     }
                                 }
                             }
@@ -2301,7 +3550,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                         // Merge-update (only merge strategy supported).
                         KeyCode::Char('m' | 'M') => {
                             let pr_info =
-                                get_current_pr_info(&prs_state, current_filter_idx, cursor.get());
+                                list::item_info(&prs_state, current_filter_idx, selected_pr);
                             if let Some((owner, repo, number)) = pr_info
                                 && let Some(ref eng) = engine
                             {
@@ -2309,6 +3558,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                                     owner: owner.clone(),
                                     repo: repo.clone(),
                                     number,
+                                    host: filter_host_for_kb.clone(),
                                     reply_tx: event_tx.clone(),
                                 });
                             }
@@ -2324,7 +3574,13 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 }
             }
             TerminalEvent::FullscreenMouse(mouse_event) => {
-                if !is_active || help_visible.get() {
+                if !is_active
+                    || help_visible.get()
+                    || profile_overlay.read().is_some()
+                    || copy_menu_state.read().is_some()
+                    || share_menu_state.read().is_some()
+                    || files_menu_state.read().is_some()
+                {
                     return;
                 }
                 let delta = match mouse_event.kind {
@@ -2366,25 +3622,29 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     let tabs: Vec<Tab> = all_filters
         .iter()
         .enumerate()
-        .map(|(i, (f, is_eph))| Tab {
-            title: f.title.clone(),
-            count: state_ref.filters.get(i).map(|d| d.pr_count),
-            is_ephemeral: *is_eph,
+        .map(|(i, (f, is_eph))| {
+            let data = state_ref.filters.get(i);
+            let hidden = if hides_drafts(i) {
+                data.map_or(0, |d| {
+                    d.items
+                        .iter()
+                        .filter(|pr| pr.is_draft_or_wip(wip_patterns))
+                        .count()
+                })
+            } else {
+                0
+            };
+            Tab {
+                title: f.title.clone(),
+                count: data.map(|d| d.count.saturating_sub(hidden)),
+                hidden,
+                is_ephemeral: *is_eph,
+            }
         })
         .collect();
 
-    // Current filter data.
-    let current_data = state_ref.filters.get(current_filter_idx);
-    let columns = pr_columns(&theme.icons);
-
-    // Layout config for hidden/width overrides.
-    let layout = filters_cfg
-        .get(current_filter_idx)
-        .and_then(|s| s.layout.as_ref());
-    let hidden_set: HashSet<String> = layout
-        .map(|l| l.hidden.iter().cloned().collect())
-        .unwrap_or_default();
-    let width_map: HashMap<String, u16> = layout.map(|l| l.widths.clone()).unwrap_or_default();
+    // Current filter data.
+    let current_data = state_ref.filters.get(current_filter_idx);
 
     // Compute widths for table vs sidebar.
     let is_preview_open = preview_open.get();
@@ -2398,14 +3658,66 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     };
 
     let all_rows: &[Row] = current_data.map_or(&[], |d| d.rows.as_slice());
-    let query = search_query.read().clone();
-    let filtered_indices = filter::filter_rows(all_rows, &query);
-    let filtered_rows: Vec<Row> = filtered_indices
-        .iter()
-        .filter_map(|&i| all_rows.get(i).cloned())
-        .collect();
+    let selected_pr = visible_indices.get(cursor.get()).copied();
+    let pr_watch_key = |i: usize| {
+        let pr = current_data?.items.get(i)?;
+        let repo = pr.repo.as_ref()?;
+        Some(format!("{}/{}#{}", repo.owner, repo.name, pr.number))
+    };
+    // Visiting a changed watched PR marks its change as seen.
+    if is_active
+        && let Some(key) = selected_pr.and_then(pr_watch_key)
+        && watch_unseen.read().contains(&key)
+    {
+        let mut unseen = watch_unseen.read().clone();
+        unseen.remove(&key);
+        watch_unseen.set(unseen);
+    }
+    let filtered_rows: Vec<Row> = {
+        let watched = watched_prs.read();
+        let unseen = watch_unseen.read();
+        let armed = merge_when_green.read();
+        visible_indices
+            .iter()
+            .filter_map(|&i| {
+                let mut row = all_rows.get(i).cloned()?;
+                if let Some(key) = pr_watch_key(i).filter(|k| watched.contains(k))
+                    && let Some(info) = row.get_mut("info")
+                {
+                    info.spans.push(Span {
+                        text: format!(" {}", theme.icons.action_watched),
+                        color: Some(if unseen.contains(&key) {
+                            theme.text_warning
+                        } else {
+                            theme.text_faint
+                        }),
+                        bold: false,
+                    });
+                }
+                if pr_watch_key(i).is_some_and(|k| armed.contains(&k))
+                    && let Some(info) = row.get_mut("info")
+                {
+                    info.spans.push(Span {
+                        text: format!(" {}", theme.icons.pr_merged),
+                        color: Some(theme.text_success),
+                        bold: true,
+                    });
+                }
+                if let Some(pr) = current_data.and_then(|d| d.items.get(i))
+                    && let Some(repo) = &pr.repo
+                {
+                    notes::mark_row(&mut row, &repo.full_name(), pr.number, &theme);
+                }
+                Some(row)
+            })
+            .collect()
+    };
 
     // Pre-render table.
+    // A failed fetch shows its error in the footer, and how to retry here.
+    let failed_message = current_data
+        .is_some_and(|d| d.error.is_some())
+        .then(|| super::common::failed_tab_message(props.keybindings.as_deref()));
     let rendered_table = RenderedTable::build(&TableBuildConfig {
         columns: &columns,
         rows: &filtered_rows,
@@ -2420,7 +3732,13 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
         header_color: Some(theme.text_secondary),
         border_color: Some(theme.border_faint),
         show_separator: props.show_separator,
-        empty_message: if search_q.is_empty() {
+        empty_message: if let Some(msg) = &failed_message {
+            Some(msg.as_str())
+        } else if search_q.is_empty()
+            && active_slice.is_none()
+            && active_size.is_none()
+            && hide_wip.is_none()
+        {
             Some("No pull requests found")
         } else {
             Some("No pull requests match this filter")
@@ -2428,12 +3746,15 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
         subtitle_column: Some("subtitle"),
         row_separator: true,
         scrollbar_thumb_color: Some(theme.border_primary),
+        chrome: theme.chrome,
+        sort: current_sort.as_ref(),
     });
 
     // Request detail when sidebar is open and current PR is not cached.
     if is_preview_open {
-        let cursor_idx = cursor.get();
-        let current_pr = current_data.and_then(|d| d.prs.get(cursor_idx));
+        let current_pr = current_data
+            .zip(selected_pr)
+            .and_then(|(d, i)| d.items.get(i));
         if let Some(pr) = current_pr {
             let pr_number = pr.number;
             let already_cached = detail_cache.read().contains_key(&pr_number);
@@ -2459,6 +3780,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                         base_ref: pr.base_ref.clone(),
                         head_repo_owner: pr.head_repo_owner.clone(),
                         head_ref: pr.head_ref.clone(),
+                        host: crate::url::host_of(&pr.url),
                         force,
                     }));
                     debounce_gen.set(debounce_gen.get() + 1);
@@ -2467,63 +3789,155 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
         }
     }
 
+    // Stream further commits/files pages while their tab is shown.
+    if is_preview_open
+        && pending_page.read().is_none()
+        && let Some(connection) = match sidebar_tab.get() {
+            SidebarTab::Commits => Some(PrDetailConnection::Commits),
+            SidebarTab::Files => Some(PrDetailConnection::Files),
+            _ => None,
+        }
+        && let Some(pr) = current_data
+            .zip(selected_pr)
+            .and_then(|(d, i)| d.items.get(i))
+        && let Some(repo_ref) = &pr.repo
+        && let Some(after) = detail_cache
+            .read()
+            .get(&pr.number)
+            .and_then(|d| d.next_cursor(connection))
+            .map(str::to_owned)
+        && let Some(engine) = props.engine
+    {
+        pending_page.set(Some((pr.number, connection, after.clone())));
+        engine.send(Request::FetchPrDetailPage {
+            owner: repo_ref.owner.clone(),
+            repo: repo_ref.name.clone(),
+            number: pr.number,
+            connection,
+            after,
+            host: crate::url::host_of(&pr.url),
+            reply_tx: event_tx_for_pages,
+        });
+    }
+
     // Pre-render sidebar (preview pane with tabs).
     let rendered_sidebar = if is_preview_open {
-        let cursor_idx = cursor.get();
-        let title = current_data
-            .and_then(|d| d.titles.get(cursor_idx))
+        let selected_data = current_data.zip(selected_pr);
+        let title = selected_data
+            .and_then(|(d, i)| d.titles.get(i))
             .map_or("Preview", String::as_str);
 
         let current_tab = sidebar_tab.get();
-        let current_pr = current_data.and_then(|d| d.prs.get(cursor_idx));
+        let current_pr = selected_data.and_then(|(d, i)| d.items.get(i));
         let cache_ref = detail_cache.read();
         let detail_for_pr = current_pr.and_then(|pr| cache_ref.get(&pr.number));
+        let selected_comment = comment_sel
+            .get()
+            .filter(|(n, _)| current_pr.is_some_and(|pr| pr.number == *n))
+            .map(|(_, i)| i);
+        if comment_jump.get()
+            && current_tab == SidebarTab::Activity
+            && let (Some(detail), Some(idx)) = (detail_for_pr, selected_comment)
+        {
+            comment_jump.set(false);
+            preview_scroll.set(sidebar_tabs::activity_event_line(
+                detail,
+                &theme,
+                depth,
+                idx,
+                show_noise.get(),
+            ));
+        }
+
+        let file_states = detail_for_pr.map(|detail| viewed_states(current_pr, detail));
+        // The file or check selected in the focused sidebar, and its line.
+        let selected_item = item_sel
+            .get()
+            .filter(|(n, t, _)| {
+                sidebar_focused.get()
+                    && *t == current_tab
+                    && current_pr.is_some_and(|pr| pr.number == *n)
+            })
+            .map(|(_, _, i)| i);
+        let mut selected_line = None;
 
-        let md_lines: Vec<StyledLine> = match current_tab {
+        let md_lines: Arc<Vec<StyledLine>> = match current_tab {
             SidebarTab::Overview => {
-                let body = current_data
-                    .and_then(|d| d.bodies.get(cursor_idx))
+                let body = selected_data
+                    .and_then(|(d, i)| d.bodies.get(i))
                     .map_or("", String::as_str);
-                if body.is_empty() {
-                    Vec::new()
-                } else {
-                    renderer::render_markdown(body, &theme, depth)
-                }
-            }
-            SidebarTab::Activity => {
-                if let Some(detail) = detail_for_pr {
-                    sidebar_tabs::render_activity(detail, &theme, depth)
-                } else {
-                    vec![StyledLine::from_span(
-                        crate::markdown::renderer::StyledSpan::text("Loading...", theme.text_faint),
-                    )]
-                }
-            }
-            SidebarTab::Commits => {
-                if let Some(detail) = detail_for_pr {
-                    sidebar_tabs::render_commits(detail, &theme)
-                } else {
-                    vec![StyledLine::from_span(
-                        crate::markdown::renderer::StyledSpan::text("Loading...", theme.text_faint),
-                    )]
-                }
-            }
-            SidebarTab::Checks => {
-                if let Some(pr) = current_pr {
-                    sidebar_tabs::render_checks(pr, &theme, sidebar_width)
-                } else {
-                    Vec::new()
-                }
-            }
-            SidebarTab::Files => {
-                if let Some(detail) = detail_for_pr {
-                    sidebar_tabs::render_files(detail, &theme, sidebar_width)
+                let body_lines = if body.is_empty() {
+                    Arc::default()
                 } else {
-                    vec![StyledLine::from_span(
-                        crate::markdown::renderer::StyledSpan::text("Loading...", theme.text_faint),
-                    )]
+                    md_cache
+                        .get_or_render(body, &theme, depth, md_ready)
+                        .unwrap_or_else(|| Arc::new(vec![md_cache::pending_line(&theme)]))
+                };
+                match current_pr
+                    .and_then(|item| Some((item.repo.as_ref()?.full_name(), item.number)))
+                {
+                    Some((repo, number)) => notes::overview(
+                        (&repo, number),
+                        body_lines,
+                        &md_cache,
+                        &theme,
+                        (depth, md_ready),
+                    ),
+                    None => body_lines,
                 }
             }
+            SidebarTab::Activity => Arc::new(if let Some(detail) = detail_for_pr {
+                sidebar_tabs::render_activity(
+                    detail,
+                    &theme,
+                    depth,
+                    selected_comment,
+                    show_noise.get(),
+                )
+            } else {
+                vec![StyledLine::from_span(
+                    crate::markdown::renderer::StyledSpan::text("Loading...", theme.text_faint),
+                )]
+            }),
+            SidebarTab::Commits => Arc::new(if let Some(detail) = detail_for_pr {
+                sidebar_tabs::render_commits(detail, &theme)
+            } else {
+                vec![StyledLine::from_span(
+                    crate::markdown::renderer::StyledSpan::text("Loading...", theme.text_faint),
+                )]
+            }),
+            SidebarTab::Checks => Arc::new(if let Some(pr) = current_pr {
+                let mut lines = sidebar_tabs::render_awaiting_approval(detail_for_pr, &theme);
+                lines.extend(sidebar_tabs::render_merge_status(pr, detail_for_pr, &theme));
+                lines.extend(sidebar_tabs::render_flaky_checks(
+                    &crate::check_history::flaky_checks(pr),
+                    &theme,
+                ));
+                let (checks, line) =
+                    sidebar_tabs::render_checks(pr, &theme, sidebar_width, selected_item);
+                selected_line = line.map(|line| line + lines.len());
+                lines.extend(checks);
+                lines
+            } else {
+                Vec::new()
+            }),
+            SidebarTab::Files => Arc::new(if let Some(detail) = detail_for_pr {
+                let (lines, line) = sidebar_tabs::render_files(
+                    detail,
+                    file_states.as_deref().unwrap_or_default(),
+                    &theme,
+                    sidebar_width,
+                    selected_item,
+                );
+                selected_line = line;
+                lines
+            } else {
+                vec![StyledLine::from_span(
+                    crate::markdown::renderer::StyledSpan::text("Loading...", theme.text_faint),
+                )]
+            }),
+            // Issues only.
+            SidebarTab::Links => Arc::default(),
         };
 
         // Build meta header for Overview tab.
@@ -2537,14 +3951,44 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
         #[expect(clippy::cast_possible_truncation)]
         let meta_lines = sidebar_meta.as_ref().map_or(0, SidebarMeta::line_count) as u16;
         let sidebar_visible_lines = props.height.saturating_sub(8 + meta_lines) as usize;
+        if item_jump.get()
+            && let Some(line) = selected_line
+        {
+            item_jump.set(false);
+            preview_scroll.set(sidebar_focus::follow(
+                preview_scroll.get(),
+                line,
+                sidebar_visible_lines,
+            ));
+        }
 
         let sidebar_colors = SidebarColors {
             title: Some(theme.text_primary),
-            border: Some(theme.border_faint),
+            border: Some(if sidebar_focused.get() {
+                theme.border_primary
+            } else {
+                theme.border_faint
+            }),
             indicator: Some(theme.text_faint),
             thumb: Some(theme.border_primary),
             depth,
+            chrome: theme.chrome,
         };
+        // Review progress on the Files tab itself: "Files 3/10".
+        let files_label = detail_for_pr
+            .zip(file_states.as_ref())
+            .filter(|(detail, _)| !detail.files.is_empty())
+            .map(|(detail, states)| {
+                let viewed = states
+                    .iter()
+                    .filter(|s| **s == FileViewedState::Viewed)
+                    .count();
+                let icon = &theme.icons.tab_files;
+                HashMap::from([(
+                    SidebarTab::Files,
+                    format!("{icon} Files {viewed}/{}", detail.files.len()),
+                )])
+            });
         let sidebar = RenderedSidebar::build_tabbed(
             title,
             &md_lines,
@@ -2557,7 +4001,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 icons: Some(&theme.icons),
                 meta: sidebar_meta,
                 visible_tabs: None,
-                tab_label_overrides: None,
+                tab_label_overrides: files_label.as_ref(),
             }),
         );
         // Store the clamped offset so ctrl+u works immediately.
@@ -2573,7 +4017,22 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
         active: Some(theme.footer_prs),
         inactive: Some(theme.footer_prs),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
+    let countdown = super::common::refresh_countdown(
+        filter_fetch_times
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        refresh_interval,
+        refresh_postponed
+            .read()
+            .get(current_filter_idx)
+            .copied()
+            .flatten(),
+        &theme.icons.tab_refresh,
+    );
     let rendered_tab_bar = RenderedTabBar::build(
         &tabs,
         current_filter_idx,
@@ -2582,10 +4041,24 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
         &tab_colors,
         &theme.icons.tab_filter,
         &theme.icons.tab_ephemeral,
-    );
+    )
+    .with_badge({
+        let badges: Vec<String> = active_slice
+            .map(|slice| slice.label().to_owned())
+            .into_iter()
+            .chain(active_size.map(|size| format!("size {}", size.label())))
+            .collect();
+        (!badges.is_empty()).then(|| badges.join(", "))
+    })
+    .with_countdown(countdown);
 
     // Build footer or input area based on mode.
     let current_mode = input_mode.read().clone();
+    if matches!(current_mode, InputMode::Comment | InputMode::ReplyPicker)
+        && let Some(target) = draft_target.read().as_ref()
+    {
+        drafts::save(target, &input_buffer.read());
+    }
 
     let rendered_text_input = match &current_mode {
         InputMode::Assign => {
@@ -2615,6 +4088,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                     highlight: Some(theme.text_primary),
                     highlight_bg: Some(theme.bg_selected),
                     suggestion: Some(theme.text_faint),
+                    chrome: theme.chrome,
                 },
                 &filtered,
                 selected_idx,
@@ -2648,31 +4122,98 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                     highlight: Some(theme.text_primary),
                     highlight_bg: Some(theme.bg_selected),
                     suggestion: Some(theme.text_faint),
+                    chrome: theme.chrome,
                 },
                 &filtered,
                 selected_idx,
                 &selected,
             ))
         }
-        InputMode::Comment => Some(RenderedTextInput::build(
-            "Comment (Ctrl+D to submit, Esc to cancel):",
-            &input_buffer.read(),
-            depth,
-            &TextInputColors {
-                text: Some(theme.text_primary),
-                prompt: Some(theme.text_secondary),
-                border: Some(theme.border_faint),
-                ..Default::default()
-            },
-        )),
+        InputMode::Comment => {
+            let buf = input_buffer.read().clone();
+            let (prompt, suggestions, selected_idx) = if let Some(pick) = spell_pick.read().as_ref()
+            {
+                (pick.prompt(), pick.suggestions.clone(), pick.selected())
+            } else {
+                let suggestions = crate::components::text_input::completion_suggestions(
+                    &buf,
+                    &assignee_candidates.read(),
+                    &issue_ref_candidates.read(),
+                );
+                let selected_idx = (!suggestions.is_empty())
+                    .then(|| completion_selection.get().min(suggestions.len() - 1));
+                let prompt = review_event.get().map_or_else(
+                    || {
+                        "Comment (Ctrl+D to submit, Ctrl+T for templates, Esc to cancel):"
+                            .to_owned()
+                    },
+                    review::prompt,
+                );
+                (prompt, suggestions, selected_idx)
+            };
+            Some(
+                RenderedTextInput::build_with_suggestions(
+                    &prompt,
+                    &buf,
+                    depth,
+                    &TextInputColors {
+                        text: Some(theme.text_primary),
+                        prompt: Some(theme.text_secondary),
+                        border: Some(theme.border_faint),
+                        highlight: Some(theme.text_primary),
+                        highlight_bg: Some(theme.bg_selected),
+                        suggestion: Some(theme.text_faint),
+                        chrome: theme.chrome,
+                    },
+                    &suggestions,
+                    selected_idx,
+                )
+                .with_misspellings(
+                    crate::spell::misspellings(&buf),
+                    theme.text_error.to_crossterm_color(depth),
+                ),
+            )
+        }
+        InputMode::ReplyPicker => {
+            let query = reply_query.read().clone();
+            let titles: Vec<String> = super::common::reply_matches(&props.reply_templates, &query)
+                .into_iter()
+                .map(|r| r.title.clone())
+                .collect();
+            let selected_idx =
+                (!titles.is_empty()).then(|| completion_selection.get().min(titles.len() - 1));
+            Some(RenderedTextInput::build_with_suggestions(
+                "Insert template (Enter to insert, Esc to go back):",
+                &query,
+                depth,
+                &TextInputColors {
+                    text: Some(theme.text_primary),
+                    prompt: Some(theme.text_secondary),
+                    border: Some(theme.border_faint),
+                    highlight: Some(theme.text_primary),
+                    highlight_bg: Some(theme.bg_selected),
+                    suggestion: Some(theme.text_faint),
+                    chrome: theme.chrome,
+                },
+                &titles,
+                selected_idx,
+            ))
+        }
         InputMode::Confirm(action) => {
             let prompt = match action {
                 BuiltinAction::Close => "Close this PR? (y/n)",
                 BuiltinAction::Reopen => "Reopen this PR? (y/n)",
                 BuiltinAction::Merge => "Merge this PR? (y/n)",
-                BuiltinAction::Approve => "Approve this PR? (y/n)",
+                BuiltinAction::MergeWhenGreen => {
+                    "Merge this PR once its checks and reviews pass? (y/n)"
+                }
+                BuiltinAction::Approve => "Approve this PR? (y/n, e to write a review)",
                 BuiltinAction::UpdateFromBase => "Update branch from base? (y/n)",
                 BuiltinAction::MarkReady => "Mark this draft PR ready for review? (y/n)",
+                BuiltinAction::Enqueue => "Add this PR to the merge queue? (y/n)",
+                BuiltinAction::ApproveWorkflows => {
+                    "Approve the waiting workflow runs? They run the fork's code (y/n)"
+                }
                 BuiltinAction::Checkout => "Clone repo and checkout branch? (y/n)",
                 BuiltinAction::Worktree => "Create worktree for this branch? (y/n)",
                 _ => "(y/n)",
@@ -2685,10 +4226,23 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                     text: Some(theme.text_primary),
                     prompt: Some(theme.text_warning),
                     border: Some(theme.border_faint),
+                    chrome: theme.chrome,
                     ..Default::default()
                 },
             ))
         }
+        InputMode::KeepTab => Some(RenderedTextInput::build(
+            "Keep tab as:",
+            &input_buffer.read(),
+            depth,
+            &TextInputColors {
+                text: Some(theme.text_primary),
+                prompt: Some(theme.text_secondary),
+                border: Some(theme.border_faint),
+                chrome: theme.chrome,
+                ..Default::default()
+            },
+        )),
         InputMode::Search => Some(RenderedTextInput::build(
             "/",
             &search_query.read(),
@@ -2697,6 +4251,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 text: Some(theme.text_primary),
                 prompt: Some(theme.text_secondary),
                 border: Some(theme.border_faint),
+                chrome: theme.chrome,
                 ..Default::default()
             },
         )),
@@ -2709,6 +4264,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 text: Some(theme.text_primary),
                 prompt: Some(theme.text_warning),
                 border: Some(theme.border_faint),
+                chrome: theme.chrome,
                 ..Default::default()
             },
         )),
@@ -2717,14 +4273,24 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     let context_text = if current_data.is_some_and(|d| d.loading) {
         "Fetching PRs...".to_owned()
     } else if let Some(err) = current_data.and_then(|d| d.error.as_ref()) {
-        format!("Error: {err}")
+        let cached = current_data
+            .and_then(|d| d.stale)
+            .map(|saved_at| super::common::cached_note(&saved_at, false));
+        format!("Error: {err}{}", cached.unwrap_or_default())
     } else {
-        let total = current_data.map_or(0, |d| d.pr_count);
+        let total = current_data.map_or(0, |d| d.count);
         let cursor_pos = if total_rows > 0 { cursor.get() + 1 } else { 0 };
+        let more = if current_data.is_some_and(|d| d.loading_more) {
+            " (fetching more...)".to_owned()
+        } else if let Some(saved_at) = current_data.and_then(|d| d.stale) {
+            super::common::cached_note(&saved_at, true)
+        } else {
+            String::new()
+        };
         if search_q.is_empty() {
-            format!("PR {cursor_pos}/{total}")
+            format!("PR {cursor_pos}/{total}{more}")
         } else {
-            format!("PR {cursor_pos}/{total_rows} (filtered from {total})")
+            format!("PR {cursor_pos}/{total_rows} (filtered from {total}){more}")
         }
     };
     let active_fetch_time = filter_fetch_times
@@ -2734,7 +4300,12 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
         .flatten();
     let updated_text = footer::format_updated_ago(active_fetch_time);
 
-    let rate_limit_text = footer::format_rate_limit(rate_limit_state.read().as_ref());
+    let rate_limit_text = footer::format_rate_limit(
+        rate_limit_state.read().of(all_filters
+            .get(current_filter_idx)
+            .and_then(|(f, _)| f.host.as_deref())),
+        current_data.and_then(|d| d.refresh_cost),
+    );
 
     let scope_label = match scope_repo {
         Some(repo) => repo.clone(),
@@ -2748,10 +4319,12 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
             Some(theme.footer_alerts),
             Some(theme.footer_notifications),
             Some(theme.footer_repo),
+            Some(theme.footer_events),
         ],
         inactive: Some(theme.text_faint),
         text: Some(theme.text_faint),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
     let rendered_footer = RenderedFooter::build(
         ViewKind::Prs,
@@ -2761,6 +4334,8 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
             context_text,
             updated_text,
             rate_limit_text,
+            watch_badges: props.watch_badges.map(|b| b.get()).unwrap_or_default(),
+            view_counts: props.view_counts.map(|c| c.get()).unwrap_or_default(),
         },
         action_status.read().as_ref(),
         &theme,
@@ -2769,7 +4344,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     );
 
     let rendered_help = if help_visible.get() {
-        props.keybindings.map(|kb| {
+        props.keybindings.as_deref().map(|kb| {
             RenderedHelpOverlay::build(&HelpOverlayBuildConfig {
                 bindings: kb,
                 context: ViewContext::Prs,
@@ -2778,6 +4353,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 key_color: Some(theme.text_success),
                 desc_color: Some(theme.text_secondary),
                 border_color: Some(theme.border_primary),
+                chrome: theme.chrome,
             })
         })
     } else {
@@ -2799,6 +4375,7 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 cursor: run_selector_cursor.get(),
                 show_filter: false,
                 filter_text: String::new(),
+                hint: None,
                 depth,
                 title_color: Some(theme.text_primary),
                 item_color: Some(theme.text_secondary),
@@ -2809,28 +4386,50 @@ pub fn PrsView<'a>(props: &PrsViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
                 filter_prompt_color: None,
                 filter_text_color: None,
                 cursor_marker: theme.icons.select_cursor.clone(),
+                chrome: theme.chrome,
             })
         })
     };
 
+    let rendered_calendar = profile::render(&profile_overlay, &theme, depth, props.width);
+    let rendered_drafts = super::drafts::render(drafts_cursor.get(), &theme, depth);
+    let rendered_copy_menu = copy_menu::render(&copy_menu_state, &theme, depth);
+    let rendered_share_menu = share_menu::render(&share_menu_state, &theme, depth);
+    let rendered_files_menu = files_menu::render(&files_menu_state, &theme, depth);
+
     let width = u32::from(props.width);
     let height = u32::from(props.height);
+    // Clicks go to the overlay, prompt or composer shown, not what is under it.
+    let clickable = *input_mode.read() == InputMode::Normal
+        && !help_visible.get()
+        && run_selector_items.read().is_none()
+        && drafts_cursor.get().is_none()
+        && copy_menu_state.read().is_none()
+        && share_menu_state.read().is_none()
+        && files_menu_state.read().is_none()
+        && profile_overlay.read().is_none();
 
     element! {
         View(flex_direction: FlexDirection::Column, width, height) {
-            TabBar(tab_bar: rendered_tab_bar)
+            TabBar(tab_bar: rendered_tab_bar, on_click: clickable.then_some(tab_click))
 
             View(flex_grow: 1.0_f32, flex_direction: FlexDirection::Row, overflow: Overflow::Hidden) {
                 View(flex_grow: 1.0_f32, flex_direction: FlexDirection::Column) {
-                    ScrollableTable(table: rendered_table)
+                    WarningBanner(banner: rendered_banner)
+                    ScrollableTable(table: rendered_table, on_click: clickable.then_some(row_click))
                 }
                 Sidebar(sidebar: rendered_sidebar)
             }
 
             TextInput(input: rendered_text_input)
-            Footer(footer: rendered_footer)
+            Footer(footer: rendered_footer, on_click: props.goto_view.filter(|_| clickable))
             HelpOverlay(overlay: rendered_help, width: props.width, height: props.height)
             SelectionOverlay(overlay: rendered_run_selector, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_drafts, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_copy_menu, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_share_menu, width: props.width, height: props.height)
+            SelectionOverlay(overlay: rendered_files_menu, width: props.width, height: props.height)
+            CalendarOverlay(overlay: rendered_calendar, width: props.width, height: props.height)
         }
     }
     .into_any()
@@ -2862,6 +4461,24 @@ fn build_pr_assignee_candidates(pr: &PullRequest) -> Vec<String> {
     pool
 }
 
+/// Logins offered after `@` in the comment composer: the assignee pool plus
+/// everyone who appears in the loaded timeline.
+fn build_pr_mention_candidates(pr: &PullRequest, detail: Option<&PrDetail>) -> Vec<String> {
+    let mut pool = build_pr_assignee_candidates(pr);
+    if let Some(detail) = detail {
+        pool.extend(
+            detail
+                .timeline_events
+                .iter()
+                .filter_map(TimelineEvent::actor)
+                .map(str::to_owned),
+        );
+        pool.sort();
+        pool.dedup();
+    }
+    pool
+}
+
 /// Groups the common parameters shared by multiselect input handling
 /// in the PRs view.
 struct InputContext<'a> {
@@ -2869,52 +4486,9 @@ struct InputContext<'a> {
     input_buffer: State<String>,
     prs_state: &'a State<PrsState>,
     filter_idx: usize,
-    cursor: usize,
+    pr_idx: Option<usize>,
     engine: Option<&'a EngineHandle>,
-    event_tx: &'a std::sync::mpsc::Sender<Event>,
-}
-
-/// Extract (owner, repo, number) from the current PR at cursor position.
-fn get_current_pr_info(
-    prs_state: &State<PrsState>,
-    filter_idx: usize,
-    cursor: usize,
-) -> Option<(String, String, u64)> {
-    let state = prs_state.read();
-    let filter = state.filters.get(filter_idx)?;
-    let pr = filter.prs.get(cursor)?;
-    let repo_ref = pr.repo.as_ref()?;
-    Some((repo_ref.owner.clone(), repo_ref.name.clone(), pr.number))
-}
-
-fn get_current_pr_labels(
-    prs_state: &State<PrsState>,
-    filter_idx: usize,
-    cursor: usize,
-) -> Vec<String> {
-    let state = prs_state.read();
-    let Some(filter) = state.filters.get(filter_idx) else {
-        return vec![];
-    };
-    let Some(pr) = filter.prs.get(cursor) else {
-        return vec![];
-    };
-    pr.labels.iter().map(|l| l.name.clone()).collect()
-}
-
-fn get_current_pr_assignees(
-    prs_state: &State<PrsState>,
-    filter_idx: usize,
-    cursor: usize,
-) -> Vec<String> {
-    let state = prs_state.read();
-    let Some(filter) = state.filters.get(filter_idx) else {
-        return vec![];
-    };
-    let Some(pr) = filter.prs.get(cursor) else {
-        return vec![];
-    };
-    pr.assignees.iter().map(|a| a.login.clone()).collect()
+    event_tx: &'a EventSender,
 }
 
 /// Compute update-status text and color for the sidebar.
@@ -3078,6 +4652,19 @@ fn build_sidebar_meta(
     let lines_added = Some(format!("+{}", pr.additions));
     let lines_deleted = Some(format!("-{}", pr.deletions));
 
+    let deployments = detail
+        .map(PrDetail::latest_deployments)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|d| {
+            let (icon, color) = deployment_state_icon(d.state, theme);
+            (
+                format!("{icon} {}", d.environment),
+                color.to_crossterm_color(depth),
+            )
+        })
+        .collect();
+
     SidebarMeta {
         pill_icon,
         pill_text,
@@ -3105,7 +4692,9 @@ fn build_sidebar_meta(
         updated_age,
         lines_added,
         lines_deleted,
-        reactions_text: None,
+        reactions_text: pr.reactions.summary(),
+        deployments,
+        context_lines: Vec::new(),
         date_fg: theme.text_faint.to_crossterm_color(depth),
         date_age_fg: theme.text_secondary.to_crossterm_color(depth),
         additions_fg: theme.text_success.to_crossterm_color(depth),
@@ -3117,6 +4706,175 @@ fn build_sidebar_meta(
     }
 }
 
+/// Icon and color for a deployment state, reusing the check-run icons.
+fn deployment_state_icon(
+    state: Option<crate::types::DeploymentState>,
+    theme: &ResolvedTheme,
+) -> (&str, AppColor) {
+    use crate::types::DeploymentState;
+
+    let icons = &theme.icons;
+    match state {
+        Some(DeploymentState::Active) => (&icons.check_success, theme.text_success),
+        Some(DeploymentState::Error | DeploymentState::Failure) => {
+            (&icons.check_failure, theme.text_error)
+        }
+        Some(
+            DeploymentState::Pending
+            | DeploymentState::Queued
+            | DeploymentState::InProgress
+            | DeploymentState::Waiting,
+        ) => (&icons.check_running, theme.text_warning),
+        _ => (&icons.check_cancelled, theme.text_faint),
+    }
+}
+
+/// The comment after (or before) `current` that has a permalink, skipping
+/// bot and minimized comments unless `show_noise` is set.
+///
+/// Starts from the first (or last) one when nothing is selected, and stays
+/// put at either end.
+fn step_comment(
+    events: &[crate::types::TimelineEvent],
+    current: Option<usize>,
+    forward: bool,
+    show_noise: bool,
+) -> Option<usize> {
+    let mut selectable = selectable_comments(events, show_noise);
+    let step = match (current, forward) {
+        (None, true) => selectable.next(),
+        (None, false) => selectable.next_back(),
+        (Some(cur), true) => selectable.find(|&i| i > cur),
+        (Some(cur), false) => selectable.rfind(|&i| i < cur),
+    };
+    step.or(current)
+}
+
+/// Indices of the `events` that can be selected: comments with a
+/// permalink, bot and minimized ones only with `show_noise`.
+fn selectable_comments(
+    events: &[crate::types::TimelineEvent],
+    show_noise: bool,
+) -> impl DoubleEndedIterator<Item = usize> + '_ {
+    events
+        .iter()
+        .enumerate()
+        .filter(move |(_, e)| e.url().is_some() && (show_noise || !e.is_noise()))
+        .map(|(i, _)| i)
+}
+
+/// What the focused sidebar steps through on `tab`, in display order:
+/// indices of the selectable comments, the changed files or the checks.
+/// The other tabs have none and scroll instead.
+fn sidebar_items(
+    tab: SidebarTab,
+    pr: &PullRequest,
+    detail: Option<&PrDetail>,
+    show_noise: bool,
+) -> Vec<usize> {
+    match (tab, detail) {
+        (SidebarTab::Activity, Some(detail)) => {
+            selectable_comments(&detail.timeline_events, show_noise).collect()
+        }
+        (SidebarTab::Files, Some(detail)) => {
+            crate::viewed_files::review_order(&viewed_states(Some(pr), detail))
+        }
+        (SidebarTab::Checks, _) => sidebar_tabs::check_order(&pr.check_runs),
+        _ => Vec::new(),
+    }
+}
+
+/// The item selected in the focused sidebar, for `open_item`, `copy_item`
+/// and `toggle_item`.
+enum SidebarItem {
+    /// A comment's permalink.
+    Comment(String),
+    /// A changed file, by index, and the PR's files page.
+    File {
+        idx: usize,
+        path: String,
+        files_url: String,
+    },
+    /// A check's details page, when it has one.
+    Check(Option<String>),
+}
+
+impl SidebarItem {
+    /// The `idx`-th timeline event, file or check of `pr` on `tab`.
+    fn of(
+        tab: SidebarTab,
+        pr: &PullRequest,
+        detail: Option<&PrDetail>,
+        idx: usize,
+    ) -> Option<Self> {
+        match tab {
+            SidebarTab::Activity => {
+                let url = detail?.timeline_events.get(idx)?.url()?;
+                Some(Self::Comment(url.to_owned()))
+            }
+            SidebarTab::Files => Some(Self::File {
+                idx,
+                path: detail?.files.get(idx)?.path.clone(),
+                files_url: format!("{}/files", pr.url),
+            }),
+            SidebarTab::Checks => Some(Self::Check(pr.check_runs.get(idx)?.url.clone())),
+            _ => None,
+        }
+    }
+
+    fn url(&self) -> Option<&str> {
+        match self {
+            Self::Comment(url) | Self::File { files_url: url, .. } => Some(url),
+            Self::Check(url) => url.as_deref(),
+        }
+    }
+
+    /// The permalink, or the file's path.
+    fn copy_text(&self) -> Option<&str> {
+        match self {
+            Self::File { path, .. } => Some(path),
+            _ => self.url(),
+        }
+    }
+
+    /// What [`copy_text`](Self::copy_text) concerns, for the footer.
+    fn copied(&self) -> &'static str {
+        match self {
+            Self::Comment(_) => "comment link",
+            Self::File { .. } => "file path",
+            Self::Check(_) => "check link",
+        }
+    }
+
+    fn noun(&self) -> &'static str {
+        match self {
+            Self::Comment(_) => "comment",
+            Self::File { .. } => "PR files",
+            Self::Check(_) => "check",
+        }
+    }
+}
+
+/// Review state of each changed file of `pr`.
+fn viewed_states(pr: Option<&PullRequest>, detail: &PrDetail) -> Vec<FileViewedState> {
+    let key = pr.and_then(|pr| {
+        let repo = pr.repo.as_ref()?;
+        Some(crate::viewed_files::key(&repo.full_name(), pr.number))
+    });
+    match key {
+        Some(key) => crate::viewed_files::states(&key, &detail.files),
+        None => detail.files.iter().map(|f| f.viewer_state).collect(),
+    }
+}
+
+/// URL of the newest deployment (one per environment) that has one.
+fn latest_deployment_url(detail: &PrDetail) -> Option<(String, String)> {
+    detail
+        .latest_deployments()
+        .into_iter()
+        .find_map(|d| Some((d.environment.clone(), d.url.clone()?)))
+}
+
 /// Fallback theme when none is provided.
 fn default_theme() -> ResolvedTheme {
     super::default_theme()
@@ -3125,7 +4883,9 @@ fn default_theme() -> ResolvedTheme {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{MergeableState, PrDetail, PullRequest};
+    use crate::types::{
+        CodeOwners, MergeRequirements, MergeableState, PageCursor, PrDetail, PullRequest,
+    };
 
     fn pr_with_status(status: Option<MergeStateStatus>) -> PullRequest {
         PullRequest {
@@ -3140,6 +4900,7 @@ mod tests {
             additions: 0,
             deletions: 0,
             head_ref: String::new(),
+            head_sha: String::new(),
             base_ref: String::new(),
             labels: vec![],
             assignees: vec![],
@@ -3147,7 +4908,9 @@ mod tests {
             comments: vec![],
             review_threads: vec![],
             review_requests: vec![],
+            team_review_requests: vec![],
             reviews: vec![],
+            review_times: crate::types::ReviewTimes::default(),
             timeline_events: vec![],
             files: vec![],
             check_runs: vec![],
@@ -3156,6 +4919,7 @@ mod tests {
             url: String::new(),
             repo: None,
             comment_count: 0,
+            reactions: crate::types::ReactionGroups::default(),
             author_association: None,
             participants: vec![],
             merge_state_status: status,
@@ -3174,6 +4938,12 @@ mod tests {
             files: vec![],
             mergeable,
             behind_by,
+            commits_page: PageCursor::default(),
+            files_page: PageCursor::default(),
+            deployments: Vec::new(),
+            merge_requirements: MergeRequirements::default(),
+            awaiting_approval: vec![],
+            code_owners: CodeOwners::default(),
         }
     }
 
@@ -3255,6 +5025,41 @@ mod tests {
         pr_with_status(None)
     }
 
+    // --- list core ---
+
+    #[test]
+    fn streamed_page_is_flagged_until_complete() {
+        let mut pr = test_pr();
+        pr.number = 7;
+        pr.title = "Fix layout".into();
+        let page = FilterData::loaded(vec![pr.clone()], vec![Row::new()], Vec::new(), false);
+        assert_eq!(
+            (page.count, page.loading, page.loading_more),
+            (1, false, true)
+        );
+        assert_eq!(page.titles, vec!["Fix layout".to_owned()]);
+
+        let done = FilterData::loaded(vec![pr.clone()], vec![Row::new()], Vec::new(), true);
+        assert!(!done.loading_more);
+        let vars = list::reply_vars(&pr);
+        assert_eq!((vars.number, vars.title.as_str()), (7, "Fix layout"));
+    }
+
+    #[test]
+    fn cached_rows_are_listed_until_fetched() {
+        let saved_at = chrono::Utc::now() - chrono::TimeDelta::hours(2);
+        let cached = FilterData::cached(vec![test_pr()], vec![Row::new()], saved_at);
+        assert_eq!(
+            (cached.count, cached.loading, cached.loading_more),
+            (1, false, false)
+        );
+        assert_eq!(cached.stale, Some(saved_at));
+        assert_eq!(
+            crate::views::common::cached_note(&saved_at, true),
+            " (cached 2h ago, refreshing...)"
+        );
+    }
+
     // --- build_state_cell ---
 
     #[test]
@@ -3343,6 +5148,7 @@ mod tests {
             state: crate::types::ReviewState::Approved,
             body: String::new(),
             submitted_at: None,
+            on_behalf_of: Vec::new(),
         }];
         let cell = build_review_cell(&pr, &theme);
         assert_eq!(cell.text(), theme.icons.review_approved);
@@ -3358,6 +5164,7 @@ mod tests {
             state: crate::types::ReviewState::ChangesRequested,
             body: String::new(),
             submitted_at: None,
+            on_behalf_of: Vec::new(),
         }];
         let cell = build_review_cell(&pr, &theme);
         assert_eq!(cell.text(), theme.icons.review_changes);
@@ -3383,11 +5190,129 @@ mod tests {
             state: crate::types::ReviewState::Commented,
             body: String::new(),
             submitted_at: None,
+            on_behalf_of: Vec::new(),
         }];
         let cell = build_review_cell(&pr, &theme);
         assert_eq!(cell.text(), theme.icons.review_commented);
     }
 
+    // --- CiSlice ---
+
+    fn check(conclusion: crate::types::CheckConclusion) -> crate::types::CheckRun {
+        crate::types::CheckRun {
+            name: "ci".to_owned(),
+            status: Some(crate::types::CheckStatus::Completed),
+            conclusion: Some(conclusion),
+            url: None,
+            workflow_run_id: None,
+            workflow_name: None,
+            job_id: None,
+            started_at: None,
+            completed_at: None,
+            is_required: false,
+        }
+    }
+
+    #[test]
+    fn ci_slice_failing_and_approved_green() {
+        use crate::types::CheckConclusion;
+
+        let mut pr = test_pr();
+        pr.review_decision = Some(crate::github::types::ReviewDecision::Approved);
+        assert!(
+            !CiSlice::ApprovedGreen.matches(&pr),
+            "no checks is not green"
+        );
+
+        pr.check_runs = vec![
+            check(CheckConclusion::Success),
+            check(CheckConclusion::Skipped),
+        ];
+        assert!(CiSlice::ApprovedGreen.matches(&pr));
+        assert!(!CiSlice::FailingCi.matches(&pr));
+
+        pr.check_runs.push(check(CheckConclusion::TimedOut));
+        assert!(!CiSlice::ApprovedGreen.matches(&pr));
+        assert!(CiSlice::FailingCi.matches(&pr));
+    }
+
+    #[test]
+    fn step_comment_skips_events_without_permalinks() {
+        use crate::types::TimelineEvent;
+
+        let at = chrono::Utc::now();
+        let comment = |url: Option<&str>| TimelineEvent::Comment {
+            author: None,
+            body: String::new(),
+            created_at: at,
+            url: url.map(str::to_owned),
+            is_bot: false,
+            is_minimized: false,
+        };
+        let events = vec![
+            comment(Some("https://x/1")),
+            TimelineEvent::Merged {
+                actor: None,
+                created_at: at,
+            },
+            comment(None),
+            comment(Some("https://x/3")),
+            TimelineEvent::Comment {
+                author: Some("ci[bot]".into()),
+                body: String::new(),
+                created_at: at,
+                url: Some("https://x/4".into()),
+                is_bot: true,
+                is_minimized: false,
+            },
+        ];
+        assert_eq!(step_comment(&events, None, true, false), Some(0));
+        assert_eq!(step_comment(&events, None, false, false), Some(3));
+        assert_eq!(step_comment(&events, Some(0), true, false), Some(3));
+        assert_eq!(step_comment(&events, Some(3), true, false), Some(3));
+        assert_eq!(step_comment(&events, Some(3), false, false), Some(0));
+        assert_eq!(step_comment(&events, Some(3), true, true), Some(4));
+        assert_eq!(step_comment(&[], None, true, false), None);
+    }
+
+    #[test]
+    fn aggregate_ci_status_downplays_optional_failures() {
+        use crate::types::CheckConclusion;
+
+        let theme = test_theme();
+        let mut required = check(CheckConclusion::Success);
+        required.is_required = true;
+        let optional = check(CheckConclusion::Failure);
+
+        // Without required checks every failure blocks.
+        let (_, color) = aggregate_ci_status(std::slice::from_ref(&optional), &theme);
+        assert_eq!(color, theme.text_error);
+
+        let (icon, color) = aggregate_ci_status(&[required.clone(), optional], &theme);
+        assert_eq!(icon, theme.icons.ci_failure);
+        assert_eq!(color, theme.text_warning);
+
+        required.conclusion = Some(CheckConclusion::Failure);
+        let (_, color) = aggregate_ci_status(&[required], &theme);
+        assert_eq!(color, theme.text_error);
+    }
+
+    #[test]
+    fn ci_slice_changes_requested_falls_back_to_reviews() {
+        let mut pr = test_pr();
+        pr.review_decision = None;
+        pr.reviews = vec![crate::types::Review {
+            author: None,
+            state: crate::types::ReviewState::ChangesRequested,
+            body: String::new(),
+            submitted_at: None,
+            on_behalf_of: Vec::new(),
+        }];
+        assert!(CiSlice::ChangesRequested.matches(&pr));
+        pr.review_decision = Some(crate::github::types::ReviewDecision::Approved);
+        assert!(!CiSlice::ChangesRequested.matches(&pr));
+    }
+
     // --- build_lines_cell ---
 
     #[test]
@@ -3426,43 +5351,67 @@ mod tests {
         assert_eq!(cell.spans[2].text, "-5");
     }
 
-    // --- build_info_cell ---
+    // --- build_diff_bar_cell ---
+
+    #[test]
+    fn diff_bar_split_is_log_scaled_and_proportional() {
+        assert_eq!(diff_bar_split(0, 0), (0, 0));
+        assert_eq!(diff_bar_split(3, 0), (1, 0));
+        assert_eq!(diff_bar_split(60, 40), (2, 1));
+        assert_eq!(diff_bar_split(990, 10), (3, 1));
+        assert_eq!(diff_bar_split(2, 99_998), (1, 4));
+        assert_eq!(diff_bar_split(1_000_000, 0), (5, 0));
+    }
+
+    #[test]
+    fn build_diff_bar_cell_fills_the_bar() {
+        let theme = test_theme();
+        let mut pr = test_pr();
+        pr.additions = 120;
+        pr.deletions = 30;
+        let cell = build_diff_bar_cell(&pr, &theme);
+        assert_eq!(cell.text().chars().count(), usize::from(DIFF_BAR_WIDTH));
+        assert_eq!(cell.spans.len(), 3);
+        assert_eq!(cell.spans[0].color, Some(theme.text_success));
+    }
+
+    // --- info line ---
 
     #[test]
-    fn build_info_cell_contains_number() {
+    fn info_line_contains_number() {
         let theme = test_theme();
         let mut pr = test_pr();
         pr.number = 123;
-        let cell = build_info_cell(&pr, &theme);
+        let cell = RowLayout::standard().info.render(&pr, &theme);
         let text = cell.text();
         assert!(text.contains("#123"), "expected #123 in '{text}'");
     }
 
     #[test]
-    fn build_info_cell_contains_author() {
+    fn info_line_contains_author() {
         let theme = test_theme();
         let mut pr = test_pr();
         pr.author = Some(crate::types::Actor {
             login: "octocat".to_owned(),
             avatar_url: String::new(),
         });
-        let cell = build_info_cell(&pr, &theme);
+        let cell = RowLayout::standard().info.render(&pr, &theme);
         let text = cell.text();
         assert!(text.contains("@octocat"), "expected @octocat in '{text}'");
     }
 
     #[test]
-    fn build_info_cell_no_author_shows_unknown() {
+    fn info_line_no_author_shows_unknown() {
         let theme = test_theme();
         let mut pr = test_pr();
         pr.author = None;
-        let cell = build_info_cell(&pr, &theme);
+        let cell = RowLayout::standard().info.render(&pr, &theme);
         let text = cell.text();
         assert!(text.contains("@unknown"), "expected @unknown in '{text}'");
     }
 
     #[test]
-    fn build_info_cell_contains_repo_name() {
+    fn info_line_contains_repo_name() {
         let theme = test_theme();
         let mut pr = test_pr();
         pr.repo = Some(crate::types::RepoRef {
@@ -3470,22 +5419,40 @@ mod tests {
             name: "repo".to_owned(),
         });
         pr.number = 42;
-        let cell = build_info_cell(&pr, &theme);
+        let cell = RowLayout::standard().info.render(&pr, &theme);
         let text = cell.text();
         assert!(text.contains("org/repo"), "expected org/repo in '{text}'");
         assert!(text.contains("#42"), "expected #42 in '{text}'");
     }
 
     #[test]
-    fn build_info_cell_no_repo() {
+    fn info_line_no_repo() {
         let theme = test_theme();
         let mut pr = test_pr();
         pr.repo = None;
         pr.number = 1;
-        let cell = build_info_cell(&pr, &theme);
+        let cell = RowLayout::standard().info.render(&pr, &theme);
         let text = cell.text();
         // Should still have the number
         assert!(text.contains("#1"), "expected #1 in '{text}'");
         assert!(text.contains("by"), "expected 'by' in '{text}'");
     }
+
+    #[test]
+    fn pr_to_row_follows_the_tab_layout() {
+        let theme = test_theme();
+        let mut pr = test_pr();
+        pr.number = 9;
+        pr.title = "Fix layout".to_owned();
+        pr.head_ref = "fix/layout".to_owned();
+        let layout = RowLayout::new(Some(&crate::config::types::LayoutConfig {
+            info: Some("{{title}}".to_owned()),
+            subtitle: Some("{{number}} on {{branch}}".to_owned()),
+            ..Default::default()
+        }));
+        let row = pr_to_row(&pr, &theme, "relative", None, &[], Some(&layout));
+        assert_eq!(row["info"].text(), "Fix layout");
+        assert_eq!(row["subtitle"].text(), "#9 on fix/layout");
+        assert_eq!(row["subtitle"].spans[1].color, Some(theme.text_faint));
+    }
 }
@@ -0,0 +1,276 @@
+//! Branch and tag picker of the Actions view.
+//!
+//! `b` lists the branches and tags of the scoped repo (else the active
+//! tab's). Typing filters the list; Enter scopes the tab's runs to the pick
+//! through the list-runs `branch` parameter, so GitHub filters them rather
+//! than the page already fetched. "All branches" drops the scope.
+
+use iocraft::prelude::*;
+
+use crate::color::ColorDepth;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::theme::ResolvedTheme;
+
+/// Engine request context of ref fetches.
+pub(crate) const FETCH_CONTEXT: &str = "FetchRepoRefs";
+
+/// Entries listed at once; the list scrolls past that.
+const VISIBLE_ROWS: usize = 20;
+
+/// An open picker.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RefPicker {
+    /// Tab the pick applies to.
+    pub tab: usize,
+    /// `owner/repo` whose refs are listed.
+    pub repo: String,
+    /// Branch the tab is scoped to now.
+    pub current: Option<String>,
+    /// Sorted names; `None` while loading.
+    pub branches: Option<Vec<String>>,
+    pub tags: Vec<String>,
+    pub error: Option<String>,
+    pub filter: String,
+    pub cursor: usize,
+    /// First entry shown.
+    pub offset: usize,
+}
+
+/// A branch picked for a tab; `None` for all branches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RefPick {
+    pub tab: usize,
+    pub branch: Option<String>,
+}
+
+/// Entry of the list: its label and the branch it scopes to.
+struct Entry<'a> {
+    label: String,
+    branch: Option<&'a str>,
+}
+
+impl RefPicker {
+    /// Entries matching the filter: "All branches" (unfiltered only), then
+    /// branches, then tags.
+    fn entries(&self) -> Vec<Entry<'_>> {
+        let Some(branches) = &self.branches else {
+            return Vec::new();
+        };
+        let query = self.filter.to_lowercase();
+        let matches = |name: &&String| name.to_lowercase().contains(&query);
+        let mut entries = Vec::new();
+        if query.is_empty() {
+            entries.push(Entry {
+                label: "All branches".to_owned(),
+                branch: None,
+            });
+        }
+        entries.extend(branches.iter().filter(matches).map(|name| Entry {
+            label: name.clone(),
+            branch: Some(name.as_str()),
+        }));
+        entries.extend(self.tags.iter().filter(matches).map(|name| Entry {
+            label: format!("{name} (tag)"),
+            branch: Some(name.as_str()),
+        }));
+        entries
+    }
+}
+
+/// Open the picker for tab `tab` and fetch the refs of `owner/repo`.
+pub(crate) fn open(
+    picker: &mut State<Option<RefPicker>>,
+    engine: Option<&EngineHandle>,
+    host: Option<String>,
+    (tab, repo): (usize, &str),
+    current: Option<String>,
+    reply_tx: &EventSender,
+) {
+    let Some((owner, name)) = repo.split_once('/') else {
+        return;
+    };
+    if let Some(engine) = engine {
+        engine.send(Request::FetchRepoRefs {
+            host,
+            owner: owner.to_owned(),
+            repo: name.to_owned(),
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    picker.set(Some(RefPicker {
+        tab,
+        repo: repo.to_owned(),
+        current,
+        ..RefPicker::default()
+    }));
+}
+
+/// Store the fetched refs of `repo` in the open picker.
+pub(crate) fn on_fetched(
+    picker: &mut State<Option<RefPicker>>,
+    repo: &str,
+    branches: Vec<String>,
+    tags: Vec<String>,
+) {
+    let Some(mut current) = picker.read().clone() else {
+        return;
+    };
+    if current.repo != repo {
+        return;
+    }
+    current.branches = Some(branches);
+    current.tags = tags;
+    picker.set(Some(current));
+}
+
+/// Show a failed fetch in the open picker.
+pub(crate) fn on_error(picker: &mut State<Option<RefPicker>>, message: String) {
+    let Some(mut current) = picker.read().clone() else {
+        return;
+    };
+    current.error = Some(message);
+    picker.set(Some(current));
+}
+
+/// Handle a key while the picker is open. Every key is consumed; returns
+/// the pick on Enter.
+pub(crate) fn handle_key(picker: &mut State<Option<RefPicker>>, code: KeyCode) -> Option<RefPick> {
+    let mut current = picker.read().clone()?;
+    match code {
+        KeyCode::Esc => {
+            picker.set(None);
+            return None;
+        }
+        KeyCode::Enter => {
+            let entries = current.entries();
+            let entry = entries.get(current.cursor)?;
+            let pick = RefPick {
+                tab: current.tab,
+                branch: entry.branch.map(str::to_owned),
+            };
+            picker.set(None);
+            return Some(pick);
+        }
+        KeyCode::Down => {
+            let len = current.entries().len();
+            current.cursor = (current.cursor + 1).min(len.saturating_sub(1));
+        }
+        KeyCode::Up => current.cursor = current.cursor.saturating_sub(1),
+        KeyCode::Backspace => {
+            current.filter.pop();
+            current.cursor = 0;
+        }
+        KeyCode::Char(ch) => {
+            current.filter.push(ch);
+            current.cursor = 0;
+        }
+        _ => return None,
+    }
+    if current.cursor < current.offset {
+        current.offset = current.cursor;
+    } else if current.cursor >= current.offset + VISIBLE_ROWS {
+        current.offset = current.cursor + 1 - VISIBLE_ROWS;
+    }
+    picker.set(Some(current));
+    None
+}
+
+/// Render the open picker, if any.
+pub(crate) fn render(
+    picker: &State<Option<RefPicker>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let picker = picker.read();
+    let current = picker.as_ref()?;
+    let line = |label: String| SelectionOverlayItem { label };
+    let entries = current.entries();
+    let (items, cursor) = match (&current.error, &current.branches) {
+        (Some(error), _) => (vec![line(format!("Error: {error}"))], usize::MAX),
+        (None, None) => (
+            vec![line(format!(
+                "Loading{}",
+                crate::components::ellipsis(theme.chrome)
+            ))],
+            usize::MAX,
+        ),
+        (None, Some(_)) if entries.is_empty() => {
+            (vec![line("No matching branch".to_owned())], usize::MAX)
+        }
+        (None, Some(_)) => {
+            let items = entries
+                .iter()
+                .skip(current.offset)
+                .take(VISIBLE_ROWS)
+                .map(|entry| {
+                    if entry.branch == current.current.as_deref() {
+                        line(format!("{} {}", entry.label, theme.icons.check_success))
+                    } else {
+                        line(entry.label.clone())
+                    }
+                })
+                .collect();
+            (items, current.cursor - current.offset)
+        }
+    };
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title: format!("Runs of {} on", current.repo),
+            items,
+            cursor,
+            show_filter: true,
+            filter_text: current.filter.clone(),
+            hint: Some("Enter scope  Esc close".to_owned()),
+            depth,
+            title_color: Some(theme.text_primary),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: Some(theme.text_faint),
+            filter_text_color: Some(theme.text_primary),
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picker(filter: &str) -> RefPicker {
+        RefPicker {
+            branches: Some(vec!["main".to_owned(), "release/2.x".to_owned()]),
+            tags: vec!["v2.1.0".to_owned()],
+            filter: filter.to_owned(),
+            ..RefPicker::default()
+        }
+    }
+
+    #[test]
+    fn entries_list_all_branches_then_branches_then_tags() {
+        let unfiltered = picker("");
+        let entries = unfiltered.entries();
+        let all: Vec<(&str, Option<&str>)> = entries
+            .iter()
+            .map(|e| (e.label.as_str(), e.branch))
+            .collect();
+        assert_eq!(
+            all,
+            [
+                ("All branches", None),
+                ("main", Some("main")),
+                ("release/2.x", Some("release/2.x")),
+                ("v2.1.0 (tag)", Some("v2.1.0")),
+            ]
+        );
+        let filtered = picker("2");
+        let labels: Vec<String> = filtered.entries().into_iter().map(|e| e.label).collect();
+        assert_eq!(labels, ["release/2.x", "v2.1.0 (tag)"]);
+    }
+}
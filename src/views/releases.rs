@@ -0,0 +1,463 @@
+//! Releases overlay of the Repo view.
+//!
+//! `L` lists the latest releases of the selected branch's repo, newest
+//! first, then the tags no release was made from. Enter opens the selected
+//! one in the browser and `y` copies its source tarball URL. On a draft, `P`
+//! publishes it and `X` deletes it, each after a confirmation.
+
+use iocraft::prelude::*;
+
+use crate::color::ColorDepth;
+use crate::components::footer::ActionFeedback;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::theme::ResolvedTheme;
+use crate::types::{GitTag, Release};
+
+/// Engine request context of release fetches.
+pub(crate) const FETCH_CONTEXT: &str = "FetchReleases";
+
+/// Entries listed at once; the list scrolls past that.
+const VISIBLE_ROWS: usize = 20;
+
+/// A draft action waiting for `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pending {
+    Publish,
+    Delete,
+}
+
+/// An open overlay.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ReleasesOverlay {
+    /// `owner/repo` whose releases are listed.
+    pub repo: String,
+    /// Its host, `None` for github.com.
+    pub host: Option<String>,
+    /// Newest first; `None` while loading.
+    pub releases: Option<Vec<Release>>,
+    pub tags: Vec<GitTag>,
+    pub error: Option<String>,
+    cursor: usize,
+    /// First entry shown.
+    offset: usize,
+    confirm: Option<Pending>,
+}
+
+/// Entry of the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry<'a> {
+    Release(&'a Release),
+    /// A tag without a release.
+    Tag(&'a GitTag),
+}
+
+/// What a key asks the Repo view to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ReleaseAction {
+    Open(String),
+    CopyTarball(String),
+    Publish { id: u64, tag: String },
+    Delete { id: u64, tag: String },
+    Notice(String),
+}
+
+impl ReleasesOverlay {
+    /// Releases, then the tags none of them is on.
+    fn entries(&self) -> Vec<Entry<'_>> {
+        let Some(releases) = &self.releases else {
+            return Vec::new();
+        };
+        let mut entries: Vec<Entry<'_>> = releases.iter().map(Entry::Release).collect();
+        entries.extend(
+            self.tags
+                .iter()
+                .filter(|t| !releases.iter().any(|r| r.tag == t.name))
+                .map(Entry::Tag),
+        );
+        entries
+    }
+
+    fn selected_draft(&self) -> Option<&Release> {
+        match self.entries().get(self.cursor)? {
+            Entry::Release(r) if r.draft => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Apply `code`, other than closing.
+    fn key(&mut self, code: KeyCode) -> Option<ReleaseAction> {
+        if let Some(pending) = self.confirm {
+            match code {
+                KeyCode::Char('y' | 'Y') => {
+                    self.confirm = None;
+                    return self.confirmed(pending);
+                }
+                KeyCode::Char('n' | 'N') | KeyCode::Esc => self.confirm = None,
+                _ => {}
+            }
+            return None;
+        }
+        let entries = self.entries();
+        let len = entries.len();
+        let selected = entries.get(self.cursor).copied();
+        let action = match (code, selected) {
+            (KeyCode::Down | KeyCode::Char('j'), _) => {
+                self.cursor = (self.cursor + 1).min(len.saturating_sub(1));
+                None
+            }
+            (KeyCode::Up | KeyCode::Char('k'), _) => {
+                self.cursor = self.cursor.saturating_sub(1);
+                None
+            }
+            (KeyCode::Enter | KeyCode::Char('o'), Some(Entry::Release(r))) => {
+                Some(ReleaseAction::Open(r.url.clone()))
+            }
+            (KeyCode::Enter | KeyCode::Char('o'), Some(Entry::Tag(t))) => {
+                Some(ReleaseAction::Open(format!(
+                    "https://{}/{}/releases/tag/{}",
+                    self.host.as_deref().unwrap_or("github.com"),
+                    self.repo,
+                    t.name
+                )))
+            }
+            (KeyCode::Char('y'), Some(Entry::Release(r))) => {
+                Some(r.tarball_url.clone().map_or_else(
+                    || ReleaseAction::Notice("A draft has no tarball until published".to_owned()),
+                    ReleaseAction::CopyTarball,
+                ))
+            }
+            (KeyCode::Char('y'), Some(Entry::Tag(t))) => {
+                Some(ReleaseAction::CopyTarball(t.tarball_url.clone()))
+            }
+            (KeyCode::Char('P' | 'X'), Some(_)) if self.selected_draft().is_none() => Some(
+                ReleaseAction::Notice("Only draft releases can be published or deleted".to_owned()),
+            ),
+            (KeyCode::Char('P'), Some(_)) => {
+                self.confirm = Some(Pending::Publish);
+                None
+            }
+            (KeyCode::Char('X'), Some(_)) => {
+                self.confirm = Some(Pending::Delete);
+                None
+            }
+            _ => None,
+        };
+        if self.cursor < self.offset {
+            self.offset = self.cursor;
+        } else if self.cursor >= self.offset + VISIBLE_ROWS {
+            self.offset = self.cursor + 1 - VISIBLE_ROWS;
+        }
+        action
+    }
+
+    /// Carry out `pending` on the selected draft, updating the list ahead
+    /// of GitHub.
+    fn confirmed(&mut self, pending: Pending) -> Option<ReleaseAction> {
+        let draft = self.selected_draft()?;
+        let (id, tag) = (draft.id, draft.tag.clone());
+        let releases = self.releases.as_mut()?;
+        match pending {
+            Pending::Publish => {
+                if let Some(r) = releases.iter_mut().find(|r| r.id == id) {
+                    r.draft = false;
+                }
+                Some(ReleaseAction::Publish { id, tag })
+            }
+            Pending::Delete => {
+                releases.retain(|r| r.id != id);
+                let len = self.entries().len();
+                self.cursor = self.cursor.min(len.saturating_sub(1));
+                Some(ReleaseAction::Delete { id, tag })
+            }
+        }
+    }
+}
+
+/// Open the overlay and fetch the releases of `owner/repo` on `host`.
+pub(crate) fn open(
+    overlay: &mut State<Option<ReleasesOverlay>>,
+    engine: Option<&EngineHandle>,
+    repo: &str,
+    host: Option<&str>,
+    reply_tx: &EventSender,
+) {
+    let Some((owner, name)) = repo.split_once('/') else {
+        return;
+    };
+    if let Some(engine) = engine {
+        engine.send(Request::FetchReleases {
+            owner: owner.to_owned(),
+            repo: name.to_owned(),
+            host: host.map(str::to_owned),
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    overlay.set(Some(ReleasesOverlay {
+        repo: repo.to_owned(),
+        host: host.map(str::to_owned),
+        ..ReleasesOverlay::default()
+    }));
+}
+
+/// Store the fetched releases of `repo` in the open overlay.
+pub(crate) fn on_fetched(
+    overlay: &mut State<Option<ReleasesOverlay>>,
+    repo: &str,
+    releases: Vec<Release>,
+    tags: Vec<GitTag>,
+) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    if current.repo != repo {
+        return;
+    }
+    current.releases = Some(releases);
+    current.tags = tags;
+    overlay.set(Some(current));
+}
+
+/// Show a failed fetch in the open overlay.
+pub(crate) fn on_error(overlay: &mut State<Option<ReleasesOverlay>>, message: String) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    current.error = Some(message);
+    overlay.set(Some(current));
+}
+
+/// Handle a key while the overlay is open. Every key is consumed; draft
+/// actions are sent to the engine here, the rest returned as feedback to
+/// show or URLs to open or copy.
+pub(crate) fn handle_key(
+    overlay: &mut State<Option<ReleasesOverlay>>,
+    code: KeyCode,
+    engine: Option<&EngineHandle>,
+    reply_tx: &EventSender,
+) -> Option<ReleaseAction> {
+    let mut current = overlay.read().clone()?;
+    if current.confirm.is_none() && matches!(code, KeyCode::Esc | KeyCode::Char('q' | 'L')) {
+        overlay.set(None);
+        return None;
+    }
+    let action = current.key(code);
+    let (owner, repo) = current
+        .repo
+        .split_once('/')
+        .map(|(o, r)| (o.to_owned(), r.to_owned()))
+        .unwrap_or_default();
+    match (&action, engine) {
+        (Some(ReleaseAction::Publish { id, tag }), Some(engine)) => {
+            engine.send(Request::PublishRelease {
+                owner,
+                repo,
+                id: *id,
+                tag: tag.clone(),
+                host: current.host.clone(),
+                reply_tx: reply_tx.clone(),
+            });
+        }
+        (Some(ReleaseAction::Delete { id, tag }), Some(engine)) => {
+            engine.send(Request::DeleteRelease {
+                owner,
+                repo,
+                id: *id,
+                tag: tag.clone(),
+                host: current.host.clone(),
+                reply_tx: reply_tx.clone(),
+            });
+        }
+        _ => {}
+    }
+    overlay.set(Some(current));
+    action
+}
+
+/// Footer feedback of `action`, opening or copying its URL.
+pub(crate) fn feedback(action: ReleaseAction) -> Option<ActionFeedback> {
+    match action {
+        ReleaseAction::Open(url) => Some(match crate::actions::clipboard::open_in_browser(&url) {
+            Ok(how) => ActionFeedback::Success(how.message(&url)),
+            Err(e) => ActionFeedback::Error(format!("Open failed: {e}")),
+        }),
+        ReleaseAction::CopyTarball(url) => {
+            Some(match crate::actions::clipboard::copy_to_clipboard(&url) {
+                Ok(()) => ActionFeedback::Success(format!("Copied {url}")),
+                Err(e) => ActionFeedback::Error(format!("Copy failed: {e}")),
+            })
+        }
+        ReleaseAction::Notice(message) => Some(ActionFeedback::Info(message)),
+        ReleaseAction::Publish { .. } | ReleaseAction::Delete { .. } => None,
+    }
+}
+
+fn entry_label(entry: Entry<'_>) -> String {
+    match entry {
+        Entry::Release(r) => {
+            let mut label = r.tag.clone();
+            if !r.name.is_empty() && r.name != r.tag {
+                label.push_str("  ");
+                label.push_str(&r.name);
+            }
+            match (&r.published_at, r.draft) {
+                (_, true) => label.push_str("  [draft]"),
+                (Some(at), false) => {
+                    label.push_str("  ");
+                    label.push_str(&crate::util::format_local(at, "%Y-%m-%d"));
+                }
+                (None, false) => {}
+            }
+            if r.prerelease {
+                label.push_str("  [pre-release]");
+            }
+            label
+        }
+        Entry::Tag(t) => format!("{}  (tag only)", t.name),
+    }
+}
+
+/// Render the open overlay, if any.
+pub(crate) fn render(
+    overlay: &State<Option<ReleasesOverlay>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let overlay = overlay.read();
+    let current = overlay.as_ref()?;
+    let line = |label: String| SelectionOverlayItem { label };
+    let entries = current.entries();
+    let (items, cursor) = match (&current.error, &current.releases) {
+        (Some(error), _) => (vec![line(format!("Error: {error}"))], usize::MAX),
+        (None, None) => (
+            vec![line(format!(
+                "Loading{}",
+                crate::components::ellipsis(theme.chrome)
+            ))],
+            usize::MAX,
+        ),
+        (None, Some(_)) if entries.is_empty() => {
+            (vec![line("No releases or tags".to_owned())], usize::MAX)
+        }
+        (None, Some(_)) => {
+            let items = entries
+                .iter()
+                .skip(current.offset)
+                .take(VISIBLE_ROWS)
+                .map(|&entry| line(entry_label(entry)))
+                .collect();
+            (items, current.cursor - current.offset)
+        }
+    };
+    let draft_tag = current.selected_draft().map_or("", |r| r.tag.as_str());
+    let (title, hint) = match current.confirm {
+        Some(Pending::Publish) => (format!("Publish draft {draft_tag}?"), "y/n"),
+        Some(Pending::Delete) => (format!("Delete draft {draft_tag}?"), "y/n"),
+        None => (
+            format!("Releases of {}", current.repo),
+            "Enter open  y tarball  P publish  X delete  Esc close",
+        ),
+    };
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title,
+            items,
+            cursor,
+            show_filter: false,
+            filter_text: String::new(),
+            hint: Some(hint.to_owned()),
+            depth,
+            title_color: Some(if current.confirm.is_some() {
+                theme.text_warning
+            } else {
+                theme.text_primary
+            }),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: None,
+            filter_text_color: None,
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(id: u64, tag: &str, draft: bool) -> Release {
+        Release {
+            id,
+            tag: tag.to_owned(),
+            name: String::new(),
+            url: format!("https://github.com/acme/app/releases/tag/{tag}"),
+            tarball_url: (!draft).then(|| format!("https://api.github.com/t/{tag}")),
+            draft,
+            prerelease: false,
+            published_at: None,
+        }
+    }
+
+    fn tag(name: &str) -> GitTag {
+        GitTag {
+            name: name.to_owned(),
+            tarball_url: format!("https://api.github.com/t/{name}"),
+        }
+    }
+
+    #[test]
+    fn drafts_publish_and_delete_after_confirmation() {
+        let mut overlay = ReleasesOverlay {
+            repo: "acme/app".to_owned(),
+            releases: Some(vec![
+                release(3, "v1.2.0", true),
+                release(2, "v1.1.0", false),
+            ]),
+            tags: vec![tag("v1.1.0"), tag("v1.0.0")],
+            ..ReleasesOverlay::default()
+        };
+        let labels: Vec<String> = overlay.entries().into_iter().map(entry_label).collect();
+        assert_eq!(labels, ["v1.2.0  [draft]", "v1.1.0", "v1.0.0  (tag only)"]);
+
+        assert!(matches!(
+            overlay.key(KeyCode::Char('y')),
+            Some(ReleaseAction::Notice(_))
+        ));
+        assert_eq!(overlay.key(KeyCode::Char('P')), None);
+        assert_eq!(overlay.key(KeyCode::Char('n')), None);
+        assert_eq!(overlay.key(KeyCode::Char('X')), None);
+        assert_eq!(
+            overlay.key(KeyCode::Char('y')),
+            Some(ReleaseAction::Delete {
+                id: 3,
+                tag: "v1.2.0".to_owned()
+            })
+        );
+        assert_eq!(overlay.entries().len(), 2);
+
+        assert!(matches!(
+            overlay.key(KeyCode::Char('P')),
+            Some(ReleaseAction::Notice(_))
+        ));
+        overlay.key(KeyCode::Down);
+        assert_eq!(
+            overlay.key(KeyCode::Enter),
+            Some(ReleaseAction::Open(
+                "https://github.com/acme/app/releases/tag/v1.0.0".to_owned()
+            ))
+        );
+        overlay.host = Some("ghe.acme.com".to_owned());
+        assert_eq!(
+            overlay.key(KeyCode::Enter),
+            Some(ReleaseAction::Open(
+                "https://ghe.acme.com/acme/app/releases/tag/v1.0.0".to_owned()
+            ))
+        );
+    }
+}
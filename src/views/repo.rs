@@ -1,18 +1,19 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use iocraft::prelude::*;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use crate::app::{NavigationTarget, ViewKind};
-use crate::color::ColorDepth;
+use crate::app::{NavigationTarget, ViewCounts, ViewKind, WatchBadges};
+use crate::color::{Color as AppColor, ColorDepth};
 use crate::components::footer::{
     self, ActionFeedback, Footer, FooterColors, FooterContent, RenderedFooter,
 };
 use crate::components::help_overlay::{HelpOverlay, HelpOverlayBuildConfig, RenderedHelpOverlay};
+use crate::components::selection_overlay::SelectionOverlay;
 use crate::components::sidebar::{
     RenderedSidebar, Sidebar, SidebarColors, SidebarTab, SidebarTabConfig,
 };
@@ -25,12 +26,16 @@ use crate::config::keybindings::{
     BuiltinAction, MergedBindings, ResolvedBinding, TemplateVars, ViewContext,
     execute_shell_command, expand_template, key_event_to_string,
 };
-use crate::config::types::PrFilter;
+use crate::config::types::{AppConfig, PrFilter, ReleaseNotesConfig};
 use crate::engine::{EngineHandle, Event};
 use crate::icons::ResolvedIcons;
 use crate::markdown::renderer::{StyledLine, StyledSpan};
-use crate::theme::ResolvedTheme;
-use crate::types::{PullRequest, RateLimitInfo};
+use crate::release_notes::{self, LastTag};
+use crate::theme::{Chrome, ResolvedTheme};
+use crate::types::{
+    CommitCheckState, MergeQueue, MergeQueueItem, PullRequest, RateLimits, RepoRef,
+};
+use crate::views::releases::{self, ReleasesOverlay};
 
 /// Sidebar tabs available for branches (subset of `SidebarTab`).
 const BRANCH_TABS: &[SidebarTab] = &[SidebarTab::Overview, SidebarTab::Commits, SidebarTab::Files];
@@ -49,6 +54,8 @@ struct Branch {
     behind: u32,
     worktree_path: Option<PathBuf>,
     repo_label: String,
+    /// Host of the clone's remote, `None` for github.com.
+    host: Option<String>,
 }
 
 /// File changed on a branch vs its merge-base.
@@ -254,6 +261,7 @@ fn list_branches(repo_path: &Path, repo_label: &str) -> Vec<Branch> {
     let default_branch = detect_default_branch(repo_path);
     let origin_default = format!("origin/{default_branch}");
     let worktrees = list_worktrees(repo_path);
+    let host = crate::git::detect_remote(repo_path).and_then(|(_, host)| host);
 
     stdout
         .lines()
@@ -287,6 +295,7 @@ fn list_branches(repo_path: &Path, repo_label: &str) -> Vec<Branch> {
                 behind,
                 worktree_path,
                 repo_label: repo_label.to_owned(),
+                host: host.clone(),
             })
         })
         .collect()
@@ -431,6 +440,7 @@ fn branch_columns(icons: &ResolvedIcons, multi_repo: bool) -> Vec<Column> {
         default_width_pct: 0.03,
         align: TextAlign::Center,
         fixed_width: Some(3),
+        sort: None,
     }];
 
     if multi_repo {
@@ -440,6 +450,7 @@ fn branch_columns(icons: &ResolvedIcons, multi_repo: bool) -> Vec<Column> {
             default_width_pct: 0.14,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: None,
         });
     }
 
@@ -456,6 +467,7 @@ fn branch_columns(icons: &ResolvedIcons, multi_repo: bool) -> Vec<Column> {
             default_width_pct: name_pct,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: None,
         },
         Column {
             id: "pr".to_owned(),
@@ -463,6 +475,7 @@ fn branch_columns(icons: &ResolvedIcons, multi_repo: bool) -> Vec<Column> {
             default_width_pct: 0.06,
             align: TextAlign::Left,
             fixed_width: Some(7),
+            sort: None,
         },
         Column {
             id: "worktree".to_owned(),
@@ -470,6 +483,7 @@ fn branch_columns(icons: &ResolvedIcons, multi_repo: bool) -> Vec<Column> {
             default_width_pct: 0.15,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: None,
         },
         Column {
             id: "ahead_behind".to_owned(),
@@ -477,6 +491,7 @@ fn branch_columns(icons: &ResolvedIcons, multi_repo: bool) -> Vec<Column> {
             default_width_pct: 0.10,
             align: TextAlign::Center,
             fixed_width: Some(10),
+            sort: None,
         },
         Column {
             id: "message".to_owned(),
@@ -484,6 +499,7 @@ fn branch_columns(icons: &ResolvedIcons, multi_repo: bool) -> Vec<Column> {
             default_width_pct: message_pct,
             align: TextAlign::Left,
             fixed_width: None,
+            sort: None,
         },
         Column {
             id: "updated".to_owned(),
@@ -491,6 +507,7 @@ fn branch_columns(icons: &ResolvedIcons, multi_repo: bool) -> Vec<Column> {
             default_width_pct: 0.12,
             align: TextAlign::Right,
             fixed_width: Some(8),
+            sort: None,
         },
     ]);
 
@@ -510,6 +527,15 @@ fn filtered_branch_at(
     }
 }
 
+/// Host of `repo` (`owner/name`) as its clone's remote names it, `None` for
+/// github.com or a repo without a clone here.
+fn repo_host(branches: &[Branch], repo: &str) -> Option<String> {
+    branches
+        .iter()
+        .find(|b| b.repo_label == repo)
+        .and_then(|b| b.host.clone())
+}
+
 /// Composite key for the PR map: `"{repo_label}\0{branch_name}"`.
 fn pr_map_key(repo_label: &str, branch_name: &str) -> String {
     format!("{repo_label}\0{branch_name}")
@@ -602,6 +628,270 @@ enum InputMode {
     ConfirmDelete,
     ConfirmWorktree,
     CreateBranch,
+    /// Create the edited release-notes draft as a draft release?
+    ConfirmRelease,
+}
+
+// ---------------------------------------------------------------------------
+// Release notes
+// ---------------------------------------------------------------------------
+
+/// `filter_idx` of the merged-PR search behind a release-notes draft, told
+/// apart from the open-PR fetches (index 0).
+const RELEASE_NOTES_FETCH: usize = usize::MAX;
+
+/// Release notes being gathered for `repo` (`owner/repo`).
+#[derive(Debug, Clone)]
+struct ReleaseJob {
+    repo: String,
+    last_tag: Option<LastTag>,
+}
+
+/// Release notes edited and waiting for the `y/n` on the draft release.
+#[derive(Debug, Clone)]
+struct ReleaseDraft {
+    repo: String,
+    tag: String,
+    body: String,
+    path: PathBuf,
+}
+
+/// Write the draft for `job` from the merged `prs`, open it in the editor and
+/// read back the tag and body the user saved.
+fn edit_release_notes(
+    job: &ReleaseJob,
+    prs: &[PullRequest],
+    config: &ReleaseNotesConfig,
+) -> Result<ReleaseDraft, ActionFeedback> {
+    let since = job.last_tag.as_ref().map(|t| t.name.as_str());
+    if prs.is_empty() {
+        return Err(ActionFeedback::Info(format!(
+            "No PRs merged in {} since {}",
+            job.repo,
+            since.unwrap_or("the first commit")
+        )));
+    }
+    let path = release_notes::draft_path(&job.repo).ok_or_else(|| {
+        ActionFeedback::Error("No state directory to write the draft to".to_owned())
+    })?;
+    let text = release_notes::draft(&job.repo, since, prs, config);
+    crate::util::write_atomic(&path, &text)
+        .map_err(|e| ActionFeedback::Error(format!("Writing {}: {e}", path.display())))?;
+    crate::terminal::edit_file(&path).map_err(|e| ActionFeedback::Error(format!("{e:#}")))?;
+    let saved = std::fs::read_to_string(&path)
+        .map_err(|e| ActionFeedback::Error(format!("Reading {}: {e}", path.display())))?;
+    let (tag, body) = release_notes::parse_draft(&saved).ok_or_else(|| {
+        ActionFeedback::Warning(format!(
+            "No `# <tag>` first line, no release created: draft kept in {}",
+            path.display()
+        ))
+    })?;
+    Ok(ReleaseDraft {
+        repo: job.repo.clone(),
+        tag,
+        body,
+        path,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Merge queue tab
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RepoTab {
+    Branches,
+    MergeQueue,
+}
+
+/// Merge queue of the scoped repo's default branch.
+#[derive(Debug, Clone)]
+enum QueueLoad {
+    Loading,
+    /// The default branch has no merge queue.
+    Disabled,
+    Loaded(MergeQueue),
+    Failed(String),
+}
+
+fn queue_columns() -> Vec<Column> {
+    vec![
+        Column {
+            id: "position".to_owned(),
+            header: "#".to_owned(),
+            default_width_pct: 0.04,
+            align: TextAlign::Right,
+            fixed_width: Some(4),
+            sort: None,
+        },
+        Column {
+            id: "pr".to_owned(),
+            header: "PR".to_owned(),
+            default_width_pct: 0.06,
+            align: TextAlign::Left,
+            fixed_width: Some(7),
+            sort: None,
+        },
+        Column {
+            id: "title".to_owned(),
+            header: "Title".to_owned(),
+            default_width_pct: 0.40,
+            align: TextAlign::Left,
+            fixed_width: None,
+            sort: None,
+        },
+        Column {
+            id: "author".to_owned(),
+            header: "Author".to_owned(),
+            default_width_pct: 0.14,
+            align: TextAlign::Left,
+            fixed_width: None,
+            sort: None,
+        },
+        Column {
+            id: "state".to_owned(),
+            header: "State".to_owned(),
+            default_width_pct: 0.14,
+            align: TextAlign::Left,
+            fixed_width: Some(16),
+            sort: None,
+        },
+        Column {
+            id: "ci".to_owned(),
+            header: "CI".to_owned(),
+            default_width_pct: 0.04,
+            align: TextAlign::Center,
+            fixed_width: Some(4),
+            sort: None,
+        },
+        Column {
+            id: "eta".to_owned(),
+            header: "ETA".to_owned(),
+            default_width_pct: 0.06,
+            align: TextAlign::Right,
+            fixed_width: Some(6),
+            sort: None,
+        },
+        Column {
+            id: "queued".to_owned(),
+            header: "Queued".to_owned(),
+            default_width_pct: 0.12,
+            align: TextAlign::Right,
+            fixed_width: Some(8),
+            sort: None,
+        },
+    ]
+}
+
+fn queue_item_to_row(item: &MergeQueueItem, theme: &ResolvedTheme, date_format: &str) -> Row {
+    let mut row = HashMap::new();
+    row.insert(
+        "position".to_owned(),
+        Cell::colored(item.entry.position.to_string(), theme.text_secondary),
+    );
+    row.insert(
+        "pr".to_owned(),
+        Cell::colored(format!("#{}", item.number), theme.text_success),
+    );
+    row.insert(
+        "title".to_owned(),
+        Cell::colored(crate::util::expand_emoji(&item.title), theme.text_primary),
+    );
+    row.insert(
+        "author".to_owned(),
+        Cell::colored(item.author.clone().unwrap_or_default(), theme.text_actor),
+    );
+    row.insert(
+        "state".to_owned(),
+        Cell::colored(item.entry.state.label(), theme.text_secondary),
+    );
+    let (ci_icon, ci_color) = queue_ci_icon(item.ci, theme);
+    row.insert("ci".to_owned(), Cell::colored(ci_icon, ci_color));
+    let eta = item
+        .entry
+        .estimated_secs
+        .map(|secs| format!("~{}m", secs.div_ceil(60)))
+        .unwrap_or_default();
+    row.insert("eta".to_owned(), Cell::colored(eta, theme.text_faint));
+    let queued = item
+        .enqueued_at
+        .as_ref()
+        .map(|dt| crate::util::format_date(dt, date_format))
+        .unwrap_or_default();
+    row.insert("queued".to_owned(), Cell::colored(queued, theme.text_faint));
+    row
+}
+
+fn queue_ci_icon(state: Option<CommitCheckState>, theme: &ResolvedTheme) -> (String, AppColor) {
+    let icons = &theme.icons;
+    match state {
+        Some(CommitCheckState::Success) => (icons.ci_success.clone(), theme.text_success),
+        Some(CommitCheckState::Failure | CommitCheckState::Error) => {
+            (icons.ci_failure.clone(), theme.text_error)
+        }
+        Some(CommitCheckState::Pending | CommitCheckState::Expected) => {
+            (icons.ci_pending.clone(), theme.text_warning)
+        }
+        Some(CommitCheckState::Unknown) | None => (icons.ci_none.clone(), theme.text_faint),
+    }
+}
+
+/// Open, jump to or copy the PR of a merge queue entry.
+fn queue_item_action(
+    action: BuiltinAction,
+    item: &MergeQueueItem,
+    repo: Option<&str>,
+    host: Option<&str>,
+    nav_target: Option<State<Option<NavigationTarget>>>,
+    mut action_status: State<Option<ActionFeedback>>,
+    mut status_set_at: State<Option<std::time::Instant>>,
+) {
+    let number = item.number;
+    let result = match action {
+        BuiltinAction::JumpToPr => {
+            if let Some(mut nt) = nav_target
+                && let Some((owner, name)) = repo.and_then(|r| r.split_once('/'))
+            {
+                nt.set(Some(NavigationTarget::PullRequest {
+                    owner: owner.to_owned(),
+                    repo: name.to_owned(),
+                    number,
+                    host: host.map(str::to_owned),
+                }));
+            }
+            return;
+        }
+        BuiltinAction::OpenBrowser => crate::actions::clipboard::open_in_browser(&item.url)
+            .map(|how| how.message(&format!("#{number}")))
+            .map_err(|e| format!("Failed to open browser: {e}")),
+        BuiltinAction::CopyNumber => {
+            crate::actions::clipboard::copy_to_clipboard(&number.to_string())
+                .map(|()| format!("Copied #{number}"))
+                .map_err(|e| format!("Copy failed: {e}"))
+        }
+        BuiltinAction::CopyUrl => crate::actions::clipboard::copy_to_clipboard(&item.url)
+            .map(|()| format!("Copied URL for #{number}"))
+            .map_err(|e| format!("Copy failed: {e}")),
+        _ => return,
+    };
+    action_status.set(Some(match result {
+        Ok(msg) => ActionFeedback::Success(msg),
+        Err(msg) => ActionFeedback::Error(msg),
+    }));
+    status_set_at.set(Some(std::time::Instant::now()));
+}
+
+/// Table placeholder for the merge queue tab when there is no entry to show.
+fn queue_empty_message(repo: Option<&str>, load: Option<&QueueLoad>) -> String {
+    let Some(repo) = repo else {
+        return "Scope a repo (S) to see its merge queue".to_owned();
+    };
+    match load {
+        None | Some(QueueLoad::Loading) => format!("Loading the merge queue of {repo}..."),
+        Some(QueueLoad::Disabled) => format!("{repo} has no merge queue on its default branch"),
+        Some(QueueLoad::Loaded(q)) => format!("The {} merge queue is empty", q.branch),
+        Some(QueueLoad::Failed(e)) => format!("Failed to load the merge queue: {e}"),
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -610,9 +900,11 @@ enum InputMode {
 
 #[derive(Default, Props)]
 pub struct RepoViewProps<'a> {
-    pub theme: Option<&'a ResolvedTheme>,
+    /// Config, replaced on each reload.
+    pub config: Option<Arc<AppConfig>>,
+    pub theme: Option<Arc<ResolvedTheme>>,
     /// Merged keybindings for help overlay.
-    pub keybindings: Option<&'a MergedBindings>,
+    pub keybindings: Option<Arc<MergedBindings>>,
     pub color_depth: ColorDepth,
     pub width: u16,
     pub height: u16,
@@ -627,42 +919,58 @@ pub struct RepoViewProps<'a> {
     pub goto_view: Option<State<Option<ViewKind>>>,
     /// Signal to toggle repo scope.
     pub scope_toggle: Option<State<bool>>,
+    /// Signal to toggle relative/absolute dates.
+    pub date_toggle: Option<State<bool>>,
+    /// Signal to open the log viewer.
+    pub show_logs: Option<State<bool>>,
     /// Signal to open the repo picker overlay.
     pub repo_picker: Option<State<bool>>,
+    /// Signal to scope to the repo worked in.
+    pub rescan_repo: Option<State<bool>>,
     /// Active scope repo (e.g. `"owner/repo"`), or `None` for global.
     pub scope_repo: Option<String>,
     pub repo_path: Option<&'a std::path::Path>,
     /// Detected repo (owner/name) from CWD remote.
     pub detected_repo: Option<&'a crate::types::common::RepoRef>,
-    /// Configured `repo_paths` mapping `"owner/repo"` to local paths.
-    pub repo_paths: Option<&'a indexmap::IndexMap<String, PathBuf>>,
+    /// Host of the detected repo, `None` for github.com.
+    pub detected_host: Option<&'a str>,
     /// Engine handle for async PR data fetching (optional).
     pub engine: Option<&'a EngineHandle>,
     /// Navigation target state for cross-view deep-linking.
     pub nav_target: Option<State<Option<NavigationTarget>>>,
-    pub date_format: Option<&'a str>,
     /// Whether this view is the currently active (visible) one.
     pub is_active: bool,
     /// Auto-refetch interval in minutes (0 = disabled).
     pub refetch_interval_minutes: u32,
     /// Shared rate-limit state (owned by App).
-    pub rate_limit: Option<State<Option<RateLimitInfo>>>,
+    pub rate_limit: Option<State<RateLimits>>,
+    /// Shared watched-item badges (owned by App).
+    pub watch_badges: Option<State<WatchBadges>>,
+    /// Shared footer counts of the views (owned by App).
+    pub view_counts: Option<State<ViewCounts>>,
 }
 
 #[component]
 pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
-    let theme = props.theme.cloned().unwrap_or_else(default_theme);
+    let theme = props
+        .theme
+        .as_deref()
+        .cloned()
+        .unwrap_or_else(default_theme);
     let depth = props.color_depth;
     let should_exit = props.should_exit;
     let switch_view = props.switch_view;
     let switch_view_back = props.switch_view_back;
     let goto_view = props.goto_view;
     let scope_toggle = props.scope_toggle;
+    let date_toggle = props.date_toggle;
+    let show_logs = props.show_logs;
     let repo_picker = props.repo_picker;
+    let rescan_repo = props.rescan_repo;
     let scope_repo = &props.scope_repo;
     let detected_repo = props.detected_repo.cloned();
     let nav_target = props.nav_target;
-    let date_format = props.date_format.unwrap_or("relative");
+    let date_format = super::common::date_format(props.config.as_deref());
     let is_active = props.is_active;
     let width = props.width;
 
@@ -698,29 +1006,102 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
     let mut pr_map = hooks.use_state(HashMap::<String, PullRequest>::new);
     let mut pr_repos_fetched = hooks.use_state(HashSet::<String>::new);
 
+    // Merge queue tab, keyed by the `owner/repo` it was loaded for.
+    let mut repo_tab = hooks.use_state(|| RepoTab::Branches);
+    let mut queue_state = hooks.use_state(|| Option::<(String, QueueLoad)>::None);
+
+    // Release notes: the merged-PR search in flight, then the edited draft.
+    let mut release_job = hooks.use_state(|| Option::<ReleaseJob>::None);
+    let mut release_draft = hooks.use_state(|| Option::<ReleaseDraft>::None);
+    let mut releases_overlay = hooks.use_state(|| None::<ReleasesOverlay>);
+    let release_config = props
+        .config
+        .as_deref()
+        .map(|c| c.release_notes.clone())
+        .unwrap_or_default();
+    // Printing nothing makes the next render redraw the whole screen, which
+    // comes back blank from the editor.
+    let (stdout, _) = hooks.use_output();
+
     // Rate-limit info from engine responses.
-    let fallback_rl = hooks.use_state(|| None);
+    let fallback_rl = hooks.use_state(RateLimits::default);
     let mut rate_limit_state = props.rate_limit.unwrap_or(fallback_rl);
 
     // Per-view event channel for engine replies.
-    let event_channel = hooks.use_state(|| {
-        let (tx, rx) = std::sync::mpsc::channel::<Event>();
-        (tx, Arc::new(Mutex::new(rx)))
-    });
-    let (event_tx, event_rx_arc) = event_channel.read().clone();
+    let event_channel = hooks.use_state(super::common::new_event_channel);
+    let (event_tx, event_rx) = event_channel.read().clone();
 
-    // Poll engine events (PR data).
+    // Handle engine events (PR data) as they arrive.
     {
-        let rx_for_poll = event_rx_arc.clone();
+        let rx_for_poll = event_rx.clone();
         hooks.use_future(async move {
-            loop {
-                smol::Timer::after(std::time::Duration::from_millis(100)).await;
-                let rx = rx_for_poll.lock().unwrap();
-                while let Ok(ev) = rx.try_recv() {
-                    if let Event::PrsFetched {
-                        prs, rate_limit, ..
-                    } = ev
+            while let Ok(ev) = rx_for_poll.recv().await {
+                match ev {
+                    Event::PrsFetched {
+                        filter_idx: RELEASE_NOTES_FETCH,
+                        prs,
+                        rate_limit,
+                        complete,
+                        ..
+                    } => {
+                        super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                        let job = release_job.read().clone();
+                        let Some(job) = job.filter(|_| complete) else {
+                            continue;
+                        };
+                        release_job.set(None);
+                        let draft = edit_release_notes(&job, &prs, &release_config);
+                        stdout.print("");
+                        match draft {
+                            Ok(draft) => {
+                                release_draft.set(Some(draft));
+                                input_mode.set(InputMode::ConfirmRelease);
+                                action_status.set(None);
+                            }
+                            Err(feedback) => {
+                                action_status.set(Some(feedback));
+                                status_set_at.set(Some(std::time::Instant::now()));
+                            }
+                        }
+                    }
+                    Event::FetchTimedOut {
+                        filter_idx: RELEASE_NOTES_FETCH,
+                        secs,
+                    } => {
+                        release_job.set(None);
+                        action_status.set(Some(ActionFeedback::Error(format!(
+                            "Gathering merged PRs timed out after {secs}s"
+                        ))));
+                        status_set_at.set(Some(std::time::Instant::now()));
+                    }
+                    Event::FetchCancelled {
+                        filter_idx: RELEASE_NOTES_FETCH,
+                    } => release_job.set(None),
+                    Event::FetchError { context, message }
+                        if context == format!("FetchPrs[{RELEASE_NOTES_FETCH}]") =>
                     {
+                        release_job.set(None);
+                        action_status.set(Some(ActionFeedback::Error(format!(
+                            "Gathering merged PRs: {message}"
+                        ))));
+                        status_set_at.set(Some(std::time::Instant::now()));
+                    }
+                    Event::MutationOk { description } => {
+                        action_status.set(Some(ActionFeedback::Success(description)));
+                        status_set_at.set(Some(std::time::Instant::now()));
+                    }
+                    Event::MutationError {
+                        description,
+                        message,
+                    } => {
+                        action_status.set(Some(ActionFeedback::Error(format!(
+                            "{description}: {message}"
+                        ))));
+                        status_set_at.set(Some(std::time::Instant::now()));
+                    }
+                    Event::PrsFetched {
+                        prs, rate_limit, ..
+                    } => {
                         super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
                         let mut map = pr_map.read().clone();
                         for pr in prs {
@@ -737,6 +1118,37 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                         }
                         pr_map.set(map);
                     }
+                    Event::ReleasesFetched {
+                        repo,
+                        releases: found,
+                        tags,
+                    } => releases::on_fetched(&mut releases_overlay, &repo, found, tags),
+                    Event::FetchError { context, message }
+                        if context == releases::FETCH_CONTEXT =>
+                    {
+                        releases::on_error(&mut releases_overlay, message);
+                    }
+                    Event::MergeQueueFetched {
+                        owner,
+                        repo,
+                        queue,
+                        rate_limit,
+                    } => {
+                        super::common::update_rate_limit(&mut rate_limit_state, rate_limit);
+                        let slug = format!("{owner}/{repo}");
+                        // Drop replies for a repo the tab no longer shows.
+                        if queue_state.read().as_ref().is_some_and(|(r, _)| *r == slug) {
+                            let load = queue.map_or(QueueLoad::Disabled, QueueLoad::Loaded);
+                            queue_state.set(Some((slug, load)));
+                        }
+                    }
+                    Event::FetchError { context, message } if context == "FetchMergeQueue" => {
+                        let loading = queue_state.read().clone();
+                        if let Some((slug, QueueLoad::Loading)) = loading {
+                            queue_state.set(Some((slug, QueueLoad::Failed(message))));
+                        }
+                    }
+                    _ => {}
                 }
             }
         });
@@ -751,20 +1163,10 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
         }
     });
 
-    // Auto-clear action status after 60 seconds.
-    {
-        hooks.use_future(async move {
-            loop {
-                smol::Timer::after(std::time::Duration::from_secs(1)).await;
-                if let Some(t) = status_set_at.get()
-                    && t.elapsed().as_secs() >= 60
-                {
-                    action_status.set(None);
-                    status_set_at.set(None);
-                }
-            }
-        });
-    }
+    hooks.use_future(super::common::expire_action_status(
+        action_status,
+        status_set_at,
+    ));
 
     // Auto-refetch if interval has elapsed (only for already-visited views).
     let refetch_interval = props.refetch_interval_minutes;
@@ -777,6 +1179,7 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
         loaded.set(false);
         pr_repos_fetched.set(HashSet::new());
         pr_map.set(HashMap::new());
+        queue_state.set(None);
     }
 
     // Compute CWD repo label.
@@ -792,14 +1195,17 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
         crate::types::common::RepoRef::full_name,
     );
 
-    let multi_repo = props.repo_paths.is_some_and(|m| !m.is_empty());
+    let multi_repo = props
+        .config
+        .as_deref()
+        .is_some_and(|c| !c.repo_paths.is_empty());
 
     if !loaded.get() && is_active {
         loaded.set(true);
         branches_state.set(list_all_branches(
             props.repo_path,
             &cwd_label,
-            props.repo_paths,
+            props.config.as_deref().map(|c| &c.repo_paths),
         ));
         last_fetch_time.set(Some(std::time::Instant::now()));
 
@@ -823,9 +1229,14 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                 let filter = PrFilter {
                     title: repo_label.clone(),
                     filters: format!("repo:{repo_label} is:pr is:open"),
-                    host: None,
+                    host: repo_host(&branches_state.read(), repo_label),
                     limit: Some(50),
                     layout: None,
+                    hide_drafts: None,
+                    preview: None,
+                    preview_tab: None,
+                    refetch_interval_minutes: None,
+                    badge: None,
                 };
                 // filter_idx is ignored in the repo view polling loop;
                 // all PR events are merged into pr_map by head_ref key.
@@ -841,6 +1252,37 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
         }
     }
 
+    // The merge queue tab follows the scoped repo, else the CWD repo.
+    let queue_tab = repo_tab.get() == RepoTab::MergeQueue;
+    let queue_repo: Option<String> = scope_repo
+        .clone()
+        .or_else(|| detected_repo.as_ref().map(RepoRef::full_name));
+    let queue_host = queue_repo
+        .as_deref()
+        .and_then(|slug| repo_host(&branches_state.read(), slug));
+    if queue_tab
+        && is_active
+        && let Some(slug) = &queue_repo
+        && queue_state.read().as_ref().is_none_or(|(r, _)| r != slug)
+        && let Some(engine) = props.engine
+        && let Some((owner, name)) = slug.split_once('/')
+    {
+        queue_state.set(Some((slug.clone(), QueueLoad::Loading)));
+        engine.send(crate::engine::Request::FetchMergeQueue {
+            owner: owner.to_owned(),
+            repo: name.to_owned(),
+            host: queue_host.clone(),
+            reply_tx: event_tx.clone(),
+        });
+    }
+    let (queue_loaded, queue_entries): (bool, Vec<MergeQueueItem>) =
+        match queue_state.read().as_ref() {
+            Some((r, QueueLoad::Loaded(q))) if queue_repo.as_ref() == Some(r) => {
+                (true, q.entries.clone())
+            }
+            _ => (false, Vec::new()),
+        };
+
     let branches = branches_state.read();
 
     // Apply scope filter.
@@ -849,15 +1291,25 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
     } else {
         branches.iter().collect()
     };
-    let total_rows = branches.len();
+    let total_rows = if queue_tab {
+        queue_entries.len()
+    } else {
+        branches.len()
+    };
     let visible_rows = (props.height.saturating_sub(5) / 2).max(1) as usize;
 
     // Keyboard handling.
     let repo_path_owned = props.repo_path.map(std::borrow::ToOwned::to_owned);
-    let keybindings = props.keybindings.cloned();
+    let keybindings = props.keybindings.as_deref().cloned();
     let scope_repo_owned = scope_repo.clone();
     let cwd_label_owned = cwd_label.clone();
-    let repo_paths_owned = props.repo_paths.cloned();
+    let repo_paths_owned = props.config.as_deref().map(|c| c.repo_paths.clone());
+    let queue_repo_owned = queue_repo.clone();
+    let detected_host = props.detected_host.map(str::to_owned);
+    let queue_entries_owned = queue_entries.clone();
+    let engine_for_keys = props.engine.cloned();
+    let event_tx_for_keys = event_tx.clone();
+    let chrome = theme.chrome;
     hooks.use_terminal_events({
         move |event| match event {
             TerminalEvent::Key(KeyEvent {
@@ -877,6 +1329,19 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                     }
                     return;
                 }
+                if releases_overlay.read().is_some() {
+                    let action = releases::handle_key(
+                        &mut releases_overlay,
+                        code,
+                        engine_for_keys.as_ref(),
+                        &event_tx_for_keys,
+                    );
+                    if let Some(feedback) = action.and_then(releases::feedback) {
+                        action_status.set(Some(feedback));
+                        status_set_at.set(Some(std::time::Instant::now()));
+                    }
+                    return;
+                }
                 let current_mode = input_mode.read().clone();
 
                 let reload = |state: &mut State<Vec<Branch>>| {
@@ -940,7 +1405,12 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                                         .cloned()
                                 };
                                 if let Some(repo_path) = path {
-                                    match crate::actions::local::create_worktree_at(&branch.name, &repo_path, "github.com", None) {
+                                    match crate::actions::local::create_worktree_at(
+                                        &branch.name,
+                                        &repo_path,
+                                        branch.host.as_deref().unwrap_or("github.com"),
+                                        None,
+                                    ) {
                                         Ok(wt_path) => {
                                             let msg = match crate::actions::clipboard::copy_to_clipboard(&wt_path) {
                                                 Ok(()) => format!("Worktree ready (copied): {wt_path}"),
@@ -966,6 +1436,47 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                         }
                         _ => {}
                     },
+                    InputMode::ConfirmRelease => {
+                        let draft = release_draft.read().clone();
+                        match code {
+                            KeyCode::Char('y' | 'Y') => {
+                                if let Some(draft) = draft
+                                    && let Some((owner, name)) = draft.repo.split_once('/')
+                                    && let Some(ref engine) = engine_for_keys
+                                {
+                                    engine.send(crate::engine::Request::CreateDraftRelease {
+                                        owner: owner.to_owned(),
+                                        repo: name.to_owned(),
+                                        name: draft.tag.clone(),
+                                        tag: draft.tag.clone(),
+                                        host: repo_host(&branches_state.read(), &draft.repo),
+                                        body: draft.body,
+                                        reply_tx: event_tx_for_keys.clone(),
+                                    });
+                                    action_status.set(Some(ActionFeedback::Info(format!(
+                                        "Creating draft release {}{}",
+                                        draft.tag,
+                                        crate::components::ellipsis(chrome)
+                                    ))));
+                                    status_set_at.set(Some(std::time::Instant::now()));
+                                }
+                                release_draft.set(None);
+                                input_mode.set(InputMode::Normal);
+                            }
+                            KeyCode::Char('n' | 'N') | KeyCode::Esc => {
+                                if let Some(draft) = draft {
+                                    action_status.set(Some(ActionFeedback::Info(format!(
+                                        "Draft kept in {}",
+                                        draft.path.display()
+                                    ))));
+                                    status_set_at.set(Some(std::time::Instant::now()));
+                                }
+                                release_draft.set(None);
+                                input_mode.set(InputMode::Normal);
+                            }
+                            _ => {}
+                        }
+                    }
                     InputMode::CreateBranch => match code {
                         KeyCode::Enter => {
                             let name = input_buffer.read().clone();
@@ -1024,6 +1535,58 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                                             exit.set(true);
                                         }
                                     }
+                                    BuiltinAction::PrevFilter | BuiltinAction::NextFilter => {
+                                        repo_tab.set(if queue_tab {
+                                            RepoTab::Branches
+                                        } else {
+                                            RepoTab::MergeQueue
+                                        });
+                                        cursor.set(0);
+                                        scroll_offset.set(0);
+                                        preview_scroll.set(0);
+                                    }
+                                    BuiltinAction::RefreshItem
+                                    | BuiltinAction::Refresh
+                                    | BuiltinAction::RefreshAll
+                                        if queue_tab =>
+                                    {
+                                        queue_state.set(None);
+                                        action_status.set(None);
+                                    }
+                                    BuiltinAction::OpenBrowser
+                                    | BuiltinAction::JumpToPr
+                                    | BuiltinAction::CopyNumber
+                                    | BuiltinAction::CopyUrl
+                                        if queue_tab =>
+                                    {
+                                        if let Some(item) = queue_entries_owned.get(cursor.get()) {
+                                            queue_item_action(
+                                                action,
+                                                item,
+                                                queue_repo_owned.as_deref(),
+                                                queue_host.as_deref(),
+                                                nav_target,
+                                                action_status,
+                                                status_set_at,
+                                            );
+                                        }
+                                    }
+                                    BuiltinAction::Checkout
+                                    | BuiltinAction::Worktree
+                                    | BuiltinAction::DeleteBranch
+                                    | BuiltinAction::NewBranch
+                                    | BuiltinAction::CreatePrFromBranch
+                                    | BuiltinAction::ViewPrsForBranch
+                                    | BuiltinAction::ReleaseNotes
+                                    | BuiltinAction::Releases
+                                    | BuiltinAction::TogglePreview
+                                        if queue_tab =>
+                                    {
+                                        action_status.set(Some(ActionFeedback::Info(
+                                            "Branch actions are on the Branches tab".to_owned(),
+                                        )));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::SwitchView => {
                                         if let Some(mut sv) = switch_view {
                                             sv.set(true);
@@ -1044,11 +1607,30 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                                             st.set(true);
                                         }
                                     }
+                                    BuiltinAction::ShowLogs => {
+                                        if let Some(mut sl) = show_logs {
+                                            sl.set(true);
+                                        }
+                                    }
+                                    BuiltinAction::ToggleDates => {
+                                        if let Some(mut dt) = date_toggle {
+                                            dt.set(true);
+                                        }
+                                    }
+                                    BuiltinAction::AuthorizeSso => {
+                                        action_status.set(Some(super::common::authorize_sso()));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::SelectRepo => {
                                         if let Some(mut rp) = repo_picker {
                                             rp.set(true);
                                         }
                                     }
+                                    BuiltinAction::RescanRepo => {
+                                        if let Some(mut rr) = rescan_repo {
+                                            rr.set(true);
+                                        }
+                                    }
                                     BuiltinAction::Checkout => {
                                         if let Some(ref repo_path) = repo_path_owned {
                                             let branch_name = filtered_branch_at(&branches_state, scope_repo_owned.as_deref(), cursor.get())
@@ -1097,6 +1679,92 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                                         input_mode.set(InputMode::ConfirmDelete);
                                         action_status.set(None);
                                     }
+                                    BuiltinAction::Releases => {
+                                        let repo = filtered_branch_at(
+                                            &branches_state, scope_repo_owned.as_deref(), cursor.get()
+                                        )
+                                        .map(|b| b.repo_label)
+                                        .filter(|label| label.contains('/'))
+                                        .or_else(|| scope_repo_owned.clone());
+                                        if let Some(repo) = repo {
+                                            releases::open(
+                                                &mut releases_overlay,
+                                                engine_for_keys.as_ref(),
+                                                &repo,
+                                                repo_host(&branches_state.read(), &repo).as_deref(),
+                                                &event_tx_for_keys,
+                                            );
+                                        } else {
+                                            action_status.set(Some(ActionFeedback::Info(
+                                                "No GitHub repo to list releases of".to_owned(),
+                                            )));
+                                            status_set_at.set(Some(std::time::Instant::now()));
+                                        }
+                                    }
+                                    BuiltinAction::ReleaseNotes => {
+                                        let branch = filtered_branch_at(
+                                            &branches_state, scope_repo_owned.as_deref(), cursor.get()
+                                        );
+                                        let path = branch.as_ref().and_then(|b| {
+                                            if b.repo_label == cwd_label_owned {
+                                                repo_path_owned.clone()
+                                            } else {
+                                                repo_paths_owned.as_ref()?.get(&b.repo_label).cloned()
+                                            }
+                                        });
+                                        let feedback = match (branch, path) {
+                                            _ if release_job.read().is_some() => ActionFeedback::Info(
+                                                "Already gathering release notes".to_owned()
+                                            ),
+                                            (Some(branch), Some(path)) if branch.repo_label.contains('/') => {
+                                                match release_notes::last_tag(&path) {
+                                                    Ok(last_tag) => {
+                                                        let repo = branch.repo_label;
+                                                        let since = last_tag.as_ref().map_or_else(
+                                                            || "the first commit".to_owned(),
+                                                            |t| t.name.clone(),
+                                                        );
+                                                        if let Some(ref engine) = engine_for_keys {
+                                                            engine.send(crate::engine::Request::FetchPrs {
+                                                                filter_idx: RELEASE_NOTES_FETCH,
+                                                                filter: PrFilter {
+                                                                    title: format!("{repo} release notes"),
+                                                                    filters: release_notes::merged_since_query(
+                                                                        &repo,
+                                                                        last_tag.as_ref(),
+                                                                    ),
+                                                                    host: branch.host.clone(),
+                                                                    limit: Some(500),
+                                                                    layout: None,
+                                                                    hide_drafts: None,
+                                                                    preview: None,
+                                                                    preview_tab: None,
+                                                                    refetch_interval_minutes: None,
+                                                                    badge: None,
+                                                                },
+                                                                force: true,
+                                                                reply_tx: event_tx_for_keys.clone(),
+                                                            });
+                                                        }
+                                                        let msg = format!(
+                                                            "Gathering PRs merged in {repo} since {since}{}",
+                                                            crate::components::ellipsis(chrome)
+                                                        );
+                                                        release_job.set(Some(ReleaseJob { repo, last_tag }));
+                                                        ActionFeedback::Info(msg)
+                                                    }
+                                                    Err(e) => ActionFeedback::Error(format!(
+                                                        "Release notes: {e:#}"
+                                                    )),
+                                                }
+                                            }
+                                            _ => ActionFeedback::Warning(
+                                                "Release notes need a local clone of a GitHub repo".to_owned()
+                                            ),
+                                        };
+                                        action_status.set(Some(feedback));
+                                        status_set_at.set(Some(std::time::Instant::now()));
+                                    }
                                     BuiltinAction::NewBranch => {
                                         input_mode.set(InputMode::CreateBranch);
                                         input_buffer.set(String::new());
@@ -1217,13 +1885,14 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                                     BuiltinAction::CreatePrFromBranch => {
                                         if let Some(ref repo) = detected_repo {
                                             let url = format!(
-                                                "https://github.com/{}/compare/{current_branch}?expand=1",
+                                                "https://{}/{}/compare/{current_branch}?expand=1",
+                                                detected_host.as_deref().unwrap_or("github.com"),
                                                 repo.full_name(),
                                             );
                                             match crate::actions::clipboard::open_in_browser(&url) {
-                                                Ok(()) => {
-                                                    action_status.set(Some(ActionFeedback::Success(format!(
-                                                        "Opened PR creation for {current_branch}"
+                                                Ok(how) => {
+                                                    action_status.set(Some(ActionFeedback::Success(how.message(
+                                                        &format!("PR creation for {current_branch}"),
                                                     ))));
                                                     status_set_at.set(Some(std::time::Instant::now()));
                                                 }
@@ -1254,7 +1923,7 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                                                         owner: repo_ref.owner.clone(),
                                                         repo: repo_ref.name.clone(),
                                                         number: pr.number,
-                                                        host: None,
+                                                        host: branch.host.clone(),
                                                     }));
                                                 }
                                             } else {
@@ -1269,13 +1938,14 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                                     BuiltinAction::ViewPrsForBranch => {
                                         if let Some(ref repo) = detected_repo {
                                             let url = format!(
-                                                "https://github.com/{}/pulls?q=is%3Apr+head%3A{current_branch}",
+                                                "https://{}/{}/pulls?q=is%3Apr+head%3A{current_branch}",
+                                                detected_host.as_deref().unwrap_or("github.com"),
                                                 repo.full_name(),
                                             );
                                             match crate::actions::clipboard::open_in_browser(&url) {
-                                                Ok(()) => {
-                                                    action_status.set(Some(ActionFeedback::Success(format!(
-                                                        "Opened PRs for {current_branch}"
+                                                Ok(how) => {
+                                                    action_status.set(Some(ActionFeedback::Success(how.message(
+                                                        &format!("PRs for {current_branch}"),
                                                     ))));
                                                     status_set_at.set(Some(std::time::Instant::now()));
                                                 }
@@ -1294,6 +1964,13 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                                     let expanded = expand_template(&cmd, &vars);
                                     let _ = execute_shell_command(&expanded);
                                 }
+                                Some(ResolvedBinding::Script(path)) => {
+                                    if let Some(feedback) =
+                                        crate::script::run(&path, "repo", &vars, engine_for_keys.clone(), chrome)
+                                    {
+                                        action_status.set(Some(feedback));
+                                    }
+                                }
                                 None => {
                                     if key_str == "]" {
                                         let current = sidebar_tab.get();
@@ -1352,7 +2029,7 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
     }
 
     // Compute widths for table vs sidebar.
-    let is_preview_open = preview_open.get();
+    let is_preview_open = preview_open.get() && !queue_tab;
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     let (table_width, sidebar_width) = if is_preview_open {
         let sb_w = (f64::from(props.width) * preview_pct).round() as u16;
@@ -1363,12 +2040,27 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
 
     // Build table.
     let show_repo_col = multi_repo && scope_repo.is_none();
-    let columns = branch_columns(&theme.icons, show_repo_col);
     let pr_map_read = pr_map.read();
-    let rows: Vec<Row> = branches
-        .iter()
-        .map(|b| branch_to_row(b, &theme, date_format, &pr_map_read))
-        .collect();
+    let (columns, rows, empty_message) = if queue_tab {
+        let rows: Vec<Row> = queue_entries
+            .iter()
+            .map(|e| queue_item_to_row(e, &theme, date_format))
+            .collect();
+        let load = queue_state.read();
+        let load = load
+            .as_ref()
+            .filter(|(r, _)| queue_repo.as_ref() == Some(r))
+            .map(|(_, l)| l);
+        let message = queue_empty_message(queue_repo.as_deref(), load);
+        (queue_columns(), rows, message)
+    } else {
+        let rows: Vec<Row> = branches
+            .iter()
+            .map(|b| branch_to_row(b, &theme, date_format, &pr_map_read))
+            .collect();
+        let columns = branch_columns(&theme.icons, show_repo_col);
+        (columns, rows, "No branches found".to_owned())
+    };
 
     let rendered_table = RenderedTable::build(&TableBuildConfig {
         columns: &columns,
@@ -1384,25 +2076,37 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
         header_color: Some(theme.text_secondary),
         border_color: Some(theme.border_faint),
         show_separator: props.show_separator,
-        empty_message: Some("No branches found"),
+        empty_message: Some(&empty_message),
         subtitle_column: None,
         row_separator: true,
         scrollbar_thumb_color: Some(theme.border_primary),
+        chrome: theme.chrome,
+        sort: None,
     });
 
-    let tabs = vec![Tab {
-        title: "Branches".to_owned(),
-        count: Some(total_rows),
-        is_ephemeral: false,
-    }];
+    let tabs = vec![
+        Tab {
+            title: "Branches".to_owned(),
+            count: Some(branches.len()),
+            hidden: 0,
+            is_ephemeral: false,
+        },
+        Tab {
+            title: "Merge queue".to_owned(),
+            count: queue_loaded.then_some(queue_entries.len()),
+            hidden: 0,
+            is_ephemeral: false,
+        },
+    ];
     let tab_colors = TabBarColors {
         active: Some(theme.footer_repo),
         inactive: Some(theme.footer_repo),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
     let rendered_tab_bar = RenderedTabBar::build(
         &tabs,
-        0,
+        usize::from(queue_tab),
         true,
         depth,
         &tab_colors,
@@ -1421,6 +2125,7 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                 text: Some(theme.text_primary),
                 prompt: Some(theme.text_secondary),
                 border: Some(theme.border_faint),
+                chrome: theme.chrome,
                 ..Default::default()
             },
         )),
@@ -1435,6 +2140,7 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                     text: Some(theme.text_primary),
                     prompt: Some(theme.text_warning),
                     border: Some(theme.border_faint),
+                    chrome: theme.chrome,
                     ..Default::default()
                 },
             ))
@@ -1450,6 +2156,24 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                     text: Some(theme.text_primary),
                     prompt: Some(theme.text_secondary),
                     border: Some(theme.border_faint),
+                    chrome: theme.chrome,
+                    ..Default::default()
+                },
+            ))
+        }
+        InputMode::ConfirmRelease => {
+            let prompt = release_draft.read().as_ref().map_or_else(String::new, |d| {
+                format!("Create draft release {} on GitHub? (y/n)", d.tag)
+            });
+            Some(RenderedTextInput::build(
+                &prompt,
+                "",
+                depth,
+                &TextInputColors {
+                    text: Some(theme.text_primary),
+                    prompt: Some(theme.text_secondary),
+                    border: Some(theme.border_faint),
+                    chrome: theme.chrome,
                     ..Default::default()
                 },
             ))
@@ -1459,7 +2183,8 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
 
     let context_text = {
         let cursor_pos = if total_rows > 0 { cursor.get() + 1 } else { 0 };
-        format!("Branch {cursor_pos}/{total_rows}")
+        let noun = if queue_tab { "Entry" } else { "Branch" };
+        format!("{noun} {cursor_pos}/{total_rows}")
     };
     let updated_text = footer::format_updated_ago(last_fetch_time.get());
 
@@ -1475,10 +2200,12 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
             Some(theme.footer_alerts),
             Some(theme.footer_notifications),
             Some(theme.footer_repo),
+            Some(theme.footer_events),
         ],
         inactive: Some(theme.text_faint),
         text: Some(theme.text_faint),
         border: Some(theme.border_faint),
+        chrome: theme.chrome,
     };
     let rendered_footer = RenderedFooter::build(
         ViewKind::Repo,
@@ -1487,7 +2214,9 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
             scope_label,
             context_text,
             updated_text,
-            rate_limit_text: footer::format_rate_limit(rate_limit_state.read().as_ref()),
+            rate_limit_text: footer::format_rate_limit(rate_limit_state.read().of(None), None),
+            watch_badges: props.watch_badges.map(|b| b.get()).unwrap_or_default(),
+            view_counts: props.view_counts.map(|c| c.get()).unwrap_or_default(),
         },
         action_status.read().as_ref(),
         &theme,
@@ -1585,6 +2314,7 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
             indicator: Some(theme.text_faint),
             thumb: Some(theme.border_primary),
             depth,
+            chrome: theme.chrome,
         };
         let sidebar = RenderedSidebar::build_tabbed(
             title,
@@ -1610,7 +2340,7 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
     };
 
     let rendered_help = if help_visible.get() {
-        props.keybindings.map(|kb| {
+        props.keybindings.as_deref().map(|kb| {
             RenderedHelpOverlay::build(&HelpOverlayBuildConfig {
                 bindings: kb,
                 context: ViewContext::Branches,
@@ -1619,12 +2349,15 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
                 key_color: Some(theme.text_success),
                 desc_color: Some(theme.text_secondary),
                 border_color: Some(theme.border_primary),
+                chrome: theme.chrome,
             })
         })
     } else {
         None
     };
 
+    let rendered_releases = releases::render(&releases_overlay, &theme, depth);
+
     let width = u32::from(props.width);
     let height = u32::from(props.height);
 
@@ -1639,6 +2372,7 @@ pub fn RepoView<'a>(props: &RepoViewProps<'a>, mut hooks: Hooks) -> impl Into<An
             }
             TextInput(input: rendered_text_input)
             Footer(footer: rendered_footer)
+            SelectionOverlay(overlay: rendered_releases, width: props.width, height: props.height)
             HelpOverlay(overlay: rendered_help, width: props.width, height: props.height)
         }
     }
@@ -1710,6 +2444,8 @@ fn render_branch_overview(
     // Tracking info
     let tracking = if branch.ahead == 0 && branch.behind == 0 {
         "Up to date".to_owned()
+    } else if theme.chrome.ascii_only() {
+        format!("{} ahead, {} behind", branch.ahead, branch.behind)
     } else {
         format!("↑{} ahead  ↓{} behind", branch.ahead, branch.behind)
     };
@@ -1814,7 +2550,7 @@ fn render_branch_files(
 
         let path_w = UnicodeWidthStr::width(file.path.as_str());
         let (display_path, display_w) = if path_w > path_col_width {
-            truncate_path_with_ellipsis(&file.path, path_col_width)
+            truncate_path_with_ellipsis(&file.path, path_col_width, theme.chrome)
         } else {
             (file.path.clone(), path_w)
         };
@@ -1846,12 +2582,14 @@ fn render_branch_files(
     lines
 }
 
-/// Truncate a path to fit within `max_width` display columns, appending `…`.
-fn truncate_path_with_ellipsis(s: &str, max_width: usize) -> (String, usize) {
+/// Truncate a path to fit within `max_width` display columns, appending `…`
+/// (`...` in ASCII chrome).
+fn truncate_path_with_ellipsis(s: &str, max_width: usize, chrome: Chrome) -> (String, usize) {
     if max_width == 0 {
         return (String::new(), 0);
     }
-    let target = max_width.saturating_sub(1); // 1 for `…`
+    let ellipsis = crate::components::ellipsis(chrome);
+    let target = max_width.saturating_sub(UnicodeWidthStr::width(ellipsis));
     let mut buf = String::new();
     let mut w = 0;
     for ch in s.chars() {
@@ -1862,8 +2600,8 @@ fn truncate_path_with_ellipsis(s: &str, max_width: usize) -> (String, usize) {
         buf.push(ch);
         w += cw;
     }
-    buf.push('…');
-    w += UnicodeWidthChar::width('…').unwrap_or(1);
+    buf.push_str(ellipsis);
+    w += UnicodeWidthStr::width(ellipsis);
     (buf, w)
 }
 
@@ -1889,6 +2627,7 @@ mod tests {
             behind: 1,
             worktree_path: None,
             repo_label: String::new(),
+            host: None,
         }
     }
 
@@ -1925,6 +2664,38 @@ mod tests {
         assert_eq!(cols[7].id, "updated");
     }
 
+    #[test]
+    fn queue_row_fills_every_column() {
+        let theme = test_theme();
+        let item = MergeQueueItem {
+            entry: crate::types::MergeQueueEntry {
+                position: 1,
+                state: crate::types::MergeQueueEntryState::AwaitingChecks,
+                estimated_secs: Some(61),
+            },
+            number: 42,
+            title: "Fix".to_owned(),
+            author: Some("alice".to_owned()),
+            url: String::new(),
+            enqueued_at: None,
+            ci: Some(CommitCheckState::Failure),
+        };
+        let row = queue_item_to_row(&item, &theme, "relative");
+        for col in queue_columns() {
+            assert!(row.contains_key(&col.id), "missing {}", col.id);
+        }
+        assert_eq!(row["pr"].text(), "#42");
+        assert_eq!(row["state"].text(), "awaiting checks");
+        assert_eq!(row["eta"].text(), "~2m");
+    }
+
+    #[test]
+    fn queue_empty_message_explains_missing_queue() {
+        assert!(queue_empty_message(None, None).contains("Scope a repo"));
+        let msg = queue_empty_message(Some("o/r"), Some(&QueueLoad::Disabled));
+        assert_eq!(msg, "o/r has no merge queue on its default branch");
+    }
+
     #[test]
     fn branch_to_row_repo_cell() {
         let theme = test_theme();
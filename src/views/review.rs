@@ -0,0 +1,126 @@
+//! Reviews written in the comment composer.
+//!
+//! `review` in the PR view opens the composer for a review, and `e` on the
+//! approve prompt opens it for an approval. Ctrl+R cycles the verdict. A line
+//! `@@ path:line` (or `@@ path:start-end`) starts a comment on those lines of
+//! the file's new version, running to the next such line; the text before the
+//! first one is the review body.
+
+use crate::types::{ReviewEvent, ReviewLineComment};
+
+/// A review as written in the composer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReviewDraft {
+    pub body: String,
+    pub comments: Vec<ReviewLineComment>,
+}
+
+/// The path, and the first and last line, of a `@@ path:line` header.
+type Header = (String, Option<u32>, u32);
+
+fn parse_header(header: &str) -> Option<Header> {
+    let (path, lines) = header.trim().rsplit_once(':')?;
+    let (start, end) = match lines.split_once('-') {
+        Some((start, end)) => (Some(start.parse().ok()?), end.parse().ok()?),
+        None => (None, lines.parse().ok()?),
+    };
+    if path.is_empty() || start.is_some_and(|s| s >= end) {
+        return None;
+    }
+    Some((path.to_owned(), start, end))
+}
+
+/// Split the composer `text` into the review body and its line comments.
+pub(crate) fn parse(text: &str) -> Result<ReviewDraft, String> {
+    let mut body = Vec::new();
+    let mut comments: Vec<(Header, Vec<&str>)> = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        if let Some(header) = line.strip_prefix("@@ ") {
+            let lines = parse_header(header).ok_or_else(|| {
+                format!(
+                    "Line {}: expected `@@ path:line` or `@@ path:start-end`",
+                    idx + 1
+                )
+            })?;
+            comments.push((lines, Vec::new()));
+        } else if let Some((_, lines)) = comments.last_mut() {
+            lines.push(line);
+        } else {
+            body.push(line);
+        }
+    }
+    let comments = comments
+        .into_iter()
+        .map(|((path, start_line, line), lines)| {
+            let body = lines.join("\n").trim().to_owned();
+            if body.is_empty() {
+                return Err(format!("No comment on {path}:{line}"));
+            }
+            Ok(ReviewLineComment {
+                path,
+                line,
+                start_line,
+                body,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(ReviewDraft {
+        body: body.join("\n").trim().to_owned(),
+        comments,
+    })
+}
+
+/// Why GitHub would refuse `draft` as an `event` review, if it would.
+pub(crate) fn missing(event: ReviewEvent, draft: &ReviewDraft) -> Option<&'static str> {
+    match event {
+        ReviewEvent::RequestChanges if draft.body.is_empty() => {
+            Some("Requesting changes needs a review body")
+        }
+        ReviewEvent::Comment if draft.body.is_empty() && draft.comments.is_empty() => {
+            Some("A comment review needs a body or a line comment")
+        }
+        _ => None,
+    }
+}
+
+/// The composer prompt while writing an `event` review.
+pub(crate) fn prompt(event: ReviewEvent) -> String {
+    format!(
+        "Review: {} (Ctrl+R verdict, `@@ path:line` comments a line, Ctrl+D to submit, Esc to cancel):",
+        event.label()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_body_and_line_comments() {
+        let draft =
+            parse("Looks good overall.\n\n@@ src/lib.rs:12\nTypo here.\n@@ src/a b.rs:3-5\nExtract this\nblock.\n")
+                .unwrap();
+        assert_eq!(draft.body, "Looks good overall.");
+        assert_eq!(
+            draft.comments,
+            [
+                ReviewLineComment {
+                    path: "src/lib.rs".to_owned(),
+                    line: 12,
+                    start_line: None,
+                    body: "Typo here.".to_owned(),
+                },
+                ReviewLineComment {
+                    path: "src/a b.rs".to_owned(),
+                    line: 5,
+                    start_line: Some(3),
+                    body: "Extract this\nblock.".to_owned(),
+                },
+            ]
+        );
+        assert!(parse("@@ src/lib.rs:x\nwhy?").is_err());
+        assert!(parse("@@ src/lib.rs:4\n\n").is_err());
+        assert_eq!(missing(ReviewEvent::RequestChanges, &draft), None);
+        assert!(missing(ReviewEvent::RequestChanges, &parse("@@ a:1\nb").unwrap()).is_some());
+    }
+}
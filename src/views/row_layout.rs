@@ -0,0 +1,190 @@
+//! Configurable lines of a PR row.
+//!
+//! A PR row shows an info line (`repo #N by @author` by default) above a
+//! subtitle line (the title). A tab's `layout.info` and `layout.subtitle`
+//! replace them with templates of literal text and `{{field}}` placeholders,
+//! parsed once per tab into the spans the row builder fills in. Unknown
+//! placeholders are kept as written.
+
+use std::sync::LazyLock;
+
+use crate::components::table::{Cell, Span};
+use crate::config::types::LayoutConfig;
+use crate::theme::ResolvedTheme;
+use crate::types::PullRequest;
+
+const DEFAULT_INFO: &str = "{{repo}} {{number}} by {{author}}";
+const DEFAULT_SUBTITLE: &str = "{{title}}";
+
+/// An item field a template can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    /// `owner/name`.
+    Repo,
+    /// The repository name alone.
+    Name,
+    /// `#N`.
+    Number,
+    /// `@login`.
+    Author,
+    Title,
+    /// Head branch.
+    Branch,
+    /// Base branch.
+    Base,
+}
+
+impl Field {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "repo" => Self::Repo,
+            "name" => Self::Name,
+            "number" => Self::Number,
+            "author" => Self::Author,
+            "title" => Self::Title,
+            "branch" => Self::Branch,
+            "base" => Self::Base,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Field(Field),
+}
+
+/// One line of a row, parsed from its template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LineTemplate(Vec<Part>);
+
+impl LineTemplate {
+    pub(crate) fn parse(template: &str) -> Self {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            let field = rest[start + 2..].find("}}").and_then(|end| {
+                let name = rest[start + 2..start + 2 + end].trim();
+                Field::from_name(name).map(|f| (f, start + 2 + end + 2))
+            });
+            if let Some((field, next)) = field {
+                text.push_str(&rest[..start]);
+                if !text.is_empty() {
+                    parts.push(Part::Text(std::mem::take(&mut text)));
+                }
+                parts.push(Part::Field(field));
+                rest = &rest[next..];
+            } else {
+                text.push_str(&rest[..start + 2]);
+                rest = &rest[start + 2..];
+            }
+        }
+        text.push_str(rest);
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Self(parts)
+    }
+
+    /// The line for `pr`: literal text faint, fields in their usual color.
+    pub(crate) fn render(&self, pr: &PullRequest, theme: &ResolvedTheme) -> Cell {
+        let spans = self
+            .0
+            .iter()
+            .map(|part| {
+                let (text, color) = match part {
+                    Part::Text(text) => (text.clone(), theme.text_faint),
+                    Part::Field(field) => field_span(*field, pr, theme),
+                };
+                Span {
+                    text,
+                    color: Some(color),
+                    bold: false,
+                }
+            })
+            .collect();
+        Cell::from_spans(spans)
+    }
+}
+
+fn field_span(
+    field: Field,
+    pr: &PullRequest,
+    theme: &ResolvedTheme,
+) -> (String, crate::color::Color) {
+    match field {
+        Field::Repo => (
+            pr.repo
+                .as_ref()
+                .map_or_else(String::new, crate::types::RepoRef::full_name),
+            theme.text_secondary,
+        ),
+        Field::Name => (
+            pr.repo
+                .as_ref()
+                .map_or_else(String::new, |r| r.name.clone()),
+            theme.text_secondary,
+        ),
+        Field::Number => (format!("#{}", pr.number), theme.text_primary),
+        Field::Author => (
+            format!(
+                "@{}",
+                pr.author.as_ref().map_or("unknown", |a| a.login.as_str())
+            ),
+            theme.text_actor,
+        ),
+        Field::Title => (
+            crate::util::expand_emoji(&pr.title).into_owned(),
+            theme.text_primary,
+        ),
+        Field::Branch => (pr.head_ref.clone(), theme.text_secondary),
+        Field::Base => (pr.base_ref.clone(), theme.text_secondary),
+    }
+}
+
+/// The info and subtitle lines of a tab's rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RowLayout {
+    pub info: LineTemplate,
+    pub subtitle: LineTemplate,
+}
+
+static STANDARD: LazyLock<RowLayout> = LazyLock::new(|| RowLayout::new(None));
+
+impl RowLayout {
+    /// The lines configured by `layout`, defaulting each to the standard one.
+    pub(crate) fn new(layout: Option<&LayoutConfig>) -> Self {
+        let info = layout.and_then(|l| l.info.as_deref());
+        let subtitle = layout.and_then(|l| l.subtitle.as_deref());
+        Self {
+            info: LineTemplate::parse(info.unwrap_or(DEFAULT_INFO)),
+            subtitle: LineTemplate::parse(subtitle.unwrap_or(DEFAULT_SUBTITLE)),
+        }
+    }
+
+    /// `repo #N by @author` above the title.
+    pub(crate) fn standard() -> &'static Self {
+        &STANDARD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_text_and_known_fields() {
+        assert_eq!(
+            LineTemplate::parse("{{title}} ({{ name }}) {{nope}} {{"),
+            LineTemplate(vec![
+                Part::Field(Field::Title),
+                Part::Text(" (".to_owned()),
+                Part::Field(Field::Name),
+                Part::Text(") {{nope}} {{".to_owned()),
+            ])
+        );
+        assert_eq!(LineTemplate::parse(""), LineTemplate(Vec::new()));
+    }
+}
@@ -0,0 +1,474 @@
+//! Manual-run overlay of the Actions view.
+//!
+//! `T` lists the workflows of the scoped repo (else the active tab's repos)
+//! that have a `workflow_dispatch` trigger. Picking one opens a form with the
+//! ref to run on and every declared input, prefilled with its default;
+//! `Enter` triggers the run. Unlike quick-run presets, nothing needs to be
+//! configured first.
+
+use indexmap::IndexMap;
+use iocraft::prelude::*;
+
+use crate::color::ColorDepth;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::theme::{Chrome, ResolvedTheme};
+use crate::types::{DispatchableWorkflow, WorkflowInput, WorkflowInputKind};
+
+/// Engine request context of workflow listings.
+pub(crate) const FETCH_CONTEXT: &str = "FetchDispatchableWorkflows";
+
+/// An open overlay.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RunWorkflowOverlay {
+    host: Option<String>,
+    /// `owner/repo` of every repo read.
+    repos: Vec<String>,
+    /// `None` while loading.
+    workflows: Option<Vec<DispatchableWorkflow>>,
+    error: Option<String>,
+    cursor: usize,
+    form: Option<RunForm>,
+}
+
+/// The ref and inputs of the picked workflow.
+#[derive(Debug, Clone)]
+struct RunForm {
+    workflow: DispatchableWorkflow,
+    /// Empty for the default branch.
+    git_ref: String,
+    /// One per input.
+    values: Vec<String>,
+    /// 0 is the ref, then the inputs.
+    field: usize,
+    /// Why the last `Enter` did not run.
+    problem: Option<String>,
+}
+
+impl RunForm {
+    fn new(workflow: DispatchableWorkflow) -> Self {
+        let values = workflow.inputs.iter().map(initial_value).collect();
+        Self {
+            workflow,
+            git_ref: String::new(),
+            values,
+            field: 0,
+            problem: None,
+        }
+    }
+
+    /// The input under the cursor, `None` on the ref.
+    fn input(&self) -> Option<&WorkflowInput> {
+        self.field
+            .checked_sub(1)
+            .and_then(|i| self.workflow.inputs.get(i))
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        match self.field.checked_sub(1) {
+            Some(i) => &mut self.values[i],
+            None => &mut self.git_ref,
+        }
+    }
+
+    /// Values the input under the cursor is picked from, if it is not typed.
+    fn choices(&self) -> Option<Vec<String>> {
+        match &self.input()?.kind {
+            WorkflowInputKind::Boolean => Some(vec!["false".to_owned(), "true".to_owned()]),
+            WorkflowInputKind::Choice(options) if !options.is_empty() => Some(options.clone()),
+            _ => None,
+        }
+    }
+
+    /// Step the picked value of the input under the cursor.
+    fn cycle(&mut self, forward: bool) {
+        let Some(mut choices) = self.choices() else {
+            return;
+        };
+        let value = self.value_mut();
+        let at = choices.iter().position(|c| c == value).unwrap_or(0);
+        let next = if forward {
+            (at + 1) % choices.len()
+        } else {
+            (at + choices.len() - 1) % choices.len()
+        };
+        *value = choices.swap_remove(next);
+    }
+
+    /// The run to trigger, or why the form is not complete.
+    fn submit(&self) -> Result<WorkflowRunRequest, String> {
+        let mut inputs = IndexMap::new();
+        for (input, value) in self.workflow.inputs.iter().zip(&self.values) {
+            let value = value.trim();
+            if value.is_empty() {
+                if input.required {
+                    return Err(format!("{} is required", input.name));
+                }
+                continue;
+            }
+            if input.kind == WorkflowInputKind::Number && value.parse::<f64>().is_err() {
+                return Err(format!("{} must be a number", input.name));
+            }
+            inputs.insert(input.name.clone(), value.to_owned());
+        }
+        let git_ref = self.git_ref.trim();
+        Ok(WorkflowRunRequest {
+            repo: self.workflow.repo.clone(),
+            name: self.workflow.name.clone(),
+            file: self.workflow.file.clone(),
+            git_ref: (!git_ref.is_empty()).then(|| git_ref.to_owned()),
+            inputs,
+        })
+    }
+}
+
+/// Starting value of an input: its default, else the first choice.
+fn initial_value(input: &WorkflowInput) -> String {
+    if let Some(default) = &input.default {
+        return default.clone();
+    }
+    match &input.kind {
+        WorkflowInputKind::Boolean => "false".to_owned(),
+        WorkflowInputKind::Choice(options) => options.first().cloned().unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+/// A filled-in form, ready to dispatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct WorkflowRunRequest {
+    pub repo: String,
+    pub name: String,
+    pub file: String,
+    pub git_ref: Option<String>,
+    pub inputs: IndexMap<String, String>,
+}
+
+/// Open the overlay and list the workflows of `repos`.
+pub(crate) fn open(
+    overlay: &mut State<Option<RunWorkflowOverlay>>,
+    engine: Option<&EngineHandle>,
+    host: Option<String>,
+    repos: Vec<String>,
+    reply_tx: &EventSender,
+) {
+    if let Some(engine) = engine {
+        engine.send(Request::FetchDispatchableWorkflows {
+            host: host.clone(),
+            repos: repos.clone(),
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    overlay.set(Some(RunWorkflowOverlay {
+        host,
+        repos,
+        ..RunWorkflowOverlay::default()
+    }));
+}
+
+/// Store the fetched workflows in the open overlay.
+pub(crate) fn on_fetched(
+    overlay: &mut State<Option<RunWorkflowOverlay>>,
+    workflows: Vec<DispatchableWorkflow>,
+) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    current.workflows = Some(workflows);
+    overlay.set(Some(current));
+}
+
+/// Show a failed listing in the open overlay.
+pub(crate) fn on_error(overlay: &mut State<Option<RunWorkflowOverlay>>, message: String) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    current.error = Some(message);
+    overlay.set(Some(current));
+}
+
+impl RunWorkflowOverlay {
+    /// Apply `code`; returns whether the overlay stays open, and the run to
+    /// trigger once the form is submitted.
+    fn key(&mut self, code: KeyCode) -> (bool, Option<WorkflowRunRequest>) {
+        if let Some(form) = self.form.as_mut() {
+            let fields = form.workflow.inputs.len() + 1;
+            match code {
+                KeyCode::Esc => self.form = None,
+                KeyCode::Enter => match form.submit() {
+                    Ok(run) => return (false, Some(run)),
+                    Err(problem) => form.problem = Some(problem),
+                },
+                KeyCode::Down | KeyCode::Tab => form.field = (form.field + 1) % fields,
+                KeyCode::Up | KeyCode::BackTab => form.field = (form.field + fields - 1) % fields,
+                KeyCode::Left => form.cycle(false),
+                KeyCode::Right | KeyCode::Char(' ') if form.choices().is_some() => {
+                    form.cycle(true);
+                }
+                KeyCode::Backspace if form.choices().is_none() => {
+                    form.value_mut().pop();
+                }
+                KeyCode::Char(ch) if form.choices().is_none() => form.value_mut().push(ch),
+                _ => {}
+            }
+            return (true, None);
+        }
+        let len = self.workflows.as_ref().map_or(0, Vec::len);
+        match code {
+            KeyCode::Esc | KeyCode::Char('q' | 'T') => return (false, None),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.cursor = (self.cursor + 1).min(len.saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Enter => {
+                if let Some(workflow) = self.workflows.as_ref().and_then(|w| w.get(self.cursor)) {
+                    self.form = Some(RunForm::new(workflow.clone()));
+                }
+            }
+            _ => {}
+        }
+        (true, None)
+    }
+}
+
+/// Handle a key while the overlay is open. Every key is consumed; returns
+/// the run to trigger once the form is submitted.
+pub(crate) fn handle_key(
+    overlay: &mut State<Option<RunWorkflowOverlay>>,
+    code: KeyCode,
+) -> Option<(WorkflowRunRequest, Option<String>)> {
+    let mut current = overlay.read().clone()?;
+    let host = current.host.clone();
+    let (open, run) = current.key(code);
+    overlay.set(open.then_some(current));
+    run.map(|run| (run, host))
+}
+
+/// Trigger `run`; returns the status message to show.
+pub(crate) fn send(
+    engine: Option<&EngineHandle>,
+    run: WorkflowRunRequest,
+    host: Option<String>,
+    reply_tx: &EventSender,
+    chrome: Chrome,
+) -> String {
+    let Some((owner, name)) = run.repo.split_once('/') else {
+        return format!("Invalid repo {:?} for {}", run.repo, run.name);
+    };
+    let status = format!(
+        "Dispatching {}{}",
+        run.name,
+        crate::components::ellipsis(chrome)
+    );
+    if let Some(engine) = engine {
+        engine.send(Request::DispatchWorkflow {
+            host,
+            owner: owner.to_owned(),
+            repo: name.to_owned(),
+            workflow: run.file,
+            git_ref: run.git_ref,
+            inputs: run.inputs,
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    status
+}
+
+/// `name (type, required): value  description` line of the form.
+fn field_label(input: &WorkflowInput, value: &str, chrome: Chrome) -> String {
+    let mut label = input.name.clone();
+    if input.required {
+        label.push('*');
+    }
+    let value = match &input.kind {
+        WorkflowInputKind::Boolean | WorkflowInputKind::Choice(_) if chrome.ascii_only() => {
+            format!("< {value} >")
+        }
+        WorkflowInputKind::Boolean | WorkflowInputKind::Choice(_) => {
+            format!("\u{2039} {value} \u{203a}")
+        }
+        _ if value.is_empty() && chrome.ascii_only() => "-".to_owned(),
+        _ if value.is_empty() => "\u{2014}".to_owned(),
+        _ => value.to_owned(),
+    };
+    label.push_str(": ");
+    label.push_str(&value);
+    if let Some(description) = &input.description {
+        label.push(' ');
+        label.push_str(crate::components::dot_separator(chrome));
+        label.push_str(description);
+    }
+    label
+}
+
+/// Render the open overlay, if any.
+pub(crate) fn render(
+    overlay: &State<Option<RunWorkflowOverlay>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let overlay = overlay.read();
+    let current = overlay.as_ref()?;
+    let line = |label: String| SelectionOverlayItem { label };
+    let show_repo = current.repos.len() > 1;
+    let (title, items, cursor, hint) = if let Some(form) = &current.form {
+        let git_ref = if form.git_ref.is_empty() {
+            "(default branch)"
+        } else {
+            &form.git_ref
+        };
+        let mut items = vec![line(format!("ref: {git_ref}"))];
+        items.extend(
+            form.workflow
+                .inputs
+                .iter()
+                .zip(&form.values)
+                .map(|(input, value)| line(field_label(input, value, theme.chrome))),
+        );
+        let hint = form.problem.clone().map_or_else(
+            || {
+                let arrows = if theme.chrome.ascii_only() {
+                    "Left/Right"
+                } else {
+                    "\u{2190}/\u{2192}" // ←/→
+                };
+                format!("Type to edit  {arrows} pick  Tab next  Enter run  Esc back")
+            },
+            |problem| format!("{problem}  Esc back"),
+        );
+        let title = format!(
+            "Run {}{}{}",
+            form.workflow.name,
+            crate::components::dot_separator(theme.chrome),
+            form.workflow.repo
+        );
+        (title, items, form.field, hint)
+    } else {
+        let items = match (&current.error, &current.workflows) {
+            (Some(error), _) => vec![line(format!("Error: {error}"))],
+            (None, None) => vec![line(format!(
+                "Loading{}",
+                crate::components::ellipsis(theme.chrome)
+            ))],
+            (None, Some(workflows)) if workflows.is_empty() => {
+                vec![line(
+                    "No workflow has a workflow_dispatch trigger".to_owned(),
+                )]
+            }
+            (None, Some(workflows)) => workflows
+                .iter()
+                .map(|w| {
+                    let mut label = format!("{}  ({})", w.name, w.file);
+                    if show_repo {
+                        label.push_str(crate::components::dot_separator(theme.chrome));
+                        label.push_str(&w.repo);
+                    }
+                    line(label)
+                })
+                .collect(),
+        };
+        let cursor = if current.workflows.as_ref().is_some_and(|w| !w.is_empty()) {
+            current.cursor
+        } else {
+            usize::MAX
+        };
+        let title = match &current.repos[..] {
+            [repo] => format!("Run a workflow: {repo}"),
+            repos => format!("Run a workflow: {} repos", repos.len()),
+        };
+        (title, items, cursor, "Enter pick  Esc close".to_owned())
+    };
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title,
+            items,
+            cursor,
+            show_filter: false,
+            filter_text: String::new(),
+            hint: Some(hint),
+            depth,
+            title_color: Some(theme.text_primary),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: None,
+            filter_text_color: None,
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(name: &str, kind: WorkflowInputKind, required: bool) -> WorkflowInput {
+        WorkflowInput {
+            name: name.to_owned(),
+            description: None,
+            required,
+            default: None,
+            kind,
+        }
+    }
+
+    #[test]
+    fn form_fills_ref_and_inputs_then_submits() {
+        let workflow = DispatchableWorkflow {
+            repo: "acme/app".to_owned(),
+            name: "Deploy".to_owned(),
+            file: "deploy.yml".to_owned(),
+            inputs: vec![
+                input(
+                    "env",
+                    WorkflowInputKind::Choice(vec!["staging".to_owned(), "prod".to_owned()]),
+                    true,
+                ),
+                input("dry_run", WorkflowInputKind::Boolean, false),
+                input("version", WorkflowInputKind::String, true),
+            ],
+        };
+        let mut overlay = RunWorkflowOverlay {
+            repos: vec!["acme/app".to_owned()],
+            workflows: Some(vec![workflow]),
+            ..RunWorkflowOverlay::default()
+        };
+        overlay.key(KeyCode::Enter);
+        for ch in "v2".chars() {
+            overlay.key(KeyCode::Char(ch));
+        }
+        overlay.key(KeyCode::Tab);
+        overlay.key(KeyCode::Right);
+        overlay.key(KeyCode::Tab);
+        overlay.key(KeyCode::Char(' '));
+        assert_eq!(overlay.key(KeyCode::Enter), (true, None));
+        assert_eq!(
+            overlay.form.as_ref().unwrap().problem.as_deref(),
+            Some("version is required")
+        );
+
+        overlay.key(KeyCode::Tab);
+        for ch in "1.4".chars() {
+            overlay.key(KeyCode::Char(ch));
+        }
+        let (open, run) = overlay.key(KeyCode::Enter);
+        assert!(!open);
+        let run = run.unwrap();
+        assert_eq!(run.file, "deploy.yml");
+        assert_eq!(run.git_ref.as_deref(), Some("v2"));
+        let inputs: Vec<(&str, &str)> = run
+            .inputs
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(
+            inputs,
+            [("env", "prod"), ("dry_run", "true"), ("version", "1.4")]
+        );
+    }
+}
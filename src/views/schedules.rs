@@ -0,0 +1,231 @@
+//! Scheduled-runs overlay of the Actions view.
+//!
+//! `C` reads the `schedule` triggers from the workflow files of the scoped
+//! repo (else the active tab's repos) and lists the next time each one
+//! fires, soonest first, in the configured timezone.
+
+use chrono::{DateTime, Utc};
+use iocraft::prelude::*;
+
+use crate::color::ColorDepth;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::cron::Cron;
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::theme::{Chrome, ResolvedTheme};
+use crate::types::WorkflowSchedule;
+
+/// Engine request context of schedule fetches.
+pub(crate) const FETCH_CONTEXT: &str = "FetchWorkflowSchedules";
+
+/// Schedules listed before the rest is summarized.
+const MAX_ROWS: usize = 20;
+
+/// An open overlay.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SchedulesOverlay {
+    /// `owner/repo` of every repo read.
+    pub repos: Vec<String>,
+    /// `None` while loading.
+    pub schedules: Option<Vec<WorkflowSchedule>>,
+    pub error: Option<String>,
+}
+
+/// Open the overlay and fetch the schedules of `repos`.
+pub(crate) fn open(
+    overlay: &mut State<Option<SchedulesOverlay>>,
+    engine: Option<&EngineHandle>,
+    host: Option<String>,
+    repos: Vec<String>,
+    reply_tx: &EventSender,
+) {
+    if let Some(engine) = engine {
+        engine.send(Request::FetchWorkflowSchedules {
+            host,
+            repos: repos.clone(),
+            reply_tx: reply_tx.clone(),
+        });
+    }
+    overlay.set(Some(SchedulesOverlay {
+        repos,
+        ..SchedulesOverlay::default()
+    }));
+}
+
+/// Handle a key while the overlay is open. Every key is consumed.
+pub(crate) fn handle_key(overlay: &mut State<Option<SchedulesOverlay>>, code: KeyCode) {
+    if matches!(code, KeyCode::Esc | KeyCode::Char('q' | 'C')) {
+        overlay.set(None);
+    }
+}
+
+/// Store fetched schedules in the open overlay.
+pub(crate) fn on_fetched(
+    overlay: &mut State<Option<SchedulesOverlay>>,
+    schedules: Vec<WorkflowSchedule>,
+) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    current.schedules = Some(schedules);
+    overlay.set(Some(current));
+}
+
+/// Show a failed fetch in the open overlay.
+pub(crate) fn on_error(overlay: &mut State<Option<SchedulesOverlay>>, message: String) {
+    let Some(mut current) = overlay.read().clone() else {
+        return;
+    };
+    current.error = Some(message);
+    overlay.set(Some(current));
+}
+
+/// Next fire time of each schedule after `now`, soonest first; schedules
+/// that never fire or do not parse come last.
+fn upcoming(
+    schedules: &[WorkflowSchedule],
+    now: DateTime<Utc>,
+) -> Vec<(Option<DateTime<Utc>>, &WorkflowSchedule)> {
+    let mut upcoming: Vec<_> = schedules
+        .iter()
+        .map(|s| {
+            let next = Cron::parse(&s.cron).ok().and_then(|c| c.next_after(now));
+            (next, s)
+        })
+        .collect();
+    upcoming.sort_by_key(|(next, _)| (next.is_none(), *next));
+    upcoming
+}
+
+/// `"in 5m"`, `"in 3h"`, `"in 2d"`.
+fn until(next: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (next - now).num_minutes().max(1);
+    match minutes {
+        ..60 => format!("in {minutes}m"),
+        60..1440 => format!("in {}h", minutes / 60),
+        _ => format!("in {}d", minutes / 1440),
+    }
+}
+
+fn item_label(
+    next: Option<DateTime<Utc>>,
+    schedule: &WorkflowSchedule,
+    now: DateTime<Utc>,
+    show_repo: bool,
+    chrome: Chrome,
+) -> String {
+    let when = match next {
+        Some(next) => format!(
+            "{}  {:>6}",
+            crate::util::format_local(&next, "%a %b %d %H:%M"),
+            until(next, now)
+        ),
+        None if Cron::parse(&schedule.cron).is_err() => format!("{:<20}", "invalid cron"),
+        None => format!("{:<20}", "never"),
+    };
+    let mut label = format!("{when}  {}  ({})", schedule.workflow, schedule.cron);
+    if show_repo {
+        label.push_str(crate::components::dot_separator(chrome));
+        label.push_str(&schedule.repo);
+    }
+    label
+}
+
+/// Render the open overlay, if any.
+pub(crate) fn render(
+    overlay: &State<Option<SchedulesOverlay>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let overlay = overlay.read();
+    let current = overlay.as_ref()?;
+    let now = Utc::now();
+    let line = |label: String| SelectionOverlayItem { label };
+    let items: Vec<SelectionOverlayItem> = match (&current.error, &current.schedules) {
+        (Some(error), _) => vec![line(format!("Error: {error}"))],
+        (None, None) => vec![line(format!(
+            "Loading{}",
+            crate::components::ellipsis(theme.chrome)
+        ))],
+        (None, Some(schedules)) if schedules.is_empty() => {
+            vec![line("No scheduled workflows".to_owned())]
+        }
+        (None, Some(schedules)) => {
+            let upcoming = upcoming(schedules, now);
+            let show_repo = current.repos.len() > 1;
+            let mut items: Vec<SelectionOverlayItem> = upcoming
+                .iter()
+                .take(MAX_ROWS)
+                .map(|&(next, s)| line(item_label(next, s, now, show_repo, theme.chrome)))
+                .collect();
+            if upcoming.len() > MAX_ROWS {
+                items.push(line(format!(
+                    "{} and {} more",
+                    crate::components::ellipsis(theme.chrome),
+                    upcoming.len() - MAX_ROWS
+                )));
+            }
+            items
+        }
+    };
+    let title = match &current.repos[..] {
+        [repo] => format!("Upcoming scheduled runs: {repo}"),
+        repos => format!("Upcoming scheduled runs: {} repos", repos.len()),
+    };
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title,
+            items,
+            cursor: usize::MAX,
+            show_filter: false,
+            filter_text: String::new(),
+            hint: Some("Esc close".to_owned()),
+            depth,
+            title_color: Some(theme.text_primary),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: None,
+            filter_text_color: None,
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(workflow: &str, cron: &str) -> WorkflowSchedule {
+        WorkflowSchedule {
+            repo: "acme/app".to_owned(),
+            workflow: workflow.to_owned(),
+            path: format!(".github/workflows/{workflow}.yml"),
+            cron: cron.to_owned(),
+        }
+    }
+
+    #[test]
+    fn upcoming_sorts_by_next_fire_time() {
+        let now = DateTime::parse_from_rfc3339("2024-05-15T10:07:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let schedules = [
+            schedule("nightly", "0 3 * * *"),
+            schedule("broken", "every day"),
+            schedule("hourly", "30 * * * *"),
+            schedule("weekly", "0 12 * * MON"),
+        ];
+        let names: Vec<&str> = upcoming(&schedules, now)
+            .iter()
+            .map(|(_, s)| s.workflow.as_str())
+            .collect();
+        assert_eq!(names, ["hourly", "nightly", "weekly", "broken"]);
+        let (next, _) = upcoming(&schedules, now)[0];
+        assert_eq!(until(next.unwrap(), now), "in 23m");
+    }
+}
@@ -0,0 +1,207 @@
+//! Overlay answering a running script's `confirm`, `pick` and `input`.
+//!
+//! The app opens it over whichever view ran the script and keeps the views
+//! from handling keys until it is answered. Esc answers every prompt with a
+//! cancel.
+
+use iocraft::prelude::*;
+
+use crate::color::ColorDepth;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::components::text_input::filter_suggestions;
+use crate::script::{Ask, Prompt};
+use crate::theme::ResolvedTheme;
+
+/// What a key did to the prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Open,
+    /// Done, with the answer; `None` cancels.
+    Answered(Option<String>),
+}
+
+/// A prompt being answered.
+#[derive(Clone)]
+pub(crate) struct OpenPrompt {
+    prompt: Prompt,
+    /// Filter of `pick`, text of `input`.
+    text: String,
+    cursor: usize,
+}
+
+impl OpenPrompt {
+    pub(crate) fn new(prompt: Prompt) -> Self {
+        Self {
+            prompt,
+            text: String::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Options of a `pick` matching the filter.
+    fn options(&self) -> Vec<String> {
+        match &self.prompt.ask {
+            Ask::Pick { options, .. } => filter_suggestions(options, &self.text),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Apply `code`.
+    fn key(&mut self, code: KeyCode) -> Outcome {
+        let len = match &self.prompt.ask {
+            Ask::Confirm(_) => 2,
+            Ask::Pick { .. } => self.options().len(),
+            Ask::Input(_) => 0,
+        };
+        match (&self.prompt.ask, code) {
+            (_, KeyCode::Esc) | (Ask::Confirm(_), KeyCode::Char('n')) => {
+                return Outcome::Answered(None);
+            }
+            (Ask::Confirm(_), KeyCode::Char('y')) => return Outcome::Answered(Some(String::new())),
+            (Ask::Confirm(_), KeyCode::Enter) => {
+                return Outcome::Answered((self.cursor == 0).then(String::new));
+            }
+            (Ask::Pick { .. }, KeyCode::Enter) => {
+                if let Some(option) = self.options().get(self.cursor) {
+                    return Outcome::Answered(Some(option.clone()));
+                }
+            }
+            (Ask::Input(_), KeyCode::Enter) => return Outcome::Answered(Some(self.text.clone())),
+            (Ask::Confirm(_) | Ask::Pick { .. }, KeyCode::Down) => {
+                self.cursor = (self.cursor + 1).min(len.saturating_sub(1));
+            }
+            (Ask::Confirm(_) | Ask::Pick { .. }, KeyCode::Up) => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            (Ask::Pick { .. } | Ask::Input(_), KeyCode::Backspace) => {
+                self.text.pop();
+                self.cursor = 0;
+            }
+            (Ask::Pick { .. } | Ask::Input(_), KeyCode::Char(ch)) => {
+                self.text.push(ch);
+                self.cursor = 0;
+            }
+            _ => {}
+        }
+        Outcome::Open
+    }
+}
+
+/// Handle a key while a prompt is open, answering and closing it when done.
+pub(crate) fn handle_key(open: &mut State<Option<OpenPrompt>>, code: KeyCode) {
+    let Some(mut current) = open.read().clone() else {
+        return;
+    };
+    match current.key(code) {
+        Outcome::Answered(answer) => {
+            current.prompt.answer(answer);
+            open.set(None);
+        }
+        Outcome::Open => open.set(Some(current)),
+    }
+}
+
+/// Render the open prompt, if any.
+pub(crate) fn render(
+    open: &State<Option<OpenPrompt>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let open = open.read();
+    let current = open.as_ref()?;
+    let line = |label: String| SelectionOverlayItem { label };
+    let (title, items, show_filter, hint) = match &current.prompt.ask {
+        Ask::Confirm(message) => (
+            message.clone(),
+            vec![line("Yes".to_owned()), line("No".to_owned())],
+            false,
+            "y/n",
+        ),
+        Ask::Pick { title, .. } => (
+            title.clone(),
+            current.options().into_iter().map(line).collect(),
+            true,
+            "Enter pick  Esc cancel",
+        ),
+        Ask::Input(prompt) => (
+            prompt.clone(),
+            vec![line(format!(
+                "{}{}",
+                current.text,
+                crate::components::input_cursor(theme.chrome)
+            ))],
+            false,
+            "Enter submit  Esc cancel",
+        ),
+    };
+    let cursor = if matches!(current.prompt.ask, Ask::Input(_)) {
+        usize::MAX
+    } else {
+        current.cursor
+    };
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title,
+            items,
+            cursor,
+            show_filter,
+            filter_text: current.text.clone(),
+            hint: Some(hint.to_owned()),
+            depth,
+            title_color: Some(theme.text_primary),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: Some(theme.text_faint),
+            filter_text_color: Some(theme.text_primary),
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open(ask: Ask) -> OpenPrompt {
+        OpenPrompt::new(Prompt::new(ask).0)
+    }
+
+    #[test]
+    fn keys_answer_each_kind_of_prompt() {
+        let mut confirm = open(Ask::Confirm("Close it?".to_owned()));
+        assert_eq!(confirm.key(KeyCode::Down), Outcome::Open);
+        assert_eq!(confirm.key(KeyCode::Enter), Outcome::Answered(None));
+        assert_eq!(
+            confirm.key(KeyCode::Char('y')),
+            Outcome::Answered(Some(String::new()))
+        );
+
+        let mut pick = open(Ask::Pick {
+            title: "Label".to_owned(),
+            options: vec!["bug".to_owned(), "docs".to_owned(), "debt".to_owned()],
+        });
+        pick.key(KeyCode::Char('d'));
+        pick.key(KeyCode::Down);
+        assert_eq!(
+            pick.key(KeyCode::Enter),
+            Outcome::Answered(Some("debt".to_owned()))
+        );
+        pick.key(KeyCode::Char('x'));
+        assert_eq!(pick.key(KeyCode::Enter), Outcome::Open);
+
+        let mut input = open(Ask::Input("Reason".to_owned()));
+        input.key(KeyCode::Char('n'));
+        input.key(KeyCode::Char('o'));
+        assert_eq!(
+            input.key(KeyCode::Enter),
+            Outcome::Answered(Some("no".to_owned()))
+        );
+        assert_eq!(input.key(KeyCode::Esc), Outcome::Answered(None));
+    }
+}
@@ -0,0 +1,159 @@
+//! Share menu of the PR and Issue views.
+//!
+//! `alt+s` lists the `[[share_targets]]` with the message each would post
+//! about the selected item; Enter or the target's digit posts it.
+
+use iocraft::prelude::*;
+
+use crate::color::ColorDepth;
+use crate::components::footer::ActionFeedback;
+use crate::components::selection_overlay::{
+    RenderedSelectionOverlay, SelectionOverlayBuildConfig, SelectionOverlayItem,
+};
+use crate::config::keybindings::TemplateVars;
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::share;
+use crate::theme::{Chrome, ResolvedTheme};
+
+/// An open share menu.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ShareMenu {
+    /// The item being shared.
+    pub vars: TemplateVars,
+    pub cursor: usize,
+    /// Chrome the status message is drawn in.
+    pub chrome: Chrome,
+}
+
+/// The menu for the item described by `vars`, or the feedback explaining
+/// why there is none.
+pub(crate) fn open(
+    vars: Option<&TemplateVars>,
+    chrome: Chrome,
+) -> Result<ShareMenu, ActionFeedback> {
+    if share::targets().is_empty() {
+        return Err(ActionFeedback::Info(
+            "No [[share_targets]] configured".to_owned(),
+        ));
+    }
+    let vars = vars.ok_or_else(|| ActionFeedback::Info("Nothing to share".to_owned()))?;
+    Ok(ShareMenu {
+        vars: vars.clone(),
+        cursor: 0,
+        chrome,
+    })
+}
+
+/// Post the item to target `idx`, closing the menu.
+fn send(
+    menu: &mut State<Option<ShareMenu>>,
+    current: &ShareMenu,
+    idx: usize,
+    engine: Option<&EngineHandle>,
+    reply_tx: &EventSender,
+) -> Option<ActionFeedback> {
+    let target = share::targets().get(idx)?;
+    menu.set(None);
+    let engine = engine?;
+    engine.send(Request::ShareItem {
+        target: target.name.clone(),
+        webhook: target.webhook.clone(),
+        payload: share::payload(target, &current.vars),
+        reply_tx: reply_tx.clone(),
+    });
+    Some(ActionFeedback::Info(format!(
+        "Sharing #{} to {}{}",
+        current.vars.number,
+        target.name,
+        crate::components::ellipsis(current.chrome)
+    )))
+}
+
+/// Handle a key while the menu is open. Every key is consumed; returns
+/// the feedback of the post sent, if any.
+pub(crate) fn handle_key(
+    menu: &mut State<Option<ShareMenu>>,
+    code: KeyCode,
+    engine: Option<&EngineHandle>,
+    reply_tx: &EventSender,
+) -> Option<ActionFeedback> {
+    let mut current = menu.read().clone()?;
+    let count = share::targets().len();
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => menu.set(None),
+        KeyCode::Enter => return send(menu, &current, current.cursor, engine, reply_tx),
+        KeyCode::Char(c @ '1'..='9') => {
+            return send(
+                menu,
+                &current,
+                (c as usize) - ('1' as usize),
+                engine,
+                reply_tx,
+            );
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            current.cursor = (current.cursor + 1).min(count.saturating_sub(1));
+            menu.set(Some(current));
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            current.cursor = current.cursor.saturating_sub(1);
+            menu.set(Some(current));
+        }
+        _ => {}
+    }
+    None
+}
+
+/// Render the menu, or `None` when it is closed.
+pub(crate) fn render(
+    menu: &State<Option<ShareMenu>>,
+    theme: &ResolvedTheme,
+    depth: ColorDepth,
+) -> Option<RenderedSelectionOverlay> {
+    let menu = menu.read();
+    let menu = menu.as_ref()?;
+    let items = share::targets()
+        .iter()
+        .enumerate()
+        .map(|(i, target)| {
+            let key = if i < 9 {
+                format!("{} ", i + 1)
+            } else {
+                "  ".to_owned()
+            };
+            // The message on one line: what the target will receive.
+            let message = share::message(target, &menu.vars).replace(
+                '\n',
+                if theme.chrome.ascii_only() {
+                    " / "
+                } else {
+                    " \u{21b5} " // ↵
+                },
+            );
+            SelectionOverlayItem {
+                label: format!("{key}{}: {message}", target.name),
+            }
+        })
+        .collect();
+    Some(RenderedSelectionOverlay::build(
+        SelectionOverlayBuildConfig {
+            title: format!("Share #{}", menu.vars.number),
+            items,
+            cursor: menu.cursor,
+            show_filter: false,
+            filter_text: String::new(),
+            hint: Some("Enter post  Esc close".to_owned()),
+            depth,
+            title_color: Some(theme.text_primary),
+            item_color: Some(theme.text_secondary),
+            cursor_color: Some(theme.text_primary),
+            selected_bg: Some(theme.bg_selected),
+            border_color: Some(theme.border_primary),
+            hint_color: Some(theme.text_faint),
+            filter_prompt_color: None,
+            filter_text_color: None,
+            cursor_marker: theme.icons.select_cursor.clone(),
+            chrome: theme.chrome,
+        },
+    ))
+}
@@ -0,0 +1,79 @@
+//! Keyboard focus of a view's sidebar.
+//!
+//! `tab` (`toggle_focus`) moves the focus between the table and the preview
+//! sidebar of the PR, Issue and Actions views. While the sidebar has it, the
+//! `[[keybindings.sidebar]]` bindings come first and the movement keys step
+//! through the items of what it shows, in display order: an Activity tab's
+//! comments, the changed files, the checks, an issue's links or a run's jobs.
+//! Where there are none, they scroll it instead.
+
+use crate::config::keybindings::BuiltinAction;
+
+/// The item `action` (`move_down`, `move_up`, `first` or `last`) selects
+/// among `order` from `current`.
+///
+/// Moving starts from the first (or last) item when `current` is not among
+/// them and stays put at either end; `None` when there are no items.
+pub(crate) fn step<T: Copy + PartialEq>(
+    order: &[T],
+    current: Option<T>,
+    action: BuiltinAction,
+) -> Option<T> {
+    let pos = current.and_then(|c| order.iter().position(|&item| item == c));
+    let last = order.len().checked_sub(1)?;
+    let next = match (action, pos) {
+        (BuiltinAction::MoveDown, Some(pos)) => (pos + 1).min(last),
+        (BuiltinAction::MoveUp, Some(pos)) => pos.saturating_sub(1),
+        (BuiltinAction::Last, _) | (BuiltinAction::MoveUp, None) => last,
+        _ => 0,
+    };
+    Some(order[next])
+}
+
+/// The sidebar scroll offset, from `scroll`, that keeps `line` among the
+/// `visible` lines shown.
+pub(crate) fn follow(scroll: usize, line: usize, visible: usize) -> usize {
+    if line < scroll {
+        line
+    } else if line >= scroll + visible.max(1) {
+        line + 1 - visible.max(1)
+    } else {
+        scroll
+    }
+}
+
+/// The sidebar scroll offset after `action`, for a sidebar without items:
+/// one line at a time, or to either end (clamped when the sidebar renders).
+pub(crate) fn scroll(scroll: usize, action: BuiltinAction) -> usize {
+    match action {
+        BuiltinAction::MoveDown => scroll + 1,
+        BuiltinAction::MoveUp => scroll.saturating_sub(1),
+        BuiltinAction::Last => usize::MAX / 2,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_through_items_in_display_order() {
+        let order = [4, 0, 2];
+        assert_eq!(step(&order, None, BuiltinAction::MoveDown), Some(4));
+        assert_eq!(step(&order, None, BuiltinAction::MoveUp), Some(2));
+        assert_eq!(step(&order, Some(4), BuiltinAction::MoveDown), Some(0));
+        assert_eq!(step(&order, Some(2), BuiltinAction::MoveDown), Some(2));
+        assert_eq!(step(&order, Some(4), BuiltinAction::MoveUp), Some(4));
+        assert_eq!(step(&order, Some(0), BuiltinAction::Last), Some(2));
+        // An item that is gone restarts from an end.
+        assert_eq!(step(&order, Some(7), BuiltinAction::MoveDown), Some(4));
+        assert_eq!(step::<usize>(&[], Some(1), BuiltinAction::First), None);
+
+        assert_eq!(follow(10, 4, 5), 4);
+        assert_eq!(follow(10, 12, 5), 10);
+        assert_eq!(follow(10, 16, 5), 12);
+        assert_eq!(scroll(3, BuiltinAction::MoveUp), 2);
+        assert_eq!(scroll(3, BuiltinAction::First), 0);
+    }
+}
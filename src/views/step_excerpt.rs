@@ -0,0 +1,339 @@
+//! Log excerpts of failed steps in the Actions job list.
+//!
+//! While the sidebar lists the jobs of a run, the log of each job whose step
+//! failed is fetched once, and the last lines that step wrote are shown under
+//! it (`actions.failed_step_log_lines`). Most failures read there without
+//! opening the log with `L`. `space` on a focused job folds its excerpt, `z`
+//! every excerpt of the run.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use super::job_log;
+use crate::engine::{EngineHandle, EventSender, Request};
+use crate::markdown::renderer::{StyledLine, StyledSpan};
+use crate::theme::ResolvedTheme;
+use crate::types::{JobStep, RunConclusion, RunStatus, WorkflowJob};
+use crate::util::LruCache;
+
+/// Failed jobs of a run whose logs are fetched; the others are one `L` away.
+const MAX_JOBS: usize = 10;
+
+/// When a step ran. The API gives whole seconds, so `end` is one past them.
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+enum Excerpt {
+    Loading(Window),
+    Ready {
+        lines: Arc<[String]>,
+        /// Lines of the step before those kept.
+        dropped: usize,
+    },
+    /// No log to cut it from.
+    Missing(String),
+}
+
+/// The excerpts of the jobs shown, and which are folded.
+#[derive(Debug, Clone)]
+pub(crate) struct StepExcerpts {
+    /// Lines kept of a step; `0` turns excerpts off.
+    max_lines: usize,
+    by_job: LruCache<u64, Excerpt>,
+    /// Jobs whose log is being fetched.
+    awaited: HashSet<u64>,
+    folded: HashSet<u64>,
+}
+
+impl StepExcerpts {
+    /// Excerpts of `max_lines` lines, for up to `capacity` jobs.
+    pub(crate) fn new(max_lines: usize, capacity: usize) -> Self {
+        Self {
+            max_lines,
+            by_job: LruCache::new(capacity),
+            awaited: HashSet::new(),
+            folded: HashSet::new(),
+        }
+    }
+
+    /// The failed jobs of `jobs` with an excerpt to fetch.
+    fn unfetched<'a>(&self, jobs: &'a [WorkflowJob]) -> Vec<(&'a WorkflowJob, &'a JobStep)> {
+        if self.max_lines == 0 {
+            return Vec::new();
+        }
+        jobs.iter()
+            .filter_map(|job| Some((job, failed_step(job)?)))
+            .take(MAX_JOBS)
+            .filter(|(job, _)| !self.by_job.contains_key(&job.id))
+            .collect()
+    }
+
+    /// Whether [`Self::fetch`] has logs to ask for.
+    pub(crate) fn wants(&self, jobs: &[WorkflowJob]) -> bool {
+        !self.unfetched(jobs).is_empty()
+    }
+
+    /// Fetch the logs of the failed jobs of `jobs`, a run of `owner/repo`,
+    /// not fetched yet.
+    pub(crate) fn fetch(
+        &mut self,
+        jobs: &[WorkflowJob],
+        (owner, repo, host): (&str, &str, Option<&str>),
+        engine: &EngineHandle,
+        reply_tx: &EventSender,
+    ) {
+        for (job, step) in self.unfetched(jobs) {
+            engine.send(Request::FetchJobLogs {
+                owner: owner.to_owned(),
+                repo: repo.to_owned(),
+                job_id: job.id,
+                host: host.map(str::to_owned),
+                reply_tx: reply_tx.clone(),
+            });
+            let window = Window {
+                start: step.started_at,
+                end: step.completed_at.map(|end| end + TimeDelta::seconds(1)),
+            };
+            self.by_job.insert(job.id, Excerpt::Loading(window));
+            self.awaited.insert(job.id);
+        }
+    }
+
+    /// Take the fetched log of `job_id`; returns whether it was awaited.
+    pub(crate) fn on_fetched(&mut self, job_id: u64, log: Option<&str>) -> bool {
+        if !self.awaited.remove(&job_id) {
+            return false;
+        }
+        let Some(&Excerpt::Loading(window)) = self.by_job.get(&job_id) else {
+            return false;
+        };
+        let excerpt = match log {
+            Some(log) => {
+                let (lines, dropped) = cut(log, window, self.max_lines);
+                Excerpt::Ready {
+                    lines: lines.into(),
+                    dropped,
+                }
+            }
+            None => Excerpt::Missing("GitHub kept no log for this job".to_owned()),
+        };
+        self.by_job.insert(job_id, excerpt);
+        true
+    }
+
+    /// A log fetch failed: the engine does not say whose, so every awaited
+    /// excerpt shows `message`. Returns whether any was awaited.
+    pub(crate) fn on_error(&mut self, message: &str) -> bool {
+        let awaited = std::mem::take(&mut self.awaited);
+        for &job_id in &awaited {
+            self.by_job
+                .insert(job_id, Excerpt::Missing(message.to_owned()));
+        }
+        !awaited.is_empty()
+    }
+
+    /// Whether `job` shows an excerpt.
+    pub(crate) fn has(&self, job: &WorkflowJob) -> bool {
+        failed_step(job).is_some() && self.by_job.contains_key(&job.id)
+    }
+
+    /// Fold or unfold the excerpt of `job_id`.
+    pub(crate) fn toggle(&mut self, job_id: u64) {
+        if !self.folded.remove(&job_id) {
+            self.folded.insert(job_id);
+        }
+    }
+
+    /// Fold every excerpt of `jobs`, or unfold them when all are folded.
+    /// Returns whether any is shown.
+    pub(crate) fn toggle_all(&mut self, jobs: &[WorkflowJob]) -> bool {
+        let shown: Vec<u64> = jobs
+            .iter()
+            .filter(|job| self.has(job))
+            .map(|job| job.id)
+            .collect();
+        if shown.iter().all(|id| self.folded.contains(id)) {
+            for id in &shown {
+                self.folded.remove(id);
+            }
+        } else {
+            self.folded.extend(&shown);
+        }
+        !shown.is_empty()
+    }
+
+    /// Lines shown under `step` of `job`: its excerpt when it is the step
+    /// that failed, indented by `indent` columns.
+    pub(crate) fn lines(
+        &self,
+        job: &WorkflowJob,
+        step: &JobStep,
+        indent: usize,
+        theme: &ResolvedTheme,
+    ) -> Vec<StyledLine> {
+        if failed_step(job).is_none_or(|failed| failed.number != step.number) {
+            return Vec::new();
+        }
+        let Some(excerpt) = self.by_job.get(&job.id) else {
+            return Vec::new();
+        };
+        let pad = " ".repeat(indent);
+        let note = |text: &str| {
+            StyledLine::from_spans(vec![
+                StyledSpan::text(pad.clone(), theme.text_faint),
+                StyledSpan::text(text, theme.text_faint),
+            ])
+        };
+        let (lines, dropped) = match excerpt {
+            Excerpt::Loading(_) => {
+                return vec![note(&format!(
+                    "Loading the step's log{}",
+                    crate::components::ellipsis(theme.chrome)
+                ))];
+            }
+            Excerpt::Missing(message) => return vec![note(message)],
+            Excerpt::Ready { lines, .. } if lines.is_empty() => {
+                return vec![note("The step wrote no log lines")];
+            }
+            Excerpt::Ready { lines, dropped } => (lines, *dropped),
+        };
+        let folded = self.folded.contains(&job.id);
+        let (open, closed) = if theme.chrome.ascii_only() {
+            ("v ", "> ")
+        } else {
+            ("\u{25be} ", "\u{25b8} ")
+        };
+        let title = if dropped > 0 {
+            format!("Last {} lines of the step's log", lines.len())
+        } else {
+            "The step's log".to_owned()
+        };
+        let mut header = vec![
+            StyledSpan::text(pad.clone(), theme.text_faint),
+            StyledSpan::bold(
+                format!("{}{title}", if folded { closed } else { open }),
+                theme.text_primary,
+            ),
+        ];
+        if folded {
+            header.push(StyledSpan::text(
+                format!(" ({} lines)", lines.len()),
+                theme.text_faint,
+            ));
+        }
+        let mut out = vec![StyledLine::from_spans(header)];
+        if !folded {
+            let body_pad = " ".repeat(indent + 2);
+            out.extend(
+                lines
+                    .iter()
+                    .map(|line| job_log::log_line(line, &body_pad, theme)),
+            );
+        }
+        out
+    }
+}
+
+/// The step that failed a completed job, if the job failed.
+fn failed_step(job: &WorkflowJob) -> Option<&JobStep> {
+    let failed = |conclusion| {
+        matches!(
+            conclusion,
+            Some(RunConclusion::Failure | RunConclusion::TimedOut)
+        )
+    };
+    if job.status != RunStatus::Completed || !failed(job.conclusion) {
+        return None;
+    }
+    job.steps.iter().find(|step| failed(step.conclusion))
+}
+
+/// The last `max` lines `log` has from `window`, by their timestamps, and how
+/// many were cut before them. Group markers are dropped, keeping the titles.
+fn cut(log: &str, window: Window, max: usize) -> (Vec<String>, usize) {
+    let lines: Vec<(Option<DateTime<Utc>>, &str)> = log
+        .lines()
+        .map(|line| {
+            let (stamp, rest) =
+                job_log::split_timestamp(line.strip_prefix('\u{feff}').unwrap_or(line));
+            let at = stamp
+                .and_then(|stamp| DateTime::parse_from_rfc3339(stamp).ok())
+                .map(|at| at.with_timezone(&Utc));
+            (at, rest)
+        })
+        .filter(|(_, line)| !line.starts_with("##[endgroup]"))
+        .collect();
+    let within = |at: Option<DateTime<Utc>>| {
+        at.is_some_and(|at| {
+            window.start.is_none_or(|start| at >= start) && window.end.is_none_or(|end| at < end)
+        })
+    };
+    let mut step: Vec<&str> = lines
+        .iter()
+        .filter(|(at, _)| within(*at))
+        .map(|(_, line)| *line)
+        .collect();
+    if step.is_empty() {
+        // No timestamps to go by: the failure is near the end.
+        step = lines.iter().map(|(_, line)| *line).collect();
+    }
+    let dropped = step.len().saturating_sub(max);
+    let kept = step[dropped..]
+        .iter()
+        .map(|line| line.strip_prefix("##[group]").unwrap_or(line).to_owned())
+        .collect();
+    (kept, dropped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn cuts_the_failed_step_from_the_log() {
+        let log = "\u{feff}2026-03-01T10:00:00.1234567Z ##[group]Run actions/checkout@v4\n\
+                   2026-03-01T10:00:01.2Z ##[endgroup]\n\
+                   2026-03-01T10:00:02.0Z ##[group]Run cargo test\n\
+                   2026-03-01T10:00:02.5Z ##[endgroup]\n\
+                   2026-03-01T10:00:03.0Z test api ... FAILED\n\
+                   2026-03-01T10:00:04.9Z ##[error]Process completed with exit code 101.\n\
+                   2026-03-01T10:00:05.0Z Post job cleanup.\n";
+        let window = Window {
+            start: Some(at("2026-03-01T10:00:02Z")),
+            end: Some(at("2026-03-01T10:00:05Z")),
+        };
+        let (lines, dropped) = cut(log, window, 30);
+        assert_eq!(
+            lines,
+            [
+                "Run cargo test",
+                "test api ... FAILED",
+                "##[error]Process completed with exit code 101."
+            ]
+        );
+        assert_eq!(dropped, 0);
+
+        let (lines, dropped) = cut(log, window, 1);
+        assert_eq!(lines, ["##[error]Process completed with exit code 101."]);
+        assert_eq!(dropped, 2);
+
+        // Without timestamps, the end of the log.
+        let (lines, dropped) = cut("one\ntwo\nthree\n", window, 2);
+        assert_eq!(
+            (lines, dropped),
+            (vec!["two".to_owned(), "three".to_owned()], 1)
+        );
+    }
+}
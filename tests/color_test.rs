@@ -1,5 +1,5 @@
 use gh_board::color::{Color, ColorDepth};
-use gh_board::config::types::AppConfig;
+use gh_board::config::types::{AppConfig, LabelTint};
 use gh_board::theme::{Background, ResolvedTheme};
 
 /// Extract the 16-color index from a crossterm color.  Works for both named
@@ -422,6 +422,35 @@ primary = "#xyz"
     );
 }
 
+#[test]
+fn label_tints_resolve_in_config_order() {
+    let toml_str = r##"
+[theme.ui.table]
+label_tint = "state"
+
+[theme.colors.labels]
+"priority:high" = "#5f0000"
+"good first issue" = "22"
+"##;
+
+    let config: AppConfig = toml::from_str(toml_str).unwrap();
+    let theme = ResolvedTheme::resolve(&config.theme, Background::Dark);
+    assert_eq!(theme.label_tint, LabelTint::State);
+    assert_eq!(
+        theme.label_color(&["Good First Issue", "Priority:High"]),
+        Some(Color::Hex {
+            r: 0x5f,
+            g: 0,
+            b: 0
+        })
+    );
+    assert_eq!(
+        theme.label_color(&["good first issue"]),
+        Some(Color::Ansi256(22))
+    );
+    assert_eq!(theme.label_color(&["docs"]), None);
+}
+
 // ---------------------------------------------------------------------------
 // T045: 16-color degradation produces reasonable approximations
 // ---------------------------------------------------------------------------
@@ -1,7 +1,10 @@
 use std::path::Path;
 
+use chrono::NaiveTime;
 use gh_board::config::loader::load_config;
-use gh_board::config::types::AppConfig;
+use gh_board::config::types::{
+    AppConfig, BoardGrouping, IssueFilterKind, PreviewTab, SshBrowserMode,
+};
 
 #[test]
 fn parse_minimal_config() {
@@ -117,6 +120,23 @@ string = "#00ff00"
     );
 }
 
+#[test]
+fn ui_render_modes_pin_chrome_and_icons() {
+    use gh_board::theme::{Background, Chrome, ResolvedTheme};
+
+    let config: AppConfig = toml::from_str("[theme.ui]\nascii = true\n").unwrap();
+    let theme = ResolvedTheme::resolve(&config.theme, Background::Dark);
+    assert_eq!(theme.chrome, Chrome::Ascii);
+    assert_eq!(theme.icons.pr_open, "o");
+
+    // Screen-reader mode wins over ASCII and uses words.
+    let config: AppConfig =
+        toml::from_str("[theme.ui]\nascii = true\nscreen_reader = true\n").unwrap();
+    let theme = ResolvedTheme::resolve(&config.theme, Background::Dark);
+    assert_eq!(theme.chrome, Chrome::Plain);
+    assert_eq!(theme.icons.pr_open, "open");
+}
+
 #[test]
 fn parse_prefetch_pr_details() {
     let toml = r"
@@ -254,6 +274,244 @@ date_format = "%Y-%m-%d"
     assert_eq!(config.defaults.date_format.as_deref(), Some("%Y-%m-%d"));
 }
 
+#[test]
+fn parse_timezone() {
+    let toml = r#"
+[defaults]
+timezone = "Europe/Paris"
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.defaults.timezone.as_deref(), Some("Europe/Paris"));
+}
+
+#[test]
+fn parse_hide_drafts_and_wip_patterns() {
+    let toml = r#"
+[defaults]
+wip_patterns = ["WIP", "do not merge"]
+
+[[pr_filters]]
+title = "Needs Review"
+filters = "is:open review-requested:@me"
+hide_drafts = true
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.defaults.wip_patterns,
+        Some(vec!["WIP".to_owned(), "do not merge".to_owned()])
+    );
+    assert_eq!(config.pr_filters[0].hide_drafts, Some(true));
+}
+
+#[test]
+fn parse_refresh_intervals_and_quiet_hours() {
+    let toml = r#"
+[github]
+refetch_interval_minutes = 30
+quiet_hours = { start = "22:00", end = "07:30" }
+request_timeout_seconds = 45
+
+[[pr_filters]]
+title = "Needs my review"
+filters = "is:open review-requested:@me"
+refetch_interval_minutes = 2
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.pr_filters[0].refetch_interval_minutes, Some(2));
+    assert_eq!(config.github.request_timeout_seconds, Some(45));
+    let quiet = config.github.quiet_hours.unwrap();
+    let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    assert!(quiet.contains(at(23, 15)));
+    assert!(quiet.contains(at(7, 0)));
+    assert!(!quiet.contains(at(7, 30)));
+    assert!(!quiet.contains(at(12, 0)));
+}
+
+#[test]
+fn parse_mixed_issue_filter() {
+    let toml = r#"
+[[issues_filters]]
+title = "Everything"
+filters = "is:open involves:@me"
+type = "any"
+
+[[issues_filters]]
+title = "Issues"
+filters = "is:open author:@me"
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.issues_filters[0].kind, IssueFilterKind::Any);
+    assert_eq!(config.issues_filters[1].kind, IssueFilterKind::Issue);
+}
+
+#[test]
+fn parse_issue_board() {
+    let toml = r#"
+[[issues_filters]]
+title = "Triage"
+filters = "repo:o/r is:open"
+board = { by = "label", columns = ["todo", "in progress", "done"] }
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    let board = config.issues_filters[0].board.as_ref().unwrap();
+    assert_eq!(board.by, BoardGrouping::Label);
+    assert_eq!(board.columns, ["todo", "in progress", "done"]);
+}
+
+#[test]
+fn parse_tab_preview_settings() {
+    let toml = r#"
+[[pr_filters]]
+title = "CI failures"
+filters = "is:open author:@me status:failure"
+preview = true
+preview_tab = "checks"
+
+[[issues_filters]]
+title = "Triage"
+filters = "is:open no:label"
+preview = false
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.pr_filters[0].preview, Some(true));
+    assert_eq!(config.pr_filters[0].preview_tab, Some(PreviewTab::Checks));
+    assert_eq!(config.issues_filters[0].preview, Some(false));
+    assert_eq!(config.issues_filters[0].preview_tab, None);
+}
+
+#[test]
+fn parse_stale_thresholds() {
+    let toml = r"
+[defaults.stale]
+warn_days = 7
+alert_days = 30
+";
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.defaults.stale.warn_days, Some(7));
+    assert_eq!(config.defaults.stale.alert_days, Some(30));
+}
+
+#[test]
+fn parse_pr_size_thresholds() {
+    let toml = r"
+[defaults.pr_size]
+xs = 20
+l = 2000
+";
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.defaults.pr_size.xs, Some(20));
+    assert_eq!(config.defaults.pr_size.s, None);
+    assert_eq!(config.defaults.pr_size.l, Some(2000));
+}
+
+#[test]
+fn parse_detail_cache_size() {
+    let toml = r"
+[defaults]
+detail_cache_size = 25
+";
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.defaults.detail_cache_size, Some(25));
+}
+
+#[test]
+fn parse_browser_ssh_settings() {
+    let toml = r#"
+[browser]
+ssh = "command"
+ssh_command = "ssh laptop open {{.Url}}"
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.browser.ssh, Some(SshBrowserMode::Command));
+    assert_eq!(
+        config.browser.ssh_command.as_deref(),
+        Some("ssh laptop open {{.Url}}")
+    );
+}
+
+#[test]
+fn parse_spell_settings() {
+    let toml = r#"
+[spell]
+language = "en_GB"
+words = ["gh-board", "iocraft"]
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.spell.enabled, None);
+    assert_eq!(config.spell.language.as_deref(), Some("en_GB"));
+    assert_eq!(config.spell.words, ["gh-board", "iocraft"]);
+}
+
+#[test]
+fn parse_dispatch_presets() {
+    let toml = r#"
+[[actions.dispatch_presets]]
+name = "Deploy staging"
+workflow = "deploy.yml"
+ref = "main"
+inputs = { environment = "staging", dry_run = "false" }
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    let preset = &config.actions.dispatch_presets[0];
+    assert_eq!(preset.name, "Deploy staging");
+    assert!(preset.repo.is_none());
+    assert_eq!(preset.git_ref.as_deref(), Some("main"));
+    let inputs: Vec<_> = preset.inputs.iter().collect();
+    assert_eq!(
+        inputs,
+        [
+            (&"environment".to_owned(), &"staging".to_owned()),
+            (&"dry_run".to_owned(), &"false".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn parse_multi_repo_actions_filter() {
+    let toml = r#"
+[[actions_filters]]
+title = "Platform"
+repos = ["myorg/infra", "org:myorg-services"]
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    let filter = &config.actions_filters[0];
+    assert!(filter.repo.is_empty());
+    assert_eq!(filter.repos, ["myorg/infra", "org:myorg-services"]);
+}
+
+#[test]
+fn parse_events_filters() {
+    let toml = r#"
+[[events_filters]]
+title = "Acme"
+org = "acme"
+
+[[events_filters]]
+title = "Repo changes"
+org = "acme"
+actor = "alice"
+action = "repo."
+limit = 20
+
+[[events_filters]]
+title = "Feed"
+repos = ["acme/api"]
+received = true
+"#;
+    let config: AppConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.events_filters.len(), 3);
+    assert_eq!(config.events_filters[0].org.as_deref(), Some("acme"));
+    assert!(config.events_filters[0].actor.is_none());
+    let filter = &config.events_filters[1];
+    assert_eq!(filter.actor.as_deref(), Some("alice"));
+    assert_eq!(filter.action.as_deref(), Some("repo."));
+    assert_eq!(filter.limit, Some(20));
+    let feed = &config.events_filters[2];
+    assert!(feed.org.is_none());
+    assert_eq!(feed.repos, ["acme/api"]);
+    assert!(feed.received);
+}
+
 #[test]
 fn parse_actions_config() {
     let toml = r#"
@@ -1,9 +1,17 @@
 use std::time::Duration;
 
 use gh_board::config::types::PrFilter;
-use gh_board::engine::{Engine, Event, PrRef, Request, StubEngine};
+use gh_board::engine::{Engine, Event, PrRef, Request, StubEngine, event_channel};
 use gh_board::types::PullRequest;
 
+/// Block until the engine replies on `rx`, giving up after `timeout`.
+fn recv_timeout(rx: &async_channel::Receiver<Event>, timeout: Duration) -> Option<Event> {
+    smol::block_on(smol::future::or(async { rx.recv().await.ok() }, async {
+        smol::Timer::after(timeout).await;
+        None
+    }))
+}
+
 fn load_fixture_prs() -> Vec<PullRequest> {
     let json = include_str!("fixtures/stub_prs.json");
     serde_json::from_str(json).expect("valid stub_prs.json fixture")
@@ -17,7 +25,7 @@ fn stub_engine_fetch_prs_returns_fixture_data() {
     let stub = StubEngine::new(prs.clone(), vec![], vec![]);
 
     let handle = stub.start();
-    let (tx, rx) = std::sync::mpsc::channel::<Event>();
+    let (tx, rx) = event_channel();
 
     let filter = PrFilter {
         title: "All".into(),
@@ -25,6 +33,11 @@ fn stub_engine_fetch_prs_returns_fixture_data() {
         limit: None,
         host: None,
         layout: None,
+        hide_drafts: None,
+        preview: None,
+        preview_tab: None,
+        refetch_interval_minutes: None,
+        badge: None,
     };
     handle.send(Request::FetchPrs {
         filter_idx: 0,
@@ -33,17 +46,18 @@ fn stub_engine_fetch_prs_returns_fixture_data() {
         reply_tx: tx,
     });
 
-    let event = rx
-        .recv_timeout(Duration::from_secs(2))
-        .expect("engine should reply within 2 seconds");
+    let event =
+        recv_timeout(&rx, Duration::from_secs(2)).expect("engine should reply within 2 seconds");
 
     match event {
         Event::PrsFetched {
             filter_idx,
             prs: fetched_prs,
+            complete,
             ..
         } => {
             assert_eq!(filter_idx, 0);
+            assert!(complete);
             assert_eq!(fetched_prs.len(), prs.len());
             assert_eq!(fetched_prs[0].number, 42);
             assert_eq!(fetched_prs[0].title, "Fix: resolve widget layout overflow");
@@ -57,18 +71,18 @@ fn stub_engine_mutations_succeed_instantly() {
     let stub = StubEngine::default();
 
     let handle = stub.start();
-    let (tx, rx) = std::sync::mpsc::channel::<Event>();
+    let (tx, rx) = event_channel();
 
     handle.send(Request::ClosePr {
         owner: "example".into(),
         repo: "repo".into(),
         number: 1,
+        host: None,
         reply_tx: tx,
     });
 
-    let event = rx
-        .recv_timeout(Duration::from_secs(2))
-        .expect("engine should reply within 2 seconds");
+    let event =
+        recv_timeout(&rx, Duration::from_secs(2)).expect("engine should reply within 2 seconds");
 
     assert!(
         matches!(event, Event::MutationOk { .. }),
@@ -81,7 +95,7 @@ fn stub_engine_detail_returns_fetch_error() {
     let stub = StubEngine::default();
 
     let handle = stub.start();
-    let (tx, rx) = std::sync::mpsc::channel::<Event>();
+    let (tx, rx) = event_channel();
 
     handle.send(Request::FetchPrDetail {
         pr_ref: PrRef {
@@ -93,12 +107,12 @@ fn stub_engine_detail_returns_fetch_error() {
             head_ref: "feature".into(),
         },
         force: false,
+        host: None,
         reply_tx: tx,
     });
 
-    let event = rx
-        .recv_timeout(Duration::from_secs(2))
-        .expect("engine should reply within 2 seconds");
+    let event =
+        recv_timeout(&rx, Duration::from_secs(2)).expect("engine should reply within 2 seconds");
 
     assert!(
         matches!(event, Event::FetchError { .. }),
@@ -111,7 +125,7 @@ fn stub_engine_fetch_run_by_id_returns_not_found() {
     let stub = StubEngine::default();
 
     let handle = stub.start();
-    let (tx, rx) = std::sync::mpsc::channel::<Event>();
+    let (tx, rx) = event_channel();
 
     handle.send(Request::FetchRunById {
         owner: "example".into(),
@@ -121,9 +135,8 @@ fn stub_engine_fetch_run_by_id_returns_not_found() {
         reply_tx: tx,
     });
 
-    let event = rx
-        .recv_timeout(Duration::from_secs(2))
-        .expect("engine should reply within 2 seconds");
+    let event =
+        recv_timeout(&rx, Duration::from_secs(2)).expect("engine should reply within 2 seconds");
 
     assert!(
         matches!(
@@ -143,7 +156,7 @@ fn stub_engine_watch_run_returns_completed() {
     let stub = StubEngine::default();
 
     let handle = stub.start();
-    let (tx, rx) = std::sync::mpsc::channel::<Event>();
+    let (tx, rx) = event_channel();
 
     handle.send(Request::WatchRun {
         owner: "acme".into(),
@@ -153,9 +166,8 @@ fn stub_engine_watch_run_returns_completed() {
         reply_tx: tx,
     });
 
-    let event = rx
-        .recv_timeout(Duration::from_secs(2))
-        .expect("engine should reply within 2 seconds");
+    let event =
+        recv_timeout(&rx, Duration::from_secs(2)).expect("engine should reply within 2 seconds");
 
     match event {
         Event::WatchedRunUpdated {
@@ -15,6 +15,7 @@ fn override_replaces_default() {
             key: "v".to_owned(),
             builtin: Some("comment".to_owned()),
             command: None,
+            script: None,
             name: Some("Comment via v".to_owned()),
         }],
         ..Default::default()
@@ -35,6 +36,7 @@ fn override_approve_key() {
             key: "A".to_owned(),
             builtin: Some("approve".to_owned()),
             command: None,
+            script: None,
             name: Some("Approve".to_owned()),
         }],
         ..Default::default()
@@ -64,12 +66,14 @@ fn context_priority_over_universal() {
             key: "z".to_owned(),
             builtin: Some("quit".to_owned()),
             command: None,
+            script: None,
             name: None,
         }],
         prs: vec![Keybinding {
             key: "z".to_owned(),
             builtin: Some("approve".to_owned()),
             command: None,
+            script: None,
             name: None,
         }],
         ..Default::default()
@@ -164,7 +168,17 @@ fn builtin_action_roundtrip() {
         "reopen",
         "mark_ready",
         "merge",
+        "enqueue",
+        "watch_pr",
         "update_from_base",
+        "slice_failing_ci",
+        "slice_approved_green",
+        "slice_changes_requested",
+        "toggle_drafts",
+        "open_deployment",
+        "copy_comment_url",
+        "open_comment",
+        "toggle_bot_comments",
         "label",
         "mark_read",
         "mark_all_read",
@@ -180,6 +194,8 @@ fn builtin_action_roundtrip() {
         "rerun_failed",
         "rerun_all",
         "cancel_run",
+        "cleanup_runs",
+        "compare_last_success",
         "jump_to_run",
         "jump_to_pr",
         "go_back",
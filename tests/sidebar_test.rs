@@ -3,9 +3,12 @@ use gh_board::components::sidebar::SidebarTab;
 use gh_board::components::sidebar_tabs;
 use gh_board::theme::ResolvedTheme;
 use gh_board::types::{
-    Actor, CheckConclusion, CheckRun, CheckStatus, Commit, File, FileChangeType, PrDetail, PrState,
-    PullRequest, Review, ReviewState, TimelineEvent,
+    Actor, AwaitingRun, CheckConclusion, CheckRun, CheckStatus, CodeOwners, Commit,
+    CommitSignature, File, FileChangeType, FileViewedState, MergeQueueEntry, MergeQueueEntryState,
+    MergeRequirements, PageCursor, PrDetail, PrState, PullRequest, Review, ReviewState,
+    ReviewTimes, TimelineEvent,
 };
+use unicode_width::UnicodeWidthStr;
 
 fn test_theme() -> ResolvedTheme {
     use gh_board::config::types::Theme;
@@ -30,6 +33,7 @@ fn test_pr() -> PullRequest {
         additions: 10,
         deletions: 5,
         head_ref: "feature-branch".to_owned(),
+        head_sha: String::new(),
         base_ref: "main".to_owned(),
         labels: vec![gh_board::types::Label {
             name: "bug".to_owned(),
@@ -43,7 +47,9 @@ fn test_pr() -> PullRequest {
         comments: Vec::new(),
         review_threads: Vec::new(),
         review_requests: Vec::new(),
+        team_review_requests: Vec::new(),
         reviews: Vec::new(),
+        review_times: ReviewTimes::default(),
         timeline_events: Vec::new(),
         files: Vec::new(),
         check_runs: vec![
@@ -54,8 +60,10 @@ fn test_pr() -> PullRequest {
                 url: None,
                 workflow_run_id: None,
                 workflow_name: None,
+                job_id: None,
                 started_at: None,
                 completed_at: None,
+                is_required: false,
             },
             CheckRun {
                 name: "Lint".to_owned(),
@@ -64,8 +72,10 @@ fn test_pr() -> PullRequest {
                 url: None,
                 workflow_run_id: None,
                 workflow_name: None,
+                job_id: None,
                 started_at: None,
                 completed_at: None,
+                is_required: false,
             },
         ],
         updated_at: Utc::now(),
@@ -76,6 +86,7 @@ fn test_pr() -> PullRequest {
             name: "repo".to_owned(),
         }),
         comment_count: 3,
+        reactions: gh_board::types::ReactionGroups::default(),
         author_association: None,
         participants: Vec::new(),
         merge_state_status: None,
@@ -96,6 +107,7 @@ fn test_detail() -> PrDetail {
             state: ReviewState::Approved,
             body: "LGTM".to_owned(),
             submitted_at: Some(Utc::now() - Duration::hours(1)),
+            on_behalf_of: Vec::new(),
         }],
         review_threads: Vec::new(),
         timeline_events: vec![
@@ -103,12 +115,17 @@ fn test_detail() -> PrDetail {
                 author: Some("commenter".to_owned()),
                 body: "Nice work!".to_owned(),
                 created_at: Utc::now() - Duration::hours(3),
+                url: Some("https://github.com/owner/repo/pull/42#issuecomment-1".to_owned()),
+                is_bot: false,
+                is_minimized: false,
             },
             TimelineEvent::Review {
                 author: Some("reviewer".to_owned()),
                 state: ReviewState::Approved,
                 body: "LGTM".to_owned(),
                 submitted_at: Utc::now() - Duration::hours(1),
+                url: None,
+                is_bot: false,
             },
             TimelineEvent::Merged {
                 actor: Some("merger".to_owned()),
@@ -122,6 +139,7 @@ fn test_detail() -> PrDetail {
                 author: Some("Author One".to_owned()),
                 committed_date: Some(Utc::now() - Duration::days(1)),
                 check_state: None,
+                signature: CommitSignature::Verified,
             },
             Commit {
                 sha: "def456".to_owned(),
@@ -129,6 +147,7 @@ fn test_detail() -> PrDetail {
                 author: None,
                 committed_date: None,
                 check_state: None,
+                signature: CommitSignature::Unsigned,
             },
         ],
         files: vec![
@@ -137,16 +156,24 @@ fn test_detail() -> PrDetail {
                 additions: 10,
                 deletions: 3,
                 status: Some(FileChangeType::Modified),
+                viewer_state: FileViewedState::Unviewed,
             },
             File {
                 path: "src/new.rs".to_owned(),
                 additions: 50,
                 deletions: 0,
                 status: Some(FileChangeType::Added),
+                viewer_state: FileViewedState::Unviewed,
             },
         ],
         mergeable: None,
         behind_by: None,
+        commits_page: PageCursor::default(),
+        files_page: PageCursor::default(),
+        deployments: Vec::new(),
+        merge_requirements: MergeRequirements::default(),
+        awaiting_approval: vec![],
+        code_owners: CodeOwners::default(),
     }
 }
 
@@ -223,6 +250,8 @@ fn sidebar_meta_line_count_with_all_fields() {
         lines_added: Some("+10".into()),
         lines_deleted: Some("-5".into()),
         reactions_text: None,
+        deployments: Vec::new(),
+        context_lines: Vec::new(),
         date_fg: Color::White,
         date_age_fg: Color::White,
         additions_fg: Color::Green,
@@ -271,6 +300,8 @@ fn sidebar_meta_line_count_minimal() {
         lines_added: None,
         lines_deleted: None,
         reactions_text: None,
+        deployments: Vec::new(),
+        context_lines: Vec::new(),
         date_fg: Color::White,
         date_age_fg: Color::White,
         additions_fg: Color::Green,
@@ -293,7 +324,7 @@ fn sidebar_meta_line_count_minimal() {
 fn activity_renders_timeline_events() {
     let detail = test_detail();
     let theme = test_theme();
-    let lines = sidebar_tabs::render_activity(&detail, &theme, ColorDepth::TrueColor);
+    let lines = sidebar_tabs::render_activity(&detail, &theme, ColorDepth::TrueColor, None, false);
     let text: String = lines
         .iter()
         .flat_map(|l| l.spans.iter())
@@ -307,6 +338,59 @@ fn activity_renders_timeline_events() {
     assert!(text.contains("merged"), "should show 'merged' action");
 }
 
+#[test]
+fn activity_marks_selected_event() {
+    let detail = test_detail();
+    let theme = test_theme();
+    let lines =
+        sidebar_tabs::render_activity(&detail, &theme, ColorDepth::TrueColor, Some(1), false);
+    let at = sidebar_tabs::activity_event_line(&detail, &theme, ColorDepth::TrueColor, 1, false);
+    let header: String = lines[at].spans.iter().map(|s| s.text.as_str()).collect();
+    assert!(header.starts_with(&theme.icons.select_cursor));
+    assert!(header.contains("reviewer"));
+    let marked = lines
+        .iter()
+        .filter(|l| {
+            l.spans
+                .first()
+                .is_some_and(|s| s.text.starts_with(&theme.icons.select_cursor))
+        })
+        .count();
+    assert_eq!(marked, 1);
+}
+
+#[test]
+fn activity_hides_bot_comments_until_expanded() {
+    use chrono::Utc;
+
+    let mut detail = test_detail();
+    detail.timeline_events.push(TimelineEvent::Comment {
+        author: Some("codecov".to_owned()),
+        body: "Coverage report".to_owned(),
+        created_at: Utc::now(),
+        url: None,
+        is_bot: true,
+        is_minimized: false,
+    });
+    let theme = test_theme();
+    let text = |show_noise| -> String {
+        sidebar_tabs::render_activity(&detail, &theme, ColorDepth::TrueColor, None, show_noise)
+            .iter()
+            .flat_map(|l| l.spans.iter())
+            .map(|s| s.text.as_str())
+            .collect()
+    };
+
+    let collapsed = text(false);
+    assert!(collapsed.contains("1 bot or minimized comment hidden"));
+    assert!(!collapsed.contains("Coverage report"));
+    assert!(collapsed.contains("Nice work!"));
+
+    let expanded = text(true);
+    assert!(expanded.contains("Coverage report"));
+    assert!(!expanded.contains("hidden"));
+}
+
 #[test]
 fn activity_empty_shows_placeholder() {
     let detail = PrDetail {
@@ -318,9 +402,15 @@ fn activity_empty_shows_placeholder() {
         files: Vec::new(),
         mergeable: None,
         behind_by: None,
+        commits_page: PageCursor::default(),
+        files_page: PageCursor::default(),
+        deployments: Vec::new(),
+        merge_requirements: MergeRequirements::default(),
+        awaiting_approval: vec![],
+        code_owners: CodeOwners::default(),
     };
     let theme = test_theme();
-    let lines = sidebar_tabs::render_activity(&detail, &theme, ColorDepth::TrueColor);
+    let lines = sidebar_tabs::render_activity(&detail, &theme, ColorDepth::TrueColor, None, false);
     let text: String = lines
         .iter()
         .flat_map(|l| l.spans.iter())
@@ -350,6 +440,26 @@ fn commits_renders_sha_and_message() {
     );
     assert!(text.contains("Author One"), "should show author name");
     assert!(text.contains("def456"), "should show second commit SHA");
+    assert!(
+        !text.contains("unsigned"),
+        "unsigned commits are not flagged unless signatures are required"
+    );
+}
+
+#[test]
+fn commits_flag_signatures_when_required() {
+    let mut detail = test_detail();
+    detail.commits[0].signature = CommitSignature::from_state("UNKNOWN_KEY");
+    detail.merge_requirements.requires_signatures = true;
+    let theme = test_theme();
+    let lines = sidebar_tabs::render_commits(&detail, &theme);
+    let text: String = lines
+        .iter()
+        .flat_map(|l| l.spans.iter())
+        .map(|s| s.text.as_str())
+        .collect();
+    assert!(text.contains("1 of 2 commits unsigned, 1 of 2 commits not verified"));
+    assert!(text.contains("signature: unknown key"));
 }
 
 #[test]
@@ -363,6 +473,12 @@ fn commits_empty_shows_placeholder() {
         files: Vec::new(),
         mergeable: None,
         behind_by: None,
+        commits_page: PageCursor::default(),
+        files_page: PageCursor::default(),
+        deployments: Vec::new(),
+        merge_requirements: MergeRequirements::default(),
+        awaiting_approval: vec![],
+        code_owners: CodeOwners::default(),
     };
     let theme = test_theme();
     let lines = sidebar_tabs::render_commits(&detail, &theme);
@@ -382,7 +498,7 @@ fn commits_empty_shows_placeholder() {
 fn checks_renders_status_icons() {
     let pr = test_pr();
     let theme = test_theme();
-    let lines = sidebar_tabs::render_checks(&pr, &theme, 80);
+    let (lines, _) = sidebar_tabs::render_checks(&pr, &theme, 80, None);
     let text: String = lines
         .iter()
         .flat_map(|l| l.spans.iter())
@@ -400,7 +516,7 @@ fn checks_empty_shows_placeholder() {
     let mut pr = test_pr();
     pr.check_runs.clear();
     let theme = test_theme();
-    let lines = sidebar_tabs::render_checks(&pr, &theme, 80);
+    let (lines, _) = sidebar_tabs::render_checks(&pr, &theme, 80, None);
     let text: String = lines
         .iter()
         .flat_map(|l| l.spans.iter())
@@ -409,6 +525,52 @@ fn checks_empty_shows_placeholder() {
     assert!(text.contains("no checks"));
 }
 
+#[test]
+fn merge_status_lists_blockers_and_queue_position() {
+    let pr = test_pr();
+    let mut detail = test_detail();
+    detail.merge_requirements = MergeRequirements {
+        required_checks: vec!["Lint".to_owned()],
+        merge_queue_enabled: true,
+        queue_entry: Some(MergeQueueEntry {
+            position: 2,
+            state: MergeQueueEntryState::AwaitingChecks,
+            estimated_secs: Some(90),
+        }),
+        ..MergeRequirements::default()
+    };
+    let theme = test_theme();
+    let text: String = sidebar_tabs::render_merge_status(&pr, Some(&detail), &theme)
+        .iter()
+        .flat_map(|l| l.spans.iter())
+        .map(|s| s.text.as_str())
+        .collect();
+    assert!(text.contains("position 2 · awaiting checks · ~2m"));
+    assert!(text.contains("Required check `Lint` failed"));
+    assert!(!text.contains("Ready to merge"));
+
+    assert!(sidebar_tabs::render_merge_status(&pr, None, &theme).is_empty());
+}
+
+#[test]
+fn runs_awaiting_approval_are_listed_first() {
+    let mut detail = test_detail();
+    let theme = test_theme();
+    assert!(sidebar_tabs::render_awaiting_approval(Some(&detail), &theme).is_empty());
+    detail.awaiting_approval = vec![AwaitingRun {
+        run_id: 7,
+        workflow: "CI".to_owned(),
+    }];
+    let text: String = sidebar_tabs::render_awaiting_approval(Some(&detail), &theme)
+        .iter()
+        .flat_map(|l| l.spans.iter())
+        .map(|s| s.text.as_str())
+        .collect();
+    assert!(text.contains("Awaiting approval"));
+    assert!(text.contains("alt+a: approve"));
+    assert!(text.contains("  CI"));
+}
+
 // ---------------------------------------------------------------------------
 // T077: Files Changed tab tests
 // ---------------------------------------------------------------------------
@@ -417,7 +579,7 @@ fn checks_empty_shows_placeholder() {
 fn files_renders_paths_and_stats() {
     let detail = test_detail();
     let theme = test_theme();
-    let lines = sidebar_tabs::render_files(&detail, &theme, 80);
+    let (lines, _) = sidebar_tabs::render_files(&detail, &[], &theme, 80, None);
     let text: String = lines
         .iter()
         .flat_map(|l| l.spans.iter())
@@ -428,6 +590,67 @@ fn files_renders_paths_and_stats() {
     assert!(text.contains("M "), "should show Modified marker");
     assert!(text.contains("A "), "should show Added marker");
     assert!(text.contains("+10 -3"), "should show change stats");
+    assert!(
+        !text.contains("Code owners"),
+        "no CODEOWNERS, no owners block"
+    );
+}
+
+#[test]
+fn files_show_code_owners_and_their_approval() {
+    let mut detail = test_detail();
+    detail.code_owners = CodeOwners::parse("*.rs @acme/core\n/src/new.rs @reviewer\n");
+    let theme = test_theme();
+    let (lines, _) = sidebar_tabs::render_files(&detail, &[], &theme, 80, None);
+    let text: Vec<String> = lines
+        .iter()
+        .map(|l| l.spans.iter().map(|s| s.text.as_str()).collect())
+        .collect();
+    assert_eq!(text[0], "Code owners (1 pending)");
+    assert!(text[1].ends_with("@acme/core  1 file"));
+    assert!(text[2].ends_with("@reviewer  1 file"));
+    assert!(text.iter().any(|l| l == "  @acme/core"));
+}
+
+#[test]
+fn files_list_unviewed_first_under_a_viewed_count() {
+    let detail = test_detail();
+    let theme = test_theme();
+    let text = |viewed: &[FileViewedState]| -> Vec<String> {
+        sidebar_tabs::render_files(&detail, viewed, &theme, 80, None)
+            .0
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.text.as_str()).collect())
+            .collect()
+    };
+
+    let fresh = text(&[]);
+    assert_eq!(fresh[0], "Viewed 0/2");
+    assert!(fresh[1].contains("src/main.rs"));
+
+    let halfway = text(&[FileViewedState::Viewed, FileViewedState::Unviewed]);
+    assert_eq!(halfway[0], "Viewed 1/2");
+    assert!(halfway[1].contains("src/new.rs"), "unviewed file first");
+    assert!(halfway[2].contains("src/main.rs"));
+    assert!(halfway[2].starts_with(theme.icons.check_success.as_str()));
+}
+
+#[test]
+fn files_mark_the_selected_file() {
+    let detail = test_detail();
+    let theme = test_theme();
+    let (lines, selected) = sidebar_tabs::render_files(&detail, &[], &theme, 80, Some(1));
+    let text: Vec<String> = lines
+        .iter()
+        .map(|l| l.spans.iter().map(|s| s.text.as_str()).collect())
+        .collect();
+    let cursor = format!("{} ", theme.icons.select_cursor);
+    let line = selected.expect("the selected file has a line");
+    assert!(text[line].starts_with(&cursor));
+    assert!(text[line].contains("src/new.rs"));
+    // The other rows keep their columns aligned with the marked one.
+    assert!(text[line - 1].starts_with(&" ".repeat(cursor.width())));
+    assert!(text[line - 1].contains("src/main.rs"));
 }
 
 #[test]
@@ -441,9 +664,15 @@ fn files_empty_shows_placeholder() {
         files: Vec::new(),
         mergeable: None,
         behind_by: None,
+        commits_page: PageCursor::default(),
+        files_page: PageCursor::default(),
+        deployments: Vec::new(),
+        merge_requirements: MergeRequirements::default(),
+        awaiting_approval: vec![],
+        code_owners: CodeOwners::default(),
     };
     let theme = test_theme();
-    let lines = sidebar_tabs::render_files(&detail, &theme, 80);
+    let (lines, _) = sidebar_tabs::render_files(&detail, &[], &theme, 80, None);
     let text: String = lines
         .iter()
         .flat_map(|l| l.spans.iter())
@@ -452,6 +681,31 @@ fn files_empty_shows_placeholder() {
     assert!(text.contains("no files changed"));
 }
 
+#[test]
+fn files_footer_reports_paging_progress() {
+    let mut detail = test_detail();
+    detail.files_page = PageCursor {
+        total: 250,
+        next: Some("cursor".to_owned()),
+    };
+    let theme = test_theme();
+    let text = |detail: &PrDetail| -> String {
+        sidebar_tabs::render_files(detail, &[], &theme, 80, None)
+            .0
+            .iter()
+            .flat_map(|l| l.spans.iter())
+            .map(|s| s.text.as_str())
+            .collect()
+    };
+    assert!(text(&detail).contains("loading more\u{2026} (2 of 250 files)"));
+
+    detail.files_page.next = None;
+    assert!(text(&detail).contains("showing 2 of 250 files"));
+
+    detail.files_page.total = 2;
+    assert!(!text(&detail).contains("of 2 files"));
+}
+
 // ---------------------------------------------------------------------------
 // Checks: workflow grouping + duration
 // ---------------------------------------------------------------------------
@@ -470,8 +724,10 @@ fn checks_grouped_by_workflow() {
             url: None,
             workflow_run_id: Some(1),
             workflow_name: Some("CI".to_owned()),
+            job_id: None,
             started_at: Some(now - Duration::seconds(90)),
             completed_at: Some(now),
+            is_required: false,
         },
         CheckRun {
             name: "test".to_owned(),
@@ -480,8 +736,10 @@ fn checks_grouped_by_workflow() {
             url: None,
             workflow_run_id: Some(1),
             workflow_name: Some("CI".to_owned()),
+            job_id: None,
             started_at: Some(now - Duration::seconds(45)),
             completed_at: Some(now),
+            is_required: false,
         },
         CheckRun {
             name: "deploy".to_owned(),
@@ -490,8 +748,10 @@ fn checks_grouped_by_workflow() {
             url: None,
             workflow_run_id: Some(2),
             workflow_name: Some("Deploy".to_owned()),
+            job_id: None,
             started_at: Some(now - Duration::seconds(5)),
             completed_at: Some(now),
+            is_required: false,
         },
         CheckRun {
             name: "external-check".to_owned(),
@@ -500,13 +760,15 @@ fn checks_grouped_by_workflow() {
             url: None,
             workflow_run_id: None,
             workflow_name: None,
+            job_id: None,
             started_at: None,
             completed_at: None,
+            is_required: false,
         },
     ];
 
     let theme = test_theme();
-    let lines = sidebar_tabs::render_checks(&pr, &theme, 80);
+    let (lines, _) = sidebar_tabs::render_checks(&pr, &theme, 80, None);
     let text: String = lines
         .iter()
         .flat_map(|l| l.spans.iter())
@@ -569,7 +831,7 @@ fn checks_grouped_by_workflow() {
 fn checks_with_no_workflow_all_in_other_group() {
     let pr = test_pr(); // all check_runs have workflow_name: None
     let theme = test_theme();
-    let lines = sidebar_tabs::render_checks(&pr, &theme, 80);
+    let (lines, _) = sidebar_tabs::render_checks(&pr, &theme, 80, None);
     let text: String = lines
         .iter()
         .flat_map(|l| l.spans.iter())
@@ -581,3 +843,72 @@ fn checks_with_no_workflow_all_in_other_group() {
     assert!(text.contains("CI Build"));
     assert!(text.contains("Lint"));
 }
+
+#[test]
+fn checks_list_required_first_with_marker() {
+    let mut pr = test_pr();
+    // "Lint" is the second check but the only required one.
+    pr.check_runs[1].is_required = true;
+
+    let theme = test_theme();
+    let (lines, _) = sidebar_tabs::render_checks(&pr, &theme, 80, None);
+    let rows: Vec<String> = lines
+        .iter()
+        .map(|l| l.spans.iter().map(|s| s.text.as_str()).collect())
+        .filter(|t: &String| t.contains("CI Build") || t.contains("Lint"))
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert!(rows[0].starts_with('*') && rows[0].contains("Lint"));
+    assert!(rows[1].starts_with("  ") && rows[1].contains("CI Build"));
+}
+
+// ---------------------------------------------------------------------------
+// Links tab (issues)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn links_draw_parent_and_sub_issues_as_a_tree() {
+    use gh_board::types::{Issue, IssueLink, IssueLinks, IssueState, RepoRef};
+
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "number": 10,
+        "title": "Login flow",
+        "state": "OPEN",
+        "updated_at": "2024-05-02T10:00:00Z",
+        "created_at": "2024-04-20T10:00:00Z",
+        "url": "https://github.com/acme/web/issues/10",
+        "repo": {"owner": "acme", "name": "web"},
+    }))
+    .unwrap();
+    let link = |repo: &str, number, title: &str, state| IssueLink {
+        repo: RepoRef::from_full_name(repo).unwrap(),
+        number,
+        title: title.to_owned(),
+        state,
+        sub_issue_count: 0,
+    };
+    let links = IssueLinks {
+        parent: Some(link("acme/web", 1, "Auth epic", Some(IssueState::Open))),
+        children: vec![
+            link("acme/web", 11, "Form", Some(IssueState::Closed)),
+            link("acme/api", 4, "Token endpoint", Some(IssueState::Open)),
+        ],
+        blocked_by: vec![link("acme/web", 7, "", None)],
+        blocks: Vec::new(),
+    };
+    let theme = test_theme();
+    let (rendered, selected) = sidebar_tabs::render_issue_links(&issue, &links, Some(2), &theme);
+    let texts: Vec<String> = rendered
+        .iter()
+        .map(|l| l.spans.iter().map(|s| s.text.as_str()).collect())
+        .collect();
+    assert!(texts[0].contains("#1 Auth epic"));
+    assert!(texts[1].contains("\u{2514}\u{2500}") && texts[1].contains("#10 Login flow"));
+    assert!(texts[2].contains("\u{251c}\u{2500}") && texts[2].contains("#11 Form"));
+    assert!(texts[3].contains("acme/api#4 Token endpoint"));
+    assert!(texts[4].contains("1 of 2 closed"));
+    assert!(texts.iter().any(|t| t == "Blocked by"));
+    assert!(texts.last().unwrap().contains("#7"));
+    assert_eq!(selected, Some(3), "third node is the second sub-issue");
+    assert!(texts[3].starts_with(theme.icons.select_cursor.as_str()));
+}